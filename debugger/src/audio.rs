@@ -0,0 +1,236 @@
+use crate::resampler::{Resampler, ResamplerKind};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{Device, FromSample, SampleFormat, SizedSample, Stream, StreamConfig};
+use gba_core::audio::apu::SAMPLE_RATE;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::{error, info};
+
+const BUFFER_CAPACITY: usize = SAMPLE_RATE as usize / 4; // 250ms, the oldest samples are dropped on overflow
+pub const PACING_LATENCY: usize = SAMPLE_RATE as usize / 16; // 62.5ms, queued before audio pacing waits
+const RATE_CONTROL_TARGET: usize = PACING_LATENCY; // fill level the dynamic rate control steers towards
+const MAX_RATE_DEVIATION: f32 = 0.005; // +-0.5%, small enough to not be heard as a pitch change
+
+// ring buffer between the emulator thread and the audio callback
+pub struct SampleBuffer {
+    samples: Mutex<VecDeque<[i16; 2]>>,
+    volume: AtomicU32,   // f32 bits, 0.0 to 1.0
+    playing: AtomicBool, // whether an output stream is consuming the samples
+    resampler: AtomicU8, // ResamplerKind, can be switched while playing
+    rate_control: AtomicBool,
+}
+
+impl SampleBuffer {
+    pub fn new() -> Arc<SampleBuffer> {
+        Arc::new(SampleBuffer {
+            samples: Mutex::new(VecDeque::with_capacity(BUFFER_CAPACITY)),
+            volume: AtomicU32::new(1.0f32.to_bits()),
+            playing: AtomicBool::new(false),
+            resampler: AtomicU8::new(ResamplerKind::default() as u8),
+            rate_control: AtomicBool::new(true),
+        })
+    }
+
+    pub fn push(&self, samples: &[[i16; 2]]) {
+        let mut buffer = self.samples.lock().unwrap();
+        for &sample in samples {
+            if buffer.len() == BUFFER_CAPACITY {
+                buffer.pop_front();
+            }
+            buffer.push_back(sample);
+        }
+    }
+
+    fn pop(&self) -> Option<[i16; 2]> {
+        self.samples.lock().unwrap().pop_front()
+    }
+
+    pub fn queued(&self) -> usize {
+        self.samples.lock().unwrap().len()
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing.load(Ordering::Relaxed)
+    }
+
+    pub fn volume(&self) -> f32 {
+        f32::from_bits(self.volume.load(Ordering::Relaxed))
+    }
+
+    pub fn set_volume(&self, volume: f32) {
+        self.volume.store(volume.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn resampler(&self) -> ResamplerKind {
+        match self.resampler.load(Ordering::Relaxed) {
+            0 => ResamplerKind::Linear,
+            _ => ResamplerKind::Sinc,
+        }
+    }
+
+    pub fn set_resampler(&self, kind: ResamplerKind) {
+        self.resampler.store(kind as u8, Ordering::Relaxed);
+    }
+
+    pub fn rate_control(&self) -> bool {
+        self.rate_control.load(Ordering::Relaxed)
+    }
+
+    pub fn set_rate_control(&self, enabled: bool) {
+        self.rate_control.store(enabled, Ordering::Relaxed);
+    }
+
+    // the emulator and the device clocks drift apart, so the samples are consumed a little faster while
+    // more than the target is queued and a little slower while less is, this keeps the buffer from running dry
+    fn rate_adjustment(&self) -> f32 {
+        if !self.rate_control() {
+            return 0.0;
+        }
+
+        let fill = self.queued() as f32 / RATE_CONTROL_TARGET as f32;
+        (fill - 1.0).clamp(-1.0, 1.0) * MAX_RATE_DEVIATION
+    }
+}
+
+// plays the mixed samples on an output device, the stream has to stay on the thread that created it (not Send)
+pub struct AudioOutput {
+    buffer: Arc<SampleBuffer>,
+    stream: Option<Stream>,
+    pub device_name: Option<String>, // None = default device
+}
+
+impl AudioOutput {
+    pub fn new(buffer: Arc<SampleBuffer>, device_name: Option<String>) -> AudioOutput {
+        let mut output = AudioOutput {
+            buffer,
+            stream: None,
+            device_name: None,
+        };
+        output.select_device(device_name);
+        output
+    }
+
+    pub fn device_names() -> Vec<String> {
+        match cpal::default_host().output_devices() {
+            Ok(devices) => devices.filter_map(|device| device.name().ok()).collect(),
+            Err(e) => {
+                error!(target: "audio", "Failed to list output devices: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    pub fn volume(&self) -> f32 {
+        self.buffer.volume()
+    }
+
+    pub fn set_volume(&self, volume: f32) {
+        self.buffer.set_volume(volume);
+    }
+
+    pub fn resampler(&self) -> ResamplerKind {
+        self.buffer.resampler()
+    }
+
+    pub fn set_resampler(&self, kind: ResamplerKind) {
+        self.buffer.set_resampler(kind);
+    }
+
+    pub fn rate_control(&self) -> bool {
+        self.buffer.rate_control()
+    }
+
+    pub fn set_rate_control(&self, enabled: bool) {
+        self.buffer.set_rate_control(enabled);
+    }
+
+    pub fn select_device(&mut self, name: Option<String>) {
+        // the old stream has to be gone before the device can be reopened
+        self.stream = None;
+        self.buffer.playing.store(false, Ordering::Relaxed);
+
+        let host = cpal::default_host();
+        let device = match &name {
+            Some(name) => host
+                .output_devices()
+                .ok()
+                .and_then(|mut devices| devices.find(|device| device.name().is_ok_and(|n| n == *name))),
+            None => host.default_output_device(),
+        };
+
+        self.device_name = name;
+        let Some(device) = device else {
+            error!(target: "audio", "Output device not found, audio is disabled");
+            return;
+        };
+
+        match Self::open_stream(&device, self.buffer.clone()) {
+            Ok(stream) => {
+                info!(target: "audio", "Playing audio on {}", device.name().unwrap_or_default());
+                self.stream = Some(stream);
+                self.buffer.playing.store(true, Ordering::Relaxed);
+            }
+            Err(e) => error!(target: "audio", "Failed to open output device: {}", e),
+        }
+    }
+
+    fn open_stream(device: &Device, buffer: Arc<SampleBuffer>) -> Result<Stream, String> {
+        let supported = device.default_output_config().map_err(|e| e.to_string())?;
+        let config = supported.config();
+
+        let stream = match supported.sample_format() {
+            SampleFormat::F32 => Self::build_stream::<f32>(device, &config, buffer),
+            SampleFormat::I16 => Self::build_stream::<i16>(device, &config, buffer),
+            SampleFormat::U16 => Self::build_stream::<u16>(device, &config, buffer),
+            format => return Err(format!("unsupported sample format {}", format)),
+        }?;
+
+        stream.play().map_err(|e| e.to_string())?;
+        Ok(stream)
+    }
+
+    fn build_stream<T>(device: &Device, config: &StreamConfig, buffer: Arc<SampleBuffer>) -> Result<Stream, String>
+    where
+        T: SizedSample + FromSample<f32>,
+    {
+        let channels = config.channels as usize;
+        let mut resampler = Resampler::new(buffer.resampler(), SAMPLE_RATE, config.sample_rate.0);
+
+        // the last sample is held if the emulator falls behind
+        let callback = move |data: &mut [T], _: &cpal::OutputCallbackInfo| {
+            let volume = buffer.volume();
+            resampler.kind = buffer.resampler();
+            resampler.set_rate_adjustment(buffer.rate_adjustment());
+
+            for frame in data.chunks_mut(channels) {
+                let [left, right] = resampler
+                    .next(|| buffer.pop().map(|sample| sample.map(|s| s as f32 / i16::MAX as f32)))
+                    .map(|sample| sample * volume);
+
+                for (channel, value) in frame.iter_mut().enumerate() {
+                    let sample = match (channels, channel) {
+                        (1, _) => (left + right) / 2.0,
+                        (_, 0) => left,
+                        (_, 1) => right,
+                        _ => 0.0,
+                    };
+                    *value = T::from_sample(sample);
+                }
+            }
+        };
+
+        device
+            .build_output_stream(
+                config,
+                callback,
+                |e| error!(target: "audio", "Audio stream error: {}", e),
+                None,
+            )
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,84 @@
+use crossbeam_channel::Sender;
+use gba_core::video::{Frame, SCREEN_HEIGHT, SCREEN_WIDTH, frame_to_rgb};
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, DynamicImage, RgbImage};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tracing::{error, info};
+
+const CPU_CLOCK: u32 = 16 * 1024 * 1024;
+const CYCLES_PER_FRAME: u32 = 228 * 1232; // 228 lines of 1232 cycles, about 59.73 fps
+const GIF_SPEED: i32 = 10; // 1 (best quality) to 30 (fastest), quantizing has to keep up with the emulator
+
+// encodes every (frame_skip + 1)th frame into an animated GIF, on its own thread as quantizing is slow
+pub struct GifCapture {
+    frame_tx: Option<Sender<Box<Frame>>>,
+    encoder: Option<JoinHandle<()>>,
+    frame_skip: usize,
+    skipped: usize,
+}
+
+impl GifCapture {
+    pub fn start(path: &Path, frame_skip: usize) -> std::io::Result<GifCapture> {
+        let file = BufWriter::new(File::create(path)?);
+        let (frame_tx, frame_rx) = crossbeam_channel::unbounded::<Box<Frame>>();
+        let path = path.display().to_string();
+
+        // GIFs only have 10ms steps, without skipping the clip plays a little slower
+        let frame_time = Duration::from_secs(CYCLES_PER_FRAME as u64 * (frame_skip as u64 + 1)) / CPU_CLOCK;
+        let delay = Delay::from_saturating_duration(frame_time);
+
+        let encoder = std::thread::spawn(move || {
+            let mut encoder = GifEncoder::new_with_speed(file, GIF_SPEED);
+            let mut frames = 0;
+            if let Err(e) = encoder.set_repeat(Repeat::Infinite) {
+                error!(target: "ppu", "Failed to capture to {}: {}", path, e);
+                return;
+            }
+
+            for frame in frame_rx {
+                let rgb = RgbImage::from_raw(SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32, frame_to_rgb(&frame)).unwrap();
+                let rgba = DynamicImage::ImageRgb8(rgb).to_rgba8();
+                if let Err(e) = encoder.encode_frame(image::Frame::from_parts(rgba, 0, 0, delay)) {
+                    error!(target: "ppu", "Failed to capture to {}: {}", path, e);
+                    return;
+                }
+                frames += 1;
+            }
+
+            info!(target: "ppu", "Captured {} frames to {}", frames, path);
+        });
+
+        Ok(GifCapture {
+            frame_tx: Some(frame_tx),
+            encoder: Some(encoder),
+            frame_skip,
+            skipped: frame_skip, // the first frame is always captured
+        })
+    }
+
+    pub fn push(&mut self, frame: &Frame) {
+        if self.skipped < self.frame_skip {
+            self.skipped += 1;
+            return;
+        }
+
+        self.skipped = 0;
+        if let Some(frame_tx) = &self.frame_tx {
+            let _ = frame_tx.send(Box::new(*frame));
+        }
+    }
+}
+
+// waits for the queued frames to be written
+impl Drop for GifCapture {
+    fn drop(&mut self) {
+        self.frame_tx = None;
+        if let Some(encoder) = self.encoder.take() {
+            let _ = encoder.join();
+        }
+    }
+}
@@ -1,10 +1,19 @@
+use super::session::Session;
+use super::widgets::audio::AudioWidget;
+use super::widgets::bitmap::BitmapWidget;
+use super::widgets::compare::CompareWidget;
 use super::widgets::cpu::CpuWidget;
 use super::widgets::disasm::DisassemblyWidget;
 use super::widgets::memory::MemoryWidget;
 use super::widgets::ppu::PpuWidget;
+use super::widgets::swi::SwiWidget;
+use super::widgets::timeline::TimelineWidget;
+use super::widgets::watch::WatchWidget;
+use super::widgets::window::WindowWidget;
 use crate::event::{RequestEvent, ResponseEvent};
 use crossbeam_channel::{Receiver, Sender};
-use egui::Context;
+use egui::{Context, TextureHandle};
+use gba_core::video::Frame;
 
 pub struct Debugger {
     pub open: bool,
@@ -13,23 +22,68 @@ pub struct Debugger {
     cpu_widget: CpuWidget,
     disasm_widget: DisassemblyWidget,
     ppu_widget: PpuWidget,
+    compare_widget: CompareWidget,
+    swi_widget: SwiWidget,
+    audio_widget: AudioWidget,
+    timeline_widget: TimelineWidget,
+    bitmap_widget: BitmapWidget,
+    window_widget: WindowWidget,
+    watch_widget: WatchWidget,
+    session: Session,
 }
 
 impl Debugger {
     pub fn new(
         cpu_tx: Sender<RequestEvent>, memory_tx: Sender<RequestEvent>, disasm_tx: Sender<RequestEvent>,
-        ppu_tx: Sender<RequestEvent>, rx: Receiver<ResponseEvent>,
+        ppu_tx: Sender<RequestEvent>, swi_tx: Sender<RequestEvent>, audio_tx: Sender<RequestEvent>,
+        rx: Receiver<ResponseEvent>,
     ) -> Debugger {
         Debugger {
             open: false,
             rx,
             memory_widget: MemoryWidget::new(memory_tx),
-            cpu_widget: CpuWidget::new(cpu_tx),
+            cpu_widget: CpuWidget::new(cpu_tx.clone()),
             disasm_widget: DisassemblyWidget::new(disasm_tx),
-            ppu_widget: PpuWidget::new(ppu_tx),
+            ppu_widget: PpuWidget::new(ppu_tx.clone()),
+            compare_widget: CompareWidget::new(),
+            swi_widget: SwiWidget::new(swi_tx),
+            audio_widget: AudioWidget::new(audio_tx),
+            timeline_widget: TimelineWidget::new(ppu_tx.clone()),
+            bitmap_widget: BitmapWidget::new(ppu_tx.clone()),
+            window_widget: WindowWidget::new(ppu_tx),
+            watch_widget: WatchWidget::new(cpu_tx),
+            session: Session::default(),
         }
     }
 
+    pub fn restore_session(&mut self, ctx: &Context, session: Session) {
+        self.cpu_widget.restore(&session.breakpoints, session.break_events);
+        self.watch_widget.restore(&session.watches, &session.watchpoints);
+        session.apply_layout(ctx);
+        self.session = session;
+    }
+
+    pub fn save_session(&mut self) {
+        self.session.breakpoints = self.cpu_widget.breakpoints();
+        self.session.break_events = self.cpu_widget.break_events();
+        self.session.watches = self.watch_widget.expressions();
+        self.session.watchpoints = self.watch_widget.watchpoints();
+        self.session.save();
+    }
+
+    // returns the symbol files of the new session, they still have to be loaded by the emulator
+    pub fn switch_session(&mut self, ctx: &Context, crc32: &str) -> Vec<String> {
+        if !self.session.is_persisted() {
+            return Vec::new();
+        }
+
+        self.save_session();
+        let session = Session::load(crc32);
+        let symbols = session.symbols.clone();
+        self.restore_session(ctx, session);
+        symbols
+    }
+
     pub fn update(&mut self, ctx: &Context) {
         if !self.open {
             return;
@@ -37,7 +91,16 @@ impl Debugger {
 
         match self.rx.try_recv() {
             Ok(ResponseEvent::Cpu(cpu)) => self.cpu_widget.update(cpu),
+            Ok(ResponseEvent::Watches(values)) => self.watch_widget.update(values),
             Ok(ResponseEvent::Memory(memory)) => self.memory_widget.update(memory),
+            Ok(ResponseEvent::HeatMap(touched)) => self.memory_widget.update_heat_map(touched),
+            Ok(ResponseEvent::SwiLog(stats)) => self.swi_widget.update(stats),
+            Ok(ResponseEvent::Audio(states, waveforms)) => self.audio_widget.update(states, waveforms),
+            Ok(ResponseEvent::PpuTimeline(entries)) => self.timeline_widget.update(entries),
+            Ok(ResponseEvent::Bitmap(frame, entries, disp_cnt)) => {
+                self.bitmap_widget.update(ctx, frame, entries, disp_cnt)
+            }
+            Ok(ResponseEvent::Windows(regions, registers)) => self.window_widget.update(ctx, regions, registers),
             Ok(ResponseEvent::Disassembly(pc, r15, disassembly)) => self.disasm_widget.update(disassembly, pc, r15),
             Ok(ResponseEvent::Ppu(frames, _tileset, tilemaps, palette, registers, sprites)) => {
                 // TODO: we ignore tileset cause its been causing issues
@@ -51,6 +114,32 @@ impl Debugger {
         self.memory_widget.render(ctx);
         self.disasm_widget.render(ctx);
         self.ppu_widget.render(ctx);
+        if let Some((addr, len)) = self.ppu_widget.take_jump() {
+            self.memory_widget.jump_to(addr, len);
+        }
+        self.compare_widget.render(ctx);
+        self.swi_widget.render(ctx);
+        self.audio_widget.render(ctx);
+        self.timeline_widget.render(ctx);
+        self.bitmap_widget.render(ctx);
+        self.window_widget.render(ctx);
+        self.watch_widget.render(ctx);
+
+        self.session.capture_layout(ctx);
+    }
+
+    pub fn update_screen(&mut self, frame: &Frame) {
+        self.compare_widget.update(frame);
+    }
+
+    // drawn over the game screen while the debugger is open
+    pub fn highlighted_sprite(&self) -> Option<[i32; 4]> {
+        self.open.then(|| self.ppu_widget.selected_bounds()).flatten()
+    }
+
+    // the window regions, tinted and drawn over the game screen while the debugger is open
+    pub fn window_overlay(&self) -> Option<&TextureHandle> {
+        self.open.then(|| self.window_widget.overlay()).flatten()
     }
 
     pub fn toggle_window(&mut self) {
@@ -1,3 +1,5 @@
 pub mod debugger;
+pub mod session;
 mod tracked_value;
+pub mod watch;
 pub mod widgets;
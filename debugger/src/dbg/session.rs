@@ -0,0 +1,173 @@
+use crate::dbg::watch::Watchpoint;
+use crate::emulator::BreakEvents;
+use egui::{Context, Id, Pos2, Window, pos2};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use tracing::{error, info, warn};
+
+// windows whose position is remembered between sessions
+pub const WINDOWS: [&str; 14] = [
+    "Screen",
+    "CPU",
+    "Memory",
+    "Disassembly",
+    "PPU Registers",
+    "PPU Video",
+    "Compare",
+    "Solar Sensor",
+    "SWI Calls",
+    "Audio Channels",
+    "PPU Timeline",
+    "VRAM Bitmap",
+    "PPU Windows",
+    "Watch",
+];
+
+// debugger state of a single ROM, stored as plain text in sessions/<crc32>.session
+#[derive(Default)]
+pub struct Session {
+    path: Option<PathBuf>, // none if the session should not be persisted
+    pub breakpoints: Vec<u32>,
+    pub break_events: BreakEvents,
+    pub watches: Vec<String>, // expressions of the watch window
+    pub watchpoints: Vec<Watchpoint>,
+    pub symbols: Vec<String>,         // ELF files loaded into the symbolizer
+    pub windows: Vec<(String, Pos2)>, // (title, left top position)
+}
+
+impl Session {
+    pub fn load(crc32: &str) -> Session {
+        let path = Path::new("sessions").join(format!("{}.session", crc32));
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return Session {
+                path: Some(path),
+                ..Session::default()
+            };
+        };
+
+        let mut session = Session::default();
+
+        for line in content
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        {
+            if session.parse_line(line).is_none() {
+                warn!(target: "session", "Ignoring invalid session entry: {}", line);
+            }
+        }
+
+        info!(target: "session", "Restored session from {}", path.display());
+        session.path = Some(path);
+        session
+    }
+
+    pub fn is_persisted(&self) -> bool {
+        self.path.is_some()
+    }
+
+    fn parse_line(&mut self, line: &str) -> Option<()> {
+        let (key, value) = line.split_once(' ')?;
+
+        match key {
+            "breakpoint" => self.breakpoints.push(u32::from_str_radix(value, 16).ok()?),
+            "symbols" => self.symbols.push(value.to_string()),
+            "break_swi" => self.break_events.swi = value == "1",
+            "break_swi_filter" => self.break_events.swi_filter = Some(u8::from_str_radix(value, 16).ok()?),
+            "break_irq" => self.break_events.irq = value == "1",
+            "break_undefined" => self.break_events.undefined = value == "1",
+            "watch" => self.watches.push(value.to_string()),
+            "watchpoint" => {
+                let (addr, kind) = value.split_once(' ')?;
+                self.watchpoints.push(Watchpoint {
+                    addr: u32::from_str_radix(addr, 16).ok()?,
+                    kind: kind.parse().ok()?,
+                });
+            }
+            "window" => {
+                // window <x> <y> <title>, the title goes last as it may contain spaces
+                let mut parts = value.splitn(3, ' ');
+                let x = parts.next()?.parse::<f32>().ok()?;
+                let y = parts.next()?.parse::<f32>().ok()?;
+                self.windows.push((parts.next()?.to_string(), pos2(x, y)));
+            }
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    pub fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let mut content = String::from("# ayydbg session\n");
+
+        for path in &self.symbols {
+            let _ = writeln!(content, "symbols {}", path);
+        }
+        for addr in &self.breakpoints {
+            let _ = writeln!(content, "breakpoint {:08X}", addr);
+        }
+        let _ = writeln!(content, "break_swi {}", self.break_events.swi as u8);
+        if let Some(filter) = self.break_events.swi_filter {
+            let _ = writeln!(content, "break_swi_filter {:02X}", filter);
+        }
+        let _ = writeln!(content, "break_irq {}", self.break_events.irq as u8);
+        let _ = writeln!(content, "break_undefined {}", self.break_events.undefined as u8);
+        for expression in &self.watches {
+            let _ = writeln!(content, "watch {}", expression);
+        }
+        for watchpoint in &self.watchpoints {
+            let _ = writeln!(content, "watchpoint {:08X} {}", watchpoint.addr, watchpoint.kind);
+        }
+        for (title, pos) in &self.windows {
+            let _ = writeln!(content, "window {} {} {}", pos.x, pos.y, title);
+        }
+
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+
+        match std::fs::write(path, content) {
+            Ok(_) => info!(target: "session", "Saved session to {}", path.display()),
+            Err(e) => error!(target: "session", "Failed to save session to {}: {}", path.display(), e),
+        }
+    }
+
+    pub fn apply_layout(&self, ctx: &Context) {
+        ctx.data_mut(|data| {
+            for (title, pos) in &self.windows {
+                data.insert_temp(layout_id(title), *pos);
+            }
+        });
+    }
+
+    pub fn capture_layout(&mut self, ctx: &Context) {
+        for title in WINDOWS {
+            let Some(rect) = ctx.memory(|memory| memory.area_rect(Id::new(title))) else {
+                continue;
+            };
+
+            match self.windows.iter_mut().find(|(t, _)| t == title) {
+                Some((_, pos)) => *pos = rect.min,
+                None => self.windows.push((title.to_string(), rect.min)),
+            }
+        }
+    }
+}
+
+fn layout_id(title: &str) -> Id {
+    Id::new("session_layout").with(title)
+}
+
+// egui only uses the default position until the window has been moved, so restored layouts stay movable
+pub fn window<'a>(ctx: &Context, title: &'static str) -> Window<'a> {
+    let window = Window::new(title);
+
+    match ctx.data(|data| data.get_temp::<Pos2>(layout_id(title))) {
+        Some(pos) => window.default_pos(pos),
+        None => window,
+    }
+}
@@ -0,0 +1,178 @@
+use gba_core::arm7tdmi::cpu::Cpu;
+use gba_core::arm7tdmi::decoder::TransferLength;
+use gba_core::arm7tdmi::trace::{AccessDirection, MemoryAccess};
+use gba_core::memory::device::{AccessKind, BusValue};
+use gba_core::memory::mmio::Mmio;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum WatchKind {
+    Read,
+    #[default]
+    Write,
+    Access, // read or write
+}
+
+impl WatchKind {
+    pub const ALL: [WatchKind; 3] = [WatchKind::Read, WatchKind::Write, WatchKind::Access];
+}
+
+impl FromStr for WatchKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "read" => Ok(WatchKind::Read),
+            "write" => Ok(WatchKind::Write),
+            "access" => Ok(WatchKind::Access),
+            _ => Err(format!(
+                "unknown watchpoint kind '{}', expected read, write or access",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for WatchKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WatchKind::Read => write!(f, "read"),
+            WatchKind::Write => write!(f, "write"),
+            WatchKind::Access => write!(f, "access"),
+        }
+    }
+}
+
+// breaks on the CPU's loads and stores touching the byte at addr, DMA transfers are not caught
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Watchpoint {
+    pub addr: u32,
+    pub kind: WatchKind,
+}
+
+impl Watchpoint {
+    pub fn matches(&self, access: &MemoryAccess, mirror_aware: bool) -> bool {
+        let wanted = match access.direction {
+            AccessDirection::Read => self.kind != WatchKind::Write,
+            AccessDirection::Write => self.kind != WatchKind::Read,
+        };
+        let (addr, start) = match mirror_aware {
+            true => (Mmio::canonical_address(self.addr), Mmio::canonical_address(access.addr)),
+            false => (self.addr, access.addr),
+        };
+        let width = match access.width {
+            TransferLength::Byte => 1,
+            TransferLength::HalfWord => 2,
+            TransferLength::Word => 4,
+        };
+
+        wanted && addr.wrapping_sub(start) < width
+    }
+}
+
+// evaluates a watch expression, a sum of registers (r0-r15, sp, lr, pc, cpsr), hex numbers and memory reads,
+// e.g. "u16[sp + 8]" or "[r0] - 0x02000000", a read without a width loads a word
+pub fn evaluate(expression: &str, cpu: &mut Cpu) -> Result<u32, String> {
+    let mut parser = Parser {
+        tokens: tokenize(expression),
+        position: 0,
+        cpu,
+    };
+
+    let value = parser.sum()?;
+    match parser.next() {
+        Some(token) => Err(format!("Unexpected '{}'", token)),
+        None => Ok(value),
+    }
+}
+
+fn tokenize(expression: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+
+    for c in expression.chars() {
+        if c.is_ascii_alphanumeric() {
+            word.push(c.to_ascii_lowercase());
+            continue;
+        }
+        if !word.is_empty() {
+            tokens.push(std::mem::take(&mut word));
+        }
+        if !c.is_whitespace() {
+            tokens.push(c.to_string());
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+
+    tokens
+}
+
+struct Parser<'a> {
+    tokens: Vec<String>,
+    position: usize,
+    cpu: &'a mut Cpu,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<String> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(format!("Expected '{}', got '{}'", expected, token)),
+            None => Err(format!("Expected '{}'", expected)),
+        }
+    }
+
+    fn sum(&mut self) -> Result<u32, String> {
+        let mut value = self.term()?;
+
+        loop {
+            match self.tokens.get(self.position).map(String::as_str) {
+                Some("+") => {
+                    self.position += 1;
+                    value = value.wrapping_add(self.term()?);
+                }
+                Some("-") => {
+                    self.position += 1;
+                    value = value.wrapping_sub(self.term()?);
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<u32, String> {
+        let token = self.next().ok_or("Expected a value")?;
+        let registers = &self.cpu.registers;
+
+        match token.as_str() {
+            "[" => self.read::<u32>(),
+            "u8" => self.expect("[").and_then(|_| self.read::<u8>()),
+            "u16" => self.expect("[").and_then(|_| self.read::<u16>()),
+            "u32" => self.expect("[").and_then(|_| self.read::<u32>()),
+            "sp" => Ok(registers.r[13]),
+            "lr" => Ok(registers.r[14]),
+            "pc" => Ok(registers.r[15]),
+            "cpsr" => Ok(registers.cpsr.bits()),
+            _ => match token.strip_prefix('r').and_then(|index| index.parse::<usize>().ok()) {
+                Some(index) if index < 16 => Ok(registers.r[index]),
+                _ => u32::from_str_radix(token.trim_start_matches("0x"), 16)
+                    .map_err(|_| format!("Unknown value '{}'", token)),
+            },
+        }
+    }
+
+    fn read<T: BusValue>(&mut self) -> Result<u32, String> {
+        let addr = self.sum()?;
+        self.expect("]")?;
+        Ok(self.cpu.mmio.read::<T>(addr, AccessKind::Debugger).to_u32())
+    }
+}
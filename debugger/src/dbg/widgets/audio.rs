@@ -0,0 +1,165 @@
+use crate::dbg::session;
+use crate::event::RequestEvent;
+use crossbeam_channel::Sender;
+use egui::{Color32, Context, Grid, ProgressBar, RichText, Sense, Shape, Stroke, Ui, pos2, vec2};
+use gba_core::audio::apu::{Channel, FIFO_CAPACITY, SCOPE_LENGTH};
+use gba_core::audio::channel::ChannelState;
+
+const WAVEFORM_SIZE: [f32; 2] = [192.0, 32.0];
+const DUTY_CYCLES: [&str; 4] = ["12.5%", "25%", "50%", "75%"];
+
+pub struct AudioWidget {
+    event_tx: Sender<RequestEvent>,
+    enabled: [bool; 6], // indexed by Channel
+    states: Option<[ChannelState; 6]>,
+    waveforms: [Vec<i16>; 6],
+}
+
+impl AudioWidget {
+    pub fn new(tx: Sender<RequestEvent>) -> AudioWidget {
+        let _ = tx.send(RequestEvent::UpdateAudio); // request initial APU state
+
+        AudioWidget {
+            event_tx: tx,
+            enabled: [true; 6],
+            states: None,
+            waveforms: Default::default(),
+        }
+    }
+
+    pub fn update(&mut self, states: [ChannelState; 6], waveforms: [Vec<i16>; 6]) {
+        self.states = Some(states);
+        self.waveforms = waveforms;
+
+        let _ = self.event_tx.send(RequestEvent::UpdateAudio);
+    }
+
+    // only the soloed channel stays enabled, soloing it again enables everything
+    fn solo(&mut self, channel: Channel) {
+        let soloed = self
+            .enabled
+            .iter()
+            .enumerate()
+            .all(|(i, &enabled)| enabled == (i == channel as usize));
+
+        for (i, enabled) in self.enabled.iter_mut().enumerate() {
+            *enabled = soloed || i == channel as usize;
+        }
+    }
+
+    // levels are scaled to the range of the channel, PSG channels are -15 to 15
+    fn render_waveform(ui: &mut Ui, samples: &[i16], range: f32) {
+        let (response, painter) = ui.allocate_painter(vec2(WAVEFORM_SIZE[0], WAVEFORM_SIZE[1]), Sense::hover());
+        let rect = response.rect;
+        painter.rect_filled(rect, 0.0, Color32::BLACK);
+
+        let points = samples
+            .iter()
+            .enumerate()
+            .map(|(i, &level)| {
+                let x = rect.left() + i as f32 * rect.width() / (SCOPE_LENGTH - 1) as f32;
+                let y = rect.center().y - level as f32 / range * rect.height() / 2.0;
+                pos2(x, y)
+            })
+            .collect();
+        painter.add(Shape::line(points, Stroke::new(1.0_f32, Color32::LIGHT_GREEN)));
+    }
+
+    fn render_state(ui: &mut Ui, state: &ChannelState) {
+        let (playing, text) = match *state {
+            ChannelState::Square {
+                playing,
+                frequency,
+                duty,
+                volume,
+                sweep,
+            } => (
+                playing,
+                format!(
+                    "{:>8.1} Hz  duty {: <5}  vol {:>2}  sweep {:02X}",
+                    frequency, DUTY_CYCLES[duty as usize], volume, sweep
+                ),
+            ),
+            ChannelState::Wave {
+                playing,
+                frequency,
+                volume,
+                bank,
+                samples,
+            } => (
+                playing,
+                format!(
+                    "{:>8.1} Hz  vol {:>3}%  bank {}  {} samples",
+                    frequency, volume, bank, samples
+                ),
+            ),
+            ChannelState::Noise {
+                playing,
+                frequency,
+                volume,
+                narrow,
+            } => (
+                playing,
+                format!(
+                    "{:>8.1} Hz  vol {:>2}  {} bit LFSR",
+                    frequency,
+                    volume,
+                    if narrow { 7 } else { 15 }
+                ),
+            ),
+            ChannelState::Fifo { queued, sample } => {
+                ui.horizontal(|ui| {
+                    ui.add(
+                        ProgressBar::new(queued as f32 / FIFO_CAPACITY as f32)
+                            .desired_width(120.0)
+                            .text(format!("{}/{} bytes", queued, FIFO_CAPACITY)),
+                    );
+                    ui.monospace(format!("sample {:>4}", sample));
+                });
+                return;
+            }
+        };
+
+        let text = RichText::new(text).monospace();
+        ui.label(if playing { text } else { text.weak() });
+    }
+
+    pub fn render(&mut self, ctx: &Context) {
+        session::window(ctx, "Audio Channels").resizable(false).show(ctx, |ui| {
+            let mut changed = false;
+
+            Grid::new("audio_channels").striped(true).show(ui, |ui| {
+                for channel in Channel::ALL {
+                    changed |= ui
+                        .checkbox(&mut self.enabled[channel as usize], channel.to_string())
+                        .changed();
+
+                    if ui.small_button("Solo").clicked() {
+                        self.solo(channel);
+                        changed = true;
+                    }
+
+                    let range = match channel {
+                        Channel::FifoA | Channel::FifoB => 128.0,
+                        _ => 15.0,
+                    };
+                    Self::render_waveform(ui, &self.waveforms[channel as usize], range);
+
+                    if let Some(states) = &self.states {
+                        Self::render_state(ui, &states[channel as usize]);
+                    }
+                    ui.end_row();
+                }
+            });
+
+            if ui.button("Enable all").clicked() {
+                self.enabled = [true; 6];
+                changed = true;
+            }
+
+            if changed {
+                let _ = self.event_tx.send(RequestEvent::SetAudioChannels(self.enabled));
+            }
+        });
+    }
+}
@@ -0,0 +1,147 @@
+use crate::dbg::session;
+use crate::dbg::widgets::TRANSPARENT_COLOR;
+use crate::event::RequestEvent;
+use crossbeam_channel::Sender;
+use egui::{Color32, ColorImage, Context, Image, RichText, Sense, Slider, TextureHandle, TextureOptions, vec2};
+use gba_core::video::registers::DispCnt;
+use gba_core::video::{FRAME_0_ADDRESS, FRAME_1_ADDRESS, Frame, Pixel, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+// shows VRAM as one of the bitmap modes no matter what DISPCNT is set to, for double buffered games the frame that
+// is not displayed is the one being drawn
+pub struct BitmapWidget {
+    event_tx: Sender<RequestEvent>,
+    mode: u8,
+    base_addr: u32,
+    live: bool, // requests the next bitmap as soon as one arrives
+    zoom: f32,
+    entries: Vec<Option<(u32, u16)>>, // address and raw value of every pixel
+    disp_cnt: DispCnt,
+    texture: Option<TextureHandle>,
+}
+
+impl BitmapWidget {
+    pub fn new(tx: Sender<RequestEvent>) -> BitmapWidget {
+        let _ = tx.send(RequestEvent::UpdateBitmap(3, FRAME_0_ADDRESS));
+
+        BitmapWidget {
+            event_tx: tx,
+            mode: 3,
+            base_addr: FRAME_0_ADDRESS,
+            live: true,
+            zoom: 1.0,
+            entries: Vec::new(),
+            disp_cnt: DispCnt::default(),
+            texture: None,
+        }
+    }
+
+    pub fn update(&mut self, ctx: &Context, frame: Box<Frame>, entries: Vec<Option<(u32, u16)>>, disp_cnt: DispCnt) {
+        self.entries = entries;
+        self.disp_cnt = disp_cnt;
+
+        let pixels = frame
+            .iter()
+            .map(|&color| match Pixel::unpack(color) {
+                Pixel::Rgb(r, g, b) => Color32::from_rgb(r, g, b),
+                Pixel::Transparent => TRANSPARENT_COLOR,
+            })
+            .collect();
+        let image = ColorImage {
+            size: [SCREEN_WIDTH, SCREEN_HEIGHT],
+            pixels,
+        };
+        match &mut self.texture {
+            Some(texture) => texture.set(image, TextureOptions::NEAREST),
+            None => self.texture = Some(ctx.load_texture("vram_bitmap", image, TextureOptions::NEAREST)),
+        }
+
+        if self.live {
+            self.refresh();
+        }
+    }
+
+    fn refresh(&self) {
+        let _ = self
+            .event_tx
+            .send(RequestEvent::UpdateBitmap(self.mode, self.base_addr));
+    }
+
+    fn describe_pixel(&self, x: usize, y: usize) -> String {
+        match self.entries.get(y * SCREEN_WIDTH + x).copied().flatten() {
+            Some((addr, value)) if self.mode == 4 => {
+                format!(
+                    "X: {}, Y: {}\nAddress: {:08X}\nPalette Index: {:02X}",
+                    x, y, addr, value
+                )
+            }
+            Some((addr, value)) => format!("X: {}, Y: {}\nAddress: {:08X}\nBGR555: {:04X}", x, y, addr, value),
+            None => format!("X: {}, Y: {}\nOutside of the bitmap", x, y),
+        }
+    }
+
+    pub fn render(&mut self, ctx: &Context) {
+        session::window(ctx, "VRAM Bitmap").resizable(false).show(ctx, |ui| {
+            let mut changed = false;
+            ui.horizontal(|ui| {
+                ui.label("Mode");
+                for mode in 3..=5 {
+                    changed |= ui.radio_value(&mut self.mode, mode, mode.to_string()).changed();
+                }
+
+                ui.separator();
+
+                // mode 3 only has a single frame, the second one is shown anyway to spot uploads into it
+                ui.label("Frame");
+                changed |= ui.radio_value(&mut self.base_addr, FRAME_0_ADDRESS, "0").changed();
+                changed |= ui.radio_value(&mut self.base_addr, FRAME_1_ADDRESS, "1").changed();
+
+                ui.separator();
+
+                if ui.checkbox(&mut self.live, "Live").changed() && self.live {
+                    changed = true;
+                }
+                if ui
+                    .button(format!("{} Refresh", egui_phosphor::regular::ARROW_CLOCKWISE))
+                    .clicked()
+                {
+                    changed = true;
+                }
+            });
+
+            if changed {
+                self.refresh();
+            }
+
+            ui.horizontal(|ui| {
+                ui.add(Slider::new(&mut self.zoom, 1.0..=4.0).step_by(1.0).text("Zoom"));
+                ui.separator();
+
+                let shown = self.disp_cnt.bg_mode() == self.mode && self.disp_cnt.frame_address() == self.base_addr;
+                ui.label(format!(
+                    "DISPCNT: mode {}, frame {}{}",
+                    self.disp_cnt.bg_mode(),
+                    (self.disp_cnt.frame_address() != FRAME_0_ADDRESS) as u8,
+                    if shown { " (displayed)" } else { "" },
+                ));
+            });
+
+            ui.separator();
+
+            let Some(texture) = &self.texture else {
+                return;
+            };
+            let response = ui.add(
+                Image::new(texture)
+                    .fit_to_exact_size(vec2(SCREEN_WIDTH as f32, SCREEN_HEIGHT as f32) * self.zoom)
+                    .sense(Sense::hover()),
+            );
+            if let Some(pointer) = response.hover_pos() {
+                let x = ((pointer.x - response.rect.left()) / self.zoom) as usize;
+                let y = ((pointer.y - response.rect.top()) / self.zoom) as usize;
+                if x < SCREEN_WIDTH && y < SCREEN_HEIGHT {
+                    response.on_hover_text_at_pointer(RichText::new(self.describe_pixel(x, y)).monospace());
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,224 @@
+use crate::dbg::session;
+use egui::{Color32, ColorImage, ComboBox, Context, RichText, Slider, TextEdit, TextureHandle, TextureOptions};
+use gba_core::video::{Frame, Pixel, SCREEN_HEIGHT, SCREEN_WIDTH};
+use image::{RgbImage, imageops};
+
+const DIFF_COLOR: Color32 = Color32::from_rgba_premultiplied(255, 0, 0, 255);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompareMode {
+    Overlay,
+    Difference,
+    Reference,
+}
+
+#[derive(Default, Clone, Copy)]
+struct ChannelStats {
+    mean_error: f32,
+    max_error: u8,
+}
+
+#[derive(Default)]
+struct CompareStats {
+    mismatched: usize,
+    channels: [ChannelStats; 3], // r, g, b
+}
+
+pub struct CompareWidget {
+    path: String,
+    error: Option<String>,
+    reference: Option<Vec<[u8; 3]>>,
+    frame: Vec<[u8; 3]>,
+    mode: CompareMode,
+    opacity: f32,
+    tolerance: u8,
+    stats: CompareStats,
+    texture: Option<TextureHandle>,
+}
+
+impl CompareWidget {
+    pub fn new() -> CompareWidget {
+        CompareWidget {
+            path: String::new(),
+            error: None,
+            reference: None,
+            frame: vec![[0, 0, 0]; SCREEN_WIDTH * SCREEN_HEIGHT],
+            mode: CompareMode::Difference,
+            opacity: 0.5,
+            tolerance: 0,
+            stats: CompareStats::default(),
+            texture: None,
+        }
+    }
+
+    pub fn update(&mut self, frame: &Frame) {
+        for (dst, src) in self.frame.iter_mut().zip(frame.iter()) {
+            *dst = match Pixel::unpack(*src) {
+                Pixel::Transparent => [0, 0, 0],
+                Pixel::Rgb(r, g, b) => [r, g, b],
+            };
+        }
+    }
+
+    fn load_reference(&mut self) {
+        let img = match image::open(&self.path) {
+            Ok(img) => img.to_rgb8(),
+            Err(e) => {
+                self.error = Some(format!("Failed to load {}: {}", self.path, e));
+                self.reference = None;
+                return;
+            }
+        };
+
+        // screenshots are usually scaled up (e.g. F2), bring them back down to native resolution
+        let img: RgbImage = if img.dimensions() != (SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32) {
+            imageops::resize(
+                &img,
+                SCREEN_WIDTH as u32,
+                SCREEN_HEIGHT as u32,
+                imageops::FilterType::Nearest,
+            )
+        } else {
+            img
+        };
+
+        self.reference = Some(img.pixels().map(|p| p.0).collect());
+        self.error = None;
+    }
+
+    fn compute_stats(&mut self, reference: &[[u8; 3]]) {
+        let mut stats = CompareStats::default();
+        let mut totals = [0u64; 3];
+
+        for (current, reference) in self.frame.iter().zip(reference.iter()) {
+            let mut mismatch = false;
+
+            for (channel, (total, channel_stats)) in totals.iter_mut().zip(stats.channels.iter_mut()).enumerate() {
+                let error = current[channel].abs_diff(reference[channel]);
+                *total += error as u64;
+                channel_stats.max_error = channel_stats.max_error.max(error);
+                mismatch |= error > self.tolerance;
+            }
+
+            if mismatch {
+                stats.mismatched += 1;
+            }
+        }
+
+        for (channel_stats, total) in stats.channels.iter_mut().zip(totals) {
+            channel_stats.mean_error = total as f32 / self.frame.len() as f32;
+        }
+
+        self.stats = stats;
+    }
+
+    fn compose_image(&self, reference: &[[u8; 3]]) -> ColorImage {
+        let pixels = self
+            .frame
+            .iter()
+            .zip(reference.iter())
+            .map(|(current, reference)| match self.mode {
+                CompareMode::Overlay => {
+                    let mix = |a: u8, b: u8| (a as f32 * (1.0 - self.opacity) + b as f32 * self.opacity) as u8;
+                    Color32::from_rgb(
+                        mix(current[0], reference[0]),
+                        mix(current[1], reference[1]),
+                        mix(current[2], reference[2]),
+                    )
+                }
+                CompareMode::Difference => {
+                    let mismatch = (0..3).any(|c| current[c].abs_diff(reference[c]) > self.tolerance);
+                    if mismatch {
+                        DIFF_COLOR
+                    } else {
+                        // dim matching pixels so the highlighted ones stand out
+                        Color32::from_rgb(current[0] / 3, current[1] / 3, current[2] / 3)
+                    }
+                }
+                CompareMode::Reference => Color32::from_rgb(reference[0], reference[1], reference[2]),
+            })
+            .collect();
+
+        ColorImage {
+            size: [SCREEN_WIDTH, SCREEN_HEIGHT],
+            pixels,
+        }
+    }
+
+    pub fn render(&mut self, ctx: &Context) {
+        if let Some(reference) = self.reference.take() {
+            self.compute_stats(&reference);
+            let image = self.compose_image(&reference);
+            match &mut self.texture {
+                Some(texture) => texture.set(image, TextureOptions::NEAREST),
+                None => self.texture = Some(ctx.load_texture("compare", image, TextureOptions::NEAREST)),
+            }
+            self.reference = Some(reference);
+        }
+
+        session::window(ctx, "Compare").resizable(false).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .button(format!("{} Load", egui_phosphor::regular::FOLDER_OPEN))
+                    .clicked()
+                {
+                    self.load_reference();
+                }
+
+                TextEdit::singleline(&mut self.path)
+                    .hint_text("Reference screenshot (.png)")
+                    .show(ui);
+            });
+
+            if let Some(error) = &self.error {
+                ui.label(RichText::new(error).color(DIFF_COLOR));
+            }
+
+            ui.horizontal(|ui| {
+                ComboBox::from_label("Mode")
+                    .selected_text(format!("{:?}", self.mode))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.mode, CompareMode::Overlay, "Overlay");
+                        ui.selectable_value(&mut self.mode, CompareMode::Difference, "Difference");
+                        ui.selectable_value(&mut self.mode, CompareMode::Reference, "Reference");
+                    });
+
+                match self.mode {
+                    CompareMode::Overlay => ui.add(Slider::new(&mut self.opacity, 0.0..=1.0).text("Opacity")),
+                    _ => ui.add(Slider::new(&mut self.tolerance, 0..=32).text("Tolerance")),
+                };
+            });
+
+            ui.separator();
+
+            if self.reference.is_none() {
+                ui.label("No reference screenshot loaded");
+                return;
+            }
+
+            let total = SCREEN_WIDTH * SCREEN_HEIGHT;
+            ui.label(
+                RichText::new(format!(
+                    "Mismatched: {} / {} ({:.2}%)",
+                    self.stats.mismatched,
+                    total,
+                    self.stats.mismatched as f32 * 100.0 / total as f32
+                ))
+                .monospace(),
+            );
+            for (name, channel) in ["R", "G", "B"].iter().zip(self.stats.channels.iter()) {
+                ui.label(
+                    RichText::new(format!(
+                        "{}: mean error {:6.2}, max error {:3}",
+                        name, channel.mean_error, channel.max_error
+                    ))
+                    .monospace(),
+                );
+            }
+
+            if let Some(texture) = &self.texture {
+                ui.image(texture);
+            }
+        });
+    }
+}
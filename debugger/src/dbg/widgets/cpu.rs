@@ -1,8 +1,10 @@
+use crate::dbg::session;
 use crate::dbg::tracked_value::TrackedValue;
 use crate::dbg::widgets::DIRTY_COLOR;
+use crate::emulator::BreakEvents;
 use crate::event::RequestEvent;
 use crossbeam_channel::Sender;
-use egui::{CollapsingHeader, ComboBox, Context, RichText, TextEdit, Window};
+use egui::{CollapsingHeader, ComboBox, Context, RichText, TextEdit};
 use gba_core::arm7tdmi::registers::Psr;
 use gba_core::arm7tdmi::timer::Timers;
 use gba_core::memory::dma::Dma;
@@ -29,6 +31,9 @@ pub struct CpuWidget {
     breakpoint: String,
     selected_breakpoint: String,
     breakpoints: Vec<String>,
+    break_events: BreakEvents,
+    swi_filter: String,
+    step_back_count: String,
 }
 
 impl CpuWidget {
@@ -41,6 +46,9 @@ impl CpuWidget {
             breakpoint: String::new(),
             selected_breakpoint: String::new(),
             breakpoints: Vec::new(),
+            break_events: BreakEvents::default(),
+            swi_filter: String::new(),
+            step_back_count: String::from("1"),
         }
     }
 
@@ -53,8 +61,38 @@ impl CpuWidget {
         self.cpu.timers.set(cpu.timers);
     }
 
+    pub fn restore(&mut self, breakpoints: &[u32], break_events: BreakEvents) {
+        for addr in self.breakpoints() {
+            let _ = self.event_tx.send(RequestEvent::RemoveBreakpoint(addr));
+        }
+        self.breakpoints.clear();
+
+        for &addr in breakpoints {
+            self.breakpoints.push(format!("{:08X}", addr));
+            let _ = self.event_tx.send(RequestEvent::AddBreakpoint(addr));
+        }
+
+        self.break_events = break_events;
+        self.swi_filter = break_events
+            .swi_filter
+            .map(|number| format!("{:02X}", number))
+            .unwrap_or_default();
+        let _ = self.event_tx.send(RequestEvent::UpdateBreakEvents(break_events));
+    }
+
+    pub fn breakpoints(&self) -> Vec<u32> {
+        self.breakpoints
+            .iter()
+            .filter_map(|breakpoint| u32::from_str_radix(breakpoint, 16).ok())
+            .collect()
+    }
+
+    pub fn break_events(&self) -> BreakEvents {
+        self.break_events
+    }
+
     pub fn render(&mut self, ctx: &Context) {
-        Window::new("CPU").resizable(false).show(ctx, |ui| {
+        session::window(ctx, "CPU").resizable(false).show(ctx, |ui| {
             ui.horizontal(|ui| {
                 ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
                     if ui.button(format!("{} Run", egui_phosphor::regular::PLAY)).clicked() {
@@ -70,6 +108,20 @@ impl CpuWidget {
                         let _ = self.event_tx.send(RequestEvent::Break);
                         let _ = self.event_tx.send(RequestEvent::UpdateCpu);
                     }
+
+                    if ui
+                        .button(format!("{} Step Back", egui_phosphor::regular::ARROW_ARC_LEFT))
+                        .clicked()
+                    {
+                        let count = self.step_back_count.parse::<usize>().unwrap_or(1);
+                        let _ = self.event_tx.send(RequestEvent::StepBack(count));
+                        let _ = self.event_tx.send(RequestEvent::UpdateCpu);
+                    }
+
+                    TextEdit::singleline(&mut self.step_back_count)
+                        .hint_text("Count")
+                        .desired_width(40.0)
+                        .show(ui);
                 });
 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
@@ -79,6 +131,14 @@ impl CpuWidget {
                     {
                         let _ = self.event_tx.send(RequestEvent::UpdateCpu);
                     }
+
+                    if ui
+                        .button(format!("{} Reset", egui_phosphor::regular::ARROW_COUNTER_CLOCKWISE))
+                        .clicked()
+                    {
+                        let _ = self.event_tx.send(RequestEvent::Reset);
+                        let _ = self.event_tx.send(RequestEvent::UpdateCpu);
+                    }
                 });
             });
 
@@ -121,6 +181,26 @@ impl CpuWidget {
                     });
             });
 
+            ui.horizontal(|ui| {
+                let mut changed = false;
+
+                ui.label("Break on:");
+                changed |= ui.checkbox(&mut self.break_events.swi, "SWI").changed();
+                changed |= TextEdit::singleline(&mut self.swi_filter)
+                    .hint_text("SWI number")
+                    .desired_width(80.0)
+                    .show(ui)
+                    .response
+                    .changed();
+                changed |= ui.checkbox(&mut self.break_events.irq, "IRQ").changed();
+                changed |= ui.checkbox(&mut self.break_events.undefined, "Undefined").changed();
+
+                if changed {
+                    self.break_events.swi_filter = u8::from_str_radix(&self.swi_filter, 16).ok();
+                    let _ = self.event_tx.send(RequestEvent::UpdateBreakEvents(self.break_events));
+                }
+            });
+
             ui.separator();
 
             let format_register = |idx: usize| {
@@ -1,7 +1,8 @@
+use crate::dbg::session;
 use crate::dbg::widgets::{PC_COLOR, R15_COLOR};
 use crate::event::RequestEvent;
 use crossbeam_channel::Sender;
-use egui::{Context, RichText, ScrollArea, Window};
+use egui::{Context, RichText, ScrollArea};
 
 pub struct DecodedInstruction {
     pub addr: u32,
@@ -35,7 +36,7 @@ impl DisassemblyWidget {
     }
 
     pub fn render(&mut self, ctx: &Context) {
-        Window::new("Disassembly").resizable(false).show(ctx, |ui| {
+        session::window(ctx, "Disassembly").resizable(false).show(ctx, |ui| {
             ui.vertical(|ui| {
                 ScrollArea::vertical().auto_shrink([false; 2]).show(ui, |ui| {
                     for line in self.disassembly.iter() {
@@ -1,8 +1,12 @@
+use crate::dbg::session;
 use crate::dbg::tracked_value::TrackedValue;
-use crate::dbg::widgets::DIRTY_COLOR;
+use crate::dbg::widgets::{DIRTY_COLOR, SELECTED_COLOR};
 use crate::event::RequestEvent;
+use chrono::Utc;
 use crossbeam_channel::Sender;
-use egui::{ComboBox, Context, RichText, ScrollArea, TextStyle, Window};
+use egui::{ComboBox, Context, Grid, ProgressBar, RichText, ScrollArea, TextStyle};
+use gba_core::memory::heat_map::AccessCounts;
+use std::ops::Range;
 
 const BYTES_PER_ROW: usize = 16;
 
@@ -10,6 +14,11 @@ pub struct MemoryWidget {
     memory_view: MemoryView,
     event_tx: Sender<RequestEvent>,
     memory: Vec<TrackedValue<u8>>,
+    track_accesses: bool,
+    track_io_writes: bool,
+    heat_map: Vec<(u32, AccessCounts)>, // touched 1 KiB buckets of the current view
+    highlight: Option<Range<u32>>,      // bytes shown by the last jump
+    scroll_to_row: Option<usize>,
 }
 
 impl MemoryWidget {
@@ -19,35 +28,97 @@ impl MemoryWidget {
             memory_view: MemoryView::Bios,
             event_tx: tx,
             memory: vec![TrackedValue::default(); 0x0FFF_FFFF + 1],
+            track_accesses: false,
+            track_io_writes: false,
+            heat_map: Vec::new(),
+            highlight: None,
+            scroll_to_row: None,
         }
     }
 
+    // switches to the region containing the bytes and scrolls to them, they stay highlighted until the next jump
+    pub fn jump_to(&mut self, addr: u32, len: usize) {
+        let Some(view) = MemoryView::ALL.into_iter().find(|view| view.range().contains(&addr)) else {
+            return;
+        };
+
+        self.memory_view = view;
+        self.highlight = Some(addr..addr + len as u32);
+        self.scroll_to_row = Some((addr - view.start()) as usize / BYTES_PER_ROW);
+        self.refresh();
+    }
+
     pub fn update(&mut self, memory: Box<[u8; 0x0FFF_FFFF + 1]>) {
         memory.iter().enumerate().for_each(|(i, v)| self.memory[i].set(*v));
     }
 
+    pub fn update_heat_map(&mut self, touched: Vec<(u32, AccessCounts)>) {
+        self.heat_map = touched;
+    }
+
+    fn refresh(&self) {
+        let _ = self.event_tx.send(RequestEvent::UpdateMemory);
+        if self.track_accesses {
+            let range = self.memory_view.range();
+            let _ = self
+                .event_tx
+                .send(RequestEvent::UpdateHeatMap(*range.start(), *range.end()));
+        }
+    }
+
+    fn render_heat_map(&self, ui: &mut egui::Ui) {
+        let max = self
+            .heat_map
+            .iter()
+            .map(|(_, counts)| counts.total())
+            .max()
+            .unwrap_or(1);
+
+        ui.collapsing("Heat Map (1 KiB)", |ui| {
+            ScrollArea::vertical()
+                .id_salt("heat_map")
+                .max_height(150.0)
+                .show(ui, |ui| {
+                    Grid::new("heat_map_grid").striped(true).show(ui, |ui| {
+                        for header in ["Address", "Reads", "Writes", "Executes", ""] {
+                            ui.label(RichText::new(header).monospace().strong());
+                        }
+                        ui.end_row();
+
+                        for (addr, counts) in &self.heat_map {
+                            ui.monospace(format!("{:08X}", addr));
+                            ui.monospace(counts.reads.to_string());
+                            ui.monospace(counts.writes.to_string());
+                            ui.monospace(counts.executes.to_string());
+                            ui.add(ProgressBar::new(counts.total() as f32 / max as f32).desired_width(100.0));
+                            ui.end_row();
+                        }
+                    });
+                });
+        });
+    }
+
     pub fn render(&mut self, ctx: &Context) {
-        Window::new("Memory").resizable(false).vscroll(false).show(ctx, |ui| {
+        let window = session::window(ctx, "Memory");
+        window.resizable(false).vscroll(false).show(ctx, |ui| {
             ui.horizontal(|ui| {
                 ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
                     ComboBox::from_label("Memory Map")
                         .selected_text(format!("{}", self.memory_view))
                         .show_ui(ui, |ui| {
-                            for region in [
-                                MemoryView::Bios,
-                                MemoryView::OnboardWram,
-                                MemoryView::OnchipWram,
-                                MemoryView::IoRegisters,
-                                MemoryView::PaletteRam,
-                                MemoryView::Vram,
-                                MemoryView::Oam,
-                                MemoryView::GamePak,
-                                MemoryView::GamePakSram,
-                                MemoryView::Eeprom,
-                            ] {
+                            for region in MemoryView::ALL {
                                 ui.selectable_value(&mut self.memory_view, region, region.to_string());
                             }
                         });
+
+                    if ui.checkbox(&mut self.track_accesses, "Heat Map").changed() {
+                        let _ = self.event_tx.send(RequestEvent::TrackAccesses(self.track_accesses));
+                        self.heat_map.clear();
+                    }
+
+                    if ui.checkbox(&mut self.track_io_writes, "I/O Journal").changed() {
+                        let _ = self.event_tx.send(RequestEvent::TrackIoWrites(self.track_io_writes));
+                    }
                 });
 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
@@ -55,13 +126,29 @@ impl MemoryWidget {
                         .button(format!("{} Refresh", egui_phosphor::regular::ARROW_CLOCKWISE))
                         .clicked()
                     {
-                        let _ = self.event_tx.send(RequestEvent::UpdateMemory);
+                        self.refresh();
+                    }
+
+                    if self.track_io_writes
+                        && ui
+                            .button(format!("{} Export Journal", egui_phosphor::regular::FLOPPY_DISK))
+                            .clicked()
+                    {
+                        let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
+                        let _ = self
+                            .event_tx
+                            .send(RequestEvent::ExportIoJournal(format!("io_journal_{}.json", timestamp)));
                     }
                 });
             });
 
             ui.separator();
 
+            if self.track_accesses {
+                self.render_heat_map(ui);
+                ui.separator();
+            }
+
             ui.horizontal(|ui| {
                 ui.label(RichText::new("        ").monospace().strong());
                 for idx in 0..BYTES_PER_ROW {
@@ -78,50 +165,56 @@ impl MemoryWidget {
             let mem_slice = &self.memory[start..start + size];
             let total_rows = (mem_slice.len() + BYTES_PER_ROW - 1) / BYTES_PER_ROW;
 
-            ScrollArea::vertical().auto_shrink([false; 2]).show_rows(
-                ui,
-                ui.text_style_height(&TextStyle::Monospace),
-                total_rows,
-                |ui, rows| {
-                    for row in rows {
-                        let base_addr = start + row * BYTES_PER_ROW;
-                        let slice_off = row * BYTES_PER_ROW;
-                        let take = BYTES_PER_ROW.min(mem_slice.len() - slice_off);
-                        let chunk = &mem_slice[slice_off..slice_off + take];
-
-                        ui.horizontal(|ui| {
-                            ui.label(RichText::new(format!("{:08X}", base_addr)).monospace().strong());
-
-                            for cell in chunk.iter() {
-                                let mut richtext = RichText::new(format!("{:02X}", cell.get())).monospace();
-                                if cell.has_changed() {
-                                    richtext = richtext.color(DIRTY_COLOR);
-                                }
-                                ui.label(richtext);
-                            }
+            let row_height = ui.text_style_height(&TextStyle::Monospace);
+            let mut scroll_area = ScrollArea::vertical().auto_shrink([false; 2]);
+            if let Some(row) = self.scroll_to_row.take() {
+                // a few rows of context above the target
+                let offset = row.saturating_sub(4) as f32 * (row_height + ui.spacing().item_spacing.y);
+                scroll_area = scroll_area.vertical_scroll_offset(offset);
+            }
+
+            scroll_area.show_rows(ui, row_height, total_rows, |ui, rows| {
+                for row in rows {
+                    let base_addr = start + row * BYTES_PER_ROW;
+                    let slice_off = row * BYTES_PER_ROW;
+                    let take = BYTES_PER_ROW.min(mem_slice.len() - slice_off);
+                    let chunk = &mem_slice[slice_off..slice_off + take];
+
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(format!("{:08X}", base_addr)).monospace().strong());
 
-                            for _ in 0..(BYTES_PER_ROW - take) {
-                                ui.monospace("");
+                        for (i, cell) in chunk.iter().enumerate() {
+                            let mut richtext = RichText::new(format!("{:02X}", cell.get())).monospace();
+                            if cell.has_changed() {
+                                richtext = richtext.color(DIRTY_COLOR);
                             }
+                            if self
+                                .highlight
+                                .as_ref()
+                                .is_some_and(|highlight| highlight.contains(&((base_addr + i) as u32)))
+                            {
+                                richtext = richtext.background_color(SELECTED_COLOR);
+                            }
+                            ui.label(richtext);
+                        }
 
-                            ui.add_space(5.0);
-
-                            let ascii: String = chunk
-                                .iter()
-                                .map(|b| {
-                                    let v: u8 = b.get();
-                                    if (0x20..=0x7E).contains(&v) {
-                                        v as char
-                                    } else {
-                                        '.'
-                                    }
-                                })
-                                .collect();
-                            ui.monospace(ascii);
-                        });
-                    }
-                },
-            );
+                        for _ in 0..(BYTES_PER_ROW - take) {
+                            ui.monospace("");
+                        }
+
+                        ui.add_space(5.0);
+
+                        let ascii: String = chunk
+                            .iter()
+                            .map(|b| {
+                                let v: u8 = b.get();
+                                if (0x20..=0x7E).contains(&v) { v as char } else { '.' }
+                            })
+                            .collect();
+                        ui.monospace(ascii);
+                    });
+                }
+            });
         });
     }
 }
@@ -158,6 +251,19 @@ impl std::fmt::Display for MemoryView {
 }
 
 impl MemoryView {
+    pub const ALL: [MemoryView; 10] = [
+        MemoryView::Bios,
+        MemoryView::OnboardWram,
+        MemoryView::OnchipWram,
+        MemoryView::IoRegisters,
+        MemoryView::PaletteRam,
+        MemoryView::Vram,
+        MemoryView::Oam,
+        MemoryView::GamePak,
+        MemoryView::GamePakSram,
+        MemoryView::Eeprom,
+    ];
+
     pub fn range(self) -> std::ops::RangeInclusive<u32> {
         match self {
             MemoryView::Bios => 0x0000_0000..=0x0000_3FFF,
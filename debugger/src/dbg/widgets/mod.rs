@@ -1,11 +1,19 @@
 use egui::Color32;
 
+pub mod audio;
+pub mod bitmap;
+pub mod compare;
 pub mod cpu;
 pub mod disasm;
 pub mod memory;
 pub mod ppu;
+pub mod swi;
+pub mod timeline;
+pub mod watch;
+pub mod window;
 
 const DIRTY_COLOR: Color32 = Color32::from_rgba_premultiplied(250, 160, 160, 255);
 const PC_COLOR: Color32 = Color32::from_rgba_premultiplied(193, 225, 193, 255);
 const R15_COLOR: Color32 = Color32::from_rgba_premultiplied(195, 177, 225, 255);
 const TRANSPARENT_COLOR: Color32 = Color32::from_rgba_premultiplied(255, 192, 203, 255);
+pub const SELECTED_COLOR: Color32 = Color32::from_rgba_premultiplied(255, 64, 255, 255);
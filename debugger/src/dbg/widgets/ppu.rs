@@ -1,10 +1,14 @@
-use crate::dbg::widgets::TRANSPARENT_COLOR;
+use crate::dbg::session;
+use crate::dbg::widgets::{SELECTED_COLOR, TRANSPARENT_COLOR};
 use crate::event::RequestEvent;
 use crossbeam_channel::Sender;
-use egui::{CollapsingHeader, Color32, ColorImage, Context, Image, RichText, TextureHandle, TextureOptions, Window};
-use gba_core::video::ppu::Sprite;
+use egui::{
+    CollapsingHeader, Color32, ColorImage, Context, DragValue, Image, Label, Pos2, Rect, RichText, Sense, Shape,
+    Stroke, StrokeKind, TextureHandle, TextureOptions, Ui, vec2,
+};
+use gba_core::video::ppu::{Sprite, TilemapEntry};
 use gba_core::video::registers::{BgCnt, BgOffset, DispCnt, DispStat, InternalScreenSize, ObjSize};
-use gba_core::video::{Frame, Pixel, SCREEN_HEIGHT, SCREEN_WIDTH};
+use gba_core::video::{Frame, PALETTE_ADDR_START, PALETTE_ENTRIES, Pixel, SCREEN_HEIGHT, SCREEN_WIDTH};
 
 #[derive(Default)]
 pub struct PpuRegisters {
@@ -13,11 +17,14 @@ pub struct PpuRegisters {
     pub bg_cnt: [BgCnt; 4],
     pub bg_vofs: [BgOffset; 4],
     pub bg_hofs: [BgOffset; 4],
+    pub bg_affine: [([i32; 2], [i32; 4]); 2], // reference point and PA, PB, PC, PD of BG2 and BG3
 }
 
+pub type Tilemap = (InternalScreenSize, Vec<Pixel>, Vec<TilemapEntry>);
+
 pub struct PpuWidget {
-    pub frames: Vec<Frame>,
-    pub tilemaps: [(InternalScreenSize, Vec<Pixel>); 4],
+    pub frames: Vec<Box<Frame>>,
+    pub tilemaps: [Tilemap; 4],
     pub palette: Vec<Pixel>,
     pub registers: PpuRegisters,
     pub sprites: Vec<Sprite>,
@@ -32,6 +39,10 @@ pub struct PpuWidget {
     bgmode4_frame1_texture: Option<TextureHandle>,
     bgmode5_frame0_texture: Option<TextureHandle>,
     bgmode5_frame1_texture: Option<TextureHandle>,
+    layers: [bool; 5],          // BG0-BG3 and OBJ
+    selected: Option<usize>,    // OAM index of the sprite highlighted on the screen
+    jump: Option<(u32, usize)>, // tilemap entry clicked this frame, address and size
+    editing: Option<usize>,     // palette entry shown in the editor
     event_tx: Sender<RequestEvent>,
 }
 
@@ -42,10 +53,10 @@ impl PpuWidget {
         PpuWidget {
             frames: Vec::new(),
             tilemaps: [
-                (InternalScreenSize::Text256x256, Vec::new()),
-                (InternalScreenSize::Text256x256, Vec::new()),
-                (InternalScreenSize::Text256x256, Vec::new()),
-                (InternalScreenSize::Text256x256, Vec::new()),
+                (InternalScreenSize::Text256x256, Vec::new(), Vec::new()),
+                (InternalScreenSize::Text256x256, Vec::new(), Vec::new()),
+                (InternalScreenSize::Text256x256, Vec::new(), Vec::new()),
+                (InternalScreenSize::Text256x256, Vec::new(), Vec::new()),
             ],
             palette: Vec::new(),
             registers: PpuRegisters::default(),
@@ -61,13 +72,17 @@ impl PpuWidget {
             bgmode4_frame1_texture: None,
             bgmode5_frame0_texture: None,
             bgmode5_frame1_texture: None,
+            layers: [true; 5],
+            selected: None,
+            jump: None,
+            editing: None,
             event_tx: tx,
         }
     }
 
     pub fn update(
-        &mut self, ctx: &Context, frames: Vec<Frame>, tilemaps: [(InternalScreenSize, Vec<Pixel>); 4],
-        palette: Vec<Pixel>, registers: PpuRegisters, sprites: Vec<Sprite>,
+        &mut self, ctx: &Context, frames: Vec<Box<Frame>>, tilemaps: [Tilemap; 4], palette: Vec<Pixel>,
+        registers: PpuRegisters, sprites: Vec<Sprite>,
     ) {
         self.frames = frames;
         self.tilemaps = tilemaps;
@@ -77,15 +92,13 @@ impl PpuWidget {
 
         let update_texture = |texture: &mut Option<TextureHandle>, frame: &Frame| {
             if let Some(texture) = texture {
-                let mut pixels = vec![TRANSPARENT_COLOR; SCREEN_WIDTH * SCREEN_HEIGHT];
-                for y in 0..SCREEN_HEIGHT {
-                    for x in 0..SCREEN_WIDTH {
-                        let color = frame[y][x];
-                        if let Pixel::Rgb(r, g, b) = color {
-                            pixels[y * SCREEN_WIDTH + x] = Color32::from_rgba_premultiplied(r, g, b, 255);
-                        }
-                    }
-                }
+                let pixels = frame
+                    .iter()
+                    .map(|&color| match Pixel::unpack(color) {
+                        Pixel::Rgb(r, g, b) => Color32::from_rgba_premultiplied(r, g, b, 255),
+                        Pixel::Transparent => TRANSPARENT_COLOR,
+                    })
+                    .collect();
                 let image = ColorImage {
                     size: [SCREEN_WIDTH, SCREEN_HEIGHT],
                     pixels,
@@ -250,8 +263,196 @@ impl PpuWidget {
         let _ = self.event_tx.send(RequestEvent::UpdatePpu);
     }
 
+    fn describe_sprite(sprite: &Sprite) -> String {
+        format!(
+            "ID: {}, Tile Nr: {}\nX: {}, Y: {}\nSize: {}\nShape: {:?}\nPriority: {:?}\nPalette: {}\nColor Depth: {}\nFlip X: {}, Flip Y: {}\nAttribute 0: {:04X} @ {:08X}\nAttribute 1: {:04X} @ {:08X}\nAttribute 2: {:04X} @ {:08X}",
+            sprite.id,
+            sprite.tile_number,
+            sprite.x,
+            sprite.y,
+            sprite.size,
+            sprite.shape,
+            sprite.priority,
+            sprite.palette,
+            sprite.attr0.bpp(),
+            sprite.x_flip,
+            sprite.y_flip,
+            sprite.attr0.bits(),
+            sprite.attr0_addr,
+            sprite.attr1.bits(),
+            sprite.attr1_addr,
+            sprite.attr2.bits(),
+            sprite.attr2_addr,
+        )
+    }
+
+    // the bounding box of the selected sprite on the screen, x, y, width and height
+    pub fn selected_bounds(&self) -> Option<[i32; 4]> {
+        self.selected_sprite().map(|sprite| sprite.bounds)
+    }
+
+    fn selected_sprite(&self) -> Option<&Sprite> {
+        self.sprites.iter().find(|sprite| Some(sprite.id) == self.selected)
+    }
+
+    // palette RAM only stores 5 bits per channel, the lower bits of the unpacked color repeat the upper ones
+    fn bgr555(color: &Pixel) -> u16 {
+        match color {
+            Pixel::Rgb(r, g, b) => (*r as u16 >> 3) | (*g as u16 >> 3) << 5 | (*b as u16 >> 3) << 10,
+            Pixel::Transparent => 0,
+        }
+    }
+
+    fn describe_palette_entry(index: usize, color: &Pixel) -> String {
+        format!(
+            "{} Palette {}, Color {}\nAddress: {:08X}\nBGR555: {:04X}\nClick to edit",
+            if index < PALETTE_ENTRIES { "BG" } else { "OBJ" },
+            (index % PALETTE_ENTRIES) / 16,
+            index % 16,
+            PALETTE_ADDR_START + index as u32 * 2,
+            Self::bgr555(color),
+        )
+    }
+
+    // changes are written to palette RAM right away, the game may overwrite them again
+    fn render_palette_editor(&mut self, ui: &mut Ui, index: usize) {
+        let Some(color) = self.palette.get(index) else {
+            return;
+        };
+        let mut bgr555 = Self::bgr555(color);
+        let mut channels = [0, 5, 10].map(|shift| (bgr555 >> shift) & 0x1F);
+
+        ui.monospace(format!(
+            "Entry {:03X} @ {:08X}",
+            index,
+            PALETTE_ADDR_START + index as u32 * 2
+        ));
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            for (channel, name) in channels.iter_mut().zip(["R", "G", "B"]) {
+                ui.label(name);
+                changed |= ui.add(DragValue::new(channel).range(0..=0x1F)).changed();
+            }
+            if changed {
+                bgr555 = channels[0] | channels[1] << 5 | channels[2] << 10;
+            }
+
+            ui.separator();
+            ui.label("BGR555");
+            changed |= ui
+                .add(
+                    DragValue::new(&mut bgr555)
+                        .range(0..=0x7FFF)
+                        .hexadecimal(4, false, true),
+                )
+                .changed();
+        });
+
+        if changed {
+            // shown right away instead of waiting for the next PPU update
+            let [r, g, b] = [0, 5, 10].map(|shift| {
+                let channel = (bgr555 >> shift & 0x1F) as u8;
+                channel << 3 | channel >> 2
+            });
+            self.palette[index] = Pixel::Rgb(r, g, b);
+            let _ = self.event_tx.send(RequestEvent::WritePalette(index, bgr555));
+        }
+    }
+
+    // the memory viewer shows the entry that was clicked in a tilemap
+    pub fn take_jump(&mut self) -> Option<(u32, usize)> {
+        self.jump.take()
+    }
+
+    // the part of the map that ends up on the screen, in map pixels
+    fn viewport(&self, bg: usize, size: InternalScreenSize) -> Vec<[Pos2; 4]> {
+        let (width, height) = (SCREEN_WIDTH as f32, SCREEN_HEIGHT as f32);
+
+        if size.is_affine() {
+            // every corner of the screen goes through the matrix, BG0 and BG1 are not shown in the affine modes
+            let Some(&([ref_x, ref_y], [pa, pb, pc, pd])) =
+                bg.checked_sub(2).and_then(|i| self.registers.bg_affine.get(i))
+            else {
+                return Vec::new();
+            };
+            let corner = |x: f32, y: f32| {
+                Pos2::new(
+                    (ref_x as f32 + pa as f32 * x + pb as f32 * y) / 256.0,
+                    (ref_y as f32 + pc as f32 * x + pd as f32 * y) / 256.0,
+                )
+            };
+            return vec![[
+                corner(0.0, 0.0),
+                corner(width, 0.0),
+                corner(width, height),
+                corner(0.0, height),
+            ]];
+        }
+
+        // text maps wrap around, the parts past the right and bottom edge show up on the other side
+        let (map_w, map_h) = (size.width() as f32, size.height() as f32);
+        let x = (self.registers.bg_hofs[bg].offset() % size.width()) as f32;
+        let y = (self.registers.bg_vofs[bg].offset() % size.height()) as f32;
+        [(0.0, 0.0), (-map_w, 0.0), (0.0, -map_h), (-map_w, -map_h)]
+            .map(|(dx, dy)| {
+                let rect = Rect::from_min_size(Pos2::new(x + dx, y + dy), vec2(width, height));
+                [
+                    rect.left_top(),
+                    rect.right_top(),
+                    rect.right_bottom(),
+                    rect.left_bottom(),
+                ]
+            })
+            .to_vec()
+    }
+
+    fn describe_tilemap_entry(bg: usize, (tx, ty): (usize, usize), entry: &TilemapEntry) -> String {
+        format!(
+            "BG{} Tile ({}, {})\nEntry Address: {:08X}\nTile ID: {}\nTile Address: {:08X}\nPalette: {}\nFlip X: {}, Flip Y: {}\nClick to show in memory",
+            bg, tx, ty, entry.addr, entry.tile_id, entry.tile_addr, entry.palette, entry.x_flip, entry.y_flip,
+        )
+    }
+
+    fn render_tilemap(&mut self, ui: &mut Ui, bg: usize, texture: &TextureHandle) {
+        let (size, _, entries) = &self.tilemaps[bg];
+        let response = ui.add(Image::new(texture).sense(Sense::click()));
+        let rect = response.rect;
+        let scale = vec2(rect.width() / size.width() as f32, rect.height() / size.height() as f32);
+
+        let painter = ui.painter_at(rect);
+        for corners in self.viewport(bg, *size) {
+            let points = corners.map(|point| rect.min + point.to_vec2() * scale).to_vec();
+            painter.add(Shape::closed_line(points, Stroke::new(1.0_f32, SELECTED_COLOR)));
+        }
+
+        let Some(pointer) = response.hover_pos() else {
+            return;
+        };
+        let tiles_x = size.width() / 8;
+        let tile = (
+            ((pointer.x - rect.left()) / scale.x) as usize / 8,
+            ((pointer.y - rect.top()) / scale.y) as usize / 8,
+        );
+        let Some(entry) = entries.get(tile.1 * tiles_x + tile.0).copied() else {
+            return;
+        };
+
+        let tile_rect = Rect::from_min_size(
+            rect.min + vec2(tile.0 as f32 * 8.0, tile.1 as f32 * 8.0) * scale,
+            vec2(8.0, 8.0) * scale,
+        );
+        painter.rect_stroke(tile_rect, 0.0, Stroke::new(1.0_f32, Color32::WHITE), StrokeKind::Inside);
+
+        let response = response
+            .on_hover_text_at_pointer(RichText::new(Self::describe_tilemap_entry(bg, tile, &entry)).monospace());
+        if response.clicked() {
+            // text map entries are 16-bit, affine ones a single byte
+            self.jump = Some((entry.addr, if size.is_affine() { 1 } else { 2 }));
+        }
+    }
+
     pub fn render(&mut self, ctx: &Context) {
-        Window::new("PPU Registers").resizable(false).show(ctx, |ui| {
+        session::window(ctx, "PPU Registers").resizable(false).show(ctx, |ui| {
             CollapsingHeader::new("Display Control (DISP_CNT)")
                 .default_open(true)
                 .show(ui, |ui| {
@@ -409,23 +610,38 @@ impl PpuWidget {
                 });
         });
 
-        Window::new("PPU Video").resizable(false).show(ctx, |ui| {
-            CollapsingHeader::new("Tilemaps").default_open(true).show(ui, |ui| {
+        session::window(ctx, "PPU Video").resizable(false).show(ctx, |ui| {
+            // hidden layers are left out of the composed frame, to find out which one a glitch comes from
+            CollapsingHeader::new("Layers").default_open(true).show(ui, |ui| {
                 ui.horizontal(|ui| {
-                    if let Some(texture) = &self.tilemap0_texture {
-                        ui.image(texture);
+                    let mut changed = false;
+                    for (i, name) in ["BG0", "BG1", "BG2", "BG3", "OBJ"].iter().enumerate() {
+                        changed |= ui.checkbox(&mut self.layers[i], *name).changed();
                     }
 
-                    if let Some(texture) = &self.tilemap1_texture {
-                        ui.image(texture);
+                    if ui.button("Show all").clicked() {
+                        self.layers = [true; 5];
+                        changed = true;
                     }
 
-                    if let Some(texture) = &self.tilemap2_texture {
-                        ui.image(texture);
+                    if changed {
+                        let _ = self.event_tx.send(RequestEvent::SetPpuLayers(self.layers));
                     }
+                });
+            });
 
-                    if let Some(texture) = &self.tilemap3_texture {
-                        ui.image(texture);
+            CollapsingHeader::new("Tilemaps").default_open(true).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let textures = [
+                        self.tilemap0_texture.clone(),
+                        self.tilemap1_texture.clone(),
+                        self.tilemap2_texture.clone(),
+                        self.tilemap3_texture.clone(),
+                    ];
+                    for (bg, texture) in textures.iter().enumerate() {
+                        if let Some(texture) = texture {
+                            self.render_tilemap(ui, bg, texture);
+                        }
                     }
                 });
             });
@@ -435,55 +651,83 @@ impl PpuWidget {
                     ui.horizontal(|ui| {
                         for (col_index, color) in row.iter().enumerate() {
                             let i = row_index * 16 + col_index;
+                            let mut text = RichText::new(format!("{:04X}", i)).monospace();
                             if let Pixel::Rgb(r, g, b) = color {
-                                let color32 = Color32::from_rgb(*r, *g, *b);
-                                ui.label(
-                                    RichText::new(format!("{:04X}", i))
-                                        .background_color(color32)
-                                        .monospace(),
-                                );
-                            } else {
-                                ui.label(RichText::new(format!("{:04X}", i)).monospace());
+                                text = text.background_color(Color32::from_rgb(*r, *g, *b));
+                            }
+                            if self.editing == Some(i) {
+                                text = text.color(SELECTED_COLOR).strong();
+                            }
+
+                            let response = ui
+                                .add(Label::new(text).sense(Sense::click()))
+                                .on_hover_text(RichText::new(Self::describe_palette_entry(i, color)).monospace());
+                            if response.clicked() {
+                                self.editing = (self.editing != Some(i)).then_some(i);
                             }
                         }
                     });
                 }
+
+                if let Some(index) = self.editing {
+                    ui.separator();
+                    self.render_palette_editor(ui, index);
+                }
             });
 
             CollapsingHeader::new("Sprites").default_open(true).show(ui, |ui| {
                 ui.horizontal_wrapped(|ui| {
                     for sprite in &self.sprites {
                         let texture = self.sprite_textures.get(sprite.id).and_then(|t| t.as_ref()).unwrap();
-                        ui.add(
-                            Image::from_texture(texture)
-                                .fit_to_original_size(2.0)
-                                .texture_options(egui::TextureOptions::NEAREST),
-                        )
-                        .on_hover_text(
-                            RichText::new(format!(
-                                "ID: {}, Tile Nr: {}\nX: {}, Y: {}\nSize: {}\nShape: {:?}\nPriority: {:?}\nPalette: {}\nColor Depth: {}\nFlip X: {}, Flip Y: {}\nAttribute 0: {:04X} @ {:08X}\nAttribute 1: {:04X} @ {:08X}\nAttribute 2: {:04X} @ {:08X}",
-                                sprite.id,
-                                sprite.tile_number,
-                                sprite.x,
-                                sprite.y,
-                                sprite.size,
-                                sprite.shape,
-                                sprite.priority,
-                                sprite.palette,
-                                sprite.attr0.bpp(),
-                                sprite.x_flip,
-                                sprite.y_flip,
-                                sprite.attr0.bits(),
-                                sprite.attr0_addr,
-                                sprite.attr1.bits(),
-                                sprite.attr1_addr,
-                                sprite.attr2.bits(),
-                                sprite.attr2_addr,
-                            ))
-                            .monospace(),
-                        );
+                        let response = ui
+                            .add(
+                                Image::from_texture(texture)
+                                    .fit_to_original_size(2.0)
+                                    .texture_options(egui::TextureOptions::NEAREST)
+                                    .sense(Sense::click()),
+                            )
+                            .on_hover_text(RichText::new(Self::describe_sprite(sprite)).monospace());
+
+                        if response.clicked() {
+                            // clicking the selected sprite again removes the highlight
+                            self.selected = (self.selected != Some(sprite.id)).then_some(sprite.id);
+                        }
+                        if self.selected == Some(sprite.id) {
+                            ui.painter().rect_stroke(
+                                response.rect,
+                                0.0,
+                                Stroke::new(2.0_f32, SELECTED_COLOR),
+                                StrokeKind::Outside,
+                            );
+                        }
                     }
                 });
+
+                if let Some(sprite) = self.selected_sprite() {
+                    ui.separator();
+                    ui.monospace(Self::describe_sprite(sprite));
+                    match sprite.affine {
+                        Some((group, [pa, pb, pc, pd])) => {
+                            let fixed = |value: i32| value as f32 / 256.0;
+                            ui.monospace(format!(
+                                "Affine group: {}\nPA: {:04X} ({:>8.4})  PB: {:04X} ({:>8.4})\nPC: {:04X} ({:>8.4})  PD: {:04X} ({:>8.4})\nDouble size: {}",
+                                group,
+                                pa as u16,
+                                fixed(pa),
+                                pb as u16,
+                                fixed(pb),
+                                pc as u16,
+                                fixed(pc),
+                                pd as u16,
+                                fixed(pd),
+                                sprite.attr0.double_size(),
+                            ));
+                        }
+                        None => {
+                            ui.monospace("Affine: no");
+                        }
+                    }
+                }
             });
 
             CollapsingHeader::new("Internal Frames")
@@ -0,0 +1,83 @@
+use crate::dbg::session;
+use crate::event::RequestEvent;
+use crossbeam_channel::Sender;
+use egui::{CollapsingHeader, Context, RichText, ScrollArea};
+use gba_core::arm7tdmi::swi_log::{SwiStats, swi_name};
+
+pub struct SwiWidget {
+    event_tx: Sender<RequestEvent>,
+    track_calls: bool,
+    stats: Vec<SwiStats>,
+}
+
+impl SwiWidget {
+    pub fn new(tx: Sender<RequestEvent>) -> SwiWidget {
+        SwiWidget {
+            event_tx: tx,
+            track_calls: false,
+            stats: Vec::new(),
+        }
+    }
+
+    pub fn update(&mut self, stats: Vec<SwiStats>) {
+        self.stats = stats;
+    }
+
+    pub fn render(&mut self, ctx: &Context) {
+        session::window(ctx, "SWI Calls").resizable(false).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.checkbox(&mut self.track_calls, "Track").changed() {
+                    let _ = self.event_tx.send(RequestEvent::TrackSwis(self.track_calls));
+                    self.stats.clear();
+                }
+
+                ui.add_enabled_ui(self.track_calls, |ui| {
+                    if ui
+                        .button(format!("{} Refresh", egui_phosphor::regular::ARROW_CLOCKWISE))
+                        .clicked()
+                    {
+                        let _ = self.event_tx.send(RequestEvent::UpdateSwiLog);
+                    }
+
+                    // tracking again starts with an empty log
+                    if ui.button(format!("{} Clear", egui_phosphor::regular::TRASH)).clicked() {
+                        let _ = self.event_tx.send(RequestEvent::TrackSwis(true));
+                        self.stats.clear();
+                    }
+                });
+            });
+
+            ui.separator();
+
+            if self.stats.is_empty() {
+                ui.label(RichText::new("No BIOS calls recorded").italics());
+                return;
+            }
+
+            ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                for stats in &self.stats {
+                    let title = format!(
+                        "{:02X} {: <22} {: >8}",
+                        stats.number,
+                        swi_name(stats.number),
+                        stats.count
+                    );
+
+                    CollapsingHeader::new(RichText::new(title).monospace())
+                        .id_salt(stats.number)
+                        .show(ui, |ui| {
+                            let [r0, r1, r2, r3] = stats.last_args;
+                            ui.monospace(format!(
+                                "last call: r0={:08X} r1={:08X} r2={:08X} r3={:08X}",
+                                r0, r1, r2, r3
+                            ));
+
+                            for (addr, count) in &stats.callsites {
+                                ui.monospace(format!("{:08X} {: >8}", addr, count));
+                            }
+                        });
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,148 @@
+use crate::dbg::session;
+use crate::event::RequestEvent;
+use crossbeam_channel::Sender;
+use egui::{Color32, Context, Pos2, Rect, RichText, Sense, Stroke, Ui, vec2};
+use gba_core::video::SCREEN_HEIGHT;
+use gba_core::video::timeline::{FRAME_LINES, LINE_DOTS, TimelineEntry, TimelineEvent};
+
+const DOT_SCALE: f32 = 2.0; // screen pixels per PPU tick and per scanline
+const HOVER_DISTANCE: f32 = 4.0; // events this close to the cursor show up in the tooltip
+
+// legend order, also the order the kinds are toggled in
+const KINDS: [(&str, Color32); 6] = [
+    ("VBlank", Color32::from_rgb(120, 160, 255)),
+    ("HBlank", Color32::from_rgb(90, 90, 90)),
+    ("VCOUNT", Color32::from_rgb(255, 220, 80)),
+    ("DISPCNT", Color32::from_rgb(255, 110, 110)),
+    ("BLDCNT", Color32::from_rgb(220, 120, 255)),
+    ("DMA", Color32::from_rgb(110, 230, 140)),
+];
+
+pub struct TimelineWidget {
+    event_tx: Sender<RequestEvent>,
+    tracking: bool,
+    visible: [bool; 6], // indexed like KINDS
+    entries: Vec<TimelineEntry>,
+}
+
+impl TimelineWidget {
+    pub fn new(tx: Sender<RequestEvent>) -> TimelineWidget {
+        TimelineWidget {
+            event_tx: tx,
+            tracking: false,
+            visible: [true, false, true, true, true, true], // HBlank happens on every line and clutters the plot
+            entries: Vec::new(),
+        }
+    }
+
+    // the next frame is requested as soon as one arrives, like the audio scopes
+    pub fn update(&mut self, entries: Vec<TimelineEntry>) {
+        self.entries = entries;
+
+        if self.tracking {
+            let _ = self.event_tx.send(RequestEvent::UpdatePpuTimeline);
+        }
+    }
+
+    fn kind(event: &TimelineEvent) -> usize {
+        match event {
+            TimelineEvent::VBlank => 0,
+            TimelineEvent::HBlank => 1,
+            TimelineEvent::VCount => 2,
+            TimelineEvent::DispCnt(_) => 3,
+            TimelineEvent::BldCnt(_) => 4,
+            TimelineEvent::Dma(_) => 5,
+        }
+    }
+
+    fn describe(entry: &TimelineEntry) -> String {
+        let event = match entry.event {
+            TimelineEvent::VBlank => "VBlank".to_string(),
+            TimelineEvent::HBlank => "HBlank".to_string(),
+            TimelineEvent::VCount => "VCOUNT match".to_string(),
+            TimelineEvent::DispCnt(value) => format!("DISPCNT = {:04X}", value),
+            TimelineEvent::BldCnt(value) => format!("BLDCNT = {:04X}", value),
+            TimelineEvent::Dma(channel) => format!("DMA{} started", channel),
+        };
+        format!("line {:>3} dot {:>3}  {}", entry.scanline, entry.dot, event)
+    }
+
+    fn position(rect: Rect, entry: &TimelineEntry) -> Pos2 {
+        rect.left_top() + vec2(entry.dot as f32, entry.scanline as f32 + 0.5) * DOT_SCALE
+    }
+
+    // scanlines go down, PPU ticks go right, the visible area is separated from VBlank by a line
+    fn render_plot(&self, ui: &mut Ui) {
+        let size = vec2(LINE_DOTS as f32, FRAME_LINES as f32) * DOT_SCALE;
+        let (response, painter) = ui.allocate_painter(size, Sense::hover());
+        let rect = response.rect;
+        painter.rect_filled(rect, 0.0, Color32::BLACK);
+
+        let vblank_y = rect.top() + SCREEN_HEIGHT as f32 * DOT_SCALE;
+        painter.hline(rect.x_range(), vblank_y, Stroke::new(1.0_f32, Color32::DARK_GRAY));
+
+        let shown = || {
+            self.entries
+                .iter()
+                .filter(|entry| self.visible[Self::kind(&entry.event)])
+        };
+        for entry in shown() {
+            let color = KINDS[Self::kind(&entry.event)].1;
+            painter.circle_filled(Self::position(rect, entry), DOT_SCALE, color);
+        }
+
+        let Some(cursor) = response.hover_pos() else {
+            return;
+        };
+        let hovered: Vec<String> = shown()
+            .filter(|entry| Self::position(rect, entry).distance(cursor) <= HOVER_DISTANCE)
+            .map(Self::describe)
+            .collect();
+
+        let line = ((cursor.y - rect.top()) / DOT_SCALE) as u16;
+        let dot = ((cursor.x - rect.left()) / DOT_SCALE) as u16;
+        response.on_hover_ui_at_pointer(|ui| {
+            ui.monospace(format!("line {:>3} dot {:>3}", line, dot));
+            for text in hovered {
+                ui.monospace(text);
+            }
+        });
+    }
+
+    pub fn render(&mut self, ctx: &Context) {
+        session::window(ctx, "PPU Timeline").resizable(false).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.checkbox(&mut self.tracking, "Track").changed() {
+                    let _ = self.event_tx.send(RequestEvent::TrackPpuTimeline(self.tracking));
+                    self.entries.clear();
+                    if self.tracking {
+                        let _ = self.event_tx.send(RequestEvent::UpdatePpuTimeline);
+                    }
+                }
+
+                ui.separator();
+
+                for (i, (name, color)) in KINDS.iter().enumerate() {
+                    let count = self
+                        .entries
+                        .iter()
+                        .filter(|entry| Self::kind(&entry.event) == i)
+                        .count();
+                    ui.checkbox(
+                        &mut self.visible[i],
+                        RichText::new(format!("{} ({})", name, count)).color(*color),
+                    );
+                }
+            });
+
+            ui.separator();
+
+            if !self.tracking {
+                ui.label(RichText::new("Not tracking, events are only recorded while enabled").italics());
+                return;
+            }
+
+            self.render_plot(ui);
+        });
+    }
+}
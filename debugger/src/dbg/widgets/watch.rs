@@ -0,0 +1,144 @@
+use crate::dbg::session;
+use crate::dbg::watch::{WatchKind, Watchpoint};
+use crate::event::RequestEvent;
+use crossbeam_channel::Sender;
+use egui::{Color32, ComboBox, Context, Grid, RichText, TextEdit};
+
+pub struct WatchWidget {
+    event_tx: Sender<RequestEvent>,
+    expression: String,
+    expressions: Vec<String>,
+    values: Vec<Result<u32, String>>, // one per expression, evaluated by the emulator
+    watchpoint: String,
+    watch_kind: WatchKind,
+    watchpoints: Vec<Watchpoint>,
+}
+
+impl WatchWidget {
+    pub fn new(tx: Sender<RequestEvent>) -> WatchWidget {
+        let _ = tx.send(RequestEvent::UpdateWatches(Vec::new()));
+
+        WatchWidget {
+            event_tx: tx,
+            expression: String::new(),
+            expressions: Vec::new(),
+            values: Vec::new(),
+            watchpoint: String::new(),
+            watch_kind: WatchKind::default(),
+            watchpoints: Vec::new(),
+        }
+    }
+
+    pub fn update(&mut self, values: Vec<Result<u32, String>>) {
+        self.values = values;
+
+        let _ = self
+            .event_tx
+            .send(RequestEvent::UpdateWatches(self.expressions.clone()));
+    }
+
+    pub fn restore(&mut self, expressions: &[String], watchpoints: &[Watchpoint]) {
+        for watchpoint in self.watchpoints.drain(..) {
+            let _ = self.event_tx.send(RequestEvent::RemoveWatchpoint(watchpoint));
+        }
+
+        for &watchpoint in watchpoints {
+            self.watchpoints.push(watchpoint);
+            let _ = self.event_tx.send(RequestEvent::AddWatchpoint(watchpoint));
+        }
+
+        self.expressions = expressions.to_vec();
+        self.values.clear();
+    }
+
+    pub fn expressions(&self) -> Vec<String> {
+        self.expressions.clone()
+    }
+
+    pub fn watchpoints(&self) -> Vec<Watchpoint> {
+        self.watchpoints.clone()
+    }
+
+    pub fn render(&mut self, ctx: &Context) {
+        session::window(ctx, "Watch").resizable(false).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .button(format!("{} Add Watch", egui_phosphor::regular::PLUS))
+                    .clicked()
+                    && !self.expression.trim().is_empty()
+                {
+                    self.expressions.push(self.expression.trim().to_string());
+                }
+
+                TextEdit::singleline(&mut self.expression)
+                    .hint_text("Expression, e.g. u16[sp + 8]")
+                    .show(ui);
+            });
+
+            let mut removed = None;
+            Grid::new("watch_expressions").striped(true).show(ui, |ui| {
+                for (i, expression) in self.expressions.iter().enumerate() {
+                    ui.monospace(expression);
+                    match self.values.get(i) {
+                        Some(Ok(value)) => ui.monospace(format!("{:08X} ({})", value, value)),
+                        Some(Err(e)) => ui.label(RichText::new(e).color(Color32::RED)),
+                        None => ui.label(""),
+                    };
+                    if ui.button(egui_phosphor::regular::TRASH).clicked() {
+                        removed = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
+            if let Some(i) = removed {
+                self.expressions.remove(i);
+                self.values.clear();
+            }
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui
+                    .button(format!("{} Add Watchpoint", egui_phosphor::regular::EYE))
+                    .clicked()
+                    && let Ok(addr) = u32::from_str_radix(self.watchpoint.trim_start_matches("0x"), 16)
+                {
+                    let watchpoint = Watchpoint {
+                        addr,
+                        kind: self.watch_kind,
+                    };
+                    self.watchpoints.push(watchpoint);
+                    let _ = self.event_tx.send(RequestEvent::AddWatchpoint(watchpoint));
+                }
+
+                TextEdit::singleline(&mut self.watchpoint)
+                    .hint_text("Address")
+                    .desired_width(80.0)
+                    .show(ui);
+
+                ComboBox::from_id_salt("watch_kind")
+                    .selected_text(self.watch_kind.to_string())
+                    .show_ui(ui, |ui| {
+                        for kind in WatchKind::ALL {
+                            ui.selectable_value(&mut self.watch_kind, kind, kind.to_string());
+                        }
+                    });
+            });
+
+            let mut removed = None;
+            for (i, watchpoint) in self.watchpoints.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.monospace(format!("{:08X} {}", watchpoint.addr, watchpoint.kind));
+                    if ui.button(egui_phosphor::regular::TRASH).clicked() {
+                        removed = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = removed {
+                let _ = self
+                    .event_tx
+                    .send(RequestEvent::RemoveWatchpoint(self.watchpoints.remove(i)));
+            }
+        });
+    }
+}
@@ -0,0 +1,165 @@
+use crate::dbg::session;
+use crate::event::RequestEvent;
+use crossbeam_channel::Sender;
+use egui::{Color32, ColorImage, Context, Grid, Image, RichText, TextureHandle, TextureOptions};
+use gba_core::video::ppu::WindowRegion;
+use gba_core::video::registers::{DispCnt, WindowControl, WindowDimensions};
+use gba_core::video::{SCREEN_HEIGHT, SCREEN_WIDTH};
+
+const TINT_ALPHA: u8 = 96; // the game has to stay visible below the overlay
+
+// legend order, Outside is left untinted on the game screen
+const REGIONS: [(WindowRegion, &str, Color32); 4] = [
+    (WindowRegion::Win0, "WIN0", Color32::from_rgb(255, 80, 80)),
+    (WindowRegion::Win1, "WIN1", Color32::from_rgb(80, 220, 80)),
+    (WindowRegion::Obj, "OBJ Window", Color32::from_rgb(80, 140, 255)),
+    (WindowRegion::Outside, "Outside", Color32::from_rgb(60, 60, 60)),
+];
+
+#[derive(Default)]
+pub struct WindowRegisters {
+    pub disp_cnt: DispCnt,
+    pub win_h: [WindowDimensions; 2], // WIN0H, WIN1H
+    pub win_v: [WindowDimensions; 2], // WIN0V, WIN1V
+    pub winin: WindowControl,
+    pub winout: WindowControl,
+}
+
+pub struct WindowWidget {
+    event_tx: Sender<RequestEvent>,
+    registers: WindowRegisters,
+    overlay: bool, // tints the game screen
+    preview_texture: Option<TextureHandle>,
+    overlay_texture: Option<TextureHandle>,
+}
+
+impl WindowWidget {
+    pub fn new(tx: Sender<RequestEvent>) -> WindowWidget {
+        let _ = tx.send(RequestEvent::UpdateWindows);
+
+        WindowWidget {
+            event_tx: tx,
+            registers: WindowRegisters::default(),
+            overlay: false,
+            preview_texture: None,
+            overlay_texture: None,
+        }
+    }
+
+    fn color(region: WindowRegion) -> Color32 {
+        REGIONS.iter().find(|(r, _, _)| *r == region).unwrap().2
+    }
+
+    pub fn update(&mut self, ctx: &Context, regions: Vec<WindowRegion>, registers: WindowRegisters) {
+        self.registers = registers;
+
+        let image = |pixels: Vec<Color32>| ColorImage {
+            size: [SCREEN_WIDTH, SCREEN_HEIGHT],
+            pixels,
+        };
+        let preview = image(regions.iter().map(|&region| Self::color(region)).collect());
+        let overlay = image(
+            regions
+                .iter()
+                .map(|&region| match region {
+                    WindowRegion::Outside => Color32::TRANSPARENT,
+                    region => Self::color(region).gamma_multiply(TINT_ALPHA as f32 / 255.0),
+                })
+                .collect(),
+        );
+
+        for (texture, image, name) in [
+            (&mut self.preview_texture, preview, "window_preview"),
+            (&mut self.overlay_texture, overlay, "window_overlay"),
+        ] {
+            match texture {
+                Some(texture) => texture.set(image, TextureOptions::NEAREST),
+                None => *texture = Some(ctx.load_texture(name, image, TextureOptions::NEAREST)),
+            }
+        }
+
+        let _ = self.event_tx.send(RequestEvent::UpdateWindows);
+    }
+
+    // drawn over the game screen, unrotated
+    pub fn overlay(&self) -> Option<&TextureHandle> {
+        self.overlay.then_some(self.overlay_texture.as_ref()).flatten()
+    }
+
+    // which layers and whether the color effects are shown in every region, WINOUT holds both Outside and the OBJ window
+    fn render_masks(&self, ui: &mut egui::Ui) {
+        let (winin, winout) = (&self.registers.winin, &self.registers.winout);
+        let masks: [(bool, [bool; 4], bool, bool); 4] = [
+            (
+                self.registers.disp_cnt.contains(DispCnt::WIN0_ON),
+                [0, 1, 2, 3].map(|bg| winin.is_bg_enabled_win0(bg)),
+                winin.obj_enabled_win0(),
+                winin.effects_enabled_win0(),
+            ),
+            (
+                self.registers.disp_cnt.contains(DispCnt::WIN1_ON),
+                [0, 1, 2, 3].map(|bg| winin.is_bg_enabled_win1(bg)),
+                winin.obj_enabled_win1(),
+                winin.effects_enabled_win1(),
+            ),
+            (
+                self.registers.disp_cnt.contains(DispCnt::OBJ_WIN_ON),
+                [0, 1, 2, 3].map(|bg| winout.is_bg_enabled_win1(bg)),
+                winout.obj_enabled_win1(),
+                winout.effects_enabled_win1(),
+            ),
+            (
+                true,
+                [0, 1, 2, 3].map(|bg| winout.is_bg_enabled_out(bg)),
+                winout.obj_enabled_out(),
+                winout.effects_enabled_out(),
+            ),
+        ];
+        let flag = |enabled: bool| if enabled { "x" } else { "-" };
+
+        Grid::new("window_masks").striped(true).show(ui, |ui| {
+            for header in ["Region", "On", "BG0", "BG1", "BG2", "BG3", "OBJ", "SFX"] {
+                ui.label(RichText::new(header).monospace().strong());
+            }
+            ui.end_row();
+
+            for ((_, name, color), (enabled, bgs, obj, effects)) in REGIONS.iter().zip(masks) {
+                ui.label(RichText::new(*name).monospace().color(*color));
+                ui.monospace(flag(enabled));
+                for bg in bgs {
+                    ui.monospace(flag(bg));
+                }
+                ui.monospace(flag(obj));
+                ui.monospace(flag(effects));
+                ui.end_row();
+            }
+        });
+    }
+
+    pub fn render(&mut self, ctx: &Context) {
+        session::window(ctx, "PPU Windows").resizable(false).show(ctx, |ui| {
+            ui.checkbox(&mut self.overlay, "Tint the game screen");
+            ui.separator();
+
+            // x1 is inclusive and x2 exclusive, x1 > x2 wraps around the edge of the screen
+            for (i, (h, v)) in self.registers.win_h.iter().zip(self.registers.win_v.iter()).enumerate() {
+                ui.monospace(format!(
+                    "WIN{}: X {:>3}..{:>3}, Y {:>3}..{:>3}",
+                    i,
+                    h.x1(),
+                    h.x2(),
+                    v.x1(),
+                    v.x2()
+                ));
+            }
+            ui.separator();
+
+            self.render_masks(ui);
+            ui.separator();
+
+            if let Some(texture) = &self.preview_texture {
+                ui.add(Image::new(texture));
+            }
+        });
+    }
+}
@@ -1,70 +1,396 @@
 use crossbeam_channel::{Receiver, Sender};
-use gba_core::arm7tdmi::decoder::{Instruction, Register};
+use gba_core::arm7tdmi::decoder::{Instruction, Opcode, Register};
+use gba_core::arm7tdmi::error::CpuError;
+use gba_core::arm7tdmi::swi_log::SwiLog;
 use gba_core::cartridge::storage::BackupType;
-use gba_core::gba::Gba;
-use gba_core::video::{FRAME_0_ADDRESS, FRAME_1_ADDRESS, Frame};
+use gba_core::gba::{BootOptions, CARTRIDGE_HEADER_SIZE, Gba};
+use gba_core::input::movie::Movie;
+use gba_core::input::registers::KeyInput;
+use gba_core::memory::device::AccessKind;
+use gba_core::memory::heat_map::HeatMap;
+use gba_core::memory::io_journal::IoJournal;
+use gba_core::video::timeline::PpuTimeline;
+use gba_core::video::{
+    FRAME_0_ADDRESS, FRAME_1_ADDRESS, Frame, PALETTE_ADDR_START, SCREEN_HEIGHT, SCREEN_WIDTH, frame_to_rgb,
+};
+use image::RgbImage;
 use lazy_static::lazy_static;
-use std::fs::File;
 use std::io::{Cursor, Read};
-use std::path::Path;
-use std::sync::Mutex;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tracing::{error, info, warn};
+use video_dump::VideoDumper;
 use zip::ZipArchive;
 
+use crate::audio::{PACING_LATENCY, SampleBuffer};
+use crate::capture::GifCapture;
+use crate::dbg::watch::{self, Watchpoint};
 use crate::dbg::widgets;
 use crate::dbg::widgets::disasm::DecodedInstruction;
 use crate::dbg::widgets::ppu::PpuRegisters;
-use crate::event::{RequestEvent, ResponseEvent};
+use crate::dbg::widgets::window::WindowRegisters;
+use crate::event::{EmulatorEvent, RequestEvent, ResponseEvent};
+use crate::netplay::{MAX_ROLLBACK, Netplay, Snapshot};
 
 lazy_static! {
     pub static ref BREAKPOINTS: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+    pub static ref BREAK_EVENTS: Mutex<BreakEvents> = Mutex::new(BreakEvents::default());
+    pub static ref WATCHPOINTS: Mutex<Vec<Watchpoint>> = Mutex::new(Vec::new());
 }
 
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BreakEvents {
+    pub swi: bool,
+    pub swi_filter: Option<u8>, // only break on this SWI number
+    pub irq: bool,
+    pub undefined: bool,
+}
+
+// what decides the emulation speed
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Pacing {
+    #[default]
+    Free, // as fast as the frontend takes frames
+    Audio, // as fast as the audio device plays samples, frames are dropped if the frontend lags behind
+}
+
+impl FromStr for Pacing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "free" => Ok(Pacing::Free),
+            "audio" => Ok(Pacing::Audio),
+            _ => Err(format!("unknown pacing mode '{}', expected free or audio", s)),
+        }
+    }
+}
+
+pub const MAX_RUN_AHEAD: usize = 4; // every frame ahead is emulated again on every frame
+const IO_JOURNAL_CAPACITY: usize = 100_000; // most recent I/O writes kept for the export
+
 pub struct Emulator {
     pub gba: Gba,
-    pub display_tx: Sender<Frame>,
+    pub display_tx: Sender<Box<Frame>>,
+    pub event_tx: Sender<EmulatorEvent>,
     pub dbg_req_rx: Receiver<RequestEvent>,
     pub dbg_resp_tx: Sender<ResponseEvent>,
+    pub screenshots: Vec<(usize, String)>, // (frame, path)
+    pub symbol_files: Vec<String>,
+    pub audio_buffer: Option<Arc<SampleBuffer>>, // played by the frontend
+    pub pacing: Pacing,
+    pub run_ahead: usize, // frames shown ahead of the emulated state
+    capture: Option<GifCapture>,
+    video_dump: Option<VideoDumper>,
+    frame_count: usize,
+    movie: Option<Movie>, // overrides the joypad until it runs out of frames
+    pub netplay: Option<Netplay>,
+    netplay_waiting: bool, // the next frame can't start before more input of the other player arrives
+    local_keys: KeyInput,  // held during netplay, the joypad gets them merged with the other player's
+    paused: bool,
+    frame_advance: bool, // pauses again once the current frame is drawn
+    rumble: bool,
+    save_path: PathBuf,
+    script_path: Option<String>,         // reloaded for every ROM
+    script_modified: Option<SystemTime>, // the script is reloaded when the file changes
+    boot_options: BootOptions,           // the parts that are not specific to a ROM
+}
+
+// multiboot images are usually distributed with a .mb extension
+pub fn is_multiboot_image(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "mb")
 }
 
 impl Emulator {
     pub fn new(
-        display_tx: Sender<Frame>, dbg_req_rx: Receiver<RequestEvent>, dbg_resp_tx: Sender<ResponseEvent>,
-        script_path: Option<String>, rom_path: String,
+        display_tx: Sender<Box<Frame>>, event_tx: Sender<EmulatorEvent>, dbg_req_rx: Receiver<RequestEvent>,
+        dbg_resp_tx: Sender<ResponseEvent>, script_path: Option<String>, rom_path: String, options: BootOptions,
     ) -> Self {
-        // Load ROM from file
-        let mut rom_data = Vec::new();
-        let mut rom_file = File::open(&rom_path).expect("Failed to open ROM file");
-        rom_file.read_to_end(&mut rom_data).expect("Failed to read ROM file");
-
-        // If it's a ZIP file, extract the ROM
-        if rom_path.ends_with(".zip") {
-            rom_data = Self::unzip_archive(&rom_data);
-        }
-
-        // Check for corresponding ELF file (for symbolizer)
-        let elf_path = rom_path.replace(".gba", ".elf");
-        let elf_data = if Path::new(&elf_path).exists() {
-            let mut elf_file = File::open(&elf_path).expect("Failed to open ELF file");
-            let mut data = Vec::new();
-            elf_file.read_to_end(&mut data).expect("Failed to read ELF file");
-            data
-        } else {
-            Vec::new()
+        let (rom_data, elf_data, elf_path) = Self::read_rom(&rom_path).expect("Failed to read ROM file");
+        let boot_options = BootOptions {
+            skip_bios: options.skip_bios,
+            fast_boot: options.fast_boot,
+            bios: options.bios.clone(),
+            ..Default::default()
         };
 
-        let mut gba = Gba::new(&rom_data, &elf_data);
-        if let Some(script_path) = script_path {
-            gba.load_rhai_script(script_path);
+        let mut gba = Gba::new(&rom_data, &elf_data, options);
+        if let Some(script_path) = &script_path {
+            gba.load_rhai_script(script_path.clone());
         }
-
-        let save_base_path = Path::new("saves");
-        gba.load_devices(&save_base_path);
+        let script_modified = script_path.as_deref().and_then(Self::modified);
 
         Self {
             gba,
             display_tx,
+            event_tx,
             dbg_req_rx,
             dbg_resp_tx,
+            screenshots: Vec::new(),
+            symbol_files: elf_path.into_iter().collect(),
+            audio_buffer: None,
+            pacing: Pacing::Free,
+            run_ahead: 0,
+            capture: None,
+            video_dump: None,
+            frame_count: 0,
+            movie: None,
+            netplay: None,
+            netplay_waiting: false,
+            local_keys: KeyInput::empty(),
+            paused: false,
+            frame_advance: false,
+            rumble: false,
+            save_path: PathBuf::new(),
+            script_path,
+            script_modified,
+            boot_options,
+        }
+    }
+
+    // returns the ROM (extracted if it is zipped) and the ELF next to it for the symbolizer, if there is one
+    fn read_rom(rom_path: &str) -> std::io::Result<(Vec<u8>, Vec<u8>, Option<String>)> {
+        let mut rom_data = std::fs::read(rom_path)?;
+        if rom_path.ends_with(".zip") {
+            rom_data = Self::unzip_archive(&rom_data)?;
+        }
+        if rom_data.len() < CARTRIDGE_HEADER_SIZE {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{} bytes are too small for a ROM", rom_data.len()),
+            ));
+        }
+
+        let elf_path = Path::new(rom_path).with_extension("elf").to_string_lossy().to_string();
+        if !Path::new(&elf_path).exists() {
+            return Ok((rom_data, Vec::new(), None));
+        }
+
+        let elf_data = std::fs::read(&elf_path)?;
+        Ok((rom_data, elf_data, Some(elf_path)))
+    }
+
+    // swaps the cartridge, the frontend and debugger stay connected and the settings carry over
+    pub fn load_rom(&mut self, rom_path: &str) {
+        let (rom_data, elf_data, elf_path) = match Self::read_rom(rom_path) {
+            Ok(data) => data,
+            Err(e) => {
+                error!(target: "cartridge", "Failed to load ROM {}: {}", rom_path, e);
+                return;
+            }
+        };
+
+        // write the old cartridge's save before it is dropped
+        self.gba.save_devices(&self.save_path);
+        self.gba.cpu.mmio.apu.stop_recording();
+
+        let options = BootOptions {
+            multiboot: is_multiboot_image(Path::new(rom_path)),
+            ..self.boot_options.clone()
+        };
+        let mut gba = Gba::new(&rom_data, &elf_data, options);
+        gba.set_mirror_aware_breakpoints(self.gba.mirror_aware_breakpoints);
+        gba.cpu.mmio.gpio.interpolate_missing = self.gba.cpu.mmio.gpio.interpolate_missing;
+        if let Some(history) = &self.gba.cpu.history {
+            gba.cpu.enable_history(history.capacity());
+        }
+        gba.cpu.mmio.heat_map = self.gba.cpu.mmio.heat_map.as_ref().map(|_| HeatMap::new());
+        gba.cpu.swi_log = self.gba.cpu.swi_log.as_ref().map(|_| SwiLog::new());
+        gba.cpu.mmio.ppu_timeline = self.gba.cpu.mmio.ppu_timeline.as_ref().map(|_| PpuTimeline::new());
+        gba.cpu.mmio.apu.channel_enabled = self.gba.cpu.mmio.apu.channel_enabled;
+        gba.cpu.mmio.ppu.layer_enabled = self.gba.cpu.mmio.ppu.layer_enabled;
+        gba.cpu.mmio.sio.link = self.gba.cpu.mmio.sio.link.clone();
+        if let Some(script_path) = &self.script_path {
+            gba.load_rhai_script(script_path.clone());
+            self.script_modified = Self::modified(script_path);
+        }
+
+        self.gba = gba;
+        self.symbol_files = elf_path.into_iter().collect();
+        self.frame_count = 0;
+        self.movie = None;
+        self.end_netplay("a different ROM was loaded");
+        self.load_save(Path::new(rom_path).with_extension("sav"));
+
+        info!(target: "cartridge", "Loaded ROM {}", rom_path);
+        let _ = self.event_tx.send(EmulatorEvent::RomLoaded(
+            self.gba.rom_title.clone(),
+            self.gba.crc32.clone(),
+        ));
+    }
+
+    // the save is written back to the same path on exit
+    pub fn load_save(&mut self, path: PathBuf) {
+        // fall back to where earlier versions kept the save, it moves to the new path on exit
+        if !self.gba.load_devices(&path) && self.gba.load_legacy_devices(Path::new("saves")) {
+            info!(target: "storage", "Migrating save data to {}", path.display());
+        }
+
+        self.save_path = path;
+    }
+
+    // plays back from power-on, so it only syncs if the ROM boots the same way it did when it was recorded
+    pub fn load_movie(&mut self, path: &Path) {
+        let movie = std::fs::read(path)
+            .map_err(|e| e.to_string())
+            .and_then(|data| Movie::from_vbm(&data).map_err(|e| e.to_string()));
+        let movie = match movie {
+            Ok(movie) => movie,
+            Err(e) => {
+                error!(target: "input", "Failed to load movie {}: {}", path.display(), e);
+                return;
+            }
+        };
+
+        let game_code = (0..4)
+            .map(|i| self.gba.cpu.mmio.read::<u8>(0x080000AC + i, AccessKind::Debugger) as char)
+            .collect::<String>();
+        let header_checksum = self.gba.cpu.mmio.read::<u8>(0x080000BD, AccessKind::Debugger);
+        if movie.game_code != game_code || movie.header_checksum != header_checksum {
+            warn!(target: "input",
+                "Movie was recorded with '{}' (checksum {:02X}), the loaded ROM is '{}' (checksum {:02X})",
+                movie.game_code, movie.header_checksum, game_code, header_checksum
+            );
+        }
+
+        info!(target: "input",
+            "Playing back {} frames ({} rerecords) from {}",
+            movie.len(), movie.rerecords, path.display()
+        );
+        self.movie = Some(movie);
+    }
+
+    // applied right before the frame starts, the input is polled by the game during the frame
+    fn play_movie(&mut self) {
+        let Some(movie) = &self.movie else {
+            return;
+        };
+        let Some(frame) = movie.frame(self.frame_count) else {
+            info!(target: "input", "Movie ended after {} frames", movie.len());
+            self.movie = None;
+            return;
+        };
+
+        if frame.reset {
+            self.gba.reset();
+        }
+        self.set_joypad(frame.keys);
+    }
+
+    fn set_joypad(&mut self, keys: KeyInput) {
+        for key in KeyInput::all().iter() {
+            self.gba.cpu.mmio.joypad.set_key_state(key, keys.contains(key));
+        }
+    }
+
+    // runs right before every frame like a movie, returns false while the other player is too far behind for the
+    // frame to start
+    fn sync_netplay(&mut self) -> bool {
+        let Some(mut netplay) = self.netplay.take() else {
+            return true;
+        };
+
+        let frame = self.frame_count;
+        netplay.push_local(frame, self.local_keys);
+        if let Err(e) = netplay.poll() {
+            error!(target: "netplay", "Netplay ended: {}", e);
+            return true;
+        }
+        if frame >= netplay.confirmed() + MAX_ROLLBACK {
+            self.netplay = Some(netplay);
+            return false;
+        }
+
+        self.roll_back(&mut netplay);
+
+        // frames that run with a guess can be rolled back to
+        let remote = netplay.remote_input(frame);
+        if frame >= netplay.confirmed() {
+            netplay.snapshots.push_back(Snapshot {
+                frame,
+                state: self.gba.save_state(),
+                remote,
+            });
+        }
+        self.set_joypad(netplay.local_input(frame) | remote);
+        self.netplay = Some(netplay);
+        true
+    }
+
+    // once the other player's input turns out to differ from the guess, every frame since is emulated again, the
+    // snapshots always cover the frames from the first unconfirmed one up to the current one
+    fn roll_back(&mut self, netplay: &mut Netplay) {
+        let confirmed = netplay.confirmed();
+        let wrong_guess = netplay
+            .snapshots
+            .iter()
+            .position(|snapshot| snapshot.frame < confirmed && snapshot.remote != netplay.remote_input(snapshot.frame));
+
+        if let Some(index) = wrong_guess {
+            // the replaced frames are not part of the rewind history
+            let history = self.gba.cpu.history.take();
+            self.gba.load_state(&netplay.snapshots[index].state);
+
+            for i in index..netplay.snapshots.len() {
+                let frame = netplay.snapshots[i].frame;
+                if i > index {
+                    netplay.snapshots[i].state = self.gba.save_state();
+                }
+                let remote = netplay.remote_input(frame);
+                netplay.snapshots[i].remote = remote;
+                self.set_joypad(netplay.local_input(frame) | remote);
+
+                while self.gba.cpu.mmio.ppu.scanline.0 != 160 {
+                    self.tick_silently();
+                }
+                while self.gba.cpu.mmio.ppu.scanline.0 != 0 {
+                    self.tick_silently();
+                }
+            }
+
+            // the audio of these frames was already played with the guessed input
+            self.gba.cpu.mmio.apu.drain_samples();
+            self.gba.cpu.history = history;
+        }
+
+        // the input of confirmed frames can't change anymore
+        while netplay
+            .snapshots
+            .front()
+            .is_some_and(|snapshot| snapshot.frame < confirmed)
+        {
+            netplay.snapshots.pop_front();
+        }
+    }
+
+    // the other player drops the session once nothing arrives for a while, it has to hear from a paused one too
+    fn poll_netplay(&mut self) {
+        if let Some(Err(e)) = self.netplay.as_mut().map(|netplay| netplay.poll()) {
+            self.end_netplay(&e.to_string());
+        }
+    }
+
+    // anything that changes the state on only one side breaks the lockstep
+    fn end_netplay(&mut self, reason: &str) {
+        if self.netplay.take().is_some() {
+            warn!(target: "netplay", "Netplay ended, {}", reason);
+            self.netplay_waiting = false;
+        }
+    }
+
+    pub fn load_symbols(&mut self, path: &str) {
+        if self.symbol_files.iter().any(|p| p == path) {
+            return;
+        }
+
+        match std::fs::read(path) {
+            Ok(data) => {
+                self.gba.cpu.load_symbols(&data);
+                self.symbol_files.push(path.to_string());
+            }
+            Err(e) => error!(target: "symbols", "Failed to load symbols from {}: {}", path, e),
         }
     }
 
@@ -72,6 +398,8 @@ impl Emulator {
         let mut frame_rendered = false;
         let mut tick = false;
         let mut step = false;
+        self.play_movie();
+        self.netplay_waiting = !self.sync_netplay();
 
         loop {
             if exit_rx.try_recv().is_ok() {
@@ -87,8 +415,18 @@ impl Emulator {
                 _ => (),
             }
 
-            if tick || step {
+            if self.netplay_waiting {
+                self.netplay_waiting = !self.sync_netplay();
+                if self.netplay_waiting {
+                    std::thread::sleep(Duration::from_millis(1));
+                    continue;
+                }
+            }
+
+            if (tick && !self.paused) || step {
                 self.do_tick(&mut tick);
+            } else {
+                self.poll_netplay();
             }
 
             if step {
@@ -96,15 +434,184 @@ impl Emulator {
             }
 
             if self.gba.cpu.mmio.ppu.scanline.0 == 160 && !frame_rendered {
-                let _ = self.display_tx.send(self.gba.cpu.mmio.ppu.get_frame());
+                let frame = if self.run_ahead > 0 && tick && !self.paused {
+                    self.run_ahead()
+                } else {
+                    self.gba.cpu.mmio.ppu.get_frame()
+                };
+                self.frame_count += 1;
+                self.take_screenshots(&frame);
+                if let Some(capture) = &mut self.capture {
+                    capture.push(&frame);
+                }
+                if let Some(video_dump) = &mut self.video_dump {
+                    video_dump.push_frame(&frame);
+                }
+                // screenshots and recordings only show the game
+                let mut frame = frame;
+                self.gba.draw_overlay(&mut frame);
+                if self.audio_paced() {
+                    let _ = self.display_tx.try_send(frame);
+                } else {
+                    let _ = self.display_tx.send(frame);
+                }
+                self.update_rumble();
+                self.queue_audio();
+                if std::mem::take(&mut self.frame_advance) {
+                    self.set_paused(true);
+                }
+                frame_rendered = true;
+            } else if self.gba.cpu.mmio.ppu.scanline.0 == 0 && frame_rendered {
+                frame_rendered = false;
+                self.watch_script();
+                if self.gba.script_loaded_state() {
+                    self.end_netplay("the script loaded a savestate");
+                }
+                self.play_movie();
+                self.netplay_waiting = !self.sync_netplay();
+            }
+        }
+
+        self.gba.save_devices(&self.save_path);
+        self.gba.cpu.mmio.apu.stop_recording();
+        self.capture = None;
+        self.finish_video_dump();
+    }
+
+    // no window, breakpoints or pacing, the frames are only written to the screenshots and the video dump
+    pub fn run_headless(&mut self) -> bool {
+        let last = self.screenshots.iter().map(|(frame, _)| *frame).max().unwrap_or(0);
+        let mut frame_rendered = false;
+        let mut tick = true;
+        let mut saved = true;
+        self.play_movie();
+
+        while self.frame_count < last {
+            self.do_tick(&mut tick);
+
+            if self.gba.cpu.mmio.ppu.scanline.0 == 160 && !frame_rendered {
+                let frame = self.gba.cpu.mmio.ppu.get_frame();
+                self.frame_count += 1;
+                saved &= self.take_screenshots(&frame);
+                if let Some(video_dump) = &mut self.video_dump {
+                    video_dump.push_frame(&frame);
+                }
+                self.queue_audio();
                 frame_rendered = true;
             } else if self.gba.cpu.mmio.ppu.scanline.0 == 0 && frame_rendered {
                 frame_rendered = false;
+                self.play_movie();
+            }
+        }
+
+        self.finish_video_dump();
+        saved
+    }
+
+    // every emulated frame and sample ends up in the video, independent of the pacing
+    pub fn start_video_dump(&mut self, path: &Path) {
+        match VideoDumper::create(path) {
+            Ok(video_dump) => {
+                info!(target: "ppu", "Dumping video to {}", path.display());
+                self.video_dump = Some(video_dump);
             }
+            Err(e) => error!(target: "ppu", "Failed to start ffmpeg for {}: {}", path.display(), e),
+        }
+    }
+
+    fn finish_video_dump(&mut self) {
+        let Some(video_dump) = self.video_dump.take() else {
+            return;
+        };
+
+        let path = video_dump.path.clone();
+        match video_dump.finish() {
+            Ok(()) => info!(target: "ppu", "Video saved to {}", path.display()),
+            Err(e) => error!(target: "ppu", "Failed to finish {}: {}", path.display(), e),
+        }
+    }
+
+    // falls back to free running while there is no output device
+    fn audio_paced(&self) -> bool {
+        self.pacing == Pacing::Audio && self.audio_buffer.as_ref().is_some_and(|buffer| buffer.is_playing())
+    }
+
+    fn queue_audio(&mut self) {
+        let samples = self.gba.cpu.mmio.apu.drain_samples();
+        if let Some(video_dump) = &mut self.video_dump {
+            video_dump.push_samples(&samples);
         }
+        let Some(buffer) = &self.audio_buffer else {
+            return;
+        };
 
-        let save_base_path = Path::new("saves");
-        self.gba.save_devices(&save_base_path);
+        // wait for the device to catch up, this keeps the buffer from overflowing or running dry
+        while self.audio_paced() && buffer.queued() > PACING_LATENCY {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        buffer.push(&samples);
+    }
+
+    // the frame that is shown is emulated a few frames ahead with the current input and then thrown away again, so
+    // games that take a few frames to react to a button press respond on screen that much sooner
+    fn run_ahead(&mut self) -> Box<Frame> {
+        // the speculative frames are not part of the rewind history
+        let history = self.gba.cpu.history.take();
+        let state = self.gba.save_state();
+
+        for _ in 0..self.run_ahead {
+            while self.gba.cpu.mmio.ppu.scanline.0 == 160 {
+                self.tick_silently();
+            }
+            while self.gba.cpu.mmio.ppu.scanline.0 != 160 {
+                self.tick_silently();
+            }
+        }
+        let frame = self.gba.cpu.mmio.ppu.get_frame();
+
+        // also drops the samples of the speculative frames
+        self.gba.load_state(&state);
+        self.gba.cpu.history = history;
+        frame
+    }
+
+    fn modified(path: &str) -> Option<SystemTime> {
+        std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+    }
+
+    // checked once per frame, editors may save a file in several steps but a broken version is not applied
+    fn watch_script(&mut self) {
+        let Some(script_path) = &self.script_path else {
+            return;
+        };
+
+        let modified = Self::modified(script_path);
+        if modified.is_some() && modified != self.script_modified {
+            self.script_modified = modified;
+            self.gba.reload_rhai_script(script_path.clone());
+        }
+    }
+
+    // no breakpoints or script handlers, whatever happens here is undone
+    fn tick_silently(&mut self) {
+        let _ = self.gba.cpu.tick();
+        self.gba.cpu.mmio.tick_components();
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+        let _ = self
+            .event_tx
+            .try_send(EmulatorEvent::Paused(paused.then_some(self.frame_count)));
+    }
+
+    // sampled once per frame, games pulse the motor faster than that to vary the strength
+    fn update_rumble(&mut self) {
+        let rumble = self.gba.cpu.mmio.gpio.is_rumbling();
+        if rumble != self.rumble {
+            self.rumble = rumble;
+            let _ = self.event_tx.try_send(EmulatorEvent::Rumble(rumble));
+        }
     }
 
     fn process_debug_events(&mut self) -> EventResult {
@@ -120,6 +627,14 @@ impl Emulator {
                     }));
                     EventResult::None
                 }
+                RequestEvent::UpdateWatches(expressions) => {
+                    let values = expressions
+                        .iter()
+                        .map(|expression| watch::evaluate(expression, &mut self.gba.cpu))
+                        .collect();
+                    let _ = self.dbg_resp_tx.send(ResponseEvent::Watches(values));
+                    EventResult::None
+                }
                 RequestEvent::UpdateMemory => {
                     let mut memory = unsafe {
                         let memory = Box::<[u8; 0x0FFFFFFF + 1]>::new_zeroed();
@@ -144,10 +659,45 @@ impl Emulator {
                 RequestEvent::Break => EventResult::Break,
                 RequestEvent::Run => EventResult::Continue,
                 RequestEvent::Step => EventResult::Step,
+                RequestEvent::StepBack(count) => {
+                    self.end_netplay("instructions were stepped back");
+                    let rewound = (0..count).take_while(|_| self.gba.cpu.step_back()).count();
+                    if rewound < count {
+                        warn!(target: "cpu", "Only {} of {} instruction(s) could be rewound", rewound, count);
+                    }
+                    EventResult::Break
+                }
+                RequestEvent::TogglePause => {
+                    self.frame_advance = false;
+                    self.set_paused(!self.paused);
+                    EventResult::None
+                }
+                RequestEvent::FrameAdvance => {
+                    self.frame_advance = true;
+                    self.paused = false;
+                    EventResult::None
+                }
+                RequestEvent::Reset => {
+                    self.end_netplay("the game was reset");
+                    self.gba.reset();
+                    EventResult::None
+                }
+                RequestEvent::LoadRom(path) => {
+                    self.load_rom(&path);
+                    EventResult::None
+                }
+                RequestEvent::LoadSymbols(path) => {
+                    self.load_symbols(&path);
+                    EventResult::None
+                }
                 RequestEvent::AddBreakpoint(addr) => {
                     BREAKPOINTS.lock().unwrap().push(addr);
                     EventResult::None
                 }
+                RequestEvent::UpdateBreakEvents(events) => {
+                    *BREAK_EVENTS.lock().unwrap() = events;
+                    EventResult::None
+                }
                 RequestEvent::RemoveBreakpoint(addr) => {
                     let mut breakpoints = BREAKPOINTS.lock().unwrap();
                     if let Some(index) = breakpoints.iter().position(|&x| x == addr) {
@@ -155,6 +705,17 @@ impl Emulator {
                     }
                     EventResult::None
                 }
+                RequestEvent::AddWatchpoint(watchpoint) => {
+                    WATCHPOINTS.lock().unwrap().push(watchpoint);
+                    EventResult::None
+                }
+                RequestEvent::RemoveWatchpoint(watchpoint) => {
+                    let mut watchpoints = WATCHPOINTS.lock().unwrap();
+                    if let Some(index) = watchpoints.iter().position(|&x| x == watchpoint) {
+                        watchpoints.remove(index);
+                    }
+                    EventResult::None
+                }
                 RequestEvent::UpdateDisassembly(base, count) => {
                     // decoded instruction would never be available here
                     let base = base.unwrap_or(if let Some(state) = self.gba.cpu.pipeline.peek_fetch() {
@@ -165,7 +726,7 @@ impl Emulator {
                     let mut disasm: Vec<DecodedInstruction> = Vec::new();
                     for addr in 0..count {
                         let addr = base + (addr * if self.gba.cpu.is_thumb() { 2 } else { 4 });
-                        let opcode = self.gba.cpu.mmio.read_u32(addr);
+                        let opcode = self.gba.cpu.mmio.read::<u32>(addr, AccessKind::Debugger);
                         match Instruction::decode(opcode, self.gba.cpu.is_thumb()) {
                             Ok(instr) => disasm.push(DecodedInstruction {
                                 addr,
@@ -184,12 +745,167 @@ impl Emulator {
                     ));
                     EventResult::None
                 }
+                RequestEvent::UpdateKeyState(_) if self.movie.is_some() => EventResult::None,
+                RequestEvent::UpdateKeyState(state) if self.netplay.is_some() => {
+                    for (key, pressed) in state {
+                        self.local_keys.set(key, pressed);
+                    }
+                    EventResult::None
+                }
                 RequestEvent::UpdateKeyState(state) => {
                     for (key, pressed) in state {
                         self.gba.cpu.mmio.joypad.set_key_state(key, pressed);
                     }
                     EventResult::None
                 }
+                RequestEvent::TrackAccesses(enabled) => {
+                    self.gba.cpu.mmio.heat_map = enabled.then(HeatMap::new);
+                    EventResult::None
+                }
+                RequestEvent::TrackIoWrites(enabled) => {
+                    self.gba.cpu.mmio.io_journal = enabled.then(|| IoJournal::new(IO_JOURNAL_CAPACITY));
+                    EventResult::None
+                }
+                RequestEvent::ExportIoJournal(path) => {
+                    if let Some(journal) = &self.gba.cpu.mmio.io_journal {
+                        match std::fs::write(&path, journal.to_json()) {
+                            Ok(()) => info!(target: "cpu", "Exported {} I/O writes to {}", journal.len(), path),
+                            Err(e) => error!(target: "cpu", "Failed to export the I/O journal to {}: {}", path, e),
+                        }
+                    }
+                    EventResult::None
+                }
+                RequestEvent::TrackSwis(enabled) => {
+                    self.gba.cpu.swi_log = enabled.then(SwiLog::new);
+                    EventResult::None
+                }
+                RequestEvent::UpdateSwiLog => {
+                    let stats = match &self.gba.cpu.swi_log {
+                        Some(log) => log.stats(),
+                        None => Vec::new(),
+                    };
+                    let _ = self.dbg_resp_tx.send(ResponseEvent::SwiLog(stats));
+                    EventResult::None
+                }
+                RequestEvent::TrackPpuTimeline(enabled) => {
+                    self.gba.cpu.mmio.ppu_timeline = enabled.then(PpuTimeline::new);
+                    EventResult::None
+                }
+                RequestEvent::UpdatePpuTimeline => {
+                    let entries = match &self.gba.cpu.mmio.ppu_timeline {
+                        Some(timeline) => timeline.last_frame().to_vec(),
+                        None => Vec::new(),
+                    };
+                    let _ = self.dbg_resp_tx.send(ResponseEvent::PpuTimeline(entries));
+                    EventResult::None
+                }
+                RequestEvent::UpdateBitmap(mode, base_addr) => {
+                    let ppu = &self.gba.cpu.mmio.ppu;
+                    let _ = self.dbg_resp_tx.send(ResponseEvent::Bitmap(
+                        ppu.get_background_frame(mode as usize, base_addr),
+                        ppu.bitmap_entries(mode, base_addr),
+                        *ppu.disp_cnt.value(),
+                    ));
+                    EventResult::None
+                }
+                RequestEvent::UpdateWindows => {
+                    let ppu = &self.gba.cpu.mmio.ppu;
+                    let _ = self.dbg_resp_tx.send(ResponseEvent::Windows(
+                        ppu.window_regions(),
+                        WindowRegisters {
+                            disp_cnt: *ppu.disp_cnt.value(),
+                            win_h: [*ppu.win0_h.value(), *ppu.win1_h.value()],
+                            win_v: [*ppu.win0_v.value(), *ppu.win1_v.value()],
+                            winin: *ppu.winin.value(),
+                            winout: *ppu.winout.value(),
+                        },
+                    ));
+                    EventResult::None
+                }
+                RequestEvent::UpdateHeatMap(start, end) => {
+                    let touched = match &self.gba.cpu.mmio.heat_map {
+                        Some(heat_map) => heat_map.touched(start, end),
+                        None => Vec::new(),
+                    };
+                    let _ = self.dbg_resp_tx.send(ResponseEvent::HeatMap(touched));
+                    EventResult::None
+                }
+                // the sensors are only read on one side, moving them ends netplay
+                RequestEvent::SetSolarLevel(level) => {
+                    if let Some(sensor) = &mut self.gba.cpu.mmio.gpio.solar_sensor
+                        && sensor.level != level
+                    {
+                        sensor.level = level;
+                        self.end_netplay("the solar sensor changed");
+                    }
+                    EventResult::None
+                }
+                RequestEvent::UpdateMotion(x, y) => {
+                    let mut moved = false;
+                    if let Some(tilt) = &mut self.gba.cpu.mmio.tilt_sensor {
+                        moved |= tilt.x != x || tilt.y != y;
+                        tilt.x = x;
+                        tilt.y = y;
+                    }
+                    if let Some(gyro) = &mut self.gba.cpu.mmio.gpio.gyro {
+                        moved |= gyro.rotation != x;
+                        gyro.rotation = x;
+                    }
+                    if moved {
+                        self.end_netplay("the motion sensor moved");
+                    }
+                    EventResult::None
+                }
+                RequestEvent::SetPacing(pacing) => {
+                    self.pacing = pacing;
+                    EventResult::None
+                }
+                RequestEvent::SetRunAhead(frames) => {
+                    self.run_ahead = frames;
+                    EventResult::None
+                }
+                RequestEvent::SetAudioChannels(enabled) => {
+                    self.gba.cpu.mmio.apu.channel_enabled = enabled;
+                    EventResult::None
+                }
+                RequestEvent::SetPpuLayers(enabled) => {
+                    self.gba.cpu.mmio.ppu.layer_enabled = enabled;
+                    EventResult::None
+                }
+                RequestEvent::WritePalette(index, color) => {
+                    self.end_netplay("the palette was edited");
+                    let addr = PALETTE_ADDR_START + index as u32 * 2;
+                    self.gba.cpu.mmio.write::<u16>(addr, color, AccessKind::Debugger);
+                    EventResult::None
+                }
+                RequestEvent::StartRecording(path, stems) => {
+                    self.gba.cpu.mmio.apu.start_recording(Path::new(&path), stems);
+                    EventResult::None
+                }
+                RequestEvent::StopRecording => {
+                    self.gba.cpu.mmio.apu.stop_recording();
+                    EventResult::None
+                }
+                RequestEvent::StartCapture(path, frame_skip) => {
+                    match GifCapture::start(Path::new(&path), frame_skip) {
+                        Ok(capture) => self.capture = Some(capture),
+                        Err(e) => error!(target: "ppu", "Failed to capture to {}: {}", path, e),
+                    }
+                    EventResult::None
+                }
+                RequestEvent::StopCapture => {
+                    self.capture = None;
+                    EventResult::None
+                }
+                RequestEvent::UpdateAudio => {
+                    let apu = &mut self.gba.cpu.mmio.apu;
+                    let scope = apu.scope.get_or_insert_with(Default::default);
+                    let waveforms = scope.each_ref().map(|samples| samples.iter().copied().collect());
+                    let _ = self
+                        .dbg_resp_tx
+                        .send(ResponseEvent::Audio(apu.channel_states(), waveforms));
+                    EventResult::None
+                }
                 RequestEvent::UpdatePpu => {
                     let _ = self.dbg_resp_tx.send(ResponseEvent::Ppu(
                         vec![
@@ -230,6 +946,7 @@ impl Emulator {
                             bg_cnt: self.gba.cpu.mmio.ppu.bg_cnt.map(|bg| *bg.value()),
                             bg_vofs: self.gba.cpu.mmio.ppu.bg_vofs.map(|bg| *bg.value()),
                             bg_hofs: self.gba.cpu.mmio.ppu.bg_hofs.map(|bg| *bg.value()),
+                            bg_affine: [0, 1].map(|i| self.gba.cpu.mmio.ppu.affine_parameters(i)),
                         },
                         self.gba.cpu.mmio.ppu.create_sprite_debug_map(),
                     ));
@@ -242,41 +959,118 @@ impl Emulator {
     fn do_tick(&mut self, tick: &mut bool) -> Option<Instruction> {
         let mut executed_instr: Option<Instruction> = None;
 
-        if let Ok((instr, state)) = self.gba.cpu.tick() {
-            if BREAKPOINTS
-                .lock()
-                .unwrap()
-                .contains(&(state.pc + if self.gba.cpu.is_thumb() { 2 } else { 4 }))
-            {
-                *tick = false;
-            }
+        let break_events = *BREAK_EVENTS.lock().unwrap();
 
-            self.gba.try_execute_breakpoint(state.pc, state.pc);
-            for addr in self.gba.cpu.mmio.last_rw_addr.clone() {
-                self.gba.try_execute_breakpoint(addr, state.pc);
-            }
+        match self.gba.cpu.tick() {
+            Ok((instr, state)) => {
+                let next_pc = state.pc + if self.gba.cpu.is_thumb() { 2 } else { 4 };
+                if BREAKPOINTS
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .any(|&addr| self.gba.breakpoint_matches(addr, next_pc))
+                {
+                    *tick = false;
+                }
+
+                // coprocessor instructions decode fine, but always end up in the UND exception
+                if break_events.undefined && instr.opcode.is_coprocessor() {
+                    *tick = false;
+                }
+
+                if self.hit_watchpoint() {
+                    *tick = false;
+                }
+
+                if break_events.swi && instr.opcode == Opcode::Swi {
+                    let number = instr.swi_number(state.is_thumb);
+                    if break_events.swi_filter.is_none() || break_events.swi_filter == number {
+                        *tick = false;
+                    }
+                }
+
+                self.gba.try_execute_breakpoint(state.pc, state.pc);
+                for addr in self.gba.cpu.mmio.last_rw_addr.clone() {
+                    self.gba.try_execute_breakpoint(addr, state.pc);
+                }
+                self.gba.try_execute_hooks(state.pc);
 
-            executed_instr = Some(instr);
+                executed_instr = Some(instr);
+            }
+            Err(CpuError::InterruptTriggered) if break_events.irq => *tick = false,
+            Err(CpuError::FailedToDecode) if break_events.undefined => *tick = false,
+            _ => {}
         }
 
         self.gba.cpu.mmio.tick_components();
+        self.gba.try_execute_callbacks();
 
         executed_instr
     }
 
-    fn unzip_archive(buffer: &[u8]) -> Vec<u8> {
-        let mut archive = ZipArchive::new(Cursor::new(buffer)).unwrap();
+    // the access log only starts with the next instruction once the first watchpoint was added
+    fn hit_watchpoint(&mut self) -> bool {
+        let watchpoints = WATCHPOINTS.lock().unwrap();
+        if watchpoints.is_empty() {
+            return false;
+        }
+
+        let mirror_aware = self.gba.mirror_aware_breakpoints;
+        let Some(accesses) = &self.gba.cpu.mmio.access_log else {
+            self.gba.cpu.mmio.access_log = Some(Vec::new());
+            return false;
+        };
+
+        let hit = accesses.iter().find_map(|access| {
+            let watchpoint = watchpoints.iter().find(|w| w.matches(access, mirror_aware))?;
+            Some((watchpoint, access))
+        });
+        match hit {
+            Some((watchpoint, access)) => {
+                info!(
+                    target: "cpu",
+                    "Watchpoint {:08X} ({}) hit by a {:?} of {:08X} at {:08X}",
+                    watchpoint.addr,
+                    watchpoint.kind,
+                    access.direction,
+                    access.value,
+                    self.gba.cpu.mmio.pc
+                );
+                true
+            }
+            None => false,
+        }
+    }
+
+    // false if one of the screenshots of this frame could not be saved
+    fn take_screenshots(&self, frame: &Frame) -> bool {
+        let mut saved = true;
+        for (_, path) in self.screenshots.iter().filter(|(n, _)| *n == self.frame_count) {
+            let img = RgbImage::from_raw(SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32, frame_to_rgb(frame)).unwrap();
+
+            match img.save(path) {
+                Ok(_) => info!(target: "ppu", "Saved frame {} to {}", self.frame_count, path),
+                Err(e) => {
+                    error!(target: "ppu", "Failed to save frame {} to {}: {}", self.frame_count, path, e);
+                    saved = false;
+                }
+            }
+        }
+        saved
+    }
+
+    fn unzip_archive(buffer: &[u8]) -> std::io::Result<Vec<u8>> {
+        let mut archive = ZipArchive::new(Cursor::new(buffer))?;
 
         let gba_index = (0..archive.len())
-            .filter(|&i| archive.by_index(i).unwrap().name().contains(".gba"))
-            .next()
-            .unwrap_or_else(|| panic!("No .gba file found in archive"));
+            .find(|&i| archive.by_index(i).is_ok_and(|file| file.name().contains(".gba")))
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "No .gba file found in archive"))?;
 
-        let mut file = archive.by_index(gba_index).unwrap();
+        let mut file = archive.by_index(gba_index)?;
         let mut buffer = Vec::with_capacity(file.size() as usize);
-        let _ = file.read_to_end(&mut buffer).unwrap();
+        file.read_to_end(&mut buffer)?;
 
-        buffer
+        Ok(buffer)
     }
 }
 
@@ -1,9 +1,16 @@
+use gba_core::arm7tdmi::swi_log::SwiStats;
+use gba_core::audio::channel::ChannelState;
 use gba_core::input::registers::KeyInput;
-use gba_core::video::ppu::Sprite;
-use gba_core::video::registers::InternalScreenSize;
+use gba_core::memory::heat_map::AccessCounts;
+use gba_core::video::ppu::{Sprite, WindowRegion};
+use gba_core::video::registers::DispCnt;
+use gba_core::video::timeline::TimelineEntry;
 use gba_core::video::{Frame, Pixel};
 
-use crate::dbg::widgets::ppu::PpuRegisters;
+use crate::dbg::watch::Watchpoint;
+use crate::dbg::widgets::ppu::{PpuRegisters, Tilemap};
+use crate::dbg::widgets::window::WindowRegisters;
+use crate::emulator::{BreakEvents, Pacing};
 
 use super::dbg::widgets::cpu::Cpu;
 use super::dbg::widgets::disasm::DecodedInstruction;
@@ -17,19 +24,66 @@ pub enum RequestEvent {
     Break,
     Run,
     Step,
+    StepBack(usize),
+    Reset,
+    LoadRom(String),     // path to the new ROM, replaces the running cartridge
+    LoadSymbols(String), // path to an ELF file
     AddBreakpoint(u32),
     RemoveBreakpoint(u32),
+    UpdateBreakEvents(BreakEvents),
+    AddWatchpoint(Watchpoint),
+    RemoveWatchpoint(Watchpoint),
+    UpdateWatches(Vec<String>), // expressions to evaluate
     UpdateKeyState(Vec<(KeyInput, bool)>),
+    TrackAccesses(bool),
+    UpdateHeatMap(u32, u32), // start, end (inclusive)
+    TrackIoWrites(bool),     // also clears the journal
+    ExportIoJournal(String), // path to the .json file
+    TrackSwis(bool),         // also clears the recorded calls
+    UpdateSwiLog,
+    SetSolarLevel(u8),
+    UpdateMotion(f32, f32), // x, y in -1.0..=1.0, x doubles as the gyro rotation
+    SetPacing(Pacing),
+    SetRunAhead(usize),           // frames, 0 disables it
+    SetAudioChannels([bool; 6]),  // indexed by Channel, false mutes the channel
+    SetPpuLayers([bool; 5]),      // BG0-BG3 and OBJ, false hides the layer
+    WritePalette(usize, u16),     // index into palette RAM (OBJ from 256 on), BGR555 color
+    StartRecording(String, bool), // path to the .wav file, also record every channel on its own
+    StopRecording,
+    StartCapture(String, usize), // path to the .gif file, frames skipped between the captured ones
+    StopCapture,
+    UpdateAudio,            // also starts capturing the channel waveforms
+    TrackPpuTimeline(bool), // also clears the recorded events
+    UpdatePpuTimeline,
+    UpdateBitmap(u8, u32), // mode 3 to 5, frame address
+    UpdateWindows,
+    TogglePause,  // independent of the debugger, a break keeps the emulator stopped either way
+    FrameAdvance, // runs until the next frame is drawn and pauses again
+}
+
+// sent by the emulator to the frontend, independent of the debugger
+#[derive(Debug)]
+pub enum EmulatorEvent {
+    Rumble(bool),              // rumble motor switched on or off
+    RomLoaded(String, String), // title, crc32
+    Paused(Option<usize>),     // frame the emulator paused on, None once it resumes
 }
 
 pub enum ResponseEvent {
     Memory(Box<[u8; 0x0FFFFFFF + 1]>),
     Cpu(Cpu),
+    Watches(Vec<Result<u32, String>>), // value or error of every expression
     Disassembly(u32, u32, Vec<DecodedInstruction>),
+    HeatMap(Vec<(u32, AccessCounts)>),
+    SwiLog(Vec<SwiStats>),
+    Audio([ChannelState; 6], [Vec<i16>; 6]), // states and recent levels indexed by Channel
+    PpuTimeline(Vec<TimelineEntry>),         // events of the last completed frame
+    Bitmap(Box<Frame>, Vec<Option<(u32, u16)>>, DispCnt), // address and raw value of every pixel
+    Windows(Vec<WindowRegion>, WindowRegisters), // region of every pixel
     Ppu(
-        Vec<Frame>,
+        Vec<Box<Frame>>,
         (usize, Vec<Pixel>),
-        [(InternalScreenSize, Vec<Pixel>); 4],
+        [Tilemap; 4],
         Vec<Pixel>,
         PpuRegisters,
         Vec<Sprite>,
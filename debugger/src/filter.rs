@@ -0,0 +1,173 @@
+use crate::upscale::UpscalerKind;
+use eframe::egui::{Color32, ColorImage};
+use gba_core::video::{Frame, SCREEN_HEIGHT, SCREEN_WIDTH};
+use std::fmt::Display;
+use std::str::FromStr;
+
+const LCD_GAMMA: f32 = 4.0; // the GBA screen is a lot darker than a modern display
+const OUT_GAMMA: f32 = 2.2;
+pub const MAX_INTERFRAME_BLEND: f32 = 0.5; // beyond that the previous frame would outweigh the current one
+
+// clockwise, for games that are played with the handheld turned on its side
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Rotation {
+    #[default]
+    None,
+    Clockwise90,
+    Clockwise180,
+    Clockwise270,
+}
+
+impl Rotation {
+    pub const ALL: [Rotation; 4] = [
+        Rotation::None,
+        Rotation::Clockwise90,
+        Rotation::Clockwise180,
+        Rotation::Clockwise270,
+    ];
+
+    pub fn quarter_turns(&self) -> usize {
+        *self as usize
+    }
+
+    // size of the rotated screen in GBA pixels
+    pub fn screen_size(&self) -> [usize; 2] {
+        match self.quarter_turns() % 2 {
+            0 => [SCREEN_WIDTH, SCREEN_HEIGHT],
+            _ => [SCREEN_HEIGHT, SCREEN_WIDTH],
+        }
+    }
+
+    // where a point of the unrotated screen ends up, both given as fractions of the screen size
+    pub fn map_point(&self, [u, v]: [f32; 2]) -> [f32; 2] {
+        match self {
+            Rotation::None => [u, v],
+            Rotation::Clockwise90 => [1.0 - v, u],
+            Rotation::Clockwise180 => [1.0 - u, 1.0 - v],
+            Rotation::Clockwise270 => [v, 1.0 - u],
+        }
+    }
+
+    fn apply(&self, image: ColorImage) -> ColorImage {
+        let [width, height] = image.size;
+        let size = match self.quarter_turns() % 2 {
+            0 => [width, height],
+            _ => [height, width],
+        };
+
+        // source pixel of every destination pixel
+        let source = |x: usize, y: usize| match self {
+            Rotation::None => (x, y),
+            Rotation::Clockwise90 => (y, height - 1 - x),
+            Rotation::Clockwise180 => (width - 1 - x, height - 1 - y),
+            Rotation::Clockwise270 => (width - 1 - y, x),
+        };
+
+        let pixels = (0..size[1])
+            .flat_map(|y| (0..size[0]).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (x, y) = source(x, y);
+                image.pixels[y * width + x]
+            })
+            .collect();
+        ColorImage { size, pixels }
+    }
+}
+
+impl FromStr for Rotation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0" => Ok(Rotation::None),
+            "90" => Ok(Rotation::Clockwise90),
+            "180" => Ok(Rotation::Clockwise180),
+            "270" => Ok(Rotation::Clockwise270),
+            _ => Err(format!("unknown rotation '{}', expected 0, 90, 180 or 270", s)),
+        }
+    }
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}°", self.quarter_turns() * 90)
+    }
+}
+
+// post-processing of the emulated frame before it is shown
+pub struct FrameFilter {
+    pub color_correction: bool,
+    pub interframe_blend: f32, // weight of the previous frame, 0.0 disables the ghosting
+    pub upscaler: UpscalerKind,
+    pub rotation: Rotation,
+    corrected: Vec<Color32>, // indexed by the BGR555 color
+}
+
+impl FrameFilter {
+    pub fn new() -> FrameFilter {
+        FrameFilter {
+            color_correction: false,
+            interframe_blend: 0.0,
+            upscaler: UpscalerKind::None,
+            rotation: Rotation::None,
+            corrected: (0..0x8000).map(Self::correct).collect(),
+        }
+    }
+
+    // the gamma and saturation matrix from higan, the colors bleed into each other and the whites get a tint
+    fn correct(bgr555: u16) -> Color32 {
+        let channel = |shift: u16| (((bgr555 >> shift) & 0x1F) as f32 / 31.0).powf(LCD_GAMMA);
+        let (r, g, b) = (channel(0), channel(5), channel(10));
+        let output = |value: f32| ((value / 255.0).powf(1.0 / OUT_GAMMA) * 255.0 * 255.0 / 280.0) as u8;
+
+        Color32::from_rgb(
+            output(255.0 * r + 50.0 * g),
+            output(10.0 * r + 230.0 * g + 30.0 * b),
+            output(50.0 * r + 10.0 * g + 220.0 * b),
+        )
+    }
+
+    // the LCD takes a while to change, games rely on that to make sprites flickering every other frame look
+    // transparent, so every pixel is mixed with the one of the previous frame
+    fn blend(&self, current: u32, previous: u32) -> [u8; 3] {
+        [16, 8, 0].map(|shift| {
+            let (current, previous) = ((current >> shift) as u8 as f32, (previous >> shift) as u8 as f32);
+            (current + (previous - current) * self.interframe_blend).round() as u8
+        })
+    }
+
+    // the composed frames are always opaque, the image is larger than the frame with an upscaler
+    pub fn apply(&self, frame: &Frame, previous: &Frame) -> ColorImage {
+        let pixels: Vec<Color32> = frame
+            .iter()
+            .zip(previous.iter())
+            .map(|(&pixel, &previous)| {
+                let [r, g, b] = self.blend(pixel, previous);
+                if self.color_correction {
+                    let bgr555 = (r as usize >> 3) | (g as usize >> 3) << 5 | (b as usize >> 3) << 10;
+                    self.corrected[bgr555]
+                } else {
+                    Color32::from_rgb(r, g, b)
+                }
+            })
+            .collect();
+
+        let image = match self.upscaler.upscaler() {
+            Some(upscaler) => ColorImage {
+                size: [SCREEN_WIDTH * upscaler.factor(), SCREEN_HEIGHT * upscaler.factor()],
+                pixels: upscaler.upscale(&pixels, SCREEN_WIDTH, SCREEN_HEIGHT),
+            },
+            None => ColorImage {
+                size: [SCREEN_WIDTH, SCREEN_HEIGHT],
+                pixels,
+            },
+        };
+        self.rotation.apply(image)
+    }
+}
+
+impl Default for FrameFilter {
+    fn default() -> Self {
+        FrameFilter::new()
+    }
+}
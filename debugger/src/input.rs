@@ -0,0 +1,427 @@
+use egui::{InputState, Key};
+use gilrs::ff::{BaseEffect, BaseEffectType, Effect, EffectBuilder, Replay, Ticks};
+use gilrs::{Axis, Button, EventType, Gilrs};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Display;
+use std::path::Path;
+use tracing::{error, info, warn};
+
+pub const INPUT_CONFIG_PATH: &str = "input.toml";
+
+// gamepad buttons by the names they are stored with, gilrs only serializes them behind a feature
+const BUTTONS: [(Button, &str); 19] = [
+    (Button::South, "South"),
+    (Button::East, "East"),
+    (Button::North, "North"),
+    (Button::West, "West"),
+    (Button::C, "C"),
+    (Button::Z, "Z"),
+    (Button::LeftTrigger, "LeftTrigger"),
+    (Button::LeftTrigger2, "LeftTrigger2"),
+    (Button::RightTrigger, "RightTrigger"),
+    (Button::RightTrigger2, "RightTrigger2"),
+    (Button::Select, "Select"),
+    (Button::Start, "Start"),
+    (Button::Mode, "Mode"),
+    (Button::LeftThumb, "LeftThumb"),
+    (Button::RightThumb, "RightThumb"),
+    (Button::DPadUp, "DPadUp"),
+    (Button::DPadDown, "DPadDown"),
+    (Button::DPadLeft, "DPadLeft"),
+    (Button::DPadRight, "DPadRight"),
+];
+
+// every GBA button and hotkey that can be rebound, in the order they are listed in the settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    A,
+    B,
+    L,
+    R,
+    Start,
+    Select,
+    Up,
+    Right,
+    Down,
+    Left,
+    TiltUp,
+    TiltRight,
+    TiltDown,
+    TiltLeft,
+    Run,
+    Pause,
+    FrameAdvance,
+    ToggleDebugger,
+    Screenshot,
+    LoadRom,
+    AudioSettings,
+    RecordAudio,
+    VideoSettings,
+    CaptureGif,
+    InputSettings,
+    Fullscreen,
+}
+
+impl Action {
+    pub const ALL: [Action; 26] = [
+        Action::A,
+        Action::B,
+        Action::L,
+        Action::R,
+        Action::Start,
+        Action::Select,
+        Action::Up,
+        Action::Right,
+        Action::Down,
+        Action::Left,
+        Action::TiltUp,
+        Action::TiltRight,
+        Action::TiltDown,
+        Action::TiltLeft,
+        Action::Run,
+        Action::Pause,
+        Action::FrameAdvance,
+        Action::ToggleDebugger,
+        Action::Screenshot,
+        Action::LoadRom,
+        Action::AudioSettings,
+        Action::RecordAudio,
+        Action::VideoSettings,
+        Action::CaptureGif,
+        Action::InputSettings,
+        Action::Fullscreen,
+    ];
+
+    fn default_key(&self) -> Option<Key> {
+        Some(match self {
+            Action::A => Key::A,
+            Action::B => Key::S,
+            Action::L => Key::Q,
+            Action::R => Key::W,
+            Action::Start => Key::Enter,
+            Action::Select => Key::Backspace,
+            Action::Up => Key::ArrowUp,
+            Action::Right => Key::ArrowRight,
+            Action::Down => Key::ArrowDown,
+            Action::Left => Key::ArrowLeft,
+            Action::TiltUp => Key::I,
+            Action::TiltRight => Key::L,
+            Action::TiltDown => Key::K,
+            Action::TiltLeft => Key::J,
+            Action::Run => Key::Space,
+            Action::Pause => Key::P,
+            Action::FrameAdvance => Key::N,
+            Action::ToggleDebugger => Key::F1,
+            Action::Screenshot => Key::F2,
+            Action::LoadRom => Key::F3,
+            Action::AudioSettings => Key::F4,
+            Action::RecordAudio => Key::F5,
+            Action::VideoSettings => Key::F6,
+            Action::CaptureGif => Key::F7,
+            Action::InputSettings => Key::F8,
+            Action::Fullscreen => Key::F11,
+        })
+    }
+
+    // laid out like the GBA, B and A are the bottom and right face buttons
+    fn default_button(&self) -> Option<Button> {
+        match self {
+            Action::A => Some(Button::East),
+            Action::B => Some(Button::South),
+            Action::L => Some(Button::LeftTrigger),
+            Action::R => Some(Button::RightTrigger),
+            Action::Start => Some(Button::Start),
+            Action::Select => Some(Button::Select),
+            Action::Up => Some(Button::DPadUp),
+            Action::Right => Some(Button::DPadRight),
+            Action::Down => Some(Button::DPadDown),
+            Action::Left => Some(Button::DPadLeft),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Action::A => "A",
+            Action::B => "B",
+            Action::L => "L",
+            Action::R => "R",
+            Action::Start => "Start",
+            Action::Select => "Select",
+            Action::Up => "Up",
+            Action::Right => "Right",
+            Action::Down => "Down",
+            Action::Left => "Left",
+            Action::TiltUp => "Tilt up",
+            Action::TiltRight => "Tilt right / rotate clockwise",
+            Action::TiltDown => "Tilt down",
+            Action::TiltLeft => "Tilt left / rotate counter-clockwise",
+            Action::Run => "Run the emulator",
+            Action::Pause => "Pause or resume",
+            Action::FrameAdvance => "Advance a single frame while paused",
+            Action::ToggleDebugger => "Toggle debugger window",
+            Action::Screenshot => "Take a screenshot",
+            Action::LoadRom => "Load a ROM (or drop it onto the window)",
+            Action::AudioSettings => "Audio device and volume",
+            Action::RecordAudio => "Start or stop recording audio",
+            Action::VideoSettings => "Video settings",
+            Action::CaptureGif => "Start or stop capturing a GIF",
+            Action::InputSettings => "Input settings",
+            Action::Fullscreen => "Toggle fullscreen",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Device {
+    Keyboard,
+    Gamepad,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Binding {
+    pub key: Option<Key>,
+    pub button: Option<Button>,
+}
+
+// the keys and buttons are stored by name, an empty name leaves the action unbound
+#[derive(Default, Serialize, Deserialize)]
+struct InputFile {
+    #[serde(default)]
+    keyboard: BTreeMap<Action, String>,
+    #[serde(default)]
+    gamepad: BTreeMap<Action, String>,
+}
+
+pub struct InputMap {
+    bindings: BTreeMap<Action, Binding>,
+}
+
+impl InputMap {
+    pub fn new() -> InputMap {
+        InputMap {
+            bindings: Action::ALL
+                .iter()
+                .map(|action| {
+                    let binding = Binding {
+                        key: action.default_key(),
+                        button: action.default_button(),
+                    };
+                    (*action, binding)
+                })
+                .collect(),
+        }
+    }
+
+    // actions missing from the file keep their default binding
+    pub fn load(path: &Path) -> InputMap {
+        let mut map = InputMap::new();
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return map;
+        };
+
+        let file: InputFile = match toml::from_str(&content) {
+            Ok(file) => file,
+            Err(e) => {
+                error!(target: "input", "Failed to parse {}, using the default bindings: {}", path.display(), e);
+                return map;
+            }
+        };
+
+        for (action, name) in file.keyboard {
+            let binding = map.binding_mut(action);
+            match Key::from_name(&name) {
+                Some(key) => binding.key = Some(key),
+                None if name.is_empty() => binding.key = None,
+                None => warn!(target: "input", "Ignoring unknown key '{}' for {}", name, action),
+            }
+        }
+        for (action, name) in file.gamepad {
+            let binding = map.binding_mut(action);
+            match button_from_name(&name) {
+                Some(button) => binding.button = Some(button),
+                None if name.is_empty() => binding.button = None,
+                None => warn!(target: "input", "Ignoring unknown gamepad button '{}' for {}", name, action),
+            }
+        }
+
+        info!(target: "input", "Loaded input bindings from {}", path.display());
+        map
+    }
+
+    pub fn save(&self, path: &Path) {
+        let file = InputFile {
+            keyboard: self
+                .bindings
+                .iter()
+                .map(|(action, binding)| (*action, binding.key.map_or("", |key| key.name()).to_string()))
+                .collect(),
+            gamepad: self
+                .bindings
+                .iter()
+                .map(|(action, binding)| (*action, binding.button.map_or("", button_name).to_string()))
+                .collect(),
+        };
+
+        let result = toml::to_string_pretty(&file)
+            .map_err(|e| e.to_string())
+            .and_then(|content| std::fs::write(path, content).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            error!(target: "input", "Failed to save the input bindings to {}: {}", path.display(), e);
+        }
+    }
+
+    pub fn binding(&self, action: Action) -> Binding {
+        self.bindings.get(&action).copied().unwrap_or_default()
+    }
+
+    pub fn binding_mut(&mut self, action: Action) -> &mut Binding {
+        self.bindings.entry(action).or_default()
+    }
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        InputMap::new()
+    }
+}
+
+pub fn button_name(button: Button) -> &'static str {
+    BUTTONS
+        .iter()
+        .find(|(b, _)| *b == button)
+        .map_or("Unknown", |(_, name)| name)
+}
+
+fn button_from_name(name: &str) -> Option<Button> {
+    BUTTONS.iter().find(|(_, n)| *n == name).map(|(button, _)| *button)
+}
+
+// keyboard and gamepad state of the current frame, the gamepads are polled once per frame
+pub struct Input {
+    pub map: InputMap,
+    gilrs: Option<Gilrs>,
+    held: HashSet<Button>,
+    pressed: Vec<Button>,   // since the last poll, in order
+    stick: (f32, f32),      // left stick, right and down are positive like the tilt
+    rumble: Option<Effect>, // plays while the cartridge's motor is on
+}
+
+impl Input {
+    pub fn new(map: InputMap) -> Input {
+        let gilrs = match Gilrs::new() {
+            Ok(gilrs) => Some(gilrs),
+            Err(e) => {
+                error!(target: "input", "Failed to initialize the gamepad support: {}", e);
+                None
+            }
+        };
+
+        Input {
+            map,
+            gilrs,
+            held: HashSet::new(),
+            pressed: Vec::new(),
+            stick: (0.0, 0.0),
+            rumble: None,
+        }
+    }
+
+    // buttons of every connected gamepad are merged, as if there was only one
+    pub fn poll(&mut self) {
+        self.pressed.clear();
+        let Some(gilrs) = &mut self.gilrs else {
+            return;
+        };
+
+        while let Some(event) = gilrs.next_event() {
+            match event.event {
+                EventType::ButtonPressed(button, _) => {
+                    self.held.insert(button);
+                    self.pressed.push(button);
+                }
+                EventType::ButtonReleased(button, _) => {
+                    self.held.remove(&button);
+                }
+                EventType::AxisChanged(Axis::LeftStickX, value, _) => self.stick.0 = value,
+                EventType::AxisChanged(Axis::LeftStickY, value, _) => self.stick.1 = -value,
+                EventType::Disconnected => {
+                    self.held.clear();
+                    self.stick = (0.0, 0.0);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // the motor is either on or off, so the strong motor of every gamepad that has one runs at full strength
+    pub fn set_rumble(&mut self, rumble: bool) {
+        if let Some(effect) = self.rumble.take() {
+            let _ = effect.stop();
+        }
+        let Some(gilrs) = &mut self.gilrs else {
+            return;
+        };
+        if !rumble {
+            return;
+        }
+
+        let gamepads: Vec<_> = gilrs
+            .gamepads()
+            .filter(|(_, gamepad)| gamepad.is_ff_supported())
+            .map(|(id, _)| id)
+            .collect();
+        if gamepads.is_empty() {
+            return;
+        }
+
+        let effect = EffectBuilder::new()
+            .add_effect(BaseEffect {
+                kind: BaseEffectType::Strong { magnitude: u16::MAX },
+                scheduling: Replay {
+                    play_for: Ticks::from_ms(100),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .gamepads(&gamepads)
+            .finish(gilrs)
+            .and_then(|effect| effect.play().map(|_| effect));
+        match effect {
+            Ok(effect) => self.rumble = Some(effect),
+            Err(e) => warn!(target: "input", "Failed to start the gamepad rumble: {}", e),
+        }
+    }
+
+    // the tilt keys on top of the left stick, drives the tilt sensor and the gyro
+    pub fn motion(&self, i: &InputState) -> (f32, f32) {
+        let axis = |negative: Action, positive: Action, stick: f32| {
+            let keys = self.is_down(i, positive) as i8 as f32 - self.is_down(i, negative) as i8 as f32;
+            (keys + stick).clamp(-1.0, 1.0)
+        };
+        (
+            axis(Action::TiltLeft, Action::TiltRight, self.stick.0),
+            axis(Action::TiltUp, Action::TiltDown, self.stick.1),
+        )
+    }
+
+    // the first gamepad button pressed this frame, for rebinding
+    pub fn pressed_button(&self) -> Option<Button> {
+        self.pressed.first().copied()
+    }
+
+    pub fn is_down(&self, i: &InputState, action: Action) -> bool {
+        let binding = self.map.binding(action);
+        binding.key.is_some_and(|key| i.key_down(key)) || binding.button.is_some_and(|b| self.held.contains(&b))
+    }
+
+    pub fn is_pressed(&self, i: &InputState, action: Action) -> bool {
+        let binding = self.map.binding(action);
+        binding.key.is_some_and(|key| i.key_pressed(key)) || binding.button.is_some_and(|b| self.pressed.contains(&b))
+    }
+}
@@ -0,0 +1,208 @@
+use gba_core::serial::link::LinkDevice;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::time::Duration;
+use tracing::{info, warn};
+
+const MAX_CHILDREN: usize = 3;
+const REPLY_TIMEOUT: Duration = Duration::from_secs(1); // the child may be waiting for its frame to be displayed
+// messages, all of them have a fixed size
+const ID: u8 = b'I'; // parent to child, the ID it was given
+const START: u8 = b'S'; // parent to child, a transfer started with the parent's word
+const REPLY: u8 = b'D'; // child to parent, the child's word
+const DONE: u8 = b'C'; // parent to child, the words of all four GBAs
+
+fn message_size(kind: u8) -> usize {
+    match kind {
+        ID => 2,
+        START | REPLY => 3,
+        DONE => 9,
+        _ => 1,
+    }
+}
+
+enum Role {
+    // owns the connections, the transfers it starts are passed on to every child
+    Parent {
+        listener: TcpListener,
+        children: [Option<TcpStream>; MAX_CHILDREN], // by ID - 1
+    },
+    Child {
+        stream: Option<TcpStream>, // none once the parent is gone
+        id: Option<u8>,
+        received: Vec<u8>, // bytes of messages that are not complete yet
+    },
+}
+
+// a link cable between up to four emulator instances on the same machine in multiplayer mode, the first instance
+// on a port becomes the parent and the ones started after join as children
+pub struct LinkCable {
+    role: Role,
+}
+
+impl LinkCable {
+    pub fn connect(port: u16) -> std::io::Result<LinkCable> {
+        match TcpListener::bind((Ipv4Addr::LOCALHOST, port)) {
+            Ok(listener) => {
+                listener.set_nonblocking(true)?;
+                info!(target: "sio", "Link cable is waiting for other instances on port {}, this one is the parent", port);
+                Ok(LinkCable {
+                    role: Role::Parent {
+                        listener,
+                        children: [None, None, None],
+                    },
+                })
+            }
+            Err(e) if e.kind() == ErrorKind::AddrInUse => {
+                let stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port))?;
+                stream.set_nodelay(true)?;
+                stream.set_nonblocking(true)?;
+                info!(target: "sio", "Link cable connected to the parent on port {}", port);
+                Ok(LinkCable {
+                    role: Role::Child {
+                        stream: Some(stream),
+                        id: None,
+                        received: Vec::new(),
+                    },
+                })
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn accept_children(listener: &TcpListener, children: &mut [Option<TcpStream>; MAX_CHILDREN]) {
+        while let Ok((mut stream, address)) = listener.accept() {
+            // a full cable closes the connection right away
+            let Some(slot) = children.iter().position(|child| child.is_none()) else {
+                warn!(target: "sio", "Link cable is full, turning away {}", address);
+                continue;
+            };
+
+            let id = slot as u8 + 1;
+            let setup = stream
+                .set_nonblocking(false)
+                .and_then(|_| stream.set_nodelay(true))
+                .and_then(|_| stream.set_read_timeout(Some(REPLY_TIMEOUT)))
+                .and_then(|_| stream.write_all(&[ID, id]));
+            match setup {
+                Ok(()) => {
+                    info!(target: "sio", "Player {} joined the link cable", id + 1);
+                    children[slot] = Some(stream);
+                }
+                Err(e) => warn!(target: "sio", "Failed to set up the link with {}: {}", address, e),
+            }
+        }
+    }
+}
+
+impl LinkDevice for LinkCable {
+    // normal mode is not passed on
+    fn transfer(&mut self, _data: u32, _bits: u32) -> u32 {
+        u32::MAX
+    }
+
+    fn multiplayer_id(&mut self) -> Option<u8> {
+        match &self.role {
+            Role::Parent { .. } => Some(0),
+            Role::Child { id, .. } => *id,
+        }
+    }
+
+    // blocks until every child answered, children that don't are dropped
+    fn transfer_multiplayer(&mut self, data: u16) -> [u16; 4] {
+        let mut words = [data, 0xFFFF, 0xFFFF, 0xFFFF];
+        let Role::Parent { listener, children } = &mut self.role else {
+            return words;
+        };
+        Self::accept_children(listener, children);
+
+        let [low, high] = data.to_le_bytes();
+        for (i, slot) in children.iter_mut().enumerate() {
+            let Some(stream) = slot else {
+                continue;
+            };
+
+            let mut reply = [0u8; 3];
+            let result = stream
+                .write_all(&[START, low, high])
+                .and_then(|_| stream.read_exact(&mut reply));
+            match result {
+                Ok(()) if reply[0] == REPLY => words[i + 1] = u16::from_le_bytes([reply[1], reply[2]]),
+                Ok(()) => {}
+                Err(e) => {
+                    warn!(target: "sio", "Player {} left the link cable: {}", i + 2, e);
+                    *slot = None;
+                }
+            }
+        }
+
+        let mut done = vec![DONE];
+        for word in words {
+            done.extend_from_slice(&word.to_le_bytes());
+        }
+        for stream in children.iter_mut().flatten() {
+            let _ = stream.write_all(&done);
+        }
+
+        words
+    }
+
+    fn poll_multiplayer(&mut self, data: u16) -> Option<[u16; 4]> {
+        let (stream, id, received) = match &mut self.role {
+            Role::Parent { listener, children } => {
+                Self::accept_children(listener, children);
+                return None;
+            }
+            Role::Child { stream, id, received } => (stream, id, received),
+        };
+        let connection = stream.as_mut()?;
+
+        let mut buffer = [0u8; 64];
+        loop {
+            match connection.read(&mut buffer) {
+                Ok(0) if id.is_none() => {
+                    warn!(target: "sio", "The parent turned this instance away, the link cable is full");
+                    *stream = None;
+                    return None;
+                }
+                Ok(0) => {
+                    warn!(target: "sio", "The parent left the link cable");
+                    *stream = None;
+                    *id = None;
+                    return None;
+                }
+                Ok(size) => received.extend_from_slice(&buffer[..size]),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    warn!(target: "sio", "Lost the link cable connection: {}", e);
+                    *stream = None;
+                    *id = None;
+                    return None;
+                }
+            }
+        }
+
+        let mut words = None;
+        while let Some(&kind) = received.first() {
+            let size = message_size(kind);
+            if received.len() < size {
+                break;
+            }
+            let message: Vec<u8> = received.drain(..size).collect();
+
+            match kind {
+                ID => *id = Some(message[1]),
+                START => {
+                    let [low, high] = data.to_le_bytes();
+                    let _ = connection.write_all(&[REPLY, low, high]);
+                }
+                DONE => {
+                    let word = |i: usize| u16::from_le_bytes([message[1 + i * 2], message[2 + i * 2]]);
+                    words = Some([word(0), word(1), word(2), word(3)]);
+                }
+                _ => {}
+            }
+        }
+        words
+    }
+}
@@ -1,23 +1,47 @@
 #![feature(new_zeroed_alloc)]
 #![feature(if_let_guard)]
 
+mod audio;
+mod capture;
 mod dbg;
 mod emulator;
 mod event;
+mod filter;
+mod input;
+mod link;
+mod netplay;
 mod renderer;
+mod resampler;
+mod shader;
+mod uart;
+mod upscale;
 
-use crate::emulator::Emulator;
+use crate::audio::{AudioOutput, SampleBuffer};
+use crate::dbg::session::Session;
+use crate::emulator::{Emulator, MAX_RUN_AHEAD, Pacing, is_multiboot_image};
+use crate::filter::Rotation;
+use crate::link::LinkCable;
+use crate::netplay::{DEFAULT_PORT, Netplay};
 use crate::renderer::SCALE;
 use clap::Parser;
 use crossbeam_channel::{self, Receiver, Sender};
 use eframe::NativeOptions;
-use gba_core::video::{Frame, SCREEN_HEIGHT, SCREEN_WIDTH};
+use gba_core::cartridge::flash::FlashVendor;
+use gba_core::cartridge::storage::BackupType;
+use gba_core::gba::BootOptions;
+use gba_core::video::Frame;
 use renderer::Renderer;
+use resampler::ResamplerKind;
+use shader::PostShader;
 use shadow_rs::shadow;
-use tracing::Level;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::{Level, error};
 use tracing_subscriber::Layer;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
+use uart::UartBridge;
+use upscale::UpscalerKind;
 
 shadow!(build_info);
 
@@ -32,18 +56,170 @@ struct Args {
     debug: bool,
 
     /// Targets to enable logging for
-    #[arg(long, default_value = "cpu,mmio,cartridge,storage,ppu,irq,pipeline,rhai")]
+    #[arg(
+        long,
+        default_value = "cpu,mmio,cartridge,storage,ppu,irq,pipeline,rhai,audio,input,netplay"
+    )]
     targets: String,
 
     /// Path to a custom script file
     #[arg(long)]
     script: Option<String>,
 
+    /// Return "no device" patterns for unemulated GPIO peripherals (e.g. RTC) instead of zeros
+    #[arg(long)]
+    interpolate_gpio: bool,
+
+    /// Save frame N as a PNG, e.g. 1200:out.png (can be repeated), frames are counted from power-on starting at 1
+    #[arg(long, value_parser = parse_screenshot_at)]
+    screenshot_at: Vec<(usize, String)>,
+
+    /// Run without a window until the last --screenshot-at frame is saved, exits with 1 if one could not be saved
+    #[arg(long, requires = "screenshot_at")]
+    headless: bool,
+
+    /// Only match breakpoints and script handlers on the exact address, ignoring memory mirrors
+    #[arg(long)]
+    exact_breakpoints: bool,
+
+    /// Number of instructions to keep in the rewind history for stepping back (0 = disabled)
+    #[arg(long, default_value_t = 0)]
+    history: usize,
+
+    /// Path to an ELF file to load symbols from (can be repeated)
+    #[arg(long)]
+    symbols: Vec<String>,
+
+    /// Do not restore or save the per-ROM debugger session
+    #[arg(long)]
+    no_session: bool,
+
+    /// Start directly at the cartridge entry point instead of running the BIOS boot sequence
+    #[arg(long)]
+    skip_bios: bool,
+
+    /// Run the BIOS boot sequence in the background and start the emulator right at the cartridge entry point
+    #[arg(long)]
+    fast_boot: bool,
+
+    /// Path to a BIOS image to use instead of the built-in one (an official dump is recommended)
+    #[arg(long)]
+    bios: Option<PathBuf>,
+
+    /// Flash chip to report in ID mode: sanyo, macronix, panasonic, atmel or sst
+    #[arg(long)]
+    flash_vendor: Option<FlashVendor>,
+
+    /// Override the detected save type: none, sram, flash64, flash128, eeprom512 or eeprom8k
+    #[arg(long)]
+    save_type: Option<BackupType>,
+
+    /// Path to the battery save file, mGBA/VBA compatible (default: next to the ROM with a .sav extension)
+    #[arg(long)]
+    save: Option<PathBuf>,
+
+    /// Path to an IPS, UPS or BPS patch to apply to the ROM in memory (the file is left untouched)
+    #[arg(long)]
+    patch: Option<PathBuf>,
+
+    /// Load the ROM as a multiboot image into EWRAM (implied for .mb files)
+    #[arg(long)]
+    multiboot: bool,
+
+    /// Name of the audio output device to use instead of the default one
+    #[arg(long)]
+    audio_device: Option<String>,
+
+    /// Resampler used to convert to the output device's sample rate: linear or sinc
+    #[arg(long, default_value = "sinc")]
+    resampler: ResamplerKind,
+
+    /// What decides the emulation speed: free (display refresh) or audio (audio device, no crackling)
+    #[arg(long, default_value = "free")]
+    pacing: Pacing,
+
+    /// Emulate the darker, less saturated colors of the GBA screen
+    #[arg(long)]
+    color_correction: bool,
+
+    /// Weight of the previous frame in percent (up to 50) to emulate the ghosting of the GBA screen
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=50))]
+    interframe_blend: u8,
+
+    /// Software upscaler applied to the frame before the shader: none, scale2x, scale3x or xbr2x
+    #[arg(long, default_value = "none")]
+    upscaler: UpscalerKind,
+
+    /// Post shader the screen is drawn with: none, lcd, crt or sharp-bilinear
+    #[arg(long, default_value = "none")]
+    shader: PostShader,
+
+    /// Fragment shader to draw the screen with instead, a main() writing f_color (see the README for its inputs)
+    #[arg(long)]
+    shader_file: Option<PathBuf>,
+
+    /// Turn the screen and the d-pad clockwise: 0, 90, 180 or 270
+    #[arg(long, default_value = "0")]
+    rotation: Rotation,
+
+    /// Show the game this many frames ahead (up to 4) to cut the input latency, costs as many extra frames of emulation
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=MAX_RUN_AHEAD as i64))]
+    run_ahead: u8,
+
+    /// Path to a VisualBoyAdvance .vbm movie to play back from power-on, the keyboard is ignored until it ends
+    #[arg(long)]
+    movie: Option<PathBuf>,
+
+    /// Host a netplay session on this UDP port and wait for the other player to join, both play on the same joypad
+    #[arg(long, num_args = 0..=1, default_missing_value = "7845", conflicts_with = "netplay_join")]
+    netplay_host: Option<u16>,
+
+    /// Join the netplay session hosted at this address, e.g. 192.168.0.2 or 192.168.0.2:7845
+    #[arg(long)]
+    netplay_join: Option<String>,
+
+    /// Frames the local input is held back during netplay, more means fewer rollbacks when the connection is slow
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(0..=8))]
+    netplay_delay: u8,
+
+    /// Plug a link cable in multiplayer mode into up to 4 instances on this machine started with the same port, the first one is the parent
+    #[arg(long, num_args = 0..=1, default_missing_value = "7846")]
+    link: Option<u16>,
+
+    /// Log the lines the game prints over the UART, with a port the UART is also bridged to a TCP client on it
+    #[arg(long, num_args = 0..=1, conflicts_with = "link")]
+    uart: Option<Option<u16>>,
+
+    /// Pipe every frame and sample into ffmpeg for a lossless recording, e.g. out.mkv (ffmpeg has to be installed)
+    #[arg(long)]
+    dump_video: Option<PathBuf>,
+
     /// Path to the ROM file
     #[arg(long)]
     rom: String,
 }
 
+fn parse_screenshot_at(value: &str) -> Result<(usize, String), String> {
+    let (frame, path) = value
+        .split_once(':')
+        .ok_or_else(|| format!("expected <frame>:<path>, got '{}'", value))?;
+    let frame = frame
+        .parse::<usize>()
+        .map_err(|e| format!("invalid frame number '{}': {}", frame, e))?;
+    if frame == 0 {
+        return Err("frames are counted from 1".to_string());
+    }
+    if path.is_empty() {
+        return Err(format!("missing the path after '{}:'", frame));
+    }
+
+    Ok((frame, path.to_string()))
+}
+
+pub fn window_title(rom_title: &str) -> String {
+    format!("ayyboy advance [{}] - {}", build_info::SHORT_COMMIT, rom_title)
+}
+
 fn main() {
     let args = Args::parse();
 
@@ -63,37 +239,135 @@ fn main() {
     let fmt_layer = tracing_subscriber::fmt::layer().without_time().with_filter(targets);
     tracing_subscriber::registry().with(fmt_layer).init();
 
-    let (display_tx, display_rx): (Sender<Frame>, Receiver<Frame>) = crossbeam_channel::bounded(1);
+    let (display_tx, display_rx): (Sender<Box<Frame>>, Receiver<Box<Frame>>) = crossbeam_channel::bounded(1);
+    let (event_tx, event_rx) = crossbeam_channel::bounded(25);
     let (dbg_req_tx, dbg_req_rx) = crossbeam_channel::bounded(25);
     let (dbg_resp_tx, dbg_resp_rx) = crossbeam_channel::bounded(25);
     let (exit_tx, exit_rx) = crossbeam_channel::bounded(1);
 
-    let mut emulator = Emulator::new(display_tx, dbg_req_rx, dbg_resp_tx, args.script, args.rom);
+    let save_path = args.save.unwrap_or_else(|| Path::new(&args.rom).with_extension("sav"));
+    let multiboot = args.multiboot || is_multiboot_image(Path::new(&args.rom));
+    let mut emulator = Emulator::new(
+        display_tx,
+        event_tx,
+        dbg_req_rx,
+        dbg_resp_tx,
+        args.script,
+        args.rom,
+        BootOptions {
+            skip_bios: args.skip_bios,
+            fast_boot: args.fast_boot,
+            save_type: args.save_type,
+            patch: args
+                .patch
+                .map(|path| std::fs::read(path).expect("Failed to read patch file")),
+            multiboot,
+            bios: args
+                .bios
+                .map(|path| std::fs::read(path).expect("Failed to read BIOS file")),
+        },
+    );
+    emulator.gba.set_mirror_aware_breakpoints(!args.exact_breakpoints);
+    if args.history > 0 {
+        emulator.gba.cpu.enable_history(args.history);
+    }
+    emulator.load_save(save_path);
+    emulator.gba.cpu.mmio.gpio.interpolate_missing = args.interpolate_gpio;
+    if let Some(vendor) = args.flash_vendor {
+        emulator.gba.cpu.mmio.storage_chip.select_flash_vendor(vendor);
+    }
+    if let Some(path) = &args.movie {
+        emulator.load_movie(path);
+    }
+    if let Some(path) = &args.dump_video {
+        emulator.start_video_dump(path);
+    }
+    emulator.screenshots = args.screenshot_at;
+    if args.headless {
+        let saved = emulator.run_headless();
+        std::process::exit(if saved { 0 } else { 1 });
+    }
+    let audio_buffer = SampleBuffer::new();
+    emulator.audio_buffer = Some(audio_buffer.clone());
+    emulator.pacing = args.pacing;
+    emulator.run_ahead = args.run_ahead as usize;
+
+    if let Some(port) = args.link {
+        match LinkCable::connect(port) {
+            Ok(cable) => emulator.gba.cpu.mmio.sio.link = Some(Arc::new(Mutex::new(cable))),
+            Err(e) => error!(target: "sio", "Failed to connect the link cable: {}", e),
+        }
+    }
+    if let Some(port) = args.uart {
+        match UartBridge::new(port) {
+            Ok(bridge) => emulator.gba.cpu.mmio.sio.link = Some(Arc::new(Mutex::new(bridge))),
+            Err(e) => error!(target: "sio", "Failed to bridge the UART: {}", e),
+        }
+    }
+
+    // both instances have to start from the same state, so the session is set up before the first frame
+    let delay = args.netplay_delay as usize;
+    let netplay = match (args.netplay_host, &args.netplay_join) {
+        (Some(port), _) => Some(Netplay::host(port, delay, &emulator.gba.crc32)),
+        (None, Some(address)) if address.contains(':') => Some(Netplay::join(address, delay, &emulator.gba.crc32)),
+        (None, Some(address)) => Some(Netplay::join(
+            &format!("{}:{}", address, DEFAULT_PORT),
+            delay,
+            &emulator.gba.crc32,
+        )),
+        (None, None) => None,
+    };
+    match netplay {
+        Some(Ok(netplay)) => emulator.netplay = Some(netplay),
+        Some(Err(e)) => error!(target: "netplay", "Failed to start netplay: {}", e),
+        None => {}
+    }
     let rom_title = emulator.gba.rom_title.clone();
 
+    // breakpoints, symbols and window layout are kept per ROM
+    let mut session = if args.no_session {
+        Session::default()
+    } else {
+        Session::load(&emulator.gba.crc32)
+    };
+    for path in args.symbols.iter().chain(session.symbols.iter()) {
+        emulator.load_symbols(path);
+    }
+    session.symbols = emulator.symbol_files.clone();
+
     std::thread::spawn(move || {
         emulator.run(exit_rx);
     });
 
+    let [width, height] = args.rotation.screen_size();
     let native_options = NativeOptions {
         viewport: egui::ViewportBuilder::default()
-            .with_inner_size([(SCREEN_WIDTH * SCALE) as f32, (SCREEN_HEIGHT * SCALE) as f32])
-            .with_resizable(false),
+            .with_inner_size([(width * SCALE) as f32, (height * SCALE) as f32])
+            .with_min_inner_size([width as f32, height as f32])
+            .with_resizable(true),
         vsync: false,
         ..Default::default()
     };
 
     let _ = eframe::run_native(
-        &format!("ayyboy advance [{}] - {}", build_info::SHORT_COMMIT, rom_title),
+        &window_title(&rom_title),
         native_options,
         Box::new(move |cc| {
-            Ok(Box::new(Renderer::new(
-                cc,
-                display_rx,
-                dbg_req_tx,
-                dbg_resp_rx,
-                exit_tx,
-            )))
+            let mut renderer = Renderer::new(cc, display_rx, event_rx, dbg_req_tx, dbg_resp_rx, exit_tx, session);
+            // the stream is tied to the UI thread
+            audio_buffer.set_resampler(args.resampler);
+            renderer.audio = Some(AudioOutput::new(audio_buffer, args.audio_device));
+            renderer.pacing = args.pacing;
+            renderer.run_ahead = args.run_ahead as usize;
+            renderer.filter.color_correction = args.color_correction;
+            renderer.filter.interframe_blend = args.interframe_blend as f32 / 100.0;
+            renderer.filter.upscaler = args.upscaler;
+            renderer.shader = args.shader;
+            if let Some(path) = args.shader_file {
+                renderer.load_shader(&path);
+            }
+            renderer.filter.rotation = args.rotation;
+            Ok(Box::new(renderer))
         }),
     );
 }
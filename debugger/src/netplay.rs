@@ -0,0 +1,235 @@
+use gba_core::gba::SaveState;
+use gba_core::input::registers::KeyInput;
+use std::collections::VecDeque;
+use std::io::{Error, ErrorKind};
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+pub const DEFAULT_PORT: u16 = 7845;
+pub const MAX_ROLLBACK: usize = 8; // frames the local side may run ahead of the last confirmed remote input
+
+const MAGIC: &[u8; 4] = b"AYNP";
+const HELLO: u8 = 0;
+const WELCOME: u8 = 1;
+const INPUTS: u8 = 2;
+const MAX_INPUTS_PER_PACKET: usize = 64;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+const DISCONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const RESEND_INTERVAL: Duration = Duration::from_millis(16);
+
+// the state at the start of a frame that ran with a guessed remote input, rolled back to if the guess was wrong
+pub struct Snapshot {
+    pub frame: usize,
+    pub state: SaveState,
+    pub remote: KeyInput,
+}
+
+// two instances running the same ROM in lockstep, both players' buttons are merged into the one joypad. every
+// packet repeats all the local inputs the peer has not acknowledged yet, so lost packets need no retransmission
+pub struct Netplay {
+    socket: UdpSocket,
+    peer: SocketAddr,
+    crc32: String,
+    delay: usize,
+    local: Vec<KeyInput>,  // by frame, set bits are pressed
+    remote: Vec<KeyInput>, // confirmed by the peer, without gaps
+    acked: usize,          // local frames the peer has received
+    last_sent: Instant,
+    last_received: Instant,
+    pub snapshots: VecDeque<Snapshot>,
+}
+
+impl Netplay {
+    // waits for the other player to join
+    pub fn host(port: u16, delay: usize, crc32: &str) -> std::io::Result<Netplay> {
+        let socket = UdpSocket::bind(("0.0.0.0", port))?;
+        socket.set_read_timeout(Some(CONNECT_TIMEOUT))?;
+        info!(target: "netplay", "Waiting for a player to join on port {}", port);
+
+        let mut buffer = [0u8; 64];
+        let peer = loop {
+            let (size, peer) = socket.recv_from(&mut buffer).map_err(Self::timed_out)?;
+            if let Some(peer_crc32) = Self::parse_hello(&buffer[..size], HELLO) {
+                Self::check_rom(crc32, &peer_crc32);
+                break peer;
+            }
+        };
+        socket.send_to(&Self::hello(WELCOME, crc32), peer)?;
+
+        Self::connected(socket, peer, delay, crc32)
+    }
+
+    // retries until the host answers, the host may be started after
+    pub fn join(address: &str, delay: usize, crc32: &str) -> std::io::Result<Netplay> {
+        let peer = address
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, format!("cannot resolve {}", address)))?;
+        let socket = UdpSocket::bind(("0.0.0.0", 0))?;
+        socket.set_read_timeout(Some(Duration::from_millis(500)))?;
+        info!(target: "netplay", "Joining {}", peer);
+
+        let start = Instant::now();
+        let mut buffer = [0u8; 64];
+        loop {
+            if start.elapsed() > CONNECT_TIMEOUT {
+                return Err(Self::timed_out(Error::from(ErrorKind::TimedOut)));
+            }
+
+            socket.send_to(&Self::hello(HELLO, crc32), peer)?;
+            match socket.recv_from(&mut buffer) {
+                Ok((size, from)) if from == peer => {
+                    if let Some(peer_crc32) = Self::parse_hello(&buffer[..size], WELCOME) {
+                        Self::check_rom(crc32, &peer_crc32);
+                        break;
+                    }
+                }
+                Ok(_) => {}
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Self::connected(socket, peer, delay, crc32)
+    }
+
+    fn connected(socket: UdpSocket, peer: SocketAddr, delay: usize, crc32: &str) -> std::io::Result<Netplay> {
+        socket.set_nonblocking(true)?;
+        info!(target: "netplay", "Connected to {} with {} frames of input delay", peer, delay);
+
+        Ok(Netplay {
+            socket,
+            peer,
+            crc32: crc32.to_string(),
+            delay,
+            // the local input of a frame is only used `delay` frames later, the first frames run without any
+            local: vec![KeyInput::empty(); delay],
+            remote: Vec::new(),
+            acked: 0,
+            last_sent: Instant::now(),
+            last_received: Instant::now(),
+            snapshots: VecDeque::new(),
+        })
+    }
+
+    fn timed_out(e: Error) -> Error {
+        match e.kind() {
+            ErrorKind::WouldBlock | ErrorKind::TimedOut => Error::new(ErrorKind::TimedOut, "no other player showed up"),
+            _ => e,
+        }
+    }
+
+    fn hello(kind: u8, crc32: &str) -> Vec<u8> {
+        let mut packet = MAGIC.to_vec();
+        packet.push(kind);
+        packet.extend_from_slice(crc32.as_bytes());
+        packet
+    }
+
+    fn parse_hello(packet: &[u8], kind: u8) -> Option<String> {
+        let crc32 = packet.strip_prefix(MAGIC)?.strip_prefix(&[kind])?;
+        Some(String::from_utf8_lossy(crc32).to_string())
+    }
+
+    // different ROMs are bound to desync, but patched or translated versions may still play along
+    fn check_rom(crc32: &str, peer_crc32: &str) {
+        if crc32 != peer_crc32 {
+            warn!(
+                target: "netplay",
+                "The other player runs a different ROM ({} instead of {}), the games will likely desync",
+                peer_crc32, crc32
+            );
+        }
+    }
+
+    // the input of the local player at the start of `frame`, it is used `delay` frames later
+    pub fn push_local(&mut self, frame: usize, keys: KeyInput) {
+        if self.local.len() <= frame + self.delay {
+            self.local.push(keys);
+            self.send();
+        }
+    }
+
+    pub fn local_input(&self, frame: usize) -> KeyInput {
+        self.local.get(frame).copied().unwrap_or(KeyInput::empty())
+    }
+
+    // frames for which the remote input is known
+    pub fn confirmed(&self) -> usize {
+        self.remote.len()
+    }
+
+    // unconfirmed frames are guessed to repeat the last known input, buttons are mostly held for many frames
+    pub fn remote_input(&self, frame: usize) -> KeyInput {
+        self.remote
+            .get(frame)
+            .or(self.remote.last())
+            .copied()
+            .unwrap_or(KeyInput::empty())
+    }
+
+    fn send(&mut self) {
+        let start = self.acked.min(self.local.len());
+        let end = self.local.len().min(start + MAX_INPUTS_PER_PACKET);
+
+        let mut packet = MAGIC.to_vec();
+        packet.push(INPUTS);
+        packet.extend_from_slice(&(self.remote.len() as u32).to_le_bytes());
+        packet.extend_from_slice(&(start as u32).to_le_bytes());
+        for keys in &self.local[start..end] {
+            packet.extend_from_slice(&keys.bits().to_le_bytes());
+        }
+
+        // a full send buffer is the same as a lost packet
+        let _ = self.socket.send_to(&packet, self.peer);
+        self.last_sent = Instant::now();
+    }
+
+    // takes in everything the peer sent so far, fails once the peer has been silent for too long
+    pub fn poll(&mut self) -> std::io::Result<()> {
+        let mut buffer = [0u8; 16 + MAX_INPUTS_PER_PACKET * 2];
+        loop {
+            let size = match self.socket.recv_from(&mut buffer) {
+                Ok((size, from)) if from == self.peer => size,
+                Ok(_) => continue,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                // the ICMP of a packet the peer was not ready for yet, e.g. while it still loads
+                Err(e) if e.kind() == ErrorKind::ConnectionReset => continue,
+                Err(e) => return Err(e),
+            };
+            let Some(payload) = buffer[..size].strip_prefix(MAGIC) else {
+                continue;
+            };
+            self.last_received = Instant::now();
+
+            // a repeated hello means the welcome got lost
+            if payload.first() == Some(&HELLO) {
+                let _ = self.socket.send_to(&Self::hello(WELCOME, &self.crc32), self.peer);
+                continue;
+            }
+            if payload.first() != Some(&INPUTS) || payload.len() < 9 {
+                continue;
+            }
+
+            let u32_at = |offset: usize| u32::from_le_bytes(payload[offset..offset + 4].try_into().unwrap()) as usize;
+            self.acked = self.acked.max(u32_at(1));
+            let start = u32_at(5);
+            for (i, keys) in payload[9..].chunks_exact(2).enumerate() {
+                if start + i == self.remote.len() {
+                    self.remote
+                        .push(KeyInput::from_bits_truncate(u16::from_le_bytes([keys[0], keys[1]])));
+                }
+            }
+        }
+
+        if self.last_received.elapsed() > DISCONNECT_TIMEOUT {
+            return Err(Error::new(ErrorKind::TimedOut, "the other player stopped responding"));
+        }
+        // keeps the acknowledgements flowing while one side waits for the other
+        if self.last_sent.elapsed() > RESEND_INTERVAL {
+            self.send();
+        }
+        Ok(())
+    }
+}
@@ -1,17 +1,34 @@
+use super::audio::AudioOutput;
 use super::dbg::debugger::Debugger;
-use super::event::ResponseEvent;
+use super::dbg::session::{self, Session};
+use super::dbg::widgets::SELECTED_COLOR;
+use super::event::{EmulatorEvent, ResponseEvent};
+use crate::emulator::{MAX_RUN_AHEAD, Pacing};
 use crate::event::RequestEvent;
+use crate::filter::{FrameFilter, MAX_INTERFRAME_BLEND, Rotation};
+use crate::input::{Action, Device, INPUT_CONFIG_PATH, Input, InputMap, button_name};
+use crate::resampler::ResamplerKind;
+use crate::shader::{PostShader, ShaderPipeline};
+use crate::upscale::UpscalerKind;
 use chrono::Utc;
 use crossbeam_channel::{Receiver, Sender};
 use eframe::egui::{CentralPanel, Color32, ColorImage, Context, Image, TextureHandle, TextureOptions, vec2};
-use eframe::{App, CreationContext};
-use egui::{Align2, Key, RichText, Window};
+use eframe::{App, CreationContext, egui_glow};
+use egui::epaint::{Mesh, Vertex};
+use egui::{
+    Align2, Checkbox, ComboBox, Event, Grid, Key, Rect, RichText, Slider, Stroke, StrokeKind, TextEdit,
+    ViewportCommand, Window, pos2,
+};
 use egui_extras::{Column, TableBuilder};
 use egui_toast::{Toast, ToastKind, ToastOptions, Toasts};
 use gba_core::input::registers::KeyInput;
-use gba_core::video::{Frame, Pixel, SCREEN_HEIGHT, SCREEN_WIDTH};
-use image::{ImageBuffer, Rgb, RgbImage, imageops};
+use gba_core::video::{Frame, SCREEN_HEIGHT, SCREEN_WIDTH, frame_to_rgb};
+use image::{RgbImage, imageops};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::error;
 
+// initial window scale, the window can be resized freely afterwards
 // TODO: make it a bit smaller for when im on my macbook
 #[cfg(target_os = "macos")]
 pub const SCALE: usize = 6;
@@ -19,21 +36,50 @@ pub const SCALE: usize = 6;
 #[cfg(not(target_os = "macos"))]
 pub const SCALE: usize = 8;
 
+const MAX_WINDOW_SCALE: usize = 8; // largest preset in the video settings
+const MAX_CAPTURE_FRAME_SKIP: usize = 5;
+
 pub struct Renderer {
     screen_texture: TextureHandle,
-    screen_buffer: Frame,
+    screen_buffer: Box<Frame>,
+    previous_buffer: Box<Frame>, // for the interframe blending
     debugger: Debugger,
-    display_rx: Receiver<Frame>,
+    display_rx: Receiver<Box<Frame>>,
+    event_rx: Receiver<EmulatorEvent>,
     backend_tx: Sender<RequestEvent>,
     exit_tx: Sender<()>,
     toasts: Toasts,
     running: bool,
+    solar_level: u8,
+    rumble: bool,
+    paused: Option<usize>, // frame the emulator is paused on
+    load_rom_open: bool,
+    rom_path: String,
+    pub audio: Option<AudioOutput>,
+    audio_open: bool,
+    audio_devices: Vec<String>,
+    pub pacing: Pacing,
+    pub run_ahead: usize,
+    recording: bool,
+    record_stems: bool, // also record every channel to its own file
+    pub filter: FrameFilter,
+    video_open: bool,
+    integer_scaling: bool, // only scales by whole multiples, the rest of the window is letterboxed
+    pub shader: PostShader,
+    pipeline: Option<Arc<Mutex<ShaderPipeline>>>, // None without the glow renderer
+    shader_path: String,                          // fragment shader of PostShader::Custom
+    screenshot_native: bool,                      // 240x160 instead of scaled up by SCALE
+    capturing: bool,
+    capture_frame_skip: usize, // frames left out between the captured ones
+    input: Input,
+    input_open: bool,
+    rebinding: Option<(Action, Device)>, // waiting for the next key or button press
 }
 
 impl Renderer {
     pub fn new(
-        cc: &CreationContext, display_rx: Receiver<Frame>, backend_tx: Sender<RequestEvent>,
-        backend_rx: Receiver<ResponseEvent>, exit_tx: Sender<()>,
+        cc: &CreationContext, display_rx: Receiver<Box<Frame>>, event_rx: Receiver<EmulatorEvent>,
+        backend_tx: Sender<RequestEvent>, backend_rx: Receiver<ResponseEvent>, exit_tx: Sender<()>, session: Session,
     ) -> Renderer {
         // TODO: debugger is currently designed for big screens
         // so scale everything down a bit in case im on my macbook
@@ -45,13 +91,16 @@ impl Renderer {
             ColorImage::new([SCREEN_WIDTH, SCREEN_HEIGHT], Color32::BLACK),
             TextureOptions::NEAREST,
         );
-        let debugger = Debugger::new(
+        let mut debugger = Debugger::new(
+            backend_tx.clone(),
+            backend_tx.clone(),
             backend_tx.clone(),
             backend_tx.clone(),
             backend_tx.clone(),
             backend_tx.clone(),
             backend_rx.clone(),
         );
+        debugger.restore_session(&cc.egui_ctx, session);
 
         let mut fonts = egui::FontDefinitions::default();
         egui_phosphor::add_to_fonts(&mut fonts, egui_phosphor::Variant::Regular);
@@ -59,98 +108,420 @@ impl Renderer {
 
         let toasts = Toasts::new();
 
+        let pipeline = cc.gl.as_ref().and_then(|gl| match ShaderPipeline::new(gl) {
+            Ok(pipeline) => Some(Arc::new(Mutex::new(pipeline))),
+            Err(e) => {
+                error!(target: "ppu", "Failed to compile the post shaders: {}", e);
+                None
+            }
+        });
+
         Renderer {
             screen_texture,
-            screen_buffer: [[Pixel::Transparent; SCREEN_WIDTH]; SCREEN_HEIGHT],
+            screen_buffer: Box::new([0; SCREEN_WIDTH * SCREEN_HEIGHT]),
+            previous_buffer: Box::new([0; SCREEN_WIDTH * SCREEN_HEIGHT]),
             debugger,
             display_rx,
+            event_rx,
             backend_tx,
             toasts,
             running: false,
+            solar_level: 0x80,
+            rumble: false,
+            paused: None,
+            load_rom_open: false,
+            rom_path: String::new(),
+            audio: None,
+            audio_open: false,
+            audio_devices: Vec::new(),
+            pacing: Pacing::Free,
+            run_ahead: 0,
+            recording: false,
+            record_stems: false,
+            filter: FrameFilter::new(),
+            video_open: false,
+            integer_scaling: false,
+            shader: PostShader::None,
+            pipeline,
+            shader_path: String::new(),
+            screenshot_native: false,
+            capturing: false,
+            capture_frame_skip: 1,
+            input: Input::new(InputMap::load(Path::new(INPUT_CONFIG_PATH))),
+            input_open: false,
+            rebinding: None,
             exit_tx,
         }
     }
 
-    pub fn update_screen(&mut self, texture: &Frame) {
-        self.screen_buffer = texture.clone();
+    pub fn update_screen(&mut self, texture: Box<Frame>) {
+        self.debugger.update_screen(&texture);
+        self.previous_buffer = std::mem::replace(&mut self.screen_buffer, texture);
+        self.present();
+    }
 
-        let mut pixels = vec![Color32::BLACK; SCREEN_WIDTH * SCREEN_HEIGHT];
+    // also called when the filter settings change, so they apply while paused
+    fn present(&mut self) {
+        let image = self.filter.apply(&self.screen_buffer, &self.previous_buffer);
 
-        for y in 0..SCREEN_HEIGHT {
-            for x in 0..SCREEN_WIDTH {
-                let color = texture[y][x];
-                if let Pixel::Rgb(r, g, b) = color {
-                    pixels[y * SCREEN_WIDTH + x] = Color32::from_rgba_premultiplied(r, g, b, 255);
-                }
+        if let Some(pipeline) = &self.pipeline {
+            pipeline.lock().unwrap().update(image.clone());
+        }
+
+        self.screen_texture.set(image, TextureOptions::NEAREST);
+    }
+
+    // the largest area with the aspect ratio of the GBA that fits into the window, centered
+    fn screen_rect(&self, available: Rect, pixels_per_point: f32) -> Rect {
+        let [width, height] = self.filter.rotation.screen_size().map(|size| size as f32);
+        let mut scale = (available.width() / width).min(available.height() / height);
+        if self.integer_scaling {
+            // whole multiples of physical pixels, not points
+            scale = (scale * pixels_per_point).floor().max(1.0) / pixels_per_point;
+        }
+
+        Rect::from_center_size(available.center(), vec2(width * scale, height * scale))
+    }
+
+    // the bounding box of a sprite (x, y, width, height in GBA pixels) on the scaled and rotated screen
+    fn sprite_rect(&self, screen: Rect, [x, y, width, height]: [i32; 4]) -> Rect {
+        let corner = |x: i32, y: i32| {
+            let [u, v] = self
+                .filter
+                .rotation
+                .map_point([x as f32 / SCREEN_WIDTH as f32, y as f32 / SCREEN_HEIGHT as f32]);
+            screen.left_top() + vec2(u * screen.width(), v * screen.height())
+        };
+        Rect::from_two_pos(corner(x, y), corner(x + width, y + height))
+    }
+
+    // a texture of the unrotated screen stretched over the scaled and rotated one
+    fn screen_overlay(&self, screen: Rect, texture: &TextureHandle) -> Mesh {
+        let mut mesh = Mesh::with_texture(texture.id());
+        for [u, v] in [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]] {
+            let [x, y] = self.filter.rotation.map_point([u, v]);
+            mesh.vertices.push(Vertex {
+                pos: screen.left_top() + vec2(x * screen.width(), y * screen.height()),
+                uv: pos2(u, v),
+                color: Color32::WHITE,
+            });
+        }
+        mesh.add_triangle(0, 1, 2);
+        mesh.add_triangle(0, 2, 3);
+        mesh
+    }
+
+    fn load_rom(&mut self, path: String) {
+        self.rom_path = path.clone();
+        self.load_rom_open = false;
+        let _ = self.backend_tx.send(RequestEvent::LoadRom(path));
+    }
+
+    // the raw frame, without any of the filters
+    fn save_screenshot(&mut self) {
+        let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
+        let screenshot_path = format!("screenshot_{}.png", timestamp);
+
+        let mut img = RgbImage::from_raw(
+            SCREEN_WIDTH as u32,
+            SCREEN_HEIGHT as u32,
+            frame_to_rgb(&self.screen_buffer),
+        )
+        .unwrap();
+        if !self.screenshot_native {
+            img = imageops::resize(
+                &img,
+                (SCREEN_WIDTH * SCALE) as u32,
+                (SCREEN_HEIGHT * SCALE) as u32,
+                imageops::FilterType::Nearest,
+            );
+        }
+
+        let (text, kind) = match img.save(&screenshot_path) {
+            Ok(_) => (format!("Screenshot saved as {}", screenshot_path), ToastKind::Info),
+            Err(e) => (format!("Failed to save screenshot: {}", e), ToastKind::Error),
+        };
+        self.toasts.add(Toast {
+            text: text.into(),
+            kind,
+            options: ToastOptions::default().duration_in_seconds(3.0),
+            ..Default::default()
+        });
+    }
+
+    // switches to the shader, it is compiled on the next paint and any error is shown in the video settings
+    pub fn load_shader(&mut self, path: &Path) {
+        let Some(pipeline) = &self.pipeline else {
+            error!(target: "ppu", "Custom shaders need the glow renderer");
+            return;
+        };
+
+        match pipeline.lock().unwrap().load_custom(path) {
+            Ok(()) => self.shader = PostShader::Custom,
+            Err(e) => {
+                self.toasts.add(Toast {
+                    text: e.into(),
+                    kind: ToastKind::Error,
+                    options: ToastOptions::default().duration_in_seconds(3.0),
+                    ..Default::default()
+                });
             }
         }
+        self.shader_path = path.display().to_string();
+    }
 
-        let image = ColorImage {
-            size: [SCREEN_WIDTH, SCREEN_HEIGHT],
-            pixels,
+    fn toggle_capture(&mut self) {
+        self.capturing = !self.capturing;
+
+        let text = if self.capturing {
+            let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
+            let capture_path = format!("capture_{}.gif", timestamp);
+            let _ = self.backend_tx.send(RequestEvent::StartCapture(
+                capture_path.clone(),
+                self.capture_frame_skip,
+            ));
+            format!("Capturing frames to {}", capture_path)
+        } else {
+            let _ = self.backend_tx.send(RequestEvent::StopCapture);
+            "Frame capture stopped".to_string()
         };
 
-        self.screen_texture.set(image, TextureOptions::NEAREST);
+        self.toasts.add(Toast {
+            text: text.into(),
+            kind: ToastKind::Info,
+            options: ToastOptions::default().duration_in_seconds(3.0),
+            ..Default::default()
+        });
+    }
+
+    fn toggle_recording(&mut self) {
+        self.recording = !self.recording;
+
+        let text = if self.recording {
+            let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
+            let recording_path = format!("recording_{}.wav", timestamp);
+            let _ = self
+                .backend_tx
+                .send(RequestEvent::StartRecording(recording_path.clone(), self.record_stems));
+            format!("Recording audio to {}", recording_path)
+        } else {
+            let _ = self.backend_tx.send(RequestEvent::StopRecording);
+            "Audio recording stopped".to_string()
+        };
+
+        self.toasts.add(Toast {
+            text: text.into(),
+            kind: ToastKind::Info,
+            options: ToastOptions::default().duration_in_seconds(3.0),
+            ..Default::default()
+        });
+    }
+
+    // the next key or gamepad button pressed is bound to the action, escape cancels
+    fn capture_binding(&mut self, ctx: &Context, action: Action, device: Device) {
+        let key = ctx.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                Event::Key {
+                    key,
+                    pressed: true,
+                    repeat: false,
+                    ..
+                } => Some(*key),
+                _ => None,
+            })
+        });
+
+        let button = self.input.pressed_button();
+        let binding = self.input.map.binding_mut(action);
+        match (device, key, button) {
+            (_, Some(Key::Escape), _) => {}
+            (Device::Keyboard, Some(key), _) => binding.key = Some(key),
+            (Device::Gamepad, _, Some(button)) => binding.button = Some(button),
+            _ => return,
+        }
+
+        self.rebinding = None;
+        self.input.map.save(Path::new(INPUT_CONFIG_PATH));
     }
 
     pub fn handle_input(&mut self, ctx: &Context) {
+        self.input.poll();
+
+        // nothing else reacts to the key that is being bound
+        if let Some((action, device)) = self.rebinding {
+            self.capture_binding(ctx, action, device);
+            return;
+        }
+
+        // Toggle fullscreen, viewport commands can't be sent while the input is locked
+        let fullscreen = ctx.input(|i| i.viewport().fullscreen.unwrap_or(false));
+        if ctx.input(|i| self.input.is_pressed(i, Action::Fullscreen)) {
+            ctx.send_viewport_cmd(ViewportCommand::Fullscreen(!fullscreen));
+        }
+
         ctx.input(|i| {
             // Toggle debugger window
-            if i.key_pressed(Key::F1) {
+            if self.input.is_pressed(i, Action::ToggleDebugger) {
                 self.debugger.toggle_window();
                 self.running = false;
             }
 
             // Take a screenshot
-            if i.key_pressed(Key::F2) {
-                let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
-                let screenshot_path = format!("screenshot_{}.png", timestamp);
+            if self.input.is_pressed(i, Action::Screenshot) {
+                self.save_screenshot();
+            }
 
-                let img: RgbImage = ImageBuffer::from_fn(SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32, |x, y| match self
-                    .screen_buffer[y as usize][x as usize]
-                {
-                    Pixel::Transparent => Rgb([0, 0, 0]),
-                    Pixel::Rgb(r, g, b) => Rgb([r, g, b]),
-                });
+            // Load a different ROM
+            if self.input.is_pressed(i, Action::LoadRom) {
+                self.load_rom_open = !self.load_rom_open;
+            }
+            // Audio settings
+            if self.input.is_pressed(i, Action::AudioSettings) {
+                self.audio_open = !self.audio_open;
+                if self.audio_open {
+                    self.audio_devices = AudioOutput::device_names();
+                }
+            }
 
-                let scaled_img = imageops::resize(
-                    &img,
-                    (SCREEN_WIDTH * SCALE) as u32,
-                    (SCREEN_HEIGHT * SCALE) as u32,
-                    imageops::FilterType::Nearest,
-                );
+            // Start or stop recording the audio output
+            if self.input.is_pressed(i, Action::RecordAudio) {
+                self.toggle_recording();
+            }
 
-                scaled_img.save(&screenshot_path).unwrap();
+            // Video settings
+            if self.input.is_pressed(i, Action::VideoSettings) {
+                self.video_open = !self.video_open;
+            }
 
-                self.toasts.add(Toast {
-                    text: format!("Screenshot saved as {}", screenshot_path).into(),
-                    kind: ToastKind::Info,
-                    options: ToastOptions::default().duration_in_seconds(3.0),
-                    ..Default::default()
-                });
+            // Start or stop capturing the frames to a GIF
+            if self.input.is_pressed(i, Action::CaptureGif) {
+                self.toggle_capture();
+            }
+
+            // Input bindings
+            if self.input.is_pressed(i, Action::InputSettings) {
+                self.input_open = !self.input_open;
+            }
+
+            if let Some(path) = i.raw.dropped_files.iter().find_map(|file| file.path.as_ref()) {
+                self.load_rom(path.to_string_lossy().to_string());
+            }
+
+            // Pause and step through the game frame by frame
+            if self.input.is_pressed(i, Action::Pause) {
+                self.backend_tx.send(RequestEvent::TogglePause).unwrap();
+            }
+            if self.input.is_pressed(i, Action::FrameAdvance) {
+                self.backend_tx.send(RequestEvent::FrameAdvance).unwrap();
             }
 
             // Run the emulator
-            if i.key_pressed(Key::Space) && !self.running {
+            if self.input.is_pressed(i, Action::Run) && !self.running && !self.settings_open() {
                 self.backend_tx.send(RequestEvent::Run).unwrap();
                 self.running = true;
             }
 
             // Update key state
             let mut key_state: Vec<(KeyInput, bool)> = Vec::new();
-            key_state.push((KeyInput::A, i.key_down(Key::A)));
-            key_state.push((KeyInput::B, i.key_down(Key::S)));
-            key_state.push((KeyInput::START, i.key_down(Key::Enter)));
-            key_state.push((KeyInput::SELECT, i.key_down(Key::Backspace)));
-            key_state.push((KeyInput::UP, i.key_down(Key::ArrowUp)));
-            key_state.push((KeyInput::DOWN, i.key_down(Key::ArrowDown)));
-            key_state.push((KeyInput::LEFT, i.key_down(Key::ArrowLeft)));
-            key_state.push((KeyInput::RIGHT, i.key_down(Key::ArrowRight)));
-            key_state.push((KeyInput::L, i.key_down(Key::Q)));
-            key_state.push((KeyInput::R, i.key_down(Key::W)));
+            key_state.push((KeyInput::A, self.input.is_down(i, Action::A)));
+            key_state.push((KeyInput::B, self.input.is_down(i, Action::B)));
+            key_state.push((KeyInput::START, self.input.is_down(i, Action::Start)));
+            key_state.push((KeyInput::SELECT, self.input.is_down(i, Action::Select)));
+            key_state.push((KeyInput::L, self.input.is_down(i, Action::L)));
+            key_state.push((KeyInput::R, self.input.is_down(i, Action::R)));
+
+            // the d-pad turns with the screen, both are listed clockwise
+            let turns = self.filter.rotation.quarter_turns();
+            let dpad = [KeyInput::UP, KeyInput::RIGHT, KeyInput::DOWN, KeyInput::LEFT];
+            let arrows = [Action::Up, Action::Right, Action::Down, Action::Left];
+            for (index, action) in arrows.into_iter().enumerate() {
+                key_state.push((dpad[(index + 4 - turns) % 4], self.input.is_down(i, action)));
+            }
             self.backend_tx.send(RequestEvent::UpdateKeyState(key_state)).unwrap();
+
+            // Update tilt and gyro sensors, rotated like the d-pad
+            let (mut x, mut y) = self.input.motion(i);
+            for _ in 0..turns {
+                (x, y) = (y, -x);
+            }
+            self.backend_tx.send(RequestEvent::UpdateMotion(x, y)).unwrap();
         })
     }
+
+    fn settings_open(&self) -> bool {
+        self.load_rom_open || self.audio_open || self.video_open || self.input_open
+    }
+
+    fn binding_name(&self, action: Action, device: Device) -> String {
+        let binding = self.input.map.binding(action);
+        let name = match device {
+            Device::Keyboard => binding.key.map(|key| key.name()),
+            Device::Gamepad => binding.button.map(button_name),
+        };
+        name.unwrap_or("-").to_string()
+    }
+
+    // every change is written to the config right away
+    fn render_input_settings(&mut self, ctx: &Context) {
+        Window::new("Input")
+            .anchor(Align2::CENTER_CENTER, vec2(0.0, 0.0))
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                let mut changed = false;
+
+                Grid::new("input_bindings").striped(true).show(ui, |ui| {
+                    for header in ["Action", "Keyboard", "Gamepad"] {
+                        ui.label(RichText::new(header).italics());
+                    }
+                    ui.end_row();
+
+                    for action in Action::ALL {
+                        ui.label(action.to_string());
+                        for device in [Device::Keyboard, Device::Gamepad] {
+                            ui.horizontal(|ui| {
+                                let text = match (self.rebinding == Some((action, device)), device) {
+                                    (true, Device::Keyboard) => "Press a key...".to_string(),
+                                    (true, Device::Gamepad) => "Press a button...".to_string(),
+                                    (false, _) => self.binding_name(action, device),
+                                };
+                                if ui.button(text).clicked() {
+                                    self.rebinding = Some((action, device));
+                                }
+
+                                if ui
+                                    .small_button(egui_phosphor::regular::X)
+                                    .on_hover_text("Unbind")
+                                    .clicked()
+                                {
+                                    let binding = self.input.map.binding_mut(action);
+                                    match device {
+                                        Device::Keyboard => binding.key = None,
+                                        Device::Gamepad => binding.button = None,
+                                    }
+                                    changed = true;
+                                }
+                            });
+                        }
+                        ui.end_row();
+                    }
+                });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Reset to defaults").clicked() {
+                        self.input.map = InputMap::new();
+                        changed = true;
+                    }
+                    ui.label(RichText::new(format!("Escape cancels, saved to {}", INPUT_CONFIG_PATH)).italics());
+                });
+
+                if changed {
+                    self.rebinding = None;
+                    self.input.map.save(Path::new(INPUT_CONFIG_PATH));
+                }
+            });
+    }
 }
 
 impl App for Renderer {
@@ -160,101 +531,347 @@ impl App for Renderer {
         self.debugger.update(ctx);
 
         match self.display_rx.try_recv() {
-            Ok(frame) => self.update_screen(&frame),
+            Ok(frame) => self.update_screen(frame),
             _ => {}
         }
 
-        CentralPanel::default().show(ctx, |ui| {
-            let image = Image::new(&self.screen_texture);
-            let image = image.fit_to_exact_size(vec2((SCREEN_WIDTH * SCALE) as f32, (SCREEN_HEIGHT * SCALE) as f32));
-            image.paint_at(ui, ui.ctx().screen_rect());
-        });
+        while let Ok(event) = self.event_rx.try_recv() {
+            match event {
+                // shown on screen as well, not every gamepad can rumble
+                EmulatorEvent::Rumble(rumble) => {
+                    self.rumble = rumble;
+                    self.input.set_rumble(rumble);
+                }
+                EmulatorEvent::Paused(frame) => self.paused = frame,
+                EmulatorEvent::RomLoaded(title, crc32) => {
+                    ctx.send_viewport_cmd(ViewportCommand::Title(crate::window_title(&title)));
+                    for path in self.debugger.switch_session(ctx, &crc32) {
+                        let _ = self.backend_tx.send(RequestEvent::LoadSymbols(path));
+                    }
+                    let _ = self.backend_tx.send(RequestEvent::SetSolarLevel(self.solar_level));
+
+                    self.toasts.add(Toast {
+                        text: format!("Loaded {}", title).into(),
+                        kind: ToastKind::Info,
+                        options: ToastOptions::default().duration_in_seconds(3.0),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
 
-        if self.debugger.open {
-            Window::new("Screen")
+        if self.load_rom_open {
+            Window::new("Load ROM")
+                .anchor(Align2::CENTER_CENTER, vec2(0.0, 0.0))
+                .collapsible(false)
                 .resizable(false)
-                .show(ctx, |ui| ui.image(&self.screen_texture));
+                .show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        let response = ui.add(TextEdit::singleline(&mut self.rom_path).hint_text("Path to a ROM"));
+                        let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+                        if (ui.button("Load").clicked() || submitted) && !self.rom_path.is_empty() {
+                            self.load_rom(self.rom_path.clone());
+                        }
+                    });
+                });
         }
 
-        if !self.running && !self.debugger.open {
-            Window::new("Controls")
+        if self.audio_open
+            && let Some(audio) = &mut self.audio
+        {
+            Window::new("Audio")
                 .anchor(Align2::CENTER_CENTER, vec2(0.0, 0.0))
                 .collapsible(false)
                 .resizable(false)
                 .show(ctx, |ui| {
-                    TableBuilder::new(ui)
-                        .columns(Column::auto(), 2)
-                        .header(0.0, |mut header| {
-                            header.col(|ui| {
-                                ui.label(RichText::new("Key").italics());
-                            });
-                            header.col(|ui| {
-                                ui.label(RichText::new("Action").italics());
+                    let mut device = audio.device_name.clone();
+                    ComboBox::from_label("Device")
+                        .selected_text(device.as_deref().unwrap_or("Default"))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut device, None, "Default");
+                            for name in &self.audio_devices {
+                                ui.selectable_value(&mut device, Some(name.clone()), name);
+                            }
+                        });
+                    if device != audio.device_name {
+                        audio.select_device(device);
+                    }
+
+                    let mut volume = (audio.volume() * 100.0).round() as u8;
+                    if ui
+                        .add(Slider::new(&mut volume, 0..=100).text("Volume").suffix("%"))
+                        .changed()
+                    {
+                        audio.set_volume(volume as f32 / 100.0);
+                    }
+
+                    let mut resampler = audio.resampler();
+                    ComboBox::from_label("Resampler")
+                        .selected_text(resampler.to_string())
+                        .show_ui(ui, |ui| {
+                            for kind in [ResamplerKind::Linear, ResamplerKind::Sinc] {
+                                ui.selectable_value(&mut resampler, kind, kind.to_string());
+                            }
+                        });
+                    if resampler != audio.resampler() {
+                        audio.set_resampler(resampler);
+                    }
+
+                    let mut rate_control = audio.rate_control();
+                    if ui
+                        .checkbox(&mut rate_control, "Dynamic rate control")
+                        .on_hover_text("Adjusts the playback rate by up to 0.5% to avoid crackling")
+                        .changed()
+                    {
+                        audio.set_rate_control(rate_control);
+                    }
+
+                    ui.horizontal(|ui| {
+                        ui.label("Pacing");
+                        let free = ui.radio_value(&mut self.pacing, Pacing::Free, "Free running");
+                        let synced = ui.radio_value(&mut self.pacing, Pacing::Audio, "Sync to audio");
+                        if free.changed() || synced.changed() {
+                            let _ = self.backend_tx.send(RequestEvent::SetPacing(self.pacing));
+                        }
+                    });
+
+                    ui.add_enabled(
+                        !self.recording,
+                        Checkbox::new(&mut self.record_stems, "Record every channel to its own file"),
+                    );
+
+                    if !audio.is_playing() {
+                        ui.label(RichText::new("No output device, audio is disabled").italics());
+                    }
+                });
+        }
+
+        if self.video_open {
+            Window::new("Video")
+                .anchor(Align2::CENTER_CENTER, vec2(0.0, 0.0))
+                .collapsible(false)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    if ui
+                        .checkbox(&mut self.filter.color_correction, "LCD color correction")
+                        .on_hover_text("Darkens and desaturates the colors like the screen of the GBA")
+                        .changed()
+                    {
+                        self.present();
+                    }
+
+                    let mut blend = (self.filter.interframe_blend * 100.0).round() as u8;
+                    if ui
+                        .add(
+                            Slider::new(&mut blend, 0..=(MAX_INTERFRAME_BLEND * 100.0) as u8)
+                                .text("Interframe blending")
+                                .suffix("%"),
+                        )
+                        .on_hover_text("Mixes in the previous frame to emulate the ghosting of the LCD")
+                        .changed()
+                    {
+                        self.filter.interframe_blend = blend as f32 / 100.0;
+                        self.present();
+                    }
+
+                    let mut upscaler = self.filter.upscaler;
+                    ComboBox::from_label("Upscaler")
+                        .selected_text(upscaler.to_string())
+                        .show_ui(ui, |ui| {
+                            for kind in UpscalerKind::ALL {
+                                ui.selectable_value(&mut upscaler, kind, kind.to_string());
+                            }
+                        });
+                    if upscaler != self.filter.upscaler {
+                        self.filter.upscaler = upscaler;
+                        self.present();
+                    }
+
+                    ui.add_enabled_ui(self.pipeline.is_some(), |ui| {
+                        ComboBox::from_label("Shader")
+                            .selected_text(self.shader.to_string())
+                            .show_ui(ui, |ui| {
+                                for shader in PostShader::PRESETS.into_iter().chain([PostShader::Custom]) {
+                                    ui.selectable_value(&mut self.shader, shader, shader.to_string());
+                                }
                             });
+
+                        ui.horizontal(|ui| {
+                            ui.add(TextEdit::singleline(&mut self.shader_path).hint_text("Fragment shader path"));
+                            if ui.button("Load").clicked() {
+                                let path = PathBuf::from(&self.shader_path);
+                                self.load_shader(&path);
+                            }
+                        });
+
+                        let error = self
+                            .pipeline
+                            .as_ref()
+                            .and_then(|p| p.lock().unwrap().custom_error().map(String::from));
+                        if let Some(error) = error {
+                            ui.colored_label(Color32::RED, error);
+                        }
+                    })
+                    .response
+                    .on_disabled_hover_text("Post shaders need the glow renderer");
+
+                    let mut rotation = self.filter.rotation;
+                    ComboBox::from_label("Rotation")
+                        .selected_text(rotation.to_string())
+                        .show_ui(ui, |ui| {
+                            for turns in Rotation::ALL {
+                                ui.selectable_value(&mut rotation, turns, turns.to_string());
+                            }
                         })
-                        .body(|mut body| {
-                            body.row(0.0, |mut row| {
-                                row.col(|ui| {
-                                    ui.label(RichText::new("F1").strong());
-                                });
-                                row.col(|ui| {
-                                    ui.label("Toggle debugger window");
-                                });
-                            });
+                        .response
+                        .on_hover_text("Clockwise, the arrow keys are turned along with the screen");
+                    if rotation != self.filter.rotation {
+                        self.filter.rotation = rotation;
+                        self.present();
+                    }
+
+                    if ui
+                        .add(Slider::new(&mut self.run_ahead, 0..=MAX_RUN_AHEAD).text("Run-ahead frames"))
+                        .on_hover_text("Shows the game ahead of time to cut the input latency, needs a faster machine")
+                        .changed()
+                    {
+                        let _ = self.backend_tx.send(RequestEvent::SetRunAhead(self.run_ahead));
+                    }
+
+                    ui.checkbox(&mut self.integer_scaling, "Integer scaling")
+                        .on_hover_text("Keeps every pixel the same size, the rest of the window is left black");
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Save screenshot").clicked() {
+                            self.save_screenshot();
+                        }
+                        ui.checkbox(&mut self.screenshot_native, "Native resolution")
+                            .on_hover_text("Saves the frame at 240x160 instead of scaling it up");
+                    });
+
+                    ui.horizontal(|ui| {
+                        let label = if self.capturing { "Stop capture" } else { "Capture GIF" };
+                        if ui.button(label).clicked() {
+                            self.toggle_capture();
+                        }
+                        ui.add_enabled(
+                            !self.capturing,
+                            Slider::new(&mut self.capture_frame_skip, 0..=MAX_CAPTURE_FRAME_SKIP).text("Frame skip"),
+                        );
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label("Window size");
+                        for scale in 1..=MAX_WINDOW_SCALE {
+                            if ui.button(format!("{}x", scale)).clicked() {
+                                let [width, height] = self.filter.rotation.screen_size();
+                                let size = vec2((width * scale) as f32, (height * scale) as f32);
+                                ctx.send_viewport_cmd(ViewportCommand::Fullscreen(false));
+                                ctx.send_viewport_cmd(ViewportCommand::InnerSize(size / ctx.pixels_per_point()));
+                            }
+                        }
+                    });
+                });
+        }
 
-                            body.row(0.0, |mut row| {
-                                row.col(|ui| {
-                                    ui.label(RichText::new("F2").strong());
-                                });
-                                row.col(|ui| {
-                                    ui.label("Take a screenshot");
-                                });
-                            });
+        let letterbox = egui::Frame::NONE.fill(Color32::BLACK);
+        CentralPanel::default().frame(letterbox).show(ctx, |ui| {
+            let rect = self.screen_rect(ui.max_rect(), ctx.pixels_per_point());
+            match &self.pipeline {
+                Some(pipeline) if pipeline.lock().unwrap().can_paint(self.shader) => {
+                    let (pipeline, shader) = (pipeline.clone(), self.shader);
+                    let callback = egui_glow::CallbackFn::new(move |info, painter| {
+                        let viewport = info.viewport_in_pixels();
+                        let output_size = [viewport.width_px as f32, viewport.height_px as f32];
+                        pipeline.lock().unwrap().paint(painter.gl(), shader, output_size);
+                    });
+                    ui.painter().add(egui::PaintCallback {
+                        rect,
+                        callback: Arc::new(callback),
+                    });
+                }
+                _ => Image::new(&self.screen_texture).paint_at(ui, rect),
+            }
 
-                            body.row(0.0, |mut row| {
-                                row.col(|ui| {
-                                    ui.label(RichText::new("Space").strong());
-                                });
-                                row.col(|ui| {
-                                    ui.label("Run the emulator");
-                                });
-                            });
+            if let Some(texture) = self.debugger.window_overlay() {
+                ui.painter().add(self.screen_overlay(rect, texture));
+            }
 
-                            body.row(0.0, |mut row| {
-                                row.col(|ui| {
-                                    ui.label(RichText::new("A, S").strong());
-                                });
-                                row.col(|ui| {
-                                    ui.label("A, B");
-                                });
-                            });
+            if let Some(bounds) = self.debugger.highlighted_sprite() {
+                ui.painter().with_clip_rect(rect).rect_stroke(
+                    self.sprite_rect(rect, bounds),
+                    0.0,
+                    Stroke::new(2.0_f32, SELECTED_COLOR),
+                    StrokeKind::Outside,
+                );
+            }
 
-                            body.row(0.0, |mut row| {
-                                row.col(|ui| {
-                                    ui.label(RichText::new("Q, W").strong());
-                                });
-                                row.col(|ui| {
-                                    ui.label("L, R");
-                                });
-                            });
+            if let Some(frame) = self.paused {
+                ui.label(
+                    RichText::new(format!("{} Frame {}", egui_phosphor::regular::PAUSE, frame))
+                        .size(24.0)
+                        .color(Color32::WHITE),
+                );
+            }
 
-                            body.row(0.0, |mut row| {
-                                row.col(|ui| {
-                                    ui.label(RichText::new("Enter, Backspace").strong());
-                                });
-                                row.col(|ui| {
-                                    ui.label("Start, Select");
-                                });
-                            });
+            if self.rumble {
+                ui.label(
+                    RichText::new(egui_phosphor::regular::VIBRATE)
+                        .size(32.0)
+                        .color(Color32::WHITE),
+                );
+            }
+        });
 
-                            body.row(0.0, |mut row| {
-                                row.col(|ui| {
-                                    ui.label(RichText::new("Arrow keys").strong());
+        if self.debugger.open {
+            session::window(ctx, "Screen")
+                .resizable(false)
+                .show(ctx, |ui| ui.image(&self.screen_texture));
+
+            // only has an effect on carts with a solar sensor (Boktai)
+            session::window(ctx, "Solar Sensor").resizable(false).show(ctx, |ui| {
+                if ui
+                    .add(Slider::new(&mut self.solar_level, 0..=0xFF).text("Brightness"))
+                    .changed()
+                {
+                    let _ = self.backend_tx.send(RequestEvent::SetSolarLevel(self.solar_level));
+                }
+            });
+        }
+
+        if self.input_open {
+            self.render_input_settings(ctx);
+        }
+
+        if !self.running && !self.debugger.open && !self.settings_open() {
+            Window::new("Controls")
+                .anchor(Align2::CENTER_CENTER, vec2(0.0, 0.0))
+                .collapsible(false)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    TableBuilder::new(ui)
+                        .columns(Column::auto(), 3)
+                        .header(0.0, |mut header| {
+                            for title in ["Key", "Gamepad", "Action"] {
+                                header.col(|ui| {
+                                    ui.label(RichText::new(title).italics());
                                 });
-                                row.col(|ui| {
-                                    ui.label("D-pad");
+                            }
+                        })
+                        .body(|mut body| {
+                            for action in Action::ALL {
+                                body.row(0.0, |mut row| {
+                                    row.col(|ui| {
+                                        ui.label(RichText::new(self.binding_name(action, Device::Keyboard)).strong());
+                                    });
+                                    row.col(|ui| {
+                                        ui.label(RichText::new(self.binding_name(action, Device::Gamepad)).strong());
+                                    });
+                                    row.col(|ui| {
+                                        ui.label(action.to_string());
+                                    });
                                 });
-                            });
+                            }
                         });
                 });
         }
@@ -264,7 +881,13 @@ impl App for Renderer {
         ctx.request_repaint();
     }
 
-    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+    fn on_exit(&mut self, gl: Option<&eframe::glow::Context>) {
+        self.debugger.save_session();
+
+        if let (Some(gl), Some(pipeline)) = (gl, &self.pipeline) {
+            pipeline.lock().unwrap().destroy(gl);
+        }
+
         // Send exit signal to the emulator thread to ensure components can save their state
         self.exit_tx.send(()).unwrap();
     }
@@ -0,0 +1,116 @@
+use std::collections::VecDeque;
+use std::f32::consts::PI;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const SINC_HALF_TAPS: usize = 8; // input samples on each side of the output position
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ResamplerKind {
+    Linear,
+    #[default]
+    Sinc, // Blackman windowed sinc
+}
+
+impl FromStr for ResamplerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "linear" => Ok(ResamplerKind::Linear),
+            "sinc" => Ok(ResamplerKind::Sinc),
+            _ => Err(format!("unknown resampler '{}', expected linear or sinc", s)),
+        }
+    }
+}
+
+impl Display for ResamplerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResamplerKind::Linear => write!(f, "Linear"),
+            ResamplerKind::Sinc => write!(f, "Windowed sinc"),
+        }
+    }
+}
+
+// converts the emulator's sample rate to the rate of the output device
+pub struct Resampler {
+    pub kind: ResamplerKind,
+    base_step: f32,
+    step: f32,     // input samples per output sample
+    position: f32, // fraction between the two input samples in the middle of the history
+    cutoff: f32,   // relative to the input nyquist frequency, lowered when downsampling
+    history: VecDeque<[f32; 2]>,
+}
+
+impl Resampler {
+    pub fn new(kind: ResamplerKind, input_rate: u32, output_rate: u32) -> Resampler {
+        let step = input_rate as f32 / output_rate as f32;
+
+        Resampler {
+            kind,
+            base_step: step,
+            step,
+            position: 0.0,
+            cutoff: (1.0 / step).min(1.0),
+            history: VecDeque::from(vec![[0.0; 2]; SINC_HALF_TAPS * 2]),
+        }
+    }
+
+    // speeds up (positive) or slows down (negative) the consumption of input samples, e.g. 0.005 for +0.5%
+    pub fn set_rate_adjustment(&mut self, adjustment: f32) {
+        self.step = self.base_step * (1.0 + adjustment);
+    }
+
+    // pulls as many input samples as needed for the next output sample, the last one is repeated if none are left
+    pub fn next(&mut self, mut pull: impl FnMut() -> Option<[f32; 2]>) -> [f32; 2] {
+        self.position += self.step;
+        while self.position >= 1.0 {
+            self.position -= 1.0;
+
+            let sample = pull().unwrap_or(self.history[self.history.len() - 1]);
+            self.history.pop_front();
+            self.history.push_back(sample);
+        }
+
+        match self.kind {
+            ResamplerKind::Linear => self.linear(),
+            ResamplerKind::Sinc => self.sinc(),
+        }
+    }
+
+    fn linear(&self) -> [f32; 2] {
+        let (a, b) = (self.history[SINC_HALF_TAPS - 1], self.history[SINC_HALF_TAPS]);
+        [0, 1].map(|channel| a[channel] + (b[channel] - a[channel]) * self.position)
+    }
+
+    fn sinc(&self) -> [f32; 2] {
+        let mut output = [0.0; 2];
+
+        for (i, sample) in self.history.iter().enumerate() {
+            // distance of this input sample to the output position
+            let t = i as f32 - (SINC_HALF_TAPS - 1) as f32 - self.position;
+            let weight = Self::kernel(t * self.cutoff) * Self::window(t) * self.cutoff;
+            output[0] += sample[0] * weight;
+            output[1] += sample[1] * weight;
+        }
+
+        output
+    }
+
+    fn kernel(t: f32) -> f32 {
+        if t.abs() < f32::EPSILON {
+            1.0
+        } else {
+            (PI * t).sin() / (PI * t)
+        }
+    }
+
+    fn window(t: f32) -> f32 {
+        let x = t / SINC_HALF_TAPS as f32;
+        if x.abs() >= 1.0 {
+            return 0.0;
+        }
+        0.42 + 0.5 * (PI * x).cos() + 0.08 * (2.0 * PI * x).cos()
+    }
+}
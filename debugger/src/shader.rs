@@ -0,0 +1,310 @@
+use eframe::egui::ColorImage;
+use eframe::glow::{self, HasContext};
+use gba_core::video::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+use tracing::error;
+
+// covers the viewport of the paint callback with two triangles, the first texture row is the top of the screen
+const VERTEX_SHADER: &str = r#"
+    const vec2 corners[4] = vec2[4](vec2(-1.0, -1.0), vec2(1.0, -1.0), vec2(-1.0, 1.0), vec2(1.0, 1.0));
+    out vec2 v_uv;
+
+    void main() {
+        vec2 corner = corners[gl_VertexID];
+        v_uv = vec2(corner.x + 1.0, 1.0 - corner.y) / 2.0;
+        gl_Position = vec4(corner, 0.0, 1.0);
+    }
+"#;
+
+const FRAGMENT_PRELUDE: &str = r#"
+    precision mediump float;
+    uniform sampler2D u_screen;
+    uniform vec2 u_source_size; // pixels of the (upscaled) frame
+    uniform vec2 u_output_size; // physical pixels of the viewport
+    in vec2 v_uv;
+    out vec4 f_color;
+
+    vec3 source_pixel(vec2 pixel) {
+        return texture(u_screen, (floor(pixel) + 0.5) / u_source_size).rgb;
+    }
+"#;
+
+// darkens the gaps between the pixels, one output pixel wide
+const LCD_GRID: &str = r#"
+    void main() {
+        vec2 pixel = v_uv * u_source_size;
+        vec2 gap = step(u_source_size / u_output_size, fract(pixel));
+        f_color = vec4(source_pixel(pixel) * mix(0.6, 1.0, gap.x * gap.y), 1.0);
+    }
+"#;
+
+// every line is brightest in its center, slightly boosted to make up for the dark edges
+const CRT_SCANLINES: &str = r#"
+    void main() {
+        vec2 pixel = v_uv * u_source_size;
+        float scanline = 0.6 + 0.4 * sin(fract(pixel.y) * 3.14159265);
+        f_color = vec4(min(source_pixel(pixel) * scanline * 1.15, 1.0), 1.0);
+    }
+"#;
+
+// nearest neighbor scaled by the largest integer factor, only the edges between the pixels are interpolated
+const SHARP_BILINEAR: &str = r#"
+    void main() {
+        vec2 pixel = v_uv * u_source_size;
+        vec2 scale = max(floor(u_output_size / u_source_size), vec2(1.0));
+        vec2 region = 0.5 - 0.5 / scale;
+        vec2 center = fract(pixel) - 0.5;
+        vec2 offset = (center - clamp(center, -region, region)) * scale + 0.5;
+        f_color = vec4(texture(u_screen, (floor(pixel) + offset) / u_source_size).rgb, 1.0);
+    }
+"#;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum PostShader {
+    #[default]
+    None, // nearest neighbor, drawn by egui
+    LcdGrid,
+    CrtScanlines,
+    SharpBilinear,
+    Custom, // fragment shader loaded from a file
+}
+
+impl PostShader {
+    // built in, PostShader::Custom is loaded from a file
+    pub const PRESETS: [PostShader; 4] = [
+        PostShader::None,
+        PostShader::LcdGrid,
+        PostShader::CrtScanlines,
+        PostShader::SharpBilinear,
+    ];
+
+    fn source(&self) -> Option<&'static str> {
+        match self {
+            PostShader::None => None,
+            PostShader::LcdGrid => Some(LCD_GRID),
+            PostShader::CrtScanlines => Some(CRT_SCANLINES),
+            PostShader::SharpBilinear => Some(SHARP_BILINEAR),
+            PostShader::Custom => None,
+        }
+    }
+}
+
+impl FromStr for PostShader {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(PostShader::None),
+            "lcd" => Ok(PostShader::LcdGrid),
+            "crt" => Ok(PostShader::CrtScanlines),
+            "sharp-bilinear" => Ok(PostShader::SharpBilinear),
+            _ => Err(format!(
+                "unknown shader '{}', expected none, lcd, crt or sharp-bilinear",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for PostShader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PostShader::None => write!(f, "None"),
+            PostShader::LcdGrid => write!(f, "LCD grid"),
+            PostShader::CrtScanlines => write!(f, "CRT scanlines"),
+            PostShader::SharpBilinear => write!(f, "Sharp bilinear"),
+            PostShader::Custom => write!(f, "Custom"),
+        }
+    }
+}
+
+// presents the frame through one of the post shaders, only available with the glow renderer
+pub struct ShaderPipeline {
+    programs: Vec<(PostShader, glow::Program)>,
+    texture: glow::Texture,
+    vertex_array: glow::VertexArray,
+    pending: Option<ColorImage>, // uploaded on the next paint, the GL context is only current while painting
+    size: [usize; 2],
+    custom_source: Option<String>, // compiled on the next paint as well
+    custom_error: Option<String>,  // compile log of the last custom shader
+}
+
+impl ShaderPipeline {
+    pub fn new(gl: &glow::Context) -> Result<ShaderPipeline, String> {
+        unsafe {
+            let mut programs = Vec::new();
+            for shader in PostShader::PRESETS {
+                if let Some(source) = shader.source() {
+                    programs.push((shader, Self::compile(gl, source)?));
+                }
+            }
+
+            let texture = gl.create_texture()?;
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+            gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_MIN_FILTER, glow::LINEAR as i32);
+            gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_MAG_FILTER, glow::LINEAR as i32);
+            gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_WRAP_S, glow::CLAMP_TO_EDGE as i32);
+            gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_WRAP_T, glow::CLAMP_TO_EDGE as i32);
+
+            Ok(ShaderPipeline {
+                programs,
+                texture,
+                vertex_array: gl.create_vertex_array()?,
+                pending: None,
+                size: [SCREEN_WIDTH, SCREEN_HEIGHT],
+                custom_source: None,
+                custom_error: None,
+            })
+        }
+    }
+
+    unsafe fn compile(gl: &glow::Context, fragment_source: &str) -> Result<glow::Program, String> {
+        unsafe {
+            let program = gl.create_program()?;
+            let sources = [
+                (glow::VERTEX_SHADER, VERTEX_SHADER.to_string()),
+                (
+                    glow::FRAGMENT_SHADER,
+                    format!("{}{}", FRAGMENT_PRELUDE, fragment_source),
+                ),
+            ];
+
+            let mut shaders = Vec::new();
+            for (kind, source) in sources {
+                let shader = gl.create_shader(kind)?;
+                gl.shader_source(shader, &format!("#version 330\n{}", source));
+                gl.compile_shader(shader);
+                gl.attach_shader(program, shader);
+                shaders.push(shader);
+                if !gl.get_shader_compile_status(shader) {
+                    let log = gl.get_shader_info_log(shader);
+                    Self::delete(gl, program, shaders);
+                    return Err(log);
+                }
+            }
+
+            gl.link_program(program);
+            if !gl.get_program_link_status(program) {
+                let log = gl.get_program_info_log(program);
+                Self::delete(gl, program, shaders);
+                return Err(log);
+            }
+
+            for shader in shaders {
+                gl.detach_shader(program, shader);
+                gl.delete_shader(shader);
+            }
+            Ok(program)
+        }
+    }
+
+    // a custom shader can fail to compile any number of times, nothing of it is kept
+    unsafe fn delete(gl: &glow::Context, program: glow::Program, shaders: Vec<glow::Shader>) {
+        unsafe {
+            for shader in shaders {
+                gl.detach_shader(program, shader);
+                gl.delete_shader(shader);
+            }
+            gl.delete_program(program);
+        }
+    }
+
+    pub fn update(&mut self, image: ColorImage) {
+        self.pending = Some(image);
+    }
+
+    // the file only contains main(), it gets the same prelude as the presets
+    pub fn load_custom(&mut self, path: &Path) -> Result<(), String> {
+        let source = std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        self.custom_source = Some(source);
+        self.custom_error = None;
+        Ok(())
+    }
+
+    pub fn custom_error(&self) -> Option<&str> {
+        self.custom_error.as_deref()
+    }
+
+    // false if the shader failed to compile, the screen is drawn without it then
+    pub fn can_paint(&self, shader: PostShader) -> bool {
+        match shader {
+            PostShader::None => false,
+            PostShader::Custom if self.custom_source.is_some() => true,
+            _ => self.programs.iter().any(|(kind, _)| *kind == shader),
+        }
+    }
+
+    unsafe fn compile_custom(&mut self, gl: &glow::Context, source: &str) {
+        unsafe {
+            if let Some(index) = self.programs.iter().position(|(kind, _)| *kind == PostShader::Custom) {
+                gl.delete_program(self.programs.remove(index).1);
+            }
+
+            match Self::compile(gl, source) {
+                Ok(program) => self.programs.push((PostShader::Custom, program)),
+                Err(e) => {
+                    error!(target: "ppu", "Failed to compile the custom shader: {}", e);
+                    self.custom_error = Some(e);
+                }
+            }
+        }
+    }
+
+    pub fn paint(&mut self, gl: &glow::Context, shader: PostShader, output_size: [f32; 2]) {
+        if let Some(source) = self.custom_source.take() {
+            unsafe { self.compile_custom(gl, &source) };
+        }
+
+        let Some((_, program)) = self.programs.iter().find(|(kind, _)| *kind == shader) else {
+            return;
+        };
+
+        unsafe {
+            gl.active_texture(glow::TEXTURE0);
+            gl.bind_texture(glow::TEXTURE_2D, Some(self.texture));
+            if let Some(image) = self.pending.take() {
+                let bytes: Vec<u8> = image.pixels.iter().flat_map(|color| color.to_array()).collect();
+                self.size = image.size;
+                gl.tex_image_2d(
+                    glow::TEXTURE_2D,
+                    0,
+                    glow::RGBA8 as i32,
+                    self.size[0] as i32,
+                    self.size[1] as i32,
+                    0,
+                    glow::RGBA,
+                    glow::UNSIGNED_BYTE,
+                    glow::PixelUnpackData::Slice(Some(&bytes)),
+                );
+            }
+
+            gl.use_program(Some(*program));
+            gl.uniform_1_i32(gl.get_uniform_location(*program, "u_screen").as_ref(), 0);
+            gl.uniform_2_f32(
+                gl.get_uniform_location(*program, "u_source_size").as_ref(),
+                self.size[0] as f32,
+                self.size[1] as f32,
+            );
+            gl.uniform_2_f32(
+                gl.get_uniform_location(*program, "u_output_size").as_ref(),
+                output_size[0],
+                output_size[1],
+            );
+
+            gl.bind_vertex_array(Some(self.vertex_array));
+            gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+        }
+    }
+
+    pub fn destroy(&self, gl: &glow::Context) {
+        unsafe {
+            for (_, program) in &self.programs {
+                gl.delete_program(*program);
+            }
+            gl.delete_texture(self.texture);
+            gl.delete_vertex_array(self.vertex_array);
+        }
+    }
+}
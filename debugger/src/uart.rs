@@ -0,0 +1,101 @@
+use gba_core::serial::link::LinkDevice;
+use std::collections::VecDeque;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use tracing::{info, warn};
+
+// the host end of the GBA's UART, the lines the game sends are logged and with a port every byte is also passed on
+// to a TCP client, which in turn can send bytes to the game. socat can put a pseudo-terminal in front of it
+pub struct UartBridge {
+    line: Vec<u8>,
+    listener: Option<TcpListener>,
+    client: Option<TcpStream>,
+    received: VecDeque<u8>, // from the client, waiting for the game to be ready
+}
+
+impl UartBridge {
+    pub fn new(port: Option<u16>) -> std::io::Result<UartBridge> {
+        let listener = match port {
+            Some(port) => {
+                let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+                listener.set_nonblocking(true)?;
+                info!(target: "sio", "UART is bridged to port {}", port);
+                Some(listener)
+            }
+            None => None,
+        };
+
+        Ok(UartBridge {
+            line: Vec::new(),
+            listener,
+            client: None,
+            received: VecDeque::new(),
+        })
+    }
+
+    // only one client at a time, a new one replaces the old one. checked on every byte in either direction, a game
+    // that only sends never polls for received bytes
+    fn accept(&mut self) {
+        let Some(listener) = &self.listener else {
+            return;
+        };
+
+        if let Ok((client, address)) = listener.accept() {
+            match client.set_nonblocking(true) {
+                Ok(()) => {
+                    info!(target: "sio", "UART client {} connected", address);
+                    self.client = Some(client);
+                }
+                Err(e) => warn!(target: "sio", "Failed to set up the UART client {}: {}", address, e),
+            }
+        }
+    }
+
+    fn drop_client(&mut self, reason: &str) {
+        if self.client.take().is_some() {
+            info!(target: "sio", "UART client disconnected: {}", reason);
+        }
+    }
+}
+
+impl LinkDevice for UartBridge {
+    // normal mode is not passed on
+    fn transfer(&mut self, _data: u32, _bits: u32) -> u32 {
+        u32::MAX
+    }
+
+    fn uart_send(&mut self, byte: u8) {
+        match byte {
+            b'\n' => {
+                info!(target: "sio", "UART: {}", String::from_utf8_lossy(&self.line));
+                self.line.clear();
+            }
+            b'\r' => {}
+            _ => self.line.push(byte),
+        }
+
+        self.accept();
+        if let Some(client) = &mut self.client
+            && let Err(e) = client.write_all(&[byte])
+        {
+            self.drop_client(&e.to_string());
+        }
+    }
+
+    fn uart_receive(&mut self) -> Option<u8> {
+        self.accept();
+
+        if self.received.is_empty()
+            && let Some(client) = &mut self.client
+        {
+            let mut buffer = [0u8; 256];
+            match client.read(&mut buffer) {
+                Ok(0) => self.drop_client("closed"),
+                Ok(size) => self.received.extend(&buffer[..size]),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {}
+                Err(e) => self.drop_client(&e.to_string()),
+            }
+        }
+        self.received.pop_front()
+    }
+}
@@ -0,0 +1,264 @@
+use eframe::egui::Color32;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const XBR_EQUAL_THRESHOLD: i32 = 155; // below that the YUV difference counts as the same color
+
+// smooths the edges while enlarging the frame, runs after the color filters and before the shaders
+pub trait Upscaler {
+    fn factor(&self) -> usize;
+    fn upscale(&self, pixels: &[Color32], width: usize, height: usize) -> Vec<Color32>;
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum UpscalerKind {
+    #[default]
+    None,
+    Scale2x,
+    Scale3x,
+    Xbr2x,
+}
+
+impl UpscalerKind {
+    pub const ALL: [UpscalerKind; 4] = [
+        UpscalerKind::None,
+        UpscalerKind::Scale2x,
+        UpscalerKind::Scale3x,
+        UpscalerKind::Xbr2x,
+    ];
+
+    pub fn upscaler(&self) -> Option<Box<dyn Upscaler>> {
+        match self {
+            UpscalerKind::None => None,
+            UpscalerKind::Scale2x => Some(Box::new(Scale2x)),
+            UpscalerKind::Scale3x => Some(Box::new(Scale3x)),
+            UpscalerKind::Xbr2x => Some(Box::new(Xbr2x)),
+        }
+    }
+}
+
+impl FromStr for UpscalerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(UpscalerKind::None),
+            "scale2x" => Ok(UpscalerKind::Scale2x),
+            "scale3x" => Ok(UpscalerKind::Scale3x),
+            "xbr2x" => Ok(UpscalerKind::Xbr2x),
+            _ => Err(format!(
+                "unknown upscaler '{}', expected none, scale2x, scale3x or xbr2x",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for UpscalerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UpscalerKind::None => write!(f, "None"),
+            UpscalerKind::Scale2x => write!(f, "Scale2x"),
+            UpscalerKind::Scale3x => write!(f, "Scale3x"),
+            UpscalerKind::Xbr2x => write!(f, "xBR 2x"),
+        }
+    }
+}
+
+// pixels outside of the frame repeat the edge
+fn neighbor(pixels: &[Color32], width: usize, height: usize, x: usize, y: usize, dx: isize, dy: isize) -> Color32 {
+    let x = x.saturating_add_signed(dx).min(width - 1);
+    let y = y.saturating_add_signed(dy).min(height - 1);
+    pixels[y * width + x]
+}
+
+// writes the factor × factor block of every source pixel
+fn upscale_blocks<const N: usize>(
+    pixels: &[Color32], width: usize, height: usize, block: impl Fn(usize, usize) -> [Color32; N],
+) -> Vec<Color32> {
+    let factor = N.isqrt();
+    let mut output = vec![Color32::BLACK; pixels.len() * N];
+
+    for y in 0..height {
+        for x in 0..width {
+            for (i, color) in block(x, y).into_iter().enumerate() {
+                output[(y * factor + i / factor) * width * factor + x * factor + i % factor] = color;
+            }
+        }
+    }
+
+    output
+}
+
+// https://www.scale2x.it/algorithm
+pub struct Scale2x;
+
+impl Upscaler for Scale2x {
+    fn factor(&self) -> usize {
+        2
+    }
+
+    fn upscale(&self, pixels: &[Color32], width: usize, height: usize) -> Vec<Color32> {
+        upscale_blocks(pixels, width, height, |x, y| {
+            let at = |dx, dy| neighbor(pixels, width, height, x, y, dx, dy);
+            let (b, d, e, f, h) = (at(0, -1), at(-1, 0), at(0, 0), at(1, 0), at(0, 1));
+
+            if b == h || d == f {
+                return [e; 4];
+            }
+            [
+                if d == b { d } else { e },
+                if b == f { f } else { e },
+                if d == h { d } else { e },
+                if h == f { f } else { e },
+            ]
+        })
+    }
+}
+
+pub struct Scale3x;
+
+impl Upscaler for Scale3x {
+    fn factor(&self) -> usize {
+        3
+    }
+
+    fn upscale(&self, pixels: &[Color32], width: usize, height: usize) -> Vec<Color32> {
+        upscale_blocks(pixels, width, height, |x, y| {
+            let at = |dx, dy| neighbor(pixels, width, height, x, y, dx, dy);
+            let (a, b, c) = (at(-1, -1), at(0, -1), at(1, -1));
+            let (d, e, f) = (at(-1, 0), at(0, 0), at(1, 0));
+            let (g, h, i) = (at(-1, 1), at(0, 1), at(1, 1));
+
+            if b == h || d == f {
+                return [e; 9];
+            }
+            [
+                if d == b { d } else { e },
+                if (d == b && e != c) || (b == f && e != a) { b } else { e },
+                if b == f { f } else { e },
+                if (d == b && e != g) || (d == h && e != a) { d } else { e },
+                e,
+                if (b == f && e != i) || (h == f && e != c) { f } else { e },
+                if d == h { d } else { e },
+                if (d == h && e != i) || (h == f && e != g) { h } else { e },
+                if h == f { f } else { e },
+            ]
+        })
+    }
+}
+
+// Hyllian's xBR, the rules follow the FFmpeg port
+pub struct Xbr2x;
+
+impl Xbr2x {
+    fn yuv(color: Color32) -> [i32; 3] {
+        let [r, g, b] = [color.r(), color.g(), color.b()].map(|c| c as i32);
+        [
+            (299 * r + 587 * g + 114 * b) / 1000,
+            (-169 * r - 331 * g + 500 * b) / 1000 + 128,
+            (500 * r - 419 * g - 81 * b) / 1000 + 128,
+        ]
+    }
+
+    fn diff(a: Color32, b: Color32) -> i32 {
+        let (a, b) = (Self::yuv(a), Self::yuv(b));
+        (0..3).map(|i| (a[i] - b[i]).abs()).sum()
+    }
+
+    fn equal(a: Color32, b: Color32) -> bool {
+        Self::diff(a, b) < XBR_EQUAL_THRESHOLD
+    }
+
+    // the same rules apply to every corner, with the neighborhood rotated by 90° each time
+    fn rotate(quarter_turns: usize, dx: isize, dy: isize) -> (isize, isize) {
+        match quarter_turns {
+            0 => (dx, dy),
+            1 => (dy, -dx),
+            2 => (-dx, -dy),
+            _ => (-dy, dx),
+        }
+    }
+
+    // moves dst towards src by weight / 2^shift
+    fn blend(dst: Color32, src: Color32, weight: i32, shift: u32) -> Color32 {
+        let mix = |d: u8, s: u8| (d as i32 + (((s as i32 - d as i32) * weight) >> shift)) as u8;
+        Color32::from_rgb(mix(dst.r(), src.r()), mix(dst.g(), src.g()), mix(dst.b(), src.b()))
+    }
+
+    // blends the corner of the block that points along the rotated (1, 1) direction, `at` is rotated as well
+    fn corner(
+        block: &mut [Color32; 4], at: impl Fn(isize, isize) -> Color32, quadrant: impl Fn(isize, isize) -> usize,
+    ) {
+        let df = Self::diff;
+        let eq = Self::equal;
+        let (pb, pc) = (at(0, -1), at(1, -1));
+        let (pd, pe, pf) = (at(-1, 0), at(0, 0), at(1, 0));
+        let (pg, ph, pi) = (at(-1, 1), at(0, 1), at(1, 1));
+        let (f4, i4, h5, i5) = (at(2, 0), at(2, 1), at(0, 2), at(1, 2));
+        let (n1, n2, n3) = (quadrant(1, -1), quadrant(-1, 1), quadrant(1, 1));
+
+        if pe == ph || pe == pf {
+            return;
+        }
+
+        // weighted edge strength across and along the diagonal
+        let e = df(pe, pc) + df(pe, pg) + df(pi, h5) + df(pi, f4) + (df(ph, pf) << 2);
+        let i = df(ph, pd) + df(ph, i5) + df(pf, i4) + df(pf, pb) + (df(pe, pi) << 2);
+        if e > i {
+            return;
+        }
+
+        let px = if df(pe, pf) <= df(pe, ph) { pf } else { ph };
+        let sharp =
+            (!eq(pf, pb) && !eq(ph, pd)) || (eq(pe, pi) && !eq(pf, i4) && !eq(ph, i5)) || eq(pe, pg) || eq(pe, pc);
+        if e == i || !sharp {
+            block[n3] = Self::blend(block[n3], px, 1, 1);
+            return;
+        }
+
+        let (ke, ki) = (df(pf, pg), df(ph, pc));
+        let left = ke << 1 <= ki && pe != pg && pd != pg;
+        let up = ke >= ki << 1 && pe != pc && pb != pc;
+        match (left, up) {
+            (true, true) => {
+                block[n3] = Self::blend(block[n3], px, 7, 3);
+                block[n2] = Self::blend(block[n2], px, 1, 2);
+                block[n1] = block[n2];
+            }
+            (true, false) => {
+                block[n3] = Self::blend(block[n3], px, 3, 2);
+                block[n2] = Self::blend(block[n2], px, 1, 2);
+            }
+            (false, true) => {
+                block[n3] = Self::blend(block[n3], px, 3, 2);
+                block[n1] = Self::blend(block[n1], px, 1, 2);
+            }
+            (false, false) => block[n3] = Self::blend(block[n3], px, 1, 1),
+        }
+    }
+}
+
+impl Upscaler for Xbr2x {
+    fn factor(&self) -> usize {
+        2
+    }
+
+    fn upscale(&self, pixels: &[Color32], width: usize, height: usize) -> Vec<Color32> {
+        upscale_blocks(pixels, width, height, |x, y| {
+            let mut block = [neighbor(pixels, width, height, x, y, 0, 0); 4];
+            for quarter_turns in 0..4 {
+                let at = |dx, dy| {
+                    let (dx, dy) = Self::rotate(quarter_turns, dx, dy);
+                    neighbor(pixels, width, height, x, y, dx, dy)
+                };
+                let quadrant = |dx, dy| {
+                    let (dx, dy) = Self::rotate(quarter_turns, dx, dy);
+                    (dy > 0) as usize * 2 + (dx > 0) as usize
+                };
+                Self::corner(&mut block, at, quadrant);
+            }
+            block
+        })
+    }
+}
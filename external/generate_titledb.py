@@ -8,6 +8,14 @@ Reads a MAME GBA software-list XML file and outputs a CSV with:
   - rtc               ("1" if any feature value mentions RTC, else "0")
   - description       (from <description> text)
 
+Optionally also outputs a CSV keyed by the 4 character game code from the serial, with:
+  - game_code         (e.g. "U3IE" from "AGB-U3IE-USA", the first dump of a code wins)
+  - savetype_code     (first feature that maps to a backup type, so peripheral slots resolve to their chip)
+  - rtc
+  - flash_vendor      (from the Flash chip part number, empty if unknown)
+  - quirks            ("|" separated cartridge peripherals, from the slot)
+  - description
+
 Mapping:
   0: No Backup
   1: Eeprom4k
@@ -17,16 +25,35 @@ Mapping:
   5: Flash 1M
 
 Usage:
-  python parse_gba_hash.py <input_xml> <output_csv>
+  python parse_gba_hash.py <input_xml> <output_csv> [<output_gamedb_csv>]
 """
 
 import xml.etree.ElementTree as ET
 import csv
+import re
 import sys
 
 # Order to check features for savetype, lower-case
 SAVETYPE_FEATURE_ORDER = ["slot", "u1", "u2", "savetype", "save"]
 
+# Flash part number prefixes, lower-case
+FLASH_VENDORS = [
+    ("mx29l", "macronix"),
+    ("le26fv", "sanyo"),
+    ("39vf", "sst"),
+    ("39fv", "sst"),
+    ("at29lv", "atmel"),
+    ("mn63f", "panasonic"),
+]
+
+# Cartridge slots with extra hardware
+SLOT_QUIRKS = {
+    "gba_boktai": ["solar_sensor"],
+    "gba_drilldoz": ["rumble"],
+    "gba_wariotws": ["gyro", "rumble"],
+    "gba_yoshiug": ["tilt"],
+}
+
 
 def map_savetype_to_code(savetype_str):
     """
@@ -112,10 +139,71 @@ def parse_gba_xml_to_csv(xml_file_path, csv_file_path):
             writer.writerow([crc, savetype_code, has_rtc, description])
 
 
+def map_flash_vendor(features):
+    for value in features.values():
+        value = value.lower()
+        if 'flash' not in value:
+            continue
+        for prefix, vendor in FLASH_VENDORS:
+            if prefix in value:
+                return vendor
+    return ''
+
+
+def parse_gba_xml_to_gamedb_csv(xml_file_path, csv_file_path):
+    with open(xml_file_path, "r", encoding="utf-8") as f:
+        root = ET.fromstring(f.read())
+
+    seen_codes = set()
+    with open(csv_file_path, "w", newline="", encoding="utf-8") as csvfile:
+        writer = csv.writer(csvfile)
+        writer.writerow(["game_code", "savetype_code", "rtc", "flash_vendor", "quirks", "description"])
+
+        for software in root.findall('.//software'):
+            serials = [info.get('value', '') for info in software.findall('info') if info.get('name') == 'serial']
+            codes = re.findall(r'AGB-([A-Z0-9]{4})', ' '.join(serials))
+            codes = [code for code in dict.fromkeys(codes) if code not in seen_codes]
+            if not codes:
+                continue
+
+            desc_elem = software.find('description')
+            description = ' '.join(desc_elem.text.split()) if desc_elem is not None else ''
+
+            feature_map = {}
+            part = software.find('part')
+            if part is not None:
+                for feat in part.findall('feature'):
+                    name = feat.get('name', '').lower()
+                    val = feat.get('value', '').strip()
+                    if name and val:
+                        feature_map[name] = val
+
+            has_rtc = int(any('rtc' in val.lower() for val in feature_map.values()))
+
+            savetype_code = 0
+            for key in SAVETYPE_FEATURE_ORDER:
+                if key in feature_map:
+                    # FRAM is a drop-in replacement for SRAM
+                    value = feature_map[key]
+                    savetype_code = 3 if 'fram' in value.lower() else map_savetype_to_code(value)
+                    if savetype_code != 0:
+                        break
+
+            flash_vendor = map_flash_vendor(feature_map) if savetype_code in (4, 5) else ''
+            quirks = '|'.join(SLOT_QUIRKS.get(feature_map.get('slot', ''), []))
+
+            for code in codes:
+                seen_codes.add(code)
+                writer.writerow([code, savetype_code, has_rtc, flash_vendor, quirks, description])
+
+
 if __name__ == '__main__':
-    if len(sys.argv) != 3:
-        print(f"Usage: {sys.argv[0]} <input_xml> <output_csv>")
+    if len(sys.argv) not in (3, 4):
+        print(f"Usage: {sys.argv[0]} <input_xml> <output_csv> [<output_gamedb_csv>]")
         sys.exit(1)
 
     input_xml, output_csv = sys.argv[1], sys.argv[2]
     parse_gba_xml_to_csv(input_xml, output_csv)
+
+    if len(sys.argv) == 4:
+        parse_gba_xml_to_gamedb_csv(input_xml, sys.argv[3])
@@ -0,0 +1,91 @@
+use criterion::{BatchSize, Criterion, black_box, criterion_group, criterion_main};
+use gba_core::arm7tdmi::cpu::Cpu;
+use gba_core::arm7tdmi::decoder::Instruction;
+use gba_core::arm7tdmi::mode::ProcessorMode;
+use gba_core::cartridge::storage::BackupType;
+use gba_core::memory::device::AccessKind;
+use gba_core::memory::mmio::Mmio;
+
+const BIOS: &[u8] = include_bytes!("../../external/gba_bios.bin");
+const ARM_TEST: &[u8] = include_bytes!("../../external/gba-tests/arm/arm.gba");
+
+fn cpu(pc: u32, mode: ProcessorMode) -> Cpu {
+    let mut mmio = Mmio::new(BackupType::Sram, false);
+    mmio.load(0x00000000, BIOS);
+    mmio.load(0x08000000, ARM_TEST);
+
+    let mut cpu = Cpu::new(&[], mmio);
+    cpu.registers.r[13] = 0x03007f00;
+    cpu.registers.r[15] = pc;
+    cpu.set_processor_mode(mode);
+    cpu
+}
+
+// the CPU is returned so dropping it is not measured
+fn run(mut cpu: Cpu, ticks: usize) -> Cpu {
+    for _ in 0..ticks {
+        let _ = cpu.tick();
+        cpu.mmio.tick_components();
+    }
+    cpu
+}
+
+fn interpreter(c: &mut Criterion) {
+    // every instruction type, including the block transfers, until the suite reports its result
+    c.bench_function("arm.gba", |b| {
+        b.iter_batched(
+            || cpu(0x08000000, ProcessorMode::System),
+            |cpu| run(cpu, 1900),
+            BatchSize::LargeInput,
+        )
+    });
+
+    // clearing memory and decompressing the logo
+    c.bench_function("bios boot", |b| {
+        b.iter_batched(
+            || cpu(0x00000000, ProcessorMode::Supervisor),
+            |cpu| run(cpu, 100_000),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+// push, pop and ldmia in both instruction sets, the register list used to be a heap allocated Vec
+fn decode(c: &mut Criterion) {
+    let opcodes = [
+        (0xE92D4FF0, false),
+        (0xE8BD8FF0, false),
+        (0xE8900003, false),
+        (0xB5F0, true),
+        (0xBDF0, true),
+        (0xC8FF, true),
+    ];
+
+    c.bench_function("decode block transfers", |b| {
+        b.iter(|| {
+            for (opcode, is_thumb) in opcodes {
+                let _ = black_box(Instruction::decode(black_box(opcode), is_thumb));
+            }
+        })
+    });
+}
+
+// word loads from EWRAM, IWRAM and the ROM and halfword loads from the PPU registers, as the CPU issues them
+fn memory(c: &mut Criterion) {
+    let mut mmio = Mmio::new(BackupType::Sram, false);
+    mmio.load(0x08000000, ARM_TEST);
+
+    c.bench_function("memory reads", |b| {
+        b.iter(|| {
+            for offset in (0..0x400).step_by(4) {
+                black_box(mmio.read::<u32>(0x02000000 + offset, AccessKind::Data));
+                black_box(mmio.read::<u32>(0x03000000 + offset, AccessKind::Data));
+                black_box(mmio.read::<u32>(0x08000000 + offset, AccessKind::Data));
+                black_box(mmio.read::<u16>(0x04000000 + (offset & 0x5E), AccessKind::Data));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, interpreter, decode, memory);
+criterion_main!(benches);
@@ -1,46 +1,101 @@
-use csv::Reader;
+use csv::{Reader, StringRecord};
 
-const TEMPLATE: &str = r#"// This file is automatically generated from the GBA cartridge database CSV file.
+const TEMPLATE: &str = r#"// This file is automatically generated from the GBA cartridge database CSV files.
 // Do not edit this file manually.
 
 use phf::phf_map;
 
-pub static TITLE_DATABASE: phf::Map<&'static str, (u8, bool, &'static str)> = phf_map! {
+// (backup type, has RTC, title, backup type is verified and must not be autodetected)
+pub static TITLE_DATABASE: phf::Map<&'static str, (u8, bool, &'static str, bool)> = phf_map! {
 $DB$
+};
+
+// game code => (backup type, has RTC, flash vendor, "|" separated quirks, title)
+pub static GAME_CODE_DATABASE: phf::Map<&'static str, (u8, bool, &'static str, &'static str, &'static str)> = phf_map! {
+$GAMEDB$
 };"#;
 
+fn read_records(path: &str) -> Vec<StringRecord> {
+    Reader::from_path(path)
+        .unwrap_or_else(|e| panic!("Failed to read GBA cartridge database CSV file {}: {}", path, e))
+        .records()
+        .map(|result| result.expect("Failed to read record from CSV"))
+        .collect()
+}
+
+// (record, comes from the overrides), keyed by the first column
+fn merge_records(database: &str, overrides: &str) -> Vec<(StringRecord, bool)> {
+    // hand-maintained corrections for titles where the MAME database or backup type detection is wrong
+    let mut overrides = read_records(overrides);
+
+    let mut entries = Vec::new();
+    for record in read_records(database) {
+        match overrides.iter().position(|o| o.get(0) == record.get(0)) {
+            Some(index) => entries.push((overrides.remove(index), true)),
+            None => entries.push((record, false)),
+        }
+    }
+    // overrides for titles that are missing from the MAME database
+    entries.extend(overrides.into_iter().map(|record| (record, true)));
+    entries
+}
+
+fn field<'a>(record: &'a StringRecord, index: usize, name: &str) -> &'a str {
+    record
+        .get(index)
+        .unwrap_or_else(|| panic!("Missing {} in record: {:?}", name, record))
+}
+
 fn main() {
-    let mut csv_reader =
-        Reader::from_path("../external/titledb_mame.csv").expect("Failed to read GBA cartridge database CSV file");
+    let entries = merge_records("../external/titledb_mame.csv", "../external/titledb_overrides.csv");
 
     let mut table = String::new();
 
-    for result in csv_reader.records().skip(1) {
-        let record = result.expect("Failed to read record from CSV");
-        let crc32 = record
-            .get(0)
-            .unwrap_or_else(|| panic!("Missing crc32 in record: {:?}", record));
-        let backup_type = record
-            .get(1)
-            .unwrap_or_else(|| panic!("Missing backup_type in record: {:?}", record));
-        let has_rtc = record
-            .get(2)
-            .unwrap_or_else(|| panic!("Missing has_rtc in record: {:?}", record));
-        let game_title = record
-            .get(3)
-            .unwrap_or_else(|| panic!("Missing game_title in record: {:?}", record));
-
+    for (record, verified) in entries {
         table.push_str(&format!(
-            "    \"{}\" => ({}, {}, r#\"{}\"#),\n",
-            crc32,
-            backup_type,
-            if has_rtc == "1" { "true" } else { "false" },
-            game_title
+            "    \"{}\" => ({}, {}, r#\"{}\"#, {}),\n",
+            field(&record, 0, "crc32"),
+            field(&record, 1, "backup_type"),
+            if field(&record, 2, "has_rtc") == "1" {
+                "true"
+            } else {
+                "false"
+            },
+            field(&record, 3, "game_title"),
+            verified
         ));
     }
 
-    let output = TEMPLATE.replace("$DB$", &table);
-    std::fs::write("src/cartridge/database.rs", output).expect("Failed to write cartridge database source file");
+    let mut game_table = String::new();
+
+    for (record, _) in merge_records("../external/gamedb_mame.csv", "../external/gamedb_overrides.csv") {
+        game_table.push_str(&format!(
+            "    \"{}\" => ({}, {}, \"{}\", \"{}\", r#\"{}\"#),\n",
+            field(&record, 0, "game_code"),
+            field(&record, 1, "backup_type"),
+            if field(&record, 2, "has_rtc") == "1" {
+                "true"
+            } else {
+                "false"
+            },
+            field(&record, 3, "flash_vendor"),
+            field(&record, 4, "quirks"),
+            field(&record, 5, "game_title"),
+        ));
+    }
+
+    let output = TEMPLATE.replace("$DB$", &table).replace("$GAMEDB$", &game_table);
+    std::fs::write("src/cartridge/database/generated.rs", output)
+        .expect("Failed to write cartridge database source file");
+
+    // the BIOS is optional, without one the BIOS functions are emulated
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is not set");
+    let bios = std::fs::read("../external/gba_bios.bin").unwrap_or_default();
+    std::fs::write(format!("{}/gba_bios.bin", out_dir), bios).expect("Failed to write BIOS image");
 
+    println!("cargo:rerun-if-changed=../external/gba_bios.bin");
     println!("cargo:rerun-if-changed=../external/titledb_mame.csv");
+    println!("cargo:rerun-if-changed=../external/titledb_overrides.csv");
+    println!("cargo:rerun-if-changed=../external/gamedb_mame.csv");
+    println!("cargo:rerun-if-changed=../external/gamedb_overrides.csv");
 }
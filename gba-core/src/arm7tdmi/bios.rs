@@ -0,0 +1,478 @@
+use super::cpu::Cpu;
+use super::decoder::Register;
+use crate::memory::device::{AccessKind, BusValue};
+use crate::memory::registers::RamResetFlags;
+use std::f32::consts::PI;
+use tracing::*;
+
+// placed at 0x00 when there is no BIOS image, only the IRQ vector is needed as the SWIs never reach the BIOS
+pub const HLE_BIOS: [u32; 12] = [
+    0xEAFFFFFE, // 0x00: reset, b 0x00
+    0xEAFFFFFE, // 0x04: undefined instruction, b 0x04
+    0xEAFFFFFE, // 0x08: software interrupt, b 0x08
+    0xEAFFFFFE, // 0x0C: prefetch abort, b 0x0C
+    0xEAFFFFFE, // 0x10: data abort, b 0x10
+    0xEAFFFFFE, // 0x14: reserved, b 0x14
+    0xE92D500F, // 0x18: irq, stmfd sp!, {r0-r3, r12, lr}
+    0xE3A00301, // mov r0, #0x04000000
+    0xE28FE000, // add lr, pc, #0
+    0xE510F004, // ldr pc, [r0, #-4] (user handler at 0x03007FFC)
+    0xE8BD500F, // ldmfd sp!, {r0-r3, r12, lr}
+    0xE25EF004, // subs pc, lr, #4
+];
+
+// BIOS interrupt flags, acknowledged by the game's IRQ handler for IntrWait
+const INTR_CHECK: u32 = 0x03007FF8;
+
+pub enum SwiResult {
+    Return,  // continue after the SWI
+    Restart, // the CPU halted, execute the SWI again once an interrupt woke it up
+    Jump,    // the function set the pc itself
+}
+
+// high level emulation of the BIOS calls, used when there is no BIOS image
+// https://problemkaputt.de/gbatek.htm#biosfunctions
+pub fn software_interrupt(cpu: &mut Cpu, number: u8) -> SwiResult {
+    let r0 = cpu.read_register(&Register::R0);
+    let r1 = cpu.read_register(&Register::R1);
+    let r2 = cpu.read_register(&Register::R2);
+    let r3 = cpu.read_register(&Register::R3);
+
+    match number {
+        0x00 => {
+            cpu.soft_reset();
+            return SwiResult::Jump;
+        }
+        0x01 => cpu.mmio.register_ram_reset(RamResetFlags::from_bits_truncate(r0 as u8)),
+        0x02 => {
+            cpu.mmio.io_halt_cnt.set(0x00);
+        }
+        0x04 => return intr_wait(cpu, r0 != 0, r1 as u16),
+        0x05 => return intr_wait(cpu, true, 0x0001),
+        0x06 => div(cpu, r0 as i32, r1 as i32),
+        0x07 => div(cpu, r1 as i32, r0 as i32),
+        0x08 => cpu.write_register(&Register::R0, (r0 as f64).sqrt() as u32),
+        0x09 => cpu.write_register(&Register::R0, arc_tan(r0 as i32) as u32),
+        0x0A => cpu.write_register(&Register::R0, arc_tan2(r0 as i32, r1 as i32) as u16 as u32),
+        0x0B => cpu_set(cpu, r0, r1, r2),
+        0x0C => cpu_fast_set(cpu, r0, r1, r2),
+        0x0E => bg_affine_set(cpu, r0, r1, r2),
+        0x0F => obj_affine_set(cpu, r0, r1, r2, r3),
+        0x10 => bit_unpack(cpu, r0, r1, r2),
+        0x11..=0x18 => {
+            // (decompressed data, size of the writes)
+            let (data, unit) = match number {
+                0x11 => (lz77_uncomp(cpu, r0), 1),
+                0x12 => (lz77_uncomp(cpu, r0), 2),
+                0x13 => (huff_uncomp(cpu, r0), 4),
+                0x14 => (rl_uncomp(cpu, r0), 1),
+                0x15 => (rl_uncomp(cpu, r0), 2),
+                0x16 => (diff_unfilter(cpu, r0, 1), 1),
+                0x17 => (diff_unfilter(cpu, r0, 1), 2),
+                _ => (diff_unfilter(cpu, r0, 2), 2),
+            };
+            write_output(cpu, r1, &data, unit);
+        }
+        0x25 => {
+            // MultiBoot: there is never a slave on the other end of the link cable,
+            // so fail right away instead of running the BIOS transfer loop
+            warn!(target: "cpu", "MultiBoot called without a link partner, reporting failure");
+            cpu.write_register(&Register::R0, 1);
+        }
+        _ => error!(target: "cpu", "BIOS function {:02X} is not emulated, ignoring it", number),
+    }
+
+    SwiResult::Return
+}
+
+// https://problemkaputt.de/gbatek.htm#biosinterruptfunctions
+fn intr_wait(cpu: &mut Cpu, discard: bool, flags: u16) -> SwiResult {
+    cpu.mmio.write::<u16>(0x04000208, 1, AccessKind::Data);
+
+    let intr_check = cpu.mmio.read::<u16>(INTR_CHECK, AccessKind::Data);
+    if !discard && intr_check & flags != 0 {
+        cpu.mmio.write::<u16>(INTR_CHECK, intr_check & !flags, AccessKind::Data);
+        return SwiResult::Return;
+    }
+
+    if discard {
+        cpu.mmio.write::<u16>(INTR_CHECK, intr_check & !flags, AccessKind::Data);
+    }
+
+    // the flags are only discarded on the first call, the restarted SWI only checks them
+    cpu.write_register(&Register::R0, 0);
+    cpu.write_register(&Register::R1, flags as u32);
+    cpu.mmio.io_halt_cnt.set(0x00);
+    SwiResult::Restart
+}
+
+// https://problemkaputt.de/gbatek.htm#biosarithmeticfunctions
+fn div(cpu: &mut Cpu, numerator: i32, denominator: i32) {
+    if denominator == 0 {
+        // the BIOS never returns, keep going with something sane instead
+        error!(target: "cpu", "Division of {} by zero", numerator);
+        let quotient = if numerator < 0 { -1i32 } else { 1 };
+        cpu.write_register(&Register::R0, quotient as u32);
+        cpu.write_register(&Register::R1, numerator as u32);
+        cpu.write_register(&Register::R3, 1);
+        return;
+    }
+
+    let quotient = numerator.wrapping_div(denominator);
+    cpu.write_register(&Register::R0, quotient as u32);
+    cpu.write_register(&Register::R1, numerator.wrapping_rem(denominator) as u32);
+    cpu.write_register(&Register::R3, quotient.unsigned_abs());
+}
+
+// same polynomial as the BIOS so the results match bit for bit
+fn arc_tan(tan: i32) -> i16 {
+    let a = -(tan.wrapping_mul(tan) >> 14);
+    let mut b = (0xA9i32.wrapping_mul(a) >> 14) + 0x390;
+    for constant in [0x91C, 0xFB6, 0x16AA, 0x2081, 0x3651, 0xA2F9] {
+        b = (b.wrapping_mul(a) >> 14) + constant;
+    }
+    (tan.wrapping_mul(b) >> 16) as i16
+}
+
+// full circle, 0x0000..=0xFFFF
+fn arc_tan2(x: i32, y: i32) -> i32 {
+    let tan = |a: i32, b: i32| arc_tan(a.wrapping_shl(14).wrapping_div(b)) as i32;
+
+    match (x, y) {
+        (x, 0) => {
+            if x >= 0 {
+                0
+            } else {
+                0x8000
+            }
+        }
+        (0, y) => {
+            if y >= 0 {
+                0x4000
+            } else {
+                0xC000
+            }
+        }
+        (x, y) if y >= 0 => {
+            if x >= 0 && x >= y {
+                tan(y, x)
+            } else if x < 0 && -x >= y {
+                tan(y, x) + 0x8000
+            } else {
+                0x4000 - tan(x, y)
+            }
+        }
+        (x, y) => {
+            if x <= 0 && -x > -y {
+                tan(y, x) + 0x8000
+            } else if x > 0 && x >= -y {
+                tan(y, x) + 0x10000
+            } else {
+                0xC000 - tan(x, y)
+            }
+        }
+    }
+}
+
+// https://problemkaputt.de/gbatek.htm#biosmemorycopy
+fn cpu_set(cpu: &mut Cpu, src: u32, dst: u32, control: u32) {
+    let count = control & 0x1FFFFF;
+    let fill = control & (1 << 24) != 0;
+    let words = control & (1 << 26) != 0;
+
+    if words {
+        copy::<u32>(cpu, src & !3, dst & !3, count, fill);
+    } else {
+        copy::<u16>(cpu, src & !1, dst & !1, count, fill);
+    }
+}
+
+// always copies words, in blocks of 8
+fn cpu_fast_set(cpu: &mut Cpu, src: u32, dst: u32, control: u32) {
+    let count = ((control & 0x1FFFFF) + 7) & !7;
+    let fill = control & (1 << 24) != 0;
+
+    copy::<u32>(cpu, src & !3, dst & !3, count, fill);
+}
+
+fn copy<T: BusValue>(cpu: &mut Cpu, src: u32, dst: u32, count: u32, fill: bool) {
+    let size = size_of::<T>() as u32;
+    let value = cpu.mmio.read::<T>(src, AccessKind::Data);
+
+    for i in 0..count {
+        let value = if fill {
+            value
+        } else {
+            cpu.mmio.read::<T>(src + i * size, AccessKind::Data)
+        };
+        cpu.mmio.write::<T>(dst + i * size, value, AccessKind::Data);
+    }
+}
+
+// rotation angle in the upper byte, a full circle is 0x100
+fn angle(value: u16) -> f32 {
+    (value >> 8) as f32 / 128.0 * PI
+}
+
+// https://problemkaputt.de/gbatek.htm#biosrotationscalingfunctions
+fn bg_affine_set(cpu: &mut Cpu, mut src: u32, mut dst: u32, count: u32) {
+    for _ in 0..count {
+        let origin_x = cpu.mmio.read::<u32>(src, AccessKind::Data) as i32 as f32 / 256.0;
+        let origin_y = cpu.mmio.read::<u32>(src + 4, AccessKind::Data) as i32 as f32 / 256.0;
+        let center_x = cpu.mmio.read::<u16>(src + 8, AccessKind::Data) as i16 as f32;
+        let center_y = cpu.mmio.read::<u16>(src + 10, AccessKind::Data) as i16 as f32;
+        let scale_x = cpu.mmio.read::<u16>(src + 12, AccessKind::Data) as i16 as f32 / 256.0;
+        let scale_y = cpu.mmio.read::<u16>(src + 14, AccessKind::Data) as i16 as f32 / 256.0;
+        let theta = angle(cpu.mmio.read::<u16>(src + 16, AccessKind::Data));
+        src += 20;
+
+        let (sin, cos) = theta.sin_cos();
+        let (pa, pb, pc, pd) = (cos * scale_x, -sin * scale_x, sin * scale_y, cos * scale_y);
+
+        // start of the scanline so the center of the screen ends up at the origin
+        let start_x = origin_x - (pa * center_x + pb * center_y);
+        let start_y = origin_y - (pc * center_x + pd * center_y);
+
+        for (offset, value) in [pa, pb, pc, pd].into_iter().enumerate() {
+            cpu.mmio
+                .write::<u16>(dst + offset as u32 * 2, (value * 256.0) as i32 as u16, AccessKind::Data);
+        }
+        cpu.mmio
+            .write::<u32>(dst + 8, (start_x * 256.0) as i32 as u32, AccessKind::Data);
+        cpu.mmio
+            .write::<u32>(dst + 12, (start_y * 256.0) as i32 as u32, AccessKind::Data);
+        dst += 16;
+    }
+}
+
+// stride is 2 for BG parameters and 8 to write straight into OAM
+fn obj_affine_set(cpu: &mut Cpu, mut src: u32, mut dst: u32, count: u32, stride: u32) {
+    for _ in 0..count {
+        let scale_x = cpu.mmio.read::<u16>(src, AccessKind::Data) as i16 as f32 / 256.0;
+        let scale_y = cpu.mmio.read::<u16>(src + 2, AccessKind::Data) as i16 as f32 / 256.0;
+        let theta = angle(cpu.mmio.read::<u16>(src + 4, AccessKind::Data));
+        src += 8;
+
+        let (sin, cos) = theta.sin_cos();
+        let parameters = [cos * scale_x, -sin * scale_x, sin * scale_y, cos * scale_y];
+
+        for (i, value) in parameters.into_iter().enumerate() {
+            cpu.mmio
+                .write::<u16>(dst + i as u32 * stride, (value * 256.0) as i32 as u16, AccessKind::Data);
+        }
+        dst += stride * 4;
+    }
+}
+
+// https://problemkaputt.de/gbatek.htm#biosdecompressionfunctions
+fn bit_unpack(cpu: &mut Cpu, mut src: u32, mut dst: u32, info: u32) {
+    let mut length = cpu.mmio.read::<u16>(info, AccessKind::Data);
+    let src_width = cpu.mmio.read::<u8>(info + 2, AccessKind::Data) as u32;
+    let dst_width = cpu.mmio.read::<u8>(info + 3, AccessKind::Data) as u32;
+    let offset = cpu.mmio.read::<u32>(info + 4, AccessKind::Data);
+
+    if ![1, 2, 4, 8].contains(&src_width) || ![1, 2, 4, 8, 16, 32].contains(&dst_width) {
+        error!(target: "cpu", "BitUnPack with invalid widths {} => {}", src_width, dst_width);
+        return;
+    }
+
+    let mut data = 0u32;
+    let mut bits_left = 0;
+    let mut output = 0u32;
+    let mut bits_written = 0;
+
+    while length > 0 || bits_left > 0 {
+        if bits_left == 0 {
+            data = cpu.mmio.read::<u8>(src, AccessKind::Data) as u32;
+            bits_left = 8;
+            src += 1;
+            length -= 1;
+        }
+
+        let mut value = data & ((1 << src_width) - 1);
+        data >>= src_width;
+        bits_left -= src_width;
+
+        // bit 31 also adds the offset to zero values
+        if value != 0 || offset & 0x80000000 != 0 {
+            value = value.wrapping_add(offset & 0x7FFFFFFF);
+        }
+
+        output |= value.wrapping_shl(bits_written);
+        bits_written += dst_width;
+        if bits_written == 32 {
+            cpu.mmio.write::<u32>(dst, output, AccessKind::Data);
+            dst += 4;
+            output = 0;
+            bits_written = 0;
+        }
+    }
+}
+
+// the decompressed data is buffered, the VRAM variants only differ in writing halfwords as VRAM ignores byte writes
+fn write_output(cpu: &mut Cpu, dst: u32, data: &[u8], unit: usize) {
+    for (i, chunk) in data.chunks(unit).enumerate() {
+        let addr = dst + (i * unit) as u32;
+        let value = chunk.iter().rev().fold(0u32, |value, &byte| (value << 8) | byte as u32);
+        match unit {
+            1 => cpu.mmio.write::<u8>(addr, value as u8, AccessKind::Data),
+            2 => cpu.mmio.write::<u16>(addr, value as u16, AccessKind::Data),
+            _ => cpu.mmio.write::<u32>(addr, value, AccessKind::Data),
+        }
+    }
+}
+
+// bits 4-7 hold the compression type, bits 8-31 the decompressed size
+fn decompressed_size(cpu: &mut Cpu, src: u32) -> usize {
+    (cpu.mmio.read::<u32>(src, AccessKind::Data) >> 8) as usize
+}
+
+fn lz77_uncomp(cpu: &mut Cpu, mut src: u32) -> Vec<u8> {
+    let size = decompressed_size(cpu, src);
+    let mut output = Vec::with_capacity(size);
+    src += 4;
+
+    while output.len() < size {
+        let flags = cpu.mmio.read::<u8>(src, AccessKind::Data);
+        src += 1;
+
+        // one flag per block, most significant bit first
+        for bit in (0..8).rev() {
+            if output.len() >= size {
+                break;
+            }
+
+            if flags & (1 << bit) == 0 {
+                output.push(cpu.mmio.read::<u8>(src, AccessKind::Data));
+                src += 1;
+                continue;
+            }
+
+            let high = cpu.mmio.read::<u8>(src, AccessKind::Data) as usize;
+            let low = cpu.mmio.read::<u8>(src + 1, AccessKind::Data) as usize;
+            src += 2;
+
+            let length = (high >> 4) + 3;
+            let distance = (((high & 0xF) << 8) | low) + 1;
+            if distance > output.len() {
+                error!(target: "cpu", "LZ77 data refers to {} bytes before the start of the output", distance);
+                return output;
+            }
+
+            // the copy may overlap with the bytes it produces
+            for _ in 0..length.min(size - output.len()) {
+                output.push(output[output.len() - distance]);
+            }
+        }
+    }
+
+    output
+}
+
+fn huff_uncomp(cpu: &mut Cpu, src: u32) -> Vec<u8> {
+    let size = decompressed_size(cpu, src);
+    let data_bits = cpu.mmio.read::<u8>(src, AccessKind::Data) as u32 & 0xF;
+    if data_bits != 4 && data_bits != 8 {
+        error!(target: "cpu", "Huffman data with {} bits per value is not supported", data_bits);
+        return Vec::new();
+    }
+
+    let tree_size = (cpu.mmio.read::<u8>(src + 4, AccessKind::Data) as u32 + 1) * 2;
+    let root = src + 5;
+    let mut stream = src + 4 + tree_size;
+
+    let mut output = Vec::with_capacity(size);
+    let mut word = 0u32;
+    let mut word_bits = 0;
+    let mut node_addr = root;
+    let mut node = cpu.mmio.read::<u8>(root, AccessKind::Data);
+
+    while output.len() < size {
+        // the bitstream is read in words, most significant bit first
+        let bits = cpu.mmio.read::<u32>(stream, AccessKind::Data);
+        stream += 4;
+
+        for bit in (0..32).rev() {
+            // bits 0-5 are the offset to the children, bit 7/6 mark child 0/1 as data
+            let children = (node_addr & !1) + (node & 0x3F) as u32 * 2 + 2;
+            let (child, is_data) = if bits & (1 << bit) == 0 {
+                (children, node & 0x80 != 0)
+            } else {
+                (children + 1, node & 0x40 != 0)
+            };
+
+            if !is_data {
+                node_addr = child;
+                node = cpu.mmio.read::<u8>(child, AccessKind::Data);
+                continue;
+            }
+
+            // values are packed into words starting at the least significant bits
+            let value = cpu.mmio.read::<u8>(child, AccessKind::Data) as u32;
+            word |= value << word_bits;
+            word_bits += data_bits;
+            if word_bits == 32 {
+                output.extend_from_slice(&word.to_le_bytes());
+                word = 0;
+                word_bits = 0;
+                if output.len() >= size {
+                    break;
+                }
+            }
+
+            node_addr = root;
+            node = cpu.mmio.read::<u8>(root, AccessKind::Data);
+        }
+    }
+
+    output.truncate(size);
+    output
+}
+
+fn rl_uncomp(cpu: &mut Cpu, mut src: u32) -> Vec<u8> {
+    let size = decompressed_size(cpu, src);
+    let mut output = Vec::with_capacity(size);
+    src += 4;
+
+    while output.len() < size {
+        let flag = cpu.mmio.read::<u8>(src, AccessKind::Data);
+        src += 1;
+
+        if flag & 0x80 != 0 {
+            // run of a single byte
+            let length = (flag & 0x7F) as usize + 3;
+            let value = cpu.mmio.read::<u8>(src, AccessKind::Data);
+            src += 1;
+            output.extend(std::iter::repeat_n(value, length));
+        } else {
+            let length = (flag & 0x7F) as u32 + 1;
+            for i in 0..length {
+                output.push(cpu.mmio.read::<u8>(src + i, AccessKind::Data));
+            }
+            src += length;
+        }
+    }
+
+    output.truncate(size);
+    output
+}
+
+// every unit is stored as the difference to the previous one
+fn diff_unfilter(cpu: &mut Cpu, src: u32, unit: u32) -> Vec<u8> {
+    let size = decompressed_size(cpu, src);
+    let mut output = Vec::with_capacity(size);
+    let mut value = 0u16;
+
+    for i in 0..(size as u32).div_ceil(unit) {
+        let addr = src + 4 + i * unit;
+        if unit == 1 {
+            value = (value as u8).wrapping_add(cpu.mmio.read::<u8>(addr, AccessKind::Data)) as u16;
+            output.push(value as u8);
+        } else {
+            value = value.wrapping_add(cpu.mmio.read::<u16>(addr, AccessKind::Data));
+            output.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    output
+}
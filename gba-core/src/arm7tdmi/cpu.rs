@@ -1,14 +1,18 @@
-use super::decoder::{Instruction, Register};
+use super::decoder::{Condition, Instruction, Register, RegisterList};
+use super::history::History;
 use super::mode::ProcessorMode;
 use super::pipeline::{Pipeline, State};
 use super::registers::{Psr, Registers};
+use super::swi_log::SwiLog;
 use super::symbolizer::Symbolizer;
+use super::trace::StepTrace;
 use crate::arm7tdmi::decoder::Opcode;
 use crate::arm7tdmi::error::CpuError;
 use crate::arm7tdmi::handlers::Handlers;
-use crate::memory::device::IoRegister;
+use crate::memory::device::{AccessKind, IoRegister};
 use crate::memory::mmio::Mmio;
 use crate::memory::registers::Interrupt;
+use std::cell::Cell;
 use std::fmt::Display;
 use tracing::*;
 
@@ -16,7 +20,12 @@ pub struct Cpu {
     pub registers: Registers,
     pub pipeline: Pipeline,
     pub mmio: Mmio,
+    pub history: Option<History>,
+    pub hle_bios: bool,          // SWIs are handled without running BIOS code
+    pub swi_log: Option<SwiLog>, // BIOS calls, only recorded when enabled
     symbolizer: Symbolizer,
+    registers_read: Cell<RegisterList>, // r0-r15 accessed by the current instruction
+    registers_written: RegisterList,
 }
 
 impl Cpu {
@@ -25,11 +34,18 @@ impl Cpu {
             registers: Registers::default(),
             pipeline: Pipeline::new(),
             mmio,
+            history: None,
+            hle_bios: false,
+            swi_log: None,
             symbolizer: Symbolizer::new(buffer),
+            registers_read: Cell::new(RegisterList::default()),
+            registers_written: RegisterList::default(),
         }
     }
 
     pub fn tick(&mut self) -> Result<(Instruction, State), CpuError> {
+        self.record_history();
+
         let IoRegister(ime_value) = self.mmio.io_ime;
         let IoRegister(halt_cnt) = self.mmio.io_halt_cnt;
 
@@ -43,19 +59,18 @@ impl Cpu {
         self.pipeline.advance(self.get_pc(), self.is_thumb(), &mut self.mmio);
         trace!(target: "pipeline", "Pipeline: {}", self.pipeline);
 
-        let vblank_available =
-            self.mmio.io_if.contains_flags(Interrupt::VBLANK) && self.mmio.io_ie.contains_flags(Interrupt::VBLANK);
-        let hblank_available =
-            self.mmio.io_if.contains_flags(Interrupt::HBLANK) && self.mmio.io_ie.contains_flags(Interrupt::HBLANK);
+        let mut irq_available = self.mmio.io_if.value().intersects(*self.mmio.io_ie.value());
+
+        // STOP mode can only be left through the keypad, GamePak or serial interrupt
+        if halt_cnt == 0x80 {
+            let wake_up = Interrupt::KEYPAD | Interrupt::GAMEPAK | Interrupt::SERIAL;
+            irq_available = (*self.mmio.io_if.value() & *self.mmio.io_ie.value()).intersects(wake_up);
+        }
 
         // we need to make sure the pipeline is full before we trigger an IRQ
         // the IRQ always returns using subs pc, lr, #4, so if the pipeline has been flushed recently
         // PC = current instruction, so on return we get current instruction - 4 which is behind the current instruction
-        if ime_value != 0
-            && (vblank_available || hblank_available)
-            && !self.registers.cpsr.contains(Psr::I)
-            && self.pipeline.is_full()
-        {
+        if ime_value != 0 && irq_available && !self.registers.cpsr.contains(Psr::I) && self.pipeline.is_full() {
             trace!(target: "irq", "IRQ available, switching to IRQ mode");
 
             // copy CPSR to SPSR and switch to IRQ mode
@@ -85,7 +100,6 @@ impl Cpu {
             return Err(CpuError::InterruptTriggered);
         }
 
-        // TODO: 0x80 is STOP MODE, it should be handled differently
         // We need to check this AFTER the IRQ check, or else we will never enter
         // another IRQ during halt
         if halt_cnt == 0 {
@@ -93,7 +107,27 @@ impl Cpu {
             return Err(CpuError::CpuPaused);
         }
 
+        if halt_cnt == 0x80 {
+            trace!(target: "cpu", "CPU is stopped");
+            return Err(CpuError::CpuPaused);
+        }
+
         if let Some((instruction, state)) = self.pipeline.pop() {
+            // undefined instructions take the UND exception, and are reported to the caller
+            let Ok(instruction) = instruction else {
+                // a failed condition skips them like any other instruction, NV never executes on the ARM7
+                let executes = self.is_thumb()
+                    || Condition::from(state.opcode >> 28).is_ok_and(|c| Handlers::check_condition(self, &c));
+                if !executes {
+                    self.registers.r[15] += 4;
+                    return Err(CpuError::NothingToDo);
+                }
+
+                warn!(target: "cpu", "Undefined instruction {:08X} @ {:08X}", state.opcode, state.pc);
+                self.undefined_instruction();
+                return Err(CpuError::FailedToDecode);
+            };
+
             self.symbolizer.find(state.pc).map(|symbol| {
                 trace!(target: "symbols", "Found matching symbols @ PC: {}", symbol.join(", "));
             });
@@ -116,6 +150,14 @@ impl Cpu {
 
             // clear the last read/write addresses
             self.mmio.last_rw_addr.clear();
+            self.mmio.pc = state.pc;
+
+            // only track side effects of the instruction itself, not of the fetch
+            self.registers_read.set(RegisterList::default());
+            self.registers_written = RegisterList::default();
+            if let Some(accesses) = &mut self.mmio.access_log {
+                accesses.clear();
+            }
 
             match instruction.opcode {
                 Opcode::B | Opcode::Bl | Opcode::Bx => Handlers::branch(&instruction, self),
@@ -148,6 +190,9 @@ impl Cpu {
                 | Opcode::Smull
                 | Opcode::Smlal => Handlers::alu(&instruction, self),
                 Opcode::Swi => Handlers::software_interrupt(&instruction, self),
+                Opcode::Cdp | Opcode::Ldc | Opcode::Stc | Opcode::Mrc | Opcode::Mcr => {
+                    Handlers::coprocessor(&instruction, self)
+                }
             }
 
             trace!(target: "cpu", "\n{}", self);
@@ -173,21 +218,157 @@ impl Cpu {
         Err(CpuError::NothingToDo)
     }
 
+    // executes a single tick like `tick`, but also reports the registers and memory that were accessed
+    pub fn step_traced(&mut self) -> Result<StepTrace, CpuError> {
+        let registers_before = self.registers.r;
+        let cycles_before = self.mmio.cycles;
+        let logging = self.mmio.access_log.is_some(); // script watchpoints keep the log enabled
+        self.mmio.access_log = Some(Vec::new());
+
+        let result = self.tick();
+        let memory = match logging {
+            true => self.mmio.access_log.clone().unwrap_or_default(),
+            false => self.mmio.access_log.take().unwrap_or_default(),
+        };
+        let (instruction, state) = result?;
+
+        // handlers also write some registers directly (e.g. PC on exceptions), so include anything that changed
+        let changed = (0..16).filter(|&i| registers_before[i] != self.registers.r[i]);
+        let written = RegisterList(changed.fold(self.registers_written.0, |mask, i| mask | (1 << i)));
+
+        Ok(StepTrace {
+            instruction,
+            state,
+            registers_read: self.registers_read.get(),
+            registers_written: written
+                .iter()
+                .map(|register| (register, self.read_register(&register)))
+                .collect(),
+            memory,
+            cycles: self.mmio.cycles - cycles_before,
+        })
+    }
+
+    pub fn load_symbols(&mut self, buffer: &[u8]) {
+        self.symbolizer.merge(Symbolizer::new(buffer));
+    }
+
+    pub fn enable_history(&mut self, capacity: usize) {
+        self.history = Some(History::new(capacity));
+    }
+
+    fn record_history(&mut self) {
+        if let Some(mut history) = self.history.take() {
+            history.record(self);
+            self.history = Some(history);
+        }
+    }
+
+    // rewinds the last tick, including the components ticked after it
+    pub fn step_back(&mut self) -> bool {
+        let Some(mut history) = self.history.take() else {
+            return false;
+        };
+        let Some((checkpoint, ticks)) = history.rewind() else {
+            self.history = Some(history);
+            return false;
+        };
+
+        // the logs and the audio output already saw the replayed ticks, the samples queued now are kept
+        let output = self.mmio.apu.take_output();
+        checkpoint.restore(self);
+
+        let swi_log = self.swi_log.take();
+        let io_journal = self.mmio.io_journal.take();
+        let heat_map = self.mmio.heat_map.take();
+        for _ in 0..ticks {
+            let _ = self.tick();
+            self.mmio.tick_components();
+        }
+        self.swi_log = swi_log;
+        self.mmio.io_journal = io_journal;
+        self.mmio.heat_map = heat_map;
+        self.mmio.apu.restore_output(output);
+
+        self.history = Some(history);
+        true
+    }
+
+    // state the BIOS hands over to the cartridge entry point
     pub fn skip_bios(&mut self) {
-        // Initialize CPU state (post BIOS)
         self.set_processor_mode(ProcessorMode::Irq);
         self.write_register(&Register::R13, 0x03007fa0);
+        self.write_register(&Register::R14, 0);
+        self.write_to_spsr(ProcessorMode::Irq, Psr::empty());
         self.set_processor_mode(ProcessorMode::Supervisor);
         self.write_register(&Register::R13, 0x03007fe0);
-        self.set_processor_mode(ProcessorMode::User);
+        self.write_register(&Register::R14, 0);
+        self.write_to_spsr(ProcessorMode::Supervisor, Psr::empty());
+
+        // system mode in ARM state with IRQs enabled and r0-r12 cleared
+        self.registers.cpsr = Psr::from_bits_truncate(ProcessorMode::System as u32);
+        self.registers.r[0..=12].fill(0);
+        let entry = self.boot_entry();
         self.write_register(&Register::R13, 0x03007f00);
-        self.set_processor_mode(ProcessorMode::System);
+        self.write_register(&Register::R14, entry);
+        self.write_register(&Register::R15, entry);
+        self.mmio.skip_bios();
+    }
+
+    // https://problemkaputt.de/gbatek.htm#biosfunctions (SWI 00h)
+    pub fn soft_reset(&mut self) {
+        let entry = self.boot_entry();
+        debug!(target: "cpu", "SoftReset, returning to {:08X}", entry);
+
+        // clears the stacks and the BIOS IRQ vector/flags
+        self.mmio.internal_memory[0x03007E00..=0x03007FFF].fill(0);
+
+        self.set_processor_mode(ProcessorMode::Supervisor);
+        self.write_register(&Register::R13, 0x03007fe0);
+        self.write_register(&Register::R14, 0);
+        self.write_to_spsr(ProcessorMode::Supervisor, Psr::empty());
+        self.set_processor_mode(ProcessorMode::Irq);
+        self.write_register(&Register::R13, 0x03007fa0);
+        self.write_register(&Register::R14, 0);
+        self.write_to_spsr(ProcessorMode::Irq, Psr::empty());
+
+        // enter system mode in ARM state with r0-r12 cleared
+        self.registers.cpsr = Psr::from_bits_truncate(ProcessorMode::System as u32);
+        self.registers.r[0..=12].fill(0);
         self.write_register(&Register::R13, 0x03007f00);
-        self.write_register(&Register::R14, 0x08000000);
-        self.write_register(&Register::R15, 0x08000000);
-        self.mmio.io_postflg.write(0x01);
-        self.mmio.openbus_bios = 0xE129F000; // initial openbus value after BIOS execution
-        self.mmio.disable_bios_access();
+        self.write_register(&Register::R14, entry);
+        self.write_register(&Register::R15, entry);
+        self.mmio.io_halt_cnt.set(0xff);
+    }
+
+    // https://problemkaputt.de/gbatek.htm#armcpuexceptions (Undefined Instruction)
+    pub fn undefined_instruction(&mut self) {
+        let pc = self.get_pc();
+        let cpsr = self.read_register(&Register::Cpsr);
+
+        // the link register points to the instruction after the undefined one
+        let addr_next_instr = pc - if self.is_thumb() { 2 } else { 4 };
+
+        // switch to undefined mode and save the cpsr in spsr_und
+        self.set_processor_mode(ProcessorMode::Undefined);
+        self.write_register(&Register::Spsr, cpsr);
+        self.write_register(&Register::R14, addr_next_instr);
+
+        // disable interrupts, switch to ARM and jump to the UND vector
+        self.registers.cpsr.set(Psr::I, true);
+        self.registers.cpsr.set(Psr::T, false);
+        self.registers.r[15] = 0x04;
+
+        self.pipeline.flush();
+    }
+
+    // 0x03007FFA: 0x00 = return to ROM, anything else = return to EWRAM
+    fn boot_entry(&mut self) -> u32 {
+        if self.mmio.read::<u8>(0x03007FFA, AccessKind::Data) == 0 {
+            0x08000000
+        } else {
+            0x02000000
+        }
     }
 
     fn compact_registers(&self) -> String {
@@ -221,6 +402,10 @@ impl Cpu {
     }
 
     pub fn read_register_for_mode(&self, register: &Register, mode: ProcessorMode) -> u32 {
+        let mut registers_read = self.registers_read.get();
+        registers_read.insert(register);
+        self.registers_read.set(registers_read);
+
         match register {
             Register::R0 => self.registers.r[0],
             Register::R1 => self.registers.r[1],
@@ -286,6 +471,8 @@ impl Cpu {
     }
 
     pub fn write_register_for_mode(&mut self, register: &Register, value: u32, mode: ProcessorMode) {
+        self.registers_written.insert(register);
+
         match register {
             Register::R0 => self.registers.r[0] = value,
             Register::R1 => self.registers.r[1] = value,
@@ -433,13 +620,13 @@ impl Cpu {
     pub fn push_stack(&mut self, value: u32) {
         let sp = self.get_sp();
         let addr = sp.wrapping_sub(4);
-        self.mmio.write_u32(addr, value);
+        self.mmio.write::<u32>(addr, value, AccessKind::Data);
         self.write_register(&Register::R13, addr);
     }
 
     pub fn pop_stack(&mut self) -> u32 {
         let sp = self.get_sp();
-        let value = self.mmio.read_u32(sp);
+        let value = self.mmio.read::<u32>(sp, AccessKind::Data);
         self.write_register(&Register::R13, sp.wrapping_add(4));
         value
     }
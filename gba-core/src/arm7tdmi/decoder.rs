@@ -1,7 +1,8 @@
+use super::error::DecodeError;
 use bitmatch::bitmatch;
 use std::fmt::Display;
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum Condition {
     Equal,
     NotEqual,
@@ -21,7 +22,7 @@ pub enum Condition {
 }
 
 impl Condition {
-    pub fn from(value: u32) -> Result<Condition, String> {
+    pub fn from(value: u32) -> Result<Condition, DecodeError> {
         match value {
             0b0000 => Ok(Condition::Equal),
             0b0001 => Ok(Condition::NotEqual),
@@ -38,7 +39,7 @@ impl Condition {
             0b1100 => Ok(Condition::GreaterThan),
             0b1101 => Ok(Condition::LessThanOrEqual),
             0b1110 => Ok(Condition::Always),
-            _ => Err(format!("Unknown condition code: {:b}", value)),
+            _ => Err(DecodeError::UnknownCondition(value)),
         }
     }
 }
@@ -95,7 +96,7 @@ pub enum Register {
 }
 
 impl Register {
-    pub fn from(value: u32) -> Result<Register, String> {
+    pub fn from(value: u32) -> Result<Register, DecodeError> {
         match value {
             0b0000 => Ok(Register::R0),
             0b0001 => Ok(Register::R1),
@@ -113,9 +114,15 @@ impl Register {
             0b1101 => Ok(Register::R13),
             0b1110 => Ok(Register::R14),
             0b1111 => Ok(Register::R15),
-            _ => Err(format!("Unknown register code: {:b}", value)),
+            _ => Err(DecodeError::UnknownRegister(value)),
         }
     }
+
+    // index of a general purpose register (r0-r15), none for the PSRs
+    pub fn index(&self) -> Option<usize> {
+        let index = *self as usize;
+        (index < 16).then_some(index)
+    }
 }
 
 #[derive(PartialEq, Debug, Copy, Clone)]
@@ -133,7 +140,7 @@ impl Display for ShiftSource {
     }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum ShiftType {
     LogicalLeft(ShiftSource),
     LogicalRight(ShiftSource),
@@ -143,7 +150,7 @@ pub enum ShiftType {
 }
 
 impl ShiftType {
-    pub fn from(shift_type: u32, value: ShiftSource) -> Result<ShiftType, String> {
+    pub fn from(shift_type: u32, value: ShiftSource) -> Result<ShiftType, DecodeError> {
         // The form of the shift field which might be expected to give
         // ROR #0 is used to encode a special function of the barrel
         // shifter, rotate right extended (RRX). This instruction rotates
@@ -175,7 +182,7 @@ impl ShiftType {
                 ShiftSource::Immediate(i) => Ok(ShiftType::RotateRight(ShiftSource::Immediate(i))),
                 ShiftSource::Register(_) => Ok(ShiftType::RotateRight(value)),
             },
-            _ => Err(format!("Unknown shift type: {}", shift_type)),
+            _ => Err(DecodeError::UnknownShiftType(shift_type)),
         }
     }
 }
@@ -192,12 +199,46 @@ impl Display for ShiftType {
     }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+// r0-r15 as used by LDM/STM and PUSH/POP, bit n = rn
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Default)]
+pub struct RegisterList(pub u16);
+
+impl RegisterList {
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn contains(&self, register: &Register) -> bool {
+        register.index().is_some_and(|i| self.0 & (1 << i) != 0)
+    }
+
+    pub fn insert(&mut self, register: &Register) {
+        if let Some(i) = register.index() {
+            self.0 |= 1 << i;
+        }
+    }
+
+    // registers in ascending order, which is also the order they are transferred in
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Register> + use<> {
+        let mask = self.0;
+        (0..16)
+            .filter(move |i| mask & (1 << i) != 0)
+            .map(|i| Register::from(i).unwrap())
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum Operand {
     Immediate(u32, Option<ShiftType>),
     Offset(i32),
     Register(Register, Option<ShiftType>),
-    RegisterList(Vec<Register>),
+    RegisterList(RegisterList),
+    Coprocessor(u32, u32, u32), // number, opcode 1, opcode 2
+    CoprocessorRegister(u32),
 }
 
 impl Operand {
@@ -209,7 +250,7 @@ impl Operand {
     }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum Opcode {
     B,
     Bl,
@@ -251,6 +292,11 @@ pub enum Opcode {
     Smlal,
     Neg,
     Swp,
+    Cdp,
+    Ldc,
+    Stc,
+    Mrc,
+    Mcr,
 }
 
 impl Opcode {
@@ -261,6 +307,13 @@ impl Opcode {
     pub fn is_load_store(&self) -> bool {
         *self == Opcode::Ldr || *self == Opcode::Str
     }
+
+    pub fn is_coprocessor(&self) -> bool {
+        matches!(
+            self,
+            Opcode::Cdp | Opcode::Ldc | Opcode::Stc | Opcode::Mrc | Opcode::Mcr
+        )
+    }
 }
 
 impl Display for Opcode {
@@ -306,11 +359,16 @@ impl Display for Opcode {
             Opcode::Smlal => write!(f, "smlal"),
             Opcode::Neg => write!(f, "neg"),
             Opcode::Swp => write!(f, "swp"),
+            Opcode::Cdp => write!(f, "cdp"),
+            Opcode::Ldc => write!(f, "ldc"),
+            Opcode::Stc => write!(f, "stc"),
+            Opcode::Mrc => write!(f, "mrc"),
+            Opcode::Mcr => write!(f, "mcr"),
         }
     }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum TransferLength {
     Byte,
     HalfWord,
@@ -348,7 +406,7 @@ pub enum Indexing {
     Post,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Instruction {
     pub opcode: Opcode,
     pub condition: Condition,
@@ -366,7 +424,7 @@ pub struct Instruction {
 
 #[allow(unused_variables)]
 impl Instruction {
-    pub fn decode(opcode: u32, is_thumb: bool) -> Result<Instruction, String> {
+    pub fn decode(opcode: u32, is_thumb: bool) -> Result<Instruction, DecodeError> {
         if is_thumb {
             Instruction::decode_thumb(opcode)
         } else {
@@ -374,6 +432,21 @@ impl Instruction {
         }
     }
 
+    pub fn swi_number(&self, is_thumb: bool) -> Option<u8> {
+        match self {
+            Instruction {
+                opcode: Opcode::Swi,
+                operand1: Some(Operand::Immediate(value, None)),
+                ..
+            } => {
+                // the comment field holds the BIOS function, in ARM state it's stored in bits 16-23
+                let value = if is_thumb { *value } else { *value >> 16 };
+                Some((value & 0xFF) as u8)
+            }
+            _ => None,
+        }
+    }
+
     pub fn nop() -> Instruction {
         Instruction {
             opcode: Opcode::Mov,
@@ -384,7 +457,7 @@ impl Instruction {
     }
 
     #[bitmatch]
-    fn decode_armv4t(opcode: u32) -> Result<Instruction, String> {
+    fn decode_armv4t(opcode: u32) -> Result<Instruction, DecodeError> {
         #[bitmatch]
         match opcode {
             // Software Interrupt (SWI) [also known as Supervisor Call (SVC)]
@@ -554,7 +627,7 @@ impl Instruction {
                         (0, 1) => Some(TransferLength::HalfWord), // unsigned
                         (1, 0) => Some(TransferLength::Byte),     // signed
                         (1, 1) => Some(TransferLength::HalfWord), // signed
-                        _ => return Err("Invalid transfer length for LDRH/STRH".to_string()),
+                        _ => return Err(DecodeError::InvalidEncoding("transfer length for LDRH/STRH")),
                     },
                     signed_transfer: s == 1,
                     offset_direction: if u == 1 {
@@ -625,7 +698,7 @@ impl Instruction {
                                 (1, 1, 1) => Register::SpsrFlagControl,
                                 (0, 1, 1) => Register::CpsrFlagControl,
                                 (0, 0, 0) => Register::PsrNone,
-                                _ => Err(format!("Invalid PSR transfer destination: d={}, f={}, x={}", d, f, x))?,
+                                _ => Err(DecodeError::InvalidEncoding("PSR transfer destination"))?,
                             };
 
                             let operand2 = if i == 1 {
@@ -741,7 +814,7 @@ impl Instruction {
                     condition,
                     set_psr_flags: s == 1,
                     operand1: Some(Operand::Register(base_register, None)),
-                    operand2: Some(Operand::RegisterList(Instruction::extract_register_list(r)?)),
+                    operand2: Some(Operand::RegisterList(RegisterList(r as u16))),
                     indexing: if p == 1 {
                         Some(Indexing::Pre)
                     } else {
@@ -809,12 +882,68 @@ impl Instruction {
                     ..Instruction::default()
                 })
             }
-            _ => Err(format!("Unknown instruction: {:08X} | {:032b}", opcode, opcode)),
+            // Coprocessor Data Transfers (LDC, STC)
+            "cccc_110p_unwl_bbbb_dddd_kkkk_oooo_oooo" => {
+                // the GBA has no coprocessors, these are only decoded so they can raise UND
+                let condition = Condition::from(c)?;
+                let base_register = Register::from(b)?;
+
+                Ok(Instruction {
+                    opcode: if l == 1 { Opcode::Ldc } else { Opcode::Stc },
+                    condition,
+                    operand1: Some(Operand::Coprocessor(k, 0, 0)),
+                    operand2: Some(Operand::CoprocessorRegister(d)),
+                    operand3: Some(Operand::Register(base_register, None)),
+                    operand4: Some(Operand::Immediate(o << 2, None)),
+                    offset_direction: if u == 1 {
+                        Some(Direction::Up)
+                    } else {
+                        Some(Direction::Down)
+                    },
+                    indexing: if p == 1 {
+                        Some(Indexing::Pre)
+                    } else {
+                        Some(Indexing::Post)
+                    },
+                    writeback: w == 1,
+                    ..Instruction::default()
+                })
+            }
+            // Coprocessor Register Transfers (MRC, MCR)
+            "cccc_1110_aaal_nnnn_dddd_kkkk_bbb1_mmmm" => {
+                let condition = Condition::from(c)?;
+                let register = Register::from(d)?;
+
+                Ok(Instruction {
+                    opcode: if l == 1 { Opcode::Mrc } else { Opcode::Mcr },
+                    condition,
+                    operand1: Some(Operand::Coprocessor(k, a, b)),
+                    operand2: Some(Operand::Register(register, None)),
+                    operand3: Some(Operand::CoprocessorRegister(n)),
+                    operand4: Some(Operand::CoprocessorRegister(m)),
+                    ..Instruction::default()
+                })
+            }
+            // Coprocessor Data Operations (CDP)
+            "cccc_1110_aaaa_nnnn_dddd_kkkk_bbb0_mmmm" => {
+                let condition = Condition::from(c)?;
+
+                Ok(Instruction {
+                    opcode: Opcode::Cdp,
+                    condition,
+                    operand1: Some(Operand::Coprocessor(k, a, b)),
+                    operand2: Some(Operand::CoprocessorRegister(d)),
+                    operand3: Some(Operand::CoprocessorRegister(n)),
+                    operand4: Some(Operand::CoprocessorRegister(m)),
+                    ..Instruction::default()
+                })
+            }
+            _ => Err(DecodeError::UnknownArmInstruction(opcode)),
         }
     }
 
     #[bitmatch]
-    fn decode_thumb(opcode: u32) -> Result<Instruction, String> {
+    fn decode_thumb(opcode: u32) -> Result<Instruction, DecodeError> {
         #[bitmatch]
         match opcode & 0xffff {
             // add/subtract
@@ -844,7 +973,7 @@ impl Instruction {
                     0b00 => Opcode::Lsl,
                     0b01 => Opcode::Lsr,
                     0b10 => Opcode::Asr,
-                    _ => return Err("Invalid shift type for move shifted register".to_string()),
+                    _ => return Err(DecodeError::InvalidEncoding("shift type for move shifted register")),
                 };
                 let operand1 = Register::from(d)?;
                 let operand2 = Register::from(s)?;
@@ -866,7 +995,9 @@ impl Instruction {
                     0b01 => Opcode::Cmp,
                     0b10 => Opcode::Add,
                     0b11 => Opcode::Sub,
-                    _ => Err("Invalid opcode for move/compare/add/subtract immediate")?,
+                    _ => Err(DecodeError::InvalidEncoding(
+                        "opcode for move/compare/add/subtract immediate",
+                    ))?,
                 };
                 let operand1 = Register::from(r)?;
                 let operand2 = Operand::Immediate(i, None);
@@ -979,7 +1110,7 @@ impl Instruction {
                     ),
                     (0b11, 0, 0) => (Opcode::Bx, Some(Operand::Register(Register::from(s)?, None)), None),
                     (0b11, 0, 1) => (Opcode::Bx, Some(Operand::Register(Register::from(8 + s)?, None)), None),
-                    _ => Err("Invalid opcode for Hi register operations")?,
+                    _ => Err(DecodeError::InvalidEncoding("opcode for Hi register operations"))?,
                 };
 
                 // Note: In this group only CMP (Op = 01) sets the CPSR
@@ -1058,7 +1189,9 @@ impl Instruction {
                         (0, 1) => Some(TransferLength::HalfWord),
                         (1, 0) => Some(TransferLength::Byte),
                         (1, 1) => Some(TransferLength::HalfWord),
-                        _ => Err("Invalid transfer length for load/store sign-extended byte/halfword")?,
+                        _ => Err(DecodeError::InvalidEncoding(
+                            "transfer length for load/store sign-extended byte/halfword",
+                        ))?,
                     },
                     offset_direction: Some(Direction::Up),
                     indexing: Some(Indexing::Pre),
@@ -1136,19 +1269,19 @@ impl Instruction {
             }
             // Push and Pop
             "1011_l10r_xxxx_xxxx" => {
-                let mut registers = Instruction::extract_register_list(x)?;
+                let mut registers = RegisterList(x as u16);
                 let opcode = match (l, r) {
                     (0, 0) => Opcode::Push,
                     (0, 1) => {
-                        registers.push(Register::R14);
+                        registers.insert(&Register::R14);
                         Opcode::Push
                     }
                     (1, 0) => Opcode::Pop,
                     (1, 1) => {
-                        registers.push(Register::R15);
+                        registers.insert(&Register::R15);
                         Opcode::Pop
                     }
-                    _ => Err("Invalid opcode for Push/Pop")?,
+                    _ => Err(DecodeError::InvalidEncoding("opcode for Push/Pop"))?,
                 };
 
                 Ok(Instruction {
@@ -1167,7 +1300,7 @@ impl Instruction {
                 condition: Condition::Always,
                 set_psr_flags: false,
                 operand1: Some(Operand::Register(Register::from(b)?, None)),
-                operand2: Some(Operand::RegisterList(Instruction::extract_register_list(r)?)),
+                operand2: Some(Operand::RegisterList(RegisterList(r as u16))),
                 operand3: None,
                 indexing: Some(Indexing::Post),
                 offset_direction: Some(Direction::Up),
@@ -1234,15 +1367,11 @@ impl Instruction {
                     ..Instruction::default()
                 })
             }
-            _ => Err(format!(
-                "Unknown instruction: {:04X} | {:016b}",
-                opcode & 0xffff,
-                opcode & 0xffff
-            )),
+            _ => Err(DecodeError::UnknownThumbInstruction((opcode & 0xffff) as u16)),
         }
     }
 
-    fn translate_opcode_armv4t(opcode: u32) -> Result<Opcode, String> {
+    fn translate_opcode_armv4t(opcode: u32) -> Result<Opcode, DecodeError> {
         match opcode {
             0b0000 => Ok(Opcode::And),
             0b0001 => Ok(Opcode::Eor),
@@ -1260,11 +1389,11 @@ impl Instruction {
             0b1101 => Ok(Opcode::Mov),
             0b1110 => Ok(Opcode::Bic),
             0b1111 => Ok(Opcode::Mvn),
-            _ => Err(format!("Unknown opcode: {:04b}", opcode)),
+            _ => Err(DecodeError::UnknownOpcode(opcode)),
         }
     }
 
-    fn translate_opcode_thumb(opcode: u32) -> Result<Opcode, String> {
+    fn translate_opcode_thumb(opcode: u32) -> Result<Opcode, DecodeError> {
         match opcode {
             0b0000 => Ok(Opcode::And),
             0b0001 => Ok(Opcode::Eor),
@@ -1282,18 +1411,8 @@ impl Instruction {
             0b1101 => Ok(Opcode::Mul),
             0b1110 => Ok(Opcode::Bic),
             0b1111 => Ok(Opcode::Mvn),
-            _ => Err(format!("Unknown opcode: {:04b}", opcode)),
-        }
-    }
-
-    fn extract_register_list(value: u32) -> Result<Vec<Register>, String> {
-        let mut registers = Vec::new();
-        for i in 0..16 {
-            if value & (1 << i) != 0 {
-                registers.push(Register::from(i as u32)?);
-            }
+            _ => Err(DecodeError::UnknownOpcode(opcode)),
         }
-        Ok(registers)
     }
 }
 
@@ -1428,6 +1547,53 @@ impl Display for Instruction {
                 )?;
                 write!(f, ", [{}]", self.operand3.as_ref().unwrap())?;
             }
+            Opcode::Cdp | Opcode::Mrc | Opcode::Mcr => {
+                let Some(Operand::Coprocessor(_, opcode1, opcode2)) = self.operand1 else {
+                    unreachable!()
+                };
+
+                write!(
+                    f,
+                    "{}{} {}, {}, {}, {}, {}, {}",
+                    self.opcode,
+                    self.condition,
+                    self.operand1.as_ref().unwrap(),
+                    opcode1,
+                    self.operand2.as_ref().unwrap(),
+                    self.operand3.as_ref().unwrap(),
+                    self.operand4.as_ref().unwrap(),
+                    opcode2
+                )?;
+            }
+            Opcode::Ldc | Opcode::Stc => {
+                write!(
+                    f,
+                    "{}{} {}, {}, ",
+                    self.opcode,
+                    self.condition,
+                    self.operand1.as_ref().unwrap(),
+                    self.operand2.as_ref().unwrap()
+                )?;
+
+                if self.indexing == Some(Indexing::Pre) {
+                    write!(
+                        f,
+                        "[{}, {}{}]{}",
+                        self.operand3.as_ref().unwrap(),
+                        self.offset_direction.as_ref().unwrap(),
+                        self.operand4.as_ref().unwrap(),
+                        if self.writeback { "!" } else { "" }
+                    )?;
+                } else {
+                    write!(
+                        f,
+                        "[{}], {}{}",
+                        self.operand3.as_ref().unwrap(),
+                        self.offset_direction.as_ref().unwrap(),
+                        self.operand4.as_ref().unwrap()
+                    )?;
+                }
+            }
             // Opcode::Add | Opcode::Sub
             //     if let Some(Operand::Register(reg, None)) = &self.operand2
             //         && *reg == Register::R15 =>
@@ -1516,13 +1682,17 @@ impl Display for Operand {
             Operand::Offset(value) if *value < 0 => write!(f, "-0x{:04X}", -1 * value),
             Operand::Offset(value) => write!(f, "0x{:04X}", value),
             Operand::RegisterList(registers) => {
-                let output = registers
-                    .iter()
-                    .map(|r| format!("{}", r))
-                    .collect::<Vec<String>>()
-                    .join(", ");
-                write!(f, "{{{}}}", output)
+                write!(f, "{{")?;
+                for (i, register) in registers.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", register)?;
+                }
+                write!(f, "}}")
             }
+            Operand::Coprocessor(number, _, _) => write!(f, "p{}", number),
+            Operand::CoprocessorRegister(register) => write!(f, "c{}", register),
             _ => panic!("Unknown operand type"),
         }
     }
@@ -11,3 +11,21 @@ pub enum CpuError {
     #[error("CPU has nothing to do")]
     NothingToDo,
 }
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+pub enum DecodeError {
+    #[error("Unknown condition code: {0:04b}")]
+    UnknownCondition(u32),
+    #[error("Unknown register code: {0:04b}")]
+    UnknownRegister(u32),
+    #[error("Unknown shift type: {0}")]
+    UnknownShiftType(u32),
+    #[error("Unknown opcode: {0:04b}")]
+    UnknownOpcode(u32),
+    #[error("Invalid {0}")]
+    InvalidEncoding(&'static str),
+    #[error("Unknown instruction: {0:08X} | {0:032b}")]
+    UnknownArmInstruction(u32),
+    #[error("Unknown instruction: {0:04X} | {0:016b}")]
+    UnknownThumbInstruction(u16),
+}
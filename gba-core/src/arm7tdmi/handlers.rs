@@ -1,8 +1,11 @@
+use super::bios::{self, SwiResult};
 use super::cpu::Cpu;
-use super::decoder::{Condition, Instruction, Opcode, Operand, ShiftSource, ShiftType};
+use super::decoder::{Condition, Instruction, Opcode, Operand, RegisterList, ShiftSource, ShiftType};
 use super::registers::Psr;
+use super::swi_log::swi_name;
 use crate::arm7tdmi::decoder::{Direction, Indexing, Register, TransferLength};
 use crate::arm7tdmi::mode::ProcessorMode;
+use crate::memory::device::AccessKind;
 use tracing::*;
 
 macro_rules! check_condition {
@@ -96,6 +99,41 @@ impl Handlers {
                 ..
             } => {
                 let pc = cpu.get_pc();
+                let number = instr.swi_number(cpu.is_thumb());
+                let addr = pc - if cpu.is_thumb() { 4 } else { 8 };
+
+                if let Some(number) = number {
+                    let args: [u32; 4] = cpu.registers.r[0..4].try_into().unwrap();
+                    debug!(target: "cpu",
+                        "SWI {:02X} ({}) from {:08X}, r0-r3: {:08X?}",
+                        number,
+                        swi_name(number),
+                        addr,
+                        args
+                    );
+                    if let Some(log) = &mut cpu.swi_log {
+                        log.record(number, addr, args);
+                    }
+                }
+
+                match number {
+                    Some(number) if cpu.hle_bios => {
+                        cpu.registers.r[15] = match bios::software_interrupt(cpu, number) {
+                            SwiResult::Return => pc - if cpu.is_thumb() { 2 } else { 4 },
+                            SwiResult::Restart => {
+                                if let Some(log) = &mut cpu.swi_log {
+                                    log.restart(addr);
+                                }
+                                addr
+                            }
+                            SwiResult::Jump => cpu.registers.r[15],
+                        };
+                        cpu.pipeline.flush();
+                        return;
+                    }
+                    _ => {}
+                }
+
                 cpu.registers.r[15] = 0x08;
 
                 // cache the current program status register
@@ -120,6 +158,15 @@ impl Handlers {
         cpu.pipeline.flush();
     }
 
+    pub fn coprocessor(instr: &Instruction, cpu: &mut Cpu) {
+        check_condition!(cpu, instr);
+
+        // there are no coprocessors attached, so every coprocessor instruction takes the UND exception
+        warn!(target: "cpu", "Coprocessor instruction without coprocessor: {}", instr);
+
+        cpu.undefined_instruction();
+    }
+
     pub fn push_pop(instr: &Instruction, cpu: &mut Cpu) {
         check_condition!(cpu, instr);
 
@@ -131,11 +178,11 @@ impl Handlers {
             } => {
                 let current_sp = cpu.read_register(&Register::R13);
                 for register in registers.iter().rev() {
-                    if *register == Register::R13 {
+                    if register == Register::R13 {
                         // If the stack pointer is pushed, we need to push the original stack pointer
                         cpu.push_stack(current_sp);
                     } else {
-                        cpu.push_stack(cpu.read_register(register));
+                        cpu.push_stack(cpu.read_register(&register));
                     }
                 }
             }
@@ -144,9 +191,9 @@ impl Handlers {
                 operand1: Some(Operand::RegisterList(registers)),
                 ..
             } => {
-                for register in registers {
+                for register in registers.iter() {
                     let value = cpu.pop_stack();
-                    cpu.write_register(register, value);
+                    cpu.write_register(&register, value);
                 }
             }
             _ => todo!("{:?}", instr),
@@ -317,10 +364,15 @@ impl Handlers {
                             // The LDRSB instruction loads the selected Byte into bits 7
                             // to 0 of the destination register and bits 31 to 8 of the desti-
                             // nation register are set to the value of bit 7, the sign bit.
-                            let value = cpu.mmio.read(aligned_address).rotate_right(rotation);
+                            let value = cpu
+                                .mmio
+                                .read::<u8>(aligned_address, AccessKind::Data)
+                                .rotate_right(rotation);
                             value as i8 as u32
                         } else {
-                            cpu.mmio.read(aligned_address).rotate_right(rotation) as u32
+                            cpu.mmio
+                                .read::<u8>(aligned_address, AccessKind::Data)
+                                .rotate_right(rotation) as u32
                         };
 
                         cpu.write_register(dst, value as u32);
@@ -336,7 +388,7 @@ impl Handlers {
                             // bits 15 to 0 of the destination register and bits 31 to 16 of
                             // the destination register are set to the value of bit 15, the
                             // sign bit.
-                            let value = cpu.mmio.read_u16(aligned_address) as u32;
+                            let value = cpu.mmio.read::<u16>(aligned_address, AccessKind::Data) as u32;
                             let value = value.rotate_right(rotation);
                             let sign_bit = value & (1 << 15);
                             if sign_bit != 0 {
@@ -349,11 +401,11 @@ impl Handlers {
                             // On ARM7 aka ARMv4 aka NDS7/GBA:
                             //   LDRH Rd,[odd]   -->  LDRH Rd,[odd-1] ROR 8  ;read to bit0-7 and bit24-31
                             //   LDRSH Rd,[odd]  -->  LDRSB Rd,[odd]         ;sign-expand BYTE value
-                            let value = cpu.mmio.read(address); // Bits 0-7
+                            let value = cpu.mmio.read::<u8>(address, AccessKind::Data); // Bits 0-7
                             // TODO: value as i8 as u32
                             value as u32
                         } else {
-                            let value = cpu.mmio.read_u16(aligned_address) as u32;
+                            let value = cpu.mmio.read::<u16>(aligned_address, AccessKind::Data) as u32;
                             value.rotate_right(rotation)
                         };
 
@@ -365,7 +417,10 @@ impl Handlers {
                         }
                     }
                     TransferLength::Word => {
-                        let value = cpu.mmio.read_u32(aligned_address).rotate_right(rotation);
+                        let value = cpu
+                            .mmio
+                            .read::<u32>(aligned_address, AccessKind::Data)
+                            .rotate_right(rotation);
                         cpu.write_register(dst, value);
 
                         if *set_psr_flags {
@@ -421,7 +476,7 @@ impl Handlers {
                 match length {
                     TransferLength::Byte => {
                         let value = cpu_read_reg(src) as u8;
-                        cpu.mmio.write(address, value);
+                        cpu.mmio.write::<u8>(address, value, AccessKind::Data);
                         if *set_psr_flags {
                             cpu.update_flag(Psr::N, value & 0x80 != 0);
                             cpu.update_flag(Psr::Z, value == 0);
@@ -431,7 +486,7 @@ impl Handlers {
                         address &= !0b01; // align address
 
                         let value = cpu_read_reg(src) as u16;
-                        cpu.mmio.write_u16(address, value);
+                        cpu.mmio.write::<u16>(address, value, AccessKind::Data);
                         if *set_psr_flags {
                             cpu.update_flag(Psr::N, value & 0x8000 != 0);
                             cpu.update_flag(Psr::Z, value == 0);
@@ -441,7 +496,7 @@ impl Handlers {
                         address &= !0b11; // align address
 
                         let value = cpu_read_reg(src);
-                        cpu.mmio.write_u32(address, value);
+                        cpu.mmio.write::<u32>(address, value, AccessKind::Data);
                         if *set_psr_flags {
                             cpu.update_flag(Psr::N, value & 0x8000_0000 != 0);
                             cpu.update_flag(Psr::Z, value == 0);
@@ -484,8 +539,8 @@ impl Handlers {
                 };
 
                 let original_value = match length {
-                    TransferLength::Byte => cpu.mmio.read(aligned_addr) as u32,
-                    TransferLength::Word => cpu.mmio.read_u32(aligned_addr),
+                    TransferLength::Byte => cpu.mmio.read::<u8>(aligned_addr, AccessKind::Data) as u32,
+                    TransferLength::Word => cpu.mmio.read::<u32>(aligned_addr, AccessKind::Data),
                     _ => unreachable!(),
                 }
                 .rotate_right(rotation);
@@ -493,11 +548,11 @@ impl Handlers {
                 match length {
                     TransferLength::Byte => {
                         let value = cpu.read_register(src) as u8;
-                        cpu.mmio.write(aligned_addr, value);
+                        cpu.mmio.write::<u8>(aligned_addr, value, AccessKind::Data);
                     }
                     TransferLength::Word => {
                         let value = cpu.read_register(src);
-                        cpu.mmio.write_u32(aligned_addr, value);
+                        cpu.mmio.write::<u32>(aligned_addr, value, AccessKind::Data);
                     }
                     _ => unreachable!(),
                 }
@@ -525,9 +580,9 @@ impl Handlers {
                 let registers = if registers.is_empty() {
                     // Empty Rlist: R15 loaded/stored (ARMv4 only), and Rb=Rb+/-40h (ARMv4-v5).
                     // http://problemkaputt.de/gbatek-arm-opcodes-memory-block-data-transfer-ldm-stm.htm
-                    vec![Register::R15]
+                    RegisterList(1 << 15)
                 } else {
-                    registers.clone()
+                    *registers
                 };
 
                 let mut address = original_base;
@@ -545,8 +600,8 @@ impl Handlers {
                 }
 
                 for register in registers.iter() {
-                    let value = cpu.mmio.read_u32(address & !0b11);
-                    cpu_write_register(cpu, register, value);
+                    let value = cpu.mmio.read::<u32>(address & !0b11, AccessKind::Data);
+                    cpu_write_register(cpu, &register, value);
                     address = address.wrapping_add(4);
                 }
 
@@ -572,9 +627,9 @@ impl Handlers {
                 let registers = if registers.is_empty() {
                     // Empty Rlist: R15 loaded/stored (ARMv4 only), and Rb=Rb+/-40h (ARMv4-v5).
                     // http://problemkaputt.de/gbatek-arm-opcodes-memory-block-data-transfer-ldm-stm.htm
-                    vec![Register::R15]
+                    RegisterList(1 << 15)
                 } else {
-                    registers.clone()
+                    *registers
                 };
 
                 let mut address = original_base;
@@ -596,24 +651,24 @@ impl Handlers {
                     (Direction::Down, _) => original_base.wrapping_sub(total_transfer_size),
                 };
 
-                let base_index = registers.iter().position(|&r| r == *dst_base);
+                let base_index = registers.iter().position(|r| r == *dst_base);
 
                 for (i, register) in registers.iter().enumerate() {
-                    let value = if *register == *dst_base {
+                    let value = if register == *dst_base {
                         if base_index == Some(0) || !writeback {
                             original_base
                         } else {
                             final_address
                         }
-                    } else if *register == Register::R15 {
-                        cpu.read_register(register) + 4
+                    } else if register == Register::R15 {
+                        cpu.read_register(&register) + 4
                     } else if *set_psr_flags {
-                        cpu.read_register_for_mode(register, ProcessorMode::User)
+                        cpu.read_register_for_mode(&register, ProcessorMode::User)
                     } else {
-                        cpu.read_register(register)
+                        cpu.read_register(&register)
                     };
 
-                    cpu.mmio.write_u32(address & !0b11, value);
+                    cpu.mmio.write::<u32>(address & !0b11, value, AccessKind::Data);
                     address = address.wrapping_add(4);
                 }
 
@@ -1588,7 +1643,7 @@ impl Handlers {
         }
     }
 
-    fn check_condition(cpu: &Cpu, condition: &Condition) -> bool {
+    pub(crate) fn check_condition(cpu: &Cpu, condition: &Condition) -> bool {
         match condition {
             Condition::Always => true,
             Condition::Equal => cpu.registers.cpsr.contains(Psr::Z), // Z == 1
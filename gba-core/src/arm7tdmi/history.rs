@@ -0,0 +1,79 @@
+use super::cpu::Cpu;
+use crate::gba::SaveState;
+use crate::input::registers::KeyInput;
+use std::collections::VecDeque;
+
+// stepping back replays at most this many ticks
+const CHECKPOINT_INTERVAL: u64 = 4096;
+
+// savestates taken while running, a tick is undone by loading the last one before it and running up to it again
+pub struct History {
+    checkpoints: VecDeque<(u64, KeyInput, SaveState)>, // (tick, pressed keys, state before it)
+    ticks: u64,
+    keys: KeyInput, // a replay only reproduces the ticks after a checkpoint if the input stays the same
+    capacity: usize,
+    spare: Option<SaveState>, // the last evicted checkpoint, the next one is captured into it
+}
+
+impl History {
+    pub fn new(capacity: usize) -> History {
+        History {
+            checkpoints: VecDeque::new(),
+            ticks: 0,
+            keys: KeyInput::empty(),
+            capacity,
+            spare: None,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    // called before every tick
+    pub fn record(&mut self, cpu: &Cpu) {
+        let keys = cpu.mmio.joypad.pressed_keys();
+        let due = self
+            .checkpoints
+            .back()
+            .is_none_or(|(tick, ..)| self.ticks - tick >= CHECKPOINT_INTERVAL);
+
+        if due || keys != self.keys {
+            let state = match self.spare.take() {
+                Some(mut state) => {
+                    state.recapture(cpu);
+                    state
+                }
+                None => SaveState::capture(cpu),
+            };
+            self.checkpoints.push_back((self.ticks, keys, state));
+            self.keys = keys;
+        }
+
+        // only the last checkpoint at or before the oldest tick that can be stepped back to is needed
+        let oldest = self.ticks.saturating_sub(self.capacity as u64);
+        while self.checkpoints.get(1).is_some_and(|(tick, ..)| *tick <= oldest) {
+            self.spare = self.checkpoints.pop_front().map(|(.., state)| state);
+        }
+
+        self.ticks += 1;
+    }
+
+    // forgets the last tick, returns the checkpoint to load and the number of ticks to run from it
+    pub fn rewind(&mut self) -> Option<(&SaveState, u64)> {
+        let target = self.ticks.checked_sub(1)?;
+        let (tick, ..) = self.checkpoints.front()?;
+        if *tick > target {
+            return None;
+        }
+
+        while self.checkpoints.back().is_some_and(|(tick, ..)| *tick > target) {
+            self.spare = self.checkpoints.pop_back().map(|(.., state)| state);
+        }
+        self.ticks = target;
+
+        let (tick, keys, state) = self.checkpoints.back()?;
+        self.keys = *keys;
+        Some((state, target - tick))
+    }
+}
@@ -1,9 +1,13 @@
+pub mod bios;
 pub mod cpu;
 pub mod decoder;
 pub mod error;
 mod handlers;
+pub mod history;
 pub mod mode;
-mod pipeline;
+pub(crate) mod pipeline;
 pub mod registers;
+pub mod swi_log;
 mod symbolizer;
 pub mod timer;
+pub mod trace;
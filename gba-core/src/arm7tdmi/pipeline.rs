@@ -1,14 +1,18 @@
 use super::decoder::Instruction;
+use super::error::DecodeError;
+use crate::memory::device::AccessKind;
 use crate::memory::mmio::Mmio;
 use std::fmt::Display;
 use tracing::*;
 
+#[derive(Clone, Copy)]
 pub struct State {
     pub pc: u32,
     pub opcode: u32,
     pub is_thumb: bool,
 }
 
+#[derive(Clone)]
 pub struct Pipeline {
     states: Vec<State>,
 }
@@ -21,19 +25,18 @@ impl Pipeline {
     }
 
     pub fn advance(&mut self, pc: u32, is_thumb: bool, mmio: &mut Mmio) {
-        let opcode = mmio.read_u32(pc);
+        let opcode = mmio.read::<u32>(pc, AccessKind::Fetch);
         self.states.push(State { pc, opcode, is_thumb });
     }
 
-    pub fn pop(&mut self) -> Option<(Instruction, State)> {
+    pub fn pop(&mut self) -> Option<(Result<Instruction, DecodeError>, State)> {
         if self.states.len() < 3 {
             return None;
         }
 
         let state = self.states.remove(0);
-        let instr = Instruction::decode(state.opcode, state.is_thumb).unwrap_or_else(|e| {
-            error!(target: "pipeline", "Failed to decode instruction: {:?} at {:08X}", e, state.pc);
-            Instruction::nop()
+        let instr = Instruction::decode(state.opcode, state.is_thumb).inspect_err(|e| {
+            error!(target: "pipeline", "Failed to decode instruction: {} at {:08X}", e, state.pc);
         });
 
         Some((instr, state))
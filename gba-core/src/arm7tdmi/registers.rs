@@ -73,6 +73,7 @@ impl Display for Psr {
     }
 }
 
+#[derive(Clone)]
 pub struct Registers {
     pub r: [u32; 16],
     pub cpsr: Psr,
@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Default)]
+pub struct SwiStats {
+    pub number: u8,
+    pub count: u64,
+    pub last_args: [u32; 4],        // r0-r3 of the most recent call
+    pub callsites: Vec<(u32, u64)>, // (address of the SWI instruction, calls)
+}
+
+// BIOS calls made by the game, only recorded when enabled
+#[derive(Default)]
+pub struct SwiLog {
+    calls: BTreeMap<u8, SwiStats>,
+    restarted: Option<u32>, // a halted IntrWait runs the same SWI again, which is not a new call
+}
+
+impl SwiLog {
+    pub fn new() -> SwiLog {
+        SwiLog::default()
+    }
+
+    pub fn record(&mut self, number: u8, addr: u32, args: [u32; 4]) {
+        if self.restarted.take() == Some(addr) {
+            return;
+        }
+
+        let stats = self.calls.entry(number).or_insert_with(|| SwiStats {
+            number,
+            ..SwiStats::default()
+        });
+        stats.count += 1;
+        stats.last_args = args;
+
+        match stats.callsites.iter_mut().find(|(callsite, _)| *callsite == addr) {
+            Some((_, count)) => *count += 1,
+            None => stats.callsites.push((addr, 1)),
+        }
+    }
+
+    pub fn restart(&mut self, addr: u32) {
+        self.restarted = Some(addr);
+    }
+
+    // ordered by SWI number
+    pub fn stats(&self) -> Vec<SwiStats> {
+        self.calls.values().cloned().collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.calls.clear();
+        self.restarted = None;
+    }
+}
+
+// https://problemkaputt.de/gbatek.htm#biosfunctions
+pub fn swi_name(number: u8) -> &'static str {
+    match number {
+        0x00 => "SoftReset",
+        0x01 => "RegisterRamReset",
+        0x02 => "Halt",
+        0x03 => "Stop",
+        0x04 => "IntrWait",
+        0x05 => "VBlankIntrWait",
+        0x06 => "Div",
+        0x07 => "DivArm",
+        0x08 => "Sqrt",
+        0x09 => "ArcTan",
+        0x0A => "ArcTan2",
+        0x0B => "CpuSet",
+        0x0C => "CpuFastSet",
+        0x0D => "GetBiosChecksum",
+        0x0E => "BgAffineSet",
+        0x0F => "ObjAffineSet",
+        0x10 => "BitUnPack",
+        0x11 => "LZ77UnCompWram",
+        0x12 => "LZ77UnCompVram",
+        0x13 => "HuffUnComp",
+        0x14 => "RLUnCompWram",
+        0x15 => "RLUnCompVram",
+        0x16 => "Diff8bitUnFilterWram",
+        0x17 => "Diff8bitUnFilterVram",
+        0x18 => "Diff16bitUnFilter",
+        0x19 => "SoundBias",
+        0x1A => "SoundDriverInit",
+        0x1B => "SoundDriverMode",
+        0x1C => "SoundDriverMain",
+        0x1D => "SoundDriverVSync",
+        0x1E => "SoundChannelClear",
+        0x1F => "MidiKey2Freq",
+        0x20..=0x24 => "SoundWhatever",
+        0x25 => "MultiBoot",
+        0x26 => "HardReset",
+        0x27 => "CustomHalt",
+        0x28 => "SoundDriverVSyncOff",
+        0x29 => "SoundDriverVSyncOn",
+        0x2A => "SoundGetJumpList",
+        _ => "Invalid",
+    }
+}
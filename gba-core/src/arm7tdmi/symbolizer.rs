@@ -36,6 +36,12 @@ impl Symbolizer {
         Symbolizer { symbols }
     }
 
+    pub fn merge(&mut self, other: Symbolizer) {
+        for (addr, names) in other.symbols {
+            self.symbols.entry(addr).or_default().extend(names);
+        }
+    }
+
     pub fn find(&self, addr: u32) -> Option<&Vec<String>> {
         self.symbols.get(&addr)
     }
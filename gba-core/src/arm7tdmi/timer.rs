@@ -1,11 +1,13 @@
+use crate::audio::apu::tick_cycles;
 use crate::memory::device::{Addressable, IoRegister};
-use crate::memory::registers::TimerControl;
+use crate::memory::registers::{Interrupt, TimerControl};
 
 #[derive(Default, PartialEq, Clone, Copy)]
 pub struct Timer {
     pub counter: IoRegister<u16>,
     pub reload: IoRegister<u16>,
     pub control: IoRegister<TimerControl>,
+    cycles: u32, // cycles elapsed since the last prescaler step
 }
 
 impl Timer {
@@ -14,6 +16,7 @@ impl Timer {
             counter: IoRegister::default(),
             reload: IoRegister::default(),
             control: IoRegister::default(),
+            cycles: 0,
         }
     }
 
@@ -21,11 +24,47 @@ impl Timer {
         self.control.contains_flags(TimerControl::ENABLE)
     }
 
-    pub fn tick(&mut self) {
-        self.counter.set(self.counter.value().wrapping_add(1));
+    pub fn is_cascade(&self) -> bool {
+        self.control.contains_flags(TimerControl::COUNT_UP_TIMING)
+    }
+
+    pub fn irq_on_overflow(&self) -> bool {
+        self.control.contains_flags(TimerControl::IRQ_ON_OVERFLOW)
+    }
+
+    // returns how often the timer overflowed during the given cycles
+    pub fn tick(&mut self, cycles: u32) -> u32 {
+        let prescaler = self.control.value().prescaler() as u32;
+        self.cycles += cycles;
+
+        let steps = self.cycles / prescaler;
+        self.cycles %= prescaler;
+        self.count(steps)
+    }
+
+    // increments the counter, returns how often it overflowed
+    pub fn count(&mut self, steps: u32) -> u32 {
+        let until_overflow = 0x10000 - *self.counter.value() as u32;
+        if steps < until_overflow {
+            self.counter.set(*self.counter.value() + steps as u16);
+            return 0;
+        }
+
+        // after the first overflow the counter starts over from the reload value
+        let steps = steps - until_overflow;
+        let period = 0x10000 - self.reload.0 as u32;
+        self.counter.set(self.reload.0 + (steps % period) as u16);
+        1 + steps / period
+    }
 
-        if self.counter.0 == 0 {
+    fn write_control(&mut self, addr: u32, value: u8) {
+        let was_enabled = self.is_enabled();
+        self.control.write(addr, value);
+
+        // the reload value is copied into the counter when the timer gets started
+        if !was_enabled && self.is_enabled() {
             self.counter.set(self.reload.0);
+            self.cycles = 0;
         }
     }
 }
@@ -33,26 +72,55 @@ impl Timer {
 #[derive(Default, PartialEq, Clone, Copy)]
 pub struct Timers {
     pub timers: [Timer; 4],
+    clock_remainder: u32, // fraction of a cycle left over from converting ticks
 }
 
 impl Timers {
     pub fn new() -> Self {
         Timers {
             timers: [Timer::new(), Timer::new(), Timer::new(), Timer::new()],
+            clock_remainder: 0,
+        }
+    }
+
+    // returns how often each timer overflowed during this tick, the timers run at the real clock rate like the APU
+    pub fn tick(&mut self) -> [u32; 4] {
+        let cycles = tick_cycles(&mut self.clock_remainder);
+        let mut overflows = [0; 4];
+
+        for i in 0..4 {
+            let timer = &mut self.timers[i];
+            if !timer.is_enabled() {
+                continue;
+            }
+
+            // in count-up mode the timer ignores the prescaler and counts overflows of the previous timer,
+            // this has no effect on timer 0
+            overflows[i] = if i > 0 && timer.is_cascade() {
+                timer.count(overflows[i - 1])
+            } else {
+                timer.tick(cycles)
+            };
         }
+
+        overflows
     }
 
-    pub fn tick(&mut self) {
-        for timer in &mut self.timers {
-            if timer.is_enabled() {
-                timer.tick();
+    pub fn pending_interrupts(&self, overflows: &[u32; 4]) -> Interrupt {
+        let mut interrupts = Interrupt::empty();
+
+        for (i, timer) in self.timers.iter().enumerate() {
+            if overflows[i] > 0 && timer.irq_on_overflow() {
+                interrupts |= Interrupt::from_bits_truncate(Interrupt::TIMER0.bits() << i);
             }
         }
+
+        interrupts
     }
 }
 
 impl Addressable for Timers {
-    fn read(&self, addr: u32) -> u8 {
+    fn read_byte(&self, addr: u32) -> u8 {
         match addr {
             0x04000100..=0x04000101 => self.timers[0].counter.read(addr),
             0x04000102..=0x04000103 => self.timers[0].control.read(addr),
@@ -66,16 +134,16 @@ impl Addressable for Timers {
         }
     }
 
-    fn write(&mut self, addr: u32, value: u8) {
+    fn write_byte(&mut self, addr: u32, value: u8) {
         match addr {
             0x04000100..=0x04000101 => self.timers[0].reload.write(addr, value),
-            0x04000102..=0x04000103 => self.timers[0].control.write(addr, value),
+            0x04000102..=0x04000103 => self.timers[0].write_control(addr, value),
             0x04000104..=0x04000105 => self.timers[1].reload.write(addr, value),
-            0x04000106..=0x04000107 => self.timers[1].control.write(addr, value),
+            0x04000106..=0x04000107 => self.timers[1].write_control(addr, value),
             0x04000108..=0x04000109 => self.timers[2].reload.write(addr, value),
-            0x0400010A..=0x0400010B => self.timers[2].control.write(addr, value),
+            0x0400010A..=0x0400010B => self.timers[2].write_control(addr, value),
             0x0400010C..=0x0400010D => self.timers[3].reload.write(addr, value),
-            0x0400010E..=0x0400010F => self.timers[3].control.write(addr, value),
+            0x0400010E..=0x0400010F => self.timers[3].write_control(addr, value),
             _ => unreachable!(),
         }
     }
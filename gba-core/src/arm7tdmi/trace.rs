@@ -0,0 +1,26 @@
+use super::decoder::{Instruction, Register, RegisterList, TransferLength};
+use super::pipeline::State;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessDirection {
+    Read,
+    Write,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryAccess {
+    pub direction: AccessDirection,
+    pub addr: u32,
+    pub width: TransferLength,
+    pub value: u32,
+}
+
+// side effects of a single executed instruction, see Cpu::step_traced
+pub struct StepTrace {
+    pub instruction: Instruction,
+    pub state: State,
+    pub registers_read: RegisterList,
+    pub registers_written: Vec<(Register, u32)>, // (register, new value)
+    pub memory: Vec<MemoryAccess>,               // CPU data accesses in order, excluding fetches and DMA
+    pub cycles: u64,                             // bus cycles, including the opcode fetch
+}
@@ -1,34 +1,574 @@
+use super::channel::ChannelState;
+use super::noise::Noise;
+use super::recorder::AudioRecorder;
+use super::square::Square;
+use super::wave::Wave;
 use crate::memory::device::Addressable;
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::path::Path;
+use tracing::{error, info, trace};
+
+pub const FIFO_CAPACITY: usize = 32; // in bytes, 8 words
+const FIFO_REFILL_THRESHOLD: usize = 16; // DMA is requested once half of the FIFO has been played
+
+pub const FIFO_A: u32 = 0x040000A0;
+pub const FIFO_B: u32 = 0x040000A4;
+
+pub const SAMPLE_RATE: u32 = 32768;
+pub const CPU_CLOCK: u32 = 16 * 1024 * 1024;
+const TICKS_PER_SECOND: u32 = 240 * 228 * 60; // components are ticked at least once per instruction, 240 * 228 times a frame
+const CYCLES_PER_SAMPLE: u32 = CPU_CLOCK / SAMPLE_RATE;
+const CYCLES_PER_FRAME_STEP: u32 = CPU_CLOCK / 512; // the frame sequencer runs at 512 Hz
+const SAMPLE_BUFFER_CAPACITY: usize = SAMPLE_RATE as usize; // one second, older samples are dropped
+pub const SCOPE_LENGTH: usize = 512; // samples kept per channel for visualization, ~16ms
+
+// sound sources that can be muted for debugging, independent of the game's own enable bits
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Channel {
+    Square1,
+    Square2,
+    Wave,
+    Noise,
+    FifoA,
+    FifoB,
+}
+
+impl Channel {
+    pub const ALL: [Channel; 6] = [
+        Channel::Square1,
+        Channel::Square2,
+        Channel::Wave,
+        Channel::Noise,
+        Channel::FifoA,
+        Channel::FifoB,
+    ];
+}
+
+impl Display for Channel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Channel::Square1 => write!(f, "PSG 1 (Tone & Sweep)"),
+            Channel::Square2 => write!(f, "PSG 2 (Tone)"),
+            Channel::Wave => write!(f, "PSG 3 (Wave)"),
+            Channel::Noise => write!(f, "PSG 4 (Noise)"),
+            Channel::FifoA => write!(f, "FIFO A"),
+            Channel::FifoB => write!(f, "FIFO B"),
+        }
+    }
+}
+
+// https://problemkaputt.de/gbatek.htm#gbasoundchannelaandbdmasound
+#[derive(Default, Clone)]
+pub struct DirectSound {
+    fifo: VecDeque<i8>,
+    pub sample: i8, // sample currently being output
+}
+
+impl DirectSound {
+    fn push(&mut self, value: u8) {
+        // writes to a full FIFO are lost
+        if self.fifo.len() < FIFO_CAPACITY {
+            self.fifo.push_back(value as i8);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.fifo.clear();
+        self.sample = 0;
+    }
+
+    // consumes the next sample, returns true if the FIFO wants to be refilled
+    fn clock(&mut self) -> bool {
+        if let Some(sample) = self.fifo.pop_front() {
+            self.sample = sample;
+        }
+
+        self.fifo.len() <= FIFO_REFILL_THRESHOLD
+    }
+
+    fn len(&self) -> usize {
+        self.fifo.len()
+    }
+}
+
+// cycles of the real clock that pass during one tick, the fraction is carried over to the next one
+pub(crate) fn tick_cycles(remainder: &mut u32) -> u32 {
+    *remainder += CPU_CLOCK;
+    let cycles = *remainder / TICKS_PER_SECOND;
+    *remainder %= TICKS_PER_SECOND;
+    cycles
+}
+
+// ticks that pass during the given cycles of the real clock, the inverse of the above
+pub(crate) fn cycle_ticks(cycles: u64, remainder: &mut u64) -> u64 {
+    *remainder += cycles * TICKS_PER_SECOND as u64;
+    let ticks = *remainder / CPU_CLOCK as u64;
+    *remainder %= CPU_CLOCK as u64;
+    ticks
+}
+
+// where the mixed samples end up, taken out while ticks that were already heard are emulated again
+pub struct ApuOutput {
+    samples: VecDeque<[i16; 2]>,
+    recorder: Option<AudioRecorder>,
+    scope: Option<[VecDeque<i16>; 6]>,
+}
 
 pub struct Apu {
     io: Box<[u8; (0x400008E - 0x4000080) + 1]>,
+    pub square1: Square,
+    pub square2: Square,
+    pub wave: Wave,
+    pub noise: Noise,
+    pub fifo_a: DirectSound,
+    pub fifo_b: DirectSound,
+    pub channel_enabled: [bool; 6], // indexed by Channel, only affects the mixed output
+    samples: VecDeque<[i16; 2]>,    // mixed stereo output (left, right)
+    recorder: Option<AudioRecorder>,
+    pub scope: Option<[VecDeque<i16>; 6]>, // recent output levels indexed by Channel, only captured when enabled
+    clock_remainder: u32,                  // fraction of a cycle left over from converting ticks
+    sample_cycles: u32,
+    frame_cycles: u32,
+    frame_step: u8,
 }
 
 impl Apu {
     pub fn new() -> Apu {
         let io = Box::<[u8; (0x400008E - 0x4000080) + 1]>::new_zeroed();
+        let mut io = unsafe { io.assume_init() };
+        io[0x09] = 0x02; // SOUNDBIAS is set to 0x200 by the BIOS
 
         Apu {
-            io: unsafe { io.assume_init() },
+            io,
+            square1: Square::new(true),
+            square2: Square::new(false),
+            wave: Wave::new(),
+            noise: Noise::new(),
+            fifo_a: DirectSound::default(),
+            fifo_b: DirectSound::default(),
+            channel_enabled: [true; 6],
+            samples: VecDeque::with_capacity(SAMPLE_BUFFER_CAPACITY),
+            recorder: None,
+            scope: None,
+            clock_remainder: 0,
+            sample_cycles: 0,
+            frame_cycles: 0,
+            frame_step: 0,
         }
     }
+
+    // the recording, the debugger's scope and the muted channels are not part of a savestate
+    pub fn carry_over(&mut self, previous: Apu) {
+        self.recorder = previous.recorder;
+        self.scope = previous.scope;
+        self.channel_enabled = previous.channel_enabled;
+    }
+
+    // ticks until the output is given back are not played, recorded or shown in the scope
+    pub fn take_output(&mut self) -> ApuOutput {
+        ApuOutput {
+            samples: std::mem::take(&mut self.samples),
+            recorder: self.recorder.take(),
+            scope: self.scope.take(),
+        }
+    }
+
+    pub fn restore_output(&mut self, output: ApuOutput) {
+        self.samples = output.samples;
+        self.recorder = output.recorder;
+        self.scope = output.scope;
+    }
+
+    pub fn tick(&mut self) {
+        // run the channels at the real clock rate
+        let cycles = tick_cycles(&mut self.clock_remainder);
+
+        self.square1.tick(cycles);
+        self.square2.tick(cycles);
+        self.wave.tick(cycles);
+        self.noise.tick(cycles);
+
+        self.frame_cycles += cycles;
+        if self.frame_cycles >= CYCLES_PER_FRAME_STEP {
+            self.frame_cycles -= CYCLES_PER_FRAME_STEP;
+            self.clock_frame_sequencer();
+        }
+
+        self.sample_cycles += cycles;
+        if self.sample_cycles >= CYCLES_PER_SAMPLE {
+            self.sample_cycles -= CYCLES_PER_SAMPLE;
+
+            if self.samples.len() == SAMPLE_BUFFER_CAPACITY {
+                self.samples.pop_front();
+            }
+            let sample = self.mix();
+            self.record(sample);
+            self.capture_scope();
+            self.samples.push_back(sample);
+        }
+    }
+
+    // length at 256 Hz, sweep at 128 Hz and envelope at 64 Hz
+    fn clock_frame_sequencer(&mut self) {
+        if self.frame_step.is_multiple_of(2) {
+            self.square1.clock_length();
+            self.square2.clock_length();
+            self.wave.clock_length();
+            self.noise.clock_length();
+        }
+
+        if self.frame_step == 2 || self.frame_step == 6 {
+            self.square1.clock_sweep();
+        }
+
+        if self.frame_step == 7 {
+            self.square1.clock_envelope();
+            self.square2.clock_envelope();
+            self.noise.clock_envelope();
+        }
+
+        self.frame_step = (self.frame_step + 1) % 8;
+    }
+
+    // SOUNDCNT_X bit 7, turns off the PSG and FIFO circuits
+    fn master_enabled(&self) -> bool {
+        self.io[0x04] & 0b1000_0000 != 0
+    }
+
+    // turning the master enable off resets the PSG registers, wave RAM is kept
+    fn power_off(&mut self) {
+        self.square1 = Square::new(true);
+        self.square2 = Square::new(false);
+        self.noise = Noise::new();
+        for offset in 0..6 {
+            self.wave.write_byte(offset, 0);
+        }
+        self.io[0x00] = 0;
+        self.io[0x01] = 0;
+        self.frame_step = 0;
+
+        trace!(target: "apu", "Sound master disabled");
+    }
+
+    // SOUNDCNT_X bits 0-3, set while a PSG channel is playing
+    fn channel_status(&self) -> u8 {
+        [
+            self.square1.enabled,
+            self.square2.enabled,
+            self.wave.enabled,
+            self.noise.enabled,
+        ]
+        .iter()
+        .enumerate()
+        .fold(0, |status, (channel, &enabled)| status | (enabled as u8) << channel)
+    }
+
+    fn register(&self, addr: u32) -> u16 {
+        let offset = (addr - 0x4000080) as usize;
+        u16::from_le_bytes([self.io[offset], self.io[offset + 1]])
+    }
+
+    fn mix(&self) -> [i16; 2] {
+        self.mix_channels(&self.channel_enabled)
+    }
+
+    // https://problemkaputt.de/gbatek.htm#gbasoundcontrolregisters
+    fn mix_channels(&self, enabled: &[bool; 6]) -> [i16; 2] {
+        if !self.master_enabled() {
+            return [0, 0];
+        }
+
+        let soundcnt_l = self.register(0x04000080);
+        let soundcnt_h = self.register(0x04000082);
+        let soundbias = self.register(0x04000088);
+
+        let outputs = [
+            self.square1.output(),
+            self.square2.output(),
+            self.wave.output(),
+            self.noise.output(),
+        ];
+
+        // PSG volume is 25%, 50% or 100%, 3 is prohibited
+        let psg_shift = 2 - (soundcnt_h & 0b11).min(2) as i32;
+
+        // direct sound samples are 8 bit and either played at 50% or 100%
+        let fifo_a = self.fifo_a.sample as i32 * if soundcnt_h & (1 << 2) != 0 { 4 } else { 2 };
+        let fifo_b = self.fifo_b.sample as i32 * if soundcnt_h & (1 << 3) != 0 { 4 } else { 2 };
+
+        let bias = (soundbias & 0x3FE) as i32;
+        let resolution_shift = 1 + (soundbias >> 14) as i32; // 9 bit down to 6 bit
+
+        // SOUNDCNT_L bits 12-15 enable the PSG channels on the left, bits 8-11 on the right
+        // SOUNDCNT_H bits 9 and 13 enable the FIFOs on the left, bits 8 and 12 on the right
+        // disabled channels (muted by the debugger) are left out on both sides
+        let side = |psg_enable: u16, volume_shift: u16, fifo_enable: u16| {
+            let psg: i32 = outputs
+                .iter()
+                .enumerate()
+                .filter(|(channel, _)| enabled[*channel] && soundcnt_l & (1 << (psg_enable + *channel as u16)) != 0)
+                .map(|(_, &output)| output as i32)
+                .sum();
+            let volume = ((soundcnt_l >> volume_shift) & 0b111) as i32 + 1;
+
+            let mut sample = bias + ((psg * volume) >> psg_shift);
+            if soundcnt_h & (1 << fifo_enable) != 0 && enabled[Channel::FifoA as usize] {
+                sample += fifo_a;
+            }
+            if soundcnt_h & (1 << (fifo_enable + 4)) != 0 && enabled[Channel::FifoB as usize] {
+                sample += fifo_b;
+            }
+
+            // the output is 10 bit, the resolution drops the lower bits
+            let sample = (sample.clamp(0, 0x3FF) >> resolution_shift) << resolution_shift;
+            ((sample - bias) << 5) as i16
+        };
+
+        [side(12, 4, 9), side(8, 0, 8)]
+    }
+
+    // stems are mixed like the output with only a single channel enabled, muting is ignored
+    fn record(&mut self, sample: [i16; 2]) {
+        let Some(recorder) = &self.recorder else {
+            return;
+        };
+
+        let stems = if recorder.records_stems() {
+            Channel::ALL.map(|channel| {
+                let mut enabled = [false; 6];
+                enabled[channel as usize] = true;
+                self.mix_channels(&enabled)
+            })
+        } else {
+            [[0; 2]; 6]
+        };
+
+        if let Some(recorder) = &mut self.recorder
+            && let Err(e) = recorder.write(sample, &stems)
+        {
+            error!(target: "apu", "Failed to write to {}: {}", recorder.path.display(), e);
+            self.recorder = None;
+        }
+    }
+
+    // PSG levels are -15 to 15, FIFO samples -128 to 127
+    fn capture_scope(&mut self) {
+        let Some(scope) = &mut self.scope else {
+            return;
+        };
+
+        let levels = [
+            self.square1.output(),
+            self.square2.output(),
+            self.wave.output(),
+            self.noise.output(),
+            self.fifo_a.sample as i16,
+            self.fifo_b.sample as i16,
+        ];
+
+        for (samples, level) in scope.iter_mut().zip(levels) {
+            if samples.len() == SCOPE_LENGTH {
+                samples.pop_front();
+            }
+            samples.push_back(level);
+        }
+    }
+
+    pub fn channel_states(&self) -> [ChannelState; 6] {
+        [
+            self.square1.state(),
+            self.square2.state(),
+            self.wave.state(),
+            self.noise.state(),
+            ChannelState::Fifo {
+                queued: self.fifo_a.len(),
+                sample: self.fifo_a.sample,
+            },
+            ChannelState::Fifo {
+                queued: self.fifo_b.len(),
+                sample: self.fifo_b.sample,
+            },
+        ]
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    // a running recording is finished first
+    pub fn start_recording(&mut self, path: &Path, stems: bool) {
+        self.stop_recording();
+
+        match AudioRecorder::create(path, stems) {
+            Ok(recorder) => {
+                info!(target: "apu", "Recording audio to {}", path.display());
+                self.recorder = Some(recorder);
+            }
+            Err(e) => error!(target: "apu", "Failed to create {}: {}", path.display(), e),
+        }
+    }
+
+    pub fn stop_recording(&mut self) {
+        let Some(recorder) = self.recorder.take() else {
+            return;
+        };
+
+        let path = recorder.path.clone();
+        match recorder.finish() {
+            Ok(()) => info!(target: "apu", "Audio recording saved to {}", path.display()),
+            Err(e) => error!(target: "apu", "Failed to finish {}: {}", path.display(), e),
+        }
+    }
+
+    // fills the buffer with interleaved stereo samples (left, right) and returns how many values were written,
+    // samples that don't fit stay queued for the next call
+    pub fn read_samples(&mut self, buffer: &mut [i16]) -> usize {
+        let frames = (buffer.len() / 2).min(self.samples.len());
+        for (out, [left, right]) in buffer.chunks_exact_mut(2).zip(self.samples.drain(..frames)) {
+            out[0] = left;
+            out[1] = right;
+        }
+        frames * 2
+    }
+
+    // takes the samples mixed since the last call
+    pub fn drain_samples(&mut self) -> Vec<[i16; 2]> {
+        self.samples.drain(..).collect()
+    }
+
+    // SOUNDCNT_H bit 10 and 14, 0 = timer 0, 1 = timer 1
+    fn fifo_timer(&self, fifo: usize) -> usize {
+        let soundcnt_h = self.io[(0x04000083 - 0x4000080) as usize];
+        let bit = if fifo == 0 { 2 } else { 6 };
+        ((soundcnt_h >> bit) & 1) as usize
+    }
+
+    // called whenever timer 0 or 1 overflows, returns the FIFOs (A, B) that request a DMA refill
+    pub fn timer_overflow(&mut self, timer: usize) -> [bool; 2] {
+        let mut requests = [false; 2];
+        if !self.master_enabled() {
+            return requests;
+        }
+
+        if self.fifo_timer(0) == timer {
+            requests[0] = self.fifo_a.clock();
+        }
+
+        if self.fifo_timer(1) == timer {
+            requests[1] = self.fifo_b.clock();
+        }
+
+        trace!(target: "apu", "Timer {} overflow, FIFO A: {} bytes, FIFO B: {} bytes",
+            timer, self.fifo_a.len(), self.fifo_b.len());
+
+        requests
+    }
+}
+
+// a clone never records, there is only one file to write to
+impl Clone for Apu {
+    fn clone(&self) -> Self {
+        Apu {
+            io: self.io.clone(),
+            square1: self.square1.clone(),
+            square2: self.square2.clone(),
+            wave: self.wave.clone(),
+            noise: self.noise.clone(),
+            fifo_a: self.fifo_a.clone(),
+            fifo_b: self.fifo_b.clone(),
+            channel_enabled: self.channel_enabled,
+            samples: self.samples.clone(),
+            recorder: None,
+            scope: self.scope.clone(),
+            clock_remainder: self.clock_remainder,
+            sample_cycles: self.sample_cycles,
+            frame_cycles: self.frame_cycles,
+            frame_step: self.frame_step,
+        }
+    }
+
+    // keeps the buffers, the rewind history captures its checkpoints into the ones it evicted
+    fn clone_from(&mut self, source: &Self) {
+        self.io.clone_from(&source.io);
+        self.square1.clone_from(&source.square1);
+        self.square2.clone_from(&source.square2);
+        self.wave.clone_from(&source.wave);
+        self.noise.clone_from(&source.noise);
+        self.fifo_a.clone_from(&source.fifo_a);
+        self.fifo_b.clone_from(&source.fifo_b);
+        self.channel_enabled = source.channel_enabled;
+        self.samples.clone_from(&source.samples);
+        self.recorder = None;
+        self.scope.clone_from(&source.scope);
+        self.clock_remainder = source.clock_remainder;
+        self.sample_cycles = source.sample_cycles;
+        self.frame_cycles = source.frame_cycles;
+        self.frame_step = source.frame_step;
+    }
 }
 
 impl Addressable for Apu {
-    fn read(&self, addr: u32) -> u8 {
+    fn read_byte(&self, addr: u32) -> u8 {
         match addr {
-            // SOUNDBIAS register
-            0x04000088 => 0x00,
-            0x04000089 => 0x02,
-            // rest of the registers
-            0x4000080..=0x400008E => self.io[(addr - 0x4000080) as usize],
+            0x04000060..=0x04000065 => self.square1.read_byte((addr - 0x04000060) as usize),
+            0x04000068..=0x04000069 => self.square2.read_byte((addr - 0x04000066) as usize),
+            0x0400006C..=0x0400006D => self.square2.read_byte((addr - 0x04000068) as usize),
+            0x04000070..=0x04000075 => self.wave.read_byte((addr - 0x04000070) as usize),
+            0x04000078..=0x04000079 => self.noise.read_byte((addr - 0x04000078) as usize),
+            0x0400007C..=0x0400007D => self.noise.read_byte((addr - 0x04000078) as usize),
+            0x04000066..=0x0400007F => 0x00,
+            // SOUNDCNT_L, PSG master volume and channel enables
+            0x04000080 => self.io[0x00] & 0x77,
+            0x04000081 => self.io[0x01],
+            // SOUNDCNT_H, PSG/FIFO volume, FIFO enables and timers
+            0x04000082 => self.io[0x02] & 0x0F,
+            0x04000083 => self.io[0x03] & 0x77,
+            // SOUNDCNT_X, master enable and the read-only channel status
+            0x04000084 => (self.io[0x04] & 0x80) | self.channel_status(),
+            // SOUNDBIAS, bias level and amplitude resolution
+            0x04000088 => self.io[0x08] & 0xFE,
+            0x04000089 => self.io[0x09] & 0xC3,
+            0x04000085..=0x0400008E => 0x00,
+            0x04000090..=0x0400009F => self.wave.read_wave_ram((addr - 0x04000090) as usize),
+            // FIFOs are write-only
+            0x40000A0..=0x40000A7 => 0x00,
             _ => unreachable!(),
         }
     }
 
-    fn write(&mut self, addr: u32, value: u8) {
+    fn write_byte(&mut self, addr: u32, value: u8) {
         match addr {
+            // the PSG registers can't be written while the sound circuits are off
+            0x04000060..=0x04000081 if !self.master_enabled() => {}
+            0x04000060..=0x04000065 => self.square1.write_byte((addr - 0x04000060) as usize, value),
+            0x04000068..=0x04000069 => self.square2.write_byte((addr - 0x04000066) as usize, value),
+            0x0400006C..=0x0400006D => self.square2.write_byte((addr - 0x04000068) as usize, value),
+            0x04000070..=0x04000075 => self.wave.write_byte((addr - 0x04000070) as usize, value),
+            0x04000078..=0x04000079 => self.noise.write_byte((addr - 0x04000078) as usize, value),
+            0x0400007C..=0x0400007D => self.noise.write_byte((addr - 0x04000078) as usize, value),
+            0x04000066..=0x0400007F => {}
+            // SOUNDCNT_H, bit 11 and 15 reset FIFO A/B and always read as zero
+            0x04000083 => {
+                if value & 0b0000_1000 != 0 {
+                    self.fifo_a.reset();
+                }
+                if value & 0b1000_0000 != 0 {
+                    self.fifo_b.reset();
+                }
+                self.io[(addr - 0x4000080) as usize] = value & 0b0111_0111;
+            }
+            0x04000084 => {
+                let was_enabled = self.master_enabled();
+                self.io[0x04] = value & 0x80;
+                if was_enabled && !self.master_enabled() {
+                    self.power_off();
+                }
+            }
             0x4000080..=0x400008E => self.io[(addr - 0x4000080) as usize] = value,
+            0x04000090..=0x0400009F => self.wave.write_wave_ram((addr - 0x04000090) as usize, value),
+            0x40000A0..=0x40000A3 => self.fifo_a.push(value),
+            0x40000A4..=0x40000A7 => self.fifo_b.push(value),
             _ => unreachable!(),
         }
     }
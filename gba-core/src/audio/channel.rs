@@ -0,0 +1,98 @@
+// building blocks shared by the PSG channels
+// https://problemkaputt.de/gbatek.htm#gbasoundchannel1toneswee
+
+// snapshot of a channel for debuggers, frequencies are in Hz
+#[derive(Debug, Clone, Copy)]
+pub enum ChannelState {
+    Square {
+        playing: bool,
+        frequency: f32,
+        duty: u8, // 0-3, 12.5% to 75%
+        volume: u8,
+        sweep: u8, // raw SOUND1CNT_L, 0 for channel 2
+    },
+    Wave {
+        playing: bool,
+        frequency: f32, // of the whole waveform
+        volume: u8,     // in percent
+        bank: usize,
+        samples: usize, // 32 or 64
+    },
+    Noise {
+        playing: bool,
+        frequency: f32, // of the LFSR shifts
+        volume: u8,
+        narrow: bool, // 7 bit LFSR
+    },
+    Fifo {
+        queued: usize, // bytes
+        sample: i8,
+    },
+}
+
+// upper byte of the envelope register: initial volume, direction and step time
+pub fn dac_enabled(envelope: u8) -> bool {
+    // a zero volume that decreases keeps the channel silent, hardware turns it off instead
+    envelope & 0xF8 != 0
+}
+
+#[derive(Default, Clone)]
+pub struct Envelope {
+    pub volume: u8,
+    timer: u8,
+}
+
+impl Envelope {
+    pub fn trigger(&mut self, envelope: u8) {
+        self.volume = envelope >> 4;
+        self.timer = envelope & 0b111;
+    }
+
+    // clocked at 64 Hz by the frame sequencer
+    pub fn clock(&mut self, envelope: u8) {
+        let period = envelope & 0b111;
+        if period == 0 {
+            return;
+        }
+
+        self.timer = self.timer.saturating_sub(1);
+        if self.timer > 0 {
+            return;
+        }
+        self.timer = period;
+
+        if envelope & 0b1000 != 0 {
+            self.volume = (self.volume + 1).min(15);
+        } else {
+            self.volume = self.volume.saturating_sub(1);
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct LengthCounter {
+    remaining: u16,
+}
+
+impl LengthCounter {
+    // the register holds the elapsed part of the length, not the remaining one
+    pub fn load(&mut self, max: u16, value: u16) {
+        self.remaining = max - value;
+    }
+
+    pub fn trigger(&mut self, max: u16) {
+        if self.remaining == 0 {
+            self.remaining = max;
+        }
+    }
+
+    // clocked at 256 Hz by the frame sequencer, returns true once the length has expired
+    pub fn clock(&mut self, enabled: bool) -> bool {
+        if !enabled || self.remaining == 0 {
+            return false;
+        }
+
+        self.remaining -= 1;
+        self.remaining == 0
+    }
+}
@@ -1 +1,6 @@
 pub mod apu;
+pub mod channel;
+pub mod noise;
+pub mod recorder;
+pub mod square;
+pub mod wave;
@@ -0,0 +1,153 @@
+use super::apu::CPU_CLOCK;
+use super::channel::{ChannelState, Envelope, LengthCounter, dac_enabled};
+use tracing::trace;
+
+const LENGTH_MAX: u16 = 64;
+const CYCLES_PER_DIVIDER: u32 = 32; // 524288 Hz for a dividing ratio of 1
+
+// register offsets
+pub const LENGTH: usize = 0; // SOUND4CNT_L
+pub const ENVELOPE: usize = 1;
+pub const FREQUENCY: usize = 4; // SOUND4CNT_H
+pub const CONTROL: usize = 5;
+
+// https://problemkaputt.de/gbatek.htm#gbasoundchannel4noise
+#[derive(Clone)]
+pub struct Noise {
+    registers: [u8; 6],
+    pub enabled: bool,
+    timer: u32, // cycles until the next shift
+    lfsr: u16,
+    high: bool, // carry of the last shift
+    length: LengthCounter,
+    envelope: Envelope,
+}
+
+impl Noise {
+    pub fn new() -> Noise {
+        Noise {
+            registers: [0; 6],
+            enabled: false,
+            timer: CYCLES_PER_DIVIDER,
+            lfsr: 0x4000,
+            high: false,
+            length: LengthCounter::default(),
+            envelope: Envelope::default(),
+        }
+    }
+
+    fn narrow(&self) -> bool {
+        self.registers[FREQUENCY] & 0b1000 != 0
+    }
+
+    // a dividing ratio of 0 counts as 0.5
+    fn period(&self) -> u32 {
+        let ratio = (self.registers[FREQUENCY] & 0b111) as u32;
+        let shift = (self.registers[FREQUENCY] >> 4) as u32;
+        let divider = if ratio == 0 {
+            CYCLES_PER_DIVIDER / 2
+        } else {
+            CYCLES_PER_DIVIDER * ratio
+        };
+        divider << (shift + 1)
+    }
+
+    fn length_enabled(&self) -> bool {
+        self.registers[CONTROL] & 0b0100_0000 != 0
+    }
+
+    fn trigger(&mut self) {
+        self.enabled = dac_enabled(self.registers[ENVELOPE]);
+        self.timer = self.period();
+        self.lfsr = if self.narrow() { 0x40 } else { 0x4000 };
+        self.length.trigger(LENGTH_MAX);
+        self.envelope.trigger(self.registers[ENVELOPE]);
+
+        trace!(target: "apu", "Noise channel triggered, control: {:02X}, volume: {}",
+            self.registers[FREQUENCY], self.envelope.volume);
+    }
+
+    pub fn tick(&mut self, cycles: u32) {
+        let mut cycles = cycles;
+        while cycles >= self.timer {
+            cycles -= self.timer;
+            self.timer = self.period();
+
+            // the carry decides the output, the taps are fed back from the top
+            self.high = self.lfsr & 1 != 0;
+            self.lfsr >>= 1;
+            if self.high {
+                self.lfsr ^= if self.narrow() { 0x60 } else { 0x6000 };
+            }
+        }
+        self.timer -= cycles;
+    }
+
+    pub fn clock_length(&mut self) {
+        if self.length.clock(self.length_enabled()) {
+            self.enabled = false;
+        }
+    }
+
+    pub fn clock_envelope(&mut self) {
+        self.envelope.clock(self.registers[ENVELOPE]);
+    }
+
+    // current output level, -15 to 15 around the center
+    pub fn output(&self) -> i16 {
+        if !self.enabled {
+            return 0;
+        }
+
+        let volume = self.envelope.volume as i16;
+        if self.high { volume } else { -volume }
+    }
+
+    pub fn state(&self) -> ChannelState {
+        ChannelState::Noise {
+            playing: self.enabled,
+            frequency: CPU_CLOCK as f32 / self.period() as f32,
+            volume: self.envelope.volume,
+            narrow: self.narrow(),
+        }
+    }
+
+    pub fn read_byte(&self, offset: usize) -> u8 {
+        match offset {
+            // the length is write-only
+            ENVELOPE => self.registers[ENVELOPE],
+            FREQUENCY => self.registers[FREQUENCY],
+            // only the length enable flag can be read back
+            CONTROL => self.registers[CONTROL] & 0x40,
+            _ => 0,
+        }
+    }
+
+    pub fn write_byte(&mut self, offset: usize, value: u8) {
+        match offset {
+            LENGTH => {
+                self.registers[LENGTH] = value;
+                self.length.load(LENGTH_MAX, (value & 0x3F) as u16);
+            }
+            ENVELOPE => {
+                self.registers[ENVELOPE] = value;
+                if !dac_enabled(value) {
+                    self.enabled = false;
+                }
+            }
+            CONTROL => {
+                self.registers[CONTROL] = value & 0x40;
+                if value & 0x80 != 0 {
+                    self.trigger();
+                }
+            }
+            _ => self.registers[offset] = value,
+        }
+    }
+}
+
+impl Default for Noise {
+    fn default() -> Self {
+        Noise::new()
+    }
+}
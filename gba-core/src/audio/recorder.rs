@@ -0,0 +1,112 @@
+use super::apu::{Channel, SAMPLE_RATE};
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+const HEADER_SIZE: u32 = 44;
+
+// 16 bit stereo PCM, the sizes in the header are filled in once the recording is finished
+struct WavWriter {
+    writer: BufWriter<File>,
+    data_size: u32,
+}
+
+impl WavWriter {
+    fn create(path: &Path) -> std::io::Result<WavWriter> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        let block_align: u16 = 2 * 2;
+
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&0u32.to_le_bytes())?;
+        writer.write_all(b"WAVE")?;
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&16u32.to_le_bytes())?;
+        writer.write_all(&1u16.to_le_bytes())?; // PCM
+        writer.write_all(&2u16.to_le_bytes())?; // channels
+        writer.write_all(&SAMPLE_RATE.to_le_bytes())?;
+        writer.write_all(&(SAMPLE_RATE * block_align as u32).to_le_bytes())?;
+        writer.write_all(&block_align.to_le_bytes())?;
+        writer.write_all(&16u16.to_le_bytes())?; // bits per sample
+        writer.write_all(b"data")?;
+        writer.write_all(&0u32.to_le_bytes())?;
+
+        Ok(WavWriter { writer, data_size: 0 })
+    }
+
+    fn write(&mut self, sample: [i16; 2]) -> std::io::Result<()> {
+        self.writer.write_all(&sample[0].to_le_bytes())?;
+        self.writer.write_all(&sample[1].to_le_bytes())?;
+        self.data_size += 4;
+        Ok(())
+    }
+
+    fn finish(mut self) -> std::io::Result<()> {
+        self.writer.seek(SeekFrom::Start(4))?;
+        self.writer
+            .write_all(&(HEADER_SIZE - 8 + self.data_size).to_le_bytes())?;
+        self.writer.seek(SeekFrom::Start(40))?;
+        self.writer.write_all(&self.data_size.to_le_bytes())?;
+        self.writer.flush()
+    }
+}
+
+// dumps the mixed output and optionally every channel on its own (stems) to .wav files
+pub struct AudioRecorder {
+    pub path: PathBuf,
+    mix: WavWriter,
+    stems: Vec<WavWriter>, // indexed by Channel, empty if no stems are recorded
+}
+
+impl AudioRecorder {
+    // stems are written next to the mix, e.g. music.wav -> music_fifo_a.wav
+    pub fn create(path: &Path, stems: bool) -> std::io::Result<AudioRecorder> {
+        let mix = WavWriter::create(path)?;
+        let stems = if stems {
+            Channel::ALL
+                .iter()
+                .map(|channel| WavWriter::create(&Self::stem_path(path, *channel)))
+                .collect::<std::io::Result<Vec<_>>>()?
+        } else {
+            Vec::new()
+        };
+
+        Ok(AudioRecorder {
+            path: path.to_path_buf(),
+            mix,
+            stems,
+        })
+    }
+
+    fn stem_path(path: &Path, channel: Channel) -> PathBuf {
+        let suffix = match channel {
+            Channel::Square1 => "square1",
+            Channel::Square2 => "square2",
+            Channel::Wave => "wave",
+            Channel::Noise => "noise",
+            Channel::FifoA => "fifo_a",
+            Channel::FifoB => "fifo_b",
+        };
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        path.with_file_name(format!("{}_{}.wav", stem, suffix))
+    }
+
+    pub fn records_stems(&self) -> bool {
+        !self.stems.is_empty()
+    }
+
+    pub fn write(&mut self, mix: [i16; 2], stems: &[[i16; 2]; 6]) -> std::io::Result<()> {
+        self.mix.write(mix)?;
+        for (writer, &sample) in self.stems.iter_mut().zip(stems) {
+            writer.write(sample)?;
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> std::io::Result<()> {
+        self.mix.finish()?;
+        for writer in self.stems {
+            writer.finish()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+use super::apu::CPU_CLOCK;
+use super::channel::{ChannelState, Envelope, LengthCounter, dac_enabled};
+use tracing::trace;
+
+const LENGTH_MAX: u16 = 64;
+const CYCLES_PER_STEP: u32 = 16; // 131072 Hz for a full 8 step waveform at the highest frequency
+
+// https://problemkaputt.de/gbatek.htm#gbasoundchannel1toneswee
+const DUTY_PATTERNS: [[u8; 8]; 4] = [
+    [0, 0, 0, 0, 0, 0, 0, 1], // 12.5%
+    [1, 0, 0, 0, 0, 0, 0, 1], // 25%
+    [1, 0, 0, 0, 0, 1, 1, 1], // 50%
+    [0, 1, 1, 1, 1, 1, 1, 0], // 75%
+];
+
+// register offsets, channel 2 uses the same layout without the sweep register
+pub const SWEEP: usize = 0; // SOUND1CNT_L
+pub const DUTY_LENGTH: usize = 2; // SOUND1CNT_H, SOUND2CNT_L
+pub const ENVELOPE: usize = 3;
+pub const FREQUENCY: usize = 4; // SOUND1CNT_X, SOUND2CNT_H
+pub const CONTROL: usize = 5;
+
+// tone & sweep (channel 1) and tone (channel 2)
+#[derive(Clone)]
+pub struct Square {
+    registers: [u8; 6],
+    has_sweep: bool,
+    pub enabled: bool,
+    timer: u32, // cycles until the next duty step
+    duty_step: usize,
+    length: LengthCounter,
+    envelope: Envelope,
+    sweep_enabled: bool,
+    sweep_timer: u8,
+    shadow_frequency: u16,
+}
+
+impl Square {
+    pub fn new(has_sweep: bool) -> Square {
+        Square {
+            registers: [0; 6],
+            has_sweep,
+            enabled: false,
+            timer: 2048 * CYCLES_PER_STEP,
+            duty_step: 0,
+            length: LengthCounter::default(),
+            envelope: Envelope::default(),
+            sweep_enabled: false,
+            sweep_timer: 0,
+            shadow_frequency: 0,
+        }
+    }
+
+    fn frequency(&self) -> u16 {
+        u16::from_le_bytes([self.registers[FREQUENCY], self.registers[CONTROL]]) & 0x7FF
+    }
+
+    fn set_frequency(&mut self, frequency: u16) {
+        self.registers[FREQUENCY] = frequency as u8;
+        self.registers[CONTROL] = (self.registers[CONTROL] & !0b111) | (frequency >> 8) as u8;
+    }
+
+    fn period(&self) -> u32 {
+        (2048 - self.frequency() as u32) * CYCLES_PER_STEP
+    }
+
+    fn length_enabled(&self) -> bool {
+        self.registers[CONTROL] & 0b0100_0000 != 0
+    }
+
+    fn trigger(&mut self) {
+        self.enabled = dac_enabled(self.registers[ENVELOPE]);
+        self.timer = self.period();
+        self.length.trigger(LENGTH_MAX);
+        self.envelope.trigger(self.registers[ENVELOPE]);
+
+        if self.has_sweep {
+            let sweep = self.registers[SWEEP];
+            let (period, shift) = ((sweep >> 4) & 0b111, sweep & 0b111);
+
+            self.shadow_frequency = self.frequency();
+            self.sweep_timer = if period == 0 { 8 } else { period };
+            self.sweep_enabled = period != 0 || shift != 0;
+
+            // the overflow check also happens right away
+            if shift != 0 && self.sweep_frequency() > 0x7FF {
+                self.enabled = false;
+            }
+        }
+
+        trace!(target: "apu", "Square channel triggered, frequency: {:03X}, volume: {}",
+            self.frequency(), self.envelope.volume);
+    }
+
+    fn sweep_frequency(&self) -> u16 {
+        let sweep = self.registers[SWEEP];
+        let delta = self.shadow_frequency >> (sweep & 0b111);
+
+        if sweep & 0b1000 != 0 {
+            self.shadow_frequency - delta
+        } else {
+            self.shadow_frequency + delta
+        }
+    }
+
+    pub fn tick(&mut self, cycles: u32) {
+        let mut cycles = cycles;
+        while cycles >= self.timer {
+            cycles -= self.timer;
+            self.timer = self.period();
+            self.duty_step = (self.duty_step + 1) % 8;
+        }
+        self.timer -= cycles;
+    }
+
+    pub fn clock_length(&mut self) {
+        if self.length.clock(self.length_enabled()) {
+            self.enabled = false;
+        }
+    }
+
+    pub fn clock_envelope(&mut self) {
+        self.envelope.clock(self.registers[ENVELOPE]);
+    }
+
+    pub fn clock_sweep(&mut self) {
+        if !self.has_sweep {
+            return;
+        }
+
+        self.sweep_timer = self.sweep_timer.saturating_sub(1);
+        if self.sweep_timer > 0 {
+            return;
+        }
+
+        let sweep = self.registers[SWEEP];
+        let (period, shift) = ((sweep >> 4) & 0b111, sweep & 0b111);
+        self.sweep_timer = if period == 0 { 8 } else { period };
+
+        if !self.sweep_enabled || period == 0 {
+            return;
+        }
+
+        let frequency = self.sweep_frequency();
+        if frequency > 0x7FF {
+            self.enabled = false;
+            return;
+        }
+
+        if shift != 0 {
+            self.shadow_frequency = frequency;
+            self.set_frequency(frequency);
+
+            if self.sweep_frequency() > 0x7FF {
+                self.enabled = false;
+            }
+        }
+    }
+
+    // current output level, -15 to 15 around the center
+    pub fn output(&self) -> i16 {
+        if !self.enabled {
+            return 0;
+        }
+
+        let duty = (self.registers[DUTY_LENGTH] >> 6) as usize;
+        let volume = self.envelope.volume as i16;
+        if DUTY_PATTERNS[duty][self.duty_step] != 0 {
+            volume
+        } else {
+            -volume
+        }
+    }
+
+    pub fn state(&self) -> ChannelState {
+        ChannelState::Square {
+            playing: self.enabled,
+            frequency: CPU_CLOCK as f32 / (self.period() * 8) as f32,
+            duty: self.registers[DUTY_LENGTH] >> 6,
+            volume: self.envelope.volume,
+            sweep: if self.has_sweep {
+                self.registers[SWEEP] & 0x7F
+            } else {
+                0
+            },
+        }
+    }
+
+    pub fn read_byte(&self, offset: usize) -> u8 {
+        match offset {
+            SWEEP => self.registers[SWEEP] & 0x7F,
+            // the length is write-only
+            DUTY_LENGTH => self.registers[DUTY_LENGTH] & 0xC0,
+            ENVELOPE => self.registers[ENVELOPE],
+            // only the length enable flag can be read back
+            FREQUENCY => 0,
+            CONTROL => self.registers[CONTROL] & 0x40,
+            _ => 0,
+        }
+    }
+
+    pub fn write_byte(&mut self, offset: usize, value: u8) {
+        match offset {
+            DUTY_LENGTH => {
+                self.registers[DUTY_LENGTH] = value;
+                self.length.load(LENGTH_MAX, (value & 0x3F) as u16);
+            }
+            ENVELOPE => {
+                self.registers[ENVELOPE] = value;
+                if !dac_enabled(value) {
+                    self.enabled = false;
+                }
+            }
+            CONTROL => {
+                self.registers[CONTROL] = value & 0x47;
+                if value & 0x80 != 0 {
+                    self.trigger();
+                }
+            }
+            _ => self.registers[offset] = value,
+        }
+    }
+}
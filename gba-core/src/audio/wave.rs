@@ -0,0 +1,200 @@
+use super::apu::CPU_CLOCK;
+use super::channel::{ChannelState, LengthCounter};
+use tracing::trace;
+
+const LENGTH_MAX: u16 = 256;
+const CYCLES_PER_SAMPLE: u32 = 8; // 2097152 Hz at the highest rate
+const BANK_SIZE: usize = 16; // 32 4-bit samples
+
+// register offsets
+pub const CONTROL: usize = 0; // SOUND3CNT_L
+pub const LENGTH: usize = 2; // SOUND3CNT_H
+pub const VOLUME: usize = 3;
+pub const RATE: usize = 4; // SOUND3CNT_X
+pub const RESTART: usize = 5;
+
+// https://problemkaputt.de/gbatek.htm#gbasoundchannel3waveoutput
+#[derive(Clone)]
+pub struct Wave {
+    registers: [u8; 6],
+    wave_ram: [[u8; BANK_SIZE]; 2],
+    pub enabled: bool,
+    timer: u32, // cycles until the next sample
+    position: usize,
+    sample: u8, // 4-bit sample currently being output
+    length: LengthCounter,
+}
+
+impl Wave {
+    pub fn new() -> Wave {
+        Wave {
+            registers: [0; 6],
+            wave_ram: [[0; BANK_SIZE]; 2],
+            enabled: false,
+            timer: 2048 * CYCLES_PER_SAMPLE,
+            position: 0,
+            sample: 0,
+            length: LengthCounter::default(),
+        }
+    }
+
+    fn dac_enabled(&self) -> bool {
+        self.registers[CONTROL] & 0b1000_0000 != 0
+    }
+
+    // the bank being played, the other one is mapped to 0x04000090
+    fn bank(&self) -> usize {
+        ((self.registers[CONTROL] >> 6) & 1) as usize
+    }
+
+    // 64 samples across both banks, otherwise 32 from the selected bank
+    fn sample_count(&self) -> usize {
+        if self.registers[CONTROL] & 0b0010_0000 != 0 {
+            64
+        } else {
+            32
+        }
+    }
+
+    fn rate(&self) -> u16 {
+        u16::from_le_bytes([self.registers[RATE], self.registers[RESTART]]) & 0x7FF
+    }
+
+    fn period(&self) -> u32 {
+        (2048 - self.rate() as u32) * CYCLES_PER_SAMPLE
+    }
+
+    fn length_enabled(&self) -> bool {
+        self.registers[RESTART] & 0b0100_0000 != 0
+    }
+
+    fn trigger(&mut self) {
+        self.enabled = self.dac_enabled();
+        self.timer = self.period();
+        self.position = 0;
+        self.length.trigger(LENGTH_MAX);
+
+        trace!(target: "apu", "Wave channel triggered, rate: {:03X}, bank: {}, samples: {}",
+            self.rate(), self.bank(), self.sample_count());
+    }
+
+    fn fetch_sample(&mut self) {
+        let bank = (self.bank() + self.position / 32) % 2;
+        let byte = self.wave_ram[bank][(self.position % 32) / 2];
+
+        // the upper nibble is played first
+        self.sample = if self.position.is_multiple_of(2) {
+            byte >> 4
+        } else {
+            byte & 0x0F
+        };
+    }
+
+    pub fn tick(&mut self, cycles: u32) {
+        let mut cycles = cycles;
+        while cycles >= self.timer {
+            cycles -= self.timer;
+            self.timer = self.period();
+
+            if self.enabled {
+                self.fetch_sample();
+                self.position = (self.position + 1) % self.sample_count();
+            }
+        }
+        self.timer -= cycles;
+    }
+
+    pub fn clock_length(&mut self) {
+        if self.length.clock(self.length_enabled()) {
+            self.enabled = false;
+        }
+    }
+
+    // current output level, -15 to 15 around the center
+    pub fn output(&self) -> i16 {
+        if !self.enabled {
+            return 0;
+        }
+
+        let sample = self.sample as i16 * 2 - 15;
+        let volume = self.registers[VOLUME];
+
+        // the force 75% flag overrides the volume
+        if volume & 0b1000_0000 != 0 {
+            return sample * 3 / 4;
+        }
+
+        match (volume >> 5) & 0b11 {
+            0 => 0,
+            1 => sample,
+            2 => sample / 2,
+            _ => sample / 4,
+        }
+    }
+
+    pub fn state(&self) -> ChannelState {
+        let volume = self.registers[VOLUME];
+        let volume = if volume & 0b1000_0000 != 0 {
+            75
+        } else {
+            [0, 100, 50, 25][((volume >> 5) & 0b11) as usize]
+        };
+
+        ChannelState::Wave {
+            playing: self.enabled,
+            frequency: CPU_CLOCK as f32 / (self.period() * self.sample_count() as u32) as f32,
+            volume,
+            bank: self.bank(),
+            samples: self.sample_count(),
+        }
+    }
+
+    pub fn read_byte(&self, offset: usize) -> u8 {
+        match offset {
+            CONTROL => self.registers[CONTROL] & 0xE0,
+            // the length is write-only
+            VOLUME => self.registers[VOLUME] & 0xE0,
+            // only the length enable flag can be read back
+            RESTART => self.registers[RESTART] & 0x40,
+            _ => 0,
+        }
+    }
+
+    pub fn write_byte(&mut self, offset: usize, value: u8) {
+        match offset {
+            CONTROL => {
+                self.registers[CONTROL] = value;
+                if !self.dac_enabled() {
+                    self.enabled = false;
+                }
+            }
+            LENGTH => {
+                self.registers[LENGTH] = value;
+                self.length.load(LENGTH_MAX, value as u16);
+            }
+            RESTART => {
+                self.registers[RESTART] = value & 0x47;
+                if value & 0x80 != 0 {
+                    self.trigger();
+                }
+            }
+            _ => self.registers[offset] = value,
+        }
+    }
+
+    // wave RAM accesses always go to the bank that is not being played
+    pub fn read_wave_ram(&self, offset: usize) -> u8 {
+        self.wave_ram[self.bank() ^ 1][offset]
+    }
+
+    pub fn write_wave_ram(&mut self, offset: usize, value: u8) {
+        let bank = self.bank() ^ 1;
+        self.wave_ram[bank][offset] = value;
+    }
+}
+
+impl Default for Wave {
+    fn default() -> Self {
+        Wave::new()
+    }
+}
@@ -1,3135 +0,0 @@
-// This file is automatically generated from the GBA cartridge database CSV file.
-// Do not edit this file manually.
-
-use phf::phf_map;
-
-pub static TITLE_DATABASE: phf::Map<&'static str, (u8, bool, &'static str)> = phf_map! {
-    "caf2e99f" => (1, false, r#"007 - Everything or Nothing (Japan)"#),
-    "55ae4312" => (3, false, r#"Apotris (v3.4.5)"#),
-    "56c83c16" => (1, false, r#"007 - NightFire (Europe, USA)"#),
-    "50ab4544" => (1, false, r#"2006 FIFA World Cup - Germany 2006 (Europe, USA)"#),
-    "8f3ff0e4" => (0, false, r#"2K Sports - Major League Baseball 2K7 (USA)"#),
-    "fb7a7567" => (1, false, r#"2 Games in 1: Dr. Mario & Puzzle League (Europe)"#),
-    "4c2b7349" => (1, false, r#"2 Games in One! - Dr. Mario + Puzzle League (USA, Australia)"#),
-    "129bf78f" => (1, false, r#"Dr. Mario & Panel de Pon (Japan)"#),
-    "36717b52" => (0, false, r#"2 Games in One!: Gauntlet + Rampart (Europe)"#),
-    "5f255362" => (0, false, r#"2 Games in One!: Gauntlet + Rampart (USA)"#),
-    "40da6c26" => (0, false, r#"2 Games in One!: Marble Madness + Klax (Europe)"#),
-    "5da05880" => (0, false, r#"2 Games in One!: Marble Madness + Klax (USA)"#),
-    "44e1761a" => (0, false, r#"2 Games in One!: Paperboy + Rampage (Europe)"#),
-    "79fe9284" => (0, false, r#"2 Games in One!: Paperboy + Rampage (USA)"#),
-    "bbf32ee3" => (0, false, r#"2 Games in One!: Spy Hunter + Super Sprint (Europe)"#),
-    "ad2f5353" => (0, false, r#"2 Games in One!: Spy Hunter + Super Sprint (USA)"#),
-    "628a8e32" => (0, false, r#"3 Game Pack!: Candy Land - Chutes and Ladders - Original Memory Game (USA)"#),
-    "e6dc35a9" => (0, false, r#"3 Game Pack!: The Game of Life - Payday - Yahtzee (USA)"#),
-    "20287753" => (0, false, r#"3 Game Pack!: Mouse Trap - Simon - Operation (USA)"#),
-    "8b523ff7" => (0, false, r#"3 Game Pack!: Ker Plunk! - Toss Across - Tip It (USA)"#),
-    "2af65e94" => (0, false, r#"3 Games in One!: Breakout - Centipede - Warlords (Europe)"#),
-    "02e0cca4" => (0, false, r#"3 Games in One!: Breakout - Centipede - Warlords (USA)"#),
-    "b6ef1ed0" => (0, false, r#"3 Games in One!: Super Breakout - Millipede - Lunar Lander (Europe)"#),
-    "d191b56f" => (0, false, r#"3 Games in One!: Super Breakout - Millipede - Lunar Lander (USA)"#),
-    "f42b4e70" => (0, false, r#"3 Games in One!: Yars' Revenge - Asteroids - Pong (Europe)"#),
-    "dbd04637" => (0, false, r#"3 Games in One!: Yars' Revenge - Asteroids - Pong (USA)"#),
-    "43f5e157" => (0, false, r#"Ace Combat Advance (Europe, USA)"#),
-    "e94aafc9" => (1, false, r#"Ace Lightning (Europe)"#),
-    "71720e98" => (1, false, r#"Acrobat Kid (Japan)"#),
-    "d07214a2" => (0, false, r#"Action Man - Robot Atak (Europe)"#),
-    "14a28d68" => (1, false, r#"Activision Anthology (USA)"#),
-    "edcb7d46" => (2, false, r#"Advance GT2 (Japan)"#),
-    "fdc82e98" => (3, false, r#"Advance GTA (Japan)"#),
-    "2487b85b" => (1, false, r#"Advance Guardian Heroes (Europe)"#),
-    "1527ca8c" => (1, false, r#"Advance Guardian Heroes (Japan)"#),
-    "c501917f" => (1, false, r#"Advance Guardian Heroes (USA)"#),
-    "86ed2599" => (3, false, r#"Advance Rally (Japan)"#),
-    "66fb29e9" => (5, false, r#"Advance Wars (Europe)"#),
-    "26fd0fc9" => (5, false, r#"Advance Wars (USA, rev. 1)"#),
-    "dbef116c" => (5, false, r#"Advance Wars (USA)"#),
-    "5a09aae6" => (5, false, r#"Advance Wars 2 - Black Hole Rising (Europe)"#),
-    "5ad0e571" => (5, false, r#"Advance Wars 2 - Black Hole Rising (USA, Australia)"#),
-    "5781acaf" => (3, false, r#"Adventure of Tokyo Disney Sea (Japan)"#),
-    "d2ced674" => (0, false, r#"The Adventures of Jimmy Neutron vs Jimmy Negatron (Europe, USA)"#),
-    "3690c0fd" => (0, false, r#"Jimmy Neutron vs. Jimmy Negatron (Germany)"#),
-    "220ccf25" => (0, false, r#"Jimmy Neutron vs. Jimmy Negatron (Germany, prototype)"#),
-    "d59d753b" => (1, false, r#"The Adventures of Jimmy Neutron Boy Genius - Attack of the Twonkies (Europe,
-			USA)"#),
-    "33816e51" => (1, false, r#"Jimmy Neutron un Garcon Genial - L'Attaque des Twonkies (France)"#),
-    "67756000" => (0, false, r#"The Adventures of Jimmy Neutron Boy Genius - Jet Fusion (Europe, USA)"#),
-    "6f3ea564" => (1, false, r#"Aero the Acro-Bat - Rascal Rival Revenge (Europe, prototype 20020327)"#),
-    "beed50f0" => (0, false, r#"Aero the Acro-Bat - Rascal Rival Revenge (Europe, prototype earlier)"#),
-    "a7e98ebe" => (1, false, r#"Aero the Acro-Bat - Rascal Rival Revenge (Europe)"#),
-    "b47ac020" => (1, false, r#"Aero the Acro-Bat - Rascal Rival Revenge (USA)"#),
-    "90d759e2" => (0, false, r#"Agassi Tennis Generation (Europe)"#),
-    "8ba179b8" => (0, false, r#"Agassi Tennis Generation (USA)"#),
-    "83bc2d2c" => (0, false, r#"Agent Hugo - Roborumble (Europe)"#),
-    "bf553530" => (1, false, r#"AGB Aging Cartridge (World, version 1.0)"#),
-    "bbb6a960" => (1, false, r#"AGB Aging Cartridge (World, version 7.0)"#),
-    "2e69686d" => (1, false, r#"AGB Aging Cartridge (World, version 9.0)"#),
-    "a2f6bcde" => (0, false, r#"Aggressive Inline (Europe)"#),
-    "3a44fcd5" => (0, false, r#"Aggressive Inline (USA)"#),
-    "36a0e152" => (3, false, r#"L'Aigle de Guerre (France)"#),
-    "58a972df" => (1, false, r#"AirForce Delta II (Japan)"#),
-    "ebf757b8" => (1, false, r#"AirForce Delta Storm (USA)"#),
-    "b50cd166" => (2, false, r#"Aka-chan Doubutsuen (Japan)"#),
-    "c1072e26" => (2, false, r#"Aka-chan Doubutsuen (Japan, rev. 1)"#),
-    "f3e41d73" => (3, false, r#"Akumajou Dracula - Circle of the Moon (Japan)"#),
-    "594fbb7c" => (1, false, r#"Disney's Aladdin (Europe)"#),
-    "587f1aef" => (1, false, r#"Disney's Aladdin (Japan)"#),
-    "40b383f6" => (1, false, r#"Disney's Aladdin (USA)"#),
-    "e068728f" => (1, false, r#"Aleck Bordon Adventure - Tower & Shaft Advance (Japan)"#),
-    "25e8e2cd" => (1, false, r#"Alex Rider - Stormbreaker (Europe)"#),
-    "cca55dfd" => (1, false, r#"Alex Rider - Stormbreaker (USA)"#),
-    "dff47236" => (1, false, r#"Alien Hominid (Europe)"#),
-    "0d694ca4" => (0, false, r#"Alienators - Evolution Continues (Europe, USA)"#),
-    "e9c9dd2b" => (1, false, r#"All Grown Up! - Express Yourself (Europe, USA)"#),
-    "e30691fe" => (3, false, r#"All-Star Baseball 2003 - Featuring Derek Jeter (USA)"#),
-    "d6106c26" => (3, false, r#"All-Star Baseball 2004 (USA, prototype earlier)"#),
-    "6aa9b41e" => (3, false, r#"All-Star Baseball 2004 (USA, prototype)"#),
-    "60e2db03" => (3, false, r#"All-Star Baseball 2004 - Featuring Derek Jeter (USA)"#),
-    "654f7916" => (1, false, r#"Altered Beast - Guardian of the Realms (Europe)"#),
-    "c4955f69" => (1, false, r#"Altered Beast - Guardian of the Realms (USA)"#),
-    "d76ad62d" => (2, false, r#"American Bass Challenge (USA)"#),
-    "698377d2" => (1, false, r#"Disney's American Dragon Jake Long - Rise of the Huntsclan! (Europe, USA)"#),
-    "f053ffbf" => (0, false, r#"American Idol (USA)"#),
-    "c8ea6d9e" => (0, false, r#"An American Tail - Fievel's Gold Rush (Europe)"#),
-    "2a882ea6" => (0, false, r#"An American Tail - Fievel's Gold Rush (USA)"#),
-    "da6555d8" => (2, false, r#"Angel Collection - Mezase! Gakuen no Fashion Leader (Japan)"#),
-    "7b46d462" => (2, false, r#"Angel Collection 2 - Pichimo ni Narou (Japan)"#),
-    "c9d41f35" => (2, false, r#"Angelique (Japan)"#),
-    "89e6cd89" => (3, false, r#"Animal Mania - Dokidoki Aishou Check (Japan)"#),
-    "25015475" => (0, false, r#"Animal Snap - Rescue Them 2 by 2 (Europe)"#),
-    "7304dca4" => (0, false, r#"Animal Snap - Rescue Them 2 by 2 (USA)"#),
-    "d53c9438" => (3, false, r#"Animal Yokochou - Doki Doki Kyuushutsu Daisakusen! no Maki (Japan)"#),
-    "41b39214" => (3, false, r#"Animal Yokochou - Doki Doki Shinkyuu Shiken! no Maki (Japan)"#),
-    "840d11c1" => (0, false, r#"Animaniacs - Lights, Camera, Action! (Europe)"#),
-    "52dc386b" => (1, false, r#"The Ant Bully (Europe)"#),
-    "8d51a101" => (1, false, r#"The Ant Bully (USA)"#),
-    "367927ed" => (0, false, r#"Antz - Extreme Racing (Europe)"#),
-    "f4efc5ed" => (0, false, r#"Antz - Extreme Racing (USA)"#),
-    "ad9af125" => (1, false, r#"Ao-Zora to Nakama-tachi - Yume no Bouken (Japan)"#),
-    "4202a9cd" => (0, false, r#"Archer Maclean Presents Pool (USA)"#),
-    "81397ac0" => (0, false, r#"Arctic Tale (USA)"#),
-    "6d174e28" => (0, false, r#"Army Men - Operation Green (USA)"#),
-    "65ac74cc" => (1, false, r#"Army Men - Turf Wars (USA)"#),
-    "9a4a509f" => (0, false, r#"Army Men Advance (Europe, USA)"#),
-    "2dd8cbd7" => (0, false, r#"Around the World in 80 Days (Europe)"#),
-    "c2c22af2" => (0, false, r#"Around the World in 80 Days (USA)"#),
-    "87c25055" => (1, false, r#"Arthur and the Invisibles (USA)"#),
-    "a4dae90b" => (1, false, r#"Arthur and the Minimoys (Europe)"#),
-    "69cde456" => (3, false, r#"Ashita no Joe - Makka ni Moeagare! (Japan)"#),
-    "92360109" => (0, false, r#"Astérix & Obélix - Bash Them All! (Europe)"#),
-    "a9b1ece5" => (1, false, r#"Astérix & Obélix XXL (Europe)"#),
-    "1e2e8937" => (2, false, r#"Astro Boy - Omega Factor (Europe)"#),
-    "e8619031" => (2, false, r#"Astro Boy - Omega Factor (USA)"#),
-    "e2d94b0d" => (2, false, r#"Astro Boy - Tetsuwan Atom - Atom Heart no Himitsu (Japan)"#),
-    "b9b89fdf" => (0, false, r#"Atari Anniversary Advance (Europe)"#),
-    "4505a638" => (0, false, r#"Atari Anniversary Advance (USA)"#),
-    "b3948dbc" => (0, false, r#"Disney's Atlantis - The Lost Empire (Europe)"#),
-    "8bd224c5" => (0, false, r#"Disney's Atlantis - The Lost Empire (Europe, USA)"#),
-    "8919d82c" => (0, false, r#"Atomic Betty (Europe, USA)"#),
-    "4b4e8bc7" => (0, false, r#"ATV - Quad Power Racing (Europe, rev. 1)"#),
-    "9c1a7dcb" => (0, false, r#"ATV - Quad Power Racing (Europe, USA)"#),
-    "b1d2896f" => (0, false, r#"ATV - Thunder Ridge Riders (Europe)"#),
-    "773e73c7" => (0, false, r#"ATV - Thunder Ridge Riders (USA)"#),
-    "946787c0" => (2, false, r#"Avatar - The Last Airbender (USA)"#),
-    "52d8d101" => (0, false, r#"Avatar - The Last Airbender - The Burning Earth (USA)"#),
-    "52fe8a62" => (2, false, r#"Avatar - The Legend of Aang (Europe)"#),
-    "ec5d6afd" => (0, false, r#"Avatar - The Legend of Aang - The Burning Earth (Europe)"#),
-    "216704f6" => (2, false, r#"Azumanga Daiou Advance (Japan)"#),
-    "ee41c0eb" => (2, false, r#"B-Densetsu! Battle B-Daman - Fire Spirits! (Japan)"#),
-    "d6e4064f" => (2, false, r#"B-Densetsu! Battle B-Daman - Moero! B-Damashii!! (Japan)"#),
-    "ab7d6c17" => (1, false, r#"Babar to the Rescue (Europe)"#),
-    "d4073e33" => (1, false, r#"Babar to the Rescue (USA)"#),
-    "5730c85a" => (0, false, r#"Back to Stone (Europe)"#),
-    "4c29c9c8" => (0, false, r#"Back to Stone (USA)"#),
-    "48539b4b" => (1, false, r#"Back Track (Europe, USA)"#),
-    "9f36b4e5" => (1, false, r#"Backyard Baseball (USA)"#),
-    "92bcd192" => (1, false, r#"Backyard Baseball 2006 - Featuring Pros as Kids (USA)"#),
-    "cbda1d31" => (1, false, r#"Backyard Basketball (USA)"#),
-    "7ccc79b4" => (1, false, r#"Backyard Football (USA)"#),
-    "db0723e3" => (1, false, r#"Backyard Football 2006 (USA)"#),
-    "3a8e31af" => (1, false, r#"Backyard Hockey (USA)"#),
-    "4eb19b87" => (1, false, r#"Backyard Skateboarding (USA)"#),
-    "0ee82569" => (1, false, r#"Backyard Sports - Baseball 2007 (USA)"#),
-    "28724ca5" => (1, false, r#"Backyard Sports - Basketball 2007 (USA)"#),
-    "e9fdc229" => (1, false, r#"Backyard Sports - Football 2007 (USA)"#),
-    "9030515d" => (3, false, r#"Bakunetsu Dodge Ball Fighters (Japan)"#),
-    "7f2e0320" => (3, false, r#"Bakuten Shoot Beyblade - Gekitou! Saikyou Blader (Japan)"#),
-    "f01ee06c" => (3, false, r#"Bakuten Shoot Beyblade 2002 - Gekisen! Team Battle!! Kouryuu no Shou - Daichi
-			Hen (Japan)"#),
-    "8778b36a" => (3, false, r#"Bakuten Shoot Beyblade 2002 - Gekisen! Team Battle!! Seiryuu no Shou - Takao
-			Hen (Japan)"#),
-    "1d1bb02b" => (3, false, r#"Bakuten Shoot Beyblade 2002 - Ikuze! Bakutou! Chou Jiryoku Battle!! (Japan)"#),
-    "494492dc" => (1, false, r#"Baldur's Gate - Dark Alliance (Europe)"#),
-    "deedd7d9" => (1, false, r#"Baldur's Gate - Dark Alliance (USA)"#),
-    "b98de3a4" => (1, false, r#"Banjo-Pilot (Europe)"#),
-    "6e4030a1" => (1, false, r#"Banjo-Pilot (USA)"#),
-    "817fd31d" => (1, false, r#"Banjo-Pilot (prototype)"#),
-    "fb4f38e2" => (2, false, r#"Banjo-Kazooie - Grunty's Revenge (Europe)"#),
-    "dfb5aba3" => (2, false, r#"Banjo-Kazooie - Grunty's Revenge (Europe, USA)"#),
-    "106ed779" => (2, false, r#"Banjo-Kazooie - Grunty's Revenge (Europe, prototype)"#),
-    "4e0f10fa" => (2, false, r#"Banjo-Kazooie - Grunty's Revenge (Europe, prototype earlier)"#),
-    "67ad698f" => (2, false, r#"Banjo-Kazooie - La Vendetta di Grunty (Italy)"#),
-    "32544634" => (2, false, r#"Banjo-Kazooie - La Venganza de Grunty (Spain)"#),
-    "116194f2" => (0, false, r#"Barbie - The Princess and the Pauper (Europe)"#),
-    "f508a7b7" => (0, false, r#"Barbie - The Princess and the Pauper (USA)"#),
-    "5661e71a" => (0, false, r#"Barbie and the Magic of Pegasus (Europe)"#),
-    "29d23835" => (0, false, r#"Barbie and the Magic of Pegasus (USA)"#),
-    "db6160eb" => (1, false, r#"Barbie as the Island Princess (USA)"#),
-    "4e8a650c" => (1, false, r#"The Barbie Diaries - High School Mystery (Europe)"#),
-    "5c704cbe" => (1, false, r#"The Barbie Diaries - High School Mystery (USA)"#),
-    "749339b2" => (0, false, r#"Barbie - Groovy Games (Europe)"#),
-    "aa017567" => (0, false, r#"Barbie - Groovy Games (USA)"#),
-    "7c6d9ef3" => (0, false, r#"Barbie Horse Adventures (Europe)"#),
-    "ba184025" => (0, false, r#"Barbie Horse Adventures - Blue Ribbon Race (USA)"#),
-    "7ca7aa12" => (0, false, r#"Barbie in the 12 Dancing Princesses (Europe)"#),
-    "9c0abddb" => (0, false, r#"Barbie in the 12 Dancing Princesses (USA)"#),
-    "f2b06467" => (2, false, r#"Barnyard (Europe)"#),
-    "ff7987da" => (2, false, r#"Barnyard (USA)"#),
-    "339f35b8" => (3, false, r#"Baseball Advance (USA)"#),
-    "1326e683" => (2, false, r#"Bass Tsuri Shiyouze! - Tournament wa Senryaku Da! (Japan)"#),
-    "ef3790d1" => (0, false, r#"Batman - Rise of Sin Tzu (USA)"#),
-    "783ac0c7" => (0, false, r#"Batman - Vengeance (Europe)"#),
-    "9fa17935" => (0, false, r#"Batman - Vengeance (USA)"#),
-    "83c90106" => (1, false, r#"Batman Begins (Europe, USA)"#),
-    "6dce8a26" => (2, false, r#"Battle B-Daman (USA)"#),
-    "5789f441" => (2, false, r#"Battle B-Daman - Fire Spirits! (USA)"#),
-    "d9516e50" => (3, false, r#"Battle Network Rockman EXE (Japan)"#),
-    "98e4f096" => (3, false, r#"Battle Network Rockman EXE 2 (Japan)"#),
-    "41576087" => (3, false, r#"Battle Network Rockman EXE 2 (Japan, rev. 1)"#),
-    "1c57724e" => (3, false, r#"Battle Network Rockman EXE 3 (Japan)"#),
-    "e48e6bc9" => (3, false, r#"Battle Network Rockman EXE 3 (Japan, rev. 1)"#),
-    "1f13c41f" => (3, false, r#"Battle Network Rockman EXE 3 Black (Japan, promo)"#),
-    "fd57493b" => (3, false, r#"Battle Network Rockman EXE 3 Black (Japan, rev. 1)"#),
-    "0c01f3b8" => (1, false, r#"Battle x Battle - Kyodai Gyo Densetsu (Japan)"#),
-    "60bfcc8e" => (1, false, r#"BattleBots - Beyond the BattleBox (Europe)"#),
-    "985b3704" => (1, false, r#"BattleBots - Beyond the BattleBox (USA)"#),
-    "8d5ffbca" => (0, false, r#"BattleBots - Design & Destroy (USA)"#),
-    "31480d89" => (0, false, r#"Battletoads (prototype)"#),
-    "f6989fbf" => (3, false, r#"BB Ball (Japan)"#),
-    "a13760bc" => (3, false, r#"Beast Shooter - Mezase Beast King! (Japan)"#),
-    "da747c99" => (1, false, r#"The Bee Game (USA)"#),
-    "0363c23f" => (1, false, r#"Die Biene Maja - Klatschmohnwiese in Gefahr (Germany)"#),
-    "f87b787b" => (0, false, r#"The Berenstain Bears - Spooky Old Tree (USA)"#),
-    "f71761ba" => (2, false, r#"Best Friends - Hunde & Katzen (Germany)"#),
-    "61709d3d" => (5, false, r#"Best Play Pro Yakyuu Baseball (Japan)"#),
-    "5111df55" => (2, false, r#"Beyblade G-Revolution (Europe)"#),
-    "33afdbe8" => (2, false, r#"Beyblade G-Revolution (USA)"#),
-    "02cf5e5c" => (2, false, r#"Beyblade V-Force - Ultimate Blader Jam (Europe)"#),
-    "4a49272b" => (2, false, r#"Beyblade V-Force - Ultimate Blader Jam (USA)"#),
-    "516ceac6" => (0, false, r#"Bibi Blocksberg - Der Magische Hexenkreis (Germany)"#),
-    "fd8c06ee" => (1, false, r#"Bibi und Tina - Ferien auf dem Martinshof (Germany, rev. 1)"#),
-    "95a19c3c" => (1, false, r#"Bibi und Tina - Ferien auf dem Martinshof (Germany)"#),
-    "cee6904e" => (0, false, r#"The Bible Game (USA)"#),
-    "c54c02d0" => (1, false, r#"Big Mutha Truckers (Europe)"#),
-    "574907f4" => (1, false, r#"Big Mutha Truckers (USA)"#),
-    "5412a24d" => (0, false, r#"Bionicle (Europe)"#),
-    "c38f3530" => (0, false, r#"Bionicle (USA)"#),
-    "daec2264" => (1, false, r#"Bionicle - Matoran Adventures (Europe, USA)"#),
-    "9d66ec5e" => (1, false, r#"Bionicle - Maze of Shadows (Europe, rev. 1)"#),
-    "bce2d68e" => (1, false, r#"Bionicle - Maze of Shadows (Europe)"#),
-    "3fe09c34" => (1, false, r#"Bionicle - Maze of Shadows (USA)"#),
-    "b8dc715b" => (1, false, r#"Bionicle Heroes (Europe)"#),
-    "f4fafccf" => (1, false, r#"Bionicle Heroes (USA)"#),
-    "904b55d4" => (3, false, r#"bit Generations - Boundish (Japan)"#),
-    "a04be1ed" => (3, false, r#"bit Generations - Coloris (Japan)"#),
-    "dd58aecd" => (0, false, r#"Commandos 2 (USA, prototype, 20021128)"#),
-    "812ef3a9" => (3, false, r#"bit Generations - Dialhex (Japan)"#),
-    "0867dc29" => (3, false, r#"bit Generations - Digidrive (Japan)"#),
-    "f0760400" => (3, false, r#"bit Generations - Dotstream (Japan)"#),
-    "d5d43b4b" => (3, false, r#"bit Generations - Orbital (Japan)"#),
-    "b32e0839" => (3, false, r#"bit Generations - Soundvoyager (Japan)"#),
-    "72a55741" => (1, false, r#"Black Belt Challenge (Europe)"#),
-    "05a4303e" => (3, false, r#"Black Black - Bura Bura (Japan)"#),
-    "2b2afa2e" => (2, false, r#"Black Matrix Zero (Japan)"#),
-    "e1d36a8c" => (1, false, r#"Blackthorne (Europe)"#),
-    "8e6dcd53" => (1, false, r#"Blackthorne (USA)"#),
-    "2ccd4915" => (0, false, r#"Blades of Thunder (USA)"#),
-    "9de5cd08" => (4, false, r#"Bleach Advance - Kurenai ni Somaru Soul Society (Japan)"#),
-    "9a91d7f1" => (3, false, r#"Blender Bros. (USA)"#),
-    "0cec865b" => (0, false, r#"Blue Angelo - Angels from the Shrine (Europe, prototype 1)"#),
-    "f1b924dc" => (0, false, r#"Blue Angelo - Angels from the Shrine (Europe, prototype 2)"#),
-    "b6d79476" => (1, false, r#"BMX Trick Racer (USA)"#),
-    "e5c745d8" => (0, false, r#"Board Game Classics (Europe)"#),
-    "69d760bb" => (0, false, r#"Board Game Classics (USA)"#),
-    "0bd9700b" => (3, false, r#"Boboboubo Boubobo - 9 Kyoku Senshi Gag Yuugou (Japan)"#),
-    "166feecb" => (3, false, r#"Boboboubo Boubobo - Bakutou Hajike Taisen (Japan)"#),
-    "37938ca4" => (3, false, r#"Boboboubo Boubobo - Maji de!!? Shinken Battle (Japan)"#),
-    "58105f89" => (3, false, r#"Boboboubo Boubobo - Ougi 87.5 Bakuretsu Hanage Shinken (Japan)"#),
-    "9686c36b" => (0, true, r#"Boktai - The Sun Is in Your Hand (Europe)"#),
-    "cf692572" => (0, false, r#"Boktai - The Sun Is in Your Hand (USA, demo)"#),
-    "e715ac45" => (0, true, r#"Boktai - The Sun Is in Your Hand (USA)"#),
-    "748189b5" => (0, true, r#"Boktai 2 - Solar Boy Django (Europe)"#),
-    "e1ffb2d1" => (0, true, r#"Boktai 2 - Solar Boy Django (USA)"#),
-    "5e3b163e" => (3, false, r#"Boku wa Koukuu Kanseikan (Japan, rev. 1)"#),
-    "09185657" => (3, false, r#"Bokujou Monogatari - Mineral Town no Nakama-tachi (Japan)"#),
-    "7c26672f" => (3, false, r#"Bokujou Monogatari - Mineral Town no Nakama-tachi for Girl (Japan)"#),
-    "a09c0807" => (0, true, r#"Bokura no Taiyou - Taiyou Action RPG (Japan)"#),
-    "70c423b8" => (3, false, r#"Bomberman Jetters - Densetsu no Bomberman (Japan)"#),
-    "b9dea90d" => (1, false, r#"Bomberman Jetters - Game Collection (Japan)"#),
-    "09fc4d53" => (2, false, r#"Bomberman Max 2 - Blue Advance (Europe)"#),
-    "94c620f3" => (2, false, r#"Bomberman Max 2 - Blue Advance (USA)"#),
-    "656cf22e" => (2, false, r#"Bomberman Max 2 - Bomberman Version (Japan)"#),
-    "290d8810" => (2, false, r#"Bomberman Max 2 - Max Version (Japan)"#),
-    "67d2e5b8" => (2, false, r#"Bomberman Max 2 - Red Advance (Europe)"#),
-    "4db517c1" => (2, false, r#"Bomberman Max 2 - Red Advance (USA)"#),
-    "f7f40189" => (3, false, r#"Bomberman Story (Japan)"#),
-    "240282e6" => (3, false, r#"Bomberman Tournament (Europe, USA)"#),
-    "4d540384" => (1, false, r#"Bookworm (USA)"#),
-    "57438e39" => (3, false, r#"Bouken Yuuki Pluster World - Densetsu no Plust Gate (Japan)"#),
-    "3f60bf6a" => (3, false, r#"Bouken Yuuki Pluster World - Densetsu no Plust Gate EX (Japan)"#),
-    "ce7a07f0" => (3, false, r#"Bouken Yuuki Pluster World - Pluston GP (Japan)"#),
-    "1cc42ebc" => (2, false, r#"Bouken-ou Beet - Busters Road (Japan)"#),
-    "128edc4f" => (1, false, r#"Boukyaku no Senritsu - The Melody of Oblivion (Japan)"#),
-    "d41866a9" => (1, false, r#"Boulder Dash EX (Europe)"#),
-    "e77d5f9b" => (1, false, r#"Boulder Dash EX (Japan)"#),
-    "b355365a" => (1, false, r#"Boulder Dash EX (USA)"#),
-    "08a4fdc4" => (0, false, r#"Boxing Fever (Europe, USA)"#),
-    "b30241e2" => (1, false, r#"Bratz (Europe)"#),
-    "21e5a010" => (1, false, r#"Bratz (USA)"#),
-    "08b89540" => (0, false, r#"Bratz - Babyz (Europe)"#),
-    "23e8a494" => (0, false, r#"Bratz - Babyz (USA)"#),
-    "db179296" => (2, false, r#"Bratz - Forever Diamondz (Europe)"#),
-    "f39cadeb" => (2, false, r#"Bratz - Forever Diamondz (Germany)"#),
-    "9e389908" => (2, false, r#"Bratz - Forever Diamondz (USA)"#),
-    "8fc8d6d2" => (1, false, r#"Bratz - Rock Angelz (France)"#),
-    "649588cb" => (1, false, r#"Bratz - Rock Angelz (Germany)"#),
-    "cfff9119" => (1, false, r#"Bratz - Rock Angelz (Spain)"#),
-    "aa245d0c" => (1, false, r#"Bratz - Rock Angelz (Europe, USA)"#),
-    "4386e26c" => (0, false, r#"Bratz - The Movie (Europe, Italian / Spanish)"#),
-    "cd485f79" => (0, false, r#"Bratz - The Movie (Europe)"#),
-    "04f21321" => (0, false, r#"Bratz - The Movie (Germany)"#),
-    "2ef801c0" => (0, false, r#"Bratz - The Movie (USA)"#),
-    "d3660549" => (3, false, r#"Breath of Fire (Europe, English / French / German)"#),
-    "a1c3165d" => (3, false, r#"Breath of Fire (Europe, English)"#),
-    "f06422a8" => (3, false, r#"Breath of Fire (USA)"#),
-    "66e96b35" => (3, false, r#"Breath of Fire - Ryuu no Senshi (Japan)"#),
-    "ff5e8e39" => (3, false, r#"Breath of Fire II (Europe)"#),
-    "6f098da3" => (3, false, r#"Breath of Fire II (USA)"#),
-    "8a824a70" => (3, false, r#"Breath of Fire II - Shimei no Ko (Japan)"#),
-    "cfe69825" => (0, false, r#"Britney's Dance Beat (Europe, English / German)"#),
-    "021cda8e" => (0, false, r#"Britney's Dance Beat (Europe, English / French)"#),
-    "886121a8" => (0, false, r#"Britney's Dance Beat (Europe)"#),
-    "443198d8" => (0, false, r#"Britney's Dance Beat (USA)"#),
-    "3212c09b" => (1, false, r#"Broken Circle (Europe)"#),
-    "e78bc690" => (1, false, r#"Broken Circle (Europe, prototype)"#),
-    "3a23efd7" => (2, false, r#"Broken Sword - The Shadow of the Templars (Europe)"#),
-    "3278ce51" => (2, false, r#"Broken Sword - The Shadow of the Templars (USA)"#),
-    "fd814097" => (1, false, r#"Disney's Brother Bear (Europe, French / German / Italian / Spanish / Dutch /
-			Danish / Swedish)"#),
-    "6220a4b8" => (1, false, r#"Disney's Brother Bear (Europe, English)"#),
-    "342de1d6" => (1, false, r#"Brother Bear (USA)"#),
-    "b5ff39dc" => (1, false, r#"Bruce Lee - Return of the Legend (Europe)"#),
-    "b2a45a4c" => (1, false, r#"Bruce Lee - Return of the Legend (USA)"#),
-    "dfa1a201" => (1, false, r#"Bubble Bobble - Old & New (Europe)"#),
-    "be9fd5e7" => (1, false, r#"Bubble Bobble - Old & New (Japan)"#),
-    "feec503c" => (1, false, r#"Bubble Bobble - Old & New (USA)"#),
-    "4d2a1d88" => (1, false, r#"Buffy - Im Bann der Dämonen (Germany)"#),
-    "339c7586" => (1, false, r#"Buffy contre les Vampires (France)"#),
-    "f5c85beb" => (1, false, r#"Buffy the Vampire Slayer (Europe, USA)"#),
-    "89b09753" => (3, false, r#"Bura Bura Donkey (Japan)"#),
-    "7cff011a" => (0, false, r#"Butt-Ugly Martians - B.K.M. Battles (Europe)"#),
-    "74be9148" => (0, false, r#"Butt-Ugly Martians - B.K.M. Battles (USA)"#),
-    "ed2eb57d" => (1, false, r#"Cabbage Patch Kids - The Patch Puppy Rescue (Europe)"#),
-    "c812a494" => (1, false, r#"Cabbage Patch Kids - The Patch Puppy Rescue (USA)"#),
-    "70a8a141" => (0, false, r#"Cabela's Big Game Hunter (USA)"#),
-    "bd054567" => (1, false, r#"Cabela's Big Game Hunter - 2005 Adventures (Europe, USA)"#),
-    "13dc0731" => (0, false, r#"Caesars Palace Advance - Millennium Gold Edition (USA, prototype)"#),
-    "5d54ece5" => (0, false, r#"Caesars Palace Advance - Millennium Gold Edition (Europe, USA)"#),
-    "498443da" => (5, false, r#"Calciobit (Japan)"#),
-    "37de9a3d" => (1, false, r#"Camp Lazlo - Leaky Lake Games (Europe)"#),
-    "40c2894e" => (1, false, r#"Camp Lazlo - Leaky Lake Games (USA)"#),
-    "0bb2d391" => (0, false, r#"Capcom Classics Mini Mix (USA)"#),
-    "5341b274" => (3, false, r#"Captain Tsubasa - Eikou no Kiseki (Japan)"#),
-    "176da49f" => (0, false, r#"Captain Harlock (Europe, prototype demo)"#),
-    "ea0a1b94" => (2, false, r#"Car Battler Joe (USA)"#),
-    "3d5a2c07" => (0, false, r#"Card Party (Japan)"#),
-    "dbc9cd2e" => (2, false, r#"Cardcaptor Sakura - Sakura Card de Mini Game (Japan)"#),
-    "d5bb189c" => (1, false, r#"Cardcaptor Sakura - Sakura Card Hen - Sakura to Card to Otomodachi (Japan)"#),
-    "6111ed1e" => (1, false, r#"Care Bears - The Care Quests (Europe)"#),
-    "f848c327" => (1, false, r#"Care Bears - The Care Quests (USA)"#),
-    "98d72420" => (1, false, r#"Carrera Power Slide (Europe)"#),
-    "0b058db2" => (1, false, r#"Disney/Pixar Cars (Europe, Spanish / Portuguese)"#),
-    "bd1bfc39" => (1, false, r#"Cars (Europe, French / Dutch)"#),
-    "4f21fd49" => (1, false, r#"Cars (Europe, Danish / Finnish / Norwegian / Swedish)"#),
-    "ebd92f43" => (1, false, r#"Cars (Germany)"#),
-    "6d4de0ee" => (1, false, r#"Disney Pixar Cars (Japan)"#),
-    "107639a8" => (1, false, r#"Cars (Europe, USA)"#),
-    "d3db9787" => (0, false, r#"Disney/Pixar Cars - Mater-National Championship (Europe)"#),
-    "189da289" => (0, false, r#"Disney Pixar Cars - Mater-National Championship (USA)"#),
-    "23cb1a36" => (1, false, r#"Cars - Motori Ruggenti (Italy)"#),
-    "edc0ccee" => (0, false, r#"Cartoon Network Block Party (USA)"#),
-    "066a2705" => (0, false, r#"Cartoon Network Speedway (USA)"#),
-    "ddaf8bac" => (0, false, r#"Casper (Europe)"#),
-    "9f905e60" => (0, false, r#"Casper (USA)"#),
-    "9542e247" => (0, false, r#"Chokkan Hitofude Advance (Japan, prototype)"#),
-    "611535dc" => (3, false, r#"Castlevania (Europe)"#),
-    "284e3092" => (3, false, r#"Castlevania - Akatsuki no Minuet (Japan)"#),
-    "d0c91f74" => (3, false, r#"Castlevania - Aria of Sorrow (Europe)"#),
-    "35536183" => (3, false, r#"Castlevania - Aria of Sorrow (USA)"#),
-    "379b3248" => (3, false, r#"Castlevania - Byakuya no Concerto (Japan)"#),
-    "1cc059a4" => (3, false, r#"Castlevania - Circle of the Moon (USA)"#),
-    "521b3091" => (3, false, r#"Castlevania - Harmony of Dissonance (Europe)"#),
-    "88c1b562" => (3, false, r#"Castlevania - Harmony of Dissonance (USA)"#),
-    "11a9b76e" => (0, false, r#"Castleween (Europe)"#),
-    "13c2249e" => (0, false, r#"The Cat in the Hat by Dr. Seuss (USA)"#),
-    "1adb373d" => (1, false, r#"Catwoman (Europe, USA)"#),
-    "c3aa382d" => (2, false, r#"Catz (Europe, English / French / German / Italian)"#),
-    "ce418ed1" => (2, false, r#"Catz (Europe, USA)"#),
-    "14a87b75" => (1, false, r#"Chaoji Maliou 2 (China)"#),
-    "d97d4156" => (2, false, r#"Chaoji Maliou Shijie (China)"#),
-    "2b3fcc74" => (1, false, r#"Charlie and the Chocolate Factory (Europe)"#),
-    "4e90884d" => (1, false, r#"Charlie and the Chocolate Factory (USA)"#),
-    "8658bb4f" => (1, false, r#"Charlotte's Web (Europe)"#),
-    "ba511fb2" => (1, false, r#"Charlotte's Web (USA)"#),
-    "e2efc2aa" => (1, false, r#"The Cheetah Girls (USA)"#),
-    "27e7fd09" => (1, false, r#"Chessmaster (Europe)"#),
-    "55ef8392" => (1, false, r#"Chessmaster (France)"#),
-    "06abacc5" => (1, false, r#"Chessmaster (Germany)"#),
-    "25b0e933" => (1, false, r#"Chessmaster (USA)"#),
-    "dcbd4991" => (1, false, r#"Chicken Little (Japan)"#),
-    "1e86f5b0" => (1, false, r#"Chicken Little (Europe, USA)"#),
-    "5749fdc9" => (0, false, r#"Chicken Shoot (Europe)"#),
-    "4b22e081" => (0, false, r#"Chicken Shoot (USA)"#),
-    "02d12544" => (0, false, r#"Chicken Shoot 2 (Europe)"#),
-    "1dfe38c2" => (0, false, r#"Chicken Shoot 2 (USA)"#),
-    "2c00b4e6" => (3, false, r#"Chinmoku no Iseki - Estpolis Gaiden (Japan)"#),
-    "ddc4b118" => (1, false, r#"Chobits for Game Boy Advance - Atashi Dake no Hito (Japan)"#),
-    "d4f8152e" => (3, false, r#"Chocobo Land - A Game of Dice (Japan)"#),
-    "93fe0e34" => (3, false, r#"Choro Q Advance (Japan)"#),
-    "f5ed5f14" => (3, false, r#"Choro Q Advance 2 (Japan)"#),
-    "a4f8b4b4" => (1, false, r#"Chou Makaimura R (Japan)"#),
-    "7e198522" => (1, false, r#"The Chronicles of Narnia - The Lion, the Witch and the Wardrobe (Europe, USA)"#),
-    "8ab607c0" => (5, false, r#"ChuChu Rocket! (Europe)"#),
-    "2c8c1b5a" => (5, false, r#"ChuChu Rocket! (Japan)"#),
-    "057fb1b6" => (5, false, r#"ChuChu Rocket! (USA)"#),
-    "ebd928ed" => (2, false, r#"CIMA - The Enemy (USA)"#),
-    "e8bf28e2" => (1, false, r#"Disney's Cinderella - Magical Dreams (Europe)"#),
-    "22f19169" => (1, false, r#"Disney's Cinderella - Magical Dreams (Europe, USA)"#),
-    "8376d53b" => (1, false, r#"Cinnamoroll - Koko ni Iru yo (Japan)"#),
-    "5a2cada1" => (1, false, r#"Cinnamon Yume no Daibouken (Japan)"#),
-    "a1cba145" => (1, false, r#"Cinnamon Fuwafuwa Daibouken (Japan)"#),
-    "cd67f2ba" => (1, false, r#"Classic NES Series - Bomberman (Europe, USA)"#),
-    "2b510046" => (1, false, r#"NES Classics - Castlevania (Europe)"#),
-    "cfc1f558" => (1, false, r#"Classic NES Series - Castlevania (USA)"#),
-    "ba82d416" => (1, false, r#"Classic NES Series - Donkey Kong (Europe, USA)"#),
-    "ca9f5d2f" => (1, false, r#"Classic NES Series - Dr. Mario (Europe, USA)"#),
-    "945c292f" => (2, false, r#"Classic NES Series - Excitebike (Europe, USA)"#),
-    "3126d612" => (1, false, r#"Classic NES Series - Ice Climber (Europe, USA)"#),
-    "68b1b7a8" => (2, false, r#"Classic NES Series - The Legend of Zelda (Europe, USA)"#),
-    "b2a153c3" => (1, false, r#"Classic NES Series - Metroid (Europe, USA)"#),
-    "231f80d6" => (1, false, r#"Classic NES Series - Pac-Man (Europe, USA)"#),
-    "7eddffad" => (1, false, r#"Classic NES Series - Super Mario Bros. (Europe, USA)"#),
-    "9e923716" => (1, false, r#"Classic NES Series - Xevious (Europe, USA)"#),
-    "f2dc3b09" => (2, false, r#"Classic NES Series - Zelda II - The Adventure of Link (Europe, USA)"#),
-    "dab7c402" => (0, false, r#"Cocoto - Kart Racer (Europe)"#),
-    "27411ef5" => (0, false, r#"Cocoto - Platform Jumper (Europe)"#),
-    "420a81b7" => (0, false, r#"Codename - Kids Next Door - Operation S.O.D.A. (USA)"#),
-    "70520c5c" => (1, false, r#"Colin McRae Rally 2.0 (Europe)"#),
-    "a2e19014" => (1, false, r#"Colin McRae Rally 2.0 (USA)"#),
-    "330a0975" => (1, false, r#"Columns Crown (Europe)"#),
-    "a592f7df" => (1, false, r#"Columns Crown (Japan)"#),
-    "6242ffb2" => (1, false, r#"Columns Crown (USA)"#),
-    "4e5bfa90" => (3, false, r#"Combat Choro Q - Advance Daisakusen (Japan)"#),
-    "9f30002f" => (1, false, r#"Comix Zone (Europe)"#),
-    "5f443b14" => (0, false, r#"Contra Advance - The Alien Wars EX (Europe)"#),
-    "a817aa47" => (0, false, r#"Contra Hard Spirits (Japan)"#),
-    "59781c54" => (0, false, r#"Contra Advance - The Alien Wars EX (USA)"#),
-    "dd1499a0" => (0, false, r#"Contra Advance (prototype)"#),
-    "a4c6db01" => (1, false, r#"Corvette (USA)"#),
-    "034d2d4b" => (1, false, r#"Crash Bandicoot - The Huge Adventure (USA)"#),
-    "0f1d06a0" => (1, false, r#"Crash Bandicoot 2 - N-Tranced (Europe)"#),
-    "2e16184a" => (1, false, r#"Crash Bandicoot 2 - N-Tranced (USA)"#),
-    "64767b34" => (1, false, r#"Crash Bandicoot Advance (Japan)"#),
-    "0e6a4fee" => (1, false, r#"Crash Bandicoot Advance - Wakuwaku Tomodachi Daisakusen! (Japan)"#),
-    "04fba7cf" => (1, false, r#"Crash Bandicoot Advance 2 - Guruguru Saimin Dai-panic!? (Japan)"#),
-    "70f8291f" => (1, false, r#"Crash Bandicoot Bakusou Nitro Cart (Japan)"#),
-    "f24c8e77" => (1, false, r#"Crash Bandicoot Fusion (Europe)"#),
-    "3ddf07a1" => (1, false, r#"Crash Bandicoot Purple - Ripto's Rampage (USA, rev. A)"#),
-    "fc284e05" => (1, false, r#"Crash Bandicoot Purple - Ripto's Rampage (USA)"#),
-    "ccfd654f" => (1, false, r#"Crash Bandicoot XS (Europe)"#),
-    "03925772" => (1, false, r#"Crash Nitro Kart (Europe)"#),
-    "e6d58aa0" => (1, false, r#"Crash Nitro Kart (USA)"#),
-    "d1d07fa6" => (1, false, r#"Crash of the Titans (Europe)"#),
-    "6f28b009" => (1, false, r#"Crash of the Titans (USA)"#),
-    "6e936a54" => (2, false, r#"Crayon Shin-chan - Arashi o Yobu Cinemaland no Daibouken! (Japan)"#),
-    "45c84466" => (2, false, r#"Crayon Shin-chan - Densetsu o Yobu Omake no Miyako Shockgaan! (Japan)"#),
-    "ff7e2e9b" => (1, false, r#"Crazy Chase (Europe, prototype early)"#),
-    "5f331132" => (1, false, r#"Crazy Chase (Europe, prototype)"#),
-    "313f4ffc" => (1, false, r#"Crazy Chase (Europe)"#),
-    "caba1db9" => (1, false, r#"Crazy Chase (USA)"#),
-    "2723236b" => (1, false, r#"Crazy Frog Racer (Europe)"#),
-    "4aebd704" => (1, false, r#"Crazy Taxi - Catch a Ride (Europe)"#),
-    "8ec709d6" => (1, false, r#"Crazy Taxi - Catch a Ride (USA)"#),
-    "4f18f57e" => (2, false, r#"Creatures (Europe, English / Italian / Spanish)"#),
-    "68206301" => (2, false, r#"Creatures (Europe, English / French / German)"#),
-    "a2aad079" => (3, false, r#"Croket! - Yume no Banker Survival! (Japan)"#),
-    "8d5e08d5" => (3, false, r#"Croket! 2 - Yami no Bank to Banqueen (Japan)"#),
-    "aec6d07b" => (3, false, r#"Croket! 3 - Granu Oukoku no Nazo (Japan)"#),
-    "c86bf751" => (3, false, r#"Croket! 4 - Bank no Mori no Mamorigami (Japan)"#),
-    "f0e81971" => (3, false, r#"Croket! Great - Toki no Boukensha (Japan)"#),
-    "b9a75ac4" => (0, false, r#"Crouching Tiger, Hidden Dragon (Europe)"#),
-    "204ff8d5" => (0, false, r#"Crouching Tiger, Hidden Dragon (USA, prototype)"#),
-    "579ba507" => (0, false, r#"Crouching Tiger, Hidden Dragon (USA)"#),
-    "5436d5da" => (0, false, r#"Cruis'n Velocity (USA, prototype)"#),
-    "adf14db5" => (0, false, r#"Cruis'n Velocity (Europe, USA)"#),
-    "55fe8579" => (1, false, r#"Crushed Baseball (USA)"#),
-    "40a0e1c9" => (0, false, r#"CT Special Forces (Europe, prototype)"#),
-    "a20d9adb" => (0, false, r#"CT Special Forces (Europe)"#),
-    "aea22ae0" => (0, false, r#"CT Special Forces (USA)"#),
-    "863cbf31" => (0, false, r#"CT Special Forces - Back to Hell (Europe)"#),
-    "7dc40eda" => (0, false, r#"CT Special Forces - Bioterror (Europe)"#),
-    "d2b58a33" => (0, false, r#"CT Special Forces 2 - Back in the Trenches (USA)"#),
-    "4171c811" => (1, false, r#"Cubix - Robots for Everyone - Clash 'N Bash (USA)"#),
-    "eef104b0" => (0, false, r#"Curious George (Europe)"#),
-    "f6b549dd" => (0, false, r#"Curious George (USA)"#),
-    "f533dc13" => (2, false, r#"Custom Robo GX (Japan)"#),
-    "0020a2ed" => (3, false, r#"Cyberdrive Zoids - Kijuu no Senshi Hyuu (Japan)"#),
-    "c6ccca05" => (1, false, r#"Dai-mahjong. (Japan)"#),
-    "3d644946" => (5, false, r#"Daisenryaku for Game Boy Advance (Japan)"#),
-    "cbb8cdb8" => (3, false, r#"Daisuki Teddy (Japan)"#),
-    "0f4b50ae" => (3, false, r#"Dan Doh!! Tobase Shouri no Smile Shot (Japan)"#),
-    "e1d5cff1" => (3, false, r#"Dan Doh!! Xi (Japan)"#),
-    "e2316d47" => (1, false, r#"Dancing Sword - Senkou (Japan)"#),
-    "506780d1" => (1, false, r#"Danny Phantom - The Ultimate Enemy (Europe, English / German / Spanish)"#),
-    "e1de905f" => (1, false, r#"Danny Phantom - The Ultimate Enemy (Europe, English / French / Dutch)"#),
-    "1384ecea" => (1, false, r#"Danny Phantom - The Ultimate Enemy (USA)"#),
-    "c22e7a2f" => (1, false, r#"Danny Phantom - Urban Jungle (USA)"#),
-    "2d59c1d5" => (1, false, r#"Danny Phantom - Dschungelstadt (Germany)"#),
-    "41a9b849" => (0, false, r#"Daredevil (Europe)"#),
-    "4bb9420e" => (0, false, r#"Daredevil (Germany)"#),
-    "d438347e" => (0, false, r#"Daredevil (USA)"#),
-    "9cf479d2" => (0, false, r#"Dark Eden (demo)"#),
-    "51beeed7" => (1, false, r#"Darius R (Japan)"#),
-    "de5dcdee" => (0, false, r#"Dark Arena (Europe, USA)"#),
-    "8e39fee9" => (2, false, r#"Dave Mirra Freestyle BMX 2 (Europe, rev. 1)"#),
-    "69e89581" => (2, false, r#"Dave Mirra Freestyle BMX 2 (Europe)"#),
-    "64b1e921" => (2, false, r#"Dave Mirra Freestyle BMX 2 (USA)"#),
-    "8d8f26a5" => (2, false, r#"Dave Mirra Freestyle BMX 3 (Europe, USA)"#),
-    "e9a34f49" => (1, false, r#"David Beckham Soccer (Europe)"#),
-    "7e4db1cc" => (1, false, r#"David Beckham Soccer (USA)"#),
-    "4c7375f4" => (1, false, r#"Davis Cup (Europe)"#),
-    "82b4d89c" => (1, false, r#"Davis Cup (USA)"#),
-    "2994de1d" => (0, false, r#"Dead to Rights (Europe)"#),
-    "52c1826c" => (0, false, r#"Dead to Rights (USA)"#),
-    "fe7c8402" => (1, false, r#"Deadly Skies (Europe)"#),
-    "b6c00edb" => (0, false, r#"Deal or No Deal (USA)"#),
-    "2e271c13" => (1, false, r#"Defender (USA)"#),
-    "3b86fdc4" => (1, false, r#"Defender (Europe)"#),
-    "6968959a" => (1, false, r#"Defender of the Crown (Europe)"#),
-    "de6698fe" => (1, false, r#"Defender of the Crown (USA)"#),
-    "1bab58bd" => (3, false, r#"DemiKids - Dark Version (USA)"#),
-    "dc4357c4" => (3, false, r#"DemiKids - Light Version (USA)"#),
-    "1cc36766" => (0, false, r#"Demon Driver - Time to Burn Rubber! (Europe)"#),
-    "9374af5e" => (0, false, r#"Demon Driver - Time to Burn Rubber! (USA)"#),
-    "134be954" => (0, false, r#"Demon's Crest (prototype)"#),
-    "6144ec15" => (1, false, r#"Denki Blocks! (Europe)"#),
-    "0053fd6a" => (1, false, r#"Denki Blocks! (Japan)"#),
-    "e064107f" => (1, false, r#"Denki Blocks! (USA)"#),
-    "fae94c8b" => (3, false, r#"Densetsu no Stafy (Japan)"#),
-    "ea73eb54" => (3, false, r#"Densetsu no Stafy 2 (Japan)"#),
-    "fcaf1aa8" => (3, false, r#"Densetsu no Stafy 3 (Japan)"#),
-    "2d6e4c3b" => (3, false, r#"Densetsu no Stafy 3 (Japan, rev. 1)"#),
-    "9746ef12" => (5, false, r#"Derby Stallion Advance (Japan)"#),
-    "cc1c1405" => (0, false, r#"Desert Strike Advance (USA)"#),
-    "51f50f7d" => (0, false, r#"Deutschland Sucht den Superstar (Germany)"#),
-    "f5436f5d" => (0, false, r#"Dexter's Laboratory - Chess Challenge (Europe)"#),
-    "ae61322e" => (0, false, r#"Dexter's Laboratory - Chess Challenge (USA)"#),
-    "3e8012bf" => (1, false, r#"Dexter's Laboratory - Deesaster Strikes! (Europe)"#),
-    "6bf168e6" => (1, false, r#"Dexter's Laboratory - Deesaster Strikes! (USA, rev. 1)"#),
-    "e948d412" => (1, false, r#"Dexter's Laboratory - Deesaster Strikes! (USA)"#),
-    "a1288427" => (3, false, r#"Di Gi Charat - DigiCommunication (Japan)"#),
-    "a2d6cea6" => (2, false, r#"Diadroids World - Evil Teikoku no Yabou (Japan)"#),
-    "057dc388" => (0, false, r#"Diddy Kong Pilot (prototype)"#),
-    "1372e829" => (2, false, r#"DigiCommunication 2 - Datou! Black Gemagema Dan (Japan)"#),
-    "ea5fc3da" => (1, false, r#"Digimon - Battle Spirit (Europe)"#),
-    "ceef2008" => (1, false, r#"Digimon - Battle Spirit (USA)"#),
-    "00c906a3" => (1, false, r#"Digimon - Battle Spirit 2 (Europe)"#),
-    "c4c246ef" => (1, false, r#"Digimon - Battle Spirit 2 (USA)"#),
-    "4f1b0157" => (1, false, r#"Digimon Racing (Europe)"#),
-    "c50468a8" => (1, false, r#"Digimon Racing (Japan)"#),
-    "dadd4c10" => (1, false, r#"Digimon Racing (USA)"#),
-    "7bd01b31" => (1, false, r#"Dinotopia - The Timestone Pirates (Europe, USA)"#),
-    "4a2b9e0b" => (0, false, r#"Disney Princess (Europe, USA)"#),
-    "3944bd7e" => (0, false, r#"Disney Princesse (France)"#),
-    "e21b4285" => (0, false, r#"Disneys Prinzessinnen (Germany)"#),
-    "481e668e" => (0, false, r#"Disney Principesse (Italy)"#),
-    "425785c8" => (0, false, r#"Disney Princesas (Spain)"#),
-    "92c695cc" => (1, false, r#"Disney Princess - Royal Adventure (Europe)"#),
-    "c6ad2251" => (1, false, r#"Disney Princess - Royal Adventure (USA)"#),
-    "d6d89f81" => (1, false, r#"Disney Sports - Basketball (Europe)"#),
-    "3911b4b6" => (1, false, r#"Disney Sports - Basketball (Japan)"#),
-    "80ec6cf0" => (1, false, r#"Disney Sports - Basketball (USA)"#),
-    "01413db1" => (1, false, r#"Disney Sports - Football (USA)"#),
-    "b92e9057" => (1, false, r#"Disney Sports - American Football (Japan)"#),
-    "72dcb4f4" => (1, false, r#"Disney Sports - Motocross (Europe)"#),
-    "1a62d76f" => (1, false, r#"Disney Sports - Motocross (Japan)"#),
-    "a771ad79" => (1, false, r#"Disney Sports - Motocross (USA)"#),
-    "10c05c7b" => (1, false, r#"Disney Sports - Skateboarding (Europe)"#),
-    "5df4a476" => (1, false, r#"Disney Sports - Skateboarding (Japan)"#),
-    "37ffd837" => (1, false, r#"Disney Sports - Skateboarding (USA)"#),
-    "77b7fcce" => (1, false, r#"Disney Sports - Snowboarding (Europe)"#),
-    "bdc23a47" => (1, false, r#"Disney Sports - Snowboarding (Japan)"#),
-    "4733693c" => (1, false, r#"Disney Sports - Snowboarding (USA)"#),
-    "33f73199" => (1, false, r#"Disney Sports - Football (Europe)"#),
-    "680957e7" => (1, false, r#"Disney Sports - Soccer (Japan)"#),
-    "2f1316d2" => (1, false, r#"Disney Sports - Soccer (USA)"#),
-    "6bb63774" => (1, false, r#"Disney's Party (Europe, USA)"#),
-    "f93b73d9" => (3, false, r#"DK - King of Swing (Europe)"#),
-    "049626d1" => (3, false, r#"DK - King of Swing (USA, demo Kiosk)"#),
-    "d610b239" => (3, false, r#"DK - King of Swing (USA, Australia)"#),
-    "aa8dea30" => (1, false, r#"Dogz (Europe, English)"#),
-    "10ac14f9" => (1, false, r#"Dogz (France)"#),
-    "2216fbd6" => (1, false, r#"Dogz (Europe, English / French / German / Italian)"#),
-    "124f27c0" => (1, false, r#"Dogz (USA)"#),
-    "5236473d" => (2, false, r#"Dogz - Fashion (Europe)"#),
-    "63bd3463" => (2, false, r#"Dogz - Fashion (USA)"#),
-    "28163523" => (2, false, r#"Dogz 2 (Europe, English)"#),
-    "41ce1eed" => (2, false, r#"Dogz 2 (Europe, English / French / German / Italian)"#),
-    "0e2aca9e" => (2, false, r#"Dogz 2 (USA, rev. 1)"#),
-    "654df50e" => (3, false, r#"Dokapon (Europe)"#),
-    "5617e407" => (3, false, r#"Dokapon (USA)"#),
-    "45b3edc4" => (3, false, r#"Dokapon Q - Monster Hunter! (Japan)"#),
-    "fe198026" => (2, false, r#"Dokidoki Cooking Series 1 - Komugi-chan no Happy Cake (Japan)"#),
-    "08dfc956" => (2, false, r#"Dokidoki Cooking Series 2 - Gourmet Kitchen - Suteki na Obentou (Japan)"#),
-    "a7279ed5" => (3, false, r#"Dokodemo Taikyoku - Yakuman Advance (Japan, rev. 1)"#),
-    "c2424edd" => (3, false, r#"Dokodemo Taikyoku - Yakuman Advance (Japan)"#),
-    "e743c611" => (3, false, r#"Domo-kun no Fushigi Television (Japan)"#),
-    "4c086a5e" => (0, false, r#"Disney's Donald Duck "Adv@nce"!*# (Europe)"#),
-    "0195de8f" => (0, false, r#"Disney's Donald Duck Advance (Japan)"#),
-    "936daab2" => (0, false, r#"Disney's Donald Duck "Adv@nce"!*# (USA)"#),
-    "500922e8" => (1, false, r#"Donchan Puzzle Hanabi de Dohn Advance (Japan)"#),
-    "41d277fe" => (1, false, r#"Donkey Kong Country (Europe)"#),
-    "12f7a968" => (1, false, r#"Donkey Kong Country (USA)"#),
-    "aef11223" => (2, false, r#"Donkey Kong Country 2 (Europe)"#),
-    "11417fc1" => (2, false, r#"Donkey Kong Country 2 (USA, Australia)"#),
-    "708a6168" => (1, false, r#"Donkey Kong Country 3 (Europe)"#),
-    "fe03e5af" => (1, false, r#"Donkey Kong Country 3 (USA, Australia)"#),
-    "58c641b3" => (1, false, r#"Doom (Europe, USA)"#),
-    "60cd3207" => (1, false, r#"Doom II (Europe)"#),
-    "c885d9e9" => (1, false, r#"Doom II (USA)"#),
-    "7b311c5c" => (0, false, r#"Dora the Explorer - Dora's World Adventure! (USA)"#),
-    "650fdfdb" => (0, false, r#"Dora the Explorer - Super Spies (USA)"#),
-    "528bca02" => (0, false, r#"Dora the Explorer - Super Star Adventures! (Europe)"#),
-    "2cee050f" => (0, false, r#"Dora the Explorer - Super Star Adventures! (USA)"#),
-    "9439541c" => (0, false, r#"Dora the Explorer - The Search for the Pirate Pig's Treasure (USA)"#),
-    "e28afebc" => (2, false, r#"Doraemon - Dokodemo Walker (Japan)"#),
-    "95565762" => (3, false, r#"Doraemon - Midori no Wakusei Dokidoki Daikyuushutsu! (Japan)"#),
-    "a3330e8f" => (0, false, r#"Double Dragon Advance (Japan)"#),
-    "764fafb5" => (0, false, r#"Double Dragon Advance (USA)"#),
-    "b9c15f87" => (4, false, r#"Doubutsu-jima no Chobigurumi (Japan, rev. 1)"#),
-    "c764cc7e" => (4, false, r#"Doubutsu-jima no Chobigurumi (Japan)"#),
-    "16347e33" => (1, false, r#"Doubutsu-jima no Chobigurumi 2 - Tama-chan Monogatari (Japan)"#),
-    "eb326294" => (0, false, r#"Downforce (Europe)"#),
-    "32e9a0bd" => (2, false, r#"Downtown Nekketsu Monogatari EX (Japan)"#),
-    "a5dbe5ae" => (1, false, r#"The Cat in the Hat (Europe)"#),
-    "46736f58" => (1, false, r#"Dr. Muto (Europe)"#),
-    "13adbf3b" => (1, false, r#"Dr. Seuss' The Cat in the Hat (USA)"#),
-    "e9848d13" => (2, false, r#"Dr. Sudoku (Europe)"#),
-    "fc003e2b" => (2, false, r#"Dr. Sudoku (USA)"#),
-    "6c135820" => (1, false, r#"Dragon Ball - Advanced Adventure (Europe)"#),
-    "8a06337a" => (1, false, r#"Dragon Ball - Advance Adventure (Japan)"#),
-    "4e2cc84d" => (1, false, r#"Dragon Ball - Advance Adventure (Korea)"#),
-    "7d7306df" => (1, false, r#"Dragon Ball - Advanced Adventure (USA)"#),
-    "ad964a91" => (2, false, r#"Dragon Ball GT - Transformation (USA)"#),
-    "01c1707f" => (2, false, r#"Dragon Ball Z - Buu's Fury (USA)"#),
-    "f6124d7f" => (3, false, r#"Dragon Ball Z - Collectible Card Game (USA)"#),
-    "b4d9572c" => (1, false, r#"Dragon Ball Z - Supersonic Warriors (Europe)"#),
-    "9a857a70" => (1, false, r#"Dragon Ball Z - Supersonic Warriors (USA)"#),
-    "6c0f0b86" => (1, false, r#"Dragon Ball Z - Bukuu Tougeki (Japan)"#),
-    "e4893dd7" => (1, false, r#"Dragon Ball Z - Moogongtoogeuk (Korea)"#),
-    "d0e79665" => (1, false, r#"Dragon Ball Z - Taiketsu (Europe)"#),
-    "64ec07e7" => (1, false, r#"Dragon Ball Z - Taiketsu (USA)"#),
-    "ca6e149c" => (1, false, r#"Dragon Ball Z - The Legacy of Goku (Europe)"#),
-    "d47ccff4" => (1, false, r#"Dragon Ball Z - The Legacy of Goku (USA)"#),
-    "20684433" => (2, false, r#"Dragon Ball Z - The Legacy of Goku II (Europe)"#),
-    "204142e1" => (2, false, r#"Dragon Ball Z - The Legacy of Goku II (USA)"#),
-    "b3297d59" => (2, false, r#"Dragon Ball Z - The Legacy of Goku II International (Japan)"#),
-    "e5a571ad" => (3, false, r#"Dragon Drive - World D Break (Japan)"#),
-    "2b077791" => (5, false, r#"Dragon Quest Characters - Torneko no Daibouken 2 Advance - Fushigi no Dungeon
-			(Japan)"#),
-    "c891b2a0" => (4, false, r#"Dragon Quest Characters - Torneko no Daibouken 3 Advance - Fushigi no Dungeon
-			(Japan)"#),
-    "3c24abcc" => (2, false, r#"Dragon Quest Monsters - Caravan Heart (Japan)"#),
-    "8af23450" => (0, false, r#"Dragon Tales - Dragon Adventures (USA)"#),
-    "f0ca6d70" => (1, false, r#"Dragon's Rock (Europe)"#),
-    "63a4422e" => (0, false, r#"Drake & Josh (USA)"#),
-    "e60ec183" => (0, false, r#"Drill Dozer (USA)"#),
-    "b617b354" => (1, false, r#"Driv3r (Europe)"#),
-    "6c66cfbe" => (1, false, r#"Driv3r (USA)"#),
-    "95f2c572" => (1, false, r#"Driven (Europe)"#),
-    "f2068738" => (1, false, r#"Driven (USA)"#),
-    "69ee551c" => (1, false, r#"Driver 2 Advance (Europe)"#),
-    "b24fa448" => (1, false, r#"Driver 2 Advance (Europe, prototype)"#),
-    "bbe2a163" => (1, false, r#"Driver 2 Advance (USA)"#),
-    "d6101bc0" => (1, false, r#"Drome Racers (Europe)"#),
-    "ad356fb9" => (1, false, r#"Drome Racers (USA)"#),
-    "f14b65ef" => (0, false, r#"Droopy's Tennis Open (Europe)"#),
-    "484e7d56" => (0, false, r#"Droopy's Tennis Open (Europe, prototype)"#),
-    "aff3a0c6" => (1, false, r#"Dual Blades (USA)"#),
-    "522f1f51" => (1, false, r#"Dual Blades (Japan)"#),
-    "0d32851a" => (3, false, r#"Duel Masters (Japan)"#),
-    "4a7de98e" => (3, false, r#"Duel Masters 2 - Invincible Advance (Japan)"#),
-    "4d6a5e1d" => (3, false, r#"Duel Masters 2 - Kirifuda Shoubu Ver. (Japan)"#),
-    "d65e3e03" => (3, false, r#"Duel Masters 3 (Japan)"#),
-    "2fff9ba9" => (1, false, r#"Duel Masters - Sempai Legends (Europe)"#),
-    "1ce344ca" => (1, false, r#"Duel Masters - Sempai Legends (USA)"#),
-    "52e0aa95" => (1, false, r#"Duel Masters - Kaijudo Showdown (Europe)"#),
-    "804e5de2" => (1, false, r#"Duel Masters - Kaijudo Showdown (USA)"#),
-    "9d429f3e" => (1, false, r#"Duel Masters - Shadow of the Code (Europe)"#),
-    "94d9053d" => (1, false, r#"Duel Masters - Shadow of the Code (USA)"#),
-    "dc7dab56" => (1, false, r#"Duke Nukem Advance (Europe)"#),
-    "06570282" => (1, false, r#"Duke Nukem Advance (USA)"#),
-    "298d627b" => (0, false, r#"Frank Herbert's Dune - Ornithopter Assault (prototype)"#),
-    "26351555" => (3, false, r#"Dungeons & Dragons - Eye of the Beholder (Europe)"#),
-    "91b16892" => (3, false, r#"Dungeons & Dragons - Eye of the Beholder (USA)"#),
-    "74cdb37a" => (3, false, r#"Dynasty Warriors Advance (Europe)"#),
-    "ad5a2a4e" => (3, false, r#"Dynasty Warriors Advance (USA, Australia)"#),
-    "10ece4cb" => (0, false, r#"E.T. - The Extra-Terrestrial (Europe)"#),
-    "8b082cce" => (0, false, r#"E.T. - The Extra-Terrestrial (USA)"#),
-    "c4991d13" => (0, false, r#"Earthworm Jim (Europe, USA)"#),
-    "d211e8da" => (0, false, r#"Earthworm Jim 2 (Europe)"#),
-    "7496555c" => (0, false, r#"Earthworm Jim 2 (USA)"#),
-    "af726026" => (0, false, r#"Ecks vs Sever (Europe)"#),
-    "86a861f1" => (0, false, r#"Ecks vs Sever (USA)"#),
-    "83297d13" => (0, false, r#"Ecks vs Sever II - Ballistic (Europe)"#),
-    "0477f153" => (0, false, r#"Ballistic - Ecks vs Sever (USA)"#),
-    "e7f6e42f" => (1, false, r#"Ed, Edd n Eddy - Jawbreakers! (Europe)"#),
-    "315a9114" => (1, false, r#"Ed, Edd n Eddy - Jawbreakers! (USA, rev. 1)"#),
-    "48e5a70e" => (1, false, r#"Ed, Edd n Eddy - Jawbreakers! (USA)"#),
-    "baa13bc4" => (1, false, r#"Ed, Edd n Eddy - The Mis-Edventures (Europe)"#),
-    "64edb487" => (1, false, r#"Ed, Edd n Eddy - The Mis-Edventures (USA)"#),
-    "0b2961ce" => (0, false, r#"Eggo Mania (Europe)"#),
-    "f4c9a628" => (0, false, r#"Eggo Mania (Europe, prototype)"#),
-    "e8b15678" => (0, false, r#"Egg Mania (USA)"#),
-    "4beab3ac" => (0, false, r#"Egg Mania - Tsukande! Mawashite! Dossun Puzzle!! (Japan)"#),
-    "fa28bed8" => (1, false, r#"Elemix! (Japan)"#),
-    "2d88526a" => (1, false, r#"Elevator Action - Old & New (Japan)"#),
-    "1e107999" => (0, false, r#"Elf - The Movie (Europe)"#),
-    "9a1f8165" => (0, false, r#"Elf - The Movie (USA)"#),
-    "68feff40" => (0, false, r#"Elf Bowling 1 & 2 (USA)"#),
-    "af23ffff" => (1, false, r#"Enchanted - Once Upon Andalasia (USA)"#),
-    "bb05a63f" => (2, false, r#"Eragon (Europe)"#),
-    "af8af2e8" => (2, false, r#"Eragon (USA)"#),
-    "8c1afca4" => (2, false, r#"Elemental Gerad - Tozasareshi Uta (Japan)"#),
-    "4878bbea" => (5, false, r#"ESPN Final Round Golf (Europe)"#),
-    "c9bc75a5" => (5, false, r#"ESPN Final Round Golf 2002 (USA)"#),
-    "28f0a921" => (2, false, r#"ESPN Great Outdoor Games - Bass Tournament (Europe)"#),
-    "da9d815f" => (3, false, r#"ESPN Great Outdoor Games - Bass 2002 (USA)"#),
-    "a56afbb7" => (1, false, r#"ESPN International Winter Sports (Europe)"#),
-    "ff51a9a9" => (1, false, r#"ESPN International Winter Sports 2002 (USA)"#),
-    "ce62f1f4" => (1, false, r#"ESPN Winter X-Games Snowboarding 2 (Europe)"#),
-    "3c5ac0c5" => (2, false, r#"ESPN Winter X-Games Snowboarding 2002 (Japan)"#),
-    "de9e85bf" => (1, false, r#"ESPN Winter X-Games Snowboarding 2002 (USA)"#),
-    "2c79e3d1" => (1, false, r#"ESPN X-Games Skateboarding (Europe)"#),
-    "7abd7e9a" => (1, false, r#"ESPN X-Games Skateboarding (Japan)"#),
-    "9766b309" => (1, false, r#"ESPN X-Games Skateboarding (USA)"#),
-    "748ad738" => (0, false, r#"European Super League (Europe)"#),
-    "afbb1d35" => (1, false, r#"Ever Girl (USA)"#),
-    "d45abf21" => (3, false, r#"EX Monopoly (Japan, rev. 1)"#),
-    "916efd5b" => (3, false, r#"EX Monopoly (Japan)"#),
-    "ab27e52a" => (2, false, r#"Exciting Bass (Japan)"#),
-    "88cc876c" => (0, false, r#"Extreme Ghostbusters - Code Ecto-1 (Europe)"#),
-    "e434fa06" => (0, false, r#"Extreme Ghostbusters - Code Ecto-1 (USA)"#),
-    "58594ae9" => (1, false, r#"Disney's Extreme Skate Adventure (Europe, French / German)"#),
-    "ad7bb7c8" => (1, false, r#"Disney's Extreme Skate Adventure (Europe, USA)"#),
-    "7a3ef446" => (1, false, r#"Disney's Extreme Skate Adventure (USA, rev. 1)"#),
-    "620784af" => (3, false, r#"Eyeshield 21 - Devilbats Devildays (Japan)"#),
-    "d256f6f7" => (3, false, r#"EZ-Talk - Shokyuu Hen 1 (Japan)"#),
-    "38d91a54" => (3, false, r#"EZ-Talk - Shokyuu Hen 2 (Japan)"#),
-    "5fa07e85" => (3, false, r#"EZ-Talk - Shokyuu Hen 3 (Japan)"#),
-    "0ca6dcf1" => (3, false, r#"EZ-Talk - Shokyuu Hen 4 (Japan)"#),
-    "a0e8eb88" => (3, false, r#"EZ-Talk - Shokyuu Hen 5 (Japan)"#),
-    "a8ab645d" => (3, false, r#"EZ-Talk - Shokyuu Hen 6 (Japan)"#),
-    "1428fe1c" => (0, false, r#"F-14 Tomcat (Europe, USA)"#),
-    "dbdc71e3" => (5, false, r#"F-Zero - Climax (Japan)"#),
-    "2446b920" => (3, false, r#"F-Zero - Falcon Densetsu (Japan)"#),
-    "bd5e9798" => (3, false, r#"F-Zero - Maximum Velocity (Europe, USA)"#),
-    "f1d5601b" => (3, false, r#"F-Zero - GP Legend (Europe)"#),
-    "781aab58" => (3, false, r#"F-Zero - GP Legend (USA)"#),
-    "25e3fc9a" => (3, false, r#"F-Zero for Game Boy Advance (Japan)"#),
-    "aac551fd" => (1, false, r#"F1 2002 (Europe)"#),
-    "7dd20f33" => (1, false, r#"F1 2002 (Europe, USA)"#),
-    "9387ea7c" => (0, false, r#"F24 Stealth Fighter (USA)"#),
-    "b0533f41" => (0, false, r#"The Fairly OddParents! - Enter the Cleft (USA)"#),
-    "9bf9ca2f" => (0, false, r#"The Fairly OddParents! - Breakin' da Rules (USA)"#),
-    "23188cb3" => (0, false, r#"The Fairly OddParents! - Shadow Showdown (Europe)"#),
-    "ba72e185" => (0, false, r#"The Fairly OddParents! - Shadow Showdown (USA)"#),
-    "65e88703" => (0, false, r#"Cosmo & Wanda - Wenn Elfen Helfen! - Das Schattenduell (Germany)"#),
-    "91e7ba6c" => (0, false, r#"The Fairly OddParents! - Clash with the Anti-World (Europe)"#),
-    "6fd773cd" => (0, false, r#"The Fairly OddParents! - Clash with the Anti-World (USA)"#),
-    "423b8fc5" => (2, false, r#"Famicom Mini - Dai-2-ji Super Robot Taisen (Japan, promo)"#),
-    "fae4fdec" => (1, false, r#"Famicom Mini - Kidou Senshi Z Gundam - Hot Scramble (Japan, promo)"#),
-    "5848884c" => (1, false, r#"Famicom Mini 01 - Super Mario Bros. (Japan, rev. 1)"#),
-    "86d346af" => (1, false, r#"Famicom Mini 02 - Donkey Kong (Japan)"#),
-    "5646f9d1" => (1, false, r#"Famicom Mini 03 - Ice Climber (Japan)"#),
-    "319cdf36" => (0, false, r#"Famicom Mini 04 - Excitebike (Japan)"#),
-    "114e37a1" => (2, false, r#"Famicom Mini 05 - Zelda no Densetsu 1 - The Hyrule Fantasy (Japan)"#),
-    "3fec1606" => (1, false, r#"Famicom Mini 06 - Pac-Man (Japan)"#),
-    "1cd1f78c" => (1, false, r#"Famicom Mini 07 - Xevious (Japan)"#),
-    "d4b0779a" => (1, false, r#"Famicom Mini 08 - Mappy (Japan)"#),
-    "3b92ee60" => (1, false, r#"Famicom Mini 09 - Bomberman (Japan)"#),
-    "0958ee09" => (1, false, r#"Famicom Mini 10 - Star Soldier (Japan)"#),
-    "9cd4d6cf" => (1, false, r#"Famicom Mini 11 - Mario Bros. (Japan)"#),
-    "c4c03aa8" => (1, false, r#"Famicom Mini 12 - Clu Clu Land (Japan)"#),
-    "45d299d7" => (1, false, r#"Famicom Mini 13 - Balloon Fight (Japan)"#),
-    "6c0611f0" => (1, false, r#"Famicom Mini 14 - Wrecking Crew (Japan)"#),
-    "e23a7ed0" => (1, false, r#"Famicom Mini 15 - Dr. Mario (Japan)"#),
-    "57513b63" => (1, false, r#"Famicom Mini 16 - Dig Dug (Japan)"#),
-    "3bef3b74" => (1, false, r#"Famicom Mini 17 - Takahashi Meijin no Bouken-jima (Japan)"#),
-    "85261ec6" => (1, false, r#"Famicom Mini 18 - Makai Mura (Japan)"#),
-    "26a17681" => (1, false, r#"Famicom Mini 19 - Twin Bee (Japan)"#),
-    "10b48dba" => (1, false, r#"Famicom Mini 20 - Ganbare Goemon! - Karakuri Douchuu (Japan)"#),
-    "f55674a3" => (1, false, r#"Famicom Mini 21 - Super Mario Bros. 2 (Japan)"#),
-    "8233349c" => (1, false, r#"Famicom Mini 22 - Nazo no Murasame Jou (Japan)"#),
-    "abeccdab" => (2, false, r#"Famicom Mini 23 - Metroid (Japan)"#),
-    "f311edac" => (2, false, r#"Famicom Mini 24 - Hikari Shinwa - Palthena no Kagami (Japan)"#),
-    "1cbe712a" => (2, false, r#"Famicom Mini 25 - The Legend of Zelda 2 - Link no Bouken (Japan)"#),
-    "63b51337" => (1, false, r#"Famicom Mini 26 - Famicom Mukashibanashi - Shin Onigashima - Zen, Kouhen
-			(Japan)"#),
-    "3cf43405" => (1, false, r#"Famicom Mini 27 - Famicom Tantei Club - Kieta Koukeisha - Zen, Kouhen (Japan)"#),
-    "75e1b220" => (1, false, r#"Famicom Mini 28 - Famicom Tantei Club Part II - Ushiro ni Tatsu Shoujo - Zen,
-			Kouhen (Japan)"#),
-    "012fa057" => (1, false, r#"Famicom Mini 29 - Akumajou Dracula (Japan)"#),
-    "9ed2daeb" => (2, false, r#"Famicom Mini 30 - SD Gundam World - Gachapon Senshi Scramble Wars (Japan)"#),
-    "ec36a3df" => (0, false, r#"Family Feud (USA)"#),
-    "bb43c681" => (1, false, r#"Family Tennis Advance (Japan)"#),
-    "f7a58ae8" => (2, false, r#"Famista Advance (Japan)"#),
-    "a29a50e7" => (3, false, r#"Fancy Pocket (Japan)"#),
-    "b30ae33d" => (1, false, r#"Fantastic 4 (Europe, English)"#),
-    "88643de0" => (1, false, r#"Fantastic 4 (Europe, French / German / Dutch / Spanish)"#),
-    "5d4645e1" => (1, false, r#"Fantastic 4 (Italy)"#),
-    "ff2c6ea9" => (1, false, r#"Fantastic 4 (USA)"#),
-    "671957af" => (1, false, r#"Fantastic 4 - Flame On (Europe)"#),
-    "ddde4295" => (1, false, r#"Fantastic 4 - Flame On (USA)"#),
-    "fefe25be" => (2, false, r#"Fantastic Children (Japan)"#),
-    "ec60d573" => (2, false, r#"Fantastic Märchen - Cake-ya-san Monogatari + Doubutsu Chara Navi Uranai Kosei
-			Shinri Gaku (Japan)"#),
-    "3dfe6d2b" => (0, false, r#"Farbe (Germany)"#),
-    "bf91c27d" => (1, false, r#"Fear Factor Unleashed (USA)"#),
-    "4fd9c349" => (3, false, r#"Field of Nine - Digital Edition 2001 (Japan)"#),
-    "be4c3cb1" => (1, false, r#"FIFA 06 (Europe, USA)"#),
-    "d8c7553b" => (1, false, r#"FIFA 07 (Europe, USA)"#),
-    "81f58837" => (1, false, r#"FIFA (Europe, USA)"#),
-    "1abbc24f" => (1, false, r#"FIFA 2004 (Europe, USA)"#),
-    "da90f396" => (1, false, r#"FIFA 2005 (Europe, USA)"#),
-    "928b4e05" => (1, false, r#"FightBox (Europe)"#),
-    "07434e3a" => (0, false, r#"FILA Decathlon (Europe, prototype)"#),
-    "628355b5" => (1, false, r#"FILA Decathlon (Europe)"#),
-    "7c9854c6" => (3, false, r#"Final Fantasy I & II - Dawn of Souls (Europe)"#),
-    "1b39cdab" => (3, false, r#"Final Fantasy I & II - Dawn of Souls (USA, Australia)"#),
-    "0f3bd723" => (3, false, r#"Final Fantasy I - II Advance (Japan, rev. 1)"#),
-    "27f2bc67" => (3, false, r#"Final Fantasy IV Advance (Europe)"#),
-    "80f1133b" => (3, false, r#"Final Fantasy IV Advance (Japan, rev. 1)"#),
-    "17036e02" => (3, false, r#"Final Fantasy IV Advance (Japan)"#),
-    "feeebde4" => (3, false, r#"Final Fantasy IV Advance (USA, Australia)"#),
-    "ba5de047" => (4, false, r#"Final Fantasy Tactics Advance (Europe)"#),
-    "a57b0034" => (4, false, r#"Final Fantasy Tactics Advance (Japan)"#),
-    "5645e56c" => (4, false, r#"Final Fantasy Tactics Advance (USA, Australia)"#),
-    "7197117e" => (3, false, r#"Final Fantasy V Advance (Europe)"#),
-    "fc7909a7" => (3, false, r#"Final Fantasy V Advance (Japan)"#),
-    "7a24ab0c" => (3, false, r#"Final Fantasy V Advance (USA)"#),
-    "d15deba5" => (3, false, r#"Final Fantasy VI Advance (Europe)"#),
-    "d67478fe" => (3, false, r#"Final Fantasy VI Advance (Japan)"#),
-    "d708f5ab" => (3, false, r#"Final Fantasy VI Advance (USA)"#),
-    "9dd5e6fa" => (1, false, r#"Final Fight One (Europe)"#),
-    "c9e5e223" => (1, false, r#"Final Fight One (Japan)"#),
-    "052c9997" => (1, false, r#"Final Fight One (USA)"#),
-    "a0093822" => (5, false, r#"Final Fire Pro Wrestling - Yume no Dantai Unei! (Japan)"#),
-    "3b098667" => (0, false, r#"Finding Nemo (Europe, USA)"#),
-    "3e6f4f03" => (0, false, r#"Finding Nemo (Europe, Italian / Spanish)"#),
-    "269af7dd" => (0, false, r#"Finding Nemo (Europe, French / Dutch)"#),
-    "6b6b0908" => (0, false, r#"Findet Nemo (Germany)"#),
-    "92197fbb" => (0, false, r#"Findet Nemo (Germany, prototype)"#),
-    "f895ba2d" => (0, false, r#"Finding Nemo (Japan)"#),
-    "4f9aca4f" => (0, false, r#"Finding Nemo - The Continuing Adventures (Europe, USA)"#),
-    "4c77eda6" => (1, false, r#"Finding Nemo - Aratanaru Bouken (Japan)"#),
-    "45bc9c37" => (0, false, r#"Finding Nemo - The Continuing Adventures (Europe, English / Italian / Spanish /
-			Danish / Swedish)"#),
-    "1e8cefe9" => (0, false, r#"Finding Nemo - The Continuing Adventures (Europe, French / German / Dutch)"#),
-    "8698f50a" => (0, false, r#"Fire Eaters - Zero Bandits (Europe, demo)"#),
-    "4a805ed1" => (3, false, r#"Fire Emblem (Europe, English / French / German)"#),
-    "9decc754" => (3, false, r#"Fire Emblem (Europe, English / Italian / Spanish)"#),
-    "f0c10e72" => (3, false, r#"Fire Emblem - Rekka no Ken (Japan)"#),
-    "8146a270" => (3, false, r#"Fire Emblem - Rekka no Ken (Japan, prototype 0219)"#),
-    "b40d6854" => (3, false, r#"Fire Emblem - Rekka no Ken (Japan, prototype 0206)"#),
-    "2a524221" => (3, false, r#"Fire Emblem (USA, Australia)"#),
-    "d38763e1" => (3, false, r#"Fire Emblem - Fuuin no Tsurugi (Japan)"#),
-    "b3005195" => (3, false, r#"Fire Emblem - The Sacred Stones (Europe)"#),
-    "9d76826f" => (3, false, r#"Fire Emblem - Seima no Kouseki (Japan)"#),
-    "cd9d8972" => (3, false, r#"Fire Emblem - Seima no Kouseki (Japan, prototype)"#),
-    "a47246ae" => (3, false, r#"Fire Emblem - The Sacred Stones (USA, Australia)"#),
-    "12aa524c" => (5, false, r#"Fire Pro Wrestling (Europe, USA)"#),
-    "0bb47e83" => (5, false, r#"Fire Pro Wrestling A (Japan)"#),
-    "b9cfc1d3" => (5, false, r#"Fire Pro Wrestling 2 (USA)"#),
-    "eb33477d" => (0, false, r#"Flashback Legends (France, prototype)"#),
-    "39782c26" => (0, false, r#"The Flintstones - Big Trouble in Bedrock (Europe)"#),
-    "3b09694b" => (0, false, r#"The Flintstones - Big Trouble in Bedrock (USA)"#),
-    "62e435de" => (0, false, r#"Flushed Away (Europe)"#),
-    "0ed5df4b" => (0, false, r#"Flushed Away (USA)"#),
-    "94e3d277" => (0, false, r#"Ford Racing 3 (Europe)"#),
-    "56d38756" => (0, false, r#"Ford Racing 3 (USA)"#),
-    "47f663a0" => (3, false, r#"Formation Soccer 2002 (Japan)"#),
-    "bee67855" => (0, false, r#"Fortress (Europe, USA)"#),
-    "dee47a85" => (1, false, r#"Foster's Home for Imaginary Friends (Europe)"#),
-    "d7c80592" => (1, false, r#"Foster's Home for Imaginary Friends (USA)"#),
-    "a0952cd0" => (1, false, r#"Franklin the Turtle (Europe, rev. 2)"#),
-    "1a2f7ee6" => (1, false, r#"Franklin the Turtle (Europe, rev. 1)"#),
-    "17c48263" => (1, false, r#"Franklin the Turtle (Europe)"#),
-    "9bb561a8" => (1, false, r#"Franklin the Turtle (USA)"#),
-    "22e65767" => (1, false, r#"Franklin's Great Adventures (Europe)"#),
-    "7fbf8e98" => (1, false, r#"Franklin's Great Adventures (USA)"#),
-    "96871a23" => (0, false, r#"Freekstyle (Europe)"#),
-    "72434345" => (0, false, r#"Freekstyle (USA)"#),
-    "0187988f" => (0, false, r#"Freestyle Scooter (Europe)"#),
-    "8873bd29" => (0, false, r#"Frogger Advance - The Great Quest (Europe)"#),
-    "b75782c9" => (0, false, r#"Frogger Advance - The Great Quest (USA)"#),
-    "9f8d58aa" => (1, false, r#"Frogger's Adventures - Temple of the Frog (Europe)"#),
-    "2eb9eee6" => (1, false, r#"Frogger's Adventures - Temple of the Frog (USA)"#),
-    "8acd3afc" => (1, false, r#"Frogger's Adventures 2 - The Lost Wand (Europe)"#),
-    "27b95c01" => (1, false, r#"Frogger's Adventures 2 - The Lost Wand (USA)"#),
-    "b1ef7532" => (1, false, r#"Frogger - Mahou no Kuni no Daibouken (Japan)"#),
-    "6f8bfdbc" => (2, false, r#"Frogger's Journey - The Forgotten Relic (USA)"#),
-    "6853109b" => (2, false, r#"Frogger - Kodaibunmei no Nazo (Japan)"#),
-    "e6f5bdd5" => (5, false, r#"From TV Animation One Piece - Mezase! King of Berry (Japan)"#),
-    "fd4ce9c8" => (3, false, r#"From TV Animation One Piece - Nanatsu-jima no Daihihou (Japan)"#),
-    "4d6bf573" => (2, false, r#"Frontier Stories (Japan)"#),
-    "ebf89b0d" => (2, false, r#"Fruits Mura no Doubutsu-tachi (Japan)"#),
-    "195d7ea7" => (2, false, r#"Fruits Mura no Doubutsu-tachi (Japan, rev. 2)"#),
-    "2cda40e1" => (1, false, r#"Fushigi no Kuni no Alice - Alice's Adventures in Wonderland (Japan)"#),
-    "803fc474" => (3, false, r#"Fushigi no Kuni no Angelique (Japan)"#),
-    "6026eecd" => (1, false, r#"Futari wa Pretty Cure - Arienaai! Yume no Sono wa Daimeikyuu (Japan)"#),
-    "541c18f9" => (2, false, r#"Futari wa Pretty Cure Max Heart - Maji? Maji?! Fight de IN Janai (Japan)"#),
-    "eb9f6f11" => (5, false, r#"Gachasute! Dino Device - Blue (Japan)"#),
-    "dda5ec5e" => (5, false, r#"Gachasute! Dino Device - Red (Japan)"#),
-    "a63dfd2c" => (1, false, r#"Gachasute! Dino Device 2 - Dragon (Japan)"#),
-    "67e21bfe" => (1, false, r#"Gachasute! Dino Device 2 - Phoenix (Japan)"#),
-    "81de0c16" => (3, false, r#"Gachinko Pro Yakyuu (Japan)"#),
-    "d2bc5027" => (3, false, r#"Gadget Racers (Europe)"#),
-    "95dd156a" => (3, false, r#"Gadget Racers (USA)"#),
-    "5847a906" => (2, false, r#"Gakkou no Kaidan - Hyakuyoubako no Fuuin (Japan)"#),
-    "6eb70422" => (2, false, r#"Gakkou o Tsukurou!! Advance (Japan)"#),
-    "aab51f8c" => (3, false, r#"Gakuen Alice - Dokidoki Fushigi Taiken (Japan)"#),
-    "8cb61158" => (3, false, r#"Gakuen Alice - Dokidoki Fushigi Taiken (Japan, rev. 1)"#),
-    "82983321" => (1, false, r#"Gakuen Senki Muryou (Japan)"#),
-    "b99e2333" => (1, false, r#"Galaxy Angel Game Boy Advance - Moridakusan Tenshi no Full-Course - Okawari
-			Jiyuu (Japan)"#),
-    "4d8f49f9" => (1, false, r#"Galidor - Defenders of the Outer Dimension (USA)"#),
-    "8a879018" => (2, false, r#"Gambler Densetsu Tetsuya - Yomigaeru Densetsu (Japan)"#),
-    "85c837af" => (3, false, r#"Game & Watch Gallery Advance (Europe)"#),
-    "7e90cea2" => (3, false, r#"Game & Watch Gallery 4 (USA)"#),
-    "ffbd4da9" => (0, false, r#"Game Boy Advance Video - All Grown Up! - Volume 1 (USA)"#),
-    "fc042f18" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Edition Platinum (France)"#),
-    "58f1cde8" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Edition Premium (France)"#),
-    "71154d42" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Edition Speciale (France)"#),
-    "5d918b2d" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Limited Edition (USA)"#),
-    "6443554b" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Platinum Edition (Europe,
-			USA)"#),
-    "f2825729" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Premium Edition (Europe,
-			USA)"#),
-    "e9b7b8a4" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Special Edition (Europe,
-			USA)"#),
-    "91f39447" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Volume 1 (USA)"#),
-    "4bfaa8de" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Volume 2 (Europe, USA)"#),
-    "4463f345" => (0, false, r#"Game Boy Advance Video - Codename - Kids Next Door - Volume 1 (Europe, USA)"#),
-    "7cc985cb" => (0, false, r#"Game Boy Advance Video - Disney Channel Collection - Volume 1 (USA)"#),
-    "60dfd79c" => (0, false, r#"Game Boy Advance Video - Disney Channel Collection - Volume 2 (USA)"#),
-    "dd6a7fcc" => (0, false, r#"Game Boy Advance Video - Dora the Explorer - Volume 1 (USA)"#),
-    "da559199" => (0, false, r#"Game Boy Advance Video - Dragon Ball GT - Volume 1 (USA)"#),
-    "6fbf5ceb" => (0, false, r#"Game Boy Advance Video - Nicktoons - Volume 3 (USA)"#),
-    "5d47676f" => (0, false, r#"Game Boy Advance Video - Nicktoon's Collection - Volume 1 (USA)"#),
-    "65deeeb6" => (0, false, r#"Game Boy Advance Video - Nicktoon's Collection - Volume 2 (USA)"#),
-    "a59ef954" => (0, false, r#"Game Boy Advance Video - Pokémon: For Ho-Oh the Bells Toll! + A Hot Water
-			Battle (USA)"#),
-    "0da1a383" => (0, false, r#"Game Boy Advance Video - Pokémon: Johto Photo Finish + Playing with Fire! (USA)"#),
-    "a36aa9c5" => (0, false, r#"Game Boy Advance Video - Pokémon: Pokémon - I Choose You + Here Comes the
-			Squirtle Squad (USA)"#),
-    "be468496" => (0, false, r#"Game Boy Advance Video - Pokémon: Beach Blank-out Blastoise + Go West Young
-			Meowth (USA)"#),
-    "d2cf417a" => (0, false, r#"Game Boy Advance Video - Shark Tale (USA, rev. 6)"#),
-    "01468820" => (0, false, r#"Game Boy Advance Video - Shark Tale (USA, rev. 5)"#),
-    "4010e9fa" => (0, false, r#"Game Boy Advance Video - Shrek (USA, rev. 6)"#),
-    "690176e5" => (0, false, r#"Game Boy Advance Video - Shrek (USA)"#),
-    "925b6c02" => (0, false, r#"Game Boy Advance Video - Shrek 2 (USA, rev. 6)"#),
-    "0d353654" => (0, false, r#"Game Boy Advance Video - Shrek 2 (USA)"#),
-    "aadb3e3d" => (0, false, r#"Game Boy Advance Video - Shrek + Shark Tale (USA, rev. 5)"#),
-    "363c3eb8" => (0, false, r#"Game Boy Advance Video - Sonic X - Volume 1 (USA)"#),
-    "d47bf1d4" => (0, false, r#"Game Boy Advance Video - SpongeBob SquarePants - Volume 1 (USA, rev. 1)"#),
-    "7074364a" => (0, false, r#"Game Boy Advance Video - SpongeBob SquarePants - Volume 2 (USA, rev. 1)"#),
-    "9772ca45" => (0, false, r#"Game Boy Advance Video - SpongeBob SquarePants - Volume 3 (USA)"#),
-    "ff7582ef" => (0, false, r#"Game Boy Advance Video - Strawberry Shortcake - Volume 1 (USA)"#),
-    "d743a070" => (0, false, r#"Game Boy Advance Video - Super Robot Monkey Team - Hyper Force Go! - Volume 1
-			(USA)"#),
-    "1ee78166" => (0, false, r#"Game Boy Advance Video - Teenage Mutant Ninja Turtles - Le Demenagement
-			(France)"#),
-    "046589c8" => (0, false, r#"Game Boy Advance Video - Teenage Mutant Ninja Turtles - Things Change (Europe,
-			USA)"#),
-    "0e556edf" => (0, false, r#"Game Boy Advance Video - The Adventures of Jimmy Neutron Boy Genius - Volume 1
-			(USA)"#),
-    "7958df20" => (0, false, r#"Game Boy Advance Video - The Fairly OddParents! - Volume 1 (USA)"#),
-    "bbcbc6fd" => (0, false, r#"Game Boy Advance Video - The Fairly OddParents! - Volume 2 (USA, rev. 1)"#),
-    "c6a91365" => (0, false, r#"Game Boy Advance Video - The Proud Family - Volume 1 (USA)"#),
-    "c9b6ccf1" => (0, false, r#"Game Boy Advance Video - Yu-Gi-Oh! - Yugi vs. Joey (France)"#),
-    "8d631f4e" => (0, false, r#"Game Boy Advance Video - Yu-Gi-Oh! - Yugi vs. Joey (Europe, USA)"#),
-    "49ee1fdd" => (5, false, r#"Game Boy Wars Advance 1+2 (Japan)"#),
-    "68d63d5f" => (0, false, r#"Games Explosion! (USA)"#),
-    "276e5814" => (2, false, r#"Ganbare! Dodge Fighters (Japan)"#),
-    "f7cd830c" => (0, false, r#"Garfield - The Search for Pooky (Europe, rev. 2)"#),
-    "387eb638" => (0, false, r#"Garfield - The Search for Pooky (Europe, rev. 1)"#),
-    "ce9291f1" => (0, false, r#"Garfield - The Search for Pooky (Europe)"#),
-    "76035741" => (0, false, r#"Garfield - The Search for Pooky (USA)"#),
-    "f0ba1a8e" => (1, false, r#"Garfield and his Nine Lives (Europe)"#),
-    "d442f13d" => (1, false, r#"Garfield and his Nine Lives (USA)"#),
-    "e99de964" => (1, false, r#"Gauntlet - Dark Legacy (USA)"#),
-    "7f2b2657" => (3, false, r#"GeGeGe no Kitarou - Kikiippatsu! Youkai Rettou (Japan)"#),
-    "2fb5b819" => (0, false, r#"Gekido Advance - Kintaro's Revenge (Europe)"#),
-    "6bbc5f4d" => (0, false, r#"Gekido Advance - Kintaro's Revenge (USA)"#),
-    "3e04fdc7" => (2, false, r#"Gekitou Densetsu Noah - Dream Management (Japan)"#),
-    "321b19b6" => (2, false, r#"Gekitou! Car Battler Go!! (Japan)"#),
-    "2eb60177" => (1, false, r#"Gem Smashers (USA)"#),
-    "580a00a5" => (4, false, r#"Genseishin Justirisers - Souchaku! Chikyuu no Senshi-tachi (Japan)"#),
-    "1dfbd741" => (2, false, r#"Gensou Maden Saiyuuki - Hangyaku no Toushin-taishi (Japan)"#),
-    "5e67aab4" => (0, false, r#"Gensou Suikoden - Card Stories (Japan)"#),
-    "9cf6887c" => (4, false, r#"Get Ride! Amdriver - Senkou no Hero Tanjou! (Japan)"#),
-    "66d6e299" => (3, false, r#"Get Ride! Amdriver - Shutsugeki! Battle Party (Japan)"#),
-    "41ea70a3" => (2, false, r#"Get! - Boku no Mushi Tsukamaete (Japan)"#),
-    "205c9f56" => (3, false, r#"GetBackers Dakkanya - Jagan Fuuin! (Japan)"#),
-    "c0a0a979" => (5, false, r#"GetBackers Dakkanya - Jigoku no Scaramouche (Japan)"#),
-    "91dad468" => (4, false, r#"GetBackers Dakkanya - Metropolis Dakkan Sakusen! (Japan)"#),
-    "cf190f2e" => (1, false, r#"Ghost Rider (Europe, USA)"#),
-    "81ea54e2" => (1, false, r#"Ghost Trap (Japan)"#),
-    "383cf600" => (1, false, r#"Global Star - Sudoku Fever (Europe)"#),
-    "51a42f19" => (1, false, r#"Global Star - Sudoku Fever (USA)"#),
-    "84adb2c3" => (0, false, r#"Glory Days (Europe)"#),
-    "dff3e861" => (1, false, r#"Go! Go! Beckham! - Adventure on Soccer Island (Europe)"#),
-    "7c43545e" => (0, false, r#"Godzilla - Domination! (Europe)"#),
-    "eade083f" => (0, false, r#"Godzilla - Domination! (USA)"#),
-    "86d6c574" => (2, false, r#"Goemon - New Age Shutsudou! (Japan)"#),
-    "3157a4fc" => (0, false, r#"Godzilla - Kaijuu Dairantou Advance (Japan)"#),
-    "56b9e9e1" => (0, false, r#"Golden Nugget Casino (Europe, USA)"#),
-    "e1fb68e8" => (5, false, r#"Golden Sun (Europe, USA)"#),
-    "f6521161" => (5, false, r#"Golden Sun (France)"#),
-    "1053dce4" => (5, false, r#"Golden Sun (Germany)"#),
-    "f3128812" => (5, false, r#"Golden Sun (Italy)"#),
-    "c63008d6" => (5, false, r#"Golden Sun (Spain)"#),
-    "606a1c4d" => (5, false, r#"Golden Sun - The Lost Age (Europe, USA)"#),
-    "d981d889" => (5, false, r#"Golden Sun - Die Vergessene Epoche (Germany)"#),
-    "1090bd33" => (5, false, r#"Golden Sun - L'Age Perdu (France)"#),
-    "a3e49743" => (5, false, r#"Golden Sun - L'Era Perduta (Italy)"#),
-    "ee38ba94" => (5, false, r#"Golden Sun - La Edad Perdida (Spain)"#),
-    "4851b490" => (0, false, r#"GP-1 Racing (USA, prototype)"#),
-    "3722bdfa" => (1, false, r#"Gradius Advance (Europe)"#),
-    "8103428d" => (1, false, r#"Gradius Galaxies (USA)"#),
-    "6c20ea42" => (1, false, r#"Gradius Generation (Japan)"#),
-    "4189e07f" => (1, false, r#"Grand Theft Auto Advance (Europe)"#),
-    "1fa131e8" => (1, false, r#"Grand Theft Auto Advance (USA)"#),
-    "379e64d7" => (3, false, r#"Greatest Nine (Japan)"#),
-    "e4ae2cd1" => (1, false, r#"Green Eggs and Ham by Dr. Seuss (USA)"#),
-    "649f7bb2" => (2, false, r#"Greg Hastings' Tournament Paintball Max'd (USA)"#),
-    "68ae6bbb" => (0, false, r#"Gremlins - Stripe vs Gizmo (Europe, prototype)"#),
-    "b6225186" => (0, false, r#"Gremlins - Stripe vs Gizmo (Europe)"#),
-    "5e72899a" => (0, false, r#"Gremlins - Stripe vs Gizmo (USA)"#),
-    "155587bc" => (1, false, r#"The Grim Adventures of Billy & Mandy (USA)"#),
-    "e7421b14" => (3, false, r#"Groove Adventure Rave - Hikari to Yami no Daikessen (Japan)"#),
-    "006f9150" => (3, false, r#"Groove Adventure Rave - Hikari to Yami no Daikessen 2 (Japan)"#),
-    "8c411acc" => (0, false, r#"GT Advance - Championship Racing (Europe, USA)"#),
-    "73411578" => (1, false, r#"GT Advance 2 - Rally Racing (Europe)"#),
-    "f335db88" => (1, false, r#"GT Advance 2 - Rally Racing (USA)"#),
-    "03898e7f" => (1, false, r#"GT Advance 3 - Pro Concept Racing (Europe)"#),
-    "0a331e11" => (1, false, r#"GT Advance 3 - Pro Concept Racing (USA)"#),
-    "e92ec7aa" => (1, false, r#"GT Championship (Europe)"#),
-    "e027657a" => (0, false, r#"GT Racers (Europe, prototype, 20050713)"#),
-    "a0f745a0" => (0, false, r#"GT Racers (Europe, prototype, 20051014)"#),
-    "362d9f86" => (0, false, r#"GT Racers (Europe)"#),
-    "ba95861d" => (5, false, r#"Guilty Gear X - Advance Edition (Europe)"#),
-    "4506ada8" => (0, false, r#"Guilty Gear X - Advance Edition (Japan, prototype)"#),
-    "160903ee" => (5, false, r#"Guilty Gear X - Advance Edition (Japan)"#),
-    "70db3f96" => (5, false, r#"Guilty Gear X - Advance Edition (USA)"#),
-    "2b7e3ccb" => (1, false, r#"Gumby vs. the Astrobots (USA)"#),
-    "8c541d13" => (2, false, r#"Gunstar Future Heroes (Europe)"#),
-    "6d4307f7" => (2, false, r#"Gunstar Super Heroes (Japan)"#),
-    "7cd86b02" => (2, false, r#"Gunstar Super Heroes (USA)"#),
-    "cdd2e273" => (3, false, r#"Guranbo (Japan)"#),
-    "30d04ad9" => (5, false, r#"Guru Logic Champ (Japan)"#),
-    "a0f4801f" => (3, false, r#"Gyakuten Saiban (Japan, rev. 1)"#),
-    "c88f0952" => (3, false, r#"Gyakuten Saiban (Japan)"#),
-    "191ad26a" => (3, false, r#"Gyakuten Saiban 2 (Japan)"#),
-    "51b6cf22" => (3, false, r#"Gyakuten Saiban 3 (Japan)"#),
-    "47c45b41" => (1, false, r#"Hachiemon (Japan)"#),
-    "a8cda373" => (1, false, r#"Hagane no Renkinjutsushi - FullMetal Alchemist - Meisou no Rondo (Japan)"#),
-    "c6144316" => (1, false, r#"Hagane no Renkinjutsushi - FullMetal Alchemist - Omoide no Sonata (Japan)"#),
-    "782dc7eb" => (2, false, r#"Hajime no Ippo - The Fighting! (Japan)"#),
-    "5875c07a" => (3, false, r#"Hamepane - Tokyo Mew Mew (Japan)"#),
-    "8173f699" => (3, false, r#"Hamster Club 3 (Japan)"#),
-    "69cf5afb" => (3, false, r#"Hamster Club 4 (Japan)"#),
-    "617724c5" => (3, false, r#"Hamster Monogatari 2 GBA (Japan)"#),
-    "c99d3787" => (2, false, r#"Hamster Monogatari 3 GBA (Japan)"#),
-    "5dcbcc79" => (1, false, r#"Hamster Monogatari 3EX, 4, Special (Japan)"#),
-    "35584ea6" => (2, false, r#"Hamster Monogatari Collection (Japan)"#),
-    "0e9d0144" => (2, false, r#"Hamster Paradise - Pure Heart (Japan)"#),
-    "902befb0" => (2, false, r#"Hamster Paradise Advanchu (Japan)"#),
-    "d9341d9c" => (3, false, r#"Hamtaro - Ham-Ham Games (Europe)"#),
-    "cc1cf806" => (3, false, r#"Hamtaro - Ham-Ham Games (USA) ~ Tottoko Hamtarou: Ham Ham Sports (Japan)"#),
-    "ecbd80ea" => (3, false, r#"Hamtaro - Ham-Ham Heartbreak (Europe)"#),
-    "cd48b673" => (3, false, r#"Hamtaro - Ham-Ham Heartbreak (USA)"#),
-    "5d051081" => (3, false, r#"Hamtaro - Rainbow Rescue (Europe)"#),
-    "173a6c4a" => (4, false, r#"Hanabi Hyakkei Advance (Japan)"#),
-    "de7b8dcd" => (1, false, r#"Hanafuda Trump Mahjong - Depachika Wayouchuu (Japan)"#),
-    "7a53173c" => (1, false, r#"Happy Feet (Europe)"#),
-    "682617cf" => (1, false, r#"Happy Feet (USA)"#),
-    "5466c757" => (1, false, r#"Hardcore Pinball (Europe, USA)"#),
-    "2be4f2bc" => (0, false, r#"Hardcore Pool (Europe)"#),
-    "44779175" => (0, false, r#"Hardcore Pool (France)"#),
-    "37b20866" => (0, false, r#"Harlem Globetrotters - World Tour (Europe)"#),
-    "737c286f" => (0, false, r#"Harlem Globetrotters - World Tour (USA)"#),
-    "f54976c8" => (3, false, r#"Harobots - Robo Hero Battling!! (Japan)"#),
-    "342816db" => (1, false, r#"Harry Potter and the Chamber of Secrets (Europe, USA)"#),
-    "c0bccaad" => (2, false, r#"Harry Potter and the Prisoner of Azkaban (Europe, USA)"#),
-    "f755065c" => (1, false, r#"Harry Potter and the Philosopher's Stone (Europe)"#),
-    "a069750d" => (1, false, r#"Harry Potter to Kenja no Ishi (Japan)"#),
-    "b485dabf" => (1, false, r#"Harry Potter to Himitsu no Heya (Japan)"#),
-    "486041a4" => (1, false, r#"Harry Potter - Quidditch World Cup (Europe, USA)"#),
-    "c1f26f37" => (1, false, r#"Harry Potter - Quidditch World Cup (Japan)"#),
-    "42e590d6" => (2, false, r#"Harry Potter to Azkaban no Shuujin (Japan)"#),
-    "052708d7" => (1, false, r#"Harry Potter and the Goblet of Fire (Europe, USA)"#),
-    "302b8b3d" => (1, false, r#"Harry Potter and the Order of the Phoenix (Europe)"#),
-    "ffa9f4fb" => (2, false, r#"Harry Potter Collection (Europe)"#),
-    "f50f82ad" => (3, false, r#"Harvest Moon - Friends of Mineral Town (Europe)"#),
-    "c11757d7" => (3, false, r#"Harvest Moon - Friends of Mineral Town (Germany)"#),
-    "8e923168" => (3, false, r#"Harvest Moon - Friends of Mineral Town (USA)"#),
-    "1736b957" => (3, false, r#"Harvest Moon - More Friends of Mineral Town (USA)"#),
-    "6139e34d" => (3, false, r#"Hatena Satena (Japan)"#),
-    "debc5439" => (0, false, r#"The Haunted Mansion (USA, prototype)"#),
-    "e8c44fb8" => (1, false, r#"Heidi (Europe)"#),
-    "9c45920e" => (0, false, r#"Hello Kitty - Happy Party Pals (Europe, English)"#),
-    "1a080c10" => (0, false, r#"Hello Kitty - Happy Party Pals (Europe, English / French / German / Spanish)"#),
-    "e49c45a0" => (0, false, r#"Hello Kitty - Happy Party Pals (USA)"#),
-    "c8f4d874" => (2, false, r#"Hello Kitty Collection - Miracle Fashion Maker (Japan)"#),
-    "304bdfc3" => (2, false, r#"Hello! Idol Debut - Kids Idol Ikusei Game (Japan)"#),
-    "2521a98c" => (1, false, r#"Herbie - Fully Loaded (Europe)"#),
-    "9c6a3dd1" => (1, false, r#"Herbie - Fully Loaded (USA)"#),
-    "2b666fed" => (0, false, r#"Hey Arnold! - The Movie (Europe)"#),
-    "59dfab9e" => (0, false, r#"Hey Arnold! - The Movie (USA)"#),
-    "9e4773ba" => (0, false, r#"Hey Arnold! - The Movie (Europe, prototype)"#),
-    "e5531d04" => (1, false, r#"Hi Hi Puffy AmiYumi - Kaznapped! (Europe)"#),
-    "90239c3d" => (1, false, r#"Hi Hi Puffy AmiYumi (Japan)"#),
-    "e613aa43" => (1, false, r#"Hi Hi Puffy AmiYumi - Kaznapped! (USA)"#),
-    "c23ac287" => (2, false, r#"Higanbana (Japan, rev. 1)"#),
-    "6ea3f4e4" => (1, false, r#"High Heat Major League Baseball 2002 (Europe, USA)"#),
-    "59d72cb9" => (2, false, r#"High Heat Major League Baseball 2003 (USA)"#),
-    "0d9043a4" => (2, false, r#"High Heat Major League Baseball 2003 (Japan)"#),
-    "7b31d4da" => (1, false, r#"High School Musical (USA)"#),
-    "c340d5f7" => (1, false, r#"High School Musical (USA, prototype)"#),
-    "4955fa1b" => (0, false, r#"Hikaru no Go (Japan, promotional demo)"#),
-    "5a6c7537" => (3, false, r#"Hikaru no Go (Japan)"#),
-    "53116fd7" => (3, false, r#"Hikaru no Go (Japan, rev. 1)"#),
-    "04129a22" => (3, false, r#"Hikaru no Go 2 (Japan)"#),
-    "8ea9afcc" => (2, false, r#"Himawari Doubutsu Byouin - Pet no Oishasan Ikusei Game (Japan)"#),
-    "46810f68" => (3, false, r#"Hime Kishi Monogatari - Princess Blue (Japan)"#),
-    "d4eac9b1" => (2, false, r#"Hitsuji no Kimochi. (Japan)"#),
-    "eacdfe0f" => (1, false, r#"The Hobbit (Europe)"#),
-    "d3f654b3" => (1, false, r#"The Hobbit (USA)"#),
-    "65fb830a" => (1, false, r#"Hobbit no Bouken - Lord of the Rings - Hajimari no Monogatari (Japan)"#),
-    "d88feb4e" => (0, false, r#"Disney's Home on the Range (Europe)"#),
-    "dbd4a6cb" => (0, false, r#"Disney's Home on the Range (USA)"#),
-    "5b2b4452" => (2, false, r#"Horsez (USA)"#),
-    "683d772c" => (3, false, r#"Hoshi no Kirby - Kagami no Daimeikyuu (Japan, rev. 1)"#),
-    "bf80f1a1" => (3, false, r#"Hoshi no Kirby - Kagami no Daimeikyuu (Japan, demo?)"#),
-    "c55a3c2c" => (3, false, r#"Hoshi no Kirby - Yume no Izumi Deluxe (Japan)"#),
-    "99175c62" => (1, false, r#"Hot Potato! (Europe, English)"#),
-    "2eb72b95" => (1, false, r#"Hot Potato! (Europe, English / French / German)"#),
-    "5acb7a95" => (1, false, r#"Hot Potato! (USA)"#),
-    "74e787dc" => (0, false, r#"Hot Wheels - All Out (Europe)"#),
-    "c73658f9" => (0, false, r#"Hot Wheels - All Out (USA)"#),
-    "4764d029" => (1, false, r#"Hot Wheels - Burnin' Rubber (Europe)"#),
-    "37dcbb0d" => (1, false, r#"Hot Wheels - Burnin' Rubber (USA)"#),
-    "d0ab6a37" => (0, false, r#"Hot Wheels - Stunt Track Challenge (Europe, USA)"#),
-    "a7ddbca3" => (0, false, r#"Hot Wheels - Velocity X (Europe)"#),
-    "9592f553" => (0, false, r#"Hot Wheels - Velocity X (USA)"#),
-    "b9f17f8f" => (0, false, r#"Hot Wheels - World Race (Europe)"#),
-    "cb313505" => (0, false, r#"Hot Wheels - World Race (USA)"#),
-    "f79ffedf" => (1, false, r#"Hot Wheels Advance (Japan)"#),
-    "9d22604d" => (0, false, r#"Hudson Best Collection Vol. 1 - Bomberman Collection (Japan)"#),
-    "87390220" => (0, false, r#"Hudson Best Collection Vol. 2 - Lode Runner Collection (Japan)"#),
-    "c207a245" => (0, false, r#"Hudson Best Collection Vol. 3 - Action Collection (Japan)"#),
-    "6472067a" => (0, false, r#"Hudson Best Collection Vol. 4 - Nazotoki Collection (Japan)"#),
-    "39d88481" => (0, false, r#"Hudson Best Collection Vol. 5 - Shooting Collection (Japan)"#),
-    "c51cd05f" => (0, false, r#"Hudson Best Collection Vol. 6 - Bouken-jima Collection (Japan)"#),
-    "b34a2071" => (0, false, r#"Hugo - Bukkazoom! (Europe)"#),
-    "9e9d5ad7" => (1, false, r#"Hugo - The Evil Mirror Advance (Europe)"#),
-    "ed5ca311" => (1, false, r#"Hugo - The Evil Mirror Advance (USA)"#),
-    "86c21aeb" => (3, false, r#"Hunter X Hunter - Minna Tomodachi Daisakusen!! (Japan, rev. 1)"#),
-    "af22a5ea" => (3, false, r#"Hunter X Hunter - Minna Tomodachi Daisakusen!! (Japan)"#),
-    "1a8b6863" => (2, false, r#"Hyper Sports 2002 Winter (Japan)"#),
-    "946d2cbb" => (1, false, r#"I Spy Challenger! (USA)"#),
-    "10179d72" => (0, false, r#"Ice Age (Europe)"#),
-    "6d457f4c" => (0, false, r#"Ice Age (Japan)"#),
-    "3fc99714" => (0, false, r#"Ice Age (USA)"#),
-    "58043308" => (1, false, r#"Ice Age 2 - The Meltdown (Europe)"#),
-    "000206c0" => (1, false, r#"Ice Age 2 - The Meltdown (USA)"#),
-    "14c23257" => (1, false, r#"Ice Nine (Europe, USA)"#),
-    "7e00422c" => (1, false, r#"The Incredible Hulk (Europe)"#),
-    "0b978dfa" => (1, false, r#"The Incredible Hulk (USA)"#),
-    "194d5f6c" => (0, false, r#"The Incredibles (Europe, USA)"#),
-    "77cbdc3b" => (0, false, r#"The Incredibles (Europe, French / Dutch)"#),
-    "bdc06e57" => (0, false, r#"Die Unglaublichen (Germany)"#),
-    "615839ef" => (0, false, r#"Gli Incredibili - Una 'Normale' Famiglia di Supereroi (Italy)"#),
-    "fc6ccadb" => (0, false, r#"Los Increibles (Spain)"#),
-    "2d806290" => (0, false, r#"The Incredibles - Rise of the Underminer (Europe, USA)"#),
-    "096f98b6" => (0, false, r#"The Incredibles - Rise of the Underminer (Europe, English / French / German /
-			Italian / Dutch / Spanish / Portuguese)"#),
-    "23110a94" => (3, false, r#"Initial D - Another Stage (Japan)"#),
-    "406aa0c6" => (0, false, r#"Inspector Gadget - Advance Mission (Europe)"#),
-    "ef131209" => (0, false, r#"Inspector Gadget - Advance Mission (USA)"#),
-    "b0d1c070" => (0, false, r#"Inspector Gadget Racing (Europe)"#),
-    "d33a14af" => (0, false, r#"International Karate Advanced (Europe)"#),
-    "1052a0ea" => (0, false, r#"International Karate Plus (Europe)"#),
-    "31c4f03b" => (0, false, r#"International Karate Plus (USA)"#),
-    "9989ce55" => (2, false, r#"International Superstar Soccer (Europe)"#),
-    "7eb2f6d6" => (2, false, r#"International Superstar Soccer Advance (Europe)"#),
-    "d473b3c1" => (3, false, r#"Inukko Club (Japan)"#),
-    "8fcdf69c" => (3, false, r#"Inuyasha - Naraku no Wana! Mayoi no Mori no Shoutaijou (Japan)"#),
-    "a94d6058" => (0, false, r#"Invader (Europe)"#),
-    "39a31b68" => (1, false, r#"The Invincible Iron Man (Europe, USA)"#),
-    "34189e74" => (0, false, r#"Iridion 3D (Europe, USA)"#),
-    "57930c6a" => (0, false, r#"Iridion II (Europe)"#),
-    "dd8a7104" => (0, false, r#"Iridion II (USA)"#),
-    "326e7a8e" => (1, false, r#"Iron Kid (Korea)"#),
-    "cb5eff64" => (3, false, r#"Isseki Hatchou - Kore 1ppon de 8shurui! (Japan)"#),
-    "757a4efb" => (2, false, r#"It's Mr. Pants (Europe, USA)"#),
-    "0524e9ef" => (3, false, r#"J.League Pocket (Japan, rev. 1)"#),
-    "51be73b8" => (3, false, r#"J.League Pocket (Japan)"#),
-    "dc7d2e4a" => (3, false, r#"J.League Pocket 2 (Japan)"#),
-    "8bc59927" => (3, false, r#"J.League Pro Soccer Club o Tsukurou! Advance (Japan)"#),
-    "aee73fe7" => (2, false, r#"J.League Winning Eleven Advance 2002 (Japan)"#),
-    "13247954" => (1, false, r#"Jackie Chan Adventures - Legend of the Darkhand (Europe, USA)"#),
-    "d5fb19a0" => (1, false, r#"Les Aventures de Jackie Chan - La Légende de la Main Noire (France)"#),
-    "8ce6bce8" => (1, false, r#"Jajamaru Jr. Denshouki - Jalecolle mo Arisourou (Japan)"#),
-    "31081996" => (0, false, r#"James Pond - Codename RoboCod (Europe)"#),
-    "eb25bb5b" => (0, false, r#"James Pond - Codename RoboCod (USA)"#),
-    "948531d4" => (1, false, r#"Jazz Jackrabbit (Europe, USA)"#),
-    "ae4142b5" => (1, false, r#"Jet Grind Radio (USA)"#),
-    "716dc010" => (1, false, r#"Jet Set Radio (Europe)"#),
-    "0ba5809f" => (5, false, r#"JGTO Kounin Golf Master - Japan Golf Tour Game (Japan)"#),
-    "b863dae1" => (5, false, r#"JGTO Kounin Golf Master Mobile - Japan Golf Tour Game (Japan)"#),
-    "8bd96db9" => (2, false, r#"Jikkyou World Soccer Pocket (Japan)"#),
-    "b80aa966" => (2, false, r#"Jikkyou World Soccer Pocket 2 (Japan)"#),
-    "a9423234" => (0, false, r#"Jimmy Neutron Boy Genius (Europe)"#),
-    "d3ee0c51" => (0, false, r#"Jimmy Neutron Boy Genius (USA)"#),
-    "84a4a304" => (3, false, r#"Jinsei Game Advance (Japan)"#),
-    "07195201" => (5, false, r#"Jissen Pachi-Slot Hisshouhou! - Juuou Advance (Japan)"#),
-    "0e5c38b7" => (3, false, r#"Jisu F-Zero Weilai Saiche (China)"#),
-    "96488632" => (1, false, r#"Jonny Moseley Mad Trix (USA)"#),
-    "e62bffeb" => (1, false, r#"Juka and the Monophonic Menace (Europe)"#),
-    "0c04a2f9" => (1, false, r#"Juka and the Monophonic Menace (USA)"#),
-    "9898258b" => (0, false, r#"Disney's The Jungle Book 2 (Europe)"#),
-    "e9a21637" => (0, false, r#"Disney's The Jungle Book (USA)"#),
-    "ddceb011" => (1, false, r#"Jurassic Park III - Dino Attack (Europe)"#),
-    "d18f75e5" => (1, false, r#"Jurassic Park III - Advanced Action (Japan)"#),
-    "1e7048d2" => (1, false, r#"Jurassic Park III - Island Attack (USA)"#),
-    "f2bf8990" => (3, false, r#"Jurassic Park III - Kyouryuu ni Ainiikou! (Japan)"#),
-    "51061eff" => (3, false, r#"Jurassic Park III - Park Builder (Europe)"#),
-    "edddcf19" => (3, false, r#"Jurassic Park III - Park Builder (USA)"#),
-    "fe89c986" => (1, false, r#"Jurassic Park III - The DNA Factor (Europe)"#),
-    "f93dcc2e" => (1, false, r#"Jurassic Park III - The DNA Factor (USA)"#),
-    "ab6786dd" => (1, false, r#"Jurassic Park III - Ushinawareta Idenshi (Japan)"#),
-    "7c75555e" => (1, false, r#"Jurassic Park Institute Tour - Dinosaur Rescue (Japan)"#),
-    "d71c4382" => (1, false, r#"Justice League - Injustice for All (Europe)"#),
-    "c41081cf" => (1, false, r#"Justice League - Injustice for All (USA, prototype)"#),
-    "b2477b72" => (1, false, r#"Justice League - Injustice for All (USA)"#),
-    "07090f67" => (1, false, r#"Justice League Chronicles (USA)"#),
-    "acb22fff" => (1, false, r#"Justice League Heroes - The Flash (Europe)"#),
-    "87a4d12c" => (1, false, r#"Justice League Heroes - The Flash (USA)"#),
-    "fbdd4384" => (2, false, r#"K-1 Pocket Grand Prix (Japan)"#),
-    "19fde4c8" => (2, false, r#"K-1 Pocket Grand Prix 2 (Japan)"#),
-    "de5987bd" => (3, false, r#"Kaeru B Back (Japan)"#),
-    "259cdd6f" => (2, false, r#"Kaiketsu Zorori to Mahou no Yuuenchi - Ohimesama o Sukue! (Japan)"#),
-    "62992510" => (0, false, r#"Kaisertal - Fight the Necronis War (Europe, demo)"#),
-    "82b4f3fa" => (5, false, r#"Kamaitachi no Yoru Advance (Japan)"#),
-    "906c73ad" => (3, false, r#"Kami no Kijutsu - Illusion of the Evil Eyes (Japan)"#),
-    "51e7522c" => (0, false, r#"Kao the Kangaroo (Europe)"#),
-    "f6667b3e" => (0, false, r#"Kao the Kangaroo (USA)"#),
-    "bf5f326e" => (3, false, r#"Kappa no Kai-kata - Kaatan Daibouken! (Japan)"#),
-    "63fe3dfe" => (4, false, r#"Karnaaj Rally (Europe, USA)"#),
-    "4f09ca00" => (3, false, r#"Kawa no Nushi Tsuri 3 & 4 (Japan)"#),
-    "4a8f2f62" => (2, false, r#"Kawa no Nushi Tsuri 5 - Fushigi no Mori kara (Japan)"#),
-    "2e23c8f5" => (1, false, r#"Kawaii Koinu Wonderful (Japan)"#),
-    "bc5abd75" => (1, false, r#"Kawaii Pet Game Gallery (Japan)"#),
-    "c8c8c4c1" => (1, false, r#"Kawaii Pet Game Gallery 2 (Japan)"#),
-    "39aefb7b" => (2, false, r#"Kawaii Pet Shop Monogatari 3 (Japan)"#),
-    "b51d97de" => (1, false, r#"Kaze no Klonoa - Yumemiru Teikoku (Japan)"#),
-    "8d98dc2c" => (1, false, r#"Kaze no Klonoa G2 - Dream Champ Tournament (Japan)"#),
-    "44db280e" => (5, false, r#"Keitai Denjuu Telefang 2 - Power (Japan)"#),
-    "8f95ca53" => (5, false, r#"Keitai Denjuu Telefang 2 - Speed (Japan)"#),
-    "31f85dbe" => (1, false, r#"Kelly Slater's Pro Surfer (Europe, USA)"#),
-    "c026cbbb" => (3, false, r#"Keroro Gunsou - Taiketsu! Gekisou Keronprix Daisakusen de Arimasu!! (Japan)"#),
-    "3e7d9694" => (1, false, r#"Kessaku Sen! - Ganbare Goemon 1, 2 - Yuki Hime to Magginesu (Japan)"#),
-    "69951aa9" => (1, false, r#"Kid Paddle (Europe)"#),
-    "6f931445" => (0, false, r#"Kid's Cards (USA)"#),
-    "a7825224" => (1, false, r#"Kidou Gekidan Haro Ichiza Haro no Puyo Puyo (Japan)"#),
-    "73bf384b" => (2, false, r#"Kidou Senshi Gundam Seed - Tomo to Kimi to Koko de. (Japan)"#),
-    "aa250cca" => (1, false, r#"Kidou Senshi Gundam Seed Destiny (Japan)"#),
-    "b21ee600" => (2, false, r#"Kidou Tenshi Angelic Layer - Misaki to Yume no Tenshi-tachi (Japan)"#),
-    "185c2eca" => (0, false, r#"Kien (USA, prototype)"#),
-    "a2fa4248" => (2, false, r#"Kikaika Guntai - Mech Platoon (Japan)"#),
-    "1c61225b" => (0, false, r#"Kiki KaiKai Advance (Japan)"#),
-    "4f468670" => (1, false, r#"Kill.Switch (Europe)"#),
-    "2b207d1f" => (1, false, r#"Kill.Switch (USA)"#),
-    "e6fda065" => (0, false, r#"Killer 3D Pool (Europe)"#),
-    "1f12d048" => (0, false, r#"Killer 3D Pool (USA)"#),
-    "48428c2a" => (0, false, r#"Disney's Kim Possible (Europe, rev. 1)"#),
-    "0c35d0fc" => (0, false, r#"Disney's Kim Possible (Europe)"#),
-    "1a56efbd" => (0, false, r#"Disney's Kim Possible (USA)"#),
-    "69843f73" => (1, false, r#"Disney's Kim Possible (Japan)"#),
-    "71c505c8" => (1, false, r#"Disney's Kim Possible 2 - Drakken's Demise (Europe)"#),
-    "fa946fc6" => (1, false, r#"Disney's Kim Possible 2 - Drakken's Demise (USA)"#),
-    "fdec60ec" => (1, false, r#"Disney's Kim Possible III - Team Possible (USA)"#),
-    "37387b19" => (1, false, r#"King Kong - The Official Game of the Movie (Europe, English / French / German /
-			Spanish / Italian / Dutch)"#),
-    "17e66b52" => (1, false, r#"The King of Fighters EX - NeoBlood (Europe)"#),
-    "811ffcfa" => (0, false, r#"The King of Fighters EX - NeoBlood (Japan, prototype)"#),
-    "0814c78d" => (1, false, r#"The King of Fighters EX - NeoBlood (Japan)"#),
-    "0f960e70" => (1, false, r#"The King of Fighters EX - NeoBlood (USA, rev. 1)"#),
-    "2b92eb8e" => (1, false, r#"The King of Fighters EX - NeoBlood (USA)"#),
-    "9e8baa87" => (1, false, r#"The King of Fighters EX2 - Howling Blood (Europe)"#),
-    "dc5beacd" => (1, false, r#"The King of Fighters EX2 - Howling Blood (Japan, rev. 1)"#),
-    "15f8f9d5" => (1, false, r#"The King of Fighters EX2 - Howling Blood (USA)"#),
-    "7704ff39" => (1, false, r#"The King of Fighters EX2 - Howling Blood (USA, prototype, 20030403)"#),
-    "772d97fb" => (3, false, r#"Kingdom Hearts - Chain of Memories (Europe)"#),
-    "8d5a0d84" => (3, false, r#"Kingdom Hearts - Chain of Memories (Japan)"#),
-    "d031ce67" => (3, false, r#"Kingdom Hearts - Chain of Memories (USA)"#),
-    "40b1dd00" => (3, false, r#"Kinniku Banzuke - Kimero! Kiseki no Kanzen Seiha (Japan)"#),
-    "94519d2a" => (3, false, r#"Kinniku Banzuke - Kongou-kun no Daibouken! (Japan)"#),
-    "3fe9c701" => (1, false, r#"Kinnikuman II-Sei - Seigi Choujin e no Michi (Japan)"#),
-    "4f07c618" => (3, false, r#"Kirby & the Amazing Mirror (Europe)"#),
-    "9f2a3048" => (3, false, r#"Kirby & the Amazing Mirror (USA)"#),
-    "3b7a7477" => (3, false, r#"Kirby - Nightmare in Dream Land (Europe)"#),
-    "20ef3f64" => (3, false, r#"Kirby - Nightmare in Dream Land (USA)"#),
-    "34d65c0b" => (2, false, r#"Kisekko Gurumii - Chesty to Nuigurumi-tachi no Mahou no Bouken (Japan)"#),
-    "0c368530" => (2, false, r#"Kiss x Kiss Seirei Gakuen (Japan)"#),
-    "66f7614b" => (3, false, r#"Kiwame Mahjong Deluxe - Mirai Senshi 21 (Japan)"#),
-    "69492530" => (1, false, r#"Klonoa - Empire of Dreams (Europe)"#),
-    "f74e1036" => (1, false, r#"Klonoa - Empire of Dreams (USA)"#),
-    "8bd23a7f" => (1, false, r#"Klonoa 2 - Dream Champ Tournament (USA)"#),
-    "e7eaddcc" => (2, false, r#"Klonoa Heroes - Densetsu no Star Medal (Japan)"#),
-    "f027e89f" => (0, false, r#"Knights' Kingdom (Europe)"#),
-    "88fe3f31" => (0, false, r#"Knights' Kingdom (USA)"#),
-    "abd866ea" => (0, false, r#"The Koala Brothers - Outback Adventures (Europe)"#),
-    "6ec76bce" => (0, false, r#"The Koala Brothers - Outback Adventures (USA)"#),
-    "30e209aa" => (1, false, r#"Koinu to Issho - Aijou Monogatari (Japan)"#),
-    "2d2ac33b" => (1, false, r#"Koinu to Issho 2 (Japan)"#),
-    "1d27165a" => (2, false, r#"Koinu-chan no Hajimete no Osanpo - Koinu no Kokoro Ikusei Game (Japan)"#),
-    "6a8be56f" => (0, false, r#"Konami Collector's Series - Arcade Classics (Europe)"#),
-    "285f652f" => (0, false, r#"Konami Arcade Game Collection (Japan)"#),
-    "2db31825" => (0, false, r#"Konami Collector's Series - Arcade Advanced (USA)"#),
-    "cfec0650" => (3, false, r#"Konami Krazy Racers (Europe)"#),
-    "5f2ae8fe" => (3, false, r#"Konami Krazy Racers (USA, prototype)"#),
-    "c80d4f5c" => (3, false, r#"Konami Krazy Racers (USA)"#),
-    "aa039a8a" => (3, false, r#"Konami Wai Wai Racing Advance (Japan)"#),
-    "3aa0ce79" => (2, false, r#"Konchuu Monster Battle Master (Japan)"#),
-    "5adb68ef" => (2, false, r#"Konchuu Monster Battle Stadium (Japan)"#),
-    "88c56d65" => (1, false, r#"Konchuu no Mori no Daibouken - Fushigi na Sekai no Juunin-tachi (Japan)"#),
-    "9c12cd31" => (0, false, r#"Kong - King of Atlantis (Europe)"#),
-    "5e8b2644" => (0, false, r#"Kong - King of Atlantis (USA)"#),
-    "06079c42" => (1, false, r#"Kong - The 8th Wonder of the World (USA)"#),
-    "7a7b060f" => (0, false, r#"Kong - The Animated Series (Europe)"#),
-    "150bdd9e" => (0, false, r#"Kong - The Animated Series (USA)"#),
-    "d66379e1" => (3, false, r#"Konjiki no Gashbell!! - Makai no Bookmark (Japan)"#),
-    "64fab050" => (2, false, r#"Konjiki no Gashbell!! - Unare! Yuujou no Zakeru (Japan)"#),
-    "b7827f20" => (1, false, r#"Konjiki no Gashbell!! - Unare! Yuujou no Zakeru 2 (Japan)"#),
-    "e1e26f2a" => (2, false, r#"Konjiki no Gashbell!! The Card Battle for GBA (Japan)"#),
-    "fb5516ad" => (1, false, r#"Konjiki no Gashbell!! Yuujou no Zakeru - Dream Tag Tournament (Japan)"#),
-    "0bfe46e9" => (0, false, r#"Koro Koro Puzzle - Happy Panechu! (Japan)"#),
-    "fb67efbc" => (3, false, r#"Kotoba no Puzzle - Mojipittan Advance (Japan)"#),
-    "7ac9ffbf" => (3, false, r#"Kouchu Ouja Mushiking - Greatest Champion e no Michi (Japan)"#),
-    "576da6f6" => (3, false, r#"Koukou Juken Advance Series Eigo Koubun Hen - 26 Units Shuuroku (Japan)"#),
-    "7f2f5017" => (3, false, r#"Koukou Juken Advance Series Eijukugo Hen - 650 Phrases Shuuroku (Japan)"#),
-    "d38f6a8e" => (3, false, r#"Koukou Juken Advance Series Eitango Hen - 2000 Words Shuuroku (Japan)"#),
-    "cdfac4ee" => (1, false, r#"Koutetsu Teikoku from HOT・B (Japan)"#),
-    "4b96b600" => (0, false, r#"Kunio Kun Nekketsu Collection 1 (Japan)"#),
-    "61f9a9f4" => (0, false, r#"Kunio Kun Nekketsu Collection 2 (Japan)"#),
-    "093054fc" => (0, false, r#"Kunio Kun Nekketsu Collection 3 (Japan)"#),
-    "abfd7ac7" => (3, false, r#"Kurohige no Golf Shiyouyo (Japan)"#),
-    "99c917e9" => (3, false, r#"Kurohige no Kurutto Jintori (Japan)"#),
-    "c4dab956" => (3, false, r#"Kurukuru Kururin (Europe)"#),
-    "8a6a900b" => (3, false, r#"Kurukuru Kururin (Japan)"#),
-    "93d036f5" => (3, false, r#"Kururin Paradise (Japan)"#),
-    "958097d2" => (1, false, r#"Lady Sia (Europe)"#),
-    "139e95bd" => (1, false, r#"Lady Sia (USA)"#),
-    "cf0fa4d9" => (0, false, r#"The Land Before Time (Europe)"#),
-    "cfd8a52c" => (0, false, r#"The Land Before Time (USA)"#),
-    "5485eefa" => (1, false, r#"The Land Before Time - Into the Mysterious Beyond (Europe)"#),
-    "cff03df3" => (1, false, r#"The Land Before Time - Into the Mysterious Beyond (USA)"#),
-    "e2ff36c7" => (1, false, r#"Lara Croft Tomb Raider - Legend (Europe)"#),
-    "5a3dfb37" => (1, false, r#"Lara Croft Tomb Raider - Legend (USA)"#),
-    "4235fac3" => (0, false, r#"Lara Croft Tomb Raider - The Prophecy (Europe)"#),
-    "7799e1da" => (0, false, r#"Lara Croft Tomb Raider - The Prophecy (Japan)"#),
-    "556f40fa" => (0, false, r#"Lara Croft Tomb Raider - The Prophecy (USA)"#),
-    "03708dcd" => (0, false, r#"Lara Croft Tomb Raider - The Prophecy (USA, prototype)"#),
-    "6a808027" => (2, false, r#"Léa - Passion Vétérinaire ~ My Pet Hotel (France)"#),
-    "67f18f8e" => (3, false, r#"Legend of Dynamic Goushouden - Houkai no Rondo (Japan)"#),
-    "63df4d36" => (2, false, r#"The Legend of Spyro - A New Beginning (Europe)"#),
-    "1b81cc00" => (2, false, r#"The Legend of Spyro - A New Beginning (USA)"#),
-    "93b18353" => (1, false, r#"The Legend of Spyro - The Eternal Night (Europe)"#),
-    "bd2751e6" => (1, false, r#"The Legend of Spyro - The Eternal Night (USA)"#),
-    "5a164321" => (2, false, r#"The Legend of Zelda - A Link to the Past & Four Swords (Europe)"#),
-    "8e91cd13" => (2, false, r#"The Legend of Zelda - A Link to the Past & Four Swords (USA, Australia)"#),
-    "e8637292" => (2, false, r#"The Legend of Zelda - The Minish Cap (Europe)"#),
-    "023da338" => (0, false, r#"The Legend of Zelda - The Minish Cap (USA, demo Kiosk)"#),
-    "abcebbb1" => (2, false, r#"The Legend of Zelda - The Minish Cap (USA)"#),
-    "84440066" => (1, false, r#"Legends of Wrestling II (Europe, USA)"#),
-    "8df9759d" => (4, false, r#"Legendz - Buhwarhaneun Siryeonyi Seom (Korea)"#),
-    "98432a11" => (4, true, r#"Legendz - Sign of Nekuromu (Japan)"#),
-    "33950228" => (4, true, r#"Legendz - Yomigaeru Shiren no Shima (Japan)"#),
-    "121ab9c3" => (3, false, r#"LEGO Bionicle (Europe)"#),
-    "3e5ec871" => (3, false, r#"LEGO Bionicle (USA)"#),
-    "3beb5446" => (1, false, r#"Island - Xtreme Stunts (Europe, USA)"#),
-    "c7b5d987" => (3, false, r#"LEGO Island 2 - The Brickster's Revenge (Europe)"#),
-    "3f513cbf" => (3, false, r#"LEGO Island 2 - The Brickster's Revenge (USA)"#),
-    "7d05ae2c" => (1, false, r#"LEGO Racers 2 (Europe, prototype)"#),
-    "7a1dc458" => (1, false, r#"LEGO Racers 2 (Europe)"#),
-    "e13b63ad" => (1, false, r#"LEGO Racers 2 (USA)"#),
-    "73ef3a35" => (1, false, r#"LEGO Football Mania (Europe)"#),
-    "55a5aaf2" => (1, false, r#"LEGO Star Wars - The Video Game (Japan)"#),
-    "aeabfa5f" => (1, false, r#"LEGO Star Wars - The Video Game (Europe, USA)"#),
-    "bba91c3b" => (1, false, r#"LEGO Star Wars II - The Original Trilogy (Europe)"#),
-    "da1e9444" => (1, false, r#"LEGO Star Wars II - The Original Trilogy (USA)"#),
-    "f7ac3d9e" => (1, false, r#"Lemony Snicket - Rätselhafte Ereignisse (Germany)"#),
-    "aa6e22d7" => (1, false, r#"Lemony Snicket - Una Serie di Sfortunati Eventi (Italy)"#),
-    "63e02a0d" => (1, false, r#"Lemony Snicket's A Series of Unfortunate Events (Europe, French / Spanish)"#),
-    "72ba6b8c" => (1, false, r#"Lemony Snicket's A Series of Unfortunate Events (Europe, USA)"#),
-    "1d96366c" => (2, false, r#"Let's Ride! - Dreamer (USA)"#),
-    "8bdd13ad" => (2, false, r#"Let's Ride! - Friends Forever (USA)"#),
-    "684eb887" => (2, false, r#"Let's Ride! - Sunshine Stables (USA)"#),
-    "e9f62fe0" => (3, false, r#"Licca-chan no Oshare Nikki (Japan)"#),
-    "1afab451" => (2, false, r#"Lilliput Oukoku - Lillimoni to Issho Puni! (Japan)"#),
-    "e7bc4ef1" => (0, false, r#"Disney's Lilo & Stitch (Europe, rev. 1)"#),
-    "2a10f7e4" => (0, false, r#"Disney's Lilo & Stitch (Europe)"#),
-    "542aa4ac" => (0, false, r#"Disney's Lilo & Stitch (USA)"#),
-    "a88587a6" => (1, false, r#"Disney's Lilo & Stitch 2 (Europe)"#),
-    "021a5755" => (1, false, r#"Disney's Lilo & Stitch 2 - Hämsterviel Havoc (USA)"#),
-    "db1e7311" => (1, false, r#"Disney's Lilo & Stitch (Japan)"#),
-    "ea5ed4c0" => (1, false, r#"Disney The Lion King 1½ (USA)"#),
-    "63592451" => (1, false, r#"Disney The Lion King (Europe)"#),
-    "d26e49f8" => (3, false, r#"Little Buster Q (Japan)"#),
-    "c8340b37" => (0, false, r#"Disney's Little Einsteins (USA)"#),
-    "b550f8cb" => (0, false, r#"Little League Baseball 2002 (USA)"#),
-    "02e35663" => (1, false, r#"Disney's The Little Mermaid - Magic in Two Kingdoms (Europe, USA)"#),
-    "8fea41c4" => (2, false, r#"Little Patissier - Cake no Oshiro (Japan)"#),
-    "7e9283f2" => (2, false, r#"Lizzie McGuire (Europe)"#),
-    "70ded768" => (2, false, r#"Lizzie McGuire - On the Go! (USA)"#),
-    "2706b300" => (4, false, r#"Disney's Game + TV Episode - Lizzie McGuire 2 - Lizzie Diaries (USA)"#),
-    "f11009f9" => (1, false, r#"Lizzie McGuire 2 - Lizzie Diaries (USA)"#),
-    "a2843980" => (1, false, r#"Lizzie McGuire 3 - Homecoming Havoc (USA)"#),
-    "3e2cf3ff" => (1, false, r#"Lode Runner (Japan)"#),
-    "6897efa6" => (0, false, r#"LOCKJAW: The Reference"#),
-    "7d286b94" => (0, false, r#"LOCKJAW: The Overdose"#),
-    "8ba01ad0" => (0, false, r#"Tetanus on Drugs (Milestone 3)"#),
-    "2fcadfd3" => (0, false, r#"Tetanus on Drugs (Preview Release 1)"#),
-    "2b8b3964" => (1, false, r#"Looney Tunes - Back in Action (Europe, USA)"#),
-    "dca23b31" => (1, false, r#"Looney Tunes Double Pack (Europe)"#),
-    "ecadd047" => (1, false, r#"Looney Tunes Double Pack (USA)"#),
-    "dc8d4667" => (1, false, r#"The Lord of the Rings - Futatsu no Tou (Japan)"#),
-    "cf0534f8" => (1, false, r#"The Lord of the Rings - Nakatsukuni Daisanki (Japan)"#),
-    "465f2753" => (1, false, r#"The Lord of the Rings - Ou no Kikan (Japan)"#),
-    "2380b93a" => (2, false, r#"The Lord of the Rings - The Fellowship of the Ring (Europe)"#),
-    "5a2b01f1" => (2, false, r#"The Lord of the Rings - The Fellowship of the Ring (USA, rev. 1)"#),
-    "ee0c58bc" => (2, false, r#"The Lord of the Rings - The Fellowship of the Ring (USA)"#),
-    "7590ebfa" => (1, false, r#"The Lord of the Rings - The Return of the King (Europe, USA)"#),
-    "2def3656" => (1, false, r#"The Lord of the Rings - The Third Age (Europe, USA)"#),
-    "a1e3bf33" => (1, false, r#"The Lord of the Rings - The Two Towers (Europe, USA)"#),
-    "1c008d32" => (1, false, r#"The Lost Vikings (Europe)"#),
-    "8cb58997" => (1, false, r#"The Lost Vikings (USA)"#),
-    "b8e67449" => (1, false, r#"Love Hina Advance - Shukufuku no Kane wa Naru Kana (Japan)"#),
-    "f12f29a9" => (0, false, r#"Lucky Luke - Wanted! (Europe)"#),
-    "de5ffcbc" => (3, false, r#"Lufia - The Ruins of Lore (USA)"#),
-    "4e044191" => (3, false, r#"Lunar Legend (Japan)"#),
-    "885266a2" => (2, false, r#"Lunar Legend (USA)"#),
-    "ad38c301" => (1, false, r#"M&M's - Blast! (USA)"#),
-    "a4ea65c0" => (0, false, r#"M&M's - Break 'em (USA, rev. 1)"#),
-    "394f2126" => (1, false, r#"Madagascar (Europe, English)"#),
-    "274c9135" => (1, false, r#"Madagascar (Europe, French / German / Portuguese)"#),
-    "9467225b" => (1, false, r#"Madagascar (Italy)"#),
-    "2d6d478c" => (1, false, r#"Madagascar (Japan)"#),
-    "ebfab740" => (1, false, r#"Madagascar (Netherlands)"#),
-    "0c6070b5" => (1, false, r#"Madagascar (Spain)"#),
-    "7503f71b" => (1, false, r#"Madagascar (USA)"#),
-    "15bb7278" => (1, false, r#"Madagascar - Operation Penguin (Europe, English)"#),
-    "5b519e47" => (1, false, r#"Madagascar - Operación Pingüino (Spain)"#),
-    "6b5b70c5" => (1, false, r#"Madagascar - Operation Penguin (Europe, French / German)"#),
-    "8d8b5808" => (1, false, r#"Madagascar - Operation Penguin (Europe, Italian / Dutch)"#),
-    "da3fa501" => (1, false, r#"Madagascar - Operation Penguin (USA)"#),
-    "374fd6f9" => (2, false, r#"Madden NFL 06 (USA)"#),
-    "c5805b1d" => (2, false, r#"Madden NFL 07 (USA)"#),
-    "8e797ecd" => (1, false, r#"Madden NFL 2002 (USA)"#),
-    "64b93824" => (2, false, r#"Madden NFL 2003 (USA)"#),
-    "170bb11a" => (2, false, r#"Madden NFL 2004 (USA)"#),
-    "0b79a67b" => (2, false, r#"Madden NFL 2005 (USA)"#),
-    "87a0eea0" => (3, false, r#"Made in Wario (Japan)"#),
-    "ef6807ca" => (3, false, r#"MÄR Heaven - Knockin' on Heaven's Door (Japan)"#),
-    "6a94531b" => (2, false, r#"Magi Nation (Japan)"#),
-    "70123550" => (5, false, r#"Magical Houshin (Japan)"#),
-    "f9498038" => (1, false, r#"Disney's Magical Quest 2 Starring Mickey & Minnie (Europe)"#),
-    "69f1117d" => (1, false, r#"Disney's Magical Quest 2 Starring Mickey & Minnie (USA)"#),
-    "aad912f9" => (1, false, r#"Disney's Magical Quest 3 Starring Mickey & Donald (Europe)"#),
-    "5a23094d" => (1, false, r#"Disney's Magical Quest 3 Starring Mickey & Donald (USA)"#),
-    "94f3b7c0" => (1, false, r#"Disney's Magical Quest Starring Mickey & Minnie (Europe)"#),
-    "b4294fa7" => (1, false, r#"Disney's Magical Quest Starring Mickey & Minnie (USA)"#),
-    "59cbe914" => (5, false, r#"Magical Vacation (Japan)"#),
-    "ad32a2d3" => (3, false, r#"Mahjong Keiji (Japan)"#),
-    "f9652b4d" => (1, false, r#"Mahou no Pumpkin - Ann to Greg no Daibouken (Japan)"#),
-    "79d04dad" => (3, false, r#"Mahou Sensei Negima! - Private Lesson - Damedesuu Toshokan-jima (Japan)"#),
-    "6357c53b" => (3, false, r#"Mahou Sensei Negima! - Private Lesson 2 - Ojamashimasuu Parasite de Chuu
-			(Japan)"#),
-    "3b984291" => (5, false, r#"Mail de Cute (Japan)"#),
-    "4fbc3f62" => (0, false, r#"Rec Room Challenge (Europe)"#),
-    "aafc9afa" => (0, false, r#"Rec Room Challenge (USA)"#),
-    "99140705" => (0, false, r#"3-in-1 Sports Pack (Europe)"#),
-    "a91bec73" => (0, false, r#"3-in-1 Sports Pack (USA)"#),
-    "91bebbee" => (1, false, r#"Majokko Cream-chan no Gokko Series 1 - Wannyan Idol Gakuen (Japan)"#),
-    "2ed6c55f" => (1, false, r#"Majokko Cream-chan no Gokko Series 2 - Kisekae Angel (Japan)"#),
-    "61a602d7" => (2, false, r#"Manga-ka Debut Monogatari (Japan)"#),
-    "f0c22c62" => (2, false, r#"Manga-ka Debut Monogatari (Japan, rev. 1)"#),
-    "2412c5eb" => (1, false, r#"Maniac Racers Advance (Europe)"#),
-    "138439f4" => (0, false, r#"Manic Miner (Europe)"#),
-    "fb3d52bd" => (0, false, r#"Manic Miner (Europe, 20030307)"#),
-    "f680b0f3" => (0, false, r#"March of the Penguins (Europe)"#),
-    "5195429f" => (0, false, r#"March of the Penguins (USA)"#),
-    "1b70a454" => (2, false, r#"Marie, Elie & Anis no Atelier - Soyokaze kara no Dengon (Japan)"#),
-    "170cc574" => (2, false, r#"Mario & Luigi - Superstar Saga (Europe)"#),
-    "a94b04b2" => (1, false, r#"Mario & Luigi - Superstar Saga (USA, demo Kiosk)"#),
-    "e718d850" => (2, false, r#"Mario & Luigi - Superstar Saga (USA, Australia)"#),
-    "4ef93d41" => (2, false, r#"Mario & Luigi RPG (Japan)"#),
-    "d6ad422f" => (3, false, r#"Mario Golf - Advance Tour (Australia)"#),
-    "b483127c" => (3, false, r#"Mario Golf - Advance Tour (Europe)"#),
-    "bd5be463" => (3, false, r#"Mario Golf - Advance Tour (France)"#),
-    "3e19e279" => (3, false, r#"Mario Golf - Advance Tour (Germany)"#),
-    "05b0454a" => (3, false, r#"Mario Golf - Advance Tour (Italy)"#),
-    "c35dd9f8" => (3, false, r#"Mario Golf - Advance Tour (Spain)"#),
-    "d56c2e54" => (3, false, r#"Mario Golf - Advance Tour (USA)"#),
-    "a8342a16" => (3, false, r#"Mario Golf - GBA Tour (Japan)"#),
-    "20025842" => (5, false, r#"Mario Kart - Super Circuit (Europe)"#),
-    "ed316e37" => (5, false, r#"Mario Kart - Super Circuit (USA)"#),
-    "30e99fcd" => (5, false, r#"Mario Kart Advance (Japan)"#),
-    "e3075601" => (0, false, r#"Mario Kart XXL (demo, 20040417)"#),
-    "c8c889e2" => (2, false, r#"Mario Party Advance (Europe)"#),
-    "baa4a82b" => (2, false, r#"Mario Party Advance (Japan)"#),
-    "f094a4cb" => (2, false, r#"Mario Party Advance (USA)"#),
-    "70a6d2c1" => (1, false, r#"Mario Pinball Land (USA, Australia)"#),
-    "c8db4f60" => (3, false, r#"Mario Power Tennis (Europe)"#),
-    "975e8d98" => (3, false, r#"Mario Tennis Advance (Japan)"#),
-    "da192d29" => (3, false, r#"Mario Tennis - Power Tour (USA, Australia)"#),
-    "ca030d61" => (4, false, r#"Mario vs. Donkey Kong (Europe)"#),
-    "7c2b5269" => (4, false, r#"Mario vs. Donkey Kong (Japan)"#),
-    "25c394d1" => (4, false, r#"Mario vs. Donkey Kong (USA, Australia)"#),
-    "25505164" => (4, false, r#"Mario vs. Donkey Kong (USA, Kiosk, v16)"#),
-    "c150790f" => (1, false, r#"Marvel - Ultimate Alliance (Europe)"#),
-    "a74294db" => (1, false, r#"Marvel - Ultimate Alliance (USA)"#),
-    "8af3f3ad" => (1, false, r#"Mary-Kate and Ashley - Girls Night Out (Europe, USA)"#),
-    "5fe092c6" => (0, false, r#"Mary-Kate and Ashley Sweet 16 - Licensed to Drive (Europe, USA)"#),
-    "75491e30" => (0, false, r#"Masters of the Universe He-Man - Power of Grayskull (USA)"#),
-    "a333fa51" => (1, false, r#"Mat Hoffman's Pro BMX (Europe, USA)"#),
-    "78d22ae9" => (1, false, r#"Mat Hoffman's Pro BMX (Europe, French / German)"#),
-    "e7fa71c6" => (1, false, r#"Mat Hoffman's Pro BMX 2 (Europe, USA)"#),
-    "d647ab18" => (2, false, r#"Matantei Loki Ragnarok - Gensou no Labyrinth (Japan, rev. 1)"#),
-    "4ebdad86" => (2, false, r#"Matantei Loki Ragnarok - Gensou no Labyrinth (Japan)"#),
-    "c9ea02f5" => (0, false, r#"Matchbox Cross Town Heroes (Europe)"#),
-    "0fffb458" => (0, false, r#"Matchbox Cross Town Heroes (USA)"#),
-    "aecd1301" => (1, false, r#"Math Patrol - The Kleptoid Threat (USA)"#),
-    "e69964f1" => (0, false, r#"Mawaru - Made in Wario (Japan)"#),
-    "7d902b62" => (1, false, r#"Max Payne Advance (Europe)"#),
-    "296e1092" => (1, false, r#"Max Payne (USA)"#),
-    "a75778c7" => (1, false, r#"Maya the Bee - Sweet Gold (Europe)"#),
-    "a1764f3c" => (0, false, r#"Maya the Bee - The Great Adventure (Europe)"#),
-    "0b725a08" => (2, false, r#"Mazes of Fate (USA)"#),
-    "4b3e0531" => (2, false, r#"Mech Platoon (Europe)"#),
-    "769fde96" => (2, false, r#"Mech Platoon (USA)"#),
-    "64251e43" => (1, false, r#"Mechanic Mechanica (Japan, prototype)"#),
-    "50927f3e" => (2, false, r#"Medabots - Metabee Version (Europe)"#),
-    "7e907ec8" => (2, false, r#"Medabots - Metabee Version (Spain)"#),
-    "59f208fc" => (2, false, r#"Medabots - Metabee Version (USA)"#),
-    "a519feb5" => (2, false, r#"Medabots - Rokusho Version (Europe)"#),
-    "046d86c4" => (2, false, r#"Medabots - Rokusho Version (Spain)"#),
-    "e144ded2" => (2, false, r#"Medabots - Rokusho Version (USA)"#),
-    "5f1e5a48" => (1, false, r#"Medabots AX - Metabee Version (Europe)"#),
-    "03294511" => (1, false, r#"Medabots AX - Metabee Version (USA)"#),
-    "f5801bd8" => (1, false, r#"Medabots AX - Rokusho Version (Europe)"#),
-    "92fdb8d6" => (1, false, r#"Medabots AX - Rokusho Version (USA)"#),
-    "f23150a4" => (1, false, r#"Medal of Honor - Infiltrator (Europe, USA)"#),
-    "9db145b8" => (0, false, r#"Medal of Honor - Underground (Europe, Ubi Soft)"#),
-    "72b4cf20" => (0, false, r#"Medal of Honor - Underground (Zoo Digital) (Europe)"#),
-    "6dfa0f50" => (0, false, r#"Medal of Honor - Underground (USA)"#),
-    "a054a4d0" => (1, false, r#"Medal of Honor Advance (Japan)"#),
-    "b107c73d" => (1, false, r#"Medarot G - Kabuto Version (Japan)"#),
-    "14e3ebcc" => (1, false, r#"Medarot G - Kuwagata Version (Japan)"#),
-    "15450b63" => (5, false, r#"Medarot Navi - Kabuto (Japan)"#),
-    "e83d07d6" => (5, false, r#"Medarot Navi - Kuwagata (Japan)"#),
-    "3fcaf2d0" => (2, false, r#"Medarot Ni Core - Kabuto Version (Japan)"#),
-    "285fe485" => (2, false, r#"Medarot Ni Core - Kuwagata Version (Japan)"#),
-    "df3e58d3" => (1, false, r#"Disney's Meet the Robinsons (Europe)"#),
-    "3787c93c" => (1, false, r#"Disney's Meet the Robinsons (USA)"#),
-    "01b4d95e" => (2, false, r#"Mega Man & Bass (Europe)"#),
-    "eea68c2e" => (2, false, r#"Mega Man & Bass (USA)"#),
-    "5b4631f9" => (3, false, r#"Mega Man Battle Chip Challenge (Europe)"#),
-    "26be44fd" => (3, false, r#"Mega Man Battle Chip Challenge (USA)"#),
-    "1a7fb4fa" => (3, false, r#"Mega Man Battle Network (Europe)"#),
-    "1d347971" => (3, false, r#"Mega Man Battle Network (USA)"#),
-    "66341f3b" => (3, false, r#"Mega Man Battle Network 2 (Europe)"#),
-    "6d961f82" => (3, false, r#"Mega Man Battle Network 2 (USA)"#),
-    "1f036cba" => (3, false, r#"Mega Man Battle Network 3 Blue (Europe)"#),
-    "c0c780f9" => (3, false, r#"Mega Man Battle Network 3 Blue (USA)"#),
-    "23d0a981" => (3, false, r#"Mega Man Battle Network 3 White (Europe)"#),
-    "0be4410a" => (3, false, r#"Mega Man Battle Network 3 White (USA)"#),
-    "48758316" => (3, false, r#"Mega Man Battle Network 4 Blue Moon (Europe)"#),
-    "758a46e9" => (3, false, r#"Mega Man Battle Network 4 Blue Moon (USA)"#),
-    "0cb136c2" => (3, false, r#"Mega Man Battle Network 4 Red Sun (Europe)"#),
-    "2120695c" => (3, false, r#"Mega Man Battle Network 4 Red Sun (USA)"#),
-    "8fc8cf73" => (3, false, r#"Mega Man Battle Network 5 Team Colonel (Europe)"#),
-    "a552f683" => (3, false, r#"Mega Man Battle Network 5 Team Colonel (USA)"#),
-    "79f45ed8" => (3, false, r#"Mega Man Battle Network 5 Team Protoman (Europe)"#),
-    "a73e83a4" => (3, false, r#"Mega Man Battle Network 5 Team Protoman (USA)"#),
-    "13183967" => (3, false, r#"Mega Man Battle Network 6 Cybeast Falzar (Europe)"#),
-    "dee6f2a9" => (3, false, r#"Mega Man Battle Network 6 Cybeast Falzar (USA)"#),
-    "25c29efb" => (3, false, r#"Mega Man Battle Network 6 Cybeast Gregar (Europe)"#),
-    "79452182" => (3, false, r#"Mega Man Battle Network 6 Cybeast Gregar (USA)"#),
-    "9707d2a1" => (3, false, r#"Mega Man Zero (Europe, USA)"#),
-    "29a14b59" => (3, false, r#"Mega Man Zero 2 (Europe)"#),
-    "ce1e37bb" => (3, false, r#"Mega Man Zero 2 (USA)"#),
-    "b099577f" => (3, false, r#"Mega Man Zero 3 (Europe)"#),
-    "2784f3f2" => (3, false, r#"Mega Man Zero 3 (USA)"#),
-    "b7f022b9" => (2, false, r#"Mega Man Zero 4 (Europe)"#),
-    "7ee24793" => (2, false, r#"Mega Man Zero 4 (USA)"#),
-    "13ce32e8" => (2, false, r#"Meine Tierarztpraxis (Germany)"#),
-    "78ad82ff" => (2, false, r#"Meine Tierpension ~ My Pet Hotel (Germany)"#),
-    "c048b723" => (2, false, r#"Detective Conan: Akatsuki no Monument (Japan)"#),
-    "5f9c7a4a" => (2, false, r#"Detective Conan: Nerawareta Tantei (Japan)"#),
-    "56ba6858" => (0, false, r#"Men in Black - The Series (Europe)"#),
-    "589810a5" => (0, false, r#"Men in Black - The Series (USA)"#),
-    "63f72589" => (3, false, r#"Mermaid Melody - Pichi Pichi Pitch (Japan)"#),
-    "f786ffa7" => (3, false, r#"Mermaid Melody - Pichi Pichi Pitch - Pichi Pichi Party (Japan)"#),
-    "7a4fdec3" => (3, false, r#"Mermaid Melody - Pichi Pichi Pitch - Pichi Pichitto Live Start! (Japan)"#),
-    "66725a09" => (2, false, r#"Metal Max 2 Kai (Japan, rev. 1)"#),
-    "bc1feaf0" => (2, false, r#"Metal Max 2 Kai (Japan)"#),
-    "3806f4ae" => (1, false, r#"Metal Slug Advance (Europe)"#),
-    "0caba589" => (1, false, r#"Metal Slug Advance (Japan)"#),
-    "09980880" => (1, false, r#"Metal Slug Advance (USA)"#),
-    "8dfb7a2f" => (2, false, r#"Metalgun Slinger (Japan)"#),
-    "f1d92e63" => (3, false, r#"Metroid - Zero Mission (Europe)"#),
-    "b6438226" => (3, false, r#"Metroid - Zero Mission (Europe, prototype)"#),
-    "44b79e2b" => (3, false, r#"Metroid - Zero Mission (Japan)"#),
-    "5c61a844" => (3, false, r#"Metroid - Zero Mission (USA)"#),
-    "922c4a10" => (3, false, r#"Metroid - Zero Mission (USA, prototype)"#),
-    "974e46ab" => (3, false, r#"Metroid Fusion (Europe)"#),
-    "3acbd239" => (3, false, r#"Metroid Fusion (Europe, prototype 20020911)"#),
-    "1605e4f7" => (3, false, r#"Metroid Fusion (Europe, prototype 20020916)"#),
-    "817a7e9e" => (3, false, r#"Metroid Fusion (Japan)"#),
-    "6c75479c" => (3, false, r#"Metroid Fusion (USA, Australia)"#),
-    "50e473a8" => (4, false, r#"Mezase! Koushien (Japan)"#),
-    "edb264b8" => (1, false, r#"Mickey no Pocket Resort (Japan)"#),
-    "32d851f7" => (1, false, r#"Disney's Mickey to Donald no Magical Quest 3 (Japan)"#),
-    "bbae85c8" => (1, false, r#"Disney's Mickey to Minnie no Magical Quest (Japan)"#),
-    "092a7d0a" => (1, false, r#"Disney's Mickey to Minnie no Magical Quest 2 (Japan)"#),
-    "9d102c82" => (0, false, r#"Micro Machines (Europe)"#),
-    "091230c6" => (0, false, r#"Midnight Club - Street Racing (Europe)"#),
-    "911be520" => (0, false, r#"Midnight Club - Street Racing (USA)"#),
-    "c01c4d0a" => (0, false, r#"Midway's Greatest Arcade Hits (Europe, USA)"#),
-    "54df36da" => (1, false, r#"Mighty Beanz Pocket Puzzles (USA)"#),
-    "310eb2fe" => (2, false, r#"Mijn Dierenpension ~ My Pet Hotel (Netherlands)"#),
-    "227a5430" => (2, false, r#"Mijn Dierenpraktijk ~ My Vet Practice (Netherlands)"#),
-    "b9566812" => (1, false, r#"Mike Tyson Boxing (Europe)"#),
-    "48109f8d" => (1, false, r#"Mike Tyson Boxing (USA)"#),
-    "6e53115b" => (1, false, r#"Minami no Umi no Odyssey (Japan)"#),
-    "492cf823" => (3, false, r#"Mini Moni. - Mika no Happy Morning Chatty (Japan)"#),
-    "f11c35cc" => (1, false, r#"Mini Moni. - Onegai Ohoshi-sama! (Japan)"#),
-    "857fb1ef" => (1, false, r#"Minna de Puyo Puyo (Japan)"#),
-    "19dc05e6" => (3, false, r#"Minna no Ouji-sama (Japan)"#),
-    "20e296a6" => (2, false, r#"Minna no Shiiku Series - Boku no Kabuto, Kuwagata (Japan)"#),
-    "f83fe589" => (3, false, r#"Minna no Shiiku Series 1 - Boku no Kabutomushi (Japan)"#),
-    "c0ba281d" => (3, false, r#"Minna no Shiiku Series 2 - Boku no Kuwagata (Japan)"#),
-    "d0ef2bc4" => (1, false, r#"Minna no Soft Series - Happy Trump 20 (Japan)"#),
-    "481d1d5f" => (1, false, r#"Minna no Soft Series - Hyokkori Hyoutan-jima - Don Gabacho Daikatsuyaku no Maki
-			(Japan)"#),
-    "66b779b0" => (1, false, r#"Minna no Soft Series - Minna no Mahjong (Japan)"#),
-    "c62c15c6" => (1, false, r#"Minna no Soft Series - Minna no Shougi (Japan, rev. 1)"#),
-    "08b62391" => (1, false, r#"Minna no Soft Series - Minna no Shougi (Japan)"#),
-    "044c6f68" => (2, false, r#"Minna no Soft Series - Numpla Advance (Japan)"#),
-    "feefeddc" => (1, false, r#"Minna no Soft Series - Shanghai (Japan)"#),
-    "524d0749" => (1, false, r#"Minna no Soft Series - Tetris Advance (Japan, rev. 1)"#),
-    "67c92883" => (1, false, r#"Minna no Soft Series - Tetris Advance (Japan)"#),
-    "72eb35e3" => (2, false, r#"Minna no Soft Series - Zooo (Japan)"#),
-    "3d56ead6" => (1, false, r#"Minority Report (Europe, USA)"#),
-    "3a87f78b" => (1, false, r#"Mirakuru! Panzou - 7-tsu no Hoshi no Uchuu Kaizoku (Japan)"#),
-    "45de1669" => (1, false, r#"Mission Impossible - Operation Surma (Europe)"#),
-    "f3dc4e3e" => (1, false, r#"Mission Impossible - Operation Surma (USA)"#),
-    "e951865c" => (3, false, r#"Miteluode - Lingdian Renwu (China)"#),
-    "042e5b07" => (3, false, r#"Miteluode Ronghe (China)"#),
-    "a4e12d4b" => (0, false, r#"MLB SlugFest 20-04 (USA)"#),
-    "8df8a0c0" => (5, false, r#"Mobile Pro Yakyuu - Kantoku no Saihai (Japan)"#),
-    "180d1491" => (0, false, r#"Mobile Suit Gundam Seed - Battle Assault (USA)"#),
-    "6da36e82" => (1, false, r#"Moero!! Jaleco Collection (Japan)"#),
-    "5f3e184e" => (2, false, r#"Momotarou Dentetsu G Gold Deck o Tsukure! (Japan)"#),
-    "bf0523d1" => (3, false, r#"Momotarou Matsuri (Japan, rev. 1)"#),
-    "7b113397" => (0, false, r#"Monopoly (Europe)"#),
-    "b01cbc07" => (0, false, r#"Monopoly (USA)"#),
-    "c140ff7a" => (3, false, r#"Monster Farm Advance (Japan)"#),
-    "1b14cf53" => (3, false, r#"Monster Farm Advance 2 (Japan)"#),
-    "137b0f9a" => (1, false, r#"Monster Force (Europe)"#),
-    "ad4691d9" => (1, false, r#"Monster Force (USA)"#),
-    "5b8aaa03" => (3, false, r#"Monster Gate (Japan)"#),
-    "05ac7685" => (3, false, r#"Monster Gate - Ooinaru Dungeon - Fuuin no Orb (Japan)"#),
-    "93815cde" => (3, false, r#"Monster Guardians (Japan)"#),
-    "21c84334" => (1, false, r#"Monster House (Europe, English / French / German / Spanish)"#),
-    "aa2c1837" => (1, false, r#"Monster House (Europe, English)"#),
-    "148b5072" => (1, false, r#"Monster House (USA)"#),
-    "c3ad7701" => (3, false, r#"Monster Jam - Maximum Destruction (Europe)"#),
-    "43d9b9f1" => (3, false, r#"Monster Jam - Maximum Destruction (USA)"#),
-    "5e2c90ae" => (3, false, r#"Monster Maker 4 - Flash Card (Japan)"#),
-    "f77e6ecf" => (3, false, r#"Monster Maker 4 - Killer Dice (Japan)"#),
-    "42cfa4b5" => (3, false, r#"Monster Rancher Advance (USA)"#),
-    "1d695b1b" => (3, false, r#"Monster Rancher Advance 2 (USA)"#),
-    "f8a085e2" => (3, false, r#"Monster Summoner (Japan)"#),
-    "dd872538" => (1, false, r#"Monster Truck Madness (Europe, USA)"#),
-    "4a72170a" => (0, false, r#"Monster Trucks (Europe, USA)"#),
-    "31500381" => (0, false, r#"Monster Trucks Mayhem (Europe)"#),
-    "f47c5cf9" => (0, false, r#"Monster Trucks Mayhem (USA)"#),
-    "14db186b" => (0, false, r#"Monster! Bass Fishing (Europe)"#),
-    "3edba390" => (0, false, r#"Monster! Bass Fishing (USA)"#),
-    "327834c9" => (0, false, r#"Monsters, Inc. (Europe, USA)"#),
-    "d2c53ab8" => (0, false, r#"Monsters, Inc. (Europe, English / French / Italian)"#),
-    "d13177e0" => (0, false, r#"Monsters, Inc. (Europe, English / Spanish / Dutch)"#),
-    "a23cb45f" => (0, false, r#"Die Monster AG (Germany)"#),
-    "0dfff34f" => (0, false, r#"Monsters, Inc. (Japan)"#),
-    "a16c10a8" => (1, false, r#"Moorhen 3 - The Chicken Chase! (Europe)"#),
-    "149e01b7" => (3, false, r#"Morita Shougi Advance (Japan)"#),
-    "b734eb4e" => (0, false, r#"The Morning Adventure (Spain, promo)"#),
-    "fb291cca" => (1, false, r#"Mortal Kombat - Deadly Alliance (Europe)"#),
-    "a29ba64f" => (1, false, r#"Mortal Kombat - Deadly Alliance (USA)"#),
-    "968be7bc" => (1, false, r#"Mortal Kombat - Tournament Edition (USA)"#),
-    "78b4b5c7" => (0, false, r#"Mortal Kombat Advance (Europe)"#),
-    "bcdabb47" => (0, false, r#"Mortal Kombat Advance (USA)"#),
-    "0a44569c" => (3, false, r#"Mother 1+2 (Japan)"#),
-    "42ac9cb9" => (4, false, r#"Mother 3 (Japan)"#),
-    "508bf8dc" => (0, false, r#"Moto GP (Europe, prototype)"#),
-    "c6d17d0d" => (0, false, r#"Moto GP (Europe)"#),
-    "4f6d7e49" => (1, false, r#"Moto GP (Japan)"#),
-    "8ee93eb8" => (0, false, r#"Moto GP (USA)"#),
-    "e5a7de43" => (0, false, r#"Motocross Challenge (USA, prototype)"#),
-    "0a0faef4" => (1, false, r#"Motocross Maniacs Advance (USA)"#),
-    "043a3554" => (1, false, r#"Motocross Maniacs Advance (Japan)"#),
-    "8cb19da0" => (3, false, r#"Motoracer Advance (Europe)"#),
-    "2fdbe7a8" => (3, false, r#"Motoracer Advance (USA)"#),
-    "7f21d693" => (0, false, r#"Mr Nutz (Europe)"#),
-    "c0e9eebe" => (3, false, r#"Mr. Driller 2 (Europe)"#),
-    "5264c730" => (3, false, r#"Mr. Driller 2 (Japan)"#),
-    "02f51696" => (3, false, r#"Mr. Driller 2 (USA)"#),
-    "529f06a4" => (3, false, r#"Mr. Driller A - Fushigi na Pacteria (Japan)"#),
-    "73fa8b2a" => (0, false, r#"Mr. Incredible (Japan)"#),
-    "667f54ca" => (0, false, r#"Mr. Incredible - Kyouteki Underminer Toujou (Japan)"#),
-    "6e03fd6a" => (1, false, r#"Ms. Pac-Man - Maze Madness (Europe)"#),
-    "dde5df02" => (1, false, r#"Ms. Pac-Man - Maze Madness (USA)"#),
-    "03bbc0c9" => (1, false, r#"Mucha Lucha! - Mascaritas of the Lost Code (USA)"#),
-    "1c31bbec" => (3, false, r#"Mugenborg (Japan)"#),
-    "b0f3799f" => (0, false, r#"The Mummy (Europe)"#),
-    "55bf350b" => (0, false, r#"The Mummy (USA)"#),
-    "17cd149a" => (0, false, r#"Jim Henson's Muppets - On with the Show! (Europe, USA)"#),
-    "8d5034d7" => (1, false, r#"Muppet Pinball Mayhem (Europe)"#),
-    "58575f65" => (1, false, r#"Muppet Pinball Mayhem (USA)"#),
-    "3a45a6d9" => (3, false, r#"Mutsu - Water Looper Mutsu (Japan)"#),
-    "0bf1dc56" => (0, false, r#"MX 2002 featuring Ricky Carmichael (Europe, USA)"#),
-    "16c0e028" => (2, false, r#"Meine Tierklinik in Afrika ~ My Animal Centre in Africa (Europe)"#),
-    "90dbd5eb" => (0, false, r#"My Little Pony - Crystal Princess - The Runaway Rainbow (USA)"#),
-    "d0c608f6" => (3, false, r#"Nakayoshi Mahjong - KabuReach (Japan)"#),
-    "74930b16" => (2, false, r#"Nakayoshi Pet Advance Series 1 - Kawaii Hamster (Japan)"#),
-    "954da3e6" => (2, false, r#"Nakayoshi Pet Advance Series 2 - Kawaii Koinu (Japan)"#),
-    "89fbeb55" => (2, false, r#"Nakayoshi Pet Advance Series 3 - Kawaii Koneko (Japan, rev. 1)"#),
-    "0290dfb5" => (2, false, r#"Nakayoshi Pet Advance Series 3 - Kawaii Koneko (Japan)"#),
-    "ae2a69f3" => (2, false, r#"Nakayoshi Pet Advance Series 4 - Kawaii Koinu Mini - Wankoto Asobou!!
-			Kogata-ken (Japan)"#),
-    "b46daca0" => (2, false, r#"Nakayoshi Youchien - Sukoyaka Enji Ikusei Game (Japan, rev. 1)"#),
-    "1276a95c" => (2, false, r#"Nakayoshi Youchien - Sukoyaka Enji Ikusei Game (Japan)"#),
-    "bb82460a" => (0, false, r#"Namco Museum (Europe)"#),
-    "818f4e4b" => (0, false, r#"Namco Museum (Japan)"#),
-    "c58a04c1" => (0, false, r#"Namco Museum (USA)"#),
-    "2f098a42" => (0, false, r#"Namco Museum - 50th Anniversary (Europe)"#),
-    "38da81fe" => (0, false, r#"Namco Museum - 50th Anniversary (USA)"#),
-    "7652cd25" => (0, false, r#"Nancy Drew - Message in a Haunted Mansion (USA)"#),
-    "fa016764" => (3, false, r#"Napoleon (Japan)"#),
-    "0ff11189" => (3, false, r#"Narikiri Jockey Game - Yuushun Rhapsody (Japan)"#),
-    "69cf0418" => (3, false, r#"Naruto - Konoha Senki (Japan, rev. 1)"#),
-    "11829afa" => (3, false, r#"Naruto - Konoha Senki (Japan)"#),
-    "c402878c" => (1, false, r#"Shonen Jump's Naruto - Ninja Council (USA)"#),
-    "94699fca" => (3, false, r#"Shonen Jump Naruto - Ninja Council 2 (USA)"#),
-    "f5f8ecb4" => (3, false, r#"Naruto - Ninjutsu Zenkai! Saikyou Ninja Daikesshuu (Japan, rev. 1)"#),
-    "e15875fc" => (3, false, r#"Naruto - Ninjutsu Zenkai! Saikyou Ninja Daikesshuu (Japan)"#),
-    "b3d75ce1" => (3, false, r#"Naruto - Saikyou Ninja Daikesshuu 2 (Japan)"#),
-    "2bf6555a" => (3, false, r#"Naruto RPG - Uketsugareshi Hi no Ishi (Japan, rev. 1)"#),
-    "c036d0f0" => (3, false, r#"Naruto RPG - Uketsugareshi Hi no Ishi (Japan)"#),
-    "87e15f5b" => (1, false, r#"NASCAR Heat 2002 (USA)"#),
-    "0de8b4b4" => (5, false, r#"Natural 2 - Duo (Japan)"#),
-    "ca428a7a" => (0, false, r#"NBA Jam 2002 (Europe, USA)"#),
-    "ea15a16e" => (1, false, r#"Need for Speed - Most Wanted (Europe, USA)"#),
-    "1b6b8f88" => (1, false, r#"Need for Speed - Porsche Unleashed (Europe)"#),
-    "99078af4" => (1, false, r#"Need for Speed - Porsche Unleashed (USA)"#),
-    "828020e9" => (1, false, r#"Need for Speed - Underground (Europe, USA)"#),
-    "9a0c5090" => (1, false, r#"Need for Speed - Underground 2 (Europe, USA)"#),
-    "f4c0d140" => (1, false, r#"Need for Speed Carbon - Own the City (Europe, USA)"#),
-    "e4fe52db" => (2, false, r#"Neoromance Game - Harukanaru Toki no Naka de (Japan, rev. 1)"#),
-    "bf12b590" => (2, false, r#"Neoromance Game - Harukanaru Toki no Naka de (Japan)"#),
-    "5297693a" => (1, false, r#"NGT - Next Generation Tennis (Europe)"#),
-    "8c748dcb" => (0, false, r#"NFL Blitz 20-02 (USA)"#),
-    "e0b137e7" => (0, false, r#"NFL Blitz 20-03 (USA)"#),
-    "d1d9e515" => (2, false, r#"NHL 2002 (USA)"#),
-    "4f1adf75" => (1, false, r#"NHL Hitz 20-03 (USA)"#),
-    "dda9a035" => (0, false, r#"Nicktoons - Attack of the Toybots (USA)"#),
-    "f0b0f53c" => (0, false, r#"Nicktoons - Attack of the Toybots ~ SpongeBob und seine Freunde - Angriff der
-			Spielzeugroboter (Germany)"#),
-    "feafbd72" => (1, false, r#"Nicktoons - Battle for Volcano Island (USA)"#),
-    "a8b6766e" => (1, false, r#"Nicktoons - Freeze Frame Frenzy (USA)"#),
-    "babccefd" => (1, false, r#"Nicktoons Racing (Europe)"#),
-    "50ab9bb6" => (1, false, r#"Nicktoons Racing (USA)"#),
-    "75c35f7c" => (0, false, r#"Nicktoons Unite! (USA)"#),
-    "085c1184" => (3, false, r#"Nihon Pro Mahjong Renmei Kounin Tetsuman Advance - Menkyo Kaiden Series (Japan)"#),
-    "c14295b1" => (1, false, r#"Ninja Cop (Europe)"#),
-    "eb3954c6" => (1, false, r#"Ninja Five-0 (USA)"#),
-    "1b00bc2c" => (1, false, r#"No No No Puzzle Chailien (Japan)"#),
-    "966fa650" => (0, false, r#"No Rules - Get Phat (Europe, USA)"#),
-    "526ab88e" => (3, false, r#"Nobunaga Ibun (Japan)"#),
-    "ef23c79e" => (5, false, r#"Nobunaga no Yabou (Japan)"#),
-    "607ddc6b" => (0, false, r#"Noddy - A Day in Toyland (Europe)"#),
-    "f906c5b6" => (0, false, r#"Noddy - A Day in Toyland (USA)"#),
-    "685e62ba" => (1, false, r#"Nyan Nyan Nyanko no NyanCollection (Japan)"#),
-    "eecdd3ef" => (1, false, r#"Ochaken Kururin - Honwaka Puzzle de Hotto Shiyo? (Japan)"#),
-    "61e2799a" => (2, false, r#"Ochaken no Bouken-jima - Honwaka Yume no Island (Japan)"#),
-    "c22ce53e" => (1, false, r#"Ochaken no Heya (Japan)"#),
-    "2937a9cd" => (1, false, r#"Ochaken no Yume Bouken (Japan)"#),
-    "fcc570a7" => (0, false, r#"Oddworld - Munch's Oddysee (Germany)"#),
-    "97367887" => (0, false, r#"Oddworld - Munch's Oddysee (Europe, USA)"#),
-    "d5059733" => (2, false, r#"Ohanaya-san Monogatari GBA - Iyashikei Ohanaya-san Ikusei Game (Japan)"#),
-    "6043595e" => (1, false, r#"Ojarumaru - Gekkouchou Sanpo de Ojaru (Japan)"#),
-    "07022c3a" => (3, false, r#"Okumanchouja Game - Nottori Daisakusen! (Japan)"#),
-    "7d2cf2a1" => (1, false, r#"One Piece (USA)"#),
-    "08cff461" => (2, false, r#"One Piece - Dragon Dream! (Japan)"#),
-    "66928f27" => (2, false, r#"One Piece - Going Baseball - Haejeok Yaku (Korea)"#),
-    "0b77bcb8" => (2, false, r#"One Piece - Going Baseball - Kaizoku Yakyuu (Japan)"#),
-    "05e56a4d" => (3, false, r#"One Piece - Ilgop Seomui Debomool (Korea)"#),
-    "63aa930a" => (2, false, r#"Onimusha Tactics (Europe)"#),
-    "b521c635" => (2, false, r#"Onimusha Tactics (Japan)"#),
-    "70475d7f" => (2, false, r#"Onimusha Tactics (USA)"#),
-    "66c29472" => (2, false, r#"Onmyou Taisenki Zeroshiki (Japan)"#),
-    "4c8f6fdc" => (1, false, r#"Open Season (Europe, English / French / German / Italian / Spanish /
-			Scandinavia)"#),
-    "51b0a94b" => (1, false, r#"Open Season (Europe, English / French / Spanish / Dutch)"#),
-    "e6a1d5b2" => (1, false, r#"Open Season (USA)"#),
-    "8f289e0b" => (0, false, r#"Operation Armored Liberty (USA)"#),
-    "05e80ecc" => (3, false, r#"Oriental Blue - Ao no Tengai (Japan)"#),
-    "df6901f0" => (5, false, r#"Oshaberi Inko Club (Japan)"#),
-    "5a2299e1" => (2, false, r#"Oshare Princess (Japan)"#),
-    "8383fcda" => (2, false, r#"Oshare Princess 2 (Japan)"#),
-    "f1e8a261" => (2, false, r#"Oshare Princess 3 (Japan)"#),
-    "38af35c1" => (1, false, r#"Oshare Princess 5 (Japan)"#),
-    "1cc60c27" => (2, false, r#"Oshare Wanko (Japan)"#),
-    "77a84969" => (0, false, r#"Ottifanten Pinball (Germany)"#),
-    "fb96d9de" => (5, false, r#"Ougon no Taiyou - Hirakareshi Fuuin (Japan)"#),
-    "830b795f" => (5, false, r#"Ougon no Taiyou - Ushinawareshi Toki (Japan)"#),
-    "c7023309" => (1, false, r#"Over the Hedge (Europe)"#),
-    "31e57a19" => (1, false, r#"Ab Durch die Hecke (Germany)"#),
-    "52a5b59d" => (1, false, r#"La Gang del Bosco (Italy)"#),
-    "eb7c8402" => (1, false, r#"Nos Voisins, les Hommes (France)"#),
-    "96af60f3" => (1, false, r#"Vecinos Invasores (Spain)"#),
-    "cf0f07c7" => (1, false, r#"Over the Hedge - Beesten bij de Buren (Netherlands)"#),
-    "5f3a7bb7" => (1, false, r#"Over the Hedge (USA)"#),
-    "8f1b100d" => (1, false, r#"Over the Hedge - Hammy Goes Nuts! (Europe)"#),
-    "b9d4e9e7" => (1, false, r#"Over the Hedge - Hammy Goes Nuts! (USA)"#),
-    "ee764cf6" => (0, false, r#"Overstorm (Europe, prototype demo)"#),
-    "d7245280" => (0, false, r#"Ozzy & Drix (USA)"#),
-    "1f2708d5" => (2, false, r#"Paard & Pony - Mijn Manege (Netherlands)"#),
-    "b6fc77f3" => (2, false, r#"Paard & Pony - Paard in Galop (Netherlands)"#),
-    "e1578d25" => (0, false, r#"Pac-Man Collection (Europe)"#),
-    "b0fddb8f" => (0, false, r#"Pac-Man Collection (Japan)"#),
-    "6e5c16f4" => (0, false, r#"Pac-Man Collection (USA)"#),
-    "3e70bf4d" => (0, false, r#"Pac-Man Pinball Advance (Europe)"#),
-    "da4e1109" => (0, false, r#"Pac-Man Pinball Advance (USA)"#),
-    "1f92814e" => (1, false, r#"Pac-Man World (Europe)"#),
-    "b2a4fc78" => (1, false, r#"Pac-Man World (USA)"#),
-    "a45eb4d2" => (0, false, r#"Pac-Man World 2 (Europe)"#),
-    "814a7d33" => (0, false, r#"Pac-Man World 2 (USA)"#),
-    "0420d6ff" => (0, false, r#"Paradroid (Europe, prototype, 20030320)"#),
-    "6ecf40a0" => (2, false, r#"Paws & Claws - Best Friends - Dogs & Cats (USA)"#),
-    "e58da71e" => (2, false, r#"Paws & Claws - Pet Resort (USA)"#),
-    "efee1ddf" => (2, false, r#"Paws & Claws - Pet Vet (USA)"#),
-    "962dd201" => (1, false, r#"Payback (Europe)"#),
-    "8daa89c9" => (1, false, r#"Pazuninn - Umininn no Puzzle de Nimu (Japan)"#),
-    "8fddd48b" => (3, false, r#"Penny Racers (Europe)"#),
-    "3442f9cf" => (0, false, r#"Perplexus Diodomin (prototype)"#),
-    "4c682cc3" => (0, false, r#"Disney's Peter Pan - Return to Neverland (Europe, rev. 1)"#),
-    "be7d574c" => (0, false, r#"Disney's Peter Pan - Return to Neverland (Europe)"#),
-    "431dfea8" => (0, false, r#"Disney's Peter Pan - Return to Neverland (USA, rev. 1)"#),
-    "4cafccbd" => (0, false, r#"Disney's Peter Pan - Return to Neverland (USA)"#),
-    "98c27e8a" => (0, false, r#"Peter Pan - The Motion Picture Event (Europe)"#),
-    "8ac558ce" => (1, false, r#"Peter Pan - The Motion Picture Event (USA)"#),
-    "9f0e4a6a" => (3, false, r#"Petz - Hamsterz Life 2 (USA)"#),
-    "1dbf4d3d" => (2, false, r#"Petz Vet (USA)"#),
-    "aa02cb0c" => (2, false, r#"Pferd & Pony - Best Friends - Mein Pferd ~ Best Friends - My Horse
-			(Germany)"#),
-    "6217469f" => (2, false, r#"Pferd & Pony - Lass Uns Reiten 2 ~ Horse & Pony - Let's Ride 2
-			(Germany)"#),
-    "2819bdf5" => (2, false, r#"Pferd & Pony - Mein Gestuet ~ My Stud Farm (Germany)"#),
-    "cc74b433" => (2, false, r#"Pferd & Pony - Mein Pferdehof (Germany)"#),
-    "dfcfe06c" => (1, false, r#"Phalanx - The Enforce Fighter A-144 (Europe)"#),
-    "5f419f32" => (1, false, r#"Phalanx - The Enforce Fighter A-144 (Japan)"#),
-    "eb733713" => (1, false, r#"Phalanx - The Enforce Fighter A-144 (USA)"#),
-    "4d9c671b" => (2, false, r#"Phantasy Star Collection (Europe)"#),
-    "e5a7fe17" => (2, false, r#"Phantasy Star Collection (USA)"#),
-    "e199e132" => (1, false, r#"Phil of the Future (USA)"#),
-    "254e6f31" => (2, false, r#"Pia Carrot e Youkoso!! 3.3 (Japan)"#),
-    "ff5c9636" => (0, false, r#"Disney's Piglet's Big Game (Europe)"#),
-    "52919538" => (0, false, r#"Piglet's Big Game (USA)"#),
-    "80a081fe" => (2, false, r#"Pikapika Nurse Monogatari - Nurse Ikusei Game (Japan)"#),
-    "39479369" => (1, false, r#"Pinball Advance (Europe)"#),
-    "bcb204b7" => (0, false, r#"Pinball Challenge Deluxe (Europe)"#),
-    "a9dd7efa" => (1, false, r#"The Pinball of the Dead (Europe)"#),
-    "1a7efbd0" => (1, false, r#"The Pinball of the Dead (Japan)"#),
-    "0a09c517" => (1, false, r#"The Pinball of the Dead (USA)"#),
-    "5d52be1e" => (0, false, r#"Pinball Tycoon (Europe)"#),
-    "1c4d3fdf" => (0, false, r#"Pinball Tycoon (USA)"#),
-    "8859b808" => (0, false, r#"Pink Panther - Pinkadelic Pursuit (Europe)"#),
-    "1762a578" => (0, false, r#"Pink Panther - Pinkadelic Pursuit (USA)"#),
-    "ff7d5f62" => (0, false, r#"Pinky and the Brain - The Masterplan (Europe)"#),
-    "9f75b077" => (2, false, r#"Pinky Monkey Town (Japan)"#),
-    "e989a4ae" => (1, false, r#"Pinobee & Phoebee (Japan)"#),
-    "d9385207" => (3, false, r#"Pinobee - Wings of Adventure (Europe, USA)"#),
-    "febf9045" => (3, false, r#"Pinobee no Daibouken (Japan)"#),
-    "1c1846c2" => (2, false, r#"Pippa Funnell - Stable Adventure ~ Alexandra Ledermann (Europe)"#),
-    "f3c274c2" => (2, false, r#"Pippa Funnell 2 ~ Alexandra Ledermann (Europe)"#),
-    "d1a67be8" => (1, false, r#"Pirates of the Caribbean - Dead Man's Chest (Europe, USA)"#),
-    "2df9d7f0" => (0, false, r#"Pirates of the Caribbean - The Curse of the Black Pearl (Europe)"#),
-    "8f15bf4c" => (0, false, r#"Pirates of the Caribbean - The Curse of the Black Pearl (USA)"#),
-    "dc6512d6" => (1, false, r#"Pitfall - The Lost Expedition (Europe)"#),
-    "846f43f3" => (1, false, r#"Pitfall - L'Expedition Perdue (France)"#),
-    "5c11dc26" => (1, false, r#"Pitfall - The Lost Expedition (USA)"#),
-    "2cedbb31" => (0, false, r#"Pitfall - The Mayan Adventure (Europe, USA)"#),
-    "20b72d17" => (1, false, r#"Pixeline i Pixieland (Den)"#),
-    "d4d77bce" => (0, false, r#"Planet Monsters (Europe)"#),
-    "9b182354" => (0, false, r#"Planet Monsters (USA)"#),
-    "6e378bf3" => (0, false, r#"Planet of the Apes (Europe)"#),
-    "a0f0269b" => (0, false, r#"Planet of the Apes (USA)"#),
-    "318a1e9b" => (3, false, r#"Play Novel - Silent Hill (Japan)"#),
-    "beddc67f" => (1, false, r#"Pocket Dogs (USA)"#),
-    "4881f3f8" => (5, true, r#"Pocket Monsters - Emerald (Japan)"#),
-    "bb640df7" => (5, false, r#"Pocket Monsters - Fire Red (Japan, rev. 1)"#),
-    "3b2056e9" => (5, false, r#"Pocket Monsters - Fire Red (Japan)"#),
-    "1a1baa50" => (4, false, r#"Pocket Monsters - Fushigi no Dungeon Aka no Kyuujotai (Japan)"#),
-    "8da4601a" => (5, false, r#"Pocket Monsters - Leaf Green (Japan, rev. 1)"#),
-    "0a48556b" => (5, false, r#"Pocket Monsters - Leaf Green (Japan)"#),
-    "cee9471a" => (5, true, r#"Pocket Monsters - Ruby (Japan)"#),
-    "fd1eeb78" => (5, true, r#"Pocket Monsters - Sapphire (Japan)"#),
-    "a3fd418a" => (3, false, r#"Pocket Music (Europe)"#),
-    "07b824b1" => (1, false, r#"Pocket Professor - Kwik Notes - Vol. 1 (USA)"#),
-    "53a8ee86" => (0, false, r#"Pocky & Rocky with Becky (USA)"#),
-    "1d379dcc" => (1, false, r#"Poke Inu (Japan)"#),
-    "1f1c08fb" => (5, true, r#"Pokémon - Emerald Version (Europe, USA, Australia)"#),
-    "34c9df89" => (5, true, r#"Pokémon - Smaragd-Edition (Germany)"#),
-    "a3fdccb1" => (5, true, r#"Pokémon - Version Emeraude (France)"#),
-    "a0aec80a" => (5, true, r#"Pokémon - Versione Smeraldo (Italy)"#),
-    "8c4d3108" => (5, true, r#"Pokémon - Edición Esmeralda (Spain)"#),
-    "84ee4776" => (5, false, r#"Pokémon - FireRed Version (Europe, USA, rev. 1)"#),
-    "dd88761c" => (5, false, r#"Pokémon - FireRed Version (USA)"#),
-    "9f08064e" => (5, false, r#"Pokémon - Edición Rojo Fuego (Spain)"#),
-    "1a81eedf" => (5, false, r#"Pokémon - Feuerrote Edition (Germany)"#),
-    "73a72167" => (5, false, r#"Pokémon - Versione Rosso Fuoco (Italy)"#),
-    "5dc668f6" => (5, false, r#"Pokémon - Version Rouge Feu (France)"#),
-    "daffecec" => (5, false, r#"Pokémon - LeafGreen Version (Europe, USA, rev. 1)"#),
-    "ba3285e3" => (5, false, r#"Pokémon - Version Vert Feuille (France)"#),
-    "d12f1fdd" => (5, false, r#"Pokémon - Blattgrüne Edition (Germany)"#),
-    "16974506" => (5, false, r#"Pokémon - Versione Verde Foglia (Italy)"#),
-    "2ca11d59" => (5, false, r#"Pokémon - Edición Verde Hoja (Spain)"#),
-    "d69c96cc" => (5, false, r#"Pokémon - LeafGreen Version (USA)"#),
-    "aeac73e6" => (5, true, r#"Pokémon - Ruby Version (Europe, USA, rev. 2)"#),
-    "61641576" => (5, true, r#"Pokémon - Ruby Version (Europe, USA, rev. 1)"#),
-    "f0815ee7" => (5, true, r#"Pokémon - Ruby Version (USA)"#),
-    "cae89464" => (5, true, r#"Pokémon - Rubin-Edition (Germany, rev. 1)"#),
-    "15e1e280" => (5, true, r#"Pokémon - Rubin-Edition (Germany)"#),
-    "40686c47" => (5, true, r#"Pokémon - Rubin-Edition (Germany, prototype)"#),
-    "b980fff5" => (5, true, r#"Pokémon - Edición Rubí (Spain, rev. 1)"#),
-    "eb0729cf" => (5, true, r#"Pokémon - Edición Rubí (Spa)"#),
-    "9f981f72" => (5, true, r#"Pokémon - Version Rubis (France, rev. 1)"#),
-    "690fd310" => (5, true, r#"Pokémon - Version Rubis (France)"#),
-    "9305e793" => (5, true, r#"Pokémon - Versione Rubino (Italy, rev. 1)"#),
-    "c18231a9" => (5, true, r#"Pokémon - Versione Rubino (Italy)"#),
-    "9cc4410e" => (5, true, r#"Pokémon - Sapphire Version (Europe, USA, rev. 2)"#),
-    "bafedae5" => (5, true, r#"Pokémon - Sapphire Version (Europe, USA, rev. 1)"#),
-    "554dedc4" => (5, true, r#"Pokémon - Sapphire Version (USA)"#),
-    "b0c40c7c" => (5, true, r#"Pokémon - Saphir-Edition (Germany, rev. 1)"#),
-    "6fcd7a98" => (5, true, r#"Pokémon - Saphir-Edition (Germany)"#),
-    "f2c88931" => (5, true, r#"Pokémon - Edición Zafiro (Spain, rev. 1)"#),
-    "a04f5f0b" => (5, true, r#"Pokémon - Edición Zafiro (Spain)"#),
-    "2ae49146" => (5, true, r#"Pokémon - Version Saphir (France, rev. 1)"#),
-    "3581a05f" => (5, true, r#"Pokémon - Version Saphir (France)"#),
-    "5efe4493" => (5, true, r#"Pokémon - Versione Zaffiro (Italy, rev. 1)"#),
-    "0c7992a9" => (5, true, r#"Pokémon - Versione Zaffiro (Italy)"#),
-    "c1d18fe4" => (5, false, r#"Pokémon Mystery Dungeon - Red Rescue Team (Europe)"#),
-    "f12f908e" => (0, false, r#"Pokémon Mystery Dungeon - Red Rescue Team (USA, demo Kiosk)"#),
-    "dd0ac86c" => (5, false, r#"Pokémon Mystery Dungeon - Red Rescue Team (USA, Australia)"#),
-    "a53ac14b" => (3, false, r#"Pokémon Pinball - Ruby & Sapphire (Europe)"#),
-    "925a5684" => (3, false, r#"Pokémon Pinball - Ruby & Sapphire (Japan, rev. 1)"#),
-    "8af1ec73" => (3, false, r#"Pokémon Pinball - Ruby & Sapphire (Japan)"#),
-    "b992a3c0" => (3, false, r#"Pokémon Pinball - Ruby & Sapphire (USA)"#),
-    "62ca5620" => (1, false, r#"The Polar Express (Europe, USA)"#),
-    "8f2debb8" => (4, false, r#"Polarium Advance (Europe)"#),
-    "02d62ce9" => (4, false, r#"Polarium Advance (USA)"#),
-    "a654cba9" => (0, false, r#"Polly Pocket! - Super Splash Island (Europe)"#),
-    "b5de8c6e" => (0, false, r#"Polly Pocket! - Super Splash Island (USA)"#),
-    "13e2a64f" => (0, false, r#"Polly Pocket! - Super Splash Island (Europe, Zoo Digital re-release)"#),
-    "f120eca2" => (0, false, r#"Polly Pocket! - Super Splash Island (DSI) (USA, DSI Games Re-release)"#),
-    "b9a7e60d" => (0, false, r#"Pop Idol (Europe)"#),
-    "cffc52dd" => (0, false, r#"Popeye - Rush for Spinach (Europe, USA)"#),
-    "566cf164" => (0, false, r#"Postman Pat and the Greendale Rocket (Europe)"#),
-    "59e7ab4c" => (3, false, r#"Power Poke Dash (Japan)"#),
-    "46493fa1" => (3, false, r#"Power Pro Kun Pocket 1, 2 (Japan, promo)"#),
-    "a84032aa" => (3, false, r#"Power Pro Kun Pocket 1, 2 (Japan)"#),
-    "d8397194" => (3, false, r#"Power Pro Kun Pocket 3 (Japan, rev. 1)"#),
-    "4314d8bc" => (3, false, r#"Power Pro Kun Pocket 3 (Japan)"#),
-    "89a3a2c7" => (3, false, r#"Power Pro Kun Pocket 4 (Japan, rev. 1)"#),
-    "30b4a347" => (3, false, r#"Power Pro Kun Pocket 4 (Japan)"#),
-    "969ac691" => (3, false, r#"Power Pro Kun Pocket 5 (Japan, promo)"#),
-    "b2d8732d" => (3, false, r#"Power Pro Kun Pocket 5 (Japan, rev. 1)"#),
-    "06f0f8d4" => (3, false, r#"Power Pro Kun Pocket 5 (Japan)"#),
-    "023a9a75" => (3, false, r#"Power Pro Kun Pocket 6 (Japan, promo)"#),
-    "88513763" => (3, false, r#"Power Pro Kun Pocket 6 (Japan)"#),
-    "073047df" => (3, false, r#"Power Pro Kun Pocket 7 (Japan)"#),
-    "414bfe31" => (0, false, r#"Power Rangers - Dino Thunder (Europe, French / German)"#),
-    "bc193ee7" => (0, false, r#"Power Rangers - Dino Thunder (Europe, USA)"#),
-    "09d8ed5e" => (0, false, r#"Power Rangers - Ninja Storm (Europe)"#),
-    "e8691a0f" => (0, false, r#"Power Rangers - Ninja Storm (USA)"#),
-    "70359b35" => (0, false, r#"Saban's Power Rangers - Time Force (Europe, USA)"#),
-    "eb54d898" => (0, false, r#"Saban's Power Rangers - La Force du Temps (France)"#),
-    "933f5477" => (0, false, r#"Saban's Power Rangers - Time Force (Germany)"#),
-    "54c31afb" => (0, false, r#"Saban's Power Rangers - Wild Force (Europe, USA)"#),
-    "921b66e2" => (0, false, r#"Power Rangers S.P.D. (Europe, USA)"#),
-    "cb59cf7d" => (0, false, r#"Power Rangers S.P.D. (Europe, English / French / German)"#),
-    "dca64f27" => (0, false, r#"Power Rangers S.P.D. (Europe, English / Italian / Spanish)"#),
-    "0bfed765" => (1, false, r#"The Powerpuff Girls - Him and Seek (Europe)"#),
-    "4d22b35e" => (1, false, r#"The Powerpuff Girls - Him and Seek (USA)"#),
-    "1172d0a7" => (1, false, r#"The Powerpuff Girls - Mojo Jojo A-Go-Go (Europe)"#),
-    "dfb57dcf" => (1, false, r#"The Powerpuff Girls - Mojo Jojo A-Go-Go (USA)"#),
-    "abb50d93" => (0, false, r#"Prehistorik Man (USA, prototype)"#),
-    "0d3b922b" => (0, false, r#"Prehistorik Man (Europe, USA)"#),
-    "b5432fa4" => (2, false, r#"Anstoss Action ~ Premier Action (Europe)"#),
-    "a959f638" => (4, false, r#"Premier Manager 2003-04 (Europe)"#),
-    "2706dff2" => (4, false, r#"Premier Manager 2004-2005 (Europe)"#),
-    "3b04673b" => (4, false, r#"Premier Manager 2005-2006 (Europe)"#),
-    "f0f16b7e" => (1, false, r#"Prince of Persia - The Sands of Time (Europe)"#),
-    "5adf72e6" => (1, false, r#"Prince of Persia - The Sands of Time (USA, rev. 1)"#),
-    "0c043ba6" => (1, false, r#"Prince of Persia - The Sands of Time (USA)"#),
-    "e6567686" => (0, false, r#"Princess Natasha - Student, Secret Agent, Princess (Europe)"#),
-    "b07cb7ba" => (0, false, r#"Princess Natasha - Student, Secret Agent, Princess (USA)"#),
-    "7623e41a" => (1, false, r#"Pro Beach Soccer (Europe)"#),
-    "e4d9ae55" => (2, false, r#"Pro Mahjong Tsuwamono GBA (Japan)"#),
-    "f7171521" => (1, false, r#"Pro Tennis WTA Tour (Europe)"#),
-    "a8a3aa8b" => (3, false, r#"Pro Yakyuu Team o Tsukurou! Advance (Japan)"#),
-    "c92bde6e" => (1, false, r#"The Proud Family (USA)"#),
-    "91c4c334" => (2, false, r#"PukuPuku Tennen Kairanban (Japan)"#),
-    "b57fec3a" => (2, false, r#"PukuPuku Tennen Kairanban - Koi no Cupid Daisakusen (Japan)"#),
-    "e98cf9c3" => (2, false, r#"PukuPuku Tennen Kairanban - Youkoso! Illusion Land e (Japan)"#),
-    "3e4cb21c" => (0, false, r#"Punch King - Arcade Boxing (Europe)"#),
-    "540b6cc1" => (0, false, r#"Punch King - Arcade Boxing (USA)"#),
-    "04146dd6" => (1, false, r#"Puppy Luv - Spa and Resort (USA)"#),
-    "c85de50e" => (1, false, r#"Puyo Pop (Europe)"#),
-    "0901be49" => (1, false, r#"Puyo Pop (USA)"#),
-    "6e5880ed" => (1, false, r#"Puyo Pop Fever (Europe)"#),
-    "3ffd621a" => (1, false, r#"Puyo Puyo Fever (Japan)"#),
-    "893551bb" => (2, false, r#"Puzzle & Tantei Collection (Japan)"#),
-    "1f67bd36" => (3, false, r#"Pyuu to Fuku! Jaguar - Byoo to Deru! Megane-kun (Japan)"#),
-    "95be56e6" => (0, false, r#"Quad Desert Fury (Europe, USA)"#),
-    "10e16dd4" => (0, false, r#"Quake (demo)"#),
-    "09c59e6b" => (0, false, r#"Qwak (Europe, demo)"#),
-    "9027917f" => (3, false, r#"Qwak (Europe)"#),
-    "3e7f9100" => (0, false, r#"R3D-Demo V1 (Europe, demo)"#),
-    "41bb7f8a" => (0, false, r#"R-Type III - The Third Lightning (Europe)"#),
-    "ea863f4d" => (0, false, r#"R-Type III - The Third Lightning (USA)"#),
-    "e40ec737" => (0, false, r#"Racing Fever (Europe)"#),
-    "4f61dedb" => (0, false, r#"Racing Fever (France)"#),
-    "49a88e52" => (1, false, r#"Racing Gears Advance (Europe)"#),
-    "bf648e5c" => (1, false, r#"Racing Gears Advance (USA)"#),
-    "86a05f93" => (1, false, r#"Racing Gears Advance (USA, prototype, 20030922)"#),
-    "8eca2b0f" => (0, false, r#"Rampage - Puzzle Attack (Europe, USA)"#),
-    "964d39a7" => (1, false, r#"Rapala Pro Fishing (Europe, USA)"#),
-    "82f9c596" => (0, false, r#"Ratatouille (Europe, English / Italian / Danish / Norwegian / Swedish)"#),
-    "5c48c362" => (0, false, r#"Ratatouille (Europe, French / German / Dutch)"#),
-    "7a80b515" => (0, false, r#"Ratatouille (Europe, Spanish / Portuguese)"#),
-    "aa2eb686" => (0, false, r#"Ratatouille (USA)"#),
-    "c39b9418" => (3, false, r#"Rave Master - Special Attack Force! (USA)"#),
-    "437e95b9" => (2, false, r#"Rayman - 10th Anniversary (Europe)"#),
-    "ae72b0ee" => (2, false, r#"Rayman - 10th Anniversary (USA)"#),
-    "c221c419" => (1, false, r#"Rayman - Hoodlums' Revenge (Europe)"#),
-    "025a9998" => (1, false, r#"Rayman - Hoodlum's Revenge (USA)"#),
-    "9914da23" => (1, false, r#"Rayman - Raving Rabbids (Europe)"#),
-    "751ef0d2" => (1, false, r#"Rayman - Raving Rabbids (USA)"#),
-    "8f34b14f" => (1, false, r#"Rayman 3 (Europe, prototype)"#),
-    "29f83314" => (1, false, r#"Rayman 3 (Europe)"#),
-    "d1613266" => (1, false, r#"Rayman 3 (USA)"#),
-    "4b1b4e02" => (1, false, r#"Rayman Advance (Europe, prototype)"#),
-    "b43783b4" => (1, false, r#"Rayman Advance (Europe)"#),
-    "8aba5ab8" => (1, false, r#"Rayman Advance (USA)"#),
-    "a169b3b5" => (1, false, r#"Razbitume! - Restez Branchés! (Europe)"#),
-    "720b7ff2" => (0, false, r#"Les Razmoket Rencontrent les Delajungle (France)"#),
-    "4130f7d0" => (0, false, r#"Les Razmoket - A Moi la Fiesta (France)"#),
-    "809a103a" => (0, false, r#"Les Razmoket - Voler N'Est Pas Jouer (France)"#),
-    "ffe38431" => (0, false, r#"Razor Freestyle Scooter (USA)"#),
-    "e418e962" => (0, false, r#"Ready 2 Rumble Boxing - Round 2 (Europe)"#),
-    "5dfcc5e5" => (0, false, r#"Ready 2 Rumble Boxing - Round 2 (USA)"#),
-    "e448c5d4" => (2, false, r#"Rebelstar - Tactical Command (Europe)"#),
-    "fb6c590f" => (2, false, r#"Rebelstar - Tactical Command (USA)"#),
-    "b29bee34" => (3, false, r#"Recca no Honoo - The Game (Japan)"#),
-    "79d7f4bc" => (0, false, r#"Reign of Fire (Europe)"#),
-    "b48f8d57" => (0, false, r#"Reign of Fire (USA)"#),
-    "9f333017" => (1, false, r#"Relaxuma na Mainichi (Japan)"#),
-    "8111261c" => (0, false, r#"Rescue Heroes - Billy Blazes! (USA)"#),
-    "26b5cf8b" => (0, false, r#"Resident Evil 2 (Europe, prototype demo)"#),
-    "58a3f0e1" => (0, false, r#"The Revenge of Shinobi (Europe, prototype)"#),
-    "ddb4496a" => (0, false, r#"The Revenge of Shinobi (Europe)"#),
-    "e9339304" => (0, false, r#"The Revenge of Shinobi (USA)"#),
-    "a6cd88e1" => (3, false, r#"Rhythm Tengoku (Japan, rev. 1)"#),
-    "349d7025" => (3, false, r#"Rhythm Tengoku (Japan)"#),
-    "9850bf21" => (0, false, r#"The Ripping Friends (Europe, USA)"#),
-    "8686436e" => (2, false, r#"River City Ransom EX (USA)"#),
-    "4fb6958d" => (3, false, r#"Riviera - The Promised Land (USA)"#),
-    "146097f2" => (3, false, r#"Riviera - Yakusoku no Chi Riviera (Japan, rev. 1)"#),
-    "f73b840f" => (3, false, r#"Riviera - Yakusoku no Chi Riviera (Japan)"#),
-    "f4693458" => (0, false, r#"Road Rash - Jailbreak (Europe)"#),
-    "8834af99" => (0, false, r#"Road Rash - Jailbreak (USA)"#),
-    "549bf1c5" => (3, false, r#"Road Trip - Shifting Gears (USA)"#),
-    "282e6408" => (0, false, r#"Robocop (prototype)"#),
-    "68d3023a" => (5, false, r#"Robopon 2 - Cross Version (USA)"#),
-    "ca73e17a" => (5, false, r#"Robopon 2 - Ring Version (USA)"#),
-    "0c6f2b1b" => (5, false, r#"Robot Ponkottsu 2 - Cross Version (Japan)"#),
-    "6002159c" => (5, false, r#"Robot Ponkottsu 2 - Ring Version (Japan)"#),
-    "cf8231be" => (1, false, r#"Robot Wars - Advanced Destruction (Europe)"#),
-    "e6cb567d" => (1, false, r#"Robot Wars - Advanced Destruction (USA)"#),
-    "5ee9d6c5" => (1, false, r#"Robot Wars - Extreme Destruction (Europe)"#),
-    "325a6596" => (1, false, r#"Robotech - The Macross Saga (Europe, USA)"#),
-    "2acbdb64" => (1, false, r#"Robots (Europe)"#),
-    "1beb9e91" => (1, false, r#"Robots (Japan)"#),
-    "5370ae1f" => (1, false, r#"Robots (USA)"#),
-    "53d1a142" => (1, false, r#"Rock n' Roll Racing (Europe)"#),
-    "15f2dd16" => (1, false, r#"Rock n' Roll Racing (USA)"#),
-    "6ad6e952" => (0, false, r#"Rock'em Sock'em Robots (Europe)"#),
-    "c8896c62" => (0, false, r#"Rock'em Sock'em Robots (USA)"#),
-    "13695669" => (0, false, r#"Rocket Power - Beach Bandits (Europe, USA)"#),
-    "d3755452" => (0, false, r#"Rocket Power - Beach Bandits (USA, prototype V0.14)"#),
-    "93961cb2" => (0, false, r#"Rocket Power - Dream Scheme (Europe, USA)"#),
-    "17b8b786" => (0, false, r#"Rocket Power - Le Cauchemar d'Otto (France)"#),
-    "26d62d32" => (0, false, r#"Rocket Power - Zero Gravity Zone (USA)"#),
-    "ce2b48c4" => (2, false, r#"Rockman & Forte (Japan)"#),
-    "ed7c5b50" => (3, false, r#"Rockman EXE 4 - Tournament Blue Moon (Japan)"#),
-    "cf0e8b05" => (3, false, r#"Rockman EXE 4 - Tournament Red Sun (Japan, rev. 1)"#),
-    "6a51907d" => (3, false, r#"Rockman EXE 4 - Tournament Red Sun (Japan)"#),
-    "a646601b" => (5, true, r#"Rockman EXE 4.5 - Real Operation (Japan)"#),
-    "c73f23c0" => (3, false, r#"Rockman EXE 5 - Team of Blues (Japan)"#),
-    "16842635" => (3, false, r#"Rockman EXE 5 - Team of Colonel (Japan)"#),
-    "2dfb603e" => (3, false, r#"Rockman EXE 6 - Dennoujuu Falzar (Japan)"#),
-    "6285918a" => (3, false, r#"Rockman EXE 6 - Dennoujuu Gregar (Japan)"#),
-    "9217fb18" => (3, false, r#"Rockman EXE Battle Chip GP (Japan)"#),
-    "ff2a67b1" => (3, false, r#"Rockman Zero (Japan)"#),
-    "9b5c8a4c" => (3, false, r#"Rockman Zero 2 (Japan)"#),
-    "5a2c41a9" => (3, false, r#"Rockman Zero 3 (Japan)"#),
-    "ece42d0e" => (2, false, r#"Rockman Zero 4 (Japan)"#),
-    "d2e55fb5" => (1, false, r#"Rocky (Europe)"#),
-    "c8f4ff1b" => (1, false, r#"Rocky (USA)"#),
-    "e7fc81d0" => (4, false, r#"RPG Tsukuru Advance (Japan)"#),
-    "8aabd86a" => (0, false, r#"Rugrats - Castle Capers (Europe, USA)"#),
-    "52a33375" => (0, false, r#"Rugrats - Go Wild (Europe, USA)"#),
-    "df167d1d" => (0, false, r#"Rugrats - I Gotta Go Party (Europe, USA)"#),
-    "0bcab7d0" => (0, false, r#"Rugrats - Travesuras en el Castillo (Spain)"#),
-    "9e3eab8d" => (2, false, r#"Sabre Wulf (Europe)"#),
-    "73787a16" => (2, false, r#"Sabre Wulf (USA)"#),
-    "caa8225b" => (0, false, r#"Sabrina - The Teenage Witch - Potion Commotion (Europe)"#),
-    "a39edd2f" => (0, false, r#"Sabrina - The Teenage Witch - Potion Commotion (USA)"#),
-    "02b9f82d" => (3, false, r#"Saibara Rieko no Dendou Mahjong (Japan)"#),
-    "b0a75590" => (3, false, r#"Sakura Momoko no UkiUki Carnival (Japan)"#),
-    "68836df9" => (1, false, r#"Salt Lake 2002 (Europe)"#),
-    "4603205a" => (1, false, r#"Salt Lake 2002 (USA)"#),
-    "56ead477" => (1, false, r#"Samurai Deeper Kyo (Japan)"#),
-    "4ab65478" => (1, false, r#"Samurai Deeper Kyo (USA)"#),
-    "c0f54c65" => (3, false, r#"Samurai Evolution - Oukoku Geist (Japan)"#),
-    "b250366c" => (1, false, r#"Samurai Jack - The Amulet of Time (Europe, USA)"#),
-    "f3d3c239" => (5, false, r#"Sangokushi (Japan)"#),
-    "a4a1c956" => (4, false, r#"Sangokushi - Eiketsuden (Japan)"#),
-    "765043ff" => (4, false, r#"Sangokushi - Koumeiden (Japan)"#),
-    "5257bd92" => (2, false, r#"Sanrio Puroland - All Characters (Japan)"#),
-    "b3780a4f" => (2, false, r#"Samsara Naga 1x2 (Japan, rev. 2)"#),
-    "68076b62" => (2, false, r#"Samsara Naga 1x2 (Japan)"#),
-    "38d896bd" => (1, false, r#"Santa Claus Jr. Advance (Europe, prototype)"#),
-    "fe3e6769" => (1, false, r#"Santa Claus Jr. Advance (Europe)"#),
-    "49e012a8" => (0, false, r#"Santa Claus Saves the Earth (Europe)"#),
-    "206933f5" => (1, false, r#"The Santa Clause 3 - The Escape Clause (USA)"#),
-    "dcfddff7" => (3, false, r#"Scan Hunter - Sennen Kaigyo o Oe! (Japan)"#),
-    "e5ac4bb9" => (1, false, r#"Scooby-Doo (Europe)"#),
-    "ceefc038" => (1, false, r#"Scooby-Doo (France)"#),
-    "03a07291" => (1, false, r#"Scooby-Doo (Germany)"#),
-    "e5261946" => (1, false, r#"Scooby-Doo (Spain)"#),
-    "22d993c6" => (1, false, r#"Scooby-Doo (USA)"#),
-    "7bdab2cf" => (0, false, r#"Scooby-Doo 2 - Monsters Unleashed (Europe, English / French / German / Italian
-			/ Spanish)"#),
-    "9ff01ddd" => (0, false, r#"Scooby-Doo 2 - Monsters Unleashed (Europe, USA)"#),
-    "cf2dba97" => (0, false, r#"Scooby-Doo and the Cyber Chase (Europe, USA)"#),
-    "8b0079cb" => (0, false, r#"Scooby-Doo and the Cyber Chase (Europe, English / French / German)"#),
-    "5348a655" => (1, false, r#"Scooby-Doo! - Mystery Mayhem (Europe)"#),
-    "3d389a12" => (1, false, r#"Scooby-Doo! - Mystery Mayhem (USA)"#),
-    "d21b8344" => (0, false, r#"Scooby-Doo! - Unmasked (Europe, English / French)"#),
-    "af42f90f" => (0, false, r#"Scooby-Doo! - Unmasked (Europe, Italian / Spanish)"#),
-    "96bdd6c1" => (0, false, r#"Scooby-Doo! - Unmasked (USA)"#),
-    "d5410d32" => (0, false, r#"The Scorpion King - Sword of Osiris (Europe)"#),
-    "6e0a8585" => (0, false, r#"The Scorpion King - Sword of Osiris (USA)"#),
-    "c5c9b31b" => (3, false, r#"Scrabble (Europe)"#),
-    "5d31a1f9" => (0, false, r#"Scrabble Blast! (USA)"#),
-    "0ed5f375" => (0, false, r#"Scrabble Scramble! (Europe, English)"#),
-    "e8eb2e3a" => (0, false, r#"Scrabble Scramble! (Europe, English / French / German / Italian / Dutch /
-			Spanish)"#),
-    "b17532ee" => (0, false, r#"Screw Breaker - Goushin DoriRureRo (Japan)"#),
-    "2c119783" => (1, false, r#"Scurge - Hive (Europe)"#),
-    "1ae38ac0" => (1, false, r#"Scurge - Hive (USA)"#),
-    "83417478" => (1, false, r#"SD Gundam Force (Japan)"#),
-    "68b21289" => (1, false, r#"SD Gundam Force (USA)"#),
-    "7daf215c" => (2, false, r#"SD Gundam GGeneration Advance (Japan)"#),
-    "c822b1bc" => (1, false, r#"Sea Boy (demo)"#),
-    "3484f139" => (0, false, r#"Sea-Monkeys (USA)"#),
-    "7597e8c1" => (2, false, r#"Sea Trader - Rise of Taipan (USA)"#),
-    "da3133c3" => (0, false, r#"Secret Agent Barbie - Royal Jewels Mission (Europe)"#),
-    "610913e0" => (0, false, r#"Secret Agent Barbie - Royal Jewels Mission (USA)"#),
-    "f92140c0" => (0, false, r#"Sega Arcade Gallery (Europe)"#),
-    "3b8ffdf4" => (0, false, r#"Sega Arcade Gallery (USA)"#),
-    "d125a6bb" => (3, false, r#"Sega Rally Championship (Europe)"#),
-    "e57c16f7" => (3, false, r#"Sega Rally Championship (Japan)"#),
-    "1ae3eb16" => (3, false, r#"Sega Rally Championship (USA)"#),
-    "09310fec" => (0, false, r#"Sega Smash Pack (Europe)"#),
-    "5c2e97ba" => (0, false, r#"Sega Smash Pack (USA)"#),
-    "3dd25fd7" => (3, false, r#"Sengoku Kakumei Gaiden (Japan)"#),
-    "a2f6976c" => (5, true, r#"Sennen Kazoku (Japan)"#),
-    "596c5d4c" => (3, false, r#"Sentouin Yamada Hajime (Japan)"#),
-    "58199232" => (0, false, r#"Serious Sam Advance (Europe)"#),
-    "fdf4eca7" => (0, false, r#"Serious Sam Advance (USA)"#),
-    "798593a5" => (3, false, r#"Shonen Jump's Shaman King - Legacy of the Spirits - Soaring Hawk (USA)"#),
-    "fa1aa3f6" => (3, false, r#"Shonen Jump's Shaman King - Legacy of the Spirits - Sprinting Wolf (USA)"#),
-    "4864b878" => (3, false, r#"Shonen Jump's Shaman King - Master of Spirits (Europe)"#),
-    "10e554ae" => (3, false, r#"Shonen Jump's Shaman King - Master of Spirits (USA)"#),
-    "4a74e038" => (3, false, r#"Shonen Jump's Shaman King - Master of Spirits 2 (Europe)"#),
-    "201e3412" => (3, false, r#"Shonen Jump's Shaman King - Master of Spirits 2 (USA)"#),
-    "cb9a390a" => (3, false, r#"Shaman King Card Game - Chou Senjiryakketsu 2 (Japan)"#),
-    "2bcf66d4" => (3, false, r#"Shaman King Card Game - Chou Senjiryakketsu 3 (Japan)"#),
-    "6a0f2c8b" => (1, false, r#"Shamu's Deep Sea Adventures (Europe)"#),
-    "4e9426eb" => (1, false, r#"Shamu's Deep Sea Adventures (USA)"#),
-    "9aeea9b7" => (1, false, r#"Shanghai Advance (Japan)"#),
-    "df2518e9" => (1, false, r#"Shark Tale (Europe, French / German / Spanish)"#),
-    "804a01b1" => (1, false, r#"Shark Story (Italy)"#),
-    "128be27c" => (1, false, r#"Shark Tale (Japan)"#),
-    "7b68d93e" => (1, false, r#"Shark Tale (Europe, USA)"#),
-    "6d545fed" => (0, false, r#"Shaun Palmer's Pro Snowboarder (Germany)"#),
-    "66265ad9" => (0, false, r#"Shaun Palmer's Pro Snowboarder (Europe, USA)"#),
-    "75a92925" => (2, false, r#"Sheep (Europe)"#),
-    "e0a452a8" => (2, false, r#"Shikakui Atama o Maruku Suru. Advance - Kanji, Keisan (Japan)"#),
-    "07695a6c" => (2, false, r#"Shikakui Atama o Maruku Suru. Advance - Kokugo, Sansuu, Shakai, Rika (Japan)"#),
-    "5546b016" => (2, false, r#"Shimura Ken no Baka Tonosama - Bakushou Tenka Touitsu Game (Japan, rev. 1)"#),
-    "f29e6e2d" => (2, false, r#"Shimura Ken no Baka Tonosama - Bakushou Tenka Touitsu Game (Japan)"#),
-    "af453162" => (0, true, r#"Shin Bokura no Taiyou - Gyakushuu no Sabata (Japan)"#),
-    "769a7666" => (2, false, r#"Shin Chan - Aventuras en Cineland (Spain)"#),
-    "fd47a2fe" => (2, false, r#"Shin Chan contra los Muñecos de Shock Gahn (Spain)"#),
-    "b65095bf" => (3, false, r#"Shin Kisekae Monogatari (Japan)"#),
-    "b857c3c5" => (3, false, r#"Shin Megami Tensei (Japan)"#),
-    "5d7ee5af" => (3, false, r#"Shin Megami Tensei Devil Children - Hikari no Sho (Japan)"#),
-    "9a0903bc" => (3, false, r#"Shin Megami Tensei Devil Children - Honoo no Sho (Japan)"#),
-    "ad80d5f9" => (3, false, r#"Shin Megami Tensei Devil Children - Koori no Sho (Japan)"#),
-    "0ec98c51" => (3, false, r#"Shin Megami Tensei Devil Children - Messiah Riser (Japan)"#),
-    "ea8a185a" => (2, false, r#"Shin Megami Tensei Devil Children - Puzzle de Call! (Japan)"#),
-    "e0e153b7" => (3, false, r#"Shin Megami Tensei Devil Children - Yami no Sho (Japan)"#),
-    "af40cc99" => (3, false, r#"Shin Megami Tensei II (Japan)"#),
-    "9f0b8b79" => (3, false, r#"Shin Nihon Pro Wrestling - Toukon Retsuden Advance (Japan)"#),
-    "fe1be6c1" => (3, false, r#"Shin Sangoku Musou Advance (Japan)"#),
-    "54af534d" => (2, false, r#"Shingata Medarot - Kabuto Version (Japan)"#),
-    "0fd35628" => (2, false, r#"Shingata Medarot - Kuwagata Version (Japan)"#),
-    "4a643cc4" => (3, false, r#"Shining Force - Kuroki Ryuu no Fukkatsu (Japan)"#),
-    "07b06994" => (3, false, r#"Shining Force - Resurrection of the Dark Dragon (Europe)"#),
-    "563aa3a0" => (3, false, r#"Shining Force - Resurrection of the Dark Dragon (USA)"#),
-    "74b5d069" => (4, false, r#"Shining Soul (Europe)"#),
-    "521450d1" => (5, false, r#"Shining Soul (Japan)"#),
-    "e95bba0c" => (4, false, r#"Shining Soul (USA)"#),
-    "55e503c1" => (4, false, r#"Shining Soul II (Europe)"#),
-    "7b39a7b2" => (4, false, r#"Shining Soul II (Japan)"#),
-    "4038e282" => (4, false, r#"Shining Soul II (USA)"#),
-    "31b220e5" => (4, false, r#"Shinyaku Seiken Densetsu (Japan)"#),
-    "c1f2b5ec" => (3, false, r#"Shiren Monsters Netsal (Japan)"#),
-    "cd8e4f85" => (1, false, r#"Shrek - Hassle at the Castle (Europe)"#),
-    "09e7472c" => (1, false, r#"Shrek - Hassle at the Castle (USA)"#),
-    "1e4d4813" => (1, false, r#"Shrek - Reekin' Havoc (Europe)"#),
-    "63f49ba9" => (1, false, r#"Shrek - Reekin' Havoc (USA)"#),
-    "a45857c4" => (1, false, r#"Shrek - Smash n' Crash Racing (Europe)"#),
-    "ea8ae3b2" => (1, false, r#"Shrek - Smash n' Crash Racing (USA)"#),
-    "df73101e" => (1, false, r#"Shrek - Super Slam (Europe)"#),
-    "e2ec9eed" => (1, false, r#"Shrek - Super Slam (USA)"#),
-    "41f55560" => (1, false, r#"Shrek - Swamp Kart Speedway (USA, rev. 1)"#),
-    "91483d87" => (1, false, r#"Shrek - Swamp Kart Speedway (Europe, USA)"#),
-    "52ff42d4" => (1, false, r#"Shrek 2 (Europe, USA)"#),
-    "ac1d8c97" => (1, false, r#"Shrek 2 (Europe, French / German / Italian / Spanish / Swedish)"#),
-    "b6e091ce" => (1, false, r#"Shrek 2 - Beg for Mercy (Europe, French / German / Italian / Spanish)"#),
-    "f1861610" => (1, false, r#"Shrek 2 - Beg for Mercy (Europe, USA)"#),
-    "da1ee1e5" => (1, false, r#"Shrek the Third (Europe)"#),
-    "d7d1da8d" => (1, false, r#"Shrek the Third (USA)"#),
-    "a8b80a7c" => (1, false, r#"Sigma Star Saga (Europe, USA)"#),
-    "f03728f3" => (1, false, r#"Silent Scope (Europe)"#),
-    "8ab024e0" => (1, false, r#"Silent Scope (Japan)"#),
-    "c5ce1b2c" => (1, false, r#"Silent Scope (USA)"#),
-    "515e2127" => (3, false, r#"Silk to Cotton (Japan)"#),
-    "a74187d3" => (2, false, r#"Sim City 2000 (Europe)"#),
-    "95c79648" => (2, false, r#"Sim City 2000 (Europe, rev. 1)"#),
-    "097e8628" => (2, false, r#"Sim City 2000 (USA, rev. 1)"#),
-    "733751b3" => (2, false, r#"Sim City 2000 (USA)"#),
-    "8b72ba3c" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 1 - The Table Game Collection - Mahjong
-			Shougi Hanafuda Reversi (Japan)"#),
-    "180901e3" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 2 - The Block Kuzushi (Japan, rev. 1)"#),
-    "e731fd45" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 2 - The Block Kuzushi (Japan)"#),
-    "49b92627" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 3 - The Itsudemo Puzzle - Massugu Soroete
-			Straws (Japan)"#),
-    "07c2d7ed" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 4 - The Trump - Minna de Asoberu 12 Shurui no
-			Trump Game (Japan, rev. 1)"#),
-    "a6fc701f" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 4 - The Trump - Minna de Asoberu 12 Shurui no
-			Trump Game (Japan)"#),
-    "44548b33" => (0, false, r#"The Simpsons - Road Rage (Europe, English / French / German / Italian /
-			Spanish)"#),
-    "409e8252" => (0, false, r#"The Simpsons - Road Rage (Europe, USA)"#),
-    "abaf519c" => (2, false, r#"The Sims - Bustin' Out (Europe, USA)"#),
-    "89956199" => (2, false, r#"The Sims (Japan)"#),
-    "8b1bc7df" => (4, false, r#"The Sims 2 (Europe, USA)"#),
-    "87f8599c" => (4, false, r#"The Sims 2 - Pets (Europe, English / French / German / Italian / Spanish /
-			Dutch)"#),
-    "ec1f4029" => (4, false, r#"The Sims 2 - Pets (Europe, USA)"#),
-    "6845c671" => (1, false, r#"Sister Princess - RePure (Japan)"#),
-    "b92a8a88" => (0, false, r#"Sitting Ducks (Europe)"#),
-    "8bc90adb" => (0, false, r#"Sitting Ducks (USA)"#),
-    "91f93500" => (1, false, r#"SK8 - Tony Hawk's Pro Skater 2 (Japan)"#),
-    "3af1063b" => (0, false, r#"Sky Dancers - They Magically Fly! (Europe)"#),
-    "cadd57dd" => (0, false, r#"Sky Dancers - They Magically Fly! (USA)"#),
-    "1194d33b" => (1, false, r#"Slime Morimori Dragon Quest - Shougeki no Shippo Dan (Japan)"#),
-    "4da0b936" => (2, false, r#"Slot! Pro 2 Advance - GoGo Juggler & New Tairyou (Japan)"#),
-    "d9a8d01f" => (2, false, r#"Slot! Pro Advance - Takarabune & Ooedo Sakurafubuki 2 (Japan)"#),
-    "1034d672" => (0, false, r#"Smashing Drive (Europe)"#),
-    "2390aca6" => (0, false, r#"Smashing Drive (USA)"#),
-    "3ae0afc8" => (0, false, r#"Smuggler's Run (Europe)"#),
-    "1a6aea5e" => (0, false, r#"Smuggler's Run (USA)"#),
-    "516ce770" => (0, false, r#"The Smurfs - The Revenge of the Smurfs (Europe)"#),
-    "00f5687a" => (0, false, r#"SN Systems (Japan)"#),
-    "41e35c6a" => (3, false, r#"Snap Kid's (Japan)"#),
-    "32bd1486" => (0, false, r#"Snood (Europe)"#),
-    "f1f1f148" => (0, false, r#"Snood (USA)"#),
-    "6470252f" => (0, false, r#"Snood 2 - On Vacation (Europe)"#),
-    "d8e05727" => (0, false, r#"Snood 2 - On Vacation (USA)"#),
-    "d3485f5a" => (0, false, r#"Soccer Kid (Europe, USA)"#),
-    "6232839b" => (5, false, r#"Sonic Advance (Europe)"#),
-    "85957a24" => (5, false, r#"Sonic Advance (Japan, rev. 1)"#),
-    "5f512223" => (5, false, r#"Sonic Advance (Japan)"#),
-    "63f70fd8" => (5, false, r#"Sonic Advance (USA)"#),
-    "a80de3b2" => (5, false, r#"Sonic Advance (USA, prototype)"#),
-    "89509891" => (4, false, r#"Sonic Advance 2 (Europe)"#),
-    "513804ff" => (4, false, r#"Sonic Advance 2 (Japan)"#),
-    "7efee7f7" => (4, false, r#"Sonic Advance 2 (USA)"#),
-    "6d7567ed" => (4, false, r#"Sonic Advance 2 (USA, prototype)"#),
-    "4c93dac6" => (4, false, r#"Sonic Advance 3 (Japan, prototype)"#),
-    "5bf83456" => (4, false, r#"Sonic Advance 3 (Europe)"#),
-    "4375f1d6" => (4, false, r#"Sonic Advance 3 (Japan)"#),
-    "49dda5e6" => (4, false, r#"Sonic Advance 3 (USA)"#),
-    "d0f65125" => (4, false, r#"Sonic Battle (Europe)"#),
-    "7305ac30" => (4, false, r#"Sonic Battle (Japan)"#),
-    "9ec9d86f" => (4, false, r#"Sonic Battle (USA)"#),
-    "4435917e" => (4, false, r#"Sonic Pinball Party (Europe)"#),
-    "43b5f167" => (4, false, r#"Sonic Pinball Party (Japan)"#),
-    "08794743" => (4, false, r#"Sonic Pinball Party (USA)"#),
-    "027bc70d" => (1, false, r#"Sonic the Hedgehog - Genesis (USA)"#),
-    "cf404aee" => (0, false, r#"A Sound of Thunder (Europe)"#),
-    "72894e1b" => (0, false, r#"A Sound of Thunder (USA)"#),
-    "0f105694" => (1, false, r#"Space Channel 5 - Ulala's Cosmic Attack (Europe)"#),
-    "0666f37a" => (1, false, r#"Space Channel 5 - Ulala's Cosmic Attack (USA)"#),
-    "bd4e6008" => (3, false, r#"Space Hexcite - Maetel Legend EX (Japan)"#),
-    "59372656" => (1, false, r#"Space Invaders (France)"#),
-    "5ea3f3b5" => (1, false, r#"Space Invaders (Europe, USA)"#),
-    "297b9854" => (1, false, r#"Space Invaders EX (Japan)"#),
-    "051f3d0d" => (1, false, r#"Speedball 2 - Brutal Deluxe (Europe)"#),
-    "832ea2b1" => (1, false, r#"Spider-Man (France)"#),
-    "e9d9b35f" => (1, false, r#"Spider-Man (Germany)"#),
-    "406265b8" => (1, false, r#"Spider-Man (Europe, USA)"#),
-    "ad2c404c" => (1, false, r#"Spider-Man - Battle for New York (Europe)"#),
-    "6310ba16" => (1, false, r#"Spider-Man - Battle for New York (USA)"#),
-    "f8125c4b" => (1, false, r#"Spider-Man - Mysterio no Kyoui (Japan)"#),
-    "6e135b4d" => (0, false, r#"Spider-Man - Mysterio's Menace (Europe, USA)"#),
-    "821838bc" => (1, false, r#"Spider-Man 2 (Europe, English / French / German / Spanish)"#),
-    "66340f23" => (1, false, r#"Spider-Man 2 (Italy)"#),
-    "0145f3f4" => (1, false, r#"Spider-Man 2 (Europe, USA)"#),
-    "6be8d68c" => (1, false, r#"Spider-Man 3 (Europe)"#),
-    "450d1d48" => (1, false, r#"Spider-Man 3 (France)"#),
-    "fda3c9dd" => (1, false, r#"Spider-Man 3 (Germany)"#),
-    "e8fb4b9a" => (1, false, r#"Spider-Man 3 (Italy)"#),
-    "e6251fd3" => (1, false, r#"Spider-Man 3 (Spain)"#),
-    "ad0228a1" => (1, false, r#"Spider-Man 3 (USA)"#),
-    "caaaefb6" => (0, false, r#"Spirit - Der Wilde Mustang (Germany, prototype)"#),
-    "4785eee0" => (0, false, r#"Spirit - Der Wilde Mustang (Germany)"#),
-    "c8c76255" => (0, false, r#"Spirit - L'Etalon des Plaines (France)"#),
-    "aee21783" => (0, false, r#"Spirit - Stallion of the Cimarron (Europe)"#),
-    "9a457633" => (0, false, r#"Spirit - Stallion of the Cimarron (USA)"#),
-    "2fa6db95" => (0, false, r#"Spirits & Spells (USA)"#),
-    "de4a85c0" => (0, false, r#"SpongeBob SquarePants - Battle for Bikini Bottom (Europe)"#),
-    "235628c3" => (0, false, r#"SpongeBob SquarePants - Battle for Bikini Bottom (USA)"#),
-    "7580fbd6" => (1, false, r#"SpongeBob SquarePants - Creature from the Krusty Krab (Europe)"#),
-    "51ce91d6" => (1, false, r#"SpongeBob SquarePants - Creature from the Krusty Krab (USA)"#),
-    "c189013d" => (1, false, r#"SpongeBob SquarePants - Lights, Camera, Pants! (Europe)"#),
-    "23297579" => (1, false, r#"SpongeBob SquarePants - Lights, Camera, Pants! (USA)"#),
-    "4f21757d" => (1, false, r#"SpongeBob SquarePants - Revenge of the Flying Dutchman (USA, prototype)"#),
-    "30d6c979" => (0, false, r#"SpongeBob SquarePants - Revenge of the Flying Dutchman (Europe, USA)"#),
-    "98ad67e6" => (0, false, r#"SpongeBob SquarePants - SuperSponge (Europe, USA)"#),
-    "033229a7" => (1, false, r#"SpongeBob SquarePants and Friends - Battle for Volcano Island (Europe)"#),
-    "fc339fc7" => (1, false, r#"SpongeBob SquarePants and Friends in Freeze Frame Frenzy (Europe)"#),
-    "611cc620" => (0, false, r#"SpongeBob SquarePants and Friends Unite! (Europe)"#),
-    "5d9d52de" => (0, false, r#"SpongeBob SquarePants - The Movie (Europe)"#),
-    "df3d16a1" => (3, false, r#"SpongeBob SquarePants - The Movie (USA, prototype)"#),
-    "e1068687" => (0, false, r#"SpongeBob SquarePants - The Movie (USA)"#),
-    "190b1653" => (0, false, r#"SpongeBob's Atlantis SquarePantis (USA)"#),
-    "616088df" => (3, false, r#"Sports Illustrated for Kids - Baseball (USA)"#),
-    "9972779d" => (3, false, r#"Sports Illustrated for Kids - Football (USA)"#),
-    "2e725460" => (1, false, r#"Sportsman's Pack (USA)"#),
-    "4f196612" => (1, false, r#"Spy Hunter (Europe)"#),
-    "b04892c9" => (1, false, r#"Spy Hunter (USA)"#),
-    "a93601f5" => (1, false, r#"Spy Kids 3-D - Game Over (Europe)"#),
-    "96128b31" => (1, false, r#"Spy Kids 3-D - Game Over (USA)"#),
-    "e8227e41" => (1, false, r#"Spy Kids Challenger (USA)"#),
-    "848f9d42" => (0, false, r#"Spy Muppets - License to Croak (USA)"#),
-    "13f10a1e" => (2, false, r#"Spyro - Attack of the Rhynocs (USA)"#),
-    "afd25827" => (1, false, r#"Spyro - Season of Ice (Europe)"#),
-    "2f2389d2" => (1, false, r#"Spyro - Season of Ice (USA)"#),
-    "104b4caa" => (2, false, r#"Spyro 2 - Season of Flame (Europe)"#),
-    "37e120e7" => (2, false, r#"Spyro 2 - Season of Flame (USA)"#),
-    "d05c86bb" => (0, false, r#"Spyro 2 - Season of Flame (USA, prototype)"#),
-    "42dd5dd9" => (1, false, r#"Spyro Advance (Japan)"#),
-    "91a9e2cc" => (1, false, r#"Spyro Advance - Wakuwaku Tomodachi Daisakusen! (Japan)"#),
-    "1f62d660" => (2, false, r#"Spyro Adventure (Europe)"#),
-    "e53ba082" => (1, false, r#"Spyro Fusion (Europe)"#),
-    "bbf2549d" => (1, false, r#"Spyro Orange - The Cortex Conspiracy (USA, rev. A)"#),
-    "a9915baf" => (1, false, r#"Spyro Orange - The Cortex Conspiracy (USA)"#),
-    "8232f58a" => (1, false, r#"SSX 3 (Europe, USA)"#),
-    "e0988123" => (1, false, r#"SSX Tricky (Europe, USA)"#),
-    "db146cc3" => (0, false, r#"Stadium Games (Europe)"#),
-    "4bf6e9e8" => (0, false, r#"Stadium Games (USA)"#),
-    "c492c46d" => (0, false, r#"Star Wars - Episode II - Attack of the Clones (Europe)"#),
-    "8fb5e2c6" => (0, false, r#"Star Wars - Episode II - Attack of the Clones (USA)"#),
-    "fd49236b" => (1, false, r#"Star Wars - Episode III - Revenge of the Sith (Europe)"#),
-    "3e34aab7" => (1, false, r#"Star Wars - Episode III - Revenge of the Sith (USA)"#),
-    "a41d22ad" => (0, false, r#"Star Wars - Flight of the Falcon (Europe)"#),
-    "94d2737a" => (0, false, r#"Star Wars - Flight of the Falcon (USA)"#),
-    "fa0f055d" => (0, false, r#"Star Wars - Jedi Power Battles (Europe)"#),
-    "3f19e156" => (0, false, r#"Star Wars - Jedi Power Battles (USA)"#),
-    "677854af" => (0, false, r#"Star Wars - The New Droid Army (Europe)"#),
-    "59c52f98" => (0, false, r#"Star Wars - The New Droid Army (USA)"#),
-    "c9f0c492" => (1, false, r#"Star Wars Trilogy - Apprentice of the Force (Europe)"#),
-    "714a0d3a" => (1, false, r#"Star Wars Trilogy - Apprentice of the Force (USA)"#),
-    "b5d61c72" => (1, false, r#"Star Wars Trilogy - Apprentice of the Force (USA, prototype)"#),
-    "9dca9c3c" => (0, false, r#"Star X (Europe)"#),
-    "8b02b2b1" => (0, false, r#"Star X (USA)"#),
-    "209958e5" => (1, false, r#"Starsky & Hutch (Europe)"#),
-    "b3993252" => (1, false, r#"Starsky & Hutch (USA)"#),
-    "ab142d2e" => (1, false, r#"Starsky & Hutch (USA, prototype)"#),
-    "cb91922e" => (1, false, r#"Steel Empire (Europe)"#),
-    "f3f2e465" => (1, false, r#"Strawberry Shortcake - Ice Cream Island - Riding Camp (Europe, rev. 1)"#),
-    "c40220cf" => (1, false, r#"Strawberry Shortcake - Ice Cream Island - Riding Camp (Europe)"#),
-    "7fec3419" => (0, false, r#"Strawberry Shortcake - Summertime Adventure (USA)"#),
-    "5a66cafa" => (0, false, r#"Strawberry Shortcake - Summertime Adventure - Special Edition (USA)"#),
-    "53384966" => (1, false, r#"Strawberry Shortcake - Sweet Dreams (USA)"#),
-    "93c5cf69" => (2, false, r#"Street Fighter Alpha 3 (Europe)"#),
-    "80b707c2" => (2, false, r#"Street Fighter Alpha 3 (USA)"#),
-    "8d5d0eab" => (2, false, r#"Street Fighter Zero 3 Upper (Japan)"#),
-    "3f20a970" => (1, false, r#"Street Jam Basketball (Europe, USA)"#),
-    "ac24888b" => (1, false, r#"Street Racing Syndicate (Europe)"#),
-    "f44b6a70" => (1, false, r#"Street Racing Syndicate (USA)"#),
-    "277d2c89" => (0, false, r#"Strike Force Hydra (Europe)"#),
-    "5aeea1de" => (0, false, r#"Strike Force Hydra (USA)"#),
-    "0827d896" => (0, false, r#"Stuart Little 2 (France)"#),
-    "6eb7c688" => (0, false, r#"Stuart Little 2 (Europe, USA)"#),
-    "62a678ab" => (1, false, r#"Stuntman (Europe)"#),
-    "0d58ba07" => (1, false, r#"Stuntman (USA)"#),
-    "0a4e0ed2" => (2, false, r#"Sugar Sugar Rune - Heart ga Ippai! Moegi Gakuen (Japan)"#),
-    "1d33dd2c" => (1, false, r#"The Suite Life of Zack & Cody - Tipton Caper (USA)"#),
-    "aea15c21" => (1, false, r#"The Sum of All Fears (Europe)"#),
-    "166391d1" => (1, false, r#"The Sum of All Fears (USA)"#),
-    "a5d38236" => (2, false, r#"Summon Night - Craft Sword Monogatari (Japan)"#),
-    "12afae5d" => (2, false, r#"Summon Night - Craft Sword Monogatari - Hajimari no Ishi (Japan)"#),
-    "8cd98f6f" => (2, false, r#"Summon Night - Craft Sword Monogatari 2 (Japan)"#),
-    "3ea860c2" => (2, false, r#"Summon Night - Swordcraft Story (USA)"#),
-    "1eac1f48" => (2, false, r#"Summon Night - Swordcraft Story 2 (USA)"#),
-    "baf484be" => (0, false, r#"Super Army War (USA)"#),
-    "91238ad1" => (2, false, r#"Super Black Bass Advance (Europe)"#),
-    "67c4e470" => (3, false, r#"Super Black Bass Advance (Japan)"#),
-    "25123d97" => (1, false, r#"Super Bubble Pop (Europe)"#),
-    "5ec8ea61" => (1, false, r#"Super Bubble Pop (USA)"#),
-    "6bfd2915" => (0, false, r#"Super Bust-A-Move (Europe)"#),
-    "2fb4b14d" => (0, false, r#"Super Bust-A-Move (USA)"#),
-    "35448df4" => (1, false, r#"Super Chinese 1, 2 Advance (Japan)"#),
-    "f4634dbd" => (0, false, r#"Super Collapse! II (USA)"#),
-    "4ca4f528" => (3, false, r#"Super Dodge Ball Advance (Europe)"#),
-    "626603b5" => (3, false, r#"Super Dodge Ball Advance (USA, prototype)"#),
-    "63ca5050" => (3, false, r#"Super Dodge Ball Advance (USA, rev. 1)"#),
-    "1d31dc6b" => (3, false, r#"Super Dodge Ball Advance (USA)"#),
-    "2206b04d" => (1, false, r#"Super Donkey Kong (Japan)"#),
-    "27a1cc75" => (2, false, r#"Super Donkey Kong 2 (Japan)"#),
-    "4dcf6e20" => (1, false, r#"Super Donkey Kong 3 (Japan)"#),
-    "bab43d5c" => (0, false, r#"Archer Maclean's Dropzone (Europe)"#),
-    "2b4843fe" => (0, false, r#"Archer Maclean's Dropzone (USA)"#),
-    "8e20043f" => (1, false, r#"Super Duper Sumos (USA)"#),
-    "1ef2acf3" => (1, false, r#"Super Ghouls'n Ghosts (Europe, USA)"#),
-    "9653bac5" => (0, false, r#"Super Hornet F/A 18F (Europe, USA)"#),
-    "1e4c6d6a" => (1, false, r#"Super Mario Advance (Europe, USA)"#),
-    "69924cbd" => (1, false, r#"Super Mario Advance (USA, demo Kiosk)"#),
-    "b993b92f" => (1, false, r#"Super Mario Advance - Super Mario USA + Mario Brothers (Japan)"#),
-    "fcfef343" => (2, false, r#"Super Mario Advance 2 - Super Mario World (Europe)"#),
-    "5206880a" => (2, false, r#"Super Mario Advance 2 - Super Mario World (USA, Australia)"#),
-    "901ce373" => (2, false, r#"Super Mario Advance 2 - Super Mario World + Mario Brothers (Japan)"#),
-    "639e9d3b" => (2, false, r#"Super Mario Advance 3 - Yoshi's Island (Europe)"#),
-    "40a48276" => (2, false, r#"Super Mario Advance 3 - Yoshi's Island (USA)"#),
-    "26321120" => (2, false, r#"Super Mario Advance 3 - Yoshi's Island + Mario Brothers (Japan)"#),
-    "7b1a4c22" => (5, false, r#"Super Mario Advance 4 - Super Mario 3 + Mario Brothers (Japan, rev. 2)"#),
-    "5a676424" => (5, false, r#"Super Mario Advance 4 - Super Mario 3 + Mario Brothers (Japan, rev. 1)"#),
-    "f3c87306" => (5, false, r#"Super Mario Advance 4 - Super Mario 3 + Mario Brothers (Japan)"#),
-    "8d84acfc" => (5, false, r#"Super Mario Advance 4 - Super Mario Bros. 3 (Europe, rev. 1)"#),
-    "37141f32" => (5, false, r#"Super Mario Advance 4 - Super Mario Bros. 3 (Europe)"#),
-    "88dab27f" => (5, false, r#"Super Mario Advance 4 - Super Mario Bros. 3 (USA, Australia, rev. 1)"#),
-    "a44ba79f" => (5, false, r#"Super Mario Advance 4 - Super Mario Bros. 3 (USA)"#),
-    "3b035681" => (1, false, r#"Super Mario Ball (Europe)"#),
-    "7eb4a336" => (1, false, r#"Super Mario Ball (Japan)"#),
-    "085b271b" => (0, false, r#"Super Mario Bros. (Japan, Hot Mario Campaign)"#),
-    "d75da09d" => (1, false, r#"Super Monkey Ball Jr. (Europe)"#),
-    "0a8031f0" => (1, false, r#"Super Monkey Ball Jr. (USA)"#),
-    "f2bc49ce" => (0, false, r#"Super Puzzle Bobble Advance (Japan)"#),
-    "aac3e9ca" => (1, false, r#"Super Puzzle Fighter II Turbo (Europe)"#),
-    "62066fc9" => (1, false, r#"Super Puzzle Fighter II Turbo (USA, rev. 1)"#),
-    "9cd25c7e" => (1, false, r#"Super Puzzle Fighter II Turbo (USA)"#),
-    "9e90456b" => (1, false, r#"Super Real Mahjong Dousoukai (Japan)"#),
-    "bdb86caa" => (4, false, r#"Super Robot Taisen - Original Generation (Europe)"#),
-    "75127ec4" => (5, false, r#"Super Robot Taisen - Original Generation (Japan)"#),
-    "58e86266" => (4, false, r#"Super Robot Taisen - Original Generation (USA)"#),
-    "a608800f" => (4, false, r#"Super Robot Taisen - Original Generation 2 (Japan)"#),
-    "2aee0380" => (4, false, r#"Super Robot Taisen - Original Generation 2 (USA)"#),
-    "2f6e9788" => (5, false, r#"Super Robot Taisen A (Japan)"#),
-    "efb45117" => (4, false, r#"Super Robot Taisen D (Japan)"#),
-    "c956fd37" => (4, false, r#"Super Robot Taisen J (Japan)"#),
-    "df7f91bc" => (5, false, r#"Super Robot Taisen R (Japan)"#),
-    "461b4590" => (1, false, r#"Super Street Fighter II Turbo - Revival (Europe)"#),
-    "063045aa" => (1, false, r#"Super Street Fighter II Turbo - Revival (USA)"#),
-    "7a2c0d61" => (1, false, r#"Super Street Fighter II X - Revival (Japan)"#),
-    "2bf26a56" => (0, false, r#"Superman - Countdown to Apokolips (Europe)"#),
-    "43017d47" => (0, false, r#"Superman - Countdown to Apokolips (USA)"#),
-    "68215047" => (1, false, r#"Superman Returns - Fortress of Solitude (Europe, USA)"#),
-    "b225ae1a" => (1, false, r#"Surf's Up (Europe, English / French / German / Italian / Spanish)"#),
-    "317326b0" => (1, false, r#"Surf's Up (Europe, English / French / Spanish)"#),
-    "0adf57c5" => (1, false, r#"Surf's Up (USA)"#),
-    "260683c7" => (3, false, r#"Sutakomi - Star Communicator (Japan)"#),
-    "63f45849" => (2, false, r#"Sweet Cookie Pie (Japan)"#),
-    "88e64a8a" => (4, false, r#"Sword of Mana (Europe, English)"#),
-    "5ee17ed1" => (4, false, r#"Sword of Mana (Europe, French / German)"#),
-    "11a0af49" => (4, false, r#"Sword of Mana (Europe, Italian / Spanish)"#),
-    "7f1eac75" => (4, false, r#"Sword of Mana (USA, Australia)"#),
-    "58dc532e" => (2, false, r#"Sylvanian Families - Fashion Designer ni Naritai! - Kurumi-risu no Onnanoko
-			(Japan)"#),
-    "00911694" => (2, false, r#"Sylvanian Families - Yousei no Stick to Fushigi no Ki - Marron-inu no Onnanoko
-			(Japan)"#),
-    "3bfbf110" => (2, false, r#"Sylvanian Families 4 - Meguru Kisetsu no Tapestry (Japan)"#),
-    "6649d2ce" => (5, false, r#"Tactics Ogre - The Knight of Lodis (USA)"#),
-    "0167a878" => (5, false, r#"Tactics Ogre Gaiden - The Knight of Lodis (Japan)"#),
-    "4e6bdf8b" => (1, false, r#"Taiketsu! Ultra Hero (Japan)"#),
-    "881c8416" => (1, false, r#"Tak - The Great Juju Challenge (Europe, USA, Australia)"#),
-    "4ad90ec1" => (1, false, r#"Tak - The Great Juju Challenge (Europe, English / French / German / Dutch)"#),
-    "91dd701d" => (1, false, r#"Tak 2 - The Staff of Dreams (Europe)"#),
-    "8f7eb026" => (1, false, r#"Tak 2 - The Staff of Dreams (USA)"#),
-    "0d483aa6" => (1, false, r#"Tak and the Power of Juju (Europe, English / French / German)"#),
-    "4d0c3680" => (1, false, r#"Tak and the Power of Juju (Europe, Italian / Spanish)"#),
-    "a9af1381" => (1, false, r#"Tak and the Power of Juju (USA)"#),
-    "fa172a4a" => (2, false, r#"Tales of Phantasia (Europe)"#),
-    "d08819c1" => (2, false, r#"Tales of Phantasia (Japan)"#),
-    "01a0fca8" => (2, false, r#"Tales of Phantasia (USA, Australia)"#),
-    "231b9fca" => (2, false, r#"Tales of the World - Narikiri Dungeon 2 (Japan)"#),
-    "1867ccef" => (2, false, r#"Tales of the World - Narikiri Dungeon 3 (Japan)"#),
-    "9c534023" => (3, false, r#"Tales of the World - Summoner's Lineage (Japan)"#),
-    "2785cb89" => (3, false, r#"Tanbi Musou - Meine Liebe (Japan)"#),
-    "8bfb4cfe" => (1, false, r#"Tang Tang (Europe)"#),
-    "f5f0c143" => (1, false, r#"Tang Tang (USA)"#),
-    "77c172bd" => (3, false, r#"Tantei Gakuen Q - Kyuukyoku Trick ni Idome! (Japan)"#),
-    "552f0d90" => (3, false, r#"Tantei Gakuen Q - Meitantei wa Kimi Da! (Japan)"#),
-    "3c21d7e0" => (2, false, r#"Tantei Jinguuji Saburou - Shiroi Kage no Shoujo (Japan)"#),
-    "32c6df3f" => (1, false, r#"Disney's Tarzan - Return to the Jungle (Europe, USA)"#),
-    "35ab002e" => (1, false, r#"Disney's Tarzan - L'Appel de la Jungle (France)"#),
-    "59733b26" => (1, false, r#"Disney's Tarzan - Rückkehr in den Dschungel (Germany)"#),
-    "2d2b0c11" => (0, false, r#"Taxi 3 (France)"#),
-    "12a0442c" => (1, false, r#"Teen Titans (USA)"#),
-    "ccacd823" => (1, false, r#"Teen Titans 2 - The Brotherhood's Revenge (USA)"#),
-    "15bdf0ad" => (1, false, r#"Teenage Mutant Ninja Turtles (Europe)"#),
-    "f6c36783" => (1, false, r#"Teenage Mutant Ninja Turtles (USA)"#),
-    "ada00a65" => (2, false, r#"Teenage Mutant Ninja Turtles 2 - Battle Nexus (Europe)"#),
-    "802fc7c1" => (2, false, r#"Teenage Mutant Ninja Turtles 2 - Battle Nexus (USA)"#),
-    "4628c1b8" => (1, false, r#"Tekken Advance (Europe)"#),
-    "c2eeae53" => (1, false, r#"Tekken Advance (Japan)"#),
-    "c59ea73b" => (1, false, r#"Tekken Advance (USA)"#),
-    "3c225943" => (0, false, r#"Ten Pin Alley 2 (USA)"#),
-    "28e6bd52" => (1, false, r#"Tennis Masters Series 2003 (Europe)"#),
-    "afc3fcf7" => (3, false, r#"Tennis no Ouji-sama - Aim at the Victory! (Japan)"#),
-    "dd244c9a" => (5, false, r#"Tennis no Ouji-sama - Genius Boys Academy (Japan)"#),
-    "9f7fc898" => (3, false, r#"Tennis no Ouji-sama 2003 - Cool Blue (Japan)"#),
-    "59d1dc73" => (3, false, r#"Tennis no Ouji-sama 2003 - Passion Red (Japan)"#),
-    "26a4ee5c" => (3, false, r#"Tennis no Ouji-sama 2004 - Glorious Gold (Japan)"#),
-    "750d579d" => (3, false, r#"Tennis no Ouji-sama 2004 - Stylish Silver (Japan)"#),
-    "16c3cac3" => (0, false, r#"Terminator 3 - Rise of the Machines (Europe)"#),
-    "f74b3c7d" => (0, false, r#"Terminator 3 - Rise of the Machines (USA)"#),
-    "4a834fc2" => (0, false, r#"Tetris Worlds (Europe, English / Italian / Spanish)"#),
-    "ad97eb3f" => (0, false, r#"Tetris Worlds (Europe, English / French / German / Dutch)"#),
-    "fcf2d471" => (0, false, r#"Tetris Worlds (Japan, rev. 1)"#),
-    "dd271470" => (0, false, r#"Tetris Worlds (Japan)"#),
-    "7b729804" => (0, false, r#"Tetris Worlds (USA)"#),
-    "1a13df6b" => (0, false, r#"Texas Hold 'em Poker (Europe, rev. 1)"#),
-    "78b59aac" => (0, false, r#"Texas Hold 'em Poker (Europe, USA)"#),
-    "8e190698" => (1, false, r#"That's So Raven (USA)"#),
-    "5495cbe1" => (1, false, r#"That's So Raven 2 - Supernatural Style (USA)"#),
-    "c3c855ea" => (0, false, r#"The Three Stooges (USA)"#),
-    "d482421e" => (0, false, r#"Three-in-One Pack - Connect Four + Perfection + Trouble (USA)"#),
-    "15c24091" => (0, false, r#"Three-in-One Pack - Risk + Battleship + Clue (USA, rev. A)"#),
-    "f6f70922" => (0, false, r#"Three-in-One Pack - Risk + Battleship + Clue (USA)"#),
-    "111eb61a" => (0, false, r#"Three-in-One Pack - Sorry! + Aggravation + Scrabble Junior (USA)"#),
-    "25e8e649" => (0, false, r#"Thunder Alley (USA)"#),
-    "f09b7bb0" => (0, false, r#"Thunderbirds (Europe, USA)"#),
-    "9a6cb678" => (0, false, r#"Thunderbirds - International Rescue (Europe)"#),
-    "0e38108b" => (1, false, r#"Tiger Woods PGA Tour 2004 (Europe, USA)"#),
-    "38934732" => (1, false, r#"Tiger Woods PGA Tour Golf (Europe, English / French / German / Italian /
-			Spanish)"#),
-    "f3f26acc" => (1, false, r#"Tiger Woods PGA Tour Golf (Europe, USA)"#),
-    "9f16857d" => (1, false, r#"Tim Burton's The Nightmare Before Christmas - The Pumpkin King (Japan)"#),
-    "3c247c14" => (1, false, r#"Tim Burton's The Nightmare Before Christmas - The Pumpkin King (Europe, USA)"#),
-    "944aa8fb" => (0, false, r#"Tin Toy Advance (demo 1, 20010529)"#),
-    "7bda5802" => (0, false, r#"Tin Toy Advance (demo 2, 20010717)"#),
-    "3246a8bd" => (0, false, r#"Tiny Toon Adventures - Buster's Bad Dream (Europe)"#),
-    "fcd7a7c0" => (0, false, r#"Tiny Toon Adventures - Scary Dreams (USA)"#),
-    "4ac770b6" => (0, false, r#"Tiny Toon Adventures - Wacky Stackers (Europe)"#),
-    "e03c633e" => (0, false, r#"Tiny Toon Adventures - Wacky Stackers (USA)"#),
-    "7782a204" => (0, false, r#"Tir et But - Edition Champions du Monde (France)"#),
-    "47bc61b5" => (0, false, r#"Titeuf - Ze Gagmachine (France)"#),
-    "ffd0a045" => (0, false, r#"Titeuf Méga Compet (France)"#),
-    "89103893" => (1, false, r#"TMNT (Europe)"#),
-    "916db9d5" => (1, false, r#"TMNT (USA)"#),
-    "d8125874" => (1, false, r#"TOCA World Touring Cars (Europe)"#),
-    "6eea16bd" => (2, false, r#"Tokimeki Yume Series 1 - Ohanaya-san ni Narou! (Japan)"#),
-    "e05cef9d" => (3, false, r#"Tokyo Majin Gakuen - Fuju Houroku (Japan)"#),
-    "bd5f5f4c" => (1, false, r#"Tokyo Xtreme Racer Advance (Europe)"#),
-    "ab272800" => (1, false, r#"Tokyo Xtreme Racer Advance (USA)"#),
-    "1b7a3819" => (0, false, r#"Tom and Jerry - The Magic Ring (Europe)"#),
-    "7dc8e08e" => (0, false, r#"Tom and Jerry - The Magic Ring (USA)"#),
-    "2db83271" => (0, false, r#"Tom and Jerry in Infurnal Escape (Europe)"#),
-    "11156d88" => (0, false, r#"Tom and Jerry in Infurnal Escape (USA)"#),
-    "5a8d5736" => (0, false, r#"Tom and Jerry Tales (Europe)"#),
-    "af494f20" => (0, false, r#"Tom and Jerry Tales (USA)"#),
-    "6863ce1a" => (1, false, r#"Tom Clancy's Rainbow Six - Rogue Spear (Europe)"#),
-    "23575bf2" => (1, false, r#"Tom Clancy's Rainbow Six - Rogue Spear (USA)"#),
-    "e07ba799" => (1, false, r#"Tom Clancy's Splinter Cell (Europe, prototype)"#),
-    "7ecd29ce" => (1, false, r#"Tom Clancy's Splinter Cell (Europe)"#),
-    "308ba69c" => (1, false, r#"Tom Clancy's Splinter Cell (USA)"#),
-    "f007dfe1" => (1, false, r#"Tom Clancy's Splinter Cell - Pandora Tomorrow (Europe)"#),
-    "aefb7b54" => (1, false, r#"Tom Clancy's Splinter Cell - Pandora Tomorrow (USA)"#),
-    "e37ca939" => (2, false, r#"Tomato Adventure (Japan)"#),
-    "dbcd4541" => (0, false, r#"Tonka - On the Job (USA)"#),
-    "a824df3f" => (2, false, r#"Tony Hawk's American Sk8land (Europe, English)"#),
-    "9633084a" => (2, false, r#"Tony Hawk's American Sk8land (Europe, French / German / Italian / Spanish)"#),
-    "7b089f4a" => (2, false, r#"Tony Hawk's American Sk8land (USA)"#),
-    "4a975f48" => (1, false, r#"Tony Hawk's Downhill Jam (Europe)"#),
-    "37b0b53d" => (1, false, r#"Tony Hawk's Downhill Jam (USA)"#),
-    "812dc57a" => (1, false, r#"Tony Hawk's Pro Skater 2 (Europe, USA)"#),
-    "f0efc9d8" => (1, false, r#"Tony Hawk's Pro Skater 2 (France)"#),
-    "6d236181" => (1, false, r#"Tony Hawk's Pro Skater 2 (Germany)"#),
-    "f669a368" => (2, false, r#"Tony Hawk's Pro Skater 3 (Europe, USA)"#),
-    "8d132067" => (2, false, r#"Tony Hawk's Pro Skater 3 (France)"#),
-    "74174675" => (2, false, r#"Tony Hawk's Pro Skater 3 (Germany)"#),
-    "8cb0aa77" => (2, false, r#"Tony Hawk's Pro Skater 4 (Europe, USA)"#),
-    "1f5149bc" => (2, false, r#"Tony Hawk's Underground (Europe, USA)"#),
-    "bf864083" => (2, false, r#"Tony Hawk's Underground 2 (Europe, USA)"#),
-    "3e69a610" => (0, false, r#"Tootuff - The Gagmachine (Europe, prototype)"#),
-    "391c0497" => (1, false, r#"Top Gear GT Championship (Europe)"#),
-    "0a9c9968" => (1, false, r#"Top Gear GT Championship (USA)"#),
-    "86ad4007" => (1, false, r#"Top Gear Rally (Europe)"#),
-    "be67d878" => (1, false, r#"TG Rally (Europe)"#),
-    "c457c5db" => (1, false, r#"Top Gear Rally (Japan)"#),
-    "4dd90762" => (1, false, r#"Top Gear Rally (USA)"#),
-    "dfc88d3e" => (0, false, r#"Top Gun - Combat Zones (USA)"#),
-    "fdbe5a26" => (0, false, r#"Top Gun - Firestorm Advance (Europe, USA)"#),
-    "a8e8618b" => (1, false, r#"Top Spin 2 (Europe)"#),
-    "a960d63b" => (1, false, r#"Top Spin 2 (USA)"#),
-    "b94ddc93" => (1, false, r#"Total Soccer (Europe)"#),
-    "28c3e211" => (1, false, r#"Total Soccer Advance (Japan)"#),
-    "92f99295" => (2, false, r#"Total Soccer Manager (Europe)"#),
-    "06992f1d" => (1, false, r#"Totally Spies! (Europe)"#),
-    "aaa6e141" => (1, false, r#"Totally Spies! (USA)"#),
-    "ca245559" => (1, false, r#"Totally Spies! 2 - Undercover (Europe)"#),
-    "2c8eb8a1" => (1, false, r#"Totally Spies! 2 - Undercover (USA)"#),
-    "c2945d42" => (3, false, r#"Tottoko Hamutarou 3 - Love Love Daibouken Dechu (Japan)"#),
-    "1b6e5617" => (3, false, r#"Tottoko Hamutarou 4 - Nijiiro Daikoushin Dechu (Japan)"#),
-    "14eb6587" => (2, false, r#"Touhai Densetsu Akagi - Yami ni Mai Orita Tensai (Japan)"#),
-    "4043f1e0" => (2, false, r#"Toukon Heat (Japan)"#),
-    "95e05895" => (4, false, r#"Welcome to The Tower SP (USA)"#),
-    "ec325c92" => (4, false, r#"The Tower SP (Japan)"#),
-    "227fc16b" => (3, false, r#"Toyrobo Force (Japan)"#),
-    "794b2602" => (0, false, r#"Disney's Treasure Planet (Europe, rev. 1)"#),
-    "d52ddce1" => (0, false, r#"Disney's Treasure Planet (Europe)"#),
-    "8b14f9ec" => (0, false, r#"Disney's Treasure Planet (USA)"#),
-    "961260ae" => (1, false, r#"Trick Star (Europe)"#),
-    "b5f7bf43" => (1, false, r#"Tringo (Europe)"#),
-    "2956a0da" => (1, false, r#"Tringo (USA)"#),
-    "3e0ed609" => (1, false, r#"Trollz - Hair Affair! (Europe, English)"#),
-    "fb2284ef" => (1, false, r#"Trollz - Hair Affair! (Europe, English / German / Italian)"#),
-    "14fea5c0" => (1, false, r#"Trollz - Hair Affair! (Europe, English / French / Spanish)"#),
-    "c5cd2c22" => (1, false, r#"Trollz - Hair Affair! (USA)"#),
-    "3bf38352" => (1, false, r#"Tron 2.0 - Killer App (Europe)"#),
-    "77d50ec6" => (1, false, r#"Tron 2.0 - Killer App (USA)"#),
-    "5a7e762b" => (4, false, r#"Tsuukin Hitofude (Japan)"#),
-    "e5e0b0f1" => (1, false, r#"Turbo Turtle Adventure (USA)"#),
-    "07a32299" => (0, false, r#"Turok Evolution (Europe)"#),
-    "4edac27a" => (0, false, r#"Turok Evolution (USA)"#),
-    "378d6da7" => (0, false, r#"Turok Dinosaur Hunter (prototype)"#),
-    "9ef46f34" => (0, false, r#"Turok Dinosaur Hunter (prototype brighter)"#),
-    "401e10ea" => (0, false, r#"Turok Dinosaur Hunter (prototype earlier)"#),
-    "024a1914" => (3, false, r#"Tweety and the Magic Gems (Europe)"#),
-    "f1a49d56" => (3, false, r#"Tweety and the Magic Gems (Netherlands)"#),
-    "c66e14a4" => (3, false, r#"Titi et les Bijoux Magiques (France)"#),
-    "8aa6e91a" => (3, false, r#"Tweety and the Magic Gems (Germany)"#),
-    "2da5c91e" => (3, false, r#"Tweety and the Magic Gems (USA)"#),
-    "d38672bf" => (3, false, r#"Tweety no Hearty Party (Japan)"#),
-    "108c3535" => (2, false, r#"Ty the Tasmanian Tiger 2 - Bush Rescue (Europe, USA)"#),
-    "cf467e1b" => (2, false, r#"Ty the Tasmanian Tiger 3 - Night of the Quinkan (USA)"#),
-    "e5acba28" => (0, false, r#"Tyrian 2000 (USA, prototype)"#),
-    "5a211b60" => (2, false, r#"Uchuu Daisakusen Choco Vader - Uchuu kara no Shinryakusha (Japan)"#),
-    "a00a4530" => (3, false, r#"Uchuu no Stellvia (Japan)"#),
-    "ed368ef1" => (1, false, r#"Ueki no Housoku - Jingi Sakuretsu! Nouryokusha Battle (Japan)"#),
-    "31b1a586" => (2, false, r#"Ui-Ire - World Soccer Winning Eleven (Japan)"#),
-    "264f7a95" => (2, false, r#"Ultimate Arcade Games (USA)"#),
-    "5d4d88c5" => (1, false, r#"Ultimate Beach Soccer (USA)"#),
-    "b6c9f401" => (1, false, r#"Ultimate Brain Games (Europe, USA)"#),
-    "94863423" => (2, false, r#"Ultimate Card Games (USA, rev. 2)"#),
-    "300f5d25" => (2, false, r#"Ultimate Card Games (USA, rev. 1)"#),
-    "8a0831bd" => (2, false, r#"Ultimate Card Games (Europe, USA)"#),
-    "bb85ad55" => (1, false, r#"Ultimate Muscle - The Kinnikuman Legacy - The Path of the Superhero (USA)"#),
-    "65e2295b" => (1, false, r#"Ultimate Muscle - The Kinnikuman Legacy - The Path of the Superhero (USA,
-			prototype, 20030429)"#),
-    "a13f76a5" => (1, false, r#"Ultimate Puzzle Games (USA)"#),
-    "97e1727f" => (1, false, r#"Ultimate Spider-Man (Europe, English)"#),
-    "61a74634" => (1, false, r#"Ultimate Spider-Man (Europe, French / German / Italian / Spanish)"#),
-    "931e2c18" => (1, false, r#"Ultimate Spider-Man (USA)"#),
-    "95988ee2" => (0, false, r#"Ultimate Winter Games (USA)"#),
-    "c03785c7" => (4, false, r#"Ultra Keibitai - Monster Attack (Japan)"#),
-    "2547f3a1" => (0, false, r#"Unfabulous (USA)"#),
-    "e2018633" => (0, false, r#"2 Game Pack!: Uno - Skip-Bo (Europe)"#),
-    "0e2af604" => (0, false, r#"2 Game Pack!: Uno - Skip-Bo (USA)"#),
-    "315360d0" => (0, false, r#"Uno 52 (Europe)"#),
-    "c659c6db" => (0, false, r#"Uno 52 (USA)"#),
-    "414ea9f4" => (0, false, r#"Uno Free Fall (Europe)"#),
-    "19fcc78e" => (0, false, r#"Uno Free Fall (USA)"#),
-    "4c53ba49" => (0, false, r#"Urban Yeti! (Europe, USA)"#),
-    "8b3330bb" => (4, false, r#"The Urbz - Sims in the City (Europe, USA)"#),
-    "bbccc2fd" => (4, false, r#"The Urbz - Sims in the City (Japan)"#),
-    "7e617c46" => (0, false, r#"Uridium Advance (Europe, prototype, 20030307)"#),
-    "aefa2250" => (0, false, r#"Uridium Advance (Europe, prototype, 20020911)"#),
-    "18fb99b1" => (0, false, r#"Uridium Advance and Paradroid 2 in 1 (Europe, prototype, 20030430)"#),
-    "251b2503" => (1, false, r#"V-Master Cross (Japan)"#),
-    "02d20287" => (1, false, r#"V-Rally 3 (Europe)"#),
-    "a0aa46b9" => (1, false, r#"V-Rally 3 (Japan)"#),
-    "d3ae3d37" => (1, false, r#"V-Rally 3 (USA)"#),
-    "718a6c3b" => (0, false, r#"V.I.P. (Europe)"#),
-    "9e8748c9" => (0, false, r#"Van Helsing (Europe)"#),
-    "36159d63" => (0, false, r#"Van Helsing (USA)"#),
-    "e368a67a" => (3, false, r#"Vattroller X (Japan)"#),
-    "b5213253" => (1, false, r#"BigIdea's VeggieTales - LarryBoy and the Bad Apple (USA)"#),
-    "e52a3ab8" => (1, false, r#"Virtua Tennis (Europe)"#),
-    "e0bd3b75" => (1, false, r#"Virtua Tennis (USA)"#),
-    "23b92cf7" => (1, false, r#"Virtual Kasparov (Europe)"#),
-    "815b722f" => (1, false, r#"Virtual Kasparov (USA)"#),
-    "ca4f6d5d" => (1, false, r#"W.i.t.c.h. (Europe)"#),
-    "47b49060" => (1, false, r#"Wade Hixton's Counter Punch (Europe, USA)"#),
-    "5e5c008c" => (3, false, r#"Wagamama Fairy Mirumo de Pon! - 8 Nin no Toki no Yousei (Japan)"#),
-    "5effdc65" => (3, false, r#"Wagamama Fairy Mirumo de Pon! - Dokidoki Memorial Panic (Japan)"#),
-    "ae2fb113" => (3, false, r#"Wagamama Fairy Mirumo de Pon! - Nazo no Kagi to Shinjitsu no Tobira (Japan)"#),
-    "7d21f61f" => (2, false, r#"Wagamama Fairy Mirumo de Pon! - Ougon Maracas no Densetsu (Japan)"#),
-    "f5da0dcc" => (3, false, r#"Wagamama Fairy Mirumo de Pon! - Taisen Mahoudama (Japan)"#),
-    "a606c803" => (3, false, r#"Wagamama Fairy Mirumo de Pon! - Yume no Kakera (Japan)"#),
-    "a3ccf1ec" => (1, false, r#"Wakeboarding Unleashed featuring Shaun Murray (Europe)"#),
-    "cad6376c" => (1, false, r#"Wakeboarding Unleashed featuring Shaun Murray (USA)"#),
-    "cf7585f1" => (3, false, r#"Waliou Xunbao Ji (China)"#),
-    "78a4d2d4" => (3, false, r#"Waliou Zhizao (China)"#),
-    "b12d2724" => (2, false, r#"Wannyan Doubutsu Byouin (Japan, rev. 1)"#),
-    "6de82857" => (2, false, r#"Wannyan Doubutsu Byouin (Japan)"#),
-    "e93f547b" => (1, false, r#"Wanko de Kururin! Wancle (Japan)"#),
-    "253ad05e" => (2, false, r#"Wanko Mix Chiwanko World (Japan)"#),
-    "5f41c9fe" => (1, false, r#"Wanwan Meitantei (Japan)"#),
-    "d6141609" => (3, false, r#"Wario Land 4 (Europe, USA)"#),
-    "f56ebb7a" => (3, false, r#"Wario Land Advance (Japan)"#),
-    "500ca178" => (3, false, r#"WarioWare, Inc. - Minigame Mania (Europe)"#),
-    "ec0c0c58" => (3, false, r#"WarioWare, Inc. - Mega Microgame$! (USA, prototype)"#),
-    "785d8b8c" => (3, false, r#"WarioWare, Inc. - Mega Microgame$! (USA)"#),
-    "cb4e844b" => (0, false, r#"WarioWare - Twisted! (USA, Australia)"#),
-    "e58ef9a2" => (1, false, r#"Whac-A-Mole (USA)"#),
-    "667a0d06" => (3, false, r#"Whistle! - Dai-37-kai Tokyo-to Chuugakkou Sougou Taiiku Soccer Taikai (Japan)"#),
-    "b9c55b22" => (0, false, r#"Who Wants to Be a Millionaire (UK)"#),
-    "da7006e0" => (0, false, r#"Who Wants to Be a Millionaire (Australia)"#),
-    "41aa465e" => (0, false, r#"Weekend Miljonairs (Netherlands)"#),
-    "08d71a89" => (0, false, r#"Wer Wird Millionär (Germany)"#),
-    "8f33bd3f" => (0, false, r#"Qui Veut Gagner des Millions (France)"#),
-    "43514d44" => (0, false, r#"Quiere Ser Millonario (Spain)"#),
-    "b6f8527e" => (0, false, r#"Chi Vuol Essere Milionario (Italy)"#),
-    "c89deb4f" => (0, false, r#"Who Wants to Be a Millionaire - 2nd Edition (UK)"#),
-    "aad93da8" => (0, false, r#"Who Wants to Be a Millionaire - Junior (UK)"#),
-    "e41720f5" => (0, false, r#"The Wild Thornberrys Movie (USA, prototype)"#),
-    "4e2c47bc" => (0, false, r#"The Wild Thornberrys Movie (Europe, USA)"#),
-    "afa71c84" => (0, false, r#"La Famille Delajungle - Le Film (France)"#),
-    "f0ca0689" => (0, false, r#"The Wild Thornberrys - Chimp Chase (Europe, USA)"#),
-    "f344d661" => (0, false, r#"La Famille Delajungle - A la Poursuite de Darwin (France)"#),
-    "9d60610d" => (0, false, r#"Expedition der Stachelbeeren - Zoff im Zoo (Germany)"#),
-    "de79edc3" => (1, false, r#"The Wild (Europe, USA)"#),
-    "c8c12090" => (0, false, r#"Die Wilden Fussball-Kerle (Germany)"#),
-    "a6281dd3" => (1, false, r#"Die Wilden Fussball-Kerle - Gefahr im Wilde Kerle Land (Germany)"#),
-    "09f02f6a" => (1, false, r#"Wing Commander - Prophecy (Europe)"#),
-    "62d9e3d0" => (1, false, r#"Wing Commander - Prophecy (USA)"#),
-    "6e0d29fc" => (1, false, r#"Wings (Europe)"#),
-    "49041bc8" => (1, false, r#"Wings (USA)"#),
-    "e5a97a58" => (0, false, r#"Winnie the Pooh's Rumbly Tumbly Adventure (Europe)"#),
-    "5b098a68" => (0, false, r#"Winnie the Pooh's Rumbly Tumbly Adventure (USA)"#),
-    "9fb4af0a" => (5, false, r#"Winning Post for Game Boy Advance (Japan, rev. 2)"#),
-    "5ada60f6" => (5, false, r#"Winning Post for Game Boy Advance (Japan)"#),
-    "64c802f9" => (1, false, r#"Winter Sports (Europe)"#),
-    "afcb9ce8" => (2, false, r#"WinX Club (Europe)"#),
-    "1d6950a3" => (2, false, r#"WinX Club (USA)"#),
-    "9eb09c77" => (1, false, r#"WinX Club - Quest for the Codex (Europe)"#),
-    "29dfff34" => (1, false, r#"WinX Club - Quest for the Codex (USA)"#),
-    "b3c77a61" => (3, false, r#"Wizardry Summoner (Japan, rev. 1)"#),
-    "71695a71" => (3, false, r#"Wizardry Summoner (Japan)"#),
-    "c4aa2b7b" => (1, false, r#"Wolfenstein 3D (Europe, USA)"#),
-    "a3e1e037" => (1, false, r#"Woody Woodpecker - Crazy Castle 5 (Japan)"#),
-    "092b3dce" => (1, false, r#"Woody Woodpecker in Crazy Castle 5 (Europe)"#),
-    "b9a3c878" => (1, false, r#"Woody Woodpecker in Crazy Castle 5 (USA)"#),
-    "78e5a643" => (1, false, r#"Word Safari - The Friendship Totems (USA)"#),
-    "afe01c1f" => (2, false, r#"World Advance Soccer - Shouri e no Michi (Japan)"#),
-    "4776f86e" => (0, false, r#"World Championship Poker (Europe)"#),
-    "07f15152" => (0, false, r#"World Championship Poker (USA)"#),
-    "8fa26a97" => (2, false, r#"WPT - World Poker Tour (Europe)"#),
-    "b18784c1" => (2, false, r#"WPT - World Poker Tour (USA)"#),
-    "eefb32ff" => (0, false, r#"World Reborn (USA)"#),
-    "4c48ebb3" => (0, false, r#"World Reborn (USA, prototype)"#),
-    "b5830f5f" => (0, false, r#"World Tennis Stars (Europe)"#),
-    "036e30b0" => (0, false, r#"World Tennis Stars (USA)"#),
-    "5223f5aa" => (0, false, r#"Worms Blast (Europe)"#),
-    "8ee32cbe" => (0, false, r#"Worms World Party (Europe)"#),
-    "e8789c18" => (0, false, r#"Worms World Party (USA)"#),
-    "7f8cb0af" => (1, false, r#"WTA Tour Tennis (USA)"#),
-    "459daf35" => (1, false, r#"WTA Tour Tennis Pocket (Japan)"#),
-    "f1fb4fd1" => (1, false, r#"WWE - Road to WrestleMania X8 (Europe, USA)"#),
-    "6694f94d" => (1, false, r#"WWE - Survivor Series (Europe, USA)"#),
-    "8314421b" => (0, false, r#"WWF - Road to WrestleMania (USA, prototype)"#),
-    "8d3b116e" => (0, false, r#"WWF - Road to WrestleMania (Europe, USA)"#),
-    "c05f9907" => (0, false, r#"X-Bladez - Inline Skater (Europe)"#),
-    "09fdd665" => (0, false, r#"X-Bladez - Inline Skater (USA)"#),
-    "25f43491" => (1, false, r#"X-Men - Reign of Apocalypse (Europe, USA)"#),
-    "34be6923" => (1, false, r#"X-Men - The Official Game (Europe)"#),
-    "c87f460a" => (1, false, r#"X-Men - The Official Game (USA)"#),
-    "41a92220" => (1, false, r#"X2 - Wolverine's Revenge (Europe, USA)"#),
-    "82373b44" => (1, false, r#"X-Men 2 - La Vengeance de Wolverine (France)"#),
-    "6dbbc17c" => (0, false, r#"XS Moto (USA)"#),
-    "2b3a2ab2" => (1, false, r#"xXx (Europe, USA)"#),
-    "44a77f71" => (1, false, r#"xXx (France)"#),
-    "9c32a829" => (2, false, r#"Yaoxi Dao (China)"#),
-    "a86f844a" => (2, false, r#"Yggdra Union - We'll Never Fight Alone (Japan)"#),
-    "3e0df130" => (2, false, r#"Yggdra Union - We'll Never Fight Alone (Europe)"#),
-    "3bfa68f7" => (2, false, r#"Yggdra Union - We'll Never Fight Alone (USA)"#),
-    "e64c265c" => (0, false, r#"Yoshi - Topsy-Turvy (USA)"#),
-    "31594b7a" => (0, false, r#"Yoshi no Banyuuinryoku (Japan)"#),
-    "05f4d0aa" => (0, false, r#"Yoshi's Universal Gravitation (Europe)"#),
-    "2a4a92b4" => (2, false, r#"Youkaidou (Japan)"#),
-    "208d77ec" => (1, false, r#"Yu Yu Hakusho - Ghostfiles - Spirit Detective (Europe)"#),
-    "d3784b52" => (1, false, r#"Yu Yu Hakusho - Ghostfiles - Spirit Detective (USA)"#),
-    "aef6b7cc" => (2, false, r#"Yu Yu Hakusho - Ghostfiles - Tournament Tactics (Europe, USA)"#),
-    "ae1a8e9d" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Destiny Board Traveler (Europe)"#),
-    "611e7bbd" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Destiny Board Traveler (USA)"#),
-    "8d52b3c5" => (3, false, r#"Yu-Gi-Oh! - Sugoroku no Sugoroku (Japan)"#),
-    "ee317f69" => (5, false, r#"Yu-Gi-Oh! - Dungeon Dice Monsters (Europe)"#),
-    "51b35e87" => (5, false, r#"Yu-Gi-Oh! - Dungeon Dice Monsters (Japan)"#),
-    "8b5e0a27" => (5, false, r#"Yu-Gi-Oh! - Dungeon Dice Monsters (USA)"#),
-    "460e073f" => (3, false, r#"Yu-Gi-Oh! - Reshef of Destruction (Europe)"#),
-    "09e0c559" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Reshef of Destruction (USA)"#),
-    "d19db37c" => (3, false, r#"Yu-Gi-Oh! - The Sacred Cards (Europe)"#),
-    "141fb1cc" => (3, false, r#"Yu-Gi-Oh! - The Sacred Cards (USA)"#),
-    "3c42e84c" => (3, false, r#"Yu-Gi-Oh! - World Championship Tournament 2004 (Europe)"#),
-    "69b6f229" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - World Championship Tournament 2004 (USA)"#),
-    "17988e9b" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Day of the Duelist - World Championship Tournament
-			2005 (Europe)"#),
-    "584db6a6" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - 7 Trials to Glory - World Championship Tournament
-			2005 (USA)"#),
-    "1becf9d3" => (3, false, r#"Yu-Gi-Oh! Duel Monsters International - Worldwide Edition (Japan, rev. 1)"#),
-    "6dcfb879" => (3, false, r#"Yu-Gi-Oh! Duel Monsters International 2 (Japan)"#),
-    "dfd07a36" => (3, false, r#"Yu-Gi-Oh! - The Eternal Duelist Soul (USA)"#),
-    "cede4060" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Ultimate Masters Edition - World Championship
-			Tournament 2006 (Europe)"#),
-    "f968a196" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Ultimate Masters - World Championship Tournament 2006
-			(USA)"#),
-    "aca5ffc6" => (3, false, r#"Yu-Gi-Oh! - Worldwide Edition - Stairway to the Destined Duel (Europe)"#),
-    "39b5e771" => (3, false, r#"Yu-Gi-Oh! - Worldwide Edition - Stairway to the Destined Duel (USA)"#),
-    "98507497" => (3, false, r#"Yu-Gi-Oh! Duel Monsters International - Worldwide Edition (Japan)"#),
-    "f3041e7e" => (3, false, r#"Yu-Gi-Oh! Duel Monsters 5 Expert 1 (Japan)"#),
-    "a7054d60" => (3, false, r#"Yu-Gi-Oh! Duel Monsters 6 Expert 2 (Japan)"#),
-    "b9be9b91" => (3, false, r#"Yu-Gi-Oh! Duel Monsters 7 - Kettou Toshi Densetsu (Japan)"#),
-    "681daf1f" => (3, false, r#"Yu-Gi-Oh! Duel Monsters 8 - Hametsu no Daijashin (Japan)"#),
-    "7a1019e6" => (3, false, r#"Yu-Gi-Oh! Duel Monsters Expert 2006 (Japan)"#),
-    "7ab1ec04" => (3, false, r#"Yu-Gi-Oh! Duel Monsters Expert 3 (Japan)"#),
-    "3fb2abda" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! GX - Duel Academy (Europe)"#),
-    "3b8a00fe" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! GX - Duel Academy (USA)"#),
-    "f4295da8" => (3, false, r#"Yu-Gi-Oh! Duel Monsters GX - Mezase Duel King (Japan)"#),
-    "4d35aab7" => (1, false, r#"Yumemi-chan no Naritai Series 3 - Watashi no Makesalon (Japan)"#),
-    "ce987f90" => (3, false, r#"Yuujou no Victory Goal 4v4 Arashi - Get the Goal!! (Japan)"#),
-    "6da2166e" => (1, false, r#"Yuureiyashiki no Nijuuyojikan (Japan)"#),
-    "9feef66d" => (5, false, r#"Z.O.E. 2173 - Testament (Japan)"#),
-    "8753a1c5" => (1, false, r#"Zapper (Europe)"#),
-    "6751a4ef" => (1, false, r#"Zapper (USA)"#),
-    "b4f1e785" => (2, false, r#"Zatchbell! - Electric Arena (USA)"#),
-    "6ce771a5" => (2, false, r#"Zelda no Densetsu - Fushigi no Boushi (Japan)"#),
-    "81e42bee" => (2, false, r#"Zelda no Densetsu - Kamigami no Triforce & 4tsu no Tsurugi (Japan)"#),
-    "d320a965" => (5, false, r#"Zen-Nihon GT Senshuken (Japan)"#),
-    "268f9c7e" => (5, false, r#"Zen-Nihon Shounen Soccer Taikai 2 - Mezase Nihon-ichi! (Japan)"#),
-    "84a4c1b4" => (2, false, r#"Zero One (Japan)"#),
-    "fd6ce886" => (2, false, r#"Zero One SP (Japan)"#),
-    "de7c1ae8" => (5, false, r#"Zero-Tours (Japan)"#),
-    "00328bb3" => (3, false, r#"Zettaizetsumei Dangerous Jiisan - Naki no 1-kai Zettaifukujuu Violence Kouchou
-			- Wagahai ga 1-ban Erainjai!! (Japan)"#),
-    "7da6cc69" => (3, false, r#"Zettaizetsumei Dangerous Jiisan - Shijou Saikyou no Dogeza (Japan)"#),
-    "5be52fb7" => (3, false, r#"Zettaizetsumei Dangerous Jiisan 3 - Hateshinaki Mamonogatari (Japan)"#),
-    "99273ee3" => (3, false, r#"Zettaizetsumei Dangerous Jiisan Tsuu - Ikari no Oshioki Blues (Japan)"#),
-    "59e2635f" => (0, false, r#"Zidane Football Generation (Europe)"#),
-    "9cf348a8" => (0, false, r#"Zoey 101 (USA)"#),
-    "302e75f3" => (3, false, r#"Zoids Legacy (USA)"#),
-    "28e8b0d9" => (3, false, r#"Zoids Saga (Japan, rev. 1)"#),
-    "5975dda8" => (3, false, r#"Zoids Saga (Japan)"#),
-    "094e16ad" => (3, false, r#"Zoids Saga Fuzors (Japan)"#),
-    "0c039503" => (3, false, r#"Zoids Saga II (Japan)"#),
-    "71e2cd01" => (0, false, r#"Zoku Bokura no Taiyou - Taiyou Shounen Django (Japan, rev. 1)"#),
-    "2804e589" => (0, false, r#"Zoku Bokura no Taiyou - Taiyou Shounen Django (Japan)"#),
-    "973496e0" => (5, false, r#"Zone of the Enders - The Fist of Mars (Europe)"#),
-    "24244415" => (5, false, r#"Zone of the Enders - The Fist of Mars (USA)"#),
-    "4a23b8d6" => (1, false, r#"ZooCube (Europe)"#),
-    "09a2255f" => (1, false, r#"ZooCube (Japan)"#),
-    "af20be72" => (1, false, r#"ZooCube (USA)"#),
-    "897b2893" => (2, false, r#"Zooo (Europe)"#),
-    "d6d7a9c6" => (0, false, r#"DigimonAdventure (China)"#),
-    "12edcbcc" => (0, false, r#"Kou Dai Yao Guai - Zhi Hong Lan Dui Jue (China)"#),
-    "0295d50b" => (3, false, r#"Digimon Sapphire (China)"#),
-    "cd0a9fdb" => (3, false, r#"Digimon Ruby (China)"#),
-    "75f90391" => (0, false, r#"Dolphin Demo"#),
-    "954d2619" => (0, false, r#"Sample Yoshi Game (prototype, 20010214)"#),
-    "34f2ef47" => (0, false, r#"Sample Yoshi Game (prototype, 20000601)"#),
-    "183a2207" => (1, false, r#"2 in 1: Astérix & Obélix - Bash Them All! + Astérix & Obélix XXL
-			(Europe)"#),
-    "2c3862c3" => (0, false, r#"Barbie Superpack: Groovy Games + Secret Agent (Europe)"#),
-    "c99db2a1" => (0, false, r#"Barbie Superpack: Groovy Games + Secret Agent (USA)"#),
-    "d720bdca" => (0, false, r#"2 Games in 1: Bionicle + Knights' Kingdom (Europe)"#),
-    "3a4f8e4d" => (0, false, r#"Double Game!: Cartoon Network Block Party + Cartoon Network Speedway (Europe)"#),
-    "b3d323e4" => (0, false, r#"2 Games in 1: Cartoon Network Block Party + Cartoon Network Speedway (USA)"#),
-    "ee495897" => (3, false, r#"Castlevania Double Pack: Castlevania - Harmony of Dissonance + Castlevania -
-			Aria of Sorrow (Europe)"#),
-    "dbcecc7d" => (3, false, r#"Castlevania Double Pack: Castlevania - Harmony of Dissonance + Castlevania -
-			Aria of Sorrow (USA)"#),
-    "0beaa2f4" => (4, false, r#"2 Games in 1: Columns Crown + ChuChu Rocket! (Europe)"#),
-    "2c38bae2" => (2, false, r#"Crash Superpack: Crash Bandicoot 2 - N-Tranced + Crash Nitro Kart (USA)"#),
-    "99c96067" => (2, false, r#"2 Games in 1: Disney Princess + Lizzie McGuire (Europe)"#),
-    "57f886b0" => (2, false, r#"2 Games in 1: Disney Princesas + Lizzie McGuire (Spain)"#),
-    "34355326" => (1, false, r#"2 Games in 1: Disney Princess + Disney's Brother Bear (Europe)"#),
-    "22c54ae1" => (1, false, r#"2 Games in 1: Disney Princesse + Disney's Frère des Ours (France)"#),
-    "52c8a11f" => (1, false, r#"2 Games in 1: Disneys Prinzessinnen + Disneys Bärenbrüder (Germany)"#),
-    "458dedb1" => (1, false, r#"2 Games in 1: Disney Principesse + Disney's Koda, Fratello Orso (Italy)"#),
-    "01b143a9" => (1, false, r#"2 Games in 1: Disney Princesas + Disney's Hermano Oso (Spain)"#),
-    "3b1cf966" => (1, false, r#"2 Games in 1: Disney's The Lion King + Disney Princess (Europe)"#),
-    "cce9c2e2" => (1, false, r#"2 Games in 1: Disney Princesse + Disney's Le Roi Lion (France)"#),
-    "ad82cb84" => (1, false, r#"2 Games in 1: Disneys Prinzessinnen + Disneys Der Koenig der Loewen (Germany)"#),
-    "a4acba4a" => (1, false, r#"2 Games in 1: Disney Principesse + Disney's Il Re Leone (Italy)"#),
-    "6472ac25" => (1, false, r#"2 Games in 1: Disney Princesas + Disney's El Rey Leon (Spain)"#),
-    "9e5f961b" => (1, false, r#"2 Games in 1: Disney's Brother Bear + Disney's The Lion King (Europe)"#),
-    "c8cdb4ed" => (3, false, r#"2 Disney Games: Disney Sports - Football + Disney Sports - Skateboarding
-			(Europe)"#),
-    "75703943" => (1, false, r#"2 Disney Games: Disney's Lilo & Stitch 2 + Disney's Peter Pan - Return to
-			Neverland (Europe)"#),
-    "1f9d002e" => (0, false, r#"2 Games in One: Dora the Explorer Double Pack (USA)"#),
-    "ace78c4f" => (2, false, r#"2 Games in 1: Dragon Ball Z I & II (USA)"#),
-    "280587f2" => (2, false, r#"2 Games in 1!: Dragon Ball Z - Buu's Fury + Dragon Ball GT - Transformation
-			(USA)"#),
-    "d5a37aa8" => (0, false, r#"2 Games in 1: Finding Nemo - The Continuing Adventures + The Incredibles (USA)"#),
-    "60a35c6e" => (0, false, r#"2 Games in 1: Findet Nemo + Findet Nemo - Das Abenteuer Geht Weiter (Germany)"#),
-    "72d5f428" => (0, false, r#"2 Games in 1: Finding Nemo + Finding Nemo - The Continuing Adventures (Europe)"#),
-    "73444273" => (0, false, r#"2 Games in 1: Finding Nemo + Finding Nemo - The Continuing Adventures (Italy?,
-			Spain?)"#),
-    "2e5eda0d" => (0, false, r#"2 Games in 1: Finding Nemo + Finding Nemo - The Continuing Adventures (France?,
-			Netherlands?)"#),
-    "9702abce" => (0, false, r#"2 Games in 1: Finding Nemo + The Incredibles (Europe, English)"#),
-    "2d4d69fa" => (0, false, r#"2 Games in 1: Finding Nemo + The Incredibles (Europe, French / Dutch)"#),
-    "5db86e1c" => (0, false, r#"2 Games in 1: Findet Nemo + Die Unglaublichen (Germany)"#),
-    "9ea46982" => (0, false, r#"2 Games in 1: Alla Ricerca di Nemo + Gli Incredibili - Una 'Normale' Famiglia
-			di Supereroi (Italy)"#),
-    "d7ac0697" => (0, false, r#"2 Games in 1: Buscando a Nemo + Los Increibles (Spain)"#),
-    "cff20829" => (0, false, r#"Double Game!: Golden Nugget Casino - Texas Hold 'em Poker (Europe)"#),
-    "6f88e088" => (0, false, r#"2 Games in 1: Golden Nugget Casino - Texas Hold 'em Poker (USA)"#),
-    "20929ec1" => (0, false, r#"2 Game Pack!: Hot Wheels - Stunt Track Challenge + Hot Wheels - World Race
-			(Europe, USA)"#),
-    "b3c2cf9f" => (0, false, r#"2 Games in 1: Hot Wheels - Velocity X + Hot Wheels - World Race (Europe)"#),
-    "a440a760" => (0, false, r#"2 Games in 1: Hot Wheels - Velocity X + Hot Wheels - World Race (USA)"#),
-    "8eae8860" => (1, false, r#"Hugo 2 in 1 (Europe)"#),
-    "0a907f58" => (0, false, r#"2 Game Pack! - Matchbox Missions: Emergency Response + Air, Land & Sea
-			Rescue (Europe)"#),
-    "4080aac1" => (0, false, r#"2 Game Pack! - Matchbox Missions: Emergency Response + Air, Land & Sea
-			Rescue (USA)"#),
-    "6cb47552" => (0, false, r#"2 Games in 1: Monsters, Inc. + Finding Nemo (Europe)"#),
-    "8f66cad4" => (0, false, r#"2 Games in 1: Monsters en Co. + Finding Nemo (Netherlands)"#),
-    "20298a03" => (0, false, r#"2 Games in 1: Monstres & Cie + Le Monde de Nemo (France)"#),
-    "4fd20e32" => (0, false, r#"2 Games in 1: Die Monster AG + Findet Nemo (Germany)"#),
-    "06715995" => (0, false, r#"2 Games in 1: Monsters & Co. + Alla Ricerca di Nemo (Italy)"#),
-    "9ff018f9" => (0, false, r#"2 Games in 1: Monstruos, S.A. + Buscando a Nemo (Spain)"#),
-    "ad1c5818" => (0, false, r#"2 Games in 1 Double Pack: Monsters, Inc. + Finding Nemo (USA)"#),
-    "51d6ceb0" => (1, false, r#"2 Games in 1: Moto GP + GT Advance 3 - Pro Concept Racing (Europe)"#),
-    "b68b84c1" => (1, false, r#"Pac-Man World & Ms. Pac-Man - Maze Madness (Europe)"#),
-    "851127d4" => (1, false, r#"2 Great Games!: Pac-Man World + Ms. Pac-Man - Maze Madness (USA)"#),
-    "965391ea" => (1, false, r#"Pferd & Pony 2 in 1: Pferd & Pony - Mein Pferdehof + Pferd & Pony -
-			Lass Uns Reiten 2 (Germany)"#),
-    "1b6956d0" => (0, false, r#"2 Games in 1: Power Rangers - Ninja Storm + Power Rangers - Time Force (Europe)"#),
-    "14aa2642" => (0, false, r#"2 Games in 1: Power Rangers - Ninja Storm + Power Rangers - La Force du Temps
-			(France)"#),
-    "a5e872d7" => (0, false, r#"2 Games in 1: Power Rangers - Ninja Storm + Power Rangers - Time Force
-			(Germany)"#),
-    "994e7629" => (0, false, r#"2 Games in 1 Double Pack: Power Rangers - Ninja Storm + Power Rangers - Time
-			Force (USA)"#),
-    "2a3e1a8d" => (1, false, r#"Prince of Persia - The Sands of Time & Lara Croft Tomb Raider - The
-			Prophecy (Europe)"#),
-    "0db22e8c" => (0, false, r#"Double Game!: Quad Desert Fury + Monster Trucks (Europe)"#),
-    "f9ec06a9" => (0, false, r#"2 Games in 1: Quad Desert Fury + Monster Trucks (USA)"#),
-    "e5ac73bb" => (0, false, r#"2 Games in 1: Rugrats - Go Wild + SpongeBob SquarePants - SuperSponge (Europe)"#),
-    "b3bb9a45" => (0, false, r#"2 Games in 1: Les Razmoket Rencontrent les Delajungle + SpongeBob SquarePants -
-			SuperSponge (France)"#),
-    "bfcd892e" => (1, false, r#"2 Games in 1: Scooby-Doo + Scooby-Doo 2 - Monsters Unleashed (Europe)"#),
-    "d21582d0" => (1, false, r#"2 Games in 1: Scooby-Doo + Scooby-Doo 2 - Desatado (Spain)"#),
-    "802c9234" => (1, false, r#"2 Games in 1: Scooby-Doo + Scooby-Doo 2 - Les Monstres Se Dechainent (France)"#),
-    "1f023dbe" => (1, false, r#"2 Games in 1: Scooby-Doo + Scooby-Doo 2 - Monsters Unleashed (USA)"#),
-    "99566698" => (1, false, r#"2 Games in 1 Double Pack: Scooby-Doo! - Mystery Mayhem + Scooby-Doo and the
-			Cyber Chase (Europe)"#),
-    "5318df13" => (1, false, r#"2 Games in 1 Double Pack: Scooby-Doo! - Mystery Mayhem + Scooby-Doo and the
-			Cyber Chase (USA)"#),
-    "63bb643f" => (1, false, r#"2-in-1 Fun Pack: Shrek 2 + Madagascar (Europe)"#),
-    "39d68733" => (1, false, r#"2-in-1 Fun Pack: Shrek 2 + Madagascar (USA)"#),
-    "3535b2bf" => (1, false, r#"2-in-1 Fun Pack: Shrek 2 + Madagascar - Operation Penguin (Europe)"#),
-    "18670538" => (1, false, r#"2-in-1 Fun Pack: Shrek 2 + Madagascar - Operation Penguin (USA)"#),
-    "1802c624" => (1, false, r#"2 in 1 Game Pack: Shrek 2 + Shark Tale (Europe)"#),
-    "5cb036c7" => (1, false, r#"2 in 1 Game Pack: Shrek 2 + Shark Tale (USA)"#),
-    "22489c7c" => (4, false, r#"2 Games in 1: Sonic Advance + ChuChu Rocket! (Europe)"#),
-    "21ef5165" => (4, false, r#"Double Pack: Sonic Advance + ChuChu Rocket! (Japan)"#),
-    "94cf5b2b" => (4, false, r#"2 Games in 1: Sonic Advance + Sonic Battle (Europe)"#),
-    "31d712d3" => (4, false, r#"Double Pack: Sonic Advance + Sonic Battle (Japan)"#),
-    "1127dc09" => (4, false, r#"2 Games in 1: Sonic Advance + Sonic Pinball Party (Europe)"#),
-    "0dfaa3f2" => (4, false, r#"Double Pack: Sonic Battle + Sonic Pinball Party (Japan)"#),
-    "fbafb638" => (4, false, r#"Combo Pack - Sonic Advance + Sonic Pinball Party (USA)"#),
-    "2c57e588" => (4, false, r#"2 Games in 1: Sonic Battle + ChuChu Rocket! (Europe)"#),
-    "a50de233" => (4, false, r#"2 Games in 1: Sonic Battle + Sonic Pinball Party (Europe)"#),
-    "8577a000" => (4, false, r#"2 Games in 1: Sonic Pinball Party + Columns Crown (Europe)"#),
-    "d732e52d" => (1, false, r#"2 in 1 Game Pack: Spider-Man & Spider-Man 2 (Europe)"#),
-    "0ae3637f" => (1, false, r#"2 in 1 Game Pack: Spider-Man + Spider-Man 2 (USA)"#),
-    "e7baa5b9" => (1, false, r#"2 in 1 Game Pack: Spider-Man - Mysterio's Menace + X2 - Wolverine's Revenge
-			(Europe, USA)"#),
-    "4ba7f7cb" => (0, false, r#"2 Games in 1: SpongeBob SquarePants - Battle for Bikini Bottom + Jimmy Neutron
-			Boy Genius (Europe)"#),
-    "5802cafd" => (1, false, r#"2 Games in 1: SpongeBob SquarePants - Battle for Bikini Bottom + Nicktoons -
-			Freeze Frame Frenzy (USA)"#),
-    "82c21322" => (0, false, r#"2 Games in 1 Double Pack: SpongeBob SquarePants - Battle for Bikini Bottom +
-			The Fairly OddParents! - Breakin' da Rules (USA)"#),
-    "111d6997" => (0, false, r#"2 Games in 1: SpongeBob SquarePants - Revenge of the Flying Dutchman +
-			SpongeBob SquarePants - SuperSponge (Europe)"#),
-    "d515c0e4" => (0, false, r#"2 Games in 1 Double Pack: SpongeBob SquarePants - Revenge of the Flying
-			Dutchman + SpongeBob SquarePants - SuperSponge (USA)"#),
-    "71d52c61" => (0, false, r#"2 Games in 1: SpongeBob SquarePants - SuperSponge & Battle for Bikini
-			Bottom (Europe)"#),
-    "06f0f43c" => (0, false, r#"2 Games in 1: SpongeBob SquarePants - SuperSponge & Battle for Bikini
-			Bottom (Europe, alt)"#),
-    "a04cebfe" => (1, false, r#"2 Games in 1: The SpongeBob SquarePants Movie + SpongeBob SquarePants and
-			Friends in Freeze Frame Frenzy (Europe)"#),
-    "d8382427" => (2, false, r#"Spyro - Season of Ice + Crash Bandicoot 2 - N-Tranced (Europe)"#),
-    "7b3c4f90" => (2, false, r#"Spyro 2 - Season of Flame + Crash Nitro Kart (Europe)"#),
-    "0510b70f" => (2, false, r#"Spyro - Fusion + Crash Bandicoot - Fusion (Europe)"#),
-    "cdbe00e3" => (2, false, r#"Spyro - Season of Ice + Crash Bandicoot - The Huge Adventure (USA)"#),
-    "5940906e" => (2, false, r#"Spyro Orange - The Cortex Conspiracy + Crash Bandicoot Purple - Ripto's Rampage
-			(USA)"#),
-    "7ef359ed" => (2, false, r#"Spyro Superpack: Spyro - Season of Ice + Spyro - Season of Flame (USA)"#),
-    "e902880f" => (2, false, r#"Teenage Mutant Ninja Turtles Double Pack (Europe)"#),
-    "176a42e5" => (2, false, r#"Teenage Mutant Ninja Turtles Double Pack (USA)"#),
-    "0a5965f4" => (0, false, r#"2 Jeux en 1: Titeuf - Ze Gagmachine + Titeuf Méga Compet (France)"#),
-    "6d8ef48a" => (2, false, r#"2 in 1 Game Pack: Tony Hawk's Underground + Kelly Slater's Pro Surfer (Europe,
-			USA)"#),
-    "5813810f" => (2, false, r#"2 in 1: V-Rally 3 + Stuntman (Europe)"#),
-    "c2fc12e5" => (1, false, r#"Winnie the Pooh's Rumbly Tumbly Adventure & Rayman 3 (Europe)"#),
-    "7ea28a99" => (3, false, r#"Yu-Gi-Oh! Double Pack (Europe)"#),
-    "f3df5758" => (3, false, r#"Yu-Gi-Oh! Double Pack (USA)"#),
-    "2cb399d0" => (3, false, r#"Yu-Gi-Oh! Double Pack 2 (USA)"#),
-    "164a75ac" => (0, false, r#"Ignition Collection: Volume 1- 3 Games in 1: Animal Snap + World Tennis Stars +
-			Super Dropzone (Europe)"#),
-    "6721794b" => (1, false, r#"3 Games in 1: Rugrats - I Gotta Go Party + SpongeBob SquarePants - SuperSponge
-			+ Tak and the Power of Juju (Europe)"#),
-    "cac0c0d8" => (3, false, r#"4 Games on One Game Pak (Nicktoons) (USA)"#),
-    "04a40017" => (3, false, r#"4 Games on One Game Pak (Racing) (USA)"#),
-    "09f5e672" => (1, false, r#"Twin Series 1 - Mezase Debut! - Fashion Designer Monogatari + Kawaii Pet Game
-			Gallery 2 (Japan)"#),
-    "8b0eb948" => (1, false, r#"Twin Series 2 - Oshare Princess 4 + Renai Uranai Daisakusen! + Renai Party Game
-			- Sweet Heart (Japan)"#),
-    "68237519" => (1, false, r#"Twin Series 3 - Konchuu Monster - Ouja Ketteisen + Super Chinese Labyrinth
-			(Japan)"#),
-    "fa643377" => (2, false, r#"Twin Series 4 - Hamu Hamu Monster EX - Hamster Monogatari RPG + Fantasy Puzzle
-			- Hamster Monogatari - Mahou no Meikyuu 1.2.3 (Japan)"#),
-    "2c582ff3" => (2, false, r#"Twin Series 5 - Mahou no Kuni no Cake-ya-san Monogatari + Wanwan Meitantei EX
-			(Japan)"#),
-    "9370623d" => (1, false, r#"Twin Series 6 - Wannyan Idol Gakuen + Koinu to Issho Special (Japan)"#),
-    "3c91b0a0" => (1, false, r#"Twin Series 7 - Twin Puzzle - Kisekae Wanko EX + Nyaa to Chuu no Rainbow Magic
-			2 (Japan)"#),
-    "a4ef4e95" => (5, false, r#"Card e-Reader (Japan)"#),
-    "4139e7c3" => (5, false, r#"Card e-Reader+ (Japan)"#),
-    "8b27cd67" => (5, false, r#"e-Reader (USA)"#),
-    "424f71e5" => (0, false, r#"GBA Personal Organizer (USA)"#),
-    "a1b64f16" => (0, false, r#"GBA AV Adapter (China)"#),
-    "c879b340" => (0, false, r#"GBA Movie Player 2 CF (Spain)"#),
-    "af124f8c" => (0, false, r#"GBA TV Tuner PAL (China, v1.3)"#),
-    "b511df64" => (0, false, r#"GBA TV Tuner PAL (China, v2.0)"#),
-    "b8fda4f5" => (2, false, r#"The Holy Bible - World English Bible (USA, prototype)"#),
-    "867bec76" => (0, false, r#"Nintendo MP3 Player (Europe)"#),
-    "ba997cfa" => (3, false, r#"Play-Yan (Japan)"#),
-    "e24f6a15" => (3, false, r#"Play-Yan Micro (Japan)"#),
-    "4304bd2d" => (0, false, r#"Action Replay (Europe, v3.3)"#),
-    "45bb6f4e" => (0, false, r#"Action Replay GBX (Europe, alt)"#),
-    "18ce5322" => (0, false, r#"Action Replay GBX (Europe)"#),
-    "3db7a213" => (0, false, r#"Action Replay GBX (Europe, v3.1)"#),
-    "5ad72359" => (0, false, r#"Action Replay GBX (Europe, alt 2)"#),
-    "3fc75439" => (0, false, r#"Action Replay MAX (Europe)"#),
-    "f5b2f65e" => (0, false, r#"CodeBreaker (USA)"#),
-    "9ad94c62" => (0, false, r#"GameShark GBA (USA, alt)"#),
-    "d71dbca6" => (0, false, r#"GameShark GBA (USA)"#),
-    "9c249666" => (0, false, r#"Pro Action Replay (Japan)"#),
-    "4f3e7427" => (0, false, r#"Xploder Advance (Europe, alt)"#),
-    "ad71f6ff" => (0, false, r#"Xploder Advance (Europe)"#),
-    "d48d5587" => (0, false, r#"X-Terminator Advance (Japan)"#),
-    "6b3961fb" => (0, false, r#"Dog Trainer (Europe)"#),
-    "b2100fed" => (0, false, r#"Dog Trainer 2 (Europe)"#),
-    "d72c620f" => (0, false, r#"Rayman IV (Rayman - Sunshine of Trip) (unlicensed) (protection hacked)"#),
-    "8ee6b303" => (0, false, r#"Rayman IV (Rayman - Puteshestviye Solnechnogo Sveta) (unlicensed) (Russian)
-			(protection hacked)"#),
-    "d05c8414" => (0, false, r#"Leiman IV - Guangming Zhi Lv (unlicensed) (Chinese) (protection hacked)"#),
-    "9255c170" => (0, false, r#"Super Mario DX (unlicensed) (protection hacked)"#),
-    "2c7af7e8" => (0, false, r#"Chaoji Maliou DX (unlicensed) (Chinese) (protection hacked)"#),
-    "4b67247c" => (0, false, r#"Crash Advance IV (unlicensed) (protection hacked)"#),
-    "51ef4700" => (0, false, r#"Digimon - Ruby and Sapphire (unlicensed) (protection hacked)"#),
-    "86a602ab" => (0, false, r#"Pokemon - Sapphire Version (unlicensed) (protection hacked)"#),
-    "8e0a9112" => (0, false, r#"Sonic 3 - Fighter Sonic (unlicensed) (protection hacked)"#),
-    "8ee6d3d5" => (0, false, r#"Teenage Mutant Ninja Turtles 2 (unlicensed) (protection hacked)"#),
-    "09c7c895" => (0, false, r#"The Lord of the Rings IV - The Fellowship of the Ring (unlicensed) (protection
-			hacked)"#),
-    "4683dbb5" => (0, false, r#"Ultraman - Confrontation Between Justice And Evil (Ul'traman - Konfrontatsiya
-			mezhdu spravedlivost'yu i zlom) (unlicensed) (Russian) (protection hacked)"#),
-    "e14c9b78" => (0, false, r#"X-Man - Armour of Might (X-men) (unlicensed) (Russian) (protection hacked)"#),
-
-};
\ No newline at end of file
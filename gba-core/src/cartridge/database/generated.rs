@@ -0,0 +1,5795 @@
+// This file is automatically generated from the GBA cartridge database CSV files.
+// Do not edit this file manually.
+
+use phf::phf_map;
+
+// (backup type, has RTC, title, backup type is verified and must not be autodetected)
+pub static TITLE_DATABASE: phf::Map<&'static str, (u8, bool, &'static str, bool)> = phf_map! {
+    "9d4f1e18" => (1, false, r#"007 - Everything or Nothing (Europe, USA)"#, false),
+    "caf2e99f" => (1, false, r#"007 - Everything or Nothing (Japan)"#, false),
+    "55ae4312" => (3, false, r#"Apotris (v3.4.5)"#, false),
+    "56c83c16" => (1, false, r#"007 - NightFire (Europe, USA)"#, false),
+    "50ab4544" => (1, false, r#"2006 FIFA World Cup - Germany 2006 (Europe, USA)"#, false),
+    "8f3ff0e4" => (0, false, r#"2K Sports - Major League Baseball 2K7 (USA)"#, false),
+    "fb7a7567" => (1, false, r#"2 Games in 1: Dr. Mario & Puzzle League (Europe)"#, false),
+    "4c2b7349" => (1, false, r#"2 Games in One! - Dr. Mario + Puzzle League (USA, Australia)"#, false),
+    "129bf78f" => (1, false, r#"Dr. Mario & Panel de Pon (Japan)"#, false),
+    "36717b52" => (0, false, r#"2 Games in One!: Gauntlet + Rampart (Europe)"#, false),
+    "5f255362" => (0, false, r#"2 Games in One!: Gauntlet + Rampart (USA)"#, false),
+    "40da6c26" => (0, false, r#"2 Games in One!: Marble Madness + Klax (Europe)"#, false),
+    "5da05880" => (0, false, r#"2 Games in One!: Marble Madness + Klax (USA)"#, false),
+    "44e1761a" => (0, false, r#"2 Games in One!: Paperboy + Rampage (Europe)"#, false),
+    "79fe9284" => (0, false, r#"2 Games in One!: Paperboy + Rampage (USA)"#, false),
+    "bbf32ee3" => (0, false, r#"2 Games in One!: Spy Hunter + Super Sprint (Europe)"#, false),
+    "ad2f5353" => (0, false, r#"2 Games in One!: Spy Hunter + Super Sprint (USA)"#, false),
+    "628a8e32" => (0, false, r#"3 Game Pack!: Candy Land - Chutes and Ladders - Original Memory Game (USA)"#, false),
+    "e6dc35a9" => (0, false, r#"3 Game Pack!: The Game of Life - Payday - Yahtzee (USA)"#, false),
+    "20287753" => (0, false, r#"3 Game Pack!: Mouse Trap - Simon - Operation (USA)"#, false),
+    "8b523ff7" => (0, false, r#"3 Game Pack!: Ker Plunk! - Toss Across - Tip It (USA)"#, false),
+    "2af65e94" => (0, false, r#"3 Games in One!: Breakout - Centipede - Warlords (Europe)"#, false),
+    "02e0cca4" => (0, false, r#"3 Games in One!: Breakout - Centipede - Warlords (USA)"#, false),
+    "b6ef1ed0" => (0, false, r#"3 Games in One!: Super Breakout - Millipede - Lunar Lander (Europe)"#, false),
+    "d191b56f" => (0, false, r#"3 Games in One!: Super Breakout - Millipede - Lunar Lander (USA)"#, false),
+    "f42b4e70" => (0, false, r#"3 Games in One!: Yars' Revenge - Asteroids - Pong (Europe)"#, false),
+    "dbd04637" => (0, false, r#"3 Games in One!: Yars' Revenge - Asteroids - Pong (USA)"#, false),
+    "43f5e157" => (0, false, r#"Ace Combat Advance (Europe, USA)"#, false),
+    "e94aafc9" => (1, false, r#"Ace Lightning (Europe)"#, false),
+    "71720e98" => (1, false, r#"Acrobat Kid (Japan)"#, false),
+    "d07214a2" => (0, false, r#"Action Man - Robot Atak (Europe)"#, false),
+    "14a28d68" => (1, false, r#"Activision Anthology (USA)"#, false),
+    "edcb7d46" => (2, false, r#"Advance GT2 (Japan)"#, false),
+    "fdc82e98" => (3, false, r#"Advance GTA (Japan)"#, false),
+    "2487b85b" => (1, false, r#"Advance Guardian Heroes (Europe)"#, false),
+    "1527ca8c" => (1, false, r#"Advance Guardian Heroes (Japan)"#, false),
+    "c501917f" => (1, false, r#"Advance Guardian Heroes (USA)"#, false),
+    "86ed2599" => (3, false, r#"Advance Rally (Japan)"#, false),
+    "66fb29e9" => (5, false, r#"Advance Wars (Europe)"#, false),
+    "26fd0fc9" => (5, false, r#"Advance Wars (USA, rev. 1)"#, false),
+    "dbef116c" => (5, false, r#"Advance Wars (USA)"#, false),
+    "5a09aae6" => (5, false, r#"Advance Wars 2 - Black Hole Rising (Europe)"#, false),
+    "5ad0e571" => (5, false, r#"Advance Wars 2 - Black Hole Rising (USA, Australia)"#, false),
+    "5781acaf" => (3, false, r#"Adventure of Tokyo Disney Sea (Japan)"#, false),
+    "d2ced674" => (0, false, r#"The Adventures of Jimmy Neutron vs Jimmy Negatron (Europe, USA)"#, false),
+    "3690c0fd" => (0, false, r#"Jimmy Neutron vs. Jimmy Negatron (Germany)"#, false),
+    "220ccf25" => (0, false, r#"Jimmy Neutron vs. Jimmy Negatron (Germany, prototype)"#, false),
+    "d59d753b" => (1, false, r#"The Adventures of Jimmy Neutron Boy Genius - Attack of the Twonkies (Europe,
+			USA)"#, false),
+    "33816e51" => (1, false, r#"Jimmy Neutron un Garcon Genial - L'Attaque des Twonkies (France)"#, false),
+    "67756000" => (0, false, r#"The Adventures of Jimmy Neutron Boy Genius - Jet Fusion (Europe, USA)"#, false),
+    "6f3ea564" => (1, false, r#"Aero the Acro-Bat - Rascal Rival Revenge (Europe, prototype 20020327)"#, false),
+    "beed50f0" => (0, false, r#"Aero the Acro-Bat - Rascal Rival Revenge (Europe, prototype earlier)"#, false),
+    "a7e98ebe" => (1, false, r#"Aero the Acro-Bat - Rascal Rival Revenge (Europe)"#, false),
+    "b47ac020" => (1, false, r#"Aero the Acro-Bat - Rascal Rival Revenge (USA)"#, false),
+    "90d759e2" => (0, false, r#"Agassi Tennis Generation (Europe)"#, false),
+    "8ba179b8" => (0, false, r#"Agassi Tennis Generation (USA)"#, false),
+    "83bc2d2c" => (0, false, r#"Agent Hugo - Roborumble (Europe)"#, false),
+    "bf553530" => (1, false, r#"AGB Aging Cartridge (World, version 1.0)"#, false),
+    "bbb6a960" => (1, false, r#"AGB Aging Cartridge (World, version 7.0)"#, false),
+    "2e69686d" => (1, false, r#"AGB Aging Cartridge (World, version 9.0)"#, false),
+    "a2f6bcde" => (0, false, r#"Aggressive Inline (Europe)"#, false),
+    "3a44fcd5" => (0, false, r#"Aggressive Inline (USA)"#, false),
+    "36a0e152" => (3, false, r#"L'Aigle de Guerre (France)"#, false),
+    "58a972df" => (1, false, r#"AirForce Delta II (Japan)"#, false),
+    "ebf757b8" => (1, false, r#"AirForce Delta Storm (USA)"#, false),
+    "b50cd166" => (2, false, r#"Aka-chan Doubutsuen (Japan)"#, false),
+    "c1072e26" => (2, false, r#"Aka-chan Doubutsuen (Japan, rev. 1)"#, false),
+    "f3e41d73" => (3, false, r#"Akumajou Dracula - Circle of the Moon (Japan)"#, false),
+    "594fbb7c" => (1, false, r#"Disney's Aladdin (Europe)"#, false),
+    "587f1aef" => (1, false, r#"Disney's Aladdin (Japan)"#, false),
+    "40b383f6" => (1, false, r#"Disney's Aladdin (USA)"#, false),
+    "e068728f" => (1, false, r#"Aleck Bordon Adventure - Tower & Shaft Advance (Japan)"#, false),
+    "25e8e2cd" => (1, false, r#"Alex Rider - Stormbreaker (Europe)"#, false),
+    "cca55dfd" => (1, false, r#"Alex Rider - Stormbreaker (USA)"#, false),
+    "dff47236" => (1, false, r#"Alien Hominid (Europe)"#, false),
+    "0d694ca4" => (0, false, r#"Alienators - Evolution Continues (Europe, USA)"#, false),
+    "e9c9dd2b" => (1, false, r#"All Grown Up! - Express Yourself (Europe, USA)"#, false),
+    "e30691fe" => (3, false, r#"All-Star Baseball 2003 - Featuring Derek Jeter (USA)"#, false),
+    "d6106c26" => (3, false, r#"All-Star Baseball 2004 (USA, prototype earlier)"#, false),
+    "6aa9b41e" => (3, false, r#"All-Star Baseball 2004 (USA, prototype)"#, false),
+    "60e2db03" => (3, false, r#"All-Star Baseball 2004 - Featuring Derek Jeter (USA)"#, false),
+    "654f7916" => (1, false, r#"Altered Beast - Guardian of the Realms (Europe)"#, false),
+    "c4955f69" => (1, false, r#"Altered Beast - Guardian of the Realms (USA)"#, false),
+    "d76ad62d" => (2, false, r#"American Bass Challenge (USA)"#, false),
+    "698377d2" => (1, false, r#"Disney's American Dragon Jake Long - Rise of the Huntsclan! (Europe, USA)"#, false),
+    "f053ffbf" => (0, false, r#"American Idol (USA)"#, false),
+    "c8ea6d9e" => (0, false, r#"An American Tail - Fievel's Gold Rush (Europe)"#, false),
+    "2a882ea6" => (0, false, r#"An American Tail - Fievel's Gold Rush (USA)"#, false),
+    "da6555d8" => (2, false, r#"Angel Collection - Mezase! Gakuen no Fashion Leader (Japan)"#, false),
+    "7b46d462" => (2, false, r#"Angel Collection 2 - Pichimo ni Narou (Japan)"#, false),
+    "c9d41f35" => (2, false, r#"Angelique (Japan)"#, false),
+    "89e6cd89" => (3, false, r#"Animal Mania - Dokidoki Aishou Check (Japan)"#, false),
+    "25015475" => (0, false, r#"Animal Snap - Rescue Them 2 by 2 (Europe)"#, false),
+    "7304dca4" => (0, false, r#"Animal Snap - Rescue Them 2 by 2 (USA)"#, false),
+    "d53c9438" => (3, false, r#"Animal Yokochou - Doki Doki Kyuushutsu Daisakusen! no Maki (Japan)"#, false),
+    "41b39214" => (3, false, r#"Animal Yokochou - Doki Doki Shinkyuu Shiken! no Maki (Japan)"#, false),
+    "840d11c1" => (0, false, r#"Animaniacs - Lights, Camera, Action! (Europe)"#, false),
+    "52dc386b" => (1, false, r#"The Ant Bully (Europe)"#, false),
+    "8d51a101" => (1, false, r#"The Ant Bully (USA)"#, false),
+    "367927ed" => (0, false, r#"Antz - Extreme Racing (Europe)"#, false),
+    "f4efc5ed" => (0, false, r#"Antz - Extreme Racing (USA)"#, false),
+    "ad9af125" => (1, false, r#"Ao-Zora to Nakama-tachi - Yume no Bouken (Japan)"#, false),
+    "4202a9cd" => (0, false, r#"Archer Maclean Presents Pool (USA)"#, false),
+    "81397ac0" => (0, false, r#"Arctic Tale (USA)"#, false),
+    "6d174e28" => (0, false, r#"Army Men - Operation Green (USA)"#, false),
+    "65ac74cc" => (1, false, r#"Army Men - Turf Wars (USA)"#, false),
+    "9a4a509f" => (0, false, r#"Army Men Advance (Europe, USA)"#, false),
+    "2dd8cbd7" => (0, false, r#"Around the World in 80 Days (Europe)"#, false),
+    "c2c22af2" => (0, false, r#"Around the World in 80 Days (USA)"#, false),
+    "87c25055" => (1, false, r#"Arthur and the Invisibles (USA)"#, false),
+    "a4dae90b" => (1, false, r#"Arthur and the Minimoys (Europe)"#, false),
+    "69cde456" => (3, false, r#"Ashita no Joe - Makka ni Moeagare! (Japan)"#, false),
+    "92360109" => (0, false, r#"Astérix & Obélix - Bash Them All! (Europe)"#, false),
+    "a9b1ece5" => (1, false, r#"Astérix & Obélix XXL (Europe)"#, false),
+    "1e2e8937" => (2, false, r#"Astro Boy - Omega Factor (Europe)"#, false),
+    "e8619031" => (2, false, r#"Astro Boy - Omega Factor (USA)"#, false),
+    "e2d94b0d" => (2, false, r#"Astro Boy - Tetsuwan Atom - Atom Heart no Himitsu (Japan)"#, false),
+    "b9b89fdf" => (0, false, r#"Atari Anniversary Advance (Europe)"#, false),
+    "4505a638" => (0, false, r#"Atari Anniversary Advance (USA)"#, false),
+    "b3948dbc" => (0, false, r#"Disney's Atlantis - The Lost Empire (Europe)"#, false),
+    "8bd224c5" => (0, false, r#"Disney's Atlantis - The Lost Empire (Europe, USA)"#, false),
+    "8919d82c" => (0, false, r#"Atomic Betty (Europe, USA)"#, false),
+    "4b4e8bc7" => (0, false, r#"ATV - Quad Power Racing (Europe, rev. 1)"#, false),
+    "9c1a7dcb" => (0, false, r#"ATV - Quad Power Racing (Europe, USA)"#, false),
+    "b1d2896f" => (0, false, r#"ATV - Thunder Ridge Riders (Europe)"#, false),
+    "773e73c7" => (0, false, r#"ATV - Thunder Ridge Riders (USA)"#, false),
+    "946787c0" => (2, false, r#"Avatar - The Last Airbender (USA)"#, false),
+    "52d8d101" => (0, false, r#"Avatar - The Last Airbender - The Burning Earth (USA)"#, false),
+    "52fe8a62" => (2, false, r#"Avatar - The Legend of Aang (Europe)"#, false),
+    "ec5d6afd" => (0, false, r#"Avatar - The Legend of Aang - The Burning Earth (Europe)"#, false),
+    "216704f6" => (2, false, r#"Azumanga Daiou Advance (Japan)"#, false),
+    "ee41c0eb" => (2, false, r#"B-Densetsu! Battle B-Daman - Fire Spirits! (Japan)"#, false),
+    "d6e4064f" => (2, false, r#"B-Densetsu! Battle B-Daman - Moero! B-Damashii!! (Japan)"#, false),
+    "ab7d6c17" => (1, false, r#"Babar to the Rescue (Europe)"#, false),
+    "d4073e33" => (1, false, r#"Babar to the Rescue (USA)"#, false),
+    "5730c85a" => (0, false, r#"Back to Stone (Europe)"#, false),
+    "4c29c9c8" => (0, false, r#"Back to Stone (USA)"#, false),
+    "48539b4b" => (1, false, r#"Back Track (Europe, USA)"#, false),
+    "9f36b4e5" => (1, false, r#"Backyard Baseball (USA)"#, false),
+    "92bcd192" => (1, false, r#"Backyard Baseball 2006 - Featuring Pros as Kids (USA)"#, false),
+    "cbda1d31" => (1, false, r#"Backyard Basketball (USA)"#, false),
+    "7ccc79b4" => (1, false, r#"Backyard Football (USA)"#, false),
+    "db0723e3" => (1, false, r#"Backyard Football 2006 (USA)"#, false),
+    "3a8e31af" => (1, false, r#"Backyard Hockey (USA)"#, false),
+    "4eb19b87" => (1, false, r#"Backyard Skateboarding (USA)"#, false),
+    "0ee82569" => (1, false, r#"Backyard Sports - Baseball 2007 (USA)"#, false),
+    "28724ca5" => (1, false, r#"Backyard Sports - Basketball 2007 (USA)"#, false),
+    "e9fdc229" => (1, false, r#"Backyard Sports - Football 2007 (USA)"#, false),
+    "9030515d" => (3, false, r#"Bakunetsu Dodge Ball Fighters (Japan)"#, false),
+    "7f2e0320" => (3, false, r#"Bakuten Shoot Beyblade - Gekitou! Saikyou Blader (Japan)"#, false),
+    "f01ee06c" => (3, false, r#"Bakuten Shoot Beyblade 2002 - Gekisen! Team Battle!! Kouryuu no Shou - Daichi
+			Hen (Japan)"#, false),
+    "8778b36a" => (3, false, r#"Bakuten Shoot Beyblade 2002 - Gekisen! Team Battle!! Seiryuu no Shou - Takao
+			Hen (Japan)"#, false),
+    "1d1bb02b" => (3, false, r#"Bakuten Shoot Beyblade 2002 - Ikuze! Bakutou! Chou Jiryoku Battle!! (Japan)"#, false),
+    "494492dc" => (1, false, r#"Baldur's Gate - Dark Alliance (Europe)"#, false),
+    "deedd7d9" => (1, false, r#"Baldur's Gate - Dark Alliance (USA)"#, false),
+    "b98de3a4" => (1, false, r#"Banjo-Pilot (Europe)"#, false),
+    "6e4030a1" => (1, false, r#"Banjo-Pilot (USA)"#, false),
+    "817fd31d" => (1, false, r#"Banjo-Pilot (prototype)"#, false),
+    "fb4f38e2" => (2, false, r#"Banjo-Kazooie - Grunty's Revenge (Europe)"#, false),
+    "dfb5aba3" => (2, false, r#"Banjo-Kazooie - Grunty's Revenge (Europe, USA)"#, false),
+    "106ed779" => (2, false, r#"Banjo-Kazooie - Grunty's Revenge (Europe, prototype)"#, false),
+    "4e0f10fa" => (2, false, r#"Banjo-Kazooie - Grunty's Revenge (Europe, prototype earlier)"#, false),
+    "67ad698f" => (2, false, r#"Banjo-Kazooie - La Vendetta di Grunty (Italy)"#, false),
+    "32544634" => (2, false, r#"Banjo-Kazooie - La Venganza de Grunty (Spain)"#, false),
+    "116194f2" => (0, false, r#"Barbie - The Princess and the Pauper (Europe)"#, false),
+    "f508a7b7" => (0, false, r#"Barbie - The Princess and the Pauper (USA)"#, false),
+    "5661e71a" => (0, false, r#"Barbie and the Magic of Pegasus (Europe)"#, false),
+    "29d23835" => (0, false, r#"Barbie and the Magic of Pegasus (USA)"#, false),
+    "db6160eb" => (1, false, r#"Barbie as the Island Princess (USA)"#, false),
+    "4e8a650c" => (1, false, r#"The Barbie Diaries - High School Mystery (Europe)"#, false),
+    "5c704cbe" => (1, false, r#"The Barbie Diaries - High School Mystery (USA)"#, false),
+    "749339b2" => (0, false, r#"Barbie - Groovy Games (Europe)"#, false),
+    "aa017567" => (0, false, r#"Barbie - Groovy Games (USA)"#, false),
+    "7c6d9ef3" => (0, false, r#"Barbie Horse Adventures (Europe)"#, false),
+    "ba184025" => (0, false, r#"Barbie Horse Adventures - Blue Ribbon Race (USA)"#, false),
+    "7ca7aa12" => (0, false, r#"Barbie in the 12 Dancing Princesses (Europe)"#, false),
+    "9c0abddb" => (0, false, r#"Barbie in the 12 Dancing Princesses (USA)"#, false),
+    "f2b06467" => (2, false, r#"Barnyard (Europe)"#, false),
+    "ff7987da" => (2, false, r#"Barnyard (USA)"#, false),
+    "339f35b8" => (3, false, r#"Baseball Advance (USA)"#, false),
+    "1326e683" => (2, false, r#"Bass Tsuri Shiyouze! - Tournament wa Senryaku Da! (Japan)"#, false),
+    "ef3790d1" => (0, false, r#"Batman - Rise of Sin Tzu (USA)"#, false),
+    "783ac0c7" => (0, false, r#"Batman - Vengeance (Europe)"#, false),
+    "9fa17935" => (0, false, r#"Batman - Vengeance (USA)"#, false),
+    "83c90106" => (1, false, r#"Batman Begins (Europe, USA)"#, false),
+    "6dce8a26" => (2, false, r#"Battle B-Daman (USA)"#, false),
+    "5789f441" => (2, false, r#"Battle B-Daman - Fire Spirits! (USA)"#, false),
+    "d9516e50" => (3, false, r#"Battle Network Rockman EXE (Japan)"#, false),
+    "98e4f096" => (3, false, r#"Battle Network Rockman EXE 2 (Japan)"#, false),
+    "41576087" => (3, false, r#"Battle Network Rockman EXE 2 (Japan, rev. 1)"#, false),
+    "1c57724e" => (3, false, r#"Battle Network Rockman EXE 3 (Japan)"#, false),
+    "e48e6bc9" => (3, false, r#"Battle Network Rockman EXE 3 (Japan, rev. 1)"#, false),
+    "1f13c41f" => (3, false, r#"Battle Network Rockman EXE 3 Black (Japan, promo)"#, false),
+    "fd57493b" => (3, false, r#"Battle Network Rockman EXE 3 Black (Japan, rev. 1)"#, false),
+    "0c01f3b8" => (1, false, r#"Battle x Battle - Kyodai Gyo Densetsu (Japan)"#, false),
+    "60bfcc8e" => (1, false, r#"BattleBots - Beyond the BattleBox (Europe)"#, false),
+    "985b3704" => (1, false, r#"BattleBots - Beyond the BattleBox (USA)"#, false),
+    "8d5ffbca" => (0, false, r#"BattleBots - Design & Destroy (USA)"#, false),
+    "31480d89" => (0, false, r#"Battletoads (prototype)"#, false),
+    "f6989fbf" => (3, false, r#"BB Ball (Japan)"#, false),
+    "a13760bc" => (3, false, r#"Beast Shooter - Mezase Beast King! (Japan)"#, false),
+    "da747c99" => (1, false, r#"The Bee Game (USA)"#, false),
+    "0363c23f" => (1, false, r#"Die Biene Maja - Klatschmohnwiese in Gefahr (Germany)"#, false),
+    "f87b787b" => (0, false, r#"The Berenstain Bears - Spooky Old Tree (USA)"#, false),
+    "f71761ba" => (2, false, r#"Best Friends - Hunde & Katzen (Germany)"#, false),
+    "61709d3d" => (5, false, r#"Best Play Pro Yakyuu Baseball (Japan)"#, false),
+    "5111df55" => (2, false, r#"Beyblade G-Revolution (Europe)"#, false),
+    "33afdbe8" => (2, false, r#"Beyblade G-Revolution (USA)"#, false),
+    "02cf5e5c" => (2, false, r#"Beyblade V-Force - Ultimate Blader Jam (Europe)"#, false),
+    "4a49272b" => (2, false, r#"Beyblade V-Force - Ultimate Blader Jam (USA)"#, false),
+    "516ceac6" => (0, false, r#"Bibi Blocksberg - Der Magische Hexenkreis (Germany)"#, false),
+    "fd8c06ee" => (1, false, r#"Bibi und Tina - Ferien auf dem Martinshof (Germany, rev. 1)"#, false),
+    "95a19c3c" => (1, false, r#"Bibi und Tina - Ferien auf dem Martinshof (Germany)"#, false),
+    "cee6904e" => (0, false, r#"The Bible Game (USA)"#, false),
+    "c54c02d0" => (1, false, r#"Big Mutha Truckers (Europe)"#, false),
+    "574907f4" => (1, false, r#"Big Mutha Truckers (USA)"#, false),
+    "5412a24d" => (0, false, r#"Bionicle (Europe)"#, false),
+    "c38f3530" => (0, false, r#"Bionicle (USA)"#, false),
+    "daec2264" => (1, false, r#"Bionicle - Matoran Adventures (Europe, USA)"#, false),
+    "9d66ec5e" => (1, false, r#"Bionicle - Maze of Shadows (Europe, rev. 1)"#, false),
+    "bce2d68e" => (1, false, r#"Bionicle - Maze of Shadows (Europe)"#, false),
+    "3fe09c34" => (1, false, r#"Bionicle - Maze of Shadows (USA)"#, false),
+    "b8dc715b" => (1, false, r#"Bionicle Heroes (Europe)"#, false),
+    "f4fafccf" => (1, false, r#"Bionicle Heroes (USA)"#, false),
+    "904b55d4" => (3, false, r#"bit Generations - Boundish (Japan)"#, false),
+    "a04be1ed" => (3, false, r#"bit Generations - Coloris (Japan)"#, false),
+    "dd58aecd" => (0, false, r#"Commandos 2 (USA, prototype, 20021128)"#, false),
+    "812ef3a9" => (3, false, r#"bit Generations - Dialhex (Japan)"#, false),
+    "0867dc29" => (3, false, r#"bit Generations - Digidrive (Japan)"#, false),
+    "f0760400" => (3, false, r#"bit Generations - Dotstream (Japan)"#, false),
+    "d5d43b4b" => (3, false, r#"bit Generations - Orbital (Japan)"#, false),
+    "b32e0839" => (3, false, r#"bit Generations - Soundvoyager (Japan)"#, false),
+    "72a55741" => (1, false, r#"Black Belt Challenge (Europe)"#, false),
+    "05a4303e" => (3, false, r#"Black Black - Bura Bura (Japan)"#, false),
+    "2b2afa2e" => (2, false, r#"Black Matrix Zero (Japan)"#, false),
+    "e1d36a8c" => (1, false, r#"Blackthorne (Europe)"#, false),
+    "8e6dcd53" => (1, false, r#"Blackthorne (USA)"#, false),
+    "2ccd4915" => (0, false, r#"Blades of Thunder (USA)"#, false),
+    "9de5cd08" => (4, false, r#"Bleach Advance - Kurenai ni Somaru Soul Society (Japan)"#, false),
+    "9a91d7f1" => (3, false, r#"Blender Bros. (USA)"#, false),
+    "0cec865b" => (0, false, r#"Blue Angelo - Angels from the Shrine (Europe, prototype 1)"#, false),
+    "f1b924dc" => (0, false, r#"Blue Angelo - Angels from the Shrine (Europe, prototype 2)"#, false),
+    "b6d79476" => (1, false, r#"BMX Trick Racer (USA)"#, false),
+    "e5c745d8" => (0, false, r#"Board Game Classics (Europe)"#, false),
+    "69d760bb" => (0, false, r#"Board Game Classics (USA)"#, false),
+    "0bd9700b" => (3, false, r#"Boboboubo Boubobo - 9 Kyoku Senshi Gag Yuugou (Japan)"#, false),
+    "166feecb" => (3, false, r#"Boboboubo Boubobo - Bakutou Hajike Taisen (Japan)"#, false),
+    "37938ca4" => (3, false, r#"Boboboubo Boubobo - Maji de!!? Shinken Battle (Japan)"#, false),
+    "58105f89" => (3, false, r#"Boboboubo Boubobo - Ougi 87.5 Bakuretsu Hanage Shinken (Japan)"#, false),
+    "9686c36b" => (0, true, r#"Boktai - The Sun Is in Your Hand (Europe)"#, false),
+    "cf692572" => (0, false, r#"Boktai - The Sun Is in Your Hand (USA, demo)"#, false),
+    "e715ac45" => (0, true, r#"Boktai - The Sun Is in Your Hand (USA)"#, false),
+    "748189b5" => (0, true, r#"Boktai 2 - Solar Boy Django (Europe)"#, false),
+    "e1ffb2d1" => (0, true, r#"Boktai 2 - Solar Boy Django (USA)"#, false),
+    "5e3b163e" => (3, false, r#"Boku wa Koukuu Kanseikan (Japan, rev. 1)"#, false),
+    "09185657" => (3, false, r#"Bokujou Monogatari - Mineral Town no Nakama-tachi (Japan)"#, false),
+    "7c26672f" => (3, false, r#"Bokujou Monogatari - Mineral Town no Nakama-tachi for Girl (Japan)"#, false),
+    "a09c0807" => (0, true, r#"Bokura no Taiyou - Taiyou Action RPG (Japan)"#, false),
+    "70c423b8" => (3, false, r#"Bomberman Jetters - Densetsu no Bomberman (Japan)"#, false),
+    "b9dea90d" => (1, false, r#"Bomberman Jetters - Game Collection (Japan)"#, false),
+    "09fc4d53" => (2, false, r#"Bomberman Max 2 - Blue Advance (Europe)"#, false),
+    "94c620f3" => (2, false, r#"Bomberman Max 2 - Blue Advance (USA)"#, false),
+    "656cf22e" => (2, false, r#"Bomberman Max 2 - Bomberman Version (Japan)"#, false),
+    "290d8810" => (2, false, r#"Bomberman Max 2 - Max Version (Japan)"#, false),
+    "67d2e5b8" => (2, false, r#"Bomberman Max 2 - Red Advance (Europe)"#, false),
+    "4db517c1" => (2, false, r#"Bomberman Max 2 - Red Advance (USA)"#, false),
+    "f7f40189" => (3, false, r#"Bomberman Story (Japan)"#, false),
+    "240282e6" => (3, false, r#"Bomberman Tournament (Europe, USA)"#, false),
+    "4d540384" => (1, false, r#"Bookworm (USA)"#, false),
+    "57438e39" => (3, false, r#"Bouken Yuuki Pluster World - Densetsu no Plust Gate (Japan)"#, false),
+    "3f60bf6a" => (3, false, r#"Bouken Yuuki Pluster World - Densetsu no Plust Gate EX (Japan)"#, false),
+    "ce7a07f0" => (3, false, r#"Bouken Yuuki Pluster World - Pluston GP (Japan)"#, false),
+    "1cc42ebc" => (2, false, r#"Bouken-ou Beet - Busters Road (Japan)"#, false),
+    "128edc4f" => (1, false, r#"Boukyaku no Senritsu - The Melody of Oblivion (Japan)"#, false),
+    "d41866a9" => (1, false, r#"Boulder Dash EX (Europe)"#, false),
+    "e77d5f9b" => (1, false, r#"Boulder Dash EX (Japan)"#, false),
+    "b355365a" => (1, false, r#"Boulder Dash EX (USA)"#, false),
+    "08a4fdc4" => (0, false, r#"Boxing Fever (Europe, USA)"#, false),
+    "b30241e2" => (1, false, r#"Bratz (Europe)"#, false),
+    "21e5a010" => (1, false, r#"Bratz (USA)"#, false),
+    "08b89540" => (0, false, r#"Bratz - Babyz (Europe)"#, false),
+    "23e8a494" => (0, false, r#"Bratz - Babyz (USA)"#, false),
+    "db179296" => (2, false, r#"Bratz - Forever Diamondz (Europe)"#, false),
+    "f39cadeb" => (2, false, r#"Bratz - Forever Diamondz (Germany)"#, false),
+    "9e389908" => (2, false, r#"Bratz - Forever Diamondz (USA)"#, false),
+    "8fc8d6d2" => (1, false, r#"Bratz - Rock Angelz (France)"#, false),
+    "649588cb" => (1, false, r#"Bratz - Rock Angelz (Germany)"#, false),
+    "cfff9119" => (1, false, r#"Bratz - Rock Angelz (Spain)"#, false),
+    "aa245d0c" => (1, false, r#"Bratz - Rock Angelz (Europe, USA)"#, false),
+    "4386e26c" => (0, false, r#"Bratz - The Movie (Europe, Italian / Spanish)"#, false),
+    "cd485f79" => (0, false, r#"Bratz - The Movie (Europe)"#, false),
+    "04f21321" => (0, false, r#"Bratz - The Movie (Germany)"#, false),
+    "2ef801c0" => (0, false, r#"Bratz - The Movie (USA)"#, false),
+    "d3660549" => (3, false, r#"Breath of Fire (Europe, English / French / German)"#, false),
+    "a1c3165d" => (3, false, r#"Breath of Fire (Europe, English)"#, false),
+    "f06422a8" => (3, false, r#"Breath of Fire (USA)"#, false),
+    "66e96b35" => (3, false, r#"Breath of Fire - Ryuu no Senshi (Japan)"#, false),
+    "ff5e8e39" => (3, false, r#"Breath of Fire II (Europe)"#, false),
+    "6f098da3" => (3, false, r#"Breath of Fire II (USA)"#, false),
+    "8a824a70" => (3, false, r#"Breath of Fire II - Shimei no Ko (Japan)"#, false),
+    "cfe69825" => (0, false, r#"Britney's Dance Beat (Europe, English / German)"#, false),
+    "021cda8e" => (0, false, r#"Britney's Dance Beat (Europe, English / French)"#, false),
+    "886121a8" => (0, false, r#"Britney's Dance Beat (Europe)"#, false),
+    "443198d8" => (0, false, r#"Britney's Dance Beat (USA)"#, false),
+    "3212c09b" => (1, false, r#"Broken Circle (Europe)"#, false),
+    "e78bc690" => (1, false, r#"Broken Circle (Europe, prototype)"#, false),
+    "3a23efd7" => (2, false, r#"Broken Sword - The Shadow of the Templars (Europe)"#, false),
+    "3278ce51" => (2, false, r#"Broken Sword - The Shadow of the Templars (USA)"#, false),
+    "fd814097" => (1, false, r#"Disney's Brother Bear (Europe, French / German / Italian / Spanish / Dutch /
+			Danish / Swedish)"#, false),
+    "6220a4b8" => (1, false, r#"Disney's Brother Bear (Europe, English)"#, false),
+    "342de1d6" => (1, false, r#"Brother Bear (USA)"#, false),
+    "b5ff39dc" => (1, false, r#"Bruce Lee - Return of the Legend (Europe)"#, false),
+    "b2a45a4c" => (1, false, r#"Bruce Lee - Return of the Legend (USA)"#, false),
+    "dfa1a201" => (1, false, r#"Bubble Bobble - Old & New (Europe)"#, false),
+    "be9fd5e7" => (1, false, r#"Bubble Bobble - Old & New (Japan)"#, false),
+    "feec503c" => (1, false, r#"Bubble Bobble - Old & New (USA)"#, false),
+    "4d2a1d88" => (1, false, r#"Buffy - Im Bann der Dämonen (Germany)"#, false),
+    "339c7586" => (1, false, r#"Buffy contre les Vampires (France)"#, false),
+    "f5c85beb" => (1, false, r#"Buffy the Vampire Slayer (Europe, USA)"#, false),
+    "89b09753" => (3, false, r#"Bura Bura Donkey (Japan)"#, false),
+    "7cff011a" => (0, false, r#"Butt-Ugly Martians - B.K.M. Battles (Europe)"#, false),
+    "74be9148" => (0, false, r#"Butt-Ugly Martians - B.K.M. Battles (USA)"#, false),
+    "ed2eb57d" => (1, false, r#"Cabbage Patch Kids - The Patch Puppy Rescue (Europe)"#, false),
+    "c812a494" => (1, false, r#"Cabbage Patch Kids - The Patch Puppy Rescue (USA)"#, false),
+    "70a8a141" => (0, false, r#"Cabela's Big Game Hunter (USA)"#, false),
+    "bd054567" => (1, false, r#"Cabela's Big Game Hunter - 2005 Adventures (Europe, USA)"#, false),
+    "13dc0731" => (0, false, r#"Caesars Palace Advance - Millennium Gold Edition (USA, prototype)"#, false),
+    "5d54ece5" => (0, false, r#"Caesars Palace Advance - Millennium Gold Edition (Europe, USA)"#, false),
+    "498443da" => (5, false, r#"Calciobit (Japan)"#, false),
+    "37de9a3d" => (1, false, r#"Camp Lazlo - Leaky Lake Games (Europe)"#, false),
+    "40c2894e" => (1, false, r#"Camp Lazlo - Leaky Lake Games (USA)"#, false),
+    "0bb2d391" => (0, false, r#"Capcom Classics Mini Mix (USA)"#, false),
+    "5341b274" => (3, false, r#"Captain Tsubasa - Eikou no Kiseki (Japan)"#, false),
+    "176da49f" => (0, false, r#"Captain Harlock (Europe, prototype demo)"#, false),
+    "ea0a1b94" => (2, false, r#"Car Battler Joe (USA)"#, false),
+    "3d5a2c07" => (0, false, r#"Card Party (Japan)"#, false),
+    "dbc9cd2e" => (2, false, r#"Cardcaptor Sakura - Sakura Card de Mini Game (Japan)"#, false),
+    "d5bb189c" => (1, false, r#"Cardcaptor Sakura - Sakura Card Hen - Sakura to Card to Otomodachi (Japan)"#, false),
+    "6111ed1e" => (1, false, r#"Care Bears - The Care Quests (Europe)"#, false),
+    "f848c327" => (1, false, r#"Care Bears - The Care Quests (USA)"#, false),
+    "98d72420" => (1, false, r#"Carrera Power Slide (Europe)"#, false),
+    "0b058db2" => (1, false, r#"Disney/Pixar Cars (Europe, Spanish / Portuguese)"#, false),
+    "bd1bfc39" => (1, false, r#"Cars (Europe, French / Dutch)"#, false),
+    "4f21fd49" => (1, false, r#"Cars (Europe, Danish / Finnish / Norwegian / Swedish)"#, false),
+    "ebd92f43" => (1, false, r#"Cars (Germany)"#, false),
+    "6d4de0ee" => (1, false, r#"Disney Pixar Cars (Japan)"#, false),
+    "107639a8" => (1, false, r#"Cars (Europe, USA)"#, false),
+    "d3db9787" => (0, false, r#"Disney/Pixar Cars - Mater-National Championship (Europe)"#, false),
+    "189da289" => (0, false, r#"Disney Pixar Cars - Mater-National Championship (USA)"#, false),
+    "23cb1a36" => (1, false, r#"Cars - Motori Ruggenti (Italy)"#, false),
+    "edc0ccee" => (0, false, r#"Cartoon Network Block Party (USA)"#, false),
+    "066a2705" => (0, false, r#"Cartoon Network Speedway (USA)"#, false),
+    "ddaf8bac" => (0, false, r#"Casper (Europe)"#, false),
+    "9f905e60" => (0, false, r#"Casper (USA)"#, false),
+    "9542e247" => (0, false, r#"Chokkan Hitofude Advance (Japan, prototype)"#, false),
+    "611535dc" => (3, false, r#"Castlevania (Europe)"#, false),
+    "284e3092" => (3, false, r#"Castlevania - Akatsuki no Minuet (Japan)"#, false),
+    "d0c91f74" => (3, false, r#"Castlevania - Aria of Sorrow (Europe)"#, false),
+    "35536183" => (3, false, r#"Castlevania - Aria of Sorrow (USA)"#, false),
+    "379b3248" => (3, false, r#"Castlevania - Byakuya no Concerto (Japan)"#, false),
+    "1cc059a4" => (3, false, r#"Castlevania - Circle of the Moon (USA)"#, false),
+    "521b3091" => (3, false, r#"Castlevania - Harmony of Dissonance (Europe)"#, false),
+    "88c1b562" => (3, false, r#"Castlevania - Harmony of Dissonance (USA)"#, false),
+    "11a9b76e" => (0, false, r#"Castleween (Europe)"#, false),
+    "13c2249e" => (0, false, r#"The Cat in the Hat by Dr. Seuss (USA)"#, false),
+    "1adb373d" => (1, false, r#"Catwoman (Europe, USA)"#, false),
+    "c3aa382d" => (2, false, r#"Catz (Europe, English / French / German / Italian)"#, false),
+    "ce418ed1" => (2, false, r#"Catz (Europe, USA)"#, false),
+    "14a87b75" => (1, false, r#"Chaoji Maliou 2 (China)"#, false),
+    "d97d4156" => (2, false, r#"Chaoji Maliou Shijie (China)"#, false),
+    "2b3fcc74" => (1, false, r#"Charlie and the Chocolate Factory (Europe)"#, false),
+    "4e90884d" => (1, false, r#"Charlie and the Chocolate Factory (USA)"#, false),
+    "8658bb4f" => (1, false, r#"Charlotte's Web (Europe)"#, false),
+    "ba511fb2" => (1, false, r#"Charlotte's Web (USA)"#, false),
+    "e2efc2aa" => (1, false, r#"The Cheetah Girls (USA)"#, false),
+    "27e7fd09" => (1, false, r#"Chessmaster (Europe)"#, false),
+    "55ef8392" => (1, false, r#"Chessmaster (France)"#, false),
+    "06abacc5" => (1, false, r#"Chessmaster (Germany)"#, false),
+    "25b0e933" => (1, false, r#"Chessmaster (USA)"#, false),
+    "dcbd4991" => (1, false, r#"Chicken Little (Japan)"#, false),
+    "1e86f5b0" => (1, false, r#"Chicken Little (Europe, USA)"#, false),
+    "5749fdc9" => (0, false, r#"Chicken Shoot (Europe)"#, false),
+    "4b22e081" => (0, false, r#"Chicken Shoot (USA)"#, false),
+    "02d12544" => (0, false, r#"Chicken Shoot 2 (Europe)"#, false),
+    "1dfe38c2" => (0, false, r#"Chicken Shoot 2 (USA)"#, false),
+    "2c00b4e6" => (3, false, r#"Chinmoku no Iseki - Estpolis Gaiden (Japan)"#, false),
+    "ddc4b118" => (1, false, r#"Chobits for Game Boy Advance - Atashi Dake no Hito (Japan)"#, false),
+    "d4f8152e" => (3, false, r#"Chocobo Land - A Game of Dice (Japan)"#, false),
+    "93fe0e34" => (3, false, r#"Choro Q Advance (Japan)"#, false),
+    "f5ed5f14" => (3, false, r#"Choro Q Advance 2 (Japan)"#, false),
+    "a4f8b4b4" => (1, false, r#"Chou Makaimura R (Japan)"#, false),
+    "7e198522" => (1, false, r#"The Chronicles of Narnia - The Lion, the Witch and the Wardrobe (Europe, USA)"#, false),
+    "8ab607c0" => (5, false, r#"ChuChu Rocket! (Europe)"#, false),
+    "2c8c1b5a" => (5, false, r#"ChuChu Rocket! (Japan)"#, false),
+    "057fb1b6" => (5, false, r#"ChuChu Rocket! (USA)"#, false),
+    "ebd928ed" => (2, false, r#"CIMA - The Enemy (USA)"#, false),
+    "e8bf28e2" => (1, false, r#"Disney's Cinderella - Magical Dreams (Europe)"#, false),
+    "22f19169" => (1, false, r#"Disney's Cinderella - Magical Dreams (Europe, USA)"#, false),
+    "8376d53b" => (1, false, r#"Cinnamoroll - Koko ni Iru yo (Japan)"#, false),
+    "5a2cada1" => (1, false, r#"Cinnamon Yume no Daibouken (Japan)"#, false),
+    "a1cba145" => (1, false, r#"Cinnamon Fuwafuwa Daibouken (Japan)"#, false),
+    "cd67f2ba" => (1, false, r#"Classic NES Series - Bomberman (Europe, USA)"#, false),
+    "2b510046" => (1, false, r#"NES Classics - Castlevania (Europe)"#, false),
+    "cfc1f558" => (1, false, r#"Classic NES Series - Castlevania (USA)"#, false),
+    "ba82d416" => (1, false, r#"Classic NES Series - Donkey Kong (Europe, USA)"#, false),
+    "ca9f5d2f" => (1, false, r#"Classic NES Series - Dr. Mario (Europe, USA)"#, false),
+    "945c292f" => (2, false, r#"Classic NES Series - Excitebike (Europe, USA)"#, false),
+    "3126d612" => (1, false, r#"Classic NES Series - Ice Climber (Europe, USA)"#, false),
+    "68b1b7a8" => (2, false, r#"Classic NES Series - The Legend of Zelda (Europe, USA)"#, false),
+    "b2a153c3" => (1, false, r#"Classic NES Series - Metroid (Europe, USA)"#, false),
+    "231f80d6" => (1, false, r#"Classic NES Series - Pac-Man (Europe, USA)"#, false),
+    "7eddffad" => (1, false, r#"Classic NES Series - Super Mario Bros. (Europe, USA)"#, false),
+    "9e923716" => (1, false, r#"Classic NES Series - Xevious (Europe, USA)"#, false),
+    "f2dc3b09" => (2, false, r#"Classic NES Series - Zelda II - The Adventure of Link (Europe, USA)"#, false),
+    "dab7c402" => (0, false, r#"Cocoto - Kart Racer (Europe)"#, false),
+    "27411ef5" => (0, false, r#"Cocoto - Platform Jumper (Europe)"#, false),
+    "420a81b7" => (0, false, r#"Codename - Kids Next Door - Operation S.O.D.A. (USA)"#, false),
+    "70520c5c" => (1, false, r#"Colin McRae Rally 2.0 (Europe)"#, false),
+    "a2e19014" => (1, false, r#"Colin McRae Rally 2.0 (USA)"#, false),
+    "330a0975" => (1, false, r#"Columns Crown (Europe)"#, false),
+    "a592f7df" => (1, false, r#"Columns Crown (Japan)"#, false),
+    "6242ffb2" => (1, false, r#"Columns Crown (USA)"#, false),
+    "4e5bfa90" => (3, false, r#"Combat Choro Q - Advance Daisakusen (Japan)"#, false),
+    "9f30002f" => (1, false, r#"Comix Zone (Europe)"#, false),
+    "5f443b14" => (0, false, r#"Contra Advance - The Alien Wars EX (Europe)"#, false),
+    "a817aa47" => (0, false, r#"Contra Hard Spirits (Japan)"#, false),
+    "59781c54" => (0, false, r#"Contra Advance - The Alien Wars EX (USA)"#, false),
+    "dd1499a0" => (0, false, r#"Contra Advance (prototype)"#, false),
+    "a4c6db01" => (1, false, r#"Corvette (USA)"#, false),
+    "034d2d4b" => (1, false, r#"Crash Bandicoot - The Huge Adventure (USA)"#, false),
+    "0f1d06a0" => (1, false, r#"Crash Bandicoot 2 - N-Tranced (Europe)"#, false),
+    "2e16184a" => (1, false, r#"Crash Bandicoot 2 - N-Tranced (USA)"#, false),
+    "64767b34" => (1, false, r#"Crash Bandicoot Advance (Japan)"#, false),
+    "0e6a4fee" => (1, false, r#"Crash Bandicoot Advance - Wakuwaku Tomodachi Daisakusen! (Japan)"#, false),
+    "04fba7cf" => (1, false, r#"Crash Bandicoot Advance 2 - Guruguru Saimin Dai-panic!? (Japan)"#, false),
+    "70f8291f" => (1, false, r#"Crash Bandicoot Bakusou Nitro Cart (Japan)"#, false),
+    "f24c8e77" => (1, false, r#"Crash Bandicoot Fusion (Europe)"#, false),
+    "3ddf07a1" => (1, false, r#"Crash Bandicoot Purple - Ripto's Rampage (USA, rev. A)"#, false),
+    "fc284e05" => (1, false, r#"Crash Bandicoot Purple - Ripto's Rampage (USA)"#, false),
+    "ccfd654f" => (1, false, r#"Crash Bandicoot XS (Europe)"#, false),
+    "03925772" => (1, false, r#"Crash Nitro Kart (Europe)"#, false),
+    "e6d58aa0" => (1, false, r#"Crash Nitro Kart (USA)"#, false),
+    "d1d07fa6" => (1, false, r#"Crash of the Titans (Europe)"#, false),
+    "6f28b009" => (1, false, r#"Crash of the Titans (USA)"#, false),
+    "6e936a54" => (2, false, r#"Crayon Shin-chan - Arashi o Yobu Cinemaland no Daibouken! (Japan)"#, false),
+    "45c84466" => (2, false, r#"Crayon Shin-chan - Densetsu o Yobu Omake no Miyako Shockgaan! (Japan)"#, false),
+    "ff7e2e9b" => (1, false, r#"Crazy Chase (Europe, prototype early)"#, false),
+    "5f331132" => (1, false, r#"Crazy Chase (Europe, prototype)"#, false),
+    "313f4ffc" => (1, false, r#"Crazy Chase (Europe)"#, false),
+    "caba1db9" => (1, false, r#"Crazy Chase (USA)"#, false),
+    "2723236b" => (1, false, r#"Crazy Frog Racer (Europe)"#, false),
+    "4aebd704" => (1, false, r#"Crazy Taxi - Catch a Ride (Europe)"#, false),
+    "8ec709d6" => (1, false, r#"Crazy Taxi - Catch a Ride (USA)"#, false),
+    "4f18f57e" => (2, false, r#"Creatures (Europe, English / Italian / Spanish)"#, false),
+    "68206301" => (2, false, r#"Creatures (Europe, English / French / German)"#, false),
+    "a2aad079" => (3, false, r#"Croket! - Yume no Banker Survival! (Japan)"#, false),
+    "8d5e08d5" => (3, false, r#"Croket! 2 - Yami no Bank to Banqueen (Japan)"#, false),
+    "aec6d07b" => (3, false, r#"Croket! 3 - Granu Oukoku no Nazo (Japan)"#, false),
+    "c86bf751" => (3, false, r#"Croket! 4 - Bank no Mori no Mamorigami (Japan)"#, false),
+    "f0e81971" => (3, false, r#"Croket! Great - Toki no Boukensha (Japan)"#, false),
+    "b9a75ac4" => (0, false, r#"Crouching Tiger, Hidden Dragon (Europe)"#, false),
+    "204ff8d5" => (0, false, r#"Crouching Tiger, Hidden Dragon (USA, prototype)"#, false),
+    "579ba507" => (0, false, r#"Crouching Tiger, Hidden Dragon (USA)"#, false),
+    "5436d5da" => (0, false, r#"Cruis'n Velocity (USA, prototype)"#, false),
+    "adf14db5" => (0, false, r#"Cruis'n Velocity (Europe, USA)"#, false),
+    "55fe8579" => (1, false, r#"Crushed Baseball (USA)"#, false),
+    "40a0e1c9" => (0, false, r#"CT Special Forces (Europe, prototype)"#, false),
+    "a20d9adb" => (0, false, r#"CT Special Forces (Europe)"#, false),
+    "aea22ae0" => (0, false, r#"CT Special Forces (USA)"#, false),
+    "863cbf31" => (0, false, r#"CT Special Forces - Back to Hell (Europe)"#, false),
+    "7dc40eda" => (0, false, r#"CT Special Forces - Bioterror (Europe)"#, false),
+    "d2b58a33" => (0, false, r#"CT Special Forces 2 - Back in the Trenches (USA)"#, false),
+    "4171c811" => (1, false, r#"Cubix - Robots for Everyone - Clash 'N Bash (USA)"#, false),
+    "eef104b0" => (0, false, r#"Curious George (Europe)"#, false),
+    "f6b549dd" => (0, false, r#"Curious George (USA)"#, false),
+    "f533dc13" => (2, false, r#"Custom Robo GX (Japan)"#, false),
+    "0020a2ed" => (3, false, r#"Cyberdrive Zoids - Kijuu no Senshi Hyuu (Japan)"#, false),
+    "c6ccca05" => (1, false, r#"Dai-mahjong. (Japan)"#, false),
+    "3d644946" => (5, false, r#"Daisenryaku for Game Boy Advance (Japan)"#, false),
+    "cbb8cdb8" => (3, false, r#"Daisuki Teddy (Japan)"#, false),
+    "0f4b50ae" => (3, false, r#"Dan Doh!! Tobase Shouri no Smile Shot (Japan)"#, false),
+    "e1d5cff1" => (3, false, r#"Dan Doh!! Xi (Japan)"#, false),
+    "e2316d47" => (1, false, r#"Dancing Sword - Senkou (Japan)"#, false),
+    "506780d1" => (1, false, r#"Danny Phantom - The Ultimate Enemy (Europe, English / German / Spanish)"#, false),
+    "e1de905f" => (1, false, r#"Danny Phantom - The Ultimate Enemy (Europe, English / French / Dutch)"#, false),
+    "1384ecea" => (1, false, r#"Danny Phantom - The Ultimate Enemy (USA)"#, false),
+    "c22e7a2f" => (1, false, r#"Danny Phantom - Urban Jungle (USA)"#, false),
+    "2d59c1d5" => (1, false, r#"Danny Phantom - Dschungelstadt (Germany)"#, false),
+    "41a9b849" => (0, false, r#"Daredevil (Europe)"#, false),
+    "4bb9420e" => (0, false, r#"Daredevil (Germany)"#, false),
+    "d438347e" => (0, false, r#"Daredevil (USA)"#, false),
+    "9cf479d2" => (0, false, r#"Dark Eden (demo)"#, false),
+    "51beeed7" => (1, false, r#"Darius R (Japan)"#, false),
+    "de5dcdee" => (0, false, r#"Dark Arena (Europe, USA)"#, false),
+    "8e39fee9" => (2, false, r#"Dave Mirra Freestyle BMX 2 (Europe, rev. 1)"#, false),
+    "69e89581" => (2, false, r#"Dave Mirra Freestyle BMX 2 (Europe)"#, false),
+    "64b1e921" => (2, false, r#"Dave Mirra Freestyle BMX 2 (USA)"#, false),
+    "8d8f26a5" => (2, false, r#"Dave Mirra Freestyle BMX 3 (Europe, USA)"#, false),
+    "e9a34f49" => (1, false, r#"David Beckham Soccer (Europe)"#, false),
+    "7e4db1cc" => (1, false, r#"David Beckham Soccer (USA)"#, false),
+    "4c7375f4" => (1, false, r#"Davis Cup (Europe)"#, false),
+    "82b4d89c" => (1, false, r#"Davis Cup (USA)"#, false),
+    "2994de1d" => (0, false, r#"Dead to Rights (Europe)"#, false),
+    "52c1826c" => (0, false, r#"Dead to Rights (USA)"#, false),
+    "fe7c8402" => (1, false, r#"Deadly Skies (Europe)"#, false),
+    "b6c00edb" => (0, false, r#"Deal or No Deal (USA)"#, false),
+    "2e271c13" => (1, false, r#"Defender (USA)"#, false),
+    "3b86fdc4" => (1, false, r#"Defender (Europe)"#, false),
+    "6968959a" => (1, false, r#"Defender of the Crown (Europe)"#, false),
+    "de6698fe" => (1, false, r#"Defender of the Crown (USA)"#, false),
+    "1bab58bd" => (3, false, r#"DemiKids - Dark Version (USA)"#, false),
+    "dc4357c4" => (3, false, r#"DemiKids - Light Version (USA)"#, false),
+    "1cc36766" => (0, false, r#"Demon Driver - Time to Burn Rubber! (Europe)"#, false),
+    "9374af5e" => (0, false, r#"Demon Driver - Time to Burn Rubber! (USA)"#, false),
+    "134be954" => (0, false, r#"Demon's Crest (prototype)"#, false),
+    "6144ec15" => (1, false, r#"Denki Blocks! (Europe)"#, false),
+    "0053fd6a" => (1, false, r#"Denki Blocks! (Japan)"#, false),
+    "e064107f" => (1, false, r#"Denki Blocks! (USA)"#, false),
+    "fae94c8b" => (3, false, r#"Densetsu no Stafy (Japan)"#, false),
+    "ea73eb54" => (3, false, r#"Densetsu no Stafy 2 (Japan)"#, false),
+    "fcaf1aa8" => (3, false, r#"Densetsu no Stafy 3 (Japan)"#, false),
+    "2d6e4c3b" => (3, false, r#"Densetsu no Stafy 3 (Japan, rev. 1)"#, false),
+    "9746ef12" => (5, false, r#"Derby Stallion Advance (Japan)"#, false),
+    "cc1c1405" => (0, false, r#"Desert Strike Advance (USA)"#, false),
+    "51f50f7d" => (0, false, r#"Deutschland Sucht den Superstar (Germany)"#, false),
+    "f5436f5d" => (0, false, r#"Dexter's Laboratory - Chess Challenge (Europe)"#, false),
+    "ae61322e" => (0, false, r#"Dexter's Laboratory - Chess Challenge (USA)"#, false),
+    "3e8012bf" => (1, false, r#"Dexter's Laboratory - Deesaster Strikes! (Europe)"#, false),
+    "6bf168e6" => (1, false, r#"Dexter's Laboratory - Deesaster Strikes! (USA, rev. 1)"#, false),
+    "e948d412" => (1, false, r#"Dexter's Laboratory - Deesaster Strikes! (USA)"#, false),
+    "a1288427" => (3, false, r#"Di Gi Charat - DigiCommunication (Japan)"#, false),
+    "a2d6cea6" => (2, false, r#"Diadroids World - Evil Teikoku no Yabou (Japan)"#, false),
+    "057dc388" => (0, false, r#"Diddy Kong Pilot (prototype)"#, false),
+    "1372e829" => (2, false, r#"DigiCommunication 2 - Datou! Black Gemagema Dan (Japan)"#, false),
+    "ea5fc3da" => (1, false, r#"Digimon - Battle Spirit (Europe)"#, false),
+    "ceef2008" => (1, false, r#"Digimon - Battle Spirit (USA)"#, false),
+    "00c906a3" => (1, false, r#"Digimon - Battle Spirit 2 (Europe)"#, false),
+    "c4c246ef" => (1, false, r#"Digimon - Battle Spirit 2 (USA)"#, false),
+    "4f1b0157" => (1, false, r#"Digimon Racing (Europe)"#, false),
+    "c50468a8" => (1, false, r#"Digimon Racing (Japan)"#, false),
+    "dadd4c10" => (1, false, r#"Digimon Racing (USA)"#, false),
+    "7bd01b31" => (1, false, r#"Dinotopia - The Timestone Pirates (Europe, USA)"#, false),
+    "4a2b9e0b" => (0, false, r#"Disney Princess (Europe, USA)"#, false),
+    "3944bd7e" => (0, false, r#"Disney Princesse (France)"#, false),
+    "e21b4285" => (0, false, r#"Disneys Prinzessinnen (Germany)"#, false),
+    "481e668e" => (0, false, r#"Disney Principesse (Italy)"#, false),
+    "425785c8" => (0, false, r#"Disney Princesas (Spain)"#, false),
+    "92c695cc" => (1, false, r#"Disney Princess - Royal Adventure (Europe)"#, false),
+    "c6ad2251" => (1, false, r#"Disney Princess - Royal Adventure (USA)"#, false),
+    "d6d89f81" => (1, false, r#"Disney Sports - Basketball (Europe)"#, false),
+    "3911b4b6" => (1, false, r#"Disney Sports - Basketball (Japan)"#, false),
+    "80ec6cf0" => (1, false, r#"Disney Sports - Basketball (USA)"#, false),
+    "01413db1" => (1, false, r#"Disney Sports - Football (USA)"#, false),
+    "b92e9057" => (1, false, r#"Disney Sports - American Football (Japan)"#, false),
+    "72dcb4f4" => (1, false, r#"Disney Sports - Motocross (Europe)"#, false),
+    "1a62d76f" => (1, false, r#"Disney Sports - Motocross (Japan)"#, false),
+    "a771ad79" => (1, false, r#"Disney Sports - Motocross (USA)"#, false),
+    "10c05c7b" => (1, false, r#"Disney Sports - Skateboarding (Europe)"#, false),
+    "5df4a476" => (1, false, r#"Disney Sports - Skateboarding (Japan)"#, false),
+    "37ffd837" => (1, false, r#"Disney Sports - Skateboarding (USA)"#, false),
+    "77b7fcce" => (1, false, r#"Disney Sports - Snowboarding (Europe)"#, false),
+    "bdc23a47" => (1, false, r#"Disney Sports - Snowboarding (Japan)"#, false),
+    "4733693c" => (1, false, r#"Disney Sports - Snowboarding (USA)"#, false),
+    "33f73199" => (1, false, r#"Disney Sports - Football (Europe)"#, false),
+    "680957e7" => (1, false, r#"Disney Sports - Soccer (Japan)"#, false),
+    "2f1316d2" => (1, false, r#"Disney Sports - Soccer (USA)"#, false),
+    "6bb63774" => (1, false, r#"Disney's Party (Europe, USA)"#, false),
+    "f93b73d9" => (3, false, r#"DK - King of Swing (Europe)"#, false),
+    "049626d1" => (3, false, r#"DK - King of Swing (USA, demo Kiosk)"#, false),
+    "d610b239" => (3, false, r#"DK - King of Swing (USA, Australia)"#, false),
+    "aa8dea30" => (1, false, r#"Dogz (Europe, English)"#, false),
+    "10ac14f9" => (1, false, r#"Dogz (France)"#, false),
+    "2216fbd6" => (1, false, r#"Dogz (Europe, English / French / German / Italian)"#, false),
+    "124f27c0" => (1, false, r#"Dogz (USA)"#, false),
+    "5236473d" => (2, false, r#"Dogz - Fashion (Europe)"#, false),
+    "63bd3463" => (2, false, r#"Dogz - Fashion (USA)"#, false),
+    "28163523" => (2, false, r#"Dogz 2 (Europe, English)"#, false),
+    "41ce1eed" => (2, false, r#"Dogz 2 (Europe, English / French / German / Italian)"#, false),
+    "0e2aca9e" => (2, false, r#"Dogz 2 (USA, rev. 1)"#, false),
+    "654df50e" => (3, false, r#"Dokapon (Europe)"#, false),
+    "5617e407" => (3, false, r#"Dokapon (USA)"#, false),
+    "45b3edc4" => (3, false, r#"Dokapon Q - Monster Hunter! (Japan)"#, false),
+    "fe198026" => (2, false, r#"Dokidoki Cooking Series 1 - Komugi-chan no Happy Cake (Japan)"#, false),
+    "08dfc956" => (2, false, r#"Dokidoki Cooking Series 2 - Gourmet Kitchen - Suteki na Obentou (Japan)"#, false),
+    "a7279ed5" => (3, false, r#"Dokodemo Taikyoku - Yakuman Advance (Japan, rev. 1)"#, false),
+    "c2424edd" => (3, false, r#"Dokodemo Taikyoku - Yakuman Advance (Japan)"#, false),
+    "e743c611" => (3, false, r#"Domo-kun no Fushigi Television (Japan)"#, false),
+    "4c086a5e" => (0, false, r#"Disney's Donald Duck "Adv@nce"!*# (Europe)"#, false),
+    "0195de8f" => (0, false, r#"Disney's Donald Duck Advance (Japan)"#, false),
+    "936daab2" => (0, false, r#"Disney's Donald Duck "Adv@nce"!*# (USA)"#, false),
+    "500922e8" => (1, false, r#"Donchan Puzzle Hanabi de Dohn Advance (Japan)"#, false),
+    "41d277fe" => (1, false, r#"Donkey Kong Country (Europe)"#, false),
+    "12f7a968" => (1, false, r#"Donkey Kong Country (USA)"#, false),
+    "aef11223" => (2, false, r#"Donkey Kong Country 2 (Europe)"#, false),
+    "11417fc1" => (2, false, r#"Donkey Kong Country 2 (USA, Australia)"#, false),
+    "708a6168" => (1, false, r#"Donkey Kong Country 3 (Europe)"#, false),
+    "fe03e5af" => (1, false, r#"Donkey Kong Country 3 (USA, Australia)"#, false),
+    "58c641b3" => (1, false, r#"Doom (Europe, USA)"#, false),
+    "60cd3207" => (1, false, r#"Doom II (Europe)"#, false),
+    "c885d9e9" => (1, false, r#"Doom II (USA)"#, false),
+    "7b311c5c" => (0, false, r#"Dora the Explorer - Dora's World Adventure! (USA)"#, false),
+    "650fdfdb" => (0, false, r#"Dora the Explorer - Super Spies (USA)"#, false),
+    "528bca02" => (0, false, r#"Dora the Explorer - Super Star Adventures! (Europe)"#, false),
+    "2cee050f" => (0, false, r#"Dora the Explorer - Super Star Adventures! (USA)"#, false),
+    "9439541c" => (0, false, r#"Dora the Explorer - The Search for the Pirate Pig's Treasure (USA)"#, false),
+    "e28afebc" => (2, false, r#"Doraemon - Dokodemo Walker (Japan)"#, false),
+    "95565762" => (3, false, r#"Doraemon - Midori no Wakusei Dokidoki Daikyuushutsu! (Japan)"#, false),
+    "a3330e8f" => (0, false, r#"Double Dragon Advance (Japan)"#, false),
+    "764fafb5" => (0, false, r#"Double Dragon Advance (USA)"#, false),
+    "b9c15f87" => (4, false, r#"Doubutsu-jima no Chobigurumi (Japan, rev. 1)"#, false),
+    "c764cc7e" => (4, false, r#"Doubutsu-jima no Chobigurumi (Japan)"#, false),
+    "16347e33" => (1, false, r#"Doubutsu-jima no Chobigurumi 2 - Tama-chan Monogatari (Japan)"#, false),
+    "eb326294" => (0, false, r#"Downforce (Europe)"#, false),
+    "32e9a0bd" => (2, false, r#"Downtown Nekketsu Monogatari EX (Japan)"#, false),
+    "a5dbe5ae" => (1, false, r#"The Cat in the Hat (Europe)"#, false),
+    "46736f58" => (1, false, r#"Dr. Muto (Europe)"#, false),
+    "13adbf3b" => (1, false, r#"Dr. Seuss' The Cat in the Hat (USA)"#, false),
+    "e9848d13" => (2, false, r#"Dr. Sudoku (Europe)"#, false),
+    "fc003e2b" => (2, false, r#"Dr. Sudoku (USA)"#, false),
+    "6c135820" => (1, false, r#"Dragon Ball - Advanced Adventure (Europe)"#, false),
+    "8a06337a" => (1, false, r#"Dragon Ball - Advance Adventure (Japan)"#, false),
+    "4e2cc84d" => (1, false, r#"Dragon Ball - Advance Adventure (Korea)"#, false),
+    "7d7306df" => (1, false, r#"Dragon Ball - Advanced Adventure (USA)"#, false),
+    "ad964a91" => (2, false, r#"Dragon Ball GT - Transformation (USA)"#, false),
+    "01c1707f" => (2, false, r#"Dragon Ball Z - Buu's Fury (USA)"#, false),
+    "f6124d7f" => (3, false, r#"Dragon Ball Z - Collectible Card Game (USA)"#, false),
+    "b4d9572c" => (1, false, r#"Dragon Ball Z - Supersonic Warriors (Europe)"#, false),
+    "9a857a70" => (1, false, r#"Dragon Ball Z - Supersonic Warriors (USA)"#, false),
+    "6c0f0b86" => (1, false, r#"Dragon Ball Z - Bukuu Tougeki (Japan)"#, false),
+    "e4893dd7" => (1, false, r#"Dragon Ball Z - Moogongtoogeuk (Korea)"#, false),
+    "d0e79665" => (1, false, r#"Dragon Ball Z - Taiketsu (Europe)"#, false),
+    "64ec07e7" => (1, false, r#"Dragon Ball Z - Taiketsu (USA)"#, false),
+    "ca6e149c" => (1, false, r#"Dragon Ball Z - The Legacy of Goku (Europe)"#, false),
+    "d47ccff4" => (1, false, r#"Dragon Ball Z - The Legacy of Goku (USA)"#, false),
+    "20684433" => (2, false, r#"Dragon Ball Z - The Legacy of Goku II (Europe)"#, false),
+    "204142e1" => (2, false, r#"Dragon Ball Z - The Legacy of Goku II (USA)"#, false),
+    "b3297d59" => (2, false, r#"Dragon Ball Z - The Legacy of Goku II International (Japan)"#, false),
+    "e5a571ad" => (3, false, r#"Dragon Drive - World D Break (Japan)"#, false),
+    "2b077791" => (5, false, r#"Dragon Quest Characters - Torneko no Daibouken 2 Advance - Fushigi no Dungeon
+			(Japan)"#, false),
+    "c891b2a0" => (4, false, r#"Dragon Quest Characters - Torneko no Daibouken 3 Advance - Fushigi no Dungeon
+			(Japan)"#, false),
+    "3c24abcc" => (2, false, r#"Dragon Quest Monsters - Caravan Heart (Japan)"#, false),
+    "8af23450" => (0, false, r#"Dragon Tales - Dragon Adventures (USA)"#, false),
+    "f0ca6d70" => (1, false, r#"Dragon's Rock (Europe)"#, false),
+    "63a4422e" => (0, false, r#"Drake & Josh (USA)"#, false),
+    "e60ec183" => (0, false, r#"Drill Dozer (USA)"#, false),
+    "b617b354" => (1, false, r#"Driv3r (Europe)"#, false),
+    "6c66cfbe" => (1, false, r#"Driv3r (USA)"#, false),
+    "95f2c572" => (1, false, r#"Driven (Europe)"#, false),
+    "f2068738" => (1, false, r#"Driven (USA)"#, false),
+    "69ee551c" => (1, false, r#"Driver 2 Advance (Europe)"#, false),
+    "b24fa448" => (1, false, r#"Driver 2 Advance (Europe, prototype)"#, false),
+    "bbe2a163" => (1, false, r#"Driver 2 Advance (USA)"#, false),
+    "d6101bc0" => (1, false, r#"Drome Racers (Europe)"#, false),
+    "ad356fb9" => (1, false, r#"Drome Racers (USA)"#, false),
+    "f14b65ef" => (0, false, r#"Droopy's Tennis Open (Europe)"#, false),
+    "484e7d56" => (0, false, r#"Droopy's Tennis Open (Europe, prototype)"#, false),
+    "aff3a0c6" => (1, false, r#"Dual Blades (USA)"#, false),
+    "522f1f51" => (1, false, r#"Dual Blades (Japan)"#, false),
+    "0d32851a" => (3, false, r#"Duel Masters (Japan)"#, false),
+    "4a7de98e" => (3, false, r#"Duel Masters 2 - Invincible Advance (Japan)"#, false),
+    "4d6a5e1d" => (3, false, r#"Duel Masters 2 - Kirifuda Shoubu Ver. (Japan)"#, false),
+    "d65e3e03" => (3, false, r#"Duel Masters 3 (Japan)"#, false),
+    "2fff9ba9" => (1, false, r#"Duel Masters - Sempai Legends (Europe)"#, false),
+    "1ce344ca" => (1, false, r#"Duel Masters - Sempai Legends (USA)"#, false),
+    "52e0aa95" => (1, false, r#"Duel Masters - Kaijudo Showdown (Europe)"#, false),
+    "804e5de2" => (1, false, r#"Duel Masters - Kaijudo Showdown (USA)"#, false),
+    "9d429f3e" => (1, false, r#"Duel Masters - Shadow of the Code (Europe)"#, false),
+    "94d9053d" => (1, false, r#"Duel Masters - Shadow of the Code (USA)"#, false),
+    "dc7dab56" => (1, false, r#"Duke Nukem Advance (Europe)"#, false),
+    "06570282" => (1, false, r#"Duke Nukem Advance (USA)"#, false),
+    "298d627b" => (0, false, r#"Frank Herbert's Dune - Ornithopter Assault (prototype)"#, false),
+    "26351555" => (3, false, r#"Dungeons & Dragons - Eye of the Beholder (Europe)"#, false),
+    "91b16892" => (3, false, r#"Dungeons & Dragons - Eye of the Beholder (USA)"#, false),
+    "74cdb37a" => (3, false, r#"Dynasty Warriors Advance (Europe)"#, false),
+    "ad5a2a4e" => (3, false, r#"Dynasty Warriors Advance (USA, Australia)"#, false),
+    "10ece4cb" => (0, false, r#"E.T. - The Extra-Terrestrial (Europe)"#, false),
+    "8b082cce" => (0, false, r#"E.T. - The Extra-Terrestrial (USA)"#, false),
+    "c4991d13" => (0, false, r#"Earthworm Jim (Europe, USA)"#, false),
+    "d211e8da" => (0, false, r#"Earthworm Jim 2 (Europe)"#, false),
+    "7496555c" => (0, false, r#"Earthworm Jim 2 (USA)"#, false),
+    "af726026" => (0, false, r#"Ecks vs Sever (Europe)"#, false),
+    "86a861f1" => (0, false, r#"Ecks vs Sever (USA)"#, false),
+    "83297d13" => (0, false, r#"Ecks vs Sever II - Ballistic (Europe)"#, false),
+    "0477f153" => (0, false, r#"Ballistic - Ecks vs Sever (USA)"#, false),
+    "e7f6e42f" => (1, false, r#"Ed, Edd n Eddy - Jawbreakers! (Europe)"#, false),
+    "315a9114" => (1, false, r#"Ed, Edd n Eddy - Jawbreakers! (USA, rev. 1)"#, false),
+    "48e5a70e" => (1, false, r#"Ed, Edd n Eddy - Jawbreakers! (USA)"#, false),
+    "baa13bc4" => (1, false, r#"Ed, Edd n Eddy - The Mis-Edventures (Europe)"#, false),
+    "64edb487" => (1, false, r#"Ed, Edd n Eddy - The Mis-Edventures (USA)"#, false),
+    "0b2961ce" => (0, false, r#"Eggo Mania (Europe)"#, false),
+    "f4c9a628" => (0, false, r#"Eggo Mania (Europe, prototype)"#, false),
+    "e8b15678" => (0, false, r#"Egg Mania (USA)"#, false),
+    "4beab3ac" => (0, false, r#"Egg Mania - Tsukande! Mawashite! Dossun Puzzle!! (Japan)"#, false),
+    "fa28bed8" => (1, false, r#"Elemix! (Japan)"#, false),
+    "2d88526a" => (1, false, r#"Elevator Action - Old & New (Japan)"#, false),
+    "1e107999" => (0, false, r#"Elf - The Movie (Europe)"#, false),
+    "9a1f8165" => (0, false, r#"Elf - The Movie (USA)"#, false),
+    "68feff40" => (0, false, r#"Elf Bowling 1 & 2 (USA)"#, false),
+    "af23ffff" => (1, false, r#"Enchanted - Once Upon Andalasia (USA)"#, false),
+    "bb05a63f" => (2, false, r#"Eragon (Europe)"#, false),
+    "af8af2e8" => (2, false, r#"Eragon (USA)"#, false),
+    "8c1afca4" => (2, false, r#"Elemental Gerad - Tozasareshi Uta (Japan)"#, false),
+    "4878bbea" => (5, false, r#"ESPN Final Round Golf (Europe)"#, false),
+    "c9bc75a5" => (5, false, r#"ESPN Final Round Golf 2002 (USA)"#, false),
+    "28f0a921" => (2, false, r#"ESPN Great Outdoor Games - Bass Tournament (Europe)"#, false),
+    "da9d815f" => (3, false, r#"ESPN Great Outdoor Games - Bass 2002 (USA)"#, false),
+    "a56afbb7" => (1, false, r#"ESPN International Winter Sports (Europe)"#, false),
+    "ff51a9a9" => (1, false, r#"ESPN International Winter Sports 2002 (USA)"#, false),
+    "ce62f1f4" => (1, false, r#"ESPN Winter X-Games Snowboarding 2 (Europe)"#, false),
+    "3c5ac0c5" => (2, false, r#"ESPN Winter X-Games Snowboarding 2002 (Japan)"#, false),
+    "de9e85bf" => (1, false, r#"ESPN Winter X-Games Snowboarding 2002 (USA)"#, false),
+    "2c79e3d1" => (1, false, r#"ESPN X-Games Skateboarding (Europe)"#, false),
+    "7abd7e9a" => (1, false, r#"ESPN X-Games Skateboarding (Japan)"#, false),
+    "9766b309" => (1, false, r#"ESPN X-Games Skateboarding (USA)"#, false),
+    "748ad738" => (0, false, r#"European Super League (Europe)"#, false),
+    "afbb1d35" => (1, false, r#"Ever Girl (USA)"#, false),
+    "d45abf21" => (3, false, r#"EX Monopoly (Japan, rev. 1)"#, false),
+    "916efd5b" => (3, false, r#"EX Monopoly (Japan)"#, false),
+    "ab27e52a" => (2, false, r#"Exciting Bass (Japan)"#, false),
+    "88cc876c" => (0, false, r#"Extreme Ghostbusters - Code Ecto-1 (Europe)"#, false),
+    "e434fa06" => (0, false, r#"Extreme Ghostbusters - Code Ecto-1 (USA)"#, false),
+    "58594ae9" => (1, false, r#"Disney's Extreme Skate Adventure (Europe, French / German)"#, false),
+    "ad7bb7c8" => (1, false, r#"Disney's Extreme Skate Adventure (Europe, USA)"#, false),
+    "7a3ef446" => (1, false, r#"Disney's Extreme Skate Adventure (USA, rev. 1)"#, false),
+    "620784af" => (3, false, r#"Eyeshield 21 - Devilbats Devildays (Japan)"#, false),
+    "d256f6f7" => (3, false, r#"EZ-Talk - Shokyuu Hen 1 (Japan)"#, false),
+    "38d91a54" => (3, false, r#"EZ-Talk - Shokyuu Hen 2 (Japan)"#, false),
+    "5fa07e85" => (3, false, r#"EZ-Talk - Shokyuu Hen 3 (Japan)"#, false),
+    "0ca6dcf1" => (3, false, r#"EZ-Talk - Shokyuu Hen 4 (Japan)"#, false),
+    "a0e8eb88" => (3, false, r#"EZ-Talk - Shokyuu Hen 5 (Japan)"#, false),
+    "a8ab645d" => (3, false, r#"EZ-Talk - Shokyuu Hen 6 (Japan)"#, false),
+    "1428fe1c" => (0, false, r#"F-14 Tomcat (Europe, USA)"#, false),
+    "dbdc71e3" => (5, false, r#"F-Zero - Climax (Japan)"#, false),
+    "2446b920" => (3, false, r#"F-Zero - Falcon Densetsu (Japan)"#, false),
+    "bd5e9798" => (3, false, r#"F-Zero - Maximum Velocity (Europe, USA)"#, false),
+    "f1d5601b" => (3, false, r#"F-Zero - GP Legend (Europe)"#, false),
+    "781aab58" => (3, false, r#"F-Zero - GP Legend (USA)"#, false),
+    "25e3fc9a" => (3, false, r#"F-Zero for Game Boy Advance (Japan)"#, false),
+    "aac551fd" => (1, false, r#"F1 2002 (Europe)"#, false),
+    "7dd20f33" => (1, false, r#"F1 2002 (Europe, USA)"#, false),
+    "9387ea7c" => (0, false, r#"F24 Stealth Fighter (USA)"#, false),
+    "b0533f41" => (0, false, r#"The Fairly OddParents! - Enter the Cleft (USA)"#, false),
+    "9bf9ca2f" => (0, false, r#"The Fairly OddParents! - Breakin' da Rules (USA)"#, false),
+    "23188cb3" => (0, false, r#"The Fairly OddParents! - Shadow Showdown (Europe)"#, false),
+    "ba72e185" => (0, false, r#"The Fairly OddParents! - Shadow Showdown (USA)"#, false),
+    "65e88703" => (0, false, r#"Cosmo & Wanda - Wenn Elfen Helfen! - Das Schattenduell (Germany)"#, false),
+    "91e7ba6c" => (0, false, r#"The Fairly OddParents! - Clash with the Anti-World (Europe)"#, false),
+    "6fd773cd" => (0, false, r#"The Fairly OddParents! - Clash with the Anti-World (USA)"#, false),
+    "423b8fc5" => (2, false, r#"Famicom Mini - Dai-2-ji Super Robot Taisen (Japan, promo)"#, false),
+    "fae4fdec" => (1, false, r#"Famicom Mini - Kidou Senshi Z Gundam - Hot Scramble (Japan, promo)"#, false),
+    "5848884c" => (1, false, r#"Famicom Mini 01 - Super Mario Bros. (Japan, rev. 1)"#, false),
+    "86d346af" => (1, false, r#"Famicom Mini 02 - Donkey Kong (Japan)"#, false),
+    "5646f9d1" => (1, false, r#"Famicom Mini 03 - Ice Climber (Japan)"#, false),
+    "319cdf36" => (0, false, r#"Famicom Mini 04 - Excitebike (Japan)"#, false),
+    "114e37a1" => (2, false, r#"Famicom Mini 05 - Zelda no Densetsu 1 - The Hyrule Fantasy (Japan)"#, false),
+    "3fec1606" => (1, false, r#"Famicom Mini 06 - Pac-Man (Japan)"#, false),
+    "1cd1f78c" => (1, false, r#"Famicom Mini 07 - Xevious (Japan)"#, false),
+    "d4b0779a" => (1, false, r#"Famicom Mini 08 - Mappy (Japan)"#, false),
+    "3b92ee60" => (1, false, r#"Famicom Mini 09 - Bomberman (Japan)"#, false),
+    "0958ee09" => (1, false, r#"Famicom Mini 10 - Star Soldier (Japan)"#, false),
+    "9cd4d6cf" => (1, false, r#"Famicom Mini 11 - Mario Bros. (Japan)"#, false),
+    "c4c03aa8" => (1, false, r#"Famicom Mini 12 - Clu Clu Land (Japan)"#, false),
+    "45d299d7" => (1, false, r#"Famicom Mini 13 - Balloon Fight (Japan)"#, false),
+    "6c0611f0" => (1, false, r#"Famicom Mini 14 - Wrecking Crew (Japan)"#, false),
+    "e23a7ed0" => (1, false, r#"Famicom Mini 15 - Dr. Mario (Japan)"#, false),
+    "57513b63" => (1, false, r#"Famicom Mini 16 - Dig Dug (Japan)"#, false),
+    "3bef3b74" => (1, false, r#"Famicom Mini 17 - Takahashi Meijin no Bouken-jima (Japan)"#, false),
+    "85261ec6" => (1, false, r#"Famicom Mini 18 - Makai Mura (Japan)"#, false),
+    "26a17681" => (1, false, r#"Famicom Mini 19 - Twin Bee (Japan)"#, false),
+    "10b48dba" => (1, false, r#"Famicom Mini 20 - Ganbare Goemon! - Karakuri Douchuu (Japan)"#, false),
+    "f55674a3" => (1, false, r#"Famicom Mini 21 - Super Mario Bros. 2 (Japan)"#, false),
+    "8233349c" => (1, false, r#"Famicom Mini 22 - Nazo no Murasame Jou (Japan)"#, false),
+    "abeccdab" => (2, false, r#"Famicom Mini 23 - Metroid (Japan)"#, false),
+    "f311edac" => (2, false, r#"Famicom Mini 24 - Hikari Shinwa - Palthena no Kagami (Japan)"#, false),
+    "1cbe712a" => (2, false, r#"Famicom Mini 25 - The Legend of Zelda 2 - Link no Bouken (Japan)"#, false),
+    "63b51337" => (1, false, r#"Famicom Mini 26 - Famicom Mukashibanashi - Shin Onigashima - Zen, Kouhen
+			(Japan)"#, false),
+    "3cf43405" => (1, false, r#"Famicom Mini 27 - Famicom Tantei Club - Kieta Koukeisha - Zen, Kouhen (Japan)"#, false),
+    "75e1b220" => (1, false, r#"Famicom Mini 28 - Famicom Tantei Club Part II - Ushiro ni Tatsu Shoujo - Zen,
+			Kouhen (Japan)"#, false),
+    "012fa057" => (1, false, r#"Famicom Mini 29 - Akumajou Dracula (Japan)"#, false),
+    "9ed2daeb" => (2, false, r#"Famicom Mini 30 - SD Gundam World - Gachapon Senshi Scramble Wars (Japan)"#, false),
+    "ec36a3df" => (0, false, r#"Family Feud (USA)"#, false),
+    "bb43c681" => (1, false, r#"Family Tennis Advance (Japan)"#, false),
+    "f7a58ae8" => (2, false, r#"Famista Advance (Japan)"#, false),
+    "a29a50e7" => (3, false, r#"Fancy Pocket (Japan)"#, false),
+    "b30ae33d" => (1, false, r#"Fantastic 4 (Europe, English)"#, false),
+    "88643de0" => (1, false, r#"Fantastic 4 (Europe, French / German / Dutch / Spanish)"#, false),
+    "5d4645e1" => (1, false, r#"Fantastic 4 (Italy)"#, false),
+    "ff2c6ea9" => (1, false, r#"Fantastic 4 (USA)"#, false),
+    "671957af" => (1, false, r#"Fantastic 4 - Flame On (Europe)"#, false),
+    "ddde4295" => (1, false, r#"Fantastic 4 - Flame On (USA)"#, false),
+    "fefe25be" => (2, false, r#"Fantastic Children (Japan)"#, false),
+    "ec60d573" => (2, false, r#"Fantastic Märchen - Cake-ya-san Monogatari + Doubutsu Chara Navi Uranai Kosei
+			Shinri Gaku (Japan)"#, false),
+    "3dfe6d2b" => (0, false, r#"Farbe (Germany)"#, false),
+    "bf91c27d" => (1, false, r#"Fear Factor Unleashed (USA)"#, false),
+    "4fd9c349" => (3, false, r#"Field of Nine - Digital Edition 2001 (Japan)"#, false),
+    "be4c3cb1" => (1, false, r#"FIFA 06 (Europe, USA)"#, false),
+    "d8c7553b" => (1, false, r#"FIFA 07 (Europe, USA)"#, false),
+    "81f58837" => (1, false, r#"FIFA (Europe, USA)"#, false),
+    "1abbc24f" => (1, false, r#"FIFA 2004 (Europe, USA)"#, false),
+    "da90f396" => (1, false, r#"FIFA 2005 (Europe, USA)"#, false),
+    "928b4e05" => (1, false, r#"FightBox (Europe)"#, false),
+    "07434e3a" => (0, false, r#"FILA Decathlon (Europe, prototype)"#, false),
+    "628355b5" => (1, false, r#"FILA Decathlon (Europe)"#, false),
+    "7c9854c6" => (3, false, r#"Final Fantasy I & II - Dawn of Souls (Europe)"#, false),
+    "1b39cdab" => (3, false, r#"Final Fantasy I & II - Dawn of Souls (USA, Australia)"#, false),
+    "0f3bd723" => (3, false, r#"Final Fantasy I - II Advance (Japan, rev. 1)"#, false),
+    "27f2bc67" => (3, false, r#"Final Fantasy IV Advance (Europe)"#, false),
+    "80f1133b" => (3, false, r#"Final Fantasy IV Advance (Japan, rev. 1)"#, false),
+    "17036e02" => (3, false, r#"Final Fantasy IV Advance (Japan)"#, false),
+    "feeebde4" => (3, false, r#"Final Fantasy IV Advance (USA, Australia)"#, false),
+    "ba5de047" => (4, false, r#"Final Fantasy Tactics Advance (Europe)"#, false),
+    "a57b0034" => (4, false, r#"Final Fantasy Tactics Advance (Japan)"#, false),
+    "5645e56c" => (4, false, r#"Final Fantasy Tactics Advance (USA, Australia)"#, false),
+    "7197117e" => (3, false, r#"Final Fantasy V Advance (Europe)"#, false),
+    "fc7909a7" => (3, false, r#"Final Fantasy V Advance (Japan)"#, false),
+    "7a24ab0c" => (3, false, r#"Final Fantasy V Advance (USA)"#, false),
+    "d15deba5" => (3, false, r#"Final Fantasy VI Advance (Europe)"#, false),
+    "d67478fe" => (3, false, r#"Final Fantasy VI Advance (Japan)"#, false),
+    "d708f5ab" => (3, false, r#"Final Fantasy VI Advance (USA)"#, false),
+    "9dd5e6fa" => (1, false, r#"Final Fight One (Europe)"#, false),
+    "c9e5e223" => (1, false, r#"Final Fight One (Japan)"#, false),
+    "052c9997" => (1, false, r#"Final Fight One (USA)"#, false),
+    "a0093822" => (5, false, r#"Final Fire Pro Wrestling - Yume no Dantai Unei! (Japan)"#, false),
+    "3b098667" => (0, false, r#"Finding Nemo (Europe, USA)"#, false),
+    "3e6f4f03" => (0, false, r#"Finding Nemo (Europe, Italian / Spanish)"#, false),
+    "269af7dd" => (0, false, r#"Finding Nemo (Europe, French / Dutch)"#, false),
+    "6b6b0908" => (0, false, r#"Findet Nemo (Germany)"#, false),
+    "92197fbb" => (0, false, r#"Findet Nemo (Germany, prototype)"#, false),
+    "f895ba2d" => (0, false, r#"Finding Nemo (Japan)"#, false),
+    "4f9aca4f" => (0, false, r#"Finding Nemo - The Continuing Adventures (Europe, USA)"#, false),
+    "4c77eda6" => (1, false, r#"Finding Nemo - Aratanaru Bouken (Japan)"#, false),
+    "45bc9c37" => (0, false, r#"Finding Nemo - The Continuing Adventures (Europe, English / Italian / Spanish /
+			Danish / Swedish)"#, false),
+    "1e8cefe9" => (0, false, r#"Finding Nemo - The Continuing Adventures (Europe, French / German / Dutch)"#, false),
+    "8698f50a" => (0, false, r#"Fire Eaters - Zero Bandits (Europe, demo)"#, false),
+    "4a805ed1" => (3, false, r#"Fire Emblem (Europe, English / French / German)"#, false),
+    "9decc754" => (3, false, r#"Fire Emblem (Europe, English / Italian / Spanish)"#, false),
+    "f0c10e72" => (3, false, r#"Fire Emblem - Rekka no Ken (Japan)"#, false),
+    "8146a270" => (3, false, r#"Fire Emblem - Rekka no Ken (Japan, prototype 0219)"#, false),
+    "b40d6854" => (3, false, r#"Fire Emblem - Rekka no Ken (Japan, prototype 0206)"#, false),
+    "2a524221" => (3, false, r#"Fire Emblem (USA, Australia)"#, false),
+    "d38763e1" => (3, false, r#"Fire Emblem - Fuuin no Tsurugi (Japan)"#, false),
+    "b3005195" => (3, false, r#"Fire Emblem - The Sacred Stones (Europe)"#, false),
+    "9d76826f" => (3, false, r#"Fire Emblem - Seima no Kouseki (Japan)"#, false),
+    "cd9d8972" => (3, false, r#"Fire Emblem - Seima no Kouseki (Japan, prototype)"#, false),
+    "a47246ae" => (3, false, r#"Fire Emblem - The Sacred Stones (USA, Australia)"#, false),
+    "12aa524c" => (5, false, r#"Fire Pro Wrestling (Europe, USA)"#, false),
+    "0bb47e83" => (5, false, r#"Fire Pro Wrestling A (Japan)"#, false),
+    "b9cfc1d3" => (5, false, r#"Fire Pro Wrestling 2 (USA)"#, false),
+    "eb33477d" => (0, false, r#"Flashback Legends (France, prototype)"#, false),
+    "39782c26" => (0, false, r#"The Flintstones - Big Trouble in Bedrock (Europe)"#, false),
+    "3b09694b" => (0, false, r#"The Flintstones - Big Trouble in Bedrock (USA)"#, false),
+    "62e435de" => (0, false, r#"Flushed Away (Europe)"#, false),
+    "0ed5df4b" => (0, false, r#"Flushed Away (USA)"#, false),
+    "94e3d277" => (0, false, r#"Ford Racing 3 (Europe)"#, false),
+    "56d38756" => (0, false, r#"Ford Racing 3 (USA)"#, false),
+    "47f663a0" => (3, false, r#"Formation Soccer 2002 (Japan)"#, false),
+    "bee67855" => (0, false, r#"Fortress (Europe, USA)"#, false),
+    "dee47a85" => (1, false, r#"Foster's Home for Imaginary Friends (Europe)"#, false),
+    "d7c80592" => (1, false, r#"Foster's Home for Imaginary Friends (USA)"#, false),
+    "a0952cd0" => (1, false, r#"Franklin the Turtle (Europe, rev. 2)"#, false),
+    "1a2f7ee6" => (1, false, r#"Franklin the Turtle (Europe, rev. 1)"#, false),
+    "17c48263" => (1, false, r#"Franklin the Turtle (Europe)"#, false),
+    "9bb561a8" => (1, false, r#"Franklin the Turtle (USA)"#, false),
+    "22e65767" => (1, false, r#"Franklin's Great Adventures (Europe)"#, false),
+    "7fbf8e98" => (1, false, r#"Franklin's Great Adventures (USA)"#, false),
+    "96871a23" => (0, false, r#"Freekstyle (Europe)"#, false),
+    "72434345" => (0, false, r#"Freekstyle (USA)"#, false),
+    "0187988f" => (0, false, r#"Freestyle Scooter (Europe)"#, false),
+    "8873bd29" => (0, false, r#"Frogger Advance - The Great Quest (Europe)"#, false),
+    "b75782c9" => (0, false, r#"Frogger Advance - The Great Quest (USA)"#, false),
+    "9f8d58aa" => (1, false, r#"Frogger's Adventures - Temple of the Frog (Europe)"#, false),
+    "2eb9eee6" => (1, false, r#"Frogger's Adventures - Temple of the Frog (USA)"#, false),
+    "8acd3afc" => (1, false, r#"Frogger's Adventures 2 - The Lost Wand (Europe)"#, false),
+    "27b95c01" => (1, false, r#"Frogger's Adventures 2 - The Lost Wand (USA)"#, false),
+    "b1ef7532" => (1, false, r#"Frogger - Mahou no Kuni no Daibouken (Japan)"#, false),
+    "6f8bfdbc" => (2, false, r#"Frogger's Journey - The Forgotten Relic (USA)"#, false),
+    "6853109b" => (2, false, r#"Frogger - Kodaibunmei no Nazo (Japan)"#, false),
+    "e6f5bdd5" => (5, false, r#"From TV Animation One Piece - Mezase! King of Berry (Japan)"#, false),
+    "fd4ce9c8" => (3, false, r#"From TV Animation One Piece - Nanatsu-jima no Daihihou (Japan)"#, false),
+    "4d6bf573" => (2, false, r#"Frontier Stories (Japan)"#, false),
+    "ebf89b0d" => (2, false, r#"Fruits Mura no Doubutsu-tachi (Japan)"#, false),
+    "195d7ea7" => (2, false, r#"Fruits Mura no Doubutsu-tachi (Japan, rev. 2)"#, false),
+    "2cda40e1" => (1, false, r#"Fushigi no Kuni no Alice - Alice's Adventures in Wonderland (Japan)"#, false),
+    "803fc474" => (3, false, r#"Fushigi no Kuni no Angelique (Japan)"#, false),
+    "6026eecd" => (1, false, r#"Futari wa Pretty Cure - Arienaai! Yume no Sono wa Daimeikyuu (Japan)"#, false),
+    "541c18f9" => (2, false, r#"Futari wa Pretty Cure Max Heart - Maji? Maji?! Fight de IN Janai (Japan)"#, false),
+    "eb9f6f11" => (5, false, r#"Gachasute! Dino Device - Blue (Japan)"#, false),
+    "dda5ec5e" => (5, false, r#"Gachasute! Dino Device - Red (Japan)"#, false),
+    "a63dfd2c" => (1, false, r#"Gachasute! Dino Device 2 - Dragon (Japan)"#, false),
+    "67e21bfe" => (1, false, r#"Gachasute! Dino Device 2 - Phoenix (Japan)"#, false),
+    "81de0c16" => (3, false, r#"Gachinko Pro Yakyuu (Japan)"#, false),
+    "d2bc5027" => (3, false, r#"Gadget Racers (Europe)"#, false),
+    "95dd156a" => (3, false, r#"Gadget Racers (USA)"#, false),
+    "5847a906" => (2, false, r#"Gakkou no Kaidan - Hyakuyoubako no Fuuin (Japan)"#, false),
+    "6eb70422" => (2, false, r#"Gakkou o Tsukurou!! Advance (Japan)"#, false),
+    "aab51f8c" => (3, false, r#"Gakuen Alice - Dokidoki Fushigi Taiken (Japan)"#, false),
+    "8cb61158" => (3, false, r#"Gakuen Alice - Dokidoki Fushigi Taiken (Japan, rev. 1)"#, false),
+    "82983321" => (1, false, r#"Gakuen Senki Muryou (Japan)"#, false),
+    "b99e2333" => (1, false, r#"Galaxy Angel Game Boy Advance - Moridakusan Tenshi no Full-Course - Okawari
+			Jiyuu (Japan)"#, false),
+    "4d8f49f9" => (1, false, r#"Galidor - Defenders of the Outer Dimension (USA)"#, false),
+    "8a879018" => (2, false, r#"Gambler Densetsu Tetsuya - Yomigaeru Densetsu (Japan)"#, false),
+    "85c837af" => (3, false, r#"Game & Watch Gallery Advance (Europe)"#, false),
+    "7e90cea2" => (3, false, r#"Game & Watch Gallery 4 (USA)"#, false),
+    "ffbd4da9" => (0, false, r#"Game Boy Advance Video - All Grown Up! - Volume 1 (USA)"#, false),
+    "fc042f18" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Edition Platinum (France)"#, false),
+    "58f1cde8" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Edition Premium (France)"#, false),
+    "71154d42" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Edition Speciale (France)"#, false),
+    "5d918b2d" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Limited Edition (USA)"#, false),
+    "6443554b" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Platinum Edition (Europe,
+			USA)"#, false),
+    "f2825729" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Premium Edition (Europe,
+			USA)"#, false),
+    "e9b7b8a4" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Special Edition (Europe,
+			USA)"#, false),
+    "91f39447" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Volume 1 (USA)"#, false),
+    "4bfaa8de" => (0, false, r#"Game Boy Advance Video - Cartoon Network Collection - Volume 2 (Europe, USA)"#, false),
+    "4463f345" => (0, false, r#"Game Boy Advance Video - Codename - Kids Next Door - Volume 1 (Europe, USA)"#, false),
+    "7cc985cb" => (0, false, r#"Game Boy Advance Video - Disney Channel Collection - Volume 1 (USA)"#, false),
+    "60dfd79c" => (0, false, r#"Game Boy Advance Video - Disney Channel Collection - Volume 2 (USA)"#, false),
+    "dd6a7fcc" => (0, false, r#"Game Boy Advance Video - Dora the Explorer - Volume 1 (USA)"#, false),
+    "da559199" => (0, false, r#"Game Boy Advance Video - Dragon Ball GT - Volume 1 (USA)"#, false),
+    "6fbf5ceb" => (0, false, r#"Game Boy Advance Video - Nicktoons - Volume 3 (USA)"#, false),
+    "5d47676f" => (0, false, r#"Game Boy Advance Video - Nicktoon's Collection - Volume 1 (USA)"#, false),
+    "65deeeb6" => (0, false, r#"Game Boy Advance Video - Nicktoon's Collection - Volume 2 (USA)"#, false),
+    "a59ef954" => (0, false, r#"Game Boy Advance Video - Pokémon: For Ho-Oh the Bells Toll! + A Hot Water
+			Battle (USA)"#, false),
+    "0da1a383" => (0, false, r#"Game Boy Advance Video - Pokémon: Johto Photo Finish + Playing with Fire! (USA)"#, false),
+    "a36aa9c5" => (0, false, r#"Game Boy Advance Video - Pokémon: Pokémon - I Choose You + Here Comes the
+			Squirtle Squad (USA)"#, false),
+    "be468496" => (0, false, r#"Game Boy Advance Video - Pokémon: Beach Blank-out Blastoise + Go West Young
+			Meowth (USA)"#, false),
+    "d2cf417a" => (0, false, r#"Game Boy Advance Video - Shark Tale (USA, rev. 6)"#, false),
+    "01468820" => (0, false, r#"Game Boy Advance Video - Shark Tale (USA, rev. 5)"#, false),
+    "4010e9fa" => (0, false, r#"Game Boy Advance Video - Shrek (USA, rev. 6)"#, false),
+    "690176e5" => (0, false, r#"Game Boy Advance Video - Shrek (USA)"#, false),
+    "925b6c02" => (0, false, r#"Game Boy Advance Video - Shrek 2 (USA, rev. 6)"#, false),
+    "0d353654" => (0, false, r#"Game Boy Advance Video - Shrek 2 (USA)"#, false),
+    "aadb3e3d" => (0, false, r#"Game Boy Advance Video - Shrek + Shark Tale (USA, rev. 5)"#, false),
+    "363c3eb8" => (0, false, r#"Game Boy Advance Video - Sonic X - Volume 1 (USA)"#, false),
+    "d47bf1d4" => (0, false, r#"Game Boy Advance Video - SpongeBob SquarePants - Volume 1 (USA, rev. 1)"#, false),
+    "7074364a" => (0, false, r#"Game Boy Advance Video - SpongeBob SquarePants - Volume 2 (USA, rev. 1)"#, false),
+    "9772ca45" => (0, false, r#"Game Boy Advance Video - SpongeBob SquarePants - Volume 3 (USA)"#, false),
+    "ff7582ef" => (0, false, r#"Game Boy Advance Video - Strawberry Shortcake - Volume 1 (USA)"#, false),
+    "d743a070" => (0, false, r#"Game Boy Advance Video - Super Robot Monkey Team - Hyper Force Go! - Volume 1
+			(USA)"#, false),
+    "1ee78166" => (0, false, r#"Game Boy Advance Video - Teenage Mutant Ninja Turtles - Le Demenagement
+			(France)"#, false),
+    "046589c8" => (0, false, r#"Game Boy Advance Video - Teenage Mutant Ninja Turtles - Things Change (Europe,
+			USA)"#, false),
+    "0e556edf" => (0, false, r#"Game Boy Advance Video - The Adventures of Jimmy Neutron Boy Genius - Volume 1
+			(USA)"#, false),
+    "7958df20" => (0, false, r#"Game Boy Advance Video - The Fairly OddParents! - Volume 1 (USA)"#, false),
+    "bbcbc6fd" => (0, false, r#"Game Boy Advance Video - The Fairly OddParents! - Volume 2 (USA, rev. 1)"#, false),
+    "c6a91365" => (0, false, r#"Game Boy Advance Video - The Proud Family - Volume 1 (USA)"#, false),
+    "c9b6ccf1" => (0, false, r#"Game Boy Advance Video - Yu-Gi-Oh! - Yugi vs. Joey (France)"#, false),
+    "8d631f4e" => (0, false, r#"Game Boy Advance Video - Yu-Gi-Oh! - Yugi vs. Joey (Europe, USA)"#, false),
+    "49ee1fdd" => (5, false, r#"Game Boy Wars Advance 1+2 (Japan)"#, false),
+    "68d63d5f" => (0, false, r#"Games Explosion! (USA)"#, false),
+    "276e5814" => (2, false, r#"Ganbare! Dodge Fighters (Japan)"#, false),
+    "f7cd830c" => (0, false, r#"Garfield - The Search for Pooky (Europe, rev. 2)"#, false),
+    "387eb638" => (0, false, r#"Garfield - The Search for Pooky (Europe, rev. 1)"#, false),
+    "ce9291f1" => (0, false, r#"Garfield - The Search for Pooky (Europe)"#, false),
+    "76035741" => (0, false, r#"Garfield - The Search for Pooky (USA)"#, false),
+    "f0ba1a8e" => (1, false, r#"Garfield and his Nine Lives (Europe)"#, false),
+    "d442f13d" => (1, false, r#"Garfield and his Nine Lives (USA)"#, false),
+    "e99de964" => (1, false, r#"Gauntlet - Dark Legacy (USA)"#, false),
+    "7f2b2657" => (3, false, r#"GeGeGe no Kitarou - Kikiippatsu! Youkai Rettou (Japan)"#, false),
+    "2fb5b819" => (0, false, r#"Gekido Advance - Kintaro's Revenge (Europe)"#, false),
+    "6bbc5f4d" => (0, false, r#"Gekido Advance - Kintaro's Revenge (USA)"#, false),
+    "3e04fdc7" => (2, false, r#"Gekitou Densetsu Noah - Dream Management (Japan)"#, false),
+    "321b19b6" => (2, false, r#"Gekitou! Car Battler Go!! (Japan)"#, false),
+    "2eb60177" => (1, false, r#"Gem Smashers (USA)"#, false),
+    "580a00a5" => (4, false, r#"Genseishin Justirisers - Souchaku! Chikyuu no Senshi-tachi (Japan)"#, false),
+    "1dfbd741" => (2, false, r#"Gensou Maden Saiyuuki - Hangyaku no Toushin-taishi (Japan)"#, false),
+    "5e67aab4" => (0, false, r#"Gensou Suikoden - Card Stories (Japan)"#, false),
+    "9cf6887c" => (4, false, r#"Get Ride! Amdriver - Senkou no Hero Tanjou! (Japan)"#, false),
+    "66d6e299" => (3, false, r#"Get Ride! Amdriver - Shutsugeki! Battle Party (Japan)"#, false),
+    "41ea70a3" => (2, false, r#"Get! - Boku no Mushi Tsukamaete (Japan)"#, false),
+    "205c9f56" => (3, false, r#"GetBackers Dakkanya - Jagan Fuuin! (Japan)"#, false),
+    "c0a0a979" => (5, false, r#"GetBackers Dakkanya - Jigoku no Scaramouche (Japan)"#, false),
+    "91dad468" => (4, false, r#"GetBackers Dakkanya - Metropolis Dakkan Sakusen! (Japan)"#, false),
+    "cf190f2e" => (1, false, r#"Ghost Rider (Europe, USA)"#, false),
+    "81ea54e2" => (1, false, r#"Ghost Trap (Japan)"#, false),
+    "383cf600" => (1, false, r#"Global Star - Sudoku Fever (Europe)"#, false),
+    "51a42f19" => (1, false, r#"Global Star - Sudoku Fever (USA)"#, false),
+    "84adb2c3" => (0, false, r#"Glory Days (Europe)"#, false),
+    "dff3e861" => (1, false, r#"Go! Go! Beckham! - Adventure on Soccer Island (Europe)"#, false),
+    "7c43545e" => (0, false, r#"Godzilla - Domination! (Europe)"#, false),
+    "eade083f" => (0, false, r#"Godzilla - Domination! (USA)"#, false),
+    "86d6c574" => (2, false, r#"Goemon - New Age Shutsudou! (Japan)"#, false),
+    "3157a4fc" => (0, false, r#"Godzilla - Kaijuu Dairantou Advance (Japan)"#, false),
+    "56b9e9e1" => (0, false, r#"Golden Nugget Casino (Europe, USA)"#, false),
+    "e1fb68e8" => (5, false, r#"Golden Sun (Europe, USA)"#, false),
+    "f6521161" => (5, false, r#"Golden Sun (France)"#, false),
+    "1053dce4" => (5, false, r#"Golden Sun (Germany)"#, false),
+    "f3128812" => (5, false, r#"Golden Sun (Italy)"#, false),
+    "c63008d6" => (5, false, r#"Golden Sun (Spain)"#, false),
+    "606a1c4d" => (5, false, r#"Golden Sun - The Lost Age (Europe, USA)"#, false),
+    "d981d889" => (5, false, r#"Golden Sun - Die Vergessene Epoche (Germany)"#, false),
+    "1090bd33" => (5, false, r#"Golden Sun - L'Age Perdu (France)"#, false),
+    "a3e49743" => (5, false, r#"Golden Sun - L'Era Perduta (Italy)"#, false),
+    "ee38ba94" => (5, false, r#"Golden Sun - La Edad Perdida (Spain)"#, false),
+    "4851b490" => (0, false, r#"GP-1 Racing (USA, prototype)"#, false),
+    "3722bdfa" => (1, false, r#"Gradius Advance (Europe)"#, false),
+    "8103428d" => (1, false, r#"Gradius Galaxies (USA)"#, false),
+    "6c20ea42" => (1, false, r#"Gradius Generation (Japan)"#, false),
+    "4189e07f" => (1, false, r#"Grand Theft Auto Advance (Europe)"#, false),
+    "1fa131e8" => (1, false, r#"Grand Theft Auto Advance (USA)"#, false),
+    "379e64d7" => (3, false, r#"Greatest Nine (Japan)"#, false),
+    "e4ae2cd1" => (1, false, r#"Green Eggs and Ham by Dr. Seuss (USA)"#, false),
+    "649f7bb2" => (2, false, r#"Greg Hastings' Tournament Paintball Max'd (USA)"#, false),
+    "68ae6bbb" => (0, false, r#"Gremlins - Stripe vs Gizmo (Europe, prototype)"#, false),
+    "b6225186" => (0, false, r#"Gremlins - Stripe vs Gizmo (Europe)"#, false),
+    "5e72899a" => (0, false, r#"Gremlins - Stripe vs Gizmo (USA)"#, false),
+    "155587bc" => (1, false, r#"The Grim Adventures of Billy & Mandy (USA)"#, false),
+    "e7421b14" => (3, false, r#"Groove Adventure Rave - Hikari to Yami no Daikessen (Japan)"#, false),
+    "006f9150" => (3, false, r#"Groove Adventure Rave - Hikari to Yami no Daikessen 2 (Japan)"#, false),
+    "8c411acc" => (0, false, r#"GT Advance - Championship Racing (Europe, USA)"#, false),
+    "73411578" => (1, false, r#"GT Advance 2 - Rally Racing (Europe)"#, false),
+    "f335db88" => (1, false, r#"GT Advance 2 - Rally Racing (USA)"#, false),
+    "03898e7f" => (1, false, r#"GT Advance 3 - Pro Concept Racing (Europe)"#, false),
+    "0a331e11" => (1, false, r#"GT Advance 3 - Pro Concept Racing (USA)"#, false),
+    "e92ec7aa" => (1, false, r#"GT Championship (Europe)"#, false),
+    "e027657a" => (0, false, r#"GT Racers (Europe, prototype, 20050713)"#, false),
+    "a0f745a0" => (0, false, r#"GT Racers (Europe, prototype, 20051014)"#, false),
+    "362d9f86" => (0, false, r#"GT Racers (Europe)"#, false),
+    "ba95861d" => (5, false, r#"Guilty Gear X - Advance Edition (Europe)"#, false),
+    "4506ada8" => (0, false, r#"Guilty Gear X - Advance Edition (Japan, prototype)"#, false),
+    "160903ee" => (5, false, r#"Guilty Gear X - Advance Edition (Japan)"#, false),
+    "70db3f96" => (5, false, r#"Guilty Gear X - Advance Edition (USA)"#, false),
+    "2b7e3ccb" => (1, false, r#"Gumby vs. the Astrobots (USA)"#, false),
+    "8c541d13" => (2, false, r#"Gunstar Future Heroes (Europe)"#, false),
+    "6d4307f7" => (2, false, r#"Gunstar Super Heroes (Japan)"#, false),
+    "7cd86b02" => (2, false, r#"Gunstar Super Heroes (USA)"#, false),
+    "cdd2e273" => (3, false, r#"Guranbo (Japan)"#, false),
+    "30d04ad9" => (5, false, r#"Guru Logic Champ (Japan)"#, false),
+    "a0f4801f" => (3, false, r#"Gyakuten Saiban (Japan, rev. 1)"#, false),
+    "c88f0952" => (3, false, r#"Gyakuten Saiban (Japan)"#, false),
+    "191ad26a" => (3, false, r#"Gyakuten Saiban 2 (Japan)"#, false),
+    "51b6cf22" => (3, false, r#"Gyakuten Saiban 3 (Japan)"#, false),
+    "47c45b41" => (1, false, r#"Hachiemon (Japan)"#, false),
+    "a8cda373" => (1, false, r#"Hagane no Renkinjutsushi - FullMetal Alchemist - Meisou no Rondo (Japan)"#, false),
+    "c6144316" => (1, false, r#"Hagane no Renkinjutsushi - FullMetal Alchemist - Omoide no Sonata (Japan)"#, false),
+    "782dc7eb" => (2, false, r#"Hajime no Ippo - The Fighting! (Japan)"#, false),
+    "5875c07a" => (3, false, r#"Hamepane - Tokyo Mew Mew (Japan)"#, false),
+    "8173f699" => (3, false, r#"Hamster Club 3 (Japan)"#, false),
+    "69cf5afb" => (3, false, r#"Hamster Club 4 (Japan)"#, false),
+    "617724c5" => (3, false, r#"Hamster Monogatari 2 GBA (Japan)"#, false),
+    "c99d3787" => (2, false, r#"Hamster Monogatari 3 GBA (Japan)"#, false),
+    "5dcbcc79" => (1, false, r#"Hamster Monogatari 3EX, 4, Special (Japan)"#, false),
+    "35584ea6" => (2, false, r#"Hamster Monogatari Collection (Japan)"#, false),
+    "0e9d0144" => (2, false, r#"Hamster Paradise - Pure Heart (Japan)"#, false),
+    "902befb0" => (2, false, r#"Hamster Paradise Advanchu (Japan)"#, false),
+    "d9341d9c" => (3, false, r#"Hamtaro - Ham-Ham Games (Europe)"#, false),
+    "cc1cf806" => (3, false, r#"Hamtaro - Ham-Ham Games (USA) ~ Tottoko Hamtarou: Ham Ham Sports (Japan)"#, false),
+    "ecbd80ea" => (3, false, r#"Hamtaro - Ham-Ham Heartbreak (Europe)"#, false),
+    "cd48b673" => (3, false, r#"Hamtaro - Ham-Ham Heartbreak (USA)"#, false),
+    "5d051081" => (3, false, r#"Hamtaro - Rainbow Rescue (Europe)"#, false),
+    "173a6c4a" => (4, false, r#"Hanabi Hyakkei Advance (Japan)"#, false),
+    "de7b8dcd" => (1, false, r#"Hanafuda Trump Mahjong - Depachika Wayouchuu (Japan)"#, false),
+    "7a53173c" => (1, false, r#"Happy Feet (Europe)"#, false),
+    "682617cf" => (1, false, r#"Happy Feet (USA)"#, false),
+    "5466c757" => (1, false, r#"Hardcore Pinball (Europe, USA)"#, false),
+    "2be4f2bc" => (0, false, r#"Hardcore Pool (Europe)"#, false),
+    "44779175" => (0, false, r#"Hardcore Pool (France)"#, false),
+    "37b20866" => (0, false, r#"Harlem Globetrotters - World Tour (Europe)"#, false),
+    "737c286f" => (0, false, r#"Harlem Globetrotters - World Tour (USA)"#, false),
+    "f54976c8" => (3, false, r#"Harobots - Robo Hero Battling!! (Japan)"#, false),
+    "342816db" => (1, false, r#"Harry Potter and the Chamber of Secrets (Europe, USA)"#, false),
+    "c0bccaad" => (2, false, r#"Harry Potter and the Prisoner of Azkaban (Europe, USA)"#, false),
+    "f755065c" => (1, false, r#"Harry Potter and the Philosopher's Stone (Europe)"#, false),
+    "a069750d" => (1, false, r#"Harry Potter to Kenja no Ishi (Japan)"#, false),
+    "b485dabf" => (1, false, r#"Harry Potter to Himitsu no Heya (Japan)"#, false),
+    "486041a4" => (1, false, r#"Harry Potter - Quidditch World Cup (Europe, USA)"#, false),
+    "c1f26f37" => (1, false, r#"Harry Potter - Quidditch World Cup (Japan)"#, false),
+    "42e590d6" => (2, false, r#"Harry Potter to Azkaban no Shuujin (Japan)"#, false),
+    "052708d7" => (1, false, r#"Harry Potter and the Goblet of Fire (Europe, USA)"#, false),
+    "302b8b3d" => (1, false, r#"Harry Potter and the Order of the Phoenix (Europe)"#, false),
+    "ffa9f4fb" => (2, false, r#"Harry Potter Collection (Europe)"#, false),
+    "f50f82ad" => (3, false, r#"Harvest Moon - Friends of Mineral Town (Europe)"#, false),
+    "c11757d7" => (3, false, r#"Harvest Moon - Friends of Mineral Town (Germany)"#, false),
+    "8e923168" => (3, false, r#"Harvest Moon - Friends of Mineral Town (USA)"#, false),
+    "1736b957" => (3, false, r#"Harvest Moon - More Friends of Mineral Town (USA)"#, false),
+    "6139e34d" => (3, false, r#"Hatena Satena (Japan)"#, false),
+    "debc5439" => (0, false, r#"The Haunted Mansion (USA, prototype)"#, false),
+    "e8c44fb8" => (1, false, r#"Heidi (Europe)"#, false),
+    "9c45920e" => (0, false, r#"Hello Kitty - Happy Party Pals (Europe, English)"#, false),
+    "1a080c10" => (0, false, r#"Hello Kitty - Happy Party Pals (Europe, English / French / German / Spanish)"#, false),
+    "e49c45a0" => (0, false, r#"Hello Kitty - Happy Party Pals (USA)"#, false),
+    "c8f4d874" => (2, false, r#"Hello Kitty Collection - Miracle Fashion Maker (Japan)"#, false),
+    "304bdfc3" => (2, false, r#"Hello! Idol Debut - Kids Idol Ikusei Game (Japan)"#, false),
+    "2521a98c" => (1, false, r#"Herbie - Fully Loaded (Europe)"#, false),
+    "9c6a3dd1" => (1, false, r#"Herbie - Fully Loaded (USA)"#, false),
+    "2b666fed" => (0, false, r#"Hey Arnold! - The Movie (Europe)"#, false),
+    "59dfab9e" => (0, false, r#"Hey Arnold! - The Movie (USA)"#, false),
+    "9e4773ba" => (0, false, r#"Hey Arnold! - The Movie (Europe, prototype)"#, false),
+    "e5531d04" => (1, false, r#"Hi Hi Puffy AmiYumi - Kaznapped! (Europe)"#, false),
+    "90239c3d" => (1, false, r#"Hi Hi Puffy AmiYumi (Japan)"#, false),
+    "e613aa43" => (1, false, r#"Hi Hi Puffy AmiYumi - Kaznapped! (USA)"#, false),
+    "c23ac287" => (2, false, r#"Higanbana (Japan, rev. 1)"#, false),
+    "6ea3f4e4" => (1, false, r#"High Heat Major League Baseball 2002 (Europe, USA)"#, false),
+    "59d72cb9" => (2, false, r#"High Heat Major League Baseball 2003 (USA)"#, false),
+    "0d9043a4" => (2, false, r#"High Heat Major League Baseball 2003 (Japan)"#, false),
+    "7b31d4da" => (1, false, r#"High School Musical (USA)"#, false),
+    "c340d5f7" => (1, false, r#"High School Musical (USA, prototype)"#, false),
+    "4955fa1b" => (0, false, r#"Hikaru no Go (Japan, promotional demo)"#, false),
+    "5a6c7537" => (3, false, r#"Hikaru no Go (Japan)"#, false),
+    "53116fd7" => (3, false, r#"Hikaru no Go (Japan, rev. 1)"#, false),
+    "04129a22" => (3, false, r#"Hikaru no Go 2 (Japan)"#, false),
+    "8ea9afcc" => (2, false, r#"Himawari Doubutsu Byouin - Pet no Oishasan Ikusei Game (Japan)"#, false),
+    "46810f68" => (3, false, r#"Hime Kishi Monogatari - Princess Blue (Japan)"#, false),
+    "d4eac9b1" => (2, false, r#"Hitsuji no Kimochi. (Japan)"#, false),
+    "eacdfe0f" => (1, false, r#"The Hobbit (Europe)"#, false),
+    "d3f654b3" => (1, false, r#"The Hobbit (USA)"#, false),
+    "65fb830a" => (1, false, r#"Hobbit no Bouken - Lord of the Rings - Hajimari no Monogatari (Japan)"#, false),
+    "d88feb4e" => (0, false, r#"Disney's Home on the Range (Europe)"#, false),
+    "dbd4a6cb" => (0, false, r#"Disney's Home on the Range (USA)"#, false),
+    "5b2b4452" => (2, false, r#"Horsez (USA)"#, false),
+    "683d772c" => (3, false, r#"Hoshi no Kirby - Kagami no Daimeikyuu (Japan, rev. 1)"#, false),
+    "bf80f1a1" => (3, false, r#"Hoshi no Kirby - Kagami no Daimeikyuu (Japan, demo?)"#, false),
+    "c55a3c2c" => (3, false, r#"Hoshi no Kirby - Yume no Izumi Deluxe (Japan)"#, false),
+    "99175c62" => (1, false, r#"Hot Potato! (Europe, English)"#, false),
+    "2eb72b95" => (1, false, r#"Hot Potato! (Europe, English / French / German)"#, false),
+    "5acb7a95" => (1, false, r#"Hot Potato! (USA)"#, false),
+    "74e787dc" => (0, false, r#"Hot Wheels - All Out (Europe)"#, false),
+    "c73658f9" => (0, false, r#"Hot Wheels - All Out (USA)"#, false),
+    "4764d029" => (1, false, r#"Hot Wheels - Burnin' Rubber (Europe)"#, false),
+    "37dcbb0d" => (1, false, r#"Hot Wheels - Burnin' Rubber (USA)"#, false),
+    "d0ab6a37" => (0, false, r#"Hot Wheels - Stunt Track Challenge (Europe, USA)"#, false),
+    "a7ddbca3" => (0, false, r#"Hot Wheels - Velocity X (Europe)"#, false),
+    "9592f553" => (0, false, r#"Hot Wheels - Velocity X (USA)"#, false),
+    "b9f17f8f" => (0, false, r#"Hot Wheels - World Race (Europe)"#, false),
+    "cb313505" => (0, false, r#"Hot Wheels - World Race (USA)"#, false),
+    "f79ffedf" => (1, false, r#"Hot Wheels Advance (Japan)"#, false),
+    "9d22604d" => (0, false, r#"Hudson Best Collection Vol. 1 - Bomberman Collection (Japan)"#, false),
+    "87390220" => (0, false, r#"Hudson Best Collection Vol. 2 - Lode Runner Collection (Japan)"#, false),
+    "c207a245" => (0, false, r#"Hudson Best Collection Vol. 3 - Action Collection (Japan)"#, false),
+    "6472067a" => (0, false, r#"Hudson Best Collection Vol. 4 - Nazotoki Collection (Japan)"#, false),
+    "39d88481" => (0, false, r#"Hudson Best Collection Vol. 5 - Shooting Collection (Japan)"#, false),
+    "c51cd05f" => (0, false, r#"Hudson Best Collection Vol. 6 - Bouken-jima Collection (Japan)"#, false),
+    "b34a2071" => (0, false, r#"Hugo - Bukkazoom! (Europe)"#, false),
+    "9e9d5ad7" => (1, false, r#"Hugo - The Evil Mirror Advance (Europe)"#, false),
+    "ed5ca311" => (1, false, r#"Hugo - The Evil Mirror Advance (USA)"#, false),
+    "86c21aeb" => (3, false, r#"Hunter X Hunter - Minna Tomodachi Daisakusen!! (Japan, rev. 1)"#, false),
+    "af22a5ea" => (3, false, r#"Hunter X Hunter - Minna Tomodachi Daisakusen!! (Japan)"#, false),
+    "1a8b6863" => (2, false, r#"Hyper Sports 2002 Winter (Japan)"#, false),
+    "946d2cbb" => (1, false, r#"I Spy Challenger! (USA)"#, false),
+    "10179d72" => (0, false, r#"Ice Age (Europe)"#, false),
+    "6d457f4c" => (0, false, r#"Ice Age (Japan)"#, false),
+    "3fc99714" => (0, false, r#"Ice Age (USA)"#, false),
+    "58043308" => (1, false, r#"Ice Age 2 - The Meltdown (Europe)"#, false),
+    "000206c0" => (1, false, r#"Ice Age 2 - The Meltdown (USA)"#, false),
+    "14c23257" => (1, false, r#"Ice Nine (Europe, USA)"#, false),
+    "7e00422c" => (1, false, r#"The Incredible Hulk (Europe)"#, false),
+    "0b978dfa" => (1, false, r#"The Incredible Hulk (USA)"#, false),
+    "194d5f6c" => (0, false, r#"The Incredibles (Europe, USA)"#, false),
+    "77cbdc3b" => (0, false, r#"The Incredibles (Europe, French / Dutch)"#, false),
+    "bdc06e57" => (0, false, r#"Die Unglaublichen (Germany)"#, false),
+    "615839ef" => (0, false, r#"Gli Incredibili - Una 'Normale' Famiglia di Supereroi (Italy)"#, false),
+    "fc6ccadb" => (0, false, r#"Los Increibles (Spain)"#, false),
+    "2d806290" => (0, false, r#"The Incredibles - Rise of the Underminer (Europe, USA)"#, false),
+    "096f98b6" => (0, false, r#"The Incredibles - Rise of the Underminer (Europe, English / French / German /
+			Italian / Dutch / Spanish / Portuguese)"#, false),
+    "23110a94" => (3, false, r#"Initial D - Another Stage (Japan)"#, false),
+    "406aa0c6" => (0, false, r#"Inspector Gadget - Advance Mission (Europe)"#, false),
+    "ef131209" => (0, false, r#"Inspector Gadget - Advance Mission (USA)"#, false),
+    "b0d1c070" => (0, false, r#"Inspector Gadget Racing (Europe)"#, false),
+    "d33a14af" => (0, false, r#"International Karate Advanced (Europe)"#, false),
+    "1052a0ea" => (0, false, r#"International Karate Plus (Europe)"#, false),
+    "31c4f03b" => (0, false, r#"International Karate Plus (USA)"#, false),
+    "9989ce55" => (2, false, r#"International Superstar Soccer (Europe)"#, false),
+    "7eb2f6d6" => (2, false, r#"International Superstar Soccer Advance (Europe)"#, false),
+    "d473b3c1" => (3, false, r#"Inukko Club (Japan)"#, false),
+    "8fcdf69c" => (3, false, r#"Inuyasha - Naraku no Wana! Mayoi no Mori no Shoutaijou (Japan)"#, false),
+    "a94d6058" => (0, false, r#"Invader (Europe)"#, false),
+    "39a31b68" => (1, false, r#"The Invincible Iron Man (Europe, USA)"#, false),
+    "34189e74" => (0, false, r#"Iridion 3D (Europe, USA)"#, false),
+    "57930c6a" => (0, false, r#"Iridion II (Europe)"#, false),
+    "dd8a7104" => (0, false, r#"Iridion II (USA)"#, false),
+    "326e7a8e" => (1, false, r#"Iron Kid (Korea)"#, false),
+    "cb5eff64" => (3, false, r#"Isseki Hatchou - Kore 1ppon de 8shurui! (Japan)"#, false),
+    "757a4efb" => (2, false, r#"It's Mr. Pants (Europe, USA)"#, false),
+    "0524e9ef" => (3, false, r#"J.League Pocket (Japan, rev. 1)"#, false),
+    "51be73b8" => (3, false, r#"J.League Pocket (Japan)"#, false),
+    "dc7d2e4a" => (3, false, r#"J.League Pocket 2 (Japan)"#, false),
+    "8bc59927" => (3, false, r#"J.League Pro Soccer Club o Tsukurou! Advance (Japan)"#, false),
+    "aee73fe7" => (2, false, r#"J.League Winning Eleven Advance 2002 (Japan)"#, false),
+    "13247954" => (1, false, r#"Jackie Chan Adventures - Legend of the Darkhand (Europe, USA)"#, false),
+    "d5fb19a0" => (1, false, r#"Les Aventures de Jackie Chan - La Légende de la Main Noire (France)"#, false),
+    "8ce6bce8" => (1, false, r#"Jajamaru Jr. Denshouki - Jalecolle mo Arisourou (Japan)"#, false),
+    "31081996" => (0, false, r#"James Pond - Codename RoboCod (Europe)"#, false),
+    "eb25bb5b" => (0, false, r#"James Pond - Codename RoboCod (USA)"#, false),
+    "948531d4" => (1, false, r#"Jazz Jackrabbit (Europe, USA)"#, false),
+    "ae4142b5" => (1, false, r#"Jet Grind Radio (USA)"#, false),
+    "716dc010" => (1, false, r#"Jet Set Radio (Europe)"#, false),
+    "0ba5809f" => (5, false, r#"JGTO Kounin Golf Master - Japan Golf Tour Game (Japan)"#, false),
+    "b863dae1" => (5, false, r#"JGTO Kounin Golf Master Mobile - Japan Golf Tour Game (Japan)"#, false),
+    "8bd96db9" => (2, false, r#"Jikkyou World Soccer Pocket (Japan)"#, false),
+    "b80aa966" => (2, false, r#"Jikkyou World Soccer Pocket 2 (Japan)"#, false),
+    "a9423234" => (0, false, r#"Jimmy Neutron Boy Genius (Europe)"#, false),
+    "d3ee0c51" => (0, false, r#"Jimmy Neutron Boy Genius (USA)"#, false),
+    "84a4a304" => (3, false, r#"Jinsei Game Advance (Japan)"#, false),
+    "07195201" => (5, false, r#"Jissen Pachi-Slot Hisshouhou! - Juuou Advance (Japan)"#, false),
+    "0e5c38b7" => (3, false, r#"Jisu F-Zero Weilai Saiche (China)"#, false),
+    "96488632" => (1, false, r#"Jonny Moseley Mad Trix (USA)"#, false),
+    "e62bffeb" => (1, false, r#"Juka and the Monophonic Menace (Europe)"#, false),
+    "0c04a2f9" => (1, false, r#"Juka and the Monophonic Menace (USA)"#, false),
+    "9898258b" => (0, false, r#"Disney's The Jungle Book 2 (Europe)"#, false),
+    "e9a21637" => (0, false, r#"Disney's The Jungle Book (USA)"#, false),
+    "ddceb011" => (1, false, r#"Jurassic Park III - Dino Attack (Europe)"#, false),
+    "d18f75e5" => (1, false, r#"Jurassic Park III - Advanced Action (Japan)"#, false),
+    "1e7048d2" => (1, false, r#"Jurassic Park III - Island Attack (USA)"#, false),
+    "f2bf8990" => (3, false, r#"Jurassic Park III - Kyouryuu ni Ainiikou! (Japan)"#, false),
+    "51061eff" => (3, false, r#"Jurassic Park III - Park Builder (Europe)"#, false),
+    "edddcf19" => (3, false, r#"Jurassic Park III - Park Builder (USA)"#, false),
+    "fe89c986" => (1, false, r#"Jurassic Park III - The DNA Factor (Europe)"#, false),
+    "f93dcc2e" => (1, false, r#"Jurassic Park III - The DNA Factor (USA)"#, false),
+    "ab6786dd" => (1, false, r#"Jurassic Park III - Ushinawareta Idenshi (Japan)"#, false),
+    "7c75555e" => (1, false, r#"Jurassic Park Institute Tour - Dinosaur Rescue (Japan)"#, false),
+    "d71c4382" => (1, false, r#"Justice League - Injustice for All (Europe)"#, false),
+    "c41081cf" => (1, false, r#"Justice League - Injustice for All (USA, prototype)"#, false),
+    "b2477b72" => (1, false, r#"Justice League - Injustice for All (USA)"#, false),
+    "07090f67" => (1, false, r#"Justice League Chronicles (USA)"#, false),
+    "acb22fff" => (1, false, r#"Justice League Heroes - The Flash (Europe)"#, false),
+    "87a4d12c" => (1, false, r#"Justice League Heroes - The Flash (USA)"#, false),
+    "fbdd4384" => (2, false, r#"K-1 Pocket Grand Prix (Japan)"#, false),
+    "19fde4c8" => (2, false, r#"K-1 Pocket Grand Prix 2 (Japan)"#, false),
+    "de5987bd" => (3, false, r#"Kaeru B Back (Japan)"#, false),
+    "259cdd6f" => (2, false, r#"Kaiketsu Zorori to Mahou no Yuuenchi - Ohimesama o Sukue! (Japan)"#, false),
+    "62992510" => (0, false, r#"Kaisertal - Fight the Necronis War (Europe, demo)"#, false),
+    "82b4f3fa" => (5, false, r#"Kamaitachi no Yoru Advance (Japan)"#, false),
+    "906c73ad" => (3, false, r#"Kami no Kijutsu - Illusion of the Evil Eyes (Japan)"#, false),
+    "51e7522c" => (0, false, r#"Kao the Kangaroo (Europe)"#, false),
+    "f6667b3e" => (0, false, r#"Kao the Kangaroo (USA)"#, false),
+    "bf5f326e" => (3, false, r#"Kappa no Kai-kata - Kaatan Daibouken! (Japan)"#, false),
+    "63fe3dfe" => (4, false, r#"Karnaaj Rally (Europe, USA)"#, false),
+    "4f09ca00" => (3, false, r#"Kawa no Nushi Tsuri 3 & 4 (Japan)"#, false),
+    "4a8f2f62" => (2, false, r#"Kawa no Nushi Tsuri 5 - Fushigi no Mori kara (Japan)"#, false),
+    "2e23c8f5" => (1, false, r#"Kawaii Koinu Wonderful (Japan)"#, false),
+    "bc5abd75" => (1, false, r#"Kawaii Pet Game Gallery (Japan)"#, false),
+    "c8c8c4c1" => (1, false, r#"Kawaii Pet Game Gallery 2 (Japan)"#, false),
+    "39aefb7b" => (2, false, r#"Kawaii Pet Shop Monogatari 3 (Japan)"#, false),
+    "b51d97de" => (1, false, r#"Kaze no Klonoa - Yumemiru Teikoku (Japan)"#, false),
+    "8d98dc2c" => (1, false, r#"Kaze no Klonoa G2 - Dream Champ Tournament (Japan)"#, false),
+    "44db280e" => (5, false, r#"Keitai Denjuu Telefang 2 - Power (Japan)"#, false),
+    "8f95ca53" => (5, false, r#"Keitai Denjuu Telefang 2 - Speed (Japan)"#, false),
+    "31f85dbe" => (1, false, r#"Kelly Slater's Pro Surfer (Europe, USA)"#, false),
+    "c026cbbb" => (3, false, r#"Keroro Gunsou - Taiketsu! Gekisou Keronprix Daisakusen de Arimasu!! (Japan)"#, false),
+    "3e7d9694" => (1, false, r#"Kessaku Sen! - Ganbare Goemon 1, 2 - Yuki Hime to Magginesu (Japan)"#, false),
+    "69951aa9" => (1, false, r#"Kid Paddle (Europe)"#, false),
+    "6f931445" => (0, false, r#"Kid's Cards (USA)"#, false),
+    "a7825224" => (1, false, r#"Kidou Gekidan Haro Ichiza Haro no Puyo Puyo (Japan)"#, false),
+    "73bf384b" => (2, false, r#"Kidou Senshi Gundam Seed - Tomo to Kimi to Koko de. (Japan)"#, false),
+    "aa250cca" => (1, false, r#"Kidou Senshi Gundam Seed Destiny (Japan)"#, false),
+    "b21ee600" => (2, false, r#"Kidou Tenshi Angelic Layer - Misaki to Yume no Tenshi-tachi (Japan)"#, false),
+    "185c2eca" => (0, false, r#"Kien (USA, prototype)"#, false),
+    "a2fa4248" => (2, false, r#"Kikaika Guntai - Mech Platoon (Japan)"#, false),
+    "1c61225b" => (0, false, r#"Kiki KaiKai Advance (Japan)"#, false),
+    "4f468670" => (1, false, r#"Kill.Switch (Europe)"#, false),
+    "2b207d1f" => (1, false, r#"Kill.Switch (USA)"#, false),
+    "e6fda065" => (0, false, r#"Killer 3D Pool (Europe)"#, false),
+    "1f12d048" => (0, false, r#"Killer 3D Pool (USA)"#, false),
+    "48428c2a" => (0, false, r#"Disney's Kim Possible (Europe, rev. 1)"#, false),
+    "0c35d0fc" => (0, false, r#"Disney's Kim Possible (Europe)"#, false),
+    "1a56efbd" => (0, false, r#"Disney's Kim Possible (USA)"#, false),
+    "69843f73" => (1, false, r#"Disney's Kim Possible (Japan)"#, false),
+    "71c505c8" => (1, false, r#"Disney's Kim Possible 2 - Drakken's Demise (Europe)"#, false),
+    "fa946fc6" => (1, false, r#"Disney's Kim Possible 2 - Drakken's Demise (USA)"#, false),
+    "fdec60ec" => (1, false, r#"Disney's Kim Possible III - Team Possible (USA)"#, false),
+    "37387b19" => (1, false, r#"King Kong - The Official Game of the Movie (Europe, English / French / German /
+			Spanish / Italian / Dutch)"#, false),
+    "17e66b52" => (1, false, r#"The King of Fighters EX - NeoBlood (Europe)"#, false),
+    "811ffcfa" => (0, false, r#"The King of Fighters EX - NeoBlood (Japan, prototype)"#, false),
+    "0814c78d" => (1, false, r#"The King of Fighters EX - NeoBlood (Japan)"#, false),
+    "0f960e70" => (1, false, r#"The King of Fighters EX - NeoBlood (USA, rev. 1)"#, false),
+    "2b92eb8e" => (1, false, r#"The King of Fighters EX - NeoBlood (USA)"#, false),
+    "9e8baa87" => (1, false, r#"The King of Fighters EX2 - Howling Blood (Europe)"#, false),
+    "dc5beacd" => (1, false, r#"The King of Fighters EX2 - Howling Blood (Japan, rev. 1)"#, false),
+    "15f8f9d5" => (1, false, r#"The King of Fighters EX2 - Howling Blood (USA)"#, false),
+    "7704ff39" => (1, false, r#"The King of Fighters EX2 - Howling Blood (USA, prototype, 20030403)"#, false),
+    "772d97fb" => (3, false, r#"Kingdom Hearts - Chain of Memories (Europe)"#, false),
+    "8d5a0d84" => (3, false, r#"Kingdom Hearts - Chain of Memories (Japan)"#, false),
+    "d031ce67" => (3, false, r#"Kingdom Hearts - Chain of Memories (USA)"#, false),
+    "40b1dd00" => (3, false, r#"Kinniku Banzuke - Kimero! Kiseki no Kanzen Seiha (Japan)"#, false),
+    "94519d2a" => (3, false, r#"Kinniku Banzuke - Kongou-kun no Daibouken! (Japan)"#, false),
+    "3fe9c701" => (1, false, r#"Kinnikuman II-Sei - Seigi Choujin e no Michi (Japan)"#, false),
+    "4f07c618" => (3, false, r#"Kirby & the Amazing Mirror (Europe)"#, false),
+    "9f2a3048" => (3, false, r#"Kirby & the Amazing Mirror (USA)"#, false),
+    "3b7a7477" => (3, false, r#"Kirby - Nightmare in Dream Land (Europe)"#, false),
+    "20ef3f64" => (3, false, r#"Kirby - Nightmare in Dream Land (USA)"#, false),
+    "34d65c0b" => (2, false, r#"Kisekko Gurumii - Chesty to Nuigurumi-tachi no Mahou no Bouken (Japan)"#, false),
+    "0c368530" => (2, false, r#"Kiss x Kiss Seirei Gakuen (Japan)"#, false),
+    "66f7614b" => (3, false, r#"Kiwame Mahjong Deluxe - Mirai Senshi 21 (Japan)"#, false),
+    "69492530" => (1, false, r#"Klonoa - Empire of Dreams (Europe)"#, false),
+    "f74e1036" => (1, false, r#"Klonoa - Empire of Dreams (USA)"#, false),
+    "8bd23a7f" => (1, false, r#"Klonoa 2 - Dream Champ Tournament (USA)"#, false),
+    "e7eaddcc" => (2, false, r#"Klonoa Heroes - Densetsu no Star Medal (Japan)"#, false),
+    "f027e89f" => (0, false, r#"Knights' Kingdom (Europe)"#, false),
+    "88fe3f31" => (0, false, r#"Knights' Kingdom (USA)"#, false),
+    "abd866ea" => (0, false, r#"The Koala Brothers - Outback Adventures (Europe)"#, false),
+    "6ec76bce" => (0, false, r#"The Koala Brothers - Outback Adventures (USA)"#, false),
+    "30e209aa" => (1, false, r#"Koinu to Issho - Aijou Monogatari (Japan)"#, false),
+    "2d2ac33b" => (1, false, r#"Koinu to Issho 2 (Japan)"#, false),
+    "1d27165a" => (2, false, r#"Koinu-chan no Hajimete no Osanpo - Koinu no Kokoro Ikusei Game (Japan)"#, false),
+    "6a8be56f" => (0, false, r#"Konami Collector's Series - Arcade Classics (Europe)"#, false),
+    "285f652f" => (0, false, r#"Konami Arcade Game Collection (Japan)"#, false),
+    "2db31825" => (0, false, r#"Konami Collector's Series - Arcade Advanced (USA)"#, false),
+    "cfec0650" => (3, false, r#"Konami Krazy Racers (Europe)"#, false),
+    "5f2ae8fe" => (3, false, r#"Konami Krazy Racers (USA, prototype)"#, false),
+    "c80d4f5c" => (3, false, r#"Konami Krazy Racers (USA)"#, false),
+    "aa039a8a" => (3, false, r#"Konami Wai Wai Racing Advance (Japan)"#, false),
+    "3aa0ce79" => (2, false, r#"Konchuu Monster Battle Master (Japan)"#, false),
+    "5adb68ef" => (2, false, r#"Konchuu Monster Battle Stadium (Japan)"#, false),
+    "88c56d65" => (1, false, r#"Konchuu no Mori no Daibouken - Fushigi na Sekai no Juunin-tachi (Japan)"#, false),
+    "9c12cd31" => (0, false, r#"Kong - King of Atlantis (Europe)"#, false),
+    "5e8b2644" => (0, false, r#"Kong - King of Atlantis (USA)"#, false),
+    "06079c42" => (1, false, r#"Kong - The 8th Wonder of the World (USA)"#, false),
+    "7a7b060f" => (0, false, r#"Kong - The Animated Series (Europe)"#, false),
+    "150bdd9e" => (0, false, r#"Kong - The Animated Series (USA)"#, false),
+    "d66379e1" => (3, false, r#"Konjiki no Gashbell!! - Makai no Bookmark (Japan)"#, false),
+    "64fab050" => (2, false, r#"Konjiki no Gashbell!! - Unare! Yuujou no Zakeru (Japan)"#, false),
+    "b7827f20" => (1, false, r#"Konjiki no Gashbell!! - Unare! Yuujou no Zakeru 2 (Japan)"#, false),
+    "e1e26f2a" => (2, false, r#"Konjiki no Gashbell!! The Card Battle for GBA (Japan)"#, false),
+    "fb5516ad" => (1, false, r#"Konjiki no Gashbell!! Yuujou no Zakeru - Dream Tag Tournament (Japan)"#, false),
+    "0bfe46e9" => (0, false, r#"Koro Koro Puzzle - Happy Panechu! (Japan)"#, false),
+    "fb67efbc" => (3, false, r#"Kotoba no Puzzle - Mojipittan Advance (Japan)"#, false),
+    "7ac9ffbf" => (3, false, r#"Kouchu Ouja Mushiking - Greatest Champion e no Michi (Japan)"#, false),
+    "576da6f6" => (3, false, r#"Koukou Juken Advance Series Eigo Koubun Hen - 26 Units Shuuroku (Japan)"#, false),
+    "7f2f5017" => (3, false, r#"Koukou Juken Advance Series Eijukugo Hen - 650 Phrases Shuuroku (Japan)"#, false),
+    "d38f6a8e" => (3, false, r#"Koukou Juken Advance Series Eitango Hen - 2000 Words Shuuroku (Japan)"#, false),
+    "cdfac4ee" => (1, false, r#"Koutetsu Teikoku from HOT・B (Japan)"#, false),
+    "4b96b600" => (0, false, r#"Kunio Kun Nekketsu Collection 1 (Japan)"#, false),
+    "61f9a9f4" => (0, false, r#"Kunio Kun Nekketsu Collection 2 (Japan)"#, false),
+    "093054fc" => (0, false, r#"Kunio Kun Nekketsu Collection 3 (Japan)"#, false),
+    "abfd7ac7" => (3, false, r#"Kurohige no Golf Shiyouyo (Japan)"#, false),
+    "99c917e9" => (3, false, r#"Kurohige no Kurutto Jintori (Japan)"#, false),
+    "c4dab956" => (3, false, r#"Kurukuru Kururin (Europe)"#, false),
+    "8a6a900b" => (3, false, r#"Kurukuru Kururin (Japan)"#, false),
+    "93d036f5" => (3, false, r#"Kururin Paradise (Japan)"#, false),
+    "958097d2" => (1, false, r#"Lady Sia (Europe)"#, false),
+    "139e95bd" => (1, false, r#"Lady Sia (USA)"#, false),
+    "cf0fa4d9" => (0, false, r#"The Land Before Time (Europe)"#, false),
+    "cfd8a52c" => (0, false, r#"The Land Before Time (USA)"#, false),
+    "5485eefa" => (1, false, r#"The Land Before Time - Into the Mysterious Beyond (Europe)"#, false),
+    "cff03df3" => (1, false, r#"The Land Before Time - Into the Mysterious Beyond (USA)"#, false),
+    "e2ff36c7" => (1, false, r#"Lara Croft Tomb Raider - Legend (Europe)"#, false),
+    "5a3dfb37" => (1, false, r#"Lara Croft Tomb Raider - Legend (USA)"#, false),
+    "4235fac3" => (0, false, r#"Lara Croft Tomb Raider - The Prophecy (Europe)"#, false),
+    "7799e1da" => (0, false, r#"Lara Croft Tomb Raider - The Prophecy (Japan)"#, false),
+    "556f40fa" => (0, false, r#"Lara Croft Tomb Raider - The Prophecy (USA)"#, false),
+    "03708dcd" => (0, false, r#"Lara Croft Tomb Raider - The Prophecy (USA, prototype)"#, false),
+    "6a808027" => (2, false, r#"Léa - Passion Vétérinaire ~ My Pet Hotel (France)"#, false),
+    "67f18f8e" => (3, false, r#"Legend of Dynamic Goushouden - Houkai no Rondo (Japan)"#, false),
+    "63df4d36" => (2, false, r#"The Legend of Spyro - A New Beginning (Europe)"#, false),
+    "1b81cc00" => (2, false, r#"The Legend of Spyro - A New Beginning (USA)"#, false),
+    "93b18353" => (1, false, r#"The Legend of Spyro - The Eternal Night (Europe)"#, false),
+    "bd2751e6" => (1, false, r#"The Legend of Spyro - The Eternal Night (USA)"#, false),
+    "5a164321" => (2, false, r#"The Legend of Zelda - A Link to the Past & Four Swords (Europe)"#, false),
+    "8e91cd13" => (2, false, r#"The Legend of Zelda - A Link to the Past & Four Swords (USA, Australia)"#, false),
+    "e8637292" => (2, false, r#"The Legend of Zelda - The Minish Cap (Europe)"#, false),
+    "023da338" => (0, false, r#"The Legend of Zelda - The Minish Cap (USA, demo Kiosk)"#, false),
+    "abcebbb1" => (2, false, r#"The Legend of Zelda - The Minish Cap (USA)"#, false),
+    "84440066" => (1, false, r#"Legends of Wrestling II (Europe, USA)"#, false),
+    "8df9759d" => (4, false, r#"Legendz - Buhwarhaneun Siryeonyi Seom (Korea)"#, false),
+    "98432a11" => (4, true, r#"Legendz - Sign of Nekuromu (Japan)"#, false),
+    "33950228" => (4, true, r#"Legendz - Yomigaeru Shiren no Shima (Japan)"#, false),
+    "121ab9c3" => (3, false, r#"LEGO Bionicle (Europe)"#, false),
+    "3e5ec871" => (3, false, r#"LEGO Bionicle (USA)"#, false),
+    "3beb5446" => (1, false, r#"Island - Xtreme Stunts (Europe, USA)"#, false),
+    "c7b5d987" => (3, false, r#"LEGO Island 2 - The Brickster's Revenge (Europe)"#, false),
+    "3f513cbf" => (3, false, r#"LEGO Island 2 - The Brickster's Revenge (USA)"#, false),
+    "7d05ae2c" => (1, false, r#"LEGO Racers 2 (Europe, prototype)"#, false),
+    "7a1dc458" => (1, false, r#"LEGO Racers 2 (Europe)"#, false),
+    "e13b63ad" => (1, false, r#"LEGO Racers 2 (USA)"#, false),
+    "73ef3a35" => (1, false, r#"LEGO Football Mania (Europe)"#, false),
+    "55a5aaf2" => (1, false, r#"LEGO Star Wars - The Video Game (Japan)"#, false),
+    "aeabfa5f" => (1, false, r#"LEGO Star Wars - The Video Game (Europe, USA)"#, false),
+    "bba91c3b" => (1, false, r#"LEGO Star Wars II - The Original Trilogy (Europe)"#, false),
+    "da1e9444" => (1, false, r#"LEGO Star Wars II - The Original Trilogy (USA)"#, false),
+    "f7ac3d9e" => (1, false, r#"Lemony Snicket - Rätselhafte Ereignisse (Germany)"#, false),
+    "aa6e22d7" => (1, false, r#"Lemony Snicket - Una Serie di Sfortunati Eventi (Italy)"#, false),
+    "63e02a0d" => (1, false, r#"Lemony Snicket's A Series of Unfortunate Events (Europe, French / Spanish)"#, false),
+    "72ba6b8c" => (1, false, r#"Lemony Snicket's A Series of Unfortunate Events (Europe, USA)"#, false),
+    "1d96366c" => (2, false, r#"Let's Ride! - Dreamer (USA)"#, false),
+    "8bdd13ad" => (2, false, r#"Let's Ride! - Friends Forever (USA)"#, false),
+    "684eb887" => (2, false, r#"Let's Ride! - Sunshine Stables (USA)"#, false),
+    "e9f62fe0" => (3, false, r#"Licca-chan no Oshare Nikki (Japan)"#, false),
+    "1afab451" => (2, false, r#"Lilliput Oukoku - Lillimoni to Issho Puni! (Japan)"#, false),
+    "e7bc4ef1" => (0, false, r#"Disney's Lilo & Stitch (Europe, rev. 1)"#, false),
+    "2a10f7e4" => (0, false, r#"Disney's Lilo & Stitch (Europe)"#, false),
+    "542aa4ac" => (0, false, r#"Disney's Lilo & Stitch (USA)"#, false),
+    "a88587a6" => (1, false, r#"Disney's Lilo & Stitch 2 (Europe)"#, false),
+    "021a5755" => (1, false, r#"Disney's Lilo & Stitch 2 - Hämsterviel Havoc (USA)"#, false),
+    "db1e7311" => (1, false, r#"Disney's Lilo & Stitch (Japan)"#, false),
+    "ea5ed4c0" => (1, false, r#"Disney The Lion King 1½ (USA)"#, false),
+    "63592451" => (1, false, r#"Disney The Lion King (Europe)"#, false),
+    "d26e49f8" => (3, false, r#"Little Buster Q (Japan)"#, false),
+    "c8340b37" => (0, false, r#"Disney's Little Einsteins (USA)"#, false),
+    "b550f8cb" => (0, false, r#"Little League Baseball 2002 (USA)"#, false),
+    "02e35663" => (1, false, r#"Disney's The Little Mermaid - Magic in Two Kingdoms (Europe, USA)"#, false),
+    "8fea41c4" => (2, false, r#"Little Patissier - Cake no Oshiro (Japan)"#, false),
+    "7e9283f2" => (2, false, r#"Lizzie McGuire (Europe)"#, false),
+    "70ded768" => (2, false, r#"Lizzie McGuire - On the Go! (USA)"#, false),
+    "2706b300" => (4, false, r#"Disney's Game + TV Episode - Lizzie McGuire 2 - Lizzie Diaries (USA)"#, false),
+    "f11009f9" => (1, false, r#"Lizzie McGuire 2 - Lizzie Diaries (USA)"#, false),
+    "a2843980" => (1, false, r#"Lizzie McGuire 3 - Homecoming Havoc (USA)"#, false),
+    "3e2cf3ff" => (1, false, r#"Lode Runner (Japan)"#, false),
+    "6897efa6" => (0, false, r#"LOCKJAW: The Reference"#, false),
+    "7d286b94" => (0, false, r#"LOCKJAW: The Overdose"#, false),
+    "8ba01ad0" => (0, false, r#"Tetanus on Drugs (Milestone 3)"#, false),
+    "2fcadfd3" => (0, false, r#"Tetanus on Drugs (Preview Release 1)"#, false),
+    "2b8b3964" => (1, false, r#"Looney Tunes - Back in Action (Europe, USA)"#, false),
+    "dca23b31" => (1, false, r#"Looney Tunes Double Pack (Europe)"#, false),
+    "ecadd047" => (1, false, r#"Looney Tunes Double Pack (USA)"#, false),
+    "dc8d4667" => (1, false, r#"The Lord of the Rings - Futatsu no Tou (Japan)"#, false),
+    "cf0534f8" => (1, false, r#"The Lord of the Rings - Nakatsukuni Daisanki (Japan)"#, false),
+    "465f2753" => (1, false, r#"The Lord of the Rings - Ou no Kikan (Japan)"#, false),
+    "2380b93a" => (2, false, r#"The Lord of the Rings - The Fellowship of the Ring (Europe)"#, false),
+    "5a2b01f1" => (2, false, r#"The Lord of the Rings - The Fellowship of the Ring (USA, rev. 1)"#, false),
+    "ee0c58bc" => (2, false, r#"The Lord of the Rings - The Fellowship of the Ring (USA)"#, false),
+    "7590ebfa" => (1, false, r#"The Lord of the Rings - The Return of the King (Europe, USA)"#, false),
+    "2def3656" => (1, false, r#"The Lord of the Rings - The Third Age (Europe, USA)"#, false),
+    "a1e3bf33" => (1, false, r#"The Lord of the Rings - The Two Towers (Europe, USA)"#, false),
+    "1c008d32" => (1, false, r#"The Lost Vikings (Europe)"#, false),
+    "8cb58997" => (1, false, r#"The Lost Vikings (USA)"#, false),
+    "b8e67449" => (1, false, r#"Love Hina Advance - Shukufuku no Kane wa Naru Kana (Japan)"#, false),
+    "f12f29a9" => (0, false, r#"Lucky Luke - Wanted! (Europe)"#, false),
+    "de5ffcbc" => (3, false, r#"Lufia - The Ruins of Lore (USA)"#, false),
+    "4e044191" => (3, false, r#"Lunar Legend (Japan)"#, false),
+    "885266a2" => (2, false, r#"Lunar Legend (USA)"#, false),
+    "ad38c301" => (1, false, r#"M&M's - Blast! (USA)"#, false),
+    "a4ea65c0" => (0, false, r#"M&M's - Break 'em (USA, rev. 1)"#, false),
+    "394f2126" => (1, false, r#"Madagascar (Europe, English)"#, false),
+    "274c9135" => (1, false, r#"Madagascar (Europe, French / German / Portuguese)"#, false),
+    "9467225b" => (1, false, r#"Madagascar (Italy)"#, false),
+    "2d6d478c" => (1, false, r#"Madagascar (Japan)"#, false),
+    "ebfab740" => (1, false, r#"Madagascar (Netherlands)"#, false),
+    "0c6070b5" => (1, false, r#"Madagascar (Spain)"#, false),
+    "7503f71b" => (1, false, r#"Madagascar (USA)"#, false),
+    "15bb7278" => (1, false, r#"Madagascar - Operation Penguin (Europe, English)"#, false),
+    "5b519e47" => (1, false, r#"Madagascar - Operación Pingüino (Spain)"#, false),
+    "6b5b70c5" => (1, false, r#"Madagascar - Operation Penguin (Europe, French / German)"#, false),
+    "8d8b5808" => (1, false, r#"Madagascar - Operation Penguin (Europe, Italian / Dutch)"#, false),
+    "da3fa501" => (1, false, r#"Madagascar - Operation Penguin (USA)"#, false),
+    "374fd6f9" => (2, false, r#"Madden NFL 06 (USA)"#, false),
+    "c5805b1d" => (2, false, r#"Madden NFL 07 (USA)"#, false),
+    "8e797ecd" => (1, false, r#"Madden NFL 2002 (USA)"#, false),
+    "64b93824" => (2, false, r#"Madden NFL 2003 (USA)"#, false),
+    "170bb11a" => (2, false, r#"Madden NFL 2004 (USA)"#, false),
+    "0b79a67b" => (2, false, r#"Madden NFL 2005 (USA)"#, false),
+    "87a0eea0" => (3, false, r#"Made in Wario (Japan)"#, false),
+    "ef6807ca" => (3, false, r#"MÄR Heaven - Knockin' on Heaven's Door (Japan)"#, false),
+    "6a94531b" => (2, false, r#"Magi Nation (Japan)"#, false),
+    "70123550" => (5, false, r#"Magical Houshin (Japan)"#, false),
+    "f9498038" => (1, false, r#"Disney's Magical Quest 2 Starring Mickey & Minnie (Europe)"#, false),
+    "69f1117d" => (1, false, r#"Disney's Magical Quest 2 Starring Mickey & Minnie (USA)"#, false),
+    "aad912f9" => (1, false, r#"Disney's Magical Quest 3 Starring Mickey & Donald (Europe)"#, false),
+    "5a23094d" => (1, false, r#"Disney's Magical Quest 3 Starring Mickey & Donald (USA)"#, false),
+    "94f3b7c0" => (1, false, r#"Disney's Magical Quest Starring Mickey & Minnie (Europe)"#, false),
+    "b4294fa7" => (1, false, r#"Disney's Magical Quest Starring Mickey & Minnie (USA)"#, false),
+    "59cbe914" => (5, false, r#"Magical Vacation (Japan)"#, false),
+    "ad32a2d3" => (3, false, r#"Mahjong Keiji (Japan)"#, false),
+    "f9652b4d" => (1, false, r#"Mahou no Pumpkin - Ann to Greg no Daibouken (Japan)"#, false),
+    "79d04dad" => (3, false, r#"Mahou Sensei Negima! - Private Lesson - Damedesuu Toshokan-jima (Japan)"#, false),
+    "6357c53b" => (3, false, r#"Mahou Sensei Negima! - Private Lesson 2 - Ojamashimasuu Parasite de Chuu
+			(Japan)"#, false),
+    "3b984291" => (5, false, r#"Mail de Cute (Japan)"#, false),
+    "4fbc3f62" => (0, false, r#"Rec Room Challenge (Europe)"#, false),
+    "aafc9afa" => (0, false, r#"Rec Room Challenge (USA)"#, false),
+    "99140705" => (0, false, r#"3-in-1 Sports Pack (Europe)"#, false),
+    "a91bec73" => (0, false, r#"3-in-1 Sports Pack (USA)"#, false),
+    "91bebbee" => (1, false, r#"Majokko Cream-chan no Gokko Series 1 - Wannyan Idol Gakuen (Japan)"#, false),
+    "2ed6c55f" => (1, false, r#"Majokko Cream-chan no Gokko Series 2 - Kisekae Angel (Japan)"#, false),
+    "61a602d7" => (2, false, r#"Manga-ka Debut Monogatari (Japan)"#, false),
+    "f0c22c62" => (2, false, r#"Manga-ka Debut Monogatari (Japan, rev. 1)"#, false),
+    "2412c5eb" => (1, false, r#"Maniac Racers Advance (Europe)"#, false),
+    "138439f4" => (0, false, r#"Manic Miner (Europe)"#, false),
+    "fb3d52bd" => (0, false, r#"Manic Miner (Europe, 20030307)"#, false),
+    "f680b0f3" => (0, false, r#"March of the Penguins (Europe)"#, false),
+    "5195429f" => (0, false, r#"March of the Penguins (USA)"#, false),
+    "1b70a454" => (2, false, r#"Marie, Elie & Anis no Atelier - Soyokaze kara no Dengon (Japan)"#, false),
+    "170cc574" => (2, false, r#"Mario & Luigi - Superstar Saga (Europe)"#, false),
+    "a94b04b2" => (1, false, r#"Mario & Luigi - Superstar Saga (USA, demo Kiosk)"#, false),
+    "e718d850" => (2, false, r#"Mario & Luigi - Superstar Saga (USA, Australia)"#, false),
+    "4ef93d41" => (2, false, r#"Mario & Luigi RPG (Japan)"#, false),
+    "d6ad422f" => (3, false, r#"Mario Golf - Advance Tour (Australia)"#, false),
+    "b483127c" => (3, false, r#"Mario Golf - Advance Tour (Europe)"#, false),
+    "bd5be463" => (3, false, r#"Mario Golf - Advance Tour (France)"#, false),
+    "3e19e279" => (3, false, r#"Mario Golf - Advance Tour (Germany)"#, false),
+    "05b0454a" => (3, false, r#"Mario Golf - Advance Tour (Italy)"#, false),
+    "c35dd9f8" => (3, false, r#"Mario Golf - Advance Tour (Spain)"#, false),
+    "d56c2e54" => (3, false, r#"Mario Golf - Advance Tour (USA)"#, false),
+    "a8342a16" => (3, false, r#"Mario Golf - GBA Tour (Japan)"#, false),
+    "20025842" => (5, false, r#"Mario Kart - Super Circuit (Europe)"#, false),
+    "ed316e37" => (5, false, r#"Mario Kart - Super Circuit (USA)"#, false),
+    "30e99fcd" => (5, false, r#"Mario Kart Advance (Japan)"#, false),
+    "e3075601" => (0, false, r#"Mario Kart XXL (demo, 20040417)"#, false),
+    "c8c889e2" => (2, false, r#"Mario Party Advance (Europe)"#, false),
+    "baa4a82b" => (2, false, r#"Mario Party Advance (Japan)"#, false),
+    "f094a4cb" => (2, false, r#"Mario Party Advance (USA)"#, false),
+    "70a6d2c1" => (1, false, r#"Mario Pinball Land (USA, Australia)"#, false),
+    "c8db4f60" => (3, false, r#"Mario Power Tennis (Europe)"#, false),
+    "975e8d98" => (3, false, r#"Mario Tennis Advance (Japan)"#, false),
+    "da192d29" => (3, false, r#"Mario Tennis - Power Tour (USA, Australia)"#, false),
+    "ca030d61" => (4, false, r#"Mario vs. Donkey Kong (Europe)"#, false),
+    "7c2b5269" => (4, false, r#"Mario vs. Donkey Kong (Japan)"#, false),
+    "25c394d1" => (4, false, r#"Mario vs. Donkey Kong (USA, Australia)"#, false),
+    "25505164" => (4, false, r#"Mario vs. Donkey Kong (USA, Kiosk, v16)"#, false),
+    "c150790f" => (1, false, r#"Marvel - Ultimate Alliance (Europe)"#, false),
+    "a74294db" => (1, false, r#"Marvel - Ultimate Alliance (USA)"#, false),
+    "8af3f3ad" => (1, false, r#"Mary-Kate and Ashley - Girls Night Out (Europe, USA)"#, false),
+    "5fe092c6" => (0, false, r#"Mary-Kate and Ashley Sweet 16 - Licensed to Drive (Europe, USA)"#, false),
+    "75491e30" => (0, false, r#"Masters of the Universe He-Man - Power of Grayskull (USA)"#, false),
+    "a333fa51" => (1, false, r#"Mat Hoffman's Pro BMX (Europe, USA)"#, false),
+    "78d22ae9" => (1, false, r#"Mat Hoffman's Pro BMX (Europe, French / German)"#, false),
+    "e7fa71c6" => (1, false, r#"Mat Hoffman's Pro BMX 2 (Europe, USA)"#, false),
+    "d647ab18" => (2, false, r#"Matantei Loki Ragnarok - Gensou no Labyrinth (Japan, rev. 1)"#, false),
+    "4ebdad86" => (2, false, r#"Matantei Loki Ragnarok - Gensou no Labyrinth (Japan)"#, false),
+    "c9ea02f5" => (0, false, r#"Matchbox Cross Town Heroes (Europe)"#, false),
+    "0fffb458" => (0, false, r#"Matchbox Cross Town Heroes (USA)"#, false),
+    "aecd1301" => (1, false, r#"Math Patrol - The Kleptoid Threat (USA)"#, false),
+    "e69964f1" => (0, false, r#"Mawaru - Made in Wario (Japan)"#, false),
+    "7d902b62" => (1, false, r#"Max Payne Advance (Europe)"#, false),
+    "296e1092" => (1, false, r#"Max Payne (USA)"#, false),
+    "a75778c7" => (1, false, r#"Maya the Bee - Sweet Gold (Europe)"#, false),
+    "a1764f3c" => (0, false, r#"Maya the Bee - The Great Adventure (Europe)"#, false),
+    "0b725a08" => (2, false, r#"Mazes of Fate (USA)"#, false),
+    "4b3e0531" => (2, false, r#"Mech Platoon (Europe)"#, false),
+    "769fde96" => (2, false, r#"Mech Platoon (USA)"#, false),
+    "64251e43" => (1, false, r#"Mechanic Mechanica (Japan, prototype)"#, false),
+    "50927f3e" => (2, false, r#"Medabots - Metabee Version (Europe)"#, false),
+    "7e907ec8" => (2, false, r#"Medabots - Metabee Version (Spain)"#, false),
+    "59f208fc" => (2, false, r#"Medabots - Metabee Version (USA)"#, false),
+    "a519feb5" => (2, false, r#"Medabots - Rokusho Version (Europe)"#, false),
+    "046d86c4" => (2, false, r#"Medabots - Rokusho Version (Spain)"#, false),
+    "e144ded2" => (2, false, r#"Medabots - Rokusho Version (USA)"#, false),
+    "5f1e5a48" => (1, false, r#"Medabots AX - Metabee Version (Europe)"#, false),
+    "03294511" => (1, false, r#"Medabots AX - Metabee Version (USA)"#, false),
+    "f5801bd8" => (1, false, r#"Medabots AX - Rokusho Version (Europe)"#, false),
+    "92fdb8d6" => (1, false, r#"Medabots AX - Rokusho Version (USA)"#, false),
+    "f23150a4" => (1, false, r#"Medal of Honor - Infiltrator (Europe, USA)"#, false),
+    "9db145b8" => (0, false, r#"Medal of Honor - Underground (Europe, Ubi Soft)"#, false),
+    "72b4cf20" => (0, false, r#"Medal of Honor - Underground (Zoo Digital) (Europe)"#, false),
+    "6dfa0f50" => (0, false, r#"Medal of Honor - Underground (USA)"#, false),
+    "a054a4d0" => (1, false, r#"Medal of Honor Advance (Japan)"#, false),
+    "b107c73d" => (1, false, r#"Medarot G - Kabuto Version (Japan)"#, false),
+    "14e3ebcc" => (1, false, r#"Medarot G - Kuwagata Version (Japan)"#, false),
+    "15450b63" => (5, false, r#"Medarot Navi - Kabuto (Japan)"#, false),
+    "e83d07d6" => (5, false, r#"Medarot Navi - Kuwagata (Japan)"#, false),
+    "3fcaf2d0" => (2, false, r#"Medarot Ni Core - Kabuto Version (Japan)"#, false),
+    "285fe485" => (2, false, r#"Medarot Ni Core - Kuwagata Version (Japan)"#, false),
+    "df3e58d3" => (1, false, r#"Disney's Meet the Robinsons (Europe)"#, false),
+    "3787c93c" => (1, false, r#"Disney's Meet the Robinsons (USA)"#, false),
+    "01b4d95e" => (2, false, r#"Mega Man & Bass (Europe)"#, false),
+    "eea68c2e" => (2, false, r#"Mega Man & Bass (USA)"#, false),
+    "5b4631f9" => (3, false, r#"Mega Man Battle Chip Challenge (Europe)"#, false),
+    "26be44fd" => (3, false, r#"Mega Man Battle Chip Challenge (USA)"#, false),
+    "1a7fb4fa" => (3, false, r#"Mega Man Battle Network (Europe)"#, false),
+    "1d347971" => (3, false, r#"Mega Man Battle Network (USA)"#, false),
+    "66341f3b" => (3, false, r#"Mega Man Battle Network 2 (Europe)"#, false),
+    "6d961f82" => (3, false, r#"Mega Man Battle Network 2 (USA)"#, false),
+    "1f036cba" => (3, false, r#"Mega Man Battle Network 3 Blue (Europe)"#, false),
+    "c0c780f9" => (3, false, r#"Mega Man Battle Network 3 Blue (USA)"#, false),
+    "23d0a981" => (3, false, r#"Mega Man Battle Network 3 White (Europe)"#, false),
+    "0be4410a" => (3, false, r#"Mega Man Battle Network 3 White (USA)"#, false),
+    "48758316" => (3, false, r#"Mega Man Battle Network 4 Blue Moon (Europe)"#, false),
+    "758a46e9" => (3, false, r#"Mega Man Battle Network 4 Blue Moon (USA)"#, false),
+    "0cb136c2" => (3, false, r#"Mega Man Battle Network 4 Red Sun (Europe)"#, false),
+    "2120695c" => (3, false, r#"Mega Man Battle Network 4 Red Sun (USA)"#, false),
+    "8fc8cf73" => (3, false, r#"Mega Man Battle Network 5 Team Colonel (Europe)"#, false),
+    "a552f683" => (3, false, r#"Mega Man Battle Network 5 Team Colonel (USA)"#, false),
+    "79f45ed8" => (3, false, r#"Mega Man Battle Network 5 Team Protoman (Europe)"#, false),
+    "a73e83a4" => (3, false, r#"Mega Man Battle Network 5 Team Protoman (USA)"#, false),
+    "13183967" => (3, false, r#"Mega Man Battle Network 6 Cybeast Falzar (Europe)"#, false),
+    "dee6f2a9" => (3, false, r#"Mega Man Battle Network 6 Cybeast Falzar (USA)"#, false),
+    "25c29efb" => (3, false, r#"Mega Man Battle Network 6 Cybeast Gregar (Europe)"#, false),
+    "79452182" => (3, false, r#"Mega Man Battle Network 6 Cybeast Gregar (USA)"#, false),
+    "9707d2a1" => (3, false, r#"Mega Man Zero (Europe, USA)"#, false),
+    "29a14b59" => (3, false, r#"Mega Man Zero 2 (Europe)"#, false),
+    "ce1e37bb" => (3, false, r#"Mega Man Zero 2 (USA)"#, false),
+    "b099577f" => (3, false, r#"Mega Man Zero 3 (Europe)"#, false),
+    "2784f3f2" => (3, false, r#"Mega Man Zero 3 (USA)"#, false),
+    "b7f022b9" => (2, false, r#"Mega Man Zero 4 (Europe)"#, false),
+    "7ee24793" => (2, false, r#"Mega Man Zero 4 (USA)"#, false),
+    "13ce32e8" => (2, false, r#"Meine Tierarztpraxis (Germany)"#, false),
+    "78ad82ff" => (2, false, r#"Meine Tierpension ~ My Pet Hotel (Germany)"#, false),
+    "c048b723" => (2, false, r#"Detective Conan: Akatsuki no Monument (Japan)"#, false),
+    "5f9c7a4a" => (2, false, r#"Detective Conan: Nerawareta Tantei (Japan)"#, false),
+    "56ba6858" => (0, false, r#"Men in Black - The Series (Europe)"#, false),
+    "589810a5" => (0, false, r#"Men in Black - The Series (USA)"#, false),
+    "63f72589" => (3, false, r#"Mermaid Melody - Pichi Pichi Pitch (Japan)"#, false),
+    "f786ffa7" => (3, false, r#"Mermaid Melody - Pichi Pichi Pitch - Pichi Pichi Party (Japan)"#, false),
+    "7a4fdec3" => (3, false, r#"Mermaid Melody - Pichi Pichi Pitch - Pichi Pichitto Live Start! (Japan)"#, false),
+    "66725a09" => (2, false, r#"Metal Max 2 Kai (Japan, rev. 1)"#, false),
+    "bc1feaf0" => (2, false, r#"Metal Max 2 Kai (Japan)"#, false),
+    "3806f4ae" => (1, false, r#"Metal Slug Advance (Europe)"#, false),
+    "0caba589" => (1, false, r#"Metal Slug Advance (Japan)"#, false),
+    "09980880" => (1, false, r#"Metal Slug Advance (USA)"#, false),
+    "8dfb7a2f" => (2, false, r#"Metalgun Slinger (Japan)"#, false),
+    "f1d92e63" => (3, false, r#"Metroid - Zero Mission (Europe)"#, false),
+    "b6438226" => (3, false, r#"Metroid - Zero Mission (Europe, prototype)"#, false),
+    "44b79e2b" => (3, false, r#"Metroid - Zero Mission (Japan)"#, false),
+    "5c61a844" => (3, false, r#"Metroid - Zero Mission (USA)"#, false),
+    "922c4a10" => (3, false, r#"Metroid - Zero Mission (USA, prototype)"#, false),
+    "974e46ab" => (3, false, r#"Metroid Fusion (Europe)"#, false),
+    "3acbd239" => (3, false, r#"Metroid Fusion (Europe, prototype 20020911)"#, false),
+    "1605e4f7" => (3, false, r#"Metroid Fusion (Europe, prototype 20020916)"#, false),
+    "817a7e9e" => (3, false, r#"Metroid Fusion (Japan)"#, false),
+    "6c75479c" => (3, false, r#"Metroid Fusion (USA, Australia)"#, false),
+    "50e473a8" => (4, false, r#"Mezase! Koushien (Japan)"#, false),
+    "edb264b8" => (1, false, r#"Mickey no Pocket Resort (Japan)"#, false),
+    "32d851f7" => (1, false, r#"Disney's Mickey to Donald no Magical Quest 3 (Japan)"#, false),
+    "bbae85c8" => (1, false, r#"Disney's Mickey to Minnie no Magical Quest (Japan)"#, false),
+    "092a7d0a" => (1, false, r#"Disney's Mickey to Minnie no Magical Quest 2 (Japan)"#, false),
+    "9d102c82" => (0, false, r#"Micro Machines (Europe)"#, false),
+    "091230c6" => (0, false, r#"Midnight Club - Street Racing (Europe)"#, false),
+    "911be520" => (0, false, r#"Midnight Club - Street Racing (USA)"#, false),
+    "c01c4d0a" => (0, false, r#"Midway's Greatest Arcade Hits (Europe, USA)"#, false),
+    "54df36da" => (1, false, r#"Mighty Beanz Pocket Puzzles (USA)"#, false),
+    "310eb2fe" => (2, false, r#"Mijn Dierenpension ~ My Pet Hotel (Netherlands)"#, false),
+    "227a5430" => (2, false, r#"Mijn Dierenpraktijk ~ My Vet Practice (Netherlands)"#, false),
+    "b9566812" => (1, false, r#"Mike Tyson Boxing (Europe)"#, false),
+    "48109f8d" => (1, false, r#"Mike Tyson Boxing (USA)"#, false),
+    "6e53115b" => (1, false, r#"Minami no Umi no Odyssey (Japan)"#, false),
+    "492cf823" => (3, false, r#"Mini Moni. - Mika no Happy Morning Chatty (Japan)"#, false),
+    "f11c35cc" => (1, false, r#"Mini Moni. - Onegai Ohoshi-sama! (Japan)"#, false),
+    "857fb1ef" => (1, false, r#"Minna de Puyo Puyo (Japan)"#, false),
+    "19dc05e6" => (3, false, r#"Minna no Ouji-sama (Japan)"#, false),
+    "20e296a6" => (2, false, r#"Minna no Shiiku Series - Boku no Kabuto, Kuwagata (Japan)"#, false),
+    "f83fe589" => (3, false, r#"Minna no Shiiku Series 1 - Boku no Kabutomushi (Japan)"#, false),
+    "c0ba281d" => (3, false, r#"Minna no Shiiku Series 2 - Boku no Kuwagata (Japan)"#, false),
+    "d0ef2bc4" => (1, false, r#"Minna no Soft Series - Happy Trump 20 (Japan)"#, false),
+    "481d1d5f" => (1, false, r#"Minna no Soft Series - Hyokkori Hyoutan-jima - Don Gabacho Daikatsuyaku no Maki
+			(Japan)"#, false),
+    "66b779b0" => (1, false, r#"Minna no Soft Series - Minna no Mahjong (Japan)"#, false),
+    "c62c15c6" => (1, false, r#"Minna no Soft Series - Minna no Shougi (Japan, rev. 1)"#, false),
+    "08b62391" => (1, false, r#"Minna no Soft Series - Minna no Shougi (Japan)"#, false),
+    "044c6f68" => (2, false, r#"Minna no Soft Series - Numpla Advance (Japan)"#, false),
+    "feefeddc" => (1, false, r#"Minna no Soft Series - Shanghai (Japan)"#, false),
+    "524d0749" => (1, false, r#"Minna no Soft Series - Tetris Advance (Japan, rev. 1)"#, false),
+    "67c92883" => (1, false, r#"Minna no Soft Series - Tetris Advance (Japan)"#, false),
+    "72eb35e3" => (2, false, r#"Minna no Soft Series - Zooo (Japan)"#, false),
+    "3d56ead6" => (1, false, r#"Minority Report (Europe, USA)"#, false),
+    "3a87f78b" => (1, false, r#"Mirakuru! Panzou - 7-tsu no Hoshi no Uchuu Kaizoku (Japan)"#, false),
+    "45de1669" => (1, false, r#"Mission Impossible - Operation Surma (Europe)"#, false),
+    "f3dc4e3e" => (1, false, r#"Mission Impossible - Operation Surma (USA)"#, false),
+    "e951865c" => (3, false, r#"Miteluode - Lingdian Renwu (China)"#, false),
+    "042e5b07" => (3, false, r#"Miteluode Ronghe (China)"#, false),
+    "a4e12d4b" => (0, false, r#"MLB SlugFest 20-04 (USA)"#, false),
+    "8df8a0c0" => (5, false, r#"Mobile Pro Yakyuu - Kantoku no Saihai (Japan)"#, false),
+    "180d1491" => (0, false, r#"Mobile Suit Gundam Seed - Battle Assault (USA)"#, false),
+    "6da36e82" => (1, false, r#"Moero!! Jaleco Collection (Japan)"#, false),
+    "5f3e184e" => (2, false, r#"Momotarou Dentetsu G Gold Deck o Tsukure! (Japan)"#, false),
+    "bf0523d1" => (3, false, r#"Momotarou Matsuri (Japan, rev. 1)"#, false),
+    "7b113397" => (0, false, r#"Monopoly (Europe)"#, false),
+    "b01cbc07" => (0, false, r#"Monopoly (USA)"#, false),
+    "c140ff7a" => (3, false, r#"Monster Farm Advance (Japan)"#, false),
+    "1b14cf53" => (3, false, r#"Monster Farm Advance 2 (Japan)"#, false),
+    "137b0f9a" => (1, false, r#"Monster Force (Europe)"#, false),
+    "ad4691d9" => (1, false, r#"Monster Force (USA)"#, false),
+    "5b8aaa03" => (3, false, r#"Monster Gate (Japan)"#, false),
+    "05ac7685" => (3, false, r#"Monster Gate - Ooinaru Dungeon - Fuuin no Orb (Japan)"#, false),
+    "93815cde" => (3, false, r#"Monster Guardians (Japan)"#, false),
+    "21c84334" => (1, false, r#"Monster House (Europe, English / French / German / Spanish)"#, false),
+    "aa2c1837" => (1, false, r#"Monster House (Europe, English)"#, false),
+    "148b5072" => (1, false, r#"Monster House (USA)"#, false),
+    "c3ad7701" => (3, false, r#"Monster Jam - Maximum Destruction (Europe)"#, false),
+    "43d9b9f1" => (3, false, r#"Monster Jam - Maximum Destruction (USA)"#, false),
+    "5e2c90ae" => (3, false, r#"Monster Maker 4 - Flash Card (Japan)"#, false),
+    "f77e6ecf" => (3, false, r#"Monster Maker 4 - Killer Dice (Japan)"#, false),
+    "42cfa4b5" => (3, false, r#"Monster Rancher Advance (USA)"#, false),
+    "1d695b1b" => (3, false, r#"Monster Rancher Advance 2 (USA)"#, false),
+    "f8a085e2" => (3, false, r#"Monster Summoner (Japan)"#, false),
+    "dd872538" => (1, false, r#"Monster Truck Madness (Europe, USA)"#, false),
+    "4a72170a" => (0, false, r#"Monster Trucks (Europe, USA)"#, false),
+    "31500381" => (0, false, r#"Monster Trucks Mayhem (Europe)"#, false),
+    "f47c5cf9" => (0, false, r#"Monster Trucks Mayhem (USA)"#, false),
+    "14db186b" => (0, false, r#"Monster! Bass Fishing (Europe)"#, false),
+    "3edba390" => (0, false, r#"Monster! Bass Fishing (USA)"#, false),
+    "327834c9" => (0, false, r#"Monsters, Inc. (Europe, USA)"#, false),
+    "d2c53ab8" => (0, false, r#"Monsters, Inc. (Europe, English / French / Italian)"#, false),
+    "d13177e0" => (0, false, r#"Monsters, Inc. (Europe, English / Spanish / Dutch)"#, false),
+    "a23cb45f" => (0, false, r#"Die Monster AG (Germany)"#, false),
+    "0dfff34f" => (0, false, r#"Monsters, Inc. (Japan)"#, false),
+    "a16c10a8" => (1, false, r#"Moorhen 3 - The Chicken Chase! (Europe)"#, false),
+    "149e01b7" => (3, false, r#"Morita Shougi Advance (Japan)"#, false),
+    "b734eb4e" => (0, false, r#"The Morning Adventure (Spain, promo)"#, false),
+    "fb291cca" => (1, false, r#"Mortal Kombat - Deadly Alliance (Europe)"#, false),
+    "a29ba64f" => (1, false, r#"Mortal Kombat - Deadly Alliance (USA)"#, false),
+    "968be7bc" => (1, false, r#"Mortal Kombat - Tournament Edition (USA)"#, false),
+    "78b4b5c7" => (0, false, r#"Mortal Kombat Advance (Europe)"#, false),
+    "bcdabb47" => (0, false, r#"Mortal Kombat Advance (USA)"#, false),
+    "0a44569c" => (3, false, r#"Mother 1+2 (Japan)"#, false),
+    "42ac9cb9" => (4, false, r#"Mother 3 (Japan)"#, false),
+    "508bf8dc" => (0, false, r#"Moto GP (Europe, prototype)"#, false),
+    "c6d17d0d" => (0, false, r#"Moto GP (Europe)"#, false),
+    "4f6d7e49" => (1, false, r#"Moto GP (Japan)"#, false),
+    "8ee93eb8" => (0, false, r#"Moto GP (USA)"#, false),
+    "e5a7de43" => (0, false, r#"Motocross Challenge (USA, prototype)"#, false),
+    "0a0faef4" => (1, false, r#"Motocross Maniacs Advance (USA)"#, false),
+    "043a3554" => (1, false, r#"Motocross Maniacs Advance (Japan)"#, false),
+    "8cb19da0" => (3, false, r#"Motoracer Advance (Europe)"#, false),
+    "2fdbe7a8" => (3, false, r#"Motoracer Advance (USA)"#, false),
+    "7f21d693" => (0, false, r#"Mr Nutz (Europe)"#, false),
+    "c0e9eebe" => (3, false, r#"Mr. Driller 2 (Europe)"#, false),
+    "5264c730" => (3, false, r#"Mr. Driller 2 (Japan)"#, false),
+    "02f51696" => (3, false, r#"Mr. Driller 2 (USA)"#, false),
+    "529f06a4" => (3, false, r#"Mr. Driller A - Fushigi na Pacteria (Japan)"#, false),
+    "73fa8b2a" => (0, false, r#"Mr. Incredible (Japan)"#, false),
+    "667f54ca" => (0, false, r#"Mr. Incredible - Kyouteki Underminer Toujou (Japan)"#, false),
+    "6e03fd6a" => (1, false, r#"Ms. Pac-Man - Maze Madness (Europe)"#, false),
+    "dde5df02" => (1, false, r#"Ms. Pac-Man - Maze Madness (USA)"#, false),
+    "03bbc0c9" => (1, false, r#"Mucha Lucha! - Mascaritas of the Lost Code (USA)"#, false),
+    "1c31bbec" => (3, false, r#"Mugenborg (Japan)"#, false),
+    "b0f3799f" => (0, false, r#"The Mummy (Europe)"#, false),
+    "55bf350b" => (0, false, r#"The Mummy (USA)"#, false),
+    "17cd149a" => (0, false, r#"Jim Henson's Muppets - On with the Show! (Europe, USA)"#, false),
+    "8d5034d7" => (1, false, r#"Muppet Pinball Mayhem (Europe)"#, false),
+    "58575f65" => (1, false, r#"Muppet Pinball Mayhem (USA)"#, false),
+    "3a45a6d9" => (3, false, r#"Mutsu - Water Looper Mutsu (Japan)"#, false),
+    "0bf1dc56" => (0, false, r#"MX 2002 featuring Ricky Carmichael (Europe, USA)"#, false),
+    "16c0e028" => (2, false, r#"Meine Tierklinik in Afrika ~ My Animal Centre in Africa (Europe)"#, false),
+    "90dbd5eb" => (0, false, r#"My Little Pony - Crystal Princess - The Runaway Rainbow (USA)"#, false),
+    "d0c608f6" => (3, false, r#"Nakayoshi Mahjong - KabuReach (Japan)"#, false),
+    "74930b16" => (2, false, r#"Nakayoshi Pet Advance Series 1 - Kawaii Hamster (Japan)"#, false),
+    "954da3e6" => (2, false, r#"Nakayoshi Pet Advance Series 2 - Kawaii Koinu (Japan)"#, false),
+    "89fbeb55" => (2, false, r#"Nakayoshi Pet Advance Series 3 - Kawaii Koneko (Japan, rev. 1)"#, false),
+    "0290dfb5" => (2, false, r#"Nakayoshi Pet Advance Series 3 - Kawaii Koneko (Japan)"#, false),
+    "ae2a69f3" => (2, false, r#"Nakayoshi Pet Advance Series 4 - Kawaii Koinu Mini - Wankoto Asobou!!
+			Kogata-ken (Japan)"#, false),
+    "b46daca0" => (2, false, r#"Nakayoshi Youchien - Sukoyaka Enji Ikusei Game (Japan, rev. 1)"#, false),
+    "1276a95c" => (2, false, r#"Nakayoshi Youchien - Sukoyaka Enji Ikusei Game (Japan)"#, false),
+    "bb82460a" => (0, false, r#"Namco Museum (Europe)"#, false),
+    "818f4e4b" => (0, false, r#"Namco Museum (Japan)"#, false),
+    "c58a04c1" => (0, false, r#"Namco Museum (USA)"#, false),
+    "2f098a42" => (0, false, r#"Namco Museum - 50th Anniversary (Europe)"#, false),
+    "38da81fe" => (0, false, r#"Namco Museum - 50th Anniversary (USA)"#, false),
+    "7652cd25" => (0, false, r#"Nancy Drew - Message in a Haunted Mansion (USA)"#, false),
+    "fa016764" => (3, false, r#"Napoleon (Japan)"#, false),
+    "0ff11189" => (3, false, r#"Narikiri Jockey Game - Yuushun Rhapsody (Japan)"#, false),
+    "69cf0418" => (3, false, r#"Naruto - Konoha Senki (Japan, rev. 1)"#, false),
+    "11829afa" => (3, false, r#"Naruto - Konoha Senki (Japan)"#, false),
+    "c402878c" => (1, false, r#"Shonen Jump's Naruto - Ninja Council (USA)"#, false),
+    "94699fca" => (3, false, r#"Shonen Jump Naruto - Ninja Council 2 (USA)"#, false),
+    "f5f8ecb4" => (3, false, r#"Naruto - Ninjutsu Zenkai! Saikyou Ninja Daikesshuu (Japan, rev. 1)"#, false),
+    "e15875fc" => (3, false, r#"Naruto - Ninjutsu Zenkai! Saikyou Ninja Daikesshuu (Japan)"#, false),
+    "b3d75ce1" => (3, false, r#"Naruto - Saikyou Ninja Daikesshuu 2 (Japan)"#, false),
+    "2bf6555a" => (3, false, r#"Naruto RPG - Uketsugareshi Hi no Ishi (Japan, rev. 1)"#, false),
+    "c036d0f0" => (3, false, r#"Naruto RPG - Uketsugareshi Hi no Ishi (Japan)"#, false),
+    "87e15f5b" => (1, false, r#"NASCAR Heat 2002 (USA)"#, false),
+    "0de8b4b4" => (5, false, r#"Natural 2 - Duo (Japan)"#, false),
+    "ca428a7a" => (0, false, r#"NBA Jam 2002 (Europe, USA)"#, false),
+    "ea15a16e" => (1, false, r#"Need for Speed - Most Wanted (Europe, USA)"#, false),
+    "1b6b8f88" => (1, false, r#"Need for Speed - Porsche Unleashed (Europe)"#, false),
+    "99078af4" => (1, false, r#"Need for Speed - Porsche Unleashed (USA)"#, false),
+    "828020e9" => (1, false, r#"Need for Speed - Underground (Europe, USA)"#, false),
+    "9a0c5090" => (1, false, r#"Need for Speed - Underground 2 (Europe, USA)"#, false),
+    "f4c0d140" => (1, false, r#"Need for Speed Carbon - Own the City (Europe, USA)"#, false),
+    "e4fe52db" => (2, false, r#"Neoromance Game - Harukanaru Toki no Naka de (Japan, rev. 1)"#, false),
+    "bf12b590" => (2, false, r#"Neoromance Game - Harukanaru Toki no Naka de (Japan)"#, false),
+    "5297693a" => (1, false, r#"NGT - Next Generation Tennis (Europe)"#, false),
+    "8c748dcb" => (0, false, r#"NFL Blitz 20-02 (USA)"#, false),
+    "e0b137e7" => (0, false, r#"NFL Blitz 20-03 (USA)"#, false),
+    "d1d9e515" => (2, false, r#"NHL 2002 (USA)"#, false),
+    "4f1adf75" => (1, false, r#"NHL Hitz 20-03 (USA)"#, false),
+    "dda9a035" => (0, false, r#"Nicktoons - Attack of the Toybots (USA)"#, false),
+    "f0b0f53c" => (0, false, r#"Nicktoons - Attack of the Toybots ~ SpongeBob und seine Freunde - Angriff der
+			Spielzeugroboter (Germany)"#, false),
+    "feafbd72" => (1, false, r#"Nicktoons - Battle for Volcano Island (USA)"#, false),
+    "a8b6766e" => (1, false, r#"Nicktoons - Freeze Frame Frenzy (USA)"#, false),
+    "babccefd" => (1, false, r#"Nicktoons Racing (Europe)"#, false),
+    "50ab9bb6" => (1, false, r#"Nicktoons Racing (USA)"#, false),
+    "75c35f7c" => (0, false, r#"Nicktoons Unite! (USA)"#, false),
+    "085c1184" => (3, false, r#"Nihon Pro Mahjong Renmei Kounin Tetsuman Advance - Menkyo Kaiden Series (Japan)"#, false),
+    "c14295b1" => (1, false, r#"Ninja Cop (Europe)"#, false),
+    "eb3954c6" => (1, false, r#"Ninja Five-0 (USA)"#, false),
+    "1b00bc2c" => (1, false, r#"No No No Puzzle Chailien (Japan)"#, false),
+    "966fa650" => (0, false, r#"No Rules - Get Phat (Europe, USA)"#, false),
+    "526ab88e" => (3, false, r#"Nobunaga Ibun (Japan)"#, false),
+    "ef23c79e" => (5, false, r#"Nobunaga no Yabou (Japan)"#, false),
+    "607ddc6b" => (0, false, r#"Noddy - A Day in Toyland (Europe)"#, false),
+    "f906c5b6" => (0, false, r#"Noddy - A Day in Toyland (USA)"#, false),
+    "685e62ba" => (1, false, r#"Nyan Nyan Nyanko no NyanCollection (Japan)"#, false),
+    "eecdd3ef" => (1, false, r#"Ochaken Kururin - Honwaka Puzzle de Hotto Shiyo? (Japan)"#, false),
+    "61e2799a" => (2, false, r#"Ochaken no Bouken-jima - Honwaka Yume no Island (Japan)"#, false),
+    "c22ce53e" => (1, false, r#"Ochaken no Heya (Japan)"#, false),
+    "2937a9cd" => (1, false, r#"Ochaken no Yume Bouken (Japan)"#, false),
+    "fcc570a7" => (0, false, r#"Oddworld - Munch's Oddysee (Germany)"#, false),
+    "97367887" => (0, false, r#"Oddworld - Munch's Oddysee (Europe, USA)"#, false),
+    "d5059733" => (2, false, r#"Ohanaya-san Monogatari GBA - Iyashikei Ohanaya-san Ikusei Game (Japan)"#, false),
+    "6043595e" => (1, false, r#"Ojarumaru - Gekkouchou Sanpo de Ojaru (Japan)"#, false),
+    "07022c3a" => (3, false, r#"Okumanchouja Game - Nottori Daisakusen! (Japan)"#, false),
+    "7d2cf2a1" => (1, false, r#"One Piece (USA)"#, false),
+    "08cff461" => (2, false, r#"One Piece - Dragon Dream! (Japan)"#, false),
+    "66928f27" => (2, false, r#"One Piece - Going Baseball - Haejeok Yaku (Korea)"#, false),
+    "0b77bcb8" => (2, false, r#"One Piece - Going Baseball - Kaizoku Yakyuu (Japan)"#, false),
+    "05e56a4d" => (3, false, r#"One Piece - Ilgop Seomui Debomool (Korea)"#, false),
+    "63aa930a" => (2, false, r#"Onimusha Tactics (Europe)"#, false),
+    "b521c635" => (2, false, r#"Onimusha Tactics (Japan)"#, false),
+    "70475d7f" => (2, false, r#"Onimusha Tactics (USA)"#, false),
+    "66c29472" => (2, false, r#"Onmyou Taisenki Zeroshiki (Japan)"#, false),
+    "4c8f6fdc" => (1, false, r#"Open Season (Europe, English / French / German / Italian / Spanish /
+			Scandinavia)"#, false),
+    "51b0a94b" => (1, false, r#"Open Season (Europe, English / French / Spanish / Dutch)"#, false),
+    "e6a1d5b2" => (1, false, r#"Open Season (USA)"#, false),
+    "8f289e0b" => (0, false, r#"Operation Armored Liberty (USA)"#, false),
+    "05e80ecc" => (3, false, r#"Oriental Blue - Ao no Tengai (Japan)"#, false),
+    "df6901f0" => (5, false, r#"Oshaberi Inko Club (Japan)"#, false),
+    "5a2299e1" => (2, false, r#"Oshare Princess (Japan)"#, false),
+    "8383fcda" => (2, false, r#"Oshare Princess 2 (Japan)"#, false),
+    "f1e8a261" => (2, false, r#"Oshare Princess 3 (Japan)"#, false),
+    "38af35c1" => (1, false, r#"Oshare Princess 5 (Japan)"#, false),
+    "1cc60c27" => (2, false, r#"Oshare Wanko (Japan)"#, false),
+    "77a84969" => (0, false, r#"Ottifanten Pinball (Germany)"#, false),
+    "fb96d9de" => (5, false, r#"Ougon no Taiyou - Hirakareshi Fuuin (Japan)"#, false),
+    "830b795f" => (5, false, r#"Ougon no Taiyou - Ushinawareshi Toki (Japan)"#, false),
+    "c7023309" => (1, false, r#"Over the Hedge (Europe)"#, false),
+    "31e57a19" => (1, false, r#"Ab Durch die Hecke (Germany)"#, false),
+    "52a5b59d" => (1, false, r#"La Gang del Bosco (Italy)"#, false),
+    "eb7c8402" => (1, false, r#"Nos Voisins, les Hommes (France)"#, false),
+    "96af60f3" => (1, false, r#"Vecinos Invasores (Spain)"#, false),
+    "cf0f07c7" => (1, false, r#"Over the Hedge - Beesten bij de Buren (Netherlands)"#, false),
+    "5f3a7bb7" => (1, false, r#"Over the Hedge (USA)"#, false),
+    "8f1b100d" => (1, false, r#"Over the Hedge - Hammy Goes Nuts! (Europe)"#, false),
+    "b9d4e9e7" => (1, false, r#"Over the Hedge - Hammy Goes Nuts! (USA)"#, false),
+    "ee764cf6" => (0, false, r#"Overstorm (Europe, prototype demo)"#, false),
+    "d7245280" => (0, false, r#"Ozzy & Drix (USA)"#, false),
+    "1f2708d5" => (2, false, r#"Paard & Pony - Mijn Manege (Netherlands)"#, false),
+    "b6fc77f3" => (2, false, r#"Paard & Pony - Paard in Galop (Netherlands)"#, false),
+    "e1578d25" => (0, false, r#"Pac-Man Collection (Europe)"#, false),
+    "b0fddb8f" => (0, false, r#"Pac-Man Collection (Japan)"#, false),
+    "6e5c16f4" => (0, false, r#"Pac-Man Collection (USA)"#, false),
+    "3e70bf4d" => (0, false, r#"Pac-Man Pinball Advance (Europe)"#, false),
+    "da4e1109" => (0, false, r#"Pac-Man Pinball Advance (USA)"#, false),
+    "1f92814e" => (1, false, r#"Pac-Man World (Europe)"#, false),
+    "b2a4fc78" => (1, false, r#"Pac-Man World (USA)"#, false),
+    "a45eb4d2" => (0, false, r#"Pac-Man World 2 (Europe)"#, false),
+    "814a7d33" => (0, false, r#"Pac-Man World 2 (USA)"#, false),
+    "0420d6ff" => (0, false, r#"Paradroid (Europe, prototype, 20030320)"#, false),
+    "6ecf40a0" => (2, false, r#"Paws & Claws - Best Friends - Dogs & Cats (USA)"#, false),
+    "e58da71e" => (2, false, r#"Paws & Claws - Pet Resort (USA)"#, false),
+    "efee1ddf" => (2, false, r#"Paws & Claws - Pet Vet (USA)"#, false),
+    "962dd201" => (1, false, r#"Payback (Europe)"#, false),
+    "8daa89c9" => (1, false, r#"Pazuninn - Umininn no Puzzle de Nimu (Japan)"#, false),
+    "8fddd48b" => (3, false, r#"Penny Racers (Europe)"#, false),
+    "3442f9cf" => (0, false, r#"Perplexus Diodomin (prototype)"#, false),
+    "4c682cc3" => (0, false, r#"Disney's Peter Pan - Return to Neverland (Europe, rev. 1)"#, false),
+    "be7d574c" => (0, false, r#"Disney's Peter Pan - Return to Neverland (Europe)"#, false),
+    "431dfea8" => (0, false, r#"Disney's Peter Pan - Return to Neverland (USA, rev. 1)"#, false),
+    "4cafccbd" => (0, false, r#"Disney's Peter Pan - Return to Neverland (USA)"#, false),
+    "98c27e8a" => (0, false, r#"Peter Pan - The Motion Picture Event (Europe)"#, false),
+    "8ac558ce" => (1, false, r#"Peter Pan - The Motion Picture Event (USA)"#, false),
+    "9f0e4a6a" => (3, false, r#"Petz - Hamsterz Life 2 (USA)"#, false),
+    "1dbf4d3d" => (2, false, r#"Petz Vet (USA)"#, false),
+    "aa02cb0c" => (2, false, r#"Pferd & Pony - Best Friends - Mein Pferd ~ Best Friends - My Horse
+			(Germany)"#, false),
+    "6217469f" => (2, false, r#"Pferd & Pony - Lass Uns Reiten 2 ~ Horse & Pony - Let's Ride 2
+			(Germany)"#, false),
+    "2819bdf5" => (2, false, r#"Pferd & Pony - Mein Gestuet ~ My Stud Farm (Germany)"#, false),
+    "cc74b433" => (2, false, r#"Pferd & Pony - Mein Pferdehof (Germany)"#, false),
+    "dfcfe06c" => (1, false, r#"Phalanx - The Enforce Fighter A-144 (Europe)"#, false),
+    "5f419f32" => (1, false, r#"Phalanx - The Enforce Fighter A-144 (Japan)"#, false),
+    "eb733713" => (1, false, r#"Phalanx - The Enforce Fighter A-144 (USA)"#, false),
+    "4d9c671b" => (2, false, r#"Phantasy Star Collection (Europe)"#, false),
+    "e5a7fe17" => (2, false, r#"Phantasy Star Collection (USA)"#, false),
+    "e199e132" => (1, false, r#"Phil of the Future (USA)"#, false),
+    "254e6f31" => (2, false, r#"Pia Carrot e Youkoso!! 3.3 (Japan)"#, false),
+    "ff5c9636" => (0, false, r#"Disney's Piglet's Big Game (Europe)"#, false),
+    "52919538" => (0, false, r#"Piglet's Big Game (USA)"#, false),
+    "80a081fe" => (2, false, r#"Pikapika Nurse Monogatari - Nurse Ikusei Game (Japan)"#, false),
+    "39479369" => (1, false, r#"Pinball Advance (Europe)"#, false),
+    "bcb204b7" => (0, false, r#"Pinball Challenge Deluxe (Europe)"#, false),
+    "a9dd7efa" => (1, false, r#"The Pinball of the Dead (Europe)"#, false),
+    "1a7efbd0" => (1, false, r#"The Pinball of the Dead (Japan)"#, false),
+    "0a09c517" => (1, false, r#"The Pinball of the Dead (USA)"#, false),
+    "5d52be1e" => (0, false, r#"Pinball Tycoon (Europe)"#, false),
+    "1c4d3fdf" => (0, false, r#"Pinball Tycoon (USA)"#, false),
+    "8859b808" => (0, false, r#"Pink Panther - Pinkadelic Pursuit (Europe)"#, false),
+    "1762a578" => (0, false, r#"Pink Panther - Pinkadelic Pursuit (USA)"#, false),
+    "ff7d5f62" => (0, false, r#"Pinky and the Brain - The Masterplan (Europe)"#, false),
+    "9f75b077" => (2, false, r#"Pinky Monkey Town (Japan)"#, false),
+    "e989a4ae" => (1, false, r#"Pinobee & Phoebee (Japan)"#, false),
+    "d9385207" => (3, false, r#"Pinobee - Wings of Adventure (Europe, USA)"#, false),
+    "febf9045" => (3, false, r#"Pinobee no Daibouken (Japan)"#, false),
+    "1c1846c2" => (2, false, r#"Pippa Funnell - Stable Adventure ~ Alexandra Ledermann (Europe)"#, false),
+    "f3c274c2" => (2, false, r#"Pippa Funnell 2 ~ Alexandra Ledermann (Europe)"#, false),
+    "d1a67be8" => (1, false, r#"Pirates of the Caribbean - Dead Man's Chest (Europe, USA)"#, false),
+    "2df9d7f0" => (0, false, r#"Pirates of the Caribbean - The Curse of the Black Pearl (Europe)"#, false),
+    "8f15bf4c" => (0, false, r#"Pirates of the Caribbean - The Curse of the Black Pearl (USA)"#, false),
+    "dc6512d6" => (1, false, r#"Pitfall - The Lost Expedition (Europe)"#, false),
+    "846f43f3" => (1, false, r#"Pitfall - L'Expedition Perdue (France)"#, false),
+    "5c11dc26" => (1, false, r#"Pitfall - The Lost Expedition (USA)"#, false),
+    "2cedbb31" => (0, false, r#"Pitfall - The Mayan Adventure (Europe, USA)"#, false),
+    "20b72d17" => (1, false, r#"Pixeline i Pixieland (Den)"#, false),
+    "d4d77bce" => (0, false, r#"Planet Monsters (Europe)"#, false),
+    "9b182354" => (0, false, r#"Planet Monsters (USA)"#, false),
+    "6e378bf3" => (0, false, r#"Planet of the Apes (Europe)"#, false),
+    "a0f0269b" => (0, false, r#"Planet of the Apes (USA)"#, false),
+    "318a1e9b" => (3, false, r#"Play Novel - Silent Hill (Japan)"#, false),
+    "beddc67f" => (1, false, r#"Pocket Dogs (USA)"#, false),
+    "4881f3f8" => (5, true, r#"Pocket Monsters - Emerald (Japan)"#, false),
+    "bb640df7" => (5, false, r#"Pocket Monsters - Fire Red (Japan, rev. 1)"#, false),
+    "3b2056e9" => (5, false, r#"Pocket Monsters - Fire Red (Japan)"#, false),
+    "1a1baa50" => (4, false, r#"Pocket Monsters - Fushigi no Dungeon Aka no Kyuujotai (Japan)"#, false),
+    "8da4601a" => (5, false, r#"Pocket Monsters - Leaf Green (Japan, rev. 1)"#, false),
+    "0a48556b" => (5, false, r#"Pocket Monsters - Leaf Green (Japan)"#, false),
+    "cee9471a" => (5, true, r#"Pocket Monsters - Ruby (Japan)"#, false),
+    "fd1eeb78" => (5, true, r#"Pocket Monsters - Sapphire (Japan)"#, false),
+    "a3fd418a" => (3, false, r#"Pocket Music (Europe)"#, false),
+    "07b824b1" => (1, false, r#"Pocket Professor - Kwik Notes - Vol. 1 (USA)"#, false),
+    "53a8ee86" => (0, false, r#"Pocky & Rocky with Becky (USA)"#, false),
+    "1d379dcc" => (1, false, r#"Poke Inu (Japan)"#, false),
+    "1f1c08fb" => (5, true, r#"Pokémon - Emerald Version (Europe, USA, Australia)"#, false),
+    "34c9df89" => (5, true, r#"Pokémon - Smaragd-Edition (Germany)"#, false),
+    "a3fdccb1" => (5, true, r#"Pokémon - Version Emeraude (France)"#, false),
+    "a0aec80a" => (5, true, r#"Pokémon - Versione Smeraldo (Italy)"#, false),
+    "8c4d3108" => (5, true, r#"Pokémon - Edición Esmeralda (Spain)"#, false),
+    "84ee4776" => (5, false, r#"Pokémon - FireRed Version (Europe, USA, rev. 1)"#, false),
+    "dd88761c" => (5, false, r#"Pokémon - FireRed Version (USA)"#, false),
+    "9f08064e" => (5, false, r#"Pokémon - Edición Rojo Fuego (Spain)"#, false),
+    "1a81eedf" => (5, false, r#"Pokémon - Feuerrote Edition (Germany)"#, false),
+    "73a72167" => (5, false, r#"Pokémon - Versione Rosso Fuoco (Italy)"#, false),
+    "5dc668f6" => (5, false, r#"Pokémon - Version Rouge Feu (France)"#, false),
+    "daffecec" => (5, false, r#"Pokémon - LeafGreen Version (Europe, USA, rev. 1)"#, false),
+    "ba3285e3" => (5, false, r#"Pokémon - Version Vert Feuille (France)"#, false),
+    "d12f1fdd" => (5, false, r#"Pokémon - Blattgrüne Edition (Germany)"#, false),
+    "16974506" => (5, false, r#"Pokémon - Versione Verde Foglia (Italy)"#, false),
+    "2ca11d59" => (5, false, r#"Pokémon - Edición Verde Hoja (Spain)"#, false),
+    "d69c96cc" => (5, false, r#"Pokémon - LeafGreen Version (USA)"#, false),
+    "aeac73e6" => (5, true, r#"Pokémon - Ruby Version (Europe, USA, rev. 2)"#, false),
+    "61641576" => (5, true, r#"Pokémon - Ruby Version (Europe, USA, rev. 1)"#, false),
+    "f0815ee7" => (5, true, r#"Pokémon - Ruby Version (USA)"#, false),
+    "cae89464" => (5, true, r#"Pokémon - Rubin-Edition (Germany, rev. 1)"#, false),
+    "15e1e280" => (5, true, r#"Pokémon - Rubin-Edition (Germany)"#, false),
+    "40686c47" => (5, true, r#"Pokémon - Rubin-Edition (Germany, prototype)"#, false),
+    "b980fff5" => (5, true, r#"Pokémon - Edición Rubí (Spain, rev. 1)"#, false),
+    "eb0729cf" => (5, true, r#"Pokémon - Edición Rubí (Spa)"#, false),
+    "9f981f72" => (5, true, r#"Pokémon - Version Rubis (France, rev. 1)"#, false),
+    "690fd310" => (5, true, r#"Pokémon - Version Rubis (France)"#, false),
+    "9305e793" => (5, true, r#"Pokémon - Versione Rubino (Italy, rev. 1)"#, false),
+    "c18231a9" => (5, true, r#"Pokémon - Versione Rubino (Italy)"#, false),
+    "9cc4410e" => (5, true, r#"Pokémon - Sapphire Version (Europe, USA, rev. 2)"#, false),
+    "bafedae5" => (5, true, r#"Pokémon - Sapphire Version (Europe, USA, rev. 1)"#, false),
+    "554dedc4" => (5, true, r#"Pokémon - Sapphire Version (USA)"#, false),
+    "b0c40c7c" => (5, true, r#"Pokémon - Saphir-Edition (Germany, rev. 1)"#, false),
+    "6fcd7a98" => (5, true, r#"Pokémon - Saphir-Edition (Germany)"#, false),
+    "f2c88931" => (5, true, r#"Pokémon - Edición Zafiro (Spain, rev. 1)"#, false),
+    "a04f5f0b" => (5, true, r#"Pokémon - Edición Zafiro (Spain)"#, false),
+    "2ae49146" => (5, true, r#"Pokémon - Version Saphir (France, rev. 1)"#, false),
+    "3581a05f" => (5, true, r#"Pokémon - Version Saphir (France)"#, false),
+    "5efe4493" => (5, true, r#"Pokémon - Versione Zaffiro (Italy, rev. 1)"#, false),
+    "0c7992a9" => (5, true, r#"Pokémon - Versione Zaffiro (Italy)"#, false),
+    "c1d18fe4" => (5, false, r#"Pokémon Mystery Dungeon - Red Rescue Team (Europe)"#, false),
+    "f12f908e" => (0, false, r#"Pokémon Mystery Dungeon - Red Rescue Team (USA, demo Kiosk)"#, false),
+    "dd0ac86c" => (5, false, r#"Pokémon Mystery Dungeon - Red Rescue Team (USA, Australia)"#, false),
+    "a53ac14b" => (3, false, r#"Pokémon Pinball - Ruby & Sapphire (Europe)"#, false),
+    "925a5684" => (3, false, r#"Pokémon Pinball - Ruby & Sapphire (Japan, rev. 1)"#, false),
+    "8af1ec73" => (3, false, r#"Pokémon Pinball - Ruby & Sapphire (Japan)"#, false),
+    "b992a3c0" => (3, false, r#"Pokémon Pinball - Ruby & Sapphire (USA)"#, false),
+    "62ca5620" => (1, false, r#"The Polar Express (Europe, USA)"#, false),
+    "8f2debb8" => (4, false, r#"Polarium Advance (Europe)"#, false),
+    "02d62ce9" => (4, false, r#"Polarium Advance (USA)"#, false),
+    "a654cba9" => (0, false, r#"Polly Pocket! - Super Splash Island (Europe)"#, false),
+    "b5de8c6e" => (0, false, r#"Polly Pocket! - Super Splash Island (USA)"#, false),
+    "13e2a64f" => (0, false, r#"Polly Pocket! - Super Splash Island (Europe, Zoo Digital re-release)"#, false),
+    "f120eca2" => (0, false, r#"Polly Pocket! - Super Splash Island (DSI) (USA, DSI Games Re-release)"#, false),
+    "b9a7e60d" => (0, false, r#"Pop Idol (Europe)"#, false),
+    "cffc52dd" => (0, false, r#"Popeye - Rush for Spinach (Europe, USA)"#, false),
+    "566cf164" => (0, false, r#"Postman Pat and the Greendale Rocket (Europe)"#, false),
+    "59e7ab4c" => (3, false, r#"Power Poke Dash (Japan)"#, false),
+    "46493fa1" => (3, false, r#"Power Pro Kun Pocket 1, 2 (Japan, promo)"#, false),
+    "a84032aa" => (3, false, r#"Power Pro Kun Pocket 1, 2 (Japan)"#, false),
+    "d8397194" => (3, false, r#"Power Pro Kun Pocket 3 (Japan, rev. 1)"#, false),
+    "4314d8bc" => (3, false, r#"Power Pro Kun Pocket 3 (Japan)"#, false),
+    "89a3a2c7" => (3, false, r#"Power Pro Kun Pocket 4 (Japan, rev. 1)"#, false),
+    "30b4a347" => (3, false, r#"Power Pro Kun Pocket 4 (Japan)"#, false),
+    "969ac691" => (3, false, r#"Power Pro Kun Pocket 5 (Japan, promo)"#, false),
+    "b2d8732d" => (3, false, r#"Power Pro Kun Pocket 5 (Japan, rev. 1)"#, false),
+    "06f0f8d4" => (3, false, r#"Power Pro Kun Pocket 5 (Japan)"#, false),
+    "023a9a75" => (3, false, r#"Power Pro Kun Pocket 6 (Japan, promo)"#, false),
+    "88513763" => (3, false, r#"Power Pro Kun Pocket 6 (Japan)"#, false),
+    "073047df" => (3, false, r#"Power Pro Kun Pocket 7 (Japan)"#, false),
+    "414bfe31" => (0, false, r#"Power Rangers - Dino Thunder (Europe, French / German)"#, false),
+    "bc193ee7" => (0, false, r#"Power Rangers - Dino Thunder (Europe, USA)"#, false),
+    "09d8ed5e" => (0, false, r#"Power Rangers - Ninja Storm (Europe)"#, false),
+    "e8691a0f" => (0, false, r#"Power Rangers - Ninja Storm (USA)"#, false),
+    "70359b35" => (0, false, r#"Saban's Power Rangers - Time Force (Europe, USA)"#, false),
+    "eb54d898" => (0, false, r#"Saban's Power Rangers - La Force du Temps (France)"#, false),
+    "933f5477" => (0, false, r#"Saban's Power Rangers - Time Force (Germany)"#, false),
+    "54c31afb" => (0, false, r#"Saban's Power Rangers - Wild Force (Europe, USA)"#, false),
+    "921b66e2" => (0, false, r#"Power Rangers S.P.D. (Europe, USA)"#, false),
+    "cb59cf7d" => (0, false, r#"Power Rangers S.P.D. (Europe, English / French / German)"#, false),
+    "dca64f27" => (0, false, r#"Power Rangers S.P.D. (Europe, English / Italian / Spanish)"#, false),
+    "0bfed765" => (1, false, r#"The Powerpuff Girls - Him and Seek (Europe)"#, false),
+    "4d22b35e" => (1, false, r#"The Powerpuff Girls - Him and Seek (USA)"#, false),
+    "1172d0a7" => (1, false, r#"The Powerpuff Girls - Mojo Jojo A-Go-Go (Europe)"#, false),
+    "dfb57dcf" => (1, false, r#"The Powerpuff Girls - Mojo Jojo A-Go-Go (USA)"#, false),
+    "abb50d93" => (0, false, r#"Prehistorik Man (USA, prototype)"#, false),
+    "0d3b922b" => (0, false, r#"Prehistorik Man (Europe, USA)"#, false),
+    "b5432fa4" => (2, false, r#"Anstoss Action ~ Premier Action (Europe)"#, false),
+    "a959f638" => (4, false, r#"Premier Manager 2003-04 (Europe)"#, false),
+    "2706dff2" => (4, false, r#"Premier Manager 2004-2005 (Europe)"#, false),
+    "3b04673b" => (4, false, r#"Premier Manager 2005-2006 (Europe)"#, false),
+    "f0f16b7e" => (1, false, r#"Prince of Persia - The Sands of Time (Europe)"#, false),
+    "5adf72e6" => (1, false, r#"Prince of Persia - The Sands of Time (USA, rev. 1)"#, false),
+    "0c043ba6" => (1, false, r#"Prince of Persia - The Sands of Time (USA)"#, false),
+    "e6567686" => (0, false, r#"Princess Natasha - Student, Secret Agent, Princess (Europe)"#, false),
+    "b07cb7ba" => (0, false, r#"Princess Natasha - Student, Secret Agent, Princess (USA)"#, false),
+    "7623e41a" => (1, false, r#"Pro Beach Soccer (Europe)"#, false),
+    "e4d9ae55" => (2, false, r#"Pro Mahjong Tsuwamono GBA (Japan)"#, false),
+    "f7171521" => (1, false, r#"Pro Tennis WTA Tour (Europe)"#, false),
+    "a8a3aa8b" => (3, false, r#"Pro Yakyuu Team o Tsukurou! Advance (Japan)"#, false),
+    "c92bde6e" => (1, false, r#"The Proud Family (USA)"#, false),
+    "91c4c334" => (2, false, r#"PukuPuku Tennen Kairanban (Japan)"#, false),
+    "b57fec3a" => (2, false, r#"PukuPuku Tennen Kairanban - Koi no Cupid Daisakusen (Japan)"#, false),
+    "e98cf9c3" => (2, false, r#"PukuPuku Tennen Kairanban - Youkoso! Illusion Land e (Japan)"#, false),
+    "3e4cb21c" => (0, false, r#"Punch King - Arcade Boxing (Europe)"#, false),
+    "540b6cc1" => (0, false, r#"Punch King - Arcade Boxing (USA)"#, false),
+    "04146dd6" => (1, false, r#"Puppy Luv - Spa and Resort (USA)"#, false),
+    "c85de50e" => (1, false, r#"Puyo Pop (Europe)"#, false),
+    "0901be49" => (1, false, r#"Puyo Pop (USA)"#, false),
+    "6e5880ed" => (1, false, r#"Puyo Pop Fever (Europe)"#, false),
+    "3ffd621a" => (1, false, r#"Puyo Puyo Fever (Japan)"#, false),
+    "893551bb" => (2, false, r#"Puzzle & Tantei Collection (Japan)"#, false),
+    "1f67bd36" => (3, false, r#"Pyuu to Fuku! Jaguar - Byoo to Deru! Megane-kun (Japan)"#, false),
+    "95be56e6" => (0, false, r#"Quad Desert Fury (Europe, USA)"#, false),
+    "10e16dd4" => (0, false, r#"Quake (demo)"#, false),
+    "09c59e6b" => (0, false, r#"Qwak (Europe, demo)"#, false),
+    "9027917f" => (3, false, r#"Qwak (Europe)"#, false),
+    "3e7f9100" => (0, false, r#"R3D-Demo V1 (Europe, demo)"#, false),
+    "41bb7f8a" => (0, false, r#"R-Type III - The Third Lightning (Europe)"#, false),
+    "ea863f4d" => (0, false, r#"R-Type III - The Third Lightning (USA)"#, false),
+    "e40ec737" => (0, false, r#"Racing Fever (Europe)"#, false),
+    "4f61dedb" => (0, false, r#"Racing Fever (France)"#, false),
+    "49a88e52" => (1, false, r#"Racing Gears Advance (Europe)"#, false),
+    "bf648e5c" => (1, false, r#"Racing Gears Advance (USA)"#, false),
+    "86a05f93" => (1, false, r#"Racing Gears Advance (USA, prototype, 20030922)"#, false),
+    "8eca2b0f" => (0, false, r#"Rampage - Puzzle Attack (Europe, USA)"#, false),
+    "964d39a7" => (1, false, r#"Rapala Pro Fishing (Europe, USA)"#, false),
+    "82f9c596" => (0, false, r#"Ratatouille (Europe, English / Italian / Danish / Norwegian / Swedish)"#, false),
+    "5c48c362" => (0, false, r#"Ratatouille (Europe, French / German / Dutch)"#, false),
+    "7a80b515" => (0, false, r#"Ratatouille (Europe, Spanish / Portuguese)"#, false),
+    "aa2eb686" => (0, false, r#"Ratatouille (USA)"#, false),
+    "c39b9418" => (3, false, r#"Rave Master - Special Attack Force! (USA)"#, false),
+    "437e95b9" => (2, false, r#"Rayman - 10th Anniversary (Europe)"#, false),
+    "ae72b0ee" => (2, false, r#"Rayman - 10th Anniversary (USA)"#, false),
+    "c221c419" => (1, false, r#"Rayman - Hoodlums' Revenge (Europe)"#, false),
+    "025a9998" => (1, false, r#"Rayman - Hoodlum's Revenge (USA)"#, false),
+    "9914da23" => (1, false, r#"Rayman - Raving Rabbids (Europe)"#, false),
+    "751ef0d2" => (1, false, r#"Rayman - Raving Rabbids (USA)"#, false),
+    "8f34b14f" => (1, false, r#"Rayman 3 (Europe, prototype)"#, false),
+    "29f83314" => (1, false, r#"Rayman 3 (Europe)"#, false),
+    "d1613266" => (1, false, r#"Rayman 3 (USA)"#, false),
+    "4b1b4e02" => (1, false, r#"Rayman Advance (Europe, prototype)"#, false),
+    "b43783b4" => (1, false, r#"Rayman Advance (Europe)"#, false),
+    "8aba5ab8" => (1, false, r#"Rayman Advance (USA)"#, false),
+    "a169b3b5" => (1, false, r#"Razbitume! - Restez Branchés! (Europe)"#, false),
+    "720b7ff2" => (0, false, r#"Les Razmoket Rencontrent les Delajungle (France)"#, false),
+    "4130f7d0" => (0, false, r#"Les Razmoket - A Moi la Fiesta (France)"#, false),
+    "809a103a" => (0, false, r#"Les Razmoket - Voler N'Est Pas Jouer (France)"#, false),
+    "ffe38431" => (0, false, r#"Razor Freestyle Scooter (USA)"#, false),
+    "e418e962" => (0, false, r#"Ready 2 Rumble Boxing - Round 2 (Europe)"#, false),
+    "5dfcc5e5" => (0, false, r#"Ready 2 Rumble Boxing - Round 2 (USA)"#, false),
+    "e448c5d4" => (2, false, r#"Rebelstar - Tactical Command (Europe)"#, false),
+    "fb6c590f" => (2, false, r#"Rebelstar - Tactical Command (USA)"#, false),
+    "b29bee34" => (3, false, r#"Recca no Honoo - The Game (Japan)"#, false),
+    "79d7f4bc" => (0, false, r#"Reign of Fire (Europe)"#, false),
+    "b48f8d57" => (0, false, r#"Reign of Fire (USA)"#, false),
+    "9f333017" => (1, false, r#"Relaxuma na Mainichi (Japan)"#, false),
+    "8111261c" => (0, false, r#"Rescue Heroes - Billy Blazes! (USA)"#, false),
+    "26b5cf8b" => (0, false, r#"Resident Evil 2 (Europe, prototype demo)"#, false),
+    "58a3f0e1" => (0, false, r#"The Revenge of Shinobi (Europe, prototype)"#, false),
+    "ddb4496a" => (0, false, r#"The Revenge of Shinobi (Europe)"#, false),
+    "e9339304" => (0, false, r#"The Revenge of Shinobi (USA)"#, false),
+    "a6cd88e1" => (3, false, r#"Rhythm Tengoku (Japan, rev. 1)"#, false),
+    "349d7025" => (3, false, r#"Rhythm Tengoku (Japan)"#, false),
+    "9850bf21" => (0, false, r#"The Ripping Friends (Europe, USA)"#, false),
+    "8686436e" => (2, false, r#"River City Ransom EX (USA)"#, false),
+    "4fb6958d" => (3, false, r#"Riviera - The Promised Land (USA)"#, false),
+    "146097f2" => (3, false, r#"Riviera - Yakusoku no Chi Riviera (Japan, rev. 1)"#, false),
+    "f73b840f" => (3, false, r#"Riviera - Yakusoku no Chi Riviera (Japan)"#, false),
+    "f4693458" => (0, false, r#"Road Rash - Jailbreak (Europe)"#, false),
+    "8834af99" => (0, false, r#"Road Rash - Jailbreak (USA)"#, false),
+    "549bf1c5" => (3, false, r#"Road Trip - Shifting Gears (USA)"#, false),
+    "282e6408" => (0, false, r#"Robocop (prototype)"#, false),
+    "68d3023a" => (5, false, r#"Robopon 2 - Cross Version (USA)"#, false),
+    "ca73e17a" => (5, false, r#"Robopon 2 - Ring Version (USA)"#, false),
+    "0c6f2b1b" => (5, false, r#"Robot Ponkottsu 2 - Cross Version (Japan)"#, false),
+    "6002159c" => (5, false, r#"Robot Ponkottsu 2 - Ring Version (Japan)"#, false),
+    "cf8231be" => (1, false, r#"Robot Wars - Advanced Destruction (Europe)"#, false),
+    "e6cb567d" => (1, false, r#"Robot Wars - Advanced Destruction (USA)"#, false),
+    "5ee9d6c5" => (1, false, r#"Robot Wars - Extreme Destruction (Europe)"#, false),
+    "325a6596" => (1, false, r#"Robotech - The Macross Saga (Europe, USA)"#, false),
+    "2acbdb64" => (1, false, r#"Robots (Europe)"#, false),
+    "1beb9e91" => (1, false, r#"Robots (Japan)"#, false),
+    "5370ae1f" => (1, false, r#"Robots (USA)"#, false),
+    "53d1a142" => (1, false, r#"Rock n' Roll Racing (Europe)"#, false),
+    "15f2dd16" => (1, false, r#"Rock n' Roll Racing (USA)"#, false),
+    "6ad6e952" => (0, false, r#"Rock'em Sock'em Robots (Europe)"#, false),
+    "c8896c62" => (0, false, r#"Rock'em Sock'em Robots (USA)"#, false),
+    "13695669" => (0, false, r#"Rocket Power - Beach Bandits (Europe, USA)"#, false),
+    "d3755452" => (0, false, r#"Rocket Power - Beach Bandits (USA, prototype V0.14)"#, false),
+    "93961cb2" => (0, false, r#"Rocket Power - Dream Scheme (Europe, USA)"#, false),
+    "17b8b786" => (0, false, r#"Rocket Power - Le Cauchemar d'Otto (France)"#, false),
+    "26d62d32" => (0, false, r#"Rocket Power - Zero Gravity Zone (USA)"#, false),
+    "ce2b48c4" => (2, false, r#"Rockman & Forte (Japan)"#, false),
+    "ed7c5b50" => (3, false, r#"Rockman EXE 4 - Tournament Blue Moon (Japan)"#, false),
+    "cf0e8b05" => (3, false, r#"Rockman EXE 4 - Tournament Red Sun (Japan, rev. 1)"#, false),
+    "6a51907d" => (3, false, r#"Rockman EXE 4 - Tournament Red Sun (Japan)"#, false),
+    "a646601b" => (5, true, r#"Rockman EXE 4.5 - Real Operation (Japan)"#, false),
+    "c73f23c0" => (3, false, r#"Rockman EXE 5 - Team of Blues (Japan)"#, false),
+    "16842635" => (3, false, r#"Rockman EXE 5 - Team of Colonel (Japan)"#, false),
+    "2dfb603e" => (3, false, r#"Rockman EXE 6 - Dennoujuu Falzar (Japan)"#, false),
+    "6285918a" => (3, false, r#"Rockman EXE 6 - Dennoujuu Gregar (Japan)"#, false),
+    "9217fb18" => (3, false, r#"Rockman EXE Battle Chip GP (Japan)"#, false),
+    "ff2a67b1" => (3, false, r#"Rockman Zero (Japan)"#, false),
+    "9b5c8a4c" => (3, false, r#"Rockman Zero 2 (Japan)"#, false),
+    "5a2c41a9" => (3, false, r#"Rockman Zero 3 (Japan)"#, false),
+    "ece42d0e" => (2, false, r#"Rockman Zero 4 (Japan)"#, false),
+    "d2e55fb5" => (1, false, r#"Rocky (Europe)"#, false),
+    "c8f4ff1b" => (1, false, r#"Rocky (USA)"#, false),
+    "e7fc81d0" => (4, false, r#"RPG Tsukuru Advance (Japan)"#, false),
+    "8aabd86a" => (0, false, r#"Rugrats - Castle Capers (Europe, USA)"#, false),
+    "52a33375" => (0, false, r#"Rugrats - Go Wild (Europe, USA)"#, false),
+    "df167d1d" => (0, false, r#"Rugrats - I Gotta Go Party (Europe, USA)"#, false),
+    "0bcab7d0" => (0, false, r#"Rugrats - Travesuras en el Castillo (Spain)"#, false),
+    "9e3eab8d" => (2, false, r#"Sabre Wulf (Europe)"#, false),
+    "73787a16" => (2, false, r#"Sabre Wulf (USA)"#, false),
+    "caa8225b" => (0, false, r#"Sabrina - The Teenage Witch - Potion Commotion (Europe)"#, false),
+    "a39edd2f" => (0, false, r#"Sabrina - The Teenage Witch - Potion Commotion (USA)"#, false),
+    "02b9f82d" => (3, false, r#"Saibara Rieko no Dendou Mahjong (Japan)"#, false),
+    "b0a75590" => (3, false, r#"Sakura Momoko no UkiUki Carnival (Japan)"#, false),
+    "68836df9" => (1, false, r#"Salt Lake 2002 (Europe)"#, false),
+    "4603205a" => (1, false, r#"Salt Lake 2002 (USA)"#, false),
+    "56ead477" => (1, false, r#"Samurai Deeper Kyo (Japan)"#, false),
+    "4ab65478" => (1, false, r#"Samurai Deeper Kyo (USA)"#, false),
+    "c0f54c65" => (3, false, r#"Samurai Evolution - Oukoku Geist (Japan)"#, false),
+    "b250366c" => (1, false, r#"Samurai Jack - The Amulet of Time (Europe, USA)"#, false),
+    "f3d3c239" => (5, false, r#"Sangokushi (Japan)"#, false),
+    "a4a1c956" => (4, false, r#"Sangokushi - Eiketsuden (Japan)"#, false),
+    "765043ff" => (4, false, r#"Sangokushi - Koumeiden (Japan)"#, false),
+    "5257bd92" => (2, false, r#"Sanrio Puroland - All Characters (Japan)"#, false),
+    "b3780a4f" => (2, false, r#"Samsara Naga 1x2 (Japan, rev. 2)"#, false),
+    "68076b62" => (2, false, r#"Samsara Naga 1x2 (Japan)"#, false),
+    "38d896bd" => (1, false, r#"Santa Claus Jr. Advance (Europe, prototype)"#, false),
+    "fe3e6769" => (1, false, r#"Santa Claus Jr. Advance (Europe)"#, false),
+    "49e012a8" => (0, false, r#"Santa Claus Saves the Earth (Europe)"#, false),
+    "206933f5" => (1, false, r#"The Santa Clause 3 - The Escape Clause (USA)"#, false),
+    "dcfddff7" => (3, false, r#"Scan Hunter - Sennen Kaigyo o Oe! (Japan)"#, false),
+    "e5ac4bb9" => (1, false, r#"Scooby-Doo (Europe)"#, false),
+    "ceefc038" => (1, false, r#"Scooby-Doo (France)"#, false),
+    "03a07291" => (1, false, r#"Scooby-Doo (Germany)"#, false),
+    "e5261946" => (1, false, r#"Scooby-Doo (Spain)"#, false),
+    "22d993c6" => (1, false, r#"Scooby-Doo (USA)"#, false),
+    "7bdab2cf" => (0, false, r#"Scooby-Doo 2 - Monsters Unleashed (Europe, English / French / German / Italian
+			/ Spanish)"#, false),
+    "9ff01ddd" => (0, false, r#"Scooby-Doo 2 - Monsters Unleashed (Europe, USA)"#, false),
+    "cf2dba97" => (0, false, r#"Scooby-Doo and the Cyber Chase (Europe, USA)"#, false),
+    "8b0079cb" => (0, false, r#"Scooby-Doo and the Cyber Chase (Europe, English / French / German)"#, false),
+    "5348a655" => (1, false, r#"Scooby-Doo! - Mystery Mayhem (Europe)"#, false),
+    "3d389a12" => (1, false, r#"Scooby-Doo! - Mystery Mayhem (USA)"#, false),
+    "d21b8344" => (0, false, r#"Scooby-Doo! - Unmasked (Europe, English / French)"#, false),
+    "af42f90f" => (0, false, r#"Scooby-Doo! - Unmasked (Europe, Italian / Spanish)"#, false),
+    "96bdd6c1" => (0, false, r#"Scooby-Doo! - Unmasked (USA)"#, false),
+    "d5410d32" => (0, false, r#"The Scorpion King - Sword of Osiris (Europe)"#, false),
+    "6e0a8585" => (0, false, r#"The Scorpion King - Sword of Osiris (USA)"#, false),
+    "c5c9b31b" => (3, false, r#"Scrabble (Europe)"#, false),
+    "5d31a1f9" => (0, false, r#"Scrabble Blast! (USA)"#, false),
+    "0ed5f375" => (0, false, r#"Scrabble Scramble! (Europe, English)"#, false),
+    "e8eb2e3a" => (0, false, r#"Scrabble Scramble! (Europe, English / French / German / Italian / Dutch /
+			Spanish)"#, false),
+    "b17532ee" => (0, false, r#"Screw Breaker - Goushin DoriRureRo (Japan)"#, false),
+    "2c119783" => (1, false, r#"Scurge - Hive (Europe)"#, false),
+    "1ae38ac0" => (1, false, r#"Scurge - Hive (USA)"#, false),
+    "83417478" => (1, false, r#"SD Gundam Force (Japan)"#, false),
+    "68b21289" => (1, false, r#"SD Gundam Force (USA)"#, false),
+    "7daf215c" => (2, false, r#"SD Gundam GGeneration Advance (Japan)"#, false),
+    "c822b1bc" => (1, false, r#"Sea Boy (demo)"#, false),
+    "3484f139" => (0, false, r#"Sea-Monkeys (USA)"#, false),
+    "7597e8c1" => (2, false, r#"Sea Trader - Rise of Taipan (USA)"#, false),
+    "da3133c3" => (0, false, r#"Secret Agent Barbie - Royal Jewels Mission (Europe)"#, false),
+    "610913e0" => (0, false, r#"Secret Agent Barbie - Royal Jewels Mission (USA)"#, false),
+    "f92140c0" => (0, false, r#"Sega Arcade Gallery (Europe)"#, false),
+    "3b8ffdf4" => (0, false, r#"Sega Arcade Gallery (USA)"#, false),
+    "d125a6bb" => (3, false, r#"Sega Rally Championship (Europe)"#, false),
+    "e57c16f7" => (3, false, r#"Sega Rally Championship (Japan)"#, false),
+    "1ae3eb16" => (3, false, r#"Sega Rally Championship (USA)"#, false),
+    "09310fec" => (0, false, r#"Sega Smash Pack (Europe)"#, false),
+    "5c2e97ba" => (0, false, r#"Sega Smash Pack (USA)"#, false),
+    "3dd25fd7" => (3, false, r#"Sengoku Kakumei Gaiden (Japan)"#, false),
+    "a2f6976c" => (5, true, r#"Sennen Kazoku (Japan)"#, false),
+    "596c5d4c" => (3, false, r#"Sentouin Yamada Hajime (Japan)"#, false),
+    "58199232" => (0, false, r#"Serious Sam Advance (Europe)"#, false),
+    "fdf4eca7" => (0, false, r#"Serious Sam Advance (USA)"#, false),
+    "798593a5" => (3, false, r#"Shonen Jump's Shaman King - Legacy of the Spirits - Soaring Hawk (USA)"#, false),
+    "fa1aa3f6" => (3, false, r#"Shonen Jump's Shaman King - Legacy of the Spirits - Sprinting Wolf (USA)"#, false),
+    "4864b878" => (3, false, r#"Shonen Jump's Shaman King - Master of Spirits (Europe)"#, false),
+    "10e554ae" => (3, false, r#"Shonen Jump's Shaman King - Master of Spirits (USA)"#, false),
+    "4a74e038" => (3, false, r#"Shonen Jump's Shaman King - Master of Spirits 2 (Europe)"#, false),
+    "201e3412" => (3, false, r#"Shonen Jump's Shaman King - Master of Spirits 2 (USA)"#, false),
+    "cb9a390a" => (3, false, r#"Shaman King Card Game - Chou Senjiryakketsu 2 (Japan)"#, false),
+    "2bcf66d4" => (3, false, r#"Shaman King Card Game - Chou Senjiryakketsu 3 (Japan)"#, false),
+    "6a0f2c8b" => (1, false, r#"Shamu's Deep Sea Adventures (Europe)"#, false),
+    "4e9426eb" => (1, false, r#"Shamu's Deep Sea Adventures (USA)"#, false),
+    "9aeea9b7" => (1, false, r#"Shanghai Advance (Japan)"#, false),
+    "df2518e9" => (1, false, r#"Shark Tale (Europe, French / German / Spanish)"#, false),
+    "804a01b1" => (1, false, r#"Shark Story (Italy)"#, false),
+    "128be27c" => (1, false, r#"Shark Tale (Japan)"#, false),
+    "7b68d93e" => (1, false, r#"Shark Tale (Europe, USA)"#, false),
+    "6d545fed" => (0, false, r#"Shaun Palmer's Pro Snowboarder (Germany)"#, false),
+    "66265ad9" => (0, false, r#"Shaun Palmer's Pro Snowboarder (Europe, USA)"#, false),
+    "75a92925" => (2, false, r#"Sheep (Europe)"#, false),
+    "e0a452a8" => (2, false, r#"Shikakui Atama o Maruku Suru. Advance - Kanji, Keisan (Japan)"#, false),
+    "07695a6c" => (2, false, r#"Shikakui Atama o Maruku Suru. Advance - Kokugo, Sansuu, Shakai, Rika (Japan)"#, false),
+    "5546b016" => (2, false, r#"Shimura Ken no Baka Tonosama - Bakushou Tenka Touitsu Game (Japan, rev. 1)"#, false),
+    "f29e6e2d" => (2, false, r#"Shimura Ken no Baka Tonosama - Bakushou Tenka Touitsu Game (Japan)"#, false),
+    "af453162" => (0, true, r#"Shin Bokura no Taiyou - Gyakushuu no Sabata (Japan)"#, false),
+    "769a7666" => (2, false, r#"Shin Chan - Aventuras en Cineland (Spain)"#, false),
+    "fd47a2fe" => (2, false, r#"Shin Chan contra los Muñecos de Shock Gahn (Spain)"#, false),
+    "b65095bf" => (3, false, r#"Shin Kisekae Monogatari (Japan)"#, false),
+    "b857c3c5" => (3, false, r#"Shin Megami Tensei (Japan)"#, false),
+    "5d7ee5af" => (3, false, r#"Shin Megami Tensei Devil Children - Hikari no Sho (Japan)"#, false),
+    "9a0903bc" => (3, false, r#"Shin Megami Tensei Devil Children - Honoo no Sho (Japan)"#, false),
+    "ad80d5f9" => (3, false, r#"Shin Megami Tensei Devil Children - Koori no Sho (Japan)"#, false),
+    "0ec98c51" => (3, false, r#"Shin Megami Tensei Devil Children - Messiah Riser (Japan)"#, false),
+    "ea8a185a" => (2, false, r#"Shin Megami Tensei Devil Children - Puzzle de Call! (Japan)"#, false),
+    "e0e153b7" => (3, false, r#"Shin Megami Tensei Devil Children - Yami no Sho (Japan)"#, false),
+    "af40cc99" => (3, false, r#"Shin Megami Tensei II (Japan)"#, false),
+    "9f0b8b79" => (3, false, r#"Shin Nihon Pro Wrestling - Toukon Retsuden Advance (Japan)"#, false),
+    "fe1be6c1" => (3, false, r#"Shin Sangoku Musou Advance (Japan)"#, false),
+    "54af534d" => (2, false, r#"Shingata Medarot - Kabuto Version (Japan)"#, false),
+    "0fd35628" => (2, false, r#"Shingata Medarot - Kuwagata Version (Japan)"#, false),
+    "4a643cc4" => (3, false, r#"Shining Force - Kuroki Ryuu no Fukkatsu (Japan)"#, false),
+    "07b06994" => (3, false, r#"Shining Force - Resurrection of the Dark Dragon (Europe)"#, false),
+    "563aa3a0" => (3, false, r#"Shining Force - Resurrection of the Dark Dragon (USA)"#, false),
+    "74b5d069" => (4, false, r#"Shining Soul (Europe)"#, false),
+    "521450d1" => (5, false, r#"Shining Soul (Japan)"#, false),
+    "e95bba0c" => (4, false, r#"Shining Soul (USA)"#, false),
+    "55e503c1" => (4, false, r#"Shining Soul II (Europe)"#, false),
+    "7b39a7b2" => (4, false, r#"Shining Soul II (Japan)"#, false),
+    "4038e282" => (4, false, r#"Shining Soul II (USA)"#, false),
+    "31b220e5" => (4, false, r#"Shinyaku Seiken Densetsu (Japan)"#, false),
+    "c1f2b5ec" => (3, false, r#"Shiren Monsters Netsal (Japan)"#, false),
+    "cd8e4f85" => (1, false, r#"Shrek - Hassle at the Castle (Europe)"#, false),
+    "09e7472c" => (1, false, r#"Shrek - Hassle at the Castle (USA)"#, false),
+    "1e4d4813" => (1, false, r#"Shrek - Reekin' Havoc (Europe)"#, false),
+    "63f49ba9" => (1, false, r#"Shrek - Reekin' Havoc (USA)"#, false),
+    "a45857c4" => (1, false, r#"Shrek - Smash n' Crash Racing (Europe)"#, false),
+    "ea8ae3b2" => (1, false, r#"Shrek - Smash n' Crash Racing (USA)"#, false),
+    "df73101e" => (1, false, r#"Shrek - Super Slam (Europe)"#, false),
+    "e2ec9eed" => (1, false, r#"Shrek - Super Slam (USA)"#, false),
+    "41f55560" => (1, false, r#"Shrek - Swamp Kart Speedway (USA, rev. 1)"#, false),
+    "91483d87" => (1, false, r#"Shrek - Swamp Kart Speedway (Europe, USA)"#, false),
+    "52ff42d4" => (1, false, r#"Shrek 2 (Europe, USA)"#, false),
+    "ac1d8c97" => (1, false, r#"Shrek 2 (Europe, French / German / Italian / Spanish / Swedish)"#, false),
+    "b6e091ce" => (1, false, r#"Shrek 2 - Beg for Mercy (Europe, French / German / Italian / Spanish)"#, false),
+    "f1861610" => (1, false, r#"Shrek 2 - Beg for Mercy (Europe, USA)"#, false),
+    "da1ee1e5" => (1, false, r#"Shrek the Third (Europe)"#, false),
+    "d7d1da8d" => (1, false, r#"Shrek the Third (USA)"#, false),
+    "a8b80a7c" => (1, false, r#"Sigma Star Saga (Europe, USA)"#, false),
+    "f03728f3" => (1, false, r#"Silent Scope (Europe)"#, false),
+    "8ab024e0" => (1, false, r#"Silent Scope (Japan)"#, false),
+    "c5ce1b2c" => (1, false, r#"Silent Scope (USA)"#, false),
+    "515e2127" => (3, false, r#"Silk to Cotton (Japan)"#, false),
+    "a74187d3" => (2, false, r#"Sim City 2000 (Europe)"#, false),
+    "95c79648" => (2, false, r#"Sim City 2000 (Europe, rev. 1)"#, false),
+    "097e8628" => (2, false, r#"Sim City 2000 (USA, rev. 1)"#, false),
+    "733751b3" => (2, false, r#"Sim City 2000 (USA)"#, false),
+    "8b72ba3c" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 1 - The Table Game Collection - Mahjong
+			Shougi Hanafuda Reversi (Japan)"#, false),
+    "180901e3" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 2 - The Block Kuzushi (Japan, rev. 1)"#, false),
+    "e731fd45" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 2 - The Block Kuzushi (Japan)"#, false),
+    "49b92627" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 3 - The Itsudemo Puzzle - Massugu Soroete
+			Straws (Japan)"#, false),
+    "07c2d7ed" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 4 - The Trump - Minna de Asoberu 12 Shurui no
+			Trump Game (Japan, rev. 1)"#, false),
+    "a6fc701f" => (1, false, r#"Simple 2960 Tomodachi Series Vol. 4 - The Trump - Minna de Asoberu 12 Shurui no
+			Trump Game (Japan)"#, false),
+    "44548b33" => (0, false, r#"The Simpsons - Road Rage (Europe, English / French / German / Italian /
+			Spanish)"#, false),
+    "409e8252" => (0, false, r#"The Simpsons - Road Rage (Europe, USA)"#, false),
+    "abaf519c" => (2, false, r#"The Sims - Bustin' Out (Europe, USA)"#, false),
+    "89956199" => (2, false, r#"The Sims (Japan)"#, false),
+    "8b1bc7df" => (4, false, r#"The Sims 2 (Europe, USA)"#, false),
+    "87f8599c" => (4, false, r#"The Sims 2 - Pets (Europe, English / French / German / Italian / Spanish /
+			Dutch)"#, false),
+    "ec1f4029" => (4, false, r#"The Sims 2 - Pets (Europe, USA)"#, false),
+    "6845c671" => (1, false, r#"Sister Princess - RePure (Japan)"#, false),
+    "b92a8a88" => (0, false, r#"Sitting Ducks (Europe)"#, false),
+    "8bc90adb" => (0, false, r#"Sitting Ducks (USA)"#, false),
+    "91f93500" => (1, false, r#"SK8 - Tony Hawk's Pro Skater 2 (Japan)"#, false),
+    "3af1063b" => (0, false, r#"Sky Dancers - They Magically Fly! (Europe)"#, false),
+    "cadd57dd" => (0, false, r#"Sky Dancers - They Magically Fly! (USA)"#, false),
+    "1194d33b" => (1, false, r#"Slime Morimori Dragon Quest - Shougeki no Shippo Dan (Japan)"#, false),
+    "4da0b936" => (2, false, r#"Slot! Pro 2 Advance - GoGo Juggler & New Tairyou (Japan)"#, false),
+    "d9a8d01f" => (2, false, r#"Slot! Pro Advance - Takarabune & Ooedo Sakurafubuki 2 (Japan)"#, false),
+    "1034d672" => (0, false, r#"Smashing Drive (Europe)"#, false),
+    "2390aca6" => (0, false, r#"Smashing Drive (USA)"#, false),
+    "3ae0afc8" => (0, false, r#"Smuggler's Run (Europe)"#, false),
+    "1a6aea5e" => (0, false, r#"Smuggler's Run (USA)"#, false),
+    "516ce770" => (0, false, r#"The Smurfs - The Revenge of the Smurfs (Europe)"#, false),
+    "00f5687a" => (0, false, r#"SN Systems (Japan)"#, false),
+    "41e35c6a" => (3, false, r#"Snap Kid's (Japan)"#, false),
+    "32bd1486" => (0, false, r#"Snood (Europe)"#, false),
+    "f1f1f148" => (0, false, r#"Snood (USA)"#, false),
+    "6470252f" => (0, false, r#"Snood 2 - On Vacation (Europe)"#, false),
+    "d8e05727" => (0, false, r#"Snood 2 - On Vacation (USA)"#, false),
+    "d3485f5a" => (0, false, r#"Soccer Kid (Europe, USA)"#, false),
+    "6232839b" => (5, false, r#"Sonic Advance (Europe)"#, false),
+    "85957a24" => (5, false, r#"Sonic Advance (Japan, rev. 1)"#, false),
+    "5f512223" => (5, false, r#"Sonic Advance (Japan)"#, false),
+    "63f70fd8" => (5, false, r#"Sonic Advance (USA)"#, false),
+    "a80de3b2" => (5, false, r#"Sonic Advance (USA, prototype)"#, false),
+    "89509891" => (4, false, r#"Sonic Advance 2 (Europe)"#, false),
+    "513804ff" => (4, false, r#"Sonic Advance 2 (Japan)"#, false),
+    "7efee7f7" => (4, false, r#"Sonic Advance 2 (USA)"#, false),
+    "6d7567ed" => (4, false, r#"Sonic Advance 2 (USA, prototype)"#, false),
+    "4c93dac6" => (4, false, r#"Sonic Advance 3 (Japan, prototype)"#, false),
+    "5bf83456" => (4, false, r#"Sonic Advance 3 (Europe)"#, false),
+    "4375f1d6" => (4, false, r#"Sonic Advance 3 (Japan)"#, false),
+    "49dda5e6" => (4, false, r#"Sonic Advance 3 (USA)"#, false),
+    "d0f65125" => (4, false, r#"Sonic Battle (Europe)"#, false),
+    "7305ac30" => (4, false, r#"Sonic Battle (Japan)"#, false),
+    "9ec9d86f" => (4, false, r#"Sonic Battle (USA)"#, false),
+    "4435917e" => (4, false, r#"Sonic Pinball Party (Europe)"#, false),
+    "43b5f167" => (4, false, r#"Sonic Pinball Party (Japan)"#, false),
+    "08794743" => (4, false, r#"Sonic Pinball Party (USA)"#, false),
+    "027bc70d" => (1, false, r#"Sonic the Hedgehog - Genesis (USA)"#, false),
+    "cf404aee" => (0, false, r#"A Sound of Thunder (Europe)"#, false),
+    "72894e1b" => (0, false, r#"A Sound of Thunder (USA)"#, false),
+    "0f105694" => (1, false, r#"Space Channel 5 - Ulala's Cosmic Attack (Europe)"#, false),
+    "0666f37a" => (1, false, r#"Space Channel 5 - Ulala's Cosmic Attack (USA)"#, false),
+    "bd4e6008" => (3, false, r#"Space Hexcite - Maetel Legend EX (Japan)"#, false),
+    "59372656" => (1, false, r#"Space Invaders (France)"#, false),
+    "5ea3f3b5" => (1, false, r#"Space Invaders (Europe, USA)"#, false),
+    "297b9854" => (1, false, r#"Space Invaders EX (Japan)"#, false),
+    "051f3d0d" => (1, false, r#"Speedball 2 - Brutal Deluxe (Europe)"#, false),
+    "832ea2b1" => (1, false, r#"Spider-Man (France)"#, false),
+    "e9d9b35f" => (1, false, r#"Spider-Man (Germany)"#, false),
+    "406265b8" => (1, false, r#"Spider-Man (Europe, USA)"#, false),
+    "ad2c404c" => (1, false, r#"Spider-Man - Battle for New York (Europe)"#, false),
+    "6310ba16" => (1, false, r#"Spider-Man - Battle for New York (USA)"#, false),
+    "f8125c4b" => (1, false, r#"Spider-Man - Mysterio no Kyoui (Japan)"#, false),
+    "6e135b4d" => (0, false, r#"Spider-Man - Mysterio's Menace (Europe, USA)"#, false),
+    "821838bc" => (1, false, r#"Spider-Man 2 (Europe, English / French / German / Spanish)"#, false),
+    "66340f23" => (1, false, r#"Spider-Man 2 (Italy)"#, false),
+    "0145f3f4" => (1, false, r#"Spider-Man 2 (Europe, USA)"#, false),
+    "6be8d68c" => (1, false, r#"Spider-Man 3 (Europe)"#, false),
+    "450d1d48" => (1, false, r#"Spider-Man 3 (France)"#, false),
+    "fda3c9dd" => (1, false, r#"Spider-Man 3 (Germany)"#, false),
+    "e8fb4b9a" => (1, false, r#"Spider-Man 3 (Italy)"#, false),
+    "e6251fd3" => (1, false, r#"Spider-Man 3 (Spain)"#, false),
+    "ad0228a1" => (1, false, r#"Spider-Man 3 (USA)"#, false),
+    "caaaefb6" => (0, false, r#"Spirit - Der Wilde Mustang (Germany, prototype)"#, false),
+    "4785eee0" => (0, false, r#"Spirit - Der Wilde Mustang (Germany)"#, false),
+    "c8c76255" => (0, false, r#"Spirit - L'Etalon des Plaines (France)"#, false),
+    "aee21783" => (0, false, r#"Spirit - Stallion of the Cimarron (Europe)"#, false),
+    "9a457633" => (0, false, r#"Spirit - Stallion of the Cimarron (USA)"#, false),
+    "2fa6db95" => (0, false, r#"Spirits & Spells (USA)"#, false),
+    "de4a85c0" => (0, false, r#"SpongeBob SquarePants - Battle for Bikini Bottom (Europe)"#, false),
+    "235628c3" => (0, false, r#"SpongeBob SquarePants - Battle for Bikini Bottom (USA)"#, false),
+    "7580fbd6" => (1, false, r#"SpongeBob SquarePants - Creature from the Krusty Krab (Europe)"#, false),
+    "51ce91d6" => (1, false, r#"SpongeBob SquarePants - Creature from the Krusty Krab (USA)"#, false),
+    "c189013d" => (1, false, r#"SpongeBob SquarePants - Lights, Camera, Pants! (Europe)"#, false),
+    "23297579" => (1, false, r#"SpongeBob SquarePants - Lights, Camera, Pants! (USA)"#, false),
+    "4f21757d" => (1, false, r#"SpongeBob SquarePants - Revenge of the Flying Dutchman (USA, prototype)"#, false),
+    "30d6c979" => (0, false, r#"SpongeBob SquarePants - Revenge of the Flying Dutchman (Europe, USA)"#, false),
+    "98ad67e6" => (0, false, r#"SpongeBob SquarePants - SuperSponge (Europe, USA)"#, false),
+    "033229a7" => (1, false, r#"SpongeBob SquarePants and Friends - Battle for Volcano Island (Europe)"#, false),
+    "fc339fc7" => (1, false, r#"SpongeBob SquarePants and Friends in Freeze Frame Frenzy (Europe)"#, false),
+    "611cc620" => (0, false, r#"SpongeBob SquarePants and Friends Unite! (Europe)"#, false),
+    "5d9d52de" => (0, false, r#"SpongeBob SquarePants - The Movie (Europe)"#, false),
+    "df3d16a1" => (3, false, r#"SpongeBob SquarePants - The Movie (USA, prototype)"#, false),
+    "e1068687" => (0, false, r#"SpongeBob SquarePants - The Movie (USA)"#, false),
+    "190b1653" => (0, false, r#"SpongeBob's Atlantis SquarePantis (USA)"#, false),
+    "616088df" => (3, false, r#"Sports Illustrated for Kids - Baseball (USA)"#, false),
+    "9972779d" => (3, false, r#"Sports Illustrated for Kids - Football (USA)"#, false),
+    "2e725460" => (1, false, r#"Sportsman's Pack (USA)"#, false),
+    "4f196612" => (1, false, r#"Spy Hunter (Europe)"#, false),
+    "b04892c9" => (1, false, r#"Spy Hunter (USA)"#, false),
+    "a93601f5" => (1, false, r#"Spy Kids 3-D - Game Over (Europe)"#, false),
+    "96128b31" => (1, false, r#"Spy Kids 3-D - Game Over (USA)"#, false),
+    "e8227e41" => (1, false, r#"Spy Kids Challenger (USA)"#, false),
+    "848f9d42" => (0, false, r#"Spy Muppets - License to Croak (USA)"#, false),
+    "13f10a1e" => (2, false, r#"Spyro - Attack of the Rhynocs (USA)"#, false),
+    "afd25827" => (1, false, r#"Spyro - Season of Ice (Europe)"#, false),
+    "2f2389d2" => (1, false, r#"Spyro - Season of Ice (USA)"#, false),
+    "104b4caa" => (2, false, r#"Spyro 2 - Season of Flame (Europe)"#, false),
+    "37e120e7" => (2, false, r#"Spyro 2 - Season of Flame (USA)"#, false),
+    "d05c86bb" => (0, false, r#"Spyro 2 - Season of Flame (USA, prototype)"#, false),
+    "42dd5dd9" => (1, false, r#"Spyro Advance (Japan)"#, false),
+    "91a9e2cc" => (1, false, r#"Spyro Advance - Wakuwaku Tomodachi Daisakusen! (Japan)"#, false),
+    "1f62d660" => (2, false, r#"Spyro Adventure (Europe)"#, false),
+    "e53ba082" => (1, false, r#"Spyro Fusion (Europe)"#, false),
+    "bbf2549d" => (1, false, r#"Spyro Orange - The Cortex Conspiracy (USA, rev. A)"#, false),
+    "a9915baf" => (1, false, r#"Spyro Orange - The Cortex Conspiracy (USA)"#, false),
+    "8232f58a" => (1, false, r#"SSX 3 (Europe, USA)"#, false),
+    "e0988123" => (1, false, r#"SSX Tricky (Europe, USA)"#, false),
+    "db146cc3" => (0, false, r#"Stadium Games (Europe)"#, false),
+    "4bf6e9e8" => (0, false, r#"Stadium Games (USA)"#, false),
+    "c492c46d" => (0, false, r#"Star Wars - Episode II - Attack of the Clones (Europe)"#, false),
+    "8fb5e2c6" => (0, false, r#"Star Wars - Episode II - Attack of the Clones (USA)"#, false),
+    "fd49236b" => (1, false, r#"Star Wars - Episode III - Revenge of the Sith (Europe)"#, false),
+    "3e34aab7" => (1, false, r#"Star Wars - Episode III - Revenge of the Sith (USA)"#, false),
+    "a41d22ad" => (0, false, r#"Star Wars - Flight of the Falcon (Europe)"#, false),
+    "94d2737a" => (0, false, r#"Star Wars - Flight of the Falcon (USA)"#, false),
+    "fa0f055d" => (0, false, r#"Star Wars - Jedi Power Battles (Europe)"#, false),
+    "3f19e156" => (0, false, r#"Star Wars - Jedi Power Battles (USA)"#, false),
+    "677854af" => (0, false, r#"Star Wars - The New Droid Army (Europe)"#, false),
+    "59c52f98" => (0, false, r#"Star Wars - The New Droid Army (USA)"#, false),
+    "c9f0c492" => (1, false, r#"Star Wars Trilogy - Apprentice of the Force (Europe)"#, false),
+    "714a0d3a" => (1, false, r#"Star Wars Trilogy - Apprentice of the Force (USA)"#, false),
+    "b5d61c72" => (1, false, r#"Star Wars Trilogy - Apprentice of the Force (USA, prototype)"#, false),
+    "9dca9c3c" => (0, false, r#"Star X (Europe)"#, false),
+    "8b02b2b1" => (0, false, r#"Star X (USA)"#, false),
+    "209958e5" => (1, false, r#"Starsky & Hutch (Europe)"#, false),
+    "b3993252" => (1, false, r#"Starsky & Hutch (USA)"#, false),
+    "ab142d2e" => (1, false, r#"Starsky & Hutch (USA, prototype)"#, false),
+    "cb91922e" => (1, false, r#"Steel Empire (Europe)"#, false),
+    "f3f2e465" => (1, false, r#"Strawberry Shortcake - Ice Cream Island - Riding Camp (Europe, rev. 1)"#, false),
+    "c40220cf" => (1, false, r#"Strawberry Shortcake - Ice Cream Island - Riding Camp (Europe)"#, false),
+    "7fec3419" => (0, false, r#"Strawberry Shortcake - Summertime Adventure (USA)"#, false),
+    "5a66cafa" => (0, false, r#"Strawberry Shortcake - Summertime Adventure - Special Edition (USA)"#, false),
+    "53384966" => (1, false, r#"Strawberry Shortcake - Sweet Dreams (USA)"#, false),
+    "93c5cf69" => (2, false, r#"Street Fighter Alpha 3 (Europe)"#, false),
+    "80b707c2" => (2, false, r#"Street Fighter Alpha 3 (USA)"#, false),
+    "8d5d0eab" => (2, false, r#"Street Fighter Zero 3 Upper (Japan)"#, false),
+    "3f20a970" => (1, false, r#"Street Jam Basketball (Europe, USA)"#, false),
+    "ac24888b" => (1, false, r#"Street Racing Syndicate (Europe)"#, false),
+    "f44b6a70" => (1, false, r#"Street Racing Syndicate (USA)"#, false),
+    "277d2c89" => (0, false, r#"Strike Force Hydra (Europe)"#, false),
+    "5aeea1de" => (0, false, r#"Strike Force Hydra (USA)"#, false),
+    "0827d896" => (0, false, r#"Stuart Little 2 (France)"#, false),
+    "6eb7c688" => (0, false, r#"Stuart Little 2 (Europe, USA)"#, false),
+    "62a678ab" => (1, false, r#"Stuntman (Europe)"#, false),
+    "0d58ba07" => (1, false, r#"Stuntman (USA)"#, false),
+    "0a4e0ed2" => (2, false, r#"Sugar Sugar Rune - Heart ga Ippai! Moegi Gakuen (Japan)"#, false),
+    "1d33dd2c" => (1, false, r#"The Suite Life of Zack & Cody - Tipton Caper (USA)"#, false),
+    "aea15c21" => (1, false, r#"The Sum of All Fears (Europe)"#, false),
+    "166391d1" => (1, false, r#"The Sum of All Fears (USA)"#, false),
+    "a5d38236" => (2, false, r#"Summon Night - Craft Sword Monogatari (Japan)"#, false),
+    "12afae5d" => (2, false, r#"Summon Night - Craft Sword Monogatari - Hajimari no Ishi (Japan)"#, false),
+    "8cd98f6f" => (2, false, r#"Summon Night - Craft Sword Monogatari 2 (Japan)"#, false),
+    "3ea860c2" => (2, false, r#"Summon Night - Swordcraft Story (USA)"#, false),
+    "1eac1f48" => (2, false, r#"Summon Night - Swordcraft Story 2 (USA)"#, false),
+    "baf484be" => (0, false, r#"Super Army War (USA)"#, false),
+    "91238ad1" => (2, false, r#"Super Black Bass Advance (Europe)"#, false),
+    "67c4e470" => (3, false, r#"Super Black Bass Advance (Japan)"#, false),
+    "25123d97" => (1, false, r#"Super Bubble Pop (Europe)"#, false),
+    "5ec8ea61" => (1, false, r#"Super Bubble Pop (USA)"#, false),
+    "6bfd2915" => (0, false, r#"Super Bust-A-Move (Europe)"#, false),
+    "2fb4b14d" => (0, false, r#"Super Bust-A-Move (USA)"#, false),
+    "35448df4" => (1, false, r#"Super Chinese 1, 2 Advance (Japan)"#, false),
+    "f4634dbd" => (0, false, r#"Super Collapse! II (USA)"#, false),
+    "4ca4f528" => (3, false, r#"Super Dodge Ball Advance (Europe)"#, false),
+    "626603b5" => (3, false, r#"Super Dodge Ball Advance (USA, prototype)"#, false),
+    "63ca5050" => (3, false, r#"Super Dodge Ball Advance (USA, rev. 1)"#, false),
+    "1d31dc6b" => (3, false, r#"Super Dodge Ball Advance (USA)"#, false),
+    "2206b04d" => (1, false, r#"Super Donkey Kong (Japan)"#, false),
+    "27a1cc75" => (2, false, r#"Super Donkey Kong 2 (Japan)"#, false),
+    "4dcf6e20" => (1, false, r#"Super Donkey Kong 3 (Japan)"#, false),
+    "bab43d5c" => (0, false, r#"Archer Maclean's Dropzone (Europe)"#, false),
+    "2b4843fe" => (0, false, r#"Archer Maclean's Dropzone (USA)"#, false),
+    "8e20043f" => (1, false, r#"Super Duper Sumos (USA)"#, false),
+    "1ef2acf3" => (1, false, r#"Super Ghouls'n Ghosts (Europe, USA)"#, false),
+    "9653bac5" => (0, false, r#"Super Hornet F/A 18F (Europe, USA)"#, false),
+    "1e4c6d6a" => (1, false, r#"Super Mario Advance (Europe, USA)"#, false),
+    "69924cbd" => (1, false, r#"Super Mario Advance (USA, demo Kiosk)"#, false),
+    "b993b92f" => (1, false, r#"Super Mario Advance - Super Mario USA + Mario Brothers (Japan)"#, false),
+    "fcfef343" => (2, false, r#"Super Mario Advance 2 - Super Mario World (Europe)"#, false),
+    "5206880a" => (2, false, r#"Super Mario Advance 2 - Super Mario World (USA, Australia)"#, false),
+    "901ce373" => (2, false, r#"Super Mario Advance 2 - Super Mario World + Mario Brothers (Japan)"#, false),
+    "639e9d3b" => (2, false, r#"Super Mario Advance 3 - Yoshi's Island (Europe)"#, false),
+    "40a48276" => (2, false, r#"Super Mario Advance 3 - Yoshi's Island (USA)"#, false),
+    "26321120" => (2, false, r#"Super Mario Advance 3 - Yoshi's Island + Mario Brothers (Japan)"#, false),
+    "7b1a4c22" => (5, false, r#"Super Mario Advance 4 - Super Mario 3 + Mario Brothers (Japan, rev. 2)"#, false),
+    "5a676424" => (5, false, r#"Super Mario Advance 4 - Super Mario 3 + Mario Brothers (Japan, rev. 1)"#, false),
+    "f3c87306" => (5, false, r#"Super Mario Advance 4 - Super Mario 3 + Mario Brothers (Japan)"#, false),
+    "8d84acfc" => (5, false, r#"Super Mario Advance 4 - Super Mario Bros. 3 (Europe, rev. 1)"#, false),
+    "37141f32" => (5, false, r#"Super Mario Advance 4 - Super Mario Bros. 3 (Europe)"#, false),
+    "88dab27f" => (5, false, r#"Super Mario Advance 4 - Super Mario Bros. 3 (USA, Australia, rev. 1)"#, false),
+    "a44ba79f" => (5, false, r#"Super Mario Advance 4 - Super Mario Bros. 3 (USA)"#, false),
+    "3b035681" => (1, false, r#"Super Mario Ball (Europe)"#, false),
+    "7eb4a336" => (1, false, r#"Super Mario Ball (Japan)"#, false),
+    "085b271b" => (0, false, r#"Super Mario Bros. (Japan, Hot Mario Campaign)"#, false),
+    "d75da09d" => (1, false, r#"Super Monkey Ball Jr. (Europe)"#, false),
+    "0a8031f0" => (1, false, r#"Super Monkey Ball Jr. (USA)"#, false),
+    "f2bc49ce" => (0, false, r#"Super Puzzle Bobble Advance (Japan)"#, false),
+    "aac3e9ca" => (1, false, r#"Super Puzzle Fighter II Turbo (Europe)"#, false),
+    "62066fc9" => (1, false, r#"Super Puzzle Fighter II Turbo (USA, rev. 1)"#, false),
+    "9cd25c7e" => (1, false, r#"Super Puzzle Fighter II Turbo (USA)"#, false),
+    "9e90456b" => (1, false, r#"Super Real Mahjong Dousoukai (Japan)"#, false),
+    "bdb86caa" => (4, false, r#"Super Robot Taisen - Original Generation (Europe)"#, false),
+    "75127ec4" => (5, false, r#"Super Robot Taisen - Original Generation (Japan)"#, false),
+    "58e86266" => (4, false, r#"Super Robot Taisen - Original Generation (USA)"#, false),
+    "a608800f" => (4, false, r#"Super Robot Taisen - Original Generation 2 (Japan)"#, false),
+    "2aee0380" => (4, false, r#"Super Robot Taisen - Original Generation 2 (USA)"#, false),
+    "2f6e9788" => (5, false, r#"Super Robot Taisen A (Japan)"#, false),
+    "efb45117" => (4, false, r#"Super Robot Taisen D (Japan)"#, false),
+    "c956fd37" => (4, false, r#"Super Robot Taisen J (Japan)"#, false),
+    "df7f91bc" => (5, false, r#"Super Robot Taisen R (Japan)"#, false),
+    "461b4590" => (1, false, r#"Super Street Fighter II Turbo - Revival (Europe)"#, false),
+    "063045aa" => (1, false, r#"Super Street Fighter II Turbo - Revival (USA)"#, false),
+    "7a2c0d61" => (1, false, r#"Super Street Fighter II X - Revival (Japan)"#, false),
+    "2bf26a56" => (0, false, r#"Superman - Countdown to Apokolips (Europe)"#, false),
+    "43017d47" => (0, false, r#"Superman - Countdown to Apokolips (USA)"#, false),
+    "68215047" => (1, false, r#"Superman Returns - Fortress of Solitude (Europe, USA)"#, false),
+    "b225ae1a" => (1, false, r#"Surf's Up (Europe, English / French / German / Italian / Spanish)"#, false),
+    "317326b0" => (1, false, r#"Surf's Up (Europe, English / French / Spanish)"#, false),
+    "0adf57c5" => (1, false, r#"Surf's Up (USA)"#, false),
+    "260683c7" => (3, false, r#"Sutakomi - Star Communicator (Japan)"#, false),
+    "63f45849" => (2, false, r#"Sweet Cookie Pie (Japan)"#, false),
+    "88e64a8a" => (4, false, r#"Sword of Mana (Europe, English)"#, false),
+    "5ee17ed1" => (4, false, r#"Sword of Mana (Europe, French / German)"#, false),
+    "11a0af49" => (4, false, r#"Sword of Mana (Europe, Italian / Spanish)"#, false),
+    "7f1eac75" => (4, false, r#"Sword of Mana (USA, Australia)"#, false),
+    "58dc532e" => (2, false, r#"Sylvanian Families - Fashion Designer ni Naritai! - Kurumi-risu no Onnanoko
+			(Japan)"#, false),
+    "00911694" => (2, false, r#"Sylvanian Families - Yousei no Stick to Fushigi no Ki - Marron-inu no Onnanoko
+			(Japan)"#, false),
+    "3bfbf110" => (2, false, r#"Sylvanian Families 4 - Meguru Kisetsu no Tapestry (Japan)"#, false),
+    "6649d2ce" => (5, false, r#"Tactics Ogre - The Knight of Lodis (USA)"#, false),
+    "0167a878" => (5, false, r#"Tactics Ogre Gaiden - The Knight of Lodis (Japan)"#, false),
+    "4e6bdf8b" => (1, false, r#"Taiketsu! Ultra Hero (Japan)"#, false),
+    "881c8416" => (1, false, r#"Tak - The Great Juju Challenge (Europe, USA, Australia)"#, false),
+    "4ad90ec1" => (1, false, r#"Tak - The Great Juju Challenge (Europe, English / French / German / Dutch)"#, false),
+    "91dd701d" => (1, false, r#"Tak 2 - The Staff of Dreams (Europe)"#, false),
+    "8f7eb026" => (1, false, r#"Tak 2 - The Staff of Dreams (USA)"#, false),
+    "0d483aa6" => (1, false, r#"Tak and the Power of Juju (Europe, English / French / German)"#, false),
+    "4d0c3680" => (1, false, r#"Tak and the Power of Juju (Europe, Italian / Spanish)"#, false),
+    "a9af1381" => (1, false, r#"Tak and the Power of Juju (USA)"#, false),
+    "fa172a4a" => (2, false, r#"Tales of Phantasia (Europe)"#, false),
+    "d08819c1" => (2, false, r#"Tales of Phantasia (Japan)"#, false),
+    "01a0fca8" => (2, false, r#"Tales of Phantasia (USA, Australia)"#, false),
+    "231b9fca" => (2, false, r#"Tales of the World - Narikiri Dungeon 2 (Japan)"#, false),
+    "1867ccef" => (2, false, r#"Tales of the World - Narikiri Dungeon 3 (Japan)"#, false),
+    "9c534023" => (3, false, r#"Tales of the World - Summoner's Lineage (Japan)"#, false),
+    "2785cb89" => (3, false, r#"Tanbi Musou - Meine Liebe (Japan)"#, false),
+    "8bfb4cfe" => (1, false, r#"Tang Tang (Europe)"#, false),
+    "f5f0c143" => (1, false, r#"Tang Tang (USA)"#, false),
+    "77c172bd" => (3, false, r#"Tantei Gakuen Q - Kyuukyoku Trick ni Idome! (Japan)"#, false),
+    "552f0d90" => (3, false, r#"Tantei Gakuen Q - Meitantei wa Kimi Da! (Japan)"#, false),
+    "3c21d7e0" => (2, false, r#"Tantei Jinguuji Saburou - Shiroi Kage no Shoujo (Japan)"#, false),
+    "32c6df3f" => (1, false, r#"Disney's Tarzan - Return to the Jungle (Europe, USA)"#, false),
+    "35ab002e" => (1, false, r#"Disney's Tarzan - L'Appel de la Jungle (France)"#, false),
+    "59733b26" => (1, false, r#"Disney's Tarzan - Rückkehr in den Dschungel (Germany)"#, false),
+    "2d2b0c11" => (0, false, r#"Taxi 3 (France)"#, false),
+    "12a0442c" => (1, false, r#"Teen Titans (USA)"#, false),
+    "ccacd823" => (1, false, r#"Teen Titans 2 - The Brotherhood's Revenge (USA)"#, false),
+    "15bdf0ad" => (1, false, r#"Teenage Mutant Ninja Turtles (Europe)"#, false),
+    "f6c36783" => (1, false, r#"Teenage Mutant Ninja Turtles (USA)"#, false),
+    "ada00a65" => (2, false, r#"Teenage Mutant Ninja Turtles 2 - Battle Nexus (Europe)"#, false),
+    "802fc7c1" => (2, false, r#"Teenage Mutant Ninja Turtles 2 - Battle Nexus (USA)"#, false),
+    "4628c1b8" => (1, false, r#"Tekken Advance (Europe)"#, false),
+    "c2eeae53" => (1, false, r#"Tekken Advance (Japan)"#, false),
+    "c59ea73b" => (1, false, r#"Tekken Advance (USA)"#, false),
+    "3c225943" => (0, false, r#"Ten Pin Alley 2 (USA)"#, false),
+    "28e6bd52" => (1, false, r#"Tennis Masters Series 2003 (Europe)"#, false),
+    "afc3fcf7" => (3, false, r#"Tennis no Ouji-sama - Aim at the Victory! (Japan)"#, false),
+    "dd244c9a" => (5, false, r#"Tennis no Ouji-sama - Genius Boys Academy (Japan)"#, false),
+    "9f7fc898" => (3, false, r#"Tennis no Ouji-sama 2003 - Cool Blue (Japan)"#, false),
+    "59d1dc73" => (3, false, r#"Tennis no Ouji-sama 2003 - Passion Red (Japan)"#, false),
+    "26a4ee5c" => (3, false, r#"Tennis no Ouji-sama 2004 - Glorious Gold (Japan)"#, false),
+    "750d579d" => (3, false, r#"Tennis no Ouji-sama 2004 - Stylish Silver (Japan)"#, false),
+    "16c3cac3" => (0, false, r#"Terminator 3 - Rise of the Machines (Europe)"#, false),
+    "f74b3c7d" => (0, false, r#"Terminator 3 - Rise of the Machines (USA)"#, false),
+    "4a834fc2" => (0, false, r#"Tetris Worlds (Europe, English / Italian / Spanish)"#, false),
+    "ad97eb3f" => (0, false, r#"Tetris Worlds (Europe, English / French / German / Dutch)"#, false),
+    "fcf2d471" => (0, false, r#"Tetris Worlds (Japan, rev. 1)"#, false),
+    "dd271470" => (0, false, r#"Tetris Worlds (Japan)"#, false),
+    "7b729804" => (0, false, r#"Tetris Worlds (USA)"#, false),
+    "1a13df6b" => (0, false, r#"Texas Hold 'em Poker (Europe, rev. 1)"#, false),
+    "78b59aac" => (0, false, r#"Texas Hold 'em Poker (Europe, USA)"#, false),
+    "8e190698" => (1, false, r#"That's So Raven (USA)"#, false),
+    "5495cbe1" => (1, false, r#"That's So Raven 2 - Supernatural Style (USA)"#, false),
+    "c3c855ea" => (0, false, r#"The Three Stooges (USA)"#, false),
+    "d482421e" => (0, false, r#"Three-in-One Pack - Connect Four + Perfection + Trouble (USA)"#, false),
+    "15c24091" => (0, false, r#"Three-in-One Pack - Risk + Battleship + Clue (USA, rev. A)"#, false),
+    "f6f70922" => (0, false, r#"Three-in-One Pack - Risk + Battleship + Clue (USA)"#, false),
+    "111eb61a" => (0, false, r#"Three-in-One Pack - Sorry! + Aggravation + Scrabble Junior (USA)"#, false),
+    "25e8e649" => (0, false, r#"Thunder Alley (USA)"#, false),
+    "f09b7bb0" => (0, false, r#"Thunderbirds (Europe, USA)"#, false),
+    "9a6cb678" => (0, false, r#"Thunderbirds - International Rescue (Europe)"#, false),
+    "0e38108b" => (1, false, r#"Tiger Woods PGA Tour 2004 (Europe, USA)"#, false),
+    "38934732" => (1, false, r#"Tiger Woods PGA Tour Golf (Europe, English / French / German / Italian /
+			Spanish)"#, false),
+    "f3f26acc" => (1, false, r#"Tiger Woods PGA Tour Golf (Europe, USA)"#, false),
+    "9f16857d" => (1, false, r#"Tim Burton's The Nightmare Before Christmas - The Pumpkin King (Japan)"#, false),
+    "3c247c14" => (1, false, r#"Tim Burton's The Nightmare Before Christmas - The Pumpkin King (Europe, USA)"#, false),
+    "944aa8fb" => (0, false, r#"Tin Toy Advance (demo 1, 20010529)"#, false),
+    "7bda5802" => (0, false, r#"Tin Toy Advance (demo 2, 20010717)"#, false),
+    "3246a8bd" => (0, false, r#"Tiny Toon Adventures - Buster's Bad Dream (Europe)"#, false),
+    "fcd7a7c0" => (0, false, r#"Tiny Toon Adventures - Scary Dreams (USA)"#, false),
+    "4ac770b6" => (0, false, r#"Tiny Toon Adventures - Wacky Stackers (Europe)"#, false),
+    "e03c633e" => (0, false, r#"Tiny Toon Adventures - Wacky Stackers (USA)"#, false),
+    "7782a204" => (0, false, r#"Tir et But - Edition Champions du Monde (France)"#, false),
+    "47bc61b5" => (0, false, r#"Titeuf - Ze Gagmachine (France)"#, false),
+    "ffd0a045" => (0, false, r#"Titeuf Méga Compet (France)"#, false),
+    "89103893" => (1, false, r#"TMNT (Europe)"#, false),
+    "916db9d5" => (1, false, r#"TMNT (USA)"#, false),
+    "d8125874" => (1, false, r#"TOCA World Touring Cars (Europe)"#, false),
+    "6eea16bd" => (2, false, r#"Tokimeki Yume Series 1 - Ohanaya-san ni Narou! (Japan)"#, false),
+    "e05cef9d" => (3, false, r#"Tokyo Majin Gakuen - Fuju Houroku (Japan)"#, false),
+    "bd5f5f4c" => (1, false, r#"Tokyo Xtreme Racer Advance (Europe)"#, false),
+    "ab272800" => (1, false, r#"Tokyo Xtreme Racer Advance (USA)"#, false),
+    "1b7a3819" => (0, false, r#"Tom and Jerry - The Magic Ring (Europe)"#, false),
+    "7dc8e08e" => (0, false, r#"Tom and Jerry - The Magic Ring (USA)"#, false),
+    "2db83271" => (0, false, r#"Tom and Jerry in Infurnal Escape (Europe)"#, false),
+    "11156d88" => (0, false, r#"Tom and Jerry in Infurnal Escape (USA)"#, false),
+    "5a8d5736" => (0, false, r#"Tom and Jerry Tales (Europe)"#, false),
+    "af494f20" => (0, false, r#"Tom and Jerry Tales (USA)"#, false),
+    "6863ce1a" => (1, false, r#"Tom Clancy's Rainbow Six - Rogue Spear (Europe)"#, false),
+    "23575bf2" => (1, false, r#"Tom Clancy's Rainbow Six - Rogue Spear (USA)"#, false),
+    "e07ba799" => (1, false, r#"Tom Clancy's Splinter Cell (Europe, prototype)"#, false),
+    "7ecd29ce" => (1, false, r#"Tom Clancy's Splinter Cell (Europe)"#, false),
+    "308ba69c" => (1, false, r#"Tom Clancy's Splinter Cell (USA)"#, false),
+    "f007dfe1" => (1, false, r#"Tom Clancy's Splinter Cell - Pandora Tomorrow (Europe)"#, false),
+    "aefb7b54" => (1, false, r#"Tom Clancy's Splinter Cell - Pandora Tomorrow (USA)"#, false),
+    "e37ca939" => (2, false, r#"Tomato Adventure (Japan)"#, false),
+    "dbcd4541" => (0, false, r#"Tonka - On the Job (USA)"#, false),
+    "a824df3f" => (2, false, r#"Tony Hawk's American Sk8land (Europe, English)"#, false),
+    "9633084a" => (2, false, r#"Tony Hawk's American Sk8land (Europe, French / German / Italian / Spanish)"#, false),
+    "7b089f4a" => (2, false, r#"Tony Hawk's American Sk8land (USA)"#, false),
+    "4a975f48" => (1, false, r#"Tony Hawk's Downhill Jam (Europe)"#, false),
+    "37b0b53d" => (1, false, r#"Tony Hawk's Downhill Jam (USA)"#, false),
+    "812dc57a" => (1, false, r#"Tony Hawk's Pro Skater 2 (Europe, USA)"#, false),
+    "f0efc9d8" => (1, false, r#"Tony Hawk's Pro Skater 2 (France)"#, false),
+    "6d236181" => (1, false, r#"Tony Hawk's Pro Skater 2 (Germany)"#, false),
+    "f669a368" => (2, false, r#"Tony Hawk's Pro Skater 3 (Europe, USA)"#, false),
+    "8d132067" => (2, false, r#"Tony Hawk's Pro Skater 3 (France)"#, false),
+    "74174675" => (2, false, r#"Tony Hawk's Pro Skater 3 (Germany)"#, false),
+    "8cb0aa77" => (2, false, r#"Tony Hawk's Pro Skater 4 (Europe, USA)"#, false),
+    "1f5149bc" => (2, false, r#"Tony Hawk's Underground (Europe, USA)"#, false),
+    "bf864083" => (2, false, r#"Tony Hawk's Underground 2 (Europe, USA)"#, false),
+    "3e69a610" => (0, false, r#"Tootuff - The Gagmachine (Europe, prototype)"#, false),
+    "391c0497" => (1, false, r#"Top Gear GT Championship (Europe)"#, false),
+    "0a9c9968" => (1, false, r#"Top Gear GT Championship (USA)"#, false),
+    "86ad4007" => (1, false, r#"Top Gear Rally (Europe)"#, false),
+    "be67d878" => (1, false, r#"TG Rally (Europe)"#, false),
+    "c457c5db" => (1, false, r#"Top Gear Rally (Japan)"#, false),
+    "4dd90762" => (1, false, r#"Top Gear Rally (USA)"#, false),
+    "dfc88d3e" => (0, false, r#"Top Gun - Combat Zones (USA)"#, false),
+    "fdbe5a26" => (0, false, r#"Top Gun - Firestorm Advance (Europe, USA)"#, false),
+    "a8e8618b" => (1, false, r#"Top Spin 2 (Europe)"#, false),
+    "a960d63b" => (1, false, r#"Top Spin 2 (USA)"#, false),
+    "b94ddc93" => (1, false, r#"Total Soccer (Europe)"#, false),
+    "28c3e211" => (1, false, r#"Total Soccer Advance (Japan)"#, false),
+    "92f99295" => (2, false, r#"Total Soccer Manager (Europe)"#, false),
+    "06992f1d" => (1, false, r#"Totally Spies! (Europe)"#, false),
+    "aaa6e141" => (1, false, r#"Totally Spies! (USA)"#, false),
+    "ca245559" => (1, false, r#"Totally Spies! 2 - Undercover (Europe)"#, false),
+    "2c8eb8a1" => (1, false, r#"Totally Spies! 2 - Undercover (USA)"#, false),
+    "c2945d42" => (3, false, r#"Tottoko Hamutarou 3 - Love Love Daibouken Dechu (Japan)"#, false),
+    "1b6e5617" => (3, false, r#"Tottoko Hamutarou 4 - Nijiiro Daikoushin Dechu (Japan)"#, false),
+    "14eb6587" => (2, false, r#"Touhai Densetsu Akagi - Yami ni Mai Orita Tensai (Japan)"#, false),
+    "4043f1e0" => (2, false, r#"Toukon Heat (Japan)"#, false),
+    "95e05895" => (4, false, r#"Welcome to The Tower SP (USA)"#, false),
+    "ec325c92" => (4, false, r#"The Tower SP (Japan)"#, false),
+    "227fc16b" => (3, false, r#"Toyrobo Force (Japan)"#, false),
+    "794b2602" => (0, false, r#"Disney's Treasure Planet (Europe, rev. 1)"#, false),
+    "d52ddce1" => (0, false, r#"Disney's Treasure Planet (Europe)"#, false),
+    "8b14f9ec" => (0, false, r#"Disney's Treasure Planet (USA)"#, false),
+    "961260ae" => (1, false, r#"Trick Star (Europe)"#, false),
+    "b5f7bf43" => (1, false, r#"Tringo (Europe)"#, false),
+    "2956a0da" => (1, false, r#"Tringo (USA)"#, false),
+    "3e0ed609" => (1, false, r#"Trollz - Hair Affair! (Europe, English)"#, false),
+    "fb2284ef" => (1, false, r#"Trollz - Hair Affair! (Europe, English / German / Italian)"#, false),
+    "14fea5c0" => (1, false, r#"Trollz - Hair Affair! (Europe, English / French / Spanish)"#, false),
+    "c5cd2c22" => (1, false, r#"Trollz - Hair Affair! (USA)"#, false),
+    "3bf38352" => (1, false, r#"Tron 2.0 - Killer App (Europe)"#, false),
+    "77d50ec6" => (1, false, r#"Tron 2.0 - Killer App (USA)"#, false),
+    "5a7e762b" => (4, false, r#"Tsuukin Hitofude (Japan)"#, false),
+    "e5e0b0f1" => (1, false, r#"Turbo Turtle Adventure (USA)"#, false),
+    "07a32299" => (0, false, r#"Turok Evolution (Europe)"#, false),
+    "4edac27a" => (0, false, r#"Turok Evolution (USA)"#, false),
+    "378d6da7" => (0, false, r#"Turok Dinosaur Hunter (prototype)"#, false),
+    "9ef46f34" => (0, false, r#"Turok Dinosaur Hunter (prototype brighter)"#, false),
+    "401e10ea" => (0, false, r#"Turok Dinosaur Hunter (prototype earlier)"#, false),
+    "024a1914" => (3, false, r#"Tweety and the Magic Gems (Europe)"#, false),
+    "f1a49d56" => (3, false, r#"Tweety and the Magic Gems (Netherlands)"#, false),
+    "c66e14a4" => (3, false, r#"Titi et les Bijoux Magiques (France)"#, false),
+    "8aa6e91a" => (3, false, r#"Tweety and the Magic Gems (Germany)"#, false),
+    "2da5c91e" => (3, false, r#"Tweety and the Magic Gems (USA)"#, false),
+    "d38672bf" => (3, false, r#"Tweety no Hearty Party (Japan)"#, false),
+    "108c3535" => (2, false, r#"Ty the Tasmanian Tiger 2 - Bush Rescue (Europe, USA)"#, false),
+    "cf467e1b" => (2, false, r#"Ty the Tasmanian Tiger 3 - Night of the Quinkan (USA)"#, false),
+    "e5acba28" => (0, false, r#"Tyrian 2000 (USA, prototype)"#, false),
+    "5a211b60" => (2, false, r#"Uchuu Daisakusen Choco Vader - Uchuu kara no Shinryakusha (Japan)"#, false),
+    "a00a4530" => (3, false, r#"Uchuu no Stellvia (Japan)"#, false),
+    "ed368ef1" => (1, false, r#"Ueki no Housoku - Jingi Sakuretsu! Nouryokusha Battle (Japan)"#, false),
+    "31b1a586" => (2, false, r#"Ui-Ire - World Soccer Winning Eleven (Japan)"#, false),
+    "264f7a95" => (2, false, r#"Ultimate Arcade Games (USA)"#, false),
+    "5d4d88c5" => (1, false, r#"Ultimate Beach Soccer (USA)"#, false),
+    "b6c9f401" => (1, false, r#"Ultimate Brain Games (Europe, USA)"#, false),
+    "94863423" => (2, false, r#"Ultimate Card Games (USA, rev. 2)"#, false),
+    "300f5d25" => (2, false, r#"Ultimate Card Games (USA, rev. 1)"#, false),
+    "8a0831bd" => (2, false, r#"Ultimate Card Games (Europe, USA)"#, false),
+    "bb85ad55" => (1, false, r#"Ultimate Muscle - The Kinnikuman Legacy - The Path of the Superhero (USA)"#, false),
+    "65e2295b" => (1, false, r#"Ultimate Muscle - The Kinnikuman Legacy - The Path of the Superhero (USA,
+			prototype, 20030429)"#, false),
+    "a13f76a5" => (1, false, r#"Ultimate Puzzle Games (USA)"#, false),
+    "97e1727f" => (1, false, r#"Ultimate Spider-Man (Europe, English)"#, false),
+    "61a74634" => (1, false, r#"Ultimate Spider-Man (Europe, French / German / Italian / Spanish)"#, false),
+    "931e2c18" => (1, false, r#"Ultimate Spider-Man (USA)"#, false),
+    "95988ee2" => (0, false, r#"Ultimate Winter Games (USA)"#, false),
+    "c03785c7" => (4, false, r#"Ultra Keibitai - Monster Attack (Japan)"#, false),
+    "2547f3a1" => (0, false, r#"Unfabulous (USA)"#, false),
+    "e2018633" => (0, false, r#"2 Game Pack!: Uno - Skip-Bo (Europe)"#, false),
+    "0e2af604" => (0, false, r#"2 Game Pack!: Uno - Skip-Bo (USA)"#, false),
+    "315360d0" => (0, false, r#"Uno 52 (Europe)"#, false),
+    "c659c6db" => (0, false, r#"Uno 52 (USA)"#, false),
+    "414ea9f4" => (0, false, r#"Uno Free Fall (Europe)"#, false),
+    "19fcc78e" => (0, false, r#"Uno Free Fall (USA)"#, false),
+    "4c53ba49" => (0, false, r#"Urban Yeti! (Europe, USA)"#, false),
+    "8b3330bb" => (4, false, r#"The Urbz - Sims in the City (Europe, USA)"#, false),
+    "bbccc2fd" => (4, false, r#"The Urbz - Sims in the City (Japan)"#, false),
+    "7e617c46" => (0, false, r#"Uridium Advance (Europe, prototype, 20030307)"#, false),
+    "aefa2250" => (0, false, r#"Uridium Advance (Europe, prototype, 20020911)"#, false),
+    "18fb99b1" => (0, false, r#"Uridium Advance and Paradroid 2 in 1 (Europe, prototype, 20030430)"#, false),
+    "251b2503" => (1, false, r#"V-Master Cross (Japan)"#, false),
+    "02d20287" => (1, false, r#"V-Rally 3 (Europe)"#, false),
+    "a0aa46b9" => (1, false, r#"V-Rally 3 (Japan)"#, false),
+    "d3ae3d37" => (1, false, r#"V-Rally 3 (USA)"#, false),
+    "718a6c3b" => (0, false, r#"V.I.P. (Europe)"#, false),
+    "9e8748c9" => (0, false, r#"Van Helsing (Europe)"#, false),
+    "36159d63" => (0, false, r#"Van Helsing (USA)"#, false),
+    "e368a67a" => (3, false, r#"Vattroller X (Japan)"#, false),
+    "b5213253" => (1, false, r#"BigIdea's VeggieTales - LarryBoy and the Bad Apple (USA)"#, false),
+    "e52a3ab8" => (1, false, r#"Virtua Tennis (Europe)"#, false),
+    "e0bd3b75" => (1, false, r#"Virtua Tennis (USA)"#, false),
+    "23b92cf7" => (1, false, r#"Virtual Kasparov (Europe)"#, false),
+    "815b722f" => (1, false, r#"Virtual Kasparov (USA)"#, false),
+    "ca4f6d5d" => (1, false, r#"W.i.t.c.h. (Europe)"#, false),
+    "47b49060" => (1, false, r#"Wade Hixton's Counter Punch (Europe, USA)"#, false),
+    "5e5c008c" => (3, false, r#"Wagamama Fairy Mirumo de Pon! - 8 Nin no Toki no Yousei (Japan)"#, false),
+    "5effdc65" => (3, false, r#"Wagamama Fairy Mirumo de Pon! - Dokidoki Memorial Panic (Japan)"#, false),
+    "ae2fb113" => (3, false, r#"Wagamama Fairy Mirumo de Pon! - Nazo no Kagi to Shinjitsu no Tobira (Japan)"#, false),
+    "7d21f61f" => (2, false, r#"Wagamama Fairy Mirumo de Pon! - Ougon Maracas no Densetsu (Japan)"#, false),
+    "f5da0dcc" => (3, false, r#"Wagamama Fairy Mirumo de Pon! - Taisen Mahoudama (Japan)"#, false),
+    "a606c803" => (3, false, r#"Wagamama Fairy Mirumo de Pon! - Yume no Kakera (Japan)"#, false),
+    "a3ccf1ec" => (1, false, r#"Wakeboarding Unleashed featuring Shaun Murray (Europe)"#, false),
+    "cad6376c" => (1, false, r#"Wakeboarding Unleashed featuring Shaun Murray (USA)"#, false),
+    "cf7585f1" => (3, false, r#"Waliou Xunbao Ji (China)"#, false),
+    "78a4d2d4" => (3, false, r#"Waliou Zhizao (China)"#, false),
+    "b12d2724" => (2, false, r#"Wannyan Doubutsu Byouin (Japan, rev. 1)"#, false),
+    "6de82857" => (2, false, r#"Wannyan Doubutsu Byouin (Japan)"#, false),
+    "e93f547b" => (1, false, r#"Wanko de Kururin! Wancle (Japan)"#, false),
+    "253ad05e" => (2, false, r#"Wanko Mix Chiwanko World (Japan)"#, false),
+    "5f41c9fe" => (1, false, r#"Wanwan Meitantei (Japan)"#, false),
+    "d6141609" => (3, false, r#"Wario Land 4 (Europe, USA)"#, false),
+    "f56ebb7a" => (3, false, r#"Wario Land Advance (Japan)"#, false),
+    "500ca178" => (3, false, r#"WarioWare, Inc. - Minigame Mania (Europe)"#, false),
+    "ec0c0c58" => (3, false, r#"WarioWare, Inc. - Mega Microgame$! (USA, prototype)"#, false),
+    "785d8b8c" => (3, false, r#"WarioWare, Inc. - Mega Microgame$! (USA)"#, false),
+    "cb4e844b" => (0, false, r#"WarioWare - Twisted! (USA, Australia)"#, false),
+    "e58ef9a2" => (1, false, r#"Whac-A-Mole (USA)"#, false),
+    "667a0d06" => (3, false, r#"Whistle! - Dai-37-kai Tokyo-to Chuugakkou Sougou Taiiku Soccer Taikai (Japan)"#, false),
+    "b9c55b22" => (0, false, r#"Who Wants to Be a Millionaire (UK)"#, false),
+    "da7006e0" => (0, false, r#"Who Wants to Be a Millionaire (Australia)"#, false),
+    "41aa465e" => (0, false, r#"Weekend Miljonairs (Netherlands)"#, false),
+    "08d71a89" => (0, false, r#"Wer Wird Millionär (Germany)"#, false),
+    "8f33bd3f" => (0, false, r#"Qui Veut Gagner des Millions (France)"#, false),
+    "43514d44" => (0, false, r#"Quiere Ser Millonario (Spain)"#, false),
+    "b6f8527e" => (0, false, r#"Chi Vuol Essere Milionario (Italy)"#, false),
+    "c89deb4f" => (0, false, r#"Who Wants to Be a Millionaire - 2nd Edition (UK)"#, false),
+    "aad93da8" => (0, false, r#"Who Wants to Be a Millionaire - Junior (UK)"#, false),
+    "e41720f5" => (0, false, r#"The Wild Thornberrys Movie (USA, prototype)"#, false),
+    "4e2c47bc" => (0, false, r#"The Wild Thornberrys Movie (Europe, USA)"#, false),
+    "afa71c84" => (0, false, r#"La Famille Delajungle - Le Film (France)"#, false),
+    "f0ca0689" => (0, false, r#"The Wild Thornberrys - Chimp Chase (Europe, USA)"#, false),
+    "f344d661" => (0, false, r#"La Famille Delajungle - A la Poursuite de Darwin (France)"#, false),
+    "9d60610d" => (0, false, r#"Expedition der Stachelbeeren - Zoff im Zoo (Germany)"#, false),
+    "de79edc3" => (1, false, r#"The Wild (Europe, USA)"#, false),
+    "c8c12090" => (0, false, r#"Die Wilden Fussball-Kerle (Germany)"#, false),
+    "a6281dd3" => (1, false, r#"Die Wilden Fussball-Kerle - Gefahr im Wilde Kerle Land (Germany)"#, false),
+    "09f02f6a" => (1, false, r#"Wing Commander - Prophecy (Europe)"#, false),
+    "62d9e3d0" => (1, false, r#"Wing Commander - Prophecy (USA)"#, false),
+    "6e0d29fc" => (1, false, r#"Wings (Europe)"#, false),
+    "49041bc8" => (1, false, r#"Wings (USA)"#, false),
+    "e5a97a58" => (0, false, r#"Winnie the Pooh's Rumbly Tumbly Adventure (Europe)"#, false),
+    "5b098a68" => (0, false, r#"Winnie the Pooh's Rumbly Tumbly Adventure (USA)"#, false),
+    "9fb4af0a" => (5, false, r#"Winning Post for Game Boy Advance (Japan, rev. 2)"#, false),
+    "5ada60f6" => (5, false, r#"Winning Post for Game Boy Advance (Japan)"#, false),
+    "64c802f9" => (1, false, r#"Winter Sports (Europe)"#, false),
+    "afcb9ce8" => (2, false, r#"WinX Club (Europe)"#, false),
+    "1d6950a3" => (2, false, r#"WinX Club (USA)"#, false),
+    "9eb09c77" => (1, false, r#"WinX Club - Quest for the Codex (Europe)"#, false),
+    "29dfff34" => (1, false, r#"WinX Club - Quest for the Codex (USA)"#, false),
+    "b3c77a61" => (3, false, r#"Wizardry Summoner (Japan, rev. 1)"#, false),
+    "71695a71" => (3, false, r#"Wizardry Summoner (Japan)"#, false),
+    "c4aa2b7b" => (1, false, r#"Wolfenstein 3D (Europe, USA)"#, false),
+    "a3e1e037" => (1, false, r#"Woody Woodpecker - Crazy Castle 5 (Japan)"#, false),
+    "092b3dce" => (1, false, r#"Woody Woodpecker in Crazy Castle 5 (Europe)"#, false),
+    "b9a3c878" => (1, false, r#"Woody Woodpecker in Crazy Castle 5 (USA)"#, false),
+    "78e5a643" => (1, false, r#"Word Safari - The Friendship Totems (USA)"#, false),
+    "afe01c1f" => (2, false, r#"World Advance Soccer - Shouri e no Michi (Japan)"#, false),
+    "4776f86e" => (0, false, r#"World Championship Poker (Europe)"#, false),
+    "07f15152" => (0, false, r#"World Championship Poker (USA)"#, false),
+    "8fa26a97" => (2, false, r#"WPT - World Poker Tour (Europe)"#, false),
+    "b18784c1" => (2, false, r#"WPT - World Poker Tour (USA)"#, false),
+    "eefb32ff" => (0, false, r#"World Reborn (USA)"#, false),
+    "4c48ebb3" => (0, false, r#"World Reborn (USA, prototype)"#, false),
+    "b5830f5f" => (0, false, r#"World Tennis Stars (Europe)"#, false),
+    "036e30b0" => (0, false, r#"World Tennis Stars (USA)"#, false),
+    "5223f5aa" => (0, false, r#"Worms Blast (Europe)"#, false),
+    "8ee32cbe" => (0, false, r#"Worms World Party (Europe)"#, false),
+    "e8789c18" => (0, false, r#"Worms World Party (USA)"#, false),
+    "7f8cb0af" => (1, false, r#"WTA Tour Tennis (USA)"#, false),
+    "459daf35" => (1, false, r#"WTA Tour Tennis Pocket (Japan)"#, false),
+    "f1fb4fd1" => (1, false, r#"WWE - Road to WrestleMania X8 (Europe, USA)"#, false),
+    "6694f94d" => (1, false, r#"WWE - Survivor Series (Europe, USA)"#, false),
+    "8314421b" => (0, false, r#"WWF - Road to WrestleMania (USA, prototype)"#, false),
+    "8d3b116e" => (0, false, r#"WWF - Road to WrestleMania (Europe, USA)"#, false),
+    "c05f9907" => (0, false, r#"X-Bladez - Inline Skater (Europe)"#, false),
+    "09fdd665" => (0, false, r#"X-Bladez - Inline Skater (USA)"#, false),
+    "25f43491" => (1, false, r#"X-Men - Reign of Apocalypse (Europe, USA)"#, false),
+    "34be6923" => (1, false, r#"X-Men - The Official Game (Europe)"#, false),
+    "c87f460a" => (1, false, r#"X-Men - The Official Game (USA)"#, false),
+    "41a92220" => (1, false, r#"X2 - Wolverine's Revenge (Europe, USA)"#, false),
+    "82373b44" => (1, false, r#"X-Men 2 - La Vengeance de Wolverine (France)"#, false),
+    "6dbbc17c" => (0, false, r#"XS Moto (USA)"#, false),
+    "2b3a2ab2" => (1, false, r#"xXx (Europe, USA)"#, false),
+    "44a77f71" => (1, false, r#"xXx (France)"#, false),
+    "9c32a829" => (2, false, r#"Yaoxi Dao (China)"#, false),
+    "a86f844a" => (2, false, r#"Yggdra Union - We'll Never Fight Alone (Japan)"#, false),
+    "3e0df130" => (2, false, r#"Yggdra Union - We'll Never Fight Alone (Europe)"#, false),
+    "3bfa68f7" => (2, false, r#"Yggdra Union - We'll Never Fight Alone (USA)"#, false),
+    "e64c265c" => (0, false, r#"Yoshi - Topsy-Turvy (USA)"#, false),
+    "31594b7a" => (0, false, r#"Yoshi no Banyuuinryoku (Japan)"#, false),
+    "05f4d0aa" => (0, false, r#"Yoshi's Universal Gravitation (Europe)"#, false),
+    "2a4a92b4" => (2, false, r#"Youkaidou (Japan)"#, false),
+    "208d77ec" => (1, false, r#"Yu Yu Hakusho - Ghostfiles - Spirit Detective (Europe)"#, false),
+    "d3784b52" => (1, false, r#"Yu Yu Hakusho - Ghostfiles - Spirit Detective (USA)"#, false),
+    "aef6b7cc" => (2, false, r#"Yu Yu Hakusho - Ghostfiles - Tournament Tactics (Europe, USA)"#, false),
+    "ae1a8e9d" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Destiny Board Traveler (Europe)"#, false),
+    "611e7bbd" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Destiny Board Traveler (USA)"#, false),
+    "8d52b3c5" => (3, false, r#"Yu-Gi-Oh! - Sugoroku no Sugoroku (Japan)"#, false),
+    "ee317f69" => (5, false, r#"Yu-Gi-Oh! - Dungeon Dice Monsters (Europe)"#, false),
+    "51b35e87" => (5, false, r#"Yu-Gi-Oh! - Dungeon Dice Monsters (Japan)"#, false),
+    "8b5e0a27" => (5, false, r#"Yu-Gi-Oh! - Dungeon Dice Monsters (USA)"#, false),
+    "460e073f" => (3, false, r#"Yu-Gi-Oh! - Reshef of Destruction (Europe)"#, false),
+    "09e0c559" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Reshef of Destruction (USA)"#, false),
+    "d19db37c" => (3, false, r#"Yu-Gi-Oh! - The Sacred Cards (Europe)"#, false),
+    "141fb1cc" => (3, false, r#"Yu-Gi-Oh! - The Sacred Cards (USA)"#, false),
+    "3c42e84c" => (3, false, r#"Yu-Gi-Oh! - World Championship Tournament 2004 (Europe)"#, false),
+    "69b6f229" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - World Championship Tournament 2004 (USA)"#, false),
+    "17988e9b" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Day of the Duelist - World Championship Tournament
+			2005 (Europe)"#, false),
+    "584db6a6" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - 7 Trials to Glory - World Championship Tournament
+			2005 (USA)"#, false),
+    "1becf9d3" => (3, false, r#"Yu-Gi-Oh! Duel Monsters International - Worldwide Edition (Japan, rev. 1)"#, false),
+    "6dcfb879" => (3, false, r#"Yu-Gi-Oh! Duel Monsters International 2 (Japan)"#, false),
+    "dfd07a36" => (3, false, r#"Yu-Gi-Oh! - The Eternal Duelist Soul (USA)"#, false),
+    "cede4060" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Ultimate Masters Edition - World Championship
+			Tournament 2006 (Europe)"#, false),
+    "f968a196" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! - Ultimate Masters - World Championship Tournament 2006
+			(USA)"#, false),
+    "aca5ffc6" => (3, false, r#"Yu-Gi-Oh! - Worldwide Edition - Stairway to the Destined Duel (Europe)"#, false),
+    "39b5e771" => (3, false, r#"Yu-Gi-Oh! - Worldwide Edition - Stairway to the Destined Duel (USA)"#, false),
+    "98507497" => (3, false, r#"Yu-Gi-Oh! Duel Monsters International - Worldwide Edition (Japan)"#, false),
+    "f3041e7e" => (3, false, r#"Yu-Gi-Oh! Duel Monsters 5 Expert 1 (Japan)"#, false),
+    "a7054d60" => (3, false, r#"Yu-Gi-Oh! Duel Monsters 6 Expert 2 (Japan)"#, false),
+    "b9be9b91" => (3, false, r#"Yu-Gi-Oh! Duel Monsters 7 - Kettou Toshi Densetsu (Japan)"#, false),
+    "681daf1f" => (3, false, r#"Yu-Gi-Oh! Duel Monsters 8 - Hametsu no Daijashin (Japan)"#, false),
+    "7a1019e6" => (3, false, r#"Yu-Gi-Oh! Duel Monsters Expert 2006 (Japan)"#, false),
+    "7ab1ec04" => (3, false, r#"Yu-Gi-Oh! Duel Monsters Expert 3 (Japan)"#, false),
+    "3fb2abda" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! GX - Duel Academy (Europe)"#, false),
+    "3b8a00fe" => (3, false, r#"Shonen Jump's Yu-Gi-Oh! GX - Duel Academy (USA)"#, false),
+    "f4295da8" => (3, false, r#"Yu-Gi-Oh! Duel Monsters GX - Mezase Duel King (Japan)"#, false),
+    "4d35aab7" => (1, false, r#"Yumemi-chan no Naritai Series 3 - Watashi no Makesalon (Japan)"#, false),
+    "ce987f90" => (3, false, r#"Yuujou no Victory Goal 4v4 Arashi - Get the Goal!! (Japan)"#, false),
+    "6da2166e" => (1, false, r#"Yuureiyashiki no Nijuuyojikan (Japan)"#, false),
+    "9feef66d" => (5, false, r#"Z.O.E. 2173 - Testament (Japan)"#, false),
+    "8753a1c5" => (1, false, r#"Zapper (Europe)"#, false),
+    "6751a4ef" => (1, false, r#"Zapper (USA)"#, false),
+    "b4f1e785" => (2, false, r#"Zatchbell! - Electric Arena (USA)"#, false),
+    "6ce771a5" => (2, false, r#"Zelda no Densetsu - Fushigi no Boushi (Japan)"#, false),
+    "81e42bee" => (2, false, r#"Zelda no Densetsu - Kamigami no Triforce & 4tsu no Tsurugi (Japan)"#, false),
+    "d320a965" => (5, false, r#"Zen-Nihon GT Senshuken (Japan)"#, false),
+    "268f9c7e" => (5, false, r#"Zen-Nihon Shounen Soccer Taikai 2 - Mezase Nihon-ichi! (Japan)"#, false),
+    "84a4c1b4" => (2, false, r#"Zero One (Japan)"#, false),
+    "fd6ce886" => (2, false, r#"Zero One SP (Japan)"#, false),
+    "de7c1ae8" => (5, false, r#"Zero-Tours (Japan)"#, false),
+    "00328bb3" => (3, false, r#"Zettaizetsumei Dangerous Jiisan - Naki no 1-kai Zettaifukujuu Violence Kouchou
+			- Wagahai ga 1-ban Erainjai!! (Japan)"#, false),
+    "7da6cc69" => (3, false, r#"Zettaizetsumei Dangerous Jiisan - Shijou Saikyou no Dogeza (Japan)"#, false),
+    "5be52fb7" => (3, false, r#"Zettaizetsumei Dangerous Jiisan 3 - Hateshinaki Mamonogatari (Japan)"#, false),
+    "99273ee3" => (3, false, r#"Zettaizetsumei Dangerous Jiisan Tsuu - Ikari no Oshioki Blues (Japan)"#, false),
+    "59e2635f" => (0, false, r#"Zidane Football Generation (Europe)"#, false),
+    "9cf348a8" => (0, false, r#"Zoey 101 (USA)"#, false),
+    "302e75f3" => (3, false, r#"Zoids Legacy (USA)"#, false),
+    "28e8b0d9" => (3, false, r#"Zoids Saga (Japan, rev. 1)"#, false),
+    "5975dda8" => (3, false, r#"Zoids Saga (Japan)"#, false),
+    "094e16ad" => (3, false, r#"Zoids Saga Fuzors (Japan)"#, false),
+    "0c039503" => (3, false, r#"Zoids Saga II (Japan)"#, false),
+    "71e2cd01" => (0, false, r#"Zoku Bokura no Taiyou - Taiyou Shounen Django (Japan, rev. 1)"#, false),
+    "2804e589" => (0, false, r#"Zoku Bokura no Taiyou - Taiyou Shounen Django (Japan)"#, false),
+    "973496e0" => (5, false, r#"Zone of the Enders - The Fist of Mars (Europe)"#, false),
+    "24244415" => (5, false, r#"Zone of the Enders - The Fist of Mars (USA)"#, false),
+    "4a23b8d6" => (1, false, r#"ZooCube (Europe)"#, false),
+    "09a2255f" => (1, false, r#"ZooCube (Japan)"#, false),
+    "af20be72" => (1, false, r#"ZooCube (USA)"#, false),
+    "897b2893" => (2, false, r#"Zooo (Europe)"#, false),
+    "d6d7a9c6" => (0, false, r#"DigimonAdventure (China)"#, false),
+    "12edcbcc" => (0, false, r#"Kou Dai Yao Guai - Zhi Hong Lan Dui Jue (China)"#, false),
+    "0295d50b" => (3, false, r#"Digimon Sapphire (China)"#, false),
+    "cd0a9fdb" => (3, false, r#"Digimon Ruby (China)"#, false),
+    "75f90391" => (0, false, r#"Dolphin Demo"#, false),
+    "954d2619" => (0, false, r#"Sample Yoshi Game (prototype, 20010214)"#, false),
+    "34f2ef47" => (0, false, r#"Sample Yoshi Game (prototype, 20000601)"#, false),
+    "183a2207" => (1, false, r#"2 in 1: Astérix & Obélix - Bash Them All! + Astérix & Obélix XXL
+			(Europe)"#, false),
+    "2c3862c3" => (0, false, r#"Barbie Superpack: Groovy Games + Secret Agent (Europe)"#, false),
+    "c99db2a1" => (0, false, r#"Barbie Superpack: Groovy Games + Secret Agent (USA)"#, false),
+    "d720bdca" => (0, false, r#"2 Games in 1: Bionicle + Knights' Kingdom (Europe)"#, false),
+    "3a4f8e4d" => (0, false, r#"Double Game!: Cartoon Network Block Party + Cartoon Network Speedway (Europe)"#, false),
+    "b3d323e4" => (0, false, r#"2 Games in 1: Cartoon Network Block Party + Cartoon Network Speedway (USA)"#, false),
+    "ee495897" => (3, false, r#"Castlevania Double Pack: Castlevania - Harmony of Dissonance + Castlevania -
+			Aria of Sorrow (Europe)"#, false),
+    "dbcecc7d" => (3, false, r#"Castlevania Double Pack: Castlevania - Harmony of Dissonance + Castlevania -
+			Aria of Sorrow (USA)"#, false),
+    "0beaa2f4" => (4, false, r#"2 Games in 1: Columns Crown + ChuChu Rocket! (Europe)"#, false),
+    "2c38bae2" => (2, false, r#"Crash Superpack: Crash Bandicoot 2 - N-Tranced + Crash Nitro Kart (USA)"#, false),
+    "99c96067" => (2, false, r#"2 Games in 1: Disney Princess + Lizzie McGuire (Europe)"#, false),
+    "57f886b0" => (2, false, r#"2 Games in 1: Disney Princesas + Lizzie McGuire (Spain)"#, false),
+    "34355326" => (1, false, r#"2 Games in 1: Disney Princess + Disney's Brother Bear (Europe)"#, false),
+    "22c54ae1" => (1, false, r#"2 Games in 1: Disney Princesse + Disney's Frère des Ours (France)"#, false),
+    "52c8a11f" => (1, false, r#"2 Games in 1: Disneys Prinzessinnen + Disneys Bärenbrüder (Germany)"#, false),
+    "458dedb1" => (1, false, r#"2 Games in 1: Disney Principesse + Disney's Koda, Fratello Orso (Italy)"#, false),
+    "01b143a9" => (1, false, r#"2 Games in 1: Disney Princesas + Disney's Hermano Oso (Spain)"#, false),
+    "3b1cf966" => (1, false, r#"2 Games in 1: Disney's The Lion King + Disney Princess (Europe)"#, false),
+    "cce9c2e2" => (1, false, r#"2 Games in 1: Disney Princesse + Disney's Le Roi Lion (France)"#, false),
+    "ad82cb84" => (1, false, r#"2 Games in 1: Disneys Prinzessinnen + Disneys Der Koenig der Loewen (Germany)"#, false),
+    "a4acba4a" => (1, false, r#"2 Games in 1: Disney Principesse + Disney's Il Re Leone (Italy)"#, false),
+    "6472ac25" => (1, false, r#"2 Games in 1: Disney Princesas + Disney's El Rey Leon (Spain)"#, false),
+    "9e5f961b" => (1, false, r#"2 Games in 1: Disney's Brother Bear + Disney's The Lion King (Europe)"#, false),
+    "c8cdb4ed" => (3, false, r#"2 Disney Games: Disney Sports - Football + Disney Sports - Skateboarding
+			(Europe)"#, false),
+    "75703943" => (1, false, r#"2 Disney Games: Disney's Lilo & Stitch 2 + Disney's Peter Pan - Return to
+			Neverland (Europe)"#, false),
+    "1f9d002e" => (0, false, r#"2 Games in One: Dora the Explorer Double Pack (USA)"#, false),
+    "ace78c4f" => (2, false, r#"2 Games in 1: Dragon Ball Z I & II (USA)"#, false),
+    "280587f2" => (2, false, r#"2 Games in 1!: Dragon Ball Z - Buu's Fury + Dragon Ball GT - Transformation
+			(USA)"#, false),
+    "d5a37aa8" => (0, false, r#"2 Games in 1: Finding Nemo - The Continuing Adventures + The Incredibles (USA)"#, false),
+    "60a35c6e" => (0, false, r#"2 Games in 1: Findet Nemo + Findet Nemo - Das Abenteuer Geht Weiter (Germany)"#, false),
+    "72d5f428" => (0, false, r#"2 Games in 1: Finding Nemo + Finding Nemo - The Continuing Adventures (Europe)"#, false),
+    "73444273" => (0, false, r#"2 Games in 1: Finding Nemo + Finding Nemo - The Continuing Adventures (Italy?,
+			Spain?)"#, false),
+    "2e5eda0d" => (0, false, r#"2 Games in 1: Finding Nemo + Finding Nemo - The Continuing Adventures (France?,
+			Netherlands?)"#, false),
+    "9702abce" => (0, false, r#"2 Games in 1: Finding Nemo + The Incredibles (Europe, English)"#, false),
+    "2d4d69fa" => (0, false, r#"2 Games in 1: Finding Nemo + The Incredibles (Europe, French / Dutch)"#, false),
+    "5db86e1c" => (0, false, r#"2 Games in 1: Findet Nemo + Die Unglaublichen (Germany)"#, false),
+    "9ea46982" => (0, false, r#"2 Games in 1: Alla Ricerca di Nemo + Gli Incredibili - Una 'Normale' Famiglia
+			di Supereroi (Italy)"#, false),
+    "d7ac0697" => (0, false, r#"2 Games in 1: Buscando a Nemo + Los Increibles (Spain)"#, false),
+    "cff20829" => (0, false, r#"Double Game!: Golden Nugget Casino - Texas Hold 'em Poker (Europe)"#, false),
+    "6f88e088" => (0, false, r#"2 Games in 1: Golden Nugget Casino - Texas Hold 'em Poker (USA)"#, false),
+    "20929ec1" => (0, false, r#"2 Game Pack!: Hot Wheels - Stunt Track Challenge + Hot Wheels - World Race
+			(Europe, USA)"#, false),
+    "b3c2cf9f" => (0, false, r#"2 Games in 1: Hot Wheels - Velocity X + Hot Wheels - World Race (Europe)"#, false),
+    "a440a760" => (0, false, r#"2 Games in 1: Hot Wheels - Velocity X + Hot Wheels - World Race (USA)"#, false),
+    "8eae8860" => (1, false, r#"Hugo 2 in 1 (Europe)"#, false),
+    "0a907f58" => (0, false, r#"2 Game Pack! - Matchbox Missions: Emergency Response + Air, Land & Sea
+			Rescue (Europe)"#, false),
+    "4080aac1" => (0, false, r#"2 Game Pack! - Matchbox Missions: Emergency Response + Air, Land & Sea
+			Rescue (USA)"#, false),
+    "6cb47552" => (0, false, r#"2 Games in 1: Monsters, Inc. + Finding Nemo (Europe)"#, false),
+    "8f66cad4" => (0, false, r#"2 Games in 1: Monsters en Co. + Finding Nemo (Netherlands)"#, false),
+    "20298a03" => (0, false, r#"2 Games in 1: Monstres & Cie + Le Monde de Nemo (France)"#, false),
+    "4fd20e32" => (0, false, r#"2 Games in 1: Die Monster AG + Findet Nemo (Germany)"#, false),
+    "06715995" => (0, false, r#"2 Games in 1: Monsters & Co. + Alla Ricerca di Nemo (Italy)"#, false),
+    "9ff018f9" => (0, false, r#"2 Games in 1: Monstruos, S.A. + Buscando a Nemo (Spain)"#, false),
+    "ad1c5818" => (0, false, r#"2 Games in 1 Double Pack: Monsters, Inc. + Finding Nemo (USA)"#, false),
+    "51d6ceb0" => (1, false, r#"2 Games in 1: Moto GP + GT Advance 3 - Pro Concept Racing (Europe)"#, false),
+    "b68b84c1" => (1, false, r#"Pac-Man World & Ms. Pac-Man - Maze Madness (Europe)"#, false),
+    "851127d4" => (1, false, r#"2 Great Games!: Pac-Man World + Ms. Pac-Man - Maze Madness (USA)"#, false),
+    "965391ea" => (1, false, r#"Pferd & Pony 2 in 1: Pferd & Pony - Mein Pferdehof + Pferd & Pony -
+			Lass Uns Reiten 2 (Germany)"#, false),
+    "1b6956d0" => (0, false, r#"2 Games in 1: Power Rangers - Ninja Storm + Power Rangers - Time Force (Europe)"#, false),
+    "14aa2642" => (0, false, r#"2 Games in 1: Power Rangers - Ninja Storm + Power Rangers - La Force du Temps
+			(France)"#, false),
+    "a5e872d7" => (0, false, r#"2 Games in 1: Power Rangers - Ninja Storm + Power Rangers - Time Force
+			(Germany)"#, false),
+    "994e7629" => (0, false, r#"2 Games in 1 Double Pack: Power Rangers - Ninja Storm + Power Rangers - Time
+			Force (USA)"#, false),
+    "2a3e1a8d" => (1, false, r#"Prince of Persia - The Sands of Time & Lara Croft Tomb Raider - The
+			Prophecy (Europe)"#, false),
+    "0db22e8c" => (0, false, r#"Double Game!: Quad Desert Fury + Monster Trucks (Europe)"#, false),
+    "f9ec06a9" => (0, false, r#"2 Games in 1: Quad Desert Fury + Monster Trucks (USA)"#, false),
+    "e5ac73bb" => (0, false, r#"2 Games in 1: Rugrats - Go Wild + SpongeBob SquarePants - SuperSponge (Europe)"#, false),
+    "b3bb9a45" => (0, false, r#"2 Games in 1: Les Razmoket Rencontrent les Delajungle + SpongeBob SquarePants -
+			SuperSponge (France)"#, false),
+    "bfcd892e" => (1, false, r#"2 Games in 1: Scooby-Doo + Scooby-Doo 2 - Monsters Unleashed (Europe)"#, false),
+    "d21582d0" => (1, false, r#"2 Games in 1: Scooby-Doo + Scooby-Doo 2 - Desatado (Spain)"#, false),
+    "802c9234" => (1, false, r#"2 Games in 1: Scooby-Doo + Scooby-Doo 2 - Les Monstres Se Dechainent (France)"#, false),
+    "1f023dbe" => (1, false, r#"2 Games in 1: Scooby-Doo + Scooby-Doo 2 - Monsters Unleashed (USA)"#, false),
+    "99566698" => (1, false, r#"2 Games in 1 Double Pack: Scooby-Doo! - Mystery Mayhem + Scooby-Doo and the
+			Cyber Chase (Europe)"#, false),
+    "5318df13" => (1, false, r#"2 Games in 1 Double Pack: Scooby-Doo! - Mystery Mayhem + Scooby-Doo and the
+			Cyber Chase (USA)"#, false),
+    "63bb643f" => (1, false, r#"2-in-1 Fun Pack: Shrek 2 + Madagascar (Europe)"#, false),
+    "39d68733" => (1, false, r#"2-in-1 Fun Pack: Shrek 2 + Madagascar (USA)"#, false),
+    "3535b2bf" => (1, false, r#"2-in-1 Fun Pack: Shrek 2 + Madagascar - Operation Penguin (Europe)"#, false),
+    "18670538" => (1, false, r#"2-in-1 Fun Pack: Shrek 2 + Madagascar - Operation Penguin (USA)"#, false),
+    "1802c624" => (1, false, r#"2 in 1 Game Pack: Shrek 2 + Shark Tale (Europe)"#, false),
+    "5cb036c7" => (1, false, r#"2 in 1 Game Pack: Shrek 2 + Shark Tale (USA)"#, false),
+    "22489c7c" => (4, false, r#"2 Games in 1: Sonic Advance + ChuChu Rocket! (Europe)"#, false),
+    "21ef5165" => (4, false, r#"Double Pack: Sonic Advance + ChuChu Rocket! (Japan)"#, false),
+    "94cf5b2b" => (4, false, r#"2 Games in 1: Sonic Advance + Sonic Battle (Europe)"#, false),
+    "31d712d3" => (4, false, r#"Double Pack: Sonic Advance + Sonic Battle (Japan)"#, false),
+    "1127dc09" => (4, false, r#"2 Games in 1: Sonic Advance + Sonic Pinball Party (Europe)"#, false),
+    "0dfaa3f2" => (4, false, r#"Double Pack: Sonic Battle + Sonic Pinball Party (Japan)"#, false),
+    "fbafb638" => (4, false, r#"Combo Pack - Sonic Advance + Sonic Pinball Party (USA)"#, false),
+    "2c57e588" => (4, false, r#"2 Games in 1: Sonic Battle + ChuChu Rocket! (Europe)"#, false),
+    "a50de233" => (4, false, r#"2 Games in 1: Sonic Battle + Sonic Pinball Party (Europe)"#, false),
+    "8577a000" => (4, false, r#"2 Games in 1: Sonic Pinball Party + Columns Crown (Europe)"#, false),
+    "d732e52d" => (1, false, r#"2 in 1 Game Pack: Spider-Man & Spider-Man 2 (Europe)"#, false),
+    "0ae3637f" => (1, false, r#"2 in 1 Game Pack: Spider-Man + Spider-Man 2 (USA)"#, false),
+    "e7baa5b9" => (1, false, r#"2 in 1 Game Pack: Spider-Man - Mysterio's Menace + X2 - Wolverine's Revenge
+			(Europe, USA)"#, false),
+    "4ba7f7cb" => (0, false, r#"2 Games in 1: SpongeBob SquarePants - Battle for Bikini Bottom + Jimmy Neutron
+			Boy Genius (Europe)"#, false),
+    "5802cafd" => (1, false, r#"2 Games in 1: SpongeBob SquarePants - Battle for Bikini Bottom + Nicktoons -
+			Freeze Frame Frenzy (USA)"#, false),
+    "82c21322" => (0, false, r#"2 Games in 1 Double Pack: SpongeBob SquarePants - Battle for Bikini Bottom +
+			The Fairly OddParents! - Breakin' da Rules (USA)"#, false),
+    "111d6997" => (0, false, r#"2 Games in 1: SpongeBob SquarePants - Revenge of the Flying Dutchman +
+			SpongeBob SquarePants - SuperSponge (Europe)"#, false),
+    "d515c0e4" => (0, false, r#"2 Games in 1 Double Pack: SpongeBob SquarePants - Revenge of the Flying
+			Dutchman + SpongeBob SquarePants - SuperSponge (USA)"#, false),
+    "71d52c61" => (0, false, r#"2 Games in 1: SpongeBob SquarePants - SuperSponge & Battle for Bikini
+			Bottom (Europe)"#, false),
+    "06f0f43c" => (0, false, r#"2 Games in 1: SpongeBob SquarePants - SuperSponge & Battle for Bikini
+			Bottom (Europe, alt)"#, false),
+    "a04cebfe" => (1, false, r#"2 Games in 1: The SpongeBob SquarePants Movie + SpongeBob SquarePants and
+			Friends in Freeze Frame Frenzy (Europe)"#, false),
+    "d8382427" => (2, false, r#"Spyro - Season of Ice + Crash Bandicoot 2 - N-Tranced (Europe)"#, false),
+    "7b3c4f90" => (2, false, r#"Spyro 2 - Season of Flame + Crash Nitro Kart (Europe)"#, false),
+    "0510b70f" => (2, false, r#"Spyro - Fusion + Crash Bandicoot - Fusion (Europe)"#, false),
+    "cdbe00e3" => (2, false, r#"Spyro - Season of Ice + Crash Bandicoot - The Huge Adventure (USA)"#, false),
+    "5940906e" => (2, false, r#"Spyro Orange - The Cortex Conspiracy + Crash Bandicoot Purple - Ripto's Rampage
+			(USA)"#, false),
+    "7ef359ed" => (2, false, r#"Spyro Superpack: Spyro - Season of Ice + Spyro - Season of Flame (USA)"#, false),
+    "e902880f" => (2, false, r#"Teenage Mutant Ninja Turtles Double Pack (Europe)"#, false),
+    "176a42e5" => (2, false, r#"Teenage Mutant Ninja Turtles Double Pack (USA)"#, false),
+    "0a5965f4" => (0, false, r#"2 Jeux en 1: Titeuf - Ze Gagmachine + Titeuf Méga Compet (France)"#, false),
+    "6d8ef48a" => (2, false, r#"2 in 1 Game Pack: Tony Hawk's Underground + Kelly Slater's Pro Surfer (Europe,
+			USA)"#, false),
+    "5813810f" => (2, false, r#"2 in 1: V-Rally 3 + Stuntman (Europe)"#, false),
+    "c2fc12e5" => (1, false, r#"Winnie the Pooh's Rumbly Tumbly Adventure & Rayman 3 (Europe)"#, false),
+    "7ea28a99" => (3, false, r#"Yu-Gi-Oh! Double Pack (Europe)"#, false),
+    "f3df5758" => (3, false, r#"Yu-Gi-Oh! Double Pack (USA)"#, false),
+    "2cb399d0" => (3, false, r#"Yu-Gi-Oh! Double Pack 2 (USA)"#, false),
+    "164a75ac" => (0, false, r#"Ignition Collection: Volume 1- 3 Games in 1: Animal Snap + World Tennis Stars +
+			Super Dropzone (Europe)"#, false),
+    "6721794b" => (1, false, r#"3 Games in 1: Rugrats - I Gotta Go Party + SpongeBob SquarePants - SuperSponge
+			+ Tak and the Power of Juju (Europe)"#, false),
+    "cac0c0d8" => (3, false, r#"4 Games on One Game Pak (Nicktoons) (USA)"#, false),
+    "04a40017" => (3, false, r#"4 Games on One Game Pak (Racing) (USA)"#, false),
+    "09f5e672" => (1, false, r#"Twin Series 1 - Mezase Debut! - Fashion Designer Monogatari + Kawaii Pet Game
+			Gallery 2 (Japan)"#, false),
+    "8b0eb948" => (1, false, r#"Twin Series 2 - Oshare Princess 4 + Renai Uranai Daisakusen! + Renai Party Game
+			- Sweet Heart (Japan)"#, false),
+    "68237519" => (1, false, r#"Twin Series 3 - Konchuu Monster - Ouja Ketteisen + Super Chinese Labyrinth
+			(Japan)"#, false),
+    "fa643377" => (2, false, r#"Twin Series 4 - Hamu Hamu Monster EX - Hamster Monogatari RPG + Fantasy Puzzle
+			- Hamster Monogatari - Mahou no Meikyuu 1.2.3 (Japan)"#, false),
+    "2c582ff3" => (2, false, r#"Twin Series 5 - Mahou no Kuni no Cake-ya-san Monogatari + Wanwan Meitantei EX
+			(Japan)"#, false),
+    "9370623d" => (1, false, r#"Twin Series 6 - Wannyan Idol Gakuen + Koinu to Issho Special (Japan)"#, false),
+    "3c91b0a0" => (1, false, r#"Twin Series 7 - Twin Puzzle - Kisekae Wanko EX + Nyaa to Chuu no Rainbow Magic
+			2 (Japan)"#, false),
+    "a4ef4e95" => (5, false, r#"Card e-Reader (Japan)"#, false),
+    "4139e7c3" => (5, false, r#"Card e-Reader+ (Japan)"#, false),
+    "8b27cd67" => (5, false, r#"e-Reader (USA)"#, false),
+    "424f71e5" => (0, false, r#"GBA Personal Organizer (USA)"#, false),
+    "a1b64f16" => (0, false, r#"GBA AV Adapter (China)"#, false),
+    "c879b340" => (0, false, r#"GBA Movie Player 2 CF (Spain)"#, false),
+    "af124f8c" => (0, false, r#"GBA TV Tuner PAL (China, v1.3)"#, false),
+    "b511df64" => (0, false, r#"GBA TV Tuner PAL (China, v2.0)"#, false),
+    "b8fda4f5" => (2, false, r#"The Holy Bible - World English Bible (USA, prototype)"#, false),
+    "867bec76" => (0, false, r#"Nintendo MP3 Player (Europe)"#, false),
+    "ba997cfa" => (3, false, r#"Play-Yan (Japan)"#, false),
+    "e24f6a15" => (3, false, r#"Play-Yan Micro (Japan)"#, false),
+    "4304bd2d" => (0, false, r#"Action Replay (Europe, v3.3)"#, false),
+    "45bb6f4e" => (0, false, r#"Action Replay GBX (Europe, alt)"#, false),
+    "18ce5322" => (0, false, r#"Action Replay GBX (Europe)"#, false),
+    "3db7a213" => (0, false, r#"Action Replay GBX (Europe, v3.1)"#, false),
+    "5ad72359" => (0, false, r#"Action Replay GBX (Europe, alt 2)"#, false),
+    "3fc75439" => (0, false, r#"Action Replay MAX (Europe)"#, false),
+    "f5b2f65e" => (0, false, r#"CodeBreaker (USA)"#, false),
+    "9ad94c62" => (0, false, r#"GameShark GBA (USA, alt)"#, false),
+    "d71dbca6" => (0, false, r#"GameShark GBA (USA)"#, false),
+    "9c249666" => (0, false, r#"Pro Action Replay (Japan)"#, false),
+    "4f3e7427" => (0, false, r#"Xploder Advance (Europe, alt)"#, false),
+    "ad71f6ff" => (0, false, r#"Xploder Advance (Europe)"#, false),
+    "d48d5587" => (0, false, r#"X-Terminator Advance (Japan)"#, false),
+    "6b3961fb" => (0, false, r#"Dog Trainer (Europe)"#, false),
+    "b2100fed" => (0, false, r#"Dog Trainer 2 (Europe)"#, false),
+    "d72c620f" => (0, false, r#"Rayman IV (Rayman - Sunshine of Trip) (unlicensed) (protection hacked)"#, false),
+    "8ee6b303" => (0, false, r#"Rayman IV (Rayman - Puteshestviye Solnechnogo Sveta) (unlicensed) (Russian)
+			(protection hacked)"#, false),
+    "d05c8414" => (0, false, r#"Leiman IV - Guangming Zhi Lv (unlicensed) (Chinese) (protection hacked)"#, false),
+    "9255c170" => (0, false, r#"Super Mario DX (unlicensed) (protection hacked)"#, false),
+    "2c7af7e8" => (0, false, r#"Chaoji Maliou DX (unlicensed) (Chinese) (protection hacked)"#, false),
+    "4b67247c" => (0, false, r#"Crash Advance IV (unlicensed) (protection hacked)"#, false),
+    "51ef4700" => (0, false, r#"Digimon - Ruby and Sapphire (unlicensed) (protection hacked)"#, false),
+    "86a602ab" => (0, false, r#"Pokemon - Sapphire Version (unlicensed) (protection hacked)"#, false),
+    "8e0a9112" => (0, false, r#"Sonic 3 - Fighter Sonic (unlicensed) (protection hacked)"#, false),
+    "8ee6d3d5" => (0, false, r#"Teenage Mutant Ninja Turtles 2 (unlicensed) (protection hacked)"#, false),
+    "09c7c895" => (0, false, r#"The Lord of the Rings IV - The Fellowship of the Ring (unlicensed) (protection
+			hacked)"#, false),
+    "4683dbb5" => (0, false, r#"Ultraman - Confrontation Between Justice And Evil (Ul'traman - Konfrontatsiya
+			mezhdu spravedlivost'yu i zlom) (unlicensed) (Russian) (protection hacked)"#, false),
+    "e14c9b78" => (0, false, r#"X-Man - Armour of Might (X-men) (unlicensed) (Russian) (protection hacked)"#, false),
+
+};
+
+// game code => (backup type, has RTC, flash vendor, "|" separated quirks, title)
+pub static GAME_CODE_DATABASE: phf::Map<&'static str, (u8, bool, &'static str, &'static str, &'static str)> = phf_map! {
+    "BJBE" => (1, false, "", "", r#"007 - Everything or Nothing (Europe, USA)"#),
+    "BJBP" => (1, false, "", "", r#"007 - Everything or Nothing (Europe, USA)"#),
+    "BJBJ" => (1, false, "", "", r#"007 - Everything or Nothing (Japan)"#),
+    "A7OE" => (1, false, "", "", r#"007 - NightFire (Europe, USA)"#),
+    "A7OP" => (1, false, "", "", r#"007 - NightFire (Europe, USA)"#),
+    "B6WE" => (1, false, "", "", r#"2006 FIFA World Cup - Germany 2006 (Europe, USA)"#),
+    "B6WP" => (1, false, "", "", r#"2006 FIFA World Cup - Germany 2006 (Europe, USA)"#),
+    "B2YE" => (0, false, "", "", r#"2K Sports - Major League Baseball 2K7 (USA)"#),
+    "BZPP" => (1, false, "", "", r#"2 Games in 1: Dr. Mario & Puzzle League (Europe)"#),
+    "BZPE" => (1, false, "", "", r#"2 Games in One! - Dr. Mario + Puzzle League (USA, Australia)"#),
+    "BZPJ" => (1, false, "", "", r#"Dr. Mario & Panel de Pon (Japan)"#),
+    "B69P" => (0, false, "", "", r#"2 Games in One!: Gauntlet + Rampart (Europe)"#),
+    "B69E" => (0, false, "", "", r#"2 Games in One!: Gauntlet + Rampart (USA)"#),
+    "B68P" => (0, false, "", "", r#"2 Games in One!: Marble Madness + Klax (Europe)"#),
+    "B68E" => (0, false, "", "", r#"2 Games in One!: Marble Madness + Klax (USA)"#),
+    "B6BP" => (0, false, "", "", r#"2 Games in One!: Paperboy + Rampage (Europe)"#),
+    "B6BE" => (0, false, "", "", r#"2 Games in One!: Paperboy + Rampage (USA)"#),
+    "B6AP" => (0, false, "", "", r#"2 Games in One!: Spy Hunter + Super Sprint (Europe)"#),
+    "B6AE" => (0, false, "", "", r#"2 Games in One!: Spy Hunter + Super Sprint (USA)"#),
+    "BC4E" => (0, false, "", "", r#"3 Game Pack!: Candy Land - Chutes and Ladders - Original Memory Game (USA)"#),
+    "B3UE" => (0, false, "", "", r#"3 Game Pack!: The Game of Life - Payday - Yahtzee (USA)"#),
+    "B3OE" => (0, false, "", "", r#"3 Game Pack!: Mouse Trap - Simon - Operation (USA)"#),
+    "BXCE" => (0, false, "", "", r#"3 Game Pack!: Ker Plunk! - Toss Across - Tip It (USA)"#),
+    "B6ZP" => (0, false, "", "", r#"3 Games in One!: Breakout - Centipede - Warlords (Europe)"#),
+    "B6ZE" => (0, false, "", "", r#"3 Games in One!: Breakout - Centipede - Warlords (USA)"#),
+    "B62E" => (0, false, "", "", r#"3 Games in One!: Super Breakout - Millipede - Lunar Lander (USA)"#),
+    "B64P" => (0, false, "", "", r#"3 Games in One!: Yars' Revenge - Asteroids - Pong (Europe)"#),
+    "B64E" => (0, false, "", "", r#"3 Games in One!: Yars' Revenge - Asteroids - Pong (USA)"#),
+    "BAEE" => (0, false, "", "", r#"Ace Combat Advance (Europe, USA)"#),
+    "BAEP" => (0, false, "", "", r#"Ace Combat Advance (Europe, USA)"#),
+    "ALXP" => (1, false, "", "", r#"Ace Lightning (Europe)"#),
+    "AAOJ" => (1, false, "", "", r#"Acrobat Kid (Japan)"#),
+    "BACP" => (0, false, "", "", r#"Action Man - Robot Atak (Europe)"#),
+    "BAVE" => (1, false, "", "", r#"Activision Anthology (USA)"#),
+    "A2GJ" => (2, false, "", "", r#"Advance GT2 (Japan)"#),
+    "AG7J" => (3, false, "", "", r#"Advance GTA (Japan)"#),
+    "BGCP" => (1, false, "", "", r#"Advance Guardian Heroes (Europe)"#),
+    "BAGJ" => (1, false, "", "", r#"Advance Guardian Heroes (Japan)"#),
+    "BGCE" => (1, false, "", "", r#"Advance Guardian Heroes (USA)"#),
+    "AR7J" => (3, false, "", "", r#"Advance Rally (Japan)"#),
+    "AWRP" => (5, false, "sst", "", r#"Advance Wars (Europe)"#),
+    "AWRE" => (5, false, "sst", "", r#"Advance Wars (USA, rev. 1)"#),
+    "AW2P" => (5, false, "sst", "", r#"Advance Wars 2 - Black Hole Rising (Europe)"#),
+    "AW2E" => (5, false, "", "", r#"Advance Wars 2 - Black Hole Rising (USA, Australia)"#),
+    "ADEJ" => (3, false, "", "", r#"Adventure of Tokyo Disney Sea (Japan)"#),
+    "AJXE" => (0, false, "", "", r#"The Adventures of Jimmy Neutron vs Jimmy Negatron (Europe, USA)"#),
+    "AJXP" => (0, false, "", "", r#"The Adventures of Jimmy Neutron vs Jimmy Negatron (Europe, USA)"#),
+    "BJYE" => (1, false, "", "", r#"The Adventures of Jimmy Neutron Boy Genius - Attack of the Twonkies (Europe, USA)"#),
+    "BJYP" => (1, false, "", "", r#"The Adventures of Jimmy Neutron Boy Genius - Attack of the Twonkies (Europe, USA)"#),
+    "BJNE" => (0, false, "", "", r#"The Adventures of Jimmy Neutron Boy Genius - Jet Fusion (Europe, USA)"#),
+    "BJNP" => (0, false, "", "", r#"The Adventures of Jimmy Neutron Boy Genius - Jet Fusion (Europe, USA)"#),
+    "AAOP" => (1, false, "", "", r#"Aero the Acro-Bat - Rascal Rival Revenge (Europe)"#),
+    "AAOE" => (1, false, "", "", r#"Aero the Acro-Bat - Rascal Rival Revenge (USA)"#),
+    "ACEP" => (0, false, "", "", r#"Agassi Tennis Generation (Europe)"#),
+    "ACEE" => (0, false, "", "", r#"Agassi Tennis Generation (USA)"#),
+    "BHQP" => (0, false, "", "", r#"Agent Hugo - Roborumble (Europe)"#),
+    "TCHK" => (1, false, "", "", r#"AGB Aging Cartridge (World, version 1.0)"#),
+    "AILP" => (0, false, "", "", r#"Aggressive Inline (Europe)"#),
+    "AILE" => (0, false, "", "", r#"Aggressive Inline (USA)"#),
+    "ANPF" => (3, false, "", "", r#"L'Aigle de Guerre (France)"#),
+    "AAKJ" => (1, false, "", "", r#"AirForce Delta II (Japan)"#),
+    "AAKE" => (1, false, "", "", r#"AirForce Delta Storm (USA)"#),
+    "BAZJ" => (2, false, "", "", r#"Aka-chan Doubutsuen (Japan)"#),
+    "AAMJ" => (3, false, "", "", r#"Akumajou Dracula - Circle of the Moon (Japan)"#),
+    "BADP" => (1, false, "", "", r#"Disney's Aladdin (Europe)"#),
+    "AJ6J" => (1, false, "", "", r#"Disney's Aladdin (Japan)"#),
+    "BADE" => (1, false, "", "", r#"Disney's Aladdin (USA)"#),
+    "BABJ" => (1, false, "", "", r#"Aleck Bordon Adventure - Tower & Shaft Advance (Japan)"#),
+    "BAWX" => (1, false, "", "", r#"Alex Rider - Stormbreaker (Europe)"#),
+    "BAWE" => (1, false, "", "", r#"Alex Rider - Stormbreaker (USA)"#),
+    "BAHP" => (1, false, "", "", r#"Alien Hominid (Europe)"#),
+    "AEVE" => (0, false, "", "", r#"Alienators - Evolution Continues (Europe, USA)"#),
+    "AEVP" => (0, false, "", "", r#"Alienators - Evolution Continues (Europe, USA)"#),
+    "BALE" => (1, false, "", "", r#"All Grown Up! - Express Yourself (Europe, USA)"#),
+    "BALP" => (1, false, "", "", r#"All Grown Up! - Express Yourself (Europe, USA)"#),
+    "AA3E" => (3, false, "", "", r#"All-Star Baseball 2003 - Featuring Derek Jeter (USA)"#),
+    "AA7E" => (3, false, "", "", r#"All-Star Baseball 2004 - Featuring Derek Jeter (USA)"#),
+    "AARP" => (1, false, "", "", r#"Altered Beast - Guardian of the Realms (Europe)"#),
+    "AARE" => (1, false, "", "", r#"Altered Beast - Guardian of the Realms (USA)"#),
+    "AABE" => (2, false, "", "", r#"American Bass Challenge (USA)"#),
+    "BAPE" => (1, false, "", "", r#"Disney's American Dragon Jake Long - Rise of the Huntsclan! (Europe, USA)"#),
+    "BAPP" => (1, false, "", "", r#"Disney's American Dragon Jake Long - Rise of the Huntsclan! (Europe, USA)"#),
+    "BIDE" => (0, false, "", "", r#"American Idol (USA)"#),
+    "AFGP" => (0, false, "", "", r#"An American Tail - Fievel's Gold Rush (Europe)"#),
+    "AFGE" => (0, false, "", "", r#"An American Tail - Fievel's Gold Rush (USA)"#),
+    "AFNJ" => (2, false, "", "", r#"Angel Collection - Mezase! Gakuen no Fashion Leader (Japan)"#),
+    "BECJ" => (2, false, "", "", r#"Angel Collection 2 - Pichimo ni Narou (Japan)"#),
+    "AAGJ" => (2, false, "", "", r#"Angelique (Japan)"#),
+    "AANJ" => (3, false, "", "", r#"Animal Mania - Dokidoki Aishou Check (Japan)"#),
+    "AAQP" => (0, false, "", "", r#"Animal Snap - Rescue Them 2 by 2 (Europe)"#),
+    "AAQE" => (0, false, "", "", r#"Animal Snap - Rescue Them 2 by 2 (USA)"#),
+    "BAYJ" => (3, false, "", "", r#"Animal Yokochou - Doki Doki Kyuushutsu Daisakusen! no Maki (Japan)"#),
+    "BAXJ" => (3, false, "", "", r#"Animal Yokochou - Doki Doki Shinkyuu Shiken! no Maki (Japan)"#),
+    "BUYP" => (1, false, "", "", r#"The Ant Bully (Europe)"#),
+    "BUYE" => (1, false, "", "", r#"The Ant Bully (USA)"#),
+    "ANZP" => (0, false, "", "", r#"Antz - Extreme Racing (Europe)"#),
+    "ANUE" => (0, false, "", "", r#"Antz - Extreme Racing (USA)"#),
+    "AAZJ" => (1, false, "", "", r#"Ao-Zora to Nakama-tachi - Yume no Bouken (Japan)"#),
+    "BPLE" => (0, false, "", "", r#"Archer Maclean Presents Pool (USA)"#),
+    "BB5E" => (0, false, "", "", r#"Arctic Tale (USA)"#),
+    "AMEE" => (0, false, "", "", r#"Army Men - Operation Green (USA)"#),
+    "AY3E" => (1, false, "", "", r#"Army Men - Turf Wars (USA)"#),
+    "ASAE" => (0, false, "", "", r#"Army Men Advance (Europe, USA)"#),
+    "ASAP" => (0, false, "", "", r#"Army Men Advance (Europe, USA)"#),
+    "B8DP" => (0, false, "", "", r#"Around the World in 80 Days (Europe)"#),
+    "B8DE" => (0, false, "", "", r#"Around the World in 80 Days (USA)"#),
+    "B2NE" => (1, false, "", "", r#"Arthur and the Invisibles (USA)"#),
+    "B2NP" => (1, false, "", "", r#"Arthur and the Minimoys (Europe)"#),
+    "BAMJ" => (3, false, "", "", r#"Ashita no Joe - Makka ni Moeagare! (Japan)"#),
+    "AOBP" => (0, false, "", "", r#"Astérix & Obélix - Bash Them All! (Europe)"#),
+    "BLXP" => (1, false, "", "", r#"Astérix & Obélix XXL (Europe)"#),
+    "BTAE" => (2, false, "", "", r#"Astro Boy - Omega Factor (USA)"#),
+    "BTAJ" => (2, false, "", "", r#"Astro Boy - Tetsuwan Atom - Atom Heart no Himitsu (Japan)"#),
+    "AAVP" => (0, false, "", "", r#"Atari Anniversary Advance (Europe)"#),
+    "AAVE" => (0, false, "", "", r#"Atari Anniversary Advance (USA)"#),
+    "ATLE" => (0, false, "", "", r#"Disney's Atlantis - The Lost Empire (Europe, USA)"#),
+    "ATLP" => (0, false, "", "", r#"Disney's Atlantis - The Lost Empire (Europe, USA)"#),
+    "BETE" => (0, false, "", "", r#"Atomic Betty (Europe, USA)"#),
+    "BETP" => (0, false, "", "", r#"Atomic Betty (Europe, USA)"#),
+    "AQRE" => (0, false, "", "", r#"ATV - Quad Power Racing (Europe, USA)"#),
+    "AQRP" => (0, false, "", "", r#"ATV - Quad Power Racing (Europe, USA)"#),
+    "B3BE" => (0, false, "", "", r#"ATV - Thunder Ridge Riders (USA)"#),
+    "BQZE" => (2, false, "", "", r#"Avatar - The Last Airbender (USA)"#),
+    "BBWE" => (0, false, "", "", r#"Avatar - The Last Airbender - The Burning Earth (USA)"#),
+    "BQZP" => (2, false, "", "", r#"Avatar - The Legend of Aang (Europe)"#),
+    "AZAJ" => (2, false, "", "", r#"Azumanga Daiou Advance (Japan)"#),
+    "BBMJ" => (2, false, "", "", r#"B-Densetsu! Battle B-Daman - Fire Spirits! (Japan)"#),
+    "BDXJ" => (2, false, "", "", r#"B-Densetsu! Battle B-Daman - Moero! B-Damashii!! (Japan)"#),
+    "BBVP" => (1, false, "", "", r#"Babar to the Rescue (Europe)"#),
+    "BBVE" => (1, false, "", "", r#"Babar to the Rescue (USA)"#),
+    "BBCP" => (0, false, "", "", r#"Back to Stone (Europe)"#),
+    "BBCE" => (0, false, "", "", r#"Back to Stone (USA)"#),
+    "ABKE" => (1, false, "", "", r#"Back Track (Europe, USA)"#),
+    "ABKP" => (1, false, "", "", r#"Back Track (Europe, USA)"#),
+    "ACKE" => (1, false, "", "", r#"Backyard Baseball (USA)"#),
+    "BCYE" => (1, false, "", "", r#"Backyard Baseball 2006 - Featuring Pros as Kids (USA)"#),
+    "BYBE" => (1, false, "", "", r#"Backyard Basketball (USA)"#),
+    "AYFE" => (1, false, "", "", r#"Backyard Football (USA)"#),
+    "BYFE" => (1, false, "", "", r#"Backyard Football 2006 (USA)"#),
+    "BYHE" => (1, false, "", "", r#"Backyard Hockey (USA)"#),
+    "BS6E" => (1, false, "", "", r#"Backyard Skateboarding (USA)"#),
+    "BC7E" => (1, false, "", "", r#"Backyard Sports - Baseball 2007 (USA)"#),
+    "BB7E" => (1, false, "", "", r#"Backyard Sports - Basketball 2007 (USA)"#),
+    "BF7E" => (1, false, "", "", r#"Backyard Sports - Football 2007 (USA)"#),
+    "ADFJ" => (3, false, "", "", r#"Bakunetsu Dodge Ball Fighters (Japan)"#),
+    "AHEJ" => (3, false, "", "", r#"Bakuten Shoot Beyblade - Gekitou! Saikyou Blader (Japan)"#),
+    "A3EJ" => (3, false, "", "", r#"Bakuten Shoot Beyblade 2002 - Gekisen! Team Battle!! Kouryuu no Shou - Daichi Hen (Japan)"#),
+    "A3WJ" => (3, false, "", "", r#"Bakuten Shoot Beyblade 2002 - Gekisen! Team Battle!! Seiryuu no Shou - Takao Hen (Japan)"#),
+    "AB8J" => (3, false, "", "", r#"Bakuten Shoot Beyblade 2002 - Ikuze! Bakutou! Chou Jiryoku Battle!! (Japan)"#),
+    "BGDP" => (1, false, "", "", r#"Baldur's Gate - Dark Alliance (Europe)"#),
+    "BGDE" => (1, false, "", "", r#"Baldur's Gate - Dark Alliance (USA)"#),
+    "BAJP" => (1, false, "", "", r#"Banjo-Pilot (Europe)"#),
+    "BAJE" => (1, false, "", "", r#"Banjo-Pilot (USA)"#),
+    "BKZX" => (2, false, "", "", r#"Banjo-Kazooie - Grunty's Revenge (Europe)"#),
+    "BKZE" => (2, false, "", "", r#"Banjo-Kazooie - Grunty's Revenge (Europe, USA)"#),
+    "BKZP" => (2, false, "", "", r#"Banjo-Kazooie - Grunty's Revenge (Europe, USA)"#),
+    "BAUP" => (0, false, "", "", r#"Barbie - The Princess and the Pauper (Europe)"#),
+    "BAUE" => (0, false, "", "", r#"Barbie - The Princess and the Pauper (USA)"#),
+    "BE5P" => (0, false, "", "", r#"Barbie and the Magic of Pegasus (Europe)"#),
+    "BE5E" => (0, false, "", "", r#"Barbie and the Magic of Pegasus (USA)"#),
+    "BBNE" => (1, false, "", "", r#"Barbie as the Island Princess (USA)"#),
+    "BBIP" => (1, false, "", "", r#"The Barbie Diaries - High School Mystery (Europe)"#),
+    "BBIE" => (1, false, "", "", r#"The Barbie Diaries - High School Mystery (USA)"#),
+    "AVBP" => (0, false, "", "", r#"Barbie - Groovy Games (Europe)"#),
+    "AVBE" => (0, false, "", "", r#"Barbie - Groovy Games (USA)"#),
+    "AI8P" => (0, false, "", "", r#"Barbie Horse Adventures (Europe)"#),
+    "AI8E" => (0, false, "", "", r#"Barbie Horse Adventures - Blue Ribbon Race (USA)"#),
+    "BB3P" => (0, false, "", "", r#"Barbie in the 12 Dancing Princesses (Europe)"#),
+    "BB3E" => (0, false, "", "", r#"Barbie in the 12 Dancing Princesses (USA)"#),
+    "BBYX" => (2, false, "", "", r#"Barnyard (Europe)"#),
+    "BBYE" => (2, false, "", "", r#"Barnyard (USA)"#),
+    "ABPE" => (3, false, "", "", r#"Baseball Advance (USA)"#),
+    "AZBJ" => (2, false, "", "", r#"Bass Tsuri Shiyouze! - Tournament wa Senryaku Da! (Japan)"#),
+    "BATE" => (0, false, "", "", r#"Batman - Rise of Sin Tzu (USA)"#),
+    "ABTP" => (0, false, "", "", r#"Batman - Vengeance (Europe)"#),
+    "ABTE" => (0, false, "", "", r#"Batman - Vengeance (USA)"#),
+    "BBGE" => (1, false, "", "", r#"Batman Begins (Europe, USA)"#),
+    "BBGP" => (1, false, "", "", r#"Batman Begins (Europe, USA)"#),
+    "BDXE" => (2, false, "", "", r#"Battle B-Daman (USA)"#),
+    "BBME" => (2, false, "", "", r#"Battle B-Daman - Fire Spirits! (USA)"#),
+    "AREJ" => (3, false, "", "", r#"Battle Network Rockman EXE (Japan)"#),
+    "AE2J" => (3, false, "", "", r#"Battle Network Rockman EXE 2 (Japan)"#),
+    "A6BJ" => (3, false, "", "", r#"Battle Network Rockman EXE 3 (Japan)"#),
+    "A3XJ" => (3, false, "", "", r#"Battle Network Rockman EXE 3 Black (Japan, rev. 1)"#),
+    "BBFJ" => (1, false, "", "", r#"Battle x Battle - Kyodai Gyo Densetsu (Japan)"#),
+    "ABEP" => (1, false, "", "", r#"BattleBots - Beyond the BattleBox (Europe)"#),
+    "ABEE" => (1, false, "", "", r#"BattleBots - Beyond the BattleBox (USA)"#),
+    "BBDE" => (0, false, "", "", r#"BattleBots - Design & Destroy (USA)"#),
+    "A8LJ" => (3, false, "", "", r#"BB Ball (Japan)"#),
+    "AH5J" => (3, false, "", "", r#"Beast Shooter - Mezase Beast King! (Japan)"#),
+    "BKFE" => (1, false, "", "", r#"The Bee Game (USA)"#),
+    "BBOE" => (0, false, "", "", r#"The Berenstain Bears - Spooky Old Tree (USA)"#),
+    "BHBP" => (2, false, "", "", r#"Best Friends - Hunde & Katzen (Germany)"#),
+    "A8YJ" => (5, false, "", "", r#"Best Play Pro Yakyuu Baseball (Japan)"#),
+    "BB2P" => (2, false, "", "", r#"Beyblade G-Revolution (Europe)"#),
+    "BB2E" => (2, false, "", "", r#"Beyblade G-Revolution (USA)"#),
+    "BEYP" => (2, false, "", "", r#"Beyblade V-Force - Ultimate Blader Jam (Europe)"#),
+    "BEYE" => (2, false, "", "", r#"Beyblade V-Force - Ultimate Blader Jam (USA)"#),
+    "BBXD" => (0, false, "", "", r#"Bibi Blocksberg - Der Magische Hexenkreis (Germany)"#),
+    "BUXD" => (1, false, "", "", r#"Bibi und Tina - Ferien auf dem Martinshof (Germany, rev. 1)"#),
+    "BIBE" => (0, false, "", "", r#"The Bible Game (USA)"#),
+    "B63P" => (1, false, "", "", r#"Big Mutha Truckers (Europe)"#),
+    "B63E" => (1, false, "", "", r#"Big Mutha Truckers (USA)"#),
+    "BIOP" => (0, false, "", "", r#"Bionicle (Europe)"#),
+    "BIOE" => (0, false, "", "", r#"Bionicle (USA)"#),
+    "A5AE" => (1, false, "", "", r#"Bionicle - Matoran Adventures (Europe, USA)"#),
+    "A5AP" => (1, false, "", "", r#"Bionicle - Matoran Adventures (Europe, USA)"#),
+    "BILP" => (1, false, "", "", r#"Bionicle - Maze of Shadows (Europe, rev. 1)"#),
+    "BILE" => (1, false, "", "", r#"Bionicle - Maze of Shadows (USA)"#),
+    "BIHP" => (1, false, "", "", r#"Bionicle Heroes (Europe)"#),
+    "BIHE" => (1, false, "", "", r#"Bionicle Heroes (USA)"#),
+    "BVDJ" => (3, false, "", "", r#"bit Generations - Boundish (Japan)"#),
+    "BVAJ" => (3, false, "", "", r#"bit Generations - Coloris (Japan)"#),
+    "BVBJ" => (3, false, "", "", r#"bit Generations - Dialhex (Japan)"#),
+    "BVHJ" => (3, false, "", "", r#"bit Generations - Digidrive (Japan)"#),
+    "BVCJ" => (3, false, "", "", r#"bit Generations - Dotstream (Japan)"#),
+    "BVEJ" => (3, false, "", "", r#"bit Generations - Orbital (Japan)"#),
+    "BVGJ" => (3, false, "", "", r#"bit Generations - Soundvoyager (Japan)"#),
+    "AB6P" => (1, false, "", "", r#"Black Belt Challenge (Europe)"#),
+    "AWEJ" => (3, false, "", "", r#"Black Black - Bura Bura (Japan)"#),
+    "AXBJ" => (2, false, "", "", r#"Black Matrix Zero (Japan)"#),
+    "AQXP" => (1, false, "", "", r#"Blackthorne (Europe)"#),
+    "AQXE" => (1, false, "", "", r#"Blackthorne (USA)"#),
+    "BBHE" => (0, false, "", "", r#"Blades of Thunder (USA)"#),
+    "BLEJ" => (4, false, "", "", r#"Bleach Advance - Kurenai ni Somaru Soul Society (Japan)"#),
+    "ABRE" => (3, false, "", "", r#"Blender Bros. (USA)"#),
+    "AT9E" => (1, false, "", "", r#"BMX Trick Racer (USA)"#),
+    "B6EP" => (0, false, "", "", r#"Board Game Classics (Europe)"#),
+    "B6EE" => (0, false, "", "", r#"Board Game Classics (USA)"#),
+    "BB9J" => (3, false, "", "", r#"Boboboubo Boubobo - 9 Kyoku Senshi Gag Yuugou (Japan)"#),
+    "BOSJ" => (3, false, "", "", r#"Boboboubo Boubobo - Bakutou Hajike Taisen (Japan)"#),
+    "BOBJ" => (3, false, "", "", r#"Boboboubo Boubobo - Maji de!!? Shinken Battle (Japan)"#),
+    "A8VJ" => (3, false, "", "", r#"Boboboubo Boubobo - Ougi 87.5 Bakuretsu Hanage Shinken (Japan)"#),
+    "U3IP" => (2, true, "", "solar_sensor", r#"Boktai - The Sun Is in Your Hand (Europe)"#),
+    "U3IE" => (2, true, "", "solar_sensor", r#"Boktai - The Sun Is in Your Hand (USA)"#),
+    "U32P" => (2, true, "", "solar_sensor", r#"Boktai 2 - Solar Boy Django (Europe)"#),
+    "U32E" => (2, true, "", "solar_sensor", r#"Boktai 2 - Solar Boy Django (USA)"#),
+    "ABCJ" => (3, false, "", "", r#"Boku wa Koukuu Kanseikan (Japan, rev. 1)"#),
+    "A4NJ" => (3, false, "", "", r#"Bokujou Monogatari - Mineral Town no Nakama-tachi (Japan)"#),
+    "BFGJ" => (3, false, "", "", r#"Bokujou Monogatari - Mineral Town no Nakama-tachi for Girl (Japan)"#),
+    "U3IJ" => (2, true, "", "solar_sensor", r#"Bokura no Taiyou - Taiyou Action RPG (Japan)"#),
+    "AJZJ" => (3, false, "", "", r#"Bomberman Jetters - Densetsu no Bomberman (Japan)"#),
+    "BOMJ" => (1, false, "", "", r#"Bomberman Jetters - Game Collection (Japan)"#),
+    "AMHP" => (2, false, "", "", r#"Bomberman Max 2 - Blue Advance (Europe)"#),
+    "AMHE" => (2, false, "", "", r#"Bomberman Max 2 - Blue Advance (USA)"#),
+    "AMHJ" => (2, false, "", "", r#"Bomberman Max 2 - Bomberman Version (Japan)"#),
+    "AMYJ" => (2, false, "", "", r#"Bomberman Max 2 - Max Version (Japan)"#),
+    "AMYP" => (2, false, "", "", r#"Bomberman Max 2 - Red Advance (Europe)"#),
+    "AMVE" => (2, false, "", "", r#"Bomberman Max 2 - Red Advance (USA)"#),
+    "ABSJ" => (3, false, "", "", r#"Bomberman Story (Japan)"#),
+    "ABSE" => (3, false, "", "", r#"Bomberman Tournament (Europe, USA)"#),
+    "ABSP" => (3, false, "", "", r#"Bomberman Tournament (Europe, USA)"#),
+    "BKWE" => (1, false, "", "", r#"Bookworm (USA)"#),
+    "APJJ" => (3, false, "", "", r#"Bouken Yuuki Pluster World - Densetsu no Plust Gate (Japan)"#),
+    "BPDJ" => (3, false, "", "", r#"Bouken Yuuki Pluster World - Densetsu no Plust Gate EX (Japan)"#),
+    "A2PJ" => (3, false, "", "", r#"Bouken Yuuki Pluster World - Pluston GP (Japan)"#),
+    "BOVJ" => (2, false, "", "", r#"Bouken-ou Beet - Busters Road (Japan)"#),
+    "BBSJ" => (1, false, "", "", r#"Boukyaku no Senritsu - The Melody of Oblivion (Japan)"#),
+    "ABDP" => (1, false, "", "", r#"Boulder Dash EX (Europe)"#),
+    "ABDJ" => (1, false, "", "", r#"Boulder Dash EX (Japan)"#),
+    "ABDE" => (1, false, "", "", r#"Boulder Dash EX (USA)"#),
+    "ABOE" => (0, false, "", "", r#"Boxing Fever (Europe, USA)"#),
+    "ABOP" => (0, false, "", "", r#"Boxing Fever (Europe, USA)"#),
+    "A2RP" => (1, false, "", "", r#"Bratz (Europe)"#),
+    "A2RE" => (1, false, "", "", r#"Bratz (USA)"#),
+    "BBZP" => (0, false, "", "", r#"Bratz - Babyz (Europe)"#),
+    "BBZE" => (0, false, "", "", r#"Bratz - Babyz (USA)"#),
+    "BXFP" => (2, false, "", "", r#"Bratz - Forever Diamondz (Europe)"#),
+    "BXFE" => (2, false, "", "", r#"Bratz - Forever Diamondz (USA)"#),
+    "BRRE" => (1, false, "", "", r#"Bratz - Rock Angelz (Europe, USA)"#),
+    "BRRP" => (1, false, "", "", r#"Bratz - Rock Angelz (Europe, USA)"#),
+    "BBUP" => (0, false, "", "", r#"Bratz - The Movie (Europe)"#),
+    "BBUE" => (0, false, "", "", r#"Bratz - The Movie (USA)"#),
+    "ABFP" => (3, false, "", "", r#"Breath of Fire (Europe, English)"#),
+    "ABFE" => (3, false, "", "", r#"Breath of Fire (USA)"#),
+    "ABFJ" => (3, false, "", "", r#"Breath of Fire - Ryuu no Senshi (Japan)"#),
+    "AB2P" => (3, false, "", "", r#"Breath of Fire II (Europe)"#),
+    "AB2E" => (3, false, "", "", r#"Breath of Fire II (USA)"#),
+    "AB2J" => (3, false, "", "", r#"Breath of Fire II - Shimei no Ko (Japan)"#),
+    "ABYP" => (0, false, "", "", r#"Britney's Dance Beat (Europe)"#),
+    "ABYE" => (0, false, "", "", r#"Britney's Dance Beat (USA)"#),
+    "ABJP" => (2, false, "", "", r#"Broken Sword - The Shadow of the Templars (Europe)"#),
+    "ABJE" => (2, false, "", "", r#"Broken Sword - The Shadow of the Templars (USA)"#),
+    "BBRP" => (1, false, "", "", r#"Disney's Brother Bear (Europe, English)"#),
+    "BBRE" => (1, false, "", "", r#"Brother Bear (USA)"#),
+    "ALEP" => (1, false, "", "", r#"Bruce Lee - Return of the Legend (Europe)"#),
+    "ALEE" => (1, false, "", "", r#"Bruce Lee - Return of the Legend (USA)"#),
+    "AONP" => (1, false, "", "", r#"Bubble Bobble - Old & New (Europe)"#),
+    "A2BJ" => (1, false, "", "", r#"Bubble Bobble - Old & New (Japan)"#),
+    "AONE" => (1, false, "", "", r#"Bubble Bobble - Old & New (USA)"#),
+    "AVYD" => (1, false, "", "", r#"Buffy - Im Bann der Dämonen (Germany)"#),
+    "AVYF" => (1, false, "", "", r#"Buffy contre les Vampires (France)"#),
+    "AVYE" => (1, false, "", "", r#"Buffy the Vampire Slayer (Europe, USA)"#),
+    "AVYP" => (1, false, "", "", r#"Buffy the Vampire Slayer (Europe, USA)"#),
+    "BBKJ" => (3, false, "", "", r#"Bura Bura Donkey (Japan)"#),
+    "ABWP" => (0, false, "", "", r#"Butt-Ugly Martians - B.K.M. Battles (Europe)"#),
+    "ABWE" => (0, false, "", "", r#"Butt-Ugly Martians - B.K.M. Battles (USA)"#),
+    "BCGP" => (1, false, "", "", r#"Cabbage Patch Kids - The Patch Puppy Rescue (Europe)"#),
+    "BCGE" => (1, false, "", "", r#"Cabbage Patch Kids - The Patch Puppy Rescue (USA)"#),
+    "A8HE" => (0, false, "", "", r#"Cabela's Big Game Hunter (USA)"#),
+    "BG5E" => (1, false, "", "", r#"Cabela's Big Game Hunter - 2005 Adventures (Europe, USA)"#),
+    "BG5P" => (1, false, "", "", r#"Cabela's Big Game Hunter - 2005 Adventures (Europe, USA)"#),
+    "ACPE" => (0, false, "", "", r#"Caesars Palace Advance - Millennium Gold Edition (Europe, USA)"#),
+    "ACPP" => (0, false, "", "", r#"Caesars Palace Advance - Millennium Gold Edition (Europe, USA)"#),
+    "BIXJ" => (5, false, "macronix", "", r#"Calciobit (Japan)"#),
+    "BLCE" => (1, false, "", "", r#"Camp Lazlo - Leaky Lake Games (USA)"#),
+    "BC6E" => (0, false, "", "", r#"Capcom Classics Mini Mix (USA)"#),
+    "AKYJ" => (3, false, "", "", r#"Captain Tsubasa - Eikou no Kiseki (Japan)"#),
+    "ACBE" => (2, false, "", "", r#"Car Battler Joe (USA)"#),
+    "A8CJ" => (0, false, "", "", r#"Card Party (Japan)"#),
+    "BK3J" => (2, false, "", "", r#"Cardcaptor Sakura - Sakura Card de Mini Game (Japan)"#),
+    "BKSJ" => (1, false, "", "", r#"Cardcaptor Sakura - Sakura Card Hen - Sakura to Card to Otomodachi (Japan)"#),
+    "BEAP" => (1, false, "", "", r#"Care Bears - The Care Quests (Europe)"#),
+    "BEAE" => (1, false, "", "", r#"Care Bears - The Care Quests (USA)"#),
+    "AEDP" => (1, false, "", "", r#"Carrera Power Slide (Europe)"#),
+    "BCAY" => (1, false, "", "", r#"Disney/Pixar Cars (Europe, Spanish / Portuguese)"#),
+    "BCAJ" => (1, false, "", "", r#"Disney Pixar Cars (Japan)"#),
+    "BCAE" => (1, false, "", "", r#"Cars (Europe, USA)"#),
+    "BCAP" => (1, false, "", "", r#"Cars (Europe, USA)"#),
+    "BCPP" => (0, false, "", "", r#"Disney/Pixar Cars - Mater-National Championship (Europe)"#),
+    "BCPE" => (0, false, "", "", r#"Disney Pixar Cars - Mater-National Championship (USA)"#),
+    "AC9E" => (0, false, "", "", r#"Cartoon Network Block Party (USA)"#),
+    "ANRE" => (0, false, "", "", r#"Cartoon Network Speedway (USA)"#),
+    "ACSP" => (0, false, "", "", r#"Casper (Europe)"#),
+    "ACSE" => (0, false, "", "", r#"Casper (USA)"#),
+    "AAMP" => (3, false, "", "", r#"Castlevania (Europe)"#),
+    "A2CJ" => (3, false, "", "", r#"Castlevania - Akatsuki no Minuet (Japan)"#),
+    "A2CP" => (3, false, "", "", r#"Castlevania - Aria of Sorrow (Europe)"#),
+    "A2CE" => (3, false, "", "", r#"Castlevania - Aria of Sorrow (USA)"#),
+    "ACHJ" => (3, false, "", "", r#"Castlevania - Byakuya no Concerto (Japan)"#),
+    "AAME" => (3, false, "", "", r#"Castlevania - Circle of the Moon (USA)"#),
+    "ACHP" => (3, false, "", "", r#"Castlevania - Harmony of Dissonance (Europe)"#),
+    "ACHE" => (3, false, "", "", r#"Castlevania - Harmony of Dissonance (USA)"#),
+    "AWNP" => (0, false, "", "", r#"Castleween (Europe)"#),
+    "BCTE" => (0, false, "", "", r#"The Cat in the Hat by Dr. Seuss (USA)"#),
+    "BCWE" => (1, false, "", "", r#"Catwoman (Europe, USA)"#),
+    "BCWP" => (1, false, "", "", r#"Catwoman (Europe, USA)"#),
+    "AN3E" => (2, false, "", "", r#"Catz (Europe, USA)"#),
+    "AN3P" => (2, false, "", "", r#"Catz (Europe, USA)"#),
+    "BCJP" => (1, false, "", "", r#"Charlotte's Web (Europe)"#),
+    "BCJE" => (1, false, "", "", r#"Charlotte's Web (USA)"#),
+    "BCOE" => (1, false, "", "", r#"The Cheetah Girls (USA)"#),
+    "ACYP" => (1, false, "", "", r#"Chessmaster (Europe)"#),
+    "ACYE" => (1, false, "", "", r#"Chessmaster (USA)"#),
+    "BCHJ" => (1, false, "", "", r#"Chicken Little (Japan)"#),
+    "BCHE" => (1, false, "", "", r#"Chicken Little (Europe, USA)"#),
+    "BCHP" => (1, false, "", "", r#"Chicken Little (Europe, USA)"#),
+    "B6FP" => (0, false, "", "", r#"Chicken Shoot (Europe)"#),
+    "B6FE" => (0, false, "", "", r#"Chicken Shoot (USA)"#),
+    "B6GP" => (0, false, "", "", r#"Chicken Shoot 2 (Europe)"#),
+    "B6GE" => (0, false, "", "", r#"Chicken Shoot 2 (USA)"#),
+    "AGDJ" => (3, false, "", "", r#"Chinmoku no Iseki - Estpolis Gaiden (Japan)"#),
+    "AOCJ" => (1, false, "", "", r#"Chobits for Game Boy Advance - Atashi Dake no Hito (Japan)"#),
+    "A5BJ" => (3, false, "", "", r#"Chocobo Land - A Game of Dice (Japan)"#),
+    "AQAJ" => (3, false, "", "", r#"Choro Q Advance (Japan)"#),
+    "AQ2J" => (3, false, "", "", r#"Choro Q Advance 2 (Japan)"#),
+    "ACJJ" => (1, false, "", "", r#"Chou Makaimura R (Japan)"#),
+    "B2WE" => (1, false, "", "", r#"The Chronicles of Narnia - The Lion, the Witch and the Wardrobe (Europe, USA)"#),
+    "B2WP" => (1, false, "", "", r#"The Chronicles of Narnia - The Lion, the Witch and the Wardrobe (Europe, USA)"#),
+    "ACRP" => (5, false, "", "", r#"ChuChu Rocket! (Europe)"#),
+    "ACRJ" => (5, false, "", "", r#"ChuChu Rocket! (Japan)"#),
+    "ACRE" => (5, false, "", "", r#"ChuChu Rocket! (USA)"#),
+    "BCME" => (2, false, "", "", r#"CIMA - The Enemy (USA)"#),
+    "BCDP" => (1, false, "", "", r#"Disney's Cinderella - Magical Dreams (Europe)"#),
+    "BCDE" => (1, false, "", "", r#"Disney's Cinderella - Magical Dreams (Europe, USA)"#),
+    "BPSJ" => (1, false, "", "", r#"Cinnamoroll - Koko ni Iru yo (Japan)"#),
+    "B2SJ" => (1, false, "", "", r#"Cinnamon Yume no Daibouken (Japan)"#),
+    "B43J" => (1, false, "", "", r#"Cinnamon Fuwafuwa Daibouken (Japan)"#),
+    "FBME" => (1, false, "", "", r#"Classic NES Series - Bomberman (Europe, USA)"#),
+    "FBMP" => (1, false, "", "", r#"Classic NES Series - Bomberman (Europe, USA)"#),
+    "FADP" => (1, false, "", "", r#"NES Classics - Castlevania (Europe)"#),
+    "FADE" => (1, false, "", "", r#"Classic NES Series - Castlevania (USA)"#),
+    "FDKE" => (1, false, "", "", r#"Classic NES Series - Donkey Kong (Europe, USA)"#),
+    "FDKP" => (1, false, "", "", r#"Classic NES Series - Donkey Kong (Europe, USA)"#),
+    "FDME" => (1, false, "", "", r#"Classic NES Series - Dr. Mario (Europe, USA)"#),
+    "FDMP" => (1, false, "", "", r#"Classic NES Series - Dr. Mario (Europe, USA)"#),
+    "FEBE" => (2, false, "", "", r#"Classic NES Series - Excitebike (Europe, USA)"#),
+    "FEBP" => (2, false, "", "", r#"Classic NES Series - Excitebike (Europe, USA)"#),
+    "FICE" => (1, false, "", "", r#"Classic NES Series - Ice Climber (Europe, USA)"#),
+    "FICP" => (1, false, "", "", r#"Classic NES Series - Ice Climber (Europe, USA)"#),
+    "FZLE" => (2, false, "", "", r#"Classic NES Series - The Legend of Zelda (Europe, USA)"#),
+    "FZLP" => (2, false, "", "", r#"Classic NES Series - The Legend of Zelda (Europe, USA)"#),
+    "FMRE" => (1, false, "", "", r#"Classic NES Series - Metroid (Europe, USA)"#),
+    "FMRP" => (1, false, "", "", r#"Classic NES Series - Metroid (Europe, USA)"#),
+    "FP7E" => (1, false, "", "", r#"Classic NES Series - Pac-Man (Europe, USA)"#),
+    "FP7P" => (1, false, "", "", r#"Classic NES Series - Pac-Man (Europe, USA)"#),
+    "FSME" => (1, false, "", "", r#"Classic NES Series - Super Mario Bros. (Europe, USA)"#),
+    "FSMP" => (1, false, "", "", r#"Classic NES Series - Super Mario Bros. (Europe, USA)"#),
+    "FXVE" => (1, false, "", "", r#"Classic NES Series - Xevious (Europe, USA)"#),
+    "FXVP" => (1, false, "", "", r#"Classic NES Series - Xevious (Europe, USA)"#),
+    "FLBE" => (2, false, "", "", r#"Classic NES Series - Zelda II - The Adventure of Link (Europe, USA)"#),
+    "FLBP" => (2, false, "", "", r#"Classic NES Series - Zelda II - The Adventure of Link (Europe, USA)"#),
+    "BC5P" => (0, false, "", "", r#"Cocoto - Kart Racer (Europe)"#),
+    "BC8P" => (0, false, "", "", r#"Cocoto - Platform Jumper (Europe)"#),
+    "BNDE" => (0, false, "", "", r#"Codename - Kids Next Door - Operation S.O.D.A. (USA)"#),
+    "ACMP" => (1, false, "", "", r#"Colin McRae Rally 2.0 (Europe)"#),
+    "ACME" => (1, false, "", "", r#"Colin McRae Rally 2.0 (USA)"#),
+    "ACGP" => (1, false, "", "", r#"Columns Crown (Europe)"#),
+    "ACGJ" => (1, false, "", "", r#"Columns Crown (Japan)"#),
+    "ACGE" => (1, false, "", "", r#"Columns Crown (USA)"#),
+    "AQCJ" => (3, false, "", "", r#"Combat Choro Q - Advance Daisakusen (Japan)"#),
+    "ACZP" => (1, false, "", "", r#"Comix Zone (Europe)"#),
+    "AAWP" => (0, false, "", "", r#"Contra Advance - The Alien Wars EX (Europe)"#),
+    "AAWJ" => (0, false, "", "", r#"Contra Hard Spirits (Japan)"#),
+    "AAWE" => (0, false, "", "", r#"Contra Advance - The Alien Wars EX (USA)"#),
+    "AVCE" => (1, false, "", "", r#"Corvette (USA)"#),
+    "ACQE" => (1, false, "", "", r#"Crash Bandicoot - The Huge Adventure (USA)"#),
+    "AC8P" => (1, false, "", "", r#"Crash Bandicoot 2 - N-Tranced (Europe)"#),
+    "AC8E" => (1, false, "", "", r#"Crash Bandicoot 2 - N-Tranced (USA)"#),
+    "ACUJ" => (1, false, "", "", r#"Crash Bandicoot Advance (Japan)"#),
+    "BKDJ" => (1, false, "", "", r#"Crash Bandicoot Advance - Wakuwaku Tomodachi Daisakusen! (Japan)"#),
+    "AC8J" => (1, false, "", "", r#"Crash Bandicoot Advance 2 - Guruguru Saimin Dai-panic!? (Japan)"#),
+    "BCNJ" => (1, false, "", "", r#"Crash Bandicoot Bakusou Nitro Cart (Japan)"#),
+    "BD4P" => (1, false, "", "", r#"Crash Bandicoot Fusion (Europe)"#),
+    "BD4E" => (1, false, "", "", r#"Crash Bandicoot Purple - Ripto's Rampage (USA, rev. A)"#),
+    "ACQP" => (1, false, "", "", r#"Crash Bandicoot XS (Europe)"#),
+    "BCNP" => (1, false, "", "", r#"Crash Nitro Kart (Europe)"#),
+    "BCNE" => (1, false, "", "", r#"Crash Nitro Kart (USA)"#),
+    "BQCP" => (1, false, "", "", r#"Crash of the Titans (Europe)"#),
+    "BQCE" => (1, false, "", "", r#"Crash of the Titans (USA)"#),
+    "BKCJ" => (2, false, "", "", r#"Crayon Shin-chan - Arashi o Yobu Cinemaland no Daibouken! (Japan)"#),
+    "BC2J" => (2, false, "", "", r#"Crayon Shin-chan - Densetsu o Yobu Omake no Miyako Shockgaan! (Japan)"#),
+    "ACCP" => (1, false, "", "", r#"Crazy Chase (Europe)"#),
+    "ACCE" => (1, false, "", "", r#"Crazy Chase (USA)"#),
+    "BCRP" => (1, false, "", "", r#"Crazy Frog Racer (Europe)"#),
+    "A3CP" => (1, false, "", "", r#"Crazy Taxi - Catch a Ride (Europe)"#),
+    "A3CE" => (1, false, "", "", r#"Crazy Taxi - Catch a Ride (USA)"#),
+    "A6CJ" => (3, false, "", "", r#"Croket! - Yume no Banker Survival! (Japan)"#),
+    "BK2J" => (3, false, "", "", r#"Croket! 2 - Yami no Bank to Banqueen (Japan)"#),
+    "B3KJ" => (3, false, "", "", r#"Croket! 3 - Granu Oukoku no Nazo (Japan)"#),
+    "BK4J" => (3, false, "", "", r#"Croket! 4 - Bank no Mori no Mamorigami (Japan)"#),
+    "BK5J" => (3, false, "", "", r#"Croket! Great - Toki no Boukensha (Japan)"#),
+    "AQDP" => (0, false, "", "", r#"Crouching Tiger, Hidden Dragon (Europe)"#),
+    "AQDE" => (0, false, "", "", r#"Crouching Tiger, Hidden Dragon (USA)"#),
+    "ACFE" => (0, false, "", "", r#"Cruis'n Velocity (Europe, USA)"#),
+    "ACFP" => (0, false, "", "", r#"Cruis'n Velocity (Europe, USA)"#),
+    "BCBE" => (1, false, "", "", r#"Crushed Baseball (USA)"#),
+    "AC7P" => (0, false, "", "", r#"CT Special Forces (Europe)"#),
+    "AC7E" => (0, false, "", "", r#"CT Special Forces (USA)"#),
+    "A9CP" => (0, false, "", "", r#"CT Special Forces - Back to Hell (Europe)"#),
+    "BC3P" => (0, false, "", "", r#"CT Special Forces - Bioterror (Europe)"#),
+    "A9CE" => (0, false, "", "", r#"CT Special Forces 2 - Back in the Trenches (USA)"#),
+    "ACXE" => (1, false, "", "", r#"Cubix - Robots for Everyone - Clash 'N Bash (USA)"#),
+    "B3JP" => (0, false, "", "", r#"Curious George (Europe)"#),
+    "B3JE" => (0, false, "", "", r#"Curious George (USA)"#),
+    "ARJJ" => (2, false, "", "", r#"Custom Robo GX (Japan)"#),
+    "AZ3J" => (3, false, "", "", r#"Cyberdrive Zoids - Kijuu no Senshi Hyuu (Japan)"#),
+    "AHMJ" => (1, false, "", "", r#"Dai-mahjong. (Japan)"#),
+    "ADSJ" => (5, false, "", "", r#"Daisenryaku for Game Boy Advance (Japan)"#),
+    "ATDJ" => (3, false, "", "", r#"Daisuki Teddy (Japan)"#),
+    "BDNJ" => (3, false, "", "", r#"Dan Doh!! Tobase Shouri no Smile Shot (Japan)"#),
+    "AXHJ" => (3, false, "", "", r#"Dan Doh!! Xi (Japan)"#),
+    "A9SJ" => (1, false, "", "", r#"Dancing Sword - Senkou (Japan)"#),
+    "BUEE" => (1, false, "", "", r#"Danny Phantom - The Ultimate Enemy (USA)"#),
+    "BOYE" => (1, false, "", "", r#"Danny Phantom - Urban Jungle (USA)"#),
+    "AVLP" => (0, false, "", "", r#"Daredevil (Europe)"#),
+    "AVLE" => (0, false, "", "", r#"Daredevil (USA)"#),
+    "A2DJ" => (1, false, "", "", r#"Darius R (Japan)"#),
+    "ADAE" => (0, false, "", "", r#"Dark Arena (Europe, USA)"#),
+    "ADAP" => (0, false, "", "", r#"Dark Arena (Europe, USA)"#),
+    "AX2P" => (2, false, "", "", r#"Dave Mirra Freestyle BMX 2 (Europe)"#),
+    "AX2E" => (2, false, "", "", r#"Dave Mirra Freestyle BMX 2 (USA)"#),
+    "AB3E" => (2, false, "", "", r#"Dave Mirra Freestyle BMX 3 (Europe, USA)"#),
+    "AB3P" => (2, false, "", "", r#"Dave Mirra Freestyle BMX 3 (Europe, USA)"#),
+    "ABQP" => (1, false, "", "", r#"David Beckham Soccer (Europe)"#),
+    "ABQE" => (1, false, "", "", r#"David Beckham Soccer (USA)"#),
+    "AD6P" => (1, false, "", "", r#"Davis Cup (Europe)"#),
+    "AD6E" => (1, false, "", "", r#"Davis Cup (USA)"#),
+    "BDEP" => (0, false, "", "", r#"Dead to Rights (Europe)"#),
+    "BDEE" => (0, false, "", "", r#"Dead to Rights (USA)"#),
+    "AAKP" => (1, false, "", "", r#"Deadly Skies (Europe)"#),
+    "BZNE" => (0, false, "", "", r#"Deal or No Deal (USA)"#),
+    "A2FE" => (1, false, "", "", r#"Defender (USA)"#),
+    "A2FP" => (1, false, "", "", r#"Defender (Europe)"#),
+    "ADHP" => (1, false, "", "", r#"Defender of the Crown (Europe)"#),
+    "ADHE" => (1, false, "", "", r#"Defender of the Crown (USA)"#),
+    "AC5E" => (3, false, "", "", r#"DemiKids - Dark Version (USA)"#),
+    "AL4E" => (3, false, "", "", r#"DemiKids - Light Version (USA)"#),
+    "A9AP" => (0, false, "", "", r#"Demon Driver - Time to Burn Rubber! (Europe)"#),
+    "ADBP" => (1, false, "", "", r#"Denki Blocks! (Europe)"#),
+    "ADBJ" => (1, false, "", "", r#"Denki Blocks! (Japan)"#),
+    "ADBE" => (1, false, "", "", r#"Denki Blocks! (USA)"#),
+    "ASTJ" => (3, false, "", "", r#"Densetsu no Stafy (Japan)"#),
+    "AVFJ" => (3, false, "", "", r#"Densetsu no Stafy 2 (Japan)"#),
+    "B3DJ" => (3, false, "", "", r#"Densetsu no Stafy 3 (Japan)"#),
+    "A8PJ" => (5, false, "", "", r#"Derby Stallion Advance (Japan)"#),
+    "ADIE" => (0, false, "", "", r#"Desert Strike Advance (USA)"#),
+    "ADXP" => (0, false, "", "", r#"Dexter's Laboratory - Chess Challenge (Europe)"#),
+    "ADXE" => (0, false, "", "", r#"Dexter's Laboratory - Chess Challenge (USA)"#),
+    "ADLP" => (1, false, "", "", r#"Dexter's Laboratory - Deesaster Strikes! (Europe)"#),
+    "ADLE" => (1, false, "", "", r#"Dexter's Laboratory - Deesaster Strikes! (USA, rev. 1)"#),
+    "A3OJ" => (3, false, "", "", r#"Di Gi Charat - DigiCommunication (Japan)"#),
+    "ADDJ" => (2, false, "", "", r#"Diadroids World - Evil Teikoku no Yabou (Japan)"#),
+    "BDKJ" => (2, false, "", "", r#"DigiCommunication 2 - Datou! Black Gemagema Dan (Japan)"#),
+    "A8SP" => (1, false, "", "", r#"Digimon - Battle Spirit (Europe)"#),
+    "A8SE" => (1, false, "", "", r#"Digimon - Battle Spirit (USA)"#),
+    "BDSP" => (1, false, "", "", r#"Digimon - Battle Spirit 2 (Europe)"#),
+    "BDSE" => (1, false, "", "", r#"Digimon - Battle Spirit 2 (USA)"#),
+    "BDGP" => (1, false, "", "", r#"Digimon Racing (Europe)"#),
+    "BDJJ" => (1, false, "", "", r#"Digimon Racing (Japan)"#),
+    "BDGE" => (1, false, "", "", r#"Digimon Racing (USA)"#),
+    "AD3E" => (1, false, "", "", r#"Dinotopia - The Timestone Pirates (Europe, USA)"#),
+    "AD3P" => (1, false, "", "", r#"Dinotopia - The Timestone Pirates (Europe, USA)"#),
+    "AQPE" => (0, false, "", "", r#"Disney Princess (Europe, USA)"#),
+    "AQPP" => (0, false, "", "", r#"Disney Princess (Europe, USA)"#),
+    "BQNP" => (1, false, "", "", r#"Disney Princess - Royal Adventure (Europe)"#),
+    "BQNE" => (1, false, "", "", r#"Disney Princess - Royal Adventure (USA)"#),
+    "A2AP" => (1, false, "", "", r#"Disney Sports - Basketball (Europe)"#),
+    "A2AJ" => (1, false, "", "", r#"Disney Sports - Basketball (Japan)"#),
+    "A2AE" => (1, false, "", "", r#"Disney Sports - Basketball (USA)"#),
+    "A3DE" => (1, false, "", "", r#"Disney Sports - Football (USA)"#),
+    "A3DJ" => (1, false, "", "", r#"Disney Sports - American Football (Japan)"#),
+    "AOMP" => (1, false, "", "", r#"Disney Sports - Motocross (Europe)"#),
+    "AOMJ" => (1, false, "", "", r#"Disney Sports - Motocross (Japan)"#),
+    "AOME" => (1, false, "", "", r#"Disney Sports - Motocross (USA)"#),
+    "A4DP" => (1, false, "", "", r#"Disney Sports - Skateboarding (Europe)"#),
+    "A4DJ" => (1, false, "", "", r#"Disney Sports - Skateboarding (Japan)"#),
+    "A4DE" => (1, false, "", "", r#"Disney Sports - Skateboarding (USA)"#),
+    "A5DP" => (1, false, "", "", r#"Disney Sports - Snowboarding (Europe)"#),
+    "A5DJ" => (1, false, "", "", r#"Disney Sports - Snowboarding (Japan)"#),
+    "A5DE" => (1, false, "", "", r#"Disney Sports - Snowboarding (USA)"#),
+    "A6DP" => (1, false, "", "", r#"Disney Sports - Football (Europe)"#),
+    "A6DJ" => (1, false, "", "", r#"Disney Sports - Soccer (Japan)"#),
+    "A6DE" => (1, false, "", "", r#"Disney Sports - Soccer (USA)"#),
+    "BD8E" => (1, false, "", "", r#"Disney's Party (Europe, USA)"#),
+    "BD8P" => (1, false, "", "", r#"Disney's Party (Europe, USA)"#),
+    "BBKP" => (3, false, "", "", r#"DK - King of Swing (Europe)"#),
+    "BBKE" => (3, false, "", "", r#"DK - King of Swing (USA, Australia)"#),
+    "B82P" => (1, false, "", "", r#"Dogz (Europe, English)"#),
+    "B82E" => (1, false, "", "", r#"Dogz (USA)"#),
+    "BFEP" => (2, false, "", "", r#"Dogz - Fashion (Europe)"#),
+    "BFEE" => (2, false, "", "", r#"Dogz - Fashion (USA)"#),
+    "BIMP" => (2, false, "", "", r#"Dogz 2 (Europe, English)"#),
+    "BIME" => (2, false, "", "", r#"Dogz 2 (USA, rev. 1)"#),
+    "ADQP" => (3, false, "", "", r#"Dokapon (Europe)"#),
+    "ADOE" => (3, false, "", "", r#"Dokapon (USA)"#),
+    "ADQJ" => (3, false, "", "", r#"Dokapon Q - Monster Hunter! (Japan)"#),
+    "A56J" => (2, false, "", "", r#"Dokidoki Cooking Series 1 - Komugi-chan no Happy Cake (Japan)"#),
+    "A8OJ" => (2, false, "", "", r#"Dokidoki Cooking Series 2 - Gourmet Kitchen - Suteki na Obentou (Japan)"#),
+    "AYAJ" => (3, false, "", "", r#"Dokodemo Taikyoku - Yakuman Advance (Japan, rev. 1)"#),
+    "ADOJ" => (3, false, "", "", r#"Domo-kun no Fushigi Television (Japan)"#),
+    "ADKP" => (0, false, "", "", r#"Disney's Donald Duck "Adv@nce"!*# (Europe)"#),
+    "AADJ" => (0, false, "", "", r#"Disney's Donald Duck Advance (Japan)"#),
+    "ADKE" => (0, false, "", "", r#"Disney's Donald Duck "Adv@nce"!*# (USA)"#),
+    "BDAJ" => (1, false, "", "", r#"Donchan Puzzle Hanabi de Dohn Advance (Japan)"#),
+    "A5NP" => (1, false, "", "", r#"Donkey Kong Country (Europe)"#),
+    "A5NE" => (1, false, "", "", r#"Donkey Kong Country (USA)"#),
+    "B2DP" => (2, false, "", "", r#"Donkey Kong Country 2 (Europe)"#),
+    "B2DE" => (2, false, "", "", r#"Donkey Kong Country 2 (USA, Australia)"#),
+    "B2DU" => (2, false, "", "", r#"Donkey Kong Country 2 (USA, Australia)"#),
+    "BDQP" => (1, false, "", "", r#"Donkey Kong Country 3 (Europe)"#),
+    "BDQE" => (1, false, "", "", r#"Donkey Kong Country 3 (USA, Australia)"#),
+    "ADME" => (1, false, "", "", r#"Doom (Europe, USA)"#),
+    "ADMP" => (1, false, "", "", r#"Doom (Europe, USA)"#),
+    "A9DP" => (1, false, "", "", r#"Doom II (Europe)"#),
+    "A9DE" => (1, false, "", "", r#"Doom II (USA)"#),
+    "BXPE" => (0, false, "", "", r#"Dora the Explorer - Dora's World Adventure! (USA)"#),
+    "BERE" => (0, false, "", "", r#"Dora the Explorer - Super Spies (USA)"#),
+    "BDOP" => (0, false, "", "", r#"Dora the Explorer - Super Star Adventures! (Europe)"#),
+    "BDOE" => (0, false, "", "", r#"Dora the Explorer - Super Star Adventures! (USA)"#),
+    "AERE" => (0, false, "", "", r#"Dora the Explorer - The Search for the Pirate Pig's Treasure (USA)"#),
+    "ADPJ" => (2, false, "", "", r#"Doraemon - Dokodemo Walker (Japan)"#),
+    "ADRJ" => (3, false, "", "", r#"Doraemon - Midori no Wakusei Dokidoki Daikyuushutsu! (Japan)"#),
+    "BDDJ" => (0, false, "", "", r#"Double Dragon Advance (Japan)"#),
+    "BDDE" => (0, false, "", "", r#"Double Dragon Advance (USA)"#),
+    "A8DJ" => (4, false, "", "", r#"Doubutsu-jima no Chobigurumi (Japan, rev. 1)"#),
+    "BDCJ" => (1, false, "", "", r#"Doubutsu-jima no Chobigurumi 2 - Tama-chan Monogatari (Japan)"#),
+    "ADWP" => (0, false, "", "", r#"Downforce (Europe)"#),
+    "BDTJ" => (2, false, "", "", r#"Downtown Nekketsu Monogatari EX (Japan)"#),
+    "A6TP" => (1, false, "", "", r#"Dr. Muto (Europe)"#),
+    "BUOP" => (2, false, "", "", r#"Dr. Sudoku (Europe)"#),
+    "BUOE" => (2, false, "", "", r#"Dr. Sudoku (USA)"#),
+    "BDVP" => (1, false, "", "", r#"Dragon Ball - Advanced Adventure (Europe)"#),
+    "BDVJ" => (1, false, "", "", r#"Dragon Ball - Advance Adventure (Japan)"#),
+    "BDVE" => (1, false, "", "", r#"Dragon Ball - Advanced Adventure (USA)"#),
+    "BT4E" => (2, false, "", "", r#"Dragon Ball GT - Transformation (USA)"#),
+    "BG3E" => (2, false, "", "", r#"Dragon Ball Z - Buu's Fury (USA)"#),
+    "ADZE" => (3, false, "", "", r#"Dragon Ball Z - Collectible Card Game (USA)"#),
+    "AZJP" => (1, false, "", "", r#"Dragon Ball Z - Supersonic Warriors (Europe)"#),
+    "AZJE" => (1, false, "", "", r#"Dragon Ball Z - Supersonic Warriors (USA)"#),
+    "AZJJ" => (1, false, "", "", r#"Dragon Ball Z - Bukuu Tougeki (Japan)"#),
+    "BDBP" => (1, false, "", "", r#"Dragon Ball Z - Taiketsu (Europe)"#),
+    "BDBE" => (1, false, "", "", r#"Dragon Ball Z - Taiketsu (USA)"#),
+    "ALGP" => (1, false, "", "", r#"Dragon Ball Z - The Legacy of Goku (Europe)"#),
+    "ALGE" => (1, false, "", "", r#"Dragon Ball Z - The Legacy of Goku (USA)"#),
+    "ALFP" => (2, false, "", "", r#"Dragon Ball Z - The Legacy of Goku II (Europe)"#),
+    "ALFE" => (2, false, "", "", r#"Dragon Ball Z - The Legacy of Goku II (USA)"#),
+    "ALFJ" => (2, false, "", "", r#"Dragon Ball Z - The Legacy of Goku II International (Japan)"#),
+    "A5GJ" => (3, false, "", "", r#"Dragon Drive - World D Break (Japan)"#),
+    "AT2J" => (5, false, "", "", r#"Dragon Quest Characters - Torneko no Daibouken 2 Advance - Fushigi no Dungeon (Japan)"#),
+    "BD3J" => (4, false, "", "", r#"Dragon Quest Characters - Torneko no Daibouken 3 Advance - Fushigi no Dungeon (Japan)"#),
+    "A9HJ" => (2, false, "", "", r#"Dragon Quest Monsters - Caravan Heart (Japan)"#),
+    "BD9E" => (0, false, "", "", r#"Dragon Tales - Dragon Adventures (USA)"#),
+    "BJDP" => (1, false, "", "", r#"Dragon's Rock (Europe)"#),
+    "V49E" => (3, false, "", "rumble", r#"Drill Dozer (USA)"#),
+    "B3RP" => (1, false, "", "", r#"Driv3r (Europe)"#),
+    "B3RE" => (1, false, "", "", r#"Driv3r (USA)"#),
+    "ADVP" => (1, false, "", "", r#"Driven (Europe)"#),
+    "ADVE" => (1, false, "", "", r#"Driven (USA)"#),
+    "ADUP" => (1, false, "", "", r#"Driver 2 Advance (Europe)"#),
+    "ADUE" => (1, false, "", "", r#"Driver 2 Advance (USA)"#),
+    "AOEX" => (1, false, "", "", r#"Drome Racers (Europe)"#),
+    "AOEE" => (1, false, "", "", r#"Drome Racers (USA)"#),
+    "AD7P" => (0, false, "", "", r#"Droopy's Tennis Open (Europe)"#),
+    "AB9E" => (1, false, "", "", r#"Dual Blades (USA)"#),
+    "AB9J" => (1, false, "", "", r#"Dual Blades (Japan)"#),
+    "AA9J" => (3, false, "", "", r#"Duel Masters (Japan)"#),
+    "BD2J" => (3, false, "", "", r#"Duel Masters 2 - Invincible Advance (Japan)"#),
+    "BD5J" => (3, false, "", "", r#"Duel Masters 2 - Kirifuda Shoubu Ver. (Japan)"#),
+    "BDUJ" => (3, false, "", "", r#"Duel Masters 3 (Japan)"#),
+    "AA9P" => (1, false, "", "", r#"Duel Masters - Sempai Legends (Europe)"#),
+    "AA9E" => (1, false, "", "", r#"Duel Masters - Sempai Legends (USA)"#),
+    "BD6P" => (1, false, "", "", r#"Duel Masters - Kaijudo Showdown (Europe)"#),
+    "BD6E" => (1, false, "", "", r#"Duel Masters - Kaijudo Showdown (USA)"#),
+    "BDUP" => (1, false, "", "", r#"Duel Masters - Shadow of the Code (Europe)"#),
+    "BDUE" => (1, false, "", "", r#"Duel Masters - Shadow of the Code (USA)"#),
+    "AD9P" => (1, false, "", "", r#"Duke Nukem Advance (Europe)"#),
+    "AD9E" => (1, false, "", "", r#"Duke Nukem Advance (USA)"#),
+    "AD4P" => (3, false, "", "", r#"Dungeons & Dragons - Eye of the Beholder (Europe)"#),
+    "AD4E" => (3, false, "", "", r#"Dungeons & Dragons - Eye of the Beholder (USA)"#),
+    "BE6P" => (3, false, "", "", r#"Dynasty Warriors Advance (Europe)"#),
+    "BE6E" => (3, false, "", "", r#"Dynasty Warriors Advance (USA, Australia)"#),
+    "AETP" => (0, false, "", "", r#"E.T. - The Extra-Terrestrial (Europe)"#),
+    "AETE" => (0, false, "", "", r#"E.T. - The Extra-Terrestrial (USA)"#),
+    "AEJE" => (0, false, "", "", r#"Earthworm Jim (Europe, USA)"#),
+    "AEJP" => (0, false, "", "", r#"Earthworm Jim (Europe, USA)"#),
+    "AJ4P" => (0, false, "", "", r#"Earthworm Jim 2 (Europe)"#),
+    "AJ4E" => (0, false, "", "", r#"Earthworm Jim 2 (USA)"#),
+    "AESP" => (0, false, "", "", r#"Ecks vs Sever (Europe)"#),
+    "AESE" => (0, false, "", "", r#"Ecks vs Sever (USA)"#),
+    "AEEP" => (0, false, "", "", r#"Ecks vs Sever II - Ballistic (Europe)"#),
+    "AEEE" => (0, false, "", "", r#"Ballistic - Ecks vs Sever (USA)"#),
+    "AE3P" => (1, false, "", "", r#"Ed, Edd n Eddy - Jawbreakers! (Europe)"#),
+    "AE3E" => (1, false, "", "", r#"Ed, Edd n Eddy - Jawbreakers! (USA, rev. 1)"#),
+    "BEDP" => (1, false, "", "", r#"Ed, Edd n Eddy - The Mis-Edventures (Europe)"#),
+    "BEDE" => (1, false, "", "", r#"Ed, Edd n Eddy - The Mis-Edventures (USA)"#),
+    "AEMP" => (0, false, "", "", r#"Eggo Mania (Europe)"#),
+    "AEME" => (0, false, "", "", r#"Egg Mania (USA)"#),
+    "AEMJ" => (0, false, "", "", r#"Egg Mania - Tsukande! Mawashite! Dossun Puzzle!! (Japan)"#),
+    "AEKJ" => (1, false, "", "", r#"Elemix! (Japan)"#),
+    "ANWJ" => (1, false, "", "", r#"Elevator Action - Old & New (Japan)"#),
+    "BELP" => (0, false, "", "", r#"Elf - The Movie (Europe)"#),
+    "BELE" => (0, false, "", "", r#"Elf - The Movie (USA)"#),
+    "BEBE" => (0, false, "", "", r#"Elf Bowling 1 & 2 (USA)"#),
+    "BZRE" => (1, false, "", "", r#"Enchanted - Once Upon Andalasia (USA)"#),
+    "BENP" => (2, false, "", "", r#"Eragon (Europe)"#),
+    "BENE" => (2, false, "", "", r#"Eragon (USA)"#),
+    "BEJJ" => (2, false, "", "", r#"Elemental Gerad - Tozasareshi Uta (Japan)"#),
+    "AGRP" => (5, false, "sst", "", r#"ESPN Final Round Golf (Europe)"#),
+    "AGRE" => (5, false, "", "", r#"ESPN Final Round Golf 2002 (USA)"#),
+    "AMGP" => (2, false, "", "", r#"ESPN Great Outdoor Games - Bass Tournament (Europe)"#),
+    "AMGE" => (3, false, "", "", r#"ESPN Great Outdoor Games - Bass 2002 (USA)"#),
+    "AWIP" => (1, false, "", "", r#"ESPN International Winter Sports (Europe)"#),
+    "AWIE" => (1, false, "", "", r#"ESPN International Winter Sports 2002 (USA)"#),
+    "AWXP" => (1, false, "", "", r#"ESPN Winter X-Games Snowboarding 2 (Europe)"#),
+    "AWXJ" => (2, false, "", "", r#"ESPN Winter X-Games Snowboarding 2002 (Japan)"#),
+    "AWXE" => (1, false, "", "", r#"ESPN Winter X-Games Snowboarding 2002 (USA)"#),
+    "AXSP" => (1, false, "", "", r#"ESPN X-Games Skateboarding (Europe)"#),
+    "AXSJ" => (1, false, "", "", r#"ESPN X-Games Skateboarding (Japan)"#),
+    "AXSE" => (1, false, "", "", r#"ESPN X-Games Skateboarding (USA)"#),
+    "AELP" => (0, false, "", "", r#"European Super League (Europe)"#),
+    "BEVE" => (1, false, "", "", r#"Ever Girl (USA)"#),
+    "AMOJ" => (3, false, "", "", r#"EX Monopoly (Japan, rev. 1)"#),
+    "AMGJ" => (2, false, "", "", r#"Exciting Bass (Japan)"#),
+    "AEGP" => (0, false, "", "", r#"Extreme Ghostbusters - Code Ecto-1 (Europe)"#),
+    "AEGE" => (0, false, "", "", r#"Extreme Ghostbusters - Code Ecto-1 (USA)"#),
+    "BESE" => (1, false, "", "", r#"Disney's Extreme Skate Adventure (Europe, USA)"#),
+    "BESP" => (1, false, "", "", r#"Disney's Extreme Skate Adventure (Europe, USA)"#),
+    "BE4J" => (3, false, "", "", r#"Eyeshield 21 - Devilbats Devildays (Japan)"#),
+    "A22J" => (3, false, "", "", r#"EZ-Talk - Shokyuu Hen 1 (Japan)"#),
+    "A23J" => (3, false, "", "", r#"EZ-Talk - Shokyuu Hen 2 (Japan)"#),
+    "A24J" => (3, false, "", "", r#"EZ-Talk - Shokyuu Hen 3 (Japan)"#),
+    "A25J" => (3, false, "", "", r#"EZ-Talk - Shokyuu Hen 4 (Japan)"#),
+    "A26J" => (3, false, "", "", r#"EZ-Talk - Shokyuu Hen 5 (Japan)"#),
+    "A27J" => (3, false, "", "", r#"EZ-Talk - Shokyuu Hen 6 (Japan)"#),
+    "AFTE" => (0, false, "", "", r#"F-14 Tomcat (Europe, USA)"#),
+    "BFTJ" => (5, false, "", "", r#"F-Zero - Climax (Japan)"#),
+    "BFZJ" => (3, false, "", "", r#"F-Zero - Falcon Densetsu (Japan)"#),
+    "AFZE" => (3, false, "", "", r#"F-Zero - Maximum Velocity (Europe, USA)"#),
+    "AFZP" => (3, false, "", "", r#"F-Zero - Maximum Velocity (Europe, USA)"#),
+    "BFZP" => (3, false, "", "", r#"F-Zero - GP Legend (Europe)"#),
+    "BFZE" => (3, false, "", "", r#"F-Zero - GP Legend (USA)"#),
+    "AFZJ" => (3, false, "", "", r#"F-Zero for Game Boy Advance (Japan)"#),
+    "AF8E" => (1, false, "", "", r#"F1 2002 (Europe, USA)"#),
+    "AF8P" => (1, false, "", "", r#"F1 2002 (Europe, USA)"#),
+    "BYAE" => (0, false, "", "", r#"F24 Stealth Fighter (USA)"#),
+    "AFVE" => (0, false, "", "", r#"The Fairly OddParents! - Enter the Cleft (USA)"#),
+    "AF6E" => (0, false, "", "", r#"The Fairly OddParents! - Breakin' da Rules (USA)"#),
+    "BF2P" => (0, false, "", "", r#"The Fairly OddParents! - Shadow Showdown (Europe)"#),
+    "BF2E" => (0, false, "", "", r#"The Fairly OddParents! - Shadow Showdown (USA)"#),
+    "BFOP" => (0, false, "", "", r#"The Fairly OddParents! - Clash with the Anti-World (Europe)"#),
+    "BFOE" => (0, false, "", "", r#"The Fairly OddParents! - Clash with the Anti-World (USA)"#),
+    "FGZJ" => (1, false, "", "", r#"Famicom Mini - Kidou Senshi Z Gundam - Hot Scramble (Japan, promo)"#),
+    "FSMJ" => (1, false, "", "", r#"Famicom Mini 01 - Super Mario Bros. (Japan, rev. 1)"#),
+    "FDKJ" => (1, false, "", "", r#"Famicom Mini 02 - Donkey Kong (Japan)"#),
+    "FICJ" => (1, false, "", "", r#"Famicom Mini 03 - Ice Climber (Japan)"#),
+    "FEBJ" => (0, false, "", "", r#"Famicom Mini 04 - Excitebike (Japan)"#),
+    "FZLJ" => (2, false, "", "", r#"Famicom Mini 05 - Zelda no Densetsu 1 - The Hyrule Fantasy (Japan)"#),
+    "FPMJ" => (1, false, "", "", r#"Famicom Mini 06 - Pac-Man (Japan)"#),
+    "FXVJ" => (1, false, "", "", r#"Famicom Mini 07 - Xevious (Japan)"#),
+    "FMPJ" => (1, false, "", "", r#"Famicom Mini 08 - Mappy (Japan)"#),
+    "FBMJ" => (1, false, "", "", r#"Famicom Mini 09 - Bomberman (Japan)"#),
+    "FSOJ" => (1, false, "", "", r#"Famicom Mini 10 - Star Soldier (Japan)"#),
+    "FMBJ" => (1, false, "", "", r#"Famicom Mini 11 - Mario Bros. (Japan)"#),
+    "FCLJ" => (1, false, "", "", r#"Famicom Mini 12 - Clu Clu Land (Japan)"#),
+    "FBFJ" => (1, false, "", "", r#"Famicom Mini 13 - Balloon Fight (Japan)"#),
+    "FWCJ" => (1, false, "", "", r#"Famicom Mini 14 - Wrecking Crew (Japan)"#),
+    "FDMJ" => (1, false, "", "", r#"Famicom Mini 15 - Dr. Mario (Japan)"#),
+    "FDDJ" => (1, false, "", "", r#"Famicom Mini 16 - Dig Dug (Japan)"#),
+    "FTBJ" => (1, false, "", "", r#"Famicom Mini 17 - Takahashi Meijin no Bouken-jima (Japan)"#),
+    "FMKJ" => (1, false, "", "", r#"Famicom Mini 18 - Makai Mura (Japan)"#),
+    "FTWJ" => (1, false, "", "", r#"Famicom Mini 19 - Twin Bee (Japan)"#),
+    "FGGJ" => (1, false, "", "", r#"Famicom Mini 20 - Ganbare Goemon! - Karakuri Douchuu (Japan)"#),
+    "FM2J" => (1, false, "", "", r#"Famicom Mini 21 - Super Mario Bros. 2 (Japan)"#),
+    "FNMJ" => (1, false, "", "", r#"Famicom Mini 22 - Nazo no Murasame Jou (Japan)"#),
+    "FMRJ" => (2, false, "", "", r#"Famicom Mini 23 - Metroid (Japan)"#),
+    "FPTJ" => (2, false, "", "", r#"Famicom Mini 24 - Hikari Shinwa - Palthena no Kagami (Japan)"#),
+    "FLBJ" => (2, false, "", "", r#"Famicom Mini 25 - The Legend of Zelda 2 - Link no Bouken (Japan)"#),
+    "FFMJ" => (1, false, "", "", r#"Famicom Mini 26 - Famicom Mukashibanashi - Shin Onigashima - Zen, Kouhen (Japan)"#),
+    "FTKJ" => (1, false, "", "", r#"Famicom Mini 27 - Famicom Tantei Club - Kieta Koukeisha - Zen, Kouhen (Japan)"#),
+    "FTUJ" => (1, false, "", "", r#"Famicom Mini 28 - Famicom Tantei Club Part II - Ushiro ni Tatsu Shoujo - Zen, Kouhen (Japan)"#),
+    "FADJ" => (1, false, "", "", r#"Famicom Mini 29 - Akumajou Dracula (Japan)"#),
+    "FSDJ" => (2, false, "", "", r#"Famicom Mini 30 - SD Gundam World - Gachapon Senshi Scramble Wars (Japan)"#),
+    "B2FE" => (0, false, "", "", r#"Family Feud (USA)"#),
+    "AATJ" => (1, false, "", "", r#"Family Tennis Advance (Japan)"#),
+    "AN7J" => (2, false, "", "", r#"Famista Advance (Japan)"#),
+    "AJEJ" => (3, false, "", "", r#"Fancy Pocket (Japan)"#),
+    "BF4E" => (1, false, "", "", r#"Fantastic 4 (USA)"#),
+    "BH4P" => (1, false, "", "", r#"Fantastic 4 - Flame On (Europe)"#),
+    "BH4E" => (1, false, "", "", r#"Fantastic 4 - Flame On (USA)"#),
+    "BFCJ" => (2, false, "", "", r#"Fantastic Children (Japan)"#),
+    "AAXJ" => (2, false, "", "", r#"Fantastic Märchen - Cake-ya-san Monogatari + Doubutsu Chara Navi Uranai Kosei Shinri Gaku (Japan)"#),
+    "BFUE" => (1, false, "", "", r#"Fear Factor Unleashed (USA)"#),
+    "AF9J" => (3, false, "", "", r#"Field of Nine - Digital Edition 2001 (Japan)"#),
+    "BF6E" => (1, false, "", "", r#"FIFA 06 (Europe, USA)"#),
+    "BF6P" => (1, false, "", "", r#"FIFA 06 (Europe, USA)"#),
+    "B7FE" => (1, false, "", "", r#"FIFA 07 (Europe, USA)"#),
+    "B7FP" => (1, false, "", "", r#"FIFA 07 (Europe, USA)"#),
+    "AFJE" => (1, false, "", "", r#"FIFA (Europe, USA)"#),
+    "AFJP" => (1, false, "", "", r#"FIFA (Europe, USA)"#),
+    "BFIE" => (1, false, "", "", r#"FIFA 2004 (Europe, USA)"#),
+    "BFIP" => (1, false, "", "", r#"FIFA 2004 (Europe, USA)"#),
+    "BF5E" => (1, false, "", "", r#"FIFA 2005 (Europe, USA)"#),
+    "BF5P" => (1, false, "", "", r#"FIFA 2005 (Europe, USA)"#),
+    "BOXP" => (1, false, "", "", r#"FightBox (Europe)"#),
+    "AFLP" => (1, false, "", "", r#"FILA Decathlon (Europe)"#),
+    "BFFJ" => (3, false, "", "", r#"Final Fantasy I - II Advance (Japan, rev. 1)"#),
+    "BZ4P" => (3, false, "", "", r#"Final Fantasy IV Advance (Europe)"#),
+    "BZ4J" => (3, false, "", "", r#"Final Fantasy IV Advance (Japan, rev. 1)"#),
+    "BZ4E" => (3, false, "", "", r#"Final Fantasy IV Advance (USA, Australia)"#),
+    "AFXP" => (4, false, "sst", "", r#"Final Fantasy Tactics Advance (Europe)"#),
+    "AFXJ" => (4, false, "", "", r#"Final Fantasy Tactics Advance (Japan)"#),
+    "AFXE" => (4, false, "sst", "", r#"Final Fantasy Tactics Advance (USA, Australia)"#),
+    "BZ5P" => (3, false, "", "", r#"Final Fantasy V Advance (Europe)"#),
+    "BZ5J" => (3, false, "", "", r#"Final Fantasy V Advance (Japan)"#),
+    "BZ5E" => (3, false, "", "", r#"Final Fantasy V Advance (USA)"#),
+    "BZ6P" => (3, false, "", "", r#"Final Fantasy VI Advance (Europe)"#),
+    "BZ6J" => (3, false, "", "", r#"Final Fantasy VI Advance (Japan)"#),
+    "BZ6E" => (3, false, "", "", r#"Final Fantasy VI Advance (USA)"#),
+    "AFFP" => (1, false, "", "", r#"Final Fight One (Europe)"#),
+    "AFFJ" => (1, false, "", "", r#"Final Fight One (Japan)"#),
+    "AFFE" => (1, false, "", "", r#"Final Fight One (USA)"#),
+    "AFWJ" => (5, false, "", "", r#"Final Fire Pro Wrestling - Yume no Dantai Unei! (Japan)"#),
+    "AZIE" => (0, false, "", "", r#"Finding Nemo (Europe, USA)"#),
+    "AZIP" => (0, false, "", "", r#"Finding Nemo (Europe, USA)"#),
+    "AZID" => (0, false, "", "", r#"Findet Nemo (Germany)"#),
+    "BFNJ" => (0, false, "", "", r#"Finding Nemo (Japan)"#),
+    "BZIE" => (0, false, "", "", r#"Finding Nemo - The Continuing Adventures (Europe, USA)"#),
+    "BZIJ" => (1, false, "", "", r#"Finding Nemo - Aratanaru Bouken (Japan)"#),
+    "BZIX" => (0, false, "", "", r#"Finding Nemo - The Continuing Adventures (Europe, English / Italian / Spanish / Danish / Swedish)"#),
+    "AE7X" => (3, false, "", "", r#"Fire Emblem (Europe, English / French / German)"#),
+    "AE7J" => (3, false, "", "", r#"Fire Emblem - Rekka no Ken (Japan)"#),
+    "AE7E" => (3, false, "", "", r#"Fire Emblem (USA, Australia)"#),
+    "AE7U" => (3, false, "", "", r#"Fire Emblem (USA, Australia)"#),
+    "AFEJ" => (3, false, "", "", r#"Fire Emblem - Fuuin no Tsurugi (Japan)"#),
+    "BE8P" => (3, false, "", "", r#"Fire Emblem - The Sacred Stones (Europe)"#),
+    "BE8J" => (3, false, "", "", r#"Fire Emblem - Seima no Kouseki (Japan)"#),
+    "BE8E" => (3, false, "", "", r#"Fire Emblem - The Sacred Stones (USA, Australia)"#),
+    "BE8U" => (3, false, "", "", r#"Fire Emblem - The Sacred Stones (USA, Australia)"#),
+    "AFPE" => (5, false, "", "", r#"Fire Pro Wrestling (Europe, USA)"#),
+    "AFPJ" => (5, false, "", "", r#"Fire Pro Wrestling A (Japan)"#),
+    "AFYE" => (5, false, "", "", r#"Fire Pro Wrestling 2 (USA)"#),
+    "AFSP" => (0, false, "", "", r#"The Flintstones - Big Trouble in Bedrock (Europe)"#),
+    "AFSE" => (0, false, "", "", r#"The Flintstones - Big Trouble in Bedrock (USA)"#),
+    "BLHP" => (0, false, "", "", r#"Flushed Away (Europe)"#),
+    "BLHE" => (0, false, "", "", r#"Flushed Away (USA)"#),
+    "BF3P" => (0, false, "", "", r#"Ford Racing 3 (Europe)"#),
+    "BF3E" => (0, false, "", "", r#"Ford Racing 3 (USA)"#),
+    "AFMJ" => (3, false, "", "", r#"Formation Soccer 2002 (Japan)"#),
+    "AFOE" => (0, false, "", "", r#"Fortress (Europe, USA)"#),
+    "AFOP" => (0, false, "", "", r#"Fortress (Europe, USA)"#),
+    "BFYE" => (1, false, "", "", r#"Foster's Home for Imaginary Friends (USA)"#),
+    "BFKP" => (1, false, "", "", r#"Franklin the Turtle (Europe, rev. 2)"#),
+    "BFKE" => (1, false, "", "", r#"Franklin the Turtle (USA)"#),
+    "BFLP" => (1, false, "", "", r#"Franklin's Great Adventures (Europe)"#),
+    "BFLE" => (1, false, "", "", r#"Franklin's Great Adventures (USA)"#),
+    "BFSP" => (0, false, "", "", r#"Freekstyle (Europe)"#),
+    "BFSE" => (0, false, "", "", r#"Freekstyle (USA)"#),
+    "ARFP" => (0, false, "", "", r#"Freestyle Scooter (Europe)"#),
+    "AFQP" => (0, false, "", "", r#"Frogger Advance - The Great Quest (Europe)"#),
+    "AFQE" => (0, false, "", "", r#"Frogger Advance - The Great Quest (USA)"#),
+    "AFRP" => (1, false, "", "", r#"Frogger's Adventures - Temple of the Frog (Europe)"#),
+    "AFRE" => (1, false, "", "", r#"Frogger's Adventures - Temple of the Frog (USA)"#),
+    "AFBP" => (1, false, "", "", r#"Frogger's Adventures 2 - The Lost Wand (Europe)"#),
+    "AFBE" => (1, false, "", "", r#"Frogger's Adventures 2 - The Lost Wand (USA)"#),
+    "AFBJ" => (1, false, "", "", r#"Frogger - Mahou no Kuni no Daibouken (Japan)"#),
+    "BFJE" => (2, false, "", "", r#"Frogger's Journey - The Forgotten Relic (USA)"#),
+    "BFJJ" => (2, false, "", "", r#"Frogger - Kodaibunmei no Nazo (Japan)"#),
+    "AUSJ" => (5, false, "", "", r#"From TV Animation One Piece - Mezase! King of Berry (Japan)"#),
+    "AO7J" => (3, false, "", "", r#"From TV Animation One Piece - Nanatsu-jima no Daihihou (Japan)"#),
+    "BCMJ" => (2, false, "", "", r#"Frontier Stories (Japan)"#),
+    "BFDJ" => (2, false, "", "", r#"Fruits Mura no Doubutsu-tachi (Japan)"#),
+    "AF4J" => (1, false, "", "", r#"Fushigi no Kuni no Alice - Alice's Adventures in Wonderland (Japan)"#),
+    "AFAJ" => (3, false, "", "", r#"Fushigi no Kuni no Angelique (Japan)"#),
+    "BFPJ" => (1, false, "", "", r#"Futari wa Pretty Cure - Arienaai! Yume no Sono wa Daimeikyuu (Japan)"#),
+    "BFMJ" => (2, false, "", "", r#"Futari wa Pretty Cure Max Heart - Maji? Maji?! Fight de IN Janai (Japan)"#),
+    "ABIJ" => (5, false, "", "", r#"Gachasute! Dino Device - Blue (Japan)"#),
+    "AAIJ" => (5, false, "", "", r#"Gachasute! Dino Device - Red (Japan)"#),
+    "A4XJ" => (1, false, "", "", r#"Gachasute! Dino Device 2 - Dragon (Japan)"#),
+    "A4WJ" => (1, false, "", "", r#"Gachasute! Dino Device 2 - Phoenix (Japan)"#),
+    "ANYJ" => (3, false, "", "", r#"Gachinko Pro Yakyuu (Japan)"#),
+    "AQ2P" => (3, false, "", "", r#"Gadget Racers (Europe)"#),
+    "AQAE" => (3, false, "", "", r#"Gadget Racers (USA)"#),
+    "BGHJ" => (2, false, "", "", r#"Gakkou no Kaidan - Hyakuyoubako no Fuuin (Japan)"#),
+    "AYSJ" => (2, false, "", "", r#"Gakkou o Tsukurou!! Advance (Japan)"#),
+    "BASJ" => (3, false, "", "", r#"Gakuen Alice - Dokidoki Fushigi Taiken (Japan)"#),
+    "BGSJ" => (1, false, "", "", r#"Gakuen Senki Muryou (Japan)"#),
+    "AGZJ" => (1, false, "", "", r#"Galaxy Angel Game Boy Advance - Moridakusan Tenshi no Full-Course - Okawari Jiyuu (Japan)"#),
+    "AG8E" => (1, false, "", "", r#"Galidor - Defenders of the Outer Dimension (USA)"#),
+    "ATYJ" => (2, false, "", "", r#"Gambler Densetsu Tetsuya - Yomigaeru Densetsu (Japan)"#),
+    "AQWP" => (3, false, "", "", r#"Game & Watch Gallery Advance (Europe)"#),
+    "AQWE" => (3, false, "", "", r#"Game & Watch Gallery 4 (USA)"#),
+    "MGUE" => (0, false, "", "", r#"Game Boy Advance Video - All Grown Up! - Volume 1 (USA)"#),
+    "MCNE" => (0, false, "", "", r#"Game Boy Advance Video - Cartoon Network Collection - Platinum Edition (Europe, USA)"#),
+    "MCPE" => (0, false, "", "", r#"Game Boy Advance Video - Cartoon Network Collection - Premium Edition (Europe, USA)"#),
+    "MCME" => (0, false, "", "", r#"Game Boy Advance Video - Cartoon Network Collection - Special Edition (Europe, USA)"#),
+    "MCTE" => (0, false, "", "", r#"Game Boy Advance Video - Cartoon Network Collection - Volume 1 (USA)"#),
+    "MC2E" => (0, false, "", "", r#"Game Boy Advance Video - Cartoon Network Collection - Volume 2 (Europe, USA)"#),
+    "MKDE" => (0, false, "", "", r#"Game Boy Advance Video - Codename - Kids Next Door - Volume 1 (Europe, USA)"#),
+    "MDCE" => (0, false, "", "", r#"Game Boy Advance Video - Disney Channel Collection - Volume 1 (USA)"#),
+    "MDSE" => (0, false, "", "", r#"Game Boy Advance Video - Disney Channel Collection - Volume 2 (USA)"#),
+    "MDRE" => (0, false, "", "", r#"Game Boy Advance Video - Dora the Explorer - Volume 1 (USA)"#),
+    "MDBE" => (0, false, "", "", r#"Game Boy Advance Video - Dragon Ball GT - Volume 1 (USA)"#),
+    "MN3E" => (0, false, "", "", r#"Game Boy Advance Video - Nicktoons - Volume 3 (USA)"#),
+    "MNCE" => (0, false, "", "", r#"Game Boy Advance Video - Nicktoon's Collection - Volume 1 (USA)"#),
+    "MN2E" => (0, false, "", "", r#"Game Boy Advance Video - Nicktoon's Collection - Volume 2 (USA)"#),
+    "MPAE" => (0, false, "", "", r#"Game Boy Advance Video - Pokémon: For Ho-Oh the Bells Toll! + A Hot Water Battle (USA)"#),
+    "MPBE" => (0, false, "", "", r#"Game Boy Advance Video - Pokémon: Johto Photo Finish + Playing with Fire! (USA)"#),
+    "MPCE" => (0, false, "", "", r#"Game Boy Advance Video - Pokémon: Pokémon - I Choose You + Here Comes the Squirtle Squad (USA)"#),
+    "MPDE" => (0, false, "", "", r#"Game Boy Advance Video - Pokémon: Beach Blank-out Blastoise + Go West Young Meowth (USA)"#),
+    "MSAE" => (0, false, "", "", r#"Game Boy Advance Video - Shark Tale (USA, rev. 6)"#),
+    "MSKE" => (0, false, "", "", r#"Game Boy Advance Video - Shrek (USA, rev. 6)"#),
+    "M2SE" => (0, false, "", "", r#"Game Boy Advance Video - Shrek 2 (USA, rev. 6)"#),
+    "MSTE" => (0, false, "", "", r#"Game Boy Advance Video - Shrek + Shark Tale (USA, rev. 5)"#),
+    "MSHE" => (0, false, "", "", r#"Game Boy Advance Video - Sonic X - Volume 1 (USA)"#),
+    "MSSE" => (0, false, "", "", r#"Game Boy Advance Video - SpongeBob SquarePants - Volume 1 (USA, rev. 1)"#),
+    "MS2E" => (0, false, "", "", r#"Game Boy Advance Video - SpongeBob SquarePants - Volume 2 (USA, rev. 1)"#),
+    "MS3E" => (0, false, "", "", r#"Game Boy Advance Video - SpongeBob SquarePants - Volume 3 (USA)"#),
+    "MSBE" => (0, false, "", "", r#"Game Boy Advance Video - Strawberry Shortcake - Volume 1 (USA)"#),
+    "MSRE" => (0, false, "", "", r#"Game Boy Advance Video - Super Robot Monkey Team - Hyper Force Go! - Volume 1 (USA)"#),
+    "MTME" => (0, false, "", "", r#"Game Boy Advance Video - Teenage Mutant Ninja Turtles - Things Change (Europe, USA)"#),
+    "MTMP" => (0, false, "", "", r#"Game Boy Advance Video - Teenage Mutant Ninja Turtles - Things Change (Europe, USA)"#),
+    "MJME" => (0, false, "", "", r#"Game Boy Advance Video - The Adventures of Jimmy Neutron Boy Genius - Volume 1 (USA)"#),
+    "MFOE" => (0, false, "", "", r#"Game Boy Advance Video - The Fairly OddParents! - Volume 1 (USA)"#),
+    "MF2E" => (0, false, "", "", r#"Game Boy Advance Video - The Fairly OddParents! - Volume 2 (USA, rev. 1)"#),
+    "MFPE" => (0, false, "", "", r#"Game Boy Advance Video - The Proud Family - Volume 1 (USA)"#),
+    "MYGF" => (0, false, "", "", r#"Game Boy Advance Video - Yu-Gi-Oh! - Yugi vs. Joey (France)"#),
+    "MYGE" => (0, false, "", "", r#"Game Boy Advance Video - Yu-Gi-Oh! - Yugi vs. Joey (Europe, USA)"#),
+    "BGWJ" => (5, false, "", "", r#"Game Boy Wars Advance 1+2 (Japan)"#),
+    "BG7E" => (0, false, "", "", r#"Games Explosion! (USA)"#),
+    "BG8J" => (2, false, "", "", r#"Ganbare! Dodge Fighters (Japan)"#),
+    "BGOP" => (0, false, "", "", r#"Garfield - The Search for Pooky (Europe, rev. 2)"#),
+    "BGOE" => (0, false, "", "", r#"Garfield - The Search for Pooky (USA)"#),
+    "BG9P" => (1, false, "", "", r#"Garfield and his Nine Lives (Europe)"#),
+    "BG9E" => (1, false, "", "", r#"Garfield and his Nine Lives (USA)"#),
+    "AYGE" => (1, false, "", "", r#"Gauntlet - Dark Legacy (USA)"#),
+    "BGKJ" => (3, false, "", "", r#"GeGeGe no Kitarou - Kikiippatsu! Youkai Rettou (Japan)"#),
+    "AGEP" => (0, false, "", "", r#"Gekido Advance - Kintaro's Revenge (Europe)"#),
+    "AGEE" => (0, false, "", "", r#"Gekido Advance - Kintaro's Revenge (USA)"#),
+    "ANNJ" => (2, false, "", "", r#"Gekitou Densetsu Noah - Dream Management (Japan)"#),
+    "ACBJ" => (2, false, "", "", r#"Gekitou! Car Battler Go!! (Japan)"#),
+    "AZSE" => (1, false, "", "", r#"Gem Smashers (USA)"#),
+    "BGJJ" => (4, false, "", "", r#"Genseishin Justirisers - Souchaku! Chikyuu no Senshi-tachi (Japan)"#),
+    "BGMJ" => (2, false, "", "", r#"Gensou Maden Saiyuuki - Hangyaku no Toushin-taishi (Japan)"#),
+    "AGKJ" => (0, false, "", "", r#"Gensou Suikoden - Card Stories (Japan)"#),
+    "BGIJ" => (4, false, "", "", r#"Get Ride! Amdriver - Senkou no Hero Tanjou! (Japan)"#),
+    "BGPJ" => (3, false, "", "", r#"Get Ride! Amdriver - Shutsugeki! Battle Party (Japan)"#),
+    "BGBJ" => (2, false, "", "", r#"Get! - Boku no Mushi Tsukamaete (Japan)"#),
+    "BGFJ" => (3, false, "", "", r#"GetBackers Dakkanya - Jagan Fuuin! (Japan)"#),
+    "AGBJ" => (5, false, "", "", r#"GetBackers Dakkanya - Jigoku no Scaramouche (Japan)"#),
+    "A8GJ" => (4, false, "", "", r#"GetBackers Dakkanya - Metropolis Dakkan Sakusen! (Japan)"#),
+    "BR8E" => (1, false, "", "", r#"Ghost Rider (Europe, USA)"#),
+    "BR8P" => (1, false, "", "", r#"Ghost Rider (Europe, USA)"#),
+    "AGVJ" => (1, false, "", "", r#"Ghost Trap (Japan)"#),
+    "B3ZP" => (1, false, "", "", r#"Global Star - Sudoku Fever (Europe)"#),
+    "B3ZE" => (1, false, "", "", r#"Global Star - Sudoku Fever (USA)"#),
+    "BG6P" => (0, false, "", "", r#"Glory Days (Europe)"#),
+    "AGQP" => (1, false, "", "", r#"Go! Go! Beckham! - Adventure on Soccer Island (Europe)"#),
+    "AG4P" => (0, false, "", "", r#"Godzilla - Domination! (Europe)"#),
+    "AG4E" => (0, false, "", "", r#"Godzilla - Domination! (USA)"#),
+    "AGNJ" => (2, false, "", "", r#"Goemon - New Age Shutsudou! (Japan)"#),
+    "AG4J" => (0, false, "", "", r#"Godzilla - Kaijuu Dairantou Advance (Japan)"#),
+    "BGGE" => (0, false, "", "", r#"Golden Nugget Casino (Europe, USA)"#),
+    "BGGP" => (0, false, "", "", r#"Golden Nugget Casino (Europe, USA)"#),
+    "AGSE" => (5, false, "sst", "", r#"Golden Sun (Europe, USA)"#),
+    "AGSP" => (5, false, "sst", "", r#"Golden Sun (Europe, USA)"#),
+    "AGSF" => (5, false, "sst", "", r#"Golden Sun (France)"#),
+    "AGSD" => (5, false, "", "", r#"Golden Sun (Germany)"#),
+    "AGSS" => (5, false, "sst", "", r#"Golden Sun (Spain)"#),
+    "AGFE" => (5, false, "sst", "", r#"Golden Sun - The Lost Age (Europe, USA)"#),
+    "AGFP" => (5, false, "sst", "", r#"Golden Sun - The Lost Age (Europe, USA)"#),
+    "AGFF" => (5, false, "sst", "", r#"Golden Sun - L'Age Perdu (France)"#),
+    "AGAP" => (1, false, "", "", r#"Gradius Advance (Europe)"#),
+    "AGAE" => (1, false, "", "", r#"Gradius Galaxies (USA)"#),
+    "AGAJ" => (1, false, "", "", r#"Gradius Generation (Japan)"#),
+    "BGTP" => (1, false, "", "", r#"Grand Theft Auto Advance (Europe)"#),
+    "BGTE" => (1, false, "", "", r#"Grand Theft Auto Advance (USA)"#),
+    "AG9J" => (3, false, "", "", r#"Greatest Nine (Japan)"#),
+    "BUSE" => (1, false, "", "", r#"Green Eggs and Ham by Dr. Seuss (USA)"#),
+    "AGGP" => (0, false, "", "", r#"Gremlins - Stripe vs Gizmo (Europe)"#),
+    "AGGE" => (0, false, "", "", r#"Gremlins - Stripe vs Gizmo (USA)"#),
+    "BIEE" => (1, false, "", "", r#"The Grim Adventures of Billy & Mandy (USA)"#),
+    "ARVJ" => (3, false, "", "", r#"Groove Adventure Rave - Hikari to Yami no Daikessen (Japan)"#),
+    "ARIJ" => (3, false, "", "", r#"Groove Adventure Rave - Hikari to Yami no Daikessen 2 (Japan)"#),
+    "ACAE" => (0, false, "", "", r#"GT Advance - Championship Racing (Europe, USA)"#),
+    "ACAP" => (0, false, "", "", r#"GT Advance - Championship Racing (Europe, USA)"#),
+    "AGWP" => (1, false, "", "", r#"GT Advance 2 - Rally Racing (Europe)"#),
+    "AGWE" => (1, false, "", "", r#"GT Advance 2 - Rally Racing (USA)"#),
+    "A2GP" => (1, false, "", "", r#"GT Advance 3 - Pro Concept Racing (Europe)"#),
+    "A2GE" => (1, false, "", "", r#"GT Advance 3 - Pro Concept Racing (USA)"#),
+    "BJAP" => (0, false, "", "", r#"GT Racers (Europe)"#),
+    "AGXP" => (5, false, "", "", r#"Guilty Gear X - Advance Edition (Europe)"#),
+    "AGXJ" => (5, false, "", "", r#"Guilty Gear X - Advance Edition (Japan)"#),
+    "AGXE" => (5, false, "", "", r#"Guilty Gear X - Advance Edition (USA)"#),
+    "BGVE" => (1, false, "", "", r#"Gumby vs. the Astrobots (USA)"#),
+    "BHGP" => (2, false, "", "", r#"Gunstar Future Heroes (Europe)"#),
+    "BGXJ" => (2, false, "", "", r#"Gunstar Super Heroes (Japan)"#),
+    "BHGE" => (2, false, "", "", r#"Gunstar Super Heroes (USA)"#),
+    "AIBJ" => (3, false, "", "", r#"Guranbo (Japan)"#),
+    "AGCJ" => (5, false, "", "", r#"Guru Logic Champ (Japan)"#),
+    "ASBJ" => (3, false, "", "", r#"Gyakuten Saiban (Japan, rev. 1)"#),
+    "A3GJ" => (3, false, "", "", r#"Gyakuten Saiban 2 (Japan)"#),
+    "A3JJ" => (3, false, "", "", r#"Gyakuten Saiban 3 (Japan)"#),
+    "A8EJ" => (1, false, "", "", r#"Hachiemon (Japan)"#),
+    "BHRJ" => (1, false, "", "", r#"Hagane no Renkinjutsushi - FullMetal Alchemist - Meisou no Rondo (Japan)"#),
+    "BH2J" => (1, false, "", "", r#"Hagane no Renkinjutsushi - FullMetal Alchemist - Omoide no Sonata (Japan)"#),
+    "A2HJ" => (2, false, "", "", r#"Hajime no Ippo - The Fighting! (Japan)"#),
+    "AM7J" => (3, false, "", "", r#"Hamepane - Tokyo Mew Mew (Japan)"#),
+    "AJHJ" => (3, false, "", "", r#"Hamster Club 3 (Japan)"#),
+    "A4KJ" => (3, false, "", "", r#"Hamster Club 4 (Japan)"#),
+    "AHBJ" => (3, false, "", "", r#"Hamster Monogatari 2 GBA (Japan)"#),
+    "A83J" => (2, false, "", "", r#"Hamster Monogatari 3 GBA (Japan)"#),
+    "BHSJ" => (1, false, "", "", r#"Hamster Monogatari 3EX, 4, Special (Japan)"#),
+    "BHCJ" => (2, false, "", "", r#"Hamster Monogatari Collection (Japan)"#),
+    "A82J" => (2, false, "", "", r#"Hamster Paradise - Pure Heart (Japan)"#),
+    "AHAJ" => (2, false, "", "", r#"Hamster Paradise Advanchu (Japan)"#),
+    "B85P" => (3, false, "", "", r#"Hamtaro - Ham-Ham Games (Europe)"#),
+    "B85A" => (3, false, "", "", r#"Hamtaro - Ham-Ham Games (USA) ~ Tottoko Hamtarou: Ham Ham Sports (Japan)"#),
+    "B85E" => (3, false, "", "", r#"Hamtaro - Ham-Ham Games (USA) ~ Tottoko Hamtarou: Ham Ham Sports (Japan)"#),
+    "AH3P" => (3, false, "", "", r#"Hamtaro - Ham-Ham Heartbreak (Europe)"#),
+    "AH3E" => (3, false, "", "", r#"Hamtaro - Ham-Ham Heartbreak (USA)"#),
+    "A84P" => (3, false, "", "", r#"Hamtaro - Rainbow Rescue (Europe)"#),
+    "BHAJ" => (4, false, "", "", r#"Hanabi Hyakkei Advance (Japan)"#),
+    "ADYJ" => (1, false, "", "", r#"Hanafuda Trump Mahjong - Depachika Wayouchuu (Japan)"#),
+    "BH3P" => (1, false, "", "", r#"Happy Feet (Europe)"#),
+    "BH3E" => (1, false, "", "", r#"Happy Feet (USA)"#),
+    "AH6E" => (1, false, "", "", r#"Hardcore Pinball (Europe, USA)"#),
+    "BHOP" => (0, false, "", "", r#"Hardcore Pool (Europe)"#),
+    "BHOF" => (0, false, "", "", r#"Hardcore Pool (France)"#),
+    "BHNP" => (0, false, "", "", r#"Harlem Globetrotters - World Tour (Europe)"#),
+    "BHNE" => (0, false, "", "", r#"Harlem Globetrotters - World Tour (USA)"#),
+    "AHQJ" => (3, false, "", "", r#"Harobots - Robo Hero Battling!! (Japan)"#),
+    "A7HE" => (1, false, "", "", r#"Harry Potter and the Chamber of Secrets (Europe, USA)"#),
+    "A7HP" => (1, false, "", "", r#"Harry Potter and the Chamber of Secrets (Europe, USA)"#),
+    "BHTE" => (2, false, "", "", r#"Harry Potter and the Prisoner of Azkaban (Europe, USA)"#),
+    "BHTP" => (2, false, "", "", r#"Harry Potter and the Prisoner of Azkaban (Europe, USA)"#),
+    "AHRE" => (1, false, "", "", r#"Harry Potter and the Philosopher's Stone (Europe)"#),
+    "AHRP" => (1, false, "", "", r#"Harry Potter and the Philosopher's Stone (Europe)"#),
+    "AHRJ" => (1, false, "", "", r#"Harry Potter to Kenja no Ishi (Japan)"#),
+    "A7HJ" => (1, false, "", "", r#"Harry Potter to Himitsu no Heya (Japan)"#),
+    "BHPE" => (1, false, "", "", r#"Harry Potter - Quidditch World Cup (Europe, USA)"#),
+    "BHPP" => (1, false, "", "", r#"Harry Potter - Quidditch World Cup (Europe, USA)"#),
+    "BHPJ" => (1, false, "", "", r#"Harry Potter - Quidditch World Cup (Japan)"#),
+    "BHTJ" => (2, false, "", "", r#"Harry Potter to Azkaban no Shuujin (Japan)"#),
+    "BH8E" => (1, false, "", "", r#"Harry Potter and the Goblet of Fire (Europe, USA)"#),
+    "BH8P" => (1, false, "", "", r#"Harry Potter and the Goblet of Fire (Europe, USA)"#),
+    "BJXP" => (1, false, "", "", r#"Harry Potter and the Order of the Phoenix (Europe)"#),
+    "A4NP" => (3, false, "", "", r#"Harvest Moon - Friends of Mineral Town (Europe)"#),
+    "A4ND" => (3, false, "", "", r#"Harvest Moon - Friends of Mineral Town (Germany)"#),
+    "AN5E" => (3, false, "", "", r#"Harvest Moon - Friends of Mineral Town (USA)"#),
+    "BFGE" => (3, false, "", "", r#"Harvest Moon - More Friends of Mineral Town (USA)"#),
+    "AHSJ" => (3, false, "", "", r#"Hatena Satena (Japan)"#),
+    "BHJP" => (1, false, "", "", r#"Heidi (Europe)"#),
+    "B86P" => (0, false, "", "", r#"Hello Kitty - Happy Party Pals (Europe, English)"#),
+    "B86E" => (0, false, "", "", r#"Hello Kitty - Happy Party Pals (USA)"#),
+    "AKTJ" => (2, false, "", "", r#"Hello Kitty Collection - Miracle Fashion Maker (Japan)"#),
+    "BHDJ" => (2, false, "", "", r#"Hello! Idol Debut - Kids Idol Ikusei Game (Japan)"#),
+    "B8FP" => (1, false, "", "", r#"Herbie - Fully Loaded (Europe)"#),
+    "B8FE" => (1, false, "", "", r#"Herbie - Fully Loaded (USA)"#),
+    "AAEP" => (0, false, "", "", r#"Hey Arnold! - The Movie (Europe)"#),
+    "AAEE" => (0, false, "", "", r#"Hey Arnold! - The Movie (USA)"#),
+    "BHHP" => (1, false, "", "", r#"Hi Hi Puffy AmiYumi - Kaznapped! (Europe)"#),
+    "BHHJ" => (1, false, "", "", r#"Hi Hi Puffy AmiYumi (Japan)"#),
+    "BHHE" => (1, false, "", "", r#"Hi Hi Puffy AmiYumi - Kaznapped! (USA)"#),
+    "AHZJ" => (2, false, "", "", r#"Higanbana (Japan, rev. 1)"#),
+    "ASSE" => (1, false, "", "", r#"High Heat Major League Baseball 2002 (Europe, USA)"#),
+    "AHHE" => (2, false, "", "", r#"High Heat Major League Baseball 2003 (USA)"#),
+    "AHXJ" => (2, false, "", "", r#"High Heat Major League Baseball 2003 (Japan)"#),
+    "BJ2E" => (1, false, "", "", r#"High School Musical (USA)"#),
+    "AHKJ" => (3, false, "", "", r#"Hikaru no Go (Japan)"#),
+    "AKEJ" => (3, false, "", "", r#"Hikaru no Go 2 (Japan)"#),
+    "BNBJ" => (2, false, "", "", r#"Himawari Doubutsu Byouin - Pet no Oishasan Ikusei Game (Japan)"#),
+    "A3HJ" => (3, false, "", "", r#"Hime Kishi Monogatari - Princess Blue (Japan)"#),
+    "AHIJ" => (2, false, "", "", r#"Hitsuji no Kimochi. (Japan)"#),
+    "AH9P" => (1, false, "", "", r#"The Hobbit (Europe)"#),
+    "AH9E" => (1, false, "", "", r#"The Hobbit (USA)"#),
+    "AH9J" => (1, false, "", "", r#"Hobbit no Bouken - Lord of the Rings - Hajimari no Monogatari (Japan)"#),
+    "BHMP" => (0, false, "", "", r#"Disney's Home on the Range (Europe)"#),
+    "BHME" => (0, false, "", "", r#"Disney's Home on the Range (USA)"#),
+    "BHUE" => (2, false, "", "", r#"Horsez (USA)"#),
+    "B8KJ" => (3, false, "", "", r#"Hoshi no Kirby - Kagami no Daimeikyuu (Japan, rev. 1)"#),
+    "A7KJ" => (3, false, "", "", r#"Hoshi no Kirby - Yume no Izumi Deluxe (Japan)"#),
+    "AHPP" => (1, false, "", "", r#"Hot Potato! (Europe, English)"#),
+    "AHPE" => (1, false, "", "", r#"Hot Potato! (USA)"#),
+    "BHXP" => (0, false, "", "", r#"Hot Wheels - All Out (Europe)"#),
+    "BHXE" => (0, false, "", "", r#"Hot Wheels - All Out (USA)"#),
+    "AHWP" => (1, false, "", "", r#"Hot Wheels - Burnin' Rubber (Europe)"#),
+    "AHWE" => (1, false, "", "", r#"Hot Wheels - Burnin' Rubber (USA)"#),
+    "BHEE" => (0, false, "", "", r#"Hot Wheels - Stunt Track Challenge (Europe, USA)"#),
+    "BHEP" => (0, false, "", "", r#"Hot Wheels - Stunt Track Challenge (Europe, USA)"#),
+    "AH8P" => (0, false, "", "", r#"Hot Wheels - Velocity X (Europe)"#),
+    "AH8E" => (0, false, "", "", r#"Hot Wheels - Velocity X (USA)"#),
+    "BHWP" => (0, false, "", "", r#"Hot Wheels - World Race (Europe)"#),
+    "BHWE" => (0, false, "", "", r#"Hot Wheels - World Race (USA)"#),
+    "AHWJ" => (1, false, "", "", r#"Hot Wheels Advance (Japan)"#),
+    "B7IJ" => (0, false, "", "", r#"Hudson Best Collection Vol. 1 - Bomberman Collection (Japan)"#),
+    "B72J" => (0, false, "", "", r#"Hudson Best Collection Vol. 2 - Lode Runner Collection (Japan)"#),
+    "B73J" => (0, false, "", "", r#"Hudson Best Collection Vol. 3 - Action Collection (Japan)"#),
+    "B74J" => (0, false, "", "", r#"Hudson Best Collection Vol. 4 - Nazotoki Collection (Japan)"#),
+    "B75J" => (0, false, "", "", r#"Hudson Best Collection Vol. 5 - Shooting Collection (Japan)"#),
+    "B76J" => (0, false, "", "", r#"Hudson Best Collection Vol. 6 - Bouken-jima Collection (Japan)"#),
+    "AZHP" => (0, false, "", "", r#"Hugo - Bukkazoom! (Europe)"#),
+    "AHJP" => (1, false, "", "", r#"Hugo - The Evil Mirror Advance (Europe)"#),
+    "AHJE" => (1, false, "", "", r#"Hugo - The Evil Mirror Advance (USA)"#),
+    "A8NJ" => (3, false, "", "", r#"Hunter X Hunter - Minna Tomodachi Daisakusen!! (Japan, rev. 1)"#),
+    "AWIJ" => (2, false, "", "", r#"Hyper Sports 2002 Winter (Japan)"#),
+    "A4CE" => (1, false, "", "", r#"I Spy Challenger! (USA)"#),
+    "AIAP" => (0, false, "", "", r#"Ice Age (Europe)"#),
+    "AIAJ" => (0, false, "", "", r#"Ice Age (Japan)"#),
+    "AIAE" => (0, false, "", "", r#"Ice Age (USA)"#),
+    "BIAP" => (1, false, "", "", r#"Ice Age 2 - The Meltdown (Europe)"#),
+    "BIAE" => (1, false, "", "", r#"Ice Age 2 - The Meltdown (USA)"#),
+    "AR3E" => (1, false, "", "", r#"Ice Nine (Europe, USA)"#),
+    "AHLP" => (1, false, "", "", r#"The Incredible Hulk (Europe)"#),
+    "AHLE" => (1, false, "", "", r#"The Incredible Hulk (USA)"#),
+    "BICE" => (0, false, "", "", r#"The Incredibles (Europe, USA)"#),
+    "BICP" => (0, false, "", "", r#"The Incredibles (Europe, USA)"#),
+    "BICX" => (0, false, "", "", r#"The Incredibles (Europe, French / Dutch)"#),
+    "BIQE" => (0, false, "", "", r#"The Incredibles - Rise of the Underminer (Europe, USA)"#),
+    "BIQP" => (0, false, "", "", r#"The Incredibles - Rise of the Underminer (Europe, USA)"#),
+    "AINJ" => (3, false, "", "", r#"Initial D - Another Stage (Japan)"#),
+    "AIGP" => (0, false, "", "", r#"Inspector Gadget - Advance Mission (Europe)"#),
+    "AIGE" => (0, false, "", "", r#"Inspector Gadget - Advance Mission (USA)"#),
+    "AIRP" => (0, false, "", "", r#"Inspector Gadget Racing (Europe)"#),
+    "AIKP" => (0, false, "", "", r#"International Karate Advanced (Europe)"#),
+    "A3KP" => (0, false, "", "", r#"International Karate Plus (Europe)"#),
+    "A3KE" => (0, false, "", "", r#"International Karate Plus (USA)"#),
+    "AISP" => (2, false, "", "", r#"International Superstar Soccer (Europe)"#),
+    "AY2P" => (2, false, "", "", r#"International Superstar Soccer Advance (Europe)"#),
+    "AI9J" => (3, false, "", "", r#"Inukko Club (Japan)"#),
+    "AIYJ" => (3, false, "", "", r#"Inuyasha - Naraku no Wana! Mayoi no Mori no Shoutaijou (Japan)"#),
+    "AIVP" => (0, false, "", "", r#"Invader (Europe)"#),
+    "AIOE" => (1, false, "", "", r#"The Invincible Iron Man (Europe, USA)"#),
+    "AI3E" => (0, false, "", "", r#"Iridion 3D (Europe, USA)"#),
+    "AI3P" => (0, false, "", "", r#"Iridion 3D (Europe, USA)"#),
+    "AI2P" => (0, false, "", "", r#"Iridion II (Europe)"#),
+    "AI2E" => (0, false, "", "", r#"Iridion II (USA)"#),
+    "AIEJ" => (3, false, "", "", r#"Isseki Hatchou - Kore 1ppon de 8shurui! (Japan)"#),
+    "BPIE" => (2, false, "", "", r#"It's Mr. Pants (Europe, USA)"#),
+    "BPIP" => (2, false, "", "", r#"It's Mr. Pants (Europe, USA)"#),
+    "AJPJ" => (3, false, "", "", r#"J.League Pocket (Japan, rev. 1)"#),
+    "AJ2J" => (3, false, "", "", r#"J.League Pocket 2 (Japan)"#),
+    "AC2J" => (3, false, "", "", r#"J.League Pro Soccer Club o Tsukurou! Advance (Japan)"#),
+    "A2JJ" => (2, false, "", "", r#"J.League Winning Eleven Advance 2002 (Japan)"#),
+    "AJCE" => (1, false, "", "", r#"Jackie Chan Adventures - Legend of the Darkhand (Europe, USA)"#),
+    "AJCP" => (1, false, "", "", r#"Jackie Chan Adventures - Legend of the Darkhand (Europe, USA)"#),
+    "BNJJ" => (1, false, "", "", r#"Jajamaru Jr. Denshouki - Jalecolle mo Arisourou (Japan)"#),
+    "AJDP" => (0, false, "", "", r#"James Pond - Codename RoboCod (Europe)"#),
+    "AJDE" => (0, false, "", "", r#"James Pond - Codename RoboCod (USA)"#),
+    "AJJE" => (1, false, "", "", r#"Jazz Jackrabbit (Europe, USA)"#),
+    "AJRE" => (1, false, "", "", r#"Jet Grind Radio (USA)"#),
+    "AJRP" => (1, false, "", "", r#"Jet Set Radio (Europe)"#),
+    "AGMJ" => (5, false, "", "", r#"JGTO Kounin Golf Master Mobile - Japan Golf Tour Game (Japan)"#),
+    "AJWJ" => (2, false, "", "", r#"Jikkyou World Soccer Pocket (Japan)"#),
+    "AJKJ" => (2, false, "", "", r#"Jikkyou World Soccer Pocket 2 (Japan)"#),
+    "AJNP" => (0, false, "", "", r#"Jimmy Neutron Boy Genius (Europe)"#),
+    "AJNE" => (0, false, "", "", r#"Jimmy Neutron Boy Genius (USA)"#),
+    "AZGJ" => (3, false, "", "", r#"Jinsei Game Advance (Japan)"#),
+    "AJUJ" => (5, false, "", "", r#"Jissen Pachi-Slot Hisshouhou! - Juuou Advance (Japan)"#),
+    "AJME" => (1, false, "", "", r#"Jonny Moseley Mad Trix (USA)"#),
+    "BJKP" => (1, false, "", "", r#"Juka and the Monophonic Menace (Europe)"#),
+    "BJKE" => (1, false, "", "", r#"Juka and the Monophonic Menace (USA)"#),
+    "AJFP" => (0, false, "", "", r#"Disney's The Jungle Book 2 (Europe)"#),
+    "AJFE" => (0, false, "", "", r#"Disney's The Jungle Book (USA)"#),
+    "AJQP" => (1, false, "", "", r#"Jurassic Park III - Dino Attack (Europe)"#),
+    "AJQJ" => (1, false, "", "", r#"Jurassic Park III - Advanced Action (Japan)"#),
+    "AJQE" => (1, false, "", "", r#"Jurassic Park III - Island Attack (USA)"#),
+    "AJ3J" => (3, false, "", "", r#"Jurassic Park III - Kyouryuu ni Ainiikou! (Japan)"#),
+    "AJ3P" => (3, false, "", "", r#"Jurassic Park III - Park Builder (Europe)"#),
+    "AJ3E" => (3, false, "", "", r#"Jurassic Park III - Park Builder (USA)"#),
+    "ADNP" => (1, false, "", "", r#"Jurassic Park III - The DNA Factor (Europe)"#),
+    "ADNE" => (1, false, "", "", r#"Jurassic Park III - The DNA Factor (USA)"#),
+    "ADNJ" => (1, false, "", "", r#"Jurassic Park III - Ushinawareta Idenshi (Japan)"#),
+    "AJ8J" => (1, false, "", "", r#"Jurassic Park Institute Tour - Dinosaur Rescue (Japan)"#),
+    "AJLP" => (1, false, "", "", r#"Justice League - Injustice for All (Europe)"#),
+    "AJLE" => (1, false, "", "", r#"Justice League - Injustice for All (USA)"#),
+    "BJLE" => (1, false, "", "", r#"Justice League Chronicles (USA)"#),
+    "BJHP" => (1, false, "", "", r#"Justice League Heroes - The Flash (Europe)"#),
+    "BJHE" => (1, false, "", "", r#"Justice League Heroes - The Flash (USA)"#),
+    "AKVJ" => (2, false, "", "", r#"K-1 Pocket Grand Prix (Japan)"#),
+    "A2OJ" => (2, false, "", "", r#"K-1 Pocket Grand Prix 2 (Japan)"#),
+    "AKDJ" => (3, false, "", "", r#"Kaeru B Back (Japan)"#),
+    "BKOJ" => (2, false, "", "", r#"Kaiketsu Zorori to Mahou no Yuuenchi - Ohimesama o Sukue! (Japan)"#),
+    "AKZJ" => (5, false, "", "", r#"Kamaitachi no Yoru Advance (Japan)"#),
+    "AG2J" => (3, false, "", "", r#"Kami no Kijutsu - Illusion of the Evil Eyes (Japan)"#),
+    "AKKP" => (0, false, "", "", r#"Kao the Kangaroo (Europe)"#),
+    "AKKE" => (0, false, "", "", r#"Kao the Kangaroo (USA)"#),
+    "BK8J" => (3, false, "", "", r#"Kappa no Kai-kata - Kaatan Daibouken! (Japan)"#),
+    "AYKE" => (4, false, "", "", r#"Karnaaj Rally (Europe, USA)"#),
+    "BN4J" => (3, false, "", "", r#"Kawa no Nushi Tsuri 3 & 4 (Japan)"#),
+    "AN5J" => (2, false, "", "", r#"Kawa no Nushi Tsuri 5 - Fushigi no Mori kara (Japan)"#),
+    "B82J" => (1, false, "", "", r#"Kawaii Koinu Wonderful (Japan)"#),
+    "BKGJ" => (1, false, "", "", r#"Kawaii Pet Game Gallery (Japan)"#),
+    "BKPJ" => (1, false, "", "", r#"Kawaii Pet Game Gallery 2 (Japan)"#),
+    "A63J" => (2, false, "", "", r#"Kawaii Pet Shop Monogatari 3 (Japan)"#),
+    "AKLJ" => (1, false, "", "", r#"Kaze no Klonoa - Yumemiru Teikoku (Japan)"#),
+    "AN6J" => (1, false, "", "", r#"Kaze no Klonoa G2 - Dream Champ Tournament (Japan)"#),
+    "ATPJ" => (5, false, "sst", "", r#"Keitai Denjuu Telefang 2 - Power (Japan)"#),
+    "ATSJ" => (5, false, "", "", r#"Keitai Denjuu Telefang 2 - Speed (Japan)"#),
+    "AS3E" => (1, false, "", "", r#"Kelly Slater's Pro Surfer (Europe, USA)"#),
+    "AS3P" => (1, false, "", "", r#"Kelly Slater's Pro Surfer (Europe, USA)"#),
+    "BKJJ" => (3, false, "", "", r#"Keroro Gunsou - Taiketsu! Gekisou Keronprix Daisakusen de Arimasu!! (Japan)"#),
+    "BG2J" => (1, false, "", "", r#"Kessaku Sen! - Ganbare Goemon 1, 2 - Yuki Hime to Magginesu (Japan)"#),
+    "BYLP" => (1, false, "", "", r#"Kid Paddle (Europe)"#),
+    "BCXE" => (0, false, "", "", r#"Kid's Cards (USA)"#),
+    "BH6J" => (1, false, "", "", r#"Kidou Gekidan Haro Ichiza Haro no Puyo Puyo (Japan)"#),
+    "BGNJ" => (2, false, "", "", r#"Kidou Senshi Gundam Seed - Tomo to Kimi to Koko de. (Japan)"#),
+    "B42J" => (1, false, "", "", r#"Kidou Senshi Gundam Seed Destiny (Japan)"#),
+    "AALJ" => (2, false, "", "", r#"Kidou Tenshi Angelic Layer - Misaki to Yume no Tenshi-tachi (Japan)"#),
+    "AKGJ" => (2, false, "", "", r#"Kikaika Guntai - Mech Platoon (Japan)"#),
+    "AKIJ" => (0, false, "", "", r#"Kiki KaiKai Advance (Japan)"#),
+    "BKHP" => (1, false, "", "", r#"Kill.Switch (Europe)"#),
+    "BKHE" => (1, false, "", "", r#"Kill.Switch (USA)"#),
+    "B3LP" => (0, false, "", "", r#"Killer 3D Pool (Europe)"#),
+    "B3LE" => (0, false, "", "", r#"Killer 3D Pool (USA)"#),
+    "AEYP" => (0, false, "", "", r#"Disney's Kim Possible (Europe, rev. 1)"#),
+    "AEYE" => (0, false, "", "", r#"Disney's Kim Possible (USA)"#),
+    "BKMJ" => (1, false, "", "", r#"Disney's Kim Possible (Japan)"#),
+    "BKMP" => (1, false, "", "", r#"Disney's Kim Possible 2 - Drakken's Demise (Europe)"#),
+    "BKME" => (1, false, "", "", r#"Disney's Kim Possible 2 - Drakken's Demise (USA)"#),
+    "BQPE" => (1, false, "", "", r#"Disney's Kim Possible III - Team Possible (USA)"#),
+    "BKQP" => (1, false, "", "", r#"King Kong - The Official Game of the Movie (Europe, English / French / German / Spanish / Italian / Dutch)"#),
+    "BKQX" => (1, false, "", "", r#"King Kong - The Official Game of the Movie (Europe, English / Swedish / Norwegian / Danish / Finnish)"#),
+    "AKOP" => (1, false, "", "", r#"The King of Fighters EX - NeoBlood (Europe)"#),
+    "AKOJ" => (1, false, "", "", r#"The King of Fighters EX - NeoBlood (Japan)"#),
+    "AKOE" => (1, false, "", "", r#"The King of Fighters EX - NeoBlood (USA, rev. 1)"#),
+    "AEXP" => (1, false, "", "", r#"The King of Fighters EX2 - Howling Blood (Europe)"#),
+    "AEXJ" => (1, false, "", "", r#"The King of Fighters EX2 - Howling Blood (Japan, rev. 1)"#),
+    "AEXE" => (1, false, "", "", r#"The King of Fighters EX2 - Howling Blood (USA)"#),
+    "B8CP" => (3, false, "", "", r#"Kingdom Hearts - Chain of Memories (Europe)"#),
+    "B8CJ" => (3, false, "", "", r#"Kingdom Hearts - Chain of Memories (Japan)"#),
+    "B8CE" => (3, false, "", "", r#"Kingdom Hearts - Chain of Memories (USA)"#),
+    "AK5J" => (3, false, "", "", r#"Kinniku Banzuke - Kimero! Kiseki no Kanzen Seiha (Japan)"#),
+    "AK4J" => (3, false, "", "", r#"Kinniku Banzuke - Kongou-kun no Daibouken! (Japan)"#),
+    "AK2J" => (1, false, "", "", r#"Kinnikuman II-Sei - Seigi Choujin e no Michi (Japan)"#),
+    "B8KP" => (3, false, "", "", r#"Kirby & the Amazing Mirror (Europe)"#),
+    "B8KE" => (3, false, "", "", r#"Kirby & the Amazing Mirror (USA)"#),
+    "A7KP" => (3, false, "", "", r#"Kirby - Nightmare in Dream Land (Europe)"#),
+    "A7KE" => (3, false, "", "", r#"Kirby - Nightmare in Dream Land (USA)"#),
+    "A2VJ" => (2, false, "", "", r#"Kisekko Gurumii - Chesty to Nuigurumi-tachi no Mahou no Bouken (Japan)"#),
+    "B2KJ" => (2, false, "", "", r#"Kiss x Kiss Seirei Gakuen (Japan)"#),
+    "AKMJ" => (3, false, "", "", r#"Kiwame Mahjong Deluxe - Mirai Senshi 21 (Japan)"#),
+    "AKLP" => (1, false, "", "", r#"Klonoa - Empire of Dreams (Europe)"#),
+    "AKLE" => (1, false, "", "", r#"Klonoa - Empire of Dreams (USA)"#),
+    "AN6E" => (1, false, "", "", r#"Klonoa 2 - Dream Champ Tournament (USA)"#),
+    "AK7J" => (2, false, "", "", r#"Klonoa Heroes - Densetsu no Star Medal (Japan)"#),
+    "BKNP" => (0, false, "", "", r#"Knights' Kingdom (Europe)"#),
+    "BKNE" => (0, false, "", "", r#"Knights' Kingdom (USA)"#),
+    "BAKP" => (0, false, "", "", r#"The Koala Brothers - Outback Adventures (Europe)"#),
+    "BAKE" => (0, false, "", "", r#"The Koala Brothers - Outback Adventures (USA)"#),
+    "BDIJ" => (1, false, "", "", r#"Koinu to Issho - Aijou Monogatari (Japan)"#),
+    "BI2J" => (1, false, "", "", r#"Koinu to Issho 2 (Japan)"#),
+    "BISJ" => (2, false, "", "", r#"Koinu-chan no Hajimete no Osanpo - Koinu no Kokoro Ikusei Game (Japan)"#),
+    "AKCP" => (0, false, "", "", r#"Konami Collector's Series - Arcade Classics (Europe)"#),
+    "AKCJ" => (0, false, "", "", r#"Konami Arcade Game Collection (Japan)"#),
+    "AKCE" => (0, false, "", "", r#"Konami Collector's Series - Arcade Advanced (USA)"#),
+    "AKWP" => (3, false, "", "", r#"Konami Krazy Racers (Europe)"#),
+    "AKWE" => (3, false, "", "", r#"Konami Krazy Racers (USA)"#),
+    "AKWJ" => (3, false, "", "", r#"Konami Wai Wai Racing Advance (Japan)"#),
+    "BQBJ" => (2, false, "", "", r#"Konchuu Monster Battle Master (Japan)"#),
+    "BQSJ" => (2, false, "", "", r#"Konchuu Monster Battle Stadium (Japan)"#),
+    "BQKJ" => (1, false, "", "", r#"Konchuu no Mori no Daibouken - Fushigi na Sekai no Juunin-tachi (Japan)"#),
+    "BK7P" => (0, false, "", "", r#"Kong - King of Atlantis (Europe)"#),
+    "BK7E" => (0, false, "", "", r#"Kong - King of Atlantis (USA)"#),
+    "BKQE" => (1, false, "", "", r#"Kong - The 8th Wonder of the World (USA)"#),
+    "AKQE" => (0, false, "", "", r#"Kong - The Animated Series (USA)"#),
+    "BKBJ" => (3, false, "", "", r#"Konjiki no Gashbell!! - Makai no Bookmark (Japan)"#),
+    "A4GJ" => (2, false, "", "", r#"Konjiki no Gashbell!! - Unare! Yuujou no Zakeru (Japan)"#),
+    "BGYJ" => (1, false, "", "", r#"Konjiki no Gashbell!! - Unare! Yuujou no Zakeru 2 (Japan)"#),
+    "BKEJ" => (2, false, "", "", r#"Konjiki no Gashbell!! The Card Battle for GBA (Japan)"#),
+    "BUDJ" => (1, false, "", "", r#"Konjiki no Gashbell!! Yuujou no Zakeru - Dream Tag Tournament (Japan)"#),
+    "KHPJ" => (2, false, "", "tilt", r#"Koro Koro Puzzle - Happy Panechu! (Japan)"#),
+    "A8MJ" => (3, false, "", "", r#"Kotoba no Puzzle - Mojipittan Advance (Japan)"#),
+    "BK6J" => (3, false, "", "", r#"Kouchu Ouja Mushiking - Greatest Champion e no Michi (Japan)"#),
+    "A54J" => (3, false, "", "", r#"Koukou Juken Advance Series Eigo Koubun Hen - 26 Units Shuuroku (Japan)"#),
+    "A53J" => (3, false, "", "", r#"Koukou Juken Advance Series Eijukugo Hen - 650 Phrases Shuuroku (Japan)"#),
+    "A52J" => (3, false, "", "", r#"Koukou Juken Advance Series Eitango Hen - 2000 Words Shuuroku (Japan)"#),
+    "BKTJ" => (1, false, "", "", r#"Koutetsu Teikoku from HOT・B (Japan)"#),
+    "B9AJ" => (0, false, "", "", r#"Kunio Kun Nekketsu Collection 1 (Japan)"#),
+    "B9BJ" => (0, false, "", "", r#"Kunio Kun Nekketsu Collection 2 (Japan)"#),
+    "B9CJ" => (0, false, "", "", r#"Kunio Kun Nekketsu Collection 3 (Japan)"#),
+    "AGOJ" => (3, false, "", "", r#"Kurohige no Golf Shiyouyo (Japan)"#),
+    "AKUJ" => (3, false, "", "", r#"Kurohige no Kurutto Jintori (Japan)"#),
+    "AKRP" => (3, false, "", "", r#"Kurukuru Kururin (Europe)"#),
+    "AKRJ" => (3, false, "", "", r#"Kurukuru Kururin (Japan)"#),
+    "A9QJ" => (3, false, "", "", r#"Kururin Paradise (Japan)"#),
+    "ALDP" => (1, false, "", "", r#"Lady Sia (Europe)"#),
+    "ALDE" => (1, false, "", "", r#"Lady Sia (USA)"#),
+    "ALAP" => (0, false, "", "", r#"The Land Before Time (Europe)"#),
+    "ALAE" => (0, false, "", "", r#"The Land Before Time (USA)"#),
+    "BLOP" => (1, false, "", "", r#"The Land Before Time - Into the Mysterious Beyond (Europe)"#),
+    "BLOE" => (1, false, "", "", r#"The Land Before Time - Into the Mysterious Beyond (USA)"#),
+    "BL8P" => (1, false, "", "", r#"Lara Croft Tomb Raider - Legend (Europe)"#),
+    "BL8E" => (1, false, "", "", r#"Lara Croft Tomb Raider - Legend (USA)"#),
+    "AL9P" => (0, false, "", "", r#"Lara Croft Tomb Raider - The Prophecy (Europe)"#),
+    "AUTJ" => (0, false, "", "", r#"Lara Croft Tomb Raider - The Prophecy (Japan)"#),
+    "AL9E" => (0, false, "", "", r#"Lara Croft Tomb Raider - The Prophecy (USA)"#),
+    "AVDJ" => (3, false, "", "", r#"Legend of Dynamic Goushouden - Houkai no Rondo (Japan)"#),
+    "B3YP" => (2, false, "", "", r#"The Legend of Spyro - A New Beginning (Europe)"#),
+    "B3YE" => (2, false, "", "", r#"The Legend of Spyro - A New Beginning (USA)"#),
+    "BU7P" => (1, false, "", "", r#"The Legend of Spyro - The Eternal Night (Europe)"#),
+    "BU7E" => (1, false, "", "", r#"The Legend of Spyro - The Eternal Night (USA)"#),
+    "AZLP" => (2, false, "", "", r#"The Legend of Zelda - A Link to the Past & Four Swords (Europe)"#),
+    "AZLE" => (2, false, "", "", r#"The Legend of Zelda - A Link to the Past & Four Swords (USA, Australia)"#),
+    "BZMP" => (2, false, "", "", r#"The Legend of Zelda - The Minish Cap (Europe)"#),
+    "BZME" => (2, false, "", "", r#"The Legend of Zelda - The Minish Cap (USA)"#),
+    "A2LE" => (1, false, "", "", r#"Legends of Wrestling II (Europe, USA)"#),
+    "BLVJ" => (4, true, "sst", "", r#"Legendz - Sign of Nekuromu (Japan)"#),
+    "BLJJ" => (4, true, "sst", "", r#"Legendz - Yomigaeru Shiren no Shima (Japan)"#),
+    "ALBP" => (3, false, "", "", r#"LEGO Bionicle (Europe)"#),
+    "ALBE" => (3, false, "", "", r#"LEGO Bionicle (USA)"#),
+    "AXTE" => (1, false, "", "", r#"Island - Xtreme Stunts (Europe, USA)"#),
+    "AL2P" => (3, false, "", "", r#"LEGO Island 2 - The Brickster's Revenge (Europe)"#),
+    "AL2E" => (3, false, "", "", r#"LEGO Island 2 - The Brickster's Revenge (USA)"#),
+    "ALRP" => (1, false, "", "", r#"LEGO Racers 2 (Europe)"#),
+    "ALRE" => (1, false, "", "", r#"LEGO Racers 2 (USA)"#),
+    "ALSE" => (1, false, "", "", r#"LEGO Football Mania (Europe)"#),
+    "ALSP" => (1, false, "", "", r#"LEGO Football Mania (Europe)"#),
+    "BLWJ" => (1, false, "", "", r#"LEGO Star Wars - The Video Game (Japan)"#),
+    "BLWE" => (1, false, "", "", r#"LEGO Star Wars - The Video Game (Europe, USA)"#),
+    "BLWP" => (1, false, "", "", r#"LEGO Star Wars - The Video Game (Europe, USA)"#),
+    "BL7P" => (1, false, "", "", r#"LEGO Star Wars II - The Original Trilogy (Europe)"#),
+    "BL7E" => (1, false, "", "", r#"LEGO Star Wars II - The Original Trilogy (USA)"#),
+    "BLYE" => (1, false, "", "", r#"Lemony Snicket's A Series of Unfortunate Events (Europe, USA)"#),
+    "BL9E" => (2, false, "", "", r#"Let's Ride! - Dreamer (USA)"#),
+    "BEFE" => (2, false, "", "", r#"Let's Ride! - Friends Forever (USA)"#),
+    "B34E" => (2, false, "", "", r#"Let's Ride! - Sunshine Stables (USA)"#),
+    "BRNJ" => (3, false, "", "", r#"Licca-chan no Oshare Nikki (Japan)"#),
+    "BRPJ" => (2, false, "", "", r#"Lilliput Oukoku - Lillimoni to Issho Puni! (Japan)"#),
+    "ALTP" => (0, false, "", "", r#"Disney's Lilo & Stitch (Europe, rev. 1)"#),
+    "ALTE" => (0, false, "", "", r#"Disney's Lilo & Stitch (USA)"#),
+    "BLSP" => (1, false, "", "", r#"Disney's Lilo & Stitch 2 (Europe)"#),
+    "BLSE" => (1, false, "", "", r#"Disney's Lilo & Stitch 2 - Hämsterviel Havoc (USA)"#),
+    "BLSJ" => (1, false, "", "", r#"Disney's Lilo & Stitch (Japan)"#),
+    "BLKE" => (1, false, "", "", r#"Disney The Lion King 1½ (USA)"#),
+    "BLKP" => (1, false, "", "", r#"Disney The Lion King (Europe)"#),
+    "ALQJ" => (3, false, "", "", r#"Little Buster Q (Japan)"#),
+    "BEIE" => (0, false, "", "", r#"Disney's Little Einsteins (USA)"#),
+    "ALCE" => (0, false, "", "", r#"Little League Baseball 2002 (USA)"#),
+    "BN9E" => (1, false, "", "", r#"Disney's The Little Mermaid - Magic in Two Kingdoms (Europe, USA)"#),
+    "BN9P" => (1, false, "", "", r#"Disney's The Little Mermaid - Magic in Two Kingdoms (Europe, USA)"#),
+    "BLIJ" => (2, false, "", "", r#"Little Patissier - Cake no Oshiro (Japan)"#),
+    "BLMP" => (2, false, "", "", r#"Lizzie McGuire (Europe)"#),
+    "BLME" => (2, false, "", "", r#"Lizzie McGuire - On the Go! (USA)"#),
+    "BL4E" => (4, false, "", "", r#"Disney's Game + TV Episode - Lizzie McGuire 2 - Lizzie Diaries (USA)"#),
+    "BL2E" => (1, false, "", "", r#"Lizzie McGuire 2 - Lizzie Diaries (USA)"#),
+    "BL3E" => (1, false, "", "", r#"Lizzie McGuire 3 - Homecoming Havoc (USA)"#),
+    "A39J" => (1, false, "", "", r#"Lode Runner (Japan)"#),
+    "BLTE" => (1, false, "", "", r#"Looney Tunes - Back in Action (Europe, USA)"#),
+    "BLTP" => (1, false, "", "", r#"Looney Tunes - Back in Action (Europe, USA)"#),
+    "BLNP" => (1, false, "", "", r#"Looney Tunes Double Pack (Europe)"#),
+    "BLNE" => (1, false, "", "", r#"Looney Tunes Double Pack (USA)"#),
+    "ALPJ" => (1, false, "", "", r#"The Lord of the Rings - Futatsu no Tou (Japan)"#),
+    "B3AJ" => (1, false, "", "", r#"The Lord of the Rings - Nakatsukuni Daisanki (Japan)"#),
+    "BLRJ" => (1, false, "", "", r#"The Lord of the Rings - Ou no Kikan (Japan)"#),
+    "ALOP" => (2, false, "", "", r#"The Lord of the Rings - The Fellowship of the Ring (Europe)"#),
+    "ALOE" => (2, false, "", "", r#"The Lord of the Rings - The Fellowship of the Ring (USA, rev. 1)"#),
+    "B3AE" => (1, false, "", "", r#"The Lord of the Rings - The Third Age (Europe, USA)"#),
+    "B3AP" => (1, false, "", "", r#"The Lord of the Rings - The Third Age (Europe, USA)"#),
+    "ALPE" => (1, false, "", "", r#"The Lord of the Rings - The Two Towers (Europe, USA)"#),
+    "ALPP" => (1, false, "", "", r#"The Lord of the Rings - The Two Towers (Europe, USA)"#),
+    "ALVP" => (1, false, "", "", r#"The Lost Vikings (Europe)"#),
+    "ALVE" => (1, false, "", "", r#"The Lost Vikings (USA)"#),
+    "ALHJ" => (1, false, "", "", r#"Love Hina Advance - Shukufuku no Kane wa Naru Kana (Japan)"#),
+    "ALLP" => (0, false, "", "", r#"Lucky Luke - Wanted! (Europe)"#),
+    "AGDE" => (3, false, "", "", r#"Lufia - The Ruins of Lore (USA)"#),
+    "ALNJ" => (3, false, "", "", r#"Lunar Legend (Japan)"#),
+    "ALNE" => (2, false, "", "", r#"Lunar Legend (USA)"#),
+    "AMLE" => (1, false, "", "", r#"M&M's - Blast! (USA)"#),
+    "BEME" => (0, false, "", "", r#"M&M's - Break 'em (USA, rev. 1)"#),
+    "BGZX" => (1, false, "", "", r#"Madagascar (Europe, French / German / Portuguese)"#),
+    "BGZJ" => (1, false, "", "", r#"Madagascar (Japan)"#),
+    "BGZE" => (1, false, "", "", r#"Madagascar (USA)"#),
+    "BM7P" => (1, false, "", "", r#"Madagascar - Operation Penguin (Europe, English)"#),
+    "BM7E" => (1, false, "", "", r#"Madagascar - Operation Penguin (USA)"#),
+    "B6ME" => (2, false, "", "", r#"Madden NFL 06 (USA)"#),
+    "B7ME" => (2, false, "", "", r#"Madden NFL 07 (USA)"#),
+    "A2ME" => (1, false, "", "", r#"Madden NFL 2002 (USA)"#),
+    "ANJE" => (2, false, "", "", r#"Madden NFL 2003 (USA)"#),
+    "AMDE" => (2, false, "", "", r#"Madden NFL 2004 (USA)"#),
+    "BMFE" => (2, false, "", "", r#"Madden NFL 2005 (USA)"#),
+    "AZWJ" => (3, false, "", "", r#"Made in Wario (Japan)"#),
+    "BM9J" => (3, false, "", "", r#"MÄR Heaven - Knockin' on Heaven's Door (Japan)"#),
+    "A2IJ" => (2, false, "", "", r#"Magi Nation (Japan)"#),
+    "AJOJ" => (5, false, "", "", r#"Magical Houshin (Japan)"#),
+    "AQMP" => (1, false, "", "", r#"Disney's Magical Quest 2 Starring Mickey & Minnie (Europe)"#),
+    "AQME" => (1, false, "", "", r#"Disney's Magical Quest 2 Starring Mickey & Minnie (USA)"#),
+    "BMQP" => (1, false, "", "", r#"Disney's Magical Quest 3 Starring Mickey & Donald (Europe)"#),
+    "BMQE" => (1, false, "", "", r#"Disney's Magical Quest 3 Starring Mickey & Donald (USA)"#),
+    "A3MP" => (1, false, "", "", r#"Disney's Magical Quest Starring Mickey & Minnie (Europe)"#),
+    "A3ME" => (1, false, "", "", r#"Disney's Magical Quest Starring Mickey & Minnie (USA)"#),
+    "AMVJ" => (5, false, "", "", r#"Magical Vacation (Japan)"#),
+    "AMPJ" => (3, false, "", "", r#"Mahjong Keiji (Japan)"#),
+    "AWNJ" => (1, false, "", "", r#"Mahou no Pumpkin - Ann to Greg no Daibouken (Japan)"#),
+    "BNGJ" => (3, false, "", "", r#"Mahou Sensei Negima! - Private Lesson - Damedesuu Toshokan-jima (Japan)"#),
+    "BNMJ" => (3, false, "", "", r#"Mahou Sensei Negima! - Private Lesson 2 - Ojamashimasuu Parasite de Chuu (Japan)"#),
+    "AMCJ" => (5, false, "", "", r#"Mail de Cute (Japan)"#),
+    "BRQP" => (0, false, "", "", r#"Rec Room Challenge (Europe)"#),
+    "BRQE" => (0, false, "", "", r#"Rec Room Challenge (USA)"#),
+    "B3NE" => (0, false, "", "", r#"3-in-1 Sports Pack (USA)"#),
+    "BWAJ" => (1, false, "", "", r#"Majokko Cream-chan no Gokko Series 1 - Wannyan Idol Gakuen (Japan)"#),
+    "BE2J" => (1, false, "", "", r#"Majokko Cream-chan no Gokko Series 2 - Kisekae Angel (Japan)"#),
+    "ACOJ" => (2, false, "", "", r#"Manga-ka Debut Monogatari (Japan)"#),
+    "AMRP" => (1, false, "", "", r#"Maniac Racers Advance (Europe)"#),
+    "A4MP" => (0, false, "", "", r#"Manic Miner (Europe)"#),
+    "BQLP" => (0, false, "", "", r#"March of the Penguins (Europe)"#),
+    "BQLE" => (0, false, "", "", r#"March of the Penguins (USA)"#),
+    "ANSJ" => (2, false, "", "", r#"Marie, Elie & Anis no Atelier - Soyokaze kara no Dengon (Japan)"#),
+    "A88P" => (2, false, "", "", r#"Mario & Luigi - Superstar Saga (Europe)"#),
+    "A88E" => (2, false, "", "", r#"Mario & Luigi - Superstar Saga (USA, Australia)"#),
+    "A88J" => (2, false, "", "", r#"Mario & Luigi RPG (Japan)"#),
+    "BMGP" => (3, false, "", "", r#"Mario Golf - Advance Tour (Europe)"#),
+    "BMGE" => (3, false, "", "", r#"Mario Golf - Advance Tour (USA)"#),
+    "BMGJ" => (3, false, "", "", r#"Mario Golf - GBA Tour (Japan)"#),
+    "AMKP" => (5, false, "sst", "", r#"Mario Kart - Super Circuit (Europe)"#),
+    "AMKE" => (5, false, "atmel", "", r#"Mario Kart - Super Circuit (USA)"#),
+    "AMKJ" => (5, false, "", "", r#"Mario Kart Advance (Japan)"#),
+    "B8MP" => (2, false, "", "", r#"Mario Party Advance (Europe)"#),
+    "B8MJ" => (2, false, "", "", r#"Mario Party Advance (Japan)"#),
+    "B8ME" => (2, false, "", "", r#"Mario Party Advance (USA)"#),
+    "BMVE" => (1, false, "", "", r#"Mario Pinball Land (USA, Australia)"#),
+    "BMVU" => (1, false, "", "", r#"Mario Pinball Land (USA, Australia)"#),
+    "BTMP" => (3, false, "", "", r#"Mario Power Tennis (Europe)"#),
+    "BTMJ" => (3, false, "", "", r#"Mario Tennis Advance (Japan)"#),
+    "BTME" => (3, false, "", "", r#"Mario Tennis - Power Tour (USA, Australia)"#),
+    "BTMU" => (3, false, "", "", r#"Mario Tennis - Power Tour (USA, Australia)"#),
+    "BM5P" => (4, false, "sst", "", r#"Mario vs. Donkey Kong (Europe)"#),
+    "BM5J" => (4, false, "", "", r#"Mario vs. Donkey Kong (Japan)"#),
+    "BM5E" => (4, false, "", "", r#"Mario vs. Donkey Kong (USA, Australia)"#),
+    "BM5U" => (4, false, "", "", r#"Mario vs. Donkey Kong (USA, Australia)"#),
+    "B4MP" => (1, false, "", "", r#"Marvel - Ultimate Alliance (Europe)"#),
+    "B4ME" => (1, false, "", "", r#"Marvel - Ultimate Alliance (USA)"#),
+    "AKSP" => (1, false, "", "", r#"Mary-Kate and Ashley - Girls Night Out (Europe, USA)"#),
+    "AKSE" => (1, false, "", "", r#"Mary-Kate and Ashley - Girls Night Out (Europe, USA)"#),
+    "AAYP" => (0, false, "", "", r#"Mary-Kate and Ashley Sweet 16 - Licensed to Drive (Europe, USA)"#),
+    "AAYE" => (0, false, "", "", r#"Mary-Kate and Ashley Sweet 16 - Licensed to Drive (Europe, USA)"#),
+    "AGUE" => (0, false, "", "", r#"Masters of the Universe He-Man - Power of Grayskull (USA)"#),
+    "AHOE" => (1, false, "", "", r#"Mat Hoffman's Pro BMX (Europe, USA)"#),
+    "AH2E" => (1, false, "", "", r#"Mat Hoffman's Pro BMX 2 (Europe, USA)"#),
+    "BMRJ" => (2, false, "", "", r#"Matantei Loki Ragnarok - Gensou no Labyrinth (Japan, rev. 1)"#),
+    "ARQP" => (0, false, "", "", r#"Matchbox Cross Town Heroes (Europe)"#),
+    "ARQE" => (0, false, "", "", r#"Matchbox Cross Town Heroes (USA)"#),
+    "BIYE" => (1, false, "", "", r#"Math Patrol - The Kleptoid Threat (USA)"#),
+    "RZWJ" => (3, false, "", "gyro|rumble", r#"Mawaru - Made in Wario (Japan)"#),
+    "BMEP" => (1, false, "", "", r#"Max Payne Advance (Europe)"#),
+    "BMEE" => (1, false, "", "", r#"Max Payne (USA)"#),
+    "BEEP" => (1, false, "", "", r#"Maya the Bee - Sweet Gold (Europe)"#),
+    "ABVP" => (0, false, "", "", r#"Maya the Bee - The Great Adventure (Europe)"#),
+    "BFQE" => (2, false, "", "", r#"Mazes of Fate (USA)"#),
+    "AKGP" => (2, false, "", "", r#"Mech Platoon (Europe)"#),
+    "AKGE" => (2, false, "", "", r#"Mech Platoon (USA)"#),
+    "A8BP" => (2, false, "", "", r#"Medabots - Metabee Version (Europe)"#),
+    "A8BE" => (2, false, "", "", r#"Medabots - Metabee Version (USA)"#),
+    "A9BP" => (2, false, "", "", r#"Medabots - Rokusho Version (Europe)"#),
+    "A9BE" => (2, false, "", "", r#"Medabots - Rokusho Version (USA)"#),
+    "AK8P" => (1, false, "", "", r#"Medabots AX - Metabee Version (Europe)"#),
+    "AK8E" => (1, false, "", "", r#"Medabots AX - Metabee Version (USA)"#),
+    "AK9E" => (1, false, "", "", r#"Medabots AX - Rokusho Version (USA)"#),
+    "BMHE" => (1, false, "", "", r#"Medal of Honor - Infiltrator (Europe, USA)"#),
+    "BMHP" => (1, false, "", "", r#"Medal of Honor - Infiltrator (Europe, USA)"#),
+    "AUGX" => (0, false, "", "", r#"Medal of Honor - Underground (Europe, Ubi Soft)"#),
+    "AUGE" => (0, false, "", "", r#"Medal of Honor - Underground (USA)"#),
+    "BMHJ" => (1, false, "", "", r#"Medal of Honor Advance (Japan)"#),
+    "AGHJ" => (1, false, "", "", r#"Medarot G - Kabuto Version (Japan)"#),
+    "AGIJ" => (1, false, "", "", r#"Medarot G - Kuwagata Version (Japan)"#),
+    "ANAJ" => (5, false, "", "", r#"Medarot Navi - Kabuto (Japan)"#),
+    "AVIJ" => (5, false, "", "", r#"Medarot Navi - Kuwagata (Japan)"#),
+    "A5KJ" => (2, false, "", "", r#"Medarot Ni Core - Kabuto Version (Japan)"#),
+    "A5QJ" => (2, false, "", "", r#"Medarot Ni Core - Kuwagata Version (Japan)"#),
+    "BRHP" => (1, false, "", "", r#"Disney's Meet the Robinsons (Europe)"#),
+    "BRHE" => (1, false, "", "", r#"Disney's Meet the Robinsons (USA)"#),
+    "A6MP" => (2, false, "", "", r#"Mega Man & Bass (Europe)"#),
+    "A6ME" => (2, false, "", "", r#"Mega Man & Bass (USA)"#),
+    "A89P" => (3, false, "", "", r#"Mega Man Battle Chip Challenge (Europe)"#),
+    "A89E" => (3, false, "", "", r#"Mega Man Battle Chip Challenge (USA)"#),
+    "AREP" => (3, false, "", "", r#"Mega Man Battle Network (Europe)"#),
+    "AREE" => (3, false, "", "", r#"Mega Man Battle Network (USA)"#),
+    "AM2P" => (3, false, "", "", r#"Mega Man Battle Network 2 (Europe)"#),
+    "AE2E" => (3, false, "", "", r#"Mega Man Battle Network 2 (USA)"#),
+    "A3XP" => (3, false, "", "", r#"Mega Man Battle Network 3 Blue (Europe)"#),
+    "A3XE" => (3, false, "", "", r#"Mega Man Battle Network 3 Blue (USA)"#),
+    "A6BP" => (3, false, "", "", r#"Mega Man Battle Network 3 White (Europe)"#),
+    "A6BE" => (3, false, "", "", r#"Mega Man Battle Network 3 White (USA)"#),
+    "B4BP" => (3, false, "", "", r#"Mega Man Battle Network 4 Blue Moon (Europe)"#),
+    "B4BE" => (3, false, "", "", r#"Mega Man Battle Network 4 Blue Moon (USA)"#),
+    "B4WP" => (3, false, "", "", r#"Mega Man Battle Network 4 Red Sun (Europe)"#),
+    "B4WE" => (3, false, "", "", r#"Mega Man Battle Network 4 Red Sun (USA)"#),
+    "BRKP" => (3, false, "", "", r#"Mega Man Battle Network 5 Team Colonel (Europe)"#),
+    "BRKE" => (3, false, "", "", r#"Mega Man Battle Network 5 Team Colonel (USA)"#),
+    "BRBP" => (3, false, "", "", r#"Mega Man Battle Network 5 Team Protoman (Europe)"#),
+    "BRBE" => (3, false, "", "", r#"Mega Man Battle Network 5 Team Protoman (USA)"#),
+    "BR6P" => (3, false, "", "", r#"Mega Man Battle Network 6 Cybeast Falzar (Europe)"#),
+    "BR6E" => (3, false, "", "", r#"Mega Man Battle Network 6 Cybeast Falzar (USA)"#),
+    "BR5P" => (3, false, "", "", r#"Mega Man Battle Network 6 Cybeast Gregar (Europe)"#),
+    "BR5E" => (3, false, "", "", r#"Mega Man Battle Network 6 Cybeast Gregar (USA)"#),
+    "AZCE" => (3, false, "", "", r#"Mega Man Zero (Europe, USA)"#),
+    "AZCP" => (3, false, "", "", r#"Mega Man Zero (Europe, USA)"#),
+    "A62P" => (3, false, "", "", r#"Mega Man Zero 2 (Europe)"#),
+    "A62E" => (3, false, "", "", r#"Mega Man Zero 2 (USA)"#),
+    "BZ3P" => (3, false, "", "", r#"Mega Man Zero 3 (Europe)"#),
+    "BZ3E" => (3, false, "", "", r#"Mega Man Zero 3 (USA)"#),
+    "B4ZP" => (2, false, "", "", r#"Mega Man Zero 4 (Europe)"#),
+    "B4ZE" => (2, false, "", "", r#"Mega Man Zero 4 (USA)"#),
+    "BQAJ" => (2, false, "", "", r#"Detective Conan: Akatsuki no Monument (Japan)"#),
+    "AC4J" => (2, false, "", "", r#"Detective Conan: Nerawareta Tantei (Japan)"#),
+    "AMIP" => (0, false, "", "", r#"Men in Black - The Series (Europe)"#),
+    "AMIE" => (0, false, "", "", r#"Men in Black - The Series (USA)"#),
+    "BMAJ" => (3, false, "", "", r#"Mermaid Melody - Pichi Pichi Pitch (Japan)"#),
+    "BM8J" => (3, false, "", "", r#"Mermaid Melody - Pichi Pichi Pitch - Pichi Pichi Party (Japan)"#),
+    "B3MJ" => (3, false, "", "", r#"Mermaid Melody - Pichi Pichi Pitch - Pichi Pichitto Live Start! (Japan)"#),
+    "A9TJ" => (2, false, "", "", r#"Metal Max 2 Kai (Japan, rev. 1)"#),
+    "BSMP" => (1, false, "", "", r#"Metal Slug Advance (Europe)"#),
+    "BSMJ" => (1, false, "", "", r#"Metal Slug Advance (Japan)"#),
+    "BSME" => (1, false, "", "", r#"Metal Slug Advance (USA)"#),
+    "AAPJ" => (2, false, "", "", r#"Metalgun Slinger (Japan)"#),
+    "BMXP" => (3, false, "", "", r#"Metroid - Zero Mission (Europe)"#),
+    "BMXJ" => (3, false, "", "", r#"Metroid - Zero Mission (Japan)"#),
+    "BMXE" => (3, false, "", "", r#"Metroid - Zero Mission (USA)"#),
+    "AMTP" => (3, false, "", "", r#"Metroid Fusion (Europe)"#),
+    "AMTJ" => (3, false, "", "", r#"Metroid Fusion (Japan)"#),
+    "AMTE" => (3, false, "", "", r#"Metroid Fusion (USA, Australia)"#),
+    "BMKJ" => (4, false, "", "", r#"Mezase! Koushien (Japan)"#),
+    "BM4J" => (1, false, "", "", r#"Mickey no Pocket Resort (Japan)"#),
+    "BM3J" => (1, false, "", "", r#"Disney's Mickey to Donald no Magical Quest 3 (Japan)"#),
+    "A3MJ" => (1, false, "", "", r#"Disney's Mickey to Minnie no Magical Quest (Japan)"#),
+    "A29J" => (1, false, "", "", r#"Disney's Mickey to Minnie no Magical Quest 2 (Japan)"#),
+    "AXZP" => (0, false, "", "", r#"Micro Machines (Europe)"#),
+    "AMQP" => (0, false, "", "", r#"Midnight Club - Street Racing (Europe)"#),
+    "AMQE" => (0, false, "", "", r#"Midnight Club - Street Racing (USA)"#),
+    "AM3E" => (0, false, "", "", r#"Midway's Greatest Arcade Hits (Europe, USA)"#),
+    "AM3P" => (0, false, "", "", r#"Midway's Greatest Arcade Hits (Europe, USA)"#),
+    "BMBE" => (1, false, "", "", r#"Mighty Beanz Pocket Puzzles (USA)"#),
+    "AM6P" => (1, false, "", "", r#"Mike Tyson Boxing (Europe)"#),
+    "AM6E" => (1, false, "", "", r#"Mike Tyson Boxing (USA)"#),
+    "AODJ" => (1, false, "", "", r#"Minami no Umi no Odyssey (Japan)"#),
+    "AHCJ" => (3, false, "", "", r#"Mini Moni. - Mika no Happy Morning Chatty (Japan)"#),
+    "AOHJ" => (1, false, "", "", r#"Mini Moni. - Onegai Ohoshi-sama! (Japan)"#),
+    "APYJ" => (1, false, "", "", r#"Minna de Puyo Puyo (Japan)"#),
+    "BMOJ" => (3, false, "", "", r#"Minna no Ouji-sama (Japan)"#),
+    "BKKJ" => (2, false, "", "", r#"Minna no Shiiku Series - Boku no Kabuto, Kuwagata (Japan)"#),
+    "AB7J" => (3, false, "", "", r#"Minna no Shiiku Series 1 - Boku no Kabutomushi (Japan)"#),
+    "AW7J" => (3, false, "", "", r#"Minna no Shiiku Series 2 - Boku no Kuwagata (Japan)"#),
+    "BTLJ" => (1, false, "", "", r#"Minna no Soft Series - Happy Trump 20 (Japan)"#),
+    "BHYJ" => (1, false, "", "", r#"Minna no Soft Series - Hyokkori Hyoutan-jima - Don Gabacho Daikatsuyaku no Maki (Japan)"#),
+    "BMJJ" => (1, false, "", "", r#"Minna no Soft Series - Minna no Mahjong (Japan)"#),
+    "BSGJ" => (1, false, "", "", r#"Minna no Soft Series - Minna no Shougi (Japan, rev. 1)"#),
+    "BUOJ" => (2, false, "", "", r#"Minna no Soft Series - Numpla Advance (Japan)"#),
+    "BSHJ" => (1, false, "", "", r#"Minna no Soft Series - Shanghai (Japan)"#),
+    "BTTJ" => (1, false, "", "", r#"Minna no Soft Series - Tetris Advance (Japan, rev. 1)"#),
+    "BMZJ" => (2, false, "", "", r#"Minna no Soft Series - Zooo (Japan)"#),
+    "ARME" => (1, false, "", "", r#"Minority Report (Europe, USA)"#),
+    "ARMP" => (1, false, "", "", r#"Minority Report (Europe, USA)"#),
+    "B3IJ" => (1, false, "", "", r#"Mirakuru! Panzou - 7-tsu no Hoshi no Uchuu Kaizoku (Japan)"#),
+    "AIHP" => (1, false, "", "", r#"Mission Impossible - Operation Surma (Europe)"#),
+    "AIHE" => (1, false, "", "", r#"Mission Impossible - Operation Surma (USA)"#),
+    "A5ME" => (0, false, "", "", r#"MLB SlugFest 20-04 (USA)"#),
+    "AMBJ" => (5, false, "", "", r#"Mobile Pro Yakyuu - Kantoku no Saihai (Japan)"#),
+    "BGNE" => (0, false, "", "", r#"Mobile Suit Gundam Seed - Battle Assault (USA)"#),
+    "BJCJ" => (1, false, "", "", r#"Moero!! Jaleco Collection (Japan)"#),
+    "BM2J" => (2, false, "", "", r#"Momotarou Dentetsu G Gold Deck o Tsukure! (Japan)"#),
+    "AMMJ" => (3, false, "", "", r#"Momotarou Matsuri (Japan, rev. 1)"#),
+    "BUMP" => (0, false, "", "", r#"Monopoly (Europe)"#),
+    "BUME" => (0, false, "", "", r#"Monopoly (USA)"#),
+    "AMFJ" => (3, false, "", "", r#"Monster Farm Advance (Japan)"#),
+    "A2QJ" => (3, false, "", "", r#"Monster Farm Advance 2 (Japan)"#),
+    "AM8P" => (1, false, "", "", r#"Monster Force (Europe)"#),
+    "AM8E" => (1, false, "", "", r#"Monster Force (USA)"#),
+    "ANFJ" => (3, false, "", "", r#"Monster Gate (Japan)"#),
+    "A6GJ" => (3, false, "", "", r#"Monster Gate - Ooinaru Dungeon - Fuuin no Orb (Japan)"#),
+    "AMNJ" => (3, false, "", "", r#"Monster Guardians (Japan)"#),
+    "BQ7P" => (1, false, "", "", r#"Monster House (Europe, English)"#),
+    "BQ7E" => (1, false, "", "", r#"Monster House (USA)"#),
+    "AJAP" => (3, false, "", "", r#"Monster Jam - Maximum Destruction (Europe)"#),
+    "AJAE" => (3, false, "", "", r#"Monster Jam - Maximum Destruction (USA)"#),
+    "AA4J" => (3, false, "", "", r#"Monster Maker 4 - Flash Card (Japan)"#),
+    "AA5J" => (3, false, "", "", r#"Monster Maker 4 - Killer Dice (Japan)"#),
+    "AMFE" => (3, false, "", "", r#"Monster Rancher Advance (USA)"#),
+    "A2OE" => (3, false, "", "", r#"Monster Rancher Advance 2 (USA)"#),
+    "A3NJ" => (3, false, "", "", r#"Monster Summoner (Japan)"#),
+    "BMTE" => (1, false, "", "", r#"Monster Truck Madness (Europe, USA)"#),
+    "BMCE" => (0, false, "", "", r#"Monster Trucks (Europe, USA)"#),
+    "BMCP" => (0, false, "", "", r#"Monster Trucks (Europe, USA)"#),
+    "BYMP" => (0, false, "", "", r#"Monster Trucks Mayhem (Europe)"#),
+    "BYME" => (0, false, "", "", r#"Monster Trucks Mayhem (USA)"#),
+    "A4BP" => (0, false, "", "", r#"Monster! Bass Fishing (Europe)"#),
+    "A4BE" => (0, false, "", "", r#"Monster! Bass Fishing (USA)"#),
+    "AMXE" => (0, false, "", "", r#"Monsters, Inc. (Europe, USA)"#),
+    "AMXP" => (0, false, "", "", r#"Monsters, Inc. (Europe, USA)"#),
+    "AMXD" => (0, false, "", "", r#"Die Monster AG (Germany)"#),
+    "AMXJ" => (0, false, "", "", r#"Monsters, Inc. (Japan)"#),
+    "AMSJ" => (3, false, "", "", r#"Morita Shougi Advance (Japan)"#),
+    "AXDP" => (1, false, "", "", r#"Mortal Kombat - Deadly Alliance (Europe)"#),
+    "AXDE" => (1, false, "", "", r#"Mortal Kombat - Deadly Alliance (USA)"#),
+    "AW4E" => (1, false, "", "", r#"Mortal Kombat - Tournament Edition (USA)"#),
+    "AM5P" => (0, false, "", "", r#"Mortal Kombat Advance (Europe)"#),
+    "AM5E" => (0, false, "", "", r#"Mortal Kombat Advance (USA)"#),
+    "A2UJ" => (3, false, "", "", r#"Mother 1+2 (Japan)"#),
+    "A3UJ" => (4, false, "sst", "", r#"Mother 3 (Japan)"#),
+    "AM4P" => (0, false, "", "", r#"Moto GP (Europe)"#),
+    "AM4J" => (1, false, "", "", r#"Moto GP (Japan)"#),
+    "AM4E" => (0, false, "", "", r#"Moto GP (USA)"#),
+    "AMRE" => (1, false, "", "", r#"Motocross Maniacs Advance (USA)"#),
+    "AMRJ" => (1, false, "", "", r#"Motocross Maniacs Advance (Japan)"#),
+    "A9MP" => (3, false, "", "", r#"Motoracer Advance (Europe)"#),
+    "A9ME" => (3, false, "", "", r#"Motoracer Advance (USA)"#),
+    "AZRP" => (0, false, "", "", r#"Mr Nutz (Europe)"#),
+    "AD2J" => (3, false, "", "", r#"Mr. Driller 2 (Japan)"#),
+    "BR2E" => (3, false, "", "", r#"Mr. Driller 2 (USA)"#),
+    "AD5J" => (3, false, "", "", r#"Mr. Driller A - Fushigi na Pacteria (Japan)"#),
+    "BICJ" => (0, false, "", "", r#"Mr. Incredible (Japan)"#),
+    "BIQJ" => (0, false, "", "", r#"Mr. Incredible - Kyouteki Underminer Toujou (Japan)"#),
+    "BPCP" => (1, false, "", "", r#"Ms. Pac-Man - Maze Madness (Europe)"#),
+    "BPCE" => (1, false, "", "", r#"Ms. Pac-Man - Maze Madness (USA)"#),
+    "BMLE" => (1, false, "", "", r#"Mucha Lucha! - Mascaritas of the Lost Code (USA)"#),
+    "AG6J" => (3, false, "", "", r#"Mugenborg (Japan)"#),
+    "AUMP" => (0, false, "", "", r#"The Mummy (Europe)"#),
+    "AUME" => (0, false, "", "", r#"The Mummy (USA)"#),
+    "AZME" => (0, false, "", "", r#"Jim Henson's Muppets - On with the Show! (Europe, USA)"#),
+    "AZMP" => (0, false, "", "", r#"Jim Henson's Muppets - On with the Show! (Europe, USA)"#),
+    "AMWE" => (1, false, "", "", r#"Muppet Pinball Mayhem (USA)"#),
+    "AMUJ" => (3, false, "", "", r#"Mutsu - Water Looper Mutsu (Japan)"#),
+    "A2XE" => (0, false, "", "", r#"MX 2002 featuring Ricky Carmichael (Europe, USA)"#),
+    "A2XP" => (0, false, "", "", r#"MX 2002 featuring Ricky Carmichael (Europe, USA)"#),
+    "BFRP" => (2, false, "", "", r#"Meine Tierklinik in Afrika ~ My Animal Centre in Africa (Europe)"#),
+    "BL6E" => (0, false, "", "", r#"My Little Pony - Crystal Princess - The Runaway Rainbow (USA)"#),
+    "AKPJ" => (3, false, "", "", r#"Nakayoshi Mahjong - KabuReach (Japan)"#),
+    "AH7J" => (2, false, "", "", r#"Nakayoshi Pet Advance Series 1 - Kawaii Hamster (Japan)"#),
+    "AI7J" => (2, false, "", "", r#"Nakayoshi Pet Advance Series 2 - Kawaii Koinu (Japan)"#),
+    "AN3J" => (2, false, "", "", r#"Nakayoshi Pet Advance Series 3 - Kawaii Koneko (Japan, rev. 1)"#),
+    "BKIJ" => (2, false, "", "", r#"Nakayoshi Pet Advance Series 4 - Kawaii Koinu Mini - Wankoto Asobou!! Kogata-ken (Japan)"#),
+    "AHVJ" => (2, false, "", "", r#"Nakayoshi Youchien - Sukoyaka Enji Ikusei Game (Japan, rev. 1)"#),
+    "ANMP" => (0, false, "", "", r#"Namco Museum (Europe)"#),
+    "ANMJ" => (0, false, "", "", r#"Namco Museum (Japan)"#),
+    "ANME" => (0, false, "", "", r#"Namco Museum (USA)"#),
+    "B5NP" => (0, false, "", "", r#"Namco Museum - 50th Anniversary (Europe)"#),
+    "B5NE" => (0, false, "", "", r#"Namco Museum - 50th Anniversary (USA)"#),
+    "ANDE" => (0, false, "", "", r#"Nancy Drew - Message in a Haunted Mansion (USA)"#),
+    "ANPJ" => (3, false, "", "", r#"Napoleon (Japan)"#),
+    "AYRJ" => (3, false, "", "", r#"Narikiri Jockey Game - Yuushun Rhapsody (Japan)"#),
+    "AUEJ" => (3, false, "", "", r#"Naruto - Konoha Senki (Japan, rev. 1)"#),
+    "A7AE" => (1, false, "", "", r#"Shonen Jump's Naruto - Ninja Council (USA)"#),
+    "BN2E" => (3, false, "", "", r#"Shonen Jump Naruto - Ninja Council 2 (USA)"#),
+    "A7AJ" => (3, false, "", "", r#"Naruto - Ninjutsu Zenkai! Saikyou Ninja Daikesshuu (Japan, rev. 1)"#),
+    "BN2J" => (3, false, "", "", r#"Naruto - Saikyou Ninja Daikesshuu 2 (Japan)"#),
+    "BNRJ" => (3, false, "", "", r#"Naruto RPG - Uketsugareshi Hi no Ishi (Japan, rev. 1)"#),
+    "ANHE" => (1, false, "", "", r#"NASCAR Heat 2002 (USA)"#),
+    "AN2J" => (5, false, "", "", r#"Natural 2 - Duo (Japan)"#),
+    "ABNE" => (0, false, "", "", r#"NBA Jam 2002 (Europe, USA)"#),
+    "ABNP" => (0, false, "", "", r#"NBA Jam 2002 (Europe, USA)"#),
+    "BNWE" => (1, false, "", "", r#"Need for Speed - Most Wanted (Europe, USA)"#),
+    "BNWP" => (1, false, "", "", r#"Need for Speed - Most Wanted (Europe, USA)"#),
+    "AZFP" => (1, false, "", "", r#"Need for Speed - Porsche Unleashed (Europe)"#),
+    "AZFE" => (1, false, "", "", r#"Need for Speed - Porsche Unleashed (USA)"#),
+    "BNSE" => (1, false, "", "", r#"Need for Speed - Underground (Europe, USA)"#),
+    "BNSP" => (1, false, "", "", r#"Need for Speed - Underground (Europe, USA)"#),
+    "BNFE" => (1, false, "", "", r#"Need for Speed - Underground 2 (Europe, USA)"#),
+    "BNFP" => (1, false, "", "", r#"Need for Speed - Underground 2 (Europe, USA)"#),
+    "BN7E" => (1, false, "", "", r#"Need for Speed Carbon - Own the City (Europe, USA)"#),
+    "BN7P" => (1, false, "", "", r#"Need for Speed Carbon - Own the City (Europe, USA)"#),
+    "ARNJ" => (2, false, "", "", r#"Neoromance Game - Harukanaru Toki no Naka de (Japan, rev. 1)"#),
+    "ATXP" => (1, false, "", "", r#"NGT - Next Generation Tennis (Europe)"#),
+    "ABZE" => (0, false, "", "", r#"NFL Blitz 20-02 (USA)"#),
+    "ANKE" => (0, false, "", "", r#"NFL Blitz 20-03 (USA)"#),
+    "ANLE" => (2, false, "", "", r#"NHL 2002 (USA)"#),
+    "AN4E" => (1, false, "", "", r#"NHL Hitz 20-03 (USA)"#),
+    "BUJE" => (0, false, "", "", r#"Nicktoons - Attack of the Toybots (USA)"#),
+    "BNVE" => (1, false, "", "", r#"Nicktoons - Battle for Volcano Island (USA)"#),
+    "BCCE" => (1, false, "", "", r#"Nicktoons - Freeze Frame Frenzy (USA)"#),
+    "ANQP" => (1, false, "", "", r#"Nicktoons Racing (Europe)"#),
+    "ANQE" => (1, false, "", "", r#"Nicktoons Racing (USA)"#),
+    "BNUE" => (0, false, "", "", r#"Nicktoons Unite! (USA)"#),
+    "ANTJ" => (3, false, "", "", r#"Nihon Pro Mahjong Renmei Kounin Tetsuman Advance - Menkyo Kaiden Series (Japan)"#),
+    "ANXP" => (1, false, "", "", r#"Ninja Cop (Europe)"#),
+    "ANXE" => (1, false, "", "", r#"Ninja Five-0 (USA)"#),
+    "BKRJ" => (1, false, "", "", r#"No No No Puzzle Chailien (Japan)"#),
+    "AGPE" => (0, false, "", "", r#"No Rules - Get Phat (Europe, USA)"#),
+    "AGPP" => (0, false, "", "", r#"No Rules - Get Phat (Europe, USA)"#),
+    "ANOJ" => (3, false, "", "", r#"Nobunaga Ibun (Japan)"#),
+    "ANBJ" => (5, false, "", "", r#"Nobunaga no Yabou (Japan)"#),
+    "BNKP" => (0, false, "", "", r#"Noddy - A Day in Toyland (Europe)"#),
+    "BNKE" => (0, false, "", "", r#"Noddy - A Day in Toyland (USA)"#),
+    "BNYJ" => (1, false, "", "", r#"Nyan Nyan Nyanko no NyanCollection (Japan)"#),
+    "BIKJ" => (1, false, "", "", r#"Ochaken Kururin - Honwaka Puzzle de Hotto Shiyo? (Japan)"#),
+    "BO2J" => (2, false, "", "", r#"Ochaken no Bouken-jima - Honwaka Yume no Island (Japan)"#),
+    "BDRJ" => (1, false, "", "", r#"Ochaken no Heya (Japan)"#),
+    "BCUJ" => (1, false, "", "", r#"Ochaken no Yume Bouken (Japan)"#),
+    "BODD" => (0, false, "", "", r#"Oddworld - Munch's Oddysee (Germany)"#),
+    "BODE" => (0, false, "", "", r#"Oddworld - Munch's Oddysee (Europe, USA)"#),
+    "A87J" => (2, false, "", "", r#"Ohanaya-san Monogatari GBA - Iyashikei Ohanaya-san Ikusei Game (Japan)"#),
+    "BOJJ" => (1, false, "", "", r#"Ojarumaru - Gekkouchou Sanpo de Ojaru (Japan)"#),
+    "AOKJ" => (3, false, "", "", r#"Okumanchouja Game - Nottori Daisakusen! (Japan)"#),
+    "BONE" => (1, false, "", "", r#"One Piece (USA)"#),
+    "BIPJ" => (2, false, "", "", r#"One Piece - Dragon Dream! (Japan)"#),
+    "BO8J" => (2, false, "", "", r#"One Piece - Going Baseball - Kaizoku Yakyuu (Japan)"#),
+    "A6OP" => (2, false, "", "", r#"Onimusha Tactics (Europe)"#),
+    "A6OJ" => (2, false, "", "", r#"Onimusha Tactics (Japan)"#),
+    "A6OE" => (2, false, "", "", r#"Onimusha Tactics (USA)"#),
+    "BITJ" => (2, false, "", "", r#"Onmyou Taisenki Zeroshiki (Japan)"#),
+    "BOAE" => (1, false, "", "", r#"Open Season (USA)"#),
+    "BAAE" => (0, false, "", "", r#"Operation Armored Liberty (USA)"#),
+    "AORJ" => (3, false, "", "", r#"Oriental Blue - Ao no Tengai (Japan)"#),
+    "AICJ" => (5, false, "", "", r#"Oshaberi Inko Club (Japan)"#),
+    "AOPJ" => (2, false, "", "", r#"Oshare Princess (Japan)"#),
+    "AO2J" => (2, false, "", "", r#"Oshare Princess 2 (Japan)"#),
+    "BO3J" => (2, false, "", "", r#"Oshare Princess 3 (Japan)"#),
+    "BO5J" => (1, false, "", "", r#"Oshare Princess 5 (Japan)"#),
+    "A5SJ" => (2, false, "", "", r#"Oshare Wanko (Japan)"#),
+    "BOFD" => (0, false, "", "", r#"Ottifanten Pinball (Germany)"#),
+    "AGSJ" => (5, false, "", "", r#"Ougon no Taiyou - Hirakareshi Fuuin (Japan)"#),
+    "AGFJ" => (5, false, "", "", r#"Ougon no Taiyou - Ushinawareshi Toki (Japan)"#),
+    "BH5P" => (1, false, "", "", r#"Over the Hedge (Europe)"#),
+    "BH5E" => (1, false, "", "", r#"Over the Hedge (USA)"#),
+    "BH7P" => (1, false, "", "", r#"Over the Hedge - Hammy Goes Nuts! (Europe)"#),
+    "BH7E" => (1, false, "", "", r#"Over the Hedge - Hammy Goes Nuts! (USA)"#),
+    "BOZE" => (0, false, "", "", r#"Ozzy & Drix (USA)"#),
+    "APCP" => (0, false, "", "", r#"Pac-Man Collection (Europe)"#),
+    "APCJ" => (0, false, "", "", r#"Pac-Man Collection (Japan)"#),
+    "APCE" => (0, false, "", "", r#"Pac-Man Collection (USA)"#),
+    "BP8P" => (0, false, "", "", r#"Pac-Man Pinball Advance (Europe)"#),
+    "BP8E" => (0, false, "", "", r#"Pac-Man Pinball Advance (USA)"#),
+    "BPAP" => (1, false, "", "", r#"Pac-Man World (Europe)"#),
+    "BPAE" => (1, false, "", "", r#"Pac-Man World (USA)"#),
+    "B2CP" => (0, false, "", "", r#"Pac-Man World 2 (Europe)"#),
+    "B2CE" => (0, false, "", "", r#"Pac-Man World 2 (USA)"#),
+    "BURE" => (2, false, "", "", r#"Paws & Claws - Pet Resort (USA)"#),
+    "BQVE" => (2, false, "", "", r#"Paws & Claws - Pet Vet (USA)"#),
+    "BPKP" => (1, false, "", "", r#"Payback (Europe)"#),
+    "BPZJ" => (1, false, "", "", r#"Pazuninn - Umininn no Puzzle de Nimu (Japan)"#),
+    "AQAP" => (3, false, "", "", r#"Penny Racers (Europe)"#),
+    "APPP" => (0, false, "", "", r#"Disney's Peter Pan - Return to Neverland (Europe, rev. 1)"#),
+    "APPE" => (0, false, "", "", r#"Disney's Peter Pan - Return to Neverland (USA, rev. 1)"#),
+    "BPTP" => (0, false, "", "", r#"Peter Pan - The Motion Picture Event (Europe)"#),
+    "BPTE" => (1, false, "", "", r#"Peter Pan - The Motion Picture Event (USA)"#),
+    "AJHE" => (3, false, "", "", r#"Petz - Hamsterz Life 2 (USA)"#),
+    "BNBE" => (2, false, "", "", r#"Petz Vet (USA)"#),
+    "BEFP" => (2, false, "", "", r#"Pferd & Pony - Best Friends - Mein Pferd ~ Best Friends - My Horse (Germany)"#),
+    "BYPP" => (2, false, "", "", r#"Pferd & Pony - Lass Uns Reiten 2 ~ Horse & Pony - Let's Ride 2 (Germany)"#),
+    "BHUP" => (2, false, "", "", r#"Pferd & Pony - Mein Gestuet ~ My Stud Farm (Germany)"#),
+    "BPVP" => (2, false, "", "", r#"Pferd & Pony - Mein Pferdehof (Germany)"#),
+    "APXP" => (1, false, "", "", r#"Phalanx - The Enforce Fighter A-144 (Europe)"#),
+    "APXJ" => (1, false, "", "", r#"Phalanx - The Enforce Fighter A-144 (Japan)"#),
+    "APXE" => (1, false, "", "", r#"Phalanx - The Enforce Fighter A-144 (USA)"#),
+    "AYCP" => (2, false, "", "", r#"Phantasy Star Collection (Europe)"#),
+    "AYCE" => (2, false, "", "", r#"Phantasy Star Collection (USA)"#),
+    "BFXE" => (1, false, "", "", r#"Phil of the Future (USA)"#),
+    "BP3J" => (2, false, "", "", r#"Pia Carrot e Youkoso!! 3.3 (Japan)"#),
+    "A9NX" => (0, false, "", "", r#"Disney's Piglet's Big Game (Europe)"#),
+    "A9NE" => (0, false, "", "", r#"Piglet's Big Game (USA)"#),
+    "BPNJ" => (2, false, "", "", r#"Pikapika Nurse Monogatari - Nurse Ikusei Game (Japan)"#),
+    "APZP" => (1, false, "", "", r#"Pinball Advance (Europe)"#),
+    "APLP" => (0, false, "", "", r#"Pinball Challenge Deluxe (Europe)"#),
+    "APDP" => (1, false, "", "", r#"The Pinball of the Dead (Europe)"#),
+    "AZOJ" => (1, false, "", "", r#"The Pinball of the Dead (Japan)"#),
+    "APDE" => (1, false, "", "", r#"The Pinball of the Dead (USA)"#),
+    "A2TP" => (0, false, "", "", r#"Pinball Tycoon (Europe)"#),
+    "A2TE" => (0, false, "", "", r#"Pinball Tycoon (USA)"#),
+    "AP7P" => (0, false, "", "", r#"Pink Panther - Pinkadelic Pursuit (Europe)"#),
+    "APIP" => (2, false, "", "", r#"Pinky and the Brain - The Masterplan (Europe)"#),
+    "APNJ" => (2, false, "", "", r#"Pinky Monkey Town (Japan)"#),
+    "AP6J" => (1, false, "", "", r#"Pinobee & Phoebee (Japan)"#),
+    "APBE" => (3, false, "", "", r#"Pinobee - Wings of Adventure (Europe, USA)"#),
+    "APBP" => (3, false, "", "", r#"Pinobee - Wings of Adventure (Europe, USA)"#),
+    "APBJ" => (3, false, "", "", r#"Pinobee no Daibouken (Japan)"#),
+    "B8QE" => (1, false, "", "", r#"Pirates of the Caribbean - Dead Man's Chest (Europe, USA)"#),
+    "B8QP" => (1, false, "", "", r#"Pirates of the Caribbean - Dead Man's Chest (Europe, USA)"#),
+    "A8QP" => (0, false, "", "", r#"Pirates of the Caribbean - The Curse of the Black Pearl (Europe)"#),
+    "A8QE" => (0, false, "", "", r#"Pirates of the Caribbean - The Curse of the Black Pearl (USA)"#),
+    "BPHP" => (1, false, "", "", r#"Pitfall - The Lost Expedition (Europe)"#),
+    "BPHE" => (1, false, "", "", r#"Pitfall - The Lost Expedition (USA)"#),
+    "APFE" => (0, false, "", "", r#"Pitfall - The Mayan Adventure (Europe, USA)"#),
+    "APFP" => (0, false, "", "", r#"Pitfall - The Mayan Adventure (Europe, USA)"#),
+    "APMP" => (0, false, "", "", r#"Planet Monsters (Europe)"#),
+    "APME" => (0, false, "", "", r#"Planet Monsters (USA)"#),
+    "AYNP" => (0, false, "", "", r#"Planet of the Apes (Europe)"#),
+    "AYNE" => (0, false, "", "", r#"Planet of the Apes (USA)"#),
+    "ASHJ" => (3, false, "", "", r#"Play Novel - Silent Hill (Japan)"#),
+    "BTDE" => (1, false, "", "", r#"Pocket Dogs (USA)"#),
+    "BPEJ" => (5, true, "", "", r#"Pocket Monsters - Emerald (Japan)"#),
+    "BPRJ" => (5, false, "", "", r#"Pocket Monsters - Fire Red (Japan, rev. 1)"#),
+    "B24J" => (4, false, "sst", "", r#"Pocket Monsters - Fushigi no Dungeon Aka no Kyuujotai (Japan)"#),
+    "BPGJ" => (5, false, "macronix", "", r#"Pocket Monsters - Leaf Green (Japan, rev. 1)"#),
+    "AXVJ" => (5, true, "", "", r#"Pocket Monsters - Ruby (Japan)"#),
+    "AXPJ" => (5, true, "sanyo", "", r#"Pocket Monsters - Sapphire (Japan)"#),
+    "AP9P" => (3, false, "", "", r#"Pocket Music (Europe)"#),
+    "BPJE" => (1, false, "", "", r#"Pocket Professor - Kwik Notes - Vol. 1 (USA)"#),
+    "APKE" => (0, false, "", "", r#"Pocky & Rocky with Becky (USA)"#),
+    "BTDJ" => (1, false, "", "", r#"Poke Inu (Japan)"#),
+    "BPEE" => (5, true, "macronix", "", r#"Pokémon - Emerald Version (Europe, USA, Australia)"#),
+    "BPEP" => (5, true, "macronix", "", r#"Pokémon - Emerald Version (Europe, USA, Australia)"#),
+    "BPES" => (5, true, "macronix", "", r#"Pokémon - Edición Esmeralda (Spain)"#),
+    "BPRE" => (5, false, "sanyo", "", r#"Pokémon - FireRed Version (Europe, USA, rev. 1)"#),
+    "BPRP" => (5, false, "sanyo", "", r#"Pokémon - FireRed Version (Europe, USA, rev. 1)"#),
+    "BPRI" => (5, false, "macronix", "", r#"Pokémon - Versione Rosso Fuoco (Italy)"#),
+    "BPRF" => (5, false, "sanyo", "", r#"Pokémon - Version Rouge Feu (France)"#),
+    "BPGE" => (5, false, "sanyo", "", r#"Pokémon - LeafGreen Version (Europe, USA, rev. 1)"#),
+    "BPGP" => (5, false, "sanyo", "", r#"Pokémon - LeafGreen Version (Europe, USA, rev. 1)"#),
+    "BPGI" => (5, false, "macronix", "", r#"Pokémon - Versione Verde Foglia (Italy)"#),
+    "BPGS" => (5, false, "macronix", "", r#"Pokémon - Edición Verde Hoja (Spain)"#),
+    "AXVP" => (5, true, "macronix", "", r#"Pokémon - Ruby Version (Europe, USA, rev. 2)"#),
+    "AXVE" => (5, true, "macronix", "", r#"Pokémon - Ruby Version (Europe, USA, rev. 2)"#),
+    "AXVS" => (5, true, "macronix", "", r#"Pokémon - Edición Rubí (Spain, rev. 1)"#),
+    "AXVI" => (5, true, "macronix", "", r#"Pokémon - Versione Rubino (Italy)"#),
+    "AXPP" => (5, true, "macronix", "", r#"Pokémon - Sapphire Version (Europe, USA, rev. 2)"#),
+    "AXPE" => (5, true, "macronix", "", r#"Pokémon - Sapphire Version (Europe, USA, rev. 2)"#),
+    "AXPS" => (5, true, "sanyo", "", r#"Pokémon - Edición Zafiro (Spain)"#),
+    "AXPI" => (5, true, "macronix", "", r#"Pokémon - Versione Zaffiro (Italy)"#),
+    "B24P" => (5, false, "macronix", "", r#"Pokémon Mystery Dungeon - Red Rescue Team (Europe)"#),
+    "B24E" => (5, false, "", "", r#"Pokémon Mystery Dungeon - Red Rescue Team (USA, Australia)"#),
+    "B24U" => (5, false, "", "", r#"Pokémon Mystery Dungeon - Red Rescue Team (USA, Australia)"#),
+    "BPPP" => (3, false, "", "", r#"Pokémon Pinball - Ruby & Sapphire (Europe)"#),
+    "BPPJ" => (3, false, "", "", r#"Pokémon Pinball - Ruby & Sapphire (Japan, rev. 1)"#),
+    "BPPE" => (3, false, "", "", r#"Pokémon Pinball - Ruby & Sapphire (USA)"#),
+    "BPXE" => (1, false, "", "", r#"The Polar Express (Europe, USA)"#),
+    "BPXP" => (1, false, "", "", r#"The Polar Express (Europe, USA)"#),
+    "BIIP" => (4, false, "", "", r#"Polarium Advance (Europe)"#),
+    "BIIE" => (4, false, "", "", r#"Polarium Advance (USA)"#),
+    "AOTE" => (0, false, "", "", r#"Polly Pocket! - Super Splash Island (USA)"#),
+    "B3FP" => (0, false, "", "", r#"Polly Pocket! - Super Splash Island (Europe, Zoo Digital re-release)"#),
+    "BIDP" => (0, false, "", "", r#"Pop Idol (Europe)"#),
+    "APOE" => (0, false, "", "", r#"Popeye - Rush for Spinach (Europe, USA)"#),
+    "BROP" => (0, false, "", "", r#"Postman Pat and the Greendale Rocket (Europe)"#),
+    "BBQJ" => (3, false, "", "", r#"Power Poke Dash (Japan)"#),
+    "B8PJ" => (3, false, "", "", r#"Power Pro Kun Pocket 1, 2 (Japan)"#),
+    "AP3J" => (3, false, "", "", r#"Power Pro Kun Pocket 3 (Japan, rev. 1)"#),
+    "AP4J" => (3, false, "", "", r#"Power Pro Kun Pocket 4 (Japan, rev. 1)"#),
+    "A5PJ" => (3, false, "", "", r#"Power Pro Kun Pocket 5 (Japan, rev. 1)"#),
+    "BP6J" => (3, false, "", "", r#"Power Pro Kun Pocket 6 (Japan)"#),
+    "BP7J" => (3, false, "", "", r#"Power Pro Kun Pocket 7 (Japan)"#),
+    "BPOE" => (0, false, "", "", r#"Power Rangers - Dino Thunder (Europe, USA)"#),
+    "BPOP" => (0, false, "", "", r#"Power Rangers - Dino Thunder (Europe, USA)"#),
+    "BPWP" => (0, false, "", "", r#"Power Rangers - Ninja Storm (Europe)"#),
+    "BPWE" => (0, false, "", "", r#"Power Rangers - Ninja Storm (USA)"#),
+    "APRE" => (0, false, "", "", r#"Saban's Power Rangers - Time Force (Europe, USA)"#),
+    "APRP" => (0, false, "", "", r#"Saban's Power Rangers - Time Force (Europe, USA)"#),
+    "APRD" => (0, false, "", "", r#"Saban's Power Rangers - Time Force (Germany)"#),
+    "APWE" => (0, false, "", "", r#"Saban's Power Rangers - Wild Force (Europe, USA)"#),
+    "BRDE" => (0, false, "", "", r#"Power Rangers S.P.D. (Europe, USA)"#),
+    "AP5P" => (1, false, "", "", r#"The Powerpuff Girls - Him and Seek (Europe)"#),
+    "AP5E" => (1, false, "", "", r#"The Powerpuff Girls - Him and Seek (USA)"#),
+    "APTE" => (1, false, "", "", r#"The Powerpuff Girls - Mojo Jojo A-Go-Go (USA)"#),
+    "APHE" => (0, false, "", "", r#"Prehistorik Man (Europe, USA)"#),
+    "APHP" => (0, false, "", "", r#"Prehistorik Man (Europe, USA)"#),
+    "BAQP" => (2, false, "", "", r#"Anstoss Action ~ Premier Action (Europe)"#),
+    "BPMP" => (4, false, "sst", "", r#"Premier Manager 2003-04 (Europe)"#),
+    "BP4P" => (4, false, "sst", "", r#"Premier Manager 2004-2005 (Europe)"#),
+    "BP5P" => (4, false, "sst", "", r#"Premier Manager 2005-2006 (Europe)"#),
+    "BPYP" => (1, false, "", "", r#"Prince of Persia - The Sands of Time (Europe)"#),
+    "BPYE" => (1, false, "", "", r#"Prince of Persia - The Sands of Time (USA, rev. 1)"#),
+    "BNPP" => (0, false, "", "", r#"Princess Natasha - Student, Secret Agent, Princess (Europe)"#),
+    "BNPE" => (0, false, "", "", r#"Princess Natasha - Student, Secret Agent, Princess (USA)"#),
+    "AVEP" => (1, false, "", "", r#"Pro Beach Soccer (Europe)"#),
+    "B2OJ" => (2, false, "", "", r#"Pro Mahjong Tsuwamono GBA (Japan)"#),
+    "ATEP" => (1, false, "", "", r#"Pro Tennis WTA Tour (Europe)"#),
+    "ALMJ" => (3, false, "", "", r#"Pro Yakyuu Team o Tsukurou! Advance (Japan)"#),
+    "BD7E" => (1, false, "", "", r#"The Proud Family (USA)"#),
+    "APUJ" => (2, false, "", "", r#"PukuPuku Tennen Kairanban (Japan)"#),
+    "BPQJ" => (2, false, "", "", r#"PukuPuku Tennen Kairanban - Koi no Cupid Daisakusen (Japan)"#),
+    "B3PJ" => (2, false, "", "", r#"PukuPuku Tennen Kairanban - Youkoso! Illusion Land e (Japan)"#),
+    "APGP" => (0, false, "", "", r#"Punch King - Arcade Boxing (Europe)"#),
+    "APGE" => (0, false, "", "", r#"Punch King - Arcade Boxing (USA)"#),
+    "BYXE" => (1, false, "", "", r#"Puppy Luv - Spa and Resort (USA)"#),
+    "APYP" => (1, false, "", "", r#"Puyo Pop (Europe)"#),
+    "APYE" => (1, false, "", "", r#"Puyo Pop (USA)"#),
+    "BPFP" => (1, false, "", "", r#"Puyo Pop Fever (Europe)"#),
+    "BPFJ" => (1, false, "", "", r#"Puyo Puyo Fever (Japan)"#),
+    "AEHJ" => (2, false, "", "", r#"Puzzle & Tantei Collection (Japan)"#),
+    "BPBJ" => (3, false, "", "", r#"Pyuu to Fuku! Jaguar - Byoo to Deru! Megane-kun (Japan)"#),
+    "BQDE" => (0, false, "", "", r#"Quad Desert Fury (Europe, USA)"#),
+    "BR3P" => (0, false, "", "", r#"R-Type III - The Third Lightning (Europe)"#),
+    "BR3E" => (0, false, "", "", r#"R-Type III - The Third Lightning (USA)"#),
+    "BRWP" => (0, false, "", "", r#"Racing Fever (Europe)"#),
+    "BRWF" => (0, false, "", "", r#"Racing Fever (France)"#),
+    "BRAP" => (1, false, "", "", r#"Racing Gears Advance (Europe)"#),
+    "BRAE" => (1, false, "", "", r#"Racing Gears Advance (USA)"#),
+    "ARXE" => (0, false, "", "", r#"Rampage - Puzzle Attack (Europe, USA)"#),
+    "ARXP" => (0, false, "", "", r#"Rampage - Puzzle Attack (Europe, USA)"#),
+    "BRFE" => (1, false, "", "", r#"Rapala Pro Fishing (Europe, USA)"#),
+    "BRFP" => (1, false, "", "", r#"Rapala Pro Fishing (Europe, USA)"#),
+    "BNLE" => (0, false, "", "", r#"Ratatouille (USA)"#),
+    "BRME" => (3, false, "", "", r#"Rave Master - Special Attack Force! (USA)"#),
+    "BX5P" => (2, false, "", "", r#"Rayman - 10th Anniversary (Europe)"#),
+    "BX5E" => (2, false, "", "", r#"Rayman - 10th Anniversary (USA)"#),
+    "BRYP" => (1, false, "", "", r#"Rayman - Hoodlums' Revenge (Europe)"#),
+    "BRYE" => (1, false, "", "", r#"Rayman - Hoodlum's Revenge (USA)"#),
+    "BQ3P" => (1, false, "", "", r#"Rayman - Raving Rabbids (Europe)"#),
+    "BQ3E" => (1, false, "", "", r#"Rayman - Raving Rabbids (USA)"#),
+    "AYZP" => (1, false, "", "", r#"Rayman 3 (Europe)"#),
+    "AYZE" => (1, false, "", "", r#"Rayman 3 (USA)"#),
+    "ARYP" => (1, false, "", "", r#"Rayman Advance (Europe)"#),
+    "ARYE" => (1, false, "", "", r#"Rayman Advance (USA)"#),
+    "ARFE" => (0, false, "", "", r#"Razor Freestyle Scooter (USA)"#),
+    "AR2P" => (0, false, "", "", r#"Ready 2 Rumble Boxing - Round 2 (Europe)"#),
+    "AR2E" => (0, false, "", "", r#"Ready 2 Rumble Boxing - Round 2 (USA)"#),
+    "BRLP" => (2, false, "", "", r#"Rebelstar - Tactical Command (Europe)"#),
+    "BRLE" => (2, false, "", "", r#"Rebelstar - Tactical Command (USA)"#),
+    "ARHJ" => (3, false, "", "", r#"Recca no Honoo - The Game (Japan)"#),
+    "AR9P" => (0, false, "", "", r#"Reign of Fire (Europe)"#),
+    "AR9E" => (0, false, "", "", r#"Reign of Fire (USA)"#),
+    "BR9J" => (1, false, "", "", r#"Relaxuma na Mainichi (Japan)"#),
+    "AQHE" => (0, false, "", "", r#"Rescue Heroes - Billy Blazes! (USA)"#),
+    "A3RP" => (0, false, "", "", r#"The Revenge of Shinobi (Europe)"#),
+    "A3RE" => (0, false, "", "", r#"The Revenge of Shinobi (USA)"#),
+    "BRIJ" => (3, false, "", "", r#"Rhythm Tengoku (Japan, rev. 1)"#),
+    "ARDE" => (0, false, "", "", r#"The Ripping Friends (Europe, USA)"#),
+    "ARDP" => (0, false, "", "", r#"The Ripping Friends (Europe, USA)"#),
+    "BDTE" => (2, false, "", "", r#"River City Ransom EX (USA)"#),
+    "BREE" => (3, false, "", "", r#"Riviera - The Promised Land (USA)"#),
+    "BREJ" => (3, false, "", "", r#"Riviera - Yakusoku no Chi Riviera (Japan, rev. 1)"#),
+    "A9RP" => (0, false, "", "", r#"Road Rash - Jailbreak (Europe)"#),
+    "A9RE" => (0, false, "", "", r#"Road Rash - Jailbreak (USA)"#),
+    "A6RE" => (3, false, "", "", r#"Road Trip - Shifting Gears (USA)"#),
+    "ACVE" => (5, false, "", "", r#"Robopon 2 - Cross Version (USA)"#),
+    "ARPE" => (5, false, "", "", r#"Robopon 2 - Ring Version (USA)"#),
+    "ACVJ" => (5, false, "", "", r#"Robot Ponkottsu 2 - Cross Version (Japan)"#),
+    "ARPJ" => (5, false, "", "", r#"Robot Ponkottsu 2 - Ring Version (Japan)"#),
+    "ARWP" => (1, false, "", "", r#"Robot Wars - Advanced Destruction (Europe)"#),
+    "ARUE" => (1, false, "", "", r#"Robot Wars - Advanced Destruction (USA)"#),
+    "ARSP" => (1, false, "", "", r#"Robot Wars - Extreme Destruction (Europe)"#),
+    "ARBE" => (1, false, "", "", r#"Robotech - The Macross Saga (Europe, USA)"#),
+    "ARBP" => (1, false, "", "", r#"Robotech - The Macross Saga (Europe, USA)"#),
+    "BRTP" => (1, false, "", "", r#"Robots (Europe)"#),
+    "BRTJ" => (1, false, "", "", r#"Robots (Japan)"#),
+    "BRTE" => (1, false, "", "", r#"Robots (USA)"#),
+    "A4RP" => (1, false, "", "", r#"Rock n' Roll Racing (Europe)"#),
+    "A4RE" => (1, false, "", "", r#"Rock n' Roll Racing (USA)"#),
+    "BR7P" => (0, false, "", "", r#"Rock'em Sock'em Robots (Europe)"#),
+    "BR7E" => (0, false, "", "", r#"Rock'em Sock'em Robots (USA)"#),
+    "AR4E" => (0, false, "", "", r#"Rocket Power - Beach Bandits (Europe, USA)"#),
+    "ARKE" => (0, false, "", "", r#"Rocket Power - Dream Scheme (Europe, USA)"#),
+    "ARKP" => (0, false, "", "", r#"Rocket Power - Dream Scheme (Europe, USA)"#),
+    "AZZE" => (0, false, "", "", r#"Rocket Power - Zero Gravity Zone (USA)"#),
+    "AFCJ" => (2, false, "", "", r#"Rockman & Forte (Japan)"#),
+    "B4BJ" => (3, false, "", "", r#"Rockman EXE 4 - Tournament Blue Moon (Japan)"#),
+    "B4WJ" => (3, false, "", "", r#"Rockman EXE 4 - Tournament Red Sun (Japan, rev. 1)"#),
+    "BR4J" => (5, true, "sst", "", r#"Rockman EXE 4.5 - Real Operation (Japan)"#),
+    "BRBJ" => (3, false, "", "", r#"Rockman EXE 5 - Team of Blues (Japan)"#),
+    "BRKJ" => (3, false, "", "", r#"Rockman EXE 5 - Team of Colonel (Japan)"#),
+    "BR6J" => (3, false, "", "", r#"Rockman EXE 6 - Dennoujuu Falzar (Japan)"#),
+    "BR5J" => (3, false, "", "", r#"Rockman EXE 6 - Dennoujuu Gregar (Japan)"#),
+    "A89J" => (3, false, "", "", r#"Rockman EXE Battle Chip GP (Japan)"#),
+    "ARZJ" => (3, false, "", "", r#"Rockman Zero (Japan)"#),
+    "A62J" => (3, false, "", "", r#"Rockman Zero 2 (Japan)"#),
+    "BZ3J" => (3, false, "", "", r#"Rockman Zero 3 (Japan)"#),
+    "B4ZJ" => (2, false, "", "", r#"Rockman Zero 4 (Japan)"#),
+    "AR8P" => (1, false, "", "", r#"Rocky (Europe)"#),
+    "AR8E" => (1, false, "", "", r#"Rocky (USA)"#),
+    "A8TJ" => (4, false, "", "", r#"RPG Tsukuru Advance (Japan)"#),
+    "ARGE" => (0, false, "", "", r#"Rugrats - Castle Capers (Europe, USA)"#),
+    "ARGP" => (0, false, "", "", r#"Rugrats - Castle Capers (Europe, USA)"#),
+    "A5WE" => (0, false, "", "", r#"Rugrats - Go Wild (Europe, USA)"#),
+    "A5WP" => (0, false, "", "", r#"Rugrats - Go Wild (Europe, USA)"#),
+    "AR5E" => (0, false, "", "", r#"Rugrats - I Gotta Go Party (Europe, USA)"#),
+    "AR5P" => (0, false, "", "", r#"Rugrats - I Gotta Go Party (Europe, USA)"#),
+    "AWUP" => (2, false, "", "", r#"Sabre Wulf (Europe)"#),
+    "AWUE" => (2, false, "", "", r#"Sabre Wulf (USA)"#),
+    "A3BP" => (0, false, "", "", r#"Sabrina - The Teenage Witch - Potion Commotion (Europe)"#),
+    "A3BE" => (0, false, "", "", r#"Sabrina - The Teenage Witch - Potion Commotion (USA)"#),
+    "ASMJ" => (3, false, "", "", r#"Saibara Rieko no Dendou Mahjong (Japan)"#),
+    "ACLJ" => (3, false, "", "", r#"Sakura Momoko no UkiUki Carnival (Japan)"#),
+    "AWGP" => (1, false, "", "", r#"Salt Lake 2002 (Europe)"#),
+    "AS5E" => (1, false, "", "", r#"Salt Lake 2002 (USA)"#),
+    "AOSJ" => (1, false, "", "", r#"Samurai Deeper Kyo (Japan)"#),
+    "AOSE" => (1, false, "", "", r#"Samurai Deeper Kyo (USA)"#),
+    "AECJ" => (3, false, "", "", r#"Samurai Evolution - Oukoku Geist (Japan)"#),
+    "AJTE" => (1, false, "", "", r#"Samurai Jack - The Amulet of Time (Europe, USA)"#),
+    "AJTP" => (1, false, "", "", r#"Samurai Jack - The Amulet of Time (Europe, USA)"#),
+    "ASXJ" => (5, false, "", "", r#"Sangokushi (Japan)"#),
+    "B3EJ" => (4, false, "", "", r#"Sangokushi - Eiketsuden (Japan)"#),
+    "B3QJ" => (4, false, "", "", r#"Sangokushi - Koumeiden (Japan)"#),
+    "A85J" => (2, false, "", "", r#"Sanrio Puroland - All Characters (Japan)"#),
+    "ASNJ" => (2, false, "", "", r#"Samsara Naga 1x2 (Japan, rev. 2)"#),
+    "AXXP" => (1, false, "", "", r#"Santa Claus Jr. Advance (Europe)"#),
+    "AUZP" => (0, false, "", "", r#"Santa Claus Saves the Earth (Europe)"#),
+    "B33E" => (1, false, "", "", r#"The Santa Clause 3 - The Escape Clause (USA)"#),
+    "A57J" => (3, false, "", "", r#"Scan Hunter - Sennen Kaigyo o Oe! (Japan)"#),
+    "AP8P" => (1, false, "", "", r#"Scooby-Doo (Europe)"#),
+    "AP8E" => (1, false, "", "", r#"Scooby-Doo (USA)"#),
+    "BMUE" => (0, false, "", "", r#"Scooby-Doo 2 - Monsters Unleashed (Europe, USA)"#),
+    "BMUP" => (0, false, "", "", r#"Scooby-Doo 2 - Monsters Unleashed (Europe, USA)"#),
+    "ASDE" => (0, false, "", "", r#"Scooby-Doo and the Cyber Chase (Europe, USA)"#),
+    "ASDP" => (0, false, "", "", r#"Scooby-Doo and the Cyber Chase (Europe, USA)"#),
+    "ASDX" => (0, false, "", "", r#"Scooby-Doo and the Cyber Chase (Europe, English / French / German)"#),
+    "BMMP" => (1, false, "", "", r#"Scooby-Doo! - Mystery Mayhem (Europe)"#),
+    "BMME" => (1, false, "", "", r#"Scooby-Doo! - Mystery Mayhem (USA)"#),
+    "B25E" => (0, false, "", "", r#"Scooby-Doo! - Unmasked (USA)"#),
+    "ASZP" => (0, false, "", "", r#"The Scorpion King - Sword of Osiris (Europe)"#),
+    "ASZE" => (0, false, "", "", r#"The Scorpion King - Sword of Osiris (USA)"#),
+    "AQBP" => (3, false, "", "", r#"Scrabble (Europe)"#),
+    "BLAE" => (0, false, "", "", r#"Scrabble Blast! (USA)"#),
+    "V49J" => (3, false, "", "rumble", r#"Screw Breaker - Goushin DoriRureRo (Japan)"#),
+    "BHVP" => (1, false, "", "", r#"Scurge - Hive (Europe)"#),
+    "BHVE" => (1, false, "", "", r#"Scurge - Hive (USA)"#),
+    "BG4J" => (1, false, "", "", r#"SD Gundam Force (Japan)"#),
+    "BGEE" => (1, false, "", "", r#"SD Gundam Force (USA)"#),
+    "BGAJ" => (2, false, "", "", r#"SD Gundam GGeneration Advance (Japan)"#),
+    "A7ME" => (0, false, "", "", r#"Sea-Monkeys (USA)"#),
+    "ALJE" => (2, false, "", "", r#"Sea Trader - Rise of Taipan (USA)"#),
+    "AAHP" => (0, false, "", "", r#"Secret Agent Barbie - Royal Jewels Mission (Europe)"#),
+    "AAHE" => (0, false, "", "", r#"Secret Agent Barbie - Royal Jewels Mission (USA)"#),
+    "AYPP" => (0, false, "", "", r#"Sega Arcade Gallery (Europe)"#),
+    "AYPE" => (0, false, "", "", r#"Sega Arcade Gallery (USA)"#),
+    "AYLP" => (3, false, "", "", r#"Sega Rally Championship (Europe)"#),
+    "AYLJ" => (3, false, "", "", r#"Sega Rally Championship (Japan)"#),
+    "AYLE" => (3, false, "", "", r#"Sega Rally Championship (USA)"#),
+    "A3PP" => (0, false, "", "", r#"Sega Smash Pack (Europe)"#),
+    "A3PE" => (0, false, "", "", r#"Sega Smash Pack (USA)"#),
+    "A7GJ" => (3, false, "", "", r#"Sengoku Kakumei Gaiden (Japan)"#),
+    "BKAJ" => (5, true, "macronix", "", r#"Sennen Kazoku (Japan)"#),
+    "BSYJ" => (3, false, "", "", r#"Sentouin Yamada Hajime (Japan)"#),
+    "AENP" => (0, false, "", "", r#"Serious Sam Advance (Europe)"#),
+    "AENE" => (0, false, "", "", r#"Serious Sam Advance (USA)"#),
+    "BHLE" => (3, false, "", "", r#"Shonen Jump's Shaman King - Legacy of the Spirits - Soaring Hawk (USA)"#),
+    "BWSE" => (3, false, "", "", r#"Shonen Jump's Shaman King - Legacy of the Spirits - Sprinting Wolf (USA)"#),
+    "BSOP" => (3, false, "", "", r#"Shonen Jump's Shaman King - Master of Spirits (Europe)"#),
+    "BSOE" => (3, false, "", "", r#"Shonen Jump's Shaman King - Master of Spirits (USA)"#),
+    "B2MP" => (3, false, "", "", r#"Shonen Jump's Shaman King - Master of Spirits 2 (Europe)"#),
+    "B2ME" => (3, false, "", "", r#"Shonen Jump's Shaman King - Master of Spirits 2 (USA)"#),
+    "AKAJ" => (3, false, "", "", r#"Shaman King Card Game - Chou Senjiryakketsu 2 (Japan)"#),
+    "AL3J" => (3, false, "", "", r#"Shaman King Card Game - Chou Senjiryakketsu 3 (Japan)"#),
+    "BBAP" => (1, false, "", "", r#"Shamu's Deep Sea Adventures (Europe)"#),
+    "BBAE" => (1, false, "", "", r#"Shamu's Deep Sea Adventures (USA)"#),
+    "ASVJ" => (1, false, "", "", r#"Shanghai Advance (Japan)"#),
+    "BSUX" => (1, false, "", "", r#"Shark Tale (Europe, French / German / Spanish)"#),
+    "B9TJ" => (1, false, "", "", r#"Shark Tale (Japan)"#),
+    "BSUE" => (1, false, "", "", r#"Shark Tale (Europe, USA)"#),
+    "ASCD" => (0, false, "", "", r#"Shaun Palmer's Pro Snowboarder (Germany)"#),
+    "ASCE" => (0, false, "", "", r#"Shaun Palmer's Pro Snowboarder (Europe, USA)"#),
+    "ASCP" => (0, false, "", "", r#"Shaun Palmer's Pro Snowboarder (Europe, USA)"#),
+    "AEPP" => (2, false, "", "", r#"Sheep (Europe)"#),
+    "B4KJ" => (2, false, "", "", r#"Shikakui Atama o Maruku Suru. Advance - Kanji, Keisan (Japan)"#),
+    "B4RJ" => (2, false, "", "", r#"Shikakui Atama o Maruku Suru. Advance - Kokugo, Sansuu, Shakai, Rika (Japan)"#),
+    "A64J" => (2, false, "", "", r#"Shimura Ken no Baka Tonosama - Bakushou Tenka Touitsu Game (Japan, rev. 1)"#),
+    "U33J" => (2, true, "", "solar_sensor", r#"Shin Bokura no Taiyou - Gyakushuu no Sabata (Japan)"#),
+    "BKCS" => (2, false, "", "", r#"Shin Chan - Aventuras en Cineland (Spain)"#),
+    "AAJJ" => (3, false, "", "", r#"Shin Kisekae Monogatari (Japan)"#),
+    "AAUJ" => (3, false, "", "", r#"Shin Megami Tensei (Japan)"#),
+    "AL4J" => (3, false, "", "", r#"Shin Megami Tensei Devil Children - Hikari no Sho (Japan)"#),
+    "BDHJ" => (3, false, "", "", r#"Shin Megami Tensei Devil Children - Honoo no Sho (Japan)"#),
+    "BDYJ" => (3, false, "", "", r#"Shin Megami Tensei Devil Children - Koori no Sho (Japan)"#),
+    "BDLJ" => (3, false, "", "", r#"Shin Megami Tensei Devil Children - Messiah Riser (Japan)"#),
+    "A8ZJ" => (2, false, "", "", r#"Shin Megami Tensei Devil Children - Puzzle de Call! (Japan)"#),
+    "AC5J" => (3, false, "", "", r#"Shin Megami Tensei Devil Children - Yami no Sho (Japan)"#),
+    "A5TJ" => (3, false, "", "", r#"Shin Megami Tensei II (Japan)"#),
+    "ARAJ" => (3, false, "", "", r#"Shin Nihon Pro Wrestling - Toukon Retsuden Advance (Japan)"#),
+    "B36J" => (3, false, "", "", r#"Shin Sangoku Musou Advance (Japan)"#),
+    "BKVJ" => (2, false, "", "", r#"Shingata Medarot - Kabuto Version (Japan)"#),
+    "BKUJ" => (2, false, "", "", r#"Shingata Medarot - Kuwagata Version (Japan)"#),
+    "AF5J" => (3, false, "", "", r#"Shining Force - Kuroki Ryuu no Fukkatsu (Japan)"#),
+    "AF5P" => (3, false, "", "", r#"Shining Force - Resurrection of the Dark Dragon (Europe)"#),
+    "AF5E" => (3, false, "", "", r#"Shining Force - Resurrection of the Dark Dragon (USA)"#),
+    "AHUP" => (4, false, "sst", "", r#"Shining Soul (Europe)"#),
+    "AHUJ" => (5, false, "", "", r#"Shining Soul (Japan)"#),
+    "AHUE" => (4, false, "", "", r#"Shining Soul (USA)"#),
+    "AU2P" => (4, false, "", "", r#"Shining Soul II (Europe)"#),
+    "AU2J" => (4, false, "", "", r#"Shining Soul II (Japan)"#),
+    "AU2E" => (4, false, "", "", r#"Shining Soul II (USA)"#),
+    "AVSJ" => (4, false, "", "", r#"Shinyaku Seiken Densetsu (Japan)"#),
+    "ANVJ" => (3, false, "", "", r#"Shiren Monsters Netsal (Japan)"#),
+    "AH4P" => (1, false, "", "", r#"Shrek - Hassle at the Castle (Europe)"#),
+    "AH4E" => (1, false, "", "", r#"Shrek - Hassle at the Castle (USA)"#),
+    "AOIP" => (1, false, "", "", r#"Shrek - Reekin' Havoc (Europe)"#),
+    "AOIE" => (1, false, "", "", r#"Shrek - Reekin' Havoc (USA)"#),
+    "B4IP" => (1, false, "", "", r#"Shrek - Smash n' Crash Racing (Europe)"#),
+    "B4IE" => (1, false, "", "", r#"Shrek - Smash n' Crash Racing (USA)"#),
+    "B4UP" => (1, false, "", "", r#"Shrek - Super Slam (Europe)"#),
+    "B4UE" => (1, false, "", "", r#"Shrek - Super Slam (USA)"#),
+    "AS4E" => (1, false, "", "", r#"Shrek - Swamp Kart Speedway (USA, rev. 1)"#),
+    "AS4P" => (1, false, "", "", r#"Shrek - Swamp Kart Speedway (Europe, USA)"#),
+    "BSEE" => (1, false, "", "", r#"Shrek 2 (Europe, USA)"#),
+    "BSEP" => (1, false, "", "", r#"Shrek 2 (Europe, USA)"#),
+    "BSEX" => (1, false, "", "", r#"Shrek 2 (Europe, French / German / Italian / Spanish / Swedish)"#),
+    "BSIE" => (1, false, "", "", r#"Shrek 2 - Beg for Mercy (Europe, USA)"#),
+    "BSIP" => (1, false, "", "", r#"Shrek 2 - Beg for Mercy (Europe, USA)"#),
+    "B3HP" => (1, false, "", "", r#"Shrek the Third (Europe)"#),
+    "B3HE" => (1, false, "", "", r#"Shrek the Third (USA)"#),
+    "B3GE" => (1, false, "", "", r#"Sigma Star Saga (Europe, USA)"#),
+    "B3GP" => (1, false, "", "", r#"Sigma Star Saga (Europe, USA)"#),
+    "AIPP" => (1, false, "", "", r#"Silent Scope (Europe)"#),
+    "AIPJ" => (1, false, "", "", r#"Silent Scope (Japan)"#),
+    "AIPE" => (1, false, "", "", r#"Silent Scope (USA)"#),
+    "A7IJ" => (3, false, "", "", r#"Silk to Cotton (Japan)"#),
+    "A5CP" => (2, false, "", "", r#"Sim City 2000 (Europe)"#),
+    "A5CE" => (2, false, "", "", r#"Sim City 2000 (USA, rev. 1)"#),
+    "AZKJ" => (1, false, "", "", r#"Simple 2960 Tomodachi Series Vol. 1 - The Table Game Collection - Mahjong Shougi Hanafuda Reversi (Japan)"#),
+    "AZ9J" => (1, false, "", "", r#"Simple 2960 Tomodachi Series Vol. 2 - The Block Kuzushi (Japan, rev. 1)"#),
+    "BS3J" => (1, false, "", "", r#"Simple 2960 Tomodachi Series Vol. 3 - The Itsudemo Puzzle - Massugu Soroete Straws (Japan)"#),
+    "BS4J" => (1, false, "", "", r#"Simple 2960 Tomodachi Series Vol. 4 - The Trump - Minna de Asoberu 12 Shurui no Trump Game (Japan, rev. 1)"#),
+    "A4AE" => (0, false, "", "", r#"The Simpsons - Road Rage (Europe, USA)"#),
+    "A4AP" => (0, false, "", "", r#"The Simpsons - Road Rage (Europe, USA)"#),
+    "ASIE" => (2, false, "", "", r#"The Sims - Bustin' Out (Europe, USA)"#),
+    "ASIP" => (2, false, "", "", r#"The Sims - Bustin' Out (Europe, USA)"#),
+    "B4PJ" => (2, false, "", "", r#"The Sims (Japan)"#),
+    "B46E" => (4, false, "sst", "", r#"The Sims 2 (Europe, USA)"#),
+    "B46P" => (4, false, "sst", "", r#"The Sims 2 (Europe, USA)"#),
+    "B4OE" => (4, false, "sst", "", r#"The Sims 2 - Pets (Europe, USA)"#),
+    "B4OP" => (4, false, "sst", "", r#"The Sims 2 - Pets (Europe, USA)"#),
+    "A4PJ" => (1, false, "", "", r#"Sister Princess - RePure (Japan)"#),
+    "BSDP" => (0, false, "", "", r#"Sitting Ducks (Europe)"#),
+    "BSDE" => (0, false, "", "", r#"Sitting Ducks (USA)"#),
+    "ATHJ" => (1, false, "", "", r#"SK8 - Tony Hawk's Pro Skater 2 (Japan)"#),
+    "B4DP" => (0, false, "", "", r#"Sky Dancers - They Magically Fly! (Europe)"#),
+    "B4DE" => (0, false, "", "", r#"Sky Dancers - They Magically Fly! (USA)"#),
+    "A9KJ" => (1, false, "", "", r#"Slime Morimori Dragon Quest - Shougeki no Shippo Dan (Japan)"#),
+    "ATBJ" => (2, false, "", "", r#"Slot! Pro 2 Advance - GoGo Juggler & New Tairyou (Japan)"#),
+    "ASFJ" => (2, false, "", "", r#"Slot! Pro Advance - Takarabune & Ooedo Sakurafubuki 2 (Japan)"#),
+    "BSVP" => (0, false, "", "", r#"Smashing Drive (Europe)"#),
+    "BSVE" => (0, false, "", "", r#"Smashing Drive (USA)"#),
+    "ASGP" => (0, false, "", "", r#"Smuggler's Run (Europe)"#),
+    "ASGE" => (0, false, "", "", r#"Smuggler's Run (USA)"#),
+    "A7SP" => (0, false, "", "", r#"The Smurfs - The Revenge of the Smurfs (Europe)"#),
+    "AEAJ" => (3, false, "", "", r#"Snap Kid's (Japan)"#),
+    "ASQP" => (0, false, "", "", r#"Snood (Europe)"#),
+    "ASQE" => (0, false, "", "", r#"Snood (USA)"#),
+    "B2VP" => (0, false, "", "", r#"Snood 2 - On Vacation (Europe)"#),
+    "B2VE" => (0, false, "", "", r#"Snood 2 - On Vacation (USA)"#),
+    "AK6E" => (0, false, "", "", r#"Soccer Kid (Europe, USA)"#),
+    "ASOP" => (5, false, "sst", "", r#"Sonic Advance (Europe)"#),
+    "ASOJ" => (5, false, "", "", r#"Sonic Advance (Japan, rev. 1)"#),
+    "ASOE" => (5, false, "", "", r#"Sonic Advance (USA)"#),
+    "A2NP" => (4, false, "sst", "", r#"Sonic Advance 2 (Europe)"#),
+    "A2NJ" => (4, false, "", "", r#"Sonic Advance 2 (Japan)"#),
+    "A2NE" => (4, false, "", "", r#"Sonic Advance 2 (USA)"#),
+    "B3SP" => (4, false, "sst", "", r#"Sonic Advance 3 (Europe)"#),
+    "B3SJ" => (4, false, "", "", r#"Sonic Advance 3 (Japan)"#),
+    "B3SE" => (4, false, "", "", r#"Sonic Advance 3 (USA)"#),
+    "BSBP" => (4, false, "sst", "", r#"Sonic Battle (Europe)"#),
+    "BSBJ" => (4, false, "", "", r#"Sonic Battle (Japan)"#),
+    "BSBE" => (4, false, "", "", r#"Sonic Battle (USA)"#),
+    "A3VP" => (4, false, "sst", "", r#"Sonic Pinball Party (Europe)"#),
+    "A86J" => (4, false, "", "", r#"Sonic Pinball Party (Japan)"#),
+    "A3VE" => (4, false, "", "", r#"Sonic Pinball Party (USA)"#),
+    "BIJE" => (1, false, "", "", r#"Sonic the Hedgehog - Genesis (USA)"#),
+    "A3QP" => (0, false, "", "", r#"A Sound of Thunder (Europe)"#),
+    "A3QE" => (0, false, "", "", r#"A Sound of Thunder (USA)"#),
+    "A5UP" => (1, false, "", "", r#"Space Channel 5 - Ulala's Cosmic Attack (Europe)"#),
+    "A5UE" => (1, false, "", "", r#"Space Channel 5 - Ulala's Cosmic Attack (USA)"#),
+    "AJSJ" => (3, false, "", "", r#"Space Hexcite - Maetel Legend EX (Japan)"#),
+    "AIDE" => (1, false, "", "", r#"Space Invaders (Europe, USA)"#),
+    "AIDP" => (1, false, "", "", r#"Space Invaders (Europe, USA)"#),
+    "AIDJ" => (1, false, "", "", r#"Space Invaders EX (Japan)"#),
+    "AS6P" => (1, false, "", "", r#"Speedball 2 - Brutal Deluxe (Europe)"#),
+    "AKXE" => (1, false, "", "", r#"Spider-Man (Europe, USA)"#),
+    "AKXP" => (1, false, "", "", r#"Spider-Man (Europe, USA)"#),
+    "BC9P" => (1, false, "", "", r#"Spider-Man - Battle for New York (Europe)"#),
+    "BC9E" => (1, false, "", "", r#"Spider-Man - Battle for New York (USA)"#),
+    "ASEJ" => (1, false, "", "", r#"Spider-Man - Mysterio no Kyoui (Japan)"#),
+    "ASEE" => (0, false, "", "", r#"Spider-Man - Mysterio's Menace (Europe, USA)"#),
+    "ASEP" => (0, false, "", "", r#"Spider-Man - Mysterio's Menace (Europe, USA)"#),
+    "BSPE" => (1, false, "", "", r#"Spider-Man 2 (Europe, USA)"#),
+    "BSPP" => (1, false, "", "", r#"Spider-Man 2 (Europe, USA)"#),
+    "BI3P" => (1, false, "", "", r#"Spider-Man 3 (Europe)"#),
+    "BI3E" => (1, false, "", "", r#"Spider-Man 3 (USA)"#),
+    "AC6P" => (0, false, "", "", r#"Spirit - Stallion of the Cimarron (Europe)"#),
+    "AC6E" => (0, false, "", "", r#"Spirit - Stallion of the Cimarron (USA)"#),
+    "AWNE" => (0, false, "", "", r#"Spirits & Spells (USA)"#),
+    "BSQP" => (0, false, "", "", r#"SpongeBob SquarePants - Battle for Bikini Bottom (Europe)"#),
+    "BSQE" => (0, false, "", "", r#"SpongeBob SquarePants - Battle for Bikini Bottom (USA)"#),
+    "BO4P" => (1, false, "", "", r#"SpongeBob SquarePants - Creature from the Krusty Krab (Europe)"#),
+    "BO4E" => (1, false, "", "", r#"SpongeBob SquarePants - Creature from the Krusty Krab (USA)"#),
+    "BQQX" => (1, false, "", "", r#"SpongeBob SquarePants - Lights, Camera, Pants! (Europe)"#),
+    "BQQE" => (1, false, "", "", r#"SpongeBob SquarePants - Lights, Camera, Pants! (USA)"#),
+    "AQ3E" => (0, false, "", "", r#"SpongeBob SquarePants - Revenge of the Flying Dutchman (Europe, USA)"#),
+    "AQ3P" => (0, false, "", "", r#"SpongeBob SquarePants - Revenge of the Flying Dutchman (Europe, USA)"#),
+    "ASPE" => (0, false, "", "", r#"SpongeBob SquarePants - SuperSponge (Europe, USA)"#),
+    "ASPP" => (0, false, "", "", r#"SpongeBob SquarePants - SuperSponge (Europe, USA)"#),
+    "BCCP" => (1, false, "", "", r#"SpongeBob SquarePants and Friends in Freeze Frame Frenzy (Europe)"#),
+    "BNUP" => (0, false, "", "", r#"SpongeBob SquarePants and Friends Unite! (Europe)"#),
+    "BSNX" => (0, false, "", "", r#"SpongeBob SquarePants - The Movie (Europe)"#),
+    "BSNE" => (0, false, "", "", r#"SpongeBob SquarePants - The Movie (USA)"#),
+    "BZXE" => (0, false, "", "", r#"SpongeBob's Atlantis SquarePantis (USA)"#),
+    "AKBE" => (3, false, "", "", r#"Sports Illustrated for Kids - Baseball (USA)"#),
+    "AKFE" => (3, false, "", "", r#"Sports Illustrated for Kids - Football (USA)"#),
+    "B23E" => (1, false, "", "", r#"Sportsman's Pack (USA)"#),
+    "AHNP" => (1, false, "", "", r#"Spy Hunter (Europe)"#),
+    "AHNE" => (1, false, "", "", r#"Spy Hunter (USA)"#),
+    "AV3P" => (1, false, "", "", r#"Spy Kids 3-D - Game Over (Europe)"#),
+    "AV3E" => (1, false, "", "", r#"Spy Kids 3-D - Game Over (USA)"#),
+    "A2KE" => (1, false, "", "", r#"Spy Kids Challenger (USA)"#),
+    "BSSE" => (0, false, "", "", r#"Spy Muppets - License to Croak (USA)"#),
+    "AOWE" => (2, false, "", "", r#"Spyro - Attack of the Rhynocs (USA)"#),
+    "ASYP" => (1, false, "", "", r#"Spyro - Season of Ice (Europe)"#),
+    "ASYE" => (1, false, "", "", r#"Spyro - Season of Ice (USA)"#),
+    "A2SP" => (2, false, "", "", r#"Spyro 2 - Season of Flame (Europe)"#),
+    "A2SE" => (2, false, "", "", r#"Spyro 2 - Season of Flame (USA)"#),
+    "A4SJ" => (1, false, "", "", r#"Spyro Advance (Japan)"#),
+    "BS8J" => (1, false, "", "", r#"Spyro Advance - Wakuwaku Tomodachi Daisakusen! (Japan)"#),
+    "AOWP" => (2, false, "", "", r#"Spyro Adventure (Europe)"#),
+    "BSTP" => (1, false, "", "", r#"Spyro Fusion (Europe)"#),
+    "BSTE" => (1, false, "", "", r#"Spyro Orange - The Cortex Conspiracy (USA, rev. A)"#),
+    "BSXE" => (1, false, "", "", r#"SSX 3 (Europe, USA)"#),
+    "BSXP" => (1, false, "", "", r#"SSX 3 (Europe, USA)"#),
+    "AXYE" => (1, false, "", "", r#"SSX Tricky (Europe, USA)"#),
+    "AXYP" => (1, false, "", "", r#"SSX Tricky (Europe, USA)"#),
+    "A9GP" => (0, false, "", "", r#"Stadium Games (Europe)"#),
+    "A9GE" => (0, false, "", "", r#"Stadium Games (USA)"#),
+    "AS2P" => (0, false, "", "", r#"Star Wars - Episode II - Attack of the Clones (Europe)"#),
+    "AS2E" => (0, false, "", "", r#"Star Wars - Episode II - Attack of the Clones (USA)"#),
+    "BE3P" => (1, false, "", "", r#"Star Wars - Episode III - Revenge of the Sith (Europe)"#),
+    "BE3E" => (1, false, "", "", r#"Star Wars - Episode III - Revenge of the Sith (USA)"#),
+    "BSWP" => (0, false, "", "", r#"Star Wars - Flight of the Falcon (Europe)"#),
+    "BSWE" => (0, false, "", "", r#"Star Wars - Flight of the Falcon (USA)"#),
+    "ASWP" => (0, false, "", "", r#"Star Wars - Jedi Power Battles (Europe)"#),
+    "ASWE" => (0, false, "", "", r#"Star Wars - Jedi Power Battles (USA)"#),
+    "A2WP" => (0, false, "", "", r#"Star Wars - The New Droid Army (Europe)"#),
+    "A2WE" => (0, false, "", "", r#"Star Wars - The New Droid Army (USA)"#),
+    "BCKP" => (1, false, "", "", r#"Star Wars Trilogy - Apprentice of the Force (Europe)"#),
+    "BCKE" => (1, false, "", "", r#"Star Wars Trilogy - Apprentice of the Force (USA)"#),
+    "AS8P" => (0, false, "", "", r#"Star X (Europe)"#),
+    "AS8E" => (0, false, "", "", r#"Star X (USA)"#),
+    "AYHP" => (1, false, "", "", r#"Starsky & Hutch (Europe)"#),
+    "AYHE" => (1, false, "", "", r#"Starsky & Hutch (USA)"#),
+    "BKTP" => (1, false, "", "", r#"Steel Empire (Europe)"#),
+    "B35P" => (1, false, "", "", r#"Strawberry Shortcake - Ice Cream Island - Riding Camp (Europe, rev. 1)"#),
+    "B35E" => (0, false, "", "", r#"Strawberry Shortcake - Summertime Adventure (USA)"#),
+    "BQWE" => (0, false, "", "", r#"Strawberry Shortcake - Summertime Adventure - Special Edition (USA)"#),
+    "B4TE" => (1, false, "", "", r#"Strawberry Shortcake - Sweet Dreams (USA)"#),
+    "AZUP" => (2, false, "", "", r#"Street Fighter Alpha 3 (Europe)"#),
+    "AZUE" => (2, false, "", "", r#"Street Fighter Alpha 3 (USA)"#),
+    "AZUJ" => (2, false, "", "", r#"Street Fighter Zero 3 Upper (Japan)"#),
+    "A3ZE" => (1, false, "", "", r#"Street Jam Basketball (Europe, USA)"#),
+    "A3ZP" => (1, false, "", "", r#"Street Jam Basketball (Europe, USA)"#),
+    "BCZP" => (1, false, "", "", r#"Street Racing Syndicate (Europe)"#),
+    "BCZE" => (1, false, "", "", r#"Street Racing Syndicate (USA)"#),
+    "AFHP" => (0, false, "", "", r#"Strike Force Hydra (Europe)"#),
+    "AFHE" => (0, false, "", "", r#"Strike Force Hydra (USA)"#),
+    "ASLE" => (0, false, "", "", r#"Stuart Little 2 (Europe, USA)"#),
+    "ASLP" => (0, false, "", "", r#"Stuart Little 2 (Europe, USA)"#),
+    "AUXP" => (1, false, "", "", r#"Stuntman (Europe)"#),
+    "AUXE" => (1, false, "", "", r#"Stuntman (USA)"#),
+    "B4LJ" => (2, false, "", "", r#"Sugar Sugar Rune - Heart ga Ippai! Moegi Gakuen (Japan)"#),
+    "BZCE" => (1, false, "", "", r#"The Suite Life of Zack & Cody - Tipton Caper (USA)"#),
+    "AA6P" => (1, false, "", "", r#"The Sum of All Fears (Europe)"#),
+    "AA6E" => (1, false, "", "", r#"The Sum of All Fears (USA)"#),
+    "AB4J" => (2, false, "", "", r#"Summon Night - Craft Sword Monogatari (Japan)"#),
+    "B3CJ" => (2, false, "", "", r#"Summon Night - Craft Sword Monogatari - Hajimari no Ishi (Japan)"#),
+    "BSKJ" => (2, false, "", "", r#"Summon Night - Craft Sword Monogatari 2 (Japan)"#),
+    "AB4E" => (2, false, "", "", r#"Summon Night - Swordcraft Story (USA)"#),
+    "BSKE" => (2, false, "", "", r#"Summon Night - Swordcraft Story 2 (USA)"#),
+    "BG6E" => (0, false, "", "", r#"Super Army War (USA)"#),
+    "AABJ" => (3, false, "", "", r#"Super Black Bass Advance (Japan)"#),
+    "AVZP" => (1, false, "", "", r#"Super Bubble Pop (Europe)"#),
+    "AVZE" => (1, false, "", "", r#"Super Bubble Pop (USA)"#),
+    "ABMP" => (0, false, "", "", r#"Super Bust-A-Move (Europe)"#),
+    "ABME" => (0, false, "", "", r#"Super Bust-A-Move (USA)"#),
+    "BSAJ" => (1, false, "", "", r#"Super Chinese 1, 2 Advance (Japan)"#),
+    "BCLE" => (0, false, "", "", r#"Super Collapse! II (USA)"#),
+    "ADFP" => (3, false, "", "", r#"Super Dodge Ball Advance (Europe)"#),
+    "ADFE" => (3, false, "", "", r#"Super Dodge Ball Advance (USA, rev. 1)"#),
+    "A5NJ" => (1, false, "", "", r#"Super Donkey Kong (Japan)"#),
+    "B2DJ" => (2, false, "", "", r#"Super Donkey Kong 2 (Japan)"#),
+    "BDQJ" => (1, false, "", "", r#"Super Donkey Kong 3 (Japan)"#),
+    "AZNP" => (0, false, "", "", r#"Archer Maclean's Dropzone (Europe)"#),
+    "AZNE" => (0, false, "", "", r#"Archer Maclean's Dropzone (USA)"#),
+    "BDPE" => (1, false, "", "", r#"Super Duper Sumos (USA)"#),
+    "AG5E" => (1, false, "", "", r#"Super Ghouls'n Ghosts (Europe, USA)"#),
+    "AG5P" => (1, false, "", "", r#"Super Ghouls'n Ghosts (Europe, USA)"#),
+    "BF8E" => (0, false, "", "", r#"Super Hornet F/A 18F (Europe, USA)"#),
+    "AMAE" => (1, false, "", "", r#"Super Mario Advance (Europe, USA)"#),
+    "AMAP" => (1, false, "", "", r#"Super Mario Advance (Europe, USA)"#),
+    "AMAJ" => (1, false, "", "", r#"Super Mario Advance - Super Mario USA + Mario Brothers (Japan)"#),
+    "AA2P" => (2, false, "", "", r#"Super Mario Advance 2 - Super Mario World (Europe)"#),
+    "AA2E" => (2, false, "", "", r#"Super Mario Advance 2 - Super Mario World (USA, Australia)"#),
+    "AA2U" => (2, false, "", "", r#"Super Mario Advance 2 - Super Mario World (USA, Australia)"#),
+    "AA2J" => (2, false, "", "", r#"Super Mario Advance 2 - Super Mario World + Mario Brothers (Japan)"#),
+    "A3AP" => (2, false, "", "", r#"Super Mario Advance 3 - Yoshi's Island (Europe)"#),
+    "A3AE" => (2, false, "", "", r#"Super Mario Advance 3 - Yoshi's Island (USA)"#),
+    "A3AJ" => (2, false, "", "", r#"Super Mario Advance 3 - Yoshi's Island + Mario Brothers (Japan)"#),
+    "AX4J" => (5, false, "", "", r#"Super Mario Advance 4 - Super Mario 3 + Mario Brothers (Japan, rev. 2)"#),
+    "AX4P" => (5, false, "sanyo", "", r#"Super Mario Advance 4 - Super Mario Bros. 3 (Europe, rev. 1)"#),
+    "AX4E" => (5, false, "", "", r#"Super Mario Advance 4 - Super Mario Bros. 3 (USA, Australia, rev. 1)"#),
+    "AX4U" => (5, false, "", "", r#"Super Mario Advance 4 - Super Mario Bros. 3 (USA, Australia, rev. 1)"#),
+    "BMVP" => (1, false, "", "", r#"Super Mario Ball (Europe)"#),
+    "BMVJ" => (1, false, "", "", r#"Super Mario Ball (Japan)"#),
+    "ALUP" => (1, false, "", "", r#"Super Monkey Ball Jr. (Europe)"#),
+    "ALUE" => (1, false, "", "", r#"Super Monkey Ball Jr. (USA)"#),
+    "ABMJ" => (0, false, "", "", r#"Super Puzzle Bobble Advance (Japan)"#),
+    "AZ8P" => (1, false, "", "", r#"Super Puzzle Fighter II Turbo (Europe)"#),
+    "AZ8E" => (1, false, "", "", r#"Super Puzzle Fighter II Turbo (USA, rev. 1)"#),
+    "BDMJ" => (1, false, "", "", r#"Super Real Mahjong Dousoukai (Japan)"#),
+    "AOGJ" => (5, false, "", "", r#"Super Robot Taisen - Original Generation (Japan)"#),
+    "AOGE" => (4, false, "", "", r#"Super Robot Taisen - Original Generation (USA)"#),
+    "B2RJ" => (4, false, "", "", r#"Super Robot Taisen - Original Generation 2 (Japan)"#),
+    "B2RE" => (4, false, "", "", r#"Super Robot Taisen - Original Generation 2 (USA)"#),
+    "ASRJ" => (5, false, "", "", r#"Super Robot Taisen A (Japan)"#),
+    "A6SJ" => (4, false, "", "", r#"Super Robot Taisen D (Japan)"#),
+    "B6JJ" => (4, false, "sst", "", r#"Super Robot Taisen J (Japan)"#),
+    "AJ9J" => (5, false, "sst", "", r#"Super Robot Taisen R (Japan)"#),
+    "AXRP" => (1, false, "", "", r#"Super Street Fighter II Turbo - Revival (Europe)"#),
+    "AXRJ" => (1, false, "", "", r#"Super Street Fighter II X - Revival (Japan)"#),
+    "ASUP" => (0, false, "", "", r#"Superman - Countdown to Apokolips (Europe)"#),
+    "ASUE" => (0, false, "", "", r#"Superman - Countdown to Apokolips (USA)"#),
+    "BQXE" => (1, false, "", "", r#"Superman Returns - Fortress of Solitude (Europe, USA)"#),
+    "BQXP" => (1, false, "", "", r#"Superman Returns - Fortress of Solitude (Europe, USA)"#),
+    "BXUE" => (1, false, "", "", r#"Surf's Up (USA)"#),
+    "ASKJ" => (3, false, "", "", r#"Sutakomi - Star Communicator (Japan)"#),
+    "ABGJ" => (2, false, "", "", r#"Sweet Cookie Pie (Japan)"#),
+    "AVSP" => (4, false, "", "", r#"Sword of Mana (Europe, English)"#),
+    "AVSE" => (4, false, "", "", r#"Sword of Mana (USA, Australia)"#),
+    "AVSU" => (4, false, "", "", r#"Sword of Mana (USA, Australia)"#),
+    "BSFJ" => (2, false, "", "", r#"Sylvanian Families - Fashion Designer ni Naritai! - Kurumi-risu no Onnanoko (Japan)"#),
+    "BS5J" => (2, false, "", "", r#"Sylvanian Families - Yousei no Stick to Fushigi no Ki - Marron-inu no Onnanoko (Japan)"#),
+    "A4LJ" => (2, false, "", "", r#"Sylvanian Families 4 - Meguru Kisetsu no Tapestry (Japan)"#),
+    "ATOE" => (5, false, "", "", r#"Tactics Ogre - The Knight of Lodis (USA)"#),
+    "ATOJ" => (5, false, "", "", r#"Tactics Ogre Gaiden - The Knight of Lodis (Japan)"#),
+    "BU6J" => (1, false, "", "", r#"Taiketsu! Ultra Hero (Japan)"#),
+    "BJWE" => (1, false, "", "", r#"Tak - The Great Juju Challenge (Europe, USA, Australia)"#),
+    "BJWP" => (1, false, "", "", r#"Tak - The Great Juju Challenge (Europe, USA, Australia)"#),
+    "BT9P" => (1, false, "", "", r#"Tak 2 - The Staff of Dreams (Europe)"#),
+    "BT9E" => (1, false, "", "", r#"Tak 2 - The Staff of Dreams (USA)"#),
+    "BJUP" => (1, false, "", "", r#"Tak and the Power of Juju (Europe, English / French / German)"#),
+    "BJUE" => (1, false, "", "", r#"Tak and the Power of Juju (USA)"#),
+    "AN8P" => (2, false, "", "", r#"Tales of Phantasia (Europe)"#),
+    "AN8J" => (2, false, "", "", r#"Tales of Phantasia (Japan)"#),
+    "AN8E" => (2, false, "", "", r#"Tales of Phantasia (USA, Australia)"#),
+    "AN8U" => (2, false, "", "", r#"Tales of Phantasia (USA, Australia)"#),
+    "AN9J" => (2, false, "", "", r#"Tales of the World - Narikiri Dungeon 2 (Japan)"#),
+    "B3TJ" => (2, false, "", "", r#"Tales of the World - Narikiri Dungeon 3 (Japan)"#),
+    "A9PJ" => (3, false, "", "", r#"Tales of the World - Summoner's Lineage (Japan)"#),
+    "AYMJ" => (3, false, "", "", r#"Tanbi Musou - Meine Liebe (Japan)"#),
+    "ATAP" => (1, false, "", "", r#"Tang Tang (Europe)"#),
+    "ATAE" => (1, false, "", "", r#"Tang Tang (USA)"#),
+    "BTIJ" => (3, false, "", "", r#"Tantei Gakuen Q - Kyuukyoku Trick ni Idome! (Japan)"#),
+    "BTQJ" => (3, false, "", "", r#"Tantei Gakuen Q - Meitantei wa Kimi Da! (Japan)"#),
+    "BT3J" => (2, false, "", "", r#"Tantei Jinguuji Saburou - Shiroi Kage no Shoujo (Japan)"#),
+    "AJGE" => (1, false, "", "", r#"Disney's Tarzan - Return to the Jungle (Europe, USA)"#),
+    "AJGP" => (1, false, "", "", r#"Disney's Tarzan - Return to the Jungle (Europe, USA)"#),
+    "AXQF" => (0, false, "", "", r#"Taxi 3 (France)"#),
+    "BBLE" => (1, false, "", "", r#"Teen Titans (USA)"#),
+    "BZUE" => (1, false, "", "", r#"Teen Titans 2 - The Brotherhood's Revenge (USA)"#),
+    "BNTP" => (1, false, "", "", r#"Teenage Mutant Ninja Turtles (Europe)"#),
+    "BNTE" => (1, false, "", "", r#"Teenage Mutant Ninja Turtles (USA)"#),
+    "BT2P" => (2, false, "", "", r#"Teenage Mutant Ninja Turtles 2 - Battle Nexus (Europe)"#),
+    "BT2E" => (2, false, "", "", r#"Teenage Mutant Ninja Turtles 2 - Battle Nexus (USA)"#),
+    "ATKP" => (1, false, "", "", r#"Tekken Advance (Europe)"#),
+    "ATKJ" => (1, false, "", "", r#"Tekken Advance (Japan)"#),
+    "ATKE" => (1, false, "", "", r#"Tekken Advance (USA)"#),
+    "BTPE" => (0, false, "", "", r#"Ten Pin Alley 2 (USA)"#),
+    "AVAJ" => (3, false, "", "", r#"Tennis no Ouji-sama - Aim at the Victory! (Japan)"#),
+    "ATIJ" => (5, false, "", "", r#"Tennis no Ouji-sama - Genius Boys Academy (Japan)"#),
+    "A9LJ" => (3, false, "", "", r#"Tennis no Ouji-sama 2003 - Cool Blue (Japan)"#),
+    "A8RJ" => (3, false, "", "", r#"Tennis no Ouji-sama 2003 - Passion Red (Japan)"#),
+    "B4GJ" => (3, false, "", "", r#"Tennis no Ouji-sama 2004 - Glorious Gold (Japan)"#),
+    "B4SJ" => (3, false, "", "", r#"Tennis no Ouji-sama 2004 - Stylish Silver (Japan)"#),
+    "AO3P" => (0, false, "", "", r#"Terminator 3 - Rise of the Machines (Europe)"#),
+    "AO3E" => (0, false, "", "", r#"Terminator 3 - Rise of the Machines (USA)"#),
+    "ATWJ" => (0, false, "", "", r#"Tetris Worlds (Japan, rev. 1)"#),
+    "ATWE" => (0, false, "", "", r#"Tetris Worlds (USA)"#),
+    "BXAE" => (0, false, "", "", r#"Texas Hold 'em Poker (Europe, USA)"#),
+    "BRVE" => (1, false, "", "", r#"That's So Raven (USA)"#),
+    "BZSE" => (1, false, "", "", r#"That's So Raven 2 - Supernatural Style (USA)"#),
+    "A3TE" => (0, false, "", "", r#"The Three Stooges (USA)"#),
+    "B65E" => (0, false, "", "", r#"Three-in-One Pack - Connect Four + Perfection + Trouble (USA)"#),
+    "B66E" => (0, false, "", "", r#"Three-in-One Pack - Risk + Battleship + Clue (USA, rev. A)"#),
+    "B67E" => (0, false, "", "", r#"Three-in-One Pack - Sorry! + Aggravation + Scrabble Junior (USA)"#),
+    "BTHE" => (0, false, "", "", r#"Thunder Alley (USA)"#),
+    "BTBE" => (0, false, "", "", r#"Thunderbirds (Europe, USA)"#),
+    "BTBP" => (0, false, "", "", r#"Thunderbirds (Europe, USA)"#),
+    "ATNP" => (0, false, "", "", r#"Thunderbirds - International Rescue (Europe)"#),
+    "BTWE" => (1, false, "", "", r#"Tiger Woods PGA Tour 2004 (Europe, USA)"#),
+    "BTWP" => (1, false, "", "", r#"Tiger Woods PGA Tour 2004 (Europe, USA)"#),
+    "AT5E" => (1, false, "", "", r#"Tiger Woods PGA Tour Golf (Europe, USA)"#),
+    "BNCJ" => (1, false, "", "", r#"Tim Burton's The Nightmare Before Christmas - The Pumpkin King (Japan)"#),
+    "BNCE" => (1, false, "", "", r#"Tim Burton's The Nightmare Before Christmas - The Pumpkin King (Europe, USA)"#),
+    "BNCP" => (1, false, "", "", r#"Tim Burton's The Nightmare Before Christmas - The Pumpkin King (Europe, USA)"#),
+    "ATTP" => (0, false, "", "", r#"Tiny Toon Adventures - Buster's Bad Dream (Europe)"#),
+    "ATTE" => (0, false, "", "", r#"Tiny Toon Adventures - Scary Dreams (USA)"#),
+    "AWSP" => (0, false, "", "", r#"Tiny Toon Adventures - Wacky Stackers (Europe)"#),
+    "AWSE" => (0, false, "", "", r#"Tiny Toon Adventures - Wacky Stackers (USA)"#),
+    "BEXP" => (1, false, "", "", r#"TMNT (Europe)"#),
+    "BEXE" => (1, false, "", "", r#"TMNT (USA)"#),
+    "ATQP" => (1, false, "", "", r#"TOCA World Touring Cars (Europe)"#),
+    "AF7J" => (2, false, "", "", r#"Tokimeki Yume Series 1 - Ohanaya-san ni Narou! (Japan)"#),
+    "BTFJ" => (3, false, "", "", r#"Tokyo Majin Gakuen - Fuju Houroku (Japan)"#),
+    "BTZP" => (1, false, "", "", r#"Tokyo Xtreme Racer Advance (Europe)"#),
+    "BTZE" => (1, false, "", "", r#"Tokyo Xtreme Racer Advance (USA)"#),
+    "ATJP" => (0, false, "", "", r#"Tom and Jerry - The Magic Ring (Europe)"#),
+    "ATJE" => (0, false, "", "", r#"Tom and Jerry - The Magic Ring (USA)"#),
+    "AIFP" => (0, false, "", "", r#"Tom and Jerry in Infurnal Escape (Europe)"#),
+    "AIFE" => (0, false, "", "", r#"Tom and Jerry in Infurnal Escape (USA)"#),
+    "BTJP" => (0, false, "", "", r#"Tom and Jerry Tales (Europe)"#),
+    "BTJE" => (0, false, "", "", r#"Tom and Jerry Tales (USA)"#),
+    "AR6P" => (1, false, "", "", r#"Tom Clancy's Rainbow Six - Rogue Spear (Europe)"#),
+    "AR6E" => (1, false, "", "", r#"Tom Clancy's Rainbow Six - Rogue Spear (USA)"#),
+    "AO4P" => (1, false, "", "", r#"Tom Clancy's Splinter Cell (Europe)"#),
+    "AO4E" => (1, false, "", "", r#"Tom Clancy's Splinter Cell (USA)"#),
+    "BSLP" => (1, false, "", "", r#"Tom Clancy's Splinter Cell - Pandora Tomorrow (Europe)"#),
+    "BSLE" => (1, false, "", "", r#"Tom Clancy's Splinter Cell - Pandora Tomorrow (USA)"#),
+    "AGLJ" => (2, false, "", "", r#"Tomato Adventure (Japan)"#),
+    "BT7E" => (0, false, "", "", r#"Tonka - On the Job (USA)"#),
+    "BH9P" => (2, false, "", "", r#"Tony Hawk's American Sk8land (Europe, English)"#),
+    "BH9E" => (2, false, "", "", r#"Tony Hawk's American Sk8land (USA)"#),
+    "BXSP" => (1, false, "", "", r#"Tony Hawk's Downhill Jam (Europe)"#),
+    "BXSE" => (1, false, "", "", r#"Tony Hawk's Downhill Jam (USA)"#),
+    "ATHE" => (1, false, "", "", r#"Tony Hawk's Pro Skater 2 (Europe, USA)"#),
+    "ATHP" => (1, false, "", "", r#"Tony Hawk's Pro Skater 2 (Europe, USA)"#),
+    "AT3E" => (2, false, "", "", r#"Tony Hawk's Pro Skater 3 (Europe, USA)"#),
+    "AT3P" => (2, false, "", "", r#"Tony Hawk's Pro Skater 3 (Europe, USA)"#),
+    "AT6E" => (2, false, "", "", r#"Tony Hawk's Pro Skater 4 (Europe, USA)"#),
+    "AT6P" => (2, false, "", "", r#"Tony Hawk's Pro Skater 4 (Europe, USA)"#),
+    "BTOE" => (2, false, "", "", r#"Tony Hawk's Underground (Europe, USA)"#),
+    "BTOP" => (2, false, "", "", r#"Tony Hawk's Underground (Europe, USA)"#),
+    "B2TE" => (2, false, "", "", r#"Tony Hawk's Underground 2 (Europe, USA)"#),
+    "B2TP" => (2, false, "", "", r#"Tony Hawk's Underground 2 (Europe, USA)"#),
+    "ATCP" => (1, false, "", "", r#"Top Gear GT Championship (Europe)"#),
+    "ATCE" => (1, false, "", "", r#"Top Gear GT Championship (USA)"#),
+    "BTGX" => (1, false, "", "", r#"Top Gear Rally (Europe)"#),
+    "AYEJ" => (1, false, "", "", r#"Top Gear Rally (Japan)"#),
+    "BTGE" => (1, false, "", "", r#"Top Gear Rally (USA)"#),
+    "A2YE" => (0, false, "", "", r#"Top Gun - Combat Zones (USA)"#),
+    "ATGE" => (0, false, "", "", r#"Top Gun - Firestorm Advance (Europe, USA)"#),
+    "ATGP" => (0, false, "", "", r#"Top Gun - Firestorm Advance (Europe, USA)"#),
+    "B27P" => (1, false, "", "", r#"Top Spin 2 (Europe)"#),
+    "B27E" => (1, false, "", "", r#"Top Spin 2 (USA)"#),
+    "ATUP" => (1, false, "", "", r#"Total Soccer (Europe)"#),
+    "ATUJ" => (1, false, "", "", r#"Total Soccer Advance (Japan)"#),
+    "BTUP" => (1, false, "", "", r#"Totally Spies! (Europe)"#),
+    "BTUE" => (1, false, "", "", r#"Totally Spies! (USA)"#),
+    "B2LP" => (1, false, "", "", r#"Totally Spies! 2 - Undercover (Europe)"#),
+    "B2LE" => (1, false, "", "", r#"Totally Spies! 2 - Undercover (USA)"#),
+    "AH3J" => (3, false, "", "", r#"Tottoko Hamutarou 3 - Love Love Daibouken Dechu (Japan)"#),
+    "A84J" => (3, false, "", "", r#"Tottoko Hamutarou 4 - Nijiiro Daikoushin Dechu (Japan)"#),
+    "BZWJ" => (2, false, "", "", r#"Touhai Densetsu Akagi - Yami ni Mai Orita Tensai (Japan)"#),
+    "A59J" => (2, false, "", "", r#"Toukon Heat (Japan)"#),
+    "BTRE" => (4, false, "", "", r#"Welcome to The Tower SP (USA)"#),
+    "BTRJ" => (4, false, "sst", "", r#"The Tower SP (Japan)"#),
+    "ATRJ" => (3, false, "", "", r#"Toyrobo Force (Japan)"#),
+    "AZQP" => (0, false, "", "", r#"Disney's Treasure Planet (Europe, rev. 1)"#),
+    "AZQE" => (0, false, "", "", r#"Disney's Treasure Planet (USA)"#),
+    "B9SP" => (1, false, "", "", r#"Trick Star (Europe)"#),
+    "BT6P" => (1, false, "", "", r#"Trollz - Hair Affair! (Europe, English)"#),
+    "BT6E" => (1, false, "", "", r#"Trollz - Hair Affair! (USA)"#),
+    "BTNP" => (1, false, "", "", r#"Tron 2.0 - Killer App (Europe)"#),
+    "BTNE" => (1, false, "", "", r#"Tron 2.0 - Killer App (USA)"#),
+    "BIIJ" => (4, false, "sst", "", r#"Tsuukin Hitofude (Japan)"#),
+    "AK3E" => (1, false, "", "", r#"Turbo Turtle Adventure (USA)"#),
+    "AT4P" => (0, false, "", "", r#"Turok Evolution (Europe)"#),
+    "AT4E" => (0, false, "", "", r#"Turok Evolution (USA)"#),
+    "ATMP" => (3, false, "", "", r#"Tweety and the Magic Gems (Europe)"#),
+    "ATMH" => (3, false, "", "", r#"Tweety and the Magic Gems (Netherlands)"#),
+    "ATME" => (3, false, "", "", r#"Tweety and the Magic Gems (USA)"#),
+    "AMJJ" => (3, false, "", "", r#"Tweety no Hearty Party (Japan)"#),
+    "BTYE" => (2, false, "", "", r#"Ty the Tasmanian Tiger 2 - Bush Rescue (Europe, USA)"#),
+    "BTYP" => (2, false, "", "", r#"Ty the Tasmanian Tiger 2 - Bush Rescue (Europe, USA)"#),
+    "BTVE" => (2, false, "", "", r#"Ty the Tasmanian Tiger 3 - Night of the Quinkan (USA)"#),
+    "AUCJ" => (2, false, "", "", r#"Uchuu Daisakusen Choco Vader - Uchuu kara no Shinryakusha (Japan)"#),
+    "BUVJ" => (3, false, "", "", r#"Uchuu no Stellvia (Japan)"#),
+    "BUHJ" => (1, false, "", "", r#"Ueki no Housoku - Jingi Sakuretsu! Nouryokusha Battle (Japan)"#),
+    "AEWJ" => (2, false, "", "", r#"Ui-Ire - World Soccer Winning Eleven (Japan)"#),
+    "BUZE" => (2, false, "", "", r#"Ultimate Arcade Games (USA)"#),
+    "AVEE" => (1, false, "", "", r#"Ultimate Beach Soccer (USA)"#),
+    "ABUE" => (1, false, "", "", r#"Ultimate Brain Games (Europe, USA)"#),
+    "BUCE" => (2, false, "", "", r#"Ultimate Card Games (USA, rev. 2)"#),
+    "AK2E" => (1, false, "", "", r#"Ultimate Muscle - The Kinnikuman Legacy - The Path of the Superhero (USA)"#),
+    "BUAE" => (1, false, "", "", r#"Ultimate Puzzle Games (USA)"#),
+    "BULP" => (1, false, "", "", r#"Ultimate Spider-Man (Europe, English)"#),
+    "BULE" => (1, false, "", "", r#"Ultimate Spider-Man (USA)"#),
+    "BUWE" => (0, false, "", "", r#"Ultimate Winter Games (USA)"#),
+    "BUTJ" => (4, false, "", "", r#"Ultra Keibitai - Monster Attack (Japan)"#),
+    "BU4E" => (0, false, "", "", r#"Unfabulous (USA)"#),
+    "BUQP" => (0, false, "", "", r#"2 Game Pack!: Uno - Skip-Bo (Europe)"#),
+    "BUQE" => (0, false, "", "", r#"2 Game Pack!: Uno - Skip-Bo (USA)"#),
+    "BU5P" => (0, false, "", "", r#"Uno 52 (Europe)"#),
+    "BU5E" => (0, false, "", "", r#"Uno 52 (USA)"#),
+    "BUIP" => (0, false, "", "", r#"Uno Free Fall (Europe)"#),
+    "BUIE" => (0, false, "", "", r#"Uno Free Fall (USA)"#),
+    "AYIE" => (0, false, "", "", r#"Urban Yeti! (Europe, USA)"#),
+    "BOCE" => (4, false, "sst", "", r#"The Urbz - Sims in the City (Europe, USA)"#),
+    "BOCP" => (4, false, "sst", "", r#"The Urbz - Sims in the City (Europe, USA)"#),
+    "BOCJ" => (4, false, "", "", r#"The Urbz - Sims in the City (Japan)"#),
+    "AVMJ" => (1, false, "", "", r#"V-Master Cross (Japan)"#),
+    "AVRP" => (1, false, "", "", r#"V-Rally 3 (Europe)"#),
+    "AVRJ" => (1, false, "", "", r#"V-Rally 3 (Japan)"#),
+    "AVRE" => (1, false, "", "", r#"V-Rally 3 (USA)"#),
+    "AVPP" => (0, false, "", "", r#"V.I.P. (Europe)"#),
+    "BANP" => (0, false, "", "", r#"Van Helsing (Europe)"#),
+    "BANE" => (0, false, "", "", r#"Van Helsing (USA)"#),
+    "BRXJ" => (3, false, "", "", r#"Vattroller X (Japan)"#),
+    "BZTE" => (1, false, "", "", r#"BigIdea's VeggieTales - LarryBoy and the Bad Apple (USA)"#),
+    "AVTP" => (1, false, "", "", r#"Virtua Tennis (Europe)"#),
+    "AVTE" => (1, false, "", "", r#"Virtua Tennis (USA)"#),
+    "AVKP" => (1, false, "", "", r#"Virtual Kasparov (Europe)"#),
+    "AVKE" => (1, false, "", "", r#"Virtual Kasparov (USA)"#),
+    "BWTP" => (1, false, "", "", r#"W.i.t.c.h. (Europe)"#),
+    "BSRE" => (1, false, "", "", r#"Wade Hixton's Counter Punch (Europe, USA)"#),
+    "BSRP" => (1, false, "", "", r#"Wade Hixton's Counter Punch (Europe, USA)"#),
+    "BMYJ" => (3, false, "", "", r#"Wagamama Fairy Mirumo de Pon! - 8 Nin no Toki no Yousei (Japan)"#),
+    "BMIJ" => (3, false, "", "", r#"Wagamama Fairy Mirumo de Pon! - Dokidoki Memorial Panic (Japan)"#),
+    "BWPJ" => (3, false, "", "", r#"Wagamama Fairy Mirumo de Pon! - Nazo no Kagi to Shinjitsu no Tobira (Japan)"#),
+    "AWKJ" => (2, false, "", "", r#"Wagamama Fairy Mirumo de Pon! - Ougon Maracas no Densetsu (Japan)"#),
+    "BMPJ" => (3, false, "", "", r#"Wagamama Fairy Mirumo de Pon! - Taisen Mahoudama (Japan)"#),
+    "BWFJ" => (3, false, "", "", r#"Wagamama Fairy Mirumo de Pon! - Yume no Kakera (Japan)"#),
+    "AWDE" => (1, false, "", "", r#"Wakeboarding Unleashed featuring Shaun Murray (USA)"#),
+    "AZWC" => (3, false, "", "", r#"Waliou Zhizao (China)"#),
+    "BWDJ" => (2, false, "", "", r#"Wannyan Doubutsu Byouin (Japan, rev. 1)"#),
+    "BWKJ" => (1, false, "", "", r#"Wanko de Kururin! Wancle (Japan)"#),
+    "BWXJ" => (2, false, "", "", r#"Wanko Mix Chiwanko World (Japan)"#),
+    "BWMJ" => (1, false, "", "", r#"Wanwan Meitantei (Japan)"#),
+    "AWAE" => (3, false, "", "", r#"Wario Land 4 (Europe, USA)"#),
+    "AWAP" => (3, false, "", "", r#"Wario Land 4 (Europe, USA)"#),
+    "AWAJ" => (3, false, "", "", r#"Wario Land Advance (Japan)"#),
+    "AZWP" => (3, false, "", "", r#"WarioWare, Inc. - Minigame Mania (Europe)"#),
+    "AZWE" => (3, false, "", "", r#"WarioWare, Inc. - Mega Microgame$! (USA)"#),
+    "RZWE" => (3, false, "", "gyro|rumble", r#"WarioWare - Twisted! (USA, Australia)"#),
+    "BWEE" => (1, false, "", "", r#"Whac-A-Mole (USA)"#),
+    "A73J" => (3, false, "", "", r#"Whistle! - Dai-37-kai Tokyo-to Chuugakkou Sougou Taiiku Soccer Taikai (Japan)"#),
+    "A55P" => (0, false, "", "", r#"Who Wants to Be a Millionaire (UK)"#),
+    "B55P" => (0, false, "", "", r#"Who Wants to Be a Millionaire - 2nd Edition (UK)"#),
+    "BWJP" => (0, false, "", "", r#"Who Wants to Be a Millionaire - Junior (UK)"#),
+    "AWLE" => (0, false, "", "", r#"The Wild Thornberrys Movie (Europe, USA)"#),
+    "AWTE" => (0, false, "", "", r#"The Wild Thornberrys - Chimp Chase (Europe, USA)"#),
+    "AWTP" => (0, false, "", "", r#"The Wild Thornberrys - Chimp Chase (Europe, USA)"#),
+    "BWLE" => (1, false, "", "", r#"The Wild (Europe, USA)"#),
+    "BWLP" => (1, false, "", "", r#"The Wild (Europe, USA)"#),
+    "BWUD" => (0, false, "", "", r#"Die Wilden Fussball-Kerle (Germany)"#),
+    "BXWD" => (1, false, "", "", r#"Die Wilden Fussball-Kerle - Gefahr im Wilde Kerle Land (Germany)"#),
+    "AW9P" => (1, false, "", "", r#"Wing Commander - Prophecy (Europe)"#),
+    "AW9E" => (1, false, "", "", r#"Wing Commander - Prophecy (USA)"#),
+    "AWQP" => (1, false, "", "", r#"Wings (Europe)"#),
+    "AWQE" => (1, false, "", "", r#"Wings (USA)"#),
+    "BWHP" => (0, false, "", "", r#"Winnie the Pooh's Rumbly Tumbly Adventure (Europe)"#),
+    "BWHE" => (0, false, "", "", r#"Winnie the Pooh's Rumbly Tumbly Adventure (USA)"#),
+    "AWPJ" => (5, false, "", "", r#"Winning Post for Game Boy Advance (Japan, rev. 2)"#),
+    "BWYP" => (1, false, "", "", r#"Winter Sports (Europe)"#),
+    "BWIP" => (2, false, "", "", r#"WinX Club (Europe)"#),
+    "BWIE" => (2, false, "", "", r#"WinX Club (USA)"#),
+    "BWVP" => (1, false, "", "", r#"WinX Club - Quest for the Codex (Europe)"#),
+    "BWVE" => (1, false, "", "", r#"WinX Club - Quest for the Codex (USA)"#),
+    "AWZJ" => (3, false, "", "", r#"Wizardry Summoner (Japan, rev. 1)"#),
+    "AWOP" => (1, false, "", "", r#"Wolfenstein 3D (Europe, USA)"#),
+    "AWOE" => (1, false, "", "", r#"Wolfenstein 3D (Europe, USA)"#),
+    "AWWJ" => (1, false, "", "", r#"Woody Woodpecker - Crazy Castle 5 (Japan)"#),
+    "AWWP" => (1, false, "", "", r#"Woody Woodpecker in Crazy Castle 5 (Europe)"#),
+    "AWWE" => (1, false, "", "", r#"Woody Woodpecker in Crazy Castle 5 (USA)"#),
+    "AASJ" => (2, false, "", "", r#"World Advance Soccer - Shouri e no Michi (Japan)"#),
+    "BP9P" => (0, false, "", "", r#"World Championship Poker (Europe)"#),
+    "BP9E" => (0, false, "", "", r#"World Championship Poker (USA)"#),
+    "BWOP" => (2, false, "", "", r#"WPT - World Poker Tour (Europe)"#),
+    "B26E" => (2, false, "", "", r#"WPT - World Poker Tour (USA)"#),
+    "AWCP" => (0, false, "", "", r#"World Tennis Stars (Europe)"#),
+    "AWCE" => (0, false, "", "", r#"World Tennis Stars (USA)"#),
+    "AWBP" => (0, false, "", "", r#"Worms Blast (Europe)"#),
+    "AWYP" => (0, false, "", "", r#"Worms World Party (Europe)"#),
+    "AWYE" => (0, false, "", "", r#"Worms World Party (USA)"#),
+    "ATEE" => (1, false, "", "", r#"WTA Tour Tennis (USA)"#),
+    "ACIJ" => (1, false, "", "", r#"WTA Tour Tennis Pocket (Japan)"#),
+    "AW8E" => (1, false, "", "", r#"WWE - Road to WrestleMania X8 (Europe, USA)"#),
+    "AW8P" => (1, false, "", "", r#"WWE - Road to WrestleMania X8 (Europe, USA)"#),
+    "BWWE" => (1, false, "", "", r#"WWE - Survivor Series (Europe, USA)"#),
+    "BWWP" => (1, false, "", "", r#"WWE - Survivor Series (Europe, USA)"#),
+    "AWFE" => (0, false, "", "", r#"WWF - Road to WrestleMania (Europe, USA)"#),
+    "AXIP" => (0, false, "", "", r#"X-Bladez - Inline Skater (Europe)"#),
+    "AXIE" => (0, false, "", "", r#"X-Bladez - Inline Skater (USA)"#),
+    "AXME" => (1, false, "", "", r#"X-Men - Reign of Apocalypse (Europe, USA)"#),
+    "AXMP" => (1, false, "", "", r#"X-Men - Reign of Apocalypse (Europe, USA)"#),
+    "B3XP" => (1, false, "", "", r#"X-Men - The Official Game (Europe)"#),
+    "B3XE" => (1, false, "", "", r#"X-Men - The Official Game (USA)"#),
+    "AWVE" => (1, false, "", "", r#"X2 - Wolverine's Revenge (Europe, USA)"#),
+    "AWVP" => (1, false, "", "", r#"X2 - Wolverine's Revenge (Europe, USA)"#),
+    "BXME" => (0, false, "", "", r#"XS Moto (USA)"#),
+    "AX3E" => (1, false, "", "", r#"xXx (Europe, USA)"#),
+    "AX3P" => (1, false, "", "", r#"xXx (Europe, USA)"#),
+    "BYUJ" => (2, false, "", "", r#"Yggdra Union - We'll Never Fight Alone (Japan)"#),
+    "BYUP" => (2, false, "", "", r#"Yggdra Union - We'll Never Fight Alone (Europe)"#),
+    "BYUE" => (2, false, "", "", r#"Yggdra Union - We'll Never Fight Alone (USA)"#),
+    "KYGE" => (2, false, "", "tilt", r#"Yoshi - Topsy-Turvy (USA)"#),
+    "KYGJ" => (2, false, "", "tilt", r#"Yoshi no Banyuuinryoku (Japan)"#),
+    "KYGP" => (2, false, "", "tilt", r#"Yoshi's Universal Gravitation (Europe)"#),
+    "AFUJ" => (2, false, "", "", r#"Youkaidou (Japan)"#),
+    "BYYP" => (1, false, "", "", r#"Yu Yu Hakusho - Ghostfiles - Spirit Detective (Europe)"#),
+    "BYYE" => (1, false, "", "", r#"Yu Yu Hakusho - Ghostfiles - Spirit Detective (USA)"#),
+    "BRGE" => (2, false, "", "", r#"Yu Yu Hakusho - Ghostfiles - Tournament Tactics (Europe, USA)"#),
+    "BRGP" => (2, false, "", "", r#"Yu Yu Hakusho - Ghostfiles - Tournament Tactics (Europe, USA)"#),
+    "BYDP" => (3, false, "", "", r#"Shonen Jump's Yu-Gi-Oh! - Destiny Board Traveler (Europe)"#),
+    "BYDE" => (3, false, "", "", r#"Shonen Jump's Yu-Gi-Oh! - Destiny Board Traveler (USA)"#),
+    "BYSJ" => (3, false, "", "", r#"Yu-Gi-Oh! - Sugoroku no Sugoroku (Japan)"#),
+    "AYDP" => (5, false, "sst", "", r#"Yu-Gi-Oh! - Dungeon Dice Monsters (Europe)"#),
+    "AYDJ" => (5, false, "", "", r#"Yu-Gi-Oh! - Dungeon Dice Monsters (Japan)"#),
+    "AYDE" => (5, false, "", "", r#"Yu-Gi-Oh! - Dungeon Dice Monsters (USA)"#),
+    "AY8P" => (3, false, "", "", r#"Yu-Gi-Oh! - Reshef of Destruction (Europe)"#),
+    "AY8E" => (3, false, "", "", r#"Shonen Jump's Yu-Gi-Oh! - Reshef of Destruction (USA)"#),
+    "AY7P" => (3, false, "", "", r#"Yu-Gi-Oh! - The Sacred Cards (Europe)"#),
+    "AY7E" => (3, false, "", "", r#"Yu-Gi-Oh! - The Sacred Cards (USA)"#),
+    "BYWP" => (3, false, "", "", r#"Yu-Gi-Oh! - World Championship Tournament 2004 (Europe)"#),
+    "BYWE" => (3, false, "", "", r#"Shonen Jump's Yu-Gi-Oh! - World Championship Tournament 2004 (USA)"#),
+    "BYOP" => (3, false, "", "", r#"Shonen Jump's Yu-Gi-Oh! - Day of the Duelist - World Championship Tournament 2005 (Europe)"#),
+    "BY7E" => (3, false, "", "", r#"Shonen Jump's Yu-Gi-Oh! - 7 Trials to Glory - World Championship Tournament 2005 (USA)"#),
+    "AYWJ" => (3, false, "", "", r#"Yu-Gi-Oh! Duel Monsters International - Worldwide Edition (Japan, rev. 1)"#),
+    "BYIJ" => (3, false, "", "", r#"Yu-Gi-Oh! Duel Monsters International 2 (Japan)"#),
+    "AY5E" => (3, false, "", "", r#"Yu-Gi-Oh! - The Eternal Duelist Soul (USA)"#),
+    "BY6P" => (3, false, "", "", r#"Shonen Jump's Yu-Gi-Oh! - Ultimate Masters Edition - World Championship Tournament 2006 (Europe)"#),
+    "BY6E" => (3, false, "", "", r#"Shonen Jump's Yu-Gi-Oh! - Ultimate Masters - World Championship Tournament 2006 (USA)"#),
+    "AYWP" => (3, false, "", "", r#"Yu-Gi-Oh! - Worldwide Edition - Stairway to the Destined Duel (Europe)"#),
+    "AYWE" => (3, false, "", "", r#"Yu-Gi-Oh! - Worldwide Edition - Stairway to the Destined Duel (USA)"#),
+    "AY5J" => (3, false, "", "", r#"Yu-Gi-Oh! Duel Monsters 5 Expert 1 (Japan)"#),
+    "AY6J" => (3, false, "", "", r#"Yu-Gi-Oh! Duel Monsters 6 Expert 2 (Japan)"#),
+    "AY7J" => (3, false, "", "", r#"Yu-Gi-Oh! Duel Monsters 7 - Kettou Toshi Densetsu (Japan)"#),
+    "AY8J" => (3, false, "", "", r#"Yu-Gi-Oh! Duel Monsters 8 - Hametsu no Daijashin (Japan)"#),
+    "BY6J" => (3, false, "", "", r#"Yu-Gi-Oh! Duel Monsters Expert 2006 (Japan)"#),
+    "BY3J" => (3, false, "", "", r#"Yu-Gi-Oh! Duel Monsters Expert 3 (Japan)"#),
+    "BYGP" => (3, false, "", "", r#"Shonen Jump's Yu-Gi-Oh! GX - Duel Academy (Europe)"#),
+    "BYGE" => (3, false, "", "", r#"Shonen Jump's Yu-Gi-Oh! GX - Duel Academy (USA)"#),
+    "BYGJ" => (3, false, "", "", r#"Yu-Gi-Oh! Duel Monsters GX - Mezase Duel King (Japan)"#),
+    "AW3J" => (1, false, "", "", r#"Yumemi-chan no Naritai Series 3 - Watashi no Makesalon (Japan)"#),
+    "A4VJ" => (3, false, "", "", r#"Yuujou no Victory Goal 4v4 Arashi - Get the Goal!! (Japan)"#),
+    "AUYJ" => (1, false, "", "", r#"Yuureiyashiki no Nijuuyojikan (Japan)"#),
+    "AZEJ" => (5, false, "", "", r#"Z.O.E. 2173 - Testament (Japan)"#),
+    "AZPP" => (1, false, "", "", r#"Zapper (Europe)"#),
+    "AZPE" => (1, false, "", "", r#"Zapper (USA)"#),
+    "A4GE" => (2, false, "", "", r#"Zatchbell! - Electric Arena (USA)"#),
+    "BZMJ" => (2, false, "", "", r#"Zelda no Densetsu - Fushigi no Boushi (Japan)"#),
+    "AZLJ" => (2, false, "", "", r#"Zelda no Densetsu - Kamigami no Triforce & 4tsu no Tsurugi (Japan)"#),
+    "AGTJ" => (5, false, "", "", r#"Zen-Nihon GT Senshuken (Japan)"#),
+    "A2ZJ" => (5, false, "", "", r#"Zen-Nihon Shounen Soccer Taikai 2 - Mezase Nihon-ichi! (Japan)"#),
+    "AF3J" => (2, false, "", "", r#"Zero One (Japan)"#),
+    "BZOJ" => (2, false, "", "", r#"Zero One SP (Japan)"#),
+    "AZTJ" => (5, false, "", "", r#"Zero-Tours (Japan)"#),
+    "BZGJ" => (3, false, "", "", r#"Zettaizetsumei Dangerous Jiisan - Naki no 1-kai Zettaifukujuu Violence Kouchou - Wagahai ga 1-ban Erainjai!! (Japan)"#),
+    "BZDJ" => (3, false, "", "", r#"Zettaizetsumei Dangerous Jiisan - Shijou Saikyou no Dogeza (Japan)"#),
+    "BJ3J" => (3, false, "", "", r#"Zettaizetsumei Dangerous Jiisan 3 - Hateshinaki Mamonogatari (Japan)"#),
+    "BZ2J" => (3, false, "", "", r#"Zettaizetsumei Dangerous Jiisan Tsuu - Ikari no Oshioki Blues (Japan)"#),
+    "AZDP" => (0, false, "", "", r#"Zidane Football Generation (Europe)"#),
+    "BZYE" => (0, false, "", "", r#"Zoey 101 (USA)"#),
+    "AZ2E" => (3, false, "", "", r#"Zoids Legacy (USA)"#),
+    "ATZJ" => (3, false, "", "", r#"Zoids Saga (Japan, rev. 1)"#),
+    "BZFJ" => (3, false, "", "", r#"Zoids Saga Fuzors (Japan)"#),
+    "AZ2J" => (3, false, "", "", r#"Zoids Saga II (Japan)"#),
+    "U32J" => (2, true, "", "solar_sensor", r#"Zoku Bokura no Taiyou - Taiyou Shounen Django (Japan)"#),
+    "AZEP" => (5, false, "sst", "", r#"Zone of the Enders - The Fist of Mars (Europe)"#),
+    "AZEE" => (5, false, "", "", r#"Zone of the Enders - The Fist of Mars (USA)"#),
+    "ANCJ" => (1, false, "", "", r#"ZooCube (Japan)"#),
+    "ANCE" => (1, false, "", "", r#"ZooCube (USA)"#),
+    "BMZP" => (2, false, "", "", r#"Zooo (Europe)"#),
+    "B2AP" => (1, false, "", "", r#"2 in 1: Astérix & Obélix - Bash Them All! + Astérix & Obélix XXL (Europe)"#),
+    "BBEP" => (0, false, "", "", r#"Barbie Superpack: Groovy Games + Secret Agent (Europe)"#),
+    "BBEE" => (0, false, "", "", r#"Barbie Superpack: Groovy Games + Secret Agent (USA)"#),
+    "BL5P" => (0, false, "", "", r#"2 Games in 1: Bionicle + Knights' Kingdom (Europe)"#),
+    "BW2P" => (0, false, "", "", r#"Double Game!: Cartoon Network Block Party + Cartoon Network Speedway (Europe)"#),
+    "BW2E" => (0, false, "", "", r#"2 Games in 1: Cartoon Network Block Party + Cartoon Network Speedway (USA)"#),
+    "BXKP" => (3, false, "", "", r#"Castlevania Double Pack: Castlevania - Harmony of Dissonance + Castlevania - Aria of Sorrow (Europe)"#),
+    "BXKE" => (3, false, "", "", r#"Castlevania Double Pack: Castlevania - Harmony of Dissonance + Castlevania - Aria of Sorrow (USA)"#),
+    "BW9P" => (4, false, "", "", r#"2 Games in 1: Columns Crown + ChuChu Rocket! (Europe)"#),
+    "B8AE" => (2, false, "", "", r#"Crash Superpack: Crash Bandicoot 2 - N-Tranced + Crash Nitro Kart (USA)"#),
+    "BWBP" => (1, false, "", "", r#"2 Games in 1: Disney Princess + Disney's Brother Bear (Europe)"#),
+    "BLPP" => (1, false, "", "", r#"2 Games in 1: Disney's The Lion King + Disney Princess (Europe)"#),
+    "BLBX" => (1, false, "", "", r#"2 Games in 1: Disney's Brother Bear + Disney's The Lion King (Europe)"#),
+    "BLQP" => (1, false, "", "", r#"2 Disney Games: Disney's Lilo & Stitch 2 + Disney's Peter Pan - Return to Neverland (Europe)"#),
+    "B2EE" => (0, false, "", "", r#"2 Games in One: Dora the Explorer Double Pack (USA)"#),
+    "BLFE" => (2, false, "", "", r#"2 Games in 1: Dragon Ball Z I & II (USA)"#),
+    "BUFE" => (2, false, "", "", r#"2 Games in 1!: Dragon Ball Z - Buu's Fury + Dragon Ball GT - Transformation (USA)"#),
+    "BNEE" => (0, false, "", "", r#"2 Games in 1: Finding Nemo - The Continuing Adventures + The Incredibles (USA)"#),
+    "BFWP" => (0, false, "", "", r#"2 Games in 1: Finding Nemo + Finding Nemo - The Continuing Adventures (Europe)"#),
+    "BINP" => (0, false, "", "", r#"2 Games in 1: Finding Nemo + The Incredibles (Europe, English)"#),
+    "BWCP" => (0, false, "", "", r#"Double Game!: Golden Nugget Casino - Texas Hold 'em Poker (Europe)"#),
+    "BWCE" => (0, false, "", "", r#"2 Games in 1: Golden Nugget Casino - Texas Hold 'em Poker (USA)"#),
+    "BQJE" => (0, false, "", "", r#"2 Game Pack!: Hot Wheels - Stunt Track Challenge + Hot Wheels - World Race (Europe, USA)"#),
+    "BQJP" => (0, false, "", "", r#"2 Game Pack!: Hot Wheels - Stunt Track Challenge + Hot Wheels - World Race (Europe, USA)"#),
+    "BHZE" => (0, false, "", "", r#"2 Games in 1: Hot Wheels - Velocity X + Hot Wheels - World Race (Europe)"#),
+    "B2HP" => (1, false, "", "", r#"Hugo 2 in 1 (Europe)"#),
+    "BB4P" => (0, false, "", "", r#"2 Game Pack! - Matchbox Missions: Emergency Response + Air, Land & Sea Rescue (Europe)"#),
+    "BB4E" => (0, false, "", "", r#"2 Game Pack! - Matchbox Missions: Emergency Response + Air, Land & Sea Rescue (USA)"#),
+    "BDZP" => (0, false, "", "", r#"2 Games in 1: Monsters, Inc. + Finding Nemo (Europe)"#),
+    "BDZE" => (0, false, "", "", r#"2 Games in 1 Double Pack: Monsters, Inc. + Finding Nemo (USA)"#),
+    "BARP" => (1, false, "", "", r#"2 Games in 1: Moto GP + GT Advance 3 - Pro Concept Racing (Europe)"#),
+    "B6PE" => (1, false, "", "", r#"2 Great Games!: Pac-Man World + Ms. Pac-Man - Maze Madness (USA)"#),
+    "BRZP" => (0, false, "", "", r#"2 Games in 1: Power Rangers - Ninja Storm + Power Rangers - Time Force (Europe)"#),
+    "BRZE" => (0, false, "", "", r#"2 Games in 1 Double Pack: Power Rangers - Ninja Storm + Power Rangers - Time Force (USA)"#),
+    "B2QP" => (1, false, "", "", r#"Prince of Persia - The Sands of Time & Lara Croft Tomb Raider - The Prophecy (Europe)"#),
+    "BWQE" => (0, false, "", "", r#"2 Games in 1: Quad Desert Fury + Monster Trucks (USA)"#),
+    "BDFP" => (0, false, "", "", r#"2 Games in 1: Rugrats - Go Wild + SpongeBob SquarePants - SuperSponge (Europe)"#),
+    "BPUE" => (1, false, "", "", r#"2 Games in 1: Scooby-Doo + Scooby-Doo 2 - Monsters Unleashed (USA)"#),
+    "BCVP" => (1, false, "", "", r#"2 Games in 1 Double Pack: Scooby-Doo! - Mystery Mayhem + Scooby-Doo and the Cyber Chase (Europe)"#),
+    "BCVE" => (1, false, "", "", r#"2 Games in 1 Double Pack: Scooby-Doo! - Mystery Mayhem + Scooby-Doo and the Cyber Chase (USA)"#),
+    "BXHE" => (1, false, "", "", r#"2-in-1 Fun Pack: Shrek 2 + Madagascar - Operation Penguin (USA)"#),
+    "BS7P" => (1, false, "", "", r#"2 in 1 Game Pack: Shrek 2 + Shark Tale (Europe)"#),
+    "BS7E" => (1, false, "", "", r#"2 in 1 Game Pack: Shrek 2 + Shark Tale (USA)"#),
+    "BW3P" => (4, false, "", "", r#"2 Games in 1: Sonic Advance + ChuChu Rocket! (Europe)"#),
+    "BW3J" => (4, false, "", "", r#"Double Pack: Sonic Advance + ChuChu Rocket! (Japan)"#),
+    "BW4P" => (4, false, "", "", r#"2 Games in 1: Sonic Advance + Sonic Battle (Europe)"#),
+    "BW4J" => (4, false, "", "", r#"Double Pack: Sonic Advance + Sonic Battle (Japan)"#),
+    "BW5P" => (4, false, "", "", r#"2 Games in 1: Sonic Advance + Sonic Pinball Party (Europe)"#),
+    "BW6J" => (4, false, "", "", r#"Double Pack: Sonic Battle + Sonic Pinball Party (Japan)"#),
+    "BW5E" => (4, false, "", "", r#"Combo Pack - Sonic Advance + Sonic Pinball Party (USA)"#),
+    "BW6P" => (4, false, "", "", r#"2 Games in 1: Sonic Battle + Sonic Pinball Party (Europe)"#),
+    "BW8P" => (4, false, "", "", r#"2 Games in 1: Sonic Pinball Party + Columns Crown (Europe)"#),
+    "BX3P" => (1, false, "", "", r#"2 in 1 Game Pack: Spider-Man & Spider-Man 2 (Europe)"#),
+    "BX3E" => (1, false, "", "", r#"2 in 1 Game Pack: Spider-Man + Spider-Man 2 (USA)"#),
+    "BX2P" => (1, false, "", "", r#"2 in 1 Game Pack: Spider-Man - Mysterio's Menace + X2 - Wolverine's Revenge (Europe, USA)"#),
+    "BBJP" => (0, false, "", "", r#"2 Games in 1: SpongeBob SquarePants - Battle for Bikini Bottom + Jimmy Neutron Boy Genius (Europe)"#),
+    "BX6E" => (0, false, "", "", r#"2 Games in 1 Double Pack: SpongeBob SquarePants - Battle for Bikini Bottom + The Fairly OddParents! - Breakin' da Rules (USA)"#),
+    "BDFE" => (0, false, "", "", r#"2 Games in 1 Double Pack: SpongeBob SquarePants - Revenge of the Flying Dutchman + SpongeBob SquarePants - SuperSponge (USA)"#),
+    "BSZX" => (0, false, "", "", r#"2 Games in 1: SpongeBob SquarePants - SuperSponge & Battle for Bikini Bottom (Europe)"#),
+    "B2BP" => (1, false, "", "", r#"2 Games in 1: The SpongeBob SquarePants Movie + SpongeBob SquarePants and Friends in Freeze Frame Frenzy (Europe)"#),
+    "B5AP" => (2, false, "", "", r#"Spyro - Season of Ice + Crash Bandicoot 2 - N-Tranced (Europe)"#),
+    "B52P" => (2, false, "", "", r#"Spyro 2 - Season of Flame + Crash Nitro Kart (Europe)"#),
+    "B53E" => (2, false, "", "", r#"Spyro Orange - The Cortex Conspiracy + Crash Bandicoot Purple - Ripto's Rampage (USA)"#),
+    "B8SE" => (2, false, "", "", r#"Spyro Superpack: Spyro - Season of Ice + Spyro - Season of Flame (USA)"#),
+    "BT8P" => (2, false, "", "", r#"Teenage Mutant Ninja Turtles Double Pack (Europe)"#),
+    "BT8E" => (2, false, "", "", r#"Teenage Mutant Ninja Turtles Double Pack (USA)"#),
+    "BT5F" => (0, false, "", "", r#"2 Jeux en 1: Titeuf - Ze Gagmachine + Titeuf Méga Compet (France)"#),
+    "BWZP" => (1, false, "", "", r#"Winnie the Pooh's Rumbly Tumbly Adventure & Rayman 3 (Europe)"#),
+    "BY2P" => (3, false, "", "", r#"Yu-Gi-Oh! Double Pack (Europe)"#),
+    "BY2E" => (3, false, "", "", r#"Yu-Gi-Oh! Double Pack (USA)"#),
+    "BYVE" => (3, false, "", "", r#"Yu-Gi-Oh! Double Pack 2 (USA)"#),
+    "B44P" => (1, false, "", "", r#"3 Games in 1: Rugrats - I Gotta Go Party + SpongeBob SquarePants - SuperSponge + Tak and the Power of Juju (Europe)"#),
+    "BI7E" => (3, false, "", "", r#"4 Games on One Game Pak (Nicktoons) (USA)"#),
+    "BI4E" => (3, false, "", "", r#"4 Games on One Game Pak (Racing) (USA)"#),
+    "BFVJ" => (1, false, "", "", r#"Twin Series 1 - Mezase Debut! - Fashion Designer Monogatari + Kawaii Pet Game Gallery 2 (Japan)"#),
+    "BOPJ" => (1, false, "", "", r#"Twin Series 2 - Oshare Princess 4 + Renai Uranai Daisakusen! + Renai Party Game - Sweet Heart (Japan)"#),
+    "BQMJ" => (1, false, "", "", r#"Twin Series 3 - Konchuu Monster - Ouja Ketteisen + Super Chinese Labyrinth (Japan)"#),
+    "BHFJ" => (2, false, "", "", r#"Twin Series 4 - Hamu Hamu Monster EX - Hamster Monogatari RPG + Fantasy Puzzle - Hamster Monogatari - Mahou no Meikyuu 1.2.3 (Japan)"#),
+    "BMWJ" => (2, false, "", "", r#"Twin Series 5 - Mahou no Kuni no Cake-ya-san Monogatari + Wanwan Meitantei EX (Japan)"#),
+    "BWNJ" => (1, false, "", "", r#"Twin Series 6 - Wannyan Idol Gakuen + Koinu to Issho Special (Japan)"#),
+    "B2PJ" => (1, false, "", "", r#"Twin Series 7 - Twin Puzzle - Kisekae Wanko EX + Nyaa to Chuu no Rainbow Magic 2 (Japan)"#),
+
+};
\ No newline at end of file
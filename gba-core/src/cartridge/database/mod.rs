@@ -0,0 +1,61 @@
+use crate::cartridge::flash::FlashVendor;
+use crate::cartridge::storage::BackupType;
+use bitflags::bitflags;
+use tracing::warn;
+
+mod generated;
+
+pub use generated::{GAME_CODE_DATABASE, TITLE_DATABASE};
+
+bitflags! {
+    // extra hardware on the cartridge
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Quirks: u8 {
+        const SOLAR_SENSOR  = 1 << 0;
+        const GYRO          = 1 << 1;
+        const TILT          = 1 << 2;
+        const RUMBLE        = 1 << 3;
+    }
+}
+
+impl Quirks {
+    fn parse(quirks: &str) -> Quirks {
+        quirks
+            .split('|')
+            .filter(|quirk| !quirk.is_empty())
+            .fold(Quirks::empty(), |quirks, quirk| match quirk {
+                "solar_sensor" => quirks | Quirks::SOLAR_SENSOR,
+                "gyro" => quirks | Quirks::GYRO,
+                "tilt" => quirks | Quirks::TILT,
+                "rumble" => quirks | Quirks::RUMBLE,
+                _ => {
+                    warn!(target: "cartridge", "Unknown quirk in game code database: {}", quirk);
+                    quirks
+                }
+            })
+    }
+}
+
+// everything known about a game from the code in its header, shared by all dumps and revisions
+#[derive(Clone)]
+pub struct GameInfo {
+    pub backup_type: BackupType,
+    pub has_rtc: bool,
+    pub flash_vendor: Option<FlashVendor>,
+    pub quirks: Quirks,
+    pub title: &'static str,
+}
+
+impl GameInfo {
+    pub fn lookup(game_code: &str) -> Option<GameInfo> {
+        GAME_CODE_DATABASE
+            .get(game_code)
+            .map(|&(backup_type, has_rtc, flash_vendor, quirks, title)| GameInfo {
+                backup_type: backup_type.into(),
+                has_rtc,
+                flash_vendor: flash_vendor.parse().ok(),
+                quirks: Quirks::parse(quirks),
+                title,
+            })
+    }
+}
@@ -1,11 +1,13 @@
 use crate::cartridge::StorageChip;
 use crate::cartridge::storage::BackupType;
-use crate::memory::device::{Addressable, Saveable};
+use crate::memory::device::{Addressable, Saveable, StorageError};
 use std::cell::{Cell, RefCell};
-use tracing::debug;
+use tracing::{debug, info};
 
 const EEPROM_4K_SIZE: u32 = 0x200; // 512 bytes
-const EEPROM_64K_SIZE: u32 = 0x10000; // 64 KiB
+const EEPROM_64K_SIZE: u32 = 0x2000; // 8 KiB
+const LEGACY_EEPROM_64K_SIZE: u32 = 0x10000; // earlier builds kept 8 KiB chips in 64 KiB
+const EEPROM_BLOCK_MASK: u32 = 0x3FF; // 8 KiB chips only decode the lower 10 of their 14 address bits
 
 #[derive(Default, Clone, Copy)]
 enum EepromState {
@@ -37,10 +39,12 @@ enum EepromState {
     },
 }
 
+#[derive(Clone)]
 pub struct Eeprom {
     pub eeprom: Vec<u8>,
     pub backup_type: BackupType,
     boundary: u32,
+    size_detected: bool,
     state: RefCell<EepromState>,
     last_read_bit: Cell<u8>,
 }
@@ -57,14 +61,32 @@ impl Eeprom {
             eeprom: vec![0xFF; eeprom_size as usize],
             backup_type,
             boundary: eeprom_size,
+            size_detected: false,
             state: RefCell::new(EepromState::Idle),
             last_read_bit: Cell::new(1),
         }
     }
+
+    fn resize(&mut self, backup_type: BackupType) {
+        if backup_type == self.backup_type {
+            return;
+        }
+
+        let eeprom_size = if backup_type == BackupType::Eeprom4k {
+            EEPROM_4K_SIZE
+        } else {
+            EEPROM_64K_SIZE
+        };
+
+        info!(target: "storage", "EEPROM size changed from {} to {}", self.backup_type, backup_type);
+        self.eeprom.resize(eeprom_size as usize, 0xFF);
+        self.backup_type = backup_type;
+        self.boundary = eeprom_size;
+    }
 }
 
 impl Addressable for Eeprom {
-    fn read(&self, addr: u32) -> u8 {
+    fn read_byte(&self, addr: u32) -> u8 {
         if addr & 1 == 1 {
             return self.last_read_bit.get();
         }
@@ -113,7 +135,7 @@ impl Addressable for Eeprom {
         bit
     }
 
-    fn write(&mut self, addr: u32, value: u8) {
+    fn write_byte(&mut self, addr: u32, value: u8) {
         if addr & 1 == 1 {
             return;
         }
@@ -163,7 +185,7 @@ impl Addressable for Eeprom {
                     // All bits of the address have been received
                     // Start fetching data bits
                     *state = EepromState::WriteData {
-                        addr: *addr,
+                        addr: *addr & EEPROM_BLOCK_MASK,
                         data: 0,
                         bits_left: 64,
                     };
@@ -209,7 +231,7 @@ impl Addressable for Eeprom {
                     // All bits of the address have been received
                     // Start the read transfer
                     *state = EepromState::ReadTransfer {
-                        addr: *addr,
+                        addr: *addr & EEPROM_BLOCK_MASK,
                         bits_left: 68,
                     };
                 }
@@ -231,6 +253,28 @@ impl StorageChip for Eeprom {
     fn backing_storage(&self) -> Vec<u8> {
         self.eeprom.clone()
     }
+
+    // the bus width can only be told from the first request the game sends, the address is 6 bits wide on
+    // 512 byte chips and 14 bits on 8 KiB chips: read requests are 2+6+1 or 2+14+1 bits, writes add 64 data bits
+    // https://problemkaputt.de/gbatek.htm#gbacartbackupeeprom
+    fn detect_size(&mut self, transfer_units: u32) {
+        if self.size_detected {
+            return;
+        }
+
+        let backup_type = match transfer_units {
+            9 | 73 => BackupType::Eeprom4k,
+            17 | 81 => BackupType::Eeprom64k,
+            _ => return,
+        };
+
+        self.size_detected = true;
+        self.resize(backup_type);
+    }
+
+    fn clone_box(&self) -> Box<dyn StorageChip> {
+        Box::new(self.clone())
+    }
 }
 
 impl Saveable for Eeprom {
@@ -238,16 +282,45 @@ impl Saveable for Eeprom {
         self.eeprom.clone()
     }
 
-    fn load_storage(&mut self, data: &[u8]) {
-        if data.len() != self.eeprom.len() {
-            panic!(
-                "Invalid EEPROM data length: expected {}, got {}",
-                self.eeprom.len(),
-                data.len()
-            );
-        }
+    fn load_storage(&mut self, data: &[u8]) -> Result<(), StorageError> {
+        // saves remember the size that was detected in a previous session
+        let data = match data.len() as u32 {
+            EEPROM_4K_SIZE => {
+                self.resize(BackupType::Eeprom4k);
+                data
+            }
+            EEPROM_64K_SIZE => {
+                self.resize(BackupType::Eeprom64k);
+                data
+            }
+            // only the first 8 KiB were ever addressed
+            LEGACY_EEPROM_64K_SIZE => {
+                self.resize(BackupType::Eeprom64k);
+                &data[..EEPROM_64K_SIZE as usize]
+            }
+            _ => {
+                return Err(StorageError::InvalidLength {
+                    expected: self.eeprom.len(),
+                    actual: data.len(),
+                });
+            }
+        };
 
         self.eeprom.copy_from_slice(data);
         debug!(target: "storage", "EEPROM loaded with {} bytes", data.len());
+        Ok(())
+    }
+
+    // other emulators store every 64 bit block in the order it is streamed, so msb first
+    fn export_sav(&self) -> Vec<u8> {
+        let mut data = self.eeprom.clone();
+        data.chunks_exact_mut(8).for_each(|block| block.reverse());
+        data
+    }
+
+    fn import_sav(&mut self, data: &[u8]) -> Result<(), StorageError> {
+        let mut data = data.to_vec();
+        data.chunks_exact_mut(8).for_each(|block| block.reverse());
+        self.load_storage(&data)
     }
 }
@@ -1,16 +1,96 @@
 use crate::cartridge::StorageChip;
 use crate::cartridge::storage::BackupType;
-use crate::memory::device::{Addressable, Saveable};
+use crate::memory::device::{Addressable, Saveable, StorageError};
+use std::fmt::Display;
+use std::str::FromStr;
 use tracing::debug;
 
 const FLASH_512K_SIZE: u32 = 0x10000; // 64 KiB
 const FLASH_1M_SIZE: u32 = 0x20000; // 128 KiB
+const FLASH_BANK_SIZE: u32 = 0x10000; // only 64 KiB are visible at once
+const FLASH_SECTOR_SIZE: u32 = 0x1000; // 4 KiB
+const ATMEL_PAGE_SIZE: u32 = 0x80; // Atmel programs whole 128 byte pages
 
+// https://problemkaputt.de/gbatek.htm#gbacartbackupflashrom
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlashVendor {
+    Sanyo,
+    Macronix,
+    Panasonic,
+    Atmel,
+    Sst,
+}
+
+impl FlashVendor {
+    pub fn default_for(backup_type: BackupType) -> Self {
+        match backup_type {
+            BackupType::Flash1m => FlashVendor::Sanyo,
+            _ => FlashVendor::Panasonic,
+        }
+    }
+
+    // (manufacturer, device) as reported in ID mode, Macronix made chips of both sizes
+    pub fn ids(&self, backup_type: BackupType) -> (u8, u8) {
+        match (self, backup_type) {
+            (FlashVendor::Sanyo, _) => (0x62, 0x13),
+            (FlashVendor::Macronix, BackupType::Flash1m) => (0xC2, 0x09),
+            (FlashVendor::Macronix, _) => (0xC2, 0x1C),
+            (FlashVendor::Panasonic, _) => (0x32, 0x1B),
+            (FlashVendor::Atmel, _) => (0x1F, 0x3D),
+            (FlashVendor::Sst, _) => (0xBF, 0xD4),
+        }
+    }
+}
+
+impl FromStr for FlashVendor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "sanyo" => Ok(FlashVendor::Sanyo),
+            "macronix" => Ok(FlashVendor::Macronix),
+            "panasonic" => Ok(FlashVendor::Panasonic),
+            "atmel" => Ok(FlashVendor::Atmel),
+            "sst" => Ok(FlashVendor::Sst),
+            _ => Err(format!(
+                "unknown flash vendor '{}', expected sanyo, macronix, panasonic, atmel or sst",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for FlashVendor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlashVendor::Sanyo => write!(f, "Sanyo"),
+            FlashVendor::Macronix => write!(f, "Macronix"),
+            FlashVendor::Panasonic => write!(f, "Panasonic"),
+            FlashVendor::Atmel => write!(f, "Atmel"),
+            FlashVendor::Sst => write!(f, "SST"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Ready,
+    Program(u32), // number of bytes left to program
+    BankSwitch,   // next write to 0x0E000000 selects the bank
+}
+
+#[derive(Clone)]
 pub struct Flash {
     flash: Vec<u8>,
     backup_type: BackupType,
     boundary: u32,
     _has_rtc: bool,
+    vendor: FlashVendor,
+    bank: u32,
+    mode: Mode,
+    unlock: u8,        // 0 = idle, 1 = got 0xAA at 0x5555, 2 = got 0x55 at 0x2AAA
+    id_mode: bool,     // manufacturer and device ID replace the first two bytes
+    erase_armed: bool, // got the 0x80 erase prefix
 }
 
 impl Flash {
@@ -22,43 +102,98 @@ impl Flash {
         };
 
         Flash {
-            flash: vec![0; flash_size as usize],
+            flash: vec![0xFF; flash_size as usize],
             backup_type,
             boundary: flash_size,
             _has_rtc: has_rtc,
+            vendor: FlashVendor::default_for(backup_type),
+            bank: 0,
+            mode: Mode::Ready,
+            unlock: 0,
+            id_mode: false,
+            erase_armed: false,
+        }
+    }
+
+    fn offset(&self, addr: u32) -> usize {
+        ((addr & (FLASH_BANK_SIZE - 1)) + self.bank * FLASH_BANK_SIZE) as usize
+    }
+
+    fn command(&mut self, command: u8) {
+        let erase_armed = std::mem::take(&mut self.erase_armed);
+
+        match command {
+            0x90 => self.id_mode = true,
+            0xF0 => self.id_mode = false,
+            0x80 => self.erase_armed = true,
+            0x10 if erase_armed => {
+                debug!(target: "storage", "Flash chip erase");
+                self.flash.fill(0xFF);
+            }
+            0xA0 if self.vendor == FlashVendor::Atmel => self.mode = Mode::Program(ATMEL_PAGE_SIZE),
+            0xA0 => self.mode = Mode::Program(1),
+            0xB0 if self.backup_type == BackupType::Flash1m => self.mode = Mode::BankSwitch,
+            _ => debug!(target: "storage", "Unknown Flash command: {:02X}", command),
         }
     }
 }
 
 impl Addressable for Flash {
-    fn read(&self, addr: u32) -> u8 {
+    fn read_byte(&self, addr: u32) -> u8 {
         match addr {
-            0x0E000000 => {
-                let id = self.backup_type.manufacturer_id();
-                debug!(target: "storage", "Spoofed Flash Manufacturer ID: {:02X}", id);
+            0x0E000000..=0x0E000001 if self.id_mode => {
+                let (manufacturer, device) = self.vendor.ids(self.backup_type);
+                let id = if addr & 1 == 0 { manufacturer } else { device };
+                debug!(target: "storage", "Flash ID read ({}): {:02X}", self.vendor, id);
                 id
             }
-            0x0E000001 => {
-                let id = self.backup_type.device_id();
-                debug!(target: "storage", "Spoofed Flash Device ID: {:02X}", id);
-                id
-            }
-            0x0E000002..=0x0FFFFFFF => {
-                let addr = (addr - 0x0E000000) % self.boundary;
-                self.flash[addr as usize]
-            }
+            0x0E000000..=0x0FFFFFFF => self.flash[self.offset(addr)],
             _ => unreachable!("Invalid address for Flash read: {:08X}", addr),
         }
     }
 
-    fn write(&mut self, addr: u32, value: u8) {
-        match addr {
-            0x0E000002..=0x0FFFFFFF => {
-                let addr = (addr - 0x0E000000) % self.boundary;
-                self.flash[addr as usize] = value;
+    fn write_byte(&mut self, addr: u32, value: u8) {
+        let register = addr & (FLASH_BANK_SIZE - 1);
+
+        match self.mode {
+            Mode::Program(remaining) => {
+                let offset = self.offset(addr);
+                self.flash[offset] = value;
+                self.mode = if remaining > 1 {
+                    Mode::Program(remaining - 1)
+                } else {
+                    Mode::Ready
+                };
+                return;
+            }
+            Mode::BankSwitch if register == 0 => {
+                debug!(target: "storage", "Flash bank switch: {}", value & 1);
+                self.bank = (value & 1) as u32;
+                self.mode = Mode::Ready;
+                return;
             }
             _ => {}
         }
+
+        self.unlock = match (self.unlock, register, value) {
+            (0, 0x5555, 0xAA) => 1,
+            (1, 0x2AAA, 0x55) => 2,
+            (2, 0x5555, command) => {
+                self.command(command);
+                0
+            }
+            (2, sector, 0x30) if self.erase_armed => {
+                self.erase_armed = false;
+                let start = self.offset(sector & !(FLASH_SECTOR_SIZE - 1));
+                debug!(target: "storage", "Flash sector erase: {:05X}", start);
+                self.flash[start..start + FLASH_SECTOR_SIZE as usize].fill(0xFF);
+                0
+            }
+            _ => {
+                debug!(target: "storage", "Ignoring Flash write: {:02X} to {:08X}", value, addr);
+                0
+            }
+        };
     }
 }
 
@@ -74,6 +209,15 @@ impl StorageChip for Flash {
     fn backing_storage(&self) -> Vec<u8> {
         self.flash.clone()
     }
+
+    fn select_flash_vendor(&mut self, vendor: FlashVendor) {
+        debug!(target: "storage", "Flash vendor: {}", vendor);
+        self.vendor = vendor;
+    }
+
+    fn clone_box(&self) -> Box<dyn StorageChip> {
+        Box::new(self.clone())
+    }
 }
 
 impl Saveable for Flash {
@@ -81,16 +225,16 @@ impl Saveable for Flash {
         self.flash.clone()
     }
 
-    fn load_storage(&mut self, data: &[u8]) {
+    fn load_storage(&mut self, data: &[u8]) -> Result<(), StorageError> {
         if data.len() != self.flash.len() {
-            panic!(
-                "Invalid Flash data length: expected {}, got {}",
-                self.flash.len(),
-                data.len()
-            );
+            return Err(StorageError::InvalidLength {
+                expected: self.flash.len(),
+                actual: data.len(),
+            });
         }
 
         debug!(target: "storage", "Loading Flash data of length: {}", data.len());
         self.flash.copy_from_slice(data);
+        Ok(())
     }
 }
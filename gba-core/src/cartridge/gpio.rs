@@ -0,0 +1,247 @@
+use crate::memory::device::Addressable;
+use std::fmt::Display;
+use tracing::{debug, warn};
+
+// https://problemkaputt.de/gbatek.htm#gbacartioportgpio
+const GPIO_DATA: u32 = 0x080000C4;
+const GPIO_DIRECTION: u32 = 0x080000C6;
+const GPIO_CONTROL: u32 = 0x080000C8;
+
+const GYRO_CENTER: f32 = 0x6C0 as f32; // 12 bit adc value at rest
+const GYRO_RANGE: f32 = 0x300 as f32;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompatibilityWarning {
+    pub device: &'static str,
+    pub addr: u32,
+    pub value: u8,
+}
+
+impl Display for CompatibilityWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Game probed the {} via GPIO ({:02X} to {:08X}), but it is not emulated",
+            self.device, self.value, self.addr
+        )
+    }
+}
+
+// https://problemkaputt.de/gbatek.htm#gbacartsolarsensor
+// pin 0 = clock, pin 1 = reset, pin 2 = chip select (active low), pin 3 = flag
+#[derive(Clone)]
+pub struct SolarSensor {
+    pub level: u8, // 0 = darkness, 0xFF = direct sunlight
+    counter: u8,   // clock pulses since the last reset
+    sample: u8,    // counter value at which the flag goes high, latched on reset
+    clock: bool,   // previous state of the clock pin
+}
+
+impl SolarSensor {
+    pub fn new() -> Self {
+        SolarSensor {
+            level: 0x80,
+            counter: 0,
+            sample: 0xFF,
+            clock: false,
+        }
+    }
+
+    fn write_pins(&mut self, pins: u8) {
+        if pins & 0b0100 != 0 {
+            return;
+        }
+
+        if pins & 0b0010 != 0 {
+            // the brighter the light, the sooner the adc counter passes the sample
+            self.counter = 0;
+            self.sample = 0xFF - self.level;
+        }
+
+        let clock = pins & 0b0001 != 0;
+        if clock && !self.clock {
+            self.counter = self.counter.wrapping_add(1);
+        }
+        self.clock = clock;
+    }
+
+    fn flag(&self) -> bool {
+        self.counter >= self.sample
+    }
+}
+
+impl Default for SolarSensor {
+    fn default() -> Self {
+        SolarSensor::new()
+    }
+}
+
+// https://problemkaputt.de/gbatek.htm#gbacartgyrosensor
+// pin 0 = start conversion, pin 1 = serial clock, pin 2 = serial data, pin 3 = rumble motor
+#[derive(Clone)]
+pub struct Gyro {
+    pub rotation: f32, // -1.0 = full speed counter-clockwise, 1.0 = full speed clockwise
+    sample: u16,       // shifted out msb first, the 12 bit value is preceded by 4 zero bits
+    clock: bool,       // previous state of the clock pin
+    data: bool,        // serial output
+}
+
+impl Gyro {
+    pub fn new() -> Self {
+        Gyro {
+            rotation: 0.0,
+            sample: 0,
+            clock: false,
+            data: false,
+        }
+    }
+
+    fn write_pins(&mut self, pins: u8) {
+        if pins & 0b0001 != 0 {
+            let rotation = self.rotation.clamp(-1.0, 1.0);
+            self.sample = (GYRO_CENTER + rotation * GYRO_RANGE) as u16 & 0xFFF;
+        }
+
+        // the next bit is put on the line on the falling edge of the clock
+        let clock = pins & 0b0010 != 0;
+        if self.clock && !clock {
+            self.data = self.sample & 0x8000 != 0;
+            self.sample <<= 1;
+        }
+        self.clock = clock;
+    }
+}
+
+impl Default for Gyro {
+    fn default() -> Self {
+        Gyro::new()
+    }
+}
+
+// https://problemkaputt.de/gbatek.htm#gbacartrumble
+// pin 3 drives the motor directly, games pulse it to vary the strength
+#[derive(Clone)]
+pub struct Rumble {
+    pub active: bool,
+}
+
+impl Rumble {
+    pub fn new() -> Self {
+        Rumble { active: false }
+    }
+
+    fn write_pins(&mut self, pins: u8) {
+        self.active = pins & 0b1000 != 0;
+    }
+}
+
+impl Default for Rumble {
+    fn default() -> Self {
+        Rumble::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct Gpio {
+    data: u8,      // 4 bit, pin state
+    direction: u8, // 4 bit, 0 = in (gba <- device), 1 = out (gba -> device)
+    control: u8,   // 1 bit, 0 = write only, 1 = read/write
+    has_rtc: bool,
+    pub solar_sensor: Option<SolarSensor>,
+    pub gyro: Option<Gyro>,
+    pub rumble: Option<Rumble>,
+    pub interpolate_missing: bool, // return "no device" patterns instead of zeros
+    pub compatibility_warning: Option<CompatibilityWarning>,
+}
+
+impl Gpio {
+    pub fn new(has_rtc: bool) -> Self {
+        Gpio {
+            data: 0,
+            direction: 0,
+            control: 0,
+            has_rtc,
+            solar_sensor: None,
+            gyro: None,
+            rumble: None,
+            interpolate_missing: false,
+            compatibility_warning: None,
+        }
+    }
+
+    pub fn is_rumbling(&self) -> bool {
+        self.rumble.as_ref().is_some_and(|rumble| rumble.active)
+    }
+
+    pub fn is_readable(&self) -> bool {
+        self.control & 1 != 0
+    }
+
+    fn device_name(&self) -> &'static str {
+        if self.has_rtc { "RTC" } else { "unknown peripheral" }
+    }
+
+    fn has_device(&self) -> bool {
+        self.solar_sensor.is_some() || self.gyro.is_some() || self.rumble.is_some()
+    }
+
+    fn read_data(&self) -> u8 {
+        // with no device attached, the input pins are left floating and read as pulled-up
+        let input = if let Some(sensor) = &self.solar_sensor {
+            (sensor.flag() as u8) << 3
+        } else if let Some(gyro) = &self.gyro {
+            (gyro.data as u8) << 2
+        } else if self.interpolate_missing {
+            !self.direction & 0xF
+        } else {
+            0
+        };
+        (self.data & self.direction) | (input & !self.direction)
+    }
+}
+
+impl Addressable for Gpio {
+    fn read_byte(&self, addr: u32) -> u8 {
+        match addr {
+            GPIO_DATA => self.read_data(),
+            GPIO_DIRECTION => self.direction,
+            GPIO_CONTROL => self.control,
+            0x080000C5 | 0x080000C7 | 0x080000C9 => 0,
+            _ => unreachable!(),
+        }
+    }
+
+    fn write_byte(&mut self, addr: u32, value: u8) {
+        debug!(target: "cartridge", "GPIO write: {:02X} to {:08X}", value, addr);
+
+        match addr {
+            GPIO_DATA => {
+                self.data = value & 0xF;
+                if let Some(sensor) = &mut self.solar_sensor {
+                    sensor.write_pins(self.data & self.direction);
+                }
+                if let Some(gyro) = &mut self.gyro {
+                    gyro.write_pins(self.data & self.direction);
+                }
+                if let Some(rumble) = &mut self.rumble {
+                    rumble.write_pins(self.data & self.direction);
+                }
+            }
+            GPIO_DIRECTION => self.direction = value & 0xF,
+            GPIO_CONTROL => self.control = value & 1,
+            0x080000C5 | 0x080000C7 | 0x080000C9 => {}
+            _ => unreachable!(),
+        }
+
+        // only raise the warning once, games tend to bit-bang the pins constantly
+        if self.compatibility_warning.is_none() && (self.has_rtc || !self.has_device()) {
+            let warning = CompatibilityWarning {
+                device: self.device_name(),
+                addr,
+                value,
+            };
+            warn!(target: "cartridge", "{}", warning);
+            self.compatibility_warning = Some(warning);
+        }
+    }
+}
@@ -1,14 +1,33 @@
+use crate::cartridge::flash::FlashVendor;
 use crate::cartridge::storage::BackupType;
 use crate::memory::device::{Addressable, Saveable};
 
 pub mod database;
 pub mod eeprom;
 pub mod flash;
+pub mod gpio;
+pub mod patch;
 pub mod sram;
 pub mod storage;
+pub mod tilt;
 
 pub trait StorageChip: Addressable + Saveable {
     fn size(&self) -> usize;
     fn backup_type(&self) -> BackupType;
     fn backing_storage(&self) -> Vec<u8>;
+
+    // called with the length of every DMA3 transfer written to the chip
+    fn detect_size(&mut self, _transfer_units: u32) {}
+
+    // ID reported in Flash ID mode, some games only accept specific chips
+    fn select_flash_vendor(&mut self, _vendor: FlashVendor) {}
+
+    // savestates hold on to the contents as well
+    fn clone_box(&self) -> Box<dyn StorageChip>;
+}
+
+impl Clone for Box<dyn StorageChip> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
 }
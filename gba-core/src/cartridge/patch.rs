@@ -0,0 +1,243 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum PatchError {
+    #[error("Unknown patch format")]
+    UnknownFormat,
+    #[error("Patch is truncated or malformed")]
+    Malformed,
+    #[error("Patch was made for a different ROM (CRC32 {expected:08X}, got {actual:08X})")]
+    SourceMismatch { expected: u32, actual: u32 },
+    #[error("Patched ROM does not match the expected result (CRC32 {expected:08X}, got {actual:08X})")]
+    TargetMismatch { expected: u32, actual: u32 },
+}
+
+// applies an IPS, UPS or BPS patch, the format is picked from the header
+pub fn apply_patch(rom: &[u8], patch: &[u8]) -> Result<Vec<u8>, PatchError> {
+    match patch {
+        [b'P', b'A', b'T', b'C', b'H', ..] => apply_ips(rom, &patch[5..]),
+        [b'U', b'P', b'S', b'1', ..] => apply_ups(rom, patch),
+        [b'B', b'P', b'S', b'1', ..] => apply_bps(rom, patch),
+        _ => Err(PatchError::UnknownFormat),
+    }
+}
+
+struct PatchReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> PatchReader<'a> {
+    fn new(data: &'a [u8], pos: usize) -> Self {
+        PatchReader { data, pos }
+    }
+
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8], PatchError> {
+        let bytes = self.data.get(self.pos..self.pos + count).ok_or(PatchError::Malformed)?;
+        self.pos += count;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, PatchError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    // big endian, as used by IPS
+    fn number(&mut self, count: usize) -> Result<usize, PatchError> {
+        Ok(self
+            .bytes(count)?
+            .iter()
+            .fold(0, |value, &byte| (value << 8) | byte as usize))
+    }
+
+    // variable length integer used by UPS and BPS, every continuation implicitly adds one
+    fn varint(&mut self) -> Result<usize, PatchError> {
+        let mut value = 0usize;
+        let mut shift = 1usize;
+
+        loop {
+            let byte = self.byte()?;
+            value = value
+                .checked_add((byte & 0x7F) as usize * shift)
+                .ok_or(PatchError::Malformed)?;
+            if byte & 0x80 != 0 {
+                return Ok(value);
+            }
+            shift = shift.checked_shl(7).ok_or(PatchError::Malformed)?;
+            value += shift;
+        }
+    }
+}
+
+// https://zerosoft.zophar.net/ips.php
+fn apply_ips(rom: &[u8], patch: &[u8]) -> Result<Vec<u8>, PatchError> {
+    let mut target = rom.to_vec();
+    let mut reader = PatchReader::new(patch, 0);
+
+    loop {
+        if reader.bytes(3)? == b"EOF" {
+            break;
+        }
+        reader.pos -= 3;
+
+        let offset = reader.number(3)?;
+        let (length, data) = match reader.number(2)? {
+            0 => {
+                // run length encoded record
+                let length = reader.number(2)?;
+                (length, None)
+            }
+            length => (length, Some(reader.bytes(length)?)),
+        };
+
+        if target.len() < offset + length {
+            target.resize(offset + length, 0);
+        }
+
+        match data {
+            Some(data) => target[offset..offset + length].copy_from_slice(data),
+            None => {
+                let value = reader.byte()?;
+                target[offset..offset + length].fill(value);
+            }
+        }
+    }
+
+    // optional truncation extension
+    if let Ok(length) = reader.number(3) {
+        target.truncate(length);
+    }
+
+    Ok(target)
+}
+
+// source, target and patch CRC32 at the end of UPS and BPS patches
+fn footer(patch: &[u8]) -> Result<(u32, u32), PatchError> {
+    if patch.len() < 16 {
+        return Err(PatchError::Malformed);
+    }
+
+    let crc = |offset: usize| u32::from_le_bytes(patch[offset..offset + 4].try_into().unwrap());
+    let (source, target, patch_crc) = (crc(patch.len() - 12), crc(patch.len() - 8), crc(patch.len() - 4));
+
+    if crc32fast::hash(&patch[..patch.len() - 4]) != patch_crc {
+        return Err(PatchError::Malformed);
+    }
+
+    Ok((source, target))
+}
+
+fn check_source(rom: &[u8], expected: u32) -> Result<(), PatchError> {
+    let actual = crc32fast::hash(rom);
+    if actual != expected {
+        return Err(PatchError::SourceMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+fn check_target(target: &[u8], expected: u32) -> Result<(), PatchError> {
+    let actual = crc32fast::hash(target);
+    if actual != expected {
+        return Err(PatchError::TargetMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+// https://www.romhacking.net/documents/392/
+fn apply_ups(rom: &[u8], patch: &[u8]) -> Result<Vec<u8>, PatchError> {
+    let (source_crc, target_crc) = footer(patch)?;
+    check_source(rom, source_crc)?;
+
+    let end = patch.len() - 12;
+    let mut reader = PatchReader::new(&patch[..end], 4);
+
+    let _source_size = reader.varint()?;
+    let target_size = reader.varint()?;
+
+    let mut target = rom.to_vec();
+    target.resize(target_size, 0);
+
+    let mut pos = 0;
+    while reader.pos < end {
+        pos += reader.varint()?;
+
+        // xor with the source until a zero byte, which also skips one byte
+        loop {
+            let value = reader.byte()?;
+            if value == 0 {
+                pos += 1;
+                break;
+            }
+            if let Some(byte) = target.get_mut(pos) {
+                *byte ^= value;
+            }
+            pos += 1;
+        }
+    }
+
+    check_target(&target, target_crc)?;
+    Ok(target)
+}
+
+// https://github.com/blakesmith/rombp/blob/master/docs/bps_spec.md
+fn apply_bps(rom: &[u8], patch: &[u8]) -> Result<Vec<u8>, PatchError> {
+    let (source_crc, target_crc) = footer(patch)?;
+    check_source(rom, source_crc)?;
+
+    let end = patch.len() - 12;
+    let mut reader = PatchReader::new(&patch[..end], 4);
+
+    let _source_size = reader.varint()?;
+    let target_size = reader.varint()?;
+    let metadata_size = reader.varint()?;
+    reader.bytes(metadata_size)?;
+
+    let mut target = Vec::with_capacity(target_size);
+    let mut source_offset = 0isize;
+    let mut target_offset = 0isize;
+
+    // relative offsets are stored as magnitude and sign bit
+    let relative = |reader: &mut PatchReader, offset: &mut isize| -> Result<usize, PatchError> {
+        let data = reader.varint()?;
+        let delta = (data >> 1) as isize;
+        *offset += if data & 1 != 0 { -delta } else { delta };
+        usize::try_from(*offset).map_err(|_| PatchError::Malformed)
+    };
+
+    while reader.pos < end {
+        let data = reader.varint()?;
+        let length = (data >> 2) + 1;
+
+        match data & 3 {
+            // source read
+            0 => {
+                let start = target.len();
+                target.extend_from_slice(rom.get(start..start + length).ok_or(PatchError::Malformed)?);
+            }
+            // target read
+            1 => target.extend_from_slice(reader.bytes(length)?),
+            // source copy
+            2 => {
+                let start = relative(&mut reader, &mut source_offset)?;
+                target.extend_from_slice(rom.get(start..start + length).ok_or(PatchError::Malformed)?);
+                source_offset += length as isize;
+            }
+            // target copy, may overlap with the bytes being written
+            _ => {
+                let start = relative(&mut reader, &mut target_offset)?;
+                for i in start..start + length {
+                    let byte = *target.get(i).ok_or(PatchError::Malformed)?;
+                    target.push(byte);
+                }
+                target_offset += length as isize;
+            }
+        }
+    }
+
+    if target.len() != target_size {
+        return Err(PatchError::Malformed);
+    }
+
+    check_target(&target, target_crc)?;
+    Ok(target)
+}
@@ -2,10 +2,11 @@ use tracing::debug;
 
 use crate::cartridge::StorageChip;
 use crate::cartridge::storage::BackupType;
-use crate::memory::device::{Addressable, Saveable};
+use crate::memory::device::{Addressable, Saveable, StorageError};
 
 const SRAM_SIZE: u32 = 0x8000; // 32 KiB
 
+#[derive(Clone)]
 pub struct Sram {
     sram: Vec<u8>,
     backup_type: BackupType,
@@ -21,7 +22,7 @@ impl Sram {
 }
 
 impl Addressable for Sram {
-    fn read(&self, addr: u32) -> u8 {
+    fn read_byte(&self, addr: u32) -> u8 {
         match addr {
             0x0E000000..=0x0FFFFFFF => {
                 // GamePak SRAM – mirrors every 32 KiB in 0x0E000000‑0x0FFFFFFF
@@ -32,7 +33,7 @@ impl Addressable for Sram {
         }
     }
 
-    fn write(&mut self, addr: u32, value: u8) {
+    fn write_byte(&mut self, addr: u32, value: u8) {
         match addr {
             0x0E000000..=0x0FFFFFFF => {
                 // GamePak SRAM – mirrors every 32 KiB in 0x0E000000‑0x0FFFFFFF
@@ -56,6 +57,10 @@ impl StorageChip for Sram {
     fn backing_storage(&self) -> Vec<u8> {
         self.sram.clone()
     }
+
+    fn clone_box(&self) -> Box<dyn StorageChip> {
+        Box::new(self.clone())
+    }
 }
 
 impl Saveable for Sram {
@@ -63,12 +68,16 @@ impl Saveable for Sram {
         self.sram.clone()
     }
 
-    fn load_storage(&mut self, data: &[u8]) {
+    fn load_storage(&mut self, data: &[u8]) -> Result<(), StorageError> {
         if data.len() != SRAM_SIZE as usize {
-            panic!("Invalid SRAM data size: expected {}, got {}", SRAM_SIZE, data.len());
+            return Err(StorageError::InvalidLength {
+                expected: SRAM_SIZE as usize,
+                actual: data.len(),
+            });
         }
 
         debug!(target: "storage", "Loading SRAM with {} bytes", data.len());
         self.sram.copy_from_slice(data);
+        Ok(())
     }
 }
@@ -1,11 +1,17 @@
-const SANYO_MANUFACTURER_ID: u8 = 0x62;
-const SANYO_DEVICE_ID: u8 = 0x13;
-const PANASONIC_MANUFACTURER_ID: u8 = 0x32;
-const PANASONIC_DEVICE_ID: u8 = 0x1B;
-
 // TODO: Switch to https://docs.google.com/spreadsheets/d/16-a3qDDkJJNpaYOEXi-xgTv-j1QznXHt9rTUJNFshjo/edit?pli=1&gid=0#gid=0 maybe?
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+// ID strings the Nintendo SDK backup libraries leave in the ROM, always word aligned
+// https://problemkaputt.de/gbatek.htm#gbacartbackupids
+const BACKUP_IDS: [(&[u8], BackupType); 6] = [
+    (b"EEPROM_V", BackupType::Eeprom64k), // the size is detected from the first transfer
+    (b"SRAM_V", BackupType::Sram),
+    (b"SRAM_F_V", BackupType::Sram),
+    (b"FLASH_V", BackupType::Flash512k),
+    (b"FLASH512_V", BackupType::Flash512k),
+    (b"FLASH1M_V", BackupType::Flash1m),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BackupType {
     Eeprom4k,
     Eeprom64k,
@@ -16,24 +22,17 @@ pub enum BackupType {
 }
 
 impl BackupType {
-    pub fn has_rtc(&self) -> bool {
-        matches!(self, BackupType::Flash512k | BackupType::Flash1m)
-    }
-
-    pub fn manufacturer_id(&self) -> u8 {
-        match self {
-            BackupType::Flash512k => PANASONIC_MANUFACTURER_ID,
-            BackupType::Flash1m => SANYO_MANUFACTURER_ID,
-            _ => unreachable!(),
-        }
+    pub fn scan(rom: &[u8]) -> Option<BackupType> {
+        (0..rom.len()).step_by(4).find_map(|offset| {
+            BACKUP_IDS
+                .iter()
+                .find(|(id, _)| rom[offset..].starts_with(id))
+                .map(|&(_, backup_type)| backup_type)
+        })
     }
 
-    pub fn device_id(&self) -> u8 {
-        match self {
-            BackupType::Flash512k => PANASONIC_DEVICE_ID,
-            BackupType::Flash1m => SANYO_DEVICE_ID,
-            _ => unreachable!(),
-        }
+    pub fn has_rtc(&self) -> bool {
+        matches!(self, BackupType::Flash512k | BackupType::Flash1m)
     }
 }
 
@@ -51,6 +50,26 @@ impl From<u8> for BackupType {
     }
 }
 
+// names accepted for overriding the detected backup type
+impl std::str::FromStr for BackupType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(BackupType::None),
+            "sram" => Ok(BackupType::Sram),
+            "flash64" => Ok(BackupType::Flash512k),
+            "flash128" => Ok(BackupType::Flash1m),
+            "eeprom512" => Ok(BackupType::Eeprom4k),
+            "eeprom8k" => Ok(BackupType::Eeprom64k),
+            _ => Err(format!(
+                "unknown save type '{}', expected none, sram, flash64, flash128, eeprom512 or eeprom8k",
+                s
+            )),
+        }
+    }
+}
+
 impl std::fmt::Display for BackupType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,67 @@
+use crate::memory::device::Addressable;
+use tracing::debug;
+
+const TILT_CENTER: f32 = 0x3A0 as f32; // 12 bit adc value when held flat
+const TILT_RANGE: f32 = 0xE0 as f32;
+
+// https://problemkaputt.de/gbatek.htm#gbacarttiltsensor
+// sits on the SRAM bus, writing 0x55 and then 0xAA starts a conversion of both axes
+#[derive(Clone)]
+pub struct TiltSensor {
+    pub x: f32, // -1.0 = tilted left, 1.0 = tilted right
+    pub y: f32, // -1.0 = tilted forward, 1.0 = tilted back
+    sample_x: u16,
+    sample_y: u16,
+    armed: bool, // 0x55 has been written
+    ready: bool, // a conversion has finished
+}
+
+impl TiltSensor {
+    pub fn new() -> Self {
+        TiltSensor {
+            x: 0.0,
+            y: 0.0,
+            sample_x: TILT_CENTER as u16,
+            sample_y: TILT_CENTER as u16,
+            armed: false,
+            ready: false,
+        }
+    }
+
+    fn sample(&mut self) {
+        self.sample_x = (TILT_CENTER + self.x.clamp(-1.0, 1.0) * TILT_RANGE) as u16 & 0xFFF;
+        self.sample_y = (TILT_CENTER + self.y.clamp(-1.0, 1.0) * TILT_RANGE) as u16 & 0xFFF;
+        self.ready = true;
+    }
+}
+
+impl Default for TiltSensor {
+    fn default() -> Self {
+        TiltSensor::new()
+    }
+}
+
+impl Addressable for TiltSensor {
+    fn read_byte(&self, addr: u32) -> u8 {
+        match addr & 0xFFFFFF00 {
+            0x0E008200 => self.sample_x as u8,
+            0x0E008300 => (self.sample_x >> 8) as u8 | ((self.ready as u8) << 7),
+            0x0E008400 => self.sample_y as u8,
+            0x0E008500 => (self.sample_y >> 8) as u8,
+            _ => 0,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u32, value: u8) {
+        debug!(target: "cartridge", "Tilt sensor write: {:02X} to {:08X}", value, addr);
+
+        match (addr & 0xFFFFFF00, value) {
+            (0x0E008000, 0x55) => self.armed = true,
+            (0x0E008100, 0xAA) if self.armed => {
+                self.armed = false;
+                self.sample();
+            }
+            _ => self.armed = false,
+        }
+    }
+}
@@ -1,58 +1,315 @@
+use crate::arm7tdmi::bios::HLE_BIOS;
 use crate::arm7tdmi::cpu::Cpu;
-use crate::cartridge::database::TITLE_DATABASE;
+use crate::arm7tdmi::pipeline::Pipeline;
+use crate::arm7tdmi::registers::Registers;
+use crate::cartridge::database::{GameInfo, Quirks, TITLE_DATABASE};
+use crate::cartridge::gpio::{Gyro, Rumble, SolarSensor};
+use crate::cartridge::patch::apply_patch;
 use crate::cartridge::storage::BackupType;
+use crate::cartridge::tilt::TiltSensor;
+use crate::input::registers::KeyInput;
+use crate::memory::device::AccessKind;
 use crate::memory::mmio::Mmio;
-use crate::script::engine::ScriptEngine;
+use crate::memory::registers::RamResetFlags;
+use crate::script::engine::{ScriptEngine, TestReport};
+use crate::video::Frame;
 use std::path::Path;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+// how the cartridge is started, everything that is not set here is detected from the ROM
+#[derive(Debug, Default, Clone)]
+pub struct BootOptions {
+    pub skip_bios: bool, // start at the cartridge entry point in the state the BIOS leaves behind
+    pub save_type: Option<BackupType>, // for ROMs where backup type detection gets it wrong
+    pub patch: Option<Vec<u8>>, // IPS, UPS or BPS patch applied to the ROM before booting
+    pub multiboot: bool, // the image was sent over the link cable and runs from EWRAM with an empty cartridge slot
+    pub bios: Option<Vec<u8>>, // BIOS image to use instead of the built-in one
+    pub fast_boot: bool, // run the BIOS boot sequence before returning, has no effect if the BIOS is skipped
+}
+
+// the emulated hardware at one point in time, including the backup memory, the ROM is shared with the running system
+#[derive(Clone)]
+pub struct SaveState {
+    registers: Registers,
+    pipeline: Pipeline,
+    mmio: Mmio,
+}
+
+impl SaveState {
+    pub fn capture(cpu: &Cpu) -> SaveState {
+        SaveState {
+            registers: cpu.registers.clone(),
+            pipeline: cpu.pipeline.clone(),
+            mmio: cpu.mmio.clone(),
+        }
+    }
+
+    // like capture, but the buffers of this state are reused instead of allocating new ones
+    pub fn recapture(&mut self, cpu: &Cpu) {
+        self.registers.clone_from(&cpu.registers);
+        self.pipeline.clone_from(&cpu.pipeline);
+        self.mmio.clone_from(&cpu.mmio);
+    }
+
+    // the rewind history can't step back across the load, so it starts over
+    pub fn restore(&self, cpu: &mut Cpu) {
+        cpu.registers = self.registers.clone();
+        cpu.pipeline = self.pipeline.clone();
+        cpu.mmio.restore(&self.mmio);
+
+        if let Some(history) = &cpu.history {
+            cpu.enable_history(history.capacity());
+        }
+    }
+}
+
+// the logo takes a few seconds, this is plenty
+const BOOT_SEQUENCE_MAX_TICKS: usize = 100_000_000;
+
+const BIOS_SIZE: usize = 0x4000;
+
+// CRC32 of the known BIOS dumps, both behave the same in GBA mode
+const KNOWN_BIOS: [(u32, &str); 2] = [(0x81977335, "GBA"), (0xA6473709, "NDS")];
+
+// entry point, logo, title and game code, anything shorter can't be a ROM
+pub const CARTRIDGE_HEADER_SIZE: usize = 0xC0;
+
+// multiboot images have to fit into EWRAM
+const MULTIBOOT_MAX_SIZE: usize = 0x40000;
+
+// sizes of the raw battery files: EEPROM, SRAM and Flash
+const EEPROM_4K_SIZE: usize = 0x200;
+const EEPROM_64K_SIZE: usize = 0x2000;
+const SAV_SIZES: [usize; 5] = [EEPROM_4K_SIZE, EEPROM_64K_SIZE, 0x8000, 0x10000, 0x20000];
 
 pub struct Gba {
     pub cpu: Cpu,
     pub script_engine: Option<ScriptEngine>,
     pub rom_title: String,
     pub crc32: String,
+    pub mirror_aware_breakpoints: bool,
 }
 
 impl Gba {
-    pub fn new(rom_data: &[u8], elf_data: &[u8]) -> Self {
+    pub fn new(rom_data: &[u8], elf_data: &[u8], options: BootOptions) -> Self {
+        // patched ROMs keep the game code, so only the exact dump lookup is lost
+        let patched_rom;
+        let rom_data = match options.patch.as_deref().map(|patch| apply_patch(rom_data, patch)) {
+            Some(Ok(rom)) if rom.len() < CARTRIDGE_HEADER_SIZE => {
+                error!(target: "cartridge", "Patched ROM is only {} bytes, booting the unpatched ROM", rom.len());
+                rom_data
+            }
+            Some(Ok(rom)) => {
+                info!(target: "cartridge", "Applied patch, ROM size is now {} bytes", rom.len());
+                patched_rom = rom;
+                &patched_rom[..]
+            }
+            Some(Err(e)) => {
+                error!(target: "cartridge", "Failed to apply patch, booting the unpatched ROM: {}", e);
+                rom_data
+            }
+            None => rom_data,
+        };
+
         let game_title = String::from_utf8_lossy(&rom_data[0xa0..0xa0 + 12]).to_string(); // use as backup
+        let game_code = String::from_utf8_lossy(&rom_data[0xac..0xac + 4]).to_string();
 
         let crc32 = crc32fast::hash(rom_data);
         let crc32 = format!("{:08x}", crc32);
 
-        let (save_type, has_rtc, rom_title) = TITLE_DATABASE
+        // the exact dump is the most reliable, then the game code, then whatever the ROM itself says
+        let game_info = GameInfo::lookup(&game_code);
+        let (detected_save_type, has_rtc, rom_title, verified) = TITLE_DATABASE
             .get(&crc32)
-            .map(|&(backup_type, has_rtc, game_title)| (backup_type.into(), has_rtc, game_title.to_string()))
+            .map(|&(backup_type, has_rtc, game_title, verified)| {
+                (backup_type.into(), has_rtc, game_title.to_string(), verified)
+            })
+            .or_else(|| {
+                warn!(target: "cartridge",
+                    "CRC32 '{}' not found in database, looking up game code '{}'",
+                    crc32, game_code
+                );
+                game_info
+                    .as_ref()
+                    .map(|info| (info.backup_type, info.has_rtc, info.title.to_string(), false))
+            })
             .unwrap_or_else(|| {
+                let save_type = BackupType::scan(rom_data);
                 error!(target: "cartridge",
-                    "CRC32 '{}' not found in database, using default save type and title.",
-                    crc32
+                    "Game code '{}' not found in database, detected save type {} from the ROM",
+                    game_code,
+                    save_type.map_or("none".to_string(), |t| t.to_string())
                 );
-                (BackupType::Sram, false, game_title.clone())
+                (save_type.unwrap_or(BackupType::Sram), false, game_title.clone(), false)
             });
+        let (save_type, verified) = match options.save_type {
+            Some(save_type) => {
+                info!(target: "cartridge", "Overriding save type with {}", save_type);
+                (save_type, true)
+            }
+            None => (detected_save_type, verified),
+        };
+        let quirks = game_info.as_ref().map_or(Quirks::empty(), |info| info.quirks);
         info!(target: "cartridge", "Save Type: {}", save_type);
         info!(target: "cartridge", "Game Title: {}", rom_title);
 
         let mut mmio = Mmio::new(save_type, has_rtc);
-        mmio.load(0x00000000, include_bytes!("../../external/gba_bios.bin"));
+        mmio.eeprom_size_detection = !verified;
+        if let Some(vendor) = game_info.as_ref().and_then(|info| info.flash_vendor) {
+            mmio.storage_chip.select_flash_vendor(vendor);
+        }
+        if quirks.contains(Quirks::SOLAR_SENSOR) {
+            info!(target: "cartridge", "Solar sensor attached");
+            mmio.gpio.solar_sensor = Some(SolarSensor::new());
+        }
+        if quirks.contains(Quirks::GYRO) {
+            info!(target: "cartridge", "Gyro sensor attached");
+            mmio.gpio.gyro = Some(Gyro::new());
+        }
+        if quirks.contains(Quirks::RUMBLE) {
+            info!(target: "cartridge", "Rumble motor attached");
+            mmio.gpio.rumble = Some(Rumble::new());
+        }
+        if quirks.contains(Quirks::TILT) {
+            info!(target: "cartridge", "Tilt sensor attached");
+            mmio.tilt_sensor = Some(TiltSensor::new());
+        }
+        let bios: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/gba_bios.bin"));
+        if bios.is_empty() {
+            let stub: Vec<u8> = HLE_BIOS.iter().flat_map(|opcode| opcode.to_le_bytes()).collect();
+            mmio.load(0x00000000, &stub);
+        } else {
+            if options.bios.is_none() {
+                Self::verify_bios(bios);
+            }
+            mmio.load(0x00000000, bios);
+        }
+
+        // a BIOS passed in replaces the built-in one further down
+        let hle_bios = bios.is_empty() && options.bios.as_ref().is_none_or(|bios| bios.len() != BIOS_SIZE);
+        if hle_bios {
+            info!(target: "cpu", "No BIOS image, emulating the BIOS functions");
+        }
 
-        // Load ROM into memory
-        mmio.load(0x08000000, &rom_data);
+        if options.multiboot {
+            info!(target: "cartridge", "Booting multiboot image from EWRAM");
 
-        let cpu = Cpu::new(&elf_data, mmio);
+            let size = rom_data.len().min(MULTIBOOT_MAX_SIZE);
+            if size < rom_data.len() {
+                warn!(target: "cartridge", "Multiboot image is {} bytes, truncating to {}", rom_data.len(), size);
+            }
 
-        Gba {
+            // the BIOS fills in how the image was received, report a multiplay transfer as the first slave
+            mmio.load(0x02000000, &rom_data[..size]);
+            mmio.load(0x020000C4, &[0x03, 0x01]);
+            mmio.load(0x03007FFA, &[0x01]);
+            mmio.load(0x08000000, &[]);
+        } else {
+            // Load ROM into memory
+            mmio.load(0x08000000, &rom_data);
+        }
+
+        // without a cartridge the BIOS would sit in the multiboot receive loop, so start the image directly,
+        // the same goes for the boot sequence when there is no BIOS to run
+        let mut cpu = Cpu::new(&elf_data, mmio);
+        cpu.hle_bios = hle_bios;
+        let skip_bios = options.skip_bios || options.multiboot || hle_bios;
+        if skip_bios {
+            cpu.skip_bios();
+        }
+
+        let gba = Gba {
             cpu,
             script_engine: None,
             rom_title,
             crc32,
+            mirror_aware_breakpoints: true,
+        };
+
+        let mut gba = match &options.bios {
+            Some(bios) => gba.with_bios(bios),
+            None => gba,
+        };
+        if options.fast_boot && !skip_bios {
+            gba.run_boot_sequence();
+        }
+        gba
+    }
+
+    // runs the BIOS until it jumps to the cartridge, without anyone watching the logo
+    pub fn run_boot_sequence(&mut self) {
+        for _ in 0..BOOT_SEQUENCE_MAX_TICKS {
+            // the jump flushes the pipeline, so this stops right before the first cartridge fetch
+            if self.cpu.pipeline.is_empty() && self.cpu.registers.r[15] >= 0x02000000 {
+                info!(target: "cpu", "BIOS boot sequence finished, entering {:08X}", self.cpu.registers.r[15]);
+                return;
+            }
+
+            let _ = self.cpu.tick();
+            self.cpu.mmio.tick_components();
+        }
+
+        warn!(target: "cpu", "BIOS boot sequence did not finish, continuing from {:08X}", self.cpu.get_pc());
+    }
+
+    // replaces the BIOS, has to happen before the first tick unless the BIOS is skipped
+    pub fn with_bios(mut self, bios: &[u8]) -> Self {
+        if bios.len() != BIOS_SIZE {
+            error!(target: "cpu",
+                "BIOS image is {} bytes instead of {}, ignoring it",
+                bios.len(),
+                BIOS_SIZE
+            );
+            return self;
+        }
+
+        Self::verify_bios(bios);
+        self.cpu.mmio.load(0x00000000, bios);
+        self.cpu.hle_bios = false;
+        self
+    }
+
+    // unknown dumps still boot, but SWI results and the boot sequence may differ from hardware
+    fn verify_bios(bios: &[u8]) {
+        let crc32 = crc32fast::hash(bios);
+        match KNOWN_BIOS.iter().find(|&&(known, _)| known == crc32) {
+            Some((_, name)) => info!(target: "cpu", "Using the official {} BIOS", name),
+            None => warn!(target: "cpu", "BIOS CRC32 {:08X} does not match an official dump", crc32),
         }
     }
 
+    pub fn reset(&mut self) {
+        // same path as a game calling RegisterRamReset followed by SoftReset
+        let mut flags = RamResetFlags::all();
+        if self.cpu.mmio.read::<u8>(0x03007FFA, AccessKind::Debugger) != 0 {
+            flags.remove(RamResetFlags::EWRAM);
+        }
+
+        self.cpu.mmio.register_ram_reset(flags);
+        self.cpu.soft_reset();
+
+        info!(target: "cpu", "System reset");
+    }
+
+    pub fn save_state(&self) -> SaveState {
+        SaveState::capture(&self.cpu)
+    }
+
+    pub fn load_state(&mut self, state: &SaveState) {
+        state.restore(&mut self.cpu);
+    }
+
+    // pull model for frontends that drive audio themselves, buffer receives interleaved stereo samples at
+    // audio::apu::SAMPLE_RATE, returns the number of values written (two per sample)
+    pub fn read_audio_samples(&mut self, buffer: &mut [i16]) -> usize {
+        self.cpu.mmio.apu.read_samples(buffer)
+    }
+
     pub fn load_rhai_script(&mut self, path: String) {
         let path = Path::new(&path);
 
         let mut engine = ScriptEngine::new();
+        engine.mirror_aware = self.mirror_aware_breakpoints;
         engine.load_script(path);
 
         self.script_engine = Some(engine);
@@ -60,37 +317,130 @@ impl Gba {
         info!(target: "rhai", "Successfully loaded script: {}", path.display());
     }
 
+    // runs a script that plays the game with run_frames and checks the results with assertions
+    pub fn run_test_script(&mut self, path: &Path) -> TestReport {
+        let mut engine = ScriptEngine::new();
+        engine.mirror_aware = self.mirror_aware_breakpoints;
+        engine.run_test(path, &mut self.cpu)
+    }
+
+    // picks up changes to the script while the game keeps running, the keys the old script pressed are let go
+    pub fn reload_rhai_script(&mut self, path: String) {
+        let Some(engine) = &mut self.script_engine else {
+            self.load_rhai_script(path);
+            return;
+        };
+
+        let path = Path::new(&path);
+        if engine.reload_script(path) {
+            self.cpu.mmio.joypad.hold_key(KeyInput::all(), false);
+            self.cpu.mmio.joypad.replace_keys(None);
+            info!(target: "rhai", "Reloaded script: {}", path.display());
+        }
+    }
+
+    pub fn set_mirror_aware_breakpoints(&mut self, enabled: bool) {
+        self.mirror_aware_breakpoints = enabled;
+
+        if let Some(engine) = &mut self.script_engine {
+            engine.mirror_aware = enabled;
+        }
+    }
+
+    pub fn breakpoint_matches(&self, breakpoint: u32, address: u32) -> bool {
+        if self.mirror_aware_breakpoints {
+            Mmio::canonical_address(breakpoint) == Mmio::canonical_address(address)
+        } else {
+            breakpoint == address
+        }
+    }
+
     pub fn try_execute_breakpoint(&mut self, address: u32, pc: u32) {
         if let Some(engine) = &mut self.script_engine {
             engine.handle_breakpoint(address, pc, &mut self.cpu);
         }
     }
 
-    pub fn save_devices(&self, base_path: &Path) {
-        let storage_data = self.cpu.mmio.storage_chip.aggregate_storage();
-        let storage_path = base_path.join(&self.crc32);
-        std::fs::create_dir_all(&storage_path).expect("Failed to create save directory");
+    // runs the breakpoint and watchpoint hooks of the script, pc is the address of the instruction that just executed
+    pub fn try_execute_hooks(&mut self, pc: u32) {
+        if let Some(engine) = &mut self.script_engine {
+            engine.handle_hooks(pc, &mut self.cpu);
+        }
+    }
+
+    // runs the frame, VBlank and scanline callbacks of the script once their line started
+    pub fn try_execute_callbacks(&mut self) {
+        if let Some(engine) = &mut self.script_engine {
+            engine.handle_video(&mut self.cpu);
+        }
+    }
+
+    // true once after the script loaded one of its savestates
+    pub fn script_loaded_state(&self) -> bool {
+        self.script_engine
+            .as_ref()
+            .is_some_and(|engine| engine.take_state_loaded())
+    }
+
+    // puts what the script drew for this frame on top of it
+    pub fn draw_overlay(&self, frame: &mut Frame) {
+        if let Some(engine) = &self.script_engine {
+            engine.draw_overlay(frame);
+        }
+    }
 
-        let storage_path = storage_path.join("storage.bin");
+    // battery backed storage is kept in the raw .sav layout of mGBA and VBA so saves can be moved between them
+    pub fn save_devices(&self, path: &Path) {
+        let storage_data = self.cpu.mmio.storage_chip.export_sav();
 
-        if let Err(e) = std::fs::write(&storage_path, &storage_data) {
+        if let Err(e) = std::fs::write(path, &storage_data) {
             error!(target: "storage", "Failed to save data: {}", e);
         } else {
-            info!(target: "storage", "Data saved to {}", storage_path.display());
+            info!(target: "storage", "Data saved to {}", path.display());
         }
     }
 
-    pub fn load_devices(&mut self, base_path: &Path) {
-        let storage_path = base_path.join(&self.crc32);
-        std::fs::create_dir_all(&storage_path).expect("Failed to create save directory");
+    pub fn load_devices(&mut self, path: &Path) -> bool {
+        let Ok(data) = std::fs::read(path) else {
+            info!(target: "storage", "No save data found at {}", path.display());
+            return false;
+        };
 
-        let storage_path = storage_path.join("storage.bin");
+        // other emulators may append the RTC state, only keep the part that belongs to the chip,
+        // EEPROM saves of earlier builds were padded to 64 KiB as well
+        let chip = &mut self.cpu.mmio.storage_chip;
+        let sizes = match chip.backup_type() {
+            BackupType::Eeprom4k | BackupType::Eeprom64k => &[EEPROM_4K_SIZE, EEPROM_64K_SIZE][..],
+            _ => &SAV_SIZES[..],
+        };
+        let Some(&size) = sizes.iter().rev().find(|&&size| size <= data.len()) else {
+            error!(target: "storage", "Save data in {} is too small ({} bytes)", path.display(), data.len());
+            return false;
+        };
 
-        if let Ok(data) = std::fs::read(&storage_path) {
-            self.cpu.mmio.storage_chip.load_storage(&data);
-            info!(target: "storage", "Save data loaded from {}", storage_path.display());
-        } else {
-            error!(target: "storage", "Failed to read save data from {}", storage_path.display());
+        if let Err(e) = chip.import_sav(&data[..size]) {
+            error!(target: "storage", "Failed to load save data from {} into the {} chip: {}", path.display(), chip.backup_type(), e);
+            return false;
+        }
+
+        info!(target: "storage", "Save data loaded from {}", path.display());
+        true
+    }
+
+    // saves written by earlier versions to <base_path>/<crc32>/storage.bin, in the internal layout
+    pub fn load_legacy_devices(&mut self, base_path: &Path) -> bool {
+        let storage_path = base_path.join(&self.crc32).join("storage.bin");
+
+        let Ok(data) = std::fs::read(&storage_path) else {
+            return false;
+        };
+
+        if let Err(e) = self.cpu.mmio.storage_chip.load_storage(&data) {
+            error!(target: "storage", "Failed to load save data from {}: {}", storage_path.display(), e);
+            return false;
         }
+
+        info!(target: "storage", "Save data loaded from {}", storage_path.display());
+        true
     }
 }
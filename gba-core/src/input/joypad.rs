@@ -1,16 +1,25 @@
 use super::registers::{KeyControl, KeyInput};
 use crate::memory::device::Addressable;
 
+#[derive(Clone)]
 pub struct Joypad {
-    status: KeyInput,
+    status: KeyInput,           // the player's keys, set bits are released
+    held: KeyInput,             // pressed by a script until it lets go, on top of everything else
+    replaced: Option<KeyInput>, // pressed keys a script chose for this frame instead of the player's
     irq_control: KeyControl,
+    irq_condition: bool, // the keypad IRQ is raised when the KEYCNT condition becomes true
+    irq_pending: bool,
 }
 
 impl Joypad {
     pub fn new() -> Joypad {
         Joypad {
             status: KeyInput::all(),
+            held: KeyInput::empty(),
+            replaced: None,
             irq_control: KeyControl::empty(),
+            irq_condition: false,
+            irq_pending: false,
         }
     }
 
@@ -20,44 +29,76 @@ impl Joypad {
         } else {
             self.status.insert(key);
         }
+
+        self.update_irq();
     }
 
     pub fn is_key_pressed(&self, key: KeyInput) -> bool {
-        !self.status.contains(key)
+        self.pressed_keys().contains(key)
+    }
+
+    // what the game sees
+    pub fn pressed_keys(&self) -> KeyInput {
+        self.replaced.unwrap_or(self.player_keys()) | self.held
+    }
+
+    pub fn player_keys(&self) -> KeyInput {
+        self.status.complement()
+    }
+
+    pub fn hold_key(&mut self, key: KeyInput, held: bool) {
+        self.held.set(key, held);
+        self.update_irq();
+    }
+
+    pub fn replace_keys(&mut self, keys: Option<KeyInput>) {
+        self.replaced = keys;
+        self.update_irq();
+    }
+
+    fn update_irq(&mut self) {
+        let pressed = self.pressed_keys();
+        let condition = self.irq_control.contains(KeyControl::IRQ_ENABLE) && self.irq_control.is_met(pressed);
+
+        if condition && !self.irq_condition {
+            self.irq_pending = true;
+        }
+        self.irq_condition = condition;
+    }
+
+    // returns true once for every time the KEYCNT condition has been met
+    pub fn take_irq(&mut self) -> bool {
+        std::mem::take(&mut self.irq_pending)
     }
 }
 
 impl Addressable for Joypad {
-    fn read(&self, addr: u32) -> u8 {
+    fn read_byte(&self, addr: u32) -> u8 {
         match addr {
-            0x04000130 => self.status.bits() as u8,
-            0x04000131 => ((self.status.bits() & 0xff00) >> 8) as u8,
+            0x04000130 => self.pressed_keys().complement().bits() as u8,
+            0x04000131 => ((self.pressed_keys().complement().bits() & 0xff00) >> 8) as u8,
             0x04000132 => self.irq_control.bits() as u8,
             0x04000133 => ((self.irq_control.bits() & 0xff00) >> 8) as u8,
             _ => unreachable!(),
         }
     }
 
-    fn write(&mut self, addr: u32, value: u8) {
+    fn write_byte(&mut self, addr: u32, value: u8) {
         match addr {
             0x04000130 => {
-                self.status = KeyInput::from_bits_truncate(u16::from_le_bytes([value, self.status.bits() as u8]));
+                self.status = KeyInput::from_bits_truncate((self.status.bits() & 0xff00) | value as u16);
             }
             0x04000131 => {
-                self.status = KeyInput::from_bits_truncate(u16::from_le_bytes([
-                    ((self.status.bits() & 0xff00) >> 8) as u8,
-                    value,
-                ]));
+                self.status = KeyInput::from_bits_truncate((self.status.bits() & 0x00ff) | ((value as u16) << 8));
             }
             0x04000132 => {
-                self.irq_control =
-                    KeyControl::from_bits_truncate(u16::from_le_bytes([value, self.irq_control.bits() as u8]));
+                self.irq_control = KeyControl::from_bits_truncate((self.irq_control.bits() & 0xff00) | value as u16);
+                self.update_irq();
             }
             0x04000133 => {
-                self.irq_control = KeyControl::from_bits_truncate(u16::from_le_bytes([
-                    ((self.irq_control.bits() & 0xff00) >> 8) as u8,
-                    value,
-                ]));
+                self.irq_control =
+                    KeyControl::from_bits_truncate((self.irq_control.bits() & 0x00ff) | ((value as u16) << 8));
+                self.update_irq();
             }
             _ => unreachable!(),
         }
@@ -1,2 +1,3 @@
 pub mod joypad;
+pub mod movie;
 pub mod registers;
@@ -0,0 +1,99 @@
+use super::registers::KeyInput;
+use thiserror::Error;
+
+const VBM_HEADER_SIZE: usize = 0x40;
+const VBM_START_FROM_SNAPSHOT: u8 = 1 << 0;
+const VBM_START_FROM_SRAM: u8 = 1 << 1;
+const VBM_TYPE_GBA: u8 = 1 << 0;
+const VBM_OLD_RESET: u16 = 1 << 10;
+const VBM_RESET: u16 = 1 << 11;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum MovieError {
+    #[error("Unknown movie format")]
+    UnknownFormat,
+    #[error("Movie is truncated or malformed")]
+    Malformed,
+    #[error("Movie was recorded for a different system")]
+    NotGba,
+    #[error("Movies starting from a savestate or SRAM are not supported, only from power-on")]
+    UnsupportedStart,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MovieFrame {
+    pub keys: KeyInput, // held during the frame, set bits are pressed unlike in KEYINPUT
+    pub reset: bool,    // the system is reset before the frame runs
+}
+
+// the input of every frame from power-on, frames past the end leave the joypad alone
+pub struct Movie {
+    pub frames: Vec<MovieFrame>,
+    pub rerecords: u32,
+    pub game_code: String, // of the ROM the movie was recorded with
+    pub header_checksum: u8,
+}
+
+impl Movie {
+    // https://tasvideos.org/EmulatorResources/VBA/VBM, the motion sensor bits are ignored
+    pub fn from_vbm(data: &[u8]) -> Result<Movie, MovieError> {
+        if data.len() < VBM_HEADER_SIZE {
+            return Err(MovieError::Malformed);
+        }
+        if !data.starts_with(b"VBM\x1a") {
+            return Err(MovieError::UnknownFormat);
+        }
+
+        let u32_at = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        let frame_count = u32_at(0x0c) as usize;
+        let rerecords = u32_at(0x10);
+        let (start_flags, controller_flags, type_flags) = (data[0x14], data[0x15], data[0x16]);
+        if type_flags & VBM_TYPE_GBA == 0 {
+            return Err(MovieError::NotGba);
+        }
+        if start_flags & (VBM_START_FROM_SNAPSHOT | VBM_START_FROM_SRAM) != 0 {
+            return Err(MovieError::UnsupportedStart);
+        }
+
+        // every frame holds one word per controller, the GBA only ever uses the first one
+        let controllers = (controller_flags & 0x0f).count_ones() as usize;
+        if controllers == 0 {
+            return Err(MovieError::Malformed);
+        }
+        let input_offset = u32_at(0x3c) as usize;
+        let input = data.get(input_offset..).ok_or(MovieError::Malformed)?;
+        let frames = input
+            .chunks_exact(controllers * 2)
+            .take(frame_count)
+            .map(|frame| {
+                let value = u16::from_le_bytes([frame[0], frame[1]]);
+                MovieFrame {
+                    keys: KeyInput::from_bits_truncate(value),
+                    reset: value & (VBM_RESET | VBM_OLD_RESET) != 0,
+                }
+            })
+            .collect::<Vec<_>>();
+        if frames.len() < frame_count {
+            return Err(MovieError::Malformed);
+        }
+
+        Ok(Movie {
+            frames,
+            rerecords,
+            game_code: String::from_utf8_lossy(&data[0x34..0x38]).to_string(),
+            header_checksum: data[0x31],
+        })
+    }
+
+    pub fn frame(&self, index: usize) -> Option<MovieFrame> {
+        self.frames.get(index).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
@@ -1,7 +1,7 @@
 use bitflags::bitflags;
 
 bitflags! {
-    #[derive(Debug)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct KeyInput: u16 {
         const A         = 1 << 0;
         const B         = 1 << 1;
@@ -17,6 +17,7 @@ bitflags! {
 }
 
 bitflags! {
+    #[derive(Clone, Copy)]
     pub struct KeyControl: u16 {
         const A             = 1 << 0;
         const B             = 1 << 1;
@@ -32,3 +33,22 @@ bitflags! {
         const IRQ_CONDITION = 1 << 15;
     }
 }
+
+impl KeyControl {
+    pub fn keys(&self) -> KeyInput {
+        KeyInput::from_bits_truncate(self.bits())
+    }
+
+    // IRQ_CONDITION set = all selected keys (AND), cleared = any selected key (OR)
+    pub fn is_met(&self, pressed: KeyInput) -> bool {
+        let keys = self.keys();
+
+        if keys.is_empty() {
+            false
+        } else if self.contains(KeyControl::IRQ_CONDITION) {
+            pressed.contains(keys)
+        } else {
+            pressed.intersects(keys)
+        }
+    }
+}
@@ -1,4 +1,5 @@
 #![feature(new_zeroed_alloc)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 
 pub mod arm7tdmi;
 pub mod audio;
@@ -7,6 +8,7 @@ pub mod gba;
 pub mod input;
 pub mod memory;
 pub mod script;
+pub mod serial;
 pub mod video;
 
 #[cfg(test)]
@@ -1,48 +1,96 @@
+use crate::arm7tdmi::decoder::TransferLength;
 use bitflags::Flags;
+use thiserror::Error;
 use tracing::trace;
 
-#[allow(dead_code)]
-pub trait Addressable: Send {
-    fn read(&self, addr: u32) -> u8;
-    fn write(&mut self, addr: u32, value: u8);
+// who is driving the bus, the memory map uses this for wait states, open bus and tracing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessKind {
+    Fetch,    // CPU opcode fetch
+    Data,     // CPU load/store
+    Dma,      // DMA transfer
+    Debugger, // debugger, scripts and tests, free of side effects on timing and open bus
+}
 
-    fn load(&mut self, addr: u32, data: &[u8]) {
-        for (i, &byte) in data.iter().enumerate() {
-            self.write(addr + i as u32, byte);
-        }
+// a value that is transferred in a single bus access
+pub trait BusValue: Copy {
+    const LENGTH: TransferLength;
+    const BYTES: u32 = size_of::<Self>() as u32;
+
+    fn from_u32(value: u32) -> Self; // truncates
+    fn to_u32(self) -> u32;
+}
+
+impl BusValue for u8 {
+    const LENGTH: TransferLength = TransferLength::Byte;
+
+    fn from_u32(value: u32) -> Self {
+        value as u8
+    }
+
+    fn to_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+impl BusValue for u16 {
+    const LENGTH: TransferLength = TransferLength::HalfWord;
+
+    fn from_u32(value: u32) -> Self {
+        value as u16
+    }
+
+    fn to_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+impl BusValue for u32 {
+    const LENGTH: TransferLength = TransferLength::Word;
+
+    fn from_u32(value: u32) -> Self {
+        value
     }
 
-    fn read_u16(&self, addr: u32) -> u16 {
-        u16::from_le_bytes([self.read(addr), self.read(addr + 1)])
+    fn to_u32(self) -> u32 {
+        self
     }
+}
 
-    fn read_u32(&self, addr: u32) -> u32 {
-        u32::from_le_bytes([
-            self.read(addr),
-            self.read(addr + 1),
-            self.read(addr + 2),
-            self.read(addr + 3),
-        ])
+pub trait Addressable: Send {
+    fn read_byte(&self, addr: u32) -> u8;
+    fn write_byte(&mut self, addr: u32, value: u8);
+
+    fn load(&mut self, addr: u32, data: &[u8]) {
+        for (i, &byte) in data.iter().enumerate() {
+            self.write_byte(addr + i as u32, byte);
+        }
     }
 
-    fn write_u16(&mut self, addr: u32, value: u16) {
-        let [a, b] = value.to_le_bytes();
-        self.write(addr, a);
-        self.write(addr + 1, b);
+    // little endian, one byte at a time
+    fn read<T: BusValue>(&self, addr: u32) -> T
+    where
+        Self: Sized,
+    {
+        T::from_u32((0..T::BYTES).fold(0, |value, i| value | (self.read_byte(addr + i) as u32) << (i * 8)))
     }
 
-    fn write_u32(&mut self, addr: u32, value: u32) {
-        let [a, b, c, d] = value.to_le_bytes();
-        self.write(addr, a);
-        self.write(addr + 1, b);
-        self.write(addr + 2, c);
-        self.write(addr + 3, d);
+    fn write<T: BusValue>(&mut self, addr: u32, value: T)
+    where
+        Self: Sized,
+    {
+        for i in 0..T::BYTES {
+            self.write_byte(addr + i, (value.to_u32() >> (i * 8)) as u8);
+        }
     }
 }
 
 pub trait ParseableAsFlags: Addressable {
-    fn read_as<T: Flags<Bits = u16>>(&self, addr: u32) -> T {
-        T::from_bits_truncate(self.read_u16(addr))
+    fn read_as<T: Flags<Bits = u16>>(&self, addr: u32) -> T
+    where
+        Self: Sized,
+    {
+        T::from_bits_truncate(self.read::<u16>(addr))
     }
 }
 
@@ -127,7 +175,7 @@ impl<T> Addressable for IoRegister<T>
 where
     T: Flags<Bits = u16> + Copy + Send,
 {
-    fn read(&self, addr: u32) -> u8 {
+    fn read_byte(&self, addr: u32) -> u8 {
         if addr % 2 == 0 {
             self.0.bits() as u8
         } else {
@@ -135,7 +183,7 @@ where
         }
     }
 
-    fn write(&mut self, addr: u32, value: u8) {
+    fn write_byte(&mut self, addr: u32, value: u8) {
         // Interrupts must be manually acknowledged by writing a "1" to one of the IRQ bits, the IRQ bit will then be cleared.
         match addr {
             0x4000202 => {
@@ -167,7 +215,22 @@ impl<T: Default> Default for IoRegister<T> {
     }
 }
 
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum StorageError {
+    #[error("Save data has {actual} bytes, the chip holds {expected}")]
+    InvalidLength { expected: usize, actual: usize },
+}
+
 pub trait Saveable {
     fn aggregate_storage(&self) -> Vec<u8>;
-    fn load_storage(&mut self, data: &[u8]);
+    fn load_storage(&mut self, data: &[u8]) -> Result<(), StorageError>;
+
+    // raw battery file as written by mGBA and VBA, only differs from the internal layout for EEPROM
+    fn export_sav(&self) -> Vec<u8> {
+        self.aggregate_storage()
+    }
+
+    fn import_sav(&mut self, data: &[u8]) -> Result<(), StorageError> {
+        self.load_storage(data)
+    }
 }
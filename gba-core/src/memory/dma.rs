@@ -1,5 +1,5 @@
 use super::device::Addressable;
-use super::registers::{AddrControl, DmaControl, DmaTrigger, MappedRegister16, MappedRegister32};
+use super::registers::{AddrControl, DmaControl, DmaTrigger, Interrupt, MappedRegister16, MappedRegister32};
 use std::fmt::Display;
 
 #[derive(Default, PartialEq, Clone, Copy)]
@@ -9,6 +9,10 @@ pub struct TransferChannel {
     pub cnt: MappedRegister16,
     pub ctl: MappedRegister16,
     id: usize,
+    // internal registers, latched from src/dst when the channel gets enabled
+    pub internal_src: u32,
+    pub internal_dst: u32,
+    pub pending: bool, // start condition met, the transfer runs on the next DMA step
 }
 
 impl TransferChannel {
@@ -19,6 +23,9 @@ impl TransferChannel {
             cnt: MappedRegister16::default(),
             ctl: MappedRegister16::default(),
             id,
+            internal_src: 0,
+            internal_dst: 0,
+            pending: false,
         }
     }
 
@@ -26,13 +33,12 @@ impl TransferChannel {
         self.ctl.value_as::<DmaControl>().is_enabled()
     }
 
-    pub fn transfer_units(&self) -> u16 {
-        let max_size = if self.id == 3 { 0xFFFF } else { 0x3FFF };
-        let size = self.cnt.value() & max_size;
-        if size == 0 {
-            max_size
-        } else {
-            size
+    pub fn transfer_units(&self) -> u32 {
+        // a count of zero transfers the maximum amount
+        let max_units = if self.id == 3 { 0x10000 } else { 0x4000 };
+        match self.cnt.value() as u32 & (max_units - 1) {
+            0 => max_units,
+            units => units,
         }
     }
 
@@ -62,6 +68,42 @@ impl TransferChannel {
 
     pub fn disable(&mut self) {
         self.ctl.value_as_mut::<DmaControl>().disable();
+        self.pending = false;
+    }
+
+    // DMA1 and DMA2 in special mode feed the sound FIFOs
+    pub fn is_sound(&self) -> bool {
+        (self.id == 1 || self.id == 2) && self.trigger() == DmaTrigger::Special
+    }
+
+    pub fn irq_on_complete(&self) -> bool {
+        self.ctl
+            .value_as::<DmaControl>()
+            .contains(DmaControl::IRQ_UPON_COMPLETE)
+    }
+
+    pub fn interrupt(&self) -> Interrupt {
+        Interrupt::from_bits_truncate(Interrupt::DMA0.bits() << self.id)
+    }
+
+    // DMA0 can only read from internal memory
+    fn src_mask(&self) -> u32 {
+        if self.id == 0 { 0x07FFFFFF } else { 0x0FFFFFFF }
+    }
+
+    // only DMA3 can write to the gamepak
+    fn dst_mask(&self) -> u32 {
+        if self.id == 3 { 0x0FFFFFFF } else { 0x07FFFFFF }
+    }
+
+    fn latch(&mut self) {
+        self.internal_src = self.src.value() & self.src_mask();
+        self.internal_dst = self.dst.value() & self.dst_mask();
+        self.pending = self.trigger() == DmaTrigger::Immediate;
+    }
+
+    pub fn reload_dst(&mut self) {
+        self.internal_dst = self.dst.value() & self.dst_mask();
     }
 }
 
@@ -81,49 +123,82 @@ impl Dma {
             ],
         }
     }
+
+    // marks all enabled channels with the given start timing as pending
+    pub fn request(&mut self, trigger: DmaTrigger) {
+        for channel in self.channels.iter_mut() {
+            if channel.is_enabled() && channel.trigger() == trigger {
+                channel.pending = true;
+            }
+        }
+    }
+
+    // a FIFO has been drained to half of its capacity, wake up the sound channel writing to it
+    pub fn request_fifo(&mut self, fifo: u32) {
+        for channel in self.channels.iter_mut() {
+            if channel.is_enabled() && channel.is_sound() && channel.internal_dst == fifo {
+                channel.pending = true;
+            }
+        }
+    }
+
+    // DMA3 in special mode captures video, one transfer per HBlank from line 2 until it stops at line 162
+    pub fn request_video_capture(&mut self, line: u16) {
+        let channel = &mut self.channels[3];
+        if !channel.is_enabled() || channel.trigger() != DmaTrigger::Special {
+            return;
+        }
+
+        match line {
+            2..=161 => channel.pending = true,
+            162 => channel.disable(),
+            _ => {}
+        }
+    }
+
+    fn write_control(&mut self, channel: usize, addr: u32, value: u8) {
+        let channel = &mut self.channels[channel];
+        let was_enabled = channel.is_enabled();
+        channel.ctl.write(addr, value);
+
+        // the internal registers are only reloaded when the enable bit changes from 0 to 1
+        if !was_enabled && channel.is_enabled() {
+            channel.latch();
+        }
+    }
 }
 
 impl Addressable for Dma {
-    fn read(&self, addr: u32) -> u8 {
+    fn read_byte(&self, addr: u32) -> u8 {
         match addr {
-            0x040000B0..=0x040000B3 => self.channels[0].src.read(addr - 0x040000B0),
-            0x040000B4..=0x040000B7 => self.channels[0].dst.read(addr - 0x040000B4),
-            0x040000B8..=0x040000B9 => self.channels[0].cnt.read(addr - 0x040000B8),
             0x040000BA..=0x040000BB => self.channels[0].ctl.read(addr - 0x040000BA),
-            0x040000BC..=0x040000BF => self.channels[1].src.read(addr - 0x040000BC),
-            0x040000C0..=0x040000C3 => self.channels[1].dst.read(addr - 0x040000C0),
-            0x040000C4..=0x040000C5 => self.channels[1].cnt.read(addr - 0x040000C4),
             0x040000C6..=0x040000C7 => self.channels[1].ctl.read(addr - 0x040000C6),
-            0x040000C8..=0x040000CB => self.channels[2].src.read(addr - 0x040000C8),
-            0x040000CC..=0x040000CF => self.channels[2].dst.read(addr - 0x040000CC),
-            0x040000D0..=0x040000D1 => self.channels[2].cnt.read(addr - 0x040000D0),
             0x040000D2..=0x040000D3 => self.channels[2].ctl.read(addr - 0x040000D2),
-            0x040000D4..=0x040000D7 => self.channels[3].src.read(addr - 0x040000D4),
-            0x040000D8..=0x040000DB => self.channels[3].dst.read(addr - 0x040000D8),
-            0x040000DC..=0x040000DD => self.channels[3].cnt.read(addr - 0x040000DC),
             0x040000DE..=0x040000DF => self.channels[3].ctl.read(addr - 0x040000DE),
+            // source, destination and count are write-only
+            0x040000B0..=0x040000DF => 0x00,
             _ => panic!("Invalid DMA address: {:08X}", addr),
         }
     }
 
-    fn write(&mut self, addr: u32, value: u8) {
+    fn write_byte(&mut self, addr: u32, value: u8) {
         match addr {
             0x040000B0..=0x040000B3 => self.channels[0].src.write(addr - 0x040000B0, value),
             0x040000B4..=0x040000B7 => self.channels[0].dst.write(addr - 0x040000B4, value),
             0x040000B8..=0x040000B9 => self.channels[0].cnt.write(addr - 0x040000B8, value),
-            0x040000BA..=0x040000BB => self.channels[0].ctl.write(addr - 0x040000BA, value),
+            0x040000BA..=0x040000BB => self.write_control(0, addr - 0x040000BA, value),
             0x040000BC..=0x040000BF => self.channels[1].src.write(addr - 0x040000BC, value),
             0x040000C0..=0x040000C3 => self.channels[1].dst.write(addr - 0x040000C0, value),
             0x040000C4..=0x040000C5 => self.channels[1].cnt.write(addr - 0x040000C4, value),
-            0x040000C6..=0x040000C7 => self.channels[1].ctl.write(addr - 0x040000C6, value),
+            0x040000C6..=0x040000C7 => self.write_control(1, addr - 0x040000C6, value),
             0x040000C8..=0x040000CB => self.channels[2].src.write(addr - 0x040000C8, value),
             0x040000CC..=0x040000CF => self.channels[2].dst.write(addr - 0x040000CC, value),
             0x040000D0..=0x040000D1 => self.channels[2].cnt.write(addr - 0x040000D0, value),
-            0x040000D2..=0x040000D3 => self.channels[2].ctl.write(addr - 0x040000D2, value),
+            0x040000D2..=0x040000D3 => self.write_control(2, addr - 0x040000D2, value),
             0x040000D4..=0x040000D7 => self.channels[3].src.write(addr - 0x040000D4, value),
             0x040000D8..=0x040000DB => self.channels[3].dst.write(addr - 0x040000D8, value),
             0x040000DC..=0x040000DD => self.channels[3].cnt.write(addr - 0x040000DC, value),
-            0x040000DE..=0x040000DF => self.channels[3].ctl.write(addr - 0x040000DE, value),
+            0x040000DE..=0x040000DF => self.write_control(3, addr - 0x040000DE, value),
             _ => panic!("Invalid DMA address: {:08X}", addr),
         }
     }
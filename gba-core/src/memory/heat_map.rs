@@ -0,0 +1,74 @@
+use super::device::AccessKind;
+use crate::arm7tdmi::trace::AccessDirection;
+
+pub const BUCKET_SHIFT: u32 = 10; // 1 KiB buckets
+const BUCKET_COUNT: usize = 0x10000000 >> BUCKET_SHIFT; // 28-bit address bus
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AccessCounts {
+    pub reads: u32,
+    pub writes: u32,
+    pub executes: u32, // opcode fetches
+}
+
+impl AccessCounts {
+    pub fn total(&self) -> u32 {
+        self.reads.saturating_add(self.writes).saturating_add(self.executes)
+    }
+}
+
+// per-bucket access counters, mirrors are counted towards the address they alias
+#[derive(Clone)]
+pub struct HeatMap {
+    buckets: Box<[AccessCounts]>,
+}
+
+impl HeatMap {
+    pub fn new() -> HeatMap {
+        HeatMap {
+            buckets: vec![AccessCounts::default(); BUCKET_COUNT].into_boxed_slice(),
+        }
+    }
+
+    pub fn record(&mut self, addr: u32, access: AccessKind, direction: AccessDirection) {
+        let Some(bucket) = self.buckets.get_mut((addr >> BUCKET_SHIFT) as usize) else {
+            return;
+        };
+
+        let counter = match (direction, access) {
+            (_, AccessKind::Debugger) => return,
+            (AccessDirection::Write, _) => &mut bucket.writes,
+            (AccessDirection::Read, AccessKind::Fetch) => &mut bucket.executes,
+            (AccessDirection::Read, _) => &mut bucket.reads,
+        };
+        *counter = counter.saturating_add(1);
+    }
+
+    pub fn bucket(&self, addr: u32) -> AccessCounts {
+        self.buckets
+            .get((addr >> BUCKET_SHIFT) as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    // (bucket start address, counts) of every bucket in the range that has been accessed
+    pub fn touched(&self, start: u32, end: u32) -> Vec<(u32, AccessCounts)> {
+        let first = (start >> BUCKET_SHIFT) as usize;
+        let last = ((end >> BUCKET_SHIFT) as usize).min(BUCKET_COUNT - 1);
+
+        (first..=last)
+            .map(|i| ((i as u32) << BUCKET_SHIFT, self.buckets[i]))
+            .filter(|(_, counts)| counts.total() != 0)
+            .collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.buckets.fill(AccessCounts::default());
+    }
+}
+
+impl Default for HeatMap {
+    fn default() -> Self {
+        HeatMap::new()
+    }
+}
@@ -0,0 +1,178 @@
+use super::device::AccessKind;
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+#[derive(Clone)]
+pub struct IoEvent {
+    pub cycle: u64,             // Mmio::cycles at the time of the write
+    pub addr: u32,              // address of the access, not necessarily the start of the register
+    pub register: &'static str, // name of the register at addr
+    pub width: u32,             // in bytes
+    pub old: u32,               // value read back before the write, write-only registers read as 0
+    pub new: u32,               // value written
+    pub pc: u32,                // instruction that caused the write, for DMA the last executed one
+    pub source: AccessKind,
+}
+
+// every write to the I/O registers, bounded to the most recent `capacity` events
+#[derive(Clone)]
+pub struct IoJournal {
+    events: VecDeque<IoEvent>,
+    capacity: usize,
+}
+
+impl IoJournal {
+    pub fn new(capacity: usize) -> IoJournal {
+        IoJournal {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn push(&mut self, event: IoEvent) {
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+
+        self.events.push_back(event);
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = &IoEvent> {
+        self.events.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    // array of objects, oldest event first
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[");
+
+        for (i, event) in self.events.iter().enumerate() {
+            if i != 0 {
+                json.push(',');
+            }
+
+            let source = match event.source {
+                AccessKind::Fetch => "fetch",
+                AccessKind::Data => "cpu",
+                AccessKind::Dma => "dma",
+                AccessKind::Debugger => "debugger",
+            };
+
+            let _ = write!(
+                json,
+                "\n  {{\"cycle\":{},\"address\":{},\"register\":\"{}\",\"width\":{},\"old\":{},\"new\":{},\"pc\":{},\"source\":\"{}\"}}",
+                event.cycle, event.addr, event.register, event.width, event.old, event.new, event.pc, source
+            );
+        }
+
+        json.push_str("\n]\n");
+        json
+    }
+}
+
+// https://problemkaputt.de/gbatek.htm#gbaiomap
+pub fn register_name(addr: u32) -> &'static str {
+    match addr & !1 {
+        0x04000000 => "DISPCNT",
+        0x04000002 => "GREENSWAP",
+        0x04000004 => "DISPSTAT",
+        0x04000006 => "VCOUNT",
+        0x04000008 => "BG0CNT",
+        0x0400000A => "BG1CNT",
+        0x0400000C => "BG2CNT",
+        0x0400000E => "BG3CNT",
+        0x04000010 => "BG0HOFS",
+        0x04000012 => "BG0VOFS",
+        0x04000014 => "BG1HOFS",
+        0x04000016 => "BG1VOFS",
+        0x04000018 => "BG2HOFS",
+        0x0400001A => "BG2VOFS",
+        0x0400001C => "BG3HOFS",
+        0x0400001E => "BG3VOFS",
+        0x04000020 => "BG2PA",
+        0x04000022 => "BG2PB",
+        0x04000024 => "BG2PC",
+        0x04000026 => "BG2PD",
+        0x04000028..=0x0400002A => "BG2X",
+        0x0400002C..=0x0400002E => "BG2Y",
+        0x04000030 => "BG3PA",
+        0x04000032 => "BG3PB",
+        0x04000034 => "BG3PC",
+        0x04000036 => "BG3PD",
+        0x04000038..=0x0400003A => "BG3X",
+        0x0400003C..=0x0400003E => "BG3Y",
+        0x04000040 => "WIN0H",
+        0x04000042 => "WIN1H",
+        0x04000044 => "WIN0V",
+        0x04000046 => "WIN1V",
+        0x04000048 => "WININ",
+        0x0400004A => "WINOUT",
+        0x0400004C => "MOSAIC",
+        0x04000050 => "BLDCNT",
+        0x04000052 => "BLDALPHA",
+        0x04000054 => "BLDY",
+        0x04000060 => "SOUND1CNT_L",
+        0x04000062 => "SOUND1CNT_H",
+        0x04000064 => "SOUND1CNT_X",
+        0x04000068 => "SOUND2CNT_L",
+        0x0400006C => "SOUND2CNT_H",
+        0x04000070 => "SOUND3CNT_L",
+        0x04000072 => "SOUND3CNT_H",
+        0x04000074 => "SOUND3CNT_X",
+        0x04000078 => "SOUND4CNT_L",
+        0x0400007C => "SOUND4CNT_H",
+        0x04000080 => "SOUNDCNT_L",
+        0x04000082 => "SOUNDCNT_H",
+        0x04000084 => "SOUNDCNT_X",
+        0x04000088 => "SOUNDBIAS",
+        0x04000090..=0x0400009E => "WAVE_RAM",
+        0x040000A0..=0x040000A2 => "FIFO_A",
+        0x040000A4..=0x040000A6 => "FIFO_B",
+        0x040000B0..=0x040000B2 => "DMA0SAD",
+        0x040000B4..=0x040000B6 => "DMA0DAD",
+        0x040000B8 => "DMA0CNT_L",
+        0x040000BA => "DMA0CNT_H",
+        0x040000BC..=0x040000BE => "DMA1SAD",
+        0x040000C0..=0x040000C2 => "DMA1DAD",
+        0x040000C4 => "DMA1CNT_L",
+        0x040000C6 => "DMA1CNT_H",
+        0x040000C8..=0x040000CA => "DMA2SAD",
+        0x040000CC..=0x040000CE => "DMA2DAD",
+        0x040000D0 => "DMA2CNT_L",
+        0x040000D2 => "DMA2CNT_H",
+        0x040000D4..=0x040000D6 => "DMA3SAD",
+        0x040000D8..=0x040000DA => "DMA3DAD",
+        0x040000DC => "DMA3CNT_L",
+        0x040000DE => "DMA3CNT_H",
+        0x04000100 => "TM0CNT_L",
+        0x04000102 => "TM0CNT_H",
+        0x04000104 => "TM1CNT_L",
+        0x04000106 => "TM1CNT_H",
+        0x04000108 => "TM2CNT_L",
+        0x0400010A => "TM2CNT_H",
+        0x0400010C => "TM3CNT_L",
+        0x0400010E => "TM3CNT_H",
+        0x04000120..=0x0400012A => "SIO",
+        0x04000130 => "KEYINPUT",
+        0x04000132 => "KEYCNT",
+        0x04000134 => "RCNT",
+        0x04000140..=0x0400015A => "JOY",
+        0x04000200 => "IE",
+        0x04000202 => "IF",
+        0x04000204 => "WAITCNT",
+        0x04000208 => "IME",
+        0x04000300 => "POSTFLG/HALTCNT",
+        _ => "unknown",
+    }
+}
@@ -1,19 +1,31 @@
 use core::panic;
 
-use super::device::{Addressable, IoRegister};
+use super::device::{AccessKind, Addressable, BusValue, IoRegister};
 use super::dma::Dma;
+use super::heat_map::HeatMap;
+use super::io_journal::{IoEvent, IoJournal, register_name};
+use super::pages::{Page, PageTable};
+use super::sparse::SparseMemory;
 use crate::arm7tdmi::decoder::TransferLength;
 use crate::arm7tdmi::timer::Timers;
-use crate::audio::apu::Apu;
+use crate::arm7tdmi::trace::{AccessDirection, MemoryAccess};
+use crate::audio::apu::{Apu, FIFO_A, FIFO_B, cycle_ticks};
 use crate::cartridge::StorageChip;
 use crate::cartridge::eeprom::Eeprom;
 use crate::cartridge::flash::Flash;
+use crate::cartridge::gpio::Gpio;
 use crate::cartridge::sram::Sram;
 use crate::cartridge::storage::BackupType;
+use crate::cartridge::tilt::TiltSensor;
 use crate::input::joypad::Joypad;
-use crate::memory::registers::{AddrControl, DmaTrigger, Interrupt};
+use crate::memory::registers::{AddrControl, DmaTrigger, Interrupt, RamResetFlags, WaitCnt};
+use crate::serial::registers::SioMode;
+use crate::serial::sio::Sio;
 use crate::video::ppu::{Ppu, PpuEvent};
-use crate::video::registers::DispStat;
+use crate::video::registers::{BgAffineParam, DispCnt, DispStat};
+use crate::video::timeline::{PpuTimeline, TimelineEvent};
+use std::ops::Range;
+use std::sync::Arc;
 use tracing::*;
 
 const EWRAM_SIZE: u32 = 0x40000; // 256 KiB
@@ -22,32 +34,141 @@ const PALETTE_SIZE: u32 = 0x400; // 1 KiB
 const VRAM_PHYS_SIZE: u32 = 0x18000;
 const VRAM_WINDOW_SIZE: u32 = 0x20000; // 128 KiB
 const OAM_SIZE: u32 = 0x400; // 1 KiB
+const MEMCNT_DEFAULT: u32 = 0x0D000020; // 256 KiB WRAM enabled with 2 wait states
+const MEMCNT_MASK: u32 = 0x0F00002F;
+const MAX_TICKS_PER_CALL: u64 = 240; // one scanline, so frontends that look at every line never miss one
+// BIOS, EWRAM, IWRAM and the I/O registers that are only stored
+const INTERNAL_REGIONS: [Range<usize>; 4] = [
+    0x00000000..0x00004000,
+    0x02000000..0x02000000 + EWRAM_SIZE as usize,
+    0x03000000..0x03000000 + IWRAM_SIZE as usize,
+    0x04000000..0x04000400,
+];
 
 pub struct Mmio {
-    pub internal_memory: Box<[u8; 0x04FFFFFF + 1]>,
-    pub external_memory: Box<[u8; (0x0DFFFFFF - 0x08000000) + 1]>,
+    pub internal_memory: SparseMemory<{ 0x04FFFFFF + 1 }>,
+    pub external_memory: Arc<Box<[u8; (0x0DFFFFFF - 0x08000000) + 1]>>, // only written while loading, shared by savestates
     pub ppu: Ppu,
     pub joypad: Joypad,
     pub apu: Apu,
     pub dma: Dma,
     pub timers: Timers,
+    pub sio: Sio,
     pub storage_chip: Box<dyn StorageChip>, // Storage chip, e.g. SRAM, EEPROM, Flash
+    pub gpio: Gpio,                         // Cartridge GPIO port, e.g. RTC
+    pub tilt_sensor: Option<TiltSensor>,    // Tilt sensor on the SRAM bus
     // I/O registers
-    pub io_ime: IoRegister,           // IME
-    pub io_ie: IoRegister<Interrupt>, // IE
-    pub io_if: IoRegister<Interrupt>, // IF
-    pub io_halt_cnt: IoRegister<u8>,  // HALTCNT
-    pub io_postflg: IoRegister<u8>,   // POSTFLG
+    pub io_ime: IoRegister,              // IME
+    pub io_ie: IoRegister<Interrupt>,    // IE
+    pub io_if: IoRegister<Interrupt>,    // IF
+    pub io_halt_cnt: IoRegister<u8>,     // HALTCNT
+    pub io_postflg: IoRegister<u8>,      // POSTFLG
+    pub io_waitcnt: IoRegister<WaitCnt>, // WAITCNT
+    pub io_memcnt: u32,                  // undocumented internal memory control, only stored
     // other
     pub last_rw_addr: Vec<u32>,                      // track the last read/write addresses
     pub origin_write_length: Option<TransferLength>, // cache this for cases like 8bit VRAM mirrored writes
     pub executing_bios: bool,
     pub openbus_bios: u32,
+    pub cycles: u64,                 // total bus cycles spent on memory accesses
+    ticked_cycles: u64,              // the part of them the components have been ticked for
+    pending_ticks: u64,              // component ticks owed for them, paid off over the next calls
+    tick_remainder: u64,             // fraction of a tick, carried over
+    pub eeprom_size_detection: bool, // detect the EEPROM size from the first DMA3 request
+    pages: PageTable,
+    pub access_log: Option<Vec<MemoryAccess>>, // CPU accesses, only recorded while tracing a step
+    pub io_journal: Option<IoJournal>,         // I/O register writes, only recorded when enabled
+    pub pc: u32,                               // address of the instruction being executed, set by the CPU
+    pub heat_map: Option<HeatMap>,             // access counters per 1 KiB, only recorded when enabled
+    pub ppu_timeline: Option<PpuTimeline>,     // raster events of the last frame, only recorded when enabled
+    next_sequential_addr: u32,
+}
+
+impl Clone for Mmio {
+    fn clone(&self) -> Mmio {
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+        mmio.clone_from(self);
+        mmio
+    }
+
+    // reuses the memory buffer, the rewind history captures its checkpoints into the ones it evicted
+    fn clone_from(&mut self, source: &Mmio) {
+        let Mmio {
+            internal_memory,
+            external_memory,
+            ppu,
+            joypad,
+            apu,
+            dma,
+            timers,
+            sio,
+            storage_chip,
+            gpio,
+            tilt_sensor,
+            io_ime,
+            io_ie,
+            io_if,
+            io_halt_cnt,
+            io_postflg,
+            io_waitcnt,
+            io_memcnt,
+            last_rw_addr,
+            origin_write_length,
+            executing_bios,
+            openbus_bios,
+            cycles,
+            ticked_cycles,
+            pending_ticks,
+            tick_remainder,
+            eeprom_size_detection,
+            pages,
+            access_log,
+            io_journal,
+            pc,
+            heat_map,
+            ppu_timeline,
+            next_sequential_addr,
+        } = source;
+
+        self.internal_memory.clone_from(internal_memory);
+        self.external_memory.clone_from(external_memory);
+        self.ppu.clone_from(ppu);
+        self.joypad.clone_from(joypad);
+        self.apu.clone_from(apu);
+        self.dma.clone_from(dma);
+        self.timers.clone_from(timers);
+        self.sio.clone_from(sio);
+        self.storage_chip.clone_from(storage_chip);
+        self.gpio.clone_from(gpio);
+        self.tilt_sensor.clone_from(tilt_sensor);
+        self.io_ime.clone_from(io_ime);
+        self.io_ie.clone_from(io_ie);
+        self.io_if.clone_from(io_if);
+        self.io_halt_cnt.clone_from(io_halt_cnt);
+        self.io_postflg.clone_from(io_postflg);
+        self.io_waitcnt.clone_from(io_waitcnt);
+        self.io_memcnt = *io_memcnt;
+        self.last_rw_addr.clone_from(last_rw_addr);
+        self.origin_write_length.clone_from(origin_write_length);
+        self.executing_bios = *executing_bios;
+        self.openbus_bios = *openbus_bios;
+        self.cycles = *cycles;
+        self.ticked_cycles = *ticked_cycles;
+        self.pending_ticks = *pending_ticks;
+        self.tick_remainder = *tick_remainder;
+        self.eeprom_size_detection = *eeprom_size_detection;
+        self.pages.clone_from(pages);
+        self.access_log.clone_from(access_log);
+        self.io_journal.clone_from(io_journal);
+        self.pc = *pc;
+        self.heat_map.clone_from(heat_map);
+        self.ppu_timeline.clone_from(ppu_timeline);
+        self.next_sequential_addr = *next_sequential_addr;
+    }
 }
 
 impl Mmio {
     pub fn new(backup_type: BackupType, has_rtc: bool) -> Mmio {
-        let internal_memory = Box::<[u8; 0x05000000]>::new_zeroed();
         let external_memory = Box::<[u8; 0x06000000]>::new_zeroed();
 
         let storage_chip: Box<dyn StorageChip> = match backup_type {
@@ -61,29 +182,122 @@ impl Mmio {
         };
 
         Mmio {
-            internal_memory: unsafe { internal_memory.assume_init() },
-            external_memory: unsafe { external_memory.assume_init() },
+            internal_memory: SparseMemory::new(&INTERNAL_REGIONS),
+            external_memory: Arc::new(unsafe { external_memory.assume_init() }),
             ppu: Ppu::new(),
             joypad: Joypad::new(),
             apu: Apu::new(),
             dma: Dma::new(),
             timers: Timers::new(),
+            sio: Sio::new(),
             storage_chip,
+            gpio: Gpio::new(has_rtc),
+            tilt_sensor: None,
             io_ime: IoRegister::default(),
             io_ie: IoRegister::default(),
             io_if: IoRegister::default(),
             io_halt_cnt: IoRegister(0xff),
             io_postflg: IoRegister::default(),
+            io_waitcnt: IoRegister::default(),
+            io_memcnt: MEMCNT_DEFAULT,
             origin_write_length: None,
             last_rw_addr: Vec::new(), // initialize last_rw_addr to zero
             executing_bios: true,
             openbus_bios: 0,
+            cycles: 0,
+            ticked_cycles: 0,
+            pending_ticks: 0,
+            tick_remainder: 0,
+            eeprom_size_detection: true,
+            pages: PageTable::new(matches!(backup_type, BackupType::Eeprom4k | BackupType::Eeprom64k)),
+            access_log: None,
+            io_journal: None,
+            pc: 0,
+            heat_map: None,
+            ppu_timeline: None,
+            next_sequential_addr: 0,
+        }
+    }
+
+    // resolves hardware mirrors to the address they alias, used for memory accesses and breakpoint matching
+    pub fn canonical_address(addr: u32) -> u32 {
+        match addr {
+            // External WRAM – mirrors every 256 KiB in 0x02000000‑0x02FFFFFF
+            0x02000000..=0x02FFFFFF => 0x02000000 + ((addr - 0x02000000) % EWRAM_SIZE),
+            // Internal WRAM – mirrors every 32 KiB in 0x03000000‑0x03FFFFFF
+            0x03000000..=0x03FFFFFF => 0x03000000 + ((addr - 0x03000000) % IWRAM_SIZE),
+            // Pallete RAM – mirrors every 1 KiB in 0x05000000‑0x050003FF
+            0x05000000..=0x05FFFFFF => 0x05000000 + ((addr - 0x05000000) % PALETTE_SIZE),
+            // VRAM – 64 KiB BG + 32 KiB OBJ, followed by a mirror of the OBJ area to fill each 128 KiB window
+            0x06000000..=0x06FFFFFF => {
+                let mut offset = (addr - 0x0600_0000) % VRAM_WINDOW_SIZE;
+                if offset >= VRAM_PHYS_SIZE {
+                    offset -= 0x8000;
+                }
+                0x0600_0000 + offset
+            }
+            // OAM – mirrors every 1 KiB in 0x07000000‑0x070003FF
+            0x07000000..=0x07FFFFFF => 0x07000000 + ((addr - 0x07000000) % OAM_SIZE),
+            // GamePak ROM – wait state 1 and 2 mirrors of 0x08000000‑0x09FFFFFF
+            0x0A000000..=0x0DFFFFFF => 0x08000000 + ((addr - 0x08000000) % 0x02000000),
+            _ => addr,
         }
     }
 
+    // debugging aids and settings made by the frontend are not part of a savestate and stay as they are
+    pub fn restore(&mut self, state: &Mmio) {
+        let current = std::mem::replace(self, state.clone());
+
+        self.apu.carry_over(current.apu);
+        self.ppu.layer_enabled = current.ppu.layer_enabled;
+        self.gpio.interpolate_missing = current.gpio.interpolate_missing;
+        self.sio.link = current.sio.link;
+        if let (Some(sensor), Some(current)) = (&mut self.gpio.solar_sensor, current.gpio.solar_sensor) {
+            sensor.level = current.level;
+        }
+        self.access_log = current.access_log;
+        self.io_journal = current.io_journal;
+        self.heat_map = current.heat_map;
+        self.ppu_timeline = current.ppu_timeline;
+    }
+
+    // the components catch up with the bus cycles of the accesses since the last call, the instruction takes at
+    // least one tick for its internal cycles and long DMAs are spread over the next calls
     pub fn tick_components(&mut self) {
+        self.pending_ticks += cycle_ticks(self.cycles - self.ticked_cycles, &mut self.tick_remainder);
+        self.ticked_cycles = self.cycles;
+
+        let ticks = self.pending_ticks.clamp(1, MAX_TICKS_PER_CALL);
+        self.pending_ticks = self.pending_ticks.saturating_sub(ticks);
+        for _ in 0..ticks {
+            self.tick_once();
+        }
+    }
+
+    fn tick_once(&mut self) {
         let events = self.ppu.tick();
-        self.timers.tick();
+        self.record_ppu_events(&events);
+        let overflows = self.timers.tick();
+        self.apu.tick();
+
+        // timer 0 and 1 drive the sample rate of the direct sound channels
+        for (timer, &count) in overflows.iter().enumerate().take(2) {
+            for _ in 0..count {
+                let requests = self.apu.timer_overflow(timer);
+                if requests[0] {
+                    self.dma.request_fifo(FIFO_A);
+                }
+                if requests[1] {
+                    self.dma.request_fifo(FIFO_B);
+                }
+            }
+        }
+
+        let timer_interrupts = self.timers.pending_interrupts(&overflows);
+        if !timer_interrupts.is_empty() {
+            self.io_if.set_flags(timer_interrupts);
+            trace!(target: "irq", "Timer interrupt raised: {:016b}", timer_interrupts.bits());
+        }
 
         if events.contains(&PpuEvent::VBlank) && self.ppu.disp_stat.contains_flags(DispStat::VBLANK_IRQ_ENABLE) {
             self.io_if.set_flags(Interrupt::VBLANK);
@@ -95,108 +309,290 @@ impl Mmio {
             trace!(target: "irq", "HBLANK interrupt raised");
         }
 
-        self.transfer_dma(&events);
+        if events.contains(&PpuEvent::VCount) && self.ppu.disp_stat.contains_flags(DispStat::V_COUNTER_ENABLE) {
+            self.io_if.set_flags(Interrupt::VCOUNT);
+            trace!(target: "irq", "VCOUNT interrupt raised");
+        }
+
+        if self.sio.tick() {
+            self.io_if.set_flags(Interrupt::SERIAL);
+            trace!(target: "irq", "SERIAL interrupt raised");
+        }
+
+        if self.joypad.take_irq() {
+            self.io_if.set_flags(Interrupt::KEYPAD);
+            trace!(target: "irq", "KEYPAD interrupt raised");
+        }
+
+        if events.contains(&PpuEvent::VBlank) {
+            self.dma.request(DmaTrigger::VBlank);
+        }
+
+        if events.contains(&PpuEvent::HBlank) {
+            // the scanline counter has already moved on to the next line
+            let line = self.ppu.scanline.value().wrapping_sub(1);
+            if line < 160 {
+                self.dma.request(DmaTrigger::HBlank);
+            }
+            self.dma.request_video_capture(line);
+        }
+
+        self.run_dma();
+    }
+
+    fn record_ppu_events(&mut self, events: &[PpuEvent]) {
+        let Some(timeline) = &mut self.ppu_timeline else {
+            return;
+        };
+
+        let (scanline, dot) = (*self.ppu.scanline.value(), self.ppu.h_counter);
+        if scanline == 0 && dot == 0 {
+            timeline.finish_frame();
+        }
+
+        for event in events {
+            let event = match event {
+                PpuEvent::VBlank => TimelineEvent::VBlank,
+                PpuEvent::HBlank => TimelineEvent::HBlank,
+                PpuEvent::VCount => TimelineEvent::VCount,
+            };
+            timeline.record(scanline, dot, event);
+        }
     }
 
-    pub fn transfer_dma(&mut self, events: &Vec<PpuEvent>) {
+    // DISPCNT and BLDCNT changes in the middle of a frame are the usual raster effects
+    fn record_raster_write(&mut self, addr: u32, width: u32) {
+        let Some(timeline) = &mut self.ppu_timeline else {
+            return;
+        };
+
+        let (scanline, dot) = (*self.ppu.scanline.value(), self.ppu.h_counter);
+        let touches = |register: u32| addr <= register + 1 && register < addr + width;
+        if touches(0x04000000) {
+            timeline.record(scanline, dot, TimelineEvent::DispCnt(self.ppu.disp_cnt.value().bits()));
+        }
+        if touches(0x04000050) {
+            timeline.record(scanline, dot, TimelineEvent::BldCnt(self.ppu.bld_cnt.value().bits()));
+        }
+    }
+
+    // runs every channel whose start condition has been met, lower channels have priority
+    fn run_dma(&mut self) {
         for channel in 0..4 {
-            if self.dma.channels[channel].is_enabled()
-                && (self.dma.channels[channel].trigger() == DmaTrigger::Immediate
-                    || self.dma.channels[channel].trigger() == DmaTrigger::Special // TODO: Special trigger is not implemented, just allow it
-                    || (self.dma.channels[channel].trigger() == DmaTrigger::VBlank
-                        && events.contains(&PpuEvent::VBlank))
-                    || (self.dma.channels[channel].trigger() == DmaTrigger::HBlank
-                        && events.contains(&PpuEvent::HBlank)))
-            {
-                let src = self.dma.channels[channel].src.value();
-                let dst = self.dma.channels[channel].dst.value();
-                if dst == 0x040000A0 || dst == 0x040000A4 {
-                    // TODO: WE SKIP SOUND DMA FOR NOW
-                    continue;
-                }
+            if !self.dma.channels[channel].pending {
+                continue;
+            }
 
-                debug!(target: "mmio", "DMA transfer on channel {}, src: {:08X}, dst: {:08X}, units: {}, size: {}",
-                    channel, src, dst,
-                    self.dma.channels[channel].transfer_units(),
-                    self.dma.channels[channel].transfer_size());
-
-                let units = self.dma.channels[channel].transfer_units();
-                let unit_size = self.dma.channels[channel].transfer_size() as u16;
-                let src_ctrl = self.dma.channels[channel].src_addr_control();
-                let dst_ctrl = self.dma.channels[channel].dst_addr_control();
-                let initial_cnt = self.dma.channels[channel].cnt.value();
-
-                // transfer it at once
-                for i in 0..units {
-                    let offset = (i as u32) * unit_size as u32;
-
-                    let src_addr = match src_ctrl {
-                        AddrControl::Increment => src + offset,
-                        AddrControl::Decrement => src - offset,
-                        AddrControl::Fixed => src,
-                        AddrControl::Reload => unreachable!(),
-                    } & !(unit_size as u32 - 1);
-                    let dst_addr = match dst_ctrl {
-                        AddrControl::Increment => dst + offset,
-                        AddrControl::Decrement => dst - offset,
-                        AddrControl::Fixed => dst,
-                        AddrControl::Reload => dst + offset,
-                    } & !(unit_size as u32 - 1);
-
-                    if unit_size == 4 {
-                        let value = self.read_u32(src_addr);
-                        self.write_u32(dst_addr, value);
-                    } else {
-                        let value = self.read_u16(src_addr);
-                        self.write_u16(dst_addr, value);
-                    }
-                }
+            if let Some(timeline) = &mut self.ppu_timeline {
+                timeline.record(
+                    *self.ppu.scanline.value(),
+                    self.ppu.h_counter,
+                    TimelineEvent::Dma(channel),
+                );
+            }
 
-                let final_src = match src_ctrl {
-                    AddrControl::Increment => src + units as u32 * unit_size as u32,
-                    AddrControl::Decrement => src - units as u32 * unit_size as u32,
-                    _ => src,
-                };
+            if self.dma.channels[channel].is_sound() {
+                self.transfer_fifo_dma(channel);
+            } else {
+                self.transfer_dma(channel);
+            }
+        }
+    }
 
-                let calc_dst = match dst_ctrl {
-                    AddrControl::Increment => dst + units as u32 * unit_size as u32,
-                    AddrControl::Decrement => dst - units as u32 * unit_size as u32,
-                    AddrControl::Fixed | AddrControl::Reload => dst + units as u32 * unit_size as u32,
-                };
+    pub fn transfer_dma(&mut self, channel: usize) {
+        let dma = self.dma.channels[channel];
+        self.dma.channels[channel].pending = false;
 
-                let final_dst = if dst_ctrl == AddrControl::Reload { dst } else { calc_dst };
+        let units = dma.transfer_units();
+        let unit_size = dma.transfer_size() as u32;
+        let (src_ctrl, dst_ctrl) = (dma.src_addr_control(), dma.dst_addr_control());
+        let (mut src, mut dst) = (dma.internal_src, dma.internal_dst);
 
-                // update registers
-                self.dma.channels[channel].src.set(final_src);
-                self.dma.channels[channel].dst.set(final_dst);
+        debug!(target: "mmio", "DMA transfer on channel {}, src: {:08X}, dst: {:08X}, units: {}, size: {}",
+            channel, src, dst, units, unit_size);
 
-                let cnt = if self.dma.channels[channel].is_repeat() {
-                    initial_cnt
-                } else {
-                    0
-                };
-                self.dma.channels[channel].cnt.set(cnt);
+        if channel == 3 && self.eeprom_size_detection && (0x0D000000..=0x0DFFFFFF).contains(&dst) {
+            self.storage_chip.detect_size(units);
+        }
 
-                // if it's a repeat transfer, we just leave it enabled
-                if !self.dma.channels[channel].is_repeat() {
-                    self.dma.channels[channel].disable();
-                }
+        // transfer it at once
+        for _ in 0..units {
+            if unit_size == 4 {
+                let value = self.read::<u32>(src & !3, AccessKind::Dma);
+                self.write::<u32>(dst & !3, value, AccessKind::Dma);
+            } else {
+                let value = self.read::<u16>(src & !1, AccessKind::Dma);
+                self.write::<u16>(dst & !1, value, AccessKind::Dma);
             }
+
+            src = src_ctrl.step(src, unit_size);
+            dst = dst_ctrl.step(dst, unit_size);
+        }
+
+        self.dma.channels[channel].internal_src = src;
+        self.dma.channels[channel].internal_dst = dst;
+        self.complete_dma(channel);
+    }
+
+    // sound DMA always transfers 4 words to the FIFO, ignoring count, transfer size and destination control
+    pub fn transfer_fifo_dma(&mut self, channel: usize) {
+        let dma = self.dma.channels[channel];
+        self.dma.channels[channel].pending = false;
+
+        let (mut src, fifo) = (dma.internal_src, dma.internal_dst);
+        trace!(target: "mmio", "Sound DMA on channel {}, src: {:08X}, dst: {:08X}", channel, src, fifo);
+
+        for _ in 0..4 {
+            let value = self.read::<u32>(src & !3, AccessKind::Dma);
+            self.write::<u32>(fifo, value, AccessKind::Dma);
+            src = dma.src_addr_control().step(src, 4);
         }
+
+        self.dma.channels[channel].internal_src = src;
+        self.complete_dma(channel);
     }
 
-    pub fn read(&mut self, addr: u32) -> u8 {
-        let value = match addr {
+    fn complete_dma(&mut self, channel: usize) {
+        let dma = &mut self.dma.channels[channel];
+
+        if dma.irq_on_complete() {
+            self.io_if.set_flags(dma.interrupt());
+            trace!(target: "irq", "DMA{} interrupt raised", channel);
+        }
+
+        // immediate transfers never repeat
+        if !dma.is_repeat() || dma.trigger() == DmaTrigger::Immediate {
+            dma.disable();
+        } else if dma.dst_addr_control() == AddrControl::Reload {
+            dma.reload_dst();
+        }
+    }
+
+    // https://problemkaputt.de/gbatek.htm#biosfunctions (SWI 01h)
+    pub fn register_ram_reset(&mut self, flags: RamResetFlags) {
+        debug!(target: "mmio", "RegisterRamReset with flags: {:08b}", flags.bits());
+
+        if flags.contains(RamResetFlags::EWRAM) {
+            self.internal_memory[0x02000000..0x02000000 + EWRAM_SIZE as usize].fill(0);
+        }
+
+        if flags.contains(RamResetFlags::IWRAM) {
+            // the last 0x200 bytes hold the stacks and the BIOS IRQ vector/flags
+            self.internal_memory[0x03000000..0x03007E00].fill(0);
+        }
+
+        // ppu.vram is indexed from 0x05000000
+        if flags.contains(RamResetFlags::PALETTE) {
+            self.ppu.vram[0x00000000..PALETTE_SIZE as usize].fill(0);
+        }
+
+        if flags.contains(RamResetFlags::VRAM) {
+            self.ppu.vram[0x01000000..0x01000000 + VRAM_PHYS_SIZE as usize].fill(0);
+        }
+
+        if flags.contains(RamResetFlags::OAM) {
+            self.ppu.vram[0x02000000..0x02000000 + OAM_SIZE as usize].fill(0);
+        }
+
+        // switches to general purpose mode
+        if flags.contains(RamResetFlags::SIO) {
+            for addr in 0x04000120..=0x0400012B {
+                self.sio.write_byte(addr, 0);
+            }
+            self.sio.rcnt = 0x8000;
+            self.internal_memory[0x0400012C..=0x0400012F].fill(0);
+            self.internal_memory[0x04000136..=0x0400015F].fill(0);
+        }
+
+        if flags.contains(RamResetFlags::SOUND) {
+            // SOUNDBIAS keeps its level
+            for addr in (0x04000060..=0x04000087).chain(0x0400008A..=0x0400008E) {
+                self.apu.write_byte(addr, 0);
+            }
+            self.internal_memory[0x04000060..=0x040000AF].fill(0);
+        }
+
+        if flags.contains(RamResetFlags::OTHER) {
+            for addr in 0x04000000..=0x04000056 {
+                self.ppu.write_byte(addr, 0);
+            }
+            for addr in 0x040000B0..=0x040000DF {
+                self.dma.write_byte(addr, 0);
+            }
+            for addr in 0x04000100..=0x0400010F {
+                self.timers.write_byte(addr, 0);
+            }
+            self.joypad.write_byte(0x04000132, 0);
+            self.joypad.write_byte(0x04000133, 0);
+            self.io_ie.set(Interrupt::empty());
+            self.io_if.set(Interrupt::empty());
+            self.io_ime.set(0);
+            self.io_waitcnt.set(WaitCnt::empty());
+            self.internal_memory[0x04000204..=0x0400020B].fill(0);
+        }
+    }
+
+    pub fn read<T: BusValue>(&mut self, addr: u32, access: AccessKind) -> T {
+        self.charge_access(addr, T::LENGTH, access);
+
+        // the 8-bit bus repeats the addressed byte on every lane
+        let value = if Mmio::is_8bit_bus(addr) {
+            T::from_u32(self.read_byte(addr, access) as u32 * 0x01010101)
+        } else {
+            T::from_u32((0..T::BYTES).fold(0, |value, i| {
+                value | (self.read_byte(addr + i, access) as u32) << (i * 8)
+            }))
+        };
+
+        // the BIOS open bus returns the last opcode fetched from the BIOS
+        if access == AccessKind::Fetch && self.executing_bios && (0x00000000..=0x00003FFF).contains(&addr) {
+            self.openbus_bios = value.to_u32();
+        }
+
+        self.log_access(access, AccessDirection::Read, addr, T::LENGTH, value.to_u32());
+        self.record_heat(access, AccessDirection::Read, addr);
+
+        value
+    }
+
+    fn read_byte(&mut self, addr: u32, access: AccessKind) -> u8 {
+        let value = match self.pages.read(addr) {
+            Page::Internal { base, mask } => self.internal_memory[(base + (addr & mask)) as usize],
+            Page::External { base, mask } => self.external_memory[(base + (addr & mask)) as usize],
+            Page::Slow => self.read_slow(addr, access),
+        };
+
+        self.origin_write_length = None;
+        self.last_rw_addr.push(addr);
+
+        trace!(target: "mmio", "Read {:02X} from {:08X}", value, addr);
+
+        value
+    }
+
+    fn read_slow(&mut self, addr: u32, access: AccessKind) -> u8 {
+        match addr {
             // I/O Registers & Hooks
-            0x04000000..=0x04000056 => self.ppu.read(addr),    // PPU I/O
-            0x04000080..=0x0400008E => self.apu.read(addr),    // APU I/O
-            0x040000B0..=0x040000DF => self.dma.read(addr),    // DMA I/O, 0x40000E0 = unused
-            0x04000100..=0x0400010F => self.timers.read(addr), // Timers I/O
-            0x04000130..=0x04000133 => self.joypad.read(addr), // Joypad I/O
-            0x04000200..=0x04000201 => self.io_ie.read(addr),  // Interrupt Enable
-            0x04000202..=0x04000203 => self.io_if.read(addr),  // Interrupt Flag
-            0x04000208..=0x04000209 => self.io_ime.read(addr), // Interrupt Master Enable
-            0x04000301 => self.io_halt_cnt.read(),             // HALTCNT
+            0x04000000..=0x04000056 => self.ppu.read_byte(addr), // PPU I/O
+            0x04000060..=0x0400007F => self.apu.read_byte(addr), // PSG channels
+            0x04000080..=0x0400008E => self.apu.read_byte(addr), // APU I/O
+            0x04000090..=0x0400009F => self.apu.read_byte(addr), // Wave RAM
+            0x040000A0..=0x040000A7 => self.apu.read_byte(addr), // Sound FIFOs
+            0x040000B0..=0x040000DF => self.dma.read_byte(addr), // DMA I/O, 0x40000E0 = unused
+            0x04000100..=0x0400010F => self.timers.read_byte(addr), // Timers I/O
+            // SIODATA8, only the CPU and DMA pop the receive FIFO
+            0x0400012A if self.sio.mode() == SioMode::Uart => match access {
+                AccessKind::Data | AccessKind::Dma => self.sio.read_uart(),
+                AccessKind::Fetch | AccessKind::Debugger => self.sio.peek_uart(),
+            },
+            0x04000120..=0x0400012B => self.sio.read_byte(addr), // Serial I/O
+            0x04000130..=0x04000133 => self.joypad.read_byte(addr), // Joypad I/O
+            0x04000134..=0x04000135 => self.sio.read_byte(addr), // RCNT
+            0x04000200..=0x04000201 => self.io_ie.read_byte(addr), // Interrupt Enable
+            0x04000202..=0x04000203 => self.io_if.read_byte(addr), // Interrupt Flag
+            0x04000204..=0x04000205 => self.io_waitcnt.read_byte(addr), // WAITCNT
+            0x04000208..=0x04000209 => self.io_ime.read(addr),   // Interrupt Master Enable
+            0x04000301 => self.io_halt_cnt.read(),               // HALTCNT
             0x04000300 => self.io_postflg.read(), // POSTFLG -> "After initial reset, the GBA BIOS initializes the register to 01h"
             // Internal and External Memory
             0x00000000..=0x00003FFF if self.executing_bios => self.internal_memory[addr as usize],
@@ -212,39 +608,11 @@ impl Mmio {
                 error!(target: "mmio", "Unmapped I/O read: {:08X}", addr);
                 self.internal_memory[addr as usize]
             }
-            0x00000000..=0x04FFFFFF => {
-                let addr = match addr {
-                    // External WRAM – mirrors every 256 KiB in 0x02000000‑0x02FFFFFF
-                    0x02000000..=0x02FFFFFF => 0x02000000 + ((addr - 0x02000000) % EWRAM_SIZE),
-                    // Internal WRAM – mirrors every 32 KiB in 0x03000000‑0x03FFFFFF
-                    0x03000000..=0x03FFFFFF => 0x03000000 + ((addr - 0x03000000) % IWRAM_SIZE),
-                    _ => addr,
-                };
-                self.internal_memory[addr as usize]
-            }
-            0x05000000..=0x07FFFFFF => {
-                let dispcnt = self.ppu.disp_cnt.value();
-                let bg_mode = dispcnt.bg_mode();
-
-                let addr = match addr {
-                    // Pallete RAM – mirrors every 1 KiB in 0x05000000‑0x050003FF
-                    0x05000000..=0x05FFFFFF => 0x05000000 + ((addr - 0x05000000) % PALETTE_SIZE),
-                    // VRAM – 96 KiB + 32 KiB mirror inside each 128 KiB window
-                    0x06000000..=0x06FFFFFF => {
-                        let mut offset = (addr - 0x0600_0000) % VRAM_WINDOW_SIZE;
-                        if offset >= VRAM_PHYS_SIZE {
-                            offset -= 0x0080_00;
-                        }
-                        0x0600_0000 + offset
-                    }
-                    // Lower 16 KiB of OAM is used for backgrounds in modes 3-5
-                    0x07000000..=0x07004000 if bg_mode >= 3 => addr,
-                    // OAM – mirrors every 1 KiB in 0x07000000‑0x070003FF
-                    0x07000000..=0x07FFFFFF if bg_mode < 3 => 0x07000000 + ((addr - 0x07000000) % OAM_SIZE),
-                    _ => addr,
-                };
-                self.ppu.read(addr)
-            }
+            // Internal Memory Control, mirrored every 64 KiB
+            0x04000800..=0x04FFFFFF if addr & 0xFFFC == 0x0800 => (self.io_memcnt >> ((addr & 3) * 8)) as u8,
+            0x00000000..=0x04FFFFFF => self.internal_memory[Mmio::canonical_address(addr) as usize],
+            0x05000000..=0x07FFFFFF => self.ppu.read_byte(Mmio::canonical_address(addr)),
+            0x080000C4..=0x080000C9 if self.gpio.is_readable() => self.gpio.read_byte(addr),
             0x08000000..=0x09FFFFFF => self.external_memory[(addr - 0x08000000) as usize],
             0x0A000000..=0x0BFFFFFF => self.external_memory[(addr - 0x0A000000) as usize], // Mirror of 0x08000000..=0x09FFFFFF
             0x0D000000..=0x0DFFFFFF
@@ -254,109 +622,122 @@ impl Mmio {
                 ) =>
             {
                 // TODO: I think this doesn't handle the EEPROM correctly, but it should be fine for now
-                self.storage_chip.read(addr)
+                self.storage_chip.read_byte(addr)
             }
             0x0C000000..=0x0DFFFFFF => self.external_memory[(addr - 0x0C000000) as usize], // Mirror of 0x08000000..=0x09FFFFFF
-            0x0E000000..=0x0FFFFFFF => self.storage_chip.read(addr),
+            0x0E008000..=0x0E0085FF if self.tilt_sensor.is_some() => self.tilt_sensor.as_ref().unwrap().read_byte(addr),
+            0x0E000000..=0x0FFFFFFF => self.storage_chip.read_byte(addr),
             _ => {
                 error!(target: "mmio", "Reading from unmapped memory address: {:08X}", addr);
                 0x69
             }
-        };
+        }
+    }
 
-        self.origin_write_length = None;
-        self.last_rw_addr.push(addr);
+    pub fn write<T: BusValue>(&mut self, addr: u32, value: T, access: AccessKind) {
+        self.charge_access(addr, T::LENGTH, access);
+        self.log_access(access, AccessDirection::Write, addr, T::LENGTH, value.to_u32());
+        self.record_heat(access, AccessDirection::Write, addr);
+        self.origin_write_length = Some(T::LENGTH);
+
+        let journaled = self.io_journal.is_some() && access != AccessKind::Debugger && Mmio::is_io(addr);
+        let old = journaled.then(|| {
+            (0..T::BYTES).fold(0, |old, i| {
+                old | (self.read_slow(addr + i, AccessKind::Debugger) as u32) << (i * 8)
+            })
+        });
+
+        if Mmio::is_8bit_bus(addr) {
+            // only the byte that lines up with the address is stored
+            self.write_byte(addr, value.to_u32().rotate_right((addr & (T::BYTES - 1)) * 8) as u8);
+        } else {
+            for i in 0..T::BYTES {
+                self.write_byte(addr + i, (value.to_u32() >> (i * 8)) as u8);
+            }
+        }
 
-        trace!(target: "mmio", "Read {:02X} from {:08X}", value, addr);
+        self.origin_write_length = None; // reset after writing
 
-        value
-    }
+        if access != AccessKind::Debugger && Mmio::is_io(addr) {
+            self.record_raster_write(addr, T::BYTES);
+        }
 
-    pub fn read_u16(&mut self, addr: u32) -> u16 {
-        u16::from_le_bytes([self.read(addr), self.read(addr + 1)])
+        if let Some(old) = old {
+            self.record_io_write(addr, T::BYTES, old, value.to_u32(), access);
+        }
     }
 
-    pub fn read_u32(&mut self, addr: u32) -> u32 {
-        let value = u32::from_le_bytes([
-            self.read(addr),
-            self.read(addr + 1),
-            self.read(addr + 2),
-            self.read(addr + 3),
-        ]);
+    fn is_io(addr: u32) -> bool {
+        (0x04000000..=0x040003FF).contains(&addr)
+    }
 
-        if self.executing_bios && (0x00000000..=0x00003FFF).contains(&addr) {
-            self.openbus_bios = value;
+    fn record_io_write(&mut self, addr: u32, width: u32, old: u32, new: u32, source: AccessKind) {
+        let (cycle, pc) = (self.cycles, self.pc);
+
+        if let Some(journal) = &mut self.io_journal {
+            journal.push(IoEvent {
+                cycle,
+                addr,
+                register: register_name(addr),
+                width,
+                old,
+                new,
+                pc,
+                source,
+            });
         }
-
-        value
     }
 
-    pub fn write(&mut self, addr: u32, value: u8) {
+    fn write_byte(&mut self, addr: u32, value: u8) {
         trace!(target: "mmio", "Writing {:02X} to {:08X}", value, addr);
 
+        match self.pages.write(addr) {
+            Page::Internal { base, mask } => {
+                let addr = base + (addr & mask);
+                self.internal_memory[addr as usize] = value;
+            }
+            Page::External { .. } | Page::Slow => self.write_slow(addr, value),
+        }
+
+        self.last_rw_addr.push(addr);
+    }
+
+    fn write_slow(&mut self, addr: u32, value: u8) {
         match addr {
             0x00000000..=0x00003FFF => debug!(target: "mmio", "Writing to BIOS: {:02X} to {:08X}", value, addr),
-            0x04000000..=0x04000056 => self.ppu.write(addr, value), // PPU I/O
-            0x04000080..=0x0400008E => self.apu.write(addr, value), // APU I/O
-            0x040000B0..=0x040000DF => self.dma.write(addr, value), // DMA I/O
-            0x04000100..=0x0400010F => self.timers.write(addr, value), // Timers I/O
-            0x04000130..=0x04000133 => self.joypad.write(addr, value), // Joypad I/O
-            0x04000200..=0x04000201 => self.io_ie.write(addr, value), // Interrupt Enable
-            0x04000202..=0x04000203 => self.io_if.write(addr, value), // Interrupt Flag
-            0x04000208..=0x04000209 => self.io_ime.write(addr, value), // Interrupt Master Enable
+            0x04000000..=0x04000056 => self.ppu.write_byte(addr, value), // PPU I/O
+            0x04000060..=0x0400007F => self.apu.write_byte(addr, value), // PSG channels
+            0x04000080..=0x0400008E => self.apu.write_byte(addr, value), // APU I/O
+            0x04000090..=0x0400009F => self.apu.write_byte(addr, value), // Wave RAM
+            0x040000A0..=0x040000A7 => self.apu.write_byte(addr, value), // Sound FIFOs
+            0x040000B0..=0x040000DF => self.dma.write_byte(addr, value), // DMA I/O
+            0x04000100..=0x0400010F => self.timers.write_byte(addr, value), // Timers I/O
+            0x04000120..=0x0400012B => self.sio.write_byte(addr, value), // Serial I/O
+            0x04000130..=0x04000133 => self.joypad.write_byte(addr, value), // Joypad I/O
+            0x04000134..=0x04000135 => self.sio.write_byte(addr, value), // RCNT
+            0x04000200..=0x04000201 => self.io_ie.write_byte(addr, value), // Interrupt Enable
+            0x04000202..=0x04000203 => self.io_if.write_byte(addr, value), // Interrupt Flag
+            0x04000204..=0x04000205 => self.io_waitcnt.write_byte(addr, value), // WAITCNT
+            0x04000208..=0x04000209 => self.io_ime.write(addr, value),   // Interrupt Master Enable
             0x0400020A..=0x0400020B => self.internal_memory[addr as usize] = value, // Unused
-            0x04000300 => self.io_postflg.write(value), // POSTFLG -> "After initial reset, the GBA BIOS initializes the register to 01h"
-            0x04000301 => self.io_halt_cnt.write(value), // HALTCNT
+            0x04000300 => self.io_postflg.write(value & 1), // POSTFLG -> "After initial reset, the GBA BIOS initializes the register to 01h"
+            0x04000301 => self.io_halt_cnt.write(value),    // HALTCNT
             0x04000000..=0x040003FE => {
                 error!(target: "mmio", "Unmapped I/O write: {:02X} to {:08X}", value, addr);
                 self.internal_memory[addr as usize] = value; // Unmapped I/O region
             }
+            // Internal Memory Control, mirrored every 64 KiB
+            0x04000800..=0x04FFFFFF if addr & 0xFFFC == 0x0800 => {
+                let shift = (addr & 3) * 8;
+                self.io_memcnt = (self.io_memcnt & !(0xFF << shift)) | (((value as u32) << shift) & MEMCNT_MASK);
+                debug!(target: "mmio", "Internal memory control set to {:08X}", self.io_memcnt);
+            }
             0x00000000..=0x04FFFFFF => {
-                let addr = match addr {
-                    // External WRAM – mirrors every 256 KiB in 0x02000000‑0x02FFFFFF
-                    0x02000000..=0x02FFFFFF => 0x02000000 + ((addr - 0x02000000) % EWRAM_SIZE),
-                    // Internal WRAM – mirrors every 32 KiB in 0x03000000‑0x03FFFFFF
-                    0x03000000..=0x03FFFFFF => 0x03000000 + ((addr - 0x03000000) % IWRAM_SIZE),
-                    _ => addr,
-                };
+                let addr = Mmio::canonical_address(addr);
                 self.internal_memory[addr as usize] = value;
             }
-            0x05000000..=0x07FFFFFF => {
-                let dispcnt = self.ppu.disp_cnt.value();
-                let bg_mode = dispcnt.bg_mode();
-
-                let addr = match addr {
-                    // Pallete RAM – mirrors every 1 KiB in 0x05000000‑0x050003FF
-                    0x05000000..=0x05FFFFFF => 0x05000000 + ((addr - 0x05000000) % PALETTE_SIZE),
-                    // VRAM – 96 KiB + 32 KiB mirror inside each 128 KiB window
-                    0x06000000..=0x06FFFFFF => {
-                        let mut offset = (addr - 0x0600_0000) % VRAM_WINDOW_SIZE;
-                        if offset >= VRAM_PHYS_SIZE {
-                            offset -= 0x0080_00;
-                        }
-                        0x0600_0000 + offset
-                    }
-                    // Lower 16 KiB of OAM is used for backgrounds in modes 3-5
-                    0x07000000..=0x07004000 if bg_mode >= 3 => addr,
-                    // OAM – mirrors every 1 KiB in 0x07000000‑0x070003FF
-                    0x07000000..=0x07FFFFFF => 0x07000000 + ((addr - 0x07000000) % OAM_SIZE),
-                    _ => addr,
-                };
-
-                // self.origin_write_length == None implies 8bit write
-                match addr {
-                    0x06000000..=0x06017FFF if self.origin_write_length == None => {
-                        // 8bit write to VRAM mirrors to full halfword
-                        let addr = addr & !1; // align to halfword
-                        self.ppu.write(addr, value);
-                        self.ppu.write(addr + 1, value);
-                    }
-                    // Atem — 12:06 AM
-                    // 8-bit writes to OBJ VRAM and OAM are ignored
-                    0x07000000..=0x070003FF if self.origin_write_length == None => {}
-                    _ => self.ppu.write(addr, value),
-                }
-            }
+            0x05000000..=0x07FFFFFF => self.write_video(Mmio::canonical_address(addr), value),
+            0x080000C4..=0x080000C9 => self.gpio.write_byte(addr, value),
             0x08000000..=0x09FFFFFF => {
                 debug!(target: "mmio", "Writing to GamePak memory: {:02X} to {:08X}", value, addr)
             }
@@ -370,40 +751,108 @@ impl Mmio {
                 ) =>
             {
                 // TODO: I think this doesn't handle the EEPROM correctly, but it should be fine for now
-                self.storage_chip.write(addr, value);
+                self.storage_chip.write_byte(addr, value);
             }
             0x0C000000..=0x0DFFFFFF => {
                 debug!(target: "mmio", "Writing to GamePak memory: {:02X} to {:08X}", value, addr)
             } // Mirror of 0x08000000..=0x09FFFFFF
-            0x0E000000..=0x0FFFFFFF => self.storage_chip.write(addr, value),
+            0x0E008000..=0x0E0085FF if self.tilt_sensor.is_some() => {
+                self.tilt_sensor.as_mut().unwrap().write_byte(addr, value)
+            }
+            0x0E000000..=0x0FFFFFFF => self.storage_chip.write_byte(addr, value),
             _ => {
                 error!(target: "mmio", "Writing to unmapped memory address: {:08X}", addr);
             }
         }
+    }
 
-        self.last_rw_addr.push(addr);
+    fn write_video(&mut self, addr: u32, value: u8) {
+        // 8-bit writes are widened or dropped depending on the region
+        if self.origin_write_length != Some(TransferLength::Byte) {
+            self.ppu.write_byte(addr, value);
+        } else if let Some(addr) = self.ppu.byte_write_target(addr) {
+            self.ppu.write_byte(addr, value);
+            self.ppu.write_byte(addr + 1, value);
+        }
     }
 
-    pub fn write_u16(&mut self, addr: u32, value: u16) {
-        self.origin_write_length = Some(TransferLength::HalfWord);
+    // SRAM and flash are connected through an 8-bit bus, wider reads repeat the addressed byte
+    // and wider writes only store the byte that lines up with the address
+    fn is_8bit_bus(addr: u32) -> bool {
+        (0x0E000000..=0x0FFFFFFF).contains(&addr)
+    }
 
-        let [a, b] = value.to_le_bytes();
-        self.write(addr, a);
-        self.write(addr + 1, b);
+    // cycles of a single access, including the wait states configured in WAITCNT
+    // https://problemkaputt.de/gbatek.htm#gbamemorymap
+    pub fn access_cycles(&self, addr: u32, length: &TransferLength, sequential: bool) -> u32 {
+        let waitcnt = self.io_waitcnt.value();
+        let is_word = *length == TransferLength::Word;
 
-        self.origin_write_length = None; // reset after writing
+        match addr {
+            // on-board WRAM, 16-bit bus with 2 wait states
+            0x02000000..=0x02FFFFFF if is_word => 6,
+            0x02000000..=0x02FFFFFF => 3,
+            // palette RAM and VRAM, 16-bit bus
+            0x05000000..=0x06FFFFFF if is_word => 2,
+            // GamePak ROM, 16-bit bus, words are fetched as two halfwords with the second one being sequential
+            0x08000000..=0x0DFFFFFF => {
+                let region = ((addr - 0x08000000) >> 25) as usize;
+                let wait = if sequential {
+                    waitcnt.second_access(region)
+                } else {
+                    waitcnt.first_access(region)
+                };
+
+                if is_word {
+                    2 + wait + waitcnt.second_access(region)
+                } else {
+                    1 + wait
+                }
+            }
+            // GamePak SRAM, 8-bit bus
+            0x0E000000..=0x0FFFFFFF => 1 + waitcnt.sram_wait(),
+            // BIOS, IWRAM, I/O and OAM have no wait states
+            _ => 1,
+        }
     }
 
-    pub fn write_u32(&mut self, addr: u32, value: u32) {
-        self.origin_write_length = Some(TransferLength::Word);
+    fn charge_access(&mut self, addr: u32, length: TransferLength, access: AccessKind) {
+        if access == AccessKind::Debugger {
+            return;
+        }
 
-        let [a, b, c, d] = value.to_le_bytes();
-        self.write(addr, a);
-        self.write(addr + 1, b);
-        self.write(addr + 2, c);
-        self.write(addr + 3, d);
+        let sequential = addr == self.next_sequential_addr;
+        self.cycles += self.access_cycles(addr, &length, sequential) as u64;
 
-        self.origin_write_length = None; // reset after writing
+        self.next_sequential_addr = addr.wrapping_add(match length {
+            TransferLength::Byte => 1,
+            TransferLength::HalfWord => 2,
+            TransferLength::Word => 4,
+        });
+    }
+
+    // only the instruction's own loads and stores end up in a step trace
+    fn log_access(
+        &mut self, access: AccessKind, direction: AccessDirection, addr: u32, width: TransferLength, value: u32,
+    ) {
+        if access != AccessKind::Data {
+            return;
+        }
+
+        if let Some(accesses) = &mut self.access_log {
+            accesses.push(MemoryAccess {
+                direction,
+                addr,
+                width,
+                value,
+            });
+        }
+    }
+
+    fn record_heat(&mut self, access: AccessKind, direction: AccessDirection, addr: u32) {
+        if let Some(heat_map) = &mut self.heat_map {
+            heat_map.record(Mmio::canonical_address(addr), access, direction);
+        }
     }
 
     pub fn load(&mut self, addr: u32, data: &[u8]) {
@@ -412,12 +861,42 @@ impl Mmio {
             0x00000000..=0x04FFFFFF => self.internal_memory[addr..addr + data.len()].copy_from_slice(data),
             0x05000000..=0x07FFFFFF => self.ppu.load(addr as u32, data),
             0x08000000..=0x0FFFFFFF => {
-                self.external_memory[(addr - 0x08000000)..(addr - 0x08000000) + data.len()].copy_from_slice(data)
+                let offset = addr - 0x08000000;
+                Arc::make_mut(&mut self.external_memory)[offset..offset + data.len()].copy_from_slice(data);
+
+                if offset == 0 {
+                    self.fill_rom_open_bus(data.len());
+                }
             }
             _ => panic!("Invalid memory address: {:08X}", addr),
         }
     }
 
+    // reading past the end of the ROM returns the address bits still latched on the shared address/data bus,
+    // so each halfword reads as (address / 2) & 0xFFFF
+    fn fill_rom_open_bus(&mut self, rom_size: usize) {
+        let start = (rom_size + 1) & !1;
+
+        let rom = Arc::make_mut(&mut self.external_memory);
+        for (i, halfword) in rom[start..0x02000000].chunks_exact_mut(2).enumerate() {
+            let value = ((start >> 1) + i) as u16;
+            halfword.copy_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    // I/O state after the BIOS boot sequence
+    pub fn skip_bios(&mut self) {
+        self.io_postflg.write(0x01);
+        self.openbus_bios = 0xE129F000; // initial openbus value after BIOS execution
+        self.ppu.disp_cnt.set(DispCnt::FORCED_BLANK);
+        for bg in 0..2 {
+            // identity rotation/scaling for BG2 and BG3
+            self.ppu.bg_pa[bg].set(BgAffineParam::from_bits_truncate(0x0100));
+            self.ppu.bg_pd[bg].set(BgAffineParam::from_bits_truncate(0x0100));
+        }
+        self.disable_bios_access();
+    }
+
     pub fn enable_bios_access(&mut self) {
         self.executing_bios = true;
     }
@@ -1,4 +1,8 @@
 pub mod device;
 pub mod dma;
+pub mod heat_map;
+pub mod io_journal;
 pub mod mmio;
+pub mod pages;
 pub mod registers;
+pub mod sparse;
@@ -0,0 +1,68 @@
+const PAGE_SHIFT: u32 = 16; // 64 KiB pages
+const PAGE_COUNT: usize = 0x10000000 >> PAGE_SHIFT; // 28-bit address bus
+
+#[derive(Clone, Copy)]
+pub enum Page {
+    Slow,                              // I/O, video memory, BIOS, GPIO and backup go through the regular handlers
+    Internal { base: u32, mask: u32 }, // plain memory in Mmio::internal_memory
+    External { base: u32, mask: u32 }, // plain memory in Mmio::external_memory
+}
+
+// resolves regions that are nothing but (mirrored) memory with a mask and an index
+#[derive(Clone)]
+pub struct PageTable {
+    read: Box<[Page]>,
+    write: Box<[Page]>,
+}
+
+impl PageTable {
+    pub fn new(has_eeprom: bool) -> PageTable {
+        let mut read = vec![Page::Slow; PAGE_COUNT].into_boxed_slice();
+        let mut write = vec![Page::Slow; PAGE_COUNT].into_boxed_slice();
+
+        for (page, (read, write)) in read.iter_mut().zip(write.iter_mut()).enumerate() {
+            let addr = (page as u32) << PAGE_SHIFT;
+
+            match addr {
+                // External WRAM – mirrors every 256 KiB
+                0x02000000..=0x02FFFFFF => {
+                    let base = 0x02000000 + (addr & 0x30000);
+                    *read = Page::Internal { base, mask: 0xFFFF };
+                    *write = *read;
+                }
+                // Internal WRAM – mirrors every 32 KiB, twice per page
+                0x03000000..=0x03FFFFFF => {
+                    *read = Page::Internal {
+                        base: 0x03000000,
+                        mask: 0x7FFF,
+                    };
+                    *write = *read;
+                }
+                // GamePak ROM and its wait state mirrors, except for the pages holding GPIO and EEPROM
+                0x08000000..=0x0DFFFFFF if addr != 0x08000000 && !(has_eeprom && addr >= 0x0D000000) => {
+                    *read = Page::External {
+                        base: (addr - 0x08000000) & 0x01FF0000,
+                        mask: 0xFFFF,
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        PageTable { read, write }
+    }
+
+    pub fn read(&self, addr: u32) -> Page {
+        self.read
+            .get((addr >> PAGE_SHIFT) as usize)
+            .copied()
+            .unwrap_or(Page::Slow)
+    }
+
+    pub fn write(&self, addr: u32) -> Page {
+        self.write
+            .get((addr >> PAGE_SHIFT) as usize)
+            .copied()
+            .unwrap_or(Page::Slow)
+    }
+}
@@ -46,6 +46,17 @@ pub enum AddrControl {
     Reload,
 }
 
+impl AddrControl {
+    // address of the next unit, reload only affects the destination after the transfer has completed
+    pub fn step(&self, addr: u32, unit_size: u32) -> u32 {
+        match self {
+            AddrControl::Increment | AddrControl::Reload => addr.wrapping_add(unit_size),
+            AddrControl::Decrement => addr.wrapping_sub(unit_size),
+            AddrControl::Fixed => addr,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum DmaTrigger {
     Immediate,
@@ -131,6 +142,78 @@ bitflags! {
     }
 }
 
+impl TimerControl {
+    // number of cycles per counter step
+    pub fn prescaler(&self) -> u16 {
+        match self.bits() & TimerControl::PRESCALER_SELECTION.bits() {
+            0 => 1,
+            1 => 64,
+            2 => 256,
+            3 => 1024,
+            _ => unreachable!(),
+        }
+    }
+}
+
+bitflags! {
+    // https://problemkaputt.de/gbatek.htm#gbasystemcontrol
+    #[derive(Default, Copy, Clone)]
+    pub struct WaitCnt: u16 {
+        const SRAM_WAIT          = 0b0000_0000_0000_0011;
+        const WS0_FIRST_ACCESS   = 0b0000_0000_0000_1100;
+        const WS0_SECOND_ACCESS  = 0b0000_0000_0001_0000;
+        const WS1_FIRST_ACCESS   = 0b0000_0000_0110_0000;
+        const WS1_SECOND_ACCESS  = 0b0000_0000_1000_0000;
+        const WS2_FIRST_ACCESS   = 0b0000_0011_0000_0000;
+        const WS2_SECOND_ACCESS  = 0b0000_0100_0000_0000;
+        const PHI_TERMINAL       = 0b0001_1000_0000_0000;
+        const PREFETCH_BUFFER    = 0b0100_0000_0000_0000;
+        // bit 15 (game pak type) is read-only and always 0 for GBA cartridges
+    }
+}
+
+impl WaitCnt {
+    const FIRST_ACCESS: [u32; 4] = [4, 3, 2, 8];
+
+    // wait states of SRAM accesses
+    pub fn sram_wait(&self) -> u32 {
+        Self::FIRST_ACCESS[(self.bits() & WaitCnt::SRAM_WAIT.bits()) as usize]
+    }
+
+    // wait states of the first (non-sequential) access to the given ROM wait state region
+    pub fn first_access(&self, region: usize) -> u32 {
+        let shift = 2 + region * 3;
+        Self::FIRST_ACCESS[((self.bits() >> shift) & 0b11) as usize]
+    }
+
+    // wait states of consecutive (sequential) accesses to the given ROM wait state region
+    pub fn second_access(&self, region: usize) -> u32 {
+        let (flag, slow) = match region {
+            0 => (WaitCnt::WS0_SECOND_ACCESS, 2),
+            1 => (WaitCnt::WS1_SECOND_ACCESS, 4),
+            2 => (WaitCnt::WS2_SECOND_ACCESS, 8),
+            _ => unreachable!(),
+        };
+
+        if self.contains(flag) { 1 } else { slow }
+    }
+}
+
+bitflags! {
+    // r0 argument of SWI 01h (RegisterRamReset)
+    #[derive(Default, Copy, Clone)]
+    pub struct RamResetFlags: u8 {
+        const EWRAM   = 1 << 0; // 256K on-board WRAM, don't use when returning to WRAM
+        const IWRAM   = 1 << 1; // 32K in-chip WRAM, excluding the last 0x200 bytes
+        const PALETTE = 1 << 2;
+        const VRAM    = 1 << 3;
+        const OAM     = 1 << 4;
+        const SIO     = 1 << 5;
+        const SOUND   = 1 << 6;
+        const OTHER   = 1 << 7; // all other registers
+    }
+}
+
 #[derive(PartialEq, Clone, Copy)]
 pub struct MappedRegister32(u8, u8, u8, u8);
 
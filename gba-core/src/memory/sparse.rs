@@ -0,0 +1,49 @@
+use std::ops::{Deref, DerefMut, Range};
+
+// a chunk of the address space that is indexed by address, only the regions hold anything and everything else is
+// unmapped or folded onto them, so clones copy just the regions instead of the whole chunk
+pub struct SparseMemory<const SIZE: usize> {
+    bytes: Box<[u8; SIZE]>,
+    regions: &'static [Range<usize>],
+}
+
+impl<const SIZE: usize> SparseMemory<SIZE> {
+    pub fn new(regions: &'static [Range<usize>]) -> SparseMemory<SIZE> {
+        // zeroed allocations this large are mapped lazily, untouched pages cost nothing
+        let bytes = Box::<[u8; SIZE]>::new_zeroed();
+
+        SparseMemory {
+            bytes: unsafe { bytes.assume_init() },
+            regions,
+        }
+    }
+}
+
+impl<const SIZE: usize> Clone for SparseMemory<SIZE> {
+    fn clone(&self) -> Self {
+        let mut memory = SparseMemory::new(self.regions);
+        memory.clone_from(self);
+        memory
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.regions = source.regions;
+        for region in self.regions {
+            self.bytes[region.clone()].copy_from_slice(&source.bytes[region.clone()]);
+        }
+    }
+}
+
+impl<const SIZE: usize> Deref for SparseMemory<SIZE> {
+    type Target = [u8; SIZE];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes
+    }
+}
+
+impl<const SIZE: usize> DerefMut for SparseMemory<SIZE> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.bytes
+    }
+}
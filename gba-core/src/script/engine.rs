@@ -1,18 +1,123 @@
 use crate::arm7tdmi::cpu::Cpu;
-use crate::arm7tdmi::decoder::Instruction;
-use crate::script::proxy::Proxy;
+use crate::arm7tdmi::decoder::{Instruction, TransferLength};
+use crate::arm7tdmi::trace::{AccessDirection, MemoryAccess};
+use crate::gba::SaveState;
+use crate::input::registers::KeyInput;
+use crate::memory::mmio::Mmio;
+use crate::script::overlay::Overlay;
+use crate::script::proxy::{CpuSlot, Proxy};
+use crate::video::Frame;
 use core::panic;
-use rhai::{AST, Dynamic, Engine, Map, Scope};
+use rhai::{AST, Array, CallFnOptions, Dynamic, Engine, EvalAltResult, FnPtr, Map, NativeCallContext, Scope};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use tracing::*;
 
+const SCANLINES: i64 = 228;
+
+// a range of memory the script watches, accesses are only reported for the CPU's own loads and stores
+struct Watchpoint {
+    address: u32,
+    size: u32,
+    read: bool,
+    write: bool,
+    callback: FnPtr,
+}
+
+impl Watchpoint {
+    fn matches(&self, access: &MemoryAccess, mirror_aware: bool) -> bool {
+        let wanted = match access.direction {
+            AccessDirection::Read => self.read,
+            AccessDirection::Write => self.write,
+        };
+        let (start, address) = match mirror_aware {
+            true => (
+                Mmio::canonical_address(self.address),
+                Mmio::canonical_address(access.addr),
+            ),
+            false => (self.address, access.addr),
+        };
+        let width = match access.width {
+            TransferLength::Byte => 1,
+            TransferLength::HalfWord => 2,
+            TransferLength::Word => 4,
+        };
+
+        wanted && address < start.wrapping_add(self.size) && start < address.wrapping_add(width)
+    }
+}
+
+// functions the script registered for video events, called once the event happened
+#[derive(Default)]
+struct Callbacks {
+    frame: Vec<FnPtr>,           // line 0
+    vblank: Vec<FnPtr>,          // line 160
+    scanline: Vec<(u16, FnPtr)>, // start of the line
+    breakpoints: Vec<(u32, FnPtr)>,
+    watchpoints: Vec<Watchpoint>,
+    input: Vec<FnPtr>, // start of the frame, before the frame callbacks
+}
+
+impl Callbacks {
+    // drops every registration of the function
+    fn remove(&mut self, name: &str) {
+        let keep = |callback: &FnPtr| callback.fn_name() != name;
+        self.frame.retain(keep);
+        self.vblank.retain(keep);
+        self.scanline.retain(|(_, callback)| keep(callback));
+        self.breakpoints.retain(|(_, callback)| keep(callback));
+        self.watchpoints.retain(|watchpoint| keep(&watchpoint.callback));
+        self.input.retain(keep);
+    }
+}
+
+// the outcome of the assertions a script made
+#[derive(Default)]
+pub struct TestReport {
+    pub passed: usize,
+    pub failures: Vec<String>,
+}
+
+impl TestReport {
+    pub fn success(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    fn check(&mut self, context: &NativeCallContext, what: String, actual: i64, expected: i64) {
+        if actual == expected {
+            self.passed += 1;
+            return;
+        }
+
+        let failure = format!(
+            "line {}: {} is 0x{:X}, expected 0x{:X}",
+            context.call_position().line().unwrap_or(0),
+            what,
+            actual,
+            expected
+        );
+        error!(target: "rhai", "Assertion failed at {}", failure);
+        self.failures.push(failure);
+    }
+}
+
 pub struct ScriptEngine {
     engine: Engine,
     breakpoint_handlers: HashMap<u32, String>,
+    mirrored_handlers: HashMap<u32, String>, // keyed by canonical address
     script: Option<AST>,
+    cpu: CpuSlot, // set while a handler runs
+    callbacks: Arc<Mutex<Callbacks>>,
+    hooked: Arc<AtomicBool>, // any breakpoints or watchpoints registered, checked after every instruction
+    overlay: Arc<Mutex<Overlay>>,
+    report: Arc<Mutex<TestReport>>,
+    state_loaded: Arc<AtomicBool>, // since the last check, the frontend can't tell otherwise
+    last_scanline: Option<u16>,
     loaded: bool,
+    pub mirror_aware: bool, // also trigger handlers on mirrors of an address
 }
 
 impl ScriptEngine {
@@ -68,71 +173,374 @@ impl ScriptEngine {
         engine.register_fn("write_u32", |proxy: &mut Proxy, address: i64, value: i64| {
             proxy.write_u32(address, value);
         });
-        engine.register_fn("read_register", |proxy: &mut Proxy, reg: &str| -> i64 {
-            proxy.read_register(reg) as i64
-        });
-        engine.register_fn("write_register", |proxy: &mut Proxy, reg: &str, value: i64| {
-            proxy.write_register(reg, value as u32);
-        });
+        engine.register_fn(
+            "read_register",
+            |proxy: &mut Proxy, reg: &str| -> Result<i64, Box<EvalAltResult>> { Ok(proxy.read_register(reg)? as i64) },
+        );
+        engine.register_fn(
+            "write_register",
+            |proxy: &mut Proxy, reg: &str, value: i64| -> Result<(), Box<EvalAltResult>> {
+                proxy.write_register(reg, value as u32)
+            },
+        );
         engine.register_fn("read_cpsr", |proxy: &mut Proxy| -> i64 { proxy.read_cpsr() as i64 });
         engine.register_fn("is_thumb", |proxy: &mut Proxy| -> bool { proxy.is_thumb() });
 
+        // the same memory accesses without going through `emu`, e.g. from functions called by a handler
+        let cpu = CpuSlot::default();
+        let slot = cpu.clone();
+        engine.register_fn("read_u8", move |address: i64| -> Result<i64, Box<EvalAltResult>> {
+            Ok(slot.proxy()?.read_u8(address) as i64)
+        });
+        let slot = cpu.clone();
+        engine.register_fn("read_u16", move |address: i64| -> Result<i64, Box<EvalAltResult>> {
+            Ok(slot.proxy()?.read_u16(address) as i64)
+        });
+        let slot = cpu.clone();
+        engine.register_fn("read_u32", move |address: i64| -> Result<i64, Box<EvalAltResult>> {
+            Ok(slot.proxy()?.read_u32(address) as i64)
+        });
+        let slot = cpu.clone();
+        engine.register_fn(
+            "write_u8",
+            move |address: i64, value: i64| -> Result<(), Box<EvalAltResult>> {
+                slot.proxy()?.write_u8(address, value);
+                Ok(())
+            },
+        );
+        let slot = cpu.clone();
+        engine.register_fn(
+            "write_u16",
+            move |address: i64, value: i64| -> Result<(), Box<EvalAltResult>> {
+                slot.proxy()?.write_u16(address, value);
+                Ok(())
+            },
+        );
+        let slot = cpu.clone();
+        engine.register_fn(
+            "write_u32",
+            move |address: i64, value: i64| -> Result<(), Box<EvalAltResult>> {
+                slot.proxy()?.write_u32(address, value);
+                Ok(())
+            },
+        );
+
+        // callbacks can be registered from the top level of the script or from setup()
+        let callbacks = Arc::new(Mutex::new(Callbacks::default()));
+        let registry = callbacks.clone();
+        engine.register_fn("on_frame", move |callback: FnPtr| {
+            registry.lock().unwrap().frame.push(callback);
+        });
+        let registry = callbacks.clone();
+        engine.register_fn("on_vblank", move |callback: FnPtr| {
+            registry.lock().unwrap().vblank.push(callback);
+        });
+        let registry = callbacks.clone();
+        engine.register_fn(
+            "on_scanline",
+            move |line: i64, callback: FnPtr| -> Result<(), Box<EvalAltResult>> {
+                if !(0..SCANLINES).contains(&line) {
+                    return Err(format!("Scanline {} is out of range, there are {} lines", line, SCANLINES).into());
+                }
+                registry.lock().unwrap().scanline.push((line as u16, callback));
+                Ok(())
+            },
+        );
+
+        // execution breakpoints and watchpoints, handlers receive the CPU state after the instruction
+        let hooked = Arc::new(AtomicBool::new(false));
+        let (registry, hooks) = (callbacks.clone(), hooked.clone());
+        engine.register_fn("on_breakpoint", move |address: i64, callback: FnPtr| {
+            registry.lock().unwrap().breakpoints.push((address as u32, callback));
+            hooks.store(true, Ordering::Relaxed);
+        });
+        let (registry, hooks) = (callbacks.clone(), hooked.clone());
+        engine.register_fn("on_watchpoint", move |address: i64, callback: FnPtr| {
+            registry.lock().unwrap().watchpoints.push(Watchpoint {
+                address: address as u32,
+                size: 1,
+                read: true,
+                write: true,
+                callback,
+            });
+            hooks.store(true, Ordering::Relaxed);
+        });
+        let (registry, hooks) = (callbacks.clone(), hooked.clone());
+        engine.register_fn(
+            "on_watchpoint",
+            move |address: i64, size: i64, kind: &str, callback: FnPtr| -> Result<(), Box<EvalAltResult>> {
+                let (read, write) = match kind {
+                    "read" => (true, false),
+                    "write" => (false, true),
+                    "access" => (true, true),
+                    _ => {
+                        return Err(
+                            format!("Unknown watchpoint kind '{}', expected read, write or access", kind).into(),
+                        );
+                    }
+                };
+                if size <= 0 {
+                    return Err(format!("Watchpoint size has to be positive, got {}", size).into());
+                }
+
+                registry.lock().unwrap().watchpoints.push(Watchpoint {
+                    address: address as u32,
+                    size: size as u32,
+                    read,
+                    write,
+                    callback,
+                });
+                hooks.store(true, Ordering::Relaxed);
+                Ok(())
+            },
+        );
+
+        // drawing on top of the game, the overlay starts out empty on every frame
+        let overlay = Arc::new(Mutex::new(Overlay::new()));
+        let canvas = overlay.clone();
+        engine.register_fn("draw_pixel", move |x: i64, y: i64, color: i64| {
+            canvas.lock().unwrap().pixel(x, y, color);
+        });
+        let canvas = overlay.clone();
+        engine.register_fn(
+            "draw_rect",
+            move |x: i64, y: i64, width: i64, height: i64, color: i64| {
+                canvas.lock().unwrap().rect(x, y, width, height, color);
+            },
+        );
+        let canvas = overlay.clone();
+        engine.register_fn(
+            "fill_rect",
+            move |x: i64, y: i64, width: i64, height: i64, color: i64| {
+                canvas.lock().unwrap().fill_rect(x, y, width, height, color);
+            },
+        );
+        let canvas = overlay.clone();
+        engine.register_fn("draw_text", move |x: i64, y: i64, text: &str, color: i64| {
+            canvas.lock().unwrap().text(x, y, text, color);
+        });
+        let canvas = overlay.clone();
+        engine.register_fn("clear_overlay", move || canvas.lock().unwrap().clear());
+
+        // input injection, keys pressed by the script stay down until it releases them
+        let slot = cpu.clone();
+        engine.register_fn("press", move |key: &str| -> Result<(), Box<EvalAltResult>> {
+            slot.proxy()?.hold_key(Self::parse_key(key)?, true);
+            Ok(())
+        });
+        let slot = cpu.clone();
+        engine.register_fn("release", move |key: &str| -> Result<(), Box<EvalAltResult>> {
+            slot.proxy()?.hold_key(Self::parse_key(key)?, false);
+            Ok(())
+        });
+        let slot = cpu.clone();
+        engine.register_fn("is_pressed", move |key: &str| -> Result<bool, Box<EvalAltResult>> {
+            Ok(slot.proxy()?.is_key_pressed(Self::parse_key(key)?))
+        });
+        let registry = callbacks.clone();
+        engine.register_fn("on_input", move |callback: FnPtr| {
+            registry.lock().unwrap().input.push(callback);
+        });
+
+        // savestates kept in memory until the script is unloaded, loading an empty slot does nothing and returns false
+        let states: Arc<Mutex<HashMap<i64, SaveState>>> = Arc::new(Mutex::new(HashMap::new()));
+        let (slot, slots) = (cpu.clone(), states.clone());
+        engine.register_fn("save_state", move |index: i64| -> Result<(), Box<EvalAltResult>> {
+            let state = slot.proxy()?.save_state();
+            slots.lock().unwrap().insert(index, state);
+            Ok(())
+        });
+        let state_loaded = Arc::new(AtomicBool::new(false));
+        let (slot, slots, loaded) = (cpu.clone(), states.clone(), state_loaded.clone());
+        engine.register_fn("load_state", move |index: i64| -> Result<bool, Box<EvalAltResult>> {
+            let mut proxy = slot.proxy()?;
+            match slots.lock().unwrap().get(&index) {
+                Some(state) => {
+                    proxy.load_state(state);
+                    loaded.store(true, Ordering::Relaxed);
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        });
+        let slots = states.clone();
+        engine.register_fn("has_state", move |index: i64| {
+            slots.lock().unwrap().contains_key(&index)
+        });
+
+        // assertions for regression tests, a failure is logged and reported but the script goes on
+        let report = Arc::new(Mutex::new(TestReport::default()));
+        let (slot, results) = (cpu.clone(), report.clone());
+        engine.register_fn(
+            "assert_mem",
+            move |context: NativeCallContext, address: i64, expected: i64| -> Result<(), Box<EvalAltResult>> {
+                let actual = slot.proxy()?.read_u8(address) as i64;
+                let what = format!("the byte at {:08X}", address);
+                results.lock().unwrap().check(&context, what, actual, expected);
+                Ok(())
+            },
+        );
+        let (slot, results) = (cpu.clone(), report.clone());
+        engine.register_fn(
+            "assert_mem",
+            move |context: NativeCallContext,
+                  address: i64,
+                  expected: i64,
+                  size: i64|
+                  -> Result<(), Box<EvalAltResult>> {
+                let proxy = slot.proxy()?;
+                let actual = match size {
+                    1 => proxy.read_u8(address) as i64,
+                    2 => proxy.read_u16(address) as i64,
+                    4 => proxy.read_u32(address) as i64,
+                    _ => return Err(format!("Memory assertions are 1, 2 or 4 bytes wide, not {}", size).into()),
+                };
+                let what = format!("the {}-byte value at {:08X}", size, address);
+                results.lock().unwrap().check(&context, what, actual, expected);
+                Ok(())
+            },
+        );
+        let (slot, results) = (cpu.clone(), report.clone());
+        engine.register_fn(
+            "assert_reg",
+            move |context: NativeCallContext, register: &str, expected: i64| -> Result<(), Box<EvalAltResult>> {
+                let actual = slot.proxy()?.read_register(register)? as i64;
+                results
+                    .lock()
+                    .unwrap()
+                    .check(&context, register.to_string(), actual, expected);
+                Ok(())
+            },
+        );
+        let slot = cpu.clone();
+        engine.register_fn("run_frames", move |frames: i64| -> Result<(), Box<EvalAltResult>> {
+            slot.proxy()?.run_frames(frames)
+        });
+
         Self {
             engine,
             breakpoint_handlers: HashMap::new(),
+            mirrored_handlers: HashMap::new(),
             script: None,
+            cpu,
+            callbacks,
+            hooked,
+            overlay,
+            report,
+            state_loaded,
+            last_scanline: None,
             loaded: false,
+            mirror_aware: true,
         }
     }
 
     pub fn load_script(&mut self, script_path: &Path) {
-        if !script_path.exists() {
-            panic!("Script file {} does not exist", script_path.display());
+        if let Err(e) = self.evaluate(script_path) {
+            panic!("{}", e);
         }
+    }
 
-        let script_content = match fs::read_to_string(&script_path) {
-            Ok(content) => content,
-            Err(e) => {
-                panic!("Failed to read script file {}: {}", script_path.display(), e);
-            }
-        };
+    // evaluates the script again after it changed, savestates are kept. a script that fails to load leaves the
+    // previous one running with its breakpoints and callbacks
+    pub fn reload_script(&mut self, script_path: &Path) -> bool {
+        let script = self.script.take();
+        let callbacks = std::mem::take(&mut *self.callbacks.lock().unwrap());
+        let hooked = self.hooked.swap(false, Ordering::Relaxed);
+        let breakpoint_handlers = std::mem::take(&mut self.breakpoint_handlers);
+        let mirrored_handlers = std::mem::take(&mut self.mirrored_handlers);
+        let loaded = std::mem::replace(&mut self.loaded, false);
 
-        let ast = match self.engine.compile(&script_content) {
-            Ok(ast) => ast,
+        match self.evaluate(script_path) {
+            Ok(()) => true,
             Err(e) => {
-                panic!("Failed to compile script {}: {}", script_path.display(), e);
+                error!(target: "rhai", "{}, keeping the previous version", e);
+                self.script = script;
+                *self.callbacks.lock().unwrap() = callbacks;
+                self.hooked.store(hooked, Ordering::Relaxed);
+                self.breakpoint_handlers = breakpoint_handlers;
+                self.mirrored_handlers = mirrored_handlers;
+                self.loaded = loaded;
+                false
             }
-        };
+        }
+    }
+
+    // runs a test script from top to bottom, it drives the emulation itself through run_frames. a script that fails
+    // to load or stops with an error counts as a failure
+    pub fn run_test(&mut self, script_path: &Path, cpu: &mut Cpu) -> TestReport {
+        self.cpu.enter(cpu);
+        let result = self.evaluate(script_path);
+        self.cpu.leave();
+
+        let mut report = std::mem::take(&mut *self.report.lock().unwrap());
+        if let Err(e) = result {
+            error!(target: "rhai", "{}", e);
+            report.failures.push(e);
+        }
+        report
+    }
+
+    fn evaluate(&mut self, script_path: &Path) -> Result<(), String> {
+        if !script_path.exists() {
+            return Err(format!("Script file {} does not exist", script_path.display()));
+        }
+
+        let script_content = fs::read_to_string(script_path)
+            .map_err(|e| format!("Failed to read script file {}: {}", script_path.display(), e))?;
+
+        let ast = self
+            .engine
+            .compile(&script_content)
+            .map_err(|e| format!("Failed to compile script {}: {}", script_path.display(), e))?;
 
         // Cache the AST for later use
         self.script = Some(ast.clone());
 
-        // Call the setup functions and grab the breakpoints
+        // Run the top level statements, they may register callbacks
+        self.engine
+            .run_ast(&ast)
+            .map_err(|e| format!("Failed to run script {}: {}", script_path.display(), e))?;
+
+        // Scripts that only use callbacks don't need a setup function
+        if !ast.iter_functions().any(|f| f.name == "setup") {
+            self.loaded = true;
+            return Ok(());
+        }
+
+        // Call the setup functions and grab the breakpoints, the top level already ran
         let mut scope = Scope::new();
-        match self.engine.call_fn::<Dynamic>(&mut scope, &ast, "setup", ()) {
-            Ok(result) => {
-                if self.parse_breakpoints(result) {
-                    info!(target: "rhai",
-                        "Loaded {} breakpoint(s) from script {}",
-                        self.breakpoint_handlers.len(),
-                        script_path.display()
-                    );
-                } else {
-                    panic!("Failed to parse breakpoints from script {}", script_path.display());
-                }
-            }
-            Err(e) => {
-                panic!("Failed to execute setup() in script {}: {}", script_path.display(), e);
-            }
+        let options = CallFnOptions::new().eval_ast(false);
+        let result = self
+            .engine
+            .call_fn_with_options::<Dynamic>(options, &mut scope, &ast, "setup", ())
+            .map_err(|e| format!("Failed to execute setup() in script {}: {}", script_path.display(), e))?;
+        if !self.parse_breakpoints(result) {
+            return Err(format!(
+                "Failed to parse breakpoints from script {}",
+                script_path.display()
+            ));
         }
+
+        info!(target: "rhai",
+            "Loaded {} breakpoint(s) from script {}",
+            self.breakpoint_handlers.len(),
+            script_path.display()
+        );
+        Ok(())
     }
 
     pub fn handle_breakpoint(&mut self, address: u32, instr_addr: u32, cpu: &mut Cpu) {
-        if !self.loaded || !self.breakpoint_handlers.contains_key(&address) {
+        if !self.loaded {
             return;
         }
 
-        let handler_name = match self.breakpoint_handlers.get(&address) {
+        let handler = if self.mirror_aware {
+            self.mirrored_handlers.get(&Mmio::canonical_address(address))
+        } else {
+            self.breakpoint_handlers.get(&address)
+        };
+
+        let handler_name = match handler {
             Some(name) => name,
             None => return,
         };
@@ -143,7 +551,13 @@ impl ScriptEngine {
             scope.push("addr", instr_addr as i64);
 
             // call the handler
-            match self.engine.call_fn::<()>(&mut scope, &ast, handler_name, ()) {
+            self.cpu.enter(cpu);
+            let options = CallFnOptions::new().eval_ast(false);
+            let result = self
+                .engine
+                .call_fn_with_options::<()>(options, &mut scope, ast, handler_name, ());
+            self.cpu.leave();
+            match result {
                 Ok(_) => {
                     debug!(target: "rhai",
                         "Executed script handler '{}' for breakpoint at 0x{:08X}",
@@ -158,6 +572,220 @@ impl ScriptEngine {
         }
     }
 
+    // called after every tick, runs the callbacks of a line once it started
+    pub fn handle_video(&mut self, cpu: &mut Cpu) {
+        let scanline = *cpu.mmio.ppu.scanline.value();
+        if !self.loaded || self.last_scanline == Some(scanline) {
+            return;
+        }
+        self.last_scanline = Some(scanline);
+        if scanline == 0 {
+            self.overlay.lock().unwrap().clear();
+            self.handle_input(cpu);
+        }
+
+        // copied out, a callback may register further callbacks
+        let handlers: Vec<FnPtr> = {
+            let callbacks = self.callbacks.lock().unwrap();
+            let frame = callbacks.frame.iter().filter(|_| scanline == 0);
+            let vblank = callbacks.vblank.iter().filter(|_| scanline == 160);
+            let lines = callbacks
+                .scanline
+                .iter()
+                .filter(|(line, _)| *line == scanline)
+                .map(|(_, callback)| callback);
+            frame.chain(vblank).chain(lines).cloned().collect()
+        };
+
+        let Some(ast) = &self.script else {
+            return;
+        };
+
+        self.cpu.enter(cpu);
+        for handler in handlers {
+            if let Err(e) = handler.call::<Dynamic>(&self.engine, ast, ()) {
+                error!(target: "rhai",
+                    "Failed to execute callback '{}' on scanline {}, unregistering it: {}",
+                    handler.fn_name(), scanline, e
+                );
+                self.unregister(&handler);
+            }
+        }
+        self.cpu.leave();
+    }
+
+    // a callback that failed once is dropped, it would fail again on every frame or instruction
+    fn unregister(&self, callback: &FnPtr) {
+        let mut callbacks = self.callbacks.lock().unwrap();
+        callbacks.remove(callback.fn_name());
+        let hooked = !callbacks.breakpoints.is_empty() || !callbacks.watchpoints.is_empty();
+        self.hooked.store(hooked, Ordering::Relaxed);
+    }
+
+    // every input hook gets the keys the one before returned, starting with the player's, and returns the keys
+    // for the frame, or nothing to keep them
+    fn handle_input(&mut self, cpu: &mut Cpu) {
+        let hooks = self.callbacks.lock().unwrap().input.clone();
+        if hooks.is_empty() {
+            return;
+        }
+        let Some(ast) = &self.script else {
+            return;
+        };
+
+        let mut keys: Array = cpu
+            .mmio
+            .joypad
+            .player_keys()
+            .iter_names()
+            .map(|(name, _)| name.into())
+            .collect();
+        self.cpu.enter(cpu);
+        for hook in hooks {
+            match hook.call::<Dynamic>(&self.engine, ast, (keys.clone(),)) {
+                Ok(result) if result.is_unit() => {}
+                Ok(result) => match result.try_cast::<Array>() {
+                    Some(result) => keys = result,
+                    None => {
+                        error!(target: "rhai",
+                            "Input hook '{}' did not return an array of keys, unregistering it", hook.fn_name()
+                        );
+                        self.unregister(&hook);
+                    }
+                },
+                Err(e) => {
+                    error!(target: "rhai", "Failed to execute input hook '{}', unregistering it: {}", hook.fn_name(), e);
+                    self.unregister(&hook);
+                }
+            }
+        }
+        self.cpu.leave();
+
+        let mut pressed = KeyInput::empty();
+        for key in keys {
+            match key
+                .into_string()
+                .map_err(|e| e.into())
+                .and_then(|key| Self::parse_key(&key))
+            {
+                Ok(key) => pressed |= key,
+                Err(e) => error!(target: "rhai", "Ignoring key returned by an input hook: {}", e),
+            }
+        }
+        cpu.mmio.joypad.replace_keys(Some(pressed));
+    }
+
+    // the names of the KEYINPUT bits, in any case
+    fn parse_key(name: &str) -> Result<KeyInput, Box<EvalAltResult>> {
+        KeyInput::from_name(&name.to_ascii_uppercase()).ok_or_else(|| {
+            format!(
+                "Unknown key '{}', expected A, B, SELECT, START, RIGHT, LEFT, UP, DOWN, R or L",
+                name
+            )
+            .into()
+        })
+    }
+
+    pub fn take_state_loaded(&self) -> bool {
+        self.state_loaded.swap(false, Ordering::Relaxed)
+    }
+
+    // draws what the callbacks of the current frame put on the overlay
+    pub fn draw_overlay(&self, frame: &mut Frame) {
+        self.overlay.lock().unwrap().composite(frame);
+    }
+
+    // called after every executed instruction with its address, watchpoints see its loads and stores
+    pub fn handle_hooks(&mut self, pc: u32, cpu: &mut Cpu) {
+        if !self.loaded || !self.hooked.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let mut handlers: Vec<(FnPtr, Option<Map>)> = Vec::new();
+        {
+            let callbacks = self.callbacks.lock().unwrap();
+            let executed = |address: u32| match self.mirror_aware {
+                true => Mmio::canonical_address(address) == Mmio::canonical_address(pc),
+                false => address == pc,
+            };
+            for (_, callback) in callbacks.breakpoints.iter().filter(|(address, _)| executed(*address)) {
+                handlers.push((callback.clone(), None));
+            }
+
+            if !callbacks.watchpoints.is_empty() {
+                match &cpu.mmio.access_log {
+                    Some(accesses) => {
+                        for access in accesses {
+                            let hits = callbacks
+                                .watchpoints
+                                .iter()
+                                .filter(|w| w.matches(access, self.mirror_aware));
+                            for watchpoint in hits {
+                                handlers.push((watchpoint.callback.clone(), Some(Self::access_state(access))));
+                            }
+                        }
+                    }
+                    // the log starts with the next instruction
+                    None => cpu.mmio.access_log = Some(Vec::new()),
+                }
+            }
+        }
+
+        if handlers.is_empty() {
+            return;
+        }
+        let Some(ast) = &self.script else {
+            return;
+        };
+
+        let state = Self::cpu_state(pc, cpu);
+        self.cpu.enter(cpu);
+        for (handler, access) in handlers {
+            let result = match access {
+                Some(access) => handler.call::<Dynamic>(&self.engine, ast, (state.clone(), access)),
+                None => handler.call::<Dynamic>(&self.engine, ast, (state.clone(),)),
+            };
+            if let Err(e) = result {
+                error!(target: "rhai",
+                    "Failed to execute hook '{}' for the instruction at 0x{:08X}, unregistering it: {}",
+                    handler.fn_name(), pc, e
+                );
+                self.unregister(&handler);
+            }
+        }
+        self.cpu.leave();
+    }
+
+    // registers as they are after the instruction, `pc` is the address of the instruction itself
+    fn cpu_state(pc: u32, cpu: &Cpu) -> Map {
+        let mut state = Map::new();
+        for (i, value) in cpu.registers.r.iter().enumerate() {
+            state.insert(format!("r{}", i).into(), (*value as i64).into());
+        }
+        state.insert("sp".into(), (cpu.registers.r[13] as i64).into());
+        state.insert("lr".into(), (cpu.registers.r[14] as i64).into());
+        state.insert("pc".into(), (pc as i64).into());
+        state.insert("cpsr".into(), (cpu.registers.cpsr.bits() as i64).into());
+        state.insert("thumb".into(), cpu.is_thumb().into());
+        state.insert("mode".into(), cpu.get_processor_mode().to_string().into());
+        state
+    }
+
+    fn access_state(access: &MemoryAccess) -> Map {
+        let size: i64 = match access.width {
+            TransferLength::Byte => 1,
+            TransferLength::HalfWord => 2,
+            TransferLength::Word => 4,
+        };
+
+        let mut state = Map::new();
+        state.insert("address".into(), (access.addr as i64).into());
+        state.insert("value".into(), (access.value as i64).into());
+        state.insert("size".into(), size.into());
+        state.insert("write".into(), (access.direction == AccessDirection::Write).into());
+        state
+    }
+
     fn parse_breakpoints(&mut self, result: Dynamic) -> bool {
         if let Some(map) = result.try_cast::<Map>() {
             for (addr_key, handler_value) in map.iter() {
@@ -179,6 +807,8 @@ impl ScriptEngine {
                 // Extract handler function name
                 if let Some(handler_name) = handler_value.clone().try_cast::<String>() {
                     self.breakpoint_handlers.insert(addr_value, handler_name.clone());
+                    self.mirrored_handlers
+                        .insert(Mmio::canonical_address(addr_value), handler_name.clone());
                     debug!(target: "rhai", "Added breakpoint at {} with handler '{}'", addr_str, handler_name);
                 } else {
                     error!(target: "rhai", "Handler for address {} is not a function name string", addr_str);
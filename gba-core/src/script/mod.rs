@@ -1,2 +1,3 @@
 pub mod engine;
+mod overlay;
 mod proxy;
@@ -0,0 +1,113 @@
+use crate::video::{Frame, Pixel, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+const GLYPH_WIDTH: i64 = 3;
+const GLYPH_HEIGHT: i64 = 5;
+
+// 3x5 glyphs for ASCII 0x20 to 0x5F, row by row with the top left pixel in bit 14, lowercase is drawn as uppercase
+const FONT: [u16; 64] = [
+    0x0000, 0x2482, 0x5A00, 0x5F7D, 0x3C9E, 0x42A1, 0x2AAB, 0x2400, 0x1491, 0x4494, 0x0AA8, 0x05D0, 0x0014, 0x01C0,
+    0x0002, 0x12A4, 0x7B6F, 0x2C97, 0x73E7, 0x72CF, 0x5BC9, 0x79CF, 0x79EF, 0x7292, 0x7BEF, 0x7BCF, 0x0410, 0x0414,
+    0x1511, 0x0E38, 0x4454, 0x72C2, 0x2BE3, 0x2BED, 0x6BAE, 0x3923, 0x6B6E, 0x79E7, 0x79E4, 0x396B, 0x5BED, 0x7497,
+    0x126A, 0x5BAD, 0x4927, 0x5FED, 0x6B6D, 0x2B6A, 0x6BA4, 0x2B7B, 0x6BAD, 0x388E, 0x7492, 0x5B6F, 0x5B6A, 0x5BFD,
+    0x5AAD, 0x5A92, 0x72A7, 0x3493, 0x4889, 0x6496, 0x2A00, 0x0007,
+];
+
+// what scripts draw on top of the game, packed 0xAARRGGBB where an alpha of 0 leaves the game pixel alone
+pub struct Overlay {
+    pixels: Vec<u32>,
+    drawn: bool, // nothing to composite otherwise
+}
+
+impl Overlay {
+    pub fn new() -> Overlay {
+        Overlay {
+            pixels: vec![0; SCREEN_WIDTH * SCREEN_HEIGHT],
+            drawn: false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        if std::mem::take(&mut self.drawn) {
+            self.pixels.fill(0);
+        }
+    }
+
+    // colors without an alpha are opaque
+    fn color(color: i64) -> u32 {
+        let color = color as u32;
+        match color >> 24 {
+            0 => 0xFF00_0000 | color,
+            _ => color,
+        }
+    }
+
+    // everything outside of the screen is cut off
+    pub fn pixel(&mut self, x: i64, y: i64, color: i64) {
+        if !(0..SCREEN_WIDTH as i64).contains(&x) || !(0..SCREEN_HEIGHT as i64).contains(&y) {
+            return;
+        }
+
+        self.pixels[y as usize * SCREEN_WIDTH + x as usize] = Self::color(color);
+        self.drawn = true;
+    }
+
+    pub fn fill_rect(&mut self, x: i64, y: i64, width: i64, height: i64, color: i64) {
+        for row in y.max(0)..(y + height).min(SCREEN_HEIGHT as i64) {
+            for column in x.max(0)..(x + width).min(SCREEN_WIDTH as i64) {
+                self.pixel(column, row, color);
+            }
+        }
+    }
+
+    pub fn rect(&mut self, x: i64, y: i64, width: i64, height: i64, color: i64) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+
+        self.fill_rect(x, y, width, 1, color);
+        self.fill_rect(x, y + height - 1, width, 1, color);
+        self.fill_rect(x, y, 1, height, color);
+        self.fill_rect(x + width - 1, y, 1, height, color);
+    }
+
+    // one pixel of spacing between glyphs and lines, unknown characters are drawn as '?'
+    pub fn text(&mut self, x: i64, y: i64, text: &str, color: i64) {
+        let (mut column, mut row) = (x, y);
+        for c in text.chars() {
+            if c == '\n' {
+                (column, row) = (x, row + GLYPH_HEIGHT + 1);
+                continue;
+            }
+
+            let index = match c.to_ascii_uppercase() {
+                c @ ' '..='_' => c as usize - 0x20,
+                _ => '?' as usize - 0x20,
+            };
+            for bit in 0..GLYPH_WIDTH * GLYPH_HEIGHT {
+                if FONT[index] & (0x4000 >> bit) != 0 {
+                    self.pixel(column + bit % GLYPH_WIDTH, row + bit / GLYPH_WIDTH, color);
+                }
+            }
+            column += GLYPH_WIDTH + 1;
+        }
+    }
+
+    pub fn composite(&self, frame: &mut Frame) {
+        if !self.drawn {
+            return;
+        }
+
+        for (pixel, &overlay) in frame.iter_mut().zip(&self.pixels) {
+            *pixel = match overlay >> 24 {
+                0 => *pixel,
+                0xFF => overlay,
+                alpha => {
+                    let eva = (alpha * 16 / 255) as u8;
+                    Pixel::unpack(overlay | 0xFF00_0000)
+                        .blend(Pixel::unpack(*pixel | 0xFF00_0000), eva, 16 - eva)
+                        .pack()
+                }
+            };
+        }
+    }
+}
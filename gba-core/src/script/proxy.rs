@@ -1,6 +1,14 @@
 use crate::arm7tdmi::cpu::Cpu;
 use crate::arm7tdmi::decoder::Register;
+use crate::arm7tdmi::error::CpuError;
+use crate::gba::SaveState;
+use crate::input::registers::KeyInput;
+use crate::memory::device::AccessKind;
+use rhai::EvalAltResult;
 use std::marker::PhantomData;
+use std::ptr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicPtr, Ordering};
 
 pub struct Proxy {
     cpu_ptr: *mut Cpu,
@@ -19,6 +27,32 @@ impl Clone for Proxy {
     }
 }
 
+// the CPU the script is currently called for, lets the global functions reach it outside of the `emu` variable
+#[derive(Clone, Default)]
+pub struct CpuSlot(Arc<AtomicPtr<Cpu>>);
+
+impl CpuSlot {
+    pub fn enter(&self, cpu: &mut Cpu) {
+        self.0.store(cpu as *mut Cpu, Ordering::Release);
+    }
+
+    pub fn leave(&self) {
+        self.0.store(ptr::null_mut(), Ordering::Release);
+    }
+
+    pub fn proxy(&self) -> Result<Proxy, Box<EvalAltResult>> {
+        let cpu_ptr = self.0.load(Ordering::Acquire);
+        if cpu_ptr.is_null() {
+            return Err("the emulator can only be accessed while a handler runs".into());
+        }
+
+        Ok(Proxy {
+            cpu_ptr,
+            _marker: PhantomData,
+        })
+    }
+}
+
 impl Proxy {
     pub fn new(cpu: &mut Cpu) -> Self {
         Self {
@@ -27,8 +61,8 @@ impl Proxy {
         }
     }
 
-    fn parse_register(register: &str) -> Register {
-        match register {
+    fn parse_register(register: &str) -> Result<Register, Box<EvalAltResult>> {
+        Ok(match register {
             "r0" => Register::R0,
             "r1" => Register::R1,
             "r2" => Register::R2,
@@ -45,20 +79,21 @@ impl Proxy {
             "sp" | "r13" => Register::R13,
             "lr" | "r14" => Register::R14,
             "pc" | "r15" => Register::R15,
-            _ => panic!("Invalid register name: {}", register),
-        }
+            _ => return Err(format!("Invalid register name: {}", register).into()),
+        })
     }
 
-    pub fn read_register(&self, reg: &str) -> u32 {
-        let register = Self::parse_register(reg);
-        unsafe { (*self.cpu_ptr).read_register(&register) }
+    pub fn read_register(&self, reg: &str) -> Result<u32, Box<EvalAltResult>> {
+        let register = Self::parse_register(reg)?;
+        Ok(unsafe { (*self.cpu_ptr).read_register(&register) })
     }
 
-    pub fn write_register(&mut self, reg: &str, value: u32) {
-        let register = Self::parse_register(reg);
+    pub fn write_register(&mut self, reg: &str, value: u32) -> Result<(), Box<EvalAltResult>> {
+        let register = Self::parse_register(reg)?;
         unsafe {
             (*self.cpu_ptr).write_register(&register, value);
         }
+        Ok(())
     }
 
     pub fn read_cpsr(&self) -> u32 {
@@ -66,30 +101,83 @@ impl Proxy {
     }
 
     pub fn read_u8(&self, address: i64) -> u8 {
-        unsafe { (*self.cpu_ptr).mmio.read(address as u32) }
+        unsafe { (*self.cpu_ptr).mmio.read::<u8>(address as u32, AccessKind::Debugger) }
     }
 
     pub fn read_u16(&self, address: i64) -> u16 {
-        unsafe { (*self.cpu_ptr).mmio.read_u16(address as u32) }
+        unsafe { (*self.cpu_ptr).mmio.read::<u16>(address as u32, AccessKind::Debugger) }
     }
 
     pub fn read_u32(&self, address: i64) -> u32 {
-        unsafe { (*self.cpu_ptr).mmio.read_u32(address as u32) }
+        unsafe { (*self.cpu_ptr).mmio.read::<u32>(address as u32, AccessKind::Debugger) }
     }
 
     pub fn write_u8(&mut self, address: i64, value: i64) {
-        unsafe { (*self.cpu_ptr).mmio.write(address as u32, value as u8) }
+        unsafe {
+            (*self.cpu_ptr)
+                .mmio
+                .write::<u8>(address as u32, value as u8, AccessKind::Debugger)
+        }
     }
 
     pub fn write_u16(&mut self, address: i64, value: i64) {
-        unsafe { (*self.cpu_ptr).mmio.write_u16(address as u32, value as u16) }
+        unsafe {
+            (*self.cpu_ptr)
+                .mmio
+                .write::<u16>(address as u32, value as u16, AccessKind::Debugger)
+        }
     }
 
     pub fn write_u32(&mut self, address: i64, value: i64) {
-        unsafe { (*self.cpu_ptr).mmio.write_u32(address as u32, value as u32) }
+        unsafe {
+            (*self.cpu_ptr)
+                .mmio
+                .write::<u32>(address as u32, value as u32, AccessKind::Debugger)
+        }
     }
 
     pub fn is_thumb(&self) -> bool {
         unsafe { (*self.cpu_ptr).is_thumb() }
     }
+
+    pub fn hold_key(&mut self, key: KeyInput, held: bool) {
+        unsafe { (*self.cpu_ptr).mmio.joypad.hold_key(key, held) }
+    }
+
+    pub fn is_key_pressed(&self, key: KeyInput) -> bool {
+        unsafe { (*self.cpu_ptr).mmio.joypad.is_key_pressed(key) }
+    }
+
+    pub fn save_state(&self) -> SaveState {
+        unsafe { SaveState::capture(&*self.cpu_ptr) }
+    }
+
+    pub fn load_state(&mut self, state: &SaveState) {
+        unsafe { state.restore(&mut *self.cpu_ptr) }
+    }
+
+    // runs to the start of the VBlank after the given number of frames, without any script callbacks.
+    // the run stops early if the CPU gets stuck on an instruction
+    pub fn run_frames(&mut self, frames: i64) -> Result<(), Box<EvalAltResult>> {
+        let cpu = unsafe { &mut *self.cpu_ptr };
+        let step = |cpu: &mut Cpu| -> Result<(), Box<EvalAltResult>> {
+            // the pipeline is two instructions ahead
+            let pc = cpu.registers.r[15].wrapping_sub(if cpu.is_thumb() { 4 } else { 8 });
+            if let Err(e @ CpuError::FailedToDecode) = cpu.tick() {
+                return Err(format!("CPU stopped at {:08X}: {}", pc, e).into());
+            }
+            cpu.mmio.tick_components();
+            Ok(())
+        };
+
+        for _ in 0..frames {
+            while cpu.mmio.ppu.scanline.0 == 160 {
+                step(cpu)?;
+            }
+            while cpu.mmio.ppu.scanline.0 != 160 {
+                step(cpu)?;
+            }
+        }
+        Ok(())
+    }
 }
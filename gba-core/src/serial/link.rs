@@ -0,0 +1,58 @@
+// whatever is plugged into the link port, the GBA is either the one driving the clock (master) or waits for the
+// device to drive it (slave)
+pub trait LinkDevice: Send {
+    // normal mode with the internal clock, receives the `bits` (8 or 32) the GBA shifts out and returns the ones
+    // it shifts in at the same time
+    fn transfer(&mut self, data: u32, bits: u32) -> u32;
+
+    // normal mode with the external clock, polled while the transfer waits for the device, returns the bits that
+    // were shifted in once the device has clocked a transfer
+    fn transfer_external(&mut self, _data: u32, _bits: u32) -> Option<u32> {
+        None
+    }
+
+    // multiplayer mode, the ID this GBA was given among the connected ones, 0 is the parent that starts the
+    // transfers. none while the cable is not connected
+    fn multiplayer_id(&mut self) -> Option<u8> {
+        None
+    }
+
+    // multiplayer mode, the parent sends its SIOMLT_SEND and gets back SIOMULTI0-3, 0xFFFF for missing children
+    fn transfer_multiplayer(&mut self, data: u16) -> [u16; 4] {
+        [data, 0xFFFF, 0xFFFF, 0xFFFF]
+    }
+
+    // multiplayer mode, polled on every GBA whether or not it is transferring. children answer a transfer of the
+    // parent with `data`, their SIOMLT_SEND, and get back SIOMULTI0-3 once the transfer is done
+    fn poll_multiplayer(&mut self, _data: u16) -> Option<[u16; 4]> {
+        None
+    }
+
+    // UART mode, a byte the GBA sent
+    fn uart_send(&mut self, _byte: u8) {}
+
+    // UART mode, polled while the GBA has room for another byte (its RTS is asserted), returns the next byte the
+    // device sends
+    fn uart_receive(&mut self) -> Option<u8> {
+        None
+    }
+
+    // UART mode, the device's RTS, the GBA reads it as CTS and only waits for it if the CTS flag is set
+    fn uart_cts(&mut self) -> bool {
+        true
+    }
+
+    // the level of the device's SO, which the GBA reads as SI. low tells the other side it is ready
+    fn so(&mut self) -> bool {
+        true
+    }
+}
+
+// SO wired to SI, every transfer reads back what was sent
+pub struct Loopback;
+
+impl LinkDevice for Loopback {
+    fn transfer(&mut self, data: u32, _bits: u32) -> u32 {
+        data
+    }
+}
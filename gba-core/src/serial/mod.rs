@@ -0,0 +1,3 @@
+pub mod link;
+pub mod registers;
+pub mod sio;
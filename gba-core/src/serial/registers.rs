@@ -0,0 +1,44 @@
+use bitflags::bitflags;
+
+bitflags! {
+    // SIOCNT, the low byte means something else in every mode
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct SioControl: u16 {
+        const INTERNAL_CLOCK = 1 << 0;  // the GBA drives the clock, otherwise the other side does
+        const FAST_CLOCK     = 1 << 1;  // 2 MHz instead of 256 KHz, only for the internal clock
+        const SI             = 1 << 2;  // read only, level of the other side's SO
+        const SO_INACTIVE    = 1 << 3;  // level of SO while no transfer is running
+        const MULTI_BAUD     = 0b11;    // multiplayer: 9600, 38400, 57600 or 115200 bps
+        const MULTI_CHILD    = 1 << 2;  // multiplayer: read only, SI terminal, set on the children
+        const MULTI_READY    = 1 << 3;  // multiplayer: read only, SD terminal, all GBAs are connected
+        const MULTI_ID       = 0b11 << 4; // multiplayer: read only, 0 is the parent
+        const MULTI_ERROR    = 1 << 6;  // multiplayer: read only
+        const UART_BAUD      = 0b11;    // UART: 9600, 38400, 57600 or 115200 bps
+        const UART_CTS       = 1 << 2;  // UART: only send while the other side is ready to receive
+        const UART_ODD_PARITY = 1 << 3; // UART
+        const UART_SEND_FULL = 1 << 4;  // UART: read only
+        const UART_RECEIVE_EMPTY = 1 << 5; // UART: read only
+        const UART_ERROR     = 1 << 6;  // UART: read only
+        const START          = 1 << 7;  // set to start a transfer, cleared once it completes
+        const UART_8BIT      = 1 << 7;  // UART: 8 instead of 7 data bits
+        const UART_FIFO      = 1 << 8;  // UART: 4 byte FIFOs instead of a single byte, cleared to empty them
+        const UART_PARITY    = 1 << 9;  // UART
+        const UART_SEND_ENABLE = 1 << 10; // UART
+        const UART_RECEIVE_ENABLE = 1 << 11; // UART
+        const LENGTH_32      = 1 << 12; // 32 instead of 8 bits per transfer
+        const MULTIPLAYER    = 1 << 13; // together with LENGTH_32 this selects UART mode
+        const IRQ_ENABLE     = 1 << 14;
+        const _ = !0;
+    }
+}
+
+// selected by RCNT bits 14-15 and SIOCNT bits 12-13
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SioMode {
+    Normal8,
+    Normal32,
+    Multiplayer,
+    Uart,
+    GeneralPurpose,
+    JoyBus,
+}
@@ -0,0 +1,361 @@
+use super::link::LinkDevice;
+use super::registers::{SioControl, SioMode};
+use crate::audio::apu::{CPU_CLOCK, tick_cycles};
+use crate::memory::device::Addressable;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tracing::debug;
+
+const CYCLES_PER_BIT_SLOW: u32 = 64; // 256 KHz
+const CYCLES_PER_BIT_FAST: u32 = 8; // 2 MHz
+const EXTERNAL_POLL_CYCLES: u32 = 1024; // how often a device driving the clock is asked whether it is done
+const BAUD_RATES: [u32; 4] = [9600, 38400, 57600, 115200]; // multiplayer and UART mode
+const UART_FIFO_SIZE: usize = 4;
+const MULTIPLAYER_BITS: u32 = 4 * 18; // every GBA sends a start bit, 16 data bits and a stop bit, timed as if all 4 were
+const RCNT_MASK: u16 = 0xC1FF;
+
+// https://problemkaputt.de/gbatek.htm#gbacommunicationports
+#[derive(Clone)]
+pub struct Sio {
+    pub control: SioControl,
+    pub rcnt: u16,
+    data: [u8; 8],        // SIODATA32 in normal mode, SIOMULTI0-3 in multiplayer mode
+    send: u16,            // SIODATA8 in normal mode, SIOMLT_SEND in multiplayer mode
+    cycles_left: u32,     // until the running transfer completes or the device is polled again
+    starting: bool, // the start bit was set, SIOCNT is written a byte at a time so the rest is read on the next cycle
+    poll_cycles: u32, // until the link is polled for transfers of another GBA or received UART bytes
+    clock_remainder: u32, // fraction of a cycle left over from converting ticks
+    uart_send: VecDeque<u8>,
+    uart_receive: VecDeque<u8>,
+    pub link: Option<Arc<Mutex<dyn LinkDevice>>>, // nothing plugged in if none
+}
+
+impl Sio {
+    pub fn new() -> Sio {
+        Sio {
+            control: SioControl::empty(),
+            rcnt: 0,
+            data: [0; 8],
+            send: 0,
+            cycles_left: 0,
+            starting: false,
+            poll_cycles: EXTERNAL_POLL_CYCLES,
+            clock_remainder: 0,
+            uart_send: VecDeque::new(),
+            uart_receive: VecDeque::new(),
+            link: None,
+        }
+    }
+
+    pub fn mode(&self) -> SioMode {
+        match (self.rcnt >> 14, (self.control.bits() >> 12) & 3) {
+            (2, _) => SioMode::GeneralPurpose,
+            (3, _) => SioMode::JoyBus,
+            (_, 0) => SioMode::Normal8,
+            (_, 1) => SioMode::Normal32,
+            (_, 2) => SioMode::Multiplayer,
+            _ => SioMode::Uart,
+        }
+    }
+
+    fn bits(&self) -> u32 {
+        if self.mode() == SioMode::Normal32 { 32 } else { 8 }
+    }
+
+    fn outgoing(&self) -> u32 {
+        match self.mode() {
+            SioMode::Normal32 => u32::from_le_bytes(self.data[0..4].try_into().unwrap()),
+            _ => self.send as u8 as u32,
+        }
+    }
+
+    fn start(&mut self) {
+        match self.mode() {
+            SioMode::Normal8 | SioMode::Normal32 if self.control.contains(SioControl::INTERNAL_CLOCK) => {
+                let cycles_per_bit = if self.control.contains(SioControl::FAST_CLOCK) {
+                    CYCLES_PER_BIT_FAST
+                } else {
+                    CYCLES_PER_BIT_SLOW
+                };
+                self.cycles_left = self.bits() * cycles_per_bit;
+            }
+            // the device is asked right away, it may have been waiting for the GBA to get ready
+            SioMode::Normal8 | SioMode::Normal32 => self.cycles_left = 1,
+            // only the parent can start a transfer
+            SioMode::Multiplayer if self.multiplayer_id() == 0 => {
+                let baud_rate = BAUD_RATES[(self.control & SioControl::MULTI_BAUD).bits() as usize];
+                self.cycles_left = (CPU_CLOCK as u64 * MULTIPLAYER_BITS as u64 / baud_rate as u64) as u32;
+            }
+            SioMode::Multiplayer => self.control.remove(SioControl::START),
+            mode => debug!(target: "sio", "Transfer started in unsupported mode {:?}", mode),
+        }
+    }
+
+    // returns true once a transfer completes with the IRQ enabled, transfers take as long as on the real clock
+    pub fn tick(&mut self) -> bool {
+        let cycles = tick_cycles(&mut self.clock_remainder);
+
+        if std::mem::take(&mut self.starting) {
+            self.start();
+        }
+        if self.link.is_some() && self.mode() == SioMode::Multiplayer && self.poll_multiplayer(cycles) {
+            return true;
+        }
+        if self.mode() == SioMode::Uart {
+            return self.tick_uart(cycles);
+        }
+        if !self.control.contains(SioControl::START) || self.cycles_left == 0 {
+            return false;
+        }
+
+        self.cycles_left = self.cycles_left.saturating_sub(cycles);
+        if self.cycles_left > 0 {
+            return false;
+        }
+
+        if self.mode() == SioMode::Multiplayer {
+            let words = match &self.link {
+                Some(link) => link.lock().unwrap().transfer_multiplayer(self.send),
+                None => [self.send, 0xFFFF, 0xFFFF, 0xFFFF],
+            };
+            return self.complete_multiplayer(words);
+        }
+
+        let (data, bits) = (self.outgoing(), self.bits());
+        let incoming = if self.control.contains(SioControl::INTERNAL_CLOCK) {
+            // SI is pulled up, without a device only ones are shifted in
+            Some(match &self.link {
+                Some(link) => link.lock().unwrap().transfer(data, bits),
+                None => u32::MAX,
+            })
+        } else {
+            self.cycles_left = EXTERNAL_POLL_CYCLES;
+            self.link
+                .as_ref()
+                .and_then(|link| link.lock().unwrap().transfer_external(data, bits))
+        };
+
+        match incoming {
+            Some(incoming) => self.complete(incoming),
+            None => false,
+        }
+    }
+
+    fn complete(&mut self, incoming: u32) -> bool {
+        debug!(target: "sio", "Transfer completed, sent {:08X} and received {:08X}", self.outgoing(), incoming);
+
+        if self.mode() == SioMode::Normal32 {
+            self.data[0..4].copy_from_slice(&incoming.to_le_bytes());
+        } else {
+            self.send = (self.send & 0xFF00) | incoming as u8 as u16;
+        }
+        self.control.remove(SioControl::START);
+        self.cycles_left = 0;
+
+        self.control.contains(SioControl::IRQ_ENABLE)
+    }
+
+    // the children complete a transfer once the parent's is done
+    fn poll_multiplayer(&mut self, cycles: u32) -> bool {
+        self.poll_cycles = self.poll_cycles.saturating_sub(cycles);
+        if self.poll_cycles > 0 {
+            return false;
+        }
+        self.poll_cycles = EXTERNAL_POLL_CYCLES;
+
+        let words = self.link.as_ref().unwrap().lock().unwrap().poll_multiplayer(self.send);
+        match words {
+            Some(words) => self.complete_multiplayer(words),
+            None => false,
+        }
+    }
+
+    fn complete_multiplayer(&mut self, words: [u16; 4]) -> bool {
+        debug!(target: "sio", "Multiplayer transfer completed: {:04X?}", words);
+
+        for (i, word) in words.iter().enumerate() {
+            self.data[i * 2..i * 2 + 2].copy_from_slice(&word.to_le_bytes());
+        }
+        self.control.remove(SioControl::START);
+        self.cycles_left = 0;
+
+        self.control.contains(SioControl::IRQ_ENABLE)
+    }
+
+    // without the FIFO only a single byte is buffered in either direction
+    fn uart_capacity(&self) -> usize {
+        if self.control.contains(SioControl::UART_FIFO) {
+            UART_FIFO_SIZE
+        } else {
+            1
+        }
+    }
+
+    fn uart_byte_cycles(&self) -> u32 {
+        let data_bits = if self.control.contains(SioControl::UART_8BIT) {
+            8
+        } else {
+            7
+        };
+        let bits = 1 + data_bits + self.control.contains(SioControl::UART_PARITY) as u32 + 1;
+        CPU_CLOCK * bits / BAUD_RATES[(self.control & SioControl::UART_BAUD).bits() as usize]
+    }
+
+    fn uart_data_mask(&self) -> u8 {
+        if self.control.contains(SioControl::UART_8BIT) {
+            0xFF
+        } else {
+            0x7F
+        }
+    }
+
+    // bytes go out one after another, the IRQ is raised once the last one is sent or the receive buffer is full.
+    // parity is neither generated nor checked
+    fn tick_uart(&mut self, cycles: u32) -> bool {
+        let mut irq = false;
+
+        if self.control.contains(SioControl::UART_SEND_ENABLE) && !self.uart_send.is_empty() {
+            if self.cycles_left == 0 {
+                let cts = !self.control.contains(SioControl::UART_CTS)
+                    || self.link.as_ref().is_none_or(|link| link.lock().unwrap().uart_cts());
+                if cts {
+                    self.cycles_left = self.uart_byte_cycles();
+                }
+            } else {
+                self.cycles_left = self.cycles_left.saturating_sub(cycles);
+                if self.cycles_left == 0 {
+                    let byte = self.uart_send.pop_front().unwrap();
+                    if let Some(link) = &self.link {
+                        link.lock().unwrap().uart_send(byte);
+                    }
+                    irq |= self.uart_send.is_empty();
+                }
+            }
+        }
+
+        if self.control.contains(SioControl::UART_RECEIVE_ENABLE)
+            && self.uart_receive.len() < self.uart_capacity()
+            && let Some(link) = &self.link
+        {
+            self.poll_cycles = self.poll_cycles.saturating_sub(cycles);
+            if self.poll_cycles == 0 {
+                self.poll_cycles = EXTERNAL_POLL_CYCLES;
+                if let Some(byte) = link.lock().unwrap().uart_receive() {
+                    self.uart_receive.push_back(byte & self.uart_data_mask());
+                    irq |= self.uart_receive.len() == self.uart_capacity();
+                }
+            }
+        }
+
+        irq && self.control.contains(SioControl::IRQ_ENABLE)
+    }
+
+    // SIODATA8 in UART mode, reading takes the byte out of the receive buffer
+    pub fn read_uart(&mut self) -> u8 {
+        self.uart_receive.pop_front().unwrap_or(0)
+    }
+
+    // the byte the next read of SIODATA8 returns, without taking it out
+    pub fn peek_uart(&self) -> u8 {
+        self.uart_receive.front().copied().unwrap_or(0)
+    }
+
+    fn write_uart(&mut self, value: u8) {
+        if self.control.contains(SioControl::UART_SEND_ENABLE) && self.uart_send.len() < self.uart_capacity() {
+            self.uart_send.push_back(value & self.uart_data_mask());
+        }
+    }
+
+    fn connected_id(&self) -> Option<u8> {
+        self.link
+            .as_ref()
+            .and_then(|link| link.lock().unwrap().multiplayer_id())
+    }
+
+    // a GBA without a cable is its own parent
+    fn multiplayer_id(&self) -> u8 {
+        self.connected_id().unwrap_or(0)
+    }
+
+    fn read_control(&self) -> u16 {
+        let mut control = self.control;
+        if self.mode() == SioMode::Multiplayer {
+            let id = self.connected_id();
+            control.set(SioControl::MULTI_CHILD, id.is_some_and(|id| id != 0));
+            control.set(SioControl::MULTI_READY, id.is_some());
+            control |= SioControl::from_bits_retain((id.unwrap_or(0) as u16) << 4);
+        } else if self.mode() == SioMode::Uart {
+            control.set(SioControl::UART_SEND_FULL, self.uart_send.len() >= self.uart_capacity());
+            control.set(SioControl::UART_RECEIVE_EMPTY, self.uart_receive.is_empty());
+        } else {
+            let si = self.link.as_ref().is_none_or(|link| link.lock().unwrap().so());
+            control.set(SioControl::SI, si);
+        }
+        control.bits()
+    }
+
+    fn write_control(&mut self, value: u16) {
+        let was_active = self.control.contains(SioControl::START);
+        let had_fifo = self.control.contains(SioControl::UART_FIFO);
+        let read_only = match (value >> 12) & 3 {
+            2 => SioControl::MULTI_CHILD | SioControl::MULTI_READY | SioControl::MULTI_ID | SioControl::MULTI_ERROR,
+            3 => SioControl::UART_SEND_FULL | SioControl::UART_RECEIVE_EMPTY | SioControl::UART_ERROR,
+            _ => SioControl::SI,
+        };
+        self.control = SioControl::from_bits_retain(value) - read_only;
+
+        // there is no start bit in UART mode, bit 7 selects the data length
+        if self.mode() == SioMode::Uart {
+            if had_fifo && !self.control.contains(SioControl::UART_FIFO) {
+                self.uart_send.clear();
+                self.uart_receive.clear();
+                self.cycles_left = 0;
+            }
+            return;
+        }
+
+        if !was_active && self.control.contains(SioControl::START) {
+            self.starting = true;
+        } else if !self.control.contains(SioControl::START) {
+            // clearing the start bit aborts the transfer
+            self.cycles_left = 0;
+            self.starting = false;
+        }
+    }
+}
+
+impl Default for Sio {
+    fn default() -> Self {
+        Sio::new()
+    }
+}
+
+impl Addressable for Sio {
+    fn read_byte(&self, addr: u32) -> u8 {
+        match addr {
+            0x04000120..=0x04000127 => self.data[(addr - 0x04000120) as usize],
+            0x04000128 => self.read_control() as u8,
+            0x04000129 => (self.read_control() >> 8) as u8,
+            0x0400012A if self.mode() == SioMode::Uart => self.peek_uart(),
+            0x0400012A => self.send as u8,
+            0x0400012B => (self.send >> 8) as u8,
+            0x04000134 => self.rcnt as u8,
+            0x04000135 => (self.rcnt >> 8) as u8,
+            _ => unreachable!(),
+        }
+    }
+
+    fn write_byte(&mut self, addr: u32, value: u8) {
+        match addr {
+            0x04000120..=0x04000127 => self.data[(addr - 0x04000120) as usize] = value,
+            0x04000128 => self.write_control((self.control.bits() & 0xFF00) | value as u16),
+            0x04000129 => self.write_control((self.control.bits() & 0x00FF) | (value as u16) << 8),
+            0x0400012A if self.mode() == SioMode::Uart => self.write_uart(value),
+            0x0400012A => self.send = (self.send & 0xFF00) | value as u16,
+            0x0400012B => self.send = (self.send & 0x00FF) | (value as u16) << 8,
+            0x04000134 => self.rcnt = ((self.rcnt & 0xFF00) | value as u16) & RCNT_MASK,
+            0x04000135 => self.rcnt = ((self.rcnt & 0x00FF) | (value as u16) << 8) & RCNT_MASK,
+            _ => unreachable!(),
+        }
+    }
+}
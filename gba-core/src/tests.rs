@@ -1,10 +1,20 @@
 #[cfg(test)]
 mod tests {
+    use crate::arm7tdmi::bios::software_interrupt;
     use crate::arm7tdmi::cpu::Cpu;
     use crate::arm7tdmi::decoder::{Instruction, Register};
+    use crate::arm7tdmi::error::CpuError;
     use crate::arm7tdmi::mode::ProcessorMode;
+    use crate::arm7tdmi::timer::Timer;
+    use crate::cartridge::patch::{PatchError, apply_patch};
     use crate::cartridge::storage::BackupType;
+    use crate::memory::device::AccessKind;
+    use crate::memory::io_journal::IoJournal;
     use crate::memory::mmio::Mmio;
+    use crate::serial::link::{LinkDevice, Loopback};
+    use crate::video::Pixel;
+    use crate::video::ppu::Ppu;
+    use std::sync::{Arc, Mutex};
 
     const BIOS: &[u8] = include_bytes!("../../external/gba_bios.bin");
     const ARM_TEST: &[u8] = include_bytes!("../../external/gba-tests/arm/arm.gba");
@@ -46,7 +56,7 @@ mod tests {
                         println!(
                             "{:08X}: {:032b} -> {}",
                             faulting_pc,
-                            cpu.mmio.read_u32(*faulting_pc),
+                            cpu.mmio.read::<u32>(*faulting_pc, AccessKind::Debugger),
                             faulting_instr
                         );
                     }
@@ -62,4 +72,575 @@ mod tests {
 
         assert!(true, "All tests passed");
     }
+
+    #[test]
+    fn video_memory_byte_writes() {
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+
+        // palette RAM and BG VRAM duplicate the byte into the halfword
+        mmio.write::<u8>(0x05000003, 0x12, AccessKind::Data);
+        assert_eq!(mmio.read::<u16>(0x05000002, AccessKind::Data), 0x1212);
+        mmio.write::<u8>(0x0600FFFE, 0x34, AccessKind::Data);
+        assert_eq!(mmio.read::<u16>(0x0600FFFE, AccessKind::Data), 0x3434);
+
+        // OBJ VRAM and OAM ignore them
+        mmio.write::<u8>(0x06010000, 0x56, AccessKind::Data);
+        assert_eq!(mmio.read::<u16>(0x06010000, AccessKind::Data), 0x0000);
+        mmio.write::<u8>(0x07000000, 0x78, AccessKind::Data);
+        assert_eq!(mmio.read::<u16>(0x07000000, AccessKind::Data), 0x0000);
+
+        // in the bitmap modes the BG area extends to 0x06013FFF
+        mmio.write::<u16>(0x04000000, 0x0003, AccessKind::Data);
+        mmio.write::<u8>(0x06013FFF, 0x9A, AccessKind::Data);
+        assert_eq!(mmio.read::<u16>(0x06013FFE, AccessKind::Data), 0x9A9A);
+        mmio.write::<u8>(0x06014000, 0xBC, AccessKind::Data);
+        assert_eq!(mmio.read::<u16>(0x06014000, AccessKind::Data), 0x0000);
+
+        // wider writes are unaffected
+        mmio.write::<u16>(0x07000000, 0xDEF0, AccessKind::Data);
+        assert_eq!(mmio.read::<u16>(0x07000000, AccessKind::Data), 0xDEF0);
+    }
+
+    #[test]
+    fn vcount_match() {
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+
+        // match line 80 with the VCOUNT interrupt enabled, the status bits can't be written
+        mmio.write::<u16>(0x04000004, 0x5027, AccessKind::Data);
+        assert_eq!(mmio.read::<u16>(0x04000004, AccessKind::Data), 0x5020);
+
+        while *mmio.ppu.scanline.value() != 80 {
+            mmio.tick_components();
+        }
+        assert_ne!(mmio.read::<u16>(0x04000004, AccessKind::Data) & 0b100, 0);
+        assert_ne!(mmio.read::<u16>(0x04000202, AccessKind::Data) & 0b100, 0);
+
+        // the flag is cleared on the next line
+        while *mmio.ppu.scanline.value() != 81 {
+            mmio.tick_components();
+        }
+        assert_eq!(mmio.read::<u16>(0x04000004, AccessKind::Data) & 0b100, 0);
+    }
+
+    #[test]
+    fn layer_priority() {
+        let backdrop = Pixel::Rgb(0, 0, 0);
+        let (red, green, blue) = (Pixel::Rgb(255, 0, 0), Pixel::Rgb(0, 255, 0), Pixel::Rgb(0, 0, 255));
+
+        // nothing visible, only the backdrop
+        assert_eq!(
+            Ppu::front_layers(&[None; 5], backdrop),
+            [(5, backdrop), (5, Pixel::Transparent)]
+        );
+
+        // OBJ is in front of a BG with the same priority
+        let layers = [Some((1, red)), None, None, None, Some((1, green))];
+        assert_eq!(Ppu::front_layers(&layers, backdrop), [(4, green), (0, red)]);
+
+        // among BGs with the same priority the lower one is in front
+        let layers = [None, None, Some((2, red)), Some((2, green)), None];
+        assert_eq!(Ppu::front_layers(&layers, backdrop), [(2, red), (3, green)]);
+
+        // a BG with a lower priority value is in front of OBJ, regardless of its index
+        let layers = [None, None, None, Some((0, blue)), Some((1, green))];
+        assert_eq!(Ppu::front_layers(&layers, backdrop), [(3, blue), (4, green)]);
+
+        // a single layer is blended with the backdrop
+        let layers = [None, Some((3, red)), None, None, None];
+        assert_eq!(Ppu::front_layers(&layers, backdrop), [(1, red), (5, backdrop)]);
+    }
+
+    #[test]
+    fn sprite_priority() {
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+        let render = |mmio: &mut Mmio| {
+            while *mmio.ppu.scanline.value() != 0 {
+                mmio.tick_components();
+            }
+            while *mmio.ppu.scanline.value() != 1 {
+                mmio.tick_components();
+            }
+            mmio.ppu.get_frame()[0]
+        };
+
+        // mode 0 with only OBJ, two 8x8 sprites at 0,0 filled with OBJ colors 1 and 2
+        mmio.write::<u16>(0x04000000, 0x1040, AccessKind::Data);
+        mmio.write::<u16>(0x05000202, 0x001F, AccessKind::Data);
+        mmio.write::<u16>(0x05000204, 0x03E0, AccessKind::Data);
+        for i in 0..16 {
+            mmio.write::<u16>(0x06010000 + i * 2, 0x1111, AccessKind::Data);
+            mmio.write::<u16>(0x06010020 + i * 2, 0x2222, AccessKind::Data);
+        }
+        for obj in 2..128 {
+            mmio.write::<u16>(0x07000000 + obj * 8, 0x0200, AccessKind::Data); // disabled
+        }
+        mmio.write::<u16>(0x07000004, 0x0800, AccessKind::Data); // tile 0, priority 2
+        mmio.write::<u16>(0x0700000C, 0x0401, AccessKind::Data); // tile 1, priority 1
+        let palette = mmio.ppu.fetch_palette();
+
+        // the later OAM entry has the lower priority value
+        assert_eq!(render(&mut mmio), palette[258].pack());
+
+        // on ties the lower OAM entry wins
+        mmio.write::<u16>(0x0700000C, 0x0801, AccessKind::Data);
+        assert_eq!(render(&mut mmio), palette[257].pack());
+    }
+
+    #[test]
+    fn sio_normal_transfer() {
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+        let transfer = |mmio: &mut Mmio, data: u8| {
+            // 8-bit normal mode, internal 256 KHz clock with IRQ, 64 cycles per bit
+            mmio.write::<u16>(0x0400012A, data as u16, AccessKind::Data);
+            mmio.write::<u16>(0x04000128, 0x4081, AccessKind::Data);
+            let mut ticks = 0;
+            while mmio.read::<u16>(0x04000128, AccessKind::Data) & 0x80 != 0 {
+                mmio.tick_components();
+                ticks += 1;
+            }
+            // 512 cycles are a 32768th of a second, about 100 ticks
+            assert!((100..=102).contains(&ticks), "{}", ticks);
+            assert_ne!(mmio.read::<u16>(0x04000202, AccessKind::Data) & 0x80, 0);
+            mmio.write::<u16>(0x04000202, 0x80, AccessKind::Data);
+            mmio.read::<u8>(0x0400012A, AccessKind::Data)
+        };
+
+        // SI is pulled up while nothing is plugged in
+        assert_eq!(transfer(&mut mmio, 0x5A), 0xFF);
+
+        mmio.sio.link = Some(Arc::new(Mutex::new(Loopback)));
+        assert_eq!(transfer(&mut mmio, 0x5A), 0x5A);
+    }
+
+    #[test]
+    fn sio_uart_reads_without_side_effects() {
+        struct Sender(Vec<u8>);
+
+        impl LinkDevice for Sender {
+            fn transfer(&mut self, data: u32, _bits: u32) -> u32 {
+                data
+            }
+
+            fn uart_receive(&mut self) -> Option<u8> {
+                self.0.pop()
+            }
+        }
+
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+        mmio.sio.link = Some(Arc::new(Mutex::new(Sender(vec![0x42, 0x41]))));
+        // UART mode with 8 data bits, the 4 byte FIFO and the receiver enabled
+        mmio.write::<u16>(0x04000128, 0x3980, AccessKind::Data);
+        for _ in 0..1000 {
+            mmio.tick_components();
+        }
+
+        mmio.io_journal = Some(IoJournal::new(16));
+        assert_eq!(mmio.read::<u8>(0x0400012A, AccessKind::Debugger), 0x41);
+        assert_eq!(mmio.read::<u8>(0x0400012A, AccessKind::Debugger), 0x41);
+        mmio.write::<u8>(0x0400012A, 0x5A, AccessKind::Data);
+        assert_eq!(mmio.io_journal.as_ref().unwrap().events().next().unwrap().old, 0x41);
+
+        // both bytes are still there for the CPU
+        assert_eq!(mmio.read::<u8>(0x0400012A, AccessKind::Data), 0x41);
+        assert_eq!(mmio.read::<u8>(0x0400012A, AccessKind::Data), 0x42);
+        assert_ne!(mmio.read::<u16>(0x04000128, AccessKind::Data) & 0x20, 0); // receive FIFO empty
+    }
+
+    #[test]
+    fn sav_round_trip() {
+        let chips = [
+            (BackupType::Sram, 0x8000),
+            (BackupType::Flash512k, 0x10000),
+            (BackupType::Flash1m, 0x20000),
+            (BackupType::Eeprom4k, 0x200),
+            (BackupType::Eeprom64k, 0x2000),
+        ];
+        for (backup_type, size) in chips {
+            let mut chip = Mmio::new(backup_type, false).storage_chip;
+            assert_eq!(chip.export_sav().len(), size, "{}", backup_type);
+
+            let data: Vec<u8> = (0..size).map(|i| (i ^ (i >> 8)) as u8).collect();
+            chip.import_sav(&data).unwrap();
+            assert_eq!(chip.export_sav(), data, "{}", backup_type);
+            assert!(chip.import_sav(&data[..size - 1]).is_err(), "{}", backup_type);
+        }
+
+        // EEPROM .sav files hold every 64 bit block msb first, the chip size is taken from the file
+        let mut chip = Mmio::new(BackupType::Eeprom4k, false).storage_chip;
+        chip.import_sav(&[0, 1, 2, 3, 4, 5, 6, 7].repeat(0x400)).unwrap();
+        assert_eq!(chip.backup_type(), BackupType::Eeprom64k);
+        assert_eq!(chip.aggregate_storage()[..8], [7, 6, 5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn timers_run_at_the_cpu_clock() {
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+
+        // timer 0 overflows every 256 cycles, timer 1 counts its overflows, timer 2 uses the 1024 cycle prescaler
+        mmio.write::<u16>(0x04000100, 0xFF00, AccessKind::Data);
+        mmio.write::<u16>(0x04000106, 0x0084, AccessKind::Data);
+        mmio.write::<u16>(0x0400010A, 0x0083, AccessKind::Data);
+        mmio.write::<u16>(0x04000102, 0x0080, AccessKind::Data);
+
+        // the ticks of a frame span a 60th of a second on the 16 MHz clock
+        for _ in 0..240 * 228 {
+            mmio.tick_components();
+        }
+        let cycles: u32 = 16 * 1024 * 1024 / 60;
+        assert_eq!(
+            mmio.read::<u16>(0x04000100, AccessKind::Data) as u32,
+            0xFF00 + cycles % 256
+        );
+        assert_eq!(mmio.read::<u16>(0x04000104, AccessKind::Data) as u32, cycles / 256);
+        assert_eq!(mmio.read::<u16>(0x04000108, AccessKind::Data) as u32, cycles / 1024);
+    }
+
+    #[test]
+    fn timer_counts_many_overflows_at_once() {
+        let mut timer = Timer::new();
+        timer.reload.set(0xFFF0);
+        timer.counter.set(0xFFFE);
+
+        // 2 steps to the first overflow, then one every 16 steps from the reload value
+        assert_eq!(timer.count(1), 0);
+        assert_eq!(timer.count(1 + 16 * 1000 + 5), 1001);
+        assert_eq!(*timer.counter.value(), 0xFFF5);
+
+        // a reload of 0xFFFF overflows on every step
+        timer.reload.set(0xFFFF);
+        assert_eq!(timer.count(11 + 100_000), 100_001);
+        assert_eq!(*timer.counter.value(), 0xFFFF);
+    }
+
+    #[test]
+    fn wait_states_advance_the_components() {
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+        mmio.write::<u16>(0x04000102, 0x0080, AccessKind::Data); // timer 0 counts every cycle
+        mmio.tick_components();
+        let timer = |mmio: &mut Mmio| mmio.read::<u16>(0x04000100, AccessKind::Debugger) as i32;
+
+        // the default WAITCNT takes 5 cycles for a non-sequential ROM access
+        let start = timer(&mut mmio);
+        for _ in 0..100 {
+            mmio.read::<u16>(0x08000000, AccessKind::Data);
+        }
+        mmio.tick_components();
+        let elapsed = timer(&mut mmio) - start;
+        assert!((495..=505).contains(&elapsed), "{}", elapsed);
+
+        // without accesses a call is a single tick
+        let start = timer(&mut mmio);
+        mmio.tick_components();
+        assert!((5..=6).contains(&(timer(&mut mmio) - start)));
+    }
+
+    #[test]
+    fn undefined_instruction_exception() {
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+        mmio.load(0x08000000, &0x06110610u32.to_le_bytes()); // undefined, but the EQ condition fails
+        mmio.load(0x08000004, &0xE7F000F0u32.to_le_bytes()); // permanently undefined
+
+        let mut cpu = Cpu::new(&[], mmio);
+        cpu.registers.r[15] = 0x08000000;
+        cpu.set_processor_mode(ProcessorMode::System);
+        let cpsr = cpu.read_register(&Register::Cpsr);
+
+        for _ in 0..8 {
+            if let Err(CpuError::FailedToDecode) = cpu.tick() {
+                break;
+            }
+        }
+
+        // the UND handler can return to the instruction after the undefined one with movs pc, lr
+        assert_eq!(cpu.get_pc(), 0x04);
+        assert_eq!(cpu.get_processor_mode(), ProcessorMode::Undefined);
+        assert_eq!(cpu.read_register(&Register::R14), 0x08000008);
+        assert_eq!(cpu.read_register(&Register::Spsr), cpsr);
+    }
+
+    #[test]
+    fn step_back_restores_components() {
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+        mmio.load(0x00000000, BIOS);
+        mmio.load(0x08000000, ARM_TEST);
+
+        let mut cpu = Cpu::new(&[], mmio);
+        cpu.registers.r[13] = 0x03007f00;
+        cpu.registers.r[15] = 0x08000000;
+        cpu.set_processor_mode(ProcessorMode::System);
+        cpu.enable_history(1000);
+        // timer 0 counts every cycle
+        cpu.mmio.write::<u16>(0x04000102, 0x0080, AccessKind::Data);
+
+        let run = |cpu: &mut Cpu, ticks: usize| {
+            for _ in 0..ticks {
+                let _ = cpu.tick();
+                cpu.mmio.tick_components();
+            }
+        };
+        let state = |cpu: &mut Cpu| {
+            (
+                cpu.registers.r,
+                cpu.registers.cpsr.bits(),
+                cpu.mmio.internal_memory[0x03000000..0x03008000].to_vec(),
+                *cpu.mmio.ppu.scanline.value(),
+                cpu.mmio.read::<u16>(0x04000100, AccessKind::Debugger),
+            )
+        };
+
+        run(&mut cpu, 1000);
+        let before = state(&mut cpu);
+        run(&mut cpu, 200);
+        assert!(state(&mut cpu) != before);
+        let samples = cpu.mmio.apu.drain_samples().len();
+        for _ in 0..200 {
+            assert!(cpu.step_back());
+        }
+        assert!(state(&mut cpu) == before);
+        // the replayed ticks were already heard
+        assert!(samples > 0);
+        assert!(cpu.mmio.apu.drain_samples().is_empty());
+    }
+
+    const HLE_SRC: u32 = 0x02000000;
+    const HLE_DST: u32 = 0x03000000;
+
+    fn hle_cpu(data: &[u8]) -> Cpu {
+        let mut mmio = Mmio::new(BackupType::Sram, false);
+        mmio.load(HLE_SRC, data);
+        Cpu::new(&[], mmio)
+    }
+
+    fn hle_call(cpu: &mut Cpu, number: u8, args: [u32; 4]) {
+        let registers = [Register::R0, Register::R1, Register::R2, Register::R3];
+        for (register, value) in registers.iter().zip(args) {
+            cpu.write_register(register, value);
+        }
+        software_interrupt(cpu, number);
+    }
+
+    fn read_bytes(cpu: &mut Cpu, addr: u32, length: u32) -> Vec<u8> {
+        (0..length)
+            .map(|i| cpu.mmio.read::<u8>(addr + i, AccessKind::Debugger))
+            .collect()
+    }
+
+    fn hle_decompress(number: u8, data: &[u8], length: u32) -> Vec<u8> {
+        let mut cpu = hle_cpu(data);
+        hle_call(&mut cpu, number, [HLE_SRC, HLE_DST, 0, 0]);
+        read_bytes(&mut cpu, HLE_DST, length)
+    }
+
+    #[test]
+    fn hle_div() {
+        let mut cpu = hle_cpu(&[]);
+        let mut div = |number, r0: i32, r1: i32| {
+            hle_call(&mut cpu, number, [r0 as u32, r1 as u32, 0, 0]);
+            [Register::R0, Register::R1, Register::R3].map(|register| cpu.read_register(&register) as i32)
+        };
+
+        // rounds towards zero, the remainder has the sign of the numerator
+        assert_eq!(div(0x06, 7, -2), [-3, 1, 3]);
+        assert_eq!(div(0x06, -7, 2), [-3, -1, 3]);
+        assert_eq!(div(0x07, 2, -7), [-3, -1, 3]);
+        assert_eq!(div(0x06, i32::MIN, -1), [i32::MIN, 0, i32::MIN]);
+        assert_eq!(div(0x06, -5, 0), [-1, -5, 1]);
+        assert_eq!(div(0x06, 5, 0), [1, 5, 1]);
+    }
+
+    #[test]
+    fn hle_cpu_set() {
+        let data: Vec<u8> = (1..=32).collect();
+
+        // 3 read_halfwords
+        let mut cpu = hle_cpu(&data);
+        hle_call(&mut cpu, 0x0B, [HLE_SRC, HLE_DST, 3, 0]);
+        assert_eq!(read_bytes(&mut cpu, HLE_DST, 8), [1, 2, 3, 4, 5, 6, 0, 0]);
+
+        // fill 2 words
+        let mut cpu = hle_cpu(&data);
+        hle_call(&mut cpu, 0x0B, [HLE_SRC, HLE_DST, 2 | 1 << 24 | 1 << 26, 0]);
+        assert_eq!(read_bytes(&mut cpu, HLE_DST, 12), [1, 2, 3, 4, 1, 2, 3, 4, 0, 0, 0, 0]);
+
+        // CpuFastSet rounds up to blocks of 8 words
+        let mut cpu = hle_cpu(&data);
+        hle_call(&mut cpu, 0x0C, [HLE_SRC, HLE_DST, 1, 0]);
+        assert_eq!(read_bytes(&mut cpu, HLE_DST, 36), [data, vec![0; 4]].concat());
+    }
+
+    #[test]
+    fn hle_bit_unpack() {
+        // 2 bit values 3, 2, 1, 0 to bytes, with an offset of 1
+        let mut data = vec![0b00_01_10_11];
+        data.resize(0x10, 0);
+        data.extend_from_slice(&[0x01, 0x00, 2, 8, 0x01, 0x00, 0x00, 0x00]);
+
+        let mut cpu = hle_cpu(&data);
+        hle_call(&mut cpu, 0x10, [HLE_SRC, HLE_DST, HLE_SRC + 0x10, 0]);
+        assert_eq!(read_bytes(&mut cpu, HLE_DST, 4), [4, 3, 2, 0]);
+
+        // bit 31 of the offset also applies it to zeros
+        data[0x17] = 0x80;
+        let mut cpu = hle_cpu(&data);
+        hle_call(&mut cpu, 0x10, [HLE_SRC, HLE_DST, HLE_SRC + 0x10, 0]);
+        assert_eq!(read_bytes(&mut cpu, HLE_DST, 4), [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn hle_lz77() {
+        // ABC, then 5 bytes from 3 back, overlapping the output
+        let data = [0x10, 0x08, 0x00, 0x00, 0b0001_0000, b'A', b'B', b'C', 0x20, 0x02];
+        assert_eq!(hle_decompress(0x11, &data, 8), b"ABCABCAB");
+        assert_eq!(hle_decompress(0x12, &data, 8), b"ABCABCAB");
+    }
+
+    #[test]
+    fn hle_huffman() {
+        // the root has the two leaves A and B, the bitstream 0110 reads ABBA
+        let data = [0x28, 0x04, 0x00, 0x00, 0x01, 0xC0, b'A', b'B', 0x00, 0x00, 0x00, 0x60];
+        assert_eq!(hle_decompress(0x13, &data, 4), b"ABBA");
+    }
+
+    #[test]
+    fn hle_run_length() {
+        // 4 times Z, then XYW as they are
+        let data = [0x30, 0x07, 0x00, 0x00, 0x81, b'Z', 0x02, b'X', b'Y', b'W'];
+        assert_eq!(hle_decompress(0x14, &data, 7), b"ZZZZXYW");
+        assert_eq!(hle_decompress(0x15, &data, 7), b"ZZZZXYW");
+    }
+
+    #[test]
+    fn hle_diff() {
+        let data = [0x81, 0x04, 0x00, 0x00, 1, 1, 0xFF, 2];
+        assert_eq!(hle_decompress(0x16, &data, 4), [1, 2, 1, 3]);
+
+        let data = [0x82, 0x04, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00];
+        assert_eq!(hle_decompress(0x18, &data, 4), [0x00, 0x01, 0x01, 0x01]);
+    }
+
+    fn read_halfwords(cpu: &mut Cpu, addr: u32, count: u32) -> Vec<u16> {
+        (0..count)
+            .map(|i| cpu.mmio.read::<u16>(addr + i * 2, AccessKind::Debugger))
+            .collect()
+    }
+
+    #[test]
+    fn hle_obj_affine_set() {
+        // 90 degrees with twice the width, then 45 degrees unscaled
+        let data = [
+            [0x00, 0x02, 0x00, 0x01, 0x00, 0x40, 0, 0],
+            [0x00, 0x01, 0x00, 0x01, 0x00, 0x20, 0, 0],
+        ]
+        .concat();
+
+        let mut cpu = hle_cpu(&data);
+        hle_call(&mut cpu, 0x0F, [HLE_SRC, HLE_DST, 2, 2]);
+        assert_eq!(
+            read_halfwords(&mut cpu, HLE_DST, 8),
+            [0x0000, 0xFE00, 0x0100, 0x0000, 0x00B5, 0xFF4B, 0x00B5, 0x00B5]
+        );
+
+        // OAM spreads the parameters over 4 entries
+        let mut cpu = hle_cpu(&data);
+        hle_call(&mut cpu, 0x0F, [HLE_SRC, HLE_DST + 6, 1, 8]);
+        let oam = read_halfwords(&mut cpu, HLE_DST, 16);
+        assert_eq!([oam[3], oam[7], oam[11], oam[15]], [0x0000, 0xFE00, 0x0100, 0x0000]);
+    }
+
+    #[test]
+    fn hle_bg_affine_set() {
+        // texture position 64,32 at the center of the screen, rotated by 90 degrees
+        let mut data = vec![];
+        data.extend_from_slice(&(64u32 << 8).to_le_bytes());
+        data.extend_from_slice(&(32u32 << 8).to_le_bytes());
+        data.extend_from_slice(&[120, 0, 80, 0, 0x00, 0x01, 0x00, 0x01, 0x00, 0x40, 0, 0]);
+
+        let mut cpu = hle_cpu(&data);
+        hle_call(&mut cpu, 0x0E, [HLE_SRC, HLE_DST, 1, 0]);
+        assert_eq!(read_halfwords(&mut cpu, HLE_DST, 4), [0x0000, 0xFF00, 0x0100, 0x0000]);
+
+        // the top left corner of the screen maps to 64 + 80, 32 - 120
+        assert_eq!(cpu.mmio.read::<u32>(HLE_DST + 8, AccessKind::Debugger), 144 << 8);
+        assert_eq!(
+            cpu.mmio.read::<u32>(HLE_DST + 12, AccessKind::Debugger) as i32,
+            -88 << 8
+        );
+    }
+
+    const PATCH_SOURCE: &[u8] = b"ABCD";
+
+    // source, target and patch CRC32
+    fn with_patch_footer(mut patch: Vec<u8>, source: &[u8], target: &[u8]) -> Vec<u8> {
+        patch.extend_from_slice(&crc32fast::hash(source).to_le_bytes());
+        patch.extend_from_slice(&crc32fast::hash(target).to_le_bytes());
+        patch.extend_from_slice(&crc32fast::hash(&patch).to_le_bytes());
+        patch
+    }
+
+    #[test]
+    fn ips_patch() {
+        let mut patch = b"PATCH".to_vec();
+        patch.extend_from_slice(&[0x00, 0x00, 0x01, 0x00, 0x02, b'X', b'Y']); // 2 bytes at 1
+        patch.extend_from_slice(&[0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, b'Z']); // 4 times Z at 3, grows the ROM
+        patch.extend_from_slice(b"EOF");
+        assert_eq!(apply_patch(PATCH_SOURCE, &patch), Ok(b"AXYZZZZ".to_vec()));
+
+        // truncation extension
+        patch.extend_from_slice(&[0x00, 0x00, 0x05]);
+        assert_eq!(apply_patch(PATCH_SOURCE, &patch), Ok(b"AXYZZ".to_vec()));
+
+        // records without the EOF marker
+        assert_eq!(apply_patch(PATCH_SOURCE, &patch[..12]), Err(PatchError::Malformed));
+    }
+
+    #[test]
+    fn ups_patch() {
+        let mut patch = b"UPS1".to_vec();
+        patch.extend_from_slice(&[0x84, 0x85]); // source and target size
+        patch.extend_from_slice(&[0x82, b'C' ^ b'X', 0x00]); // skip 2, xor C to X, the terminator skips D
+        patch.extend_from_slice(&[0x80, b'E', 0x00]); // past the end of the source
+        let patch = with_patch_footer(patch, PATCH_SOURCE, b"ABXDE");
+        assert_eq!(apply_patch(PATCH_SOURCE, &patch), Ok(b"ABXDE".to_vec()));
+
+        assert_eq!(
+            apply_patch(b"ABCE", &patch),
+            Err(PatchError::SourceMismatch {
+                expected: crc32fast::hash(PATCH_SOURCE),
+                actual: crc32fast::hash(b"ABCE"),
+            })
+        );
+    }
+
+    #[test]
+    fn bps_patch() {
+        let mut patch = b"BPS1".to_vec();
+        patch.extend_from_slice(&[0x84, 0x87, 0x80]); // source and target size, no metadata
+        patch.extend_from_slice(&[0x84]); // source read AB
+        patch.extend_from_slice(&[0x81, b'X']); // target read X
+        patch.extend_from_slice(&[0x82, 0x86]); // source copy D from 3
+        patch.extend_from_slice(&[0x8B, 0x84]); // target copy 3 bytes from 2, overlapping the output
+        let target = b"ABXDXDX";
+        let valid = with_patch_footer(patch.clone(), PATCH_SOURCE, target);
+        assert_eq!(apply_patch(PATCH_SOURCE, &valid), Ok(target.to_vec()));
+
+        let wrong_target = with_patch_footer(patch.clone(), PATCH_SOURCE, b"ABXDXDY");
+        assert_eq!(
+            apply_patch(PATCH_SOURCE, &wrong_target),
+            Err(PatchError::TargetMismatch {
+                expected: crc32fast::hash(b"ABXDXDY"),
+                actual: crc32fast::hash(target),
+            })
+        );
+
+        // the patch CRC covers everything before it
+        let mut corrupted = valid;
+        corrupted[8] ^= 1;
+        assert_eq!(apply_patch(PATCH_SOURCE, &corrupted), Err(PatchError::Malformed));
+        assert_eq!(apply_patch(PATCH_SOURCE, b"BPS1"), Err(PatchError::Malformed));
+    }
+
+    #[test]
+    fn unknown_patch_format() {
+        assert_eq!(apply_patch(PATCH_SOURCE, b"PATC"), Err(PatchError::UnknownFormat));
+    }
 }
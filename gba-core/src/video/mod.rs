@@ -1,6 +1,7 @@
 pub mod ppu;
 pub mod registers;
 pub mod tile;
+pub mod timeline;
 
 pub const SCREEN_WIDTH: usize = 240;
 pub const SCREEN_HEIGHT: usize = 160;
@@ -24,6 +25,21 @@ pub enum Pixel {
 }
 
 impl Pixel {
+    pub fn pack(self) -> u32 {
+        match self {
+            Pixel::Transparent => 0,
+            Pixel::Rgb(r, g, b) => 0xFF00_0000 | (r as u32) << 16 | (g as u32) << 8 | b as u32,
+        }
+    }
+
+    pub fn unpack(pixel: u32) -> Pixel {
+        if pixel >> 24 == 0 {
+            Pixel::Transparent
+        } else {
+            Pixel::Rgb((pixel >> 16) as u8, (pixel >> 8) as u8, pixel as u8)
+        }
+    }
+
     pub fn blend(self, other: Pixel, eva: u8, evb: u8) -> Pixel {
         match (self, other) {
             (Pixel::Rgb(r1, g1, b1), Pixel::Rgb(r2, g2, b2)) => {
@@ -65,4 +81,16 @@ impl Pixel {
     }
 }
 
-pub type Frame = [[Pixel; SCREEN_WIDTH]; SCREEN_HEIGHT];
+// packed 0xAARRGGBB, the alpha is only 0 for transparent pixels in the debugger's background frames
+pub type Frame = [u32; SCREEN_WIDTH * SCREEN_HEIGHT];
+
+// RGB bytes row by row for the image exports, transparent pixels are black
+pub fn frame_to_rgb(frame: &Frame) -> Vec<u8> {
+    frame
+        .iter()
+        .flat_map(|&pixel| match Pixel::unpack(pixel) {
+            Pixel::Transparent => [0, 0, 0],
+            Pixel::Rgb(r, g, b) => [r, g, b],
+        })
+        .collect()
+}
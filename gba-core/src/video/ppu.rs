@@ -1,19 +1,31 @@
 use super::registers::{BgCnt, BgOffset, ColorDepth, DispCnt, DispStat, ObjShape};
-use super::tile::Tile;
+use super::tile::{self, Tile};
 use super::{Frame, PALETTE_ADDR_END, PALETTE_ADDR_START, PALETTE_TOTAL_ENTRIES, Pixel, SCREEN_HEIGHT, SCREEN_WIDTH};
 use crate::memory::device::{Addressable, IoRegister};
+use crate::memory::sparse::SparseMemory;
 use crate::video::TILEMAP_ENTRY_SIZE;
 use crate::video::registers::{
     BgAffineParam, BgRefPointHigh, BgRefPointLow, BldAlpha, BldCnt, BldY, Dimension, InternalScreenSize, ObjAttribute0,
-    ObjAttribute1, ObjAttribute2, ObjSize, Sfx, WindowControl, WindowDimensions,
+    ObjAttribute1, ObjAttribute2, ObjMode, ObjSize, Sfx, WindowControl, WindowDimensions,
 };
 use crate::video::tile::TileInfo;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::ops::Range;
 use tracing::*;
 
-#[derive(Clone, Copy, PartialEq)]
-enum WindowRegion {
+// palette, VRAM and OAM, relative to the start of palette RAM
+const VRAM_REGIONS: [Range<usize>; 3] = [0x00000000..0x00000400, 0x01000000..0x01018000, 0x02000000..0x02000400];
+
+// priority, color and semi-transparency of the front sprite pixel
+type SpriteLine = [(usize, Pixel, bool); SCREEN_WIDTH];
+
+// the window a pixel belongs to, WIN0 has the highest priority and the OBJ window the lowest
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindowRegion {
     Win0,
     Win1,
+    Obj,
     Outside,
 }
 
@@ -21,6 +33,7 @@ enum WindowRegion {
 pub enum PpuEvent {
     VBlank,
     HBlank,
+    VCount,
 }
 
 #[derive(Clone)]
@@ -42,17 +55,36 @@ pub struct Sprite {
     pub attr0_addr: u32,
     pub attr1_addr: u32,
     pub attr2_addr: u32,
+    pub affine: Option<(usize, [i32; 4])>, // parameter group and PA, PB, PC, PD in 8.8 fixed point
+    pub bounds: [i32; 4],                  // x, y, width and height of the bounding box on screen
 }
 
+// one entry of a rendered tilemap, for inspecting it in the debugger
+#[derive(Debug, Clone, Copy)]
+pub struct TilemapEntry {
+    pub addr: u32, // of the entry itself, 2 bytes in text maps and 1 byte in affine maps
+    pub tile_id: usize,
+    pub tile_addr: u32,
+    pub palette: usize, // always 0 unless the map is 4bpp
+    pub x_flip: bool,
+    pub y_flip: bool,
+}
+
+#[derive(Clone)]
 pub struct Ppu {
     pub h_counter: u16,
-    pub vram: Box<[u8; (0x07FFFFFF - 0x05000000) + 1]>,
+    pub vram: SparseMemory<{ (0x07FFFFFF - 0x05000000) + 1 }>, // palette, VRAM and OAM
     io: Box<[u8; (0x4000056 - 0x4000000) + 1]>,
     vblank_raised_for_frame: bool,
+    frame: Box<Frame>, // scanlines are rendered into this as they enter HBlank
+    // internal BG2/BG3 reference points, latched at VBlank and on writes, advanced by PB/PD after every line
+    bg_ref: [[i32; 2]; 2],
+    pub layer_enabled: [bool; 5], // BG0-BG3 and OBJ, hides a layer during composition regardless of DISPCNT
     // I/O Registers
     pub scanline: IoRegister,
     pub disp_stat: IoRegister<DispStat>,
     pub disp_cnt: IoRegister<DispCnt>,
+    pub green_swap: IoRegister, // undocumented, bit 0 swaps the green component of each pair of pixels
     pub bg_cnt: [IoRegister<BgCnt>; 4],
     pub bg_hofs: [IoRegister<BgOffset>; 4],
     pub bg_vofs: [IoRegister<BgOffset>; 4],
@@ -77,17 +109,20 @@ pub struct Ppu {
 
 impl Ppu {
     pub fn new() -> Ppu {
-        let vram = Box::<[u8; (0x07FFFFFF - 0x05000000) + 1]>::new_zeroed();
         let io = Box::<[u8; (0x4000056 - 0x4000000) + 1]>::new_zeroed();
 
         Ppu {
             h_counter: 0,
-            vram: unsafe { vram.assume_init() },
+            vram: SparseMemory::new(&VRAM_REGIONS),
             io: unsafe { io.assume_init() },
             vblank_raised_for_frame: false,
+            frame: Box::new([0; SCREEN_WIDTH * SCREEN_HEIGHT]),
+            bg_ref: [[0; 2]; 2],
+            layer_enabled: [true; 5],
             scanline: IoRegister::default(),
             disp_stat: IoRegister::default(),
             disp_cnt: IoRegister::default(),
+            green_swap: IoRegister::default(),
             bg_cnt: [IoRegister::default(); 4],
             bg_hofs: [IoRegister::default(); 4],
             bg_vofs: [IoRegister::default(); 4],
@@ -121,6 +156,12 @@ impl Ppu {
         self.h_counter += 1;
 
         if self.h_counter == 240 {
+            // the line is drawn with the registers as they are at its end, so changes made during
+            // HBlank (IRQ or DMA) show up on the next one
+            if (self.scanline.0 as usize) < SCREEN_HEIGHT {
+                self.render_scanline();
+            }
+
             self.h_counter = 0;
             self.scanline.0 += 1;
             events.push(PpuEvent::HBlank);
@@ -137,64 +178,152 @@ impl Ppu {
             self.vblank_raised_for_frame = true;
             events.push(PpuEvent::VBlank);
             self.disp_stat.set_flags(DispStat::VBLANK_FLAG);
+            self.latch_reference_points();
+        }
+
+        // a new line has just started
+        if self.h_counter == 0 {
+            self.update_vcount_match(&mut events);
         }
 
         events
     }
 
-    pub fn get_frame(&self) -> Frame {
-        let lcd_control = self.disp_cnt.value();
-        trace!(target: "ppu", "Grabbing internal frame buffer for PPU mode: {}", lcd_control.bg_mode());
-
-        let sprite_layer = self.render_sprites();
-
-        let bg_layers = match lcd_control.bg_mode() {
-            0 => self.render_background_mode0_layers(),
-            1..=2 => self.render_background_mode0_layers(), // TODO: should prob not deal with these modes inside of mode0
-            3..=5 => {
-                let mut layers = vec![[[Pixel::Transparent; SCREEN_WIDTH]; SCREEN_HEIGHT]; 4];
-                match lcd_control.bg_mode() {
-                    3 => {
-                        layers[2] = self.render_background_mode3(lcd_control.frame_address());
-                    }
-                    4 => {
-                        layers[2] = self.render_background_mode4(lcd_control.frame_address());
-                    }
-                    5 => {
-                        layers[2] = self.render_background_mode5(lcd_control.frame_address());
+    // https://problemkaputt.de/gbatek.htm#lcdiointerruptsandstatus
+    // the flag stays set for the whole matching line, the interrupt is only raised when it is entered
+    fn update_vcount_match(&mut self, events: &mut Vec<PpuEvent>) {
+        if self.scanline.0 == self.disp_stat.value().v_count_setting() {
+            self.disp_stat.set_flags(DispStat::VCOUNTER_FLAG);
+            events.push(PpuEvent::VCount);
+        } else {
+            self.disp_stat.clear_flags(DispStat::VCOUNTER_FLAG);
+        }
+    }
+
+    // video memory sits on a 16-bit bus, an 8-bit write either fills the whole halfword or is dropped entirely
+    // returns the address of the halfword the byte is written to, see "Writing 8bit Data to Video Memory"
+    // https://problemkaputt.de/gbatek.htm#gbamemorymap
+    pub fn byte_write_target(&self, addr: u32) -> Option<u32> {
+        // OBJ tiles follow the 64 KiB of BG VRAM, or 80 KiB in the bitmap modes
+        let obj_vram = if self.disp_cnt.value().bg_mode() >= 3 {
+            0x06014000
+        } else {
+            0x06010000
+        };
+
+        match addr {
+            // Atem — 12:06 AM
+            // 8-bit writes to OBJ VRAM and OAM are ignored
+            0x06000000..=0x06017FFF if addr >= obj_vram => None,
+            0x07000000..=0x070003FF => None,
+            _ => Some(addr & !1),
+        }
+    }
+
+    // the last completed frame once the PPU has reached VBlank
+    pub fn get_frame(&self) -> Box<Frame> {
+        self.frame.clone()
+    }
+
+    // renders a whole frame from the current state for the debugger, without sprites
+    pub fn get_background_frame(&self, mode: usize, base_addr: u32) -> Box<Frame> {
+        let mut frame = Box::new([0; SCREEN_WIDTH * SCREEN_HEIGHT]);
+        let no_sprites = [(5, Pixel::Transparent, false); SCREEN_WIDTH];
+        let palette = self.fetch_palette();
+
+        for (y, row) in frame.chunks_exact_mut(SCREEN_WIDTH).enumerate() {
+            // the reference points as they would be on this line without any mid-frame writes
+            let origins = [0, 1].map(|i| {
+                let [x0, y0] = self.reference_point(i);
+                let [pb, pd] = [self.bg_pb[i].value().fixed_point(), self.bg_pd[i].value().fixed_point()];
+                [x0 + pb * y as i32, y0 + pd * y as i32]
+            });
+            match mode {
+                0..=2 => {
+                    let bg_lines = self.render_background_lines(y, &origins, &palette);
+                    row.copy_from_slice(&self.compose_line(
+                        y,
+                        &bg_lines,
+                        &no_sprites,
+                        &[false; SCREEN_WIDTH],
+                        &palette,
+                    ));
+                }
+                // the bitmap itself, without the affine transformation
+                3..=5 => {
+                    for (x, pixel) in row.iter_mut().enumerate() {
+                        *pixel = self.bitmap_pixel(mode as u8, base_addr, x, y).pack();
                     }
-                    _ => unreachable!(),
                 }
-                layers
+                _ => unreachable!(),
             }
-            _ => unreachable!(),
-        };
+        }
 
-        self.compose_layers(&bg_layers, &sprite_layer)
+        frame
     }
 
-    pub fn get_background_frame(&self, mode: usize, base_addr: u32) -> Frame {
-        match mode {
-            0 => {
-                let layers = self.render_background_mode0_layers();
-                self.compose_layers(&layers, &vec![(5, Pixel::Transparent); SCREEN_WIDTH * SCREEN_HEIGHT])
-            }
-            1..=2 => {
-                let layers = self.render_background_mode0_layers();
-                self.compose_layers(&layers, &vec![(5, Pixel::Transparent); SCREEN_WIDTH * SCREEN_HEIGHT])
-            }
-            3 => self.render_background_mode3(base_addr),
-            4 => self.render_background_mode4(base_addr),
-            5 => self.render_background_mode5(base_addr),
-            _ => unreachable!(),
+    fn render_scanline(&mut self) {
+        let y = self.scanline.0 as usize;
+        trace!(target: "ppu", "Rendering scanline {} in mode {}", y, self.disp_cnt.value().bg_mode());
+
+        let palette = self.fetch_palette();
+        let (sprite_line, bg_lines) = self.render_layers(y, &palette);
+        let obj_window = self.obj_window_line(y, &palette);
+        let row = self.compose_line(y, &bg_lines, &sprite_line, &obj_window, &palette);
+        self.frame[y * SCREEN_WIDTH..][..SCREEN_WIDTH].copy_from_slice(&row);
+
+        for i in 0..2 {
+            self.bg_ref[i][0] += self.bg_pb[i].value().fixed_point();
+            self.bg_ref[i][1] += self.bg_pd[i].value().fixed_point();
         }
     }
 
+    // the sprite and BG layers are independent of each other until they are composed
+    #[cfg(feature = "parallel")]
+    fn render_layers(
+        &self, y: usize, palette: &[Pixel; PALETTE_TOTAL_ENTRIES],
+    ) -> (SpriteLine, [[Pixel; SCREEN_WIDTH]; 4]) {
+        rayon::join(
+            || self.render_sprite_line(y, palette, false),
+            || self.render_background_lines(y, &self.bg_ref, palette),
+        )
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn render_layers(
+        &self, y: usize, palette: &[Pixel; PALETTE_TOTAL_ENTRIES],
+    ) -> (SpriteLine, [[Pixel; SCREEN_WIDTH]; 4]) {
+        (
+            self.render_sprite_line(y, palette, false),
+            self.render_background_lines(y, &self.bg_ref, palette),
+        )
+    }
+
+    // https://problemkaputt.de/gbatek.htm#lcdiobgrotationscaling
+    fn reference_point(&self, i: usize) -> [i32; 2] {
+        [
+            self.bg_refx_h[i].value().full_value(self.bg_refx_l[i].value()),
+            self.bg_refy_h[i].value().full_value(self.bg_refy_l[i].value()),
+        ]
+    }
+
+    // the reference point and PA, PB, PC, PD of an affine layer (BG2=0, BG3=1) as written by the game, 8.8 fixed point
+    pub fn affine_parameters(&self, i: usize) -> ([i32; 2], [i32; 4]) {
+        (
+            self.reference_point(i),
+            [&self.bg_pa[i], &self.bg_pb[i], &self.bg_pc[i], &self.bg_pd[i]].map(|param| param.value().fixed_point()),
+        )
+    }
+
+    fn latch_reference_points(&mut self) {
+        self.bg_ref = [self.reference_point(0), self.reference_point(1)];
+    }
+
     pub fn fetch_palette(&self) -> [Pixel; PALETTE_TOTAL_ENTRIES] {
         let mut palette = [Pixel::Transparent; PALETTE_TOTAL_ENTRIES];
 
         for addr in (PALETTE_ADDR_START..=PALETTE_ADDR_END).step_by(2) {
-            let rgb = self.read_u16(addr);
+            let rgb = self.read::<u16>(addr);
             let index = (addr - PALETTE_ADDR_START) as usize / 2;
             palette[index] = Self::extract_rgb(rgb);
         }
@@ -224,7 +353,7 @@ impl Ppu {
             let tile_data = {
                 let mut tile_data = vec![0u8; tile_size];
                 for i in 0..tile_size {
-                    tile_data[i] = self.read((tile_addr + i) as u32);
+                    tile_data[i] = self.read_byte((tile_addr + i) as u32);
                 }
                 tile_data
             };
@@ -265,17 +394,13 @@ impl Ppu {
         (tile_count, out)
     }
 
-    pub fn render_tilemap(&self, bg: usize, bg_cnt: &BgCnt) -> (InternalScreenSize, Vec<Pixel>) {
+    // the entries are in the same order as the tiles, row by row
+    pub fn render_tilemap(&self, bg: usize, bg_cnt: &BgCnt) -> (InternalScreenSize, Vec<Pixel>, Vec<TilemapEntry>) {
         let palette = self.fetch_palette();
 
         let tileset_addr = bg_cnt.tileset_addr() as usize; // cbb
         let tilemap_addr = bg_cnt.tilemap_addr() as usize; // sbb
 
-        let tile_size = match bg_cnt.bpp() {
-            ColorDepth::Bpp4 => 0x20,
-            ColorDepth::Bpp8 => 0x40,
-        };
-
         let bg_mode = self.disp_cnt.value().bg_mode();
         let (map_w, map_h, tiles_x, tiles_y) = match bg_cnt.screen_size(bg, bg_mode) {
             InternalScreenSize::Text256x256 => (256, 256, 32, 32),
@@ -298,7 +423,14 @@ impl Ppu {
                 | InternalScreenSize::Text512x512
         );
 
+        // affine maps are always 256 colors
+        let tile_size = match bg_cnt.bpp() {
+            ColorDepth::Bpp4 if is_text_mode => 0x20,
+            _ => 0x40,
+        };
+
         let mut internal_frame = vec![Pixel::Transparent; map_w * map_h];
+        let mut entries = Vec::with_capacity(tiles_x * tiles_y);
 
         for ty in 0..tiles_y {
             for tx in 0..tiles_x {
@@ -325,9 +457,9 @@ impl Ppu {
                 };
 
                 let entry = if is_text_mode {
-                    self.read_u16(addr as u32)
+                    self.read::<u16>(addr as u32)
                 } else {
-                    self.read(addr as u32) as u16
+                    self.read_byte(addr as u32) as u16
                 };
                 let tile_info = TileInfo::from_bits_truncate(entry);
 
@@ -336,7 +468,7 @@ impl Ppu {
                 let tile_data = {
                     let mut tile_data = vec![0u8; tile_size];
                     for i in 0..tile_size {
-                        tile_data[i] = self.read((tile_addr + i) as u32);
+                        tile_data[i] = self.read_byte((tile_addr + i) as u32);
                     }
                     tile_data
                 };
@@ -349,6 +481,15 @@ impl Ppu {
                 };
                 let mut tile = Tile::from_bytes(&tile_data, palette_bank);
 
+                entries.push(TilemapEntry {
+                    addr,
+                    tile_id: tile_info.tile_id(is_text_mode),
+                    tile_addr: tile_addr as u32,
+                    palette: if tile_size == 0x20 { tile_info.palette() } else { 0 },
+                    x_flip: is_text_mode && tile_info.contains(TileInfo::FLIP_X),
+                    y_flip: is_text_mode && tile_info.contains(TileInfo::FLIP_Y),
+                });
+
                 // flip the tile if needed
                 if is_text_mode {
                     if tile_info.contains(TileInfo::FLIP_X) {
@@ -383,7 +524,7 @@ impl Ppu {
             map_w * map_h
         );
 
-        (screen_size, internal_frame)
+        (screen_size, internal_frame, entries)
     }
 
     pub fn create_sprite_debug_map(&self) -> Vec<Sprite> {
@@ -403,9 +544,9 @@ impl Ppu {
             let attr1_addr = OAM_BASE + (obj_id * 8) + 2;
             let attr2_addr = OAM_BASE + (obj_id * 8) + 4;
 
-            let attr0 = ObjAttribute0::from_bits_truncate(self.read_u16(attr0_addr));
-            let attr1 = ObjAttribute1::from_bits_truncate(self.read_u16(attr1_addr));
-            let attr2 = ObjAttribute2::from_bits_truncate(self.read_u16(attr2_addr));
+            let attr0 = ObjAttribute0::from_bits_truncate(self.read::<u16>(attr0_addr));
+            let attr1 = ObjAttribute1::from_bits_truncate(self.read::<u16>(attr1_addr));
+            let attr2 = ObjAttribute2::from_bits_truncate(self.read::<u16>(attr2_addr));
 
             let shape = attr0.shape();
             let size = attr1.size(shape);
@@ -414,6 +555,24 @@ impl Ppu {
                 continue;
             }
 
+            // affine sprites are shown untransformed, their flip bits select the parameter group
+            let x_flip = !attr0.is_affine() && attr1.x_flip();
+            let y_flip = !attr0.is_affine() && attr1.y_flip();
+            let affine = attr0
+                .is_affine()
+                .then(|| (attr1.affine_group(), self.obj_affine_params(attr1.affine_group())));
+
+            // positions wrap around like they do when the sprite is drawn
+            let x = attr1.x_coordinate() as i32;
+            let y = attr0.y_coordinate() as i32;
+            let scale = if attr0.double_size() { 2 } else { 1 };
+            let bounds = [
+                if x >= 240 { x - 512 } else { x },
+                if y >= 160 { y - 256 } else { y },
+                (w_px * scale) as i32,
+                (h_px * scale) as i32,
+            ];
+
             let tiles_x = w_px / 8;
             let tiles_y = h_px / 8;
             let bpp_factor = if attr0.bpp() == ColorDepth::Bpp8 { 2 } else { 1 };
@@ -433,8 +592,8 @@ impl Ppu {
 
             for ty in 0..tiles_y {
                 for tx in 0..tiles_x {
-                    let src_tx = if attr1.x_flip() { tiles_x - 1 - tx } else { tx };
-                    let src_ty = if attr1.y_flip() { tiles_y - 1 - ty } else { ty };
+                    let src_tx = if x_flip { tiles_x - 1 - tx } else { tx };
+                    let src_ty = if y_flip { tiles_y - 1 - ty } else { ty };
 
                     let char_offset = (src_ty * row_stride + src_tx * bpp_factor) as u32;
                     let tile_nr = char_num_base + char_offset;
@@ -448,7 +607,7 @@ impl Ppu {
                     // fetch raw tile bytes
                     let mut tile_bytes = [0u8; 64];
                     for i in 0..tile_size {
-                        tile_bytes[i] = self.read(tile_addr + i as u32);
+                        tile_bytes[i] = self.read_byte(tile_addr + i as u32);
                     }
 
                     // palette slice
@@ -459,10 +618,10 @@ impl Ppu {
                     };
                     let mut tile = Tile::from_bytes(&tile_bytes[..tile_size], pal_slice);
 
-                    if attr1.x_flip() {
+                    if x_flip {
                         tile.flip_x();
                     }
-                    if attr1.y_flip() {
+                    if y_flip {
                         tile.flip_y();
                     }
 
@@ -485,8 +644,8 @@ impl Ppu {
                 size,
                 tile_number: attr2.tile_number(),
                 palette: attr2.palette(),
-                x_flip: attr1.x_flip(),
-                y_flip: attr1.y_flip(),
+                x_flip,
+                y_flip,
                 priority: attr2.priority(),
                 image: sprite_data,
                 attr0,
@@ -495,6 +654,8 @@ impl Ppu {
                 attr0_addr,
                 attr1_addr,
                 attr2_addr,
+                affine,
+                bounds,
             });
         }
 
@@ -544,34 +705,60 @@ impl Ppu {
         dims
     }
 
-    fn render_sprites(&self) -> Vec<(usize, Pixel)> {
+    // single pixel of an 8x8 tile, palette index 0 is transparent
+    fn tile_pixel(&self, tile_addr: u32, bpp: &ColorDepth, x: usize, y: usize, palette: &[Pixel]) -> Pixel {
+        let index = match bpp {
+            ColorDepth::Bpp4 => {
+                let byte = self.read_byte(tile_addr + (y * 4 + x / 2) as u32);
+                if x.is_multiple_of(2) { byte & 0x0F } else { byte >> 4 }
+            }
+            ColorDepth::Bpp8 => self.read_byte(tile_addr + (y * 8 + x) as u32),
+        };
+
+        if index == 0 {
+            Pixel::Transparent
+        } else {
+            palette[index as usize]
+        }
+    }
+
+    // PA, PB, PC and PD of an OAM rotation/scaling group, stored in the unused 4th halfword of 4 entries
+    fn obj_affine_params(&self, group: usize) -> [i32; 4] {
+        const OAM_BASE: u32 = 0x0700_0000;
+
+        let base = OAM_BASE + (group as u32 * 32) + 6;
+        [0, 1, 2, 3].map(|i| self.read::<u16>(base + i * 8) as i16 as i32)
+    }
+
+    // every pixel carries the priority of its sprite and whether it is semi-transparent. OBJ window sprites are never
+    // shown, they only make up the shape of the window and are rendered on their own
+    fn render_sprite_line(
+        &self, line: usize, palette: &[Pixel; PALETTE_TOTAL_ENTRIES], obj_window: bool,
+    ) -> SpriteLine {
         const OAM_BASE: u32 = 0x0700_0000;
         const OBJ_BASE: u32 = 0x0601_0000;
         const CHAR_UNIT_SIZE: u32 = 32;
 
-        let mut frame = vec![(5, Pixel::Transparent); SCREEN_WIDTH * SCREEN_HEIGHT];
+        let mut pixels = [(5, Pixel::Transparent, false); SCREEN_WIDTH];
 
         let lcd_control = self.disp_cnt.value();
         let bg_mode = lcd_control.bg_mode();
 
-        let palette = self.fetch_palette();
         let obj_palette = &palette[256..512];
 
         let obj_dimension = self.disp_cnt.value().dimension();
 
-        // lower OAM entry = higher priority
-        // quick hack is to go through the OAM backwards
-        for obj_id in (0..128).rev() {
-            let attr0_addr = OAM_BASE + (obj_id * 8) + 0;
+        // a sprite pixel is only replaced by one with a lower priority value, so on ties the lower OAM entry wins
+        for obj_id in 0..128 {
+            let attr0_addr = OAM_BASE + (obj_id * 8);
             let attr1_addr = OAM_BASE + (obj_id * 8) + 2;
             let attr2_addr = OAM_BASE + (obj_id * 8) + 4;
 
-            let attr0 = ObjAttribute0::from_bits_truncate(self.read_u16(attr0_addr));
-            let attr1 = ObjAttribute1::from_bits_truncate(self.read_u16(attr1_addr));
-            let attr2 = ObjAttribute2::from_bits_truncate(self.read_u16(attr2_addr));
+            let attr0 = ObjAttribute0::from_bits_truncate(self.read::<u16>(attr0_addr));
+            let attr1 = ObjAttribute1::from_bits_truncate(self.read::<u16>(attr1_addr));
+            let attr2 = ObjAttribute2::from_bits_truncate(self.read::<u16>(attr2_addr));
 
-            // disabled, TODO: check if affine?
-            if attr0.disabled() {
+            if attr0.disabled() || (attr0.mode() == ObjMode::Window) != obj_window {
                 continue;
             }
 
@@ -589,234 +776,340 @@ impl Ppu {
             let size = attr1.size(shape);
             let (w_px, h_px) = Self::obj_dimensions(shape, size);
 
-            // unsupported
-            if w_px == 0 {
+            // double size sprites are drawn into a bounding box twice as large, the sprite stays centered
+            let scale = if attr0.double_size() { 2 } else { 1 };
+            let (box_w, box_h) = (w_px * scale, h_px * scale);
+
+            // unsupported, or not on this line
+            let row = line as i32 - y;
+            if w_px == 0 || row < 0 || row >= box_h as i32 {
                 continue;
             }
 
-            // tiles per dimension
-            let tiles_x = w_px / 8;
-            let tiles_y = h_px / 8;
-
-            let bpp_factor = if attr0.bpp() == ColorDepth::Bpp8 { 2 } else { 1 };
+            let semi_transparent = attr0.mode() == ObjMode::SemiTransparent;
+            let affine = attr0.is_affine().then(|| self.obj_affine_params(attr1.affine_group()));
+            let bpp = attr0.bpp();
+            let bpp_factor = if bpp == ColorDepth::Bpp8 { 2 } else { 1 };
             let row_stride = if obj_dimension == Dimension::OneDimensional {
-                tiles_x * bpp_factor
+                (w_px / 8) * bpp_factor
             } else {
                 32
             };
+            let char_num_base = if bpp == ColorDepth::Bpp8 {
+                (attr2.tile_number() & !1) as u32 // even-align for 256-colour mode
+            } else {
+                attr2.tile_number() as u32 // leave 4-bpp numbers untouched
+            };
 
-            let tile_size = if attr0.bpp() == ColorDepth::Bpp8 { 0x40 } else { 0x20 };
-
-            for ty in 0..tiles_y {
-                for tx in 0..tiles_x {
-                    let src_tx = if attr1.x_flip() { tiles_x - 1 - tx } else { tx };
-                    let src_ty = if attr1.y_flip() { tiles_y - 1 - ty } else { ty };
+            let pal_slice = if bpp == ColorDepth::Bpp4 {
+                &obj_palette[attr2.palette() * 16..][..16]
+            } else {
+                obj_palette
+            };
 
-                    let char_num_base = if attr0.bpp() == ColorDepth::Bpp8 {
-                        (attr2.tile_number() & !1) as u32 // even-align for 256-colour mode
-                    } else {
-                        attr2.tile_number() as u32 // leave 4-bpp numbers untouched
-                    };
-                    let char_offset = (src_ty * row_stride + src_tx * bpp_factor) as u32;
-                    let tile_nr = char_num_base + char_offset;
+            for column in 0..box_w {
+                let sx = x + column as i32;
+                if sx < 0 || sx >= SCREEN_WIDTH as i32 {
+                    continue;
+                }
 
-                    // https://problemkaputt.de/gbatek.htm#lcdobjoamattributes
-                    // 2. When using BG Mode 3-5 (Bitmap Modes), only tile numbers 512-1023 may be used.
-                    // That is because lower 16K of OBJ memory are used for BG. Attempts to use tiles 0-511 are ignored (not displayed).
-                    if (3..=5).contains(&bg_mode) && tile_nr < 512 {
-                        continue;
+                // texture coordinates inside the sprite
+                let (u, v) = match affine {
+                    Some([pa, pb, pc, pd]) => {
+                        // the matrix maps from the center of the bounding box to the center of the sprite
+                        let (cx, cy) = (column as i32 - box_w as i32 / 2, row - box_h as i32 / 2);
+                        let u = ((pa * cx + pb * cy) >> 8) + w_px as i32 / 2;
+                        let v = ((pc * cx + pd * cy) >> 8) + h_px as i32 / 2;
+                        if u < 0 || u >= w_px as i32 || v < 0 || v >= h_px as i32 {
+                            continue;
+                        }
+                        (u as usize, v as usize)
                     }
-
-                    let tile_addr = OBJ_BASE + (tile_nr * CHAR_UNIT_SIZE);
-
-                    // fetch raw tile bytes
-                    let mut tile_data = [0u8; 64]; // overcommit to avoid vec! allocation
-                    for i in 0..tile_size {
-                        tile_data[i] = self.read(tile_addr + i as u32);
+                    None => {
+                        let u = if attr1.x_flip() { w_px - 1 - column } else { column };
+                        let v = if attr1.y_flip() {
+                            h_px - 1 - row as usize
+                        } else {
+                            row as usize
+                        };
+                        (u, v)
                     }
+                };
 
-                    // extract the tile pixels using the given palette bank
-                    let pal_slice = if attr0.bpp() == ColorDepth::Bpp4 {
-                        &obj_palette[attr2.palette() * 16..][..16]
-                    } else {
-                        &palette[256..512]
-                    };
-                    let mut tile = Tile::from_bytes(&tile_data[..tile_size], pal_slice);
+                let char_offset = ((v / 8) * row_stride + (u / 8) * bpp_factor) as u32;
+                let tile_nr = char_num_base + char_offset;
 
-                    // flip the tile if needed
-                    if !attr0.is_affine() {
-                        if attr1.x_flip() {
-                            tile.flip_x();
-                        }
-                        if attr1.y_flip() {
-                            tile.flip_y();
-                        }
-                    }
+                // https://problemkaputt.de/gbatek.htm#lcdobjoamattributes
+                // 2. When using BG Mode 3-5 (Bitmap Modes), only tile numbers 512-1023 may be used.
+                // That is because lower 16K of OBJ memory are used for BG. Attempts to use tiles 0-511 are ignored (not displayed).
+                if (3..=5).contains(&bg_mode) && tile_nr < 512 {
+                    continue;
+                }
 
-                    // screen-space top-left of this 8x8 tile
-                    let tile_x = x + (tx as i32) * 8;
-                    let tile_y = y + (ty as i32) * 8;
+                let tile_addr = OBJ_BASE + (tile_nr * CHAR_UNIT_SIZE);
+                let color = self.tile_pixel(tile_addr, &bpp, u % 8, v % 8, pal_slice);
+                let (priority, current, _) = pixels[sx as usize];
+                if color != Pixel::Transparent && (current == Pixel::Transparent || attr2.priority() < priority) {
+                    pixels[sx as usize] = (attr2.priority(), color, semi_transparent);
+                }
+            }
+        }
 
-                    // blit 8x8
-                    for py in 0..8 {
-                        let sy = tile_y + py as i32;
-                        if sy < 0 || sy >= SCREEN_HEIGHT as i32 {
-                            continue;
-                        }
+        pixels
+    }
 
-                        for px in 0..8 {
-                            let sx = tile_x + px as i32;
-                            if sx < 0 || sx >= SCREEN_WIDTH as i32 {
-                                continue;
-                            }
-
-                            let color = tile.pixels[py * 8 + px];
-                            if color != Pixel::Transparent {
-                                let sprite_idx = (sy as usize) * SCREEN_WIDTH + (sx as usize);
-                                frame[sprite_idx] = (attr2.priority(), color);
-                            }
-                        }
+    // one line of every background layer, disabled layers stay transparent
+    // origins are the reference points of BG2 and BG3 for this line
+    fn render_background_lines(
+        &self, y: usize, origins: &[[i32; 2]; 2], palette: &[Pixel; PALETTE_TOTAL_ENTRIES],
+    ) -> [[Pixel; SCREEN_WIDTH]; 4] {
+        let mut lines = [[Pixel::Transparent; SCREEN_WIDTH]; 4];
+        let lcd_control = self.disp_cnt.value();
+
+        match lcd_control.bg_mode() {
+            // mode 0 has four text layers, mode 1 two text layers and BG2 as affine, mode 2 only BG2 and BG3 as affine
+            mode @ 0..=2 => {
+                let enabled = [DispCnt::BG0_ON, DispCnt::BG1_ON, DispCnt::BG2_ON, DispCnt::BG3_ON];
+                let layers = match mode {
+                    0 => 0..4,
+                    1 => 0..3,
+                    _ => 2..4,
+                };
+                #[cfg(feature = "parallel")]
+                let lines_iter = lines.par_iter_mut();
+                #[cfg(not(feature = "parallel"))]
+                let lines_iter = lines.iter_mut();
+
+                lines_iter.enumerate().for_each(|(id, line)| {
+                    if layers.contains(&id) && self.disp_cnt.contains_flags(enabled[id]) {
+                        *line = self.render_tiled_line(id, y, origins, palette);
                     }
-                }
+                });
             }
+            mode @ 3..=5 => lines[2] = self.render_bitmap_line(mode, lcd_control.frame_address(), origins[0]),
+            _ => unreachable!(),
         }
 
-        frame
+        lines
     }
 
-    fn render_background_mode0_layers(&self) -> Vec<Frame> {
-        trace!(target: "ppu", "Rendering background mode 0 layers");
+    // map entry of the tile at tx, ty
+    fn tilemap_entry(&self, bg_cnt: &BgCnt, screen_size: InternalScreenSize, tx: usize, ty: usize) -> TileInfo {
+        let tilemap_addr = bg_cnt.tilemap_addr() as usize; // sbb
 
-        let mut layers = vec![[[Pixel::Transparent; SCREEN_WIDTH]; SCREEN_HEIGHT]; 4];
-        let bg_mode = self.disp_cnt.value().bg_mode();
+        if screen_size.is_affine() {
+            let addr = tilemap_addr + ty * (screen_size.width() / 8) + tx;
+            return TileInfo::from_bits_truncate(self.read_byte(addr as u32) as u16);
+        }
 
-        for id in 0..4 {
-            let enabled = match id {
-                0 => self.disp_cnt.contains_flags(DispCnt::BG0_ON),
-                1 => self.disp_cnt.contains_flags(DispCnt::BG1_ON),
-                2 => self.disp_cnt.contains_flags(DispCnt::BG2_ON),
-                3 => self.disp_cnt.contains_flags(DispCnt::BG3_ON),
-                _ => false,
-            };
+        let (block_col, block_row) = (tx / 32, ty / 32); // which 32×32 map
+        let (local_col, local_row) = (tx & 31, ty & 31); // pos inside that map
 
-            if !enabled {
-                continue;
+        let block_index = match screen_size {
+            InternalScreenSize::Text512x256 => block_col,                 // SC0‥SC1
+            InternalScreenSize::Text256x512 => block_row,                 // SC0‥SC1
+            InternalScreenSize::Text512x512 => block_row * 2 + block_col, // SC0‥SC3
+            _ => 0,                                                       // SC0
+        };
+
+        let addr = tilemap_addr + (block_index * TILEMAP_ENTRY_SIZE) + (local_row * 32 + local_col) * 2;
+        TileInfo::from_bits_truncate(self.read::<u16>(addr as u32))
+    }
+
+    // single pixel of a background's tilemap, x and y are inside the map
+    fn tilemap_pixel(
+        &self, bg_cnt: &BgCnt, screen_size: InternalScreenSize, x: usize, y: usize, palette: &[Pixel],
+    ) -> Pixel {
+        let (tx, ty) = (x / 8, y / 8);
+        let tileset_addr = bg_cnt.tileset_addr() as usize; // cbb
+        // affine maps are always 256 colors with one byte entries and no flipping
+        let bpp = if screen_size.is_affine() {
+            ColorDepth::Bpp8
+        } else {
+            bg_cnt.bpp()
+        };
+        let tile_size = if bpp == ColorDepth::Bpp4 { 0x20 } else { 0x40 };
+
+        let tile_info = self.tilemap_entry(bg_cnt, screen_size, tx, ty);
+
+        let (mut px, mut py) = (x % 8, y % 8);
+        if !screen_size.is_affine() {
+            if tile_info.flip_x() {
+                px = 7 - px;
+            }
+            if tile_info.flip_y() {
+                py = 7 - py;
             }
+        }
 
-            let bg_cnt = self.bg_cnt[id].value();
-            let screen_size = bg_cnt.screen_size(id, bg_mode);
-            let (map_w, map_h) = (screen_size.width(), screen_size.height());
-
-            let is_affine = matches!(
-                screen_size,
-                InternalScreenSize::Affine128x128
-                    | InternalScreenSize::Affine256x256
-                    | InternalScreenSize::Affine512x512
-                    | InternalScreenSize::Affine1024x1024
-            );
-
-            let (_, tilemap) = self.render_tilemap(id, &bg_cnt);
-
-            if is_affine {
-                let i = id - 2; // BG2=0, BG3=1
-                let pa = self.bg_pa[i].value().bits() as i32;
-                let pb = self.bg_pb[i].value().bits() as i32;
-                let pc = self.bg_pc[i].value().bits() as i32;
-                let pd = self.bg_pd[i].value().bits() as i32;
-                let refx = self.bg_refx_h[i].value().full_value(self.bg_refx_l[i].value());
-                let refy = self.bg_refy_h[i].value().full_value(self.bg_refy_l[i].value());
-                let wrap = !bg_cnt.contains(BgCnt::DISPLAY_OVERFLOW);
-
-                for y in 0..SCREEN_HEIGHT {
-                    for x in 0..SCREEN_WIDTH {
-                        let fx = refx + pa * x as i32 + pb * y as i32;
-                        let fy = refy + pc * x as i32 + pd * y as i32;
-                        let mut sx = (fx >> 8) as i32;
-                        let mut sy = (fy >> 8) as i32;
-
-                        if wrap {
-                            sx = sx.rem_euclid(map_w as i32);
-                            sy = sy.rem_euclid(map_h as i32);
-                        } else if sx < 0 || sx >= map_w as i32 || sy < 0 || sy >= map_h as i32 {
-                            continue;
-                        }
+        let palette_bank = if bpp == ColorDepth::Bpp4 {
+            &palette[tile_info.palette() * 16..][..16]
+        } else {
+            &palette[..256]
+        };
+        let tile_addr = tileset_addr + tile_info.tile_id(!screen_size.is_affine()) * tile_size;
+        self.tile_pixel(tile_addr as u32, &bpp, px, py, palette_bank)
+    }
 
-                        let color = tilemap[(sy as usize) * map_w + (sx as usize)];
-                        if color != Pixel::Transparent {
-                            layers[id][y][x] = color;
-                        }
-                    }
-                }
-            } else {
-                let vertical_offset = self.bg_vofs[id].value().offset();
-                let horizontal_offset = self.bg_hofs[id].value().offset();
-
-                let hoff = horizontal_offset % map_w;
-                let voff = vertical_offset % map_h;
-
-                for y in 0..SCREEN_HEIGHT {
-                    let src_y = (y + voff) % map_h;
-                    for x in 0..SCREEN_WIDTH {
-                        let src_x = (x + hoff) % map_w;
-                        let color = tilemap[src_y * map_w + src_x];
-                        if color != Pixel::Transparent {
-                            layers[id][y][x] = color;
-                        }
-                    }
+    fn render_tiled_line(
+        &self, id: usize, y: usize, origins: &[[i32; 2]; 2], palette: &[Pixel; PALETTE_TOTAL_ENTRIES],
+    ) -> [Pixel; SCREEN_WIDTH] {
+        let mut line = [Pixel::Transparent; SCREEN_WIDTH];
+
+        let bg_cnt = self.bg_cnt[id].value();
+        let screen_size = bg_cnt.screen_size(id, self.disp_cnt.value().bg_mode());
+        let (map_w, map_h) = (screen_size.width(), screen_size.height());
+
+        if screen_size.is_affine() {
+            let [ox, oy] = origins[id - 2];
+            let [dx, dy] = self.affine_step(id - 2);
+            let wrap = bg_cnt.contains(BgCnt::DISPLAY_OVERFLOW);
+
+            for (x, pixel) in line.iter_mut().enumerate() {
+                let mut sx = (ox + dx * x as i32) >> 8;
+                let mut sy = (oy + dy * x as i32) >> 8;
+
+                if wrap {
+                    sx = sx.rem_euclid(map_w as i32);
+                    sy = sy.rem_euclid(map_h as i32);
+                } else if sx < 0 || sx >= map_w as i32 || sy < 0 || sy >= map_h as i32 {
+                    continue;
                 }
+
+                *pixel = self.tilemap_pixel(bg_cnt, screen_size, sx as usize, sy as usize, palette);
             }
+        } else {
+            let hoff = self.bg_hofs[id].value().offset() % map_w;
+            let voff = self.bg_vofs[id].value().offset() % map_h;
+            let src_y = (y + voff) % map_h;
+            line = self.render_text_line(bg_cnt, screen_size, hoff, src_y, palette);
         }
 
-        layers
+        line
     }
 
-    fn render_background_mode3(&self, base_addr: u32) -> Frame {
-        trace!(target: "ppu", "Rendering background mode 3 @ {:08X}", base_addr);
+    // text backgrounds are decoded a tile row at a time, an unaligned line touches one more tile than fits
+    fn render_text_line(
+        &self, bg_cnt: &BgCnt, screen_size: InternalScreenSize, hoff: usize, src_y: usize,
+        palette: &[Pixel; PALETTE_TOTAL_ENTRIES],
+    ) -> [Pixel; SCREEN_WIDTH] {
+        const TILES: usize = SCREEN_WIDTH / 8 + 1;
+
+        let mut line = [Pixel::Transparent; SCREEN_WIDTH];
+        let bpp = bg_cnt.bpp();
+        let (tile_size, row_size) = if bpp == ColorDepth::Bpp4 { (0x20, 4) } else { (0x40, 8) };
+        let fine_x = hoff % 8;
+
+        let mut rows = [0u8; TILES * 8];
+        let mut banks = [0usize; TILES]; // first palette entry of every tile
+        let mut flipped = [false; TILES];
+
+        for tile in 0..TILES {
+            let map_x = (hoff - fine_x + tile * 8) % screen_size.width();
+            let tile_info = self.tilemap_entry(bg_cnt, screen_size, map_x / 8, src_y / 8);
+            let py = if tile_info.flip_y() { 7 - src_y % 8 } else { src_y % 8 };
+
+            let row_addr = bg_cnt.tileset_addr() as usize + tile_info.tile_id(true) * tile_size + py * row_size;
+            for (i, byte) in rows[tile * row_size..][..row_size].iter_mut().enumerate() {
+                *byte = self.read_byte((row_addr + i) as u32);
+            }
 
-        let mut frame = [[Pixel::Transparent; SCREEN_WIDTH]; SCREEN_HEIGHT];
+            banks[tile] = if bpp == ColorDepth::Bpp4 {
+                tile_info.palette() * 16
+            } else {
+                0
+            };
+            flipped[tile] = tile_info.flip_x();
+        }
 
-        for y in 0..SCREEN_HEIGHT {
-            for x in 0..SCREEN_WIDTH {
-                let addr = base_addr + ((y * SCREEN_WIDTH + x) as u32 * 2);
-                let rgb = self.read_u16(addr);
-                frame[y][x] = Self::extract_rgb(rgb);
+        let mut indices = [0u8; TILES * 8];
+        match bpp {
+            ColorDepth::Bpp4 => tile::expand_4bpp(&rows[..TILES * 4], &mut indices),
+            ColorDepth::Bpp8 => indices = rows,
+        }
+
+        for (tile, row) in indices.chunks_exact_mut(8).enumerate() {
+            if flipped[tile] {
+                row.reverse();
             }
         }
 
-        frame
+        for (x, pixel) in line.iter_mut().enumerate() {
+            let i = x + fine_x;
+            if indices[i] != 0 {
+                *pixel = palette[banks[i / 8] + indices[i] as usize];
+            }
+        }
+
+        line
     }
 
-    fn render_background_mode4(&self, base_addr: u32) -> Frame {
-        trace!(target: "ppu", "Rendering background mode 4 @ {:08X}", base_addr);
+    // how far the texture coordinates of an affine layer (BG2=0, BG3=1) move per pixel, 8.8 fixed point
+    fn affine_step(&self, i: usize) -> [i32; 2] {
+        [self.bg_pa[i].value().fixed_point(), self.bg_pc[i].value().fixed_point()]
+    }
 
-        let mut frame = [[Pixel::Transparent; SCREEN_WIDTH]; SCREEN_HEIGHT];
+    // modes 3 and 5 are 16-bit colors, mode 4 indexes the BG palette, mode 5 is only 160x128
+    fn bitmap_size(mode: u8) -> (usize, usize) {
+        if mode == 5 {
+            (160, 128)
+        } else {
+            (SCREEN_WIDTH, SCREEN_HEIGHT)
+        }
+    }
 
-        for y in 0..SCREEN_HEIGHT {
-            for x in 0..SCREEN_WIDTH {
-                let addr = base_addr + (y * SCREEN_WIDTH + x) as u32;
-                let idx = self.read(addr) as u32;
-                let rgb = self.read_u16(0x05000000 + (idx * 2));
-                frame[y][x] = Self::extract_rgb(rgb);
-            }
+    // single pixel of a bitmap, transparent outside of it
+    fn bitmap_pixel(&self, mode: u8, base_addr: u32, x: usize, y: usize) -> Pixel {
+        let (width, height) = Self::bitmap_size(mode);
+        if x >= width || y >= height {
+            return Pixel::Transparent;
         }
 
-        frame
+        let offset = (y * width + x) as u32;
+        match mode {
+            4 => {
+                let idx = self.read_byte(base_addr + offset) as u32;
+                Self::extract_rgb(self.read::<u16>(0x05000000 + (idx * 2)))
+            }
+            _ => Self::extract_rgb(self.read::<u16>(base_addr + offset * 2)),
+        }
     }
 
-    fn render_background_mode5(&self, base_addr: u32) -> Frame {
-        trace!(target: "ppu", "Rendering background mode 5 @ {:08X}", base_addr);
+    // address and raw value (BGR555 color, palette index in mode 4) of every screen pixel, none outside of the bitmap
+    pub fn bitmap_entries(&self, mode: u8, base_addr: u32) -> Vec<Option<(u32, u16)>> {
+        let (width, height) = Self::bitmap_size(mode);
 
-        let mut frame = [[Pixel::Transparent; SCREEN_WIDTH]; SCREEN_HEIGHT];
+        (0..SCREEN_HEIGHT)
+            .flat_map(|y| (0..SCREEN_WIDTH).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                if x >= width || y >= height {
+                    return None;
+                }
+
+                let offset = (y * width + x) as u32;
+                Some(match mode {
+                    4 => (base_addr + offset, self.read_byte(base_addr + offset) as u16),
+                    _ => (base_addr + offset * 2, self.read::<u16>(base_addr + offset * 2)),
+                })
+            })
+            .collect()
+    }
 
-        for y in 0..128 {
-            for x in 0..160 {
-                let addr = base_addr + ((y * SCREEN_WIDTH + x) as u32 * 2);
-                let rgb = self.read_u16(addr);
-                frame[y][x] = Self::extract_rgb(rgb);
+    // bitmaps are drawn through the BG2 matrix and never wrap around
+    fn render_bitmap_line(&self, mode: u8, base_addr: u32, origin: [i32; 2]) -> [Pixel; SCREEN_WIDTH] {
+        let mut line = [Pixel::Transparent; SCREEN_WIDTH];
+        let [ox, oy] = origin;
+        let [dx, dy] = self.affine_step(0);
+
+        for (x, pixel) in line.iter_mut().enumerate() {
+            let sx = (ox + dx * x as i32) >> 8;
+            let sy = (oy + dy * x as i32) >> 8;
+            if sx >= 0 && sy >= 0 {
+                *pixel = self.bitmap_pixel(mode, base_addr, sx as usize, sy as usize);
             }
         }
 
-        frame
+        line
     }
 
     fn point_in_window(&self, x: usize, y: usize, h: &WindowDimensions, v: &WindowDimensions) -> bool {
@@ -829,7 +1122,7 @@ impl Ppu {
         inside_x && inside_y
     }
 
-    fn window_region_for_pixel(&self, x: usize, y: usize) -> WindowRegion {
+    fn window_region_for_pixel(&self, x: usize, y: usize, obj_window: &[bool; SCREEN_WIDTH]) -> WindowRegion {
         let disp = self.disp_cnt.value();
 
         if disp.contains(DispCnt::WIN0_ON) && self.point_in_window(x, y, self.win0_h.value(), self.win0_v.value()) {
@@ -840,15 +1133,71 @@ impl Ppu {
             return WindowRegion::Win1;
         }
 
+        if obj_window[x] {
+            return WindowRegion::Obj;
+        }
+
         WindowRegion::Outside
     }
 
-    fn compose_layers(&self, bg_layers: &Vec<Frame>, sprite_frame: &Vec<(usize, Pixel)>) -> Frame {
-        assert_eq!(bg_layers.len(), 4, "Expected 4 background layers");
+    // the opaque pixels of the OBJ window sprites, only rendered if the window and OBJ are enabled
+    fn obj_window_line(&self, y: usize, palette: &[Pixel; PALETTE_TOTAL_ENTRIES]) -> [bool; SCREEN_WIDTH] {
+        let mut line = [false; SCREEN_WIDTH];
+        if self.disp_cnt.value().contains(DispCnt::OBJ_WIN_ON) && self.disp_cnt.value().contains(DispCnt::OBJ_ON) {
+            let sprites = self.render_sprite_line(y, palette, true);
+            for (covered, (_, color, _)) in line.iter_mut().zip(sprites.iter()) {
+                *covered = *color != Pixel::Transparent;
+            }
+        }
+        line
+    }
 
+    // the window of every screen pixel as the current registers and OAM would draw it, for the debugger
+    pub fn window_regions(&self) -> Vec<WindowRegion> {
         let palette = self.fetch_palette();
+        (0..SCREEN_HEIGHT)
+            .flat_map(|y| {
+                let obj_window = self.obj_window_line(y, &palette);
+                (0..SCREEN_WIDTH)
+                    .map(|x| self.window_region_for_pixel(x, y, &obj_window))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    // the two front-most of BG0-BG3 and OBJ (4) as (layer, color), falling back to the backdrop (5). lower
+    // priority values are in front, on ties OBJ is in front of the BGs and lower BGs are in front of higher ones.
+    // https://problemkaputt.de/gbatek.htm#lcdiobgcontrol
+    pub(crate) fn front_layers(layers: &[Option<(usize, Pixel)>; 5], backdrop: Pixel) -> [(usize, Pixel); 2] {
+        let mut front = [(5, backdrop), (5, Pixel::Transparent)];
+        let mut found = 0;
+
+        for priority in 0..4 {
+            for layer in [4, 0, 1, 2, 3] {
+                match layers[layer] {
+                    Some((p, color)) if p == priority && found < 2 => {
+                        front[found] = (layer, color);
+                        found += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // the backdrop is behind everything
+        if found < 2 {
+            front[found] = (5, backdrop);
+        }
+
+        front
+    }
+
+    fn compose_line(
+        &self, y: usize, bg_lines: &[[Pixel; SCREEN_WIDTH]; 4], sprite_line: &SpriteLine,
+        obj_window: &[bool; SCREEN_WIDTH], palette: &[Pixel; PALETTE_TOTAL_ENTRIES],
+    ) -> [u32; SCREEN_WIDTH] {
         let backdrop = palette[0];
-        let mut frame = [[backdrop; SCREEN_WIDTH]; SCREEN_HEIGHT];
+        let mut frame_row = [backdrop.pack(); SCREEN_WIDTH];
 
         let winin = self.winin.value();
         let winout = self.winout.value();
@@ -859,12 +1208,12 @@ impl Ppu {
         let windows_active = win0_on || win1_on || objwin_on;
 
         let master_bg = [
-            self.disp_cnt.value().contains(DispCnt::BG0_ON),
-            self.disp_cnt.value().contains(DispCnt::BG1_ON),
-            self.disp_cnt.value().contains(DispCnt::BG2_ON),
-            self.disp_cnt.value().contains(DispCnt::BG3_ON),
+            self.disp_cnt.value().contains(DispCnt::BG0_ON) && self.layer_enabled[0],
+            self.disp_cnt.value().contains(DispCnt::BG1_ON) && self.layer_enabled[1],
+            self.disp_cnt.value().contains(DispCnt::BG2_ON) && self.layer_enabled[2],
+            self.disp_cnt.value().contains(DispCnt::BG3_ON) && self.layer_enabled[3],
         ];
-        let master_obj = self.disp_cnt.value().contains(DispCnt::OBJ_ON);
+        let master_obj = self.disp_cnt.value().contains(DispCnt::OBJ_ON) && self.layer_enabled[4];
 
         let bg_enabled = |region: WindowRegion, id: usize| -> bool {
             if !master_bg[id] {
@@ -878,6 +1227,7 @@ impl Ppu {
             match region {
                 WindowRegion::Win0 => winin.is_bg_enabled_win0(id),
                 WindowRegion::Win1 => winin.is_bg_enabled_win1(id),
+                WindowRegion::Obj => winout.is_bg_enabled_win1(id), // the OBJ window is the upper half of WINOUT
                 WindowRegion::Outside => winout.is_bg_enabled_out(id),
             }
         };
@@ -894,10 +1244,23 @@ impl Ppu {
             match region {
                 WindowRegion::Win0 => winin.obj_enabled_win0(),
                 WindowRegion::Win1 => winin.obj_enabled_win1(),
+                WindowRegion::Obj => winout.obj_enabled_win1(),
                 WindowRegion::Outside => winout.obj_enabled_out(),
             }
         };
-        let bg_mode = self.disp_cnt.value().bg_mode();
+
+        let effects_enabled = |region: WindowRegion| -> bool {
+            if !windows_active {
+                return true;
+            }
+
+            match region {
+                WindowRegion::Win0 => winin.effects_enabled_win0(),
+                WindowRegion::Win1 => winin.effects_enabled_win1(),
+                WindowRegion::Obj => winout.effects_enabled_win1(),
+                WindowRegion::Outside => winout.effects_enabled_out(),
+            }
+        };
 
         let bg_priorities = [
             self.bg_cnt[0].value().priority(),
@@ -906,58 +1269,40 @@ impl Ppu {
             self.bg_cnt[3].value().priority(),
         ];
 
-        // Determine which backgrounds to process based on mode
-        let (start_bg, end_bg) = if bg_mode >= 3 { (2, 2) } else { (0, 3) };
-
-        for y in 0..SCREEN_HEIGHT {
-            let sprite_row_start = y * SCREEN_WIDTH;
-            let frame_row = &mut frame[y];
-
-            for x in 0..SCREEN_WIDTH {
-                let region = self.window_region_for_pixel(x, y);
+        for x in 0..SCREEN_WIDTH {
+            let region = self.window_region_for_pixel(x, y, obj_window);
 
-                // Collect visible surfaces at this pixel
-                let mut surfaces: Vec<(usize, Pixel, usize, usize)> = Vec::new();
-
-                // Backdrop always present
-                surfaces.push((5, backdrop, 4, 5));
-
-                // Background layers
-                for id in start_bg..=end_bg {
-                    if !bg_enabled(region, id) {
-                        continue;
-                    }
-
-                    let layer_color = bg_layers[id][y][x];
-                    if layer_color != Pixel::Transparent {
-                        let priority = bg_priorities[id];
-                        let order = id + 1; // BG0=1 .. BG3=4
-                        surfaces.push((id, layer_color, priority, order));
-                    }
+            // visible pixel and priority of every layer, layers that don't exist in this mode are transparent
+            let mut layers = [None; 5];
+            for id in 0..4 {
+                if bg_enabled(region, id) && bg_lines[id][x] != Pixel::Transparent {
+                    layers[id] = Some((bg_priorities[id], bg_lines[id][x]));
                 }
+            }
 
-                // Sprite layer
-                let sprite_idx = sprite_row_start + x;
-                let (sprite_priority, sprite_color) = sprite_frame[sprite_idx];
-                if obj_enabled(region) && sprite_color != Pixel::Transparent {
-                    surfaces.push((4, sprite_color, sprite_priority, 0));
-                }
+            let (sprite_priority, sprite_color, semi_transparent) = sprite_line[x];
+            if obj_enabled(region) && sprite_color != Pixel::Transparent {
+                layers[4] = Some((sprite_priority, sprite_color));
+            }
 
-                // Sort by priority then order
-                surfaces.sort_by(|a, b| match a.2.cmp(&b.2) {
-                    std::cmp::Ordering::Equal => a.3.cmp(&b.3),
-                    ord => ord,
-                });
+            let [(top_layer, top_color), (second_layer, second_color)] = Self::front_layers(&layers, backdrop);
 
-                let (top_layer, top_color, _, _) = surfaces[0];
-                let second = surfaces.get(1).copied().unwrap_or((5, Pixel::Transparent, 4, 5));
-                let (second_layer, second_color, _, _) = second;
+            let bld_cnt = self.bld_cnt.value();
+            let eva = self.bld_alpha.value().eva();
+            let evb = self.bld_alpha.value().evb();
 
-                let bld_cnt = self.bld_cnt.value();
-                let final_color = match bld_cnt.sfx() {
+            // https://problemkaputt.de/gbatek.htm#lcdiocolorspecialeffects
+            // semi-transparent sprites are always a 1st target and always alpha blended, regardless of BLDCNT.
+            // if they don't overlap a 2nd target the regular effect applies
+            let final_color = if !effects_enabled(region) {
+                top_color
+            } else if top_layer == 4 && semi_transparent && bld_cnt.is_second_target(second_layer) {
+                top_color.blend(second_color, eva, evb)
+            } else {
+                match bld_cnt.sfx() {
                     Sfx::AlphaBlend => {
                         if bld_cnt.is_first_target(top_layer) && bld_cnt.is_second_target(second_layer) {
-                            top_color.blend(second_color, self.bld_alpha.value().eva(), self.bld_alpha.value().evb())
+                            top_color.blend(second_color, eva, evb)
                         } else {
                             top_color
                         }
@@ -977,12 +1322,20 @@ impl Ppu {
                         }
                     }
                     Sfx::None => top_color,
-                };
-                frame_row[x] = final_color;
+                }
+            };
+            frame_row[x] = final_color.pack();
+        }
+
+        if self.green_swap.value() & 1 != 0 {
+            for pair in frame_row.chunks_exact_mut(2) {
+                let (g0, g1) = (pair[0] & 0xFF00, pair[1] & 0xFF00);
+                pair[0] = (pair[0] & !0xFF00) | g1;
+                pair[1] = (pair[1] & !0xFF00) | g0;
             }
         }
 
-        frame
+        frame_row
     }
 
     fn extract_rgb(rgb: u16) -> Pixel {
@@ -999,9 +1352,10 @@ impl Ppu {
 }
 
 impl Addressable for Ppu {
-    fn read(&self, addr: u32) -> u8 {
+    fn read_byte(&self, addr: u32) -> u8 {
         match addr {
             0x04000000..=0x04000001 => self.disp_cnt.read(addr),     // DISPCNT
+            0x04000002..=0x04000003 => self.green_swap.read(addr),   // GREENSWAP
             0x04000004..=0x04000005 => self.disp_stat.read(addr),    // DISPSTAT
             0x04000006..=0x04000007 => self.scanline.read(addr),     // VCOUNT
             0x04000008..=0x04000009 => self.bg_cnt[0].read(addr),    // BG0CNT
@@ -1051,10 +1405,17 @@ impl Addressable for Ppu {
         }
     }
 
-    fn write(&mut self, addr: u32, value: u8) {
+    fn write_byte(&mut self, addr: u32, value: u8) {
         match addr {
             0x04000000..=0x04000001 => self.disp_cnt.write(addr, value), // DISPCNT
-            0x04000004..=0x04000005 => self.disp_stat.write(addr, value), // DISPSTAT
+            0x04000002..=0x04000003 => self.green_swap.write(addr, value), // GREENSWAP
+            0x04000004 => {
+                // DISPSTAT, the status flags are read-only
+                let flags = DispStat::VBLANK_FLAG | DispStat::HBLANK_FLAG | DispStat::VCOUNTER_FLAG;
+                let status = self.disp_stat.value().bits() as u8 & flags.bits() as u8;
+                self.disp_stat.write(addr, (value & !(flags.bits() as u8)) | status);
+            }
+            0x04000005 => self.disp_stat.write(addr, value), // DISPSTAT
             0x04000006..=0x04000007 => self.scanline.write(addr, value), // VCOUNT
             0x04000008..=0x04000009 => self.bg_cnt[0].write(addr, value), // BG0CNT
             0x0400000A..=0x0400000B => self.bg_cnt[1].write(addr, value), // BG1CNT
@@ -1084,15 +1445,15 @@ impl Addressable for Ppu {
             0x0400003A..=0x0400003B => self.bg_refx_h[1].write(addr, value), // BG3X_H
             0x0400003C..=0x0400003D => self.bg_refy_l[1].write(addr, value), // BG3Y_L
             0x0400003E..=0x0400003F => self.bg_refy_h[1].write(addr, value), // BG3Y_H
-            0x04000040..=0x04000041 => self.win0_h.write(addr, value),   // WIN0H
-            0x04000042..=0x04000043 => self.win1_h.write(addr, value),   // WIN1H
-            0x04000044..=0x04000045 => self.win0_v.write(addr, value),   // WIN0V
-            0x04000046..=0x04000047 => self.win1_v.write(addr, value),   // WIN1V
-            0x04000048..=0x04000049 => self.winin.write(addr, value),    // WININ
-            0x0400004A..=0x0400004B => self.winout.write(addr, value),   // WINOUT
-            0x04000050..=0x04000051 => self.bld_cnt.write(addr, value),  // BLDCNT
+            0x04000040..=0x04000041 => self.win0_h.write(addr, value), // WIN0H
+            0x04000042..=0x04000043 => self.win1_h.write(addr, value), // WIN1H
+            0x04000044..=0x04000045 => self.win0_v.write(addr, value), // WIN0V
+            0x04000046..=0x04000047 => self.win1_v.write(addr, value), // WIN1V
+            0x04000048..=0x04000049 => self.winin.write(addr, value), // WININ
+            0x0400004A..=0x0400004B => self.winout.write(addr, value), // WINOUT
+            0x04000050..=0x04000051 => self.bld_cnt.write(addr, value), // BLDCNT
             0x04000052..=0x04000053 => self.bld_alpha.write(addr, value), // BLDALPHA
-            0x04000054..=0x04000054 => self.bld_y.write(addr, value),    // BLDY
+            0x04000054..=0x04000054 => self.bld_y.write(addr, value), // BLDY
             // rest of the registers
             0x04000000..=0x04000056 => {
                 error!(target: "ppu", "Writing to unmapped I/O address: {:08X} with value: {:02X}", addr, value);
@@ -1104,5 +1465,12 @@ impl Addressable for Ppu {
             }
             _ => unreachable!(),
         }
+
+        // reference point writes go to the internal copy right away, so they take effect from the next line on
+        if let 0x04000028..=0x0400002F | 0x04000038..=0x0400003F = addr {
+            let i = ((addr - 0x04000028) / 0x10) as usize;
+            let axis = ((addr & 0xF) - 8) as usize / 4;
+            self.bg_ref[i][axis] = self.reference_point(i)[axis];
+        }
     }
 }
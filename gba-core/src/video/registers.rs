@@ -1,6 +1,5 @@
 use super::{FRAME_0_ADDRESS, FRAME_1_ADDRESS, TILEMAP_ENTRY_SIZE, TILESET_ENTRY_SIZE};
 use bitflags::bitflags;
-use tracing::warn;
 
 bitflags! {
     #[derive(Default, Copy, Clone)]
@@ -47,6 +46,13 @@ bitflags! {
     }
 }
 
+impl DispStat {
+    // the scanline that sets the V-counter flag and raises the VCOUNT interrupt
+    pub fn v_count_setting(&self) -> u16 {
+        (self.bits() & DispStat::V_COUNT_SETTING.bits()) >> 8
+    }
+}
+
 #[derive(PartialEq)]
 pub enum Dimension {
     OneDimensional,
@@ -122,6 +128,16 @@ impl InternalScreenSize {
             InternalScreenSize::Affine1024x1024 => 1024,
         }
     }
+
+    pub fn is_affine(&self) -> bool {
+        matches!(
+            self,
+            InternalScreenSize::Affine128x128
+                | InternalScreenSize::Affine256x256
+                | InternalScreenSize::Affine512x512
+                | InternalScreenSize::Affine1024x1024
+        )
+    }
 }
 
 impl std::fmt::Display for InternalScreenSize {
@@ -248,12 +264,13 @@ impl ObjAttribute0 {
         }
     }
 
+    // rotation/scaling sprites can't be disabled, the bit selects double size for them
     pub fn disabled(&self) -> bool {
-        if self.contains(ObjAttribute0::ROTATION_SCALING) {
-            warn!(target: "ppu", "DISABLE flag cannot be used with rotation/scaling");
-        }
+        !self.is_affine() && self.contains(ObjAttribute0::DISABLE_OR_DBL_SIZE)
+    }
 
-        self.contains(ObjAttribute0::DISABLE_OR_DBL_SIZE)
+    pub fn double_size(&self) -> bool {
+        self.is_affine() && self.contains(ObjAttribute0::DISABLE_OR_DBL_SIZE)
     }
 
     pub fn bpp(&self) -> ColorDepth {
@@ -267,6 +284,23 @@ impl ObjAttribute0 {
     pub fn is_affine(&self) -> bool {
         self.contains(ObjAttribute0::ROTATION_SCALING)
     }
+
+    pub fn mode(&self) -> ObjMode {
+        match (*self & ObjAttribute0::OBJ_MODE).bits() >> 10 {
+            0 => ObjMode::Normal,
+            1 => ObjMode::SemiTransparent,
+            2 => ObjMode::Window,
+            _ => ObjMode::Prohibited,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ObjMode {
+    Normal,
+    SemiTransparent, // always blended with the layer beneath if that is a 2nd target
+    Window,
+    Prohibited,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -328,6 +362,11 @@ impl ObjAttribute1 {
         self.contains(ObjAttribute1::Y_FLIP)
     }
 
+    // rotation/scaling parameter group, replaces the flip bits for affine sprites
+    pub fn affine_group(&self) -> usize {
+        ((self.bits() >> 9) & 0b1_1111) as usize
+    }
+
     pub fn size(&self, shape: ObjShape) -> ObjSize {
         match (*self & ObjAttribute1::OBJ_SIZE).bits() {
             0b0000_0000_0000_0000 if shape == ObjShape::Square => ObjSize::Square8x8,
@@ -440,6 +479,18 @@ impl WindowControl {
     pub fn is_bg_enabled_out(&self, id: usize) -> bool {
         self.is_bg_enabled_win0(id)
     }
+
+    pub fn effects_enabled_win0(&self) -> bool {
+        self.contains(WindowControl::WIN0_COLOR_SPECIAL)
+    }
+
+    pub fn effects_enabled_win1(&self) -> bool {
+        self.contains(WindowControl::WIN1_COLOR_SPECIAL)
+    }
+
+    pub fn effects_enabled_out(&self) -> bool {
+        self.effects_enabled_win0()
+    }
 }
 
 pub enum Sfx {
@@ -571,6 +622,13 @@ bitflags! {
     }
 }
 
+impl BgAffineParam {
+    // signed 8.8 fixed point
+    pub fn fixed_point(&self) -> i32 {
+        self.bits() as i16 as i32
+    }
+}
+
 bitflags! {
     #[derive(Default, Copy, Clone)]
     pub struct BgRefPointLow: u16 {
@@ -45,18 +45,12 @@ impl Tile {
 
     fn parse_as_4bpp(bytes: &[u8], palette: &[Pixel]) -> [Pixel; 64] {
         let mut pixels = [Pixel::Transparent; 64];
+        let mut indices = [0u8; 64];
+        expand_4bpp(bytes, &mut indices);
 
-        for i in 0..32 {
-            let left_pixel = bytes[i] & 0x0F;
-            let right_pixel = (bytes[i] & 0xF0) >> 4;
-
-            let idx = i * 2;
-            if left_pixel != 0 {
-                pixels[idx] = palette[left_pixel as usize];
-            }
-
-            if right_pixel != 0 {
-                pixels[idx + 1] = palette[right_pixel as usize];
+        for (pixel, &index) in pixels.iter_mut().zip(indices.iter()) {
+            if index != 0 {
+                *pixel = palette[index as usize];
             }
         }
 
@@ -77,6 +71,41 @@ impl Tile {
     }
 }
 
+// expands packed 4bpp pixels into one palette index per byte, the low nibble is the left pixel.
+// indices has to be twice as long as bytes
+#[cfg(not(feature = "simd"))]
+pub fn expand_4bpp(bytes: &[u8], indices: &mut [u8]) {
+    expand_4bpp_scalar(bytes, indices);
+}
+
+// same as above, 16 bytes at a time
+#[cfg(feature = "simd")]
+pub fn expand_4bpp(bytes: &[u8], indices: &mut [u8]) {
+    use std::simd::u8x16;
+
+    let chunks = bytes.chunks_exact(16);
+    let remainder = chunks.remainder();
+    let mut out = indices.chunks_exact_mut(32);
+
+    for (chunk, out) in chunks.zip(out.by_ref()) {
+        let packed = u8x16::from_slice(chunk);
+        let (left, right) = (packed & u8x16::splat(0x0F), packed >> 4);
+        let (first, second) = left.interleave(right);
+        first.copy_to_slice(&mut out[..16]);
+        second.copy_to_slice(&mut out[16..]);
+    }
+
+    let tail = &mut indices[(bytes.len() - remainder.len()) * 2..];
+    expand_4bpp_scalar(remainder, tail);
+}
+
+fn expand_4bpp_scalar(bytes: &[u8], indices: &mut [u8]) {
+    for (&byte, pair) in bytes.iter().zip(indices.chunks_exact_mut(2)) {
+        pair[0] = byte & 0x0F;
+        pair[1] = byte >> 4;
+    }
+}
+
 impl Default for Tile {
     fn default() -> Self {
         Tile {
@@ -138,3 +167,39 @@ impl TileInfo {
         ((self.bits() & TileInfo::PALETTE.bits()) >> 12) as usize
     }
 }
+
+#[cfg(all(test, feature = "simd"))]
+mod tests {
+    use super::*;
+
+    fn bytes(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 37 + 11) as u8).collect()
+    }
+
+    #[test]
+    fn simd_matches_scalar() {
+        // a whole tile, and lengths that leave a tail for the scalar loop
+        for len in [0x20, 0x40, 0x23, 7] {
+            let bytes = bytes(len);
+            let (mut simd, mut scalar) = (vec![0u8; len * 2], vec![0u8; len * 2]);
+            expand_4bpp(&bytes, &mut simd);
+            expand_4bpp_scalar(&bytes, &mut scalar);
+            assert_eq!(simd, scalar, "{} bytes", len);
+        }
+    }
+
+    #[test]
+    fn tile_4bpp_matches_8bpp() {
+        let palette: Vec<Pixel> = (0..=255u8).map(|i| Pixel::Rgb(i, !i, i ^ 0x5A)).collect();
+        let bytes = bytes(0x20);
+
+        // the same tile with one palette index per byte
+        let mut indices = [0u8; 0x40];
+        expand_4bpp_scalar(&bytes, &mut indices);
+
+        let tile_4bpp = Tile::from_bytes(&bytes, &palette);
+        let tile_8bpp = Tile::from_bytes(&indices, &palette);
+        assert_eq!(tile_4bpp.pixels, tile_8bpp.pixels);
+        assert!(tile_8bpp.pixels.contains(&Pixel::Transparent));
+    }
+}
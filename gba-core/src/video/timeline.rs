@@ -0,0 +1,54 @@
+pub const LINE_DOTS: u16 = 240; // PPU ticks per scanline
+pub const FRAME_LINES: u16 = 228; // including VBlank
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimelineEvent {
+    VBlank,
+    HBlank,
+    VCount,
+    DispCnt(u16), // value after the write
+    BldCnt(u16),  // value after the write
+    Dma(usize),   // channel that started a transfer
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TimelineEntry {
+    pub scanline: u16,
+    pub dot: u16, // h_counter of the PPU, there is no separate HBlank period so it starts together with the next line
+    pub event: TimelineEvent,
+}
+
+// where in the frame the raster events happen, keeps the last completed frame next to the one being drawn
+#[derive(Clone)]
+pub struct PpuTimeline {
+    current: Vec<TimelineEntry>,
+    last: Vec<TimelineEntry>,
+}
+
+impl PpuTimeline {
+    pub fn new() -> PpuTimeline {
+        PpuTimeline {
+            current: Vec::new(),
+            last: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, scanline: u16, dot: u16, event: TimelineEvent) {
+        self.current.push(TimelineEntry { scanline, dot, event });
+    }
+
+    // called when the PPU wraps around to the first line
+    pub fn finish_frame(&mut self) {
+        self.last = std::mem::take(&mut self.current);
+    }
+
+    pub fn last_frame(&self) -> &[TimelineEntry] {
+        &self.last
+    }
+}
+
+impl Default for PpuTimeline {
+    fn default() -> Self {
+        PpuTimeline::new()
+    }
+}
@@ -1,5 +1,5 @@
 use gba_core::arm7tdmi::error::CpuError;
-use gba_core::gba::Gba;
+use gba_core::gba::{BootOptions, Gba};
 use gba_core::video::Frame;
 use std::fs::File;
 use std::io::{Cursor, Read};
@@ -22,8 +22,14 @@ impl Emulator {
             rom_data = Self::unzip_archive(&rom_data);
         }
 
-        let mut gba = Gba::new(&rom_data, &[]);
-        gba.cpu.skip_bios();
+        let gba = Gba::new(
+            &rom_data,
+            &[],
+            BootOptions {
+                skip_bios: true,
+                ..Default::default()
+            },
+        );
 
         Self {
             gba,
@@ -31,7 +37,7 @@ impl Emulator {
         }
     }
 
-    pub fn run_to_frame(&mut self) -> Option<Frame> {
+    pub fn run_to_frame(&mut self) -> Option<Box<Frame>> {
         let mut i = 0;
         loop {
             if i > 100_000_000 {
@@ -2,29 +2,33 @@ mod emulator;
 
 use emulator::Emulator;
 use gba_core::input::registers::KeyInput;
-use gba_core::video::{Frame, Pixel, SCREEN_HEIGHT, SCREEN_WIDTH};
-use image::{ImageBuffer, Rgb, RgbImage};
+use gba_core::video::{Frame, SCREEN_HEIGHT, SCREEN_WIDTH, frame_to_rgb};
+use image::RgbImage;
+use std::path::Path;
+use video_dump::VideoDumper;
 
-fn write_png(frame: &Frame, path: &str) {
-    let w = SCREEN_WIDTH as u32;
-    let h = SCREEN_HEIGHT as u32;
+const USAGE: &str = "Usage: rom-db <rom_path> [--dump-video <out.mkv> | --test <script.rhai>]";
 
-    let img: RgbImage = ImageBuffer::from_fn(w, h, |x, y| match frame[y as usize][x as usize] {
-        Pixel::Transparent => Rgb([0, 0, 0]),
-        Pixel::Rgb(r, g, b) => Rgb([r, g, b]),
-    });
-
-    img.save(path).unwrap()
+fn write_png(frame: &Frame, path: &str) -> image::ImageResult<()> {
+    let img = RgbImage::from_raw(SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32, frame_to_rgb(frame)).unwrap();
+    img.save(path)
 }
 
-fn emulate_rom(rom_path: String, output_path: String) {
+fn emulate_rom(rom_path: String, output_path: String, dump_path: Option<String>) {
     std::fs::create_dir_all(&output_path).expect("Failed to create output directory");
 
     let mut emulator = Emulator::new(rom_path);
     let mut toggle_joypad = false;
+    let mut video_dump =
+        dump_path.map(|path| VideoDumper::create(Path::new(&path)).expect("Failed to start ffmpeg, is it installed?"));
 
     for i in 0usize..10000 {
         if let Some(frame) = emulator.run_to_frame() {
+            if let Some(video_dump) = &mut video_dump {
+                video_dump.push_frame(&frame);
+                video_dump.push_samples(&emulator.gba.cpu.mmio.apu.drain_samples());
+            }
+
             if i == 5000 {
                 toggle_joypad = true;
             }
@@ -44,20 +48,50 @@ fn emulate_rom(rom_path: String, output_path: String) {
 
             if i % 500 == 0 && i != 0 {
                 let image_path = format!("{}/{}.png", output_path, i);
-                write_png(&frame, &image_path);
+                write_png(&frame, &image_path).expect("Failed to save screenshot");
             }
         } else {
             break;
         }
     }
+
+    if let Some(video_dump) = video_dump {
+        video_dump.finish().expect("Failed to finish the video dump");
+    }
+}
+
+// regression tests for game specific bugs, the script plays the game and asserts on memory and registers
+fn test_rom(rom_path: String, script_path: &Path) -> bool {
+    let mut emulator = Emulator::new(rom_path);
+    let report = emulator.gba.run_test_script(script_path);
+
+    for failure in &report.failures {
+        println!("FAIL {}", failure);
+    }
+    println!(
+        "{}: {} passed, {} failed",
+        script_path.display(),
+        report.passed,
+        report.failures.len()
+    );
+    report.success()
 }
 
 fn main() {
     let rom_path = std::env::args().nth(1).unwrap_or_else(|| {
-        println!("Usage: rom-db <rom_path>");
+        println!("{}", USAGE);
         std::process::exit(1);
     });
 
+    if std::env::args().nth(2).as_deref() == Some("--test") {
+        let script_path = std::env::args().nth(3).unwrap_or_else(|| {
+            println!("{}", USAGE);
+            std::process::exit(1);
+        });
+        let passed = test_rom(rom_path, Path::new(&script_path));
+        std::process::exit(if passed { 0 } else { 1 });
+    }
+
     const OUTPUT_FOLDER: &str = "rom-db-ui/public/screenshots";
     std::fs::create_dir_all(OUTPUT_FOLDER).expect("Failed to create output directory");
 
@@ -65,5 +99,14 @@ fn main() {
     let rom_name = rom_path.file_stem().unwrap_or_default();
     let output_path = format!("{}/{}", OUTPUT_FOLDER, rom_name.to_string_lossy());
 
-    emulate_rom(rom_path.to_string_lossy().to_string(), output_path);
+    // the video covers the whole run, not just the screenshots
+    let dump_path = match std::env::args().nth(2).as_deref() {
+        Some("--dump-video") => Some(std::env::args().nth(3).unwrap_or_else(|| {
+            println!("{}", USAGE);
+            std::process::exit(1);
+        })),
+        _ => None,
+    };
+
+    emulate_rom(rom_path.to_string_lossy().to_string(), output_path, dump_path);
 }
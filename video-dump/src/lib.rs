@@ -0,0 +1,150 @@
+use gba_core::audio::apu::{CPU_CLOCK, SAMPLE_RATE};
+use gba_core::video::{Frame, SCREEN_HEIGHT, SCREEN_WIDTH, frame_to_rgb};
+use std::io::{ErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{Receiver, Sender, TryRecvError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tracing::error;
+
+const CYCLES_PER_FRAME: u32 = 228 * 1232; // 228 lines of 1232 cycles, about 59.73 fps
+
+// pipes every frame and sample into an ffmpeg child process which encodes them losslessly (FFV1 and FLAC),
+// ffmpeg only reads a single input from stdin so the audio is streamed over a local TCP connection,
+// dropping the dumper finishes the video as well
+pub struct VideoDumper {
+    pub path: PathBuf,
+    ffmpeg: Child,
+    frame_tx: Option<Sender<Vec<u8>>>,
+    sample_tx: Option<Sender<Vec<u8>>>,
+    writers: Vec<JoinHandle<()>>,
+}
+
+impl VideoDumper {
+    // the container is picked by ffmpeg from the extension, .mkv holds both codecs
+    pub fn create(path: &Path) -> std::io::Result<VideoDumper> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let audio_url = format!("tcp://{}", listener.local_addr()?);
+
+        let mut ffmpeg = Command::new("ffmpeg")
+            .args(["-hide_banner", "-loglevel", "error", "-y"])
+            .args(["-f", "rawvideo", "-pixel_format", "rgb24"])
+            .args(["-video_size", &format!("{}x{}", SCREEN_WIDTH, SCREEN_HEIGHT)])
+            .args(["-framerate", &format!("{}/{}", CPU_CLOCK, CYCLES_PER_FRAME)])
+            .args(["-i", "pipe:0"])
+            .args(["-f", "s16le", "-ar", &SAMPLE_RATE.to_string(), "-ac", "2"])
+            .args(["-i", &audio_url])
+            .args(["-c:v", "ffv1", "-c:a", "flac"])
+            .arg(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()?;
+
+        // the writers have their own threads, ffmpeg may wait on one input while the pipe of the other one is full
+        let (frame_tx, frame_rx) = std::sync::mpsc::channel();
+        let (sample_tx, sample_rx) = std::sync::mpsc::channel();
+        let stdin = ffmpeg.stdin.take().unwrap();
+        let video_path = path.to_path_buf();
+        let audio_path = path.to_path_buf();
+
+        let writers = vec![
+            std::thread::spawn(move || Self::write_stream(stdin, frame_rx, &video_path)),
+            std::thread::spawn(move || {
+                let mut pending = Vec::new();
+                match Self::accept_audio(listener, &sample_rx, &mut pending) {
+                    Ok(Some(stream)) => Self::write_stream(stream, pending.into_iter().chain(sample_rx), &audio_path),
+                    Ok(None) => {}
+                    Err(e) => error!(target: "ppu", "Failed to stream the audio to {}: {}", audio_path.display(), e),
+                }
+            }),
+        ];
+
+        Ok(VideoDumper {
+            path: path.to_path_buf(),
+            ffmpeg,
+            frame_tx: Some(frame_tx),
+            sample_tx: Some(sample_tx),
+            writers,
+        })
+    }
+
+    // the samples pushed until ffmpeg connects are queued, gives up if the dump is finished before that
+    fn accept_audio(
+        listener: TcpListener, sample_rx: &Receiver<Vec<u8>>, pending: &mut Vec<Vec<u8>>,
+    ) -> std::io::Result<Option<TcpStream>> {
+        listener.set_nonblocking(true)?;
+        loop {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    stream.set_nonblocking(false)?;
+                    return Ok(Some(stream));
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => match sample_rx.try_recv() {
+                    Ok(samples) => pending.push(samples),
+                    Err(TryRecvError::Empty) => std::thread::sleep(Duration::from_millis(1)),
+                    Err(TryRecvError::Disconnected) => return Ok(None),
+                },
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn write_stream(mut writer: impl Write, chunks: impl IntoIterator<Item = Vec<u8>>, path: &Path) {
+        for chunk in chunks {
+            if let Err(e) = writer.write_all(&chunk) {
+                error!(target: "ppu", "Failed to dump to {}: {}", path.display(), e);
+                return;
+            }
+        }
+    }
+
+    pub fn push_frame(&mut self, frame: &Frame) {
+        if let Some(frame_tx) = &self.frame_tx {
+            let _ = frame_tx.send(frame_to_rgb(frame));
+        }
+    }
+
+    // interleaved stereo, as mixed by the APU
+    pub fn push_samples(&mut self, samples: &[[i16; 2]]) {
+        if let Some(sample_tx) = &self.sample_tx {
+            let _ = sample_tx.send(
+                samples
+                    .iter()
+                    .flatten()
+                    .flat_map(|sample| sample.to_le_bytes())
+                    .collect(),
+            );
+        }
+    }
+
+    pub fn finish(mut self) -> std::io::Result<()> {
+        self.close()
+    }
+
+    // closes both inputs and waits for ffmpeg to write the remaining frames, only the first call does anything
+    fn close(&mut self) -> std::io::Result<()> {
+        if self.frame_tx.take().is_none() {
+            return Ok(());
+        }
+        self.sample_tx = None;
+        for writer in self.writers.drain(..) {
+            let _ = writer.join();
+        }
+
+        let status = self.ffmpeg.wait()?;
+        if !status.success() {
+            return Err(std::io::Error::other(format!("ffmpeg exited with {}", status)));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for VideoDumper {
+    fn drop(&mut self) {
+        if let Err(e) = self.close() {
+            error!(target: "ppu", "Failed to finish {}: {}", self.path.display(), e);
+        }
+    }
+}